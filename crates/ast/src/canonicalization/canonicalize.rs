@@ -249,7 +249,7 @@ pub(crate) fn canonicalize_when_branch<'a>(
             && !branch_output.references.has_lookup(symbol)
             && !original_scope.contains_symbol(symbol)
         {
-            env.problem(Problem::UnusedDef(symbol, region));
+            env.problem(Problem::UnusedDef(symbol, region, None));
         }
     }
 
@@ -458,6 +458,7 @@ pub fn expr_to_expr2<'a>(
                             is_anonymous,
                             sub_symbol,
                             region,
+                            None,
                         ));
                     }
 
@@ -604,7 +605,7 @@ pub fn expr_to_expr2<'a>(
             // we defined went unused by the return expression. If any were unused, report it.
             for (symbol, region) in symbols_introduced {
                 if !output.references.has_lookup(symbol) {
-                    env.problem(Problem::UnusedDef(symbol, region));
+                    env.problem(Problem::UnusedDef(symbol, region, None));
                 }
             }
 
@@ -1,5 +1,5 @@
 use bumpalo::Bump;
-use roc_load::{ExecutionMode, LoadConfig, LoadedModule, Threading};
+use roc_load::{EmitIr, ExecutionMode, LoadConfig, LoadedModule, Threading};
 use roc_packaging::cache::RocCacheDir;
 use roc_reporting::report::DEFAULT_PALETTE;
 use roc_target::TargetInfo;
@@ -16,6 +16,9 @@ pub fn load_module(
         palette: DEFAULT_PALETTE,
         threading,
         exec_mode: ExecutionMode::Check,
+        fold_constants: true,
+        emit_ir: EmitIr::default(),
+        trace_refcounts: false,
     };
 
     let arena = Bump::new();
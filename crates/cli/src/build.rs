@@ -1,20 +1,23 @@
+use crate::FLAG_TIME_TRACE;
 use bumpalo::Bump;
 use roc_build::{
     link::{
-        legacy_host_filename, link, preprocess_host_wasm32, preprocessed_host_filename,
-        rebuild_host, LinkType, LinkingStrategy,
+        alias_host_allocators, legacy_host_filename, link, preprocess_host_wasm32,
+        preprocessed_host_filename, rebuild_host, LinkType, LinkingStrategy,
     },
-    program::{self, CodeGenBackend, CodeGenOptions},
+    program::{self, CodeGenBackend, CodeGenOptions, Emit, EmitKind},
+    trace::{ChromeTrace, HOST_REBUILD_THREAD, MAIN_THREAD},
 };
 use roc_builtins::bitcode;
+use roc_error_macros::user_error;
 use roc_load::{
-    EntryPoint, ExecutionMode, ExpectMetadata, LoadConfig, LoadMonomorphizedError, LoadedModule,
-    LoadingProblem, Threading,
+    EmitIr, EntryPoint, ExecutionMode, ExpectMetadata, LoadConfig, LoadMonomorphizedError,
+    LoadedModule, LoadingProblem, Threading,
 };
 use roc_mono::ir::OptLevel;
 use roc_packaging::cache::RocCacheDir;
 use roc_reporting::{
-    cli::Problems,
+    cli::{DiagnosticLevels, Problems},
     report::{RenderTarget, DEFAULT_PALETTE},
 };
 use roc_target::TargetInfo;
@@ -93,6 +96,9 @@ pub fn standard_load_config(
         palette: DEFAULT_PALETTE,
         threading,
         exec_mode,
+        fold_constants: true,
+        emit_ir: EmitIr::default(),
+        trace_refcounts: false,
     }
 }
 
@@ -103,6 +109,8 @@ pub fn build_file<'a>(
     app_module_path: PathBuf,
     code_gen_options: CodeGenOptions,
     emit_timings: bool,
+    time_trace: Option<PathBuf>,
+    diagnostic_levels: &DiagnosticLevels,
     link_type: LinkType,
     linking_strategy: LinkingStrategy,
     prebuilt_requested: bool,
@@ -123,6 +131,8 @@ pub fn build_file<'a>(
         app_module_path,
         code_gen_options,
         emit_timings,
+        time_trace,
+        diagnostic_levels,
         link_type,
         linking_strategy,
         prebuilt_requested,
@@ -139,6 +149,8 @@ fn build_loaded_file<'a>(
     app_module_path: PathBuf,
     code_gen_options: CodeGenOptions,
     emit_timings: bool,
+    time_trace: Option<PathBuf>,
+    diagnostic_levels: &DiagnosticLevels,
     link_type: LinkType,
     linking_strategy: LinkingStrategy,
     prebuilt_requested: bool,
@@ -177,7 +189,14 @@ fn build_loaded_file<'a>(
         output_exe_path.set_extension(extension);
     }
 
+    let mut trace = time_trace.as_ref().map(|_| {
+        let mut trace = ChromeTrace::new(compilation_start);
+        trace.add_module_timings(&loaded.interns, &loaded.timings);
+        trace
+    });
+
     // We don't need to spawn a rebuild thread when using a prebuilt host.
+    let rebuild_start = Instant::now();
     let rebuild_thread = if matches!(link_type, LinkType::Dylib | LinkType::None) {
         None
     } else if is_platform_prebuilt {
@@ -262,7 +281,7 @@ fn build_loaded_file<'a>(
     // This only needs to be mutable for report_problems. This can't be done
     // inside a nested scope without causing a borrow error!
     let mut loaded = loaded;
-    let problems = program::report_problems_monomorphized(&mut loaded);
+    let problems = program::report_problems_monomorphized(&mut loaded, diagnostic_levels);
     let loaded = loaded;
 
     enum HostRebuildTiming {
@@ -283,6 +302,10 @@ fn build_loaded_file<'a>(
                 );
             }
 
+            if let Some(trace) = trace.as_mut() {
+                add_rebuild_span(trace, rebuild_start, rebuild_duration);
+            }
+
             Some(HostRebuildTiming::BeforeApp(rebuild_duration))
         } else {
             Some(HostRebuildTiming::ConcurrentWithApp(rebuild_thread))
@@ -291,7 +314,8 @@ fn build_loaded_file<'a>(
         None
     };
 
-    let (roc_app_bytes, code_gen_timing, expect_metadata) = program::gen_from_mono_module(
+    let code_gen_start = Instant::now();
+    let (roc_app_bytes, code_gen_timing, expect_metadata, emitted) = program::gen_from_mono_module(
         arena,
         loaded,
         &app_module_path,
@@ -301,6 +325,15 @@ fn build_loaded_file<'a>(
         wasm_dev_stack_bytes,
     );
 
+    if let Some(trace) = trace.as_mut() {
+        trace.add_span(
+            "Code Generation",
+            MAIN_THREAD,
+            code_gen_start,
+            Instant::now(),
+        );
+    }
+
     buf.push('\n');
     buf.push_str("    ");
     buf.push_str("Code Generation");
@@ -331,6 +364,10 @@ fn build_loaded_file<'a>(
     if let Some(HostRebuildTiming::ConcurrentWithApp(thread)) = opt_rebuild_timing {
         let rebuild_duration = thread.join().expect("Failed to (re)build platform.");
 
+        if let Some(trace) = trace.as_mut() {
+            add_rebuild_span(trace, rebuild_start, rebuild_duration);
+        }
+
         if emit_timings && !is_platform_prebuilt {
             println!(
                 "Finished rebuilding the platform in {} ms\n",
@@ -369,19 +406,63 @@ fn build_loaded_file<'a>(
             let builtins_host_tempfile =
                 bitcode::host_tempfile().expect("failed to write host builtins object to tempfile");
 
+            // the host has been compiled into a .o or .obj file
+            let links_host = !matches!(link_type, LinkType::Dylib | LinkType::None);
+
+            // On linux, the host's own calls to roc_dealloc and friends can be checked too
+            let check_host_memory = code_gen_options.check_memory
+                && links_host
+                && target.operating_system == target_lexicon::OperatingSystem::Linux;
+
+            let memcheck_host_tempfile = if check_host_memory {
+                let tempfile = tempfile::Builder::new()
+                    .prefix("host_memcheck")
+                    .suffix(&format!(".{}", operating_system.object_file_ext()))
+                    .tempfile()
+                    .map_err(|err| {
+                        todo!("TODO Gracefully handle tempfile creation error {:?}", err)
+                    })?;
+
+                let exit_status = alias_host_allocators(&preprocessed_host_path, tempfile.path())
+                    .map_err(|_| todo!("gracefully handle `ld` failing to spawn."))?
+                    .wait()
+                    .map_err(|_| todo!("gracefully handle error after `ld` spawned"))?;
+
+                if !exit_status.success() {
+                    todo!(
+                        "gracefully handle `ld` returning exit code {:?} while preparing the host for --check-memory",
+                        exit_status.code()
+                    );
+                }
+
+                Some(tempfile)
+            } else {
+                None
+            };
+
+            let host_path = match &memcheck_host_tempfile {
+                Some(tempfile) => tempfile.path(),
+                None => preprocessed_host_path.as_path(),
+            };
+
             let mut inputs = vec![app_o_file.to_str().unwrap()];
 
-            if !matches!(link_type, LinkType::Dylib | LinkType::None) {
-                // the host has been compiled into a .o or .obj file
-                inputs.push(preprocessed_host_path.as_path().to_str().unwrap());
+            if links_host {
+                inputs.push(host_path.to_str().unwrap());
             }
 
             if matches!(code_gen_options.backend, program::CodeGenBackend::Assembly) {
                 inputs.push(builtins_host_tempfile.path().to_str().unwrap());
             }
 
-            let (mut child, _) = link(target, output_exe_path.clone(), &inputs, link_type)
-                .map_err(|_| todo!("gracefully handle `ld` failing to spawn."))?;
+            let (mut child, _) = link(
+                target,
+                output_exe_path.clone(),
+                &inputs,
+                link_type,
+                check_host_memory,
+            )
+            .map_err(|_| todo!("gracefully handle `ld` failing to spawn."))?;
 
             let exit_status = child
                 .wait()
@@ -402,10 +483,34 @@ fn build_loaded_file<'a>(
 
     let linking_time = link_start.elapsed();
 
+    if let Some(trace) = trace.as_mut() {
+        trace.add_span("Linking", MAIN_THREAD, link_start, Instant::now());
+    }
+
     if emit_timings {
         println!("Finished linking in {} ms\n", linking_time.as_millis());
     }
 
+    let mut emitted = emitted;
+
+    // this one needs the final binary, so it can only be produced after linking
+    if code_gen_options.emit.contains(EmitKind::WasmWat) {
+        emitted.push((EmitKind::WasmWat, program::wasm_to_wat(&output_exe_path)));
+    }
+
+    write_emitted(&output_exe_path, emitted);
+
+    if let (Some(trace), Some(path)) = (trace, time_trace) {
+        if let Err(error) = trace.write_to_file(&path) {
+            user_error!(
+                "I couldn't write the --{} file to {}: {}",
+                FLAG_TIME_TRACE,
+                path.display(),
+                error
+            );
+        }
+    }
+
     let total_time = compilation_start.elapsed();
 
     Ok(BuiltFile {
@@ -416,6 +521,25 @@ fn build_loaded_file<'a>(
     })
 }
 
+/// The rebuild thread only reports how long it took, but it starts right as it's spawned
+fn add_rebuild_span(trace: &mut ChromeTrace, rebuild_start: Instant, rebuild_duration: u128) {
+    let rebuild_end = rebuild_start + Duration::from_millis(rebuild_duration as u64);
+
+    trace.add_span(
+        "Rebuild platform",
+        HOST_REBUILD_THREAD,
+        rebuild_start,
+        rebuild_end,
+    );
+}
+
+/// Write each `--emit` artifact next to the output, e.g. `app.ll` beside `app`
+fn write_emitted(output_path: &Path, emitted: program::Emitted) {
+    for (kind, contents) in emitted {
+        std::fs::write(output_path.with_extension(kind.extension()), contents).unwrap();
+    }
+}
+
 fn invalid_prebuilt_platform(prebuilt_requested: bool, preprocessed_host_path: PathBuf) {
     let prefix = match prebuilt_requested {
         true => "Because I was run with --prebuilt-platform=true, ",
@@ -505,6 +629,7 @@ pub fn check_file<'a>(
     emit_timings: bool,
     roc_cache_dir: RocCacheDir<'_>,
     threading: Threading,
+    diagnostic_levels: &DiagnosticLevels,
 ) -> Result<(Problems, Duration), LoadingProblem<'a>> {
     let compilation_start = Instant::now();
 
@@ -521,6 +646,9 @@ pub fn check_file<'a>(
         palette: DEFAULT_PALETTE,
         threading,
         exec_mode: ExecutionMode::Check,
+        fold_constants: true,
+        emit_ir: EmitIr::default(),
+        trace_refcounts: false,
     };
     let mut loaded =
         roc_load::load_and_typecheck(arena, roc_file_path, roc_cache_dir, load_config)?;
@@ -569,7 +697,7 @@ pub fn check_file<'a>(
     }
 
     Ok((
-        program::report_problems_typechecked(&mut loaded),
+        program::report_problems_typechecked(&mut loaded, diagnostic_levels),
         compilation_end,
     ))
 }
@@ -586,6 +714,9 @@ pub fn build_str_test<'a>(
         backend: CodeGenBackend::Llvm,
         opt_level: OptLevel::Normal,
         emit_debug_info: false,
+        emit: Emit::NONE,
+        check_memory: false,
+        trace_refcounts: false,
     };
 
     let emit_timings = false;
@@ -618,6 +749,8 @@ pub fn build_str_test<'a>(
         app_module_path.to_path_buf(),
         code_gen_options,
         emit_timings,
+        None,
+        &DiagnosticLevels::default(),
         link_type,
         linking_strategy,
         assume_prebuild,
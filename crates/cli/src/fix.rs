@@ -0,0 +1,182 @@
+use std::path::{Path, PathBuf};
+
+use crate::format::format_str;
+use bumpalo::Bump;
+use roc_error_macros::user_error;
+use roc_load::{EmitIr, ExecutionMode, LoadConfig, LoadingProblem, Threading};
+use roc_packaging::cache::RocCacheDir;
+use roc_problem::Edit;
+use roc_region::all::Region;
+use roc_reporting::report::{RenderTarget, DEFAULT_PALETTE};
+use roc_target::TargetInfo;
+
+/// What `roc fix` changed
+pub struct Fixed {
+    pub fixes: usize,
+    pub files: Vec<PathBuf>,
+}
+
+/// Applies the edits that fix problems found while checking the module and its dependencies,
+/// then formats the files that changed. Only files in the module's own directory get changed,
+/// so builtins and downloaded packages are left alone.
+pub fn fix<'a>(
+    arena: &'a Bump,
+    roc_file_path: PathBuf,
+    roc_cache_dir: RocCacheDir<'_>,
+    threading: Threading,
+) -> Result<Fixed, LoadingProblem<'a>> {
+    let root_dir = match roc_file_path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+    let root_dir = root_dir.canonicalize().unwrap_or(root_dir);
+
+    let load_config = LoadConfig {
+        target_info: TargetInfo::default_x86_64(),
+        render: RenderTarget::ColorTerminal,
+        palette: DEFAULT_PALETTE,
+        threading,
+        exec_mode: ExecutionMode::Check,
+        fold_constants: true,
+        emit_ir: EmitIr::default(),
+        trace_refcounts: false,
+    };
+    let loaded = roc_load::load_and_typecheck(arena, roc_file_path, roc_cache_dir, load_config)?;
+
+    let mut modules: Vec<_> = loaded.can_problems.iter().collect();
+    modules.sort_by_key(|(module_id, _)| loaded.sources.get(module_id).map(|(path, _)| path));
+
+    let mut fixed = Fixed {
+        fixes: 0,
+        files: Vec::new(),
+    };
+
+    // Nothing gets written until every fixed file has been formatted, so a fix that goes wrong
+    // doesn't leave some of the files changed.
+    let mut rewritten = Vec::new();
+
+    for (module_id, problems) in modules {
+        let edits: Vec<Edit> = problems
+            .iter()
+            .filter_map(|problem| problem.fix())
+            .collect();
+
+        let (path, src) = match loaded.sources.get(module_id) {
+            Some(source) if !edits.is_empty() => source,
+            _ => continue,
+        };
+
+        if !is_in_dir(path, &root_dir) {
+            continue;
+        }
+
+        let (new_src, applied) = apply_edits(src, edits);
+
+        // The edits only remove or add a few characters, so tidy up around them
+        let formatted = format_str(&new_src, path).unwrap_or_else(|problem| {
+            user_error!(
+                "Fixing {} would leave code that can't be formatted, so I didn't change any files:\n\n{}",
+                path.display(),
+                problem
+            )
+        });
+
+        rewritten.push((path, formatted));
+
+        fixed.fixes += applied;
+        fixed.files.push(path.clone());
+    }
+
+    for (path, formatted) in rewritten {
+        if let Err(error) = std::fs::write(path, formatted) {
+            user_error!(
+                "I couldn't write the fixes to {}: {}",
+                path.display(),
+                error
+            );
+        }
+    }
+
+    Ok(fixed)
+}
+
+fn is_in_dir(path: &Path, dir: &Path) -> bool {
+    // Builtins aren't on disk, so they can't be canonicalized
+    match path.canonicalize() {
+        Ok(path) => path.starts_with(dir),
+        Err(_) => false,
+    }
+}
+
+/// Applies the edits to the code, and returns how many of them were applied.
+///
+/// Overlapping removals are combined, which happens e.g. when two neighboring items of a list
+/// are removed, since each of them removes the comma between them. Any other edit that overlaps
+/// one that was already made is skipped; running `roc fix` again picks it up.
+fn apply_edits(src: &str, mut edits: Vec<Edit>) -> (String, usize) {
+    edits.sort_by_key(|edit| edit.region);
+
+    let mut combined: Vec<Edit> = Vec::with_capacity(edits.len());
+    let mut applied = 0;
+
+    for edit in edits {
+        match combined.last_mut() {
+            Some(last)
+                if edit.region.start() < last.region.end()
+                    || edit.region.start() == last.region.start() =>
+            {
+                if edit.replacement.is_empty() && last.replacement.is_empty() {
+                    let end = edit.region.end().max(last.region.end());
+
+                    last.region = Region::new(last.region.start(), end);
+                    applied += 1;
+                }
+            }
+            _ => {
+                combined.push(edit);
+                applied += 1;
+            }
+        }
+    }
+
+    let mut new_src = String::with_capacity(src.len());
+    let mut copied = 0;
+
+    for edit in combined {
+        let (start, end) = match whole_lines(src, &edit.region) {
+            // A previous edit may already have removed part of the line
+            (start, end) if edit.replacement.is_empty() && start >= copied => (start, end),
+            _ => (
+                edit.region.start().offset as usize,
+                edit.region.end().offset as usize,
+            ),
+        };
+        let start = start.max(copied);
+
+        new_src.push_str(&src[copied..start]);
+        new_src.push_str(&edit.replacement);
+
+        copied = end.max(start);
+    }
+
+    new_src.push_str(&src[copied..]);
+
+    (new_src, applied)
+}
+
+/// Widens a removal to the lines it is on, if there is nothing else on them,
+/// so that removing e.g. a def doesn't leave a blank line behind.
+fn whole_lines(src: &str, region: &Region) -> (usize, usize) {
+    let start = region.start().offset as usize;
+    let end = region.end().offset as usize;
+    let line_start = src[..start].rfind('\n').map_or(0, |index| index + 1);
+    let line_end = src[end..]
+        .find('\n')
+        .map_or(src.len(), |index| end + index + 1);
+
+    if src[line_start..start].trim().is_empty() && src[end..line_end].trim().is_empty() {
+        (line_start, line_end)
+    } else {
+        (start, end)
+    }
+}
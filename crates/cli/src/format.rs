@@ -1,18 +1,24 @@
 use std::ffi::OsStr;
+use std::io::Read;
+use std::ops::Range;
 use std::path::{Path, PathBuf};
 
-use crate::FormatMode;
+use crate::{FormatMode, FLAG_RANGE};
 use bumpalo::Bump;
 use roc_error_macros::{internal_error, user_error};
-use roc_fmt::def::fmt_defs;
+use roc_fmt::def::{fmt_defs, fmt_type_def, fmt_value_def};
 use roc_fmt::module::fmt_module;
 use roc_fmt::spaces::RemoveSpaces;
 use roc_fmt::{Ast, Buf};
+use roc_highlight_ansi::ansi::{highlight_ansi, Theme};
 use roc_parse::{
+    ast::Defs,
     module::{self, module_defs},
     parser::{Parser, SyntaxError},
     state::State,
 };
+use roc_region::all::Region;
+use roc_reporting::report::StyleCodes;
 
 fn flatten_directories(files: std::vec::Vec<PathBuf>) -> std::vec::Vec<PathBuf> {
     let mut to_flatten = files;
@@ -58,101 +64,278 @@ fn is_roc_file(path: &Path) -> bool {
     matches!(path.extension().and_then(OsStr::to_str), Some("roc"))
 }
 
-pub fn format(files: std::vec::Vec<PathBuf>, mode: FormatMode) -> Result<(), String> {
+pub fn format(
+    files: std::vec::Vec<PathBuf>,
+    mode: FormatMode,
+    range: Option<Range<usize>>,
+) -> Result<(), String> {
     let files = flatten_directories(files);
+    let mut needs_reformatting = false;
+
+    if range.is_some() && files.len() != 1 {
+        return Err(format!(
+            "--{} can only be used when formatting a single file.",
+            FLAG_RANGE
+        ));
+    }
 
     for file in files {
         let arena = Bump::new();
 
         let src = std::fs::read_to_string(&file).unwrap();
-
-        let ast = arena.alloc(parse_all(&arena, &src).unwrap_or_else(|e| {
-            user_error!("Unexpected parse failure when parsing this formatting:\n\n{:?}\n\nParse error was:\n\n{:?}\n\n", src, e)
-        }));
-        let mut buf = Buf::new_in(&arena);
-        fmt_all(&mut buf, ast);
-
-        let reparsed_ast = arena.alloc(parse_all(&arena, buf.as_str()).unwrap_or_else(|e| {
-            let mut fail_file = file.clone();
-            fail_file.set_extension("roc-format-failed");
-            std::fs::write(&fail_file, buf.as_str()).unwrap();
-            internal_error!(
-                "Formatting bug; formatted code isn't valid\n\n\
-                I wrote the incorrect result to this file for debugging purposes:\n{}\n\n\
-                Parse error was: {:?}\n\n",
-                fail_file.display(),
-                e
-            );
-        }));
-
-        let ast_normalized = ast.remove_spaces(&arena);
-        let reparsed_ast_normalized = reparsed_ast.remove_spaces(&arena);
-
-        // HACK!
-        // We compare the debug format strings of the ASTs, because I'm finding in practice that _somewhere_ deep inside the ast,
-        // the PartialEq implementation is returning `false` even when the Debug-formatted impl is exactly the same.
-        // I don't have the patience to debug this right now, so let's leave it for another day...
-        // TODO: fix PartialEq impl on ast types
-        if format!("{:?}", ast_normalized) != format!("{:?}", reparsed_ast_normalized) {
-            let mut fail_file = file.clone();
-            fail_file.set_extension("roc-format-failed");
-            std::fs::write(&fail_file, buf.as_str()).unwrap();
-
-            let mut before_file = file.clone();
-            before_file.set_extension("roc-format-failed-ast-before");
-            std::fs::write(&before_file, &format!("{:#?}\n", ast_normalized)).unwrap();
-
-            let mut after_file = file.clone();
-            after_file.set_extension("roc-format-failed-ast-after");
-            std::fs::write(&after_file, &format!("{:#?}\n", reparsed_ast_normalized)).unwrap();
-
-            internal_error!(
-                "Formatting bug; formatting didn't reparse as the same tree\n\n\
-                I wrote the incorrect result to this file for debugging purposes:\n{}\n\n\
-                I wrote the tree before and after formatting to these files for debugging purposes:\n{}\n{}\n\n",
-                fail_file.display(),
-                before_file.display(),
-                after_file.display());
-        }
-
-        // Now verify that the resultant formatting is _stable_ - i.e. that it doesn't change again if re-formatted
-        let mut reformatted_buf = Buf::new_in(&arena);
-        fmt_all(&mut reformatted_buf, reparsed_ast);
-        if buf.as_str() != reformatted_buf.as_str() {
-            let mut unstable_1_file = file.clone();
-            unstable_1_file.set_extension("roc-format-unstable-1");
-            std::fs::write(&unstable_1_file, buf.as_str()).unwrap();
-
-            let mut unstable_2_file = file.clone();
-            unstable_2_file.set_extension("roc-format-unstable-2");
-            std::fs::write(&unstable_2_file, reformatted_buf.as_str()).unwrap();
-
-            internal_error!(
-                "Formatting bug; formatting is not stable. Reformatting the formatted file changed it again.\n\n\
-                I wrote the result of formatting to this file for debugging purposes:\n{}\n\n\
-                I wrote the result of double-formatting here:\n{}\n\n",
-                unstable_1_file.display(),
-                unstable_2_file.display());
-        }
+        let formatted = format_src(&arena, &src, range.clone(), &file)?;
 
         match mode {
             FormatMode::CheckOnly => {
-                // If we notice that this file needs to be formatted, return early
-                if buf.as_str() != src {
-                    return Err("One or more files need to be reformatted.".to_string());
+                // Keep going, so that every file that needs to be formatted gets a diff
+                if formatted != src {
+                    print!(
+                        "{}",
+                        render_diff(&file, &src, formatted, stdout_is_terminal())
+                    );
+                    needs_reformatting = true;
                 }
             }
 
             FormatMode::Format => {
-                // If all the checks above passed, actually write out the new file.
-                std::fs::write(&file, buf.as_str()).unwrap();
+                // If all the checks passed, actually write out the new file.
+                std::fs::write(&file, formatted).unwrap();
             }
         }
     }
 
+    if needs_reformatting {
+        Err("One or more files need to be reformatted.".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+/// Formats the code piped into stdin, printing the result to stdout.
+pub fn format_stdin(mode: FormatMode, range: Option<Range<usize>>) -> Result<(), String> {
+    let mut src = String::new();
+
+    std::io::stdin()
+        .read_to_string(&mut src)
+        .map_err(|error| format!("I could not read the code from stdin: {}", error))?;
+
+    let arena = Bump::new();
+
+    // There is no file to write debugging output next to, so it goes in the temp dir instead
+    let debug_file = std::env::temp_dir().join("stdin.roc");
+    let formatted = format_src(&arena, &src, range, &debug_file)?;
+
+    match mode {
+        FormatMode::CheckOnly => {
+            if formatted != src {
+                print!(
+                    "{}",
+                    render_diff(Path::new("<stdin>"), &src, formatted, stdout_is_terminal())
+                );
+
+                return Err("The code needs to be reformatted.".to_string());
+            }
+        }
+
+        FormatMode::Format => print!("{}", formatted),
+    }
+
     Ok(())
 }
 
+/// Parses a `--range` of bytes, given as `start:end`
+pub fn parse_range(range: &str) -> Result<Range<usize>, String> {
+    let invalid = || {
+        format!(
+            "The range `{}` should be two byte offsets, like `120:250`.",
+            range
+        )
+    };
+
+    let (start, end) = range.split_once(':').ok_or_else(invalid)?;
+    let start: usize = start.trim().parse().map_err(|_| invalid())?;
+    let end: usize = end.trim().parse().map_err(|_| invalid())?;
+
+    if start <= end {
+        Ok(start..end)
+    } else {
+        Err(format!("The range `{}` ends before it starts.", range))
+    }
+}
+
+/// Formats the code `roc fix` edited, without writing it anywhere.
+pub(crate) fn format_str(src: &str, file: &Path) -> Result<String, String> {
+    let arena = Bump::new();
+
+    if let Err(error) = parse_all(&arena, src) {
+        return Err(format!("Parse error was:\n\n{:?}", error));
+    }
+
+    format_src(&arena, src, None, file).map(|formatted| formatted.to_string())
+}
+
+/// Formats the code, or only the top-level defs that overlap the range if there is one, and
+/// makes sure that the result means the same thing and doesn't change when formatted again.
+/// If it does, the results are written next to `file` for debugging purposes.
+fn format_src<'a>(
+    arena: &'a Bump,
+    src: &'a str,
+    range: Option<Range<usize>>,
+    file: &Path,
+) -> Result<&'a str, String> {
+    let ast = arena.alloc(parse_all(arena, src).unwrap_or_else(|e| {
+        user_error!("Unexpected parse failure when parsing this formatting:\n\n{:?}\n\nParse error was:\n\n{:?}\n\n", src, e)
+    }));
+
+    let selected_defs = match range {
+        Some(range) => Some(select_defs(&ast.defs, range, src.len())?),
+        None => None,
+    };
+
+    let formatted = fmt_selected(arena, ast, src, selected_defs.clone());
+
+    let reparsed_ast = arena.alloc(parse_all(arena, formatted).unwrap_or_else(|e| {
+        let mut fail_file = file.to_path_buf();
+        fail_file.set_extension("roc-format-failed");
+        std::fs::write(&fail_file, formatted).unwrap();
+        internal_error!(
+            "Formatting bug; formatted code isn't valid\n\n\
+            I wrote the incorrect result to this file for debugging purposes:\n{}\n\n\
+            Parse error was: {:?}\n\n",
+            fail_file.display(),
+            e
+        );
+    }));
+
+    let ast_normalized = ast.remove_spaces(arena);
+    let reparsed_ast_normalized = reparsed_ast.remove_spaces(arena);
+
+    // HACK!
+    // We compare the debug format strings of the ASTs, because I'm finding in practice that _somewhere_ deep inside the ast,
+    // the PartialEq implementation is returning `false` even when the Debug-formatted impl is exactly the same.
+    // I don't have the patience to debug this right now, so let's leave it for another day...
+    // TODO: fix PartialEq impl on ast types
+    if format!("{:?}", ast_normalized) != format!("{:?}", reparsed_ast_normalized) {
+        let mut fail_file = file.to_path_buf();
+        fail_file.set_extension("roc-format-failed");
+        std::fs::write(&fail_file, formatted).unwrap();
+
+        let mut before_file = file.to_path_buf();
+        before_file.set_extension("roc-format-failed-ast-before");
+        std::fs::write(&before_file, &format!("{:#?}\n", ast_normalized)).unwrap();
+
+        let mut after_file = file.to_path_buf();
+        after_file.set_extension("roc-format-failed-ast-after");
+        std::fs::write(&after_file, &format!("{:#?}\n", reparsed_ast_normalized)).unwrap();
+
+        internal_error!(
+            "Formatting bug; formatting didn't reparse as the same tree\n\n\
+            I wrote the incorrect result to this file for debugging purposes:\n{}\n\n\
+            I wrote the tree before and after formatting to these files for debugging purposes:\n{}\n{}\n\n",
+            fail_file.display(),
+            before_file.display(),
+            after_file.display());
+    }
+
+    // Now verify that the resultant formatting is _stable_ - i.e. that it doesn't change again if re-formatted
+    let reformatted = fmt_selected(arena, reparsed_ast, formatted, selected_defs);
+    if formatted != reformatted {
+        let mut unstable_1_file = file.to_path_buf();
+        unstable_1_file.set_extension("roc-format-unstable-1");
+        std::fs::write(&unstable_1_file, formatted).unwrap();
+
+        let mut unstable_2_file = file.to_path_buf();
+        unstable_2_file.set_extension("roc-format-unstable-2");
+        std::fs::write(&unstable_2_file, reformatted).unwrap();
+
+        internal_error!(
+            "Formatting bug; formatting is not stable. Reformatting the formatted file changed it again.\n\n\
+            I wrote the result of formatting to this file for debugging purposes:\n{}\n\n\
+            I wrote the result of double-formatting here:\n{}\n\n",
+            unstable_1_file.display(),
+            unstable_2_file.display());
+    }
+
+    Ok(formatted)
+}
+
+/// Finds the top-level defs that overlap the range. An empty range selects the def it's in.
+fn select_defs(defs: &Defs, range: Range<usize>, src_len: usize) -> Result<Range<usize>, String> {
+    if range.end > src_len {
+        return Err(format!(
+            "The range {}:{} goes past the end of the code, which is {} bytes long.",
+            range.start, range.end, src_len
+        ));
+    }
+
+    let overlaps = |region: &Region| {
+        let start = region.start().offset as usize;
+        let end = region.end().offset as usize;
+
+        if range.is_empty() {
+            start <= range.start && range.start <= end
+        } else {
+            start < range.end && range.start < end
+        }
+    };
+
+    let first = defs
+        .regions
+        .iter()
+        .position(overlaps)
+        .unwrap_or(defs.regions.len());
+    let count = defs.regions[first..]
+        .iter()
+        .take_while(|region| overlaps(region))
+        .count();
+
+    Ok(first..first + count)
+}
+
+/// Formats the whole module, or only the selected top-level defs.
+/// Everything around the selected defs stays exactly as it was.
+fn fmt_selected<'a>(
+    arena: &'a Bump,
+    ast: &'a Ast,
+    src: &'a str,
+    selected_defs: Option<Range<usize>>,
+) -> &'a str {
+    let selected_defs = match selected_defs {
+        Some(selected_defs) => selected_defs,
+        None => {
+            let mut buf = Buf::new_in(arena);
+            fmt_all(&mut buf, ast);
+
+            return buf.into_bump_str();
+        }
+    };
+
+    let mut formatted = String::with_capacity(src.len());
+    let mut copied = 0;
+
+    for (index, def) in ast.defs.defs().enumerate() {
+        if !selected_defs.contains(&index) {
+            continue;
+        }
+
+        let region = ast.defs.regions[index];
+        let mut buf = Buf::new_in(arena);
+
+        match def {
+            Ok(type_def) => fmt_type_def(&mut buf, type_def, 0),
+            Err(value_def) => fmt_value_def(&mut buf, value_def, 0),
+        }
+
+        formatted.push_str(&src[copied..region.start().offset as usize]);
+        formatted.push_str(buf.into_bump_str().trim_end());
+        copied = region.end().offset as usize;
+    }
+
+    formatted.push_str(&src[copied..]);
+
+    arena.alloc_str(&formatted)
+}
+
 fn parse_all<'a>(arena: &'a Bump, src: &'a str) -> Result<Ast<'a>, SyntaxError<'a>> {
     let (module, state) = module::parse_header(arena, State::new(src.as_bytes()))
         .map_err(|e| SyntaxError::Header(e.problem))?;
@@ -169,3 +352,248 @@ fn fmt_all<'a>(buf: &mut Buf<'a>, ast: &'a Ast) {
 
     buf.fmt_end_of_file();
 }
+
+/// How many unchanged lines to show around each change in a diff
+const DIFF_CONTEXT_LINES: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DiffLine<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Only color diffs when they are printed to a terminal, rather than e.g. piped into a file
+fn stdout_is_terminal() -> bool {
+    atty::is(atty::Stream::Stdout)
+}
+
+const NO_STYLE_CODES: StyleCodes = StyleCodes {
+    red: "",
+    green: "",
+    yellow: "",
+    blue: "",
+    magenta: "",
+    cyan: "",
+    white: "",
+    bold: "",
+    underline: "",
+    reset: "",
+    color_reset: "",
+};
+
+/// Renders the changes the formatter would make as a unified diff, optionally colored for the terminal.
+fn render_diff(file: &Path, before: &str, after: &str, color: bool) -> String {
+    use roc_reporting::report::{ANSI_STYLE_CODES, DEFAULT_PALETTE};
+    use std::fmt::Write;
+
+    let (codes, theme) = if color {
+        (ANSI_STYLE_CODES, DEFAULT_PALETTE.code_theme())
+    } else {
+        (NO_STYLE_CODES, Theme::PLAIN)
+    };
+    let before_lines: Vec<&str> = before.lines().collect();
+    let after_lines: Vec<&str> = after.lines().collect();
+    let diff = diff_lines(&before_lines, &after_lines);
+
+    let mut buf = String::new();
+
+    writeln!(buf, "{}--- {}{}", codes.bold, file.display(), codes.reset).unwrap();
+    writeln!(buf, "{}+++ {}{}", codes.bold, file.display(), codes.reset).unwrap();
+
+    for hunk in diff_hunks(&diff) {
+        let lines = &diff[hunk.clone()];
+        let (before_start, after_start) = line_numbers_at(&diff, hunk.start);
+        let before_len = lines
+            .iter()
+            .filter(|line| !matches!(line, DiffLine::Added(_)))
+            .count();
+        let after_len = lines
+            .iter()
+            .filter(|line| !matches!(line, DiffLine::Removed(_)))
+            .count();
+
+        writeln!(
+            buf,
+            "{}@@ -{},{} +{},{} @@{}",
+            codes.cyan,
+            // an empty range is numbered by the line before it
+            before_start + (before_len > 0) as usize,
+            before_len,
+            after_start + (after_len > 0) as usize,
+            after_len,
+            codes.reset
+        )
+        .unwrap();
+
+        for line in lines {
+            match line {
+                DiffLine::Same(text) => {
+                    writeln!(buf, " {}", highlight_ansi(text, &theme))
+                }
+                DiffLine::Removed(text) => writeln!(buf, "{}-{}{}", codes.red, text, codes.reset),
+                DiffLine::Added(text) => writeln!(buf, "{}+{}{}", codes.green, text, codes.reset),
+            }
+            .unwrap();
+        }
+    }
+
+    buf
+}
+
+/// How many lines of each side of the diff come before the given index
+fn line_numbers_at(diff: &[DiffLine], index: usize) -> (usize, usize) {
+    diff[..index]
+        .iter()
+        .fold((0, 0), |(before, after), line| match line {
+            DiffLine::Same(_) => (before + 1, after + 1),
+            DiffLine::Removed(_) => (before + 1, after),
+            DiffLine::Added(_) => (before, after + 1),
+        })
+}
+
+/// Groups the changes into hunks, each with up to DIFF_CONTEXT_LINES unchanged lines around it.
+/// Changes that are close together share a hunk.
+fn diff_hunks(diff: &[DiffLine]) -> Vec<std::ops::Range<usize>> {
+    let mut hunks: Vec<std::ops::Range<usize>> = Vec::new();
+
+    for (index, line) in diff.iter().enumerate() {
+        if matches!(line, DiffLine::Same(_)) {
+            continue;
+        }
+
+        let start = index.saturating_sub(DIFF_CONTEXT_LINES);
+        let end = (index + 1 + DIFF_CONTEXT_LINES).min(diff.len());
+
+        match hunks.last_mut() {
+            Some(hunk) if hunk.end >= start => hunk.end = end,
+            _ => hunks.push(start..end),
+        }
+    }
+
+    hunks
+}
+
+/// Finds a shortest way to edit one list of lines into the other, using Myers' algorithm.
+fn diff_lines<'a>(before: &[&'a str], after: &[&'a str]) -> Vec<DiffLine<'a>> {
+    let n = before.len() as isize;
+    let m = after.len() as isize;
+    let max = (n + m) as usize;
+
+    // v[k] is the furthest `x` reached so far on diagonal `k = x - y`, offset so it can be indexed
+    let offset = max as isize + 1;
+    let mut v = vec![0isize; 2 * max + 3];
+    let mut trace = Vec::new();
+
+    'search: for d in 0..=max as isize {
+        // only diagonals -d..=d are reached in this round, so that's all that is needed to walk back
+        trace.push(v[(offset - d - 1) as usize..=(offset + d + 1) as usize].to_vec());
+
+        for k in (-d..=d).step_by(2) {
+            let i = (offset + k) as usize;
+            let mut x = if k == -d || (k != d && v[i - 1] < v[i + 1]) {
+                v[i + 1]
+            } else {
+                v[i - 1] + 1
+            };
+            let mut y = x - k;
+
+            while x < n && y < m && before[x as usize] == after[y as usize] {
+                x += 1;
+                y += 1;
+            }
+
+            v[i] = x;
+
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+    }
+
+    // walk back from the end, following the path that got there
+    let mut diff = Vec::with_capacity(max);
+    let (mut x, mut y) = (n, m);
+
+    for (d, v) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let k = x - y;
+        let i = (d + 1 + k) as usize;
+
+        let prev_k = if k == -d || (k != d && v[i - 1] < v[i + 1]) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = if d == 0 {
+            0
+        } else {
+            v[(d + 1 + prev_k) as usize]
+        };
+        let prev_y = if d == 0 { 0 } else { prev_x - prev_k };
+
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            diff.push(DiffLine::Same(before[x as usize]));
+        }
+
+        if d > 0 {
+            if x == prev_x {
+                diff.push(DiffLine::Added(after[prev_y as usize]));
+            } else {
+                diff.push(DiffLine::Removed(before[prev_x as usize]));
+            }
+        }
+
+        x = prev_x;
+        y = prev_y;
+    }
+
+    diff.reverse();
+    diff
+}
+
+#[cfg(test)]
+mod test_diff {
+    use super::{diff_lines, render_diff, DiffLine};
+    use std::path::Path;
+
+    #[test]
+    fn diff_lines_keeps_common_lines() {
+        use DiffLine::*;
+
+        assert_eq!(
+            diff_lines(&["a", "b", "c", "d"], &["a", "x", "c", "d", "e"]),
+            [
+                Same("a"),
+                Removed("b"),
+                Added("x"),
+                Same("c"),
+                Same("d"),
+                Added("e")
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_lines_of_empty_sides() {
+        use DiffLine::*;
+
+        assert_eq!(diff_lines(&[], &[]), []);
+        assert_eq!(diff_lines(&["a"], &[]), [Removed("a")]);
+        assert_eq!(diff_lines(&[], &["a", "b"]), [Added("a"), Added("b")]);
+        assert_eq!(diff_lines(&["a", "b"], &["a", "b"]), [Same("a"), Same("b")]);
+    }
+
+    #[test]
+    fn render_diff_without_color() {
+        let before = "x = 1\ny=2\nz = 3\n";
+        let after = "x = 1\ny = 2\nz = 3\n";
+
+        assert_eq!(
+            render_diff(Path::new("Main.roc"), before, after, false),
+            "--- Main.roc\n+++ Main.roc\n@@ -1,3 +1,3 @@\n x = 1\n-y=2\n+y = 2\n z = 3\n"
+        );
+    }
+}
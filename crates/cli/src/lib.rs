@@ -7,12 +7,13 @@ use build::BuiltFile;
 use bumpalo::Bump;
 use clap::{Arg, ArgMatches, Command, ValueSource};
 use roc_build::link::{LinkType, LinkingStrategy};
-use roc_build::program::{CodeGenBackend, CodeGenOptions};
+use roc_build::program::{CodeGenBackend, CodeGenOptions, Emit, EmitKind};
 use roc_error_macros::{internal_error, user_error};
-use roc_load::{ExpectMetadata, LoadingProblem, Threading};
+use roc_load::{ExpectMetadata, LoadConfig, LoadingProblem, Threading};
 use roc_mono::ir::OptLevel;
 use roc_packaging::cache::RocCacheDir;
 use roc_packaging::tarball::Compression;
+use roc_reporting::cli::{warning_names, DiagnosticLevels, Level};
 use std::env;
 use std::ffi::{CString, OsStr};
 use std::io;
@@ -30,13 +31,19 @@ use target_lexicon::{
 use tempfile::TempDir;
 
 pub mod build;
+mod fix;
 mod format;
-pub use format::format;
+pub use fix::{fix, Fixed};
+pub use format::{format, format_stdin, parse_range};
 
 use crate::build::{standard_load_config, BuildFileError, BuildOrdering};
 
 const DEFAULT_ROC_FILENAME: &str = "main.roc";
 
+/// Lines like `deny unused-import` in this file, next to the main .roc file, set the level of
+/// that kind of warning for the whole project. `--allow`, `--warn`, and `--deny` override them.
+pub const DIAGNOSTICS_CONFIG_FILENAME: &str = ".roc-diagnostics";
+
 pub const CMD_BUILD: &str = "build";
 pub const CMD_RUN: &str = "run";
 pub const CMD_DEV: &str = "dev";
@@ -44,6 +51,7 @@ pub const CMD_REPL: &str = "repl";
 pub const CMD_EDIT: &str = "edit";
 pub const CMD_DOCS: &str = "docs";
 pub const CMD_CHECK: &str = "check";
+pub const CMD_FIX: &str = "fix";
 pub const CMD_VERSION: &str = "version";
 pub const CMD_FORMAT: &str = "format";
 pub const CMD_TEST: &str = "test";
@@ -60,10 +68,20 @@ pub const FLAG_LIB: &str = "lib";
 pub const FLAG_NO_LINK: &str = "no-link";
 pub const FLAG_TARGET: &str = "target";
 pub const FLAG_TIME: &str = "time";
+pub const FLAG_TIME_TRACE: &str = "time-trace";
 pub const FLAG_LINKER: &str = "linker";
 pub const FLAG_PREBUILT: &str = "prebuilt-platform";
 pub const FLAG_CHECK: &str = "check";
+pub const FLAG_STDIN: &str = "stdin";
+pub const FLAG_RANGE: &str = "range";
 pub const FLAG_WASM_STACK_SIZE_KB: &str = "wasm-stack-size-kb";
+pub const FLAG_SPEC: &str = "spec";
+pub const FLAG_EMIT: &str = "emit";
+pub const FLAG_ALLOW: &str = "allow";
+pub const FLAG_WARN: &str = "warn";
+pub const FLAG_DENY: &str = "deny";
+pub const FLAG_CHECK_MEMORY: &str = "check-memory";
+pub const FLAG_TRACE_REFCOUNTS: &str = "trace-refcounts";
 pub const ROC_FILE: &str = "ROC_FILE";
 pub const ROC_DIR: &str = "ROC_DIR";
 pub const GLUE_FILE: &str = "GLUE_FILE";
@@ -124,6 +142,53 @@ pub fn build_app<'a>() -> Command<'a> {
         .validator(|s| s.parse::<u32>())
         .required(false);
 
+    let flag_allow = Arg::new(FLAG_ALLOW)
+        .long(FLAG_ALLOW)
+        .help("Don't report these kinds of warnings, e.g. `--allow unused-def,unused-argument`\n(Only warnings can be allowed. Errors like `shadowing` can't, because the code they're about would crash if it ran.)")
+        .value_name("WARNING")
+        .takes_value(true)
+        .multiple_occurrences(true)
+        .use_value_delimiter(true)
+        .required(false);
+
+    let flag_warn = Arg::new(FLAG_WARN)
+        .long(FLAG_WARN)
+        .help("Report these kinds of warnings as warnings, even if the project's .roc-diagnostics file allows or denies them")
+        .value_name("WARNING")
+        .takes_value(true)
+        .multiple_occurrences(true)
+        .use_value_delimiter(true)
+        .required(false);
+
+    let flag_deny = Arg::new(FLAG_DENY)
+        .long(FLAG_DENY)
+        .help("Report these kinds of warnings as errors, e.g. `--deny unused-import`\n(`roc dev` and `roc test` don't run the program if there are errors.)")
+        .value_name("WARNING")
+        .takes_value(true)
+        .multiple_occurrences(true)
+        .use_value_delimiter(true)
+        .required(false);
+
+    let flag_check_memory = Arg::new(FLAG_CHECK_MEMORY)
+        .long(FLAG_CHECK_MEMORY)
+        .help("Track every allocation the program makes, and report memory that was never deallocated or was deallocated twice\n(This makes the program slower, and always uses the legacy linker.)")
+        .required(false);
+
+    let flag_trace_refcounts = Arg::new(FLAG_TRACE_REFCOUNTS)
+        .long(FLAG_TRACE_REFCOUNTS)
+        .help("Make the program print every time a `List` or `Str` operation or a record update copies a value because it was shared, or makes a unique value shared, along with the source location of the operation\n(This makes the program slower. Nothing is printed when building for wasm32.)")
+        .required(false);
+
+    let flag_emit = Arg::new(FLAG_EMIT)
+        .long(FLAG_EMIT)
+        .help("Also write these intermediate representations next to the output, as comma-separated values\n(For example, `--emit=mono,llvm-ir` writes `app.mono` and `app.ll` when building `app`. `asm` with `--dev` runs `objdump`, and `wasm-wat` runs `wasm2wat` from wabt, so those need to be on the PATH.)")
+        .takes_value(true)
+        .multiple_values(true)
+        .use_value_delimiter(true)
+        .require_equals(true)
+        .possible_values(EmitKind::ALL.map(EmitKind::name))
+        .required(false);
+
     let roc_file_to_run = Arg::new(ROC_FILE)
         .help("The .roc file of an app to run")
         .allow_invalid_utf8(true)
@@ -151,6 +216,10 @@ pub fn build_app<'a>() -> Command<'a> {
             .arg(flag_time.clone())
             .arg(flag_linker.clone())
             .arg(flag_prebuilt.clone())
+            .arg(flag_allow.clone())
+            .arg(flag_warn.clone())
+            .arg(flag_deny.clone())
+            .arg(flag_trace_refcounts.clone())
             .arg(flag_wasm_stack_size_kb.clone())
             .arg(
                 Arg::new(FLAG_TARGET)
@@ -182,6 +251,16 @@ pub fn build_app<'a>() -> Command<'a> {
                     .help("Do not link\n(Instead, just output the `.o` file.)")
                     .required(false),
             )
+            .arg(
+                Arg::new(FLAG_TIME_TRACE)
+                    .long(FLAG_TIME_TRACE)
+                    .help("Write how long each compiler phase took for each module to this file\n(It uses the Chrome trace format, so it can be opened in https://ui.perfetto.dev or chrome://tracing.)")
+                    .takes_value(true)
+                    .require_equals(true)
+                    .allow_invalid_utf8(true)
+                    .required(false),
+            )
+            .arg(flag_emit.clone())
             .arg(
                 Arg::new(ROC_FILE)
                     .help("The .roc file to build")
@@ -200,6 +279,11 @@ pub fn build_app<'a>() -> Command<'a> {
             .arg(flag_time.clone())
             .arg(flag_linker.clone())
             .arg(flag_prebuilt.clone())
+            .arg(flag_allow.clone())
+            .arg(flag_warn.clone())
+            .arg(flag_deny.clone())
+            .arg(flag_check_memory.clone())
+            .arg(flag_trace_refcounts.clone())
             .arg(
                 Arg::new(ROC_FILE)
                     .help("The .roc file for the main module")
@@ -222,6 +306,12 @@ pub fn build_app<'a>() -> Command<'a> {
             .arg(flag_time.clone())
             .arg(flag_linker.clone())
             .arg(flag_prebuilt.clone())
+            .arg(flag_allow.clone())
+            .arg(flag_warn.clone())
+            .arg(flag_deny.clone())
+            .arg(flag_check_memory.clone())
+            .arg(flag_trace_refcounts.clone())
+            .arg(flag_emit.clone())
             .arg(roc_file_to_run.clone())
             .arg(args_for_app.clone())
         )
@@ -235,6 +325,12 @@ pub fn build_app<'a>() -> Command<'a> {
             .arg(flag_time.clone())
             .arg(flag_linker.clone())
             .arg(flag_prebuilt.clone())
+            .arg(flag_allow.clone())
+            .arg(flag_warn.clone())
+            .arg(flag_deny.clone())
+            .arg(flag_check_memory.clone())
+            .arg(flag_trace_refcounts.clone())
+            .arg(flag_emit.clone())
             .arg(roc_file_to_run.clone())
             .arg(args_for_app.clone())
         )
@@ -252,6 +348,21 @@ pub fn build_app<'a>() -> Command<'a> {
                     .help("Checks that specified files are formatted\n(If formatting is needed, return a non-zero exit code.)")
                     .required(false),
             )
+            .arg(
+                Arg::new(FLAG_STDIN)
+                    .long(FLAG_STDIN)
+                    .help("Format the code read from stdin, and print the result to stdout")
+                    .conflicts_with(DIRECTORY_OR_FILES)
+                    .required(false),
+            )
+            .arg(
+                Arg::new(FLAG_RANGE)
+                    .long(FLAG_RANGE)
+                    .help("Only format the top-level definitions that overlap this range of bytes, e.g. `--range 120:250`\n(Everything else in the file stays as it is.)")
+                    .takes_value(true)
+                    .validator(format::parse_range)
+                    .required(false),
+            )
         )
         .subcommand(Command::new(CMD_VERSION)
             .about(concatcp!("Print the Roc compiler’s version, which is currently ", VERSION)))
@@ -259,6 +370,9 @@ pub fn build_app<'a>() -> Command<'a> {
             .about("Check the code for problems, but don’t build or run it")
             .arg(flag_time.clone())
             .arg(flag_max_threads.clone())
+            .arg(flag_allow.clone())
+            .arg(flag_warn.clone())
+            .arg(flag_deny.clone())
             .arg(
                 Arg::new(ROC_FILE)
                     .help("The .roc file of an app to check")
@@ -267,6 +381,17 @@ pub fn build_app<'a>() -> Command<'a> {
                     .default_value(DEFAULT_ROC_FILENAME),
             )
             )
+        .subcommand(Command::new(CMD_FIX)
+            .about("Fix the problems that have an obvious fix, like unused imports, then format the changed files")
+            .arg(flag_max_threads.clone())
+            .arg(
+                Arg::new(ROC_FILE)
+                    .help("The .roc file to fix, along with the modules it imports")
+                    .allow_invalid_utf8(true)
+                    .required(false)
+                    .default_value(DEFAULT_ROC_FILENAME),
+            )
+            )
         .subcommand(
             Command::new(CMD_DOCS)
                 .about("Generate documentation for a Roc package")
@@ -288,10 +413,18 @@ pub fn build_app<'a>() -> Command<'a> {
             )
             .arg(
                 Arg::new(GLUE_FILE)
-                    .help("The filename for the generated glue code\n(Currently, this must be a .rs file because only Rust glue generation is supported so far.)")
+                    .help("The filename for the generated glue code\n(Without --spec, this must be a .rs file. With --spec, this is the directory to write the spec's files into.)")
                     .allow_invalid_utf8(true)
                     .required(true)
             )
+            .arg(
+                Arg::new(FLAG_SPEC)
+                    .long(FLAG_SPEC)
+                    .help("A .roc app, written against the RocType.roc glue platform, which decides what glue to generate")
+                    .takes_value(true)
+                    .allow_invalid_utf8(true)
+                    .required(false)
+            )
         )
         .subcommand(Command::new(CMD_GEN_STUB_LIB)
             .about("Generate a stubbed shared library that can be used for linking a platform binary.\nThe stubbed library has prototypes, but no function bodies.\n\nNote: This command will be removed in favor of just using `roc build` once all platforms support the surgical linker")
@@ -321,6 +454,12 @@ pub fn build_app<'a>() -> Command<'a> {
         .arg(flag_time)
         .arg(flag_linker)
         .arg(flag_prebuilt)
+        .arg(flag_allow)
+        .arg(flag_warn)
+        .arg(flag_deny)
+        .arg(flag_check_memory)
+        .arg(flag_trace_refcounts)
+        .arg(flag_emit)
         .arg(roc_file_to_run.required(false))
         .arg(args_for_app);
 
@@ -352,6 +491,51 @@ pub enum FormatMode {
     CheckOnly,
 }
 
+/// The levels from the project's .roc-diagnostics file, if it has one, overridden by
+/// `--allow`, `--warn`, and `--deny` in the order they were given.
+pub fn diagnostic_levels(matches: &ArgMatches, roc_file_path: &Path) -> DiagnosticLevels {
+    let config_path = roc_file_path.with_file_name(DIAGNOSTICS_CONFIG_FILENAME);
+
+    let mut levels = match std::fs::read_to_string(&config_path) {
+        Ok(src) => DiagnosticLevels::parse_config(&src)
+            .unwrap_or_else(|message| user_error!("In {}, {}", config_path.display(), message)),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => DiagnosticLevels::default(),
+        Err(error) => user_error!("Could not read {}: {}", config_path.display(), error),
+    };
+
+    let mut flags = Vec::new();
+
+    for (flag, level) in [
+        (FLAG_ALLOW, Level::Allow),
+        (FLAG_WARN, Level::Warn),
+        (FLAG_DENY, Level::Deny),
+    ] {
+        if let (Some(indices), Some(names)) = (matches.indices_of(flag), matches.values_of(flag)) {
+            flags.extend(indices.zip(names).map(|(index, name)| (index, name, level)));
+        }
+    }
+
+    flags.sort_by_key(|(index, _, _)| *index);
+
+    for (_, name, level) in flags {
+        levels.set(name, level);
+    }
+
+    for name in levels.names() {
+        if !warning_names().any(|warning| warning == name) {
+            let warnings: Vec<_> = warning_names().collect();
+
+            user_error!(
+                "`{}` is not a kind of warning, so it can't be allowed or denied. (Errors always stay errors, because the code they're about would crash if it ran.)\n\nThese are the kinds of warnings:\n\n    {}",
+                name,
+                warnings.join("\n    ")
+            );
+        }
+    }
+
+    levels
+}
+
 #[cfg(windows)]
 pub fn test(_matches: &ArgMatches, _triple: Triple) -> io::Result<i32> {
     todo!("running tests does not work on windows right now")
@@ -361,7 +545,7 @@ pub fn test(_matches: &ArgMatches, _triple: Triple) -> io::Result<i32> {
 pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
     use roc_build::program::report_problems_monomorphized;
     use roc_gen_llvm::llvm::build::LlvmBackendMode;
-    use roc_load::{ExecutionMode, LoadConfig, LoadMonomorphizedError};
+    use roc_load::{EmitIr, ExecutionMode, LoadMonomorphizedError};
     use roc_packaging::cache;
     use roc_target::TargetInfo;
 
@@ -414,6 +598,7 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
     let target = &triple;
     let opt_level = opt_level;
     let target_info = TargetInfo::from(target);
+    let diagnostic_levels = diagnostic_levels(matches, path);
 
     // Step 1: compile the app and generate the .o file
     let load_config = LoadConfig {
@@ -423,6 +608,9 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
         palette: roc_reporting::report::DEFAULT_PALETTE,
         threading,
         exec_mode: ExecutionMode::Test,
+        fold_constants: true,
+        emit_ir: EmitIr::default(),
+        trace_refcounts: matches.is_present(FLAG_TRACE_REFCOUNTS),
     };
     let load_result = roc_load::load_and_monomorphize(
         arena,
@@ -437,30 +625,44 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
             return handle_loading_problem(problem);
         }
         Err(LoadMonomorphizedError::ErrorModule(module)) => {
-            return handle_error_module(module, start_time.elapsed(), filename, false);
+            return handle_error_module(
+                module,
+                start_time.elapsed(),
+                filename,
+                false,
+                &diagnostic_levels,
+            );
         }
     };
-    let problems = report_problems_monomorphized(&mut loaded);
+    let problems = report_problems_monomorphized(&mut loaded, &diagnostic_levels);
+
+    // Warnings that were denied only become errors once they're reported
+    if problems.errors > 0 {
+        problems.print_to_stdout(start_time.elapsed());
+        println!();
+
+        return Ok(problems.exit_code());
+    }
 
     let mut expectations = std::mem::take(&mut loaded.expectations);
 
     let interns = loaded.interns.clone();
 
+    let check_memory = matches.is_present(FLAG_CHECK_MEMORY);
+
     let (lib, expects, layout_interner) = roc_repl_expect::run::expect_mono_module_to_dylib(
         arena,
         target.clone(),
         loaded,
         opt_level,
         LlvmBackendMode::CliTest,
+        check_memory,
+        matches.is_present(FLAG_TRACE_REFCOUNTS),
     )
     .unwrap();
 
     // Print warnings before running tests.
     {
-        debug_assert_eq!(
-            problems.errors, 0,
-            "if there were errors, we would have already exited."
-        );
         if problems.warnings > 0 {
             problems.print_to_stdout(start_time.elapsed());
             println!(".\n\nRunning tests…\n\n\x1B[36m{}\x1B[39m", "─".repeat(80));
@@ -485,6 +687,12 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
     )
     .unwrap();
 
+    let memory_problems = if check_memory {
+        roc_repl_expect::run::report_memory_problems(&lib)
+    } else {
+        0
+    };
+
     let total_time = start_time.elapsed();
 
     if failed == 0 && passed == 0 {
@@ -509,7 +717,13 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
             total_time.as_millis(),
         );
 
-        Ok((failed > 0) as i32)
+        if memory_problems > 0 {
+            println!(
+                "\x1B[31m{memory_problems}\x1B[39m memory problems were found while running the tests.\n"
+            );
+        }
+
+        Ok((failed > 0 || memory_problems > 0) as i32)
     }
 }
 
@@ -590,6 +804,8 @@ pub fn build(
         path.to_path_buf()
     };
 
+    let diagnostic_levels = diagnostic_levels(matches, &path_buf);
+
     // the process will end after this function,
     // so we don't want to spend time freeing these values
     let arena = ManuallyDrop::new(Bump::new());
@@ -620,6 +836,11 @@ pub fn build(
     };
     let emit_debug_info = matches.is_present(FLAG_DEBUG);
     let emit_timings = matches.is_present(FLAG_TIME);
+    let time_trace = if config == BuildConfig::BuildOnly {
+        matches.value_of_os(FLAG_TIME_TRACE).map(PathBuf::from)
+    } else {
+        None
+    };
 
     let threading = match matches
         .value_of(FLAG_MAX_THREADS)
@@ -634,8 +855,21 @@ pub fn build(
     let wasm_dev_backend = matches!(opt_level, OptLevel::Development)
         && matches!(code_gen_backend, CodeGenBackend::Wasm);
 
+    let check_memory = config != BuildConfig::BuildOnly && matches.is_present(FLAG_CHECK_MEMORY);
+
+    if check_memory && matches!(triple.architecture, Architecture::Wasm32) {
+        user_error!("`--{FLAG_CHECK_MEMORY}` is not supported when building for wasm32");
+    }
+
     let linking_strategy = if wasm_dev_backend {
         LinkingStrategy::Additive
+    } else if check_memory {
+        // The surgical linker drops the app's destructors, so the report at exit would never run
+        if matches.value_of(FLAG_LINKER) == Some("surgical") {
+            user_error!("`--{FLAG_CHECK_MEMORY}` needs the legacy linker, so it can't be combined with `--{FLAG_LINKER}=surgical`");
+        }
+
+        LinkingStrategy::Legacy
     } else if !roc_linker::supported(link_type, &triple)
         || matches.value_of(FLAG_LINKER) == Some("legacy")
     {
@@ -665,13 +899,45 @@ pub fn build(
         _ => BuildOrdering::AlwaysBuild,
     };
 
+    let emit: Emit = matches
+        .values_of(FLAG_EMIT)
+        .into_iter()
+        .flatten()
+        .filter_map(EmitKind::from_name)
+        .collect();
+
+    if emit.contains(EmitKind::LlvmIr)
+        && (wasm_dev_backend || matches!(code_gen_backend, CodeGenBackend::Assembly))
+    {
+        user_error!("`--{FLAG_EMIT}=llvm-ir` needs the LLVM backend, so it can't be combined with `--{FLAG_DEV}`");
+    }
+
+    if emit.contains(EmitKind::WasmWat) && !matches!(triple.architecture, Architecture::Wasm32) {
+        user_error!(
+            "`--{FLAG_EMIT}=wasm-wat` only applies when building for `--{FLAG_TARGET}=wasm32`"
+        );
+    }
+
+    if emit.contains(EmitKind::Asm) && matches!(triple.architecture, Architecture::Wasm32) {
+        user_error!("wasm32 has no assembly to emit; use `--{FLAG_EMIT}=wasm-wat` instead");
+    }
+
+    let trace_refcounts = matches.is_present(FLAG_TRACE_REFCOUNTS);
+
     let code_gen_options = CodeGenOptions {
         backend: code_gen_backend,
         opt_level,
         emit_debug_info,
+        emit,
+        check_memory,
+        trace_refcounts,
     };
 
-    let load_config = standard_load_config(&triple, build_ordering, threading);
+    let load_config = LoadConfig {
+        emit_ir: emit.emit_ir(),
+        trace_refcounts,
+        ..standard_load_config(&triple, build_ordering, threading)
+    };
 
     let res_binary_path = build_file(
         &arena,
@@ -679,6 +945,8 @@ pub fn build(
         path_buf,
         code_gen_options,
         emit_timings,
+        time_trace,
+        &diagnostic_levels,
         link_type,
         linking_strategy,
         prebuilt,
@@ -731,10 +999,15 @@ pub fn build(
                     roc_run(&arena, opt_level, triple, args, bytes, expect_metadata)
                 }
                 BuildAndRunIfNoErrors => {
-                    debug_assert_eq!(
-                        problems.errors, 0,
-                        "if there are errors, they should have been returned as an error variant"
-                    );
+                    // Errors in the code were returned as an error variant, but warnings that
+                    // were denied only become errors once they're reported.
+                    if problems.errors > 0 {
+                        problems.print_to_stdout(total_time);
+                        println!();
+
+                        return Ok(problems.exit_code());
+                    }
+
                     if problems.warnings > 0 {
                         problems.print_to_stdout(total_time);
                         println!(
@@ -754,7 +1027,7 @@ pub fn build(
             }
         }
         Err(BuildFileError::ErrorModule { module, total_time }) => {
-            handle_error_module(module, total_time, filename, true)
+            handle_error_module(module, total_time, filename, true, &diagnostic_levels)
         }
         Err(BuildFileError::LoadingProblem(problem)) => handle_loading_problem(problem),
     }
@@ -765,10 +1038,11 @@ fn handle_error_module(
     total_time: std::time::Duration,
     filename: &OsStr,
     print_run_anyway_hint: bool,
+    diagnostic_levels: &DiagnosticLevels,
 ) -> io::Result<i32> {
     debug_assert!(module.total_problems() > 0);
 
-    let problems = roc_build::program::report_problems_typechecked(&mut module);
+    let problems = roc_build::program::report_problems_typechecked(&mut module, diagnostic_levels);
 
     problems.print_to_stdout(total_time);
 
@@ -1323,40 +1597,3 @@ impl std::str::FromStr for Target {
         }
     }
 }
-
-// These functions don't end up in the final Roc binary but Windows linker needs a definition inside the crate.
-// On Windows, there seems to be less dead-code-elimination than on Linux or MacOS, or maybe it's done later.
-#[cfg(windows)]
-#[allow(unused_imports)]
-use windows_roc_platform_functions::*;
-
-#[cfg(windows)]
-mod windows_roc_platform_functions {
-    use core::ffi::c_void;
-
-    /// # Safety
-    /// The Roc application needs this.
-    #[no_mangle]
-    pub unsafe fn roc_alloc(size: usize, _alignment: u32) -> *mut c_void {
-        libc::malloc(size)
-    }
-
-    /// # Safety
-    /// The Roc application needs this.
-    #[no_mangle]
-    pub unsafe fn roc_realloc(
-        c_ptr: *mut c_void,
-        new_size: usize,
-        _old_size: usize,
-        _alignment: u32,
-    ) -> *mut c_void {
-        libc::realloc(c_ptr, new_size)
-    }
-
-    /// # Safety
-    /// The Roc application needs this.
-    #[no_mangle]
-    pub unsafe fn roc_dealloc(c_ptr: *mut c_void, _alignment: u32) {
-        libc::free(c_ptr)
-    }
-}
@@ -2,10 +2,10 @@
 use roc_build::link::LinkType;
 use roc_cli::build::check_file;
 use roc_cli::{
-    build_app, format, test, BuildConfig, FormatMode, Target, CMD_BUILD, CMD_CHECK, CMD_DEV,
-    CMD_DOCS, CMD_EDIT, CMD_FORMAT, CMD_GEN_STUB_LIB, CMD_GLUE, CMD_REPL, CMD_RUN, CMD_TEST,
-    CMD_VERSION, DIRECTORY_OR_FILES, FLAG_CHECK, FLAG_LIB, FLAG_NO_LINK, FLAG_TARGET, FLAG_TIME,
-    GLUE_FILE, ROC_FILE,
+    build_app, fix, format, format_stdin, parse_range, test, BuildConfig, FormatMode, Target,
+    CMD_BUILD, CMD_CHECK, CMD_DEV, CMD_DOCS, CMD_EDIT, CMD_FIX, CMD_FORMAT, CMD_GEN_STUB_LIB,
+    CMD_GLUE, CMD_REPL, CMD_RUN, CMD_TEST, CMD_VERSION, DIRECTORY_OR_FILES, FLAG_CHECK, FLAG_LIB,
+    FLAG_NO_LINK, FLAG_RANGE, FLAG_SPEC, FLAG_STDIN, FLAG_TARGET, FLAG_TIME, GLUE_FILE, ROC_FILE,
 };
 use roc_docs::generate_docs_html;
 use roc_error_macros::user_error;
@@ -89,11 +89,13 @@ fn main() -> io::Result<()> {
         Some((CMD_GLUE, matches)) => {
             let input_path = Path::new(matches.value_of_os(ROC_FILE).unwrap());
             let output_path = Path::new(matches.value_of_os(GLUE_FILE).unwrap());
+            let spec_path = matches.value_of_os(FLAG_SPEC).map(Path::new);
 
-            if Some("rs") == output_path.extension().and_then(OsStr::to_str) {
-                roc_glue::generate(input_path, output_path)
+            if spec_path.is_some() || Some("rs") == output_path.extension().and_then(OsStr::to_str)
+            {
+                roc_glue::generate(input_path, output_path, spec_path)
             } else {
-                eprintln!("Currently, `roc glue` only supports generating Rust glue files (with the .rs extension). In the future, the plan is to decouple `roc glue` from any particular output format, by having it accept a second .roc file which gets executed as a plugin to generate glue code for any desired language. However, this has not yet been implemented, and for now only .rs is supported.");
+                eprintln!("Without --spec, `roc glue` only supports generating Rust glue files (with the .rs extension). To generate glue for another language, pass a .roc glue spec with --spec, and a directory to write its output into.");
 
                 Ok(1)
             }
@@ -143,12 +145,15 @@ fn main() -> io::Result<()> {
                 Some(n) => Threading::AtMost(n),
             };
 
+            let diagnostic_levels = roc_cli::diagnostic_levels(matches, &roc_file_path);
+
             match check_file(
                 &arena,
                 roc_file_path,
                 emit_timings,
                 RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
                 threading,
+                &diagnostic_levels,
             ) {
                 Ok((problems, total_time)) => {
                     println!(
@@ -191,6 +196,57 @@ fn main() -> io::Result<()> {
                 }
             }
         }
+        Some((CMD_FIX, matches)) => {
+            let arena = bumpalo::Bump::new();
+
+            let filename = matches.value_of_os(ROC_FILE).unwrap();
+            let roc_file_path = PathBuf::from(filename);
+            let threading = match matches
+                .value_of(roc_cli::FLAG_MAX_THREADS)
+                .and_then(|s| s.parse::<usize>().ok())
+            {
+                None => Threading::AllAvailable,
+                Some(0) => user_error!("cannot build with at most 0 threads"),
+                Some(1) => Threading::Single,
+                Some(n) => Threading::AtMost(n),
+            };
+
+            match fix(
+                &arena,
+                roc_file_path,
+                RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
+                threading,
+            ) {
+                Ok(fixed) => {
+                    println!(
+                        "Applied {} {} in {} {}.",
+                        fixed.fixes,
+                        if fixed.fixes == 1 { "fix" } else { "fixes" },
+                        fixed.files.len(),
+                        if fixed.files.len() == 1 {
+                            "file"
+                        } else {
+                            "files"
+                        },
+                    );
+
+                    for file in fixed.files {
+                        println!("    {}", file.display());
+                    }
+
+                    Ok(0)
+                }
+
+                Err(LoadingProblem::FormattedReport(report)) => {
+                    print!("{}", report);
+
+                    Ok(1)
+                }
+                Err(other) => {
+                    panic!("fix failed with error:\n{:?}", other);
+                }
+            }
+        }
         Some((CMD_REPL, _)) => Ok(roc_repl_cli::main()),
         Some((CMD_EDIT, matches)) => {
             match matches
@@ -216,42 +272,53 @@ fn main() -> io::Result<()> {
             Ok(0)
         }
         Some((CMD_FORMAT, matches)) => {
-            let maybe_values = matches.values_of_os(DIRECTORY_OR_FILES);
-
-            let mut values: Vec<OsString> = Vec::new();
-
-            match maybe_values {
-                None => {
-                    let mut os_string_values: Vec<OsString> = Vec::new();
-                    read_all_roc_files(
-                        &std::env::current_dir()?.as_os_str().to_os_string(),
-                        &mut os_string_values,
-                    )?;
-                    for os_string in os_string_values {
-                        values.push(os_string);
+            let format_mode = match matches.is_present(FLAG_CHECK) {
+                true => FormatMode::CheckOnly,
+                false => FormatMode::Format,
+            };
+
+            // This has already been validated by clap
+            let range = matches
+                .value_of(FLAG_RANGE)
+                .map(|range| parse_range(range).unwrap());
+
+            let format_result = if matches.is_present(FLAG_STDIN) {
+                format_stdin(format_mode, range)
+            } else {
+                let maybe_values = matches.values_of_os(DIRECTORY_OR_FILES);
+
+                let mut values: Vec<OsString> = Vec::new();
+
+                match maybe_values {
+                    None => {
+                        let mut os_string_values: Vec<OsString> = Vec::new();
+                        read_all_roc_files(
+                            &std::env::current_dir()?.as_os_str().to_os_string(),
+                            &mut os_string_values,
+                        )?;
+                        for os_string in os_string_values {
+                            values.push(os_string);
+                        }
                     }
-                }
-                Some(os_values) => {
-                    for os_str in os_values {
-                        values.push(os_str.to_os_string());
+                    Some(os_values) => {
+                        for os_str in os_values {
+                            values.push(os_str.to_os_string());
+                        }
                     }
                 }
-            }
 
-            let mut roc_files = Vec::new();
+                let mut roc_files = Vec::new();
 
-            // Populate roc_files
-            for os_str in values {
-                let metadata = fs::metadata(os_str.clone())?;
-                roc_files_recursive(os_str.as_os_str(), metadata.file_type(), &mut roc_files)?;
-            }
+                // Populate roc_files
+                for os_str in values {
+                    let metadata = fs::metadata(os_str.clone())?;
+                    roc_files_recursive(os_str.as_os_str(), metadata.file_type(), &mut roc_files)?;
+                }
 
-            let format_mode = match matches.is_present(FLAG_CHECK) {
-                true => FormatMode::CheckOnly,
-                false => FormatMode::Format,
+                format(roc_files, format_mode, range)
             };
 
-            let format_exit_code = match format(roc_files, format_mode) {
+            let format_exit_code = match format_result {
                 Ok(_) => 0,
                 Err(message) => {
                     eprintln!("{}", message);
@@ -322,3 +389,77 @@ fn launch_editor(project_dir_path: Option<&Path>) -> io::Result<()> {
 fn launch_editor(_project_dir_path: Option<&Path>) -> io::Result<()> {
     panic!("Cannot launch the editor because this build of roc did not include `feature = \"editor\"`!");
 }
+
+// `roc glue` runs glue specs in this process, and roc_std allocates the values passed to a spec
+// through these. The spec gets its own copies of the allocation functions
+// (see add_default_roc_externs), which also use libc, so memory can be freed on either side.
+mod glue_platform_functions {
+    use core::ffi::c_void;
+
+    /// # Safety
+    /// roc_std needs this.
+    #[no_mangle]
+    pub unsafe extern "C" fn roc_alloc(size: usize, _alignment: u32) -> *mut c_void {
+        libc::malloc(size)
+    }
+
+    /// # Safety
+    /// roc_std needs this.
+    #[no_mangle]
+    pub unsafe extern "C" fn roc_realloc(
+        c_ptr: *mut c_void,
+        new_size: usize,
+        _old_size: usize,
+        _alignment: u32,
+    ) -> *mut c_void {
+        libc::realloc(c_ptr, new_size)
+    }
+
+    /// # Safety
+    /// roc_std needs this.
+    #[no_mangle]
+    pub unsafe extern "C" fn roc_dealloc(c_ptr: *mut c_void, _alignment: u32) {
+        libc::free(c_ptr)
+    }
+
+    /// # Safety
+    /// roc_std needs this.
+    #[no_mangle]
+    pub unsafe extern "C" fn roc_panic(c_ptr: *mut c_void, tag_id: u32) {
+        use std::ffi::CStr;
+        use std::os::raw::c_char;
+
+        let c_str = CStr::from_ptr(c_ptr as *const c_char);
+
+        match tag_id {
+            0 => eprintln!("Roc hit a panic: {}", c_str.to_string_lossy()),
+            1 => eprintln!("User crash: {}", c_str.to_string_lossy()),
+            _ => eprintln!(
+                "Roc hit a panic with tag {}: {}",
+                tag_id,
+                c_str.to_string_lossy()
+            ),
+        }
+
+        // Unwinding across this extern "C" function would abort, so exit instead.
+        std::process::exit(1);
+    }
+
+    /// # Safety
+    /// roc_std needs this.
+    #[no_mangle]
+    pub unsafe extern "C" fn roc_memcpy(
+        dst: *mut c_void,
+        src: *mut c_void,
+        n: usize,
+    ) -> *mut c_void {
+        libc::memcpy(dst, src, n)
+    }
+
+    /// # Safety
+    /// roc_std needs this.
+    #[no_mangle]
+    pub unsafe extern "C" fn roc_memset(dst: *mut c_void, c: i32, n: usize) -> *mut c_void {
+        libc::memset(dst, c, n)
+    }
+}
@@ -15,7 +15,7 @@ mod cli_run {
     };
     use const_format::concatcp;
     use indoc::indoc;
-    use roc_cli::{CMD_BUILD, CMD_CHECK, CMD_DEV, CMD_FORMAT, CMD_RUN, CMD_TEST};
+    use roc_cli::{CMD_BUILD, CMD_CHECK, CMD_DEV, CMD_FIX, CMD_FORMAT, CMD_RUN, CMD_TEST};
     use roc_test_utils::assert_multiline_str_eq;
     use serial_test::serial;
     use std::iter;
@@ -51,9 +51,20 @@ mod cli_run {
     const OPTIMIZE_FLAG: &str = concatcp!("--", roc_cli::FLAG_OPTIMIZE);
     const LINKER_FLAG: &str = concatcp!("--", roc_cli::FLAG_LINKER);
     const CHECK_FLAG: &str = concatcp!("--", roc_cli::FLAG_CHECK);
+    const STDIN_FLAG: &str = concatcp!("--", roc_cli::FLAG_STDIN);
+    const RANGE_FLAG: &str = concatcp!("--", roc_cli::FLAG_RANGE);
     const PREBUILT_PLATFORM: &str = concatcp!("--", roc_cli::FLAG_PREBUILT, "=true");
+    const EMIT_FLAG_ALL_NATIVE: &str = concatcp!(
+        "--",
+        roc_cli::FLAG_EMIT,
+        "=can,mono,mono-refcounted,llvm-ir,asm"
+    );
+    const EMIT_FLAG_MONO: &str = concatcp!("--", roc_cli::FLAG_EMIT, "=mono");
     #[allow(dead_code)]
     const TARGET_FLAG: &str = concatcp!("--", roc_cli::FLAG_TARGET);
+    const CHECK_MEMORY_FLAG: &str = concatcp!("--", roc_cli::FLAG_CHECK_MEMORY);
+    const DEV_FLAG: &str = concatcp!("--", roc_cli::FLAG_DEV);
+    const TRACE_REFCOUNTS_FLAG: &str = concatcp!("--", roc_cli::FLAG_TRACE_REFCOUNTS);
 
     #[derive(Debug)]
     enum CliMode {
@@ -894,6 +905,107 @@ mod cli_run {
         test_roc_expect("examples/parser/package", "ParserHttp.roc")
     }
 
+    #[test]
+    #[cfg_attr(windows, ignore)]
+    fn parse_http_check_memory() {
+        let path = file_path_from_root("examples/parser/package", "ParserHttp.roc");
+        let out = run_roc(
+            &[CMD_TEST, CHECK_MEMORY_FLAG, path.to_str().unwrap()],
+            &[],
+            &[],
+        );
+
+        assert!(out.status.success());
+        assert!(
+            !out.stderr.contains("Memory check:"),
+            "the memory checker found problems:\n{}",
+            out.stderr
+        );
+    }
+
+    fn check_memory(backend_flags: &[&str]) {
+        let path = file_path_from_root("crates/cli_testing_examples/refcounts", "check-memory.roc");
+        let args = [CMD_RUN, CHECK_MEMORY_FLAG]
+            .into_iter()
+            .chain(backend_flags.iter().copied())
+            .chain([path.to_str().unwrap()]);
+        let out = run_roc(args, &[], &[]);
+
+        assert!(out.status.success(), "{}", out.stderr);
+        assert!(
+            out.stdout
+                .ends_with("This string is much too long to fit inside a small Str, 42"),
+            "{}",
+            out.stdout
+        );
+        assert!(
+            !out.stderr.contains("Memory check:"),
+            "the memory checker found problems:\n{}",
+            out.stderr
+        );
+    }
+
+    // Only linux sends the host's own calls to roc_dealloc through the memory checker,
+    // elsewhere the host freeing the returned string would look like a leak.
+    #[test]
+    #[cfg_attr(not(target_os = "linux"), ignore)]
+    fn check_memory_llvm() {
+        check_memory(&[]);
+    }
+
+    #[test]
+    #[cfg_attr(not(all(target_os = "linux", target_arch = "x86_64")), ignore)]
+    fn check_memory_dev() {
+        check_memory(&[DEV_FLAG]);
+    }
+
+    #[test]
+    #[cfg_attr(windows, ignore)]
+    fn trace_refcounts() {
+        let path = file_path_from_root(
+            "crates/cli_testing_examples/refcounts",
+            "trace-refcounts.roc",
+        );
+        let out = run_roc(
+            &[CMD_RUN, TRACE_REFCOUNTS_FLAG, path.to_str().unwrap()],
+            &[],
+            &[],
+        );
+
+        assert!(out.status.success(), "{}", out.stderr);
+        assert!(out.stdout.ends_with('2'), "{}", out.stdout);
+
+        let shared = format!(
+            "Refcount trace: made a unique value shared in record update at {}:10:26\n",
+            path.display()
+        );
+        assert!(
+            out.stderr.contains(&shared),
+            "missing {:?} in:\n{}",
+            shared,
+            out.stderr
+        );
+
+        // How often `items` is shared by then depends on where the refcount pass puts its `inc`s
+        let copied = format!(" times in List.set at {}:13:15", path.display());
+        assert!(
+            out.stderr.lines().any(|line| {
+                line.starts_with("Refcount trace: copied a list of 24 bytes shared ")
+                    && line.ends_with(&copied)
+            }),
+            "missing a copy in List.set in:\n{}",
+            out.stderr
+        );
+
+        // The location is reset after each operation, so nothing else is reported
+        assert_eq!(
+            out.stderr.matches("Refcount trace:").count(),
+            2,
+            "{}",
+            out.stderr
+        );
+    }
+
     // TODO not sure if this cfg should still be here: #[cfg(not(debug_assertions))]
     // this is for testing the benchmarks, to perform proper benchmarks see crates/cli/benches/README.md
     mod test_benchmarks {
@@ -1415,6 +1527,38 @@ mod cli_run {
         );
     }
 
+    #[test]
+    fn unused_import_denied() {
+        check_compile_error(
+            &known_bad_file("UnusedImport.roc"),
+            &["--deny", "unused-import"],
+            indoc!(
+                r#"
+                ── UNUSED IMPORT ──────────────────────────── tests/known_bad/UnusedImport.roc ─
+
+                Nothing from Symbol is used in this module.
+
+                3│      imports [Symbol.{ Ident }]
+                                 ^^^^^^^^^^^^^^^^
+
+                Since Symbol isn't used, you don't need to import it.
+
+                ────────────────────────────────────────────────────────────────────────────────
+
+                1 error and 0 warnings found in <ignored for test> ms."#
+            ),
+        );
+    }
+
+    #[test]
+    fn unused_import_allowed() {
+        check_compile_error(
+            &known_bad_file("UnusedImport.roc"),
+            &["--allow", "unused-import"],
+            "0 errors and 0 warnings found in <ignored for test> ms.",
+        );
+    }
+
     #[test]
     fn unknown_generates_with() {
         check_compile_error(
@@ -1449,6 +1593,71 @@ mod cli_run {
         check_format_check_as_expected(&fixture_file("format", "NotFormatted.roc"), false);
     }
 
+    #[test]
+    fn format_check_shows_diff() {
+        let file = fixture_file("format", "NotFormatted.roc");
+        let out = run_roc([CMD_FORMAT, file.to_str().unwrap(), CHECK_FLAG], &[], &[]);
+        let diff = strip_colors(&out.stdout);
+
+        assert!(!out.status.success());
+        assert!(
+            diff.contains("@@ -1,6 +1,6 @@\n app \"formatted\"\n     packages { pf: \"platform/main.roc\" }\n-  provides [main] to pf\n+    provides [main] to pf\n"),
+            "Unexpected diff:\n{}",
+            diff
+        );
+    }
+
+    #[test]
+    fn format_stdin() {
+        let src = std::fs::read_to_string(fixture_file("format", "NotFormatted.roc")).unwrap();
+        let out = run_roc([CMD_FORMAT, STDIN_FLAG], &[&src], &[]);
+
+        assert!(out.status.success());
+        assert_multiline_str_eq!(
+            out.stdout.as_str(),
+            indoc!(
+                r#"
+                app "formatted"
+                    packages { pf: "platform/main.roc" }
+                    provides [main] to pf
+
+                main : Str
+                main = Dep1.value1 {}
+                "#
+            )
+        );
+    }
+
+    #[test]
+    fn format_stdin_range() {
+        let src = indoc!(
+            r#"
+            interface Test exposes [] imports []
+
+            a  =   1
+
+            b  =   2
+            "#
+        );
+        let b_start = src.find('b').unwrap();
+        let range = format!("{}:{}", b_start, b_start + 1);
+        let out = run_roc([CMD_FORMAT, STDIN_FLAG, RANGE_FLAG, &range], &[src], &[]);
+
+        assert!(out.status.success());
+        assert_multiline_str_eq!(
+            out.stdout.as_str(),
+            indoc!(
+                r#"
+                interface Test exposes [] imports []
+
+                a  =   1
+
+                b = 2
+                "#
+            )
+        );
+    }
+
     #[test]
     fn format_check_folders() {
         // This fails, because "NotFormatted.roc" is present in this folder
@@ -1457,6 +1666,114 @@ mod cli_run {
         // This doesn't fail, since only "Formatted.roc" and non-roc files are present in this folder
         check_format_check_as_expected(&fixtures_dir("format/formatted_directory"), true);
     }
+
+    #[test]
+    fn fix_unused_import() {
+        let dir = tempfile::tempdir().unwrap();
+        for file_name in ["UnusedImport.roc", "Symbol.roc"] {
+            std::fs::copy(known_bad_file(file_name), dir.path().join(file_name)).unwrap();
+        }
+
+        let file = dir.path().join("UnusedImport.roc");
+        let out = run_roc([CMD_FIX, file.to_str().unwrap()], &[], &[]);
+
+        assert!(out.status.success(), "{}", out.stderr);
+        assert_multiline_str_eq!(
+            std::fs::read_to_string(file).unwrap().as_str(),
+            indoc!(
+                r#"
+                interface UnusedImport
+                    exposes [plainText, emText]
+                    imports []
+
+                plainText = \str -> PlainText str
+
+                emText = \str -> EmText str
+                "#
+            )
+        );
+    }
+
+    #[test]
+    #[cfg_attr(windows, ignore)]
+    fn emit_intermediate_representations() {
+        let file_name =
+            file_path_from_root("crates/cli_testing_examples/algorithms", "fibonacci.roc");
+        let out = run_roc_on(&file_name, [CMD_BUILD, EMIT_FLAG_ALL_NATIVE], &[], &[], &[]);
+        assert!(out.status.success(), "{}", out.stderr);
+
+        let executable = file_name.with_file_name("fibonacci");
+        let expected = [
+            ("can", "fib"),
+            ("mono", "procedure"),
+            ("mono-refcounted", "procedure"),
+            ("ll", "define"),
+            ("s", "mainForHost"),
+        ];
+
+        for (extension, needle) in expected {
+            let path = executable.with_extension(extension);
+            let contents = std::fs::read_to_string(&path)
+                .unwrap_or_else(|_| panic!("`--emit` did not write {}", path.display()));
+            std::fs::remove_file(&path).unwrap();
+
+            assert!(
+                contents.contains(needle),
+                "expected {} to mention {:?}, but it was:\n{}",
+                path.display(),
+                needle,
+                contents
+            );
+        }
+    }
+
+    #[test]
+    #[cfg_attr(windows, ignore)]
+    fn emit_when_running() {
+        let file_name =
+            file_path_from_root("crates/cli_testing_examples/algorithms", "fibonacci.roc");
+        let out = run_roc_on(&file_name, [CMD_RUN, EMIT_FLAG_MONO], &[], &[], &[]);
+        assert!(out.status.success(), "{}", out.stderr);
+
+        let path = file_name.with_file_name("fibonacci").with_extension("mono");
+        let contents = std::fs::read_to_string(&path)
+            .unwrap_or_else(|_| panic!("`--emit` did not write {}", path.display()));
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(contents.contains("procedure"), "{}", contents);
+    }
+
+    #[test]
+    #[cfg_attr(windows, ignore)]
+    fn time_trace() {
+        let file_name =
+            file_path_from_root("crates/cli_testing_examples/algorithms", "fibonacci.roc");
+        let dir = tempfile::tempdir().unwrap();
+        let trace_path = dir.path().join("trace.json");
+        let time_trace_flag = format!("--{}={}", roc_cli::FLAG_TIME_TRACE, trace_path.display());
+
+        let out = run_roc_on(
+            &file_name,
+            [CMD_BUILD, time_trace_flag.as_str()],
+            &[],
+            &[],
+            &[],
+        );
+        assert!(out.status.success(), "{}", out.stderr);
+
+        let trace = std::fs::read_to_string(&trace_path).unwrap();
+
+        assert!(trace.starts_with(r#"{"displayTimeUnit":"ms","traceEvents":["#));
+        for span in [
+            r#""name":"Parse body: Application Module""#,
+            r#""name":"Solve: Application Module""#,
+            r#""name":"Make Specializations: Application Module""#,
+            r#""name":"Code Generation""#,
+            r#""name":"Linking""#,
+        ] {
+            assert!(trace.contains(span), "missing {} in:\n{}", span, trace);
+        }
+    }
 }
 
 #[cfg(feature = "wasm32-cli-run")]
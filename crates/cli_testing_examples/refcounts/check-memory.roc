@@ -0,0 +1,10 @@
+app "check-memory"
+    packages { pf: "../expects/zig-platform/main.roc" }
+    imports []
+    provides [main] to pf
+
+main =
+    # Too long to be stored inline in the `Str`, so the host has to deallocate it
+    greeting = Str.concat "This string is much too long to " "fit inside a small Str, "
+
+    Str.concat greeting (Num.toStr 42)
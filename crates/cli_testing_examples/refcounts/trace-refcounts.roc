@@ -0,0 +1,15 @@
+app "trace-refcounts"
+    packages { pf: "../expects/zig-platform/main.roc" }
+    imports []
+    provides [main] to pf
+
+main =
+    counter = { items: List.repeat 0 3, count: 0 }
+
+    # `counter` is still used below, so this shares `items` between the two records
+    bumped = { counter & count: 1 }
+
+    # `bumped.items` is shared, so `List.set` has to copy it
+    changed = List.set bumped.items 0 1
+
+    Num.toStr (List.sum changed + List.sum counter.items + counter.count + bumped.count)
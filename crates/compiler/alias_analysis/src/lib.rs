@@ -1230,6 +1230,27 @@ fn lowlevel_spec<'a>(
 
             with_new_heap_cell(builder, block, bag)
         }
+        NumReadBytesLe | NumReadBytesBe => {
+            // NOTE the read is only evaluated if the bytes are in-bounds
+            let list = env.symbols[&arguments[0]];
+
+            let cell = builder.add_get_tuple_field(block, list, LIST_CELL_INDEX)?;
+
+            let _unit = builder.add_touch(block, cell)?;
+
+            new_num(builder, block)
+        }
+        NumWriteBytesLe | NumWriteBytesBe => {
+            let list = env.symbols[&arguments[0]];
+
+            let bag = builder.add_get_tuple_field(block, list, LIST_BAG_INDEX)?;
+            let cell = builder.add_get_tuple_field(block, list, LIST_CELL_INDEX)?;
+
+            let _unit1 = builder.add_touch(block, cell)?;
+            let _unit2 = builder.add_update(block, update_mode_var, cell)?;
+
+            with_new_heap_cell(builder, block, bag)
+        }
         ListWithCapacity => {
             // essentially an empty list, capacity is not relevant for morphic
 
@@ -1616,11 +1637,21 @@ fn expr_spec<'a>(
 
             builder.add_terminate(block, type_id)
         }
+        NullPointer => {
+            // a placeholder that is overwritten before it is read
+            let type_id = layout_spec(env, builder, interner, layout, &WhenRecursive::Unreachable)?;
+
+            builder.add_unknown_with(block, &[], type_id)
+        }
         GetTagId { .. } => {
             // TODO touch heap cell in recursive cases
 
             builder.add_make_tuple(block, &[])
         }
+        UnionFieldPtrAtIndex { .. } => {
+            // an address is just a number
+            builder.add_make_tuple(block, &[])
+        }
     }
 }
 
@@ -5,3 +5,4 @@
 pub mod link;
 pub mod program;
 pub mod target;
+pub mod trace;
@@ -33,11 +33,16 @@ pub enum LinkingStrategy {
 }
 
 /// input_paths can include the host as well as the app. e.g. &["host.o", "roc_app.o"]
+///
+/// With check_memory, every call to roc_alloc, roc_realloc and roc_dealloc is sent through the
+/// memory checker in the builtins. This needs a host that went through `alias_host_allocators`,
+/// and is only supported on linux so far.
 pub fn link(
     target: &Triple,
     output_path: PathBuf,
     input_paths: &[&str],
     link_type: LinkType,
+    check_memory: bool,
 ) -> io::Result<(Child, PathBuf)> {
     match target {
         Triple {
@@ -47,7 +52,7 @@ pub fn link(
         Triple {
             operating_system: OperatingSystem::Linux,
             ..
-        } => link_linux(target, output_path, input_paths, link_type),
+        } => link_linux(target, output_path, input_paths, link_type, check_memory),
         Triple {
             operating_system: OperatingSystem::Darwin,
             ..
@@ -137,6 +142,23 @@ pub const fn preprocessed_host_filename(target: &Triple) -> Option<&'static str>
     }
 }
 
+/// Give the host's roc_alloc, roc_realloc and roc_dealloc a second name, `__real_roc_alloc` and so on.
+/// `link` with check_memory points the original names at the memory checker, and `ld --wrap` can't be
+/// used for that because it leaves the calls that the host object makes to its own functions alone.
+pub fn alias_host_allocators(host_input_path: &Path, output_path: &Path) -> io::Result<Child> {
+    Command::new("ld")
+        .args([
+            "-r",
+            host_input_path.to_str().unwrap(),
+            "--defsym=__real_roc_alloc=roc_alloc",
+            "--defsym=__real_roc_realloc=roc_realloc",
+            "--defsym=__real_roc_dealloc=roc_dealloc",
+            "-o",
+            output_path.to_str().unwrap(),
+        ])
+        .spawn()
+}
+
 pub fn get_target_triple_str(target: &Triple) -> Option<&'static str> {
     match target {
         Triple {
@@ -1001,6 +1023,7 @@ fn link_linux(
     output_path: PathBuf,
     input_paths: &[&str],
     link_type: LinkType,
+    check_memory: bool,
 ) -> io::Result<(Child, PathBuf)> {
     let architecture = format!("{}-linux-gnu", target.architecture);
 
@@ -1202,6 +1225,17 @@ fn link_linux(
             output_path.as_path().to_str().unwrap(), // app (or app.so or app.dylib etc.)
         ]);
 
+    if check_memory {
+        // Send every call to the allocator through the memory checker in the builtins, including
+        // the calls the host makes itself. The checker reaches the host's own functions through
+        // the names that `alias_host_allocators` gave them.
+        command.args([
+            "--defsym=roc_alloc=__wrap_roc_alloc",
+            "--defsym=roc_realloc=__wrap_roc_realloc",
+            "--defsym=roc_dealloc=__wrap_roc_dealloc",
+        ]);
+    }
+
     let output = command.spawn()?;
 
     Ok((output, output_path))
@@ -1390,6 +1424,7 @@ fn link_windows(
                 .args(input_paths)
                 .args([
                     "-lc",
+                    "-ladvapi32",
                     &format!("-femit-bin={}", output_path.to_str().unwrap()),
                     "-target",
                     "native",
@@ -1416,6 +1451,8 @@ fn link_windows(
                     "--subsystem",
                     "console",
                     "-lc",
+                    // the builtins get random hash seeds from RtlGenRandom
+                    "-ladvapi32",
                     &format!("-femit-bin={}", output_path.to_str().unwrap()),
                 ])
                 .spawn()?;
@@ -1456,6 +1493,7 @@ pub fn llvm_module_to_dylib(
         app_o_file.clone(),
         &[app_o_file.to_str().unwrap()],
         LinkType::Dylib,
+        false,
     )
     .unwrap();
 
@@ -1,10 +1,10 @@
 use inkwell::memory_buffer::MemoryBuffer;
-use roc_error_macros::internal_error;
+use roc_error_macros::{internal_error, user_error};
 use roc_gen_llvm::llvm::build::{module_from_builtins, LlvmBackendMode};
 use roc_gen_llvm::llvm::externs::add_default_roc_externs;
-use roc_load::{EntryPoint, ExpectMetadata, LoadedModule, MonomorphizedModule};
+use roc_load::{EmitIr, EmittedIr, EntryPoint, ExpectMetadata, LoadedModule, MonomorphizedModule};
 use roc_mono::ir::{OptLevel, SingleEntryPoint};
-use roc_reporting::cli::{report_problems, Problems};
+use roc_reporting::cli::{report_problems, DiagnosticLevels, Problems};
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
@@ -17,23 +17,31 @@ pub struct CodeGenTiming {
     pub code_gen: Duration,
 }
 
-pub fn report_problems_monomorphized(loaded: &mut MonomorphizedModule) -> Problems {
+pub fn report_problems_monomorphized(
+    loaded: &mut MonomorphizedModule,
+    levels: &DiagnosticLevels,
+) -> Problems {
     report_problems(
         loaded.total_problems(),
         &loaded.sources,
         &loaded.interns,
         &mut loaded.can_problems,
         &mut loaded.type_problems,
+        levels,
     )
 }
 
-pub fn report_problems_typechecked(loaded: &mut LoadedModule) -> Problems {
+pub fn report_problems_typechecked(
+    loaded: &mut LoadedModule,
+    levels: &DiagnosticLevels,
+) -> Problems {
     report_problems(
         loaded.total_problems(),
         &loaded.sources,
         &loaded.interns,
         &mut loaded.can_problems,
         &mut loaded.type_problems,
+        levels,
     )
 }
 
@@ -65,36 +73,165 @@ pub struct CodeGenOptions {
     pub backend: CodeGenBackend,
     pub opt_level: OptLevel,
     pub emit_debug_info: bool,
+    pub emit: Emit,
+    /// Track allocations at runtime and report leaks and bad frees, see `roc run --check-memory`
+    pub check_memory: bool,
+    /// Report values that stop being unique at runtime, see `roc run --trace-refcounts`
+    pub trace_refcounts: bool,
 }
 
-type GenFromMono<'a> = (CodeObject, CodeGenTiming, ExpectMetadata<'a>);
+/// An intermediate artifact that `roc build --emit` writes next to the output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmitKind {
+    Can,
+    Mono,
+    MonoRefcounted,
+    LlvmIr,
+    WasmWat,
+    Asm,
+}
+
+impl EmitKind {
+    pub const ALL: [EmitKind; 6] = [
+        EmitKind::Can,
+        EmitKind::Mono,
+        EmitKind::MonoRefcounted,
+        EmitKind::LlvmIr,
+        EmitKind::WasmWat,
+        EmitKind::Asm,
+    ];
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            EmitKind::Can => "can",
+            EmitKind::Mono => "mono",
+            EmitKind::MonoRefcounted => "mono-refcounted",
+            EmitKind::LlvmIr => "llvm-ir",
+            EmitKind::WasmWat => "wasm-wat",
+            EmitKind::Asm => "asm",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.name() == name)
+    }
+
+    /// The extension of the file this artifact is written to
+    pub const fn extension(self) -> &'static str {
+        match self {
+            EmitKind::Can => "can",
+            EmitKind::Mono => "mono",
+            EmitKind::MonoRefcounted => "mono-refcounted",
+            EmitKind::LlvmIr => "ll",
+            EmitKind::WasmWat => "wat",
+            EmitKind::Asm => "s",
+        }
+    }
+}
+
+/// The set of artifacts requested with `--emit`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Emit(u8);
+
+impl Emit {
+    pub const NONE: Self = Emit(0);
+
+    const fn bit(kind: EmitKind) -> u8 {
+        1 << kind as u8
+    }
+
+    pub fn insert(&mut self, kind: EmitKind) {
+        self.0 |= Self::bit(kind);
+    }
+
+    pub const fn contains(self, kind: EmitKind) -> bool {
+        self.0 & Self::bit(kind) != 0
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// What the loader has to render for us while it still has the IR around
+    pub const fn emit_ir(self) -> EmitIr {
+        EmitIr {
+            can: self.contains(EmitKind::Can),
+            mono: self.contains(EmitKind::Mono),
+            mono_refcounted: self.contains(EmitKind::MonoRefcounted),
+        }
+    }
+}
+
+impl FromIterator<EmitKind> for Emit {
+    fn from_iter<T: IntoIterator<Item = EmitKind>>(iter: T) -> Self {
+        let mut emit = Emit::NONE;
+
+        for kind in iter {
+            emit.insert(kind);
+        }
+
+        emit
+    }
+}
+
+/// The textual artifacts requested with `--emit`, paired with what they are
+pub type Emitted = Vec<(EmitKind, String)>;
+
+type GenFromMono<'a> = (CodeObject, CodeGenTiming, ExpectMetadata<'a>, Emitted);
+
+fn emitted_from_ir(emitted_ir: EmittedIr) -> Emitted {
+    let EmittedIr {
+        can,
+        mono,
+        mono_refcounted,
+    } = emitted_ir;
+
+    [
+        (EmitKind::Can, can),
+        (EmitKind::Mono, mono),
+        (EmitKind::MonoRefcounted, mono_refcounted),
+    ]
+    .into_iter()
+    .filter_map(|(kind, text)| Some((kind, text?)))
+    .collect()
+}
 
 #[allow(clippy::too_many_arguments)]
 pub fn gen_from_mono_module<'a>(
     arena: &'a bumpalo::Bump,
-    loaded: MonomorphizedModule<'a>,
+    mut loaded: MonomorphizedModule<'a>,
     roc_file_path: &Path,
     target: &target_lexicon::Triple,
     code_gen_options: CodeGenOptions,
     preprocessed_host_path: &Path,
     wasm_dev_stack_bytes: Option<u32>,
 ) -> GenFromMono<'a> {
-    match code_gen_options.backend {
-        CodeGenBackend::Assembly => gen_from_mono_module_dev(
-            arena,
-            loaded,
-            target,
-            preprocessed_host_path,
-            wasm_dev_stack_bytes,
-        ),
-        CodeGenBackend::Llvm => {
-            gen_from_mono_module_llvm(arena, loaded, roc_file_path, target, code_gen_options)
-        }
-        CodeGenBackend::Wasm => {
-            // emit wasm via the llvm backend
-            gen_from_mono_module_llvm(arena, loaded, roc_file_path, target, code_gen_options)
-        }
-    }
+    let mut emitted = emitted_from_ir(std::mem::take(&mut loaded.emitted_ir));
+
+    let (code_object, code_gen_timing, expect_metadata, emitted_by_backend) =
+        match code_gen_options.backend {
+            CodeGenBackend::Assembly => gen_from_mono_module_dev(
+                arena,
+                loaded,
+                target,
+                code_gen_options.emit,
+                code_gen_options.check_memory,
+                code_gen_options.trace_refcounts,
+                preprocessed_host_path,
+                wasm_dev_stack_bytes,
+            ),
+            CodeGenBackend::Llvm => {
+                gen_from_mono_module_llvm(arena, loaded, roc_file_path, target, code_gen_options)
+            }
+            CodeGenBackend::Wasm => {
+                // emit wasm via the llvm backend
+                gen_from_mono_module_llvm(arena, loaded, roc_file_path, target, code_gen_options)
+            }
+        };
+
+    emitted.extend(emitted_by_backend);
+
+    (code_object, code_gen_timing, expect_metadata, emitted)
 }
 
 // TODO how should imported modules factor into this? What if those use builtins too?
@@ -159,6 +296,9 @@ fn gen_from_mono_module_llvm<'a>(
         backend: _,
         opt_level,
         emit_debug_info,
+        emit,
+        check_memory,
+        trace_refcounts,
     } = code_gen_options;
 
     let builder = context.create_builder();
@@ -181,6 +321,8 @@ fn gen_from_mono_module_llvm<'a>(
         },
 
         exposed_to_host: loaded.exposed_to_host.values.keys().copied().collect(),
+        check_memory,
+        trace_refcounts,
     };
 
     // does not add any externs for this mode (we have a host) but cleans up some functions around
@@ -210,6 +352,10 @@ fn gen_from_mono_module_llvm<'a>(
         Some(&app_ll_file),
     );
 
+    if check_memory {
+        roc_gen_llvm::llvm::build::add_memcheck_report_at_exit(&env);
+    }
+
     env.dibuilder.finalize();
 
     // we don't use the debug info, and it causes weird errors.
@@ -235,6 +381,27 @@ fn gen_from_mono_module_llvm<'a>(
     // Uncomment this to see the module's optimized LLVM instruction output:
     // env.module.print_to_stderr();
 
+    let mut emitted = Emitted::new();
+
+    if emit.contains(EmitKind::LlvmIr) {
+        emitted.push((EmitKind::LlvmIr, module.print_to_string().to_string()));
+    }
+
+    if emit.contains(EmitKind::Asm) {
+        let target_machine =
+            target::target_machine(target, convert_opt_level(opt_level), RelocMode::PIC).unwrap();
+
+        // code gen mutates the module it runs on, so leave the one we emit the object from alone
+        let asm = target_machine
+            .write_to_memory_buffer(&module.clone(), FileType::Assembly)
+            .expect("Writing .s file failed");
+
+        emitted.push((
+            EmitKind::Asm,
+            String::from_utf8_lossy(asm.as_slice()).into_owned(),
+        ));
+    }
+
     // annotate the LLVM IR output with debug info
     // so errors are reported with the line number of the LLVM source
     let memory_buffer = if cfg!(feature = "sanitizers") && std::env::var("ROC_SANITIZERS").is_ok() {
@@ -421,14 +588,19 @@ fn gen_from_mono_module_llvm<'a>(
             layout_interner: loaded.layout_interner,
             expectations: loaded.expectations,
         },
+        emitted,
     )
 }
 
 #[cfg(feature = "target-wasm32")]
+#[allow(clippy::too_many_arguments)]
 fn gen_from_mono_module_dev<'a>(
     arena: &'a bumpalo::Bump,
     loaded: MonomorphizedModule<'a>,
     target: &target_lexicon::Triple,
+    emit: Emit,
+    check_memory: bool,
+    trace_refcounts: bool,
     preprocessed_host_path: &Path,
     wasm_dev_stack_bytes: Option<u32>,
 ) -> GenFromMono<'a> {
@@ -441,27 +613,41 @@ fn gen_from_mono_module_dev<'a>(
             preprocessed_host_path,
             wasm_dev_stack_bytes,
         ),
-        Architecture::X86_64 | Architecture::Aarch64(_) => {
-            gen_from_mono_module_dev_assembly(arena, loaded, target)
-        }
+        Architecture::X86_64 | Architecture::Aarch64(_) => gen_from_mono_module_dev_assembly(
+            arena,
+            loaded,
+            target,
+            emit,
+            check_memory,
+            trace_refcounts,
+        ),
         _ => todo!(),
     }
 }
 
 #[cfg(not(feature = "target-wasm32"))]
+#[allow(clippy::too_many_arguments)]
 pub fn gen_from_mono_module_dev<'a>(
     arena: &'a bumpalo::Bump,
     loaded: MonomorphizedModule<'a>,
     target: &target_lexicon::Triple,
+    emit: Emit,
+    check_memory: bool,
+    trace_refcounts: bool,
     _host_input_path: &Path,
     _wasm_dev_stack_bytes: Option<u32>,
 ) -> GenFromMono<'a> {
     use target_lexicon::Architecture;
 
     match target.architecture {
-        Architecture::X86_64 | Architecture::Aarch64(_) => {
-            gen_from_mono_module_dev_assembly(arena, loaded, target)
-        }
+        Architecture::X86_64 | Architecture::Aarch64(_) => gen_from_mono_module_dev_assembly(
+            arena,
+            loaded,
+            target,
+            emit,
+            check_memory,
+            trace_refcounts,
+        ),
         _ => todo!(),
     }
 }
@@ -530,6 +716,8 @@ fn gen_from_mono_module_dev_wasm32<'a>(
             layout_interner,
             expectations: loaded.expectations,
         },
+        // the .wat can only be produced once the final binary is written
+        Emitted::new(),
     )
 }
 
@@ -537,6 +725,9 @@ fn gen_from_mono_module_dev_assembly<'a>(
     arena: &'a bumpalo::Bump,
     loaded: MonomorphizedModule<'a>,
     target: &target_lexicon::Triple,
+    emit: Emit,
+    check_memory: bool,
+    trace_refcounts: bool,
 ) -> GenFromMono<'a> {
     let code_gen_start = Instant::now();
 
@@ -558,6 +749,8 @@ fn gen_from_mono_module_dev_assembly<'a>(
         exposed_to_host: exposed_to_host.values.keys().copied().collect(),
         lazy_literals,
         generate_allocators,
+        check_memory,
+        trace_refcounts,
     };
 
     let module_object =
@@ -569,6 +762,12 @@ fn gen_from_mono_module_dev_assembly<'a>(
         .write()
         .expect("failed to build output object");
 
+    let mut emitted = Emitted::new();
+
+    if emit.contains(EmitKind::Asm) {
+        emitted.push((EmitKind::Asm, disassemble(&module_out)));
+    }
+
     (
         CodeObject::Vector(module_out),
         CodeGenTiming { code_gen },
@@ -577,5 +776,57 @@ fn gen_from_mono_module_dev_assembly<'a>(
             layout_interner,
             expectations: loaded.expectations,
         },
+        emitted,
+    )
+}
+
+/// The dev backend writes machine code directly, so we get its assembly back out of the object file.
+fn disassemble(object_bytes: &[u8]) -> String {
+    let object_file = tempfile::Builder::new()
+        .prefix("roc_app")
+        .suffix(".o")
+        .tempfile()
+        .expect("failed to create a temporary object file");
+
+    std::fs::write(object_file.path(), object_bytes).unwrap();
+
+    run_text_tool(
+        EmitKind::Asm,
+        "objdump",
+        &["-d", "-r", "-C"],
+        object_file.path(),
     )
 }
+
+/// Turn a final .wasm binary into the WebAssembly text format, for `--emit=wasm-wat`
+pub fn wasm_to_wat(wasm_path: &Path) -> String {
+    run_text_tool(EmitKind::WasmWat, "wasm2wat", &[], wasm_path)
+}
+
+/// Run an external tool that renders `input` as text for `--emit=<kind>`.
+fn run_text_tool(kind: EmitKind, tool: &str, args: &[&str], input: &Path) -> String {
+    use std::process::Command;
+
+    match Command::new(tool).args(args).arg(input).output() {
+        Ok(output) if output.status.success() => {
+            String::from_utf8_lossy(&output.stdout).into_owned()
+        }
+        Ok(output) => internal_error!(
+            "`{}` failed on {}:\n\n{}",
+            tool,
+            input.display(),
+            String::from_utf8_lossy(&output.stderr)
+        ),
+        Err(error) => {
+            use std::io::ErrorKind;
+            match error.kind() {
+                ErrorKind::NotFound => user_error!(
+                    "`--emit={}` uses the `{}` tool, but I could not find it on the PATH. Please install it and try again.",
+                    kind.name(),
+                    tool
+                ),
+                _ => internal_error!("{:?}", error),
+            }
+        }
+    }
+}
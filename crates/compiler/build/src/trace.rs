@@ -0,0 +1,168 @@
+//! Write compiler phase timings in the [Chrome Trace Event format](https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU),
+//! so `roc build --time-trace` output can be opened in Perfetto or `chrome://tracing`.
+use roc_collections::all::MutMap;
+use roc_load::ModuleTiming;
+use roc_module::symbol::{Interns, ModuleId};
+use std::fmt::Write;
+use std::path::Path;
+use std::time::Instant;
+
+/// The thread that drives loading, code gen and linking
+pub const MAIN_THREAD: &str = "main";
+/// The thread that rebuilds the platform while the app is being compiled
+pub const HOST_REBUILD_THREAD: &str = "platform rebuild";
+
+struct TraceEvent {
+    name: String,
+    category: &'static str,
+    module: Option<String>,
+    thread: usize,
+    start: Instant,
+    end: Instant,
+}
+
+pub struct ChromeTrace {
+    /// Timestamps in the trace are relative to this
+    origin: Instant,
+    threads: Vec<String>,
+    events: Vec<TraceEvent>,
+}
+
+impl ChromeTrace {
+    pub fn new(origin: Instant) -> Self {
+        ChromeTrace {
+            origin,
+            threads: vec![MAIN_THREAD.to_string()],
+            events: Vec::new(),
+        }
+    }
+
+    fn thread_id(&mut self, thread_name: &str) -> usize {
+        match self.threads.iter().position(|name| name == thread_name) {
+            Some(id) => id,
+            None => {
+                self.threads.push(thread_name.to_string());
+                self.threads.len() - 1
+            }
+        }
+    }
+
+    /// Add a span for every phase of every module, on the thread it ran on
+    pub fn add_module_timings(
+        &mut self,
+        interns: &Interns,
+        timings: &MutMap<ModuleId, ModuleTiming>,
+    ) {
+        for (module_id, module_timing) in timings.iter() {
+            let module_name = match interns.module_name(*module_id).as_str() {
+                "" => "Application Module",
+                name => name,
+            };
+
+            for span in module_timing.spans.iter() {
+                let thread = match span.thread {
+                    0 => self.thread_id(MAIN_THREAD),
+                    worker => self.thread_id(&format!("worker {}", worker)),
+                };
+
+                self.events.push(TraceEvent {
+                    name: format!("{}: {}", span.phase, module_name),
+                    category: "load",
+                    module: Some(module_name.to_string()),
+                    thread,
+                    start: span.start,
+                    end: span.end,
+                });
+            }
+        }
+    }
+
+    /// Add a span that isn't about any one module, like code gen or linking
+    pub fn add_span(&mut self, name: &str, thread_name: &str, start: Instant, end: Instant) {
+        let thread = self.thread_id(thread_name);
+
+        self.events.push(TraceEvent {
+            name: name.to_string(),
+            category: "build",
+            module: None,
+            thread,
+            start,
+            end,
+        });
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut buf = String::from("{\"displayTimeUnit\":\"ms\",\"traceEvents\":[");
+        let mut first = true;
+
+        let mut separator = |buf: &mut String| {
+            if !std::mem::take(&mut first) {
+                buf.push(',');
+            }
+        };
+
+        for (tid, thread_name) in self.threads.iter().enumerate() {
+            separator(&mut buf);
+            buf.push_str("{\"name\":\"thread_name\",\"ph\":\"M\",\"pid\":1,");
+            write!(buf, "\"tid\":{},\"args\":{{\"name\":", tid).unwrap();
+            push_json_str(&mut buf, thread_name);
+            buf.push_str("}}");
+
+            separator(&mut buf);
+            buf.push_str("{\"name\":\"thread_sort_index\",\"ph\":\"M\",\"pid\":1,");
+            write!(buf, "\"tid\":{},\"args\":{{\"sort_index\":{}}}}}", tid, tid).unwrap();
+        }
+
+        for event in self.events.iter() {
+            // timestamps and durations are in microseconds
+            let ts = event
+                .start
+                .saturating_duration_since(self.origin)
+                .as_nanos() as f64
+                / 1000.0;
+            let dur = event.end.saturating_duration_since(event.start).as_nanos() as f64 / 1000.0;
+
+            separator(&mut buf);
+            buf.push_str("{\"name\":");
+            push_json_str(&mut buf, &event.name);
+            write!(
+                buf,
+                ",\"cat\":\"{}\",\"ph\":\"X\",\"pid\":1,\"tid\":{},\"ts\":{:.3},\"dur\":{:.3}",
+                event.category, event.thread, ts, dur
+            )
+            .unwrap();
+
+            if let Some(module) = &event.module {
+                buf.push_str(",\"args\":{\"module\":");
+                push_json_str(&mut buf, module);
+                buf.push('}');
+            }
+
+            buf.push('}');
+        }
+
+        buf.push_str("]}\n");
+
+        buf
+    }
+
+    pub fn write_to_file(&self, path: &Path) -> std::io::Result<()> {
+        std::fs::write(path, self.to_json())
+    }
+}
+
+fn push_json_str(buf: &mut String, string: &str) {
+    buf.push('"');
+
+    for c in string.chars() {
+        match c {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\n' => buf.push_str("\\n"),
+            c if c.is_control() => write!(buf, "\\u{:04x}", c as u32).unwrap(),
+            c => buf.push(c),
+        }
+    }
+
+    buf.push('"');
+}
@@ -0,0 +1,20 @@
+#!/usr/bin/env bash
+
+# https://vaneyckt.io/posts/safer_bash_scripts_with_set_euxo_pipefail/
+set -euxo pipefail
+
+# Regenerate src/helpers/unicode_tables.zig from the Unicode Character Database version
+# named in its header, and check that the result matches the checked-in file.
+UNICODE_VERSION=$(sed -n 's/.*Unicode Character Database, version \([0-9.]*\)\..*/\1/p' src/helpers/unicode_tables.zig)
+
+UCD_DIR=$(mktemp -d)
+trap 'rm -rf "$UCD_DIR"' EXIT
+
+for file in UnicodeData.txt SpecialCasing.txt CaseFolding.txt DerivedNormalizationProps.txt; do
+    curl --fail --silent --show-error -L -o "$UCD_DIR/$file" "https://www.unicode.org/Public/$UNICODE_VERSION/ucd/$file"
+done
+
+rustc -O unicode/generate_tables.rs -o "$UCD_DIR/generate_tables"
+"$UCD_DIR/generate_tables" "$UCD_DIR" > "$UCD_DIR/unicode_tables.zig"
+
+diff src/helpers/unicode_tables.zig "$UCD_DIR/unicode_tables.zig" || (echo "src/helpers/unicode_tables.zig does not match the output of unicode/generate_tables.rs! Regenerate it as described in README.md." && exit 1)
@@ -209,6 +209,122 @@ pub const RocDec = extern struct {
         return RocStr.init(&str_bytes, position);
     }
 
+    // Rounds to the given number of decimal places, with half-way cases away from zero.
+    // Precision beyond the 18 places a Dec has is filled with zeros.
+    pub fn toStrFixed(self: RocDec, precision: usize) RocStr {
+        return num_.formatToStr(FormatArgs{ .dec = self, .precision = precision }, writeFixed);
+    }
+
+    // Like toStrFixed, but with one digit before the decimal point and an exponent, like "1.50e+03".
+    pub fn toStrScientific(self: RocDec, precision: usize) RocStr {
+        return num_.formatToStr(FormatArgs{ .dec = self, .precision = precision }, writeScientific);
+    }
+
+    const FormatArgs = struct {
+        dec: RocDec,
+        precision: usize,
+    };
+
+    fn writeFixed(args: FormatArgs, writer: anytype) !void {
+        const places = math.min(args.precision, @as(usize, decimal_places));
+        const divisor = math.pow(i128, 10, @intCast(i128, decimal_places - places));
+
+        var rounded = @divTrunc(args.dec.num, divisor);
+        const remainder = @rem(args.dec.num, divisor);
+
+        if (remainder * 2 >= divisor) {
+            rounded += 1;
+        } else if (remainder * 2 <= -divisor) {
+            rounded -= 1;
+        }
+
+        var digits_storage: [max_digits]u8 = undefined;
+        const num_digits = std.fmt.formatIntBuf(digits_storage[0..], math.absCast(rounded), 10, .lower, .{});
+        const digits = digits_storage[0..num_digits];
+
+        if (rounded < 0) {
+            try writer.writeByte('-');
+        }
+
+        // the last `places` digits go after the decimal point
+        if (num_digits > places) {
+            try writer.writeAll(digits[0 .. num_digits - places]);
+        } else {
+            try writer.writeByte('0');
+        }
+
+        if (args.precision == 0) {
+            return;
+        }
+
+        try writer.writeByte('.');
+
+        if (num_digits > places) {
+            try writer.writeAll(digits[num_digits - places ..]);
+        } else {
+            try writer.writeByteNTimes('0', places - num_digits);
+            try writer.writeAll(digits);
+        }
+
+        try writer.writeByteNTimes('0', args.precision - places);
+    }
+
+    fn writeScientific(args: FormatArgs, writer: anytype) !void {
+        const precision = args.precision;
+
+        // one spare byte at the front, in case rounding carries into a new digit
+        var digits_storage: [max_digits + 1]u8 = undefined;
+        const num_digits = std.fmt.formatIntBuf(digits_storage[1..], math.absCast(args.dec.num), 10, .lower, .{});
+        var digits = digits_storage[1 .. 1 + num_digits];
+
+        var exponent: i32 = if (args.dec.num == 0) 0 else @intCast(i32, num_digits) - 1 - @as(i32, decimal_places);
+
+        // keep `precision + 1` significant digits, rounding half-way cases away from zero
+        if (num_digits > precision + 1) {
+            const kept = precision + 1;
+            const round_up = digits[kept] >= '5';
+            digits = digits[0..kept];
+
+            if (round_up) {
+                var carry = true;
+                var index = kept;
+
+                while (carry and index > 0) {
+                    index -= 1;
+
+                    if (digits[index] == '9') {
+                        digits[index] = '0';
+                    } else {
+                        digits[index] += 1;
+                        carry = false;
+                    }
+                }
+
+                if (carry) {
+                    // all the kept digits were nines, so we now have a 1 followed by zeros
+                    digits_storage[0] = '1';
+                    digits = digits_storage[0..kept];
+                    exponent += 1;
+                }
+            }
+        }
+
+        if (args.dec.num < 0) {
+            try writer.writeByte('-');
+        }
+
+        try writer.writeByte(digits[0]);
+
+        if (precision > 0) {
+            try writer.writeByte('.');
+            try writer.writeAll(digits[1..]);
+            try writer.writeByteNTimes('0', precision + 1 - digits.len);
+        }
+
+        const exponent_sign: u8 = if (exponent < 0) '-' else '+';
+        try writer.print("e{c}{d:0>2}", .{ exponent_sign, math.absCast(exponent) });
+    }
+
     pub fn eq(self: RocDec, other: RocDec) bool {
         return self.num == other.num;
     }
@@ -222,6 +338,53 @@ pub const RocDec = extern struct {
         return if (negated) |n| .{ .num = n } else null;
     }
 
+    pub fn abs(self: RocDec) ?RocDec {
+        return if (self.num < 0) self.negate() else self;
+    }
+
+    pub fn fromInt(comptime T: type, num: T) ?RocDec {
+        if (T == u128) {
+            if (num > math.maxInt(i128)) {
+                return null;
+            }
+        }
+
+        var answer: i128 = undefined;
+        if (@mulWithOverflow(i128, @intCast(i128, num), one_point_zero_i128, &answer)) {
+            return null;
+        }
+
+        return .{ .num = answer };
+    }
+
+    pub fn toF64(self: RocDec) f64 {
+        return @intToFloat(f64, self.num) / comptime @intToFloat(f64, one_point_zero_i128);
+    }
+
+    // Rounds half-way cases away from zero, like `Num.round` does for floats
+    pub fn round(self: RocDec) i128 {
+        const whole = @divTrunc(self.num, one_point_zero_i128);
+        const fraction = @rem(self.num, one_point_zero_i128);
+        const half = one_point_zero_i128 / 2;
+
+        if (fraction >= half) {
+            return whole + 1;
+        } else if (fraction <= -half) {
+            return whole - 1;
+        } else {
+            return whole;
+        }
+    }
+
+    pub fn floor(self: RocDec) i128 {
+        return @divFloor(self.num, one_point_zero_i128);
+    }
+
+    pub fn ceiling(self: RocDec) i128 {
+        const whole = @divFloor(self.num, one_point_zero_i128);
+        return if (@mod(self.num, one_point_zero_i128) == 0) whole else whole + 1;
+    }
+
     pub fn addWithOverflow(self: RocDec, other: RocDec) WithOverflow(RocDec) {
         var answer: i128 = undefined;
         const overflowed = @addWithOverflow(i128, self.num, other.num, &answer);
@@ -976,6 +1139,40 @@ test "toStr: 0" {
     try expectEqualSlices(u8, res_slice, res_roc_str.asSlice());
 }
 
+fn expectStr(actual: RocStr, expected: []const u8) !void {
+    defer actual.deinit();
+
+    try expectEqualSlices(u8, expected, actual.asSlice());
+}
+
+test "toStrFixed" {
+    const dec = RocDec.fromStr(RocStr.init("1234.5678", 9)).?;
+
+    try expectStr(dec.toStrFixed(2), "1234.57");
+    try expectStr(dec.toStrFixed(0), "1235");
+    try expectStr(dec.toStrFixed(6), "1234.567800");
+    try expectStr(dec.toStrFixed(20), "1234.56780000000000000000");
+    try expectStr(dec.negate().?.toStrFixed(1), "-1234.6");
+}
+
+test "toStrFixed: rounds half-way cases away from zero" {
+    try expectStr(RocDec.fromStr(RocStr.init("0.125", 5)).?.toStrFixed(2), "0.13");
+    try expectStr(RocDec.fromStr(RocStr.init("-0.125", 6)).?.toStrFixed(2), "-0.13");
+    try expectStr(RocDec.fromStr(RocStr.init("-0.004", 6)).?.toStrFixed(2), "0.00");
+    try expectStr(RocDec.fromStr(RocStr.init("0.0005", 6)).?.toStrFixed(3), "0.001");
+}
+
+test "toStrScientific" {
+    const dec = RocDec.fromStr(RocStr.init("1234.5678", 9)).?;
+
+    try expectStr(dec.toStrScientific(2), "1.23e+03");
+    try expectStr(dec.toStrScientific(0), "1e+03");
+    try expectStr(dec.negate().?.toStrScientific(5), "-1.23457e+03");
+    try expectStr(RocDec.fromStr(RocStr.init("0.00042", 7)).?.toStrScientific(3), "4.200e-04");
+    try expectStr(RocDec.fromStr(RocStr.init("9.99", 4)).?.toStrScientific(1), "1.0e+01");
+    try expectStr((RocDec{ .num = 0 }).toStrScientific(2), "0.00e+00");
+}
+
 test "add: 0" {
     var dec: RocDec = .{ .num = 0 };
 
@@ -1055,6 +1252,32 @@ test "div: 10 / 3" {
     try expectEqual(res, numer.div(denom));
 }
 
+test "fromInt" {
+    try expectEqual(RocDec.fromU64(25), RocDec.fromInt(i8, 25).?);
+    try expectEqual(RocDec{ .num = -25000000000000000000 }, RocDec.fromInt(i64, -25).?);
+    try expectEqual(RocDec.fromInt(u128, math.maxInt(u128)), null);
+}
+
+test "toF64" {
+    var dec: RocDec = RocDec.fromStr(RocStr.init("-1.5", 4)).?;
+    try expectEqual(@as(f64, -1.5), dec.toF64());
+}
+
+test "round, floor and ceiling" {
+    var pos: RocDec = RocDec.fromStr(RocStr.init("2.5", 3)).?;
+    var neg: RocDec = RocDec.fromStr(RocStr.init("-2.5", 4)).?;
+
+    try expectEqual(@as(i128, 3), pos.round());
+    try expectEqual(@as(i128, 2), pos.floor());
+    try expectEqual(@as(i128, 3), pos.ceiling());
+
+    try expectEqual(@as(i128, -3), neg.round());
+    try expectEqual(@as(i128, -3), neg.floor());
+    try expectEqual(@as(i128, -2), neg.ceiling());
+
+    try expectEqual(@as(i128, 2), RocDec.fromU64(2).ceiling());
+}
+
 // exports
 
 pub fn fromStr(arg: RocStr) callconv(.C) num_.NumParseResult(i128) {
@@ -1069,6 +1292,14 @@ pub fn toStr(arg: RocDec) callconv(.C) RocStr {
     return @call(.{ .modifier = always_inline }, RocDec.toStr, .{arg});
 }
 
+pub fn toStrFixedC(arg: RocDec, precision: usize) callconv(.C) RocStr {
+    return @call(.{ .modifier = always_inline }, RocDec.toStrFixed, .{ arg, precision });
+}
+
+pub fn toStrScientificC(arg: RocDec, precision: usize) callconv(.C) RocStr {
+    return @call(.{ .modifier = always_inline }, RocDec.toStrScientific, .{ arg, precision });
+}
+
 pub fn fromF64C(arg: f64) callconv(.C) i128 {
     return if (@call(.{ .modifier = always_inline }, RocDec.fromF64, .{arg})) |dec| dec.num else @panic("TODO runtime exception failing convert f64 to RocDec");
 }
@@ -1085,6 +1316,28 @@ pub fn negateC(arg: RocDec) callconv(.C) i128 {
     return if (@call(.{ .modifier = always_inline }, RocDec.negate, .{arg})) |dec| dec.num else @panic("TODO overflow for negating RocDec");
 }
 
+pub fn absC(arg: RocDec) callconv(.C) i128 {
+    if (@call(.{ .modifier = always_inline }, RocDec.abs, .{arg})) |dec| {
+        return dec.num;
+    } else {
+        roc_panic("Decimal absolute value overflowed because its argument is the minimum value", 0);
+        unreachable;
+    }
+}
+
+pub fn toF64C(arg: RocDec) callconv(.C) f64 {
+    return @call(.{ .modifier = always_inline }, RocDec.toF64, .{arg});
+}
+
+pub fn toF32CheckedC(arg: RocDec) callconv(.C) num_.ToFloatCheckedResult(f32) {
+    // Every Dec is well within the range of an F32
+    return .{ .value = @floatCast(f32, arg.toF64()), .out_of_bounds = false };
+}
+
+pub fn toF64CheckedC(arg: RocDec) callconv(.C) num_.ToFloatCheckedResult(f64) {
+    return .{ .value = arg.toF64(), .out_of_bounds = false };
+}
+
 pub fn addC(arg1: RocDec, arg2: RocDec) callconv(.C) WithOverflow(RocDec) {
     return @call(.{ .modifier = always_inline }, RocDec.addWithOverflow, .{ arg1, arg2 });
 }
@@ -1124,3 +1377,44 @@ pub fn mulOrPanicC(arg1: RocDec, arg2: RocDec) callconv(.C) RocDec {
 pub fn mulSaturatedC(arg1: RocDec, arg2: RocDec) callconv(.C) RocDec {
     return @call(.{ .modifier = always_inline }, RocDec.mulSaturated, .{ arg1, arg2 });
 }
+
+pub fn exportFromInt(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(input: T) callconv(.C) i128 {
+            if (@call(.{ .modifier = always_inline }, RocDec.fromInt, .{ T, input })) |dec| {
+                return dec.num;
+            } else {
+                roc_panic("integer is too big to convert to a Decimal", 0);
+                unreachable;
+            }
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportRound(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(input: RocDec) callconv(.C) T {
+            return @intCast(T, @call(.{ .modifier = always_inline }, RocDec.round, .{input}));
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportFloor(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(input: RocDec) callconv(.C) T {
+            return @intCast(T, @call(.{ .modifier = always_inline }, RocDec.floor, .{input}));
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportCeiling(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(input: RocDec) callconv(.C) T {
+            return @intCast(T, @call(.{ .modifier = always_inline }, RocDec.ceiling, .{input}));
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
@@ -0,0 +1,44 @@
+const std = @import("std");
+const builtin = @import("builtin");
+const roc_panic = @import("panic.zig").panic_help;
+
+// Per-process seed for `Dict.withRandomSeed`.
+//
+// The seed comes from the operating system's random number generator the first time it is
+// needed, and we hand out the same seed afterwards, so every dictionary in a process agrees
+// on it. The builtins define this themselves rather than importing it from the platform, so
+// every linker and backend sees a regular function. wasm32 has no operating system to ask,
+// so apps built for it crash with a clear message if they call `Dict.withRandomSeed`.
+
+var seed: ?u64 = null;
+
+pub fn randomSeed() callconv(.C) u64 {
+    if (seed) |s| {
+        return s;
+    }
+
+    const s = osRandom();
+    seed = s;
+
+    return s;
+}
+
+fn osRandom() u64 {
+    if (comptime builtin.target.cpu.arch == .wasm32) {
+        roc_panic("Dict.withRandomSeed needs a random seed, but there is no operating system to get one from on wasm32.", 0);
+        unreachable;
+    } else {
+        var bytes: [8]u8 = undefined;
+        std.os.getrandom(&bytes) catch {
+            roc_panic("Dict.withRandomSeed needs a random seed, but the operating system could not provide one.", 0);
+            unreachable;
+        };
+
+        return std.mem.readIntNative(u64, &bytes);
+    }
+}
+
+test "randomSeed is the same for the whole process" {
+    const first = randomSeed();
+    try std.testing.expectEqual(first, randomSeed());
+}
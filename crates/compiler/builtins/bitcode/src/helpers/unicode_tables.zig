@@ -0,0 +1,6878 @@
+// This file is generated by `crates/compiler/builtins/bitcode/unicode/generate_tables.rs`
+// from the Unicode Character Database, version 14.0.0. Do not edit it by hand!
+
+pub const CaseMapping = struct {
+    codepoint: u21,
+    upper: []const u21,
+    lower: []const u21,
+    fold: []const u21,
+};
+
+pub const CombiningClass = struct {
+    codepoint: u21,
+    class: u8,
+};
+
+pub const Decomposition = struct {
+    codepoint: u21,
+    mapping: []const u21,
+};
+
+pub const Composition = struct {
+    first: u21,
+    second: u21,
+    composite: u21,
+};
+
+pub const case_mappings = [_]CaseMapping{
+    .{ .codepoint = 0x0041, .upper = &[_]u21{}, .lower = &[_]u21{0x0061}, .fold = &[_]u21{0x0061} },
+    .{ .codepoint = 0x0042, .upper = &[_]u21{}, .lower = &[_]u21{0x0062}, .fold = &[_]u21{0x0062} },
+    .{ .codepoint = 0x0043, .upper = &[_]u21{}, .lower = &[_]u21{0x0063}, .fold = &[_]u21{0x0063} },
+    .{ .codepoint = 0x0044, .upper = &[_]u21{}, .lower = &[_]u21{0x0064}, .fold = &[_]u21{0x0064} },
+    .{ .codepoint = 0x0045, .upper = &[_]u21{}, .lower = &[_]u21{0x0065}, .fold = &[_]u21{0x0065} },
+    .{ .codepoint = 0x0046, .upper = &[_]u21{}, .lower = &[_]u21{0x0066}, .fold = &[_]u21{0x0066} },
+    .{ .codepoint = 0x0047, .upper = &[_]u21{}, .lower = &[_]u21{0x0067}, .fold = &[_]u21{0x0067} },
+    .{ .codepoint = 0x0048, .upper = &[_]u21{}, .lower = &[_]u21{0x0068}, .fold = &[_]u21{0x0068} },
+    .{ .codepoint = 0x0049, .upper = &[_]u21{}, .lower = &[_]u21{0x0069}, .fold = &[_]u21{0x0069} },
+    .{ .codepoint = 0x004A, .upper = &[_]u21{}, .lower = &[_]u21{0x006A}, .fold = &[_]u21{0x006A} },
+    .{ .codepoint = 0x004B, .upper = &[_]u21{}, .lower = &[_]u21{0x006B}, .fold = &[_]u21{0x006B} },
+    .{ .codepoint = 0x004C, .upper = &[_]u21{}, .lower = &[_]u21{0x006C}, .fold = &[_]u21{0x006C} },
+    .{ .codepoint = 0x004D, .upper = &[_]u21{}, .lower = &[_]u21{0x006D}, .fold = &[_]u21{0x006D} },
+    .{ .codepoint = 0x004E, .upper = &[_]u21{}, .lower = &[_]u21{0x006E}, .fold = &[_]u21{0x006E} },
+    .{ .codepoint = 0x004F, .upper = &[_]u21{}, .lower = &[_]u21{0x006F}, .fold = &[_]u21{0x006F} },
+    .{ .codepoint = 0x0050, .upper = &[_]u21{}, .lower = &[_]u21{0x0070}, .fold = &[_]u21{0x0070} },
+    .{ .codepoint = 0x0051, .upper = &[_]u21{}, .lower = &[_]u21{0x0071}, .fold = &[_]u21{0x0071} },
+    .{ .codepoint = 0x0052, .upper = &[_]u21{}, .lower = &[_]u21{0x0072}, .fold = &[_]u21{0x0072} },
+    .{ .codepoint = 0x0053, .upper = &[_]u21{}, .lower = &[_]u21{0x0073}, .fold = &[_]u21{0x0073} },
+    .{ .codepoint = 0x0054, .upper = &[_]u21{}, .lower = &[_]u21{0x0074}, .fold = &[_]u21{0x0074} },
+    .{ .codepoint = 0x0055, .upper = &[_]u21{}, .lower = &[_]u21{0x0075}, .fold = &[_]u21{0x0075} },
+    .{ .codepoint = 0x0056, .upper = &[_]u21{}, .lower = &[_]u21{0x0076}, .fold = &[_]u21{0x0076} },
+    .{ .codepoint = 0x0057, .upper = &[_]u21{}, .lower = &[_]u21{0x0077}, .fold = &[_]u21{0x0077} },
+    .{ .codepoint = 0x0058, .upper = &[_]u21{}, .lower = &[_]u21{0x0078}, .fold = &[_]u21{0x0078} },
+    .{ .codepoint = 0x0059, .upper = &[_]u21{}, .lower = &[_]u21{0x0079}, .fold = &[_]u21{0x0079} },
+    .{ .codepoint = 0x005A, .upper = &[_]u21{}, .lower = &[_]u21{0x007A}, .fold = &[_]u21{0x007A} },
+    .{ .codepoint = 0x0061, .upper = &[_]u21{0x0041}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0062, .upper = &[_]u21{0x0042}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0063, .upper = &[_]u21{0x0043}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0064, .upper = &[_]u21{0x0044}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0065, .upper = &[_]u21{0x0045}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0066, .upper = &[_]u21{0x0046}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0067, .upper = &[_]u21{0x0047}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0068, .upper = &[_]u21{0x0048}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0069, .upper = &[_]u21{0x0049}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x006A, .upper = &[_]u21{0x004A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x006B, .upper = &[_]u21{0x004B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x006C, .upper = &[_]u21{0x004C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x006D, .upper = &[_]u21{0x004D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x006E, .upper = &[_]u21{0x004E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x006F, .upper = &[_]u21{0x004F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0070, .upper = &[_]u21{0x0050}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0071, .upper = &[_]u21{0x0051}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0072, .upper = &[_]u21{0x0052}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0073, .upper = &[_]u21{0x0053}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0074, .upper = &[_]u21{0x0054}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0075, .upper = &[_]u21{0x0055}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0076, .upper = &[_]u21{0x0056}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0077, .upper = &[_]u21{0x0057}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0078, .upper = &[_]u21{0x0058}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0079, .upper = &[_]u21{0x0059}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x007A, .upper = &[_]u21{0x005A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00B5, .upper = &[_]u21{0x039C}, .lower = &[_]u21{}, .fold = &[_]u21{0x03BC} },
+    .{ .codepoint = 0x00C0, .upper = &[_]u21{}, .lower = &[_]u21{0x00E0}, .fold = &[_]u21{0x00E0} },
+    .{ .codepoint = 0x00C1, .upper = &[_]u21{}, .lower = &[_]u21{0x00E1}, .fold = &[_]u21{0x00E1} },
+    .{ .codepoint = 0x00C2, .upper = &[_]u21{}, .lower = &[_]u21{0x00E2}, .fold = &[_]u21{0x00E2} },
+    .{ .codepoint = 0x00C3, .upper = &[_]u21{}, .lower = &[_]u21{0x00E3}, .fold = &[_]u21{0x00E3} },
+    .{ .codepoint = 0x00C4, .upper = &[_]u21{}, .lower = &[_]u21{0x00E4}, .fold = &[_]u21{0x00E4} },
+    .{ .codepoint = 0x00C5, .upper = &[_]u21{}, .lower = &[_]u21{0x00E5}, .fold = &[_]u21{0x00E5} },
+    .{ .codepoint = 0x00C6, .upper = &[_]u21{}, .lower = &[_]u21{0x00E6}, .fold = &[_]u21{0x00E6} },
+    .{ .codepoint = 0x00C7, .upper = &[_]u21{}, .lower = &[_]u21{0x00E7}, .fold = &[_]u21{0x00E7} },
+    .{ .codepoint = 0x00C8, .upper = &[_]u21{}, .lower = &[_]u21{0x00E8}, .fold = &[_]u21{0x00E8} },
+    .{ .codepoint = 0x00C9, .upper = &[_]u21{}, .lower = &[_]u21{0x00E9}, .fold = &[_]u21{0x00E9} },
+    .{ .codepoint = 0x00CA, .upper = &[_]u21{}, .lower = &[_]u21{0x00EA}, .fold = &[_]u21{0x00EA} },
+    .{ .codepoint = 0x00CB, .upper = &[_]u21{}, .lower = &[_]u21{0x00EB}, .fold = &[_]u21{0x00EB} },
+    .{ .codepoint = 0x00CC, .upper = &[_]u21{}, .lower = &[_]u21{0x00EC}, .fold = &[_]u21{0x00EC} },
+    .{ .codepoint = 0x00CD, .upper = &[_]u21{}, .lower = &[_]u21{0x00ED}, .fold = &[_]u21{0x00ED} },
+    .{ .codepoint = 0x00CE, .upper = &[_]u21{}, .lower = &[_]u21{0x00EE}, .fold = &[_]u21{0x00EE} },
+    .{ .codepoint = 0x00CF, .upper = &[_]u21{}, .lower = &[_]u21{0x00EF}, .fold = &[_]u21{0x00EF} },
+    .{ .codepoint = 0x00D0, .upper = &[_]u21{}, .lower = &[_]u21{0x00F0}, .fold = &[_]u21{0x00F0} },
+    .{ .codepoint = 0x00D1, .upper = &[_]u21{}, .lower = &[_]u21{0x00F1}, .fold = &[_]u21{0x00F1} },
+    .{ .codepoint = 0x00D2, .upper = &[_]u21{}, .lower = &[_]u21{0x00F2}, .fold = &[_]u21{0x00F2} },
+    .{ .codepoint = 0x00D3, .upper = &[_]u21{}, .lower = &[_]u21{0x00F3}, .fold = &[_]u21{0x00F3} },
+    .{ .codepoint = 0x00D4, .upper = &[_]u21{}, .lower = &[_]u21{0x00F4}, .fold = &[_]u21{0x00F4} },
+    .{ .codepoint = 0x00D5, .upper = &[_]u21{}, .lower = &[_]u21{0x00F5}, .fold = &[_]u21{0x00F5} },
+    .{ .codepoint = 0x00D6, .upper = &[_]u21{}, .lower = &[_]u21{0x00F6}, .fold = &[_]u21{0x00F6} },
+    .{ .codepoint = 0x00D8, .upper = &[_]u21{}, .lower = &[_]u21{0x00F8}, .fold = &[_]u21{0x00F8} },
+    .{ .codepoint = 0x00D9, .upper = &[_]u21{}, .lower = &[_]u21{0x00F9}, .fold = &[_]u21{0x00F9} },
+    .{ .codepoint = 0x00DA, .upper = &[_]u21{}, .lower = &[_]u21{0x00FA}, .fold = &[_]u21{0x00FA} },
+    .{ .codepoint = 0x00DB, .upper = &[_]u21{}, .lower = &[_]u21{0x00FB}, .fold = &[_]u21{0x00FB} },
+    .{ .codepoint = 0x00DC, .upper = &[_]u21{}, .lower = &[_]u21{0x00FC}, .fold = &[_]u21{0x00FC} },
+    .{ .codepoint = 0x00DD, .upper = &[_]u21{}, .lower = &[_]u21{0x00FD}, .fold = &[_]u21{0x00FD} },
+    .{ .codepoint = 0x00DE, .upper = &[_]u21{}, .lower = &[_]u21{0x00FE}, .fold = &[_]u21{0x00FE} },
+    .{ .codepoint = 0x00DF, .upper = &[_]u21{ 0x0053, 0x0053 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x0073, 0x0073 } },
+    .{ .codepoint = 0x00E0, .upper = &[_]u21{0x00C0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00E1, .upper = &[_]u21{0x00C1}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00E2, .upper = &[_]u21{0x00C2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00E3, .upper = &[_]u21{0x00C3}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00E4, .upper = &[_]u21{0x00C4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00E5, .upper = &[_]u21{0x00C5}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00E6, .upper = &[_]u21{0x00C6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00E7, .upper = &[_]u21{0x00C7}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00E8, .upper = &[_]u21{0x00C8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00E9, .upper = &[_]u21{0x00C9}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00EA, .upper = &[_]u21{0x00CA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00EB, .upper = &[_]u21{0x00CB}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00EC, .upper = &[_]u21{0x00CC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00ED, .upper = &[_]u21{0x00CD}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00EE, .upper = &[_]u21{0x00CE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00EF, .upper = &[_]u21{0x00CF}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00F0, .upper = &[_]u21{0x00D0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00F1, .upper = &[_]u21{0x00D1}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00F2, .upper = &[_]u21{0x00D2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00F3, .upper = &[_]u21{0x00D3}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00F4, .upper = &[_]u21{0x00D4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00F5, .upper = &[_]u21{0x00D5}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00F6, .upper = &[_]u21{0x00D6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00F8, .upper = &[_]u21{0x00D8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00F9, .upper = &[_]u21{0x00D9}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00FA, .upper = &[_]u21{0x00DA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00FB, .upper = &[_]u21{0x00DB}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00FC, .upper = &[_]u21{0x00DC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00FD, .upper = &[_]u21{0x00DD}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00FE, .upper = &[_]u21{0x00DE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x00FF, .upper = &[_]u21{0x0178}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0100, .upper = &[_]u21{}, .lower = &[_]u21{0x0101}, .fold = &[_]u21{0x0101} },
+    .{ .codepoint = 0x0101, .upper = &[_]u21{0x0100}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0102, .upper = &[_]u21{}, .lower = &[_]u21{0x0103}, .fold = &[_]u21{0x0103} },
+    .{ .codepoint = 0x0103, .upper = &[_]u21{0x0102}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0104, .upper = &[_]u21{}, .lower = &[_]u21{0x0105}, .fold = &[_]u21{0x0105} },
+    .{ .codepoint = 0x0105, .upper = &[_]u21{0x0104}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0106, .upper = &[_]u21{}, .lower = &[_]u21{0x0107}, .fold = &[_]u21{0x0107} },
+    .{ .codepoint = 0x0107, .upper = &[_]u21{0x0106}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0108, .upper = &[_]u21{}, .lower = &[_]u21{0x0109}, .fold = &[_]u21{0x0109} },
+    .{ .codepoint = 0x0109, .upper = &[_]u21{0x0108}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x010A, .upper = &[_]u21{}, .lower = &[_]u21{0x010B}, .fold = &[_]u21{0x010B} },
+    .{ .codepoint = 0x010B, .upper = &[_]u21{0x010A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x010C, .upper = &[_]u21{}, .lower = &[_]u21{0x010D}, .fold = &[_]u21{0x010D} },
+    .{ .codepoint = 0x010D, .upper = &[_]u21{0x010C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x010E, .upper = &[_]u21{}, .lower = &[_]u21{0x010F}, .fold = &[_]u21{0x010F} },
+    .{ .codepoint = 0x010F, .upper = &[_]u21{0x010E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0110, .upper = &[_]u21{}, .lower = &[_]u21{0x0111}, .fold = &[_]u21{0x0111} },
+    .{ .codepoint = 0x0111, .upper = &[_]u21{0x0110}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0112, .upper = &[_]u21{}, .lower = &[_]u21{0x0113}, .fold = &[_]u21{0x0113} },
+    .{ .codepoint = 0x0113, .upper = &[_]u21{0x0112}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0114, .upper = &[_]u21{}, .lower = &[_]u21{0x0115}, .fold = &[_]u21{0x0115} },
+    .{ .codepoint = 0x0115, .upper = &[_]u21{0x0114}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0116, .upper = &[_]u21{}, .lower = &[_]u21{0x0117}, .fold = &[_]u21{0x0117} },
+    .{ .codepoint = 0x0117, .upper = &[_]u21{0x0116}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0118, .upper = &[_]u21{}, .lower = &[_]u21{0x0119}, .fold = &[_]u21{0x0119} },
+    .{ .codepoint = 0x0119, .upper = &[_]u21{0x0118}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x011A, .upper = &[_]u21{}, .lower = &[_]u21{0x011B}, .fold = &[_]u21{0x011B} },
+    .{ .codepoint = 0x011B, .upper = &[_]u21{0x011A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x011C, .upper = &[_]u21{}, .lower = &[_]u21{0x011D}, .fold = &[_]u21{0x011D} },
+    .{ .codepoint = 0x011D, .upper = &[_]u21{0x011C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x011E, .upper = &[_]u21{}, .lower = &[_]u21{0x011F}, .fold = &[_]u21{0x011F} },
+    .{ .codepoint = 0x011F, .upper = &[_]u21{0x011E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0120, .upper = &[_]u21{}, .lower = &[_]u21{0x0121}, .fold = &[_]u21{0x0121} },
+    .{ .codepoint = 0x0121, .upper = &[_]u21{0x0120}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0122, .upper = &[_]u21{}, .lower = &[_]u21{0x0123}, .fold = &[_]u21{0x0123} },
+    .{ .codepoint = 0x0123, .upper = &[_]u21{0x0122}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0124, .upper = &[_]u21{}, .lower = &[_]u21{0x0125}, .fold = &[_]u21{0x0125} },
+    .{ .codepoint = 0x0125, .upper = &[_]u21{0x0124}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0126, .upper = &[_]u21{}, .lower = &[_]u21{0x0127}, .fold = &[_]u21{0x0127} },
+    .{ .codepoint = 0x0127, .upper = &[_]u21{0x0126}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0128, .upper = &[_]u21{}, .lower = &[_]u21{0x0129}, .fold = &[_]u21{0x0129} },
+    .{ .codepoint = 0x0129, .upper = &[_]u21{0x0128}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x012A, .upper = &[_]u21{}, .lower = &[_]u21{0x012B}, .fold = &[_]u21{0x012B} },
+    .{ .codepoint = 0x012B, .upper = &[_]u21{0x012A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x012C, .upper = &[_]u21{}, .lower = &[_]u21{0x012D}, .fold = &[_]u21{0x012D} },
+    .{ .codepoint = 0x012D, .upper = &[_]u21{0x012C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x012E, .upper = &[_]u21{}, .lower = &[_]u21{0x012F}, .fold = &[_]u21{0x012F} },
+    .{ .codepoint = 0x012F, .upper = &[_]u21{0x012E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0130, .upper = &[_]u21{}, .lower = &[_]u21{ 0x0069, 0x0307 }, .fold = &[_]u21{ 0x0069, 0x0307 } },
+    .{ .codepoint = 0x0131, .upper = &[_]u21{0x0049}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0132, .upper = &[_]u21{}, .lower = &[_]u21{0x0133}, .fold = &[_]u21{0x0133} },
+    .{ .codepoint = 0x0133, .upper = &[_]u21{0x0132}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0134, .upper = &[_]u21{}, .lower = &[_]u21{0x0135}, .fold = &[_]u21{0x0135} },
+    .{ .codepoint = 0x0135, .upper = &[_]u21{0x0134}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0136, .upper = &[_]u21{}, .lower = &[_]u21{0x0137}, .fold = &[_]u21{0x0137} },
+    .{ .codepoint = 0x0137, .upper = &[_]u21{0x0136}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0139, .upper = &[_]u21{}, .lower = &[_]u21{0x013A}, .fold = &[_]u21{0x013A} },
+    .{ .codepoint = 0x013A, .upper = &[_]u21{0x0139}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x013B, .upper = &[_]u21{}, .lower = &[_]u21{0x013C}, .fold = &[_]u21{0x013C} },
+    .{ .codepoint = 0x013C, .upper = &[_]u21{0x013B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x013D, .upper = &[_]u21{}, .lower = &[_]u21{0x013E}, .fold = &[_]u21{0x013E} },
+    .{ .codepoint = 0x013E, .upper = &[_]u21{0x013D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x013F, .upper = &[_]u21{}, .lower = &[_]u21{0x0140}, .fold = &[_]u21{0x0140} },
+    .{ .codepoint = 0x0140, .upper = &[_]u21{0x013F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0141, .upper = &[_]u21{}, .lower = &[_]u21{0x0142}, .fold = &[_]u21{0x0142} },
+    .{ .codepoint = 0x0142, .upper = &[_]u21{0x0141}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0143, .upper = &[_]u21{}, .lower = &[_]u21{0x0144}, .fold = &[_]u21{0x0144} },
+    .{ .codepoint = 0x0144, .upper = &[_]u21{0x0143}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0145, .upper = &[_]u21{}, .lower = &[_]u21{0x0146}, .fold = &[_]u21{0x0146} },
+    .{ .codepoint = 0x0146, .upper = &[_]u21{0x0145}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0147, .upper = &[_]u21{}, .lower = &[_]u21{0x0148}, .fold = &[_]u21{0x0148} },
+    .{ .codepoint = 0x0148, .upper = &[_]u21{0x0147}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0149, .upper = &[_]u21{ 0x02BC, 0x004E }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x02BC, 0x006E } },
+    .{ .codepoint = 0x014A, .upper = &[_]u21{}, .lower = &[_]u21{0x014B}, .fold = &[_]u21{0x014B} },
+    .{ .codepoint = 0x014B, .upper = &[_]u21{0x014A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x014C, .upper = &[_]u21{}, .lower = &[_]u21{0x014D}, .fold = &[_]u21{0x014D} },
+    .{ .codepoint = 0x014D, .upper = &[_]u21{0x014C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x014E, .upper = &[_]u21{}, .lower = &[_]u21{0x014F}, .fold = &[_]u21{0x014F} },
+    .{ .codepoint = 0x014F, .upper = &[_]u21{0x014E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0150, .upper = &[_]u21{}, .lower = &[_]u21{0x0151}, .fold = &[_]u21{0x0151} },
+    .{ .codepoint = 0x0151, .upper = &[_]u21{0x0150}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0152, .upper = &[_]u21{}, .lower = &[_]u21{0x0153}, .fold = &[_]u21{0x0153} },
+    .{ .codepoint = 0x0153, .upper = &[_]u21{0x0152}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0154, .upper = &[_]u21{}, .lower = &[_]u21{0x0155}, .fold = &[_]u21{0x0155} },
+    .{ .codepoint = 0x0155, .upper = &[_]u21{0x0154}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0156, .upper = &[_]u21{}, .lower = &[_]u21{0x0157}, .fold = &[_]u21{0x0157} },
+    .{ .codepoint = 0x0157, .upper = &[_]u21{0x0156}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0158, .upper = &[_]u21{}, .lower = &[_]u21{0x0159}, .fold = &[_]u21{0x0159} },
+    .{ .codepoint = 0x0159, .upper = &[_]u21{0x0158}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x015A, .upper = &[_]u21{}, .lower = &[_]u21{0x015B}, .fold = &[_]u21{0x015B} },
+    .{ .codepoint = 0x015B, .upper = &[_]u21{0x015A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x015C, .upper = &[_]u21{}, .lower = &[_]u21{0x015D}, .fold = &[_]u21{0x015D} },
+    .{ .codepoint = 0x015D, .upper = &[_]u21{0x015C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x015E, .upper = &[_]u21{}, .lower = &[_]u21{0x015F}, .fold = &[_]u21{0x015F} },
+    .{ .codepoint = 0x015F, .upper = &[_]u21{0x015E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0160, .upper = &[_]u21{}, .lower = &[_]u21{0x0161}, .fold = &[_]u21{0x0161} },
+    .{ .codepoint = 0x0161, .upper = &[_]u21{0x0160}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0162, .upper = &[_]u21{}, .lower = &[_]u21{0x0163}, .fold = &[_]u21{0x0163} },
+    .{ .codepoint = 0x0163, .upper = &[_]u21{0x0162}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0164, .upper = &[_]u21{}, .lower = &[_]u21{0x0165}, .fold = &[_]u21{0x0165} },
+    .{ .codepoint = 0x0165, .upper = &[_]u21{0x0164}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0166, .upper = &[_]u21{}, .lower = &[_]u21{0x0167}, .fold = &[_]u21{0x0167} },
+    .{ .codepoint = 0x0167, .upper = &[_]u21{0x0166}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0168, .upper = &[_]u21{}, .lower = &[_]u21{0x0169}, .fold = &[_]u21{0x0169} },
+    .{ .codepoint = 0x0169, .upper = &[_]u21{0x0168}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x016A, .upper = &[_]u21{}, .lower = &[_]u21{0x016B}, .fold = &[_]u21{0x016B} },
+    .{ .codepoint = 0x016B, .upper = &[_]u21{0x016A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x016C, .upper = &[_]u21{}, .lower = &[_]u21{0x016D}, .fold = &[_]u21{0x016D} },
+    .{ .codepoint = 0x016D, .upper = &[_]u21{0x016C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x016E, .upper = &[_]u21{}, .lower = &[_]u21{0x016F}, .fold = &[_]u21{0x016F} },
+    .{ .codepoint = 0x016F, .upper = &[_]u21{0x016E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0170, .upper = &[_]u21{}, .lower = &[_]u21{0x0171}, .fold = &[_]u21{0x0171} },
+    .{ .codepoint = 0x0171, .upper = &[_]u21{0x0170}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0172, .upper = &[_]u21{}, .lower = &[_]u21{0x0173}, .fold = &[_]u21{0x0173} },
+    .{ .codepoint = 0x0173, .upper = &[_]u21{0x0172}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0174, .upper = &[_]u21{}, .lower = &[_]u21{0x0175}, .fold = &[_]u21{0x0175} },
+    .{ .codepoint = 0x0175, .upper = &[_]u21{0x0174}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0176, .upper = &[_]u21{}, .lower = &[_]u21{0x0177}, .fold = &[_]u21{0x0177} },
+    .{ .codepoint = 0x0177, .upper = &[_]u21{0x0176}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0178, .upper = &[_]u21{}, .lower = &[_]u21{0x00FF}, .fold = &[_]u21{0x00FF} },
+    .{ .codepoint = 0x0179, .upper = &[_]u21{}, .lower = &[_]u21{0x017A}, .fold = &[_]u21{0x017A} },
+    .{ .codepoint = 0x017A, .upper = &[_]u21{0x0179}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x017B, .upper = &[_]u21{}, .lower = &[_]u21{0x017C}, .fold = &[_]u21{0x017C} },
+    .{ .codepoint = 0x017C, .upper = &[_]u21{0x017B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x017D, .upper = &[_]u21{}, .lower = &[_]u21{0x017E}, .fold = &[_]u21{0x017E} },
+    .{ .codepoint = 0x017E, .upper = &[_]u21{0x017D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x017F, .upper = &[_]u21{0x0053}, .lower = &[_]u21{}, .fold = &[_]u21{0x0073} },
+    .{ .codepoint = 0x0180, .upper = &[_]u21{0x0243}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0181, .upper = &[_]u21{}, .lower = &[_]u21{0x0253}, .fold = &[_]u21{0x0253} },
+    .{ .codepoint = 0x0182, .upper = &[_]u21{}, .lower = &[_]u21{0x0183}, .fold = &[_]u21{0x0183} },
+    .{ .codepoint = 0x0183, .upper = &[_]u21{0x0182}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0184, .upper = &[_]u21{}, .lower = &[_]u21{0x0185}, .fold = &[_]u21{0x0185} },
+    .{ .codepoint = 0x0185, .upper = &[_]u21{0x0184}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0186, .upper = &[_]u21{}, .lower = &[_]u21{0x0254}, .fold = &[_]u21{0x0254} },
+    .{ .codepoint = 0x0187, .upper = &[_]u21{}, .lower = &[_]u21{0x0188}, .fold = &[_]u21{0x0188} },
+    .{ .codepoint = 0x0188, .upper = &[_]u21{0x0187}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0189, .upper = &[_]u21{}, .lower = &[_]u21{0x0256}, .fold = &[_]u21{0x0256} },
+    .{ .codepoint = 0x018A, .upper = &[_]u21{}, .lower = &[_]u21{0x0257}, .fold = &[_]u21{0x0257} },
+    .{ .codepoint = 0x018B, .upper = &[_]u21{}, .lower = &[_]u21{0x018C}, .fold = &[_]u21{0x018C} },
+    .{ .codepoint = 0x018C, .upper = &[_]u21{0x018B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x018E, .upper = &[_]u21{}, .lower = &[_]u21{0x01DD}, .fold = &[_]u21{0x01DD} },
+    .{ .codepoint = 0x018F, .upper = &[_]u21{}, .lower = &[_]u21{0x0259}, .fold = &[_]u21{0x0259} },
+    .{ .codepoint = 0x0190, .upper = &[_]u21{}, .lower = &[_]u21{0x025B}, .fold = &[_]u21{0x025B} },
+    .{ .codepoint = 0x0191, .upper = &[_]u21{}, .lower = &[_]u21{0x0192}, .fold = &[_]u21{0x0192} },
+    .{ .codepoint = 0x0192, .upper = &[_]u21{0x0191}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0193, .upper = &[_]u21{}, .lower = &[_]u21{0x0260}, .fold = &[_]u21{0x0260} },
+    .{ .codepoint = 0x0194, .upper = &[_]u21{}, .lower = &[_]u21{0x0263}, .fold = &[_]u21{0x0263} },
+    .{ .codepoint = 0x0195, .upper = &[_]u21{0x01F6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0196, .upper = &[_]u21{}, .lower = &[_]u21{0x0269}, .fold = &[_]u21{0x0269} },
+    .{ .codepoint = 0x0197, .upper = &[_]u21{}, .lower = &[_]u21{0x0268}, .fold = &[_]u21{0x0268} },
+    .{ .codepoint = 0x0198, .upper = &[_]u21{}, .lower = &[_]u21{0x0199}, .fold = &[_]u21{0x0199} },
+    .{ .codepoint = 0x0199, .upper = &[_]u21{0x0198}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x019A, .upper = &[_]u21{0x023D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x019C, .upper = &[_]u21{}, .lower = &[_]u21{0x026F}, .fold = &[_]u21{0x026F} },
+    .{ .codepoint = 0x019D, .upper = &[_]u21{}, .lower = &[_]u21{0x0272}, .fold = &[_]u21{0x0272} },
+    .{ .codepoint = 0x019E, .upper = &[_]u21{0x0220}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x019F, .upper = &[_]u21{}, .lower = &[_]u21{0x0275}, .fold = &[_]u21{0x0275} },
+    .{ .codepoint = 0x01A0, .upper = &[_]u21{}, .lower = &[_]u21{0x01A1}, .fold = &[_]u21{0x01A1} },
+    .{ .codepoint = 0x01A1, .upper = &[_]u21{0x01A0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01A2, .upper = &[_]u21{}, .lower = &[_]u21{0x01A3}, .fold = &[_]u21{0x01A3} },
+    .{ .codepoint = 0x01A3, .upper = &[_]u21{0x01A2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01A4, .upper = &[_]u21{}, .lower = &[_]u21{0x01A5}, .fold = &[_]u21{0x01A5} },
+    .{ .codepoint = 0x01A5, .upper = &[_]u21{0x01A4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01A6, .upper = &[_]u21{}, .lower = &[_]u21{0x0280}, .fold = &[_]u21{0x0280} },
+    .{ .codepoint = 0x01A7, .upper = &[_]u21{}, .lower = &[_]u21{0x01A8}, .fold = &[_]u21{0x01A8} },
+    .{ .codepoint = 0x01A8, .upper = &[_]u21{0x01A7}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01A9, .upper = &[_]u21{}, .lower = &[_]u21{0x0283}, .fold = &[_]u21{0x0283} },
+    .{ .codepoint = 0x01AC, .upper = &[_]u21{}, .lower = &[_]u21{0x01AD}, .fold = &[_]u21{0x01AD} },
+    .{ .codepoint = 0x01AD, .upper = &[_]u21{0x01AC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01AE, .upper = &[_]u21{}, .lower = &[_]u21{0x0288}, .fold = &[_]u21{0x0288} },
+    .{ .codepoint = 0x01AF, .upper = &[_]u21{}, .lower = &[_]u21{0x01B0}, .fold = &[_]u21{0x01B0} },
+    .{ .codepoint = 0x01B0, .upper = &[_]u21{0x01AF}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01B1, .upper = &[_]u21{}, .lower = &[_]u21{0x028A}, .fold = &[_]u21{0x028A} },
+    .{ .codepoint = 0x01B2, .upper = &[_]u21{}, .lower = &[_]u21{0x028B}, .fold = &[_]u21{0x028B} },
+    .{ .codepoint = 0x01B3, .upper = &[_]u21{}, .lower = &[_]u21{0x01B4}, .fold = &[_]u21{0x01B4} },
+    .{ .codepoint = 0x01B4, .upper = &[_]u21{0x01B3}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01B5, .upper = &[_]u21{}, .lower = &[_]u21{0x01B6}, .fold = &[_]u21{0x01B6} },
+    .{ .codepoint = 0x01B6, .upper = &[_]u21{0x01B5}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01B7, .upper = &[_]u21{}, .lower = &[_]u21{0x0292}, .fold = &[_]u21{0x0292} },
+    .{ .codepoint = 0x01B8, .upper = &[_]u21{}, .lower = &[_]u21{0x01B9}, .fold = &[_]u21{0x01B9} },
+    .{ .codepoint = 0x01B9, .upper = &[_]u21{0x01B8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01BC, .upper = &[_]u21{}, .lower = &[_]u21{0x01BD}, .fold = &[_]u21{0x01BD} },
+    .{ .codepoint = 0x01BD, .upper = &[_]u21{0x01BC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01BF, .upper = &[_]u21{0x01F7}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01C4, .upper = &[_]u21{}, .lower = &[_]u21{0x01C6}, .fold = &[_]u21{0x01C6} },
+    .{ .codepoint = 0x01C5, .upper = &[_]u21{0x01C4}, .lower = &[_]u21{0x01C6}, .fold = &[_]u21{0x01C6} },
+    .{ .codepoint = 0x01C6, .upper = &[_]u21{0x01C4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01C7, .upper = &[_]u21{}, .lower = &[_]u21{0x01C9}, .fold = &[_]u21{0x01C9} },
+    .{ .codepoint = 0x01C8, .upper = &[_]u21{0x01C7}, .lower = &[_]u21{0x01C9}, .fold = &[_]u21{0x01C9} },
+    .{ .codepoint = 0x01C9, .upper = &[_]u21{0x01C7}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01CA, .upper = &[_]u21{}, .lower = &[_]u21{0x01CC}, .fold = &[_]u21{0x01CC} },
+    .{ .codepoint = 0x01CB, .upper = &[_]u21{0x01CA}, .lower = &[_]u21{0x01CC}, .fold = &[_]u21{0x01CC} },
+    .{ .codepoint = 0x01CC, .upper = &[_]u21{0x01CA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01CD, .upper = &[_]u21{}, .lower = &[_]u21{0x01CE}, .fold = &[_]u21{0x01CE} },
+    .{ .codepoint = 0x01CE, .upper = &[_]u21{0x01CD}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01CF, .upper = &[_]u21{}, .lower = &[_]u21{0x01D0}, .fold = &[_]u21{0x01D0} },
+    .{ .codepoint = 0x01D0, .upper = &[_]u21{0x01CF}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01D1, .upper = &[_]u21{}, .lower = &[_]u21{0x01D2}, .fold = &[_]u21{0x01D2} },
+    .{ .codepoint = 0x01D2, .upper = &[_]u21{0x01D1}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01D3, .upper = &[_]u21{}, .lower = &[_]u21{0x01D4}, .fold = &[_]u21{0x01D4} },
+    .{ .codepoint = 0x01D4, .upper = &[_]u21{0x01D3}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01D5, .upper = &[_]u21{}, .lower = &[_]u21{0x01D6}, .fold = &[_]u21{0x01D6} },
+    .{ .codepoint = 0x01D6, .upper = &[_]u21{0x01D5}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01D7, .upper = &[_]u21{}, .lower = &[_]u21{0x01D8}, .fold = &[_]u21{0x01D8} },
+    .{ .codepoint = 0x01D8, .upper = &[_]u21{0x01D7}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01D9, .upper = &[_]u21{}, .lower = &[_]u21{0x01DA}, .fold = &[_]u21{0x01DA} },
+    .{ .codepoint = 0x01DA, .upper = &[_]u21{0x01D9}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01DB, .upper = &[_]u21{}, .lower = &[_]u21{0x01DC}, .fold = &[_]u21{0x01DC} },
+    .{ .codepoint = 0x01DC, .upper = &[_]u21{0x01DB}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01DD, .upper = &[_]u21{0x018E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01DE, .upper = &[_]u21{}, .lower = &[_]u21{0x01DF}, .fold = &[_]u21{0x01DF} },
+    .{ .codepoint = 0x01DF, .upper = &[_]u21{0x01DE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01E0, .upper = &[_]u21{}, .lower = &[_]u21{0x01E1}, .fold = &[_]u21{0x01E1} },
+    .{ .codepoint = 0x01E1, .upper = &[_]u21{0x01E0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01E2, .upper = &[_]u21{}, .lower = &[_]u21{0x01E3}, .fold = &[_]u21{0x01E3} },
+    .{ .codepoint = 0x01E3, .upper = &[_]u21{0x01E2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01E4, .upper = &[_]u21{}, .lower = &[_]u21{0x01E5}, .fold = &[_]u21{0x01E5} },
+    .{ .codepoint = 0x01E5, .upper = &[_]u21{0x01E4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01E6, .upper = &[_]u21{}, .lower = &[_]u21{0x01E7}, .fold = &[_]u21{0x01E7} },
+    .{ .codepoint = 0x01E7, .upper = &[_]u21{0x01E6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01E8, .upper = &[_]u21{}, .lower = &[_]u21{0x01E9}, .fold = &[_]u21{0x01E9} },
+    .{ .codepoint = 0x01E9, .upper = &[_]u21{0x01E8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01EA, .upper = &[_]u21{}, .lower = &[_]u21{0x01EB}, .fold = &[_]u21{0x01EB} },
+    .{ .codepoint = 0x01EB, .upper = &[_]u21{0x01EA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01EC, .upper = &[_]u21{}, .lower = &[_]u21{0x01ED}, .fold = &[_]u21{0x01ED} },
+    .{ .codepoint = 0x01ED, .upper = &[_]u21{0x01EC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01EE, .upper = &[_]u21{}, .lower = &[_]u21{0x01EF}, .fold = &[_]u21{0x01EF} },
+    .{ .codepoint = 0x01EF, .upper = &[_]u21{0x01EE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01F0, .upper = &[_]u21{ 0x004A, 0x030C }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x006A, 0x030C } },
+    .{ .codepoint = 0x01F1, .upper = &[_]u21{}, .lower = &[_]u21{0x01F3}, .fold = &[_]u21{0x01F3} },
+    .{ .codepoint = 0x01F2, .upper = &[_]u21{0x01F1}, .lower = &[_]u21{0x01F3}, .fold = &[_]u21{0x01F3} },
+    .{ .codepoint = 0x01F3, .upper = &[_]u21{0x01F1}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01F4, .upper = &[_]u21{}, .lower = &[_]u21{0x01F5}, .fold = &[_]u21{0x01F5} },
+    .{ .codepoint = 0x01F5, .upper = &[_]u21{0x01F4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01F6, .upper = &[_]u21{}, .lower = &[_]u21{0x0195}, .fold = &[_]u21{0x0195} },
+    .{ .codepoint = 0x01F7, .upper = &[_]u21{}, .lower = &[_]u21{0x01BF}, .fold = &[_]u21{0x01BF} },
+    .{ .codepoint = 0x01F8, .upper = &[_]u21{}, .lower = &[_]u21{0x01F9}, .fold = &[_]u21{0x01F9} },
+    .{ .codepoint = 0x01F9, .upper = &[_]u21{0x01F8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01FA, .upper = &[_]u21{}, .lower = &[_]u21{0x01FB}, .fold = &[_]u21{0x01FB} },
+    .{ .codepoint = 0x01FB, .upper = &[_]u21{0x01FA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01FC, .upper = &[_]u21{}, .lower = &[_]u21{0x01FD}, .fold = &[_]u21{0x01FD} },
+    .{ .codepoint = 0x01FD, .upper = &[_]u21{0x01FC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x01FE, .upper = &[_]u21{}, .lower = &[_]u21{0x01FF}, .fold = &[_]u21{0x01FF} },
+    .{ .codepoint = 0x01FF, .upper = &[_]u21{0x01FE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0200, .upper = &[_]u21{}, .lower = &[_]u21{0x0201}, .fold = &[_]u21{0x0201} },
+    .{ .codepoint = 0x0201, .upper = &[_]u21{0x0200}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0202, .upper = &[_]u21{}, .lower = &[_]u21{0x0203}, .fold = &[_]u21{0x0203} },
+    .{ .codepoint = 0x0203, .upper = &[_]u21{0x0202}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0204, .upper = &[_]u21{}, .lower = &[_]u21{0x0205}, .fold = &[_]u21{0x0205} },
+    .{ .codepoint = 0x0205, .upper = &[_]u21{0x0204}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0206, .upper = &[_]u21{}, .lower = &[_]u21{0x0207}, .fold = &[_]u21{0x0207} },
+    .{ .codepoint = 0x0207, .upper = &[_]u21{0x0206}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0208, .upper = &[_]u21{}, .lower = &[_]u21{0x0209}, .fold = &[_]u21{0x0209} },
+    .{ .codepoint = 0x0209, .upper = &[_]u21{0x0208}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x020A, .upper = &[_]u21{}, .lower = &[_]u21{0x020B}, .fold = &[_]u21{0x020B} },
+    .{ .codepoint = 0x020B, .upper = &[_]u21{0x020A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x020C, .upper = &[_]u21{}, .lower = &[_]u21{0x020D}, .fold = &[_]u21{0x020D} },
+    .{ .codepoint = 0x020D, .upper = &[_]u21{0x020C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x020E, .upper = &[_]u21{}, .lower = &[_]u21{0x020F}, .fold = &[_]u21{0x020F} },
+    .{ .codepoint = 0x020F, .upper = &[_]u21{0x020E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0210, .upper = &[_]u21{}, .lower = &[_]u21{0x0211}, .fold = &[_]u21{0x0211} },
+    .{ .codepoint = 0x0211, .upper = &[_]u21{0x0210}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0212, .upper = &[_]u21{}, .lower = &[_]u21{0x0213}, .fold = &[_]u21{0x0213} },
+    .{ .codepoint = 0x0213, .upper = &[_]u21{0x0212}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0214, .upper = &[_]u21{}, .lower = &[_]u21{0x0215}, .fold = &[_]u21{0x0215} },
+    .{ .codepoint = 0x0215, .upper = &[_]u21{0x0214}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0216, .upper = &[_]u21{}, .lower = &[_]u21{0x0217}, .fold = &[_]u21{0x0217} },
+    .{ .codepoint = 0x0217, .upper = &[_]u21{0x0216}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0218, .upper = &[_]u21{}, .lower = &[_]u21{0x0219}, .fold = &[_]u21{0x0219} },
+    .{ .codepoint = 0x0219, .upper = &[_]u21{0x0218}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x021A, .upper = &[_]u21{}, .lower = &[_]u21{0x021B}, .fold = &[_]u21{0x021B} },
+    .{ .codepoint = 0x021B, .upper = &[_]u21{0x021A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x021C, .upper = &[_]u21{}, .lower = &[_]u21{0x021D}, .fold = &[_]u21{0x021D} },
+    .{ .codepoint = 0x021D, .upper = &[_]u21{0x021C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x021E, .upper = &[_]u21{}, .lower = &[_]u21{0x021F}, .fold = &[_]u21{0x021F} },
+    .{ .codepoint = 0x021F, .upper = &[_]u21{0x021E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0220, .upper = &[_]u21{}, .lower = &[_]u21{0x019E}, .fold = &[_]u21{0x019E} },
+    .{ .codepoint = 0x0222, .upper = &[_]u21{}, .lower = &[_]u21{0x0223}, .fold = &[_]u21{0x0223} },
+    .{ .codepoint = 0x0223, .upper = &[_]u21{0x0222}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0224, .upper = &[_]u21{}, .lower = &[_]u21{0x0225}, .fold = &[_]u21{0x0225} },
+    .{ .codepoint = 0x0225, .upper = &[_]u21{0x0224}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0226, .upper = &[_]u21{}, .lower = &[_]u21{0x0227}, .fold = &[_]u21{0x0227} },
+    .{ .codepoint = 0x0227, .upper = &[_]u21{0x0226}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0228, .upper = &[_]u21{}, .lower = &[_]u21{0x0229}, .fold = &[_]u21{0x0229} },
+    .{ .codepoint = 0x0229, .upper = &[_]u21{0x0228}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x022A, .upper = &[_]u21{}, .lower = &[_]u21{0x022B}, .fold = &[_]u21{0x022B} },
+    .{ .codepoint = 0x022B, .upper = &[_]u21{0x022A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x022C, .upper = &[_]u21{}, .lower = &[_]u21{0x022D}, .fold = &[_]u21{0x022D} },
+    .{ .codepoint = 0x022D, .upper = &[_]u21{0x022C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x022E, .upper = &[_]u21{}, .lower = &[_]u21{0x022F}, .fold = &[_]u21{0x022F} },
+    .{ .codepoint = 0x022F, .upper = &[_]u21{0x022E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0230, .upper = &[_]u21{}, .lower = &[_]u21{0x0231}, .fold = &[_]u21{0x0231} },
+    .{ .codepoint = 0x0231, .upper = &[_]u21{0x0230}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0232, .upper = &[_]u21{}, .lower = &[_]u21{0x0233}, .fold = &[_]u21{0x0233} },
+    .{ .codepoint = 0x0233, .upper = &[_]u21{0x0232}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x023A, .upper = &[_]u21{}, .lower = &[_]u21{0x2C65}, .fold = &[_]u21{0x2C65} },
+    .{ .codepoint = 0x023B, .upper = &[_]u21{}, .lower = &[_]u21{0x023C}, .fold = &[_]u21{0x023C} },
+    .{ .codepoint = 0x023C, .upper = &[_]u21{0x023B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x023D, .upper = &[_]u21{}, .lower = &[_]u21{0x019A}, .fold = &[_]u21{0x019A} },
+    .{ .codepoint = 0x023E, .upper = &[_]u21{}, .lower = &[_]u21{0x2C66}, .fold = &[_]u21{0x2C66} },
+    .{ .codepoint = 0x023F, .upper = &[_]u21{0x2C7E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0240, .upper = &[_]u21{0x2C7F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0241, .upper = &[_]u21{}, .lower = &[_]u21{0x0242}, .fold = &[_]u21{0x0242} },
+    .{ .codepoint = 0x0242, .upper = &[_]u21{0x0241}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0243, .upper = &[_]u21{}, .lower = &[_]u21{0x0180}, .fold = &[_]u21{0x0180} },
+    .{ .codepoint = 0x0244, .upper = &[_]u21{}, .lower = &[_]u21{0x0289}, .fold = &[_]u21{0x0289} },
+    .{ .codepoint = 0x0245, .upper = &[_]u21{}, .lower = &[_]u21{0x028C}, .fold = &[_]u21{0x028C} },
+    .{ .codepoint = 0x0246, .upper = &[_]u21{}, .lower = &[_]u21{0x0247}, .fold = &[_]u21{0x0247} },
+    .{ .codepoint = 0x0247, .upper = &[_]u21{0x0246}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0248, .upper = &[_]u21{}, .lower = &[_]u21{0x0249}, .fold = &[_]u21{0x0249} },
+    .{ .codepoint = 0x0249, .upper = &[_]u21{0x0248}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x024A, .upper = &[_]u21{}, .lower = &[_]u21{0x024B}, .fold = &[_]u21{0x024B} },
+    .{ .codepoint = 0x024B, .upper = &[_]u21{0x024A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x024C, .upper = &[_]u21{}, .lower = &[_]u21{0x024D}, .fold = &[_]u21{0x024D} },
+    .{ .codepoint = 0x024D, .upper = &[_]u21{0x024C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x024E, .upper = &[_]u21{}, .lower = &[_]u21{0x024F}, .fold = &[_]u21{0x024F} },
+    .{ .codepoint = 0x024F, .upper = &[_]u21{0x024E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0250, .upper = &[_]u21{0x2C6F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0251, .upper = &[_]u21{0x2C6D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0252, .upper = &[_]u21{0x2C70}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0253, .upper = &[_]u21{0x0181}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0254, .upper = &[_]u21{0x0186}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0256, .upper = &[_]u21{0x0189}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0257, .upper = &[_]u21{0x018A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0259, .upper = &[_]u21{0x018F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x025B, .upper = &[_]u21{0x0190}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x025C, .upper = &[_]u21{0xA7AB}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0260, .upper = &[_]u21{0x0193}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0261, .upper = &[_]u21{0xA7AC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0263, .upper = &[_]u21{0x0194}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0265, .upper = &[_]u21{0xA78D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0266, .upper = &[_]u21{0xA7AA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0268, .upper = &[_]u21{0x0197}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0269, .upper = &[_]u21{0x0196}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x026A, .upper = &[_]u21{0xA7AE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x026B, .upper = &[_]u21{0x2C62}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x026C, .upper = &[_]u21{0xA7AD}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x026F, .upper = &[_]u21{0x019C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0271, .upper = &[_]u21{0x2C6E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0272, .upper = &[_]u21{0x019D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0275, .upper = &[_]u21{0x019F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x027D, .upper = &[_]u21{0x2C64}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0280, .upper = &[_]u21{0x01A6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0282, .upper = &[_]u21{0xA7C5}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0283, .upper = &[_]u21{0x01A9}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0287, .upper = &[_]u21{0xA7B1}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0288, .upper = &[_]u21{0x01AE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0289, .upper = &[_]u21{0x0244}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x028A, .upper = &[_]u21{0x01B1}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x028B, .upper = &[_]u21{0x01B2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x028C, .upper = &[_]u21{0x0245}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0292, .upper = &[_]u21{0x01B7}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x029D, .upper = &[_]u21{0xA7B2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x029E, .upper = &[_]u21{0xA7B0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0345, .upper = &[_]u21{0x0399}, .lower = &[_]u21{}, .fold = &[_]u21{0x03B9} },
+    .{ .codepoint = 0x0370, .upper = &[_]u21{}, .lower = &[_]u21{0x0371}, .fold = &[_]u21{0x0371} },
+    .{ .codepoint = 0x0371, .upper = &[_]u21{0x0370}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0372, .upper = &[_]u21{}, .lower = &[_]u21{0x0373}, .fold = &[_]u21{0x0373} },
+    .{ .codepoint = 0x0373, .upper = &[_]u21{0x0372}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0376, .upper = &[_]u21{}, .lower = &[_]u21{0x0377}, .fold = &[_]u21{0x0377} },
+    .{ .codepoint = 0x0377, .upper = &[_]u21{0x0376}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x037B, .upper = &[_]u21{0x03FD}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x037C, .upper = &[_]u21{0x03FE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x037D, .upper = &[_]u21{0x03FF}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x037F, .upper = &[_]u21{}, .lower = &[_]u21{0x03F3}, .fold = &[_]u21{0x03F3} },
+    .{ .codepoint = 0x0386, .upper = &[_]u21{}, .lower = &[_]u21{0x03AC}, .fold = &[_]u21{0x03AC} },
+    .{ .codepoint = 0x0388, .upper = &[_]u21{}, .lower = &[_]u21{0x03AD}, .fold = &[_]u21{0x03AD} },
+    .{ .codepoint = 0x0389, .upper = &[_]u21{}, .lower = &[_]u21{0x03AE}, .fold = &[_]u21{0x03AE} },
+    .{ .codepoint = 0x038A, .upper = &[_]u21{}, .lower = &[_]u21{0x03AF}, .fold = &[_]u21{0x03AF} },
+    .{ .codepoint = 0x038C, .upper = &[_]u21{}, .lower = &[_]u21{0x03CC}, .fold = &[_]u21{0x03CC} },
+    .{ .codepoint = 0x038E, .upper = &[_]u21{}, .lower = &[_]u21{0x03CD}, .fold = &[_]u21{0x03CD} },
+    .{ .codepoint = 0x038F, .upper = &[_]u21{}, .lower = &[_]u21{0x03CE}, .fold = &[_]u21{0x03CE} },
+    .{ .codepoint = 0x0390, .upper = &[_]u21{ 0x0399, 0x0308, 0x0301 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x03B9, 0x0308, 0x0301 } },
+    .{ .codepoint = 0x0391, .upper = &[_]u21{}, .lower = &[_]u21{0x03B1}, .fold = &[_]u21{0x03B1} },
+    .{ .codepoint = 0x0392, .upper = &[_]u21{}, .lower = &[_]u21{0x03B2}, .fold = &[_]u21{0x03B2} },
+    .{ .codepoint = 0x0393, .upper = &[_]u21{}, .lower = &[_]u21{0x03B3}, .fold = &[_]u21{0x03B3} },
+    .{ .codepoint = 0x0394, .upper = &[_]u21{}, .lower = &[_]u21{0x03B4}, .fold = &[_]u21{0x03B4} },
+    .{ .codepoint = 0x0395, .upper = &[_]u21{}, .lower = &[_]u21{0x03B5}, .fold = &[_]u21{0x03B5} },
+    .{ .codepoint = 0x0396, .upper = &[_]u21{}, .lower = &[_]u21{0x03B6}, .fold = &[_]u21{0x03B6} },
+    .{ .codepoint = 0x0397, .upper = &[_]u21{}, .lower = &[_]u21{0x03B7}, .fold = &[_]u21{0x03B7} },
+    .{ .codepoint = 0x0398, .upper = &[_]u21{}, .lower = &[_]u21{0x03B8}, .fold = &[_]u21{0x03B8} },
+    .{ .codepoint = 0x0399, .upper = &[_]u21{}, .lower = &[_]u21{0x03B9}, .fold = &[_]u21{0x03B9} },
+    .{ .codepoint = 0x039A, .upper = &[_]u21{}, .lower = &[_]u21{0x03BA}, .fold = &[_]u21{0x03BA} },
+    .{ .codepoint = 0x039B, .upper = &[_]u21{}, .lower = &[_]u21{0x03BB}, .fold = &[_]u21{0x03BB} },
+    .{ .codepoint = 0x039C, .upper = &[_]u21{}, .lower = &[_]u21{0x03BC}, .fold = &[_]u21{0x03BC} },
+    .{ .codepoint = 0x039D, .upper = &[_]u21{}, .lower = &[_]u21{0x03BD}, .fold = &[_]u21{0x03BD} },
+    .{ .codepoint = 0x039E, .upper = &[_]u21{}, .lower = &[_]u21{0x03BE}, .fold = &[_]u21{0x03BE} },
+    .{ .codepoint = 0x039F, .upper = &[_]u21{}, .lower = &[_]u21{0x03BF}, .fold = &[_]u21{0x03BF} },
+    .{ .codepoint = 0x03A0, .upper = &[_]u21{}, .lower = &[_]u21{0x03C0}, .fold = &[_]u21{0x03C0} },
+    .{ .codepoint = 0x03A1, .upper = &[_]u21{}, .lower = &[_]u21{0x03C1}, .fold = &[_]u21{0x03C1} },
+    .{ .codepoint = 0x03A3, .upper = &[_]u21{}, .lower = &[_]u21{0x03C3}, .fold = &[_]u21{0x03C3} },
+    .{ .codepoint = 0x03A4, .upper = &[_]u21{}, .lower = &[_]u21{0x03C4}, .fold = &[_]u21{0x03C4} },
+    .{ .codepoint = 0x03A5, .upper = &[_]u21{}, .lower = &[_]u21{0x03C5}, .fold = &[_]u21{0x03C5} },
+    .{ .codepoint = 0x03A6, .upper = &[_]u21{}, .lower = &[_]u21{0x03C6}, .fold = &[_]u21{0x03C6} },
+    .{ .codepoint = 0x03A7, .upper = &[_]u21{}, .lower = &[_]u21{0x03C7}, .fold = &[_]u21{0x03C7} },
+    .{ .codepoint = 0x03A8, .upper = &[_]u21{}, .lower = &[_]u21{0x03C8}, .fold = &[_]u21{0x03C8} },
+    .{ .codepoint = 0x03A9, .upper = &[_]u21{}, .lower = &[_]u21{0x03C9}, .fold = &[_]u21{0x03C9} },
+    .{ .codepoint = 0x03AA, .upper = &[_]u21{}, .lower = &[_]u21{0x03CA}, .fold = &[_]u21{0x03CA} },
+    .{ .codepoint = 0x03AB, .upper = &[_]u21{}, .lower = &[_]u21{0x03CB}, .fold = &[_]u21{0x03CB} },
+    .{ .codepoint = 0x03AC, .upper = &[_]u21{0x0386}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03AD, .upper = &[_]u21{0x0388}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03AE, .upper = &[_]u21{0x0389}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03AF, .upper = &[_]u21{0x038A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03B0, .upper = &[_]u21{ 0x03A5, 0x0308, 0x0301 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x03C5, 0x0308, 0x0301 } },
+    .{ .codepoint = 0x03B1, .upper = &[_]u21{0x0391}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03B2, .upper = &[_]u21{0x0392}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03B3, .upper = &[_]u21{0x0393}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03B4, .upper = &[_]u21{0x0394}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03B5, .upper = &[_]u21{0x0395}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03B6, .upper = &[_]u21{0x0396}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03B7, .upper = &[_]u21{0x0397}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03B8, .upper = &[_]u21{0x0398}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03B9, .upper = &[_]u21{0x0399}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03BA, .upper = &[_]u21{0x039A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03BB, .upper = &[_]u21{0x039B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03BC, .upper = &[_]u21{0x039C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03BD, .upper = &[_]u21{0x039D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03BE, .upper = &[_]u21{0x039E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03BF, .upper = &[_]u21{0x039F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03C0, .upper = &[_]u21{0x03A0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03C1, .upper = &[_]u21{0x03A1}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03C2, .upper = &[_]u21{0x03A3}, .lower = &[_]u21{}, .fold = &[_]u21{0x03C3} },
+    .{ .codepoint = 0x03C3, .upper = &[_]u21{0x03A3}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03C4, .upper = &[_]u21{0x03A4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03C5, .upper = &[_]u21{0x03A5}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03C6, .upper = &[_]u21{0x03A6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03C7, .upper = &[_]u21{0x03A7}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03C8, .upper = &[_]u21{0x03A8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03C9, .upper = &[_]u21{0x03A9}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03CA, .upper = &[_]u21{0x03AA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03CB, .upper = &[_]u21{0x03AB}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03CC, .upper = &[_]u21{0x038C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03CD, .upper = &[_]u21{0x038E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03CE, .upper = &[_]u21{0x038F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03CF, .upper = &[_]u21{}, .lower = &[_]u21{0x03D7}, .fold = &[_]u21{0x03D7} },
+    .{ .codepoint = 0x03D0, .upper = &[_]u21{0x0392}, .lower = &[_]u21{}, .fold = &[_]u21{0x03B2} },
+    .{ .codepoint = 0x03D1, .upper = &[_]u21{0x0398}, .lower = &[_]u21{}, .fold = &[_]u21{0x03B8} },
+    .{ .codepoint = 0x03D5, .upper = &[_]u21{0x03A6}, .lower = &[_]u21{}, .fold = &[_]u21{0x03C6} },
+    .{ .codepoint = 0x03D6, .upper = &[_]u21{0x03A0}, .lower = &[_]u21{}, .fold = &[_]u21{0x03C0} },
+    .{ .codepoint = 0x03D7, .upper = &[_]u21{0x03CF}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03D8, .upper = &[_]u21{}, .lower = &[_]u21{0x03D9}, .fold = &[_]u21{0x03D9} },
+    .{ .codepoint = 0x03D9, .upper = &[_]u21{0x03D8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03DA, .upper = &[_]u21{}, .lower = &[_]u21{0x03DB}, .fold = &[_]u21{0x03DB} },
+    .{ .codepoint = 0x03DB, .upper = &[_]u21{0x03DA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03DC, .upper = &[_]u21{}, .lower = &[_]u21{0x03DD}, .fold = &[_]u21{0x03DD} },
+    .{ .codepoint = 0x03DD, .upper = &[_]u21{0x03DC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03DE, .upper = &[_]u21{}, .lower = &[_]u21{0x03DF}, .fold = &[_]u21{0x03DF} },
+    .{ .codepoint = 0x03DF, .upper = &[_]u21{0x03DE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03E0, .upper = &[_]u21{}, .lower = &[_]u21{0x03E1}, .fold = &[_]u21{0x03E1} },
+    .{ .codepoint = 0x03E1, .upper = &[_]u21{0x03E0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03E2, .upper = &[_]u21{}, .lower = &[_]u21{0x03E3}, .fold = &[_]u21{0x03E3} },
+    .{ .codepoint = 0x03E3, .upper = &[_]u21{0x03E2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03E4, .upper = &[_]u21{}, .lower = &[_]u21{0x03E5}, .fold = &[_]u21{0x03E5} },
+    .{ .codepoint = 0x03E5, .upper = &[_]u21{0x03E4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03E6, .upper = &[_]u21{}, .lower = &[_]u21{0x03E7}, .fold = &[_]u21{0x03E7} },
+    .{ .codepoint = 0x03E7, .upper = &[_]u21{0x03E6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03E8, .upper = &[_]u21{}, .lower = &[_]u21{0x03E9}, .fold = &[_]u21{0x03E9} },
+    .{ .codepoint = 0x03E9, .upper = &[_]u21{0x03E8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03EA, .upper = &[_]u21{}, .lower = &[_]u21{0x03EB}, .fold = &[_]u21{0x03EB} },
+    .{ .codepoint = 0x03EB, .upper = &[_]u21{0x03EA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03EC, .upper = &[_]u21{}, .lower = &[_]u21{0x03ED}, .fold = &[_]u21{0x03ED} },
+    .{ .codepoint = 0x03ED, .upper = &[_]u21{0x03EC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03EE, .upper = &[_]u21{}, .lower = &[_]u21{0x03EF}, .fold = &[_]u21{0x03EF} },
+    .{ .codepoint = 0x03EF, .upper = &[_]u21{0x03EE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03F0, .upper = &[_]u21{0x039A}, .lower = &[_]u21{}, .fold = &[_]u21{0x03BA} },
+    .{ .codepoint = 0x03F1, .upper = &[_]u21{0x03A1}, .lower = &[_]u21{}, .fold = &[_]u21{0x03C1} },
+    .{ .codepoint = 0x03F2, .upper = &[_]u21{0x03F9}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03F3, .upper = &[_]u21{0x037F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03F4, .upper = &[_]u21{}, .lower = &[_]u21{0x03B8}, .fold = &[_]u21{0x03B8} },
+    .{ .codepoint = 0x03F5, .upper = &[_]u21{0x0395}, .lower = &[_]u21{}, .fold = &[_]u21{0x03B5} },
+    .{ .codepoint = 0x03F7, .upper = &[_]u21{}, .lower = &[_]u21{0x03F8}, .fold = &[_]u21{0x03F8} },
+    .{ .codepoint = 0x03F8, .upper = &[_]u21{0x03F7}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03F9, .upper = &[_]u21{}, .lower = &[_]u21{0x03F2}, .fold = &[_]u21{0x03F2} },
+    .{ .codepoint = 0x03FA, .upper = &[_]u21{}, .lower = &[_]u21{0x03FB}, .fold = &[_]u21{0x03FB} },
+    .{ .codepoint = 0x03FB, .upper = &[_]u21{0x03FA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x03FD, .upper = &[_]u21{}, .lower = &[_]u21{0x037B}, .fold = &[_]u21{0x037B} },
+    .{ .codepoint = 0x03FE, .upper = &[_]u21{}, .lower = &[_]u21{0x037C}, .fold = &[_]u21{0x037C} },
+    .{ .codepoint = 0x03FF, .upper = &[_]u21{}, .lower = &[_]u21{0x037D}, .fold = &[_]u21{0x037D} },
+    .{ .codepoint = 0x0400, .upper = &[_]u21{}, .lower = &[_]u21{0x0450}, .fold = &[_]u21{0x0450} },
+    .{ .codepoint = 0x0401, .upper = &[_]u21{}, .lower = &[_]u21{0x0451}, .fold = &[_]u21{0x0451} },
+    .{ .codepoint = 0x0402, .upper = &[_]u21{}, .lower = &[_]u21{0x0452}, .fold = &[_]u21{0x0452} },
+    .{ .codepoint = 0x0403, .upper = &[_]u21{}, .lower = &[_]u21{0x0453}, .fold = &[_]u21{0x0453} },
+    .{ .codepoint = 0x0404, .upper = &[_]u21{}, .lower = &[_]u21{0x0454}, .fold = &[_]u21{0x0454} },
+    .{ .codepoint = 0x0405, .upper = &[_]u21{}, .lower = &[_]u21{0x0455}, .fold = &[_]u21{0x0455} },
+    .{ .codepoint = 0x0406, .upper = &[_]u21{}, .lower = &[_]u21{0x0456}, .fold = &[_]u21{0x0456} },
+    .{ .codepoint = 0x0407, .upper = &[_]u21{}, .lower = &[_]u21{0x0457}, .fold = &[_]u21{0x0457} },
+    .{ .codepoint = 0x0408, .upper = &[_]u21{}, .lower = &[_]u21{0x0458}, .fold = &[_]u21{0x0458} },
+    .{ .codepoint = 0x0409, .upper = &[_]u21{}, .lower = &[_]u21{0x0459}, .fold = &[_]u21{0x0459} },
+    .{ .codepoint = 0x040A, .upper = &[_]u21{}, .lower = &[_]u21{0x045A}, .fold = &[_]u21{0x045A} },
+    .{ .codepoint = 0x040B, .upper = &[_]u21{}, .lower = &[_]u21{0x045B}, .fold = &[_]u21{0x045B} },
+    .{ .codepoint = 0x040C, .upper = &[_]u21{}, .lower = &[_]u21{0x045C}, .fold = &[_]u21{0x045C} },
+    .{ .codepoint = 0x040D, .upper = &[_]u21{}, .lower = &[_]u21{0x045D}, .fold = &[_]u21{0x045D} },
+    .{ .codepoint = 0x040E, .upper = &[_]u21{}, .lower = &[_]u21{0x045E}, .fold = &[_]u21{0x045E} },
+    .{ .codepoint = 0x040F, .upper = &[_]u21{}, .lower = &[_]u21{0x045F}, .fold = &[_]u21{0x045F} },
+    .{ .codepoint = 0x0410, .upper = &[_]u21{}, .lower = &[_]u21{0x0430}, .fold = &[_]u21{0x0430} },
+    .{ .codepoint = 0x0411, .upper = &[_]u21{}, .lower = &[_]u21{0x0431}, .fold = &[_]u21{0x0431} },
+    .{ .codepoint = 0x0412, .upper = &[_]u21{}, .lower = &[_]u21{0x0432}, .fold = &[_]u21{0x0432} },
+    .{ .codepoint = 0x0413, .upper = &[_]u21{}, .lower = &[_]u21{0x0433}, .fold = &[_]u21{0x0433} },
+    .{ .codepoint = 0x0414, .upper = &[_]u21{}, .lower = &[_]u21{0x0434}, .fold = &[_]u21{0x0434} },
+    .{ .codepoint = 0x0415, .upper = &[_]u21{}, .lower = &[_]u21{0x0435}, .fold = &[_]u21{0x0435} },
+    .{ .codepoint = 0x0416, .upper = &[_]u21{}, .lower = &[_]u21{0x0436}, .fold = &[_]u21{0x0436} },
+    .{ .codepoint = 0x0417, .upper = &[_]u21{}, .lower = &[_]u21{0x0437}, .fold = &[_]u21{0x0437} },
+    .{ .codepoint = 0x0418, .upper = &[_]u21{}, .lower = &[_]u21{0x0438}, .fold = &[_]u21{0x0438} },
+    .{ .codepoint = 0x0419, .upper = &[_]u21{}, .lower = &[_]u21{0x0439}, .fold = &[_]u21{0x0439} },
+    .{ .codepoint = 0x041A, .upper = &[_]u21{}, .lower = &[_]u21{0x043A}, .fold = &[_]u21{0x043A} },
+    .{ .codepoint = 0x041B, .upper = &[_]u21{}, .lower = &[_]u21{0x043B}, .fold = &[_]u21{0x043B} },
+    .{ .codepoint = 0x041C, .upper = &[_]u21{}, .lower = &[_]u21{0x043C}, .fold = &[_]u21{0x043C} },
+    .{ .codepoint = 0x041D, .upper = &[_]u21{}, .lower = &[_]u21{0x043D}, .fold = &[_]u21{0x043D} },
+    .{ .codepoint = 0x041E, .upper = &[_]u21{}, .lower = &[_]u21{0x043E}, .fold = &[_]u21{0x043E} },
+    .{ .codepoint = 0x041F, .upper = &[_]u21{}, .lower = &[_]u21{0x043F}, .fold = &[_]u21{0x043F} },
+    .{ .codepoint = 0x0420, .upper = &[_]u21{}, .lower = &[_]u21{0x0440}, .fold = &[_]u21{0x0440} },
+    .{ .codepoint = 0x0421, .upper = &[_]u21{}, .lower = &[_]u21{0x0441}, .fold = &[_]u21{0x0441} },
+    .{ .codepoint = 0x0422, .upper = &[_]u21{}, .lower = &[_]u21{0x0442}, .fold = &[_]u21{0x0442} },
+    .{ .codepoint = 0x0423, .upper = &[_]u21{}, .lower = &[_]u21{0x0443}, .fold = &[_]u21{0x0443} },
+    .{ .codepoint = 0x0424, .upper = &[_]u21{}, .lower = &[_]u21{0x0444}, .fold = &[_]u21{0x0444} },
+    .{ .codepoint = 0x0425, .upper = &[_]u21{}, .lower = &[_]u21{0x0445}, .fold = &[_]u21{0x0445} },
+    .{ .codepoint = 0x0426, .upper = &[_]u21{}, .lower = &[_]u21{0x0446}, .fold = &[_]u21{0x0446} },
+    .{ .codepoint = 0x0427, .upper = &[_]u21{}, .lower = &[_]u21{0x0447}, .fold = &[_]u21{0x0447} },
+    .{ .codepoint = 0x0428, .upper = &[_]u21{}, .lower = &[_]u21{0x0448}, .fold = &[_]u21{0x0448} },
+    .{ .codepoint = 0x0429, .upper = &[_]u21{}, .lower = &[_]u21{0x0449}, .fold = &[_]u21{0x0449} },
+    .{ .codepoint = 0x042A, .upper = &[_]u21{}, .lower = &[_]u21{0x044A}, .fold = &[_]u21{0x044A} },
+    .{ .codepoint = 0x042B, .upper = &[_]u21{}, .lower = &[_]u21{0x044B}, .fold = &[_]u21{0x044B} },
+    .{ .codepoint = 0x042C, .upper = &[_]u21{}, .lower = &[_]u21{0x044C}, .fold = &[_]u21{0x044C} },
+    .{ .codepoint = 0x042D, .upper = &[_]u21{}, .lower = &[_]u21{0x044D}, .fold = &[_]u21{0x044D} },
+    .{ .codepoint = 0x042E, .upper = &[_]u21{}, .lower = &[_]u21{0x044E}, .fold = &[_]u21{0x044E} },
+    .{ .codepoint = 0x042F, .upper = &[_]u21{}, .lower = &[_]u21{0x044F}, .fold = &[_]u21{0x044F} },
+    .{ .codepoint = 0x0430, .upper = &[_]u21{0x0410}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0431, .upper = &[_]u21{0x0411}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0432, .upper = &[_]u21{0x0412}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0433, .upper = &[_]u21{0x0413}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0434, .upper = &[_]u21{0x0414}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0435, .upper = &[_]u21{0x0415}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0436, .upper = &[_]u21{0x0416}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0437, .upper = &[_]u21{0x0417}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0438, .upper = &[_]u21{0x0418}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0439, .upper = &[_]u21{0x0419}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x043A, .upper = &[_]u21{0x041A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x043B, .upper = &[_]u21{0x041B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x043C, .upper = &[_]u21{0x041C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x043D, .upper = &[_]u21{0x041D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x043E, .upper = &[_]u21{0x041E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x043F, .upper = &[_]u21{0x041F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0440, .upper = &[_]u21{0x0420}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0441, .upper = &[_]u21{0x0421}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0442, .upper = &[_]u21{0x0422}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0443, .upper = &[_]u21{0x0423}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0444, .upper = &[_]u21{0x0424}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0445, .upper = &[_]u21{0x0425}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0446, .upper = &[_]u21{0x0426}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0447, .upper = &[_]u21{0x0427}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0448, .upper = &[_]u21{0x0428}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0449, .upper = &[_]u21{0x0429}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x044A, .upper = &[_]u21{0x042A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x044B, .upper = &[_]u21{0x042B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x044C, .upper = &[_]u21{0x042C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x044D, .upper = &[_]u21{0x042D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x044E, .upper = &[_]u21{0x042E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x044F, .upper = &[_]u21{0x042F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0450, .upper = &[_]u21{0x0400}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0451, .upper = &[_]u21{0x0401}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0452, .upper = &[_]u21{0x0402}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0453, .upper = &[_]u21{0x0403}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0454, .upper = &[_]u21{0x0404}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0455, .upper = &[_]u21{0x0405}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0456, .upper = &[_]u21{0x0406}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0457, .upper = &[_]u21{0x0407}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0458, .upper = &[_]u21{0x0408}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0459, .upper = &[_]u21{0x0409}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x045A, .upper = &[_]u21{0x040A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x045B, .upper = &[_]u21{0x040B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x045C, .upper = &[_]u21{0x040C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x045D, .upper = &[_]u21{0x040D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x045E, .upper = &[_]u21{0x040E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x045F, .upper = &[_]u21{0x040F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0460, .upper = &[_]u21{}, .lower = &[_]u21{0x0461}, .fold = &[_]u21{0x0461} },
+    .{ .codepoint = 0x0461, .upper = &[_]u21{0x0460}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0462, .upper = &[_]u21{}, .lower = &[_]u21{0x0463}, .fold = &[_]u21{0x0463} },
+    .{ .codepoint = 0x0463, .upper = &[_]u21{0x0462}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0464, .upper = &[_]u21{}, .lower = &[_]u21{0x0465}, .fold = &[_]u21{0x0465} },
+    .{ .codepoint = 0x0465, .upper = &[_]u21{0x0464}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0466, .upper = &[_]u21{}, .lower = &[_]u21{0x0467}, .fold = &[_]u21{0x0467} },
+    .{ .codepoint = 0x0467, .upper = &[_]u21{0x0466}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0468, .upper = &[_]u21{}, .lower = &[_]u21{0x0469}, .fold = &[_]u21{0x0469} },
+    .{ .codepoint = 0x0469, .upper = &[_]u21{0x0468}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x046A, .upper = &[_]u21{}, .lower = &[_]u21{0x046B}, .fold = &[_]u21{0x046B} },
+    .{ .codepoint = 0x046B, .upper = &[_]u21{0x046A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x046C, .upper = &[_]u21{}, .lower = &[_]u21{0x046D}, .fold = &[_]u21{0x046D} },
+    .{ .codepoint = 0x046D, .upper = &[_]u21{0x046C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x046E, .upper = &[_]u21{}, .lower = &[_]u21{0x046F}, .fold = &[_]u21{0x046F} },
+    .{ .codepoint = 0x046F, .upper = &[_]u21{0x046E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0470, .upper = &[_]u21{}, .lower = &[_]u21{0x0471}, .fold = &[_]u21{0x0471} },
+    .{ .codepoint = 0x0471, .upper = &[_]u21{0x0470}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0472, .upper = &[_]u21{}, .lower = &[_]u21{0x0473}, .fold = &[_]u21{0x0473} },
+    .{ .codepoint = 0x0473, .upper = &[_]u21{0x0472}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0474, .upper = &[_]u21{}, .lower = &[_]u21{0x0475}, .fold = &[_]u21{0x0475} },
+    .{ .codepoint = 0x0475, .upper = &[_]u21{0x0474}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0476, .upper = &[_]u21{}, .lower = &[_]u21{0x0477}, .fold = &[_]u21{0x0477} },
+    .{ .codepoint = 0x0477, .upper = &[_]u21{0x0476}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0478, .upper = &[_]u21{}, .lower = &[_]u21{0x0479}, .fold = &[_]u21{0x0479} },
+    .{ .codepoint = 0x0479, .upper = &[_]u21{0x0478}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x047A, .upper = &[_]u21{}, .lower = &[_]u21{0x047B}, .fold = &[_]u21{0x047B} },
+    .{ .codepoint = 0x047B, .upper = &[_]u21{0x047A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x047C, .upper = &[_]u21{}, .lower = &[_]u21{0x047D}, .fold = &[_]u21{0x047D} },
+    .{ .codepoint = 0x047D, .upper = &[_]u21{0x047C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x047E, .upper = &[_]u21{}, .lower = &[_]u21{0x047F}, .fold = &[_]u21{0x047F} },
+    .{ .codepoint = 0x047F, .upper = &[_]u21{0x047E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0480, .upper = &[_]u21{}, .lower = &[_]u21{0x0481}, .fold = &[_]u21{0x0481} },
+    .{ .codepoint = 0x0481, .upper = &[_]u21{0x0480}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x048A, .upper = &[_]u21{}, .lower = &[_]u21{0x048B}, .fold = &[_]u21{0x048B} },
+    .{ .codepoint = 0x048B, .upper = &[_]u21{0x048A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x048C, .upper = &[_]u21{}, .lower = &[_]u21{0x048D}, .fold = &[_]u21{0x048D} },
+    .{ .codepoint = 0x048D, .upper = &[_]u21{0x048C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x048E, .upper = &[_]u21{}, .lower = &[_]u21{0x048F}, .fold = &[_]u21{0x048F} },
+    .{ .codepoint = 0x048F, .upper = &[_]u21{0x048E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0490, .upper = &[_]u21{}, .lower = &[_]u21{0x0491}, .fold = &[_]u21{0x0491} },
+    .{ .codepoint = 0x0491, .upper = &[_]u21{0x0490}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0492, .upper = &[_]u21{}, .lower = &[_]u21{0x0493}, .fold = &[_]u21{0x0493} },
+    .{ .codepoint = 0x0493, .upper = &[_]u21{0x0492}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0494, .upper = &[_]u21{}, .lower = &[_]u21{0x0495}, .fold = &[_]u21{0x0495} },
+    .{ .codepoint = 0x0495, .upper = &[_]u21{0x0494}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0496, .upper = &[_]u21{}, .lower = &[_]u21{0x0497}, .fold = &[_]u21{0x0497} },
+    .{ .codepoint = 0x0497, .upper = &[_]u21{0x0496}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0498, .upper = &[_]u21{}, .lower = &[_]u21{0x0499}, .fold = &[_]u21{0x0499} },
+    .{ .codepoint = 0x0499, .upper = &[_]u21{0x0498}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x049A, .upper = &[_]u21{}, .lower = &[_]u21{0x049B}, .fold = &[_]u21{0x049B} },
+    .{ .codepoint = 0x049B, .upper = &[_]u21{0x049A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x049C, .upper = &[_]u21{}, .lower = &[_]u21{0x049D}, .fold = &[_]u21{0x049D} },
+    .{ .codepoint = 0x049D, .upper = &[_]u21{0x049C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x049E, .upper = &[_]u21{}, .lower = &[_]u21{0x049F}, .fold = &[_]u21{0x049F} },
+    .{ .codepoint = 0x049F, .upper = &[_]u21{0x049E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04A0, .upper = &[_]u21{}, .lower = &[_]u21{0x04A1}, .fold = &[_]u21{0x04A1} },
+    .{ .codepoint = 0x04A1, .upper = &[_]u21{0x04A0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04A2, .upper = &[_]u21{}, .lower = &[_]u21{0x04A3}, .fold = &[_]u21{0x04A3} },
+    .{ .codepoint = 0x04A3, .upper = &[_]u21{0x04A2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04A4, .upper = &[_]u21{}, .lower = &[_]u21{0x04A5}, .fold = &[_]u21{0x04A5} },
+    .{ .codepoint = 0x04A5, .upper = &[_]u21{0x04A4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04A6, .upper = &[_]u21{}, .lower = &[_]u21{0x04A7}, .fold = &[_]u21{0x04A7} },
+    .{ .codepoint = 0x04A7, .upper = &[_]u21{0x04A6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04A8, .upper = &[_]u21{}, .lower = &[_]u21{0x04A9}, .fold = &[_]u21{0x04A9} },
+    .{ .codepoint = 0x04A9, .upper = &[_]u21{0x04A8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04AA, .upper = &[_]u21{}, .lower = &[_]u21{0x04AB}, .fold = &[_]u21{0x04AB} },
+    .{ .codepoint = 0x04AB, .upper = &[_]u21{0x04AA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04AC, .upper = &[_]u21{}, .lower = &[_]u21{0x04AD}, .fold = &[_]u21{0x04AD} },
+    .{ .codepoint = 0x04AD, .upper = &[_]u21{0x04AC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04AE, .upper = &[_]u21{}, .lower = &[_]u21{0x04AF}, .fold = &[_]u21{0x04AF} },
+    .{ .codepoint = 0x04AF, .upper = &[_]u21{0x04AE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04B0, .upper = &[_]u21{}, .lower = &[_]u21{0x04B1}, .fold = &[_]u21{0x04B1} },
+    .{ .codepoint = 0x04B1, .upper = &[_]u21{0x04B0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04B2, .upper = &[_]u21{}, .lower = &[_]u21{0x04B3}, .fold = &[_]u21{0x04B3} },
+    .{ .codepoint = 0x04B3, .upper = &[_]u21{0x04B2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04B4, .upper = &[_]u21{}, .lower = &[_]u21{0x04B5}, .fold = &[_]u21{0x04B5} },
+    .{ .codepoint = 0x04B5, .upper = &[_]u21{0x04B4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04B6, .upper = &[_]u21{}, .lower = &[_]u21{0x04B7}, .fold = &[_]u21{0x04B7} },
+    .{ .codepoint = 0x04B7, .upper = &[_]u21{0x04B6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04B8, .upper = &[_]u21{}, .lower = &[_]u21{0x04B9}, .fold = &[_]u21{0x04B9} },
+    .{ .codepoint = 0x04B9, .upper = &[_]u21{0x04B8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04BA, .upper = &[_]u21{}, .lower = &[_]u21{0x04BB}, .fold = &[_]u21{0x04BB} },
+    .{ .codepoint = 0x04BB, .upper = &[_]u21{0x04BA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04BC, .upper = &[_]u21{}, .lower = &[_]u21{0x04BD}, .fold = &[_]u21{0x04BD} },
+    .{ .codepoint = 0x04BD, .upper = &[_]u21{0x04BC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04BE, .upper = &[_]u21{}, .lower = &[_]u21{0x04BF}, .fold = &[_]u21{0x04BF} },
+    .{ .codepoint = 0x04BF, .upper = &[_]u21{0x04BE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04C0, .upper = &[_]u21{}, .lower = &[_]u21{0x04CF}, .fold = &[_]u21{0x04CF} },
+    .{ .codepoint = 0x04C1, .upper = &[_]u21{}, .lower = &[_]u21{0x04C2}, .fold = &[_]u21{0x04C2} },
+    .{ .codepoint = 0x04C2, .upper = &[_]u21{0x04C1}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04C3, .upper = &[_]u21{}, .lower = &[_]u21{0x04C4}, .fold = &[_]u21{0x04C4} },
+    .{ .codepoint = 0x04C4, .upper = &[_]u21{0x04C3}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04C5, .upper = &[_]u21{}, .lower = &[_]u21{0x04C6}, .fold = &[_]u21{0x04C6} },
+    .{ .codepoint = 0x04C6, .upper = &[_]u21{0x04C5}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04C7, .upper = &[_]u21{}, .lower = &[_]u21{0x04C8}, .fold = &[_]u21{0x04C8} },
+    .{ .codepoint = 0x04C8, .upper = &[_]u21{0x04C7}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04C9, .upper = &[_]u21{}, .lower = &[_]u21{0x04CA}, .fold = &[_]u21{0x04CA} },
+    .{ .codepoint = 0x04CA, .upper = &[_]u21{0x04C9}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04CB, .upper = &[_]u21{}, .lower = &[_]u21{0x04CC}, .fold = &[_]u21{0x04CC} },
+    .{ .codepoint = 0x04CC, .upper = &[_]u21{0x04CB}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04CD, .upper = &[_]u21{}, .lower = &[_]u21{0x04CE}, .fold = &[_]u21{0x04CE} },
+    .{ .codepoint = 0x04CE, .upper = &[_]u21{0x04CD}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04CF, .upper = &[_]u21{0x04C0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04D0, .upper = &[_]u21{}, .lower = &[_]u21{0x04D1}, .fold = &[_]u21{0x04D1} },
+    .{ .codepoint = 0x04D1, .upper = &[_]u21{0x04D0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04D2, .upper = &[_]u21{}, .lower = &[_]u21{0x04D3}, .fold = &[_]u21{0x04D3} },
+    .{ .codepoint = 0x04D3, .upper = &[_]u21{0x04D2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04D4, .upper = &[_]u21{}, .lower = &[_]u21{0x04D5}, .fold = &[_]u21{0x04D5} },
+    .{ .codepoint = 0x04D5, .upper = &[_]u21{0x04D4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04D6, .upper = &[_]u21{}, .lower = &[_]u21{0x04D7}, .fold = &[_]u21{0x04D7} },
+    .{ .codepoint = 0x04D7, .upper = &[_]u21{0x04D6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04D8, .upper = &[_]u21{}, .lower = &[_]u21{0x04D9}, .fold = &[_]u21{0x04D9} },
+    .{ .codepoint = 0x04D9, .upper = &[_]u21{0x04D8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04DA, .upper = &[_]u21{}, .lower = &[_]u21{0x04DB}, .fold = &[_]u21{0x04DB} },
+    .{ .codepoint = 0x04DB, .upper = &[_]u21{0x04DA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04DC, .upper = &[_]u21{}, .lower = &[_]u21{0x04DD}, .fold = &[_]u21{0x04DD} },
+    .{ .codepoint = 0x04DD, .upper = &[_]u21{0x04DC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04DE, .upper = &[_]u21{}, .lower = &[_]u21{0x04DF}, .fold = &[_]u21{0x04DF} },
+    .{ .codepoint = 0x04DF, .upper = &[_]u21{0x04DE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04E0, .upper = &[_]u21{}, .lower = &[_]u21{0x04E1}, .fold = &[_]u21{0x04E1} },
+    .{ .codepoint = 0x04E1, .upper = &[_]u21{0x04E0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04E2, .upper = &[_]u21{}, .lower = &[_]u21{0x04E3}, .fold = &[_]u21{0x04E3} },
+    .{ .codepoint = 0x04E3, .upper = &[_]u21{0x04E2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04E4, .upper = &[_]u21{}, .lower = &[_]u21{0x04E5}, .fold = &[_]u21{0x04E5} },
+    .{ .codepoint = 0x04E5, .upper = &[_]u21{0x04E4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04E6, .upper = &[_]u21{}, .lower = &[_]u21{0x04E7}, .fold = &[_]u21{0x04E7} },
+    .{ .codepoint = 0x04E7, .upper = &[_]u21{0x04E6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04E8, .upper = &[_]u21{}, .lower = &[_]u21{0x04E9}, .fold = &[_]u21{0x04E9} },
+    .{ .codepoint = 0x04E9, .upper = &[_]u21{0x04E8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04EA, .upper = &[_]u21{}, .lower = &[_]u21{0x04EB}, .fold = &[_]u21{0x04EB} },
+    .{ .codepoint = 0x04EB, .upper = &[_]u21{0x04EA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04EC, .upper = &[_]u21{}, .lower = &[_]u21{0x04ED}, .fold = &[_]u21{0x04ED} },
+    .{ .codepoint = 0x04ED, .upper = &[_]u21{0x04EC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04EE, .upper = &[_]u21{}, .lower = &[_]u21{0x04EF}, .fold = &[_]u21{0x04EF} },
+    .{ .codepoint = 0x04EF, .upper = &[_]u21{0x04EE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04F0, .upper = &[_]u21{}, .lower = &[_]u21{0x04F1}, .fold = &[_]u21{0x04F1} },
+    .{ .codepoint = 0x04F1, .upper = &[_]u21{0x04F0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04F2, .upper = &[_]u21{}, .lower = &[_]u21{0x04F3}, .fold = &[_]u21{0x04F3} },
+    .{ .codepoint = 0x04F3, .upper = &[_]u21{0x04F2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04F4, .upper = &[_]u21{}, .lower = &[_]u21{0x04F5}, .fold = &[_]u21{0x04F5} },
+    .{ .codepoint = 0x04F5, .upper = &[_]u21{0x04F4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04F6, .upper = &[_]u21{}, .lower = &[_]u21{0x04F7}, .fold = &[_]u21{0x04F7} },
+    .{ .codepoint = 0x04F7, .upper = &[_]u21{0x04F6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04F8, .upper = &[_]u21{}, .lower = &[_]u21{0x04F9}, .fold = &[_]u21{0x04F9} },
+    .{ .codepoint = 0x04F9, .upper = &[_]u21{0x04F8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04FA, .upper = &[_]u21{}, .lower = &[_]u21{0x04FB}, .fold = &[_]u21{0x04FB} },
+    .{ .codepoint = 0x04FB, .upper = &[_]u21{0x04FA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04FC, .upper = &[_]u21{}, .lower = &[_]u21{0x04FD}, .fold = &[_]u21{0x04FD} },
+    .{ .codepoint = 0x04FD, .upper = &[_]u21{0x04FC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x04FE, .upper = &[_]u21{}, .lower = &[_]u21{0x04FF}, .fold = &[_]u21{0x04FF} },
+    .{ .codepoint = 0x04FF, .upper = &[_]u21{0x04FE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0500, .upper = &[_]u21{}, .lower = &[_]u21{0x0501}, .fold = &[_]u21{0x0501} },
+    .{ .codepoint = 0x0501, .upper = &[_]u21{0x0500}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0502, .upper = &[_]u21{}, .lower = &[_]u21{0x0503}, .fold = &[_]u21{0x0503} },
+    .{ .codepoint = 0x0503, .upper = &[_]u21{0x0502}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0504, .upper = &[_]u21{}, .lower = &[_]u21{0x0505}, .fold = &[_]u21{0x0505} },
+    .{ .codepoint = 0x0505, .upper = &[_]u21{0x0504}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0506, .upper = &[_]u21{}, .lower = &[_]u21{0x0507}, .fold = &[_]u21{0x0507} },
+    .{ .codepoint = 0x0507, .upper = &[_]u21{0x0506}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0508, .upper = &[_]u21{}, .lower = &[_]u21{0x0509}, .fold = &[_]u21{0x0509} },
+    .{ .codepoint = 0x0509, .upper = &[_]u21{0x0508}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x050A, .upper = &[_]u21{}, .lower = &[_]u21{0x050B}, .fold = &[_]u21{0x050B} },
+    .{ .codepoint = 0x050B, .upper = &[_]u21{0x050A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x050C, .upper = &[_]u21{}, .lower = &[_]u21{0x050D}, .fold = &[_]u21{0x050D} },
+    .{ .codepoint = 0x050D, .upper = &[_]u21{0x050C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x050E, .upper = &[_]u21{}, .lower = &[_]u21{0x050F}, .fold = &[_]u21{0x050F} },
+    .{ .codepoint = 0x050F, .upper = &[_]u21{0x050E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0510, .upper = &[_]u21{}, .lower = &[_]u21{0x0511}, .fold = &[_]u21{0x0511} },
+    .{ .codepoint = 0x0511, .upper = &[_]u21{0x0510}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0512, .upper = &[_]u21{}, .lower = &[_]u21{0x0513}, .fold = &[_]u21{0x0513} },
+    .{ .codepoint = 0x0513, .upper = &[_]u21{0x0512}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0514, .upper = &[_]u21{}, .lower = &[_]u21{0x0515}, .fold = &[_]u21{0x0515} },
+    .{ .codepoint = 0x0515, .upper = &[_]u21{0x0514}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0516, .upper = &[_]u21{}, .lower = &[_]u21{0x0517}, .fold = &[_]u21{0x0517} },
+    .{ .codepoint = 0x0517, .upper = &[_]u21{0x0516}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0518, .upper = &[_]u21{}, .lower = &[_]u21{0x0519}, .fold = &[_]u21{0x0519} },
+    .{ .codepoint = 0x0519, .upper = &[_]u21{0x0518}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x051A, .upper = &[_]u21{}, .lower = &[_]u21{0x051B}, .fold = &[_]u21{0x051B} },
+    .{ .codepoint = 0x051B, .upper = &[_]u21{0x051A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x051C, .upper = &[_]u21{}, .lower = &[_]u21{0x051D}, .fold = &[_]u21{0x051D} },
+    .{ .codepoint = 0x051D, .upper = &[_]u21{0x051C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x051E, .upper = &[_]u21{}, .lower = &[_]u21{0x051F}, .fold = &[_]u21{0x051F} },
+    .{ .codepoint = 0x051F, .upper = &[_]u21{0x051E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0520, .upper = &[_]u21{}, .lower = &[_]u21{0x0521}, .fold = &[_]u21{0x0521} },
+    .{ .codepoint = 0x0521, .upper = &[_]u21{0x0520}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0522, .upper = &[_]u21{}, .lower = &[_]u21{0x0523}, .fold = &[_]u21{0x0523} },
+    .{ .codepoint = 0x0523, .upper = &[_]u21{0x0522}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0524, .upper = &[_]u21{}, .lower = &[_]u21{0x0525}, .fold = &[_]u21{0x0525} },
+    .{ .codepoint = 0x0525, .upper = &[_]u21{0x0524}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0526, .upper = &[_]u21{}, .lower = &[_]u21{0x0527}, .fold = &[_]u21{0x0527} },
+    .{ .codepoint = 0x0527, .upper = &[_]u21{0x0526}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0528, .upper = &[_]u21{}, .lower = &[_]u21{0x0529}, .fold = &[_]u21{0x0529} },
+    .{ .codepoint = 0x0529, .upper = &[_]u21{0x0528}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x052A, .upper = &[_]u21{}, .lower = &[_]u21{0x052B}, .fold = &[_]u21{0x052B} },
+    .{ .codepoint = 0x052B, .upper = &[_]u21{0x052A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x052C, .upper = &[_]u21{}, .lower = &[_]u21{0x052D}, .fold = &[_]u21{0x052D} },
+    .{ .codepoint = 0x052D, .upper = &[_]u21{0x052C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x052E, .upper = &[_]u21{}, .lower = &[_]u21{0x052F}, .fold = &[_]u21{0x052F} },
+    .{ .codepoint = 0x052F, .upper = &[_]u21{0x052E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0531, .upper = &[_]u21{}, .lower = &[_]u21{0x0561}, .fold = &[_]u21{0x0561} },
+    .{ .codepoint = 0x0532, .upper = &[_]u21{}, .lower = &[_]u21{0x0562}, .fold = &[_]u21{0x0562} },
+    .{ .codepoint = 0x0533, .upper = &[_]u21{}, .lower = &[_]u21{0x0563}, .fold = &[_]u21{0x0563} },
+    .{ .codepoint = 0x0534, .upper = &[_]u21{}, .lower = &[_]u21{0x0564}, .fold = &[_]u21{0x0564} },
+    .{ .codepoint = 0x0535, .upper = &[_]u21{}, .lower = &[_]u21{0x0565}, .fold = &[_]u21{0x0565} },
+    .{ .codepoint = 0x0536, .upper = &[_]u21{}, .lower = &[_]u21{0x0566}, .fold = &[_]u21{0x0566} },
+    .{ .codepoint = 0x0537, .upper = &[_]u21{}, .lower = &[_]u21{0x0567}, .fold = &[_]u21{0x0567} },
+    .{ .codepoint = 0x0538, .upper = &[_]u21{}, .lower = &[_]u21{0x0568}, .fold = &[_]u21{0x0568} },
+    .{ .codepoint = 0x0539, .upper = &[_]u21{}, .lower = &[_]u21{0x0569}, .fold = &[_]u21{0x0569} },
+    .{ .codepoint = 0x053A, .upper = &[_]u21{}, .lower = &[_]u21{0x056A}, .fold = &[_]u21{0x056A} },
+    .{ .codepoint = 0x053B, .upper = &[_]u21{}, .lower = &[_]u21{0x056B}, .fold = &[_]u21{0x056B} },
+    .{ .codepoint = 0x053C, .upper = &[_]u21{}, .lower = &[_]u21{0x056C}, .fold = &[_]u21{0x056C} },
+    .{ .codepoint = 0x053D, .upper = &[_]u21{}, .lower = &[_]u21{0x056D}, .fold = &[_]u21{0x056D} },
+    .{ .codepoint = 0x053E, .upper = &[_]u21{}, .lower = &[_]u21{0x056E}, .fold = &[_]u21{0x056E} },
+    .{ .codepoint = 0x053F, .upper = &[_]u21{}, .lower = &[_]u21{0x056F}, .fold = &[_]u21{0x056F} },
+    .{ .codepoint = 0x0540, .upper = &[_]u21{}, .lower = &[_]u21{0x0570}, .fold = &[_]u21{0x0570} },
+    .{ .codepoint = 0x0541, .upper = &[_]u21{}, .lower = &[_]u21{0x0571}, .fold = &[_]u21{0x0571} },
+    .{ .codepoint = 0x0542, .upper = &[_]u21{}, .lower = &[_]u21{0x0572}, .fold = &[_]u21{0x0572} },
+    .{ .codepoint = 0x0543, .upper = &[_]u21{}, .lower = &[_]u21{0x0573}, .fold = &[_]u21{0x0573} },
+    .{ .codepoint = 0x0544, .upper = &[_]u21{}, .lower = &[_]u21{0x0574}, .fold = &[_]u21{0x0574} },
+    .{ .codepoint = 0x0545, .upper = &[_]u21{}, .lower = &[_]u21{0x0575}, .fold = &[_]u21{0x0575} },
+    .{ .codepoint = 0x0546, .upper = &[_]u21{}, .lower = &[_]u21{0x0576}, .fold = &[_]u21{0x0576} },
+    .{ .codepoint = 0x0547, .upper = &[_]u21{}, .lower = &[_]u21{0x0577}, .fold = &[_]u21{0x0577} },
+    .{ .codepoint = 0x0548, .upper = &[_]u21{}, .lower = &[_]u21{0x0578}, .fold = &[_]u21{0x0578} },
+    .{ .codepoint = 0x0549, .upper = &[_]u21{}, .lower = &[_]u21{0x0579}, .fold = &[_]u21{0x0579} },
+    .{ .codepoint = 0x054A, .upper = &[_]u21{}, .lower = &[_]u21{0x057A}, .fold = &[_]u21{0x057A} },
+    .{ .codepoint = 0x054B, .upper = &[_]u21{}, .lower = &[_]u21{0x057B}, .fold = &[_]u21{0x057B} },
+    .{ .codepoint = 0x054C, .upper = &[_]u21{}, .lower = &[_]u21{0x057C}, .fold = &[_]u21{0x057C} },
+    .{ .codepoint = 0x054D, .upper = &[_]u21{}, .lower = &[_]u21{0x057D}, .fold = &[_]u21{0x057D} },
+    .{ .codepoint = 0x054E, .upper = &[_]u21{}, .lower = &[_]u21{0x057E}, .fold = &[_]u21{0x057E} },
+    .{ .codepoint = 0x054F, .upper = &[_]u21{}, .lower = &[_]u21{0x057F}, .fold = &[_]u21{0x057F} },
+    .{ .codepoint = 0x0550, .upper = &[_]u21{}, .lower = &[_]u21{0x0580}, .fold = &[_]u21{0x0580} },
+    .{ .codepoint = 0x0551, .upper = &[_]u21{}, .lower = &[_]u21{0x0581}, .fold = &[_]u21{0x0581} },
+    .{ .codepoint = 0x0552, .upper = &[_]u21{}, .lower = &[_]u21{0x0582}, .fold = &[_]u21{0x0582} },
+    .{ .codepoint = 0x0553, .upper = &[_]u21{}, .lower = &[_]u21{0x0583}, .fold = &[_]u21{0x0583} },
+    .{ .codepoint = 0x0554, .upper = &[_]u21{}, .lower = &[_]u21{0x0584}, .fold = &[_]u21{0x0584} },
+    .{ .codepoint = 0x0555, .upper = &[_]u21{}, .lower = &[_]u21{0x0585}, .fold = &[_]u21{0x0585} },
+    .{ .codepoint = 0x0556, .upper = &[_]u21{}, .lower = &[_]u21{0x0586}, .fold = &[_]u21{0x0586} },
+    .{ .codepoint = 0x0561, .upper = &[_]u21{0x0531}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0562, .upper = &[_]u21{0x0532}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0563, .upper = &[_]u21{0x0533}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0564, .upper = &[_]u21{0x0534}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0565, .upper = &[_]u21{0x0535}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0566, .upper = &[_]u21{0x0536}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0567, .upper = &[_]u21{0x0537}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0568, .upper = &[_]u21{0x0538}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0569, .upper = &[_]u21{0x0539}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x056A, .upper = &[_]u21{0x053A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x056B, .upper = &[_]u21{0x053B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x056C, .upper = &[_]u21{0x053C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x056D, .upper = &[_]u21{0x053D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x056E, .upper = &[_]u21{0x053E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x056F, .upper = &[_]u21{0x053F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0570, .upper = &[_]u21{0x0540}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0571, .upper = &[_]u21{0x0541}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0572, .upper = &[_]u21{0x0542}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0573, .upper = &[_]u21{0x0543}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0574, .upper = &[_]u21{0x0544}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0575, .upper = &[_]u21{0x0545}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0576, .upper = &[_]u21{0x0546}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0577, .upper = &[_]u21{0x0547}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0578, .upper = &[_]u21{0x0548}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0579, .upper = &[_]u21{0x0549}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x057A, .upper = &[_]u21{0x054A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x057B, .upper = &[_]u21{0x054B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x057C, .upper = &[_]u21{0x054C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x057D, .upper = &[_]u21{0x054D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x057E, .upper = &[_]u21{0x054E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x057F, .upper = &[_]u21{0x054F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0580, .upper = &[_]u21{0x0550}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0581, .upper = &[_]u21{0x0551}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0582, .upper = &[_]u21{0x0552}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0583, .upper = &[_]u21{0x0553}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0584, .upper = &[_]u21{0x0554}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0585, .upper = &[_]u21{0x0555}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0586, .upper = &[_]u21{0x0556}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x0587, .upper = &[_]u21{ 0x0535, 0x0552 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x0565, 0x0582 } },
+    .{ .codepoint = 0x10A0, .upper = &[_]u21{}, .lower = &[_]u21{0x2D00}, .fold = &[_]u21{0x2D00} },
+    .{ .codepoint = 0x10A1, .upper = &[_]u21{}, .lower = &[_]u21{0x2D01}, .fold = &[_]u21{0x2D01} },
+    .{ .codepoint = 0x10A2, .upper = &[_]u21{}, .lower = &[_]u21{0x2D02}, .fold = &[_]u21{0x2D02} },
+    .{ .codepoint = 0x10A3, .upper = &[_]u21{}, .lower = &[_]u21{0x2D03}, .fold = &[_]u21{0x2D03} },
+    .{ .codepoint = 0x10A4, .upper = &[_]u21{}, .lower = &[_]u21{0x2D04}, .fold = &[_]u21{0x2D04} },
+    .{ .codepoint = 0x10A5, .upper = &[_]u21{}, .lower = &[_]u21{0x2D05}, .fold = &[_]u21{0x2D05} },
+    .{ .codepoint = 0x10A6, .upper = &[_]u21{}, .lower = &[_]u21{0x2D06}, .fold = &[_]u21{0x2D06} },
+    .{ .codepoint = 0x10A7, .upper = &[_]u21{}, .lower = &[_]u21{0x2D07}, .fold = &[_]u21{0x2D07} },
+    .{ .codepoint = 0x10A8, .upper = &[_]u21{}, .lower = &[_]u21{0x2D08}, .fold = &[_]u21{0x2D08} },
+    .{ .codepoint = 0x10A9, .upper = &[_]u21{}, .lower = &[_]u21{0x2D09}, .fold = &[_]u21{0x2D09} },
+    .{ .codepoint = 0x10AA, .upper = &[_]u21{}, .lower = &[_]u21{0x2D0A}, .fold = &[_]u21{0x2D0A} },
+    .{ .codepoint = 0x10AB, .upper = &[_]u21{}, .lower = &[_]u21{0x2D0B}, .fold = &[_]u21{0x2D0B} },
+    .{ .codepoint = 0x10AC, .upper = &[_]u21{}, .lower = &[_]u21{0x2D0C}, .fold = &[_]u21{0x2D0C} },
+    .{ .codepoint = 0x10AD, .upper = &[_]u21{}, .lower = &[_]u21{0x2D0D}, .fold = &[_]u21{0x2D0D} },
+    .{ .codepoint = 0x10AE, .upper = &[_]u21{}, .lower = &[_]u21{0x2D0E}, .fold = &[_]u21{0x2D0E} },
+    .{ .codepoint = 0x10AF, .upper = &[_]u21{}, .lower = &[_]u21{0x2D0F}, .fold = &[_]u21{0x2D0F} },
+    .{ .codepoint = 0x10B0, .upper = &[_]u21{}, .lower = &[_]u21{0x2D10}, .fold = &[_]u21{0x2D10} },
+    .{ .codepoint = 0x10B1, .upper = &[_]u21{}, .lower = &[_]u21{0x2D11}, .fold = &[_]u21{0x2D11} },
+    .{ .codepoint = 0x10B2, .upper = &[_]u21{}, .lower = &[_]u21{0x2D12}, .fold = &[_]u21{0x2D12} },
+    .{ .codepoint = 0x10B3, .upper = &[_]u21{}, .lower = &[_]u21{0x2D13}, .fold = &[_]u21{0x2D13} },
+    .{ .codepoint = 0x10B4, .upper = &[_]u21{}, .lower = &[_]u21{0x2D14}, .fold = &[_]u21{0x2D14} },
+    .{ .codepoint = 0x10B5, .upper = &[_]u21{}, .lower = &[_]u21{0x2D15}, .fold = &[_]u21{0x2D15} },
+    .{ .codepoint = 0x10B6, .upper = &[_]u21{}, .lower = &[_]u21{0x2D16}, .fold = &[_]u21{0x2D16} },
+    .{ .codepoint = 0x10B7, .upper = &[_]u21{}, .lower = &[_]u21{0x2D17}, .fold = &[_]u21{0x2D17} },
+    .{ .codepoint = 0x10B8, .upper = &[_]u21{}, .lower = &[_]u21{0x2D18}, .fold = &[_]u21{0x2D18} },
+    .{ .codepoint = 0x10B9, .upper = &[_]u21{}, .lower = &[_]u21{0x2D19}, .fold = &[_]u21{0x2D19} },
+    .{ .codepoint = 0x10BA, .upper = &[_]u21{}, .lower = &[_]u21{0x2D1A}, .fold = &[_]u21{0x2D1A} },
+    .{ .codepoint = 0x10BB, .upper = &[_]u21{}, .lower = &[_]u21{0x2D1B}, .fold = &[_]u21{0x2D1B} },
+    .{ .codepoint = 0x10BC, .upper = &[_]u21{}, .lower = &[_]u21{0x2D1C}, .fold = &[_]u21{0x2D1C} },
+    .{ .codepoint = 0x10BD, .upper = &[_]u21{}, .lower = &[_]u21{0x2D1D}, .fold = &[_]u21{0x2D1D} },
+    .{ .codepoint = 0x10BE, .upper = &[_]u21{}, .lower = &[_]u21{0x2D1E}, .fold = &[_]u21{0x2D1E} },
+    .{ .codepoint = 0x10BF, .upper = &[_]u21{}, .lower = &[_]u21{0x2D1F}, .fold = &[_]u21{0x2D1F} },
+    .{ .codepoint = 0x10C0, .upper = &[_]u21{}, .lower = &[_]u21{0x2D20}, .fold = &[_]u21{0x2D20} },
+    .{ .codepoint = 0x10C1, .upper = &[_]u21{}, .lower = &[_]u21{0x2D21}, .fold = &[_]u21{0x2D21} },
+    .{ .codepoint = 0x10C2, .upper = &[_]u21{}, .lower = &[_]u21{0x2D22}, .fold = &[_]u21{0x2D22} },
+    .{ .codepoint = 0x10C3, .upper = &[_]u21{}, .lower = &[_]u21{0x2D23}, .fold = &[_]u21{0x2D23} },
+    .{ .codepoint = 0x10C4, .upper = &[_]u21{}, .lower = &[_]u21{0x2D24}, .fold = &[_]u21{0x2D24} },
+    .{ .codepoint = 0x10C5, .upper = &[_]u21{}, .lower = &[_]u21{0x2D25}, .fold = &[_]u21{0x2D25} },
+    .{ .codepoint = 0x10C7, .upper = &[_]u21{}, .lower = &[_]u21{0x2D27}, .fold = &[_]u21{0x2D27} },
+    .{ .codepoint = 0x10CD, .upper = &[_]u21{}, .lower = &[_]u21{0x2D2D}, .fold = &[_]u21{0x2D2D} },
+    .{ .codepoint = 0x10D0, .upper = &[_]u21{0x1C90}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10D1, .upper = &[_]u21{0x1C91}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10D2, .upper = &[_]u21{0x1C92}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10D3, .upper = &[_]u21{0x1C93}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10D4, .upper = &[_]u21{0x1C94}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10D5, .upper = &[_]u21{0x1C95}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10D6, .upper = &[_]u21{0x1C96}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10D7, .upper = &[_]u21{0x1C97}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10D8, .upper = &[_]u21{0x1C98}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10D9, .upper = &[_]u21{0x1C99}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10DA, .upper = &[_]u21{0x1C9A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10DB, .upper = &[_]u21{0x1C9B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10DC, .upper = &[_]u21{0x1C9C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10DD, .upper = &[_]u21{0x1C9D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10DE, .upper = &[_]u21{0x1C9E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10DF, .upper = &[_]u21{0x1C9F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10E0, .upper = &[_]u21{0x1CA0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10E1, .upper = &[_]u21{0x1CA1}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10E2, .upper = &[_]u21{0x1CA2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10E3, .upper = &[_]u21{0x1CA3}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10E4, .upper = &[_]u21{0x1CA4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10E5, .upper = &[_]u21{0x1CA5}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10E6, .upper = &[_]u21{0x1CA6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10E7, .upper = &[_]u21{0x1CA7}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10E8, .upper = &[_]u21{0x1CA8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10E9, .upper = &[_]u21{0x1CA9}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10EA, .upper = &[_]u21{0x1CAA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10EB, .upper = &[_]u21{0x1CAB}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10EC, .upper = &[_]u21{0x1CAC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10ED, .upper = &[_]u21{0x1CAD}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10EE, .upper = &[_]u21{0x1CAE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10EF, .upper = &[_]u21{0x1CAF}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10F0, .upper = &[_]u21{0x1CB0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10F1, .upper = &[_]u21{0x1CB1}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10F2, .upper = &[_]u21{0x1CB2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10F3, .upper = &[_]u21{0x1CB3}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10F4, .upper = &[_]u21{0x1CB4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10F5, .upper = &[_]u21{0x1CB5}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10F6, .upper = &[_]u21{0x1CB6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10F7, .upper = &[_]u21{0x1CB7}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10F8, .upper = &[_]u21{0x1CB8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10F9, .upper = &[_]u21{0x1CB9}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10FA, .upper = &[_]u21{0x1CBA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10FD, .upper = &[_]u21{0x1CBD}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10FE, .upper = &[_]u21{0x1CBE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10FF, .upper = &[_]u21{0x1CBF}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13A0, .upper = &[_]u21{}, .lower = &[_]u21{0xAB70}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13A1, .upper = &[_]u21{}, .lower = &[_]u21{0xAB71}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13A2, .upper = &[_]u21{}, .lower = &[_]u21{0xAB72}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13A3, .upper = &[_]u21{}, .lower = &[_]u21{0xAB73}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13A4, .upper = &[_]u21{}, .lower = &[_]u21{0xAB74}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13A5, .upper = &[_]u21{}, .lower = &[_]u21{0xAB75}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13A6, .upper = &[_]u21{}, .lower = &[_]u21{0xAB76}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13A7, .upper = &[_]u21{}, .lower = &[_]u21{0xAB77}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13A8, .upper = &[_]u21{}, .lower = &[_]u21{0xAB78}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13A9, .upper = &[_]u21{}, .lower = &[_]u21{0xAB79}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13AA, .upper = &[_]u21{}, .lower = &[_]u21{0xAB7A}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13AB, .upper = &[_]u21{}, .lower = &[_]u21{0xAB7B}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13AC, .upper = &[_]u21{}, .lower = &[_]u21{0xAB7C}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13AD, .upper = &[_]u21{}, .lower = &[_]u21{0xAB7D}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13AE, .upper = &[_]u21{}, .lower = &[_]u21{0xAB7E}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13AF, .upper = &[_]u21{}, .lower = &[_]u21{0xAB7F}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13B0, .upper = &[_]u21{}, .lower = &[_]u21{0xAB80}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13B1, .upper = &[_]u21{}, .lower = &[_]u21{0xAB81}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13B2, .upper = &[_]u21{}, .lower = &[_]u21{0xAB82}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13B3, .upper = &[_]u21{}, .lower = &[_]u21{0xAB83}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13B4, .upper = &[_]u21{}, .lower = &[_]u21{0xAB84}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13B5, .upper = &[_]u21{}, .lower = &[_]u21{0xAB85}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13B6, .upper = &[_]u21{}, .lower = &[_]u21{0xAB86}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13B7, .upper = &[_]u21{}, .lower = &[_]u21{0xAB87}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13B8, .upper = &[_]u21{}, .lower = &[_]u21{0xAB88}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13B9, .upper = &[_]u21{}, .lower = &[_]u21{0xAB89}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13BA, .upper = &[_]u21{}, .lower = &[_]u21{0xAB8A}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13BB, .upper = &[_]u21{}, .lower = &[_]u21{0xAB8B}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13BC, .upper = &[_]u21{}, .lower = &[_]u21{0xAB8C}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13BD, .upper = &[_]u21{}, .lower = &[_]u21{0xAB8D}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13BE, .upper = &[_]u21{}, .lower = &[_]u21{0xAB8E}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13BF, .upper = &[_]u21{}, .lower = &[_]u21{0xAB8F}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13C0, .upper = &[_]u21{}, .lower = &[_]u21{0xAB90}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13C1, .upper = &[_]u21{}, .lower = &[_]u21{0xAB91}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13C2, .upper = &[_]u21{}, .lower = &[_]u21{0xAB92}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13C3, .upper = &[_]u21{}, .lower = &[_]u21{0xAB93}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13C4, .upper = &[_]u21{}, .lower = &[_]u21{0xAB94}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13C5, .upper = &[_]u21{}, .lower = &[_]u21{0xAB95}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13C6, .upper = &[_]u21{}, .lower = &[_]u21{0xAB96}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13C7, .upper = &[_]u21{}, .lower = &[_]u21{0xAB97}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13C8, .upper = &[_]u21{}, .lower = &[_]u21{0xAB98}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13C9, .upper = &[_]u21{}, .lower = &[_]u21{0xAB99}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13CA, .upper = &[_]u21{}, .lower = &[_]u21{0xAB9A}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13CB, .upper = &[_]u21{}, .lower = &[_]u21{0xAB9B}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13CC, .upper = &[_]u21{}, .lower = &[_]u21{0xAB9C}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13CD, .upper = &[_]u21{}, .lower = &[_]u21{0xAB9D}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13CE, .upper = &[_]u21{}, .lower = &[_]u21{0xAB9E}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13CF, .upper = &[_]u21{}, .lower = &[_]u21{0xAB9F}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13D0, .upper = &[_]u21{}, .lower = &[_]u21{0xABA0}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13D1, .upper = &[_]u21{}, .lower = &[_]u21{0xABA1}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13D2, .upper = &[_]u21{}, .lower = &[_]u21{0xABA2}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13D3, .upper = &[_]u21{}, .lower = &[_]u21{0xABA3}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13D4, .upper = &[_]u21{}, .lower = &[_]u21{0xABA4}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13D5, .upper = &[_]u21{}, .lower = &[_]u21{0xABA5}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13D6, .upper = &[_]u21{}, .lower = &[_]u21{0xABA6}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13D7, .upper = &[_]u21{}, .lower = &[_]u21{0xABA7}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13D8, .upper = &[_]u21{}, .lower = &[_]u21{0xABA8}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13D9, .upper = &[_]u21{}, .lower = &[_]u21{0xABA9}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13DA, .upper = &[_]u21{}, .lower = &[_]u21{0xABAA}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13DB, .upper = &[_]u21{}, .lower = &[_]u21{0xABAB}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13DC, .upper = &[_]u21{}, .lower = &[_]u21{0xABAC}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13DD, .upper = &[_]u21{}, .lower = &[_]u21{0xABAD}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13DE, .upper = &[_]u21{}, .lower = &[_]u21{0xABAE}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13DF, .upper = &[_]u21{}, .lower = &[_]u21{0xABAF}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13E0, .upper = &[_]u21{}, .lower = &[_]u21{0xABB0}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13E1, .upper = &[_]u21{}, .lower = &[_]u21{0xABB1}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13E2, .upper = &[_]u21{}, .lower = &[_]u21{0xABB2}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13E3, .upper = &[_]u21{}, .lower = &[_]u21{0xABB3}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13E4, .upper = &[_]u21{}, .lower = &[_]u21{0xABB4}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13E5, .upper = &[_]u21{}, .lower = &[_]u21{0xABB5}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13E6, .upper = &[_]u21{}, .lower = &[_]u21{0xABB6}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13E7, .upper = &[_]u21{}, .lower = &[_]u21{0xABB7}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13E8, .upper = &[_]u21{}, .lower = &[_]u21{0xABB8}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13E9, .upper = &[_]u21{}, .lower = &[_]u21{0xABB9}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13EA, .upper = &[_]u21{}, .lower = &[_]u21{0xABBA}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13EB, .upper = &[_]u21{}, .lower = &[_]u21{0xABBB}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13EC, .upper = &[_]u21{}, .lower = &[_]u21{0xABBC}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13ED, .upper = &[_]u21{}, .lower = &[_]u21{0xABBD}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13EE, .upper = &[_]u21{}, .lower = &[_]u21{0xABBE}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13EF, .upper = &[_]u21{}, .lower = &[_]u21{0xABBF}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13F0, .upper = &[_]u21{}, .lower = &[_]u21{0x13F8}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13F1, .upper = &[_]u21{}, .lower = &[_]u21{0x13F9}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13F2, .upper = &[_]u21{}, .lower = &[_]u21{0x13FA}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13F3, .upper = &[_]u21{}, .lower = &[_]u21{0x13FB}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13F4, .upper = &[_]u21{}, .lower = &[_]u21{0x13FC}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13F5, .upper = &[_]u21{}, .lower = &[_]u21{0x13FD}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x13F8, .upper = &[_]u21{0x13F0}, .lower = &[_]u21{}, .fold = &[_]u21{0x13F0} },
+    .{ .codepoint = 0x13F9, .upper = &[_]u21{0x13F1}, .lower = &[_]u21{}, .fold = &[_]u21{0x13F1} },
+    .{ .codepoint = 0x13FA, .upper = &[_]u21{0x13F2}, .lower = &[_]u21{}, .fold = &[_]u21{0x13F2} },
+    .{ .codepoint = 0x13FB, .upper = &[_]u21{0x13F3}, .lower = &[_]u21{}, .fold = &[_]u21{0x13F3} },
+    .{ .codepoint = 0x13FC, .upper = &[_]u21{0x13F4}, .lower = &[_]u21{}, .fold = &[_]u21{0x13F4} },
+    .{ .codepoint = 0x13FD, .upper = &[_]u21{0x13F5}, .lower = &[_]u21{}, .fold = &[_]u21{0x13F5} },
+    .{ .codepoint = 0x1C80, .upper = &[_]u21{0x0412}, .lower = &[_]u21{}, .fold = &[_]u21{0x0432} },
+    .{ .codepoint = 0x1C81, .upper = &[_]u21{0x0414}, .lower = &[_]u21{}, .fold = &[_]u21{0x0434} },
+    .{ .codepoint = 0x1C82, .upper = &[_]u21{0x041E}, .lower = &[_]u21{}, .fold = &[_]u21{0x043E} },
+    .{ .codepoint = 0x1C83, .upper = &[_]u21{0x0421}, .lower = &[_]u21{}, .fold = &[_]u21{0x0441} },
+    .{ .codepoint = 0x1C84, .upper = &[_]u21{0x0422}, .lower = &[_]u21{}, .fold = &[_]u21{0x0442} },
+    .{ .codepoint = 0x1C85, .upper = &[_]u21{0x0422}, .lower = &[_]u21{}, .fold = &[_]u21{0x0442} },
+    .{ .codepoint = 0x1C86, .upper = &[_]u21{0x042A}, .lower = &[_]u21{}, .fold = &[_]u21{0x044A} },
+    .{ .codepoint = 0x1C87, .upper = &[_]u21{0x0462}, .lower = &[_]u21{}, .fold = &[_]u21{0x0463} },
+    .{ .codepoint = 0x1C88, .upper = &[_]u21{0xA64A}, .lower = &[_]u21{}, .fold = &[_]u21{0xA64B} },
+    .{ .codepoint = 0x1C90, .upper = &[_]u21{}, .lower = &[_]u21{0x10D0}, .fold = &[_]u21{0x10D0} },
+    .{ .codepoint = 0x1C91, .upper = &[_]u21{}, .lower = &[_]u21{0x10D1}, .fold = &[_]u21{0x10D1} },
+    .{ .codepoint = 0x1C92, .upper = &[_]u21{}, .lower = &[_]u21{0x10D2}, .fold = &[_]u21{0x10D2} },
+    .{ .codepoint = 0x1C93, .upper = &[_]u21{}, .lower = &[_]u21{0x10D3}, .fold = &[_]u21{0x10D3} },
+    .{ .codepoint = 0x1C94, .upper = &[_]u21{}, .lower = &[_]u21{0x10D4}, .fold = &[_]u21{0x10D4} },
+    .{ .codepoint = 0x1C95, .upper = &[_]u21{}, .lower = &[_]u21{0x10D5}, .fold = &[_]u21{0x10D5} },
+    .{ .codepoint = 0x1C96, .upper = &[_]u21{}, .lower = &[_]u21{0x10D6}, .fold = &[_]u21{0x10D6} },
+    .{ .codepoint = 0x1C97, .upper = &[_]u21{}, .lower = &[_]u21{0x10D7}, .fold = &[_]u21{0x10D7} },
+    .{ .codepoint = 0x1C98, .upper = &[_]u21{}, .lower = &[_]u21{0x10D8}, .fold = &[_]u21{0x10D8} },
+    .{ .codepoint = 0x1C99, .upper = &[_]u21{}, .lower = &[_]u21{0x10D9}, .fold = &[_]u21{0x10D9} },
+    .{ .codepoint = 0x1C9A, .upper = &[_]u21{}, .lower = &[_]u21{0x10DA}, .fold = &[_]u21{0x10DA} },
+    .{ .codepoint = 0x1C9B, .upper = &[_]u21{}, .lower = &[_]u21{0x10DB}, .fold = &[_]u21{0x10DB} },
+    .{ .codepoint = 0x1C9C, .upper = &[_]u21{}, .lower = &[_]u21{0x10DC}, .fold = &[_]u21{0x10DC} },
+    .{ .codepoint = 0x1C9D, .upper = &[_]u21{}, .lower = &[_]u21{0x10DD}, .fold = &[_]u21{0x10DD} },
+    .{ .codepoint = 0x1C9E, .upper = &[_]u21{}, .lower = &[_]u21{0x10DE}, .fold = &[_]u21{0x10DE} },
+    .{ .codepoint = 0x1C9F, .upper = &[_]u21{}, .lower = &[_]u21{0x10DF}, .fold = &[_]u21{0x10DF} },
+    .{ .codepoint = 0x1CA0, .upper = &[_]u21{}, .lower = &[_]u21{0x10E0}, .fold = &[_]u21{0x10E0} },
+    .{ .codepoint = 0x1CA1, .upper = &[_]u21{}, .lower = &[_]u21{0x10E1}, .fold = &[_]u21{0x10E1} },
+    .{ .codepoint = 0x1CA2, .upper = &[_]u21{}, .lower = &[_]u21{0x10E2}, .fold = &[_]u21{0x10E2} },
+    .{ .codepoint = 0x1CA3, .upper = &[_]u21{}, .lower = &[_]u21{0x10E3}, .fold = &[_]u21{0x10E3} },
+    .{ .codepoint = 0x1CA4, .upper = &[_]u21{}, .lower = &[_]u21{0x10E4}, .fold = &[_]u21{0x10E4} },
+    .{ .codepoint = 0x1CA5, .upper = &[_]u21{}, .lower = &[_]u21{0x10E5}, .fold = &[_]u21{0x10E5} },
+    .{ .codepoint = 0x1CA6, .upper = &[_]u21{}, .lower = &[_]u21{0x10E6}, .fold = &[_]u21{0x10E6} },
+    .{ .codepoint = 0x1CA7, .upper = &[_]u21{}, .lower = &[_]u21{0x10E7}, .fold = &[_]u21{0x10E7} },
+    .{ .codepoint = 0x1CA8, .upper = &[_]u21{}, .lower = &[_]u21{0x10E8}, .fold = &[_]u21{0x10E8} },
+    .{ .codepoint = 0x1CA9, .upper = &[_]u21{}, .lower = &[_]u21{0x10E9}, .fold = &[_]u21{0x10E9} },
+    .{ .codepoint = 0x1CAA, .upper = &[_]u21{}, .lower = &[_]u21{0x10EA}, .fold = &[_]u21{0x10EA} },
+    .{ .codepoint = 0x1CAB, .upper = &[_]u21{}, .lower = &[_]u21{0x10EB}, .fold = &[_]u21{0x10EB} },
+    .{ .codepoint = 0x1CAC, .upper = &[_]u21{}, .lower = &[_]u21{0x10EC}, .fold = &[_]u21{0x10EC} },
+    .{ .codepoint = 0x1CAD, .upper = &[_]u21{}, .lower = &[_]u21{0x10ED}, .fold = &[_]u21{0x10ED} },
+    .{ .codepoint = 0x1CAE, .upper = &[_]u21{}, .lower = &[_]u21{0x10EE}, .fold = &[_]u21{0x10EE} },
+    .{ .codepoint = 0x1CAF, .upper = &[_]u21{}, .lower = &[_]u21{0x10EF}, .fold = &[_]u21{0x10EF} },
+    .{ .codepoint = 0x1CB0, .upper = &[_]u21{}, .lower = &[_]u21{0x10F0}, .fold = &[_]u21{0x10F0} },
+    .{ .codepoint = 0x1CB1, .upper = &[_]u21{}, .lower = &[_]u21{0x10F1}, .fold = &[_]u21{0x10F1} },
+    .{ .codepoint = 0x1CB2, .upper = &[_]u21{}, .lower = &[_]u21{0x10F2}, .fold = &[_]u21{0x10F2} },
+    .{ .codepoint = 0x1CB3, .upper = &[_]u21{}, .lower = &[_]u21{0x10F3}, .fold = &[_]u21{0x10F3} },
+    .{ .codepoint = 0x1CB4, .upper = &[_]u21{}, .lower = &[_]u21{0x10F4}, .fold = &[_]u21{0x10F4} },
+    .{ .codepoint = 0x1CB5, .upper = &[_]u21{}, .lower = &[_]u21{0x10F5}, .fold = &[_]u21{0x10F5} },
+    .{ .codepoint = 0x1CB6, .upper = &[_]u21{}, .lower = &[_]u21{0x10F6}, .fold = &[_]u21{0x10F6} },
+    .{ .codepoint = 0x1CB7, .upper = &[_]u21{}, .lower = &[_]u21{0x10F7}, .fold = &[_]u21{0x10F7} },
+    .{ .codepoint = 0x1CB8, .upper = &[_]u21{}, .lower = &[_]u21{0x10F8}, .fold = &[_]u21{0x10F8} },
+    .{ .codepoint = 0x1CB9, .upper = &[_]u21{}, .lower = &[_]u21{0x10F9}, .fold = &[_]u21{0x10F9} },
+    .{ .codepoint = 0x1CBA, .upper = &[_]u21{}, .lower = &[_]u21{0x10FA}, .fold = &[_]u21{0x10FA} },
+    .{ .codepoint = 0x1CBD, .upper = &[_]u21{}, .lower = &[_]u21{0x10FD}, .fold = &[_]u21{0x10FD} },
+    .{ .codepoint = 0x1CBE, .upper = &[_]u21{}, .lower = &[_]u21{0x10FE}, .fold = &[_]u21{0x10FE} },
+    .{ .codepoint = 0x1CBF, .upper = &[_]u21{}, .lower = &[_]u21{0x10FF}, .fold = &[_]u21{0x10FF} },
+    .{ .codepoint = 0x1D79, .upper = &[_]u21{0xA77D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1D7D, .upper = &[_]u21{0x2C63}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1D8E, .upper = &[_]u21{0xA7C6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E00, .upper = &[_]u21{}, .lower = &[_]u21{0x1E01}, .fold = &[_]u21{0x1E01} },
+    .{ .codepoint = 0x1E01, .upper = &[_]u21{0x1E00}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E02, .upper = &[_]u21{}, .lower = &[_]u21{0x1E03}, .fold = &[_]u21{0x1E03} },
+    .{ .codepoint = 0x1E03, .upper = &[_]u21{0x1E02}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E04, .upper = &[_]u21{}, .lower = &[_]u21{0x1E05}, .fold = &[_]u21{0x1E05} },
+    .{ .codepoint = 0x1E05, .upper = &[_]u21{0x1E04}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E06, .upper = &[_]u21{}, .lower = &[_]u21{0x1E07}, .fold = &[_]u21{0x1E07} },
+    .{ .codepoint = 0x1E07, .upper = &[_]u21{0x1E06}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E08, .upper = &[_]u21{}, .lower = &[_]u21{0x1E09}, .fold = &[_]u21{0x1E09} },
+    .{ .codepoint = 0x1E09, .upper = &[_]u21{0x1E08}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E0A, .upper = &[_]u21{}, .lower = &[_]u21{0x1E0B}, .fold = &[_]u21{0x1E0B} },
+    .{ .codepoint = 0x1E0B, .upper = &[_]u21{0x1E0A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E0C, .upper = &[_]u21{}, .lower = &[_]u21{0x1E0D}, .fold = &[_]u21{0x1E0D} },
+    .{ .codepoint = 0x1E0D, .upper = &[_]u21{0x1E0C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E0E, .upper = &[_]u21{}, .lower = &[_]u21{0x1E0F}, .fold = &[_]u21{0x1E0F} },
+    .{ .codepoint = 0x1E0F, .upper = &[_]u21{0x1E0E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E10, .upper = &[_]u21{}, .lower = &[_]u21{0x1E11}, .fold = &[_]u21{0x1E11} },
+    .{ .codepoint = 0x1E11, .upper = &[_]u21{0x1E10}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E12, .upper = &[_]u21{}, .lower = &[_]u21{0x1E13}, .fold = &[_]u21{0x1E13} },
+    .{ .codepoint = 0x1E13, .upper = &[_]u21{0x1E12}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E14, .upper = &[_]u21{}, .lower = &[_]u21{0x1E15}, .fold = &[_]u21{0x1E15} },
+    .{ .codepoint = 0x1E15, .upper = &[_]u21{0x1E14}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E16, .upper = &[_]u21{}, .lower = &[_]u21{0x1E17}, .fold = &[_]u21{0x1E17} },
+    .{ .codepoint = 0x1E17, .upper = &[_]u21{0x1E16}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E18, .upper = &[_]u21{}, .lower = &[_]u21{0x1E19}, .fold = &[_]u21{0x1E19} },
+    .{ .codepoint = 0x1E19, .upper = &[_]u21{0x1E18}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E1A, .upper = &[_]u21{}, .lower = &[_]u21{0x1E1B}, .fold = &[_]u21{0x1E1B} },
+    .{ .codepoint = 0x1E1B, .upper = &[_]u21{0x1E1A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E1C, .upper = &[_]u21{}, .lower = &[_]u21{0x1E1D}, .fold = &[_]u21{0x1E1D} },
+    .{ .codepoint = 0x1E1D, .upper = &[_]u21{0x1E1C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E1E, .upper = &[_]u21{}, .lower = &[_]u21{0x1E1F}, .fold = &[_]u21{0x1E1F} },
+    .{ .codepoint = 0x1E1F, .upper = &[_]u21{0x1E1E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E20, .upper = &[_]u21{}, .lower = &[_]u21{0x1E21}, .fold = &[_]u21{0x1E21} },
+    .{ .codepoint = 0x1E21, .upper = &[_]u21{0x1E20}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E22, .upper = &[_]u21{}, .lower = &[_]u21{0x1E23}, .fold = &[_]u21{0x1E23} },
+    .{ .codepoint = 0x1E23, .upper = &[_]u21{0x1E22}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E24, .upper = &[_]u21{}, .lower = &[_]u21{0x1E25}, .fold = &[_]u21{0x1E25} },
+    .{ .codepoint = 0x1E25, .upper = &[_]u21{0x1E24}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E26, .upper = &[_]u21{}, .lower = &[_]u21{0x1E27}, .fold = &[_]u21{0x1E27} },
+    .{ .codepoint = 0x1E27, .upper = &[_]u21{0x1E26}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E28, .upper = &[_]u21{}, .lower = &[_]u21{0x1E29}, .fold = &[_]u21{0x1E29} },
+    .{ .codepoint = 0x1E29, .upper = &[_]u21{0x1E28}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E2A, .upper = &[_]u21{}, .lower = &[_]u21{0x1E2B}, .fold = &[_]u21{0x1E2B} },
+    .{ .codepoint = 0x1E2B, .upper = &[_]u21{0x1E2A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E2C, .upper = &[_]u21{}, .lower = &[_]u21{0x1E2D}, .fold = &[_]u21{0x1E2D} },
+    .{ .codepoint = 0x1E2D, .upper = &[_]u21{0x1E2C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E2E, .upper = &[_]u21{}, .lower = &[_]u21{0x1E2F}, .fold = &[_]u21{0x1E2F} },
+    .{ .codepoint = 0x1E2F, .upper = &[_]u21{0x1E2E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E30, .upper = &[_]u21{}, .lower = &[_]u21{0x1E31}, .fold = &[_]u21{0x1E31} },
+    .{ .codepoint = 0x1E31, .upper = &[_]u21{0x1E30}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E32, .upper = &[_]u21{}, .lower = &[_]u21{0x1E33}, .fold = &[_]u21{0x1E33} },
+    .{ .codepoint = 0x1E33, .upper = &[_]u21{0x1E32}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E34, .upper = &[_]u21{}, .lower = &[_]u21{0x1E35}, .fold = &[_]u21{0x1E35} },
+    .{ .codepoint = 0x1E35, .upper = &[_]u21{0x1E34}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E36, .upper = &[_]u21{}, .lower = &[_]u21{0x1E37}, .fold = &[_]u21{0x1E37} },
+    .{ .codepoint = 0x1E37, .upper = &[_]u21{0x1E36}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E38, .upper = &[_]u21{}, .lower = &[_]u21{0x1E39}, .fold = &[_]u21{0x1E39} },
+    .{ .codepoint = 0x1E39, .upper = &[_]u21{0x1E38}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E3A, .upper = &[_]u21{}, .lower = &[_]u21{0x1E3B}, .fold = &[_]u21{0x1E3B} },
+    .{ .codepoint = 0x1E3B, .upper = &[_]u21{0x1E3A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E3C, .upper = &[_]u21{}, .lower = &[_]u21{0x1E3D}, .fold = &[_]u21{0x1E3D} },
+    .{ .codepoint = 0x1E3D, .upper = &[_]u21{0x1E3C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E3E, .upper = &[_]u21{}, .lower = &[_]u21{0x1E3F}, .fold = &[_]u21{0x1E3F} },
+    .{ .codepoint = 0x1E3F, .upper = &[_]u21{0x1E3E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E40, .upper = &[_]u21{}, .lower = &[_]u21{0x1E41}, .fold = &[_]u21{0x1E41} },
+    .{ .codepoint = 0x1E41, .upper = &[_]u21{0x1E40}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E42, .upper = &[_]u21{}, .lower = &[_]u21{0x1E43}, .fold = &[_]u21{0x1E43} },
+    .{ .codepoint = 0x1E43, .upper = &[_]u21{0x1E42}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E44, .upper = &[_]u21{}, .lower = &[_]u21{0x1E45}, .fold = &[_]u21{0x1E45} },
+    .{ .codepoint = 0x1E45, .upper = &[_]u21{0x1E44}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E46, .upper = &[_]u21{}, .lower = &[_]u21{0x1E47}, .fold = &[_]u21{0x1E47} },
+    .{ .codepoint = 0x1E47, .upper = &[_]u21{0x1E46}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E48, .upper = &[_]u21{}, .lower = &[_]u21{0x1E49}, .fold = &[_]u21{0x1E49} },
+    .{ .codepoint = 0x1E49, .upper = &[_]u21{0x1E48}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E4A, .upper = &[_]u21{}, .lower = &[_]u21{0x1E4B}, .fold = &[_]u21{0x1E4B} },
+    .{ .codepoint = 0x1E4B, .upper = &[_]u21{0x1E4A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E4C, .upper = &[_]u21{}, .lower = &[_]u21{0x1E4D}, .fold = &[_]u21{0x1E4D} },
+    .{ .codepoint = 0x1E4D, .upper = &[_]u21{0x1E4C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E4E, .upper = &[_]u21{}, .lower = &[_]u21{0x1E4F}, .fold = &[_]u21{0x1E4F} },
+    .{ .codepoint = 0x1E4F, .upper = &[_]u21{0x1E4E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E50, .upper = &[_]u21{}, .lower = &[_]u21{0x1E51}, .fold = &[_]u21{0x1E51} },
+    .{ .codepoint = 0x1E51, .upper = &[_]u21{0x1E50}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E52, .upper = &[_]u21{}, .lower = &[_]u21{0x1E53}, .fold = &[_]u21{0x1E53} },
+    .{ .codepoint = 0x1E53, .upper = &[_]u21{0x1E52}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E54, .upper = &[_]u21{}, .lower = &[_]u21{0x1E55}, .fold = &[_]u21{0x1E55} },
+    .{ .codepoint = 0x1E55, .upper = &[_]u21{0x1E54}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E56, .upper = &[_]u21{}, .lower = &[_]u21{0x1E57}, .fold = &[_]u21{0x1E57} },
+    .{ .codepoint = 0x1E57, .upper = &[_]u21{0x1E56}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E58, .upper = &[_]u21{}, .lower = &[_]u21{0x1E59}, .fold = &[_]u21{0x1E59} },
+    .{ .codepoint = 0x1E59, .upper = &[_]u21{0x1E58}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E5A, .upper = &[_]u21{}, .lower = &[_]u21{0x1E5B}, .fold = &[_]u21{0x1E5B} },
+    .{ .codepoint = 0x1E5B, .upper = &[_]u21{0x1E5A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E5C, .upper = &[_]u21{}, .lower = &[_]u21{0x1E5D}, .fold = &[_]u21{0x1E5D} },
+    .{ .codepoint = 0x1E5D, .upper = &[_]u21{0x1E5C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E5E, .upper = &[_]u21{}, .lower = &[_]u21{0x1E5F}, .fold = &[_]u21{0x1E5F} },
+    .{ .codepoint = 0x1E5F, .upper = &[_]u21{0x1E5E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E60, .upper = &[_]u21{}, .lower = &[_]u21{0x1E61}, .fold = &[_]u21{0x1E61} },
+    .{ .codepoint = 0x1E61, .upper = &[_]u21{0x1E60}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E62, .upper = &[_]u21{}, .lower = &[_]u21{0x1E63}, .fold = &[_]u21{0x1E63} },
+    .{ .codepoint = 0x1E63, .upper = &[_]u21{0x1E62}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E64, .upper = &[_]u21{}, .lower = &[_]u21{0x1E65}, .fold = &[_]u21{0x1E65} },
+    .{ .codepoint = 0x1E65, .upper = &[_]u21{0x1E64}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E66, .upper = &[_]u21{}, .lower = &[_]u21{0x1E67}, .fold = &[_]u21{0x1E67} },
+    .{ .codepoint = 0x1E67, .upper = &[_]u21{0x1E66}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E68, .upper = &[_]u21{}, .lower = &[_]u21{0x1E69}, .fold = &[_]u21{0x1E69} },
+    .{ .codepoint = 0x1E69, .upper = &[_]u21{0x1E68}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E6A, .upper = &[_]u21{}, .lower = &[_]u21{0x1E6B}, .fold = &[_]u21{0x1E6B} },
+    .{ .codepoint = 0x1E6B, .upper = &[_]u21{0x1E6A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E6C, .upper = &[_]u21{}, .lower = &[_]u21{0x1E6D}, .fold = &[_]u21{0x1E6D} },
+    .{ .codepoint = 0x1E6D, .upper = &[_]u21{0x1E6C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E6E, .upper = &[_]u21{}, .lower = &[_]u21{0x1E6F}, .fold = &[_]u21{0x1E6F} },
+    .{ .codepoint = 0x1E6F, .upper = &[_]u21{0x1E6E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E70, .upper = &[_]u21{}, .lower = &[_]u21{0x1E71}, .fold = &[_]u21{0x1E71} },
+    .{ .codepoint = 0x1E71, .upper = &[_]u21{0x1E70}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E72, .upper = &[_]u21{}, .lower = &[_]u21{0x1E73}, .fold = &[_]u21{0x1E73} },
+    .{ .codepoint = 0x1E73, .upper = &[_]u21{0x1E72}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E74, .upper = &[_]u21{}, .lower = &[_]u21{0x1E75}, .fold = &[_]u21{0x1E75} },
+    .{ .codepoint = 0x1E75, .upper = &[_]u21{0x1E74}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E76, .upper = &[_]u21{}, .lower = &[_]u21{0x1E77}, .fold = &[_]u21{0x1E77} },
+    .{ .codepoint = 0x1E77, .upper = &[_]u21{0x1E76}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E78, .upper = &[_]u21{}, .lower = &[_]u21{0x1E79}, .fold = &[_]u21{0x1E79} },
+    .{ .codepoint = 0x1E79, .upper = &[_]u21{0x1E78}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E7A, .upper = &[_]u21{}, .lower = &[_]u21{0x1E7B}, .fold = &[_]u21{0x1E7B} },
+    .{ .codepoint = 0x1E7B, .upper = &[_]u21{0x1E7A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E7C, .upper = &[_]u21{}, .lower = &[_]u21{0x1E7D}, .fold = &[_]u21{0x1E7D} },
+    .{ .codepoint = 0x1E7D, .upper = &[_]u21{0x1E7C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E7E, .upper = &[_]u21{}, .lower = &[_]u21{0x1E7F}, .fold = &[_]u21{0x1E7F} },
+    .{ .codepoint = 0x1E7F, .upper = &[_]u21{0x1E7E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E80, .upper = &[_]u21{}, .lower = &[_]u21{0x1E81}, .fold = &[_]u21{0x1E81} },
+    .{ .codepoint = 0x1E81, .upper = &[_]u21{0x1E80}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E82, .upper = &[_]u21{}, .lower = &[_]u21{0x1E83}, .fold = &[_]u21{0x1E83} },
+    .{ .codepoint = 0x1E83, .upper = &[_]u21{0x1E82}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E84, .upper = &[_]u21{}, .lower = &[_]u21{0x1E85}, .fold = &[_]u21{0x1E85} },
+    .{ .codepoint = 0x1E85, .upper = &[_]u21{0x1E84}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E86, .upper = &[_]u21{}, .lower = &[_]u21{0x1E87}, .fold = &[_]u21{0x1E87} },
+    .{ .codepoint = 0x1E87, .upper = &[_]u21{0x1E86}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E88, .upper = &[_]u21{}, .lower = &[_]u21{0x1E89}, .fold = &[_]u21{0x1E89} },
+    .{ .codepoint = 0x1E89, .upper = &[_]u21{0x1E88}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E8A, .upper = &[_]u21{}, .lower = &[_]u21{0x1E8B}, .fold = &[_]u21{0x1E8B} },
+    .{ .codepoint = 0x1E8B, .upper = &[_]u21{0x1E8A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E8C, .upper = &[_]u21{}, .lower = &[_]u21{0x1E8D}, .fold = &[_]u21{0x1E8D} },
+    .{ .codepoint = 0x1E8D, .upper = &[_]u21{0x1E8C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E8E, .upper = &[_]u21{}, .lower = &[_]u21{0x1E8F}, .fold = &[_]u21{0x1E8F} },
+    .{ .codepoint = 0x1E8F, .upper = &[_]u21{0x1E8E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E90, .upper = &[_]u21{}, .lower = &[_]u21{0x1E91}, .fold = &[_]u21{0x1E91} },
+    .{ .codepoint = 0x1E91, .upper = &[_]u21{0x1E90}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E92, .upper = &[_]u21{}, .lower = &[_]u21{0x1E93}, .fold = &[_]u21{0x1E93} },
+    .{ .codepoint = 0x1E93, .upper = &[_]u21{0x1E92}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E94, .upper = &[_]u21{}, .lower = &[_]u21{0x1E95}, .fold = &[_]u21{0x1E95} },
+    .{ .codepoint = 0x1E95, .upper = &[_]u21{0x1E94}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E96, .upper = &[_]u21{ 0x0048, 0x0331 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x0068, 0x0331 } },
+    .{ .codepoint = 0x1E97, .upper = &[_]u21{ 0x0054, 0x0308 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x0074, 0x0308 } },
+    .{ .codepoint = 0x1E98, .upper = &[_]u21{ 0x0057, 0x030A }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x0077, 0x030A } },
+    .{ .codepoint = 0x1E99, .upper = &[_]u21{ 0x0059, 0x030A }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x0079, 0x030A } },
+    .{ .codepoint = 0x1E9A, .upper = &[_]u21{ 0x0041, 0x02BE }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x0061, 0x02BE } },
+    .{ .codepoint = 0x1E9B, .upper = &[_]u21{0x1E60}, .lower = &[_]u21{}, .fold = &[_]u21{0x1E61} },
+    .{ .codepoint = 0x1E9E, .upper = &[_]u21{}, .lower = &[_]u21{0x00DF}, .fold = &[_]u21{ 0x0073, 0x0073 } },
+    .{ .codepoint = 0x1EA0, .upper = &[_]u21{}, .lower = &[_]u21{0x1EA1}, .fold = &[_]u21{0x1EA1} },
+    .{ .codepoint = 0x1EA1, .upper = &[_]u21{0x1EA0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EA2, .upper = &[_]u21{}, .lower = &[_]u21{0x1EA3}, .fold = &[_]u21{0x1EA3} },
+    .{ .codepoint = 0x1EA3, .upper = &[_]u21{0x1EA2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EA4, .upper = &[_]u21{}, .lower = &[_]u21{0x1EA5}, .fold = &[_]u21{0x1EA5} },
+    .{ .codepoint = 0x1EA5, .upper = &[_]u21{0x1EA4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EA6, .upper = &[_]u21{}, .lower = &[_]u21{0x1EA7}, .fold = &[_]u21{0x1EA7} },
+    .{ .codepoint = 0x1EA7, .upper = &[_]u21{0x1EA6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EA8, .upper = &[_]u21{}, .lower = &[_]u21{0x1EA9}, .fold = &[_]u21{0x1EA9} },
+    .{ .codepoint = 0x1EA9, .upper = &[_]u21{0x1EA8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EAA, .upper = &[_]u21{}, .lower = &[_]u21{0x1EAB}, .fold = &[_]u21{0x1EAB} },
+    .{ .codepoint = 0x1EAB, .upper = &[_]u21{0x1EAA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EAC, .upper = &[_]u21{}, .lower = &[_]u21{0x1EAD}, .fold = &[_]u21{0x1EAD} },
+    .{ .codepoint = 0x1EAD, .upper = &[_]u21{0x1EAC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EAE, .upper = &[_]u21{}, .lower = &[_]u21{0x1EAF}, .fold = &[_]u21{0x1EAF} },
+    .{ .codepoint = 0x1EAF, .upper = &[_]u21{0x1EAE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EB0, .upper = &[_]u21{}, .lower = &[_]u21{0x1EB1}, .fold = &[_]u21{0x1EB1} },
+    .{ .codepoint = 0x1EB1, .upper = &[_]u21{0x1EB0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EB2, .upper = &[_]u21{}, .lower = &[_]u21{0x1EB3}, .fold = &[_]u21{0x1EB3} },
+    .{ .codepoint = 0x1EB3, .upper = &[_]u21{0x1EB2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EB4, .upper = &[_]u21{}, .lower = &[_]u21{0x1EB5}, .fold = &[_]u21{0x1EB5} },
+    .{ .codepoint = 0x1EB5, .upper = &[_]u21{0x1EB4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EB6, .upper = &[_]u21{}, .lower = &[_]u21{0x1EB7}, .fold = &[_]u21{0x1EB7} },
+    .{ .codepoint = 0x1EB7, .upper = &[_]u21{0x1EB6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EB8, .upper = &[_]u21{}, .lower = &[_]u21{0x1EB9}, .fold = &[_]u21{0x1EB9} },
+    .{ .codepoint = 0x1EB9, .upper = &[_]u21{0x1EB8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EBA, .upper = &[_]u21{}, .lower = &[_]u21{0x1EBB}, .fold = &[_]u21{0x1EBB} },
+    .{ .codepoint = 0x1EBB, .upper = &[_]u21{0x1EBA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EBC, .upper = &[_]u21{}, .lower = &[_]u21{0x1EBD}, .fold = &[_]u21{0x1EBD} },
+    .{ .codepoint = 0x1EBD, .upper = &[_]u21{0x1EBC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EBE, .upper = &[_]u21{}, .lower = &[_]u21{0x1EBF}, .fold = &[_]u21{0x1EBF} },
+    .{ .codepoint = 0x1EBF, .upper = &[_]u21{0x1EBE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EC0, .upper = &[_]u21{}, .lower = &[_]u21{0x1EC1}, .fold = &[_]u21{0x1EC1} },
+    .{ .codepoint = 0x1EC1, .upper = &[_]u21{0x1EC0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EC2, .upper = &[_]u21{}, .lower = &[_]u21{0x1EC3}, .fold = &[_]u21{0x1EC3} },
+    .{ .codepoint = 0x1EC3, .upper = &[_]u21{0x1EC2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EC4, .upper = &[_]u21{}, .lower = &[_]u21{0x1EC5}, .fold = &[_]u21{0x1EC5} },
+    .{ .codepoint = 0x1EC5, .upper = &[_]u21{0x1EC4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EC6, .upper = &[_]u21{}, .lower = &[_]u21{0x1EC7}, .fold = &[_]u21{0x1EC7} },
+    .{ .codepoint = 0x1EC7, .upper = &[_]u21{0x1EC6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EC8, .upper = &[_]u21{}, .lower = &[_]u21{0x1EC9}, .fold = &[_]u21{0x1EC9} },
+    .{ .codepoint = 0x1EC9, .upper = &[_]u21{0x1EC8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1ECA, .upper = &[_]u21{}, .lower = &[_]u21{0x1ECB}, .fold = &[_]u21{0x1ECB} },
+    .{ .codepoint = 0x1ECB, .upper = &[_]u21{0x1ECA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1ECC, .upper = &[_]u21{}, .lower = &[_]u21{0x1ECD}, .fold = &[_]u21{0x1ECD} },
+    .{ .codepoint = 0x1ECD, .upper = &[_]u21{0x1ECC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1ECE, .upper = &[_]u21{}, .lower = &[_]u21{0x1ECF}, .fold = &[_]u21{0x1ECF} },
+    .{ .codepoint = 0x1ECF, .upper = &[_]u21{0x1ECE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1ED0, .upper = &[_]u21{}, .lower = &[_]u21{0x1ED1}, .fold = &[_]u21{0x1ED1} },
+    .{ .codepoint = 0x1ED1, .upper = &[_]u21{0x1ED0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1ED2, .upper = &[_]u21{}, .lower = &[_]u21{0x1ED3}, .fold = &[_]u21{0x1ED3} },
+    .{ .codepoint = 0x1ED3, .upper = &[_]u21{0x1ED2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1ED4, .upper = &[_]u21{}, .lower = &[_]u21{0x1ED5}, .fold = &[_]u21{0x1ED5} },
+    .{ .codepoint = 0x1ED5, .upper = &[_]u21{0x1ED4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1ED6, .upper = &[_]u21{}, .lower = &[_]u21{0x1ED7}, .fold = &[_]u21{0x1ED7} },
+    .{ .codepoint = 0x1ED7, .upper = &[_]u21{0x1ED6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1ED8, .upper = &[_]u21{}, .lower = &[_]u21{0x1ED9}, .fold = &[_]u21{0x1ED9} },
+    .{ .codepoint = 0x1ED9, .upper = &[_]u21{0x1ED8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EDA, .upper = &[_]u21{}, .lower = &[_]u21{0x1EDB}, .fold = &[_]u21{0x1EDB} },
+    .{ .codepoint = 0x1EDB, .upper = &[_]u21{0x1EDA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EDC, .upper = &[_]u21{}, .lower = &[_]u21{0x1EDD}, .fold = &[_]u21{0x1EDD} },
+    .{ .codepoint = 0x1EDD, .upper = &[_]u21{0x1EDC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EDE, .upper = &[_]u21{}, .lower = &[_]u21{0x1EDF}, .fold = &[_]u21{0x1EDF} },
+    .{ .codepoint = 0x1EDF, .upper = &[_]u21{0x1EDE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EE0, .upper = &[_]u21{}, .lower = &[_]u21{0x1EE1}, .fold = &[_]u21{0x1EE1} },
+    .{ .codepoint = 0x1EE1, .upper = &[_]u21{0x1EE0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EE2, .upper = &[_]u21{}, .lower = &[_]u21{0x1EE3}, .fold = &[_]u21{0x1EE3} },
+    .{ .codepoint = 0x1EE3, .upper = &[_]u21{0x1EE2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EE4, .upper = &[_]u21{}, .lower = &[_]u21{0x1EE5}, .fold = &[_]u21{0x1EE5} },
+    .{ .codepoint = 0x1EE5, .upper = &[_]u21{0x1EE4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EE6, .upper = &[_]u21{}, .lower = &[_]u21{0x1EE7}, .fold = &[_]u21{0x1EE7} },
+    .{ .codepoint = 0x1EE7, .upper = &[_]u21{0x1EE6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EE8, .upper = &[_]u21{}, .lower = &[_]u21{0x1EE9}, .fold = &[_]u21{0x1EE9} },
+    .{ .codepoint = 0x1EE9, .upper = &[_]u21{0x1EE8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EEA, .upper = &[_]u21{}, .lower = &[_]u21{0x1EEB}, .fold = &[_]u21{0x1EEB} },
+    .{ .codepoint = 0x1EEB, .upper = &[_]u21{0x1EEA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EEC, .upper = &[_]u21{}, .lower = &[_]u21{0x1EED}, .fold = &[_]u21{0x1EED} },
+    .{ .codepoint = 0x1EED, .upper = &[_]u21{0x1EEC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EEE, .upper = &[_]u21{}, .lower = &[_]u21{0x1EEF}, .fold = &[_]u21{0x1EEF} },
+    .{ .codepoint = 0x1EEF, .upper = &[_]u21{0x1EEE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EF0, .upper = &[_]u21{}, .lower = &[_]u21{0x1EF1}, .fold = &[_]u21{0x1EF1} },
+    .{ .codepoint = 0x1EF1, .upper = &[_]u21{0x1EF0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EF2, .upper = &[_]u21{}, .lower = &[_]u21{0x1EF3}, .fold = &[_]u21{0x1EF3} },
+    .{ .codepoint = 0x1EF3, .upper = &[_]u21{0x1EF2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EF4, .upper = &[_]u21{}, .lower = &[_]u21{0x1EF5}, .fold = &[_]u21{0x1EF5} },
+    .{ .codepoint = 0x1EF5, .upper = &[_]u21{0x1EF4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EF6, .upper = &[_]u21{}, .lower = &[_]u21{0x1EF7}, .fold = &[_]u21{0x1EF7} },
+    .{ .codepoint = 0x1EF7, .upper = &[_]u21{0x1EF6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EF8, .upper = &[_]u21{}, .lower = &[_]u21{0x1EF9}, .fold = &[_]u21{0x1EF9} },
+    .{ .codepoint = 0x1EF9, .upper = &[_]u21{0x1EF8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EFA, .upper = &[_]u21{}, .lower = &[_]u21{0x1EFB}, .fold = &[_]u21{0x1EFB} },
+    .{ .codepoint = 0x1EFB, .upper = &[_]u21{0x1EFA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EFC, .upper = &[_]u21{}, .lower = &[_]u21{0x1EFD}, .fold = &[_]u21{0x1EFD} },
+    .{ .codepoint = 0x1EFD, .upper = &[_]u21{0x1EFC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1EFE, .upper = &[_]u21{}, .lower = &[_]u21{0x1EFF}, .fold = &[_]u21{0x1EFF} },
+    .{ .codepoint = 0x1EFF, .upper = &[_]u21{0x1EFE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F00, .upper = &[_]u21{0x1F08}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F01, .upper = &[_]u21{0x1F09}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F02, .upper = &[_]u21{0x1F0A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F03, .upper = &[_]u21{0x1F0B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F04, .upper = &[_]u21{0x1F0C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F05, .upper = &[_]u21{0x1F0D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F06, .upper = &[_]u21{0x1F0E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F07, .upper = &[_]u21{0x1F0F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F08, .upper = &[_]u21{}, .lower = &[_]u21{0x1F00}, .fold = &[_]u21{0x1F00} },
+    .{ .codepoint = 0x1F09, .upper = &[_]u21{}, .lower = &[_]u21{0x1F01}, .fold = &[_]u21{0x1F01} },
+    .{ .codepoint = 0x1F0A, .upper = &[_]u21{}, .lower = &[_]u21{0x1F02}, .fold = &[_]u21{0x1F02} },
+    .{ .codepoint = 0x1F0B, .upper = &[_]u21{}, .lower = &[_]u21{0x1F03}, .fold = &[_]u21{0x1F03} },
+    .{ .codepoint = 0x1F0C, .upper = &[_]u21{}, .lower = &[_]u21{0x1F04}, .fold = &[_]u21{0x1F04} },
+    .{ .codepoint = 0x1F0D, .upper = &[_]u21{}, .lower = &[_]u21{0x1F05}, .fold = &[_]u21{0x1F05} },
+    .{ .codepoint = 0x1F0E, .upper = &[_]u21{}, .lower = &[_]u21{0x1F06}, .fold = &[_]u21{0x1F06} },
+    .{ .codepoint = 0x1F0F, .upper = &[_]u21{}, .lower = &[_]u21{0x1F07}, .fold = &[_]u21{0x1F07} },
+    .{ .codepoint = 0x1F10, .upper = &[_]u21{0x1F18}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F11, .upper = &[_]u21{0x1F19}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F12, .upper = &[_]u21{0x1F1A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F13, .upper = &[_]u21{0x1F1B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F14, .upper = &[_]u21{0x1F1C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F15, .upper = &[_]u21{0x1F1D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F18, .upper = &[_]u21{}, .lower = &[_]u21{0x1F10}, .fold = &[_]u21{0x1F10} },
+    .{ .codepoint = 0x1F19, .upper = &[_]u21{}, .lower = &[_]u21{0x1F11}, .fold = &[_]u21{0x1F11} },
+    .{ .codepoint = 0x1F1A, .upper = &[_]u21{}, .lower = &[_]u21{0x1F12}, .fold = &[_]u21{0x1F12} },
+    .{ .codepoint = 0x1F1B, .upper = &[_]u21{}, .lower = &[_]u21{0x1F13}, .fold = &[_]u21{0x1F13} },
+    .{ .codepoint = 0x1F1C, .upper = &[_]u21{}, .lower = &[_]u21{0x1F14}, .fold = &[_]u21{0x1F14} },
+    .{ .codepoint = 0x1F1D, .upper = &[_]u21{}, .lower = &[_]u21{0x1F15}, .fold = &[_]u21{0x1F15} },
+    .{ .codepoint = 0x1F20, .upper = &[_]u21{0x1F28}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F21, .upper = &[_]u21{0x1F29}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F22, .upper = &[_]u21{0x1F2A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F23, .upper = &[_]u21{0x1F2B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F24, .upper = &[_]u21{0x1F2C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F25, .upper = &[_]u21{0x1F2D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F26, .upper = &[_]u21{0x1F2E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F27, .upper = &[_]u21{0x1F2F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F28, .upper = &[_]u21{}, .lower = &[_]u21{0x1F20}, .fold = &[_]u21{0x1F20} },
+    .{ .codepoint = 0x1F29, .upper = &[_]u21{}, .lower = &[_]u21{0x1F21}, .fold = &[_]u21{0x1F21} },
+    .{ .codepoint = 0x1F2A, .upper = &[_]u21{}, .lower = &[_]u21{0x1F22}, .fold = &[_]u21{0x1F22} },
+    .{ .codepoint = 0x1F2B, .upper = &[_]u21{}, .lower = &[_]u21{0x1F23}, .fold = &[_]u21{0x1F23} },
+    .{ .codepoint = 0x1F2C, .upper = &[_]u21{}, .lower = &[_]u21{0x1F24}, .fold = &[_]u21{0x1F24} },
+    .{ .codepoint = 0x1F2D, .upper = &[_]u21{}, .lower = &[_]u21{0x1F25}, .fold = &[_]u21{0x1F25} },
+    .{ .codepoint = 0x1F2E, .upper = &[_]u21{}, .lower = &[_]u21{0x1F26}, .fold = &[_]u21{0x1F26} },
+    .{ .codepoint = 0x1F2F, .upper = &[_]u21{}, .lower = &[_]u21{0x1F27}, .fold = &[_]u21{0x1F27} },
+    .{ .codepoint = 0x1F30, .upper = &[_]u21{0x1F38}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F31, .upper = &[_]u21{0x1F39}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F32, .upper = &[_]u21{0x1F3A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F33, .upper = &[_]u21{0x1F3B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F34, .upper = &[_]u21{0x1F3C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F35, .upper = &[_]u21{0x1F3D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F36, .upper = &[_]u21{0x1F3E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F37, .upper = &[_]u21{0x1F3F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F38, .upper = &[_]u21{}, .lower = &[_]u21{0x1F30}, .fold = &[_]u21{0x1F30} },
+    .{ .codepoint = 0x1F39, .upper = &[_]u21{}, .lower = &[_]u21{0x1F31}, .fold = &[_]u21{0x1F31} },
+    .{ .codepoint = 0x1F3A, .upper = &[_]u21{}, .lower = &[_]u21{0x1F32}, .fold = &[_]u21{0x1F32} },
+    .{ .codepoint = 0x1F3B, .upper = &[_]u21{}, .lower = &[_]u21{0x1F33}, .fold = &[_]u21{0x1F33} },
+    .{ .codepoint = 0x1F3C, .upper = &[_]u21{}, .lower = &[_]u21{0x1F34}, .fold = &[_]u21{0x1F34} },
+    .{ .codepoint = 0x1F3D, .upper = &[_]u21{}, .lower = &[_]u21{0x1F35}, .fold = &[_]u21{0x1F35} },
+    .{ .codepoint = 0x1F3E, .upper = &[_]u21{}, .lower = &[_]u21{0x1F36}, .fold = &[_]u21{0x1F36} },
+    .{ .codepoint = 0x1F3F, .upper = &[_]u21{}, .lower = &[_]u21{0x1F37}, .fold = &[_]u21{0x1F37} },
+    .{ .codepoint = 0x1F40, .upper = &[_]u21{0x1F48}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F41, .upper = &[_]u21{0x1F49}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F42, .upper = &[_]u21{0x1F4A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F43, .upper = &[_]u21{0x1F4B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F44, .upper = &[_]u21{0x1F4C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F45, .upper = &[_]u21{0x1F4D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F48, .upper = &[_]u21{}, .lower = &[_]u21{0x1F40}, .fold = &[_]u21{0x1F40} },
+    .{ .codepoint = 0x1F49, .upper = &[_]u21{}, .lower = &[_]u21{0x1F41}, .fold = &[_]u21{0x1F41} },
+    .{ .codepoint = 0x1F4A, .upper = &[_]u21{}, .lower = &[_]u21{0x1F42}, .fold = &[_]u21{0x1F42} },
+    .{ .codepoint = 0x1F4B, .upper = &[_]u21{}, .lower = &[_]u21{0x1F43}, .fold = &[_]u21{0x1F43} },
+    .{ .codepoint = 0x1F4C, .upper = &[_]u21{}, .lower = &[_]u21{0x1F44}, .fold = &[_]u21{0x1F44} },
+    .{ .codepoint = 0x1F4D, .upper = &[_]u21{}, .lower = &[_]u21{0x1F45}, .fold = &[_]u21{0x1F45} },
+    .{ .codepoint = 0x1F50, .upper = &[_]u21{ 0x03A5, 0x0313 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x03C5, 0x0313 } },
+    .{ .codepoint = 0x1F51, .upper = &[_]u21{0x1F59}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F52, .upper = &[_]u21{ 0x03A5, 0x0313, 0x0300 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x03C5, 0x0313, 0x0300 } },
+    .{ .codepoint = 0x1F53, .upper = &[_]u21{0x1F5B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F54, .upper = &[_]u21{ 0x03A5, 0x0313, 0x0301 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x03C5, 0x0313, 0x0301 } },
+    .{ .codepoint = 0x1F55, .upper = &[_]u21{0x1F5D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F56, .upper = &[_]u21{ 0x03A5, 0x0313, 0x0342 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x03C5, 0x0313, 0x0342 } },
+    .{ .codepoint = 0x1F57, .upper = &[_]u21{0x1F5F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F59, .upper = &[_]u21{}, .lower = &[_]u21{0x1F51}, .fold = &[_]u21{0x1F51} },
+    .{ .codepoint = 0x1F5B, .upper = &[_]u21{}, .lower = &[_]u21{0x1F53}, .fold = &[_]u21{0x1F53} },
+    .{ .codepoint = 0x1F5D, .upper = &[_]u21{}, .lower = &[_]u21{0x1F55}, .fold = &[_]u21{0x1F55} },
+    .{ .codepoint = 0x1F5F, .upper = &[_]u21{}, .lower = &[_]u21{0x1F57}, .fold = &[_]u21{0x1F57} },
+    .{ .codepoint = 0x1F60, .upper = &[_]u21{0x1F68}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F61, .upper = &[_]u21{0x1F69}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F62, .upper = &[_]u21{0x1F6A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F63, .upper = &[_]u21{0x1F6B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F64, .upper = &[_]u21{0x1F6C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F65, .upper = &[_]u21{0x1F6D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F66, .upper = &[_]u21{0x1F6E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F67, .upper = &[_]u21{0x1F6F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F68, .upper = &[_]u21{}, .lower = &[_]u21{0x1F60}, .fold = &[_]u21{0x1F60} },
+    .{ .codepoint = 0x1F69, .upper = &[_]u21{}, .lower = &[_]u21{0x1F61}, .fold = &[_]u21{0x1F61} },
+    .{ .codepoint = 0x1F6A, .upper = &[_]u21{}, .lower = &[_]u21{0x1F62}, .fold = &[_]u21{0x1F62} },
+    .{ .codepoint = 0x1F6B, .upper = &[_]u21{}, .lower = &[_]u21{0x1F63}, .fold = &[_]u21{0x1F63} },
+    .{ .codepoint = 0x1F6C, .upper = &[_]u21{}, .lower = &[_]u21{0x1F64}, .fold = &[_]u21{0x1F64} },
+    .{ .codepoint = 0x1F6D, .upper = &[_]u21{}, .lower = &[_]u21{0x1F65}, .fold = &[_]u21{0x1F65} },
+    .{ .codepoint = 0x1F6E, .upper = &[_]u21{}, .lower = &[_]u21{0x1F66}, .fold = &[_]u21{0x1F66} },
+    .{ .codepoint = 0x1F6F, .upper = &[_]u21{}, .lower = &[_]u21{0x1F67}, .fold = &[_]u21{0x1F67} },
+    .{ .codepoint = 0x1F70, .upper = &[_]u21{0x1FBA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F71, .upper = &[_]u21{0x1FBB}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F72, .upper = &[_]u21{0x1FC8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F73, .upper = &[_]u21{0x1FC9}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F74, .upper = &[_]u21{0x1FCA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F75, .upper = &[_]u21{0x1FCB}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F76, .upper = &[_]u21{0x1FDA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F77, .upper = &[_]u21{0x1FDB}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F78, .upper = &[_]u21{0x1FF8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F79, .upper = &[_]u21{0x1FF9}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F7A, .upper = &[_]u21{0x1FEA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F7B, .upper = &[_]u21{0x1FEB}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F7C, .upper = &[_]u21{0x1FFA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F7D, .upper = &[_]u21{0x1FFB}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1F80, .upper = &[_]u21{ 0x1F08, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x1F00, 0x03B9 } },
+    .{ .codepoint = 0x1F81, .upper = &[_]u21{ 0x1F09, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x1F01, 0x03B9 } },
+    .{ .codepoint = 0x1F82, .upper = &[_]u21{ 0x1F0A, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x1F02, 0x03B9 } },
+    .{ .codepoint = 0x1F83, .upper = &[_]u21{ 0x1F0B, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x1F03, 0x03B9 } },
+    .{ .codepoint = 0x1F84, .upper = &[_]u21{ 0x1F0C, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x1F04, 0x03B9 } },
+    .{ .codepoint = 0x1F85, .upper = &[_]u21{ 0x1F0D, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x1F05, 0x03B9 } },
+    .{ .codepoint = 0x1F86, .upper = &[_]u21{ 0x1F0E, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x1F06, 0x03B9 } },
+    .{ .codepoint = 0x1F87, .upper = &[_]u21{ 0x1F0F, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x1F07, 0x03B9 } },
+    .{ .codepoint = 0x1F88, .upper = &[_]u21{ 0x1F08, 0x0399 }, .lower = &[_]u21{0x1F80}, .fold = &[_]u21{ 0x1F00, 0x03B9 } },
+    .{ .codepoint = 0x1F89, .upper = &[_]u21{ 0x1F09, 0x0399 }, .lower = &[_]u21{0x1F81}, .fold = &[_]u21{ 0x1F01, 0x03B9 } },
+    .{ .codepoint = 0x1F8A, .upper = &[_]u21{ 0x1F0A, 0x0399 }, .lower = &[_]u21{0x1F82}, .fold = &[_]u21{ 0x1F02, 0x03B9 } },
+    .{ .codepoint = 0x1F8B, .upper = &[_]u21{ 0x1F0B, 0x0399 }, .lower = &[_]u21{0x1F83}, .fold = &[_]u21{ 0x1F03, 0x03B9 } },
+    .{ .codepoint = 0x1F8C, .upper = &[_]u21{ 0x1F0C, 0x0399 }, .lower = &[_]u21{0x1F84}, .fold = &[_]u21{ 0x1F04, 0x03B9 } },
+    .{ .codepoint = 0x1F8D, .upper = &[_]u21{ 0x1F0D, 0x0399 }, .lower = &[_]u21{0x1F85}, .fold = &[_]u21{ 0x1F05, 0x03B9 } },
+    .{ .codepoint = 0x1F8E, .upper = &[_]u21{ 0x1F0E, 0x0399 }, .lower = &[_]u21{0x1F86}, .fold = &[_]u21{ 0x1F06, 0x03B9 } },
+    .{ .codepoint = 0x1F8F, .upper = &[_]u21{ 0x1F0F, 0x0399 }, .lower = &[_]u21{0x1F87}, .fold = &[_]u21{ 0x1F07, 0x03B9 } },
+    .{ .codepoint = 0x1F90, .upper = &[_]u21{ 0x1F28, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x1F20, 0x03B9 } },
+    .{ .codepoint = 0x1F91, .upper = &[_]u21{ 0x1F29, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x1F21, 0x03B9 } },
+    .{ .codepoint = 0x1F92, .upper = &[_]u21{ 0x1F2A, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x1F22, 0x03B9 } },
+    .{ .codepoint = 0x1F93, .upper = &[_]u21{ 0x1F2B, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x1F23, 0x03B9 } },
+    .{ .codepoint = 0x1F94, .upper = &[_]u21{ 0x1F2C, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x1F24, 0x03B9 } },
+    .{ .codepoint = 0x1F95, .upper = &[_]u21{ 0x1F2D, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x1F25, 0x03B9 } },
+    .{ .codepoint = 0x1F96, .upper = &[_]u21{ 0x1F2E, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x1F26, 0x03B9 } },
+    .{ .codepoint = 0x1F97, .upper = &[_]u21{ 0x1F2F, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x1F27, 0x03B9 } },
+    .{ .codepoint = 0x1F98, .upper = &[_]u21{ 0x1F28, 0x0399 }, .lower = &[_]u21{0x1F90}, .fold = &[_]u21{ 0x1F20, 0x03B9 } },
+    .{ .codepoint = 0x1F99, .upper = &[_]u21{ 0x1F29, 0x0399 }, .lower = &[_]u21{0x1F91}, .fold = &[_]u21{ 0x1F21, 0x03B9 } },
+    .{ .codepoint = 0x1F9A, .upper = &[_]u21{ 0x1F2A, 0x0399 }, .lower = &[_]u21{0x1F92}, .fold = &[_]u21{ 0x1F22, 0x03B9 } },
+    .{ .codepoint = 0x1F9B, .upper = &[_]u21{ 0x1F2B, 0x0399 }, .lower = &[_]u21{0x1F93}, .fold = &[_]u21{ 0x1F23, 0x03B9 } },
+    .{ .codepoint = 0x1F9C, .upper = &[_]u21{ 0x1F2C, 0x0399 }, .lower = &[_]u21{0x1F94}, .fold = &[_]u21{ 0x1F24, 0x03B9 } },
+    .{ .codepoint = 0x1F9D, .upper = &[_]u21{ 0x1F2D, 0x0399 }, .lower = &[_]u21{0x1F95}, .fold = &[_]u21{ 0x1F25, 0x03B9 } },
+    .{ .codepoint = 0x1F9E, .upper = &[_]u21{ 0x1F2E, 0x0399 }, .lower = &[_]u21{0x1F96}, .fold = &[_]u21{ 0x1F26, 0x03B9 } },
+    .{ .codepoint = 0x1F9F, .upper = &[_]u21{ 0x1F2F, 0x0399 }, .lower = &[_]u21{0x1F97}, .fold = &[_]u21{ 0x1F27, 0x03B9 } },
+    .{ .codepoint = 0x1FA0, .upper = &[_]u21{ 0x1F68, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x1F60, 0x03B9 } },
+    .{ .codepoint = 0x1FA1, .upper = &[_]u21{ 0x1F69, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x1F61, 0x03B9 } },
+    .{ .codepoint = 0x1FA2, .upper = &[_]u21{ 0x1F6A, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x1F62, 0x03B9 } },
+    .{ .codepoint = 0x1FA3, .upper = &[_]u21{ 0x1F6B, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x1F63, 0x03B9 } },
+    .{ .codepoint = 0x1FA4, .upper = &[_]u21{ 0x1F6C, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x1F64, 0x03B9 } },
+    .{ .codepoint = 0x1FA5, .upper = &[_]u21{ 0x1F6D, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x1F65, 0x03B9 } },
+    .{ .codepoint = 0x1FA6, .upper = &[_]u21{ 0x1F6E, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x1F66, 0x03B9 } },
+    .{ .codepoint = 0x1FA7, .upper = &[_]u21{ 0x1F6F, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x1F67, 0x03B9 } },
+    .{ .codepoint = 0x1FA8, .upper = &[_]u21{ 0x1F68, 0x0399 }, .lower = &[_]u21{0x1FA0}, .fold = &[_]u21{ 0x1F60, 0x03B9 } },
+    .{ .codepoint = 0x1FA9, .upper = &[_]u21{ 0x1F69, 0x0399 }, .lower = &[_]u21{0x1FA1}, .fold = &[_]u21{ 0x1F61, 0x03B9 } },
+    .{ .codepoint = 0x1FAA, .upper = &[_]u21{ 0x1F6A, 0x0399 }, .lower = &[_]u21{0x1FA2}, .fold = &[_]u21{ 0x1F62, 0x03B9 } },
+    .{ .codepoint = 0x1FAB, .upper = &[_]u21{ 0x1F6B, 0x0399 }, .lower = &[_]u21{0x1FA3}, .fold = &[_]u21{ 0x1F63, 0x03B9 } },
+    .{ .codepoint = 0x1FAC, .upper = &[_]u21{ 0x1F6C, 0x0399 }, .lower = &[_]u21{0x1FA4}, .fold = &[_]u21{ 0x1F64, 0x03B9 } },
+    .{ .codepoint = 0x1FAD, .upper = &[_]u21{ 0x1F6D, 0x0399 }, .lower = &[_]u21{0x1FA5}, .fold = &[_]u21{ 0x1F65, 0x03B9 } },
+    .{ .codepoint = 0x1FAE, .upper = &[_]u21{ 0x1F6E, 0x0399 }, .lower = &[_]u21{0x1FA6}, .fold = &[_]u21{ 0x1F66, 0x03B9 } },
+    .{ .codepoint = 0x1FAF, .upper = &[_]u21{ 0x1F6F, 0x0399 }, .lower = &[_]u21{0x1FA7}, .fold = &[_]u21{ 0x1F67, 0x03B9 } },
+    .{ .codepoint = 0x1FB0, .upper = &[_]u21{0x1FB8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1FB1, .upper = &[_]u21{0x1FB9}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1FB2, .upper = &[_]u21{ 0x1FBA, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x1F70, 0x03B9 } },
+    .{ .codepoint = 0x1FB3, .upper = &[_]u21{ 0x0391, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x03B1, 0x03B9 } },
+    .{ .codepoint = 0x1FB4, .upper = &[_]u21{ 0x0386, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x03AC, 0x03B9 } },
+    .{ .codepoint = 0x1FB6, .upper = &[_]u21{ 0x0391, 0x0342 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x03B1, 0x0342 } },
+    .{ .codepoint = 0x1FB7, .upper = &[_]u21{ 0x0391, 0x0342, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x03B1, 0x0342, 0x03B9 } },
+    .{ .codepoint = 0x1FB8, .upper = &[_]u21{}, .lower = &[_]u21{0x1FB0}, .fold = &[_]u21{0x1FB0} },
+    .{ .codepoint = 0x1FB9, .upper = &[_]u21{}, .lower = &[_]u21{0x1FB1}, .fold = &[_]u21{0x1FB1} },
+    .{ .codepoint = 0x1FBA, .upper = &[_]u21{}, .lower = &[_]u21{0x1F70}, .fold = &[_]u21{0x1F70} },
+    .{ .codepoint = 0x1FBB, .upper = &[_]u21{}, .lower = &[_]u21{0x1F71}, .fold = &[_]u21{0x1F71} },
+    .{ .codepoint = 0x1FBC, .upper = &[_]u21{ 0x0391, 0x0399 }, .lower = &[_]u21{0x1FB3}, .fold = &[_]u21{ 0x03B1, 0x03B9 } },
+    .{ .codepoint = 0x1FBE, .upper = &[_]u21{0x0399}, .lower = &[_]u21{}, .fold = &[_]u21{0x03B9} },
+    .{ .codepoint = 0x1FC2, .upper = &[_]u21{ 0x1FCA, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x1F74, 0x03B9 } },
+    .{ .codepoint = 0x1FC3, .upper = &[_]u21{ 0x0397, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x03B7, 0x03B9 } },
+    .{ .codepoint = 0x1FC4, .upper = &[_]u21{ 0x0389, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x03AE, 0x03B9 } },
+    .{ .codepoint = 0x1FC6, .upper = &[_]u21{ 0x0397, 0x0342 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x03B7, 0x0342 } },
+    .{ .codepoint = 0x1FC7, .upper = &[_]u21{ 0x0397, 0x0342, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x03B7, 0x0342, 0x03B9 } },
+    .{ .codepoint = 0x1FC8, .upper = &[_]u21{}, .lower = &[_]u21{0x1F72}, .fold = &[_]u21{0x1F72} },
+    .{ .codepoint = 0x1FC9, .upper = &[_]u21{}, .lower = &[_]u21{0x1F73}, .fold = &[_]u21{0x1F73} },
+    .{ .codepoint = 0x1FCA, .upper = &[_]u21{}, .lower = &[_]u21{0x1F74}, .fold = &[_]u21{0x1F74} },
+    .{ .codepoint = 0x1FCB, .upper = &[_]u21{}, .lower = &[_]u21{0x1F75}, .fold = &[_]u21{0x1F75} },
+    .{ .codepoint = 0x1FCC, .upper = &[_]u21{ 0x0397, 0x0399 }, .lower = &[_]u21{0x1FC3}, .fold = &[_]u21{ 0x03B7, 0x03B9 } },
+    .{ .codepoint = 0x1FD0, .upper = &[_]u21{0x1FD8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1FD1, .upper = &[_]u21{0x1FD9}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1FD2, .upper = &[_]u21{ 0x0399, 0x0308, 0x0300 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x03B9, 0x0308, 0x0300 } },
+    .{ .codepoint = 0x1FD3, .upper = &[_]u21{ 0x0399, 0x0308, 0x0301 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x03B9, 0x0308, 0x0301 } },
+    .{ .codepoint = 0x1FD6, .upper = &[_]u21{ 0x0399, 0x0342 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x03B9, 0x0342 } },
+    .{ .codepoint = 0x1FD7, .upper = &[_]u21{ 0x0399, 0x0308, 0x0342 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x03B9, 0x0308, 0x0342 } },
+    .{ .codepoint = 0x1FD8, .upper = &[_]u21{}, .lower = &[_]u21{0x1FD0}, .fold = &[_]u21{0x1FD0} },
+    .{ .codepoint = 0x1FD9, .upper = &[_]u21{}, .lower = &[_]u21{0x1FD1}, .fold = &[_]u21{0x1FD1} },
+    .{ .codepoint = 0x1FDA, .upper = &[_]u21{}, .lower = &[_]u21{0x1F76}, .fold = &[_]u21{0x1F76} },
+    .{ .codepoint = 0x1FDB, .upper = &[_]u21{}, .lower = &[_]u21{0x1F77}, .fold = &[_]u21{0x1F77} },
+    .{ .codepoint = 0x1FE0, .upper = &[_]u21{0x1FE8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1FE1, .upper = &[_]u21{0x1FE9}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1FE2, .upper = &[_]u21{ 0x03A5, 0x0308, 0x0300 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x03C5, 0x0308, 0x0300 } },
+    .{ .codepoint = 0x1FE3, .upper = &[_]u21{ 0x03A5, 0x0308, 0x0301 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x03C5, 0x0308, 0x0301 } },
+    .{ .codepoint = 0x1FE4, .upper = &[_]u21{ 0x03A1, 0x0313 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x03C1, 0x0313 } },
+    .{ .codepoint = 0x1FE5, .upper = &[_]u21{0x1FEC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1FE6, .upper = &[_]u21{ 0x03A5, 0x0342 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x03C5, 0x0342 } },
+    .{ .codepoint = 0x1FE7, .upper = &[_]u21{ 0x03A5, 0x0308, 0x0342 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x03C5, 0x0308, 0x0342 } },
+    .{ .codepoint = 0x1FE8, .upper = &[_]u21{}, .lower = &[_]u21{0x1FE0}, .fold = &[_]u21{0x1FE0} },
+    .{ .codepoint = 0x1FE9, .upper = &[_]u21{}, .lower = &[_]u21{0x1FE1}, .fold = &[_]u21{0x1FE1} },
+    .{ .codepoint = 0x1FEA, .upper = &[_]u21{}, .lower = &[_]u21{0x1F7A}, .fold = &[_]u21{0x1F7A} },
+    .{ .codepoint = 0x1FEB, .upper = &[_]u21{}, .lower = &[_]u21{0x1F7B}, .fold = &[_]u21{0x1F7B} },
+    .{ .codepoint = 0x1FEC, .upper = &[_]u21{}, .lower = &[_]u21{0x1FE5}, .fold = &[_]u21{0x1FE5} },
+    .{ .codepoint = 0x1FF2, .upper = &[_]u21{ 0x1FFA, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x1F7C, 0x03B9 } },
+    .{ .codepoint = 0x1FF3, .upper = &[_]u21{ 0x03A9, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x03C9, 0x03B9 } },
+    .{ .codepoint = 0x1FF4, .upper = &[_]u21{ 0x038F, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x03CE, 0x03B9 } },
+    .{ .codepoint = 0x1FF6, .upper = &[_]u21{ 0x03A9, 0x0342 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x03C9, 0x0342 } },
+    .{ .codepoint = 0x1FF7, .upper = &[_]u21{ 0x03A9, 0x0342, 0x0399 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x03C9, 0x0342, 0x03B9 } },
+    .{ .codepoint = 0x1FF8, .upper = &[_]u21{}, .lower = &[_]u21{0x1F78}, .fold = &[_]u21{0x1F78} },
+    .{ .codepoint = 0x1FF9, .upper = &[_]u21{}, .lower = &[_]u21{0x1F79}, .fold = &[_]u21{0x1F79} },
+    .{ .codepoint = 0x1FFA, .upper = &[_]u21{}, .lower = &[_]u21{0x1F7C}, .fold = &[_]u21{0x1F7C} },
+    .{ .codepoint = 0x1FFB, .upper = &[_]u21{}, .lower = &[_]u21{0x1F7D}, .fold = &[_]u21{0x1F7D} },
+    .{ .codepoint = 0x1FFC, .upper = &[_]u21{ 0x03A9, 0x0399 }, .lower = &[_]u21{0x1FF3}, .fold = &[_]u21{ 0x03C9, 0x03B9 } },
+    .{ .codepoint = 0x2126, .upper = &[_]u21{}, .lower = &[_]u21{0x03C9}, .fold = &[_]u21{0x03C9} },
+    .{ .codepoint = 0x212A, .upper = &[_]u21{}, .lower = &[_]u21{0x006B}, .fold = &[_]u21{0x006B} },
+    .{ .codepoint = 0x212B, .upper = &[_]u21{}, .lower = &[_]u21{0x00E5}, .fold = &[_]u21{0x00E5} },
+    .{ .codepoint = 0x2132, .upper = &[_]u21{}, .lower = &[_]u21{0x214E}, .fold = &[_]u21{0x214E} },
+    .{ .codepoint = 0x214E, .upper = &[_]u21{0x2132}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2160, .upper = &[_]u21{}, .lower = &[_]u21{0x2170}, .fold = &[_]u21{0x2170} },
+    .{ .codepoint = 0x2161, .upper = &[_]u21{}, .lower = &[_]u21{0x2171}, .fold = &[_]u21{0x2171} },
+    .{ .codepoint = 0x2162, .upper = &[_]u21{}, .lower = &[_]u21{0x2172}, .fold = &[_]u21{0x2172} },
+    .{ .codepoint = 0x2163, .upper = &[_]u21{}, .lower = &[_]u21{0x2173}, .fold = &[_]u21{0x2173} },
+    .{ .codepoint = 0x2164, .upper = &[_]u21{}, .lower = &[_]u21{0x2174}, .fold = &[_]u21{0x2174} },
+    .{ .codepoint = 0x2165, .upper = &[_]u21{}, .lower = &[_]u21{0x2175}, .fold = &[_]u21{0x2175} },
+    .{ .codepoint = 0x2166, .upper = &[_]u21{}, .lower = &[_]u21{0x2176}, .fold = &[_]u21{0x2176} },
+    .{ .codepoint = 0x2167, .upper = &[_]u21{}, .lower = &[_]u21{0x2177}, .fold = &[_]u21{0x2177} },
+    .{ .codepoint = 0x2168, .upper = &[_]u21{}, .lower = &[_]u21{0x2178}, .fold = &[_]u21{0x2178} },
+    .{ .codepoint = 0x2169, .upper = &[_]u21{}, .lower = &[_]u21{0x2179}, .fold = &[_]u21{0x2179} },
+    .{ .codepoint = 0x216A, .upper = &[_]u21{}, .lower = &[_]u21{0x217A}, .fold = &[_]u21{0x217A} },
+    .{ .codepoint = 0x216B, .upper = &[_]u21{}, .lower = &[_]u21{0x217B}, .fold = &[_]u21{0x217B} },
+    .{ .codepoint = 0x216C, .upper = &[_]u21{}, .lower = &[_]u21{0x217C}, .fold = &[_]u21{0x217C} },
+    .{ .codepoint = 0x216D, .upper = &[_]u21{}, .lower = &[_]u21{0x217D}, .fold = &[_]u21{0x217D} },
+    .{ .codepoint = 0x216E, .upper = &[_]u21{}, .lower = &[_]u21{0x217E}, .fold = &[_]u21{0x217E} },
+    .{ .codepoint = 0x216F, .upper = &[_]u21{}, .lower = &[_]u21{0x217F}, .fold = &[_]u21{0x217F} },
+    .{ .codepoint = 0x2170, .upper = &[_]u21{0x2160}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2171, .upper = &[_]u21{0x2161}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2172, .upper = &[_]u21{0x2162}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2173, .upper = &[_]u21{0x2163}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2174, .upper = &[_]u21{0x2164}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2175, .upper = &[_]u21{0x2165}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2176, .upper = &[_]u21{0x2166}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2177, .upper = &[_]u21{0x2167}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2178, .upper = &[_]u21{0x2168}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2179, .upper = &[_]u21{0x2169}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x217A, .upper = &[_]u21{0x216A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x217B, .upper = &[_]u21{0x216B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x217C, .upper = &[_]u21{0x216C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x217D, .upper = &[_]u21{0x216D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x217E, .upper = &[_]u21{0x216E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x217F, .upper = &[_]u21{0x216F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2183, .upper = &[_]u21{}, .lower = &[_]u21{0x2184}, .fold = &[_]u21{0x2184} },
+    .{ .codepoint = 0x2184, .upper = &[_]u21{0x2183}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x24B6, .upper = &[_]u21{}, .lower = &[_]u21{0x24D0}, .fold = &[_]u21{0x24D0} },
+    .{ .codepoint = 0x24B7, .upper = &[_]u21{}, .lower = &[_]u21{0x24D1}, .fold = &[_]u21{0x24D1} },
+    .{ .codepoint = 0x24B8, .upper = &[_]u21{}, .lower = &[_]u21{0x24D2}, .fold = &[_]u21{0x24D2} },
+    .{ .codepoint = 0x24B9, .upper = &[_]u21{}, .lower = &[_]u21{0x24D3}, .fold = &[_]u21{0x24D3} },
+    .{ .codepoint = 0x24BA, .upper = &[_]u21{}, .lower = &[_]u21{0x24D4}, .fold = &[_]u21{0x24D4} },
+    .{ .codepoint = 0x24BB, .upper = &[_]u21{}, .lower = &[_]u21{0x24D5}, .fold = &[_]u21{0x24D5} },
+    .{ .codepoint = 0x24BC, .upper = &[_]u21{}, .lower = &[_]u21{0x24D6}, .fold = &[_]u21{0x24D6} },
+    .{ .codepoint = 0x24BD, .upper = &[_]u21{}, .lower = &[_]u21{0x24D7}, .fold = &[_]u21{0x24D7} },
+    .{ .codepoint = 0x24BE, .upper = &[_]u21{}, .lower = &[_]u21{0x24D8}, .fold = &[_]u21{0x24D8} },
+    .{ .codepoint = 0x24BF, .upper = &[_]u21{}, .lower = &[_]u21{0x24D9}, .fold = &[_]u21{0x24D9} },
+    .{ .codepoint = 0x24C0, .upper = &[_]u21{}, .lower = &[_]u21{0x24DA}, .fold = &[_]u21{0x24DA} },
+    .{ .codepoint = 0x24C1, .upper = &[_]u21{}, .lower = &[_]u21{0x24DB}, .fold = &[_]u21{0x24DB} },
+    .{ .codepoint = 0x24C2, .upper = &[_]u21{}, .lower = &[_]u21{0x24DC}, .fold = &[_]u21{0x24DC} },
+    .{ .codepoint = 0x24C3, .upper = &[_]u21{}, .lower = &[_]u21{0x24DD}, .fold = &[_]u21{0x24DD} },
+    .{ .codepoint = 0x24C4, .upper = &[_]u21{}, .lower = &[_]u21{0x24DE}, .fold = &[_]u21{0x24DE} },
+    .{ .codepoint = 0x24C5, .upper = &[_]u21{}, .lower = &[_]u21{0x24DF}, .fold = &[_]u21{0x24DF} },
+    .{ .codepoint = 0x24C6, .upper = &[_]u21{}, .lower = &[_]u21{0x24E0}, .fold = &[_]u21{0x24E0} },
+    .{ .codepoint = 0x24C7, .upper = &[_]u21{}, .lower = &[_]u21{0x24E1}, .fold = &[_]u21{0x24E1} },
+    .{ .codepoint = 0x24C8, .upper = &[_]u21{}, .lower = &[_]u21{0x24E2}, .fold = &[_]u21{0x24E2} },
+    .{ .codepoint = 0x24C9, .upper = &[_]u21{}, .lower = &[_]u21{0x24E3}, .fold = &[_]u21{0x24E3} },
+    .{ .codepoint = 0x24CA, .upper = &[_]u21{}, .lower = &[_]u21{0x24E4}, .fold = &[_]u21{0x24E4} },
+    .{ .codepoint = 0x24CB, .upper = &[_]u21{}, .lower = &[_]u21{0x24E5}, .fold = &[_]u21{0x24E5} },
+    .{ .codepoint = 0x24CC, .upper = &[_]u21{}, .lower = &[_]u21{0x24E6}, .fold = &[_]u21{0x24E6} },
+    .{ .codepoint = 0x24CD, .upper = &[_]u21{}, .lower = &[_]u21{0x24E7}, .fold = &[_]u21{0x24E7} },
+    .{ .codepoint = 0x24CE, .upper = &[_]u21{}, .lower = &[_]u21{0x24E8}, .fold = &[_]u21{0x24E8} },
+    .{ .codepoint = 0x24CF, .upper = &[_]u21{}, .lower = &[_]u21{0x24E9}, .fold = &[_]u21{0x24E9} },
+    .{ .codepoint = 0x24D0, .upper = &[_]u21{0x24B6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x24D1, .upper = &[_]u21{0x24B7}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x24D2, .upper = &[_]u21{0x24B8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x24D3, .upper = &[_]u21{0x24B9}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x24D4, .upper = &[_]u21{0x24BA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x24D5, .upper = &[_]u21{0x24BB}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x24D6, .upper = &[_]u21{0x24BC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x24D7, .upper = &[_]u21{0x24BD}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x24D8, .upper = &[_]u21{0x24BE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x24D9, .upper = &[_]u21{0x24BF}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x24DA, .upper = &[_]u21{0x24C0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x24DB, .upper = &[_]u21{0x24C1}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x24DC, .upper = &[_]u21{0x24C2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x24DD, .upper = &[_]u21{0x24C3}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x24DE, .upper = &[_]u21{0x24C4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x24DF, .upper = &[_]u21{0x24C5}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x24E0, .upper = &[_]u21{0x24C6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x24E1, .upper = &[_]u21{0x24C7}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x24E2, .upper = &[_]u21{0x24C8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x24E3, .upper = &[_]u21{0x24C9}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x24E4, .upper = &[_]u21{0x24CA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x24E5, .upper = &[_]u21{0x24CB}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x24E6, .upper = &[_]u21{0x24CC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x24E7, .upper = &[_]u21{0x24CD}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x24E8, .upper = &[_]u21{0x24CE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x24E9, .upper = &[_]u21{0x24CF}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C00, .upper = &[_]u21{}, .lower = &[_]u21{0x2C30}, .fold = &[_]u21{0x2C30} },
+    .{ .codepoint = 0x2C01, .upper = &[_]u21{}, .lower = &[_]u21{0x2C31}, .fold = &[_]u21{0x2C31} },
+    .{ .codepoint = 0x2C02, .upper = &[_]u21{}, .lower = &[_]u21{0x2C32}, .fold = &[_]u21{0x2C32} },
+    .{ .codepoint = 0x2C03, .upper = &[_]u21{}, .lower = &[_]u21{0x2C33}, .fold = &[_]u21{0x2C33} },
+    .{ .codepoint = 0x2C04, .upper = &[_]u21{}, .lower = &[_]u21{0x2C34}, .fold = &[_]u21{0x2C34} },
+    .{ .codepoint = 0x2C05, .upper = &[_]u21{}, .lower = &[_]u21{0x2C35}, .fold = &[_]u21{0x2C35} },
+    .{ .codepoint = 0x2C06, .upper = &[_]u21{}, .lower = &[_]u21{0x2C36}, .fold = &[_]u21{0x2C36} },
+    .{ .codepoint = 0x2C07, .upper = &[_]u21{}, .lower = &[_]u21{0x2C37}, .fold = &[_]u21{0x2C37} },
+    .{ .codepoint = 0x2C08, .upper = &[_]u21{}, .lower = &[_]u21{0x2C38}, .fold = &[_]u21{0x2C38} },
+    .{ .codepoint = 0x2C09, .upper = &[_]u21{}, .lower = &[_]u21{0x2C39}, .fold = &[_]u21{0x2C39} },
+    .{ .codepoint = 0x2C0A, .upper = &[_]u21{}, .lower = &[_]u21{0x2C3A}, .fold = &[_]u21{0x2C3A} },
+    .{ .codepoint = 0x2C0B, .upper = &[_]u21{}, .lower = &[_]u21{0x2C3B}, .fold = &[_]u21{0x2C3B} },
+    .{ .codepoint = 0x2C0C, .upper = &[_]u21{}, .lower = &[_]u21{0x2C3C}, .fold = &[_]u21{0x2C3C} },
+    .{ .codepoint = 0x2C0D, .upper = &[_]u21{}, .lower = &[_]u21{0x2C3D}, .fold = &[_]u21{0x2C3D} },
+    .{ .codepoint = 0x2C0E, .upper = &[_]u21{}, .lower = &[_]u21{0x2C3E}, .fold = &[_]u21{0x2C3E} },
+    .{ .codepoint = 0x2C0F, .upper = &[_]u21{}, .lower = &[_]u21{0x2C3F}, .fold = &[_]u21{0x2C3F} },
+    .{ .codepoint = 0x2C10, .upper = &[_]u21{}, .lower = &[_]u21{0x2C40}, .fold = &[_]u21{0x2C40} },
+    .{ .codepoint = 0x2C11, .upper = &[_]u21{}, .lower = &[_]u21{0x2C41}, .fold = &[_]u21{0x2C41} },
+    .{ .codepoint = 0x2C12, .upper = &[_]u21{}, .lower = &[_]u21{0x2C42}, .fold = &[_]u21{0x2C42} },
+    .{ .codepoint = 0x2C13, .upper = &[_]u21{}, .lower = &[_]u21{0x2C43}, .fold = &[_]u21{0x2C43} },
+    .{ .codepoint = 0x2C14, .upper = &[_]u21{}, .lower = &[_]u21{0x2C44}, .fold = &[_]u21{0x2C44} },
+    .{ .codepoint = 0x2C15, .upper = &[_]u21{}, .lower = &[_]u21{0x2C45}, .fold = &[_]u21{0x2C45} },
+    .{ .codepoint = 0x2C16, .upper = &[_]u21{}, .lower = &[_]u21{0x2C46}, .fold = &[_]u21{0x2C46} },
+    .{ .codepoint = 0x2C17, .upper = &[_]u21{}, .lower = &[_]u21{0x2C47}, .fold = &[_]u21{0x2C47} },
+    .{ .codepoint = 0x2C18, .upper = &[_]u21{}, .lower = &[_]u21{0x2C48}, .fold = &[_]u21{0x2C48} },
+    .{ .codepoint = 0x2C19, .upper = &[_]u21{}, .lower = &[_]u21{0x2C49}, .fold = &[_]u21{0x2C49} },
+    .{ .codepoint = 0x2C1A, .upper = &[_]u21{}, .lower = &[_]u21{0x2C4A}, .fold = &[_]u21{0x2C4A} },
+    .{ .codepoint = 0x2C1B, .upper = &[_]u21{}, .lower = &[_]u21{0x2C4B}, .fold = &[_]u21{0x2C4B} },
+    .{ .codepoint = 0x2C1C, .upper = &[_]u21{}, .lower = &[_]u21{0x2C4C}, .fold = &[_]u21{0x2C4C} },
+    .{ .codepoint = 0x2C1D, .upper = &[_]u21{}, .lower = &[_]u21{0x2C4D}, .fold = &[_]u21{0x2C4D} },
+    .{ .codepoint = 0x2C1E, .upper = &[_]u21{}, .lower = &[_]u21{0x2C4E}, .fold = &[_]u21{0x2C4E} },
+    .{ .codepoint = 0x2C1F, .upper = &[_]u21{}, .lower = &[_]u21{0x2C4F}, .fold = &[_]u21{0x2C4F} },
+    .{ .codepoint = 0x2C20, .upper = &[_]u21{}, .lower = &[_]u21{0x2C50}, .fold = &[_]u21{0x2C50} },
+    .{ .codepoint = 0x2C21, .upper = &[_]u21{}, .lower = &[_]u21{0x2C51}, .fold = &[_]u21{0x2C51} },
+    .{ .codepoint = 0x2C22, .upper = &[_]u21{}, .lower = &[_]u21{0x2C52}, .fold = &[_]u21{0x2C52} },
+    .{ .codepoint = 0x2C23, .upper = &[_]u21{}, .lower = &[_]u21{0x2C53}, .fold = &[_]u21{0x2C53} },
+    .{ .codepoint = 0x2C24, .upper = &[_]u21{}, .lower = &[_]u21{0x2C54}, .fold = &[_]u21{0x2C54} },
+    .{ .codepoint = 0x2C25, .upper = &[_]u21{}, .lower = &[_]u21{0x2C55}, .fold = &[_]u21{0x2C55} },
+    .{ .codepoint = 0x2C26, .upper = &[_]u21{}, .lower = &[_]u21{0x2C56}, .fold = &[_]u21{0x2C56} },
+    .{ .codepoint = 0x2C27, .upper = &[_]u21{}, .lower = &[_]u21{0x2C57}, .fold = &[_]u21{0x2C57} },
+    .{ .codepoint = 0x2C28, .upper = &[_]u21{}, .lower = &[_]u21{0x2C58}, .fold = &[_]u21{0x2C58} },
+    .{ .codepoint = 0x2C29, .upper = &[_]u21{}, .lower = &[_]u21{0x2C59}, .fold = &[_]u21{0x2C59} },
+    .{ .codepoint = 0x2C2A, .upper = &[_]u21{}, .lower = &[_]u21{0x2C5A}, .fold = &[_]u21{0x2C5A} },
+    .{ .codepoint = 0x2C2B, .upper = &[_]u21{}, .lower = &[_]u21{0x2C5B}, .fold = &[_]u21{0x2C5B} },
+    .{ .codepoint = 0x2C2C, .upper = &[_]u21{}, .lower = &[_]u21{0x2C5C}, .fold = &[_]u21{0x2C5C} },
+    .{ .codepoint = 0x2C2D, .upper = &[_]u21{}, .lower = &[_]u21{0x2C5D}, .fold = &[_]u21{0x2C5D} },
+    .{ .codepoint = 0x2C2E, .upper = &[_]u21{}, .lower = &[_]u21{0x2C5E}, .fold = &[_]u21{0x2C5E} },
+    .{ .codepoint = 0x2C2F, .upper = &[_]u21{}, .lower = &[_]u21{0x2C5F}, .fold = &[_]u21{0x2C5F} },
+    .{ .codepoint = 0x2C30, .upper = &[_]u21{0x2C00}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C31, .upper = &[_]u21{0x2C01}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C32, .upper = &[_]u21{0x2C02}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C33, .upper = &[_]u21{0x2C03}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C34, .upper = &[_]u21{0x2C04}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C35, .upper = &[_]u21{0x2C05}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C36, .upper = &[_]u21{0x2C06}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C37, .upper = &[_]u21{0x2C07}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C38, .upper = &[_]u21{0x2C08}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C39, .upper = &[_]u21{0x2C09}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C3A, .upper = &[_]u21{0x2C0A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C3B, .upper = &[_]u21{0x2C0B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C3C, .upper = &[_]u21{0x2C0C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C3D, .upper = &[_]u21{0x2C0D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C3E, .upper = &[_]u21{0x2C0E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C3F, .upper = &[_]u21{0x2C0F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C40, .upper = &[_]u21{0x2C10}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C41, .upper = &[_]u21{0x2C11}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C42, .upper = &[_]u21{0x2C12}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C43, .upper = &[_]u21{0x2C13}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C44, .upper = &[_]u21{0x2C14}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C45, .upper = &[_]u21{0x2C15}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C46, .upper = &[_]u21{0x2C16}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C47, .upper = &[_]u21{0x2C17}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C48, .upper = &[_]u21{0x2C18}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C49, .upper = &[_]u21{0x2C19}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C4A, .upper = &[_]u21{0x2C1A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C4B, .upper = &[_]u21{0x2C1B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C4C, .upper = &[_]u21{0x2C1C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C4D, .upper = &[_]u21{0x2C1D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C4E, .upper = &[_]u21{0x2C1E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C4F, .upper = &[_]u21{0x2C1F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C50, .upper = &[_]u21{0x2C20}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C51, .upper = &[_]u21{0x2C21}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C52, .upper = &[_]u21{0x2C22}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C53, .upper = &[_]u21{0x2C23}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C54, .upper = &[_]u21{0x2C24}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C55, .upper = &[_]u21{0x2C25}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C56, .upper = &[_]u21{0x2C26}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C57, .upper = &[_]u21{0x2C27}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C58, .upper = &[_]u21{0x2C28}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C59, .upper = &[_]u21{0x2C29}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C5A, .upper = &[_]u21{0x2C2A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C5B, .upper = &[_]u21{0x2C2B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C5C, .upper = &[_]u21{0x2C2C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C5D, .upper = &[_]u21{0x2C2D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C5E, .upper = &[_]u21{0x2C2E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C5F, .upper = &[_]u21{0x2C2F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C60, .upper = &[_]u21{}, .lower = &[_]u21{0x2C61}, .fold = &[_]u21{0x2C61} },
+    .{ .codepoint = 0x2C61, .upper = &[_]u21{0x2C60}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C62, .upper = &[_]u21{}, .lower = &[_]u21{0x026B}, .fold = &[_]u21{0x026B} },
+    .{ .codepoint = 0x2C63, .upper = &[_]u21{}, .lower = &[_]u21{0x1D7D}, .fold = &[_]u21{0x1D7D} },
+    .{ .codepoint = 0x2C64, .upper = &[_]u21{}, .lower = &[_]u21{0x027D}, .fold = &[_]u21{0x027D} },
+    .{ .codepoint = 0x2C65, .upper = &[_]u21{0x023A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C66, .upper = &[_]u21{0x023E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C67, .upper = &[_]u21{}, .lower = &[_]u21{0x2C68}, .fold = &[_]u21{0x2C68} },
+    .{ .codepoint = 0x2C68, .upper = &[_]u21{0x2C67}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C69, .upper = &[_]u21{}, .lower = &[_]u21{0x2C6A}, .fold = &[_]u21{0x2C6A} },
+    .{ .codepoint = 0x2C6A, .upper = &[_]u21{0x2C69}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C6B, .upper = &[_]u21{}, .lower = &[_]u21{0x2C6C}, .fold = &[_]u21{0x2C6C} },
+    .{ .codepoint = 0x2C6C, .upper = &[_]u21{0x2C6B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C6D, .upper = &[_]u21{}, .lower = &[_]u21{0x0251}, .fold = &[_]u21{0x0251} },
+    .{ .codepoint = 0x2C6E, .upper = &[_]u21{}, .lower = &[_]u21{0x0271}, .fold = &[_]u21{0x0271} },
+    .{ .codepoint = 0x2C6F, .upper = &[_]u21{}, .lower = &[_]u21{0x0250}, .fold = &[_]u21{0x0250} },
+    .{ .codepoint = 0x2C70, .upper = &[_]u21{}, .lower = &[_]u21{0x0252}, .fold = &[_]u21{0x0252} },
+    .{ .codepoint = 0x2C72, .upper = &[_]u21{}, .lower = &[_]u21{0x2C73}, .fold = &[_]u21{0x2C73} },
+    .{ .codepoint = 0x2C73, .upper = &[_]u21{0x2C72}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C75, .upper = &[_]u21{}, .lower = &[_]u21{0x2C76}, .fold = &[_]u21{0x2C76} },
+    .{ .codepoint = 0x2C76, .upper = &[_]u21{0x2C75}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C7E, .upper = &[_]u21{}, .lower = &[_]u21{0x023F}, .fold = &[_]u21{0x023F} },
+    .{ .codepoint = 0x2C7F, .upper = &[_]u21{}, .lower = &[_]u21{0x0240}, .fold = &[_]u21{0x0240} },
+    .{ .codepoint = 0x2C80, .upper = &[_]u21{}, .lower = &[_]u21{0x2C81}, .fold = &[_]u21{0x2C81} },
+    .{ .codepoint = 0x2C81, .upper = &[_]u21{0x2C80}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C82, .upper = &[_]u21{}, .lower = &[_]u21{0x2C83}, .fold = &[_]u21{0x2C83} },
+    .{ .codepoint = 0x2C83, .upper = &[_]u21{0x2C82}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C84, .upper = &[_]u21{}, .lower = &[_]u21{0x2C85}, .fold = &[_]u21{0x2C85} },
+    .{ .codepoint = 0x2C85, .upper = &[_]u21{0x2C84}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C86, .upper = &[_]u21{}, .lower = &[_]u21{0x2C87}, .fold = &[_]u21{0x2C87} },
+    .{ .codepoint = 0x2C87, .upper = &[_]u21{0x2C86}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C88, .upper = &[_]u21{}, .lower = &[_]u21{0x2C89}, .fold = &[_]u21{0x2C89} },
+    .{ .codepoint = 0x2C89, .upper = &[_]u21{0x2C88}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C8A, .upper = &[_]u21{}, .lower = &[_]u21{0x2C8B}, .fold = &[_]u21{0x2C8B} },
+    .{ .codepoint = 0x2C8B, .upper = &[_]u21{0x2C8A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C8C, .upper = &[_]u21{}, .lower = &[_]u21{0x2C8D}, .fold = &[_]u21{0x2C8D} },
+    .{ .codepoint = 0x2C8D, .upper = &[_]u21{0x2C8C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C8E, .upper = &[_]u21{}, .lower = &[_]u21{0x2C8F}, .fold = &[_]u21{0x2C8F} },
+    .{ .codepoint = 0x2C8F, .upper = &[_]u21{0x2C8E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C90, .upper = &[_]u21{}, .lower = &[_]u21{0x2C91}, .fold = &[_]u21{0x2C91} },
+    .{ .codepoint = 0x2C91, .upper = &[_]u21{0x2C90}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C92, .upper = &[_]u21{}, .lower = &[_]u21{0x2C93}, .fold = &[_]u21{0x2C93} },
+    .{ .codepoint = 0x2C93, .upper = &[_]u21{0x2C92}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C94, .upper = &[_]u21{}, .lower = &[_]u21{0x2C95}, .fold = &[_]u21{0x2C95} },
+    .{ .codepoint = 0x2C95, .upper = &[_]u21{0x2C94}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C96, .upper = &[_]u21{}, .lower = &[_]u21{0x2C97}, .fold = &[_]u21{0x2C97} },
+    .{ .codepoint = 0x2C97, .upper = &[_]u21{0x2C96}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C98, .upper = &[_]u21{}, .lower = &[_]u21{0x2C99}, .fold = &[_]u21{0x2C99} },
+    .{ .codepoint = 0x2C99, .upper = &[_]u21{0x2C98}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C9A, .upper = &[_]u21{}, .lower = &[_]u21{0x2C9B}, .fold = &[_]u21{0x2C9B} },
+    .{ .codepoint = 0x2C9B, .upper = &[_]u21{0x2C9A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C9C, .upper = &[_]u21{}, .lower = &[_]u21{0x2C9D}, .fold = &[_]u21{0x2C9D} },
+    .{ .codepoint = 0x2C9D, .upper = &[_]u21{0x2C9C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2C9E, .upper = &[_]u21{}, .lower = &[_]u21{0x2C9F}, .fold = &[_]u21{0x2C9F} },
+    .{ .codepoint = 0x2C9F, .upper = &[_]u21{0x2C9E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CA0, .upper = &[_]u21{}, .lower = &[_]u21{0x2CA1}, .fold = &[_]u21{0x2CA1} },
+    .{ .codepoint = 0x2CA1, .upper = &[_]u21{0x2CA0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CA2, .upper = &[_]u21{}, .lower = &[_]u21{0x2CA3}, .fold = &[_]u21{0x2CA3} },
+    .{ .codepoint = 0x2CA3, .upper = &[_]u21{0x2CA2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CA4, .upper = &[_]u21{}, .lower = &[_]u21{0x2CA5}, .fold = &[_]u21{0x2CA5} },
+    .{ .codepoint = 0x2CA5, .upper = &[_]u21{0x2CA4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CA6, .upper = &[_]u21{}, .lower = &[_]u21{0x2CA7}, .fold = &[_]u21{0x2CA7} },
+    .{ .codepoint = 0x2CA7, .upper = &[_]u21{0x2CA6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CA8, .upper = &[_]u21{}, .lower = &[_]u21{0x2CA9}, .fold = &[_]u21{0x2CA9} },
+    .{ .codepoint = 0x2CA9, .upper = &[_]u21{0x2CA8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CAA, .upper = &[_]u21{}, .lower = &[_]u21{0x2CAB}, .fold = &[_]u21{0x2CAB} },
+    .{ .codepoint = 0x2CAB, .upper = &[_]u21{0x2CAA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CAC, .upper = &[_]u21{}, .lower = &[_]u21{0x2CAD}, .fold = &[_]u21{0x2CAD} },
+    .{ .codepoint = 0x2CAD, .upper = &[_]u21{0x2CAC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CAE, .upper = &[_]u21{}, .lower = &[_]u21{0x2CAF}, .fold = &[_]u21{0x2CAF} },
+    .{ .codepoint = 0x2CAF, .upper = &[_]u21{0x2CAE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CB0, .upper = &[_]u21{}, .lower = &[_]u21{0x2CB1}, .fold = &[_]u21{0x2CB1} },
+    .{ .codepoint = 0x2CB1, .upper = &[_]u21{0x2CB0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CB2, .upper = &[_]u21{}, .lower = &[_]u21{0x2CB3}, .fold = &[_]u21{0x2CB3} },
+    .{ .codepoint = 0x2CB3, .upper = &[_]u21{0x2CB2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CB4, .upper = &[_]u21{}, .lower = &[_]u21{0x2CB5}, .fold = &[_]u21{0x2CB5} },
+    .{ .codepoint = 0x2CB5, .upper = &[_]u21{0x2CB4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CB6, .upper = &[_]u21{}, .lower = &[_]u21{0x2CB7}, .fold = &[_]u21{0x2CB7} },
+    .{ .codepoint = 0x2CB7, .upper = &[_]u21{0x2CB6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CB8, .upper = &[_]u21{}, .lower = &[_]u21{0x2CB9}, .fold = &[_]u21{0x2CB9} },
+    .{ .codepoint = 0x2CB9, .upper = &[_]u21{0x2CB8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CBA, .upper = &[_]u21{}, .lower = &[_]u21{0x2CBB}, .fold = &[_]u21{0x2CBB} },
+    .{ .codepoint = 0x2CBB, .upper = &[_]u21{0x2CBA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CBC, .upper = &[_]u21{}, .lower = &[_]u21{0x2CBD}, .fold = &[_]u21{0x2CBD} },
+    .{ .codepoint = 0x2CBD, .upper = &[_]u21{0x2CBC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CBE, .upper = &[_]u21{}, .lower = &[_]u21{0x2CBF}, .fold = &[_]u21{0x2CBF} },
+    .{ .codepoint = 0x2CBF, .upper = &[_]u21{0x2CBE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CC0, .upper = &[_]u21{}, .lower = &[_]u21{0x2CC1}, .fold = &[_]u21{0x2CC1} },
+    .{ .codepoint = 0x2CC1, .upper = &[_]u21{0x2CC0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CC2, .upper = &[_]u21{}, .lower = &[_]u21{0x2CC3}, .fold = &[_]u21{0x2CC3} },
+    .{ .codepoint = 0x2CC3, .upper = &[_]u21{0x2CC2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CC4, .upper = &[_]u21{}, .lower = &[_]u21{0x2CC5}, .fold = &[_]u21{0x2CC5} },
+    .{ .codepoint = 0x2CC5, .upper = &[_]u21{0x2CC4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CC6, .upper = &[_]u21{}, .lower = &[_]u21{0x2CC7}, .fold = &[_]u21{0x2CC7} },
+    .{ .codepoint = 0x2CC7, .upper = &[_]u21{0x2CC6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CC8, .upper = &[_]u21{}, .lower = &[_]u21{0x2CC9}, .fold = &[_]u21{0x2CC9} },
+    .{ .codepoint = 0x2CC9, .upper = &[_]u21{0x2CC8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CCA, .upper = &[_]u21{}, .lower = &[_]u21{0x2CCB}, .fold = &[_]u21{0x2CCB} },
+    .{ .codepoint = 0x2CCB, .upper = &[_]u21{0x2CCA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CCC, .upper = &[_]u21{}, .lower = &[_]u21{0x2CCD}, .fold = &[_]u21{0x2CCD} },
+    .{ .codepoint = 0x2CCD, .upper = &[_]u21{0x2CCC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CCE, .upper = &[_]u21{}, .lower = &[_]u21{0x2CCF}, .fold = &[_]u21{0x2CCF} },
+    .{ .codepoint = 0x2CCF, .upper = &[_]u21{0x2CCE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CD0, .upper = &[_]u21{}, .lower = &[_]u21{0x2CD1}, .fold = &[_]u21{0x2CD1} },
+    .{ .codepoint = 0x2CD1, .upper = &[_]u21{0x2CD0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CD2, .upper = &[_]u21{}, .lower = &[_]u21{0x2CD3}, .fold = &[_]u21{0x2CD3} },
+    .{ .codepoint = 0x2CD3, .upper = &[_]u21{0x2CD2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CD4, .upper = &[_]u21{}, .lower = &[_]u21{0x2CD5}, .fold = &[_]u21{0x2CD5} },
+    .{ .codepoint = 0x2CD5, .upper = &[_]u21{0x2CD4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CD6, .upper = &[_]u21{}, .lower = &[_]u21{0x2CD7}, .fold = &[_]u21{0x2CD7} },
+    .{ .codepoint = 0x2CD7, .upper = &[_]u21{0x2CD6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CD8, .upper = &[_]u21{}, .lower = &[_]u21{0x2CD9}, .fold = &[_]u21{0x2CD9} },
+    .{ .codepoint = 0x2CD9, .upper = &[_]u21{0x2CD8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CDA, .upper = &[_]u21{}, .lower = &[_]u21{0x2CDB}, .fold = &[_]u21{0x2CDB} },
+    .{ .codepoint = 0x2CDB, .upper = &[_]u21{0x2CDA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CDC, .upper = &[_]u21{}, .lower = &[_]u21{0x2CDD}, .fold = &[_]u21{0x2CDD} },
+    .{ .codepoint = 0x2CDD, .upper = &[_]u21{0x2CDC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CDE, .upper = &[_]u21{}, .lower = &[_]u21{0x2CDF}, .fold = &[_]u21{0x2CDF} },
+    .{ .codepoint = 0x2CDF, .upper = &[_]u21{0x2CDE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CE0, .upper = &[_]u21{}, .lower = &[_]u21{0x2CE1}, .fold = &[_]u21{0x2CE1} },
+    .{ .codepoint = 0x2CE1, .upper = &[_]u21{0x2CE0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CE2, .upper = &[_]u21{}, .lower = &[_]u21{0x2CE3}, .fold = &[_]u21{0x2CE3} },
+    .{ .codepoint = 0x2CE3, .upper = &[_]u21{0x2CE2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CEB, .upper = &[_]u21{}, .lower = &[_]u21{0x2CEC}, .fold = &[_]u21{0x2CEC} },
+    .{ .codepoint = 0x2CEC, .upper = &[_]u21{0x2CEB}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CED, .upper = &[_]u21{}, .lower = &[_]u21{0x2CEE}, .fold = &[_]u21{0x2CEE} },
+    .{ .codepoint = 0x2CEE, .upper = &[_]u21{0x2CED}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2CF2, .upper = &[_]u21{}, .lower = &[_]u21{0x2CF3}, .fold = &[_]u21{0x2CF3} },
+    .{ .codepoint = 0x2CF3, .upper = &[_]u21{0x2CF2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D00, .upper = &[_]u21{0x10A0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D01, .upper = &[_]u21{0x10A1}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D02, .upper = &[_]u21{0x10A2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D03, .upper = &[_]u21{0x10A3}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D04, .upper = &[_]u21{0x10A4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D05, .upper = &[_]u21{0x10A5}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D06, .upper = &[_]u21{0x10A6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D07, .upper = &[_]u21{0x10A7}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D08, .upper = &[_]u21{0x10A8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D09, .upper = &[_]u21{0x10A9}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D0A, .upper = &[_]u21{0x10AA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D0B, .upper = &[_]u21{0x10AB}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D0C, .upper = &[_]u21{0x10AC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D0D, .upper = &[_]u21{0x10AD}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D0E, .upper = &[_]u21{0x10AE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D0F, .upper = &[_]u21{0x10AF}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D10, .upper = &[_]u21{0x10B0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D11, .upper = &[_]u21{0x10B1}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D12, .upper = &[_]u21{0x10B2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D13, .upper = &[_]u21{0x10B3}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D14, .upper = &[_]u21{0x10B4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D15, .upper = &[_]u21{0x10B5}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D16, .upper = &[_]u21{0x10B6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D17, .upper = &[_]u21{0x10B7}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D18, .upper = &[_]u21{0x10B8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D19, .upper = &[_]u21{0x10B9}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D1A, .upper = &[_]u21{0x10BA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D1B, .upper = &[_]u21{0x10BB}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D1C, .upper = &[_]u21{0x10BC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D1D, .upper = &[_]u21{0x10BD}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D1E, .upper = &[_]u21{0x10BE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D1F, .upper = &[_]u21{0x10BF}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D20, .upper = &[_]u21{0x10C0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D21, .upper = &[_]u21{0x10C1}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D22, .upper = &[_]u21{0x10C2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D23, .upper = &[_]u21{0x10C3}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D24, .upper = &[_]u21{0x10C4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D25, .upper = &[_]u21{0x10C5}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D27, .upper = &[_]u21{0x10C7}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x2D2D, .upper = &[_]u21{0x10CD}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA640, .upper = &[_]u21{}, .lower = &[_]u21{0xA641}, .fold = &[_]u21{0xA641} },
+    .{ .codepoint = 0xA641, .upper = &[_]u21{0xA640}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA642, .upper = &[_]u21{}, .lower = &[_]u21{0xA643}, .fold = &[_]u21{0xA643} },
+    .{ .codepoint = 0xA643, .upper = &[_]u21{0xA642}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA644, .upper = &[_]u21{}, .lower = &[_]u21{0xA645}, .fold = &[_]u21{0xA645} },
+    .{ .codepoint = 0xA645, .upper = &[_]u21{0xA644}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA646, .upper = &[_]u21{}, .lower = &[_]u21{0xA647}, .fold = &[_]u21{0xA647} },
+    .{ .codepoint = 0xA647, .upper = &[_]u21{0xA646}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA648, .upper = &[_]u21{}, .lower = &[_]u21{0xA649}, .fold = &[_]u21{0xA649} },
+    .{ .codepoint = 0xA649, .upper = &[_]u21{0xA648}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA64A, .upper = &[_]u21{}, .lower = &[_]u21{0xA64B}, .fold = &[_]u21{0xA64B} },
+    .{ .codepoint = 0xA64B, .upper = &[_]u21{0xA64A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA64C, .upper = &[_]u21{}, .lower = &[_]u21{0xA64D}, .fold = &[_]u21{0xA64D} },
+    .{ .codepoint = 0xA64D, .upper = &[_]u21{0xA64C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA64E, .upper = &[_]u21{}, .lower = &[_]u21{0xA64F}, .fold = &[_]u21{0xA64F} },
+    .{ .codepoint = 0xA64F, .upper = &[_]u21{0xA64E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA650, .upper = &[_]u21{}, .lower = &[_]u21{0xA651}, .fold = &[_]u21{0xA651} },
+    .{ .codepoint = 0xA651, .upper = &[_]u21{0xA650}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA652, .upper = &[_]u21{}, .lower = &[_]u21{0xA653}, .fold = &[_]u21{0xA653} },
+    .{ .codepoint = 0xA653, .upper = &[_]u21{0xA652}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA654, .upper = &[_]u21{}, .lower = &[_]u21{0xA655}, .fold = &[_]u21{0xA655} },
+    .{ .codepoint = 0xA655, .upper = &[_]u21{0xA654}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA656, .upper = &[_]u21{}, .lower = &[_]u21{0xA657}, .fold = &[_]u21{0xA657} },
+    .{ .codepoint = 0xA657, .upper = &[_]u21{0xA656}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA658, .upper = &[_]u21{}, .lower = &[_]u21{0xA659}, .fold = &[_]u21{0xA659} },
+    .{ .codepoint = 0xA659, .upper = &[_]u21{0xA658}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA65A, .upper = &[_]u21{}, .lower = &[_]u21{0xA65B}, .fold = &[_]u21{0xA65B} },
+    .{ .codepoint = 0xA65B, .upper = &[_]u21{0xA65A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA65C, .upper = &[_]u21{}, .lower = &[_]u21{0xA65D}, .fold = &[_]u21{0xA65D} },
+    .{ .codepoint = 0xA65D, .upper = &[_]u21{0xA65C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA65E, .upper = &[_]u21{}, .lower = &[_]u21{0xA65F}, .fold = &[_]u21{0xA65F} },
+    .{ .codepoint = 0xA65F, .upper = &[_]u21{0xA65E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA660, .upper = &[_]u21{}, .lower = &[_]u21{0xA661}, .fold = &[_]u21{0xA661} },
+    .{ .codepoint = 0xA661, .upper = &[_]u21{0xA660}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA662, .upper = &[_]u21{}, .lower = &[_]u21{0xA663}, .fold = &[_]u21{0xA663} },
+    .{ .codepoint = 0xA663, .upper = &[_]u21{0xA662}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA664, .upper = &[_]u21{}, .lower = &[_]u21{0xA665}, .fold = &[_]u21{0xA665} },
+    .{ .codepoint = 0xA665, .upper = &[_]u21{0xA664}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA666, .upper = &[_]u21{}, .lower = &[_]u21{0xA667}, .fold = &[_]u21{0xA667} },
+    .{ .codepoint = 0xA667, .upper = &[_]u21{0xA666}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA668, .upper = &[_]u21{}, .lower = &[_]u21{0xA669}, .fold = &[_]u21{0xA669} },
+    .{ .codepoint = 0xA669, .upper = &[_]u21{0xA668}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA66A, .upper = &[_]u21{}, .lower = &[_]u21{0xA66B}, .fold = &[_]u21{0xA66B} },
+    .{ .codepoint = 0xA66B, .upper = &[_]u21{0xA66A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA66C, .upper = &[_]u21{}, .lower = &[_]u21{0xA66D}, .fold = &[_]u21{0xA66D} },
+    .{ .codepoint = 0xA66D, .upper = &[_]u21{0xA66C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA680, .upper = &[_]u21{}, .lower = &[_]u21{0xA681}, .fold = &[_]u21{0xA681} },
+    .{ .codepoint = 0xA681, .upper = &[_]u21{0xA680}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA682, .upper = &[_]u21{}, .lower = &[_]u21{0xA683}, .fold = &[_]u21{0xA683} },
+    .{ .codepoint = 0xA683, .upper = &[_]u21{0xA682}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA684, .upper = &[_]u21{}, .lower = &[_]u21{0xA685}, .fold = &[_]u21{0xA685} },
+    .{ .codepoint = 0xA685, .upper = &[_]u21{0xA684}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA686, .upper = &[_]u21{}, .lower = &[_]u21{0xA687}, .fold = &[_]u21{0xA687} },
+    .{ .codepoint = 0xA687, .upper = &[_]u21{0xA686}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA688, .upper = &[_]u21{}, .lower = &[_]u21{0xA689}, .fold = &[_]u21{0xA689} },
+    .{ .codepoint = 0xA689, .upper = &[_]u21{0xA688}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA68A, .upper = &[_]u21{}, .lower = &[_]u21{0xA68B}, .fold = &[_]u21{0xA68B} },
+    .{ .codepoint = 0xA68B, .upper = &[_]u21{0xA68A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA68C, .upper = &[_]u21{}, .lower = &[_]u21{0xA68D}, .fold = &[_]u21{0xA68D} },
+    .{ .codepoint = 0xA68D, .upper = &[_]u21{0xA68C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA68E, .upper = &[_]u21{}, .lower = &[_]u21{0xA68F}, .fold = &[_]u21{0xA68F} },
+    .{ .codepoint = 0xA68F, .upper = &[_]u21{0xA68E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA690, .upper = &[_]u21{}, .lower = &[_]u21{0xA691}, .fold = &[_]u21{0xA691} },
+    .{ .codepoint = 0xA691, .upper = &[_]u21{0xA690}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA692, .upper = &[_]u21{}, .lower = &[_]u21{0xA693}, .fold = &[_]u21{0xA693} },
+    .{ .codepoint = 0xA693, .upper = &[_]u21{0xA692}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA694, .upper = &[_]u21{}, .lower = &[_]u21{0xA695}, .fold = &[_]u21{0xA695} },
+    .{ .codepoint = 0xA695, .upper = &[_]u21{0xA694}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA696, .upper = &[_]u21{}, .lower = &[_]u21{0xA697}, .fold = &[_]u21{0xA697} },
+    .{ .codepoint = 0xA697, .upper = &[_]u21{0xA696}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA698, .upper = &[_]u21{}, .lower = &[_]u21{0xA699}, .fold = &[_]u21{0xA699} },
+    .{ .codepoint = 0xA699, .upper = &[_]u21{0xA698}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA69A, .upper = &[_]u21{}, .lower = &[_]u21{0xA69B}, .fold = &[_]u21{0xA69B} },
+    .{ .codepoint = 0xA69B, .upper = &[_]u21{0xA69A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA722, .upper = &[_]u21{}, .lower = &[_]u21{0xA723}, .fold = &[_]u21{0xA723} },
+    .{ .codepoint = 0xA723, .upper = &[_]u21{0xA722}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA724, .upper = &[_]u21{}, .lower = &[_]u21{0xA725}, .fold = &[_]u21{0xA725} },
+    .{ .codepoint = 0xA725, .upper = &[_]u21{0xA724}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA726, .upper = &[_]u21{}, .lower = &[_]u21{0xA727}, .fold = &[_]u21{0xA727} },
+    .{ .codepoint = 0xA727, .upper = &[_]u21{0xA726}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA728, .upper = &[_]u21{}, .lower = &[_]u21{0xA729}, .fold = &[_]u21{0xA729} },
+    .{ .codepoint = 0xA729, .upper = &[_]u21{0xA728}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA72A, .upper = &[_]u21{}, .lower = &[_]u21{0xA72B}, .fold = &[_]u21{0xA72B} },
+    .{ .codepoint = 0xA72B, .upper = &[_]u21{0xA72A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA72C, .upper = &[_]u21{}, .lower = &[_]u21{0xA72D}, .fold = &[_]u21{0xA72D} },
+    .{ .codepoint = 0xA72D, .upper = &[_]u21{0xA72C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA72E, .upper = &[_]u21{}, .lower = &[_]u21{0xA72F}, .fold = &[_]u21{0xA72F} },
+    .{ .codepoint = 0xA72F, .upper = &[_]u21{0xA72E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA732, .upper = &[_]u21{}, .lower = &[_]u21{0xA733}, .fold = &[_]u21{0xA733} },
+    .{ .codepoint = 0xA733, .upper = &[_]u21{0xA732}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA734, .upper = &[_]u21{}, .lower = &[_]u21{0xA735}, .fold = &[_]u21{0xA735} },
+    .{ .codepoint = 0xA735, .upper = &[_]u21{0xA734}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA736, .upper = &[_]u21{}, .lower = &[_]u21{0xA737}, .fold = &[_]u21{0xA737} },
+    .{ .codepoint = 0xA737, .upper = &[_]u21{0xA736}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA738, .upper = &[_]u21{}, .lower = &[_]u21{0xA739}, .fold = &[_]u21{0xA739} },
+    .{ .codepoint = 0xA739, .upper = &[_]u21{0xA738}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA73A, .upper = &[_]u21{}, .lower = &[_]u21{0xA73B}, .fold = &[_]u21{0xA73B} },
+    .{ .codepoint = 0xA73B, .upper = &[_]u21{0xA73A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA73C, .upper = &[_]u21{}, .lower = &[_]u21{0xA73D}, .fold = &[_]u21{0xA73D} },
+    .{ .codepoint = 0xA73D, .upper = &[_]u21{0xA73C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA73E, .upper = &[_]u21{}, .lower = &[_]u21{0xA73F}, .fold = &[_]u21{0xA73F} },
+    .{ .codepoint = 0xA73F, .upper = &[_]u21{0xA73E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA740, .upper = &[_]u21{}, .lower = &[_]u21{0xA741}, .fold = &[_]u21{0xA741} },
+    .{ .codepoint = 0xA741, .upper = &[_]u21{0xA740}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA742, .upper = &[_]u21{}, .lower = &[_]u21{0xA743}, .fold = &[_]u21{0xA743} },
+    .{ .codepoint = 0xA743, .upper = &[_]u21{0xA742}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA744, .upper = &[_]u21{}, .lower = &[_]u21{0xA745}, .fold = &[_]u21{0xA745} },
+    .{ .codepoint = 0xA745, .upper = &[_]u21{0xA744}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA746, .upper = &[_]u21{}, .lower = &[_]u21{0xA747}, .fold = &[_]u21{0xA747} },
+    .{ .codepoint = 0xA747, .upper = &[_]u21{0xA746}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA748, .upper = &[_]u21{}, .lower = &[_]u21{0xA749}, .fold = &[_]u21{0xA749} },
+    .{ .codepoint = 0xA749, .upper = &[_]u21{0xA748}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA74A, .upper = &[_]u21{}, .lower = &[_]u21{0xA74B}, .fold = &[_]u21{0xA74B} },
+    .{ .codepoint = 0xA74B, .upper = &[_]u21{0xA74A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA74C, .upper = &[_]u21{}, .lower = &[_]u21{0xA74D}, .fold = &[_]u21{0xA74D} },
+    .{ .codepoint = 0xA74D, .upper = &[_]u21{0xA74C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA74E, .upper = &[_]u21{}, .lower = &[_]u21{0xA74F}, .fold = &[_]u21{0xA74F} },
+    .{ .codepoint = 0xA74F, .upper = &[_]u21{0xA74E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA750, .upper = &[_]u21{}, .lower = &[_]u21{0xA751}, .fold = &[_]u21{0xA751} },
+    .{ .codepoint = 0xA751, .upper = &[_]u21{0xA750}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA752, .upper = &[_]u21{}, .lower = &[_]u21{0xA753}, .fold = &[_]u21{0xA753} },
+    .{ .codepoint = 0xA753, .upper = &[_]u21{0xA752}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA754, .upper = &[_]u21{}, .lower = &[_]u21{0xA755}, .fold = &[_]u21{0xA755} },
+    .{ .codepoint = 0xA755, .upper = &[_]u21{0xA754}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA756, .upper = &[_]u21{}, .lower = &[_]u21{0xA757}, .fold = &[_]u21{0xA757} },
+    .{ .codepoint = 0xA757, .upper = &[_]u21{0xA756}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA758, .upper = &[_]u21{}, .lower = &[_]u21{0xA759}, .fold = &[_]u21{0xA759} },
+    .{ .codepoint = 0xA759, .upper = &[_]u21{0xA758}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA75A, .upper = &[_]u21{}, .lower = &[_]u21{0xA75B}, .fold = &[_]u21{0xA75B} },
+    .{ .codepoint = 0xA75B, .upper = &[_]u21{0xA75A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA75C, .upper = &[_]u21{}, .lower = &[_]u21{0xA75D}, .fold = &[_]u21{0xA75D} },
+    .{ .codepoint = 0xA75D, .upper = &[_]u21{0xA75C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA75E, .upper = &[_]u21{}, .lower = &[_]u21{0xA75F}, .fold = &[_]u21{0xA75F} },
+    .{ .codepoint = 0xA75F, .upper = &[_]u21{0xA75E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA760, .upper = &[_]u21{}, .lower = &[_]u21{0xA761}, .fold = &[_]u21{0xA761} },
+    .{ .codepoint = 0xA761, .upper = &[_]u21{0xA760}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA762, .upper = &[_]u21{}, .lower = &[_]u21{0xA763}, .fold = &[_]u21{0xA763} },
+    .{ .codepoint = 0xA763, .upper = &[_]u21{0xA762}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA764, .upper = &[_]u21{}, .lower = &[_]u21{0xA765}, .fold = &[_]u21{0xA765} },
+    .{ .codepoint = 0xA765, .upper = &[_]u21{0xA764}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA766, .upper = &[_]u21{}, .lower = &[_]u21{0xA767}, .fold = &[_]u21{0xA767} },
+    .{ .codepoint = 0xA767, .upper = &[_]u21{0xA766}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA768, .upper = &[_]u21{}, .lower = &[_]u21{0xA769}, .fold = &[_]u21{0xA769} },
+    .{ .codepoint = 0xA769, .upper = &[_]u21{0xA768}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA76A, .upper = &[_]u21{}, .lower = &[_]u21{0xA76B}, .fold = &[_]u21{0xA76B} },
+    .{ .codepoint = 0xA76B, .upper = &[_]u21{0xA76A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA76C, .upper = &[_]u21{}, .lower = &[_]u21{0xA76D}, .fold = &[_]u21{0xA76D} },
+    .{ .codepoint = 0xA76D, .upper = &[_]u21{0xA76C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA76E, .upper = &[_]u21{}, .lower = &[_]u21{0xA76F}, .fold = &[_]u21{0xA76F} },
+    .{ .codepoint = 0xA76F, .upper = &[_]u21{0xA76E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA779, .upper = &[_]u21{}, .lower = &[_]u21{0xA77A}, .fold = &[_]u21{0xA77A} },
+    .{ .codepoint = 0xA77A, .upper = &[_]u21{0xA779}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA77B, .upper = &[_]u21{}, .lower = &[_]u21{0xA77C}, .fold = &[_]u21{0xA77C} },
+    .{ .codepoint = 0xA77C, .upper = &[_]u21{0xA77B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA77D, .upper = &[_]u21{}, .lower = &[_]u21{0x1D79}, .fold = &[_]u21{0x1D79} },
+    .{ .codepoint = 0xA77E, .upper = &[_]u21{}, .lower = &[_]u21{0xA77F}, .fold = &[_]u21{0xA77F} },
+    .{ .codepoint = 0xA77F, .upper = &[_]u21{0xA77E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA780, .upper = &[_]u21{}, .lower = &[_]u21{0xA781}, .fold = &[_]u21{0xA781} },
+    .{ .codepoint = 0xA781, .upper = &[_]u21{0xA780}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA782, .upper = &[_]u21{}, .lower = &[_]u21{0xA783}, .fold = &[_]u21{0xA783} },
+    .{ .codepoint = 0xA783, .upper = &[_]u21{0xA782}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA784, .upper = &[_]u21{}, .lower = &[_]u21{0xA785}, .fold = &[_]u21{0xA785} },
+    .{ .codepoint = 0xA785, .upper = &[_]u21{0xA784}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA786, .upper = &[_]u21{}, .lower = &[_]u21{0xA787}, .fold = &[_]u21{0xA787} },
+    .{ .codepoint = 0xA787, .upper = &[_]u21{0xA786}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA78B, .upper = &[_]u21{}, .lower = &[_]u21{0xA78C}, .fold = &[_]u21{0xA78C} },
+    .{ .codepoint = 0xA78C, .upper = &[_]u21{0xA78B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA78D, .upper = &[_]u21{}, .lower = &[_]u21{0x0265}, .fold = &[_]u21{0x0265} },
+    .{ .codepoint = 0xA790, .upper = &[_]u21{}, .lower = &[_]u21{0xA791}, .fold = &[_]u21{0xA791} },
+    .{ .codepoint = 0xA791, .upper = &[_]u21{0xA790}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA792, .upper = &[_]u21{}, .lower = &[_]u21{0xA793}, .fold = &[_]u21{0xA793} },
+    .{ .codepoint = 0xA793, .upper = &[_]u21{0xA792}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA794, .upper = &[_]u21{0xA7C4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA796, .upper = &[_]u21{}, .lower = &[_]u21{0xA797}, .fold = &[_]u21{0xA797} },
+    .{ .codepoint = 0xA797, .upper = &[_]u21{0xA796}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA798, .upper = &[_]u21{}, .lower = &[_]u21{0xA799}, .fold = &[_]u21{0xA799} },
+    .{ .codepoint = 0xA799, .upper = &[_]u21{0xA798}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA79A, .upper = &[_]u21{}, .lower = &[_]u21{0xA79B}, .fold = &[_]u21{0xA79B} },
+    .{ .codepoint = 0xA79B, .upper = &[_]u21{0xA79A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA79C, .upper = &[_]u21{}, .lower = &[_]u21{0xA79D}, .fold = &[_]u21{0xA79D} },
+    .{ .codepoint = 0xA79D, .upper = &[_]u21{0xA79C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA79E, .upper = &[_]u21{}, .lower = &[_]u21{0xA79F}, .fold = &[_]u21{0xA79F} },
+    .{ .codepoint = 0xA79F, .upper = &[_]u21{0xA79E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA7A0, .upper = &[_]u21{}, .lower = &[_]u21{0xA7A1}, .fold = &[_]u21{0xA7A1} },
+    .{ .codepoint = 0xA7A1, .upper = &[_]u21{0xA7A0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA7A2, .upper = &[_]u21{}, .lower = &[_]u21{0xA7A3}, .fold = &[_]u21{0xA7A3} },
+    .{ .codepoint = 0xA7A3, .upper = &[_]u21{0xA7A2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA7A4, .upper = &[_]u21{}, .lower = &[_]u21{0xA7A5}, .fold = &[_]u21{0xA7A5} },
+    .{ .codepoint = 0xA7A5, .upper = &[_]u21{0xA7A4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA7A6, .upper = &[_]u21{}, .lower = &[_]u21{0xA7A7}, .fold = &[_]u21{0xA7A7} },
+    .{ .codepoint = 0xA7A7, .upper = &[_]u21{0xA7A6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA7A8, .upper = &[_]u21{}, .lower = &[_]u21{0xA7A9}, .fold = &[_]u21{0xA7A9} },
+    .{ .codepoint = 0xA7A9, .upper = &[_]u21{0xA7A8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA7AA, .upper = &[_]u21{}, .lower = &[_]u21{0x0266}, .fold = &[_]u21{0x0266} },
+    .{ .codepoint = 0xA7AB, .upper = &[_]u21{}, .lower = &[_]u21{0x025C}, .fold = &[_]u21{0x025C} },
+    .{ .codepoint = 0xA7AC, .upper = &[_]u21{}, .lower = &[_]u21{0x0261}, .fold = &[_]u21{0x0261} },
+    .{ .codepoint = 0xA7AD, .upper = &[_]u21{}, .lower = &[_]u21{0x026C}, .fold = &[_]u21{0x026C} },
+    .{ .codepoint = 0xA7AE, .upper = &[_]u21{}, .lower = &[_]u21{0x026A}, .fold = &[_]u21{0x026A} },
+    .{ .codepoint = 0xA7B0, .upper = &[_]u21{}, .lower = &[_]u21{0x029E}, .fold = &[_]u21{0x029E} },
+    .{ .codepoint = 0xA7B1, .upper = &[_]u21{}, .lower = &[_]u21{0x0287}, .fold = &[_]u21{0x0287} },
+    .{ .codepoint = 0xA7B2, .upper = &[_]u21{}, .lower = &[_]u21{0x029D}, .fold = &[_]u21{0x029D} },
+    .{ .codepoint = 0xA7B3, .upper = &[_]u21{}, .lower = &[_]u21{0xAB53}, .fold = &[_]u21{0xAB53} },
+    .{ .codepoint = 0xA7B4, .upper = &[_]u21{}, .lower = &[_]u21{0xA7B5}, .fold = &[_]u21{0xA7B5} },
+    .{ .codepoint = 0xA7B5, .upper = &[_]u21{0xA7B4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA7B6, .upper = &[_]u21{}, .lower = &[_]u21{0xA7B7}, .fold = &[_]u21{0xA7B7} },
+    .{ .codepoint = 0xA7B7, .upper = &[_]u21{0xA7B6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA7B8, .upper = &[_]u21{}, .lower = &[_]u21{0xA7B9}, .fold = &[_]u21{0xA7B9} },
+    .{ .codepoint = 0xA7B9, .upper = &[_]u21{0xA7B8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA7BA, .upper = &[_]u21{}, .lower = &[_]u21{0xA7BB}, .fold = &[_]u21{0xA7BB} },
+    .{ .codepoint = 0xA7BB, .upper = &[_]u21{0xA7BA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA7BC, .upper = &[_]u21{}, .lower = &[_]u21{0xA7BD}, .fold = &[_]u21{0xA7BD} },
+    .{ .codepoint = 0xA7BD, .upper = &[_]u21{0xA7BC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA7BE, .upper = &[_]u21{}, .lower = &[_]u21{0xA7BF}, .fold = &[_]u21{0xA7BF} },
+    .{ .codepoint = 0xA7BF, .upper = &[_]u21{0xA7BE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA7C0, .upper = &[_]u21{}, .lower = &[_]u21{0xA7C1}, .fold = &[_]u21{0xA7C1} },
+    .{ .codepoint = 0xA7C1, .upper = &[_]u21{0xA7C0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA7C2, .upper = &[_]u21{}, .lower = &[_]u21{0xA7C3}, .fold = &[_]u21{0xA7C3} },
+    .{ .codepoint = 0xA7C3, .upper = &[_]u21{0xA7C2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA7C4, .upper = &[_]u21{}, .lower = &[_]u21{0xA794}, .fold = &[_]u21{0xA794} },
+    .{ .codepoint = 0xA7C5, .upper = &[_]u21{}, .lower = &[_]u21{0x0282}, .fold = &[_]u21{0x0282} },
+    .{ .codepoint = 0xA7C6, .upper = &[_]u21{}, .lower = &[_]u21{0x1D8E}, .fold = &[_]u21{0x1D8E} },
+    .{ .codepoint = 0xA7C7, .upper = &[_]u21{}, .lower = &[_]u21{0xA7C8}, .fold = &[_]u21{0xA7C8} },
+    .{ .codepoint = 0xA7C8, .upper = &[_]u21{0xA7C7}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA7C9, .upper = &[_]u21{}, .lower = &[_]u21{0xA7CA}, .fold = &[_]u21{0xA7CA} },
+    .{ .codepoint = 0xA7CA, .upper = &[_]u21{0xA7C9}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA7D0, .upper = &[_]u21{}, .lower = &[_]u21{0xA7D1}, .fold = &[_]u21{0xA7D1} },
+    .{ .codepoint = 0xA7D1, .upper = &[_]u21{0xA7D0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA7D6, .upper = &[_]u21{}, .lower = &[_]u21{0xA7D7}, .fold = &[_]u21{0xA7D7} },
+    .{ .codepoint = 0xA7D7, .upper = &[_]u21{0xA7D6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA7D8, .upper = &[_]u21{}, .lower = &[_]u21{0xA7D9}, .fold = &[_]u21{0xA7D9} },
+    .{ .codepoint = 0xA7D9, .upper = &[_]u21{0xA7D8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xA7F5, .upper = &[_]u21{}, .lower = &[_]u21{0xA7F6}, .fold = &[_]u21{0xA7F6} },
+    .{ .codepoint = 0xA7F6, .upper = &[_]u21{0xA7F5}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xAB53, .upper = &[_]u21{0xA7B3}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xAB70, .upper = &[_]u21{0x13A0}, .lower = &[_]u21{}, .fold = &[_]u21{0x13A0} },
+    .{ .codepoint = 0xAB71, .upper = &[_]u21{0x13A1}, .lower = &[_]u21{}, .fold = &[_]u21{0x13A1} },
+    .{ .codepoint = 0xAB72, .upper = &[_]u21{0x13A2}, .lower = &[_]u21{}, .fold = &[_]u21{0x13A2} },
+    .{ .codepoint = 0xAB73, .upper = &[_]u21{0x13A3}, .lower = &[_]u21{}, .fold = &[_]u21{0x13A3} },
+    .{ .codepoint = 0xAB74, .upper = &[_]u21{0x13A4}, .lower = &[_]u21{}, .fold = &[_]u21{0x13A4} },
+    .{ .codepoint = 0xAB75, .upper = &[_]u21{0x13A5}, .lower = &[_]u21{}, .fold = &[_]u21{0x13A5} },
+    .{ .codepoint = 0xAB76, .upper = &[_]u21{0x13A6}, .lower = &[_]u21{}, .fold = &[_]u21{0x13A6} },
+    .{ .codepoint = 0xAB77, .upper = &[_]u21{0x13A7}, .lower = &[_]u21{}, .fold = &[_]u21{0x13A7} },
+    .{ .codepoint = 0xAB78, .upper = &[_]u21{0x13A8}, .lower = &[_]u21{}, .fold = &[_]u21{0x13A8} },
+    .{ .codepoint = 0xAB79, .upper = &[_]u21{0x13A9}, .lower = &[_]u21{}, .fold = &[_]u21{0x13A9} },
+    .{ .codepoint = 0xAB7A, .upper = &[_]u21{0x13AA}, .lower = &[_]u21{}, .fold = &[_]u21{0x13AA} },
+    .{ .codepoint = 0xAB7B, .upper = &[_]u21{0x13AB}, .lower = &[_]u21{}, .fold = &[_]u21{0x13AB} },
+    .{ .codepoint = 0xAB7C, .upper = &[_]u21{0x13AC}, .lower = &[_]u21{}, .fold = &[_]u21{0x13AC} },
+    .{ .codepoint = 0xAB7D, .upper = &[_]u21{0x13AD}, .lower = &[_]u21{}, .fold = &[_]u21{0x13AD} },
+    .{ .codepoint = 0xAB7E, .upper = &[_]u21{0x13AE}, .lower = &[_]u21{}, .fold = &[_]u21{0x13AE} },
+    .{ .codepoint = 0xAB7F, .upper = &[_]u21{0x13AF}, .lower = &[_]u21{}, .fold = &[_]u21{0x13AF} },
+    .{ .codepoint = 0xAB80, .upper = &[_]u21{0x13B0}, .lower = &[_]u21{}, .fold = &[_]u21{0x13B0} },
+    .{ .codepoint = 0xAB81, .upper = &[_]u21{0x13B1}, .lower = &[_]u21{}, .fold = &[_]u21{0x13B1} },
+    .{ .codepoint = 0xAB82, .upper = &[_]u21{0x13B2}, .lower = &[_]u21{}, .fold = &[_]u21{0x13B2} },
+    .{ .codepoint = 0xAB83, .upper = &[_]u21{0x13B3}, .lower = &[_]u21{}, .fold = &[_]u21{0x13B3} },
+    .{ .codepoint = 0xAB84, .upper = &[_]u21{0x13B4}, .lower = &[_]u21{}, .fold = &[_]u21{0x13B4} },
+    .{ .codepoint = 0xAB85, .upper = &[_]u21{0x13B5}, .lower = &[_]u21{}, .fold = &[_]u21{0x13B5} },
+    .{ .codepoint = 0xAB86, .upper = &[_]u21{0x13B6}, .lower = &[_]u21{}, .fold = &[_]u21{0x13B6} },
+    .{ .codepoint = 0xAB87, .upper = &[_]u21{0x13B7}, .lower = &[_]u21{}, .fold = &[_]u21{0x13B7} },
+    .{ .codepoint = 0xAB88, .upper = &[_]u21{0x13B8}, .lower = &[_]u21{}, .fold = &[_]u21{0x13B8} },
+    .{ .codepoint = 0xAB89, .upper = &[_]u21{0x13B9}, .lower = &[_]u21{}, .fold = &[_]u21{0x13B9} },
+    .{ .codepoint = 0xAB8A, .upper = &[_]u21{0x13BA}, .lower = &[_]u21{}, .fold = &[_]u21{0x13BA} },
+    .{ .codepoint = 0xAB8B, .upper = &[_]u21{0x13BB}, .lower = &[_]u21{}, .fold = &[_]u21{0x13BB} },
+    .{ .codepoint = 0xAB8C, .upper = &[_]u21{0x13BC}, .lower = &[_]u21{}, .fold = &[_]u21{0x13BC} },
+    .{ .codepoint = 0xAB8D, .upper = &[_]u21{0x13BD}, .lower = &[_]u21{}, .fold = &[_]u21{0x13BD} },
+    .{ .codepoint = 0xAB8E, .upper = &[_]u21{0x13BE}, .lower = &[_]u21{}, .fold = &[_]u21{0x13BE} },
+    .{ .codepoint = 0xAB8F, .upper = &[_]u21{0x13BF}, .lower = &[_]u21{}, .fold = &[_]u21{0x13BF} },
+    .{ .codepoint = 0xAB90, .upper = &[_]u21{0x13C0}, .lower = &[_]u21{}, .fold = &[_]u21{0x13C0} },
+    .{ .codepoint = 0xAB91, .upper = &[_]u21{0x13C1}, .lower = &[_]u21{}, .fold = &[_]u21{0x13C1} },
+    .{ .codepoint = 0xAB92, .upper = &[_]u21{0x13C2}, .lower = &[_]u21{}, .fold = &[_]u21{0x13C2} },
+    .{ .codepoint = 0xAB93, .upper = &[_]u21{0x13C3}, .lower = &[_]u21{}, .fold = &[_]u21{0x13C3} },
+    .{ .codepoint = 0xAB94, .upper = &[_]u21{0x13C4}, .lower = &[_]u21{}, .fold = &[_]u21{0x13C4} },
+    .{ .codepoint = 0xAB95, .upper = &[_]u21{0x13C5}, .lower = &[_]u21{}, .fold = &[_]u21{0x13C5} },
+    .{ .codepoint = 0xAB96, .upper = &[_]u21{0x13C6}, .lower = &[_]u21{}, .fold = &[_]u21{0x13C6} },
+    .{ .codepoint = 0xAB97, .upper = &[_]u21{0x13C7}, .lower = &[_]u21{}, .fold = &[_]u21{0x13C7} },
+    .{ .codepoint = 0xAB98, .upper = &[_]u21{0x13C8}, .lower = &[_]u21{}, .fold = &[_]u21{0x13C8} },
+    .{ .codepoint = 0xAB99, .upper = &[_]u21{0x13C9}, .lower = &[_]u21{}, .fold = &[_]u21{0x13C9} },
+    .{ .codepoint = 0xAB9A, .upper = &[_]u21{0x13CA}, .lower = &[_]u21{}, .fold = &[_]u21{0x13CA} },
+    .{ .codepoint = 0xAB9B, .upper = &[_]u21{0x13CB}, .lower = &[_]u21{}, .fold = &[_]u21{0x13CB} },
+    .{ .codepoint = 0xAB9C, .upper = &[_]u21{0x13CC}, .lower = &[_]u21{}, .fold = &[_]u21{0x13CC} },
+    .{ .codepoint = 0xAB9D, .upper = &[_]u21{0x13CD}, .lower = &[_]u21{}, .fold = &[_]u21{0x13CD} },
+    .{ .codepoint = 0xAB9E, .upper = &[_]u21{0x13CE}, .lower = &[_]u21{}, .fold = &[_]u21{0x13CE} },
+    .{ .codepoint = 0xAB9F, .upper = &[_]u21{0x13CF}, .lower = &[_]u21{}, .fold = &[_]u21{0x13CF} },
+    .{ .codepoint = 0xABA0, .upper = &[_]u21{0x13D0}, .lower = &[_]u21{}, .fold = &[_]u21{0x13D0} },
+    .{ .codepoint = 0xABA1, .upper = &[_]u21{0x13D1}, .lower = &[_]u21{}, .fold = &[_]u21{0x13D1} },
+    .{ .codepoint = 0xABA2, .upper = &[_]u21{0x13D2}, .lower = &[_]u21{}, .fold = &[_]u21{0x13D2} },
+    .{ .codepoint = 0xABA3, .upper = &[_]u21{0x13D3}, .lower = &[_]u21{}, .fold = &[_]u21{0x13D3} },
+    .{ .codepoint = 0xABA4, .upper = &[_]u21{0x13D4}, .lower = &[_]u21{}, .fold = &[_]u21{0x13D4} },
+    .{ .codepoint = 0xABA5, .upper = &[_]u21{0x13D5}, .lower = &[_]u21{}, .fold = &[_]u21{0x13D5} },
+    .{ .codepoint = 0xABA6, .upper = &[_]u21{0x13D6}, .lower = &[_]u21{}, .fold = &[_]u21{0x13D6} },
+    .{ .codepoint = 0xABA7, .upper = &[_]u21{0x13D7}, .lower = &[_]u21{}, .fold = &[_]u21{0x13D7} },
+    .{ .codepoint = 0xABA8, .upper = &[_]u21{0x13D8}, .lower = &[_]u21{}, .fold = &[_]u21{0x13D8} },
+    .{ .codepoint = 0xABA9, .upper = &[_]u21{0x13D9}, .lower = &[_]u21{}, .fold = &[_]u21{0x13D9} },
+    .{ .codepoint = 0xABAA, .upper = &[_]u21{0x13DA}, .lower = &[_]u21{}, .fold = &[_]u21{0x13DA} },
+    .{ .codepoint = 0xABAB, .upper = &[_]u21{0x13DB}, .lower = &[_]u21{}, .fold = &[_]u21{0x13DB} },
+    .{ .codepoint = 0xABAC, .upper = &[_]u21{0x13DC}, .lower = &[_]u21{}, .fold = &[_]u21{0x13DC} },
+    .{ .codepoint = 0xABAD, .upper = &[_]u21{0x13DD}, .lower = &[_]u21{}, .fold = &[_]u21{0x13DD} },
+    .{ .codepoint = 0xABAE, .upper = &[_]u21{0x13DE}, .lower = &[_]u21{}, .fold = &[_]u21{0x13DE} },
+    .{ .codepoint = 0xABAF, .upper = &[_]u21{0x13DF}, .lower = &[_]u21{}, .fold = &[_]u21{0x13DF} },
+    .{ .codepoint = 0xABB0, .upper = &[_]u21{0x13E0}, .lower = &[_]u21{}, .fold = &[_]u21{0x13E0} },
+    .{ .codepoint = 0xABB1, .upper = &[_]u21{0x13E1}, .lower = &[_]u21{}, .fold = &[_]u21{0x13E1} },
+    .{ .codepoint = 0xABB2, .upper = &[_]u21{0x13E2}, .lower = &[_]u21{}, .fold = &[_]u21{0x13E2} },
+    .{ .codepoint = 0xABB3, .upper = &[_]u21{0x13E3}, .lower = &[_]u21{}, .fold = &[_]u21{0x13E3} },
+    .{ .codepoint = 0xABB4, .upper = &[_]u21{0x13E4}, .lower = &[_]u21{}, .fold = &[_]u21{0x13E4} },
+    .{ .codepoint = 0xABB5, .upper = &[_]u21{0x13E5}, .lower = &[_]u21{}, .fold = &[_]u21{0x13E5} },
+    .{ .codepoint = 0xABB6, .upper = &[_]u21{0x13E6}, .lower = &[_]u21{}, .fold = &[_]u21{0x13E6} },
+    .{ .codepoint = 0xABB7, .upper = &[_]u21{0x13E7}, .lower = &[_]u21{}, .fold = &[_]u21{0x13E7} },
+    .{ .codepoint = 0xABB8, .upper = &[_]u21{0x13E8}, .lower = &[_]u21{}, .fold = &[_]u21{0x13E8} },
+    .{ .codepoint = 0xABB9, .upper = &[_]u21{0x13E9}, .lower = &[_]u21{}, .fold = &[_]u21{0x13E9} },
+    .{ .codepoint = 0xABBA, .upper = &[_]u21{0x13EA}, .lower = &[_]u21{}, .fold = &[_]u21{0x13EA} },
+    .{ .codepoint = 0xABBB, .upper = &[_]u21{0x13EB}, .lower = &[_]u21{}, .fold = &[_]u21{0x13EB} },
+    .{ .codepoint = 0xABBC, .upper = &[_]u21{0x13EC}, .lower = &[_]u21{}, .fold = &[_]u21{0x13EC} },
+    .{ .codepoint = 0xABBD, .upper = &[_]u21{0x13ED}, .lower = &[_]u21{}, .fold = &[_]u21{0x13ED} },
+    .{ .codepoint = 0xABBE, .upper = &[_]u21{0x13EE}, .lower = &[_]u21{}, .fold = &[_]u21{0x13EE} },
+    .{ .codepoint = 0xABBF, .upper = &[_]u21{0x13EF}, .lower = &[_]u21{}, .fold = &[_]u21{0x13EF} },
+    .{ .codepoint = 0xFB00, .upper = &[_]u21{ 0x0046, 0x0046 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x0066, 0x0066 } },
+    .{ .codepoint = 0xFB01, .upper = &[_]u21{ 0x0046, 0x0049 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x0066, 0x0069 } },
+    .{ .codepoint = 0xFB02, .upper = &[_]u21{ 0x0046, 0x004C }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x0066, 0x006C } },
+    .{ .codepoint = 0xFB03, .upper = &[_]u21{ 0x0046, 0x0046, 0x0049 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x0066, 0x0066, 0x0069 } },
+    .{ .codepoint = 0xFB04, .upper = &[_]u21{ 0x0046, 0x0046, 0x004C }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x0066, 0x0066, 0x006C } },
+    .{ .codepoint = 0xFB05, .upper = &[_]u21{ 0x0053, 0x0054 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x0073, 0x0074 } },
+    .{ .codepoint = 0xFB06, .upper = &[_]u21{ 0x0053, 0x0054 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x0073, 0x0074 } },
+    .{ .codepoint = 0xFB13, .upper = &[_]u21{ 0x0544, 0x0546 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x0574, 0x0576 } },
+    .{ .codepoint = 0xFB14, .upper = &[_]u21{ 0x0544, 0x0535 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x0574, 0x0565 } },
+    .{ .codepoint = 0xFB15, .upper = &[_]u21{ 0x0544, 0x053B }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x0574, 0x056B } },
+    .{ .codepoint = 0xFB16, .upper = &[_]u21{ 0x054E, 0x0546 }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x057E, 0x0576 } },
+    .{ .codepoint = 0xFB17, .upper = &[_]u21{ 0x0544, 0x053D }, .lower = &[_]u21{}, .fold = &[_]u21{ 0x0574, 0x056D } },
+    .{ .codepoint = 0xFF21, .upper = &[_]u21{}, .lower = &[_]u21{0xFF41}, .fold = &[_]u21{0xFF41} },
+    .{ .codepoint = 0xFF22, .upper = &[_]u21{}, .lower = &[_]u21{0xFF42}, .fold = &[_]u21{0xFF42} },
+    .{ .codepoint = 0xFF23, .upper = &[_]u21{}, .lower = &[_]u21{0xFF43}, .fold = &[_]u21{0xFF43} },
+    .{ .codepoint = 0xFF24, .upper = &[_]u21{}, .lower = &[_]u21{0xFF44}, .fold = &[_]u21{0xFF44} },
+    .{ .codepoint = 0xFF25, .upper = &[_]u21{}, .lower = &[_]u21{0xFF45}, .fold = &[_]u21{0xFF45} },
+    .{ .codepoint = 0xFF26, .upper = &[_]u21{}, .lower = &[_]u21{0xFF46}, .fold = &[_]u21{0xFF46} },
+    .{ .codepoint = 0xFF27, .upper = &[_]u21{}, .lower = &[_]u21{0xFF47}, .fold = &[_]u21{0xFF47} },
+    .{ .codepoint = 0xFF28, .upper = &[_]u21{}, .lower = &[_]u21{0xFF48}, .fold = &[_]u21{0xFF48} },
+    .{ .codepoint = 0xFF29, .upper = &[_]u21{}, .lower = &[_]u21{0xFF49}, .fold = &[_]u21{0xFF49} },
+    .{ .codepoint = 0xFF2A, .upper = &[_]u21{}, .lower = &[_]u21{0xFF4A}, .fold = &[_]u21{0xFF4A} },
+    .{ .codepoint = 0xFF2B, .upper = &[_]u21{}, .lower = &[_]u21{0xFF4B}, .fold = &[_]u21{0xFF4B} },
+    .{ .codepoint = 0xFF2C, .upper = &[_]u21{}, .lower = &[_]u21{0xFF4C}, .fold = &[_]u21{0xFF4C} },
+    .{ .codepoint = 0xFF2D, .upper = &[_]u21{}, .lower = &[_]u21{0xFF4D}, .fold = &[_]u21{0xFF4D} },
+    .{ .codepoint = 0xFF2E, .upper = &[_]u21{}, .lower = &[_]u21{0xFF4E}, .fold = &[_]u21{0xFF4E} },
+    .{ .codepoint = 0xFF2F, .upper = &[_]u21{}, .lower = &[_]u21{0xFF4F}, .fold = &[_]u21{0xFF4F} },
+    .{ .codepoint = 0xFF30, .upper = &[_]u21{}, .lower = &[_]u21{0xFF50}, .fold = &[_]u21{0xFF50} },
+    .{ .codepoint = 0xFF31, .upper = &[_]u21{}, .lower = &[_]u21{0xFF51}, .fold = &[_]u21{0xFF51} },
+    .{ .codepoint = 0xFF32, .upper = &[_]u21{}, .lower = &[_]u21{0xFF52}, .fold = &[_]u21{0xFF52} },
+    .{ .codepoint = 0xFF33, .upper = &[_]u21{}, .lower = &[_]u21{0xFF53}, .fold = &[_]u21{0xFF53} },
+    .{ .codepoint = 0xFF34, .upper = &[_]u21{}, .lower = &[_]u21{0xFF54}, .fold = &[_]u21{0xFF54} },
+    .{ .codepoint = 0xFF35, .upper = &[_]u21{}, .lower = &[_]u21{0xFF55}, .fold = &[_]u21{0xFF55} },
+    .{ .codepoint = 0xFF36, .upper = &[_]u21{}, .lower = &[_]u21{0xFF56}, .fold = &[_]u21{0xFF56} },
+    .{ .codepoint = 0xFF37, .upper = &[_]u21{}, .lower = &[_]u21{0xFF57}, .fold = &[_]u21{0xFF57} },
+    .{ .codepoint = 0xFF38, .upper = &[_]u21{}, .lower = &[_]u21{0xFF58}, .fold = &[_]u21{0xFF58} },
+    .{ .codepoint = 0xFF39, .upper = &[_]u21{}, .lower = &[_]u21{0xFF59}, .fold = &[_]u21{0xFF59} },
+    .{ .codepoint = 0xFF3A, .upper = &[_]u21{}, .lower = &[_]u21{0xFF5A}, .fold = &[_]u21{0xFF5A} },
+    .{ .codepoint = 0xFF41, .upper = &[_]u21{0xFF21}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xFF42, .upper = &[_]u21{0xFF22}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xFF43, .upper = &[_]u21{0xFF23}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xFF44, .upper = &[_]u21{0xFF24}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xFF45, .upper = &[_]u21{0xFF25}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xFF46, .upper = &[_]u21{0xFF26}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xFF47, .upper = &[_]u21{0xFF27}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xFF48, .upper = &[_]u21{0xFF28}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xFF49, .upper = &[_]u21{0xFF29}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xFF4A, .upper = &[_]u21{0xFF2A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xFF4B, .upper = &[_]u21{0xFF2B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xFF4C, .upper = &[_]u21{0xFF2C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xFF4D, .upper = &[_]u21{0xFF2D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xFF4E, .upper = &[_]u21{0xFF2E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xFF4F, .upper = &[_]u21{0xFF2F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xFF50, .upper = &[_]u21{0xFF30}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xFF51, .upper = &[_]u21{0xFF31}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xFF52, .upper = &[_]u21{0xFF32}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xFF53, .upper = &[_]u21{0xFF33}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xFF54, .upper = &[_]u21{0xFF34}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xFF55, .upper = &[_]u21{0xFF35}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xFF56, .upper = &[_]u21{0xFF36}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xFF57, .upper = &[_]u21{0xFF37}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xFF58, .upper = &[_]u21{0xFF38}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xFF59, .upper = &[_]u21{0xFF39}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0xFF5A, .upper = &[_]u21{0xFF3A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10400, .upper = &[_]u21{}, .lower = &[_]u21{0x10428}, .fold = &[_]u21{0x10428} },
+    .{ .codepoint = 0x10401, .upper = &[_]u21{}, .lower = &[_]u21{0x10429}, .fold = &[_]u21{0x10429} },
+    .{ .codepoint = 0x10402, .upper = &[_]u21{}, .lower = &[_]u21{0x1042A}, .fold = &[_]u21{0x1042A} },
+    .{ .codepoint = 0x10403, .upper = &[_]u21{}, .lower = &[_]u21{0x1042B}, .fold = &[_]u21{0x1042B} },
+    .{ .codepoint = 0x10404, .upper = &[_]u21{}, .lower = &[_]u21{0x1042C}, .fold = &[_]u21{0x1042C} },
+    .{ .codepoint = 0x10405, .upper = &[_]u21{}, .lower = &[_]u21{0x1042D}, .fold = &[_]u21{0x1042D} },
+    .{ .codepoint = 0x10406, .upper = &[_]u21{}, .lower = &[_]u21{0x1042E}, .fold = &[_]u21{0x1042E} },
+    .{ .codepoint = 0x10407, .upper = &[_]u21{}, .lower = &[_]u21{0x1042F}, .fold = &[_]u21{0x1042F} },
+    .{ .codepoint = 0x10408, .upper = &[_]u21{}, .lower = &[_]u21{0x10430}, .fold = &[_]u21{0x10430} },
+    .{ .codepoint = 0x10409, .upper = &[_]u21{}, .lower = &[_]u21{0x10431}, .fold = &[_]u21{0x10431} },
+    .{ .codepoint = 0x1040A, .upper = &[_]u21{}, .lower = &[_]u21{0x10432}, .fold = &[_]u21{0x10432} },
+    .{ .codepoint = 0x1040B, .upper = &[_]u21{}, .lower = &[_]u21{0x10433}, .fold = &[_]u21{0x10433} },
+    .{ .codepoint = 0x1040C, .upper = &[_]u21{}, .lower = &[_]u21{0x10434}, .fold = &[_]u21{0x10434} },
+    .{ .codepoint = 0x1040D, .upper = &[_]u21{}, .lower = &[_]u21{0x10435}, .fold = &[_]u21{0x10435} },
+    .{ .codepoint = 0x1040E, .upper = &[_]u21{}, .lower = &[_]u21{0x10436}, .fold = &[_]u21{0x10436} },
+    .{ .codepoint = 0x1040F, .upper = &[_]u21{}, .lower = &[_]u21{0x10437}, .fold = &[_]u21{0x10437} },
+    .{ .codepoint = 0x10410, .upper = &[_]u21{}, .lower = &[_]u21{0x10438}, .fold = &[_]u21{0x10438} },
+    .{ .codepoint = 0x10411, .upper = &[_]u21{}, .lower = &[_]u21{0x10439}, .fold = &[_]u21{0x10439} },
+    .{ .codepoint = 0x10412, .upper = &[_]u21{}, .lower = &[_]u21{0x1043A}, .fold = &[_]u21{0x1043A} },
+    .{ .codepoint = 0x10413, .upper = &[_]u21{}, .lower = &[_]u21{0x1043B}, .fold = &[_]u21{0x1043B} },
+    .{ .codepoint = 0x10414, .upper = &[_]u21{}, .lower = &[_]u21{0x1043C}, .fold = &[_]u21{0x1043C} },
+    .{ .codepoint = 0x10415, .upper = &[_]u21{}, .lower = &[_]u21{0x1043D}, .fold = &[_]u21{0x1043D} },
+    .{ .codepoint = 0x10416, .upper = &[_]u21{}, .lower = &[_]u21{0x1043E}, .fold = &[_]u21{0x1043E} },
+    .{ .codepoint = 0x10417, .upper = &[_]u21{}, .lower = &[_]u21{0x1043F}, .fold = &[_]u21{0x1043F} },
+    .{ .codepoint = 0x10418, .upper = &[_]u21{}, .lower = &[_]u21{0x10440}, .fold = &[_]u21{0x10440} },
+    .{ .codepoint = 0x10419, .upper = &[_]u21{}, .lower = &[_]u21{0x10441}, .fold = &[_]u21{0x10441} },
+    .{ .codepoint = 0x1041A, .upper = &[_]u21{}, .lower = &[_]u21{0x10442}, .fold = &[_]u21{0x10442} },
+    .{ .codepoint = 0x1041B, .upper = &[_]u21{}, .lower = &[_]u21{0x10443}, .fold = &[_]u21{0x10443} },
+    .{ .codepoint = 0x1041C, .upper = &[_]u21{}, .lower = &[_]u21{0x10444}, .fold = &[_]u21{0x10444} },
+    .{ .codepoint = 0x1041D, .upper = &[_]u21{}, .lower = &[_]u21{0x10445}, .fold = &[_]u21{0x10445} },
+    .{ .codepoint = 0x1041E, .upper = &[_]u21{}, .lower = &[_]u21{0x10446}, .fold = &[_]u21{0x10446} },
+    .{ .codepoint = 0x1041F, .upper = &[_]u21{}, .lower = &[_]u21{0x10447}, .fold = &[_]u21{0x10447} },
+    .{ .codepoint = 0x10420, .upper = &[_]u21{}, .lower = &[_]u21{0x10448}, .fold = &[_]u21{0x10448} },
+    .{ .codepoint = 0x10421, .upper = &[_]u21{}, .lower = &[_]u21{0x10449}, .fold = &[_]u21{0x10449} },
+    .{ .codepoint = 0x10422, .upper = &[_]u21{}, .lower = &[_]u21{0x1044A}, .fold = &[_]u21{0x1044A} },
+    .{ .codepoint = 0x10423, .upper = &[_]u21{}, .lower = &[_]u21{0x1044B}, .fold = &[_]u21{0x1044B} },
+    .{ .codepoint = 0x10424, .upper = &[_]u21{}, .lower = &[_]u21{0x1044C}, .fold = &[_]u21{0x1044C} },
+    .{ .codepoint = 0x10425, .upper = &[_]u21{}, .lower = &[_]u21{0x1044D}, .fold = &[_]u21{0x1044D} },
+    .{ .codepoint = 0x10426, .upper = &[_]u21{}, .lower = &[_]u21{0x1044E}, .fold = &[_]u21{0x1044E} },
+    .{ .codepoint = 0x10427, .upper = &[_]u21{}, .lower = &[_]u21{0x1044F}, .fold = &[_]u21{0x1044F} },
+    .{ .codepoint = 0x10428, .upper = &[_]u21{0x10400}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10429, .upper = &[_]u21{0x10401}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1042A, .upper = &[_]u21{0x10402}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1042B, .upper = &[_]u21{0x10403}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1042C, .upper = &[_]u21{0x10404}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1042D, .upper = &[_]u21{0x10405}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1042E, .upper = &[_]u21{0x10406}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1042F, .upper = &[_]u21{0x10407}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10430, .upper = &[_]u21{0x10408}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10431, .upper = &[_]u21{0x10409}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10432, .upper = &[_]u21{0x1040A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10433, .upper = &[_]u21{0x1040B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10434, .upper = &[_]u21{0x1040C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10435, .upper = &[_]u21{0x1040D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10436, .upper = &[_]u21{0x1040E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10437, .upper = &[_]u21{0x1040F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10438, .upper = &[_]u21{0x10410}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10439, .upper = &[_]u21{0x10411}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1043A, .upper = &[_]u21{0x10412}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1043B, .upper = &[_]u21{0x10413}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1043C, .upper = &[_]u21{0x10414}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1043D, .upper = &[_]u21{0x10415}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1043E, .upper = &[_]u21{0x10416}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1043F, .upper = &[_]u21{0x10417}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10440, .upper = &[_]u21{0x10418}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10441, .upper = &[_]u21{0x10419}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10442, .upper = &[_]u21{0x1041A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10443, .upper = &[_]u21{0x1041B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10444, .upper = &[_]u21{0x1041C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10445, .upper = &[_]u21{0x1041D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10446, .upper = &[_]u21{0x1041E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10447, .upper = &[_]u21{0x1041F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10448, .upper = &[_]u21{0x10420}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10449, .upper = &[_]u21{0x10421}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1044A, .upper = &[_]u21{0x10422}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1044B, .upper = &[_]u21{0x10423}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1044C, .upper = &[_]u21{0x10424}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1044D, .upper = &[_]u21{0x10425}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1044E, .upper = &[_]u21{0x10426}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1044F, .upper = &[_]u21{0x10427}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104B0, .upper = &[_]u21{}, .lower = &[_]u21{0x104D8}, .fold = &[_]u21{0x104D8} },
+    .{ .codepoint = 0x104B1, .upper = &[_]u21{}, .lower = &[_]u21{0x104D9}, .fold = &[_]u21{0x104D9} },
+    .{ .codepoint = 0x104B2, .upper = &[_]u21{}, .lower = &[_]u21{0x104DA}, .fold = &[_]u21{0x104DA} },
+    .{ .codepoint = 0x104B3, .upper = &[_]u21{}, .lower = &[_]u21{0x104DB}, .fold = &[_]u21{0x104DB} },
+    .{ .codepoint = 0x104B4, .upper = &[_]u21{}, .lower = &[_]u21{0x104DC}, .fold = &[_]u21{0x104DC} },
+    .{ .codepoint = 0x104B5, .upper = &[_]u21{}, .lower = &[_]u21{0x104DD}, .fold = &[_]u21{0x104DD} },
+    .{ .codepoint = 0x104B6, .upper = &[_]u21{}, .lower = &[_]u21{0x104DE}, .fold = &[_]u21{0x104DE} },
+    .{ .codepoint = 0x104B7, .upper = &[_]u21{}, .lower = &[_]u21{0x104DF}, .fold = &[_]u21{0x104DF} },
+    .{ .codepoint = 0x104B8, .upper = &[_]u21{}, .lower = &[_]u21{0x104E0}, .fold = &[_]u21{0x104E0} },
+    .{ .codepoint = 0x104B9, .upper = &[_]u21{}, .lower = &[_]u21{0x104E1}, .fold = &[_]u21{0x104E1} },
+    .{ .codepoint = 0x104BA, .upper = &[_]u21{}, .lower = &[_]u21{0x104E2}, .fold = &[_]u21{0x104E2} },
+    .{ .codepoint = 0x104BB, .upper = &[_]u21{}, .lower = &[_]u21{0x104E3}, .fold = &[_]u21{0x104E3} },
+    .{ .codepoint = 0x104BC, .upper = &[_]u21{}, .lower = &[_]u21{0x104E4}, .fold = &[_]u21{0x104E4} },
+    .{ .codepoint = 0x104BD, .upper = &[_]u21{}, .lower = &[_]u21{0x104E5}, .fold = &[_]u21{0x104E5} },
+    .{ .codepoint = 0x104BE, .upper = &[_]u21{}, .lower = &[_]u21{0x104E6}, .fold = &[_]u21{0x104E6} },
+    .{ .codepoint = 0x104BF, .upper = &[_]u21{}, .lower = &[_]u21{0x104E7}, .fold = &[_]u21{0x104E7} },
+    .{ .codepoint = 0x104C0, .upper = &[_]u21{}, .lower = &[_]u21{0x104E8}, .fold = &[_]u21{0x104E8} },
+    .{ .codepoint = 0x104C1, .upper = &[_]u21{}, .lower = &[_]u21{0x104E9}, .fold = &[_]u21{0x104E9} },
+    .{ .codepoint = 0x104C2, .upper = &[_]u21{}, .lower = &[_]u21{0x104EA}, .fold = &[_]u21{0x104EA} },
+    .{ .codepoint = 0x104C3, .upper = &[_]u21{}, .lower = &[_]u21{0x104EB}, .fold = &[_]u21{0x104EB} },
+    .{ .codepoint = 0x104C4, .upper = &[_]u21{}, .lower = &[_]u21{0x104EC}, .fold = &[_]u21{0x104EC} },
+    .{ .codepoint = 0x104C5, .upper = &[_]u21{}, .lower = &[_]u21{0x104ED}, .fold = &[_]u21{0x104ED} },
+    .{ .codepoint = 0x104C6, .upper = &[_]u21{}, .lower = &[_]u21{0x104EE}, .fold = &[_]u21{0x104EE} },
+    .{ .codepoint = 0x104C7, .upper = &[_]u21{}, .lower = &[_]u21{0x104EF}, .fold = &[_]u21{0x104EF} },
+    .{ .codepoint = 0x104C8, .upper = &[_]u21{}, .lower = &[_]u21{0x104F0}, .fold = &[_]u21{0x104F0} },
+    .{ .codepoint = 0x104C9, .upper = &[_]u21{}, .lower = &[_]u21{0x104F1}, .fold = &[_]u21{0x104F1} },
+    .{ .codepoint = 0x104CA, .upper = &[_]u21{}, .lower = &[_]u21{0x104F2}, .fold = &[_]u21{0x104F2} },
+    .{ .codepoint = 0x104CB, .upper = &[_]u21{}, .lower = &[_]u21{0x104F3}, .fold = &[_]u21{0x104F3} },
+    .{ .codepoint = 0x104CC, .upper = &[_]u21{}, .lower = &[_]u21{0x104F4}, .fold = &[_]u21{0x104F4} },
+    .{ .codepoint = 0x104CD, .upper = &[_]u21{}, .lower = &[_]u21{0x104F5}, .fold = &[_]u21{0x104F5} },
+    .{ .codepoint = 0x104CE, .upper = &[_]u21{}, .lower = &[_]u21{0x104F6}, .fold = &[_]u21{0x104F6} },
+    .{ .codepoint = 0x104CF, .upper = &[_]u21{}, .lower = &[_]u21{0x104F7}, .fold = &[_]u21{0x104F7} },
+    .{ .codepoint = 0x104D0, .upper = &[_]u21{}, .lower = &[_]u21{0x104F8}, .fold = &[_]u21{0x104F8} },
+    .{ .codepoint = 0x104D1, .upper = &[_]u21{}, .lower = &[_]u21{0x104F9}, .fold = &[_]u21{0x104F9} },
+    .{ .codepoint = 0x104D2, .upper = &[_]u21{}, .lower = &[_]u21{0x104FA}, .fold = &[_]u21{0x104FA} },
+    .{ .codepoint = 0x104D3, .upper = &[_]u21{}, .lower = &[_]u21{0x104FB}, .fold = &[_]u21{0x104FB} },
+    .{ .codepoint = 0x104D8, .upper = &[_]u21{0x104B0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104D9, .upper = &[_]u21{0x104B1}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104DA, .upper = &[_]u21{0x104B2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104DB, .upper = &[_]u21{0x104B3}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104DC, .upper = &[_]u21{0x104B4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104DD, .upper = &[_]u21{0x104B5}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104DE, .upper = &[_]u21{0x104B6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104DF, .upper = &[_]u21{0x104B7}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104E0, .upper = &[_]u21{0x104B8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104E1, .upper = &[_]u21{0x104B9}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104E2, .upper = &[_]u21{0x104BA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104E3, .upper = &[_]u21{0x104BB}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104E4, .upper = &[_]u21{0x104BC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104E5, .upper = &[_]u21{0x104BD}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104E6, .upper = &[_]u21{0x104BE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104E7, .upper = &[_]u21{0x104BF}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104E8, .upper = &[_]u21{0x104C0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104E9, .upper = &[_]u21{0x104C1}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104EA, .upper = &[_]u21{0x104C2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104EB, .upper = &[_]u21{0x104C3}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104EC, .upper = &[_]u21{0x104C4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104ED, .upper = &[_]u21{0x104C5}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104EE, .upper = &[_]u21{0x104C6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104EF, .upper = &[_]u21{0x104C7}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104F0, .upper = &[_]u21{0x104C8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104F1, .upper = &[_]u21{0x104C9}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104F2, .upper = &[_]u21{0x104CA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104F3, .upper = &[_]u21{0x104CB}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104F4, .upper = &[_]u21{0x104CC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104F5, .upper = &[_]u21{0x104CD}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104F6, .upper = &[_]u21{0x104CE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104F7, .upper = &[_]u21{0x104CF}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104F8, .upper = &[_]u21{0x104D0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104F9, .upper = &[_]u21{0x104D1}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104FA, .upper = &[_]u21{0x104D2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x104FB, .upper = &[_]u21{0x104D3}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10570, .upper = &[_]u21{}, .lower = &[_]u21{0x10597}, .fold = &[_]u21{0x10597} },
+    .{ .codepoint = 0x10571, .upper = &[_]u21{}, .lower = &[_]u21{0x10598}, .fold = &[_]u21{0x10598} },
+    .{ .codepoint = 0x10572, .upper = &[_]u21{}, .lower = &[_]u21{0x10599}, .fold = &[_]u21{0x10599} },
+    .{ .codepoint = 0x10573, .upper = &[_]u21{}, .lower = &[_]u21{0x1059A}, .fold = &[_]u21{0x1059A} },
+    .{ .codepoint = 0x10574, .upper = &[_]u21{}, .lower = &[_]u21{0x1059B}, .fold = &[_]u21{0x1059B} },
+    .{ .codepoint = 0x10575, .upper = &[_]u21{}, .lower = &[_]u21{0x1059C}, .fold = &[_]u21{0x1059C} },
+    .{ .codepoint = 0x10576, .upper = &[_]u21{}, .lower = &[_]u21{0x1059D}, .fold = &[_]u21{0x1059D} },
+    .{ .codepoint = 0x10577, .upper = &[_]u21{}, .lower = &[_]u21{0x1059E}, .fold = &[_]u21{0x1059E} },
+    .{ .codepoint = 0x10578, .upper = &[_]u21{}, .lower = &[_]u21{0x1059F}, .fold = &[_]u21{0x1059F} },
+    .{ .codepoint = 0x10579, .upper = &[_]u21{}, .lower = &[_]u21{0x105A0}, .fold = &[_]u21{0x105A0} },
+    .{ .codepoint = 0x1057A, .upper = &[_]u21{}, .lower = &[_]u21{0x105A1}, .fold = &[_]u21{0x105A1} },
+    .{ .codepoint = 0x1057C, .upper = &[_]u21{}, .lower = &[_]u21{0x105A3}, .fold = &[_]u21{0x105A3} },
+    .{ .codepoint = 0x1057D, .upper = &[_]u21{}, .lower = &[_]u21{0x105A4}, .fold = &[_]u21{0x105A4} },
+    .{ .codepoint = 0x1057E, .upper = &[_]u21{}, .lower = &[_]u21{0x105A5}, .fold = &[_]u21{0x105A5} },
+    .{ .codepoint = 0x1057F, .upper = &[_]u21{}, .lower = &[_]u21{0x105A6}, .fold = &[_]u21{0x105A6} },
+    .{ .codepoint = 0x10580, .upper = &[_]u21{}, .lower = &[_]u21{0x105A7}, .fold = &[_]u21{0x105A7} },
+    .{ .codepoint = 0x10581, .upper = &[_]u21{}, .lower = &[_]u21{0x105A8}, .fold = &[_]u21{0x105A8} },
+    .{ .codepoint = 0x10582, .upper = &[_]u21{}, .lower = &[_]u21{0x105A9}, .fold = &[_]u21{0x105A9} },
+    .{ .codepoint = 0x10583, .upper = &[_]u21{}, .lower = &[_]u21{0x105AA}, .fold = &[_]u21{0x105AA} },
+    .{ .codepoint = 0x10584, .upper = &[_]u21{}, .lower = &[_]u21{0x105AB}, .fold = &[_]u21{0x105AB} },
+    .{ .codepoint = 0x10585, .upper = &[_]u21{}, .lower = &[_]u21{0x105AC}, .fold = &[_]u21{0x105AC} },
+    .{ .codepoint = 0x10586, .upper = &[_]u21{}, .lower = &[_]u21{0x105AD}, .fold = &[_]u21{0x105AD} },
+    .{ .codepoint = 0x10587, .upper = &[_]u21{}, .lower = &[_]u21{0x105AE}, .fold = &[_]u21{0x105AE} },
+    .{ .codepoint = 0x10588, .upper = &[_]u21{}, .lower = &[_]u21{0x105AF}, .fold = &[_]u21{0x105AF} },
+    .{ .codepoint = 0x10589, .upper = &[_]u21{}, .lower = &[_]u21{0x105B0}, .fold = &[_]u21{0x105B0} },
+    .{ .codepoint = 0x1058A, .upper = &[_]u21{}, .lower = &[_]u21{0x105B1}, .fold = &[_]u21{0x105B1} },
+    .{ .codepoint = 0x1058C, .upper = &[_]u21{}, .lower = &[_]u21{0x105B3}, .fold = &[_]u21{0x105B3} },
+    .{ .codepoint = 0x1058D, .upper = &[_]u21{}, .lower = &[_]u21{0x105B4}, .fold = &[_]u21{0x105B4} },
+    .{ .codepoint = 0x1058E, .upper = &[_]u21{}, .lower = &[_]u21{0x105B5}, .fold = &[_]u21{0x105B5} },
+    .{ .codepoint = 0x1058F, .upper = &[_]u21{}, .lower = &[_]u21{0x105B6}, .fold = &[_]u21{0x105B6} },
+    .{ .codepoint = 0x10590, .upper = &[_]u21{}, .lower = &[_]u21{0x105B7}, .fold = &[_]u21{0x105B7} },
+    .{ .codepoint = 0x10591, .upper = &[_]u21{}, .lower = &[_]u21{0x105B8}, .fold = &[_]u21{0x105B8} },
+    .{ .codepoint = 0x10592, .upper = &[_]u21{}, .lower = &[_]u21{0x105B9}, .fold = &[_]u21{0x105B9} },
+    .{ .codepoint = 0x10594, .upper = &[_]u21{}, .lower = &[_]u21{0x105BB}, .fold = &[_]u21{0x105BB} },
+    .{ .codepoint = 0x10595, .upper = &[_]u21{}, .lower = &[_]u21{0x105BC}, .fold = &[_]u21{0x105BC} },
+    .{ .codepoint = 0x10597, .upper = &[_]u21{0x10570}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10598, .upper = &[_]u21{0x10571}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10599, .upper = &[_]u21{0x10572}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1059A, .upper = &[_]u21{0x10573}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1059B, .upper = &[_]u21{0x10574}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1059C, .upper = &[_]u21{0x10575}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1059D, .upper = &[_]u21{0x10576}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1059E, .upper = &[_]u21{0x10577}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1059F, .upper = &[_]u21{0x10578}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x105A0, .upper = &[_]u21{0x10579}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x105A1, .upper = &[_]u21{0x1057A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x105A3, .upper = &[_]u21{0x1057C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x105A4, .upper = &[_]u21{0x1057D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x105A5, .upper = &[_]u21{0x1057E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x105A6, .upper = &[_]u21{0x1057F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x105A7, .upper = &[_]u21{0x10580}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x105A8, .upper = &[_]u21{0x10581}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x105A9, .upper = &[_]u21{0x10582}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x105AA, .upper = &[_]u21{0x10583}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x105AB, .upper = &[_]u21{0x10584}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x105AC, .upper = &[_]u21{0x10585}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x105AD, .upper = &[_]u21{0x10586}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x105AE, .upper = &[_]u21{0x10587}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x105AF, .upper = &[_]u21{0x10588}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x105B0, .upper = &[_]u21{0x10589}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x105B1, .upper = &[_]u21{0x1058A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x105B3, .upper = &[_]u21{0x1058C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x105B4, .upper = &[_]u21{0x1058D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x105B5, .upper = &[_]u21{0x1058E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x105B6, .upper = &[_]u21{0x1058F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x105B7, .upper = &[_]u21{0x10590}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x105B8, .upper = &[_]u21{0x10591}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x105B9, .upper = &[_]u21{0x10592}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x105BB, .upper = &[_]u21{0x10594}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x105BC, .upper = &[_]u21{0x10595}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10C80, .upper = &[_]u21{}, .lower = &[_]u21{0x10CC0}, .fold = &[_]u21{0x10CC0} },
+    .{ .codepoint = 0x10C81, .upper = &[_]u21{}, .lower = &[_]u21{0x10CC1}, .fold = &[_]u21{0x10CC1} },
+    .{ .codepoint = 0x10C82, .upper = &[_]u21{}, .lower = &[_]u21{0x10CC2}, .fold = &[_]u21{0x10CC2} },
+    .{ .codepoint = 0x10C83, .upper = &[_]u21{}, .lower = &[_]u21{0x10CC3}, .fold = &[_]u21{0x10CC3} },
+    .{ .codepoint = 0x10C84, .upper = &[_]u21{}, .lower = &[_]u21{0x10CC4}, .fold = &[_]u21{0x10CC4} },
+    .{ .codepoint = 0x10C85, .upper = &[_]u21{}, .lower = &[_]u21{0x10CC5}, .fold = &[_]u21{0x10CC5} },
+    .{ .codepoint = 0x10C86, .upper = &[_]u21{}, .lower = &[_]u21{0x10CC6}, .fold = &[_]u21{0x10CC6} },
+    .{ .codepoint = 0x10C87, .upper = &[_]u21{}, .lower = &[_]u21{0x10CC7}, .fold = &[_]u21{0x10CC7} },
+    .{ .codepoint = 0x10C88, .upper = &[_]u21{}, .lower = &[_]u21{0x10CC8}, .fold = &[_]u21{0x10CC8} },
+    .{ .codepoint = 0x10C89, .upper = &[_]u21{}, .lower = &[_]u21{0x10CC9}, .fold = &[_]u21{0x10CC9} },
+    .{ .codepoint = 0x10C8A, .upper = &[_]u21{}, .lower = &[_]u21{0x10CCA}, .fold = &[_]u21{0x10CCA} },
+    .{ .codepoint = 0x10C8B, .upper = &[_]u21{}, .lower = &[_]u21{0x10CCB}, .fold = &[_]u21{0x10CCB} },
+    .{ .codepoint = 0x10C8C, .upper = &[_]u21{}, .lower = &[_]u21{0x10CCC}, .fold = &[_]u21{0x10CCC} },
+    .{ .codepoint = 0x10C8D, .upper = &[_]u21{}, .lower = &[_]u21{0x10CCD}, .fold = &[_]u21{0x10CCD} },
+    .{ .codepoint = 0x10C8E, .upper = &[_]u21{}, .lower = &[_]u21{0x10CCE}, .fold = &[_]u21{0x10CCE} },
+    .{ .codepoint = 0x10C8F, .upper = &[_]u21{}, .lower = &[_]u21{0x10CCF}, .fold = &[_]u21{0x10CCF} },
+    .{ .codepoint = 0x10C90, .upper = &[_]u21{}, .lower = &[_]u21{0x10CD0}, .fold = &[_]u21{0x10CD0} },
+    .{ .codepoint = 0x10C91, .upper = &[_]u21{}, .lower = &[_]u21{0x10CD1}, .fold = &[_]u21{0x10CD1} },
+    .{ .codepoint = 0x10C92, .upper = &[_]u21{}, .lower = &[_]u21{0x10CD2}, .fold = &[_]u21{0x10CD2} },
+    .{ .codepoint = 0x10C93, .upper = &[_]u21{}, .lower = &[_]u21{0x10CD3}, .fold = &[_]u21{0x10CD3} },
+    .{ .codepoint = 0x10C94, .upper = &[_]u21{}, .lower = &[_]u21{0x10CD4}, .fold = &[_]u21{0x10CD4} },
+    .{ .codepoint = 0x10C95, .upper = &[_]u21{}, .lower = &[_]u21{0x10CD5}, .fold = &[_]u21{0x10CD5} },
+    .{ .codepoint = 0x10C96, .upper = &[_]u21{}, .lower = &[_]u21{0x10CD6}, .fold = &[_]u21{0x10CD6} },
+    .{ .codepoint = 0x10C97, .upper = &[_]u21{}, .lower = &[_]u21{0x10CD7}, .fold = &[_]u21{0x10CD7} },
+    .{ .codepoint = 0x10C98, .upper = &[_]u21{}, .lower = &[_]u21{0x10CD8}, .fold = &[_]u21{0x10CD8} },
+    .{ .codepoint = 0x10C99, .upper = &[_]u21{}, .lower = &[_]u21{0x10CD9}, .fold = &[_]u21{0x10CD9} },
+    .{ .codepoint = 0x10C9A, .upper = &[_]u21{}, .lower = &[_]u21{0x10CDA}, .fold = &[_]u21{0x10CDA} },
+    .{ .codepoint = 0x10C9B, .upper = &[_]u21{}, .lower = &[_]u21{0x10CDB}, .fold = &[_]u21{0x10CDB} },
+    .{ .codepoint = 0x10C9C, .upper = &[_]u21{}, .lower = &[_]u21{0x10CDC}, .fold = &[_]u21{0x10CDC} },
+    .{ .codepoint = 0x10C9D, .upper = &[_]u21{}, .lower = &[_]u21{0x10CDD}, .fold = &[_]u21{0x10CDD} },
+    .{ .codepoint = 0x10C9E, .upper = &[_]u21{}, .lower = &[_]u21{0x10CDE}, .fold = &[_]u21{0x10CDE} },
+    .{ .codepoint = 0x10C9F, .upper = &[_]u21{}, .lower = &[_]u21{0x10CDF}, .fold = &[_]u21{0x10CDF} },
+    .{ .codepoint = 0x10CA0, .upper = &[_]u21{}, .lower = &[_]u21{0x10CE0}, .fold = &[_]u21{0x10CE0} },
+    .{ .codepoint = 0x10CA1, .upper = &[_]u21{}, .lower = &[_]u21{0x10CE1}, .fold = &[_]u21{0x10CE1} },
+    .{ .codepoint = 0x10CA2, .upper = &[_]u21{}, .lower = &[_]u21{0x10CE2}, .fold = &[_]u21{0x10CE2} },
+    .{ .codepoint = 0x10CA3, .upper = &[_]u21{}, .lower = &[_]u21{0x10CE3}, .fold = &[_]u21{0x10CE3} },
+    .{ .codepoint = 0x10CA4, .upper = &[_]u21{}, .lower = &[_]u21{0x10CE4}, .fold = &[_]u21{0x10CE4} },
+    .{ .codepoint = 0x10CA5, .upper = &[_]u21{}, .lower = &[_]u21{0x10CE5}, .fold = &[_]u21{0x10CE5} },
+    .{ .codepoint = 0x10CA6, .upper = &[_]u21{}, .lower = &[_]u21{0x10CE6}, .fold = &[_]u21{0x10CE6} },
+    .{ .codepoint = 0x10CA7, .upper = &[_]u21{}, .lower = &[_]u21{0x10CE7}, .fold = &[_]u21{0x10CE7} },
+    .{ .codepoint = 0x10CA8, .upper = &[_]u21{}, .lower = &[_]u21{0x10CE8}, .fold = &[_]u21{0x10CE8} },
+    .{ .codepoint = 0x10CA9, .upper = &[_]u21{}, .lower = &[_]u21{0x10CE9}, .fold = &[_]u21{0x10CE9} },
+    .{ .codepoint = 0x10CAA, .upper = &[_]u21{}, .lower = &[_]u21{0x10CEA}, .fold = &[_]u21{0x10CEA} },
+    .{ .codepoint = 0x10CAB, .upper = &[_]u21{}, .lower = &[_]u21{0x10CEB}, .fold = &[_]u21{0x10CEB} },
+    .{ .codepoint = 0x10CAC, .upper = &[_]u21{}, .lower = &[_]u21{0x10CEC}, .fold = &[_]u21{0x10CEC} },
+    .{ .codepoint = 0x10CAD, .upper = &[_]u21{}, .lower = &[_]u21{0x10CED}, .fold = &[_]u21{0x10CED} },
+    .{ .codepoint = 0x10CAE, .upper = &[_]u21{}, .lower = &[_]u21{0x10CEE}, .fold = &[_]u21{0x10CEE} },
+    .{ .codepoint = 0x10CAF, .upper = &[_]u21{}, .lower = &[_]u21{0x10CEF}, .fold = &[_]u21{0x10CEF} },
+    .{ .codepoint = 0x10CB0, .upper = &[_]u21{}, .lower = &[_]u21{0x10CF0}, .fold = &[_]u21{0x10CF0} },
+    .{ .codepoint = 0x10CB1, .upper = &[_]u21{}, .lower = &[_]u21{0x10CF1}, .fold = &[_]u21{0x10CF1} },
+    .{ .codepoint = 0x10CB2, .upper = &[_]u21{}, .lower = &[_]u21{0x10CF2}, .fold = &[_]u21{0x10CF2} },
+    .{ .codepoint = 0x10CC0, .upper = &[_]u21{0x10C80}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CC1, .upper = &[_]u21{0x10C81}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CC2, .upper = &[_]u21{0x10C82}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CC3, .upper = &[_]u21{0x10C83}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CC4, .upper = &[_]u21{0x10C84}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CC5, .upper = &[_]u21{0x10C85}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CC6, .upper = &[_]u21{0x10C86}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CC7, .upper = &[_]u21{0x10C87}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CC8, .upper = &[_]u21{0x10C88}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CC9, .upper = &[_]u21{0x10C89}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CCA, .upper = &[_]u21{0x10C8A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CCB, .upper = &[_]u21{0x10C8B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CCC, .upper = &[_]u21{0x10C8C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CCD, .upper = &[_]u21{0x10C8D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CCE, .upper = &[_]u21{0x10C8E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CCF, .upper = &[_]u21{0x10C8F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CD0, .upper = &[_]u21{0x10C90}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CD1, .upper = &[_]u21{0x10C91}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CD2, .upper = &[_]u21{0x10C92}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CD3, .upper = &[_]u21{0x10C93}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CD4, .upper = &[_]u21{0x10C94}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CD5, .upper = &[_]u21{0x10C95}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CD6, .upper = &[_]u21{0x10C96}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CD7, .upper = &[_]u21{0x10C97}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CD8, .upper = &[_]u21{0x10C98}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CD9, .upper = &[_]u21{0x10C99}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CDA, .upper = &[_]u21{0x10C9A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CDB, .upper = &[_]u21{0x10C9B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CDC, .upper = &[_]u21{0x10C9C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CDD, .upper = &[_]u21{0x10C9D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CDE, .upper = &[_]u21{0x10C9E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CDF, .upper = &[_]u21{0x10C9F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CE0, .upper = &[_]u21{0x10CA0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CE1, .upper = &[_]u21{0x10CA1}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CE2, .upper = &[_]u21{0x10CA2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CE3, .upper = &[_]u21{0x10CA3}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CE4, .upper = &[_]u21{0x10CA4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CE5, .upper = &[_]u21{0x10CA5}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CE6, .upper = &[_]u21{0x10CA6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CE7, .upper = &[_]u21{0x10CA7}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CE8, .upper = &[_]u21{0x10CA8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CE9, .upper = &[_]u21{0x10CA9}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CEA, .upper = &[_]u21{0x10CAA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CEB, .upper = &[_]u21{0x10CAB}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CEC, .upper = &[_]u21{0x10CAC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CED, .upper = &[_]u21{0x10CAD}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CEE, .upper = &[_]u21{0x10CAE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CEF, .upper = &[_]u21{0x10CAF}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CF0, .upper = &[_]u21{0x10CB0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CF1, .upper = &[_]u21{0x10CB1}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x10CF2, .upper = &[_]u21{0x10CB2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118A0, .upper = &[_]u21{}, .lower = &[_]u21{0x118C0}, .fold = &[_]u21{0x118C0} },
+    .{ .codepoint = 0x118A1, .upper = &[_]u21{}, .lower = &[_]u21{0x118C1}, .fold = &[_]u21{0x118C1} },
+    .{ .codepoint = 0x118A2, .upper = &[_]u21{}, .lower = &[_]u21{0x118C2}, .fold = &[_]u21{0x118C2} },
+    .{ .codepoint = 0x118A3, .upper = &[_]u21{}, .lower = &[_]u21{0x118C3}, .fold = &[_]u21{0x118C3} },
+    .{ .codepoint = 0x118A4, .upper = &[_]u21{}, .lower = &[_]u21{0x118C4}, .fold = &[_]u21{0x118C4} },
+    .{ .codepoint = 0x118A5, .upper = &[_]u21{}, .lower = &[_]u21{0x118C5}, .fold = &[_]u21{0x118C5} },
+    .{ .codepoint = 0x118A6, .upper = &[_]u21{}, .lower = &[_]u21{0x118C6}, .fold = &[_]u21{0x118C6} },
+    .{ .codepoint = 0x118A7, .upper = &[_]u21{}, .lower = &[_]u21{0x118C7}, .fold = &[_]u21{0x118C7} },
+    .{ .codepoint = 0x118A8, .upper = &[_]u21{}, .lower = &[_]u21{0x118C8}, .fold = &[_]u21{0x118C8} },
+    .{ .codepoint = 0x118A9, .upper = &[_]u21{}, .lower = &[_]u21{0x118C9}, .fold = &[_]u21{0x118C9} },
+    .{ .codepoint = 0x118AA, .upper = &[_]u21{}, .lower = &[_]u21{0x118CA}, .fold = &[_]u21{0x118CA} },
+    .{ .codepoint = 0x118AB, .upper = &[_]u21{}, .lower = &[_]u21{0x118CB}, .fold = &[_]u21{0x118CB} },
+    .{ .codepoint = 0x118AC, .upper = &[_]u21{}, .lower = &[_]u21{0x118CC}, .fold = &[_]u21{0x118CC} },
+    .{ .codepoint = 0x118AD, .upper = &[_]u21{}, .lower = &[_]u21{0x118CD}, .fold = &[_]u21{0x118CD} },
+    .{ .codepoint = 0x118AE, .upper = &[_]u21{}, .lower = &[_]u21{0x118CE}, .fold = &[_]u21{0x118CE} },
+    .{ .codepoint = 0x118AF, .upper = &[_]u21{}, .lower = &[_]u21{0x118CF}, .fold = &[_]u21{0x118CF} },
+    .{ .codepoint = 0x118B0, .upper = &[_]u21{}, .lower = &[_]u21{0x118D0}, .fold = &[_]u21{0x118D0} },
+    .{ .codepoint = 0x118B1, .upper = &[_]u21{}, .lower = &[_]u21{0x118D1}, .fold = &[_]u21{0x118D1} },
+    .{ .codepoint = 0x118B2, .upper = &[_]u21{}, .lower = &[_]u21{0x118D2}, .fold = &[_]u21{0x118D2} },
+    .{ .codepoint = 0x118B3, .upper = &[_]u21{}, .lower = &[_]u21{0x118D3}, .fold = &[_]u21{0x118D3} },
+    .{ .codepoint = 0x118B4, .upper = &[_]u21{}, .lower = &[_]u21{0x118D4}, .fold = &[_]u21{0x118D4} },
+    .{ .codepoint = 0x118B5, .upper = &[_]u21{}, .lower = &[_]u21{0x118D5}, .fold = &[_]u21{0x118D5} },
+    .{ .codepoint = 0x118B6, .upper = &[_]u21{}, .lower = &[_]u21{0x118D6}, .fold = &[_]u21{0x118D6} },
+    .{ .codepoint = 0x118B7, .upper = &[_]u21{}, .lower = &[_]u21{0x118D7}, .fold = &[_]u21{0x118D7} },
+    .{ .codepoint = 0x118B8, .upper = &[_]u21{}, .lower = &[_]u21{0x118D8}, .fold = &[_]u21{0x118D8} },
+    .{ .codepoint = 0x118B9, .upper = &[_]u21{}, .lower = &[_]u21{0x118D9}, .fold = &[_]u21{0x118D9} },
+    .{ .codepoint = 0x118BA, .upper = &[_]u21{}, .lower = &[_]u21{0x118DA}, .fold = &[_]u21{0x118DA} },
+    .{ .codepoint = 0x118BB, .upper = &[_]u21{}, .lower = &[_]u21{0x118DB}, .fold = &[_]u21{0x118DB} },
+    .{ .codepoint = 0x118BC, .upper = &[_]u21{}, .lower = &[_]u21{0x118DC}, .fold = &[_]u21{0x118DC} },
+    .{ .codepoint = 0x118BD, .upper = &[_]u21{}, .lower = &[_]u21{0x118DD}, .fold = &[_]u21{0x118DD} },
+    .{ .codepoint = 0x118BE, .upper = &[_]u21{}, .lower = &[_]u21{0x118DE}, .fold = &[_]u21{0x118DE} },
+    .{ .codepoint = 0x118BF, .upper = &[_]u21{}, .lower = &[_]u21{0x118DF}, .fold = &[_]u21{0x118DF} },
+    .{ .codepoint = 0x118C0, .upper = &[_]u21{0x118A0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118C1, .upper = &[_]u21{0x118A1}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118C2, .upper = &[_]u21{0x118A2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118C3, .upper = &[_]u21{0x118A3}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118C4, .upper = &[_]u21{0x118A4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118C5, .upper = &[_]u21{0x118A5}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118C6, .upper = &[_]u21{0x118A6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118C7, .upper = &[_]u21{0x118A7}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118C8, .upper = &[_]u21{0x118A8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118C9, .upper = &[_]u21{0x118A9}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118CA, .upper = &[_]u21{0x118AA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118CB, .upper = &[_]u21{0x118AB}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118CC, .upper = &[_]u21{0x118AC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118CD, .upper = &[_]u21{0x118AD}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118CE, .upper = &[_]u21{0x118AE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118CF, .upper = &[_]u21{0x118AF}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118D0, .upper = &[_]u21{0x118B0}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118D1, .upper = &[_]u21{0x118B1}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118D2, .upper = &[_]u21{0x118B2}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118D3, .upper = &[_]u21{0x118B3}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118D4, .upper = &[_]u21{0x118B4}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118D5, .upper = &[_]u21{0x118B5}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118D6, .upper = &[_]u21{0x118B6}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118D7, .upper = &[_]u21{0x118B7}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118D8, .upper = &[_]u21{0x118B8}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118D9, .upper = &[_]u21{0x118B9}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118DA, .upper = &[_]u21{0x118BA}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118DB, .upper = &[_]u21{0x118BB}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118DC, .upper = &[_]u21{0x118BC}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118DD, .upper = &[_]u21{0x118BD}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118DE, .upper = &[_]u21{0x118BE}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x118DF, .upper = &[_]u21{0x118BF}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E40, .upper = &[_]u21{}, .lower = &[_]u21{0x16E60}, .fold = &[_]u21{0x16E60} },
+    .{ .codepoint = 0x16E41, .upper = &[_]u21{}, .lower = &[_]u21{0x16E61}, .fold = &[_]u21{0x16E61} },
+    .{ .codepoint = 0x16E42, .upper = &[_]u21{}, .lower = &[_]u21{0x16E62}, .fold = &[_]u21{0x16E62} },
+    .{ .codepoint = 0x16E43, .upper = &[_]u21{}, .lower = &[_]u21{0x16E63}, .fold = &[_]u21{0x16E63} },
+    .{ .codepoint = 0x16E44, .upper = &[_]u21{}, .lower = &[_]u21{0x16E64}, .fold = &[_]u21{0x16E64} },
+    .{ .codepoint = 0x16E45, .upper = &[_]u21{}, .lower = &[_]u21{0x16E65}, .fold = &[_]u21{0x16E65} },
+    .{ .codepoint = 0x16E46, .upper = &[_]u21{}, .lower = &[_]u21{0x16E66}, .fold = &[_]u21{0x16E66} },
+    .{ .codepoint = 0x16E47, .upper = &[_]u21{}, .lower = &[_]u21{0x16E67}, .fold = &[_]u21{0x16E67} },
+    .{ .codepoint = 0x16E48, .upper = &[_]u21{}, .lower = &[_]u21{0x16E68}, .fold = &[_]u21{0x16E68} },
+    .{ .codepoint = 0x16E49, .upper = &[_]u21{}, .lower = &[_]u21{0x16E69}, .fold = &[_]u21{0x16E69} },
+    .{ .codepoint = 0x16E4A, .upper = &[_]u21{}, .lower = &[_]u21{0x16E6A}, .fold = &[_]u21{0x16E6A} },
+    .{ .codepoint = 0x16E4B, .upper = &[_]u21{}, .lower = &[_]u21{0x16E6B}, .fold = &[_]u21{0x16E6B} },
+    .{ .codepoint = 0x16E4C, .upper = &[_]u21{}, .lower = &[_]u21{0x16E6C}, .fold = &[_]u21{0x16E6C} },
+    .{ .codepoint = 0x16E4D, .upper = &[_]u21{}, .lower = &[_]u21{0x16E6D}, .fold = &[_]u21{0x16E6D} },
+    .{ .codepoint = 0x16E4E, .upper = &[_]u21{}, .lower = &[_]u21{0x16E6E}, .fold = &[_]u21{0x16E6E} },
+    .{ .codepoint = 0x16E4F, .upper = &[_]u21{}, .lower = &[_]u21{0x16E6F}, .fold = &[_]u21{0x16E6F} },
+    .{ .codepoint = 0x16E50, .upper = &[_]u21{}, .lower = &[_]u21{0x16E70}, .fold = &[_]u21{0x16E70} },
+    .{ .codepoint = 0x16E51, .upper = &[_]u21{}, .lower = &[_]u21{0x16E71}, .fold = &[_]u21{0x16E71} },
+    .{ .codepoint = 0x16E52, .upper = &[_]u21{}, .lower = &[_]u21{0x16E72}, .fold = &[_]u21{0x16E72} },
+    .{ .codepoint = 0x16E53, .upper = &[_]u21{}, .lower = &[_]u21{0x16E73}, .fold = &[_]u21{0x16E73} },
+    .{ .codepoint = 0x16E54, .upper = &[_]u21{}, .lower = &[_]u21{0x16E74}, .fold = &[_]u21{0x16E74} },
+    .{ .codepoint = 0x16E55, .upper = &[_]u21{}, .lower = &[_]u21{0x16E75}, .fold = &[_]u21{0x16E75} },
+    .{ .codepoint = 0x16E56, .upper = &[_]u21{}, .lower = &[_]u21{0x16E76}, .fold = &[_]u21{0x16E76} },
+    .{ .codepoint = 0x16E57, .upper = &[_]u21{}, .lower = &[_]u21{0x16E77}, .fold = &[_]u21{0x16E77} },
+    .{ .codepoint = 0x16E58, .upper = &[_]u21{}, .lower = &[_]u21{0x16E78}, .fold = &[_]u21{0x16E78} },
+    .{ .codepoint = 0x16E59, .upper = &[_]u21{}, .lower = &[_]u21{0x16E79}, .fold = &[_]u21{0x16E79} },
+    .{ .codepoint = 0x16E5A, .upper = &[_]u21{}, .lower = &[_]u21{0x16E7A}, .fold = &[_]u21{0x16E7A} },
+    .{ .codepoint = 0x16E5B, .upper = &[_]u21{}, .lower = &[_]u21{0x16E7B}, .fold = &[_]u21{0x16E7B} },
+    .{ .codepoint = 0x16E5C, .upper = &[_]u21{}, .lower = &[_]u21{0x16E7C}, .fold = &[_]u21{0x16E7C} },
+    .{ .codepoint = 0x16E5D, .upper = &[_]u21{}, .lower = &[_]u21{0x16E7D}, .fold = &[_]u21{0x16E7D} },
+    .{ .codepoint = 0x16E5E, .upper = &[_]u21{}, .lower = &[_]u21{0x16E7E}, .fold = &[_]u21{0x16E7E} },
+    .{ .codepoint = 0x16E5F, .upper = &[_]u21{}, .lower = &[_]u21{0x16E7F}, .fold = &[_]u21{0x16E7F} },
+    .{ .codepoint = 0x16E60, .upper = &[_]u21{0x16E40}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E61, .upper = &[_]u21{0x16E41}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E62, .upper = &[_]u21{0x16E42}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E63, .upper = &[_]u21{0x16E43}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E64, .upper = &[_]u21{0x16E44}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E65, .upper = &[_]u21{0x16E45}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E66, .upper = &[_]u21{0x16E46}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E67, .upper = &[_]u21{0x16E47}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E68, .upper = &[_]u21{0x16E48}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E69, .upper = &[_]u21{0x16E49}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E6A, .upper = &[_]u21{0x16E4A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E6B, .upper = &[_]u21{0x16E4B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E6C, .upper = &[_]u21{0x16E4C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E6D, .upper = &[_]u21{0x16E4D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E6E, .upper = &[_]u21{0x16E4E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E6F, .upper = &[_]u21{0x16E4F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E70, .upper = &[_]u21{0x16E50}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E71, .upper = &[_]u21{0x16E51}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E72, .upper = &[_]u21{0x16E52}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E73, .upper = &[_]u21{0x16E53}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E74, .upper = &[_]u21{0x16E54}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E75, .upper = &[_]u21{0x16E55}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E76, .upper = &[_]u21{0x16E56}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E77, .upper = &[_]u21{0x16E57}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E78, .upper = &[_]u21{0x16E58}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E79, .upper = &[_]u21{0x16E59}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E7A, .upper = &[_]u21{0x16E5A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E7B, .upper = &[_]u21{0x16E5B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E7C, .upper = &[_]u21{0x16E5C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E7D, .upper = &[_]u21{0x16E5D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E7E, .upper = &[_]u21{0x16E5E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x16E7F, .upper = &[_]u21{0x16E5F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E900, .upper = &[_]u21{}, .lower = &[_]u21{0x1E922}, .fold = &[_]u21{0x1E922} },
+    .{ .codepoint = 0x1E901, .upper = &[_]u21{}, .lower = &[_]u21{0x1E923}, .fold = &[_]u21{0x1E923} },
+    .{ .codepoint = 0x1E902, .upper = &[_]u21{}, .lower = &[_]u21{0x1E924}, .fold = &[_]u21{0x1E924} },
+    .{ .codepoint = 0x1E903, .upper = &[_]u21{}, .lower = &[_]u21{0x1E925}, .fold = &[_]u21{0x1E925} },
+    .{ .codepoint = 0x1E904, .upper = &[_]u21{}, .lower = &[_]u21{0x1E926}, .fold = &[_]u21{0x1E926} },
+    .{ .codepoint = 0x1E905, .upper = &[_]u21{}, .lower = &[_]u21{0x1E927}, .fold = &[_]u21{0x1E927} },
+    .{ .codepoint = 0x1E906, .upper = &[_]u21{}, .lower = &[_]u21{0x1E928}, .fold = &[_]u21{0x1E928} },
+    .{ .codepoint = 0x1E907, .upper = &[_]u21{}, .lower = &[_]u21{0x1E929}, .fold = &[_]u21{0x1E929} },
+    .{ .codepoint = 0x1E908, .upper = &[_]u21{}, .lower = &[_]u21{0x1E92A}, .fold = &[_]u21{0x1E92A} },
+    .{ .codepoint = 0x1E909, .upper = &[_]u21{}, .lower = &[_]u21{0x1E92B}, .fold = &[_]u21{0x1E92B} },
+    .{ .codepoint = 0x1E90A, .upper = &[_]u21{}, .lower = &[_]u21{0x1E92C}, .fold = &[_]u21{0x1E92C} },
+    .{ .codepoint = 0x1E90B, .upper = &[_]u21{}, .lower = &[_]u21{0x1E92D}, .fold = &[_]u21{0x1E92D} },
+    .{ .codepoint = 0x1E90C, .upper = &[_]u21{}, .lower = &[_]u21{0x1E92E}, .fold = &[_]u21{0x1E92E} },
+    .{ .codepoint = 0x1E90D, .upper = &[_]u21{}, .lower = &[_]u21{0x1E92F}, .fold = &[_]u21{0x1E92F} },
+    .{ .codepoint = 0x1E90E, .upper = &[_]u21{}, .lower = &[_]u21{0x1E930}, .fold = &[_]u21{0x1E930} },
+    .{ .codepoint = 0x1E90F, .upper = &[_]u21{}, .lower = &[_]u21{0x1E931}, .fold = &[_]u21{0x1E931} },
+    .{ .codepoint = 0x1E910, .upper = &[_]u21{}, .lower = &[_]u21{0x1E932}, .fold = &[_]u21{0x1E932} },
+    .{ .codepoint = 0x1E911, .upper = &[_]u21{}, .lower = &[_]u21{0x1E933}, .fold = &[_]u21{0x1E933} },
+    .{ .codepoint = 0x1E912, .upper = &[_]u21{}, .lower = &[_]u21{0x1E934}, .fold = &[_]u21{0x1E934} },
+    .{ .codepoint = 0x1E913, .upper = &[_]u21{}, .lower = &[_]u21{0x1E935}, .fold = &[_]u21{0x1E935} },
+    .{ .codepoint = 0x1E914, .upper = &[_]u21{}, .lower = &[_]u21{0x1E936}, .fold = &[_]u21{0x1E936} },
+    .{ .codepoint = 0x1E915, .upper = &[_]u21{}, .lower = &[_]u21{0x1E937}, .fold = &[_]u21{0x1E937} },
+    .{ .codepoint = 0x1E916, .upper = &[_]u21{}, .lower = &[_]u21{0x1E938}, .fold = &[_]u21{0x1E938} },
+    .{ .codepoint = 0x1E917, .upper = &[_]u21{}, .lower = &[_]u21{0x1E939}, .fold = &[_]u21{0x1E939} },
+    .{ .codepoint = 0x1E918, .upper = &[_]u21{}, .lower = &[_]u21{0x1E93A}, .fold = &[_]u21{0x1E93A} },
+    .{ .codepoint = 0x1E919, .upper = &[_]u21{}, .lower = &[_]u21{0x1E93B}, .fold = &[_]u21{0x1E93B} },
+    .{ .codepoint = 0x1E91A, .upper = &[_]u21{}, .lower = &[_]u21{0x1E93C}, .fold = &[_]u21{0x1E93C} },
+    .{ .codepoint = 0x1E91B, .upper = &[_]u21{}, .lower = &[_]u21{0x1E93D}, .fold = &[_]u21{0x1E93D} },
+    .{ .codepoint = 0x1E91C, .upper = &[_]u21{}, .lower = &[_]u21{0x1E93E}, .fold = &[_]u21{0x1E93E} },
+    .{ .codepoint = 0x1E91D, .upper = &[_]u21{}, .lower = &[_]u21{0x1E93F}, .fold = &[_]u21{0x1E93F} },
+    .{ .codepoint = 0x1E91E, .upper = &[_]u21{}, .lower = &[_]u21{0x1E940}, .fold = &[_]u21{0x1E940} },
+    .{ .codepoint = 0x1E91F, .upper = &[_]u21{}, .lower = &[_]u21{0x1E941}, .fold = &[_]u21{0x1E941} },
+    .{ .codepoint = 0x1E920, .upper = &[_]u21{}, .lower = &[_]u21{0x1E942}, .fold = &[_]u21{0x1E942} },
+    .{ .codepoint = 0x1E921, .upper = &[_]u21{}, .lower = &[_]u21{0x1E943}, .fold = &[_]u21{0x1E943} },
+    .{ .codepoint = 0x1E922, .upper = &[_]u21{0x1E900}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E923, .upper = &[_]u21{0x1E901}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E924, .upper = &[_]u21{0x1E902}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E925, .upper = &[_]u21{0x1E903}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E926, .upper = &[_]u21{0x1E904}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E927, .upper = &[_]u21{0x1E905}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E928, .upper = &[_]u21{0x1E906}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E929, .upper = &[_]u21{0x1E907}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E92A, .upper = &[_]u21{0x1E908}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E92B, .upper = &[_]u21{0x1E909}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E92C, .upper = &[_]u21{0x1E90A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E92D, .upper = &[_]u21{0x1E90B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E92E, .upper = &[_]u21{0x1E90C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E92F, .upper = &[_]u21{0x1E90D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E930, .upper = &[_]u21{0x1E90E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E931, .upper = &[_]u21{0x1E90F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E932, .upper = &[_]u21{0x1E910}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E933, .upper = &[_]u21{0x1E911}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E934, .upper = &[_]u21{0x1E912}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E935, .upper = &[_]u21{0x1E913}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E936, .upper = &[_]u21{0x1E914}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E937, .upper = &[_]u21{0x1E915}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E938, .upper = &[_]u21{0x1E916}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E939, .upper = &[_]u21{0x1E917}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E93A, .upper = &[_]u21{0x1E918}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E93B, .upper = &[_]u21{0x1E919}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E93C, .upper = &[_]u21{0x1E91A}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E93D, .upper = &[_]u21{0x1E91B}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E93E, .upper = &[_]u21{0x1E91C}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E93F, .upper = &[_]u21{0x1E91D}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E940, .upper = &[_]u21{0x1E91E}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E941, .upper = &[_]u21{0x1E91F}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E942, .upper = &[_]u21{0x1E920}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+    .{ .codepoint = 0x1E943, .upper = &[_]u21{0x1E921}, .lower = &[_]u21{}, .fold = &[_]u21{} },
+};
+
+pub const combining_classes = [_]CombiningClass{
+    .{ .codepoint = 0x0300, .class = 230 },
+    .{ .codepoint = 0x0301, .class = 230 },
+    .{ .codepoint = 0x0302, .class = 230 },
+    .{ .codepoint = 0x0303, .class = 230 },
+    .{ .codepoint = 0x0304, .class = 230 },
+    .{ .codepoint = 0x0305, .class = 230 },
+    .{ .codepoint = 0x0306, .class = 230 },
+    .{ .codepoint = 0x0307, .class = 230 },
+    .{ .codepoint = 0x0308, .class = 230 },
+    .{ .codepoint = 0x0309, .class = 230 },
+    .{ .codepoint = 0x030A, .class = 230 },
+    .{ .codepoint = 0x030B, .class = 230 },
+    .{ .codepoint = 0x030C, .class = 230 },
+    .{ .codepoint = 0x030D, .class = 230 },
+    .{ .codepoint = 0x030E, .class = 230 },
+    .{ .codepoint = 0x030F, .class = 230 },
+    .{ .codepoint = 0x0310, .class = 230 },
+    .{ .codepoint = 0x0311, .class = 230 },
+    .{ .codepoint = 0x0312, .class = 230 },
+    .{ .codepoint = 0x0313, .class = 230 },
+    .{ .codepoint = 0x0314, .class = 230 },
+    .{ .codepoint = 0x0315, .class = 232 },
+    .{ .codepoint = 0x0316, .class = 220 },
+    .{ .codepoint = 0x0317, .class = 220 },
+    .{ .codepoint = 0x0318, .class = 220 },
+    .{ .codepoint = 0x0319, .class = 220 },
+    .{ .codepoint = 0x031A, .class = 232 },
+    .{ .codepoint = 0x031B, .class = 216 },
+    .{ .codepoint = 0x031C, .class = 220 },
+    .{ .codepoint = 0x031D, .class = 220 },
+    .{ .codepoint = 0x031E, .class = 220 },
+    .{ .codepoint = 0x031F, .class = 220 },
+    .{ .codepoint = 0x0320, .class = 220 },
+    .{ .codepoint = 0x0321, .class = 202 },
+    .{ .codepoint = 0x0322, .class = 202 },
+    .{ .codepoint = 0x0323, .class = 220 },
+    .{ .codepoint = 0x0324, .class = 220 },
+    .{ .codepoint = 0x0325, .class = 220 },
+    .{ .codepoint = 0x0326, .class = 220 },
+    .{ .codepoint = 0x0327, .class = 202 },
+    .{ .codepoint = 0x0328, .class = 202 },
+    .{ .codepoint = 0x0329, .class = 220 },
+    .{ .codepoint = 0x032A, .class = 220 },
+    .{ .codepoint = 0x032B, .class = 220 },
+    .{ .codepoint = 0x032C, .class = 220 },
+    .{ .codepoint = 0x032D, .class = 220 },
+    .{ .codepoint = 0x032E, .class = 220 },
+    .{ .codepoint = 0x032F, .class = 220 },
+    .{ .codepoint = 0x0330, .class = 220 },
+    .{ .codepoint = 0x0331, .class = 220 },
+    .{ .codepoint = 0x0332, .class = 220 },
+    .{ .codepoint = 0x0333, .class = 220 },
+    .{ .codepoint = 0x0334, .class = 1 },
+    .{ .codepoint = 0x0335, .class = 1 },
+    .{ .codepoint = 0x0336, .class = 1 },
+    .{ .codepoint = 0x0337, .class = 1 },
+    .{ .codepoint = 0x0338, .class = 1 },
+    .{ .codepoint = 0x0339, .class = 220 },
+    .{ .codepoint = 0x033A, .class = 220 },
+    .{ .codepoint = 0x033B, .class = 220 },
+    .{ .codepoint = 0x033C, .class = 220 },
+    .{ .codepoint = 0x033D, .class = 230 },
+    .{ .codepoint = 0x033E, .class = 230 },
+    .{ .codepoint = 0x033F, .class = 230 },
+    .{ .codepoint = 0x0340, .class = 230 },
+    .{ .codepoint = 0x0341, .class = 230 },
+    .{ .codepoint = 0x0342, .class = 230 },
+    .{ .codepoint = 0x0343, .class = 230 },
+    .{ .codepoint = 0x0344, .class = 230 },
+    .{ .codepoint = 0x0345, .class = 240 },
+    .{ .codepoint = 0x0346, .class = 230 },
+    .{ .codepoint = 0x0347, .class = 220 },
+    .{ .codepoint = 0x0348, .class = 220 },
+    .{ .codepoint = 0x0349, .class = 220 },
+    .{ .codepoint = 0x034A, .class = 230 },
+    .{ .codepoint = 0x034B, .class = 230 },
+    .{ .codepoint = 0x034C, .class = 230 },
+    .{ .codepoint = 0x034D, .class = 220 },
+    .{ .codepoint = 0x034E, .class = 220 },
+    .{ .codepoint = 0x0350, .class = 230 },
+    .{ .codepoint = 0x0351, .class = 230 },
+    .{ .codepoint = 0x0352, .class = 230 },
+    .{ .codepoint = 0x0353, .class = 220 },
+    .{ .codepoint = 0x0354, .class = 220 },
+    .{ .codepoint = 0x0355, .class = 220 },
+    .{ .codepoint = 0x0356, .class = 220 },
+    .{ .codepoint = 0x0357, .class = 230 },
+    .{ .codepoint = 0x0358, .class = 232 },
+    .{ .codepoint = 0x0359, .class = 220 },
+    .{ .codepoint = 0x035A, .class = 220 },
+    .{ .codepoint = 0x035B, .class = 230 },
+    .{ .codepoint = 0x035C, .class = 233 },
+    .{ .codepoint = 0x035D, .class = 234 },
+    .{ .codepoint = 0x035E, .class = 234 },
+    .{ .codepoint = 0x035F, .class = 233 },
+    .{ .codepoint = 0x0360, .class = 234 },
+    .{ .codepoint = 0x0361, .class = 234 },
+    .{ .codepoint = 0x0362, .class = 233 },
+    .{ .codepoint = 0x0363, .class = 230 },
+    .{ .codepoint = 0x0364, .class = 230 },
+    .{ .codepoint = 0x0365, .class = 230 },
+    .{ .codepoint = 0x0366, .class = 230 },
+    .{ .codepoint = 0x0367, .class = 230 },
+    .{ .codepoint = 0x0368, .class = 230 },
+    .{ .codepoint = 0x0369, .class = 230 },
+    .{ .codepoint = 0x036A, .class = 230 },
+    .{ .codepoint = 0x036B, .class = 230 },
+    .{ .codepoint = 0x036C, .class = 230 },
+    .{ .codepoint = 0x036D, .class = 230 },
+    .{ .codepoint = 0x036E, .class = 230 },
+    .{ .codepoint = 0x036F, .class = 230 },
+    .{ .codepoint = 0x0483, .class = 230 },
+    .{ .codepoint = 0x0484, .class = 230 },
+    .{ .codepoint = 0x0485, .class = 230 },
+    .{ .codepoint = 0x0486, .class = 230 },
+    .{ .codepoint = 0x0487, .class = 230 },
+    .{ .codepoint = 0x0591, .class = 220 },
+    .{ .codepoint = 0x0592, .class = 230 },
+    .{ .codepoint = 0x0593, .class = 230 },
+    .{ .codepoint = 0x0594, .class = 230 },
+    .{ .codepoint = 0x0595, .class = 230 },
+    .{ .codepoint = 0x0596, .class = 220 },
+    .{ .codepoint = 0x0597, .class = 230 },
+    .{ .codepoint = 0x0598, .class = 230 },
+    .{ .codepoint = 0x0599, .class = 230 },
+    .{ .codepoint = 0x059A, .class = 222 },
+    .{ .codepoint = 0x059B, .class = 220 },
+    .{ .codepoint = 0x059C, .class = 230 },
+    .{ .codepoint = 0x059D, .class = 230 },
+    .{ .codepoint = 0x059E, .class = 230 },
+    .{ .codepoint = 0x059F, .class = 230 },
+    .{ .codepoint = 0x05A0, .class = 230 },
+    .{ .codepoint = 0x05A1, .class = 230 },
+    .{ .codepoint = 0x05A2, .class = 220 },
+    .{ .codepoint = 0x05A3, .class = 220 },
+    .{ .codepoint = 0x05A4, .class = 220 },
+    .{ .codepoint = 0x05A5, .class = 220 },
+    .{ .codepoint = 0x05A6, .class = 220 },
+    .{ .codepoint = 0x05A7, .class = 220 },
+    .{ .codepoint = 0x05A8, .class = 230 },
+    .{ .codepoint = 0x05A9, .class = 230 },
+    .{ .codepoint = 0x05AA, .class = 220 },
+    .{ .codepoint = 0x05AB, .class = 230 },
+    .{ .codepoint = 0x05AC, .class = 230 },
+    .{ .codepoint = 0x05AD, .class = 222 },
+    .{ .codepoint = 0x05AE, .class = 228 },
+    .{ .codepoint = 0x05AF, .class = 230 },
+    .{ .codepoint = 0x05B0, .class = 10 },
+    .{ .codepoint = 0x05B1, .class = 11 },
+    .{ .codepoint = 0x05B2, .class = 12 },
+    .{ .codepoint = 0x05B3, .class = 13 },
+    .{ .codepoint = 0x05B4, .class = 14 },
+    .{ .codepoint = 0x05B5, .class = 15 },
+    .{ .codepoint = 0x05B6, .class = 16 },
+    .{ .codepoint = 0x05B7, .class = 17 },
+    .{ .codepoint = 0x05B8, .class = 18 },
+    .{ .codepoint = 0x05B9, .class = 19 },
+    .{ .codepoint = 0x05BA, .class = 19 },
+    .{ .codepoint = 0x05BB, .class = 20 },
+    .{ .codepoint = 0x05BC, .class = 21 },
+    .{ .codepoint = 0x05BD, .class = 22 },
+    .{ .codepoint = 0x05BF, .class = 23 },
+    .{ .codepoint = 0x05C1, .class = 24 },
+    .{ .codepoint = 0x05C2, .class = 25 },
+    .{ .codepoint = 0x05C4, .class = 230 },
+    .{ .codepoint = 0x05C5, .class = 220 },
+    .{ .codepoint = 0x05C7, .class = 18 },
+    .{ .codepoint = 0x0610, .class = 230 },
+    .{ .codepoint = 0x0611, .class = 230 },
+    .{ .codepoint = 0x0612, .class = 230 },
+    .{ .codepoint = 0x0613, .class = 230 },
+    .{ .codepoint = 0x0614, .class = 230 },
+    .{ .codepoint = 0x0615, .class = 230 },
+    .{ .codepoint = 0x0616, .class = 230 },
+    .{ .codepoint = 0x0617, .class = 230 },
+    .{ .codepoint = 0x0618, .class = 30 },
+    .{ .codepoint = 0x0619, .class = 31 },
+    .{ .codepoint = 0x061A, .class = 32 },
+    .{ .codepoint = 0x064B, .class = 27 },
+    .{ .codepoint = 0x064C, .class = 28 },
+    .{ .codepoint = 0x064D, .class = 29 },
+    .{ .codepoint = 0x064E, .class = 30 },
+    .{ .codepoint = 0x064F, .class = 31 },
+    .{ .codepoint = 0x0650, .class = 32 },
+    .{ .codepoint = 0x0651, .class = 33 },
+    .{ .codepoint = 0x0652, .class = 34 },
+    .{ .codepoint = 0x0653, .class = 230 },
+    .{ .codepoint = 0x0654, .class = 230 },
+    .{ .codepoint = 0x0655, .class = 220 },
+    .{ .codepoint = 0x0656, .class = 220 },
+    .{ .codepoint = 0x0657, .class = 230 },
+    .{ .codepoint = 0x0658, .class = 230 },
+    .{ .codepoint = 0x0659, .class = 230 },
+    .{ .codepoint = 0x065A, .class = 230 },
+    .{ .codepoint = 0x065B, .class = 230 },
+    .{ .codepoint = 0x065C, .class = 220 },
+    .{ .codepoint = 0x065D, .class = 230 },
+    .{ .codepoint = 0x065E, .class = 230 },
+    .{ .codepoint = 0x065F, .class = 220 },
+    .{ .codepoint = 0x0670, .class = 35 },
+    .{ .codepoint = 0x06D6, .class = 230 },
+    .{ .codepoint = 0x06D7, .class = 230 },
+    .{ .codepoint = 0x06D8, .class = 230 },
+    .{ .codepoint = 0x06D9, .class = 230 },
+    .{ .codepoint = 0x06DA, .class = 230 },
+    .{ .codepoint = 0x06DB, .class = 230 },
+    .{ .codepoint = 0x06DC, .class = 230 },
+    .{ .codepoint = 0x06DF, .class = 230 },
+    .{ .codepoint = 0x06E0, .class = 230 },
+    .{ .codepoint = 0x06E1, .class = 230 },
+    .{ .codepoint = 0x06E2, .class = 230 },
+    .{ .codepoint = 0x06E3, .class = 220 },
+    .{ .codepoint = 0x06E4, .class = 230 },
+    .{ .codepoint = 0x06E7, .class = 230 },
+    .{ .codepoint = 0x06E8, .class = 230 },
+    .{ .codepoint = 0x06EA, .class = 220 },
+    .{ .codepoint = 0x06EB, .class = 230 },
+    .{ .codepoint = 0x06EC, .class = 230 },
+    .{ .codepoint = 0x06ED, .class = 220 },
+    .{ .codepoint = 0x0711, .class = 36 },
+    .{ .codepoint = 0x0730, .class = 230 },
+    .{ .codepoint = 0x0731, .class = 220 },
+    .{ .codepoint = 0x0732, .class = 230 },
+    .{ .codepoint = 0x0733, .class = 230 },
+    .{ .codepoint = 0x0734, .class = 220 },
+    .{ .codepoint = 0x0735, .class = 230 },
+    .{ .codepoint = 0x0736, .class = 230 },
+    .{ .codepoint = 0x0737, .class = 220 },
+    .{ .codepoint = 0x0738, .class = 220 },
+    .{ .codepoint = 0x0739, .class = 220 },
+    .{ .codepoint = 0x073A, .class = 230 },
+    .{ .codepoint = 0x073B, .class = 220 },
+    .{ .codepoint = 0x073C, .class = 220 },
+    .{ .codepoint = 0x073D, .class = 230 },
+    .{ .codepoint = 0x073E, .class = 220 },
+    .{ .codepoint = 0x073F, .class = 230 },
+    .{ .codepoint = 0x0740, .class = 230 },
+    .{ .codepoint = 0x0741, .class = 230 },
+    .{ .codepoint = 0x0742, .class = 220 },
+    .{ .codepoint = 0x0743, .class = 230 },
+    .{ .codepoint = 0x0744, .class = 220 },
+    .{ .codepoint = 0x0745, .class = 230 },
+    .{ .codepoint = 0x0746, .class = 220 },
+    .{ .codepoint = 0x0747, .class = 230 },
+    .{ .codepoint = 0x0748, .class = 220 },
+    .{ .codepoint = 0x0749, .class = 230 },
+    .{ .codepoint = 0x074A, .class = 230 },
+    .{ .codepoint = 0x07EB, .class = 230 },
+    .{ .codepoint = 0x07EC, .class = 230 },
+    .{ .codepoint = 0x07ED, .class = 230 },
+    .{ .codepoint = 0x07EE, .class = 230 },
+    .{ .codepoint = 0x07EF, .class = 230 },
+    .{ .codepoint = 0x07F0, .class = 230 },
+    .{ .codepoint = 0x07F1, .class = 230 },
+    .{ .codepoint = 0x07F2, .class = 220 },
+    .{ .codepoint = 0x07F3, .class = 230 },
+    .{ .codepoint = 0x07FD, .class = 220 },
+    .{ .codepoint = 0x0816, .class = 230 },
+    .{ .codepoint = 0x0817, .class = 230 },
+    .{ .codepoint = 0x0818, .class = 230 },
+    .{ .codepoint = 0x0819, .class = 230 },
+    .{ .codepoint = 0x081B, .class = 230 },
+    .{ .codepoint = 0x081C, .class = 230 },
+    .{ .codepoint = 0x081D, .class = 230 },
+    .{ .codepoint = 0x081E, .class = 230 },
+    .{ .codepoint = 0x081F, .class = 230 },
+    .{ .codepoint = 0x0820, .class = 230 },
+    .{ .codepoint = 0x0821, .class = 230 },
+    .{ .codepoint = 0x0822, .class = 230 },
+    .{ .codepoint = 0x0823, .class = 230 },
+    .{ .codepoint = 0x0825, .class = 230 },
+    .{ .codepoint = 0x0826, .class = 230 },
+    .{ .codepoint = 0x0827, .class = 230 },
+    .{ .codepoint = 0x0829, .class = 230 },
+    .{ .codepoint = 0x082A, .class = 230 },
+    .{ .codepoint = 0x082B, .class = 230 },
+    .{ .codepoint = 0x082C, .class = 230 },
+    .{ .codepoint = 0x082D, .class = 230 },
+    .{ .codepoint = 0x0859, .class = 220 },
+    .{ .codepoint = 0x085A, .class = 220 },
+    .{ .codepoint = 0x085B, .class = 220 },
+    .{ .codepoint = 0x0898, .class = 230 },
+    .{ .codepoint = 0x0899, .class = 220 },
+    .{ .codepoint = 0x089A, .class = 220 },
+    .{ .codepoint = 0x089B, .class = 220 },
+    .{ .codepoint = 0x089C, .class = 230 },
+    .{ .codepoint = 0x089D, .class = 230 },
+    .{ .codepoint = 0x089E, .class = 230 },
+    .{ .codepoint = 0x089F, .class = 230 },
+    .{ .codepoint = 0x08CA, .class = 230 },
+    .{ .codepoint = 0x08CB, .class = 230 },
+    .{ .codepoint = 0x08CC, .class = 230 },
+    .{ .codepoint = 0x08CD, .class = 230 },
+    .{ .codepoint = 0x08CE, .class = 230 },
+    .{ .codepoint = 0x08CF, .class = 220 },
+    .{ .codepoint = 0x08D0, .class = 220 },
+    .{ .codepoint = 0x08D1, .class = 220 },
+    .{ .codepoint = 0x08D2, .class = 220 },
+    .{ .codepoint = 0x08D3, .class = 220 },
+    .{ .codepoint = 0x08D4, .class = 230 },
+    .{ .codepoint = 0x08D5, .class = 230 },
+    .{ .codepoint = 0x08D6, .class = 230 },
+    .{ .codepoint = 0x08D7, .class = 230 },
+    .{ .codepoint = 0x08D8, .class = 230 },
+    .{ .codepoint = 0x08D9, .class = 230 },
+    .{ .codepoint = 0x08DA, .class = 230 },
+    .{ .codepoint = 0x08DB, .class = 230 },
+    .{ .codepoint = 0x08DC, .class = 230 },
+    .{ .codepoint = 0x08DD, .class = 230 },
+    .{ .codepoint = 0x08DE, .class = 230 },
+    .{ .codepoint = 0x08DF, .class = 230 },
+    .{ .codepoint = 0x08E0, .class = 230 },
+    .{ .codepoint = 0x08E1, .class = 230 },
+    .{ .codepoint = 0x08E3, .class = 220 },
+    .{ .codepoint = 0x08E4, .class = 230 },
+    .{ .codepoint = 0x08E5, .class = 230 },
+    .{ .codepoint = 0x08E6, .class = 220 },
+    .{ .codepoint = 0x08E7, .class = 230 },
+    .{ .codepoint = 0x08E8, .class = 230 },
+    .{ .codepoint = 0x08E9, .class = 220 },
+    .{ .codepoint = 0x08EA, .class = 230 },
+    .{ .codepoint = 0x08EB, .class = 230 },
+    .{ .codepoint = 0x08EC, .class = 230 },
+    .{ .codepoint = 0x08ED, .class = 220 },
+    .{ .codepoint = 0x08EE, .class = 220 },
+    .{ .codepoint = 0x08EF, .class = 220 },
+    .{ .codepoint = 0x08F0, .class = 27 },
+    .{ .codepoint = 0x08F1, .class = 28 },
+    .{ .codepoint = 0x08F2, .class = 29 },
+    .{ .codepoint = 0x08F3, .class = 230 },
+    .{ .codepoint = 0x08F4, .class = 230 },
+    .{ .codepoint = 0x08F5, .class = 230 },
+    .{ .codepoint = 0x08F6, .class = 220 },
+    .{ .codepoint = 0x08F7, .class = 230 },
+    .{ .codepoint = 0x08F8, .class = 230 },
+    .{ .codepoint = 0x08F9, .class = 220 },
+    .{ .codepoint = 0x08FA, .class = 220 },
+    .{ .codepoint = 0x08FB, .class = 230 },
+    .{ .codepoint = 0x08FC, .class = 230 },
+    .{ .codepoint = 0x08FD, .class = 230 },
+    .{ .codepoint = 0x08FE, .class = 230 },
+    .{ .codepoint = 0x08FF, .class = 230 },
+    .{ .codepoint = 0x093C, .class = 7 },
+    .{ .codepoint = 0x094D, .class = 9 },
+    .{ .codepoint = 0x0951, .class = 230 },
+    .{ .codepoint = 0x0952, .class = 220 },
+    .{ .codepoint = 0x0953, .class = 230 },
+    .{ .codepoint = 0x0954, .class = 230 },
+    .{ .codepoint = 0x09BC, .class = 7 },
+    .{ .codepoint = 0x09CD, .class = 9 },
+    .{ .codepoint = 0x09FE, .class = 230 },
+    .{ .codepoint = 0x0A3C, .class = 7 },
+    .{ .codepoint = 0x0A4D, .class = 9 },
+    .{ .codepoint = 0x0ABC, .class = 7 },
+    .{ .codepoint = 0x0ACD, .class = 9 },
+    .{ .codepoint = 0x0B3C, .class = 7 },
+    .{ .codepoint = 0x0B4D, .class = 9 },
+    .{ .codepoint = 0x0BCD, .class = 9 },
+    .{ .codepoint = 0x0C3C, .class = 7 },
+    .{ .codepoint = 0x0C4D, .class = 9 },
+    .{ .codepoint = 0x0C55, .class = 84 },
+    .{ .codepoint = 0x0C56, .class = 91 },
+    .{ .codepoint = 0x0CBC, .class = 7 },
+    .{ .codepoint = 0x0CCD, .class = 9 },
+    .{ .codepoint = 0x0D3B, .class = 9 },
+    .{ .codepoint = 0x0D3C, .class = 9 },
+    .{ .codepoint = 0x0D4D, .class = 9 },
+    .{ .codepoint = 0x0DCA, .class = 9 },
+    .{ .codepoint = 0x0E38, .class = 103 },
+    .{ .codepoint = 0x0E39, .class = 103 },
+    .{ .codepoint = 0x0E3A, .class = 9 },
+    .{ .codepoint = 0x0E48, .class = 107 },
+    .{ .codepoint = 0x0E49, .class = 107 },
+    .{ .codepoint = 0x0E4A, .class = 107 },
+    .{ .codepoint = 0x0E4B, .class = 107 },
+    .{ .codepoint = 0x0EB8, .class = 118 },
+    .{ .codepoint = 0x0EB9, .class = 118 },
+    .{ .codepoint = 0x0EBA, .class = 9 },
+    .{ .codepoint = 0x0EC8, .class = 122 },
+    .{ .codepoint = 0x0EC9, .class = 122 },
+    .{ .codepoint = 0x0ECA, .class = 122 },
+    .{ .codepoint = 0x0ECB, .class = 122 },
+    .{ .codepoint = 0x0F18, .class = 220 },
+    .{ .codepoint = 0x0F19, .class = 220 },
+    .{ .codepoint = 0x0F35, .class = 220 },
+    .{ .codepoint = 0x0F37, .class = 220 },
+    .{ .codepoint = 0x0F39, .class = 216 },
+    .{ .codepoint = 0x0F71, .class = 129 },
+    .{ .codepoint = 0x0F72, .class = 130 },
+    .{ .codepoint = 0x0F74, .class = 132 },
+    .{ .codepoint = 0x0F7A, .class = 130 },
+    .{ .codepoint = 0x0F7B, .class = 130 },
+    .{ .codepoint = 0x0F7C, .class = 130 },
+    .{ .codepoint = 0x0F7D, .class = 130 },
+    .{ .codepoint = 0x0F80, .class = 130 },
+    .{ .codepoint = 0x0F82, .class = 230 },
+    .{ .codepoint = 0x0F83, .class = 230 },
+    .{ .codepoint = 0x0F84, .class = 9 },
+    .{ .codepoint = 0x0F86, .class = 230 },
+    .{ .codepoint = 0x0F87, .class = 230 },
+    .{ .codepoint = 0x0FC6, .class = 220 },
+    .{ .codepoint = 0x1037, .class = 7 },
+    .{ .codepoint = 0x1039, .class = 9 },
+    .{ .codepoint = 0x103A, .class = 9 },
+    .{ .codepoint = 0x108D, .class = 220 },
+    .{ .codepoint = 0x135D, .class = 230 },
+    .{ .codepoint = 0x135E, .class = 230 },
+    .{ .codepoint = 0x135F, .class = 230 },
+    .{ .codepoint = 0x1714, .class = 9 },
+    .{ .codepoint = 0x1715, .class = 9 },
+    .{ .codepoint = 0x1734, .class = 9 },
+    .{ .codepoint = 0x17D2, .class = 9 },
+    .{ .codepoint = 0x17DD, .class = 230 },
+    .{ .codepoint = 0x18A9, .class = 228 },
+    .{ .codepoint = 0x1939, .class = 222 },
+    .{ .codepoint = 0x193A, .class = 230 },
+    .{ .codepoint = 0x193B, .class = 220 },
+    .{ .codepoint = 0x1A17, .class = 230 },
+    .{ .codepoint = 0x1A18, .class = 220 },
+    .{ .codepoint = 0x1A60, .class = 9 },
+    .{ .codepoint = 0x1A75, .class = 230 },
+    .{ .codepoint = 0x1A76, .class = 230 },
+    .{ .codepoint = 0x1A77, .class = 230 },
+    .{ .codepoint = 0x1A78, .class = 230 },
+    .{ .codepoint = 0x1A79, .class = 230 },
+    .{ .codepoint = 0x1A7A, .class = 230 },
+    .{ .codepoint = 0x1A7B, .class = 230 },
+    .{ .codepoint = 0x1A7C, .class = 230 },
+    .{ .codepoint = 0x1A7F, .class = 220 },
+    .{ .codepoint = 0x1AB0, .class = 230 },
+    .{ .codepoint = 0x1AB1, .class = 230 },
+    .{ .codepoint = 0x1AB2, .class = 230 },
+    .{ .codepoint = 0x1AB3, .class = 230 },
+    .{ .codepoint = 0x1AB4, .class = 230 },
+    .{ .codepoint = 0x1AB5, .class = 220 },
+    .{ .codepoint = 0x1AB6, .class = 220 },
+    .{ .codepoint = 0x1AB7, .class = 220 },
+    .{ .codepoint = 0x1AB8, .class = 220 },
+    .{ .codepoint = 0x1AB9, .class = 220 },
+    .{ .codepoint = 0x1ABA, .class = 220 },
+    .{ .codepoint = 0x1ABB, .class = 230 },
+    .{ .codepoint = 0x1ABC, .class = 230 },
+    .{ .codepoint = 0x1ABD, .class = 220 },
+    .{ .codepoint = 0x1ABF, .class = 220 },
+    .{ .codepoint = 0x1AC0, .class = 220 },
+    .{ .codepoint = 0x1AC1, .class = 230 },
+    .{ .codepoint = 0x1AC2, .class = 230 },
+    .{ .codepoint = 0x1AC3, .class = 220 },
+    .{ .codepoint = 0x1AC4, .class = 220 },
+    .{ .codepoint = 0x1AC5, .class = 230 },
+    .{ .codepoint = 0x1AC6, .class = 230 },
+    .{ .codepoint = 0x1AC7, .class = 230 },
+    .{ .codepoint = 0x1AC8, .class = 230 },
+    .{ .codepoint = 0x1AC9, .class = 230 },
+    .{ .codepoint = 0x1ACA, .class = 220 },
+    .{ .codepoint = 0x1ACB, .class = 230 },
+    .{ .codepoint = 0x1ACC, .class = 230 },
+    .{ .codepoint = 0x1ACD, .class = 230 },
+    .{ .codepoint = 0x1ACE, .class = 230 },
+    .{ .codepoint = 0x1B34, .class = 7 },
+    .{ .codepoint = 0x1B44, .class = 9 },
+    .{ .codepoint = 0x1B6B, .class = 230 },
+    .{ .codepoint = 0x1B6C, .class = 220 },
+    .{ .codepoint = 0x1B6D, .class = 230 },
+    .{ .codepoint = 0x1B6E, .class = 230 },
+    .{ .codepoint = 0x1B6F, .class = 230 },
+    .{ .codepoint = 0x1B70, .class = 230 },
+    .{ .codepoint = 0x1B71, .class = 230 },
+    .{ .codepoint = 0x1B72, .class = 230 },
+    .{ .codepoint = 0x1B73, .class = 230 },
+    .{ .codepoint = 0x1BAA, .class = 9 },
+    .{ .codepoint = 0x1BAB, .class = 9 },
+    .{ .codepoint = 0x1BE6, .class = 7 },
+    .{ .codepoint = 0x1BF2, .class = 9 },
+    .{ .codepoint = 0x1BF3, .class = 9 },
+    .{ .codepoint = 0x1C37, .class = 7 },
+    .{ .codepoint = 0x1CD0, .class = 230 },
+    .{ .codepoint = 0x1CD1, .class = 230 },
+    .{ .codepoint = 0x1CD2, .class = 230 },
+    .{ .codepoint = 0x1CD4, .class = 1 },
+    .{ .codepoint = 0x1CD5, .class = 220 },
+    .{ .codepoint = 0x1CD6, .class = 220 },
+    .{ .codepoint = 0x1CD7, .class = 220 },
+    .{ .codepoint = 0x1CD8, .class = 220 },
+    .{ .codepoint = 0x1CD9, .class = 220 },
+    .{ .codepoint = 0x1CDA, .class = 230 },
+    .{ .codepoint = 0x1CDB, .class = 230 },
+    .{ .codepoint = 0x1CDC, .class = 220 },
+    .{ .codepoint = 0x1CDD, .class = 220 },
+    .{ .codepoint = 0x1CDE, .class = 220 },
+    .{ .codepoint = 0x1CDF, .class = 220 },
+    .{ .codepoint = 0x1CE0, .class = 230 },
+    .{ .codepoint = 0x1CE2, .class = 1 },
+    .{ .codepoint = 0x1CE3, .class = 1 },
+    .{ .codepoint = 0x1CE4, .class = 1 },
+    .{ .codepoint = 0x1CE5, .class = 1 },
+    .{ .codepoint = 0x1CE6, .class = 1 },
+    .{ .codepoint = 0x1CE7, .class = 1 },
+    .{ .codepoint = 0x1CE8, .class = 1 },
+    .{ .codepoint = 0x1CED, .class = 220 },
+    .{ .codepoint = 0x1CF4, .class = 230 },
+    .{ .codepoint = 0x1CF8, .class = 230 },
+    .{ .codepoint = 0x1CF9, .class = 230 },
+    .{ .codepoint = 0x1DC0, .class = 230 },
+    .{ .codepoint = 0x1DC1, .class = 230 },
+    .{ .codepoint = 0x1DC2, .class = 220 },
+    .{ .codepoint = 0x1DC3, .class = 230 },
+    .{ .codepoint = 0x1DC4, .class = 230 },
+    .{ .codepoint = 0x1DC5, .class = 230 },
+    .{ .codepoint = 0x1DC6, .class = 230 },
+    .{ .codepoint = 0x1DC7, .class = 230 },
+    .{ .codepoint = 0x1DC8, .class = 230 },
+    .{ .codepoint = 0x1DC9, .class = 230 },
+    .{ .codepoint = 0x1DCA, .class = 220 },
+    .{ .codepoint = 0x1DCB, .class = 230 },
+    .{ .codepoint = 0x1DCC, .class = 230 },
+    .{ .codepoint = 0x1DCD, .class = 234 },
+    .{ .codepoint = 0x1DCE, .class = 214 },
+    .{ .codepoint = 0x1DCF, .class = 220 },
+    .{ .codepoint = 0x1DD0, .class = 202 },
+    .{ .codepoint = 0x1DD1, .class = 230 },
+    .{ .codepoint = 0x1DD2, .class = 230 },
+    .{ .codepoint = 0x1DD3, .class = 230 },
+    .{ .codepoint = 0x1DD4, .class = 230 },
+    .{ .codepoint = 0x1DD5, .class = 230 },
+    .{ .codepoint = 0x1DD6, .class = 230 },
+    .{ .codepoint = 0x1DD7, .class = 230 },
+    .{ .codepoint = 0x1DD8, .class = 230 },
+    .{ .codepoint = 0x1DD9, .class = 230 },
+    .{ .codepoint = 0x1DDA, .class = 230 },
+    .{ .codepoint = 0x1DDB, .class = 230 },
+    .{ .codepoint = 0x1DDC, .class = 230 },
+    .{ .codepoint = 0x1DDD, .class = 230 },
+    .{ .codepoint = 0x1DDE, .class = 230 },
+    .{ .codepoint = 0x1DDF, .class = 230 },
+    .{ .codepoint = 0x1DE0, .class = 230 },
+    .{ .codepoint = 0x1DE1, .class = 230 },
+    .{ .codepoint = 0x1DE2, .class = 230 },
+    .{ .codepoint = 0x1DE3, .class = 230 },
+    .{ .codepoint = 0x1DE4, .class = 230 },
+    .{ .codepoint = 0x1DE5, .class = 230 },
+    .{ .codepoint = 0x1DE6, .class = 230 },
+    .{ .codepoint = 0x1DE7, .class = 230 },
+    .{ .codepoint = 0x1DE8, .class = 230 },
+    .{ .codepoint = 0x1DE9, .class = 230 },
+    .{ .codepoint = 0x1DEA, .class = 230 },
+    .{ .codepoint = 0x1DEB, .class = 230 },
+    .{ .codepoint = 0x1DEC, .class = 230 },
+    .{ .codepoint = 0x1DED, .class = 230 },
+    .{ .codepoint = 0x1DEE, .class = 230 },
+    .{ .codepoint = 0x1DEF, .class = 230 },
+    .{ .codepoint = 0x1DF0, .class = 230 },
+    .{ .codepoint = 0x1DF1, .class = 230 },
+    .{ .codepoint = 0x1DF2, .class = 230 },
+    .{ .codepoint = 0x1DF3, .class = 230 },
+    .{ .codepoint = 0x1DF4, .class = 230 },
+    .{ .codepoint = 0x1DF5, .class = 230 },
+    .{ .codepoint = 0x1DF6, .class = 232 },
+    .{ .codepoint = 0x1DF7, .class = 228 },
+    .{ .codepoint = 0x1DF8, .class = 228 },
+    .{ .codepoint = 0x1DF9, .class = 220 },
+    .{ .codepoint = 0x1DFA, .class = 218 },
+    .{ .codepoint = 0x1DFB, .class = 230 },
+    .{ .codepoint = 0x1DFC, .class = 233 },
+    .{ .codepoint = 0x1DFD, .class = 220 },
+    .{ .codepoint = 0x1DFE, .class = 230 },
+    .{ .codepoint = 0x1DFF, .class = 220 },
+    .{ .codepoint = 0x20D0, .class = 230 },
+    .{ .codepoint = 0x20D1, .class = 230 },
+    .{ .codepoint = 0x20D2, .class = 1 },
+    .{ .codepoint = 0x20D3, .class = 1 },
+    .{ .codepoint = 0x20D4, .class = 230 },
+    .{ .codepoint = 0x20D5, .class = 230 },
+    .{ .codepoint = 0x20D6, .class = 230 },
+    .{ .codepoint = 0x20D7, .class = 230 },
+    .{ .codepoint = 0x20D8, .class = 1 },
+    .{ .codepoint = 0x20D9, .class = 1 },
+    .{ .codepoint = 0x20DA, .class = 1 },
+    .{ .codepoint = 0x20DB, .class = 230 },
+    .{ .codepoint = 0x20DC, .class = 230 },
+    .{ .codepoint = 0x20E1, .class = 230 },
+    .{ .codepoint = 0x20E5, .class = 1 },
+    .{ .codepoint = 0x20E6, .class = 1 },
+    .{ .codepoint = 0x20E7, .class = 230 },
+    .{ .codepoint = 0x20E8, .class = 220 },
+    .{ .codepoint = 0x20E9, .class = 230 },
+    .{ .codepoint = 0x20EA, .class = 1 },
+    .{ .codepoint = 0x20EB, .class = 1 },
+    .{ .codepoint = 0x20EC, .class = 220 },
+    .{ .codepoint = 0x20ED, .class = 220 },
+    .{ .codepoint = 0x20EE, .class = 220 },
+    .{ .codepoint = 0x20EF, .class = 220 },
+    .{ .codepoint = 0x20F0, .class = 230 },
+    .{ .codepoint = 0x2CEF, .class = 230 },
+    .{ .codepoint = 0x2CF0, .class = 230 },
+    .{ .codepoint = 0x2CF1, .class = 230 },
+    .{ .codepoint = 0x2D7F, .class = 9 },
+    .{ .codepoint = 0x2DE0, .class = 230 },
+    .{ .codepoint = 0x2DE1, .class = 230 },
+    .{ .codepoint = 0x2DE2, .class = 230 },
+    .{ .codepoint = 0x2DE3, .class = 230 },
+    .{ .codepoint = 0x2DE4, .class = 230 },
+    .{ .codepoint = 0x2DE5, .class = 230 },
+    .{ .codepoint = 0x2DE6, .class = 230 },
+    .{ .codepoint = 0x2DE7, .class = 230 },
+    .{ .codepoint = 0x2DE8, .class = 230 },
+    .{ .codepoint = 0x2DE9, .class = 230 },
+    .{ .codepoint = 0x2DEA, .class = 230 },
+    .{ .codepoint = 0x2DEB, .class = 230 },
+    .{ .codepoint = 0x2DEC, .class = 230 },
+    .{ .codepoint = 0x2DED, .class = 230 },
+    .{ .codepoint = 0x2DEE, .class = 230 },
+    .{ .codepoint = 0x2DEF, .class = 230 },
+    .{ .codepoint = 0x2DF0, .class = 230 },
+    .{ .codepoint = 0x2DF1, .class = 230 },
+    .{ .codepoint = 0x2DF2, .class = 230 },
+    .{ .codepoint = 0x2DF3, .class = 230 },
+    .{ .codepoint = 0x2DF4, .class = 230 },
+    .{ .codepoint = 0x2DF5, .class = 230 },
+    .{ .codepoint = 0x2DF6, .class = 230 },
+    .{ .codepoint = 0x2DF7, .class = 230 },
+    .{ .codepoint = 0x2DF8, .class = 230 },
+    .{ .codepoint = 0x2DF9, .class = 230 },
+    .{ .codepoint = 0x2DFA, .class = 230 },
+    .{ .codepoint = 0x2DFB, .class = 230 },
+    .{ .codepoint = 0x2DFC, .class = 230 },
+    .{ .codepoint = 0x2DFD, .class = 230 },
+    .{ .codepoint = 0x2DFE, .class = 230 },
+    .{ .codepoint = 0x2DFF, .class = 230 },
+    .{ .codepoint = 0x302A, .class = 218 },
+    .{ .codepoint = 0x302B, .class = 228 },
+    .{ .codepoint = 0x302C, .class = 232 },
+    .{ .codepoint = 0x302D, .class = 222 },
+    .{ .codepoint = 0x302E, .class = 224 },
+    .{ .codepoint = 0x302F, .class = 224 },
+    .{ .codepoint = 0x3099, .class = 8 },
+    .{ .codepoint = 0x309A, .class = 8 },
+    .{ .codepoint = 0xA66F, .class = 230 },
+    .{ .codepoint = 0xA674, .class = 230 },
+    .{ .codepoint = 0xA675, .class = 230 },
+    .{ .codepoint = 0xA676, .class = 230 },
+    .{ .codepoint = 0xA677, .class = 230 },
+    .{ .codepoint = 0xA678, .class = 230 },
+    .{ .codepoint = 0xA679, .class = 230 },
+    .{ .codepoint = 0xA67A, .class = 230 },
+    .{ .codepoint = 0xA67B, .class = 230 },
+    .{ .codepoint = 0xA67C, .class = 230 },
+    .{ .codepoint = 0xA67D, .class = 230 },
+    .{ .codepoint = 0xA69E, .class = 230 },
+    .{ .codepoint = 0xA69F, .class = 230 },
+    .{ .codepoint = 0xA6F0, .class = 230 },
+    .{ .codepoint = 0xA6F1, .class = 230 },
+    .{ .codepoint = 0xA806, .class = 9 },
+    .{ .codepoint = 0xA82C, .class = 9 },
+    .{ .codepoint = 0xA8C4, .class = 9 },
+    .{ .codepoint = 0xA8E0, .class = 230 },
+    .{ .codepoint = 0xA8E1, .class = 230 },
+    .{ .codepoint = 0xA8E2, .class = 230 },
+    .{ .codepoint = 0xA8E3, .class = 230 },
+    .{ .codepoint = 0xA8E4, .class = 230 },
+    .{ .codepoint = 0xA8E5, .class = 230 },
+    .{ .codepoint = 0xA8E6, .class = 230 },
+    .{ .codepoint = 0xA8E7, .class = 230 },
+    .{ .codepoint = 0xA8E8, .class = 230 },
+    .{ .codepoint = 0xA8E9, .class = 230 },
+    .{ .codepoint = 0xA8EA, .class = 230 },
+    .{ .codepoint = 0xA8EB, .class = 230 },
+    .{ .codepoint = 0xA8EC, .class = 230 },
+    .{ .codepoint = 0xA8ED, .class = 230 },
+    .{ .codepoint = 0xA8EE, .class = 230 },
+    .{ .codepoint = 0xA8EF, .class = 230 },
+    .{ .codepoint = 0xA8F0, .class = 230 },
+    .{ .codepoint = 0xA8F1, .class = 230 },
+    .{ .codepoint = 0xA92B, .class = 220 },
+    .{ .codepoint = 0xA92C, .class = 220 },
+    .{ .codepoint = 0xA92D, .class = 220 },
+    .{ .codepoint = 0xA953, .class = 9 },
+    .{ .codepoint = 0xA9B3, .class = 7 },
+    .{ .codepoint = 0xA9C0, .class = 9 },
+    .{ .codepoint = 0xAAB0, .class = 230 },
+    .{ .codepoint = 0xAAB2, .class = 230 },
+    .{ .codepoint = 0xAAB3, .class = 230 },
+    .{ .codepoint = 0xAAB4, .class = 220 },
+    .{ .codepoint = 0xAAB7, .class = 230 },
+    .{ .codepoint = 0xAAB8, .class = 230 },
+    .{ .codepoint = 0xAABE, .class = 230 },
+    .{ .codepoint = 0xAABF, .class = 230 },
+    .{ .codepoint = 0xAAC1, .class = 230 },
+    .{ .codepoint = 0xAAF6, .class = 9 },
+    .{ .codepoint = 0xABED, .class = 9 },
+    .{ .codepoint = 0xFB1E, .class = 26 },
+    .{ .codepoint = 0xFE20, .class = 230 },
+    .{ .codepoint = 0xFE21, .class = 230 },
+    .{ .codepoint = 0xFE22, .class = 230 },
+    .{ .codepoint = 0xFE23, .class = 230 },
+    .{ .codepoint = 0xFE24, .class = 230 },
+    .{ .codepoint = 0xFE25, .class = 230 },
+    .{ .codepoint = 0xFE26, .class = 230 },
+    .{ .codepoint = 0xFE27, .class = 220 },
+    .{ .codepoint = 0xFE28, .class = 220 },
+    .{ .codepoint = 0xFE29, .class = 220 },
+    .{ .codepoint = 0xFE2A, .class = 220 },
+    .{ .codepoint = 0xFE2B, .class = 220 },
+    .{ .codepoint = 0xFE2C, .class = 220 },
+    .{ .codepoint = 0xFE2D, .class = 220 },
+    .{ .codepoint = 0xFE2E, .class = 230 },
+    .{ .codepoint = 0xFE2F, .class = 230 },
+    .{ .codepoint = 0x101FD, .class = 220 },
+    .{ .codepoint = 0x102E0, .class = 220 },
+    .{ .codepoint = 0x10376, .class = 230 },
+    .{ .codepoint = 0x10377, .class = 230 },
+    .{ .codepoint = 0x10378, .class = 230 },
+    .{ .codepoint = 0x10379, .class = 230 },
+    .{ .codepoint = 0x1037A, .class = 230 },
+    .{ .codepoint = 0x10A0D, .class = 220 },
+    .{ .codepoint = 0x10A0F, .class = 230 },
+    .{ .codepoint = 0x10A38, .class = 230 },
+    .{ .codepoint = 0x10A39, .class = 1 },
+    .{ .codepoint = 0x10A3A, .class = 220 },
+    .{ .codepoint = 0x10A3F, .class = 9 },
+    .{ .codepoint = 0x10AE5, .class = 230 },
+    .{ .codepoint = 0x10AE6, .class = 220 },
+    .{ .codepoint = 0x10D24, .class = 230 },
+    .{ .codepoint = 0x10D25, .class = 230 },
+    .{ .codepoint = 0x10D26, .class = 230 },
+    .{ .codepoint = 0x10D27, .class = 230 },
+    .{ .codepoint = 0x10EAB, .class = 230 },
+    .{ .codepoint = 0x10EAC, .class = 230 },
+    .{ .codepoint = 0x10F46, .class = 220 },
+    .{ .codepoint = 0x10F47, .class = 220 },
+    .{ .codepoint = 0x10F48, .class = 230 },
+    .{ .codepoint = 0x10F49, .class = 230 },
+    .{ .codepoint = 0x10F4A, .class = 230 },
+    .{ .codepoint = 0x10F4B, .class = 220 },
+    .{ .codepoint = 0x10F4C, .class = 230 },
+    .{ .codepoint = 0x10F4D, .class = 220 },
+    .{ .codepoint = 0x10F4E, .class = 220 },
+    .{ .codepoint = 0x10F4F, .class = 220 },
+    .{ .codepoint = 0x10F50, .class = 220 },
+    .{ .codepoint = 0x10F82, .class = 230 },
+    .{ .codepoint = 0x10F83, .class = 220 },
+    .{ .codepoint = 0x10F84, .class = 230 },
+    .{ .codepoint = 0x10F85, .class = 220 },
+    .{ .codepoint = 0x11046, .class = 9 },
+    .{ .codepoint = 0x11070, .class = 9 },
+    .{ .codepoint = 0x1107F, .class = 9 },
+    .{ .codepoint = 0x110B9, .class = 9 },
+    .{ .codepoint = 0x110BA, .class = 7 },
+    .{ .codepoint = 0x11100, .class = 230 },
+    .{ .codepoint = 0x11101, .class = 230 },
+    .{ .codepoint = 0x11102, .class = 230 },
+    .{ .codepoint = 0x11133, .class = 9 },
+    .{ .codepoint = 0x11134, .class = 9 },
+    .{ .codepoint = 0x11173, .class = 7 },
+    .{ .codepoint = 0x111C0, .class = 9 },
+    .{ .codepoint = 0x111CA, .class = 7 },
+    .{ .codepoint = 0x11235, .class = 9 },
+    .{ .codepoint = 0x11236, .class = 7 },
+    .{ .codepoint = 0x112E9, .class = 7 },
+    .{ .codepoint = 0x112EA, .class = 9 },
+    .{ .codepoint = 0x1133B, .class = 7 },
+    .{ .codepoint = 0x1133C, .class = 7 },
+    .{ .codepoint = 0x1134D, .class = 9 },
+    .{ .codepoint = 0x11366, .class = 230 },
+    .{ .codepoint = 0x11367, .class = 230 },
+    .{ .codepoint = 0x11368, .class = 230 },
+    .{ .codepoint = 0x11369, .class = 230 },
+    .{ .codepoint = 0x1136A, .class = 230 },
+    .{ .codepoint = 0x1136B, .class = 230 },
+    .{ .codepoint = 0x1136C, .class = 230 },
+    .{ .codepoint = 0x11370, .class = 230 },
+    .{ .codepoint = 0x11371, .class = 230 },
+    .{ .codepoint = 0x11372, .class = 230 },
+    .{ .codepoint = 0x11373, .class = 230 },
+    .{ .codepoint = 0x11374, .class = 230 },
+    .{ .codepoint = 0x11442, .class = 9 },
+    .{ .codepoint = 0x11446, .class = 7 },
+    .{ .codepoint = 0x1145E, .class = 230 },
+    .{ .codepoint = 0x114C2, .class = 9 },
+    .{ .codepoint = 0x114C3, .class = 7 },
+    .{ .codepoint = 0x115BF, .class = 9 },
+    .{ .codepoint = 0x115C0, .class = 7 },
+    .{ .codepoint = 0x1163F, .class = 9 },
+    .{ .codepoint = 0x116B6, .class = 9 },
+    .{ .codepoint = 0x116B7, .class = 7 },
+    .{ .codepoint = 0x1172B, .class = 9 },
+    .{ .codepoint = 0x11839, .class = 9 },
+    .{ .codepoint = 0x1183A, .class = 7 },
+    .{ .codepoint = 0x1193D, .class = 9 },
+    .{ .codepoint = 0x1193E, .class = 9 },
+    .{ .codepoint = 0x11943, .class = 7 },
+    .{ .codepoint = 0x119E0, .class = 9 },
+    .{ .codepoint = 0x11A34, .class = 9 },
+    .{ .codepoint = 0x11A47, .class = 9 },
+    .{ .codepoint = 0x11A99, .class = 9 },
+    .{ .codepoint = 0x11C3F, .class = 9 },
+    .{ .codepoint = 0x11D42, .class = 7 },
+    .{ .codepoint = 0x11D44, .class = 9 },
+    .{ .codepoint = 0x11D45, .class = 9 },
+    .{ .codepoint = 0x11D97, .class = 9 },
+    .{ .codepoint = 0x16AF0, .class = 1 },
+    .{ .codepoint = 0x16AF1, .class = 1 },
+    .{ .codepoint = 0x16AF2, .class = 1 },
+    .{ .codepoint = 0x16AF3, .class = 1 },
+    .{ .codepoint = 0x16AF4, .class = 1 },
+    .{ .codepoint = 0x16B30, .class = 230 },
+    .{ .codepoint = 0x16B31, .class = 230 },
+    .{ .codepoint = 0x16B32, .class = 230 },
+    .{ .codepoint = 0x16B33, .class = 230 },
+    .{ .codepoint = 0x16B34, .class = 230 },
+    .{ .codepoint = 0x16B35, .class = 230 },
+    .{ .codepoint = 0x16B36, .class = 230 },
+    .{ .codepoint = 0x16FF0, .class = 6 },
+    .{ .codepoint = 0x16FF1, .class = 6 },
+    .{ .codepoint = 0x1BC9E, .class = 1 },
+    .{ .codepoint = 0x1D165, .class = 216 },
+    .{ .codepoint = 0x1D166, .class = 216 },
+    .{ .codepoint = 0x1D167, .class = 1 },
+    .{ .codepoint = 0x1D168, .class = 1 },
+    .{ .codepoint = 0x1D169, .class = 1 },
+    .{ .codepoint = 0x1D16D, .class = 226 },
+    .{ .codepoint = 0x1D16E, .class = 216 },
+    .{ .codepoint = 0x1D16F, .class = 216 },
+    .{ .codepoint = 0x1D170, .class = 216 },
+    .{ .codepoint = 0x1D171, .class = 216 },
+    .{ .codepoint = 0x1D172, .class = 216 },
+    .{ .codepoint = 0x1D17B, .class = 220 },
+    .{ .codepoint = 0x1D17C, .class = 220 },
+    .{ .codepoint = 0x1D17D, .class = 220 },
+    .{ .codepoint = 0x1D17E, .class = 220 },
+    .{ .codepoint = 0x1D17F, .class = 220 },
+    .{ .codepoint = 0x1D180, .class = 220 },
+    .{ .codepoint = 0x1D181, .class = 220 },
+    .{ .codepoint = 0x1D182, .class = 220 },
+    .{ .codepoint = 0x1D185, .class = 230 },
+    .{ .codepoint = 0x1D186, .class = 230 },
+    .{ .codepoint = 0x1D187, .class = 230 },
+    .{ .codepoint = 0x1D188, .class = 230 },
+    .{ .codepoint = 0x1D189, .class = 230 },
+    .{ .codepoint = 0x1D18A, .class = 220 },
+    .{ .codepoint = 0x1D18B, .class = 220 },
+    .{ .codepoint = 0x1D1AA, .class = 230 },
+    .{ .codepoint = 0x1D1AB, .class = 230 },
+    .{ .codepoint = 0x1D1AC, .class = 230 },
+    .{ .codepoint = 0x1D1AD, .class = 230 },
+    .{ .codepoint = 0x1D242, .class = 230 },
+    .{ .codepoint = 0x1D243, .class = 230 },
+    .{ .codepoint = 0x1D244, .class = 230 },
+    .{ .codepoint = 0x1E000, .class = 230 },
+    .{ .codepoint = 0x1E001, .class = 230 },
+    .{ .codepoint = 0x1E002, .class = 230 },
+    .{ .codepoint = 0x1E003, .class = 230 },
+    .{ .codepoint = 0x1E004, .class = 230 },
+    .{ .codepoint = 0x1E005, .class = 230 },
+    .{ .codepoint = 0x1E006, .class = 230 },
+    .{ .codepoint = 0x1E008, .class = 230 },
+    .{ .codepoint = 0x1E009, .class = 230 },
+    .{ .codepoint = 0x1E00A, .class = 230 },
+    .{ .codepoint = 0x1E00B, .class = 230 },
+    .{ .codepoint = 0x1E00C, .class = 230 },
+    .{ .codepoint = 0x1E00D, .class = 230 },
+    .{ .codepoint = 0x1E00E, .class = 230 },
+    .{ .codepoint = 0x1E00F, .class = 230 },
+    .{ .codepoint = 0x1E010, .class = 230 },
+    .{ .codepoint = 0x1E011, .class = 230 },
+    .{ .codepoint = 0x1E012, .class = 230 },
+    .{ .codepoint = 0x1E013, .class = 230 },
+    .{ .codepoint = 0x1E014, .class = 230 },
+    .{ .codepoint = 0x1E015, .class = 230 },
+    .{ .codepoint = 0x1E016, .class = 230 },
+    .{ .codepoint = 0x1E017, .class = 230 },
+    .{ .codepoint = 0x1E018, .class = 230 },
+    .{ .codepoint = 0x1E01B, .class = 230 },
+    .{ .codepoint = 0x1E01C, .class = 230 },
+    .{ .codepoint = 0x1E01D, .class = 230 },
+    .{ .codepoint = 0x1E01E, .class = 230 },
+    .{ .codepoint = 0x1E01F, .class = 230 },
+    .{ .codepoint = 0x1E020, .class = 230 },
+    .{ .codepoint = 0x1E021, .class = 230 },
+    .{ .codepoint = 0x1E023, .class = 230 },
+    .{ .codepoint = 0x1E024, .class = 230 },
+    .{ .codepoint = 0x1E026, .class = 230 },
+    .{ .codepoint = 0x1E027, .class = 230 },
+    .{ .codepoint = 0x1E028, .class = 230 },
+    .{ .codepoint = 0x1E029, .class = 230 },
+    .{ .codepoint = 0x1E02A, .class = 230 },
+    .{ .codepoint = 0x1E130, .class = 230 },
+    .{ .codepoint = 0x1E131, .class = 230 },
+    .{ .codepoint = 0x1E132, .class = 230 },
+    .{ .codepoint = 0x1E133, .class = 230 },
+    .{ .codepoint = 0x1E134, .class = 230 },
+    .{ .codepoint = 0x1E135, .class = 230 },
+    .{ .codepoint = 0x1E136, .class = 230 },
+    .{ .codepoint = 0x1E2AE, .class = 230 },
+    .{ .codepoint = 0x1E2EC, .class = 230 },
+    .{ .codepoint = 0x1E2ED, .class = 230 },
+    .{ .codepoint = 0x1E2EE, .class = 230 },
+    .{ .codepoint = 0x1E2EF, .class = 230 },
+    .{ .codepoint = 0x1E8D0, .class = 220 },
+    .{ .codepoint = 0x1E8D1, .class = 220 },
+    .{ .codepoint = 0x1E8D2, .class = 220 },
+    .{ .codepoint = 0x1E8D3, .class = 220 },
+    .{ .codepoint = 0x1E8D4, .class = 220 },
+    .{ .codepoint = 0x1E8D5, .class = 220 },
+    .{ .codepoint = 0x1E8D6, .class = 220 },
+    .{ .codepoint = 0x1E944, .class = 230 },
+    .{ .codepoint = 0x1E945, .class = 230 },
+    .{ .codepoint = 0x1E946, .class = 230 },
+    .{ .codepoint = 0x1E947, .class = 230 },
+    .{ .codepoint = 0x1E948, .class = 230 },
+    .{ .codepoint = 0x1E949, .class = 230 },
+    .{ .codepoint = 0x1E94A, .class = 7 },
+};
+
+pub const decompositions = [_]Decomposition{
+    .{ .codepoint = 0x00C0, .mapping = &[_]u21{ 0x0041, 0x0300 } },
+    .{ .codepoint = 0x00C1, .mapping = &[_]u21{ 0x0041, 0x0301 } },
+    .{ .codepoint = 0x00C2, .mapping = &[_]u21{ 0x0041, 0x0302 } },
+    .{ .codepoint = 0x00C3, .mapping = &[_]u21{ 0x0041, 0x0303 } },
+    .{ .codepoint = 0x00C4, .mapping = &[_]u21{ 0x0041, 0x0308 } },
+    .{ .codepoint = 0x00C5, .mapping = &[_]u21{ 0x0041, 0x030A } },
+    .{ .codepoint = 0x00C7, .mapping = &[_]u21{ 0x0043, 0x0327 } },
+    .{ .codepoint = 0x00C8, .mapping = &[_]u21{ 0x0045, 0x0300 } },
+    .{ .codepoint = 0x00C9, .mapping = &[_]u21{ 0x0045, 0x0301 } },
+    .{ .codepoint = 0x00CA, .mapping = &[_]u21{ 0x0045, 0x0302 } },
+    .{ .codepoint = 0x00CB, .mapping = &[_]u21{ 0x0045, 0x0308 } },
+    .{ .codepoint = 0x00CC, .mapping = &[_]u21{ 0x0049, 0x0300 } },
+    .{ .codepoint = 0x00CD, .mapping = &[_]u21{ 0x0049, 0x0301 } },
+    .{ .codepoint = 0x00CE, .mapping = &[_]u21{ 0x0049, 0x0302 } },
+    .{ .codepoint = 0x00CF, .mapping = &[_]u21{ 0x0049, 0x0308 } },
+    .{ .codepoint = 0x00D1, .mapping = &[_]u21{ 0x004E, 0x0303 } },
+    .{ .codepoint = 0x00D2, .mapping = &[_]u21{ 0x004F, 0x0300 } },
+    .{ .codepoint = 0x00D3, .mapping = &[_]u21{ 0x004F, 0x0301 } },
+    .{ .codepoint = 0x00D4, .mapping = &[_]u21{ 0x004F, 0x0302 } },
+    .{ .codepoint = 0x00D5, .mapping = &[_]u21{ 0x004F, 0x0303 } },
+    .{ .codepoint = 0x00D6, .mapping = &[_]u21{ 0x004F, 0x0308 } },
+    .{ .codepoint = 0x00D9, .mapping = &[_]u21{ 0x0055, 0x0300 } },
+    .{ .codepoint = 0x00DA, .mapping = &[_]u21{ 0x0055, 0x0301 } },
+    .{ .codepoint = 0x00DB, .mapping = &[_]u21{ 0x0055, 0x0302 } },
+    .{ .codepoint = 0x00DC, .mapping = &[_]u21{ 0x0055, 0x0308 } },
+    .{ .codepoint = 0x00DD, .mapping = &[_]u21{ 0x0059, 0x0301 } },
+    .{ .codepoint = 0x00E0, .mapping = &[_]u21{ 0x0061, 0x0300 } },
+    .{ .codepoint = 0x00E1, .mapping = &[_]u21{ 0x0061, 0x0301 } },
+    .{ .codepoint = 0x00E2, .mapping = &[_]u21{ 0x0061, 0x0302 } },
+    .{ .codepoint = 0x00E3, .mapping = &[_]u21{ 0x0061, 0x0303 } },
+    .{ .codepoint = 0x00E4, .mapping = &[_]u21{ 0x0061, 0x0308 } },
+    .{ .codepoint = 0x00E5, .mapping = &[_]u21{ 0x0061, 0x030A } },
+    .{ .codepoint = 0x00E7, .mapping = &[_]u21{ 0x0063, 0x0327 } },
+    .{ .codepoint = 0x00E8, .mapping = &[_]u21{ 0x0065, 0x0300 } },
+    .{ .codepoint = 0x00E9, .mapping = &[_]u21{ 0x0065, 0x0301 } },
+    .{ .codepoint = 0x00EA, .mapping = &[_]u21{ 0x0065, 0x0302 } },
+    .{ .codepoint = 0x00EB, .mapping = &[_]u21{ 0x0065, 0x0308 } },
+    .{ .codepoint = 0x00EC, .mapping = &[_]u21{ 0x0069, 0x0300 } },
+    .{ .codepoint = 0x00ED, .mapping = &[_]u21{ 0x0069, 0x0301 } },
+    .{ .codepoint = 0x00EE, .mapping = &[_]u21{ 0x0069, 0x0302 } },
+    .{ .codepoint = 0x00EF, .mapping = &[_]u21{ 0x0069, 0x0308 } },
+    .{ .codepoint = 0x00F1, .mapping = &[_]u21{ 0x006E, 0x0303 } },
+    .{ .codepoint = 0x00F2, .mapping = &[_]u21{ 0x006F, 0x0300 } },
+    .{ .codepoint = 0x00F3, .mapping = &[_]u21{ 0x006F, 0x0301 } },
+    .{ .codepoint = 0x00F4, .mapping = &[_]u21{ 0x006F, 0x0302 } },
+    .{ .codepoint = 0x00F5, .mapping = &[_]u21{ 0x006F, 0x0303 } },
+    .{ .codepoint = 0x00F6, .mapping = &[_]u21{ 0x006F, 0x0308 } },
+    .{ .codepoint = 0x00F9, .mapping = &[_]u21{ 0x0075, 0x0300 } },
+    .{ .codepoint = 0x00FA, .mapping = &[_]u21{ 0x0075, 0x0301 } },
+    .{ .codepoint = 0x00FB, .mapping = &[_]u21{ 0x0075, 0x0302 } },
+    .{ .codepoint = 0x00FC, .mapping = &[_]u21{ 0x0075, 0x0308 } },
+    .{ .codepoint = 0x00FD, .mapping = &[_]u21{ 0x0079, 0x0301 } },
+    .{ .codepoint = 0x00FF, .mapping = &[_]u21{ 0x0079, 0x0308 } },
+    .{ .codepoint = 0x0100, .mapping = &[_]u21{ 0x0041, 0x0304 } },
+    .{ .codepoint = 0x0101, .mapping = &[_]u21{ 0x0061, 0x0304 } },
+    .{ .codepoint = 0x0102, .mapping = &[_]u21{ 0x0041, 0x0306 } },
+    .{ .codepoint = 0x0103, .mapping = &[_]u21{ 0x0061, 0x0306 } },
+    .{ .codepoint = 0x0104, .mapping = &[_]u21{ 0x0041, 0x0328 } },
+    .{ .codepoint = 0x0105, .mapping = &[_]u21{ 0x0061, 0x0328 } },
+    .{ .codepoint = 0x0106, .mapping = &[_]u21{ 0x0043, 0x0301 } },
+    .{ .codepoint = 0x0107, .mapping = &[_]u21{ 0x0063, 0x0301 } },
+    .{ .codepoint = 0x0108, .mapping = &[_]u21{ 0x0043, 0x0302 } },
+    .{ .codepoint = 0x0109, .mapping = &[_]u21{ 0x0063, 0x0302 } },
+    .{ .codepoint = 0x010A, .mapping = &[_]u21{ 0x0043, 0x0307 } },
+    .{ .codepoint = 0x010B, .mapping = &[_]u21{ 0x0063, 0x0307 } },
+    .{ .codepoint = 0x010C, .mapping = &[_]u21{ 0x0043, 0x030C } },
+    .{ .codepoint = 0x010D, .mapping = &[_]u21{ 0x0063, 0x030C } },
+    .{ .codepoint = 0x010E, .mapping = &[_]u21{ 0x0044, 0x030C } },
+    .{ .codepoint = 0x010F, .mapping = &[_]u21{ 0x0064, 0x030C } },
+    .{ .codepoint = 0x0112, .mapping = &[_]u21{ 0x0045, 0x0304 } },
+    .{ .codepoint = 0x0113, .mapping = &[_]u21{ 0x0065, 0x0304 } },
+    .{ .codepoint = 0x0114, .mapping = &[_]u21{ 0x0045, 0x0306 } },
+    .{ .codepoint = 0x0115, .mapping = &[_]u21{ 0x0065, 0x0306 } },
+    .{ .codepoint = 0x0116, .mapping = &[_]u21{ 0x0045, 0x0307 } },
+    .{ .codepoint = 0x0117, .mapping = &[_]u21{ 0x0065, 0x0307 } },
+    .{ .codepoint = 0x0118, .mapping = &[_]u21{ 0x0045, 0x0328 } },
+    .{ .codepoint = 0x0119, .mapping = &[_]u21{ 0x0065, 0x0328 } },
+    .{ .codepoint = 0x011A, .mapping = &[_]u21{ 0x0045, 0x030C } },
+    .{ .codepoint = 0x011B, .mapping = &[_]u21{ 0x0065, 0x030C } },
+    .{ .codepoint = 0x011C, .mapping = &[_]u21{ 0x0047, 0x0302 } },
+    .{ .codepoint = 0x011D, .mapping = &[_]u21{ 0x0067, 0x0302 } },
+    .{ .codepoint = 0x011E, .mapping = &[_]u21{ 0x0047, 0x0306 } },
+    .{ .codepoint = 0x011F, .mapping = &[_]u21{ 0x0067, 0x0306 } },
+    .{ .codepoint = 0x0120, .mapping = &[_]u21{ 0x0047, 0x0307 } },
+    .{ .codepoint = 0x0121, .mapping = &[_]u21{ 0x0067, 0x0307 } },
+    .{ .codepoint = 0x0122, .mapping = &[_]u21{ 0x0047, 0x0327 } },
+    .{ .codepoint = 0x0123, .mapping = &[_]u21{ 0x0067, 0x0327 } },
+    .{ .codepoint = 0x0124, .mapping = &[_]u21{ 0x0048, 0x0302 } },
+    .{ .codepoint = 0x0125, .mapping = &[_]u21{ 0x0068, 0x0302 } },
+    .{ .codepoint = 0x0128, .mapping = &[_]u21{ 0x0049, 0x0303 } },
+    .{ .codepoint = 0x0129, .mapping = &[_]u21{ 0x0069, 0x0303 } },
+    .{ .codepoint = 0x012A, .mapping = &[_]u21{ 0x0049, 0x0304 } },
+    .{ .codepoint = 0x012B, .mapping = &[_]u21{ 0x0069, 0x0304 } },
+    .{ .codepoint = 0x012C, .mapping = &[_]u21{ 0x0049, 0x0306 } },
+    .{ .codepoint = 0x012D, .mapping = &[_]u21{ 0x0069, 0x0306 } },
+    .{ .codepoint = 0x012E, .mapping = &[_]u21{ 0x0049, 0x0328 } },
+    .{ .codepoint = 0x012F, .mapping = &[_]u21{ 0x0069, 0x0328 } },
+    .{ .codepoint = 0x0130, .mapping = &[_]u21{ 0x0049, 0x0307 } },
+    .{ .codepoint = 0x0134, .mapping = &[_]u21{ 0x004A, 0x0302 } },
+    .{ .codepoint = 0x0135, .mapping = &[_]u21{ 0x006A, 0x0302 } },
+    .{ .codepoint = 0x0136, .mapping = &[_]u21{ 0x004B, 0x0327 } },
+    .{ .codepoint = 0x0137, .mapping = &[_]u21{ 0x006B, 0x0327 } },
+    .{ .codepoint = 0x0139, .mapping = &[_]u21{ 0x004C, 0x0301 } },
+    .{ .codepoint = 0x013A, .mapping = &[_]u21{ 0x006C, 0x0301 } },
+    .{ .codepoint = 0x013B, .mapping = &[_]u21{ 0x004C, 0x0327 } },
+    .{ .codepoint = 0x013C, .mapping = &[_]u21{ 0x006C, 0x0327 } },
+    .{ .codepoint = 0x013D, .mapping = &[_]u21{ 0x004C, 0x030C } },
+    .{ .codepoint = 0x013E, .mapping = &[_]u21{ 0x006C, 0x030C } },
+    .{ .codepoint = 0x0143, .mapping = &[_]u21{ 0x004E, 0x0301 } },
+    .{ .codepoint = 0x0144, .mapping = &[_]u21{ 0x006E, 0x0301 } },
+    .{ .codepoint = 0x0145, .mapping = &[_]u21{ 0x004E, 0x0327 } },
+    .{ .codepoint = 0x0146, .mapping = &[_]u21{ 0x006E, 0x0327 } },
+    .{ .codepoint = 0x0147, .mapping = &[_]u21{ 0x004E, 0x030C } },
+    .{ .codepoint = 0x0148, .mapping = &[_]u21{ 0x006E, 0x030C } },
+    .{ .codepoint = 0x014C, .mapping = &[_]u21{ 0x004F, 0x0304 } },
+    .{ .codepoint = 0x014D, .mapping = &[_]u21{ 0x006F, 0x0304 } },
+    .{ .codepoint = 0x014E, .mapping = &[_]u21{ 0x004F, 0x0306 } },
+    .{ .codepoint = 0x014F, .mapping = &[_]u21{ 0x006F, 0x0306 } },
+    .{ .codepoint = 0x0150, .mapping = &[_]u21{ 0x004F, 0x030B } },
+    .{ .codepoint = 0x0151, .mapping = &[_]u21{ 0x006F, 0x030B } },
+    .{ .codepoint = 0x0154, .mapping = &[_]u21{ 0x0052, 0x0301 } },
+    .{ .codepoint = 0x0155, .mapping = &[_]u21{ 0x0072, 0x0301 } },
+    .{ .codepoint = 0x0156, .mapping = &[_]u21{ 0x0052, 0x0327 } },
+    .{ .codepoint = 0x0157, .mapping = &[_]u21{ 0x0072, 0x0327 } },
+    .{ .codepoint = 0x0158, .mapping = &[_]u21{ 0x0052, 0x030C } },
+    .{ .codepoint = 0x0159, .mapping = &[_]u21{ 0x0072, 0x030C } },
+    .{ .codepoint = 0x015A, .mapping = &[_]u21{ 0x0053, 0x0301 } },
+    .{ .codepoint = 0x015B, .mapping = &[_]u21{ 0x0073, 0x0301 } },
+    .{ .codepoint = 0x015C, .mapping = &[_]u21{ 0x0053, 0x0302 } },
+    .{ .codepoint = 0x015D, .mapping = &[_]u21{ 0x0073, 0x0302 } },
+    .{ .codepoint = 0x015E, .mapping = &[_]u21{ 0x0053, 0x0327 } },
+    .{ .codepoint = 0x015F, .mapping = &[_]u21{ 0x0073, 0x0327 } },
+    .{ .codepoint = 0x0160, .mapping = &[_]u21{ 0x0053, 0x030C } },
+    .{ .codepoint = 0x0161, .mapping = &[_]u21{ 0x0073, 0x030C } },
+    .{ .codepoint = 0x0162, .mapping = &[_]u21{ 0x0054, 0x0327 } },
+    .{ .codepoint = 0x0163, .mapping = &[_]u21{ 0x0074, 0x0327 } },
+    .{ .codepoint = 0x0164, .mapping = &[_]u21{ 0x0054, 0x030C } },
+    .{ .codepoint = 0x0165, .mapping = &[_]u21{ 0x0074, 0x030C } },
+    .{ .codepoint = 0x0168, .mapping = &[_]u21{ 0x0055, 0x0303 } },
+    .{ .codepoint = 0x0169, .mapping = &[_]u21{ 0x0075, 0x0303 } },
+    .{ .codepoint = 0x016A, .mapping = &[_]u21{ 0x0055, 0x0304 } },
+    .{ .codepoint = 0x016B, .mapping = &[_]u21{ 0x0075, 0x0304 } },
+    .{ .codepoint = 0x016C, .mapping = &[_]u21{ 0x0055, 0x0306 } },
+    .{ .codepoint = 0x016D, .mapping = &[_]u21{ 0x0075, 0x0306 } },
+    .{ .codepoint = 0x016E, .mapping = &[_]u21{ 0x0055, 0x030A } },
+    .{ .codepoint = 0x016F, .mapping = &[_]u21{ 0x0075, 0x030A } },
+    .{ .codepoint = 0x0170, .mapping = &[_]u21{ 0x0055, 0x030B } },
+    .{ .codepoint = 0x0171, .mapping = &[_]u21{ 0x0075, 0x030B } },
+    .{ .codepoint = 0x0172, .mapping = &[_]u21{ 0x0055, 0x0328 } },
+    .{ .codepoint = 0x0173, .mapping = &[_]u21{ 0x0075, 0x0328 } },
+    .{ .codepoint = 0x0174, .mapping = &[_]u21{ 0x0057, 0x0302 } },
+    .{ .codepoint = 0x0175, .mapping = &[_]u21{ 0x0077, 0x0302 } },
+    .{ .codepoint = 0x0176, .mapping = &[_]u21{ 0x0059, 0x0302 } },
+    .{ .codepoint = 0x0177, .mapping = &[_]u21{ 0x0079, 0x0302 } },
+    .{ .codepoint = 0x0178, .mapping = &[_]u21{ 0x0059, 0x0308 } },
+    .{ .codepoint = 0x0179, .mapping = &[_]u21{ 0x005A, 0x0301 } },
+    .{ .codepoint = 0x017A, .mapping = &[_]u21{ 0x007A, 0x0301 } },
+    .{ .codepoint = 0x017B, .mapping = &[_]u21{ 0x005A, 0x0307 } },
+    .{ .codepoint = 0x017C, .mapping = &[_]u21{ 0x007A, 0x0307 } },
+    .{ .codepoint = 0x017D, .mapping = &[_]u21{ 0x005A, 0x030C } },
+    .{ .codepoint = 0x017E, .mapping = &[_]u21{ 0x007A, 0x030C } },
+    .{ .codepoint = 0x01A0, .mapping = &[_]u21{ 0x004F, 0x031B } },
+    .{ .codepoint = 0x01A1, .mapping = &[_]u21{ 0x006F, 0x031B } },
+    .{ .codepoint = 0x01AF, .mapping = &[_]u21{ 0x0055, 0x031B } },
+    .{ .codepoint = 0x01B0, .mapping = &[_]u21{ 0x0075, 0x031B } },
+    .{ .codepoint = 0x01CD, .mapping = &[_]u21{ 0x0041, 0x030C } },
+    .{ .codepoint = 0x01CE, .mapping = &[_]u21{ 0x0061, 0x030C } },
+    .{ .codepoint = 0x01CF, .mapping = &[_]u21{ 0x0049, 0x030C } },
+    .{ .codepoint = 0x01D0, .mapping = &[_]u21{ 0x0069, 0x030C } },
+    .{ .codepoint = 0x01D1, .mapping = &[_]u21{ 0x004F, 0x030C } },
+    .{ .codepoint = 0x01D2, .mapping = &[_]u21{ 0x006F, 0x030C } },
+    .{ .codepoint = 0x01D3, .mapping = &[_]u21{ 0x0055, 0x030C } },
+    .{ .codepoint = 0x01D4, .mapping = &[_]u21{ 0x0075, 0x030C } },
+    .{ .codepoint = 0x01D5, .mapping = &[_]u21{ 0x00DC, 0x0304 } },
+    .{ .codepoint = 0x01D6, .mapping = &[_]u21{ 0x00FC, 0x0304 } },
+    .{ .codepoint = 0x01D7, .mapping = &[_]u21{ 0x00DC, 0x0301 } },
+    .{ .codepoint = 0x01D8, .mapping = &[_]u21{ 0x00FC, 0x0301 } },
+    .{ .codepoint = 0x01D9, .mapping = &[_]u21{ 0x00DC, 0x030C } },
+    .{ .codepoint = 0x01DA, .mapping = &[_]u21{ 0x00FC, 0x030C } },
+    .{ .codepoint = 0x01DB, .mapping = &[_]u21{ 0x00DC, 0x0300 } },
+    .{ .codepoint = 0x01DC, .mapping = &[_]u21{ 0x00FC, 0x0300 } },
+    .{ .codepoint = 0x01DE, .mapping = &[_]u21{ 0x00C4, 0x0304 } },
+    .{ .codepoint = 0x01DF, .mapping = &[_]u21{ 0x00E4, 0x0304 } },
+    .{ .codepoint = 0x01E0, .mapping = &[_]u21{ 0x0226, 0x0304 } },
+    .{ .codepoint = 0x01E1, .mapping = &[_]u21{ 0x0227, 0x0304 } },
+    .{ .codepoint = 0x01E2, .mapping = &[_]u21{ 0x00C6, 0x0304 } },
+    .{ .codepoint = 0x01E3, .mapping = &[_]u21{ 0x00E6, 0x0304 } },
+    .{ .codepoint = 0x01E6, .mapping = &[_]u21{ 0x0047, 0x030C } },
+    .{ .codepoint = 0x01E7, .mapping = &[_]u21{ 0x0067, 0x030C } },
+    .{ .codepoint = 0x01E8, .mapping = &[_]u21{ 0x004B, 0x030C } },
+    .{ .codepoint = 0x01E9, .mapping = &[_]u21{ 0x006B, 0x030C } },
+    .{ .codepoint = 0x01EA, .mapping = &[_]u21{ 0x004F, 0x0328 } },
+    .{ .codepoint = 0x01EB, .mapping = &[_]u21{ 0x006F, 0x0328 } },
+    .{ .codepoint = 0x01EC, .mapping = &[_]u21{ 0x01EA, 0x0304 } },
+    .{ .codepoint = 0x01ED, .mapping = &[_]u21{ 0x01EB, 0x0304 } },
+    .{ .codepoint = 0x01EE, .mapping = &[_]u21{ 0x01B7, 0x030C } },
+    .{ .codepoint = 0x01EF, .mapping = &[_]u21{ 0x0292, 0x030C } },
+    .{ .codepoint = 0x01F0, .mapping = &[_]u21{ 0x006A, 0x030C } },
+    .{ .codepoint = 0x01F4, .mapping = &[_]u21{ 0x0047, 0x0301 } },
+    .{ .codepoint = 0x01F5, .mapping = &[_]u21{ 0x0067, 0x0301 } },
+    .{ .codepoint = 0x01F8, .mapping = &[_]u21{ 0x004E, 0x0300 } },
+    .{ .codepoint = 0x01F9, .mapping = &[_]u21{ 0x006E, 0x0300 } },
+    .{ .codepoint = 0x01FA, .mapping = &[_]u21{ 0x00C5, 0x0301 } },
+    .{ .codepoint = 0x01FB, .mapping = &[_]u21{ 0x00E5, 0x0301 } },
+    .{ .codepoint = 0x01FC, .mapping = &[_]u21{ 0x00C6, 0x0301 } },
+    .{ .codepoint = 0x01FD, .mapping = &[_]u21{ 0x00E6, 0x0301 } },
+    .{ .codepoint = 0x01FE, .mapping = &[_]u21{ 0x00D8, 0x0301 } },
+    .{ .codepoint = 0x01FF, .mapping = &[_]u21{ 0x00F8, 0x0301 } },
+    .{ .codepoint = 0x0200, .mapping = &[_]u21{ 0x0041, 0x030F } },
+    .{ .codepoint = 0x0201, .mapping = &[_]u21{ 0x0061, 0x030F } },
+    .{ .codepoint = 0x0202, .mapping = &[_]u21{ 0x0041, 0x0311 } },
+    .{ .codepoint = 0x0203, .mapping = &[_]u21{ 0x0061, 0x0311 } },
+    .{ .codepoint = 0x0204, .mapping = &[_]u21{ 0x0045, 0x030F } },
+    .{ .codepoint = 0x0205, .mapping = &[_]u21{ 0x0065, 0x030F } },
+    .{ .codepoint = 0x0206, .mapping = &[_]u21{ 0x0045, 0x0311 } },
+    .{ .codepoint = 0x0207, .mapping = &[_]u21{ 0x0065, 0x0311 } },
+    .{ .codepoint = 0x0208, .mapping = &[_]u21{ 0x0049, 0x030F } },
+    .{ .codepoint = 0x0209, .mapping = &[_]u21{ 0x0069, 0x030F } },
+    .{ .codepoint = 0x020A, .mapping = &[_]u21{ 0x0049, 0x0311 } },
+    .{ .codepoint = 0x020B, .mapping = &[_]u21{ 0x0069, 0x0311 } },
+    .{ .codepoint = 0x020C, .mapping = &[_]u21{ 0x004F, 0x030F } },
+    .{ .codepoint = 0x020D, .mapping = &[_]u21{ 0x006F, 0x030F } },
+    .{ .codepoint = 0x020E, .mapping = &[_]u21{ 0x004F, 0x0311 } },
+    .{ .codepoint = 0x020F, .mapping = &[_]u21{ 0x006F, 0x0311 } },
+    .{ .codepoint = 0x0210, .mapping = &[_]u21{ 0x0052, 0x030F } },
+    .{ .codepoint = 0x0211, .mapping = &[_]u21{ 0x0072, 0x030F } },
+    .{ .codepoint = 0x0212, .mapping = &[_]u21{ 0x0052, 0x0311 } },
+    .{ .codepoint = 0x0213, .mapping = &[_]u21{ 0x0072, 0x0311 } },
+    .{ .codepoint = 0x0214, .mapping = &[_]u21{ 0x0055, 0x030F } },
+    .{ .codepoint = 0x0215, .mapping = &[_]u21{ 0x0075, 0x030F } },
+    .{ .codepoint = 0x0216, .mapping = &[_]u21{ 0x0055, 0x0311 } },
+    .{ .codepoint = 0x0217, .mapping = &[_]u21{ 0x0075, 0x0311 } },
+    .{ .codepoint = 0x0218, .mapping = &[_]u21{ 0x0053, 0x0326 } },
+    .{ .codepoint = 0x0219, .mapping = &[_]u21{ 0x0073, 0x0326 } },
+    .{ .codepoint = 0x021A, .mapping = &[_]u21{ 0x0054, 0x0326 } },
+    .{ .codepoint = 0x021B, .mapping = &[_]u21{ 0x0074, 0x0326 } },
+    .{ .codepoint = 0x021E, .mapping = &[_]u21{ 0x0048, 0x030C } },
+    .{ .codepoint = 0x021F, .mapping = &[_]u21{ 0x0068, 0x030C } },
+    .{ .codepoint = 0x0226, .mapping = &[_]u21{ 0x0041, 0x0307 } },
+    .{ .codepoint = 0x0227, .mapping = &[_]u21{ 0x0061, 0x0307 } },
+    .{ .codepoint = 0x0228, .mapping = &[_]u21{ 0x0045, 0x0327 } },
+    .{ .codepoint = 0x0229, .mapping = &[_]u21{ 0x0065, 0x0327 } },
+    .{ .codepoint = 0x022A, .mapping = &[_]u21{ 0x00D6, 0x0304 } },
+    .{ .codepoint = 0x022B, .mapping = &[_]u21{ 0x00F6, 0x0304 } },
+    .{ .codepoint = 0x022C, .mapping = &[_]u21{ 0x00D5, 0x0304 } },
+    .{ .codepoint = 0x022D, .mapping = &[_]u21{ 0x00F5, 0x0304 } },
+    .{ .codepoint = 0x022E, .mapping = &[_]u21{ 0x004F, 0x0307 } },
+    .{ .codepoint = 0x022F, .mapping = &[_]u21{ 0x006F, 0x0307 } },
+    .{ .codepoint = 0x0230, .mapping = &[_]u21{ 0x022E, 0x0304 } },
+    .{ .codepoint = 0x0231, .mapping = &[_]u21{ 0x022F, 0x0304 } },
+    .{ .codepoint = 0x0232, .mapping = &[_]u21{ 0x0059, 0x0304 } },
+    .{ .codepoint = 0x0233, .mapping = &[_]u21{ 0x0079, 0x0304 } },
+    .{ .codepoint = 0x0340, .mapping = &[_]u21{0x0300} },
+    .{ .codepoint = 0x0341, .mapping = &[_]u21{0x0301} },
+    .{ .codepoint = 0x0343, .mapping = &[_]u21{0x0313} },
+    .{ .codepoint = 0x0344, .mapping = &[_]u21{ 0x0308, 0x0301 } },
+    .{ .codepoint = 0x0374, .mapping = &[_]u21{0x02B9} },
+    .{ .codepoint = 0x037E, .mapping = &[_]u21{0x003B} },
+    .{ .codepoint = 0x0385, .mapping = &[_]u21{ 0x00A8, 0x0301 } },
+    .{ .codepoint = 0x0386, .mapping = &[_]u21{ 0x0391, 0x0301 } },
+    .{ .codepoint = 0x0387, .mapping = &[_]u21{0x00B7} },
+    .{ .codepoint = 0x0388, .mapping = &[_]u21{ 0x0395, 0x0301 } },
+    .{ .codepoint = 0x0389, .mapping = &[_]u21{ 0x0397, 0x0301 } },
+    .{ .codepoint = 0x038A, .mapping = &[_]u21{ 0x0399, 0x0301 } },
+    .{ .codepoint = 0x038C, .mapping = &[_]u21{ 0x039F, 0x0301 } },
+    .{ .codepoint = 0x038E, .mapping = &[_]u21{ 0x03A5, 0x0301 } },
+    .{ .codepoint = 0x038F, .mapping = &[_]u21{ 0x03A9, 0x0301 } },
+    .{ .codepoint = 0x0390, .mapping = &[_]u21{ 0x03CA, 0x0301 } },
+    .{ .codepoint = 0x03AA, .mapping = &[_]u21{ 0x0399, 0x0308 } },
+    .{ .codepoint = 0x03AB, .mapping = &[_]u21{ 0x03A5, 0x0308 } },
+    .{ .codepoint = 0x03AC, .mapping = &[_]u21{ 0x03B1, 0x0301 } },
+    .{ .codepoint = 0x03AD, .mapping = &[_]u21{ 0x03B5, 0x0301 } },
+    .{ .codepoint = 0x03AE, .mapping = &[_]u21{ 0x03B7, 0x0301 } },
+    .{ .codepoint = 0x03AF, .mapping = &[_]u21{ 0x03B9, 0x0301 } },
+    .{ .codepoint = 0x03B0, .mapping = &[_]u21{ 0x03CB, 0x0301 } },
+    .{ .codepoint = 0x03CA, .mapping = &[_]u21{ 0x03B9, 0x0308 } },
+    .{ .codepoint = 0x03CB, .mapping = &[_]u21{ 0x03C5, 0x0308 } },
+    .{ .codepoint = 0x03CC, .mapping = &[_]u21{ 0x03BF, 0x0301 } },
+    .{ .codepoint = 0x03CD, .mapping = &[_]u21{ 0x03C5, 0x0301 } },
+    .{ .codepoint = 0x03CE, .mapping = &[_]u21{ 0x03C9, 0x0301 } },
+    .{ .codepoint = 0x03D3, .mapping = &[_]u21{ 0x03D2, 0x0301 } },
+    .{ .codepoint = 0x03D4, .mapping = &[_]u21{ 0x03D2, 0x0308 } },
+    .{ .codepoint = 0x0400, .mapping = &[_]u21{ 0x0415, 0x0300 } },
+    .{ .codepoint = 0x0401, .mapping = &[_]u21{ 0x0415, 0x0308 } },
+    .{ .codepoint = 0x0403, .mapping = &[_]u21{ 0x0413, 0x0301 } },
+    .{ .codepoint = 0x0407, .mapping = &[_]u21{ 0x0406, 0x0308 } },
+    .{ .codepoint = 0x040C, .mapping = &[_]u21{ 0x041A, 0x0301 } },
+    .{ .codepoint = 0x040D, .mapping = &[_]u21{ 0x0418, 0x0300 } },
+    .{ .codepoint = 0x040E, .mapping = &[_]u21{ 0x0423, 0x0306 } },
+    .{ .codepoint = 0x0419, .mapping = &[_]u21{ 0x0418, 0x0306 } },
+    .{ .codepoint = 0x0439, .mapping = &[_]u21{ 0x0438, 0x0306 } },
+    .{ .codepoint = 0x0450, .mapping = &[_]u21{ 0x0435, 0x0300 } },
+    .{ .codepoint = 0x0451, .mapping = &[_]u21{ 0x0435, 0x0308 } },
+    .{ .codepoint = 0x0453, .mapping = &[_]u21{ 0x0433, 0x0301 } },
+    .{ .codepoint = 0x0457, .mapping = &[_]u21{ 0x0456, 0x0308 } },
+    .{ .codepoint = 0x045C, .mapping = &[_]u21{ 0x043A, 0x0301 } },
+    .{ .codepoint = 0x045D, .mapping = &[_]u21{ 0x0438, 0x0300 } },
+    .{ .codepoint = 0x045E, .mapping = &[_]u21{ 0x0443, 0x0306 } },
+    .{ .codepoint = 0x0476, .mapping = &[_]u21{ 0x0474, 0x030F } },
+    .{ .codepoint = 0x0477, .mapping = &[_]u21{ 0x0475, 0x030F } },
+    .{ .codepoint = 0x04C1, .mapping = &[_]u21{ 0x0416, 0x0306 } },
+    .{ .codepoint = 0x04C2, .mapping = &[_]u21{ 0x0436, 0x0306 } },
+    .{ .codepoint = 0x04D0, .mapping = &[_]u21{ 0x0410, 0x0306 } },
+    .{ .codepoint = 0x04D1, .mapping = &[_]u21{ 0x0430, 0x0306 } },
+    .{ .codepoint = 0x04D2, .mapping = &[_]u21{ 0x0410, 0x0308 } },
+    .{ .codepoint = 0x04D3, .mapping = &[_]u21{ 0x0430, 0x0308 } },
+    .{ .codepoint = 0x04D6, .mapping = &[_]u21{ 0x0415, 0x0306 } },
+    .{ .codepoint = 0x04D7, .mapping = &[_]u21{ 0x0435, 0x0306 } },
+    .{ .codepoint = 0x04DA, .mapping = &[_]u21{ 0x04D8, 0x0308 } },
+    .{ .codepoint = 0x04DB, .mapping = &[_]u21{ 0x04D9, 0x0308 } },
+    .{ .codepoint = 0x04DC, .mapping = &[_]u21{ 0x0416, 0x0308 } },
+    .{ .codepoint = 0x04DD, .mapping = &[_]u21{ 0x0436, 0x0308 } },
+    .{ .codepoint = 0x04DE, .mapping = &[_]u21{ 0x0417, 0x0308 } },
+    .{ .codepoint = 0x04DF, .mapping = &[_]u21{ 0x0437, 0x0308 } },
+    .{ .codepoint = 0x04E2, .mapping = &[_]u21{ 0x0418, 0x0304 } },
+    .{ .codepoint = 0x04E3, .mapping = &[_]u21{ 0x0438, 0x0304 } },
+    .{ .codepoint = 0x04E4, .mapping = &[_]u21{ 0x0418, 0x0308 } },
+    .{ .codepoint = 0x04E5, .mapping = &[_]u21{ 0x0438, 0x0308 } },
+    .{ .codepoint = 0x04E6, .mapping = &[_]u21{ 0x041E, 0x0308 } },
+    .{ .codepoint = 0x04E7, .mapping = &[_]u21{ 0x043E, 0x0308 } },
+    .{ .codepoint = 0x04EA, .mapping = &[_]u21{ 0x04E8, 0x0308 } },
+    .{ .codepoint = 0x04EB, .mapping = &[_]u21{ 0x04E9, 0x0308 } },
+    .{ .codepoint = 0x04EC, .mapping = &[_]u21{ 0x042D, 0x0308 } },
+    .{ .codepoint = 0x04ED, .mapping = &[_]u21{ 0x044D, 0x0308 } },
+    .{ .codepoint = 0x04EE, .mapping = &[_]u21{ 0x0423, 0x0304 } },
+    .{ .codepoint = 0x04EF, .mapping = &[_]u21{ 0x0443, 0x0304 } },
+    .{ .codepoint = 0x04F0, .mapping = &[_]u21{ 0x0423, 0x0308 } },
+    .{ .codepoint = 0x04F1, .mapping = &[_]u21{ 0x0443, 0x0308 } },
+    .{ .codepoint = 0x04F2, .mapping = &[_]u21{ 0x0423, 0x030B } },
+    .{ .codepoint = 0x04F3, .mapping = &[_]u21{ 0x0443, 0x030B } },
+    .{ .codepoint = 0x04F4, .mapping = &[_]u21{ 0x0427, 0x0308 } },
+    .{ .codepoint = 0x04F5, .mapping = &[_]u21{ 0x0447, 0x0308 } },
+    .{ .codepoint = 0x04F8, .mapping = &[_]u21{ 0x042B, 0x0308 } },
+    .{ .codepoint = 0x04F9, .mapping = &[_]u21{ 0x044B, 0x0308 } },
+    .{ .codepoint = 0x0622, .mapping = &[_]u21{ 0x0627, 0x0653 } },
+    .{ .codepoint = 0x0623, .mapping = &[_]u21{ 0x0627, 0x0654 } },
+    .{ .codepoint = 0x0624, .mapping = &[_]u21{ 0x0648, 0x0654 } },
+    .{ .codepoint = 0x0625, .mapping = &[_]u21{ 0x0627, 0x0655 } },
+    .{ .codepoint = 0x0626, .mapping = &[_]u21{ 0x064A, 0x0654 } },
+    .{ .codepoint = 0x06C0, .mapping = &[_]u21{ 0x06D5, 0x0654 } },
+    .{ .codepoint = 0x06C2, .mapping = &[_]u21{ 0x06C1, 0x0654 } },
+    .{ .codepoint = 0x06D3, .mapping = &[_]u21{ 0x06D2, 0x0654 } },
+    .{ .codepoint = 0x0929, .mapping = &[_]u21{ 0x0928, 0x093C } },
+    .{ .codepoint = 0x0931, .mapping = &[_]u21{ 0x0930, 0x093C } },
+    .{ .codepoint = 0x0934, .mapping = &[_]u21{ 0x0933, 0x093C } },
+    .{ .codepoint = 0x0958, .mapping = &[_]u21{ 0x0915, 0x093C } },
+    .{ .codepoint = 0x0959, .mapping = &[_]u21{ 0x0916, 0x093C } },
+    .{ .codepoint = 0x095A, .mapping = &[_]u21{ 0x0917, 0x093C } },
+    .{ .codepoint = 0x095B, .mapping = &[_]u21{ 0x091C, 0x093C } },
+    .{ .codepoint = 0x095C, .mapping = &[_]u21{ 0x0921, 0x093C } },
+    .{ .codepoint = 0x095D, .mapping = &[_]u21{ 0x0922, 0x093C } },
+    .{ .codepoint = 0x095E, .mapping = &[_]u21{ 0x092B, 0x093C } },
+    .{ .codepoint = 0x095F, .mapping = &[_]u21{ 0x092F, 0x093C } },
+    .{ .codepoint = 0x09CB, .mapping = &[_]u21{ 0x09C7, 0x09BE } },
+    .{ .codepoint = 0x09CC, .mapping = &[_]u21{ 0x09C7, 0x09D7 } },
+    .{ .codepoint = 0x09DC, .mapping = &[_]u21{ 0x09A1, 0x09BC } },
+    .{ .codepoint = 0x09DD, .mapping = &[_]u21{ 0x09A2, 0x09BC } },
+    .{ .codepoint = 0x09DF, .mapping = &[_]u21{ 0x09AF, 0x09BC } },
+    .{ .codepoint = 0x0A33, .mapping = &[_]u21{ 0x0A32, 0x0A3C } },
+    .{ .codepoint = 0x0A36, .mapping = &[_]u21{ 0x0A38, 0x0A3C } },
+    .{ .codepoint = 0x0A59, .mapping = &[_]u21{ 0x0A16, 0x0A3C } },
+    .{ .codepoint = 0x0A5A, .mapping = &[_]u21{ 0x0A17, 0x0A3C } },
+    .{ .codepoint = 0x0A5B, .mapping = &[_]u21{ 0x0A1C, 0x0A3C } },
+    .{ .codepoint = 0x0A5E, .mapping = &[_]u21{ 0x0A2B, 0x0A3C } },
+    .{ .codepoint = 0x0B48, .mapping = &[_]u21{ 0x0B47, 0x0B56 } },
+    .{ .codepoint = 0x0B4B, .mapping = &[_]u21{ 0x0B47, 0x0B3E } },
+    .{ .codepoint = 0x0B4C, .mapping = &[_]u21{ 0x0B47, 0x0B57 } },
+    .{ .codepoint = 0x0B5C, .mapping = &[_]u21{ 0x0B21, 0x0B3C } },
+    .{ .codepoint = 0x0B5D, .mapping = &[_]u21{ 0x0B22, 0x0B3C } },
+    .{ .codepoint = 0x0B94, .mapping = &[_]u21{ 0x0B92, 0x0BD7 } },
+    .{ .codepoint = 0x0BCA, .mapping = &[_]u21{ 0x0BC6, 0x0BBE } },
+    .{ .codepoint = 0x0BCB, .mapping = &[_]u21{ 0x0BC7, 0x0BBE } },
+    .{ .codepoint = 0x0BCC, .mapping = &[_]u21{ 0x0BC6, 0x0BD7 } },
+    .{ .codepoint = 0x0C48, .mapping = &[_]u21{ 0x0C46, 0x0C56 } },
+    .{ .codepoint = 0x0CC0, .mapping = &[_]u21{ 0x0CBF, 0x0CD5 } },
+    .{ .codepoint = 0x0CC7, .mapping = &[_]u21{ 0x0CC6, 0x0CD5 } },
+    .{ .codepoint = 0x0CC8, .mapping = &[_]u21{ 0x0CC6, 0x0CD6 } },
+    .{ .codepoint = 0x0CCA, .mapping = &[_]u21{ 0x0CC6, 0x0CC2 } },
+    .{ .codepoint = 0x0CCB, .mapping = &[_]u21{ 0x0CCA, 0x0CD5 } },
+    .{ .codepoint = 0x0D4A, .mapping = &[_]u21{ 0x0D46, 0x0D3E } },
+    .{ .codepoint = 0x0D4B, .mapping = &[_]u21{ 0x0D47, 0x0D3E } },
+    .{ .codepoint = 0x0D4C, .mapping = &[_]u21{ 0x0D46, 0x0D57 } },
+    .{ .codepoint = 0x0DDA, .mapping = &[_]u21{ 0x0DD9, 0x0DCA } },
+    .{ .codepoint = 0x0DDC, .mapping = &[_]u21{ 0x0DD9, 0x0DCF } },
+    .{ .codepoint = 0x0DDD, .mapping = &[_]u21{ 0x0DDC, 0x0DCA } },
+    .{ .codepoint = 0x0DDE, .mapping = &[_]u21{ 0x0DD9, 0x0DDF } },
+    .{ .codepoint = 0x0F43, .mapping = &[_]u21{ 0x0F42, 0x0FB7 } },
+    .{ .codepoint = 0x0F4D, .mapping = &[_]u21{ 0x0F4C, 0x0FB7 } },
+    .{ .codepoint = 0x0F52, .mapping = &[_]u21{ 0x0F51, 0x0FB7 } },
+    .{ .codepoint = 0x0F57, .mapping = &[_]u21{ 0x0F56, 0x0FB7 } },
+    .{ .codepoint = 0x0F5C, .mapping = &[_]u21{ 0x0F5B, 0x0FB7 } },
+    .{ .codepoint = 0x0F69, .mapping = &[_]u21{ 0x0F40, 0x0FB5 } },
+    .{ .codepoint = 0x0F73, .mapping = &[_]u21{ 0x0F71, 0x0F72 } },
+    .{ .codepoint = 0x0F75, .mapping = &[_]u21{ 0x0F71, 0x0F74 } },
+    .{ .codepoint = 0x0F76, .mapping = &[_]u21{ 0x0FB2, 0x0F80 } },
+    .{ .codepoint = 0x0F78, .mapping = &[_]u21{ 0x0FB3, 0x0F80 } },
+    .{ .codepoint = 0x0F81, .mapping = &[_]u21{ 0x0F71, 0x0F80 } },
+    .{ .codepoint = 0x0F93, .mapping = &[_]u21{ 0x0F92, 0x0FB7 } },
+    .{ .codepoint = 0x0F9D, .mapping = &[_]u21{ 0x0F9C, 0x0FB7 } },
+    .{ .codepoint = 0x0FA2, .mapping = &[_]u21{ 0x0FA1, 0x0FB7 } },
+    .{ .codepoint = 0x0FA7, .mapping = &[_]u21{ 0x0FA6, 0x0FB7 } },
+    .{ .codepoint = 0x0FAC, .mapping = &[_]u21{ 0x0FAB, 0x0FB7 } },
+    .{ .codepoint = 0x0FB9, .mapping = &[_]u21{ 0x0F90, 0x0FB5 } },
+    .{ .codepoint = 0x1026, .mapping = &[_]u21{ 0x1025, 0x102E } },
+    .{ .codepoint = 0x1B06, .mapping = &[_]u21{ 0x1B05, 0x1B35 } },
+    .{ .codepoint = 0x1B08, .mapping = &[_]u21{ 0x1B07, 0x1B35 } },
+    .{ .codepoint = 0x1B0A, .mapping = &[_]u21{ 0x1B09, 0x1B35 } },
+    .{ .codepoint = 0x1B0C, .mapping = &[_]u21{ 0x1B0B, 0x1B35 } },
+    .{ .codepoint = 0x1B0E, .mapping = &[_]u21{ 0x1B0D, 0x1B35 } },
+    .{ .codepoint = 0x1B12, .mapping = &[_]u21{ 0x1B11, 0x1B35 } },
+    .{ .codepoint = 0x1B3B, .mapping = &[_]u21{ 0x1B3A, 0x1B35 } },
+    .{ .codepoint = 0x1B3D, .mapping = &[_]u21{ 0x1B3C, 0x1B35 } },
+    .{ .codepoint = 0x1B40, .mapping = &[_]u21{ 0x1B3E, 0x1B35 } },
+    .{ .codepoint = 0x1B41, .mapping = &[_]u21{ 0x1B3F, 0x1B35 } },
+    .{ .codepoint = 0x1B43, .mapping = &[_]u21{ 0x1B42, 0x1B35 } },
+    .{ .codepoint = 0x1E00, .mapping = &[_]u21{ 0x0041, 0x0325 } },
+    .{ .codepoint = 0x1E01, .mapping = &[_]u21{ 0x0061, 0x0325 } },
+    .{ .codepoint = 0x1E02, .mapping = &[_]u21{ 0x0042, 0x0307 } },
+    .{ .codepoint = 0x1E03, .mapping = &[_]u21{ 0x0062, 0x0307 } },
+    .{ .codepoint = 0x1E04, .mapping = &[_]u21{ 0x0042, 0x0323 } },
+    .{ .codepoint = 0x1E05, .mapping = &[_]u21{ 0x0062, 0x0323 } },
+    .{ .codepoint = 0x1E06, .mapping = &[_]u21{ 0x0042, 0x0331 } },
+    .{ .codepoint = 0x1E07, .mapping = &[_]u21{ 0x0062, 0x0331 } },
+    .{ .codepoint = 0x1E08, .mapping = &[_]u21{ 0x00C7, 0x0301 } },
+    .{ .codepoint = 0x1E09, .mapping = &[_]u21{ 0x00E7, 0x0301 } },
+    .{ .codepoint = 0x1E0A, .mapping = &[_]u21{ 0x0044, 0x0307 } },
+    .{ .codepoint = 0x1E0B, .mapping = &[_]u21{ 0x0064, 0x0307 } },
+    .{ .codepoint = 0x1E0C, .mapping = &[_]u21{ 0x0044, 0x0323 } },
+    .{ .codepoint = 0x1E0D, .mapping = &[_]u21{ 0x0064, 0x0323 } },
+    .{ .codepoint = 0x1E0E, .mapping = &[_]u21{ 0x0044, 0x0331 } },
+    .{ .codepoint = 0x1E0F, .mapping = &[_]u21{ 0x0064, 0x0331 } },
+    .{ .codepoint = 0x1E10, .mapping = &[_]u21{ 0x0044, 0x0327 } },
+    .{ .codepoint = 0x1E11, .mapping = &[_]u21{ 0x0064, 0x0327 } },
+    .{ .codepoint = 0x1E12, .mapping = &[_]u21{ 0x0044, 0x032D } },
+    .{ .codepoint = 0x1E13, .mapping = &[_]u21{ 0x0064, 0x032D } },
+    .{ .codepoint = 0x1E14, .mapping = &[_]u21{ 0x0112, 0x0300 } },
+    .{ .codepoint = 0x1E15, .mapping = &[_]u21{ 0x0113, 0x0300 } },
+    .{ .codepoint = 0x1E16, .mapping = &[_]u21{ 0x0112, 0x0301 } },
+    .{ .codepoint = 0x1E17, .mapping = &[_]u21{ 0x0113, 0x0301 } },
+    .{ .codepoint = 0x1E18, .mapping = &[_]u21{ 0x0045, 0x032D } },
+    .{ .codepoint = 0x1E19, .mapping = &[_]u21{ 0x0065, 0x032D } },
+    .{ .codepoint = 0x1E1A, .mapping = &[_]u21{ 0x0045, 0x0330 } },
+    .{ .codepoint = 0x1E1B, .mapping = &[_]u21{ 0x0065, 0x0330 } },
+    .{ .codepoint = 0x1E1C, .mapping = &[_]u21{ 0x0228, 0x0306 } },
+    .{ .codepoint = 0x1E1D, .mapping = &[_]u21{ 0x0229, 0x0306 } },
+    .{ .codepoint = 0x1E1E, .mapping = &[_]u21{ 0x0046, 0x0307 } },
+    .{ .codepoint = 0x1E1F, .mapping = &[_]u21{ 0x0066, 0x0307 } },
+    .{ .codepoint = 0x1E20, .mapping = &[_]u21{ 0x0047, 0x0304 } },
+    .{ .codepoint = 0x1E21, .mapping = &[_]u21{ 0x0067, 0x0304 } },
+    .{ .codepoint = 0x1E22, .mapping = &[_]u21{ 0x0048, 0x0307 } },
+    .{ .codepoint = 0x1E23, .mapping = &[_]u21{ 0x0068, 0x0307 } },
+    .{ .codepoint = 0x1E24, .mapping = &[_]u21{ 0x0048, 0x0323 } },
+    .{ .codepoint = 0x1E25, .mapping = &[_]u21{ 0x0068, 0x0323 } },
+    .{ .codepoint = 0x1E26, .mapping = &[_]u21{ 0x0048, 0x0308 } },
+    .{ .codepoint = 0x1E27, .mapping = &[_]u21{ 0x0068, 0x0308 } },
+    .{ .codepoint = 0x1E28, .mapping = &[_]u21{ 0x0048, 0x0327 } },
+    .{ .codepoint = 0x1E29, .mapping = &[_]u21{ 0x0068, 0x0327 } },
+    .{ .codepoint = 0x1E2A, .mapping = &[_]u21{ 0x0048, 0x032E } },
+    .{ .codepoint = 0x1E2B, .mapping = &[_]u21{ 0x0068, 0x032E } },
+    .{ .codepoint = 0x1E2C, .mapping = &[_]u21{ 0x0049, 0x0330 } },
+    .{ .codepoint = 0x1E2D, .mapping = &[_]u21{ 0x0069, 0x0330 } },
+    .{ .codepoint = 0x1E2E, .mapping = &[_]u21{ 0x00CF, 0x0301 } },
+    .{ .codepoint = 0x1E2F, .mapping = &[_]u21{ 0x00EF, 0x0301 } },
+    .{ .codepoint = 0x1E30, .mapping = &[_]u21{ 0x004B, 0x0301 } },
+    .{ .codepoint = 0x1E31, .mapping = &[_]u21{ 0x006B, 0x0301 } },
+    .{ .codepoint = 0x1E32, .mapping = &[_]u21{ 0x004B, 0x0323 } },
+    .{ .codepoint = 0x1E33, .mapping = &[_]u21{ 0x006B, 0x0323 } },
+    .{ .codepoint = 0x1E34, .mapping = &[_]u21{ 0x004B, 0x0331 } },
+    .{ .codepoint = 0x1E35, .mapping = &[_]u21{ 0x006B, 0x0331 } },
+    .{ .codepoint = 0x1E36, .mapping = &[_]u21{ 0x004C, 0x0323 } },
+    .{ .codepoint = 0x1E37, .mapping = &[_]u21{ 0x006C, 0x0323 } },
+    .{ .codepoint = 0x1E38, .mapping = &[_]u21{ 0x1E36, 0x0304 } },
+    .{ .codepoint = 0x1E39, .mapping = &[_]u21{ 0x1E37, 0x0304 } },
+    .{ .codepoint = 0x1E3A, .mapping = &[_]u21{ 0x004C, 0x0331 } },
+    .{ .codepoint = 0x1E3B, .mapping = &[_]u21{ 0x006C, 0x0331 } },
+    .{ .codepoint = 0x1E3C, .mapping = &[_]u21{ 0x004C, 0x032D } },
+    .{ .codepoint = 0x1E3D, .mapping = &[_]u21{ 0x006C, 0x032D } },
+    .{ .codepoint = 0x1E3E, .mapping = &[_]u21{ 0x004D, 0x0301 } },
+    .{ .codepoint = 0x1E3F, .mapping = &[_]u21{ 0x006D, 0x0301 } },
+    .{ .codepoint = 0x1E40, .mapping = &[_]u21{ 0x004D, 0x0307 } },
+    .{ .codepoint = 0x1E41, .mapping = &[_]u21{ 0x006D, 0x0307 } },
+    .{ .codepoint = 0x1E42, .mapping = &[_]u21{ 0x004D, 0x0323 } },
+    .{ .codepoint = 0x1E43, .mapping = &[_]u21{ 0x006D, 0x0323 } },
+    .{ .codepoint = 0x1E44, .mapping = &[_]u21{ 0x004E, 0x0307 } },
+    .{ .codepoint = 0x1E45, .mapping = &[_]u21{ 0x006E, 0x0307 } },
+    .{ .codepoint = 0x1E46, .mapping = &[_]u21{ 0x004E, 0x0323 } },
+    .{ .codepoint = 0x1E47, .mapping = &[_]u21{ 0x006E, 0x0323 } },
+    .{ .codepoint = 0x1E48, .mapping = &[_]u21{ 0x004E, 0x0331 } },
+    .{ .codepoint = 0x1E49, .mapping = &[_]u21{ 0x006E, 0x0331 } },
+    .{ .codepoint = 0x1E4A, .mapping = &[_]u21{ 0x004E, 0x032D } },
+    .{ .codepoint = 0x1E4B, .mapping = &[_]u21{ 0x006E, 0x032D } },
+    .{ .codepoint = 0x1E4C, .mapping = &[_]u21{ 0x00D5, 0x0301 } },
+    .{ .codepoint = 0x1E4D, .mapping = &[_]u21{ 0x00F5, 0x0301 } },
+    .{ .codepoint = 0x1E4E, .mapping = &[_]u21{ 0x00D5, 0x0308 } },
+    .{ .codepoint = 0x1E4F, .mapping = &[_]u21{ 0x00F5, 0x0308 } },
+    .{ .codepoint = 0x1E50, .mapping = &[_]u21{ 0x014C, 0x0300 } },
+    .{ .codepoint = 0x1E51, .mapping = &[_]u21{ 0x014D, 0x0300 } },
+    .{ .codepoint = 0x1E52, .mapping = &[_]u21{ 0x014C, 0x0301 } },
+    .{ .codepoint = 0x1E53, .mapping = &[_]u21{ 0x014D, 0x0301 } },
+    .{ .codepoint = 0x1E54, .mapping = &[_]u21{ 0x0050, 0x0301 } },
+    .{ .codepoint = 0x1E55, .mapping = &[_]u21{ 0x0070, 0x0301 } },
+    .{ .codepoint = 0x1E56, .mapping = &[_]u21{ 0x0050, 0x0307 } },
+    .{ .codepoint = 0x1E57, .mapping = &[_]u21{ 0x0070, 0x0307 } },
+    .{ .codepoint = 0x1E58, .mapping = &[_]u21{ 0x0052, 0x0307 } },
+    .{ .codepoint = 0x1E59, .mapping = &[_]u21{ 0x0072, 0x0307 } },
+    .{ .codepoint = 0x1E5A, .mapping = &[_]u21{ 0x0052, 0x0323 } },
+    .{ .codepoint = 0x1E5B, .mapping = &[_]u21{ 0x0072, 0x0323 } },
+    .{ .codepoint = 0x1E5C, .mapping = &[_]u21{ 0x1E5A, 0x0304 } },
+    .{ .codepoint = 0x1E5D, .mapping = &[_]u21{ 0x1E5B, 0x0304 } },
+    .{ .codepoint = 0x1E5E, .mapping = &[_]u21{ 0x0052, 0x0331 } },
+    .{ .codepoint = 0x1E5F, .mapping = &[_]u21{ 0x0072, 0x0331 } },
+    .{ .codepoint = 0x1E60, .mapping = &[_]u21{ 0x0053, 0x0307 } },
+    .{ .codepoint = 0x1E61, .mapping = &[_]u21{ 0x0073, 0x0307 } },
+    .{ .codepoint = 0x1E62, .mapping = &[_]u21{ 0x0053, 0x0323 } },
+    .{ .codepoint = 0x1E63, .mapping = &[_]u21{ 0x0073, 0x0323 } },
+    .{ .codepoint = 0x1E64, .mapping = &[_]u21{ 0x015A, 0x0307 } },
+    .{ .codepoint = 0x1E65, .mapping = &[_]u21{ 0x015B, 0x0307 } },
+    .{ .codepoint = 0x1E66, .mapping = &[_]u21{ 0x0160, 0x0307 } },
+    .{ .codepoint = 0x1E67, .mapping = &[_]u21{ 0x0161, 0x0307 } },
+    .{ .codepoint = 0x1E68, .mapping = &[_]u21{ 0x1E62, 0x0307 } },
+    .{ .codepoint = 0x1E69, .mapping = &[_]u21{ 0x1E63, 0x0307 } },
+    .{ .codepoint = 0x1E6A, .mapping = &[_]u21{ 0x0054, 0x0307 } },
+    .{ .codepoint = 0x1E6B, .mapping = &[_]u21{ 0x0074, 0x0307 } },
+    .{ .codepoint = 0x1E6C, .mapping = &[_]u21{ 0x0054, 0x0323 } },
+    .{ .codepoint = 0x1E6D, .mapping = &[_]u21{ 0x0074, 0x0323 } },
+    .{ .codepoint = 0x1E6E, .mapping = &[_]u21{ 0x0054, 0x0331 } },
+    .{ .codepoint = 0x1E6F, .mapping = &[_]u21{ 0x0074, 0x0331 } },
+    .{ .codepoint = 0x1E70, .mapping = &[_]u21{ 0x0054, 0x032D } },
+    .{ .codepoint = 0x1E71, .mapping = &[_]u21{ 0x0074, 0x032D } },
+    .{ .codepoint = 0x1E72, .mapping = &[_]u21{ 0x0055, 0x0324 } },
+    .{ .codepoint = 0x1E73, .mapping = &[_]u21{ 0x0075, 0x0324 } },
+    .{ .codepoint = 0x1E74, .mapping = &[_]u21{ 0x0055, 0x0330 } },
+    .{ .codepoint = 0x1E75, .mapping = &[_]u21{ 0x0075, 0x0330 } },
+    .{ .codepoint = 0x1E76, .mapping = &[_]u21{ 0x0055, 0x032D } },
+    .{ .codepoint = 0x1E77, .mapping = &[_]u21{ 0x0075, 0x032D } },
+    .{ .codepoint = 0x1E78, .mapping = &[_]u21{ 0x0168, 0x0301 } },
+    .{ .codepoint = 0x1E79, .mapping = &[_]u21{ 0x0169, 0x0301 } },
+    .{ .codepoint = 0x1E7A, .mapping = &[_]u21{ 0x016A, 0x0308 } },
+    .{ .codepoint = 0x1E7B, .mapping = &[_]u21{ 0x016B, 0x0308 } },
+    .{ .codepoint = 0x1E7C, .mapping = &[_]u21{ 0x0056, 0x0303 } },
+    .{ .codepoint = 0x1E7D, .mapping = &[_]u21{ 0x0076, 0x0303 } },
+    .{ .codepoint = 0x1E7E, .mapping = &[_]u21{ 0x0056, 0x0323 } },
+    .{ .codepoint = 0x1E7F, .mapping = &[_]u21{ 0x0076, 0x0323 } },
+    .{ .codepoint = 0x1E80, .mapping = &[_]u21{ 0x0057, 0x0300 } },
+    .{ .codepoint = 0x1E81, .mapping = &[_]u21{ 0x0077, 0x0300 } },
+    .{ .codepoint = 0x1E82, .mapping = &[_]u21{ 0x0057, 0x0301 } },
+    .{ .codepoint = 0x1E83, .mapping = &[_]u21{ 0x0077, 0x0301 } },
+    .{ .codepoint = 0x1E84, .mapping = &[_]u21{ 0x0057, 0x0308 } },
+    .{ .codepoint = 0x1E85, .mapping = &[_]u21{ 0x0077, 0x0308 } },
+    .{ .codepoint = 0x1E86, .mapping = &[_]u21{ 0x0057, 0x0307 } },
+    .{ .codepoint = 0x1E87, .mapping = &[_]u21{ 0x0077, 0x0307 } },
+    .{ .codepoint = 0x1E88, .mapping = &[_]u21{ 0x0057, 0x0323 } },
+    .{ .codepoint = 0x1E89, .mapping = &[_]u21{ 0x0077, 0x0323 } },
+    .{ .codepoint = 0x1E8A, .mapping = &[_]u21{ 0x0058, 0x0307 } },
+    .{ .codepoint = 0x1E8B, .mapping = &[_]u21{ 0x0078, 0x0307 } },
+    .{ .codepoint = 0x1E8C, .mapping = &[_]u21{ 0x0058, 0x0308 } },
+    .{ .codepoint = 0x1E8D, .mapping = &[_]u21{ 0x0078, 0x0308 } },
+    .{ .codepoint = 0x1E8E, .mapping = &[_]u21{ 0x0059, 0x0307 } },
+    .{ .codepoint = 0x1E8F, .mapping = &[_]u21{ 0x0079, 0x0307 } },
+    .{ .codepoint = 0x1E90, .mapping = &[_]u21{ 0x005A, 0x0302 } },
+    .{ .codepoint = 0x1E91, .mapping = &[_]u21{ 0x007A, 0x0302 } },
+    .{ .codepoint = 0x1E92, .mapping = &[_]u21{ 0x005A, 0x0323 } },
+    .{ .codepoint = 0x1E93, .mapping = &[_]u21{ 0x007A, 0x0323 } },
+    .{ .codepoint = 0x1E94, .mapping = &[_]u21{ 0x005A, 0x0331 } },
+    .{ .codepoint = 0x1E95, .mapping = &[_]u21{ 0x007A, 0x0331 } },
+    .{ .codepoint = 0x1E96, .mapping = &[_]u21{ 0x0068, 0x0331 } },
+    .{ .codepoint = 0x1E97, .mapping = &[_]u21{ 0x0074, 0x0308 } },
+    .{ .codepoint = 0x1E98, .mapping = &[_]u21{ 0x0077, 0x030A } },
+    .{ .codepoint = 0x1E99, .mapping = &[_]u21{ 0x0079, 0x030A } },
+    .{ .codepoint = 0x1E9B, .mapping = &[_]u21{ 0x017F, 0x0307 } },
+    .{ .codepoint = 0x1EA0, .mapping = &[_]u21{ 0x0041, 0x0323 } },
+    .{ .codepoint = 0x1EA1, .mapping = &[_]u21{ 0x0061, 0x0323 } },
+    .{ .codepoint = 0x1EA2, .mapping = &[_]u21{ 0x0041, 0x0309 } },
+    .{ .codepoint = 0x1EA3, .mapping = &[_]u21{ 0x0061, 0x0309 } },
+    .{ .codepoint = 0x1EA4, .mapping = &[_]u21{ 0x00C2, 0x0301 } },
+    .{ .codepoint = 0x1EA5, .mapping = &[_]u21{ 0x00E2, 0x0301 } },
+    .{ .codepoint = 0x1EA6, .mapping = &[_]u21{ 0x00C2, 0x0300 } },
+    .{ .codepoint = 0x1EA7, .mapping = &[_]u21{ 0x00E2, 0x0300 } },
+    .{ .codepoint = 0x1EA8, .mapping = &[_]u21{ 0x00C2, 0x0309 } },
+    .{ .codepoint = 0x1EA9, .mapping = &[_]u21{ 0x00E2, 0x0309 } },
+    .{ .codepoint = 0x1EAA, .mapping = &[_]u21{ 0x00C2, 0x0303 } },
+    .{ .codepoint = 0x1EAB, .mapping = &[_]u21{ 0x00E2, 0x0303 } },
+    .{ .codepoint = 0x1EAC, .mapping = &[_]u21{ 0x1EA0, 0x0302 } },
+    .{ .codepoint = 0x1EAD, .mapping = &[_]u21{ 0x1EA1, 0x0302 } },
+    .{ .codepoint = 0x1EAE, .mapping = &[_]u21{ 0x0102, 0x0301 } },
+    .{ .codepoint = 0x1EAF, .mapping = &[_]u21{ 0x0103, 0x0301 } },
+    .{ .codepoint = 0x1EB0, .mapping = &[_]u21{ 0x0102, 0x0300 } },
+    .{ .codepoint = 0x1EB1, .mapping = &[_]u21{ 0x0103, 0x0300 } },
+    .{ .codepoint = 0x1EB2, .mapping = &[_]u21{ 0x0102, 0x0309 } },
+    .{ .codepoint = 0x1EB3, .mapping = &[_]u21{ 0x0103, 0x0309 } },
+    .{ .codepoint = 0x1EB4, .mapping = &[_]u21{ 0x0102, 0x0303 } },
+    .{ .codepoint = 0x1EB5, .mapping = &[_]u21{ 0x0103, 0x0303 } },
+    .{ .codepoint = 0x1EB6, .mapping = &[_]u21{ 0x1EA0, 0x0306 } },
+    .{ .codepoint = 0x1EB7, .mapping = &[_]u21{ 0x1EA1, 0x0306 } },
+    .{ .codepoint = 0x1EB8, .mapping = &[_]u21{ 0x0045, 0x0323 } },
+    .{ .codepoint = 0x1EB9, .mapping = &[_]u21{ 0x0065, 0x0323 } },
+    .{ .codepoint = 0x1EBA, .mapping = &[_]u21{ 0x0045, 0x0309 } },
+    .{ .codepoint = 0x1EBB, .mapping = &[_]u21{ 0x0065, 0x0309 } },
+    .{ .codepoint = 0x1EBC, .mapping = &[_]u21{ 0x0045, 0x0303 } },
+    .{ .codepoint = 0x1EBD, .mapping = &[_]u21{ 0x0065, 0x0303 } },
+    .{ .codepoint = 0x1EBE, .mapping = &[_]u21{ 0x00CA, 0x0301 } },
+    .{ .codepoint = 0x1EBF, .mapping = &[_]u21{ 0x00EA, 0x0301 } },
+    .{ .codepoint = 0x1EC0, .mapping = &[_]u21{ 0x00CA, 0x0300 } },
+    .{ .codepoint = 0x1EC1, .mapping = &[_]u21{ 0x00EA, 0x0300 } },
+    .{ .codepoint = 0x1EC2, .mapping = &[_]u21{ 0x00CA, 0x0309 } },
+    .{ .codepoint = 0x1EC3, .mapping = &[_]u21{ 0x00EA, 0x0309 } },
+    .{ .codepoint = 0x1EC4, .mapping = &[_]u21{ 0x00CA, 0x0303 } },
+    .{ .codepoint = 0x1EC5, .mapping = &[_]u21{ 0x00EA, 0x0303 } },
+    .{ .codepoint = 0x1EC6, .mapping = &[_]u21{ 0x1EB8, 0x0302 } },
+    .{ .codepoint = 0x1EC7, .mapping = &[_]u21{ 0x1EB9, 0x0302 } },
+    .{ .codepoint = 0x1EC8, .mapping = &[_]u21{ 0x0049, 0x0309 } },
+    .{ .codepoint = 0x1EC9, .mapping = &[_]u21{ 0x0069, 0x0309 } },
+    .{ .codepoint = 0x1ECA, .mapping = &[_]u21{ 0x0049, 0x0323 } },
+    .{ .codepoint = 0x1ECB, .mapping = &[_]u21{ 0x0069, 0x0323 } },
+    .{ .codepoint = 0x1ECC, .mapping = &[_]u21{ 0x004F, 0x0323 } },
+    .{ .codepoint = 0x1ECD, .mapping = &[_]u21{ 0x006F, 0x0323 } },
+    .{ .codepoint = 0x1ECE, .mapping = &[_]u21{ 0x004F, 0x0309 } },
+    .{ .codepoint = 0x1ECF, .mapping = &[_]u21{ 0x006F, 0x0309 } },
+    .{ .codepoint = 0x1ED0, .mapping = &[_]u21{ 0x00D4, 0x0301 } },
+    .{ .codepoint = 0x1ED1, .mapping = &[_]u21{ 0x00F4, 0x0301 } },
+    .{ .codepoint = 0x1ED2, .mapping = &[_]u21{ 0x00D4, 0x0300 } },
+    .{ .codepoint = 0x1ED3, .mapping = &[_]u21{ 0x00F4, 0x0300 } },
+    .{ .codepoint = 0x1ED4, .mapping = &[_]u21{ 0x00D4, 0x0309 } },
+    .{ .codepoint = 0x1ED5, .mapping = &[_]u21{ 0x00F4, 0x0309 } },
+    .{ .codepoint = 0x1ED6, .mapping = &[_]u21{ 0x00D4, 0x0303 } },
+    .{ .codepoint = 0x1ED7, .mapping = &[_]u21{ 0x00F4, 0x0303 } },
+    .{ .codepoint = 0x1ED8, .mapping = &[_]u21{ 0x1ECC, 0x0302 } },
+    .{ .codepoint = 0x1ED9, .mapping = &[_]u21{ 0x1ECD, 0x0302 } },
+    .{ .codepoint = 0x1EDA, .mapping = &[_]u21{ 0x01A0, 0x0301 } },
+    .{ .codepoint = 0x1EDB, .mapping = &[_]u21{ 0x01A1, 0x0301 } },
+    .{ .codepoint = 0x1EDC, .mapping = &[_]u21{ 0x01A0, 0x0300 } },
+    .{ .codepoint = 0x1EDD, .mapping = &[_]u21{ 0x01A1, 0x0300 } },
+    .{ .codepoint = 0x1EDE, .mapping = &[_]u21{ 0x01A0, 0x0309 } },
+    .{ .codepoint = 0x1EDF, .mapping = &[_]u21{ 0x01A1, 0x0309 } },
+    .{ .codepoint = 0x1EE0, .mapping = &[_]u21{ 0x01A0, 0x0303 } },
+    .{ .codepoint = 0x1EE1, .mapping = &[_]u21{ 0x01A1, 0x0303 } },
+    .{ .codepoint = 0x1EE2, .mapping = &[_]u21{ 0x01A0, 0x0323 } },
+    .{ .codepoint = 0x1EE3, .mapping = &[_]u21{ 0x01A1, 0x0323 } },
+    .{ .codepoint = 0x1EE4, .mapping = &[_]u21{ 0x0055, 0x0323 } },
+    .{ .codepoint = 0x1EE5, .mapping = &[_]u21{ 0x0075, 0x0323 } },
+    .{ .codepoint = 0x1EE6, .mapping = &[_]u21{ 0x0055, 0x0309 } },
+    .{ .codepoint = 0x1EE7, .mapping = &[_]u21{ 0x0075, 0x0309 } },
+    .{ .codepoint = 0x1EE8, .mapping = &[_]u21{ 0x01AF, 0x0301 } },
+    .{ .codepoint = 0x1EE9, .mapping = &[_]u21{ 0x01B0, 0x0301 } },
+    .{ .codepoint = 0x1EEA, .mapping = &[_]u21{ 0x01AF, 0x0300 } },
+    .{ .codepoint = 0x1EEB, .mapping = &[_]u21{ 0x01B0, 0x0300 } },
+    .{ .codepoint = 0x1EEC, .mapping = &[_]u21{ 0x01AF, 0x0309 } },
+    .{ .codepoint = 0x1EED, .mapping = &[_]u21{ 0x01B0, 0x0309 } },
+    .{ .codepoint = 0x1EEE, .mapping = &[_]u21{ 0x01AF, 0x0303 } },
+    .{ .codepoint = 0x1EEF, .mapping = &[_]u21{ 0x01B0, 0x0303 } },
+    .{ .codepoint = 0x1EF0, .mapping = &[_]u21{ 0x01AF, 0x0323 } },
+    .{ .codepoint = 0x1EF1, .mapping = &[_]u21{ 0x01B0, 0x0323 } },
+    .{ .codepoint = 0x1EF2, .mapping = &[_]u21{ 0x0059, 0x0300 } },
+    .{ .codepoint = 0x1EF3, .mapping = &[_]u21{ 0x0079, 0x0300 } },
+    .{ .codepoint = 0x1EF4, .mapping = &[_]u21{ 0x0059, 0x0323 } },
+    .{ .codepoint = 0x1EF5, .mapping = &[_]u21{ 0x0079, 0x0323 } },
+    .{ .codepoint = 0x1EF6, .mapping = &[_]u21{ 0x0059, 0x0309 } },
+    .{ .codepoint = 0x1EF7, .mapping = &[_]u21{ 0x0079, 0x0309 } },
+    .{ .codepoint = 0x1EF8, .mapping = &[_]u21{ 0x0059, 0x0303 } },
+    .{ .codepoint = 0x1EF9, .mapping = &[_]u21{ 0x0079, 0x0303 } },
+    .{ .codepoint = 0x1F00, .mapping = &[_]u21{ 0x03B1, 0x0313 } },
+    .{ .codepoint = 0x1F01, .mapping = &[_]u21{ 0x03B1, 0x0314 } },
+    .{ .codepoint = 0x1F02, .mapping = &[_]u21{ 0x1F00, 0x0300 } },
+    .{ .codepoint = 0x1F03, .mapping = &[_]u21{ 0x1F01, 0x0300 } },
+    .{ .codepoint = 0x1F04, .mapping = &[_]u21{ 0x1F00, 0x0301 } },
+    .{ .codepoint = 0x1F05, .mapping = &[_]u21{ 0x1F01, 0x0301 } },
+    .{ .codepoint = 0x1F06, .mapping = &[_]u21{ 0x1F00, 0x0342 } },
+    .{ .codepoint = 0x1F07, .mapping = &[_]u21{ 0x1F01, 0x0342 } },
+    .{ .codepoint = 0x1F08, .mapping = &[_]u21{ 0x0391, 0x0313 } },
+    .{ .codepoint = 0x1F09, .mapping = &[_]u21{ 0x0391, 0x0314 } },
+    .{ .codepoint = 0x1F0A, .mapping = &[_]u21{ 0x1F08, 0x0300 } },
+    .{ .codepoint = 0x1F0B, .mapping = &[_]u21{ 0x1F09, 0x0300 } },
+    .{ .codepoint = 0x1F0C, .mapping = &[_]u21{ 0x1F08, 0x0301 } },
+    .{ .codepoint = 0x1F0D, .mapping = &[_]u21{ 0x1F09, 0x0301 } },
+    .{ .codepoint = 0x1F0E, .mapping = &[_]u21{ 0x1F08, 0x0342 } },
+    .{ .codepoint = 0x1F0F, .mapping = &[_]u21{ 0x1F09, 0x0342 } },
+    .{ .codepoint = 0x1F10, .mapping = &[_]u21{ 0x03B5, 0x0313 } },
+    .{ .codepoint = 0x1F11, .mapping = &[_]u21{ 0x03B5, 0x0314 } },
+    .{ .codepoint = 0x1F12, .mapping = &[_]u21{ 0x1F10, 0x0300 } },
+    .{ .codepoint = 0x1F13, .mapping = &[_]u21{ 0x1F11, 0x0300 } },
+    .{ .codepoint = 0x1F14, .mapping = &[_]u21{ 0x1F10, 0x0301 } },
+    .{ .codepoint = 0x1F15, .mapping = &[_]u21{ 0x1F11, 0x0301 } },
+    .{ .codepoint = 0x1F18, .mapping = &[_]u21{ 0x0395, 0x0313 } },
+    .{ .codepoint = 0x1F19, .mapping = &[_]u21{ 0x0395, 0x0314 } },
+    .{ .codepoint = 0x1F1A, .mapping = &[_]u21{ 0x1F18, 0x0300 } },
+    .{ .codepoint = 0x1F1B, .mapping = &[_]u21{ 0x1F19, 0x0300 } },
+    .{ .codepoint = 0x1F1C, .mapping = &[_]u21{ 0x1F18, 0x0301 } },
+    .{ .codepoint = 0x1F1D, .mapping = &[_]u21{ 0x1F19, 0x0301 } },
+    .{ .codepoint = 0x1F20, .mapping = &[_]u21{ 0x03B7, 0x0313 } },
+    .{ .codepoint = 0x1F21, .mapping = &[_]u21{ 0x03B7, 0x0314 } },
+    .{ .codepoint = 0x1F22, .mapping = &[_]u21{ 0x1F20, 0x0300 } },
+    .{ .codepoint = 0x1F23, .mapping = &[_]u21{ 0x1F21, 0x0300 } },
+    .{ .codepoint = 0x1F24, .mapping = &[_]u21{ 0x1F20, 0x0301 } },
+    .{ .codepoint = 0x1F25, .mapping = &[_]u21{ 0x1F21, 0x0301 } },
+    .{ .codepoint = 0x1F26, .mapping = &[_]u21{ 0x1F20, 0x0342 } },
+    .{ .codepoint = 0x1F27, .mapping = &[_]u21{ 0x1F21, 0x0342 } },
+    .{ .codepoint = 0x1F28, .mapping = &[_]u21{ 0x0397, 0x0313 } },
+    .{ .codepoint = 0x1F29, .mapping = &[_]u21{ 0x0397, 0x0314 } },
+    .{ .codepoint = 0x1F2A, .mapping = &[_]u21{ 0x1F28, 0x0300 } },
+    .{ .codepoint = 0x1F2B, .mapping = &[_]u21{ 0x1F29, 0x0300 } },
+    .{ .codepoint = 0x1F2C, .mapping = &[_]u21{ 0x1F28, 0x0301 } },
+    .{ .codepoint = 0x1F2D, .mapping = &[_]u21{ 0x1F29, 0x0301 } },
+    .{ .codepoint = 0x1F2E, .mapping = &[_]u21{ 0x1F28, 0x0342 } },
+    .{ .codepoint = 0x1F2F, .mapping = &[_]u21{ 0x1F29, 0x0342 } },
+    .{ .codepoint = 0x1F30, .mapping = &[_]u21{ 0x03B9, 0x0313 } },
+    .{ .codepoint = 0x1F31, .mapping = &[_]u21{ 0x03B9, 0x0314 } },
+    .{ .codepoint = 0x1F32, .mapping = &[_]u21{ 0x1F30, 0x0300 } },
+    .{ .codepoint = 0x1F33, .mapping = &[_]u21{ 0x1F31, 0x0300 } },
+    .{ .codepoint = 0x1F34, .mapping = &[_]u21{ 0x1F30, 0x0301 } },
+    .{ .codepoint = 0x1F35, .mapping = &[_]u21{ 0x1F31, 0x0301 } },
+    .{ .codepoint = 0x1F36, .mapping = &[_]u21{ 0x1F30, 0x0342 } },
+    .{ .codepoint = 0x1F37, .mapping = &[_]u21{ 0x1F31, 0x0342 } },
+    .{ .codepoint = 0x1F38, .mapping = &[_]u21{ 0x0399, 0x0313 } },
+    .{ .codepoint = 0x1F39, .mapping = &[_]u21{ 0x0399, 0x0314 } },
+    .{ .codepoint = 0x1F3A, .mapping = &[_]u21{ 0x1F38, 0x0300 } },
+    .{ .codepoint = 0x1F3B, .mapping = &[_]u21{ 0x1F39, 0x0300 } },
+    .{ .codepoint = 0x1F3C, .mapping = &[_]u21{ 0x1F38, 0x0301 } },
+    .{ .codepoint = 0x1F3D, .mapping = &[_]u21{ 0x1F39, 0x0301 } },
+    .{ .codepoint = 0x1F3E, .mapping = &[_]u21{ 0x1F38, 0x0342 } },
+    .{ .codepoint = 0x1F3F, .mapping = &[_]u21{ 0x1F39, 0x0342 } },
+    .{ .codepoint = 0x1F40, .mapping = &[_]u21{ 0x03BF, 0x0313 } },
+    .{ .codepoint = 0x1F41, .mapping = &[_]u21{ 0x03BF, 0x0314 } },
+    .{ .codepoint = 0x1F42, .mapping = &[_]u21{ 0x1F40, 0x0300 } },
+    .{ .codepoint = 0x1F43, .mapping = &[_]u21{ 0x1F41, 0x0300 } },
+    .{ .codepoint = 0x1F44, .mapping = &[_]u21{ 0x1F40, 0x0301 } },
+    .{ .codepoint = 0x1F45, .mapping = &[_]u21{ 0x1F41, 0x0301 } },
+    .{ .codepoint = 0x1F48, .mapping = &[_]u21{ 0x039F, 0x0313 } },
+    .{ .codepoint = 0x1F49, .mapping = &[_]u21{ 0x039F, 0x0314 } },
+    .{ .codepoint = 0x1F4A, .mapping = &[_]u21{ 0x1F48, 0x0300 } },
+    .{ .codepoint = 0x1F4B, .mapping = &[_]u21{ 0x1F49, 0x0300 } },
+    .{ .codepoint = 0x1F4C, .mapping = &[_]u21{ 0x1F48, 0x0301 } },
+    .{ .codepoint = 0x1F4D, .mapping = &[_]u21{ 0x1F49, 0x0301 } },
+    .{ .codepoint = 0x1F50, .mapping = &[_]u21{ 0x03C5, 0x0313 } },
+    .{ .codepoint = 0x1F51, .mapping = &[_]u21{ 0x03C5, 0x0314 } },
+    .{ .codepoint = 0x1F52, .mapping = &[_]u21{ 0x1F50, 0x0300 } },
+    .{ .codepoint = 0x1F53, .mapping = &[_]u21{ 0x1F51, 0x0300 } },
+    .{ .codepoint = 0x1F54, .mapping = &[_]u21{ 0x1F50, 0x0301 } },
+    .{ .codepoint = 0x1F55, .mapping = &[_]u21{ 0x1F51, 0x0301 } },
+    .{ .codepoint = 0x1F56, .mapping = &[_]u21{ 0x1F50, 0x0342 } },
+    .{ .codepoint = 0x1F57, .mapping = &[_]u21{ 0x1F51, 0x0342 } },
+    .{ .codepoint = 0x1F59, .mapping = &[_]u21{ 0x03A5, 0x0314 } },
+    .{ .codepoint = 0x1F5B, .mapping = &[_]u21{ 0x1F59, 0x0300 } },
+    .{ .codepoint = 0x1F5D, .mapping = &[_]u21{ 0x1F59, 0x0301 } },
+    .{ .codepoint = 0x1F5F, .mapping = &[_]u21{ 0x1F59, 0x0342 } },
+    .{ .codepoint = 0x1F60, .mapping = &[_]u21{ 0x03C9, 0x0313 } },
+    .{ .codepoint = 0x1F61, .mapping = &[_]u21{ 0x03C9, 0x0314 } },
+    .{ .codepoint = 0x1F62, .mapping = &[_]u21{ 0x1F60, 0x0300 } },
+    .{ .codepoint = 0x1F63, .mapping = &[_]u21{ 0x1F61, 0x0300 } },
+    .{ .codepoint = 0x1F64, .mapping = &[_]u21{ 0x1F60, 0x0301 } },
+    .{ .codepoint = 0x1F65, .mapping = &[_]u21{ 0x1F61, 0x0301 } },
+    .{ .codepoint = 0x1F66, .mapping = &[_]u21{ 0x1F60, 0x0342 } },
+    .{ .codepoint = 0x1F67, .mapping = &[_]u21{ 0x1F61, 0x0342 } },
+    .{ .codepoint = 0x1F68, .mapping = &[_]u21{ 0x03A9, 0x0313 } },
+    .{ .codepoint = 0x1F69, .mapping = &[_]u21{ 0x03A9, 0x0314 } },
+    .{ .codepoint = 0x1F6A, .mapping = &[_]u21{ 0x1F68, 0x0300 } },
+    .{ .codepoint = 0x1F6B, .mapping = &[_]u21{ 0x1F69, 0x0300 } },
+    .{ .codepoint = 0x1F6C, .mapping = &[_]u21{ 0x1F68, 0x0301 } },
+    .{ .codepoint = 0x1F6D, .mapping = &[_]u21{ 0x1F69, 0x0301 } },
+    .{ .codepoint = 0x1F6E, .mapping = &[_]u21{ 0x1F68, 0x0342 } },
+    .{ .codepoint = 0x1F6F, .mapping = &[_]u21{ 0x1F69, 0x0342 } },
+    .{ .codepoint = 0x1F70, .mapping = &[_]u21{ 0x03B1, 0x0300 } },
+    .{ .codepoint = 0x1F71, .mapping = &[_]u21{0x03AC} },
+    .{ .codepoint = 0x1F72, .mapping = &[_]u21{ 0x03B5, 0x0300 } },
+    .{ .codepoint = 0x1F73, .mapping = &[_]u21{0x03AD} },
+    .{ .codepoint = 0x1F74, .mapping = &[_]u21{ 0x03B7, 0x0300 } },
+    .{ .codepoint = 0x1F75, .mapping = &[_]u21{0x03AE} },
+    .{ .codepoint = 0x1F76, .mapping = &[_]u21{ 0x03B9, 0x0300 } },
+    .{ .codepoint = 0x1F77, .mapping = &[_]u21{0x03AF} },
+    .{ .codepoint = 0x1F78, .mapping = &[_]u21{ 0x03BF, 0x0300 } },
+    .{ .codepoint = 0x1F79, .mapping = &[_]u21{0x03CC} },
+    .{ .codepoint = 0x1F7A, .mapping = &[_]u21{ 0x03C5, 0x0300 } },
+    .{ .codepoint = 0x1F7B, .mapping = &[_]u21{0x03CD} },
+    .{ .codepoint = 0x1F7C, .mapping = &[_]u21{ 0x03C9, 0x0300 } },
+    .{ .codepoint = 0x1F7D, .mapping = &[_]u21{0x03CE} },
+    .{ .codepoint = 0x1F80, .mapping = &[_]u21{ 0x1F00, 0x0345 } },
+    .{ .codepoint = 0x1F81, .mapping = &[_]u21{ 0x1F01, 0x0345 } },
+    .{ .codepoint = 0x1F82, .mapping = &[_]u21{ 0x1F02, 0x0345 } },
+    .{ .codepoint = 0x1F83, .mapping = &[_]u21{ 0x1F03, 0x0345 } },
+    .{ .codepoint = 0x1F84, .mapping = &[_]u21{ 0x1F04, 0x0345 } },
+    .{ .codepoint = 0x1F85, .mapping = &[_]u21{ 0x1F05, 0x0345 } },
+    .{ .codepoint = 0x1F86, .mapping = &[_]u21{ 0x1F06, 0x0345 } },
+    .{ .codepoint = 0x1F87, .mapping = &[_]u21{ 0x1F07, 0x0345 } },
+    .{ .codepoint = 0x1F88, .mapping = &[_]u21{ 0x1F08, 0x0345 } },
+    .{ .codepoint = 0x1F89, .mapping = &[_]u21{ 0x1F09, 0x0345 } },
+    .{ .codepoint = 0x1F8A, .mapping = &[_]u21{ 0x1F0A, 0x0345 } },
+    .{ .codepoint = 0x1F8B, .mapping = &[_]u21{ 0x1F0B, 0x0345 } },
+    .{ .codepoint = 0x1F8C, .mapping = &[_]u21{ 0x1F0C, 0x0345 } },
+    .{ .codepoint = 0x1F8D, .mapping = &[_]u21{ 0x1F0D, 0x0345 } },
+    .{ .codepoint = 0x1F8E, .mapping = &[_]u21{ 0x1F0E, 0x0345 } },
+    .{ .codepoint = 0x1F8F, .mapping = &[_]u21{ 0x1F0F, 0x0345 } },
+    .{ .codepoint = 0x1F90, .mapping = &[_]u21{ 0x1F20, 0x0345 } },
+    .{ .codepoint = 0x1F91, .mapping = &[_]u21{ 0x1F21, 0x0345 } },
+    .{ .codepoint = 0x1F92, .mapping = &[_]u21{ 0x1F22, 0x0345 } },
+    .{ .codepoint = 0x1F93, .mapping = &[_]u21{ 0x1F23, 0x0345 } },
+    .{ .codepoint = 0x1F94, .mapping = &[_]u21{ 0x1F24, 0x0345 } },
+    .{ .codepoint = 0x1F95, .mapping = &[_]u21{ 0x1F25, 0x0345 } },
+    .{ .codepoint = 0x1F96, .mapping = &[_]u21{ 0x1F26, 0x0345 } },
+    .{ .codepoint = 0x1F97, .mapping = &[_]u21{ 0x1F27, 0x0345 } },
+    .{ .codepoint = 0x1F98, .mapping = &[_]u21{ 0x1F28, 0x0345 } },
+    .{ .codepoint = 0x1F99, .mapping = &[_]u21{ 0x1F29, 0x0345 } },
+    .{ .codepoint = 0x1F9A, .mapping = &[_]u21{ 0x1F2A, 0x0345 } },
+    .{ .codepoint = 0x1F9B, .mapping = &[_]u21{ 0x1F2B, 0x0345 } },
+    .{ .codepoint = 0x1F9C, .mapping = &[_]u21{ 0x1F2C, 0x0345 } },
+    .{ .codepoint = 0x1F9D, .mapping = &[_]u21{ 0x1F2D, 0x0345 } },
+    .{ .codepoint = 0x1F9E, .mapping = &[_]u21{ 0x1F2E, 0x0345 } },
+    .{ .codepoint = 0x1F9F, .mapping = &[_]u21{ 0x1F2F, 0x0345 } },
+    .{ .codepoint = 0x1FA0, .mapping = &[_]u21{ 0x1F60, 0x0345 } },
+    .{ .codepoint = 0x1FA1, .mapping = &[_]u21{ 0x1F61, 0x0345 } },
+    .{ .codepoint = 0x1FA2, .mapping = &[_]u21{ 0x1F62, 0x0345 } },
+    .{ .codepoint = 0x1FA3, .mapping = &[_]u21{ 0x1F63, 0x0345 } },
+    .{ .codepoint = 0x1FA4, .mapping = &[_]u21{ 0x1F64, 0x0345 } },
+    .{ .codepoint = 0x1FA5, .mapping = &[_]u21{ 0x1F65, 0x0345 } },
+    .{ .codepoint = 0x1FA6, .mapping = &[_]u21{ 0x1F66, 0x0345 } },
+    .{ .codepoint = 0x1FA7, .mapping = &[_]u21{ 0x1F67, 0x0345 } },
+    .{ .codepoint = 0x1FA8, .mapping = &[_]u21{ 0x1F68, 0x0345 } },
+    .{ .codepoint = 0x1FA9, .mapping = &[_]u21{ 0x1F69, 0x0345 } },
+    .{ .codepoint = 0x1FAA, .mapping = &[_]u21{ 0x1F6A, 0x0345 } },
+    .{ .codepoint = 0x1FAB, .mapping = &[_]u21{ 0x1F6B, 0x0345 } },
+    .{ .codepoint = 0x1FAC, .mapping = &[_]u21{ 0x1F6C, 0x0345 } },
+    .{ .codepoint = 0x1FAD, .mapping = &[_]u21{ 0x1F6D, 0x0345 } },
+    .{ .codepoint = 0x1FAE, .mapping = &[_]u21{ 0x1F6E, 0x0345 } },
+    .{ .codepoint = 0x1FAF, .mapping = &[_]u21{ 0x1F6F, 0x0345 } },
+    .{ .codepoint = 0x1FB0, .mapping = &[_]u21{ 0x03B1, 0x0306 } },
+    .{ .codepoint = 0x1FB1, .mapping = &[_]u21{ 0x03B1, 0x0304 } },
+    .{ .codepoint = 0x1FB2, .mapping = &[_]u21{ 0x1F70, 0x0345 } },
+    .{ .codepoint = 0x1FB3, .mapping = &[_]u21{ 0x03B1, 0x0345 } },
+    .{ .codepoint = 0x1FB4, .mapping = &[_]u21{ 0x03AC, 0x0345 } },
+    .{ .codepoint = 0x1FB6, .mapping = &[_]u21{ 0x03B1, 0x0342 } },
+    .{ .codepoint = 0x1FB7, .mapping = &[_]u21{ 0x1FB6, 0x0345 } },
+    .{ .codepoint = 0x1FB8, .mapping = &[_]u21{ 0x0391, 0x0306 } },
+    .{ .codepoint = 0x1FB9, .mapping = &[_]u21{ 0x0391, 0x0304 } },
+    .{ .codepoint = 0x1FBA, .mapping = &[_]u21{ 0x0391, 0x0300 } },
+    .{ .codepoint = 0x1FBB, .mapping = &[_]u21{0x0386} },
+    .{ .codepoint = 0x1FBC, .mapping = &[_]u21{ 0x0391, 0x0345 } },
+    .{ .codepoint = 0x1FBE, .mapping = &[_]u21{0x03B9} },
+    .{ .codepoint = 0x1FC1, .mapping = &[_]u21{ 0x00A8, 0x0342 } },
+    .{ .codepoint = 0x1FC2, .mapping = &[_]u21{ 0x1F74, 0x0345 } },
+    .{ .codepoint = 0x1FC3, .mapping = &[_]u21{ 0x03B7, 0x0345 } },
+    .{ .codepoint = 0x1FC4, .mapping = &[_]u21{ 0x03AE, 0x0345 } },
+    .{ .codepoint = 0x1FC6, .mapping = &[_]u21{ 0x03B7, 0x0342 } },
+    .{ .codepoint = 0x1FC7, .mapping = &[_]u21{ 0x1FC6, 0x0345 } },
+    .{ .codepoint = 0x1FC8, .mapping = &[_]u21{ 0x0395, 0x0300 } },
+    .{ .codepoint = 0x1FC9, .mapping = &[_]u21{0x0388} },
+    .{ .codepoint = 0x1FCA, .mapping = &[_]u21{ 0x0397, 0x0300 } },
+    .{ .codepoint = 0x1FCB, .mapping = &[_]u21{0x0389} },
+    .{ .codepoint = 0x1FCC, .mapping = &[_]u21{ 0x0397, 0x0345 } },
+    .{ .codepoint = 0x1FCD, .mapping = &[_]u21{ 0x1FBF, 0x0300 } },
+    .{ .codepoint = 0x1FCE, .mapping = &[_]u21{ 0x1FBF, 0x0301 } },
+    .{ .codepoint = 0x1FCF, .mapping = &[_]u21{ 0x1FBF, 0x0342 } },
+    .{ .codepoint = 0x1FD0, .mapping = &[_]u21{ 0x03B9, 0x0306 } },
+    .{ .codepoint = 0x1FD1, .mapping = &[_]u21{ 0x03B9, 0x0304 } },
+    .{ .codepoint = 0x1FD2, .mapping = &[_]u21{ 0x03CA, 0x0300 } },
+    .{ .codepoint = 0x1FD3, .mapping = &[_]u21{0x0390} },
+    .{ .codepoint = 0x1FD6, .mapping = &[_]u21{ 0x03B9, 0x0342 } },
+    .{ .codepoint = 0x1FD7, .mapping = &[_]u21{ 0x03CA, 0x0342 } },
+    .{ .codepoint = 0x1FD8, .mapping = &[_]u21{ 0x0399, 0x0306 } },
+    .{ .codepoint = 0x1FD9, .mapping = &[_]u21{ 0x0399, 0x0304 } },
+    .{ .codepoint = 0x1FDA, .mapping = &[_]u21{ 0x0399, 0x0300 } },
+    .{ .codepoint = 0x1FDB, .mapping = &[_]u21{0x038A} },
+    .{ .codepoint = 0x1FDD, .mapping = &[_]u21{ 0x1FFE, 0x0300 } },
+    .{ .codepoint = 0x1FDE, .mapping = &[_]u21{ 0x1FFE, 0x0301 } },
+    .{ .codepoint = 0x1FDF, .mapping = &[_]u21{ 0x1FFE, 0x0342 } },
+    .{ .codepoint = 0x1FE0, .mapping = &[_]u21{ 0x03C5, 0x0306 } },
+    .{ .codepoint = 0x1FE1, .mapping = &[_]u21{ 0x03C5, 0x0304 } },
+    .{ .codepoint = 0x1FE2, .mapping = &[_]u21{ 0x03CB, 0x0300 } },
+    .{ .codepoint = 0x1FE3, .mapping = &[_]u21{0x03B0} },
+    .{ .codepoint = 0x1FE4, .mapping = &[_]u21{ 0x03C1, 0x0313 } },
+    .{ .codepoint = 0x1FE5, .mapping = &[_]u21{ 0x03C1, 0x0314 } },
+    .{ .codepoint = 0x1FE6, .mapping = &[_]u21{ 0x03C5, 0x0342 } },
+    .{ .codepoint = 0x1FE7, .mapping = &[_]u21{ 0x03CB, 0x0342 } },
+    .{ .codepoint = 0x1FE8, .mapping = &[_]u21{ 0x03A5, 0x0306 } },
+    .{ .codepoint = 0x1FE9, .mapping = &[_]u21{ 0x03A5, 0x0304 } },
+    .{ .codepoint = 0x1FEA, .mapping = &[_]u21{ 0x03A5, 0x0300 } },
+    .{ .codepoint = 0x1FEB, .mapping = &[_]u21{0x038E} },
+    .{ .codepoint = 0x1FEC, .mapping = &[_]u21{ 0x03A1, 0x0314 } },
+    .{ .codepoint = 0x1FED, .mapping = &[_]u21{ 0x00A8, 0x0300 } },
+    .{ .codepoint = 0x1FEE, .mapping = &[_]u21{0x0385} },
+    .{ .codepoint = 0x1FEF, .mapping = &[_]u21{0x0060} },
+    .{ .codepoint = 0x1FF2, .mapping = &[_]u21{ 0x1F7C, 0x0345 } },
+    .{ .codepoint = 0x1FF3, .mapping = &[_]u21{ 0x03C9, 0x0345 } },
+    .{ .codepoint = 0x1FF4, .mapping = &[_]u21{ 0x03CE, 0x0345 } },
+    .{ .codepoint = 0x1FF6, .mapping = &[_]u21{ 0x03C9, 0x0342 } },
+    .{ .codepoint = 0x1FF7, .mapping = &[_]u21{ 0x1FF6, 0x0345 } },
+    .{ .codepoint = 0x1FF8, .mapping = &[_]u21{ 0x039F, 0x0300 } },
+    .{ .codepoint = 0x1FF9, .mapping = &[_]u21{0x038C} },
+    .{ .codepoint = 0x1FFA, .mapping = &[_]u21{ 0x03A9, 0x0300 } },
+    .{ .codepoint = 0x1FFB, .mapping = &[_]u21{0x038F} },
+    .{ .codepoint = 0x1FFC, .mapping = &[_]u21{ 0x03A9, 0x0345 } },
+    .{ .codepoint = 0x1FFD, .mapping = &[_]u21{0x00B4} },
+    .{ .codepoint = 0x2000, .mapping = &[_]u21{0x2002} },
+    .{ .codepoint = 0x2001, .mapping = &[_]u21{0x2003} },
+    .{ .codepoint = 0x2126, .mapping = &[_]u21{0x03A9} },
+    .{ .codepoint = 0x212A, .mapping = &[_]u21{0x004B} },
+    .{ .codepoint = 0x212B, .mapping = &[_]u21{0x00C5} },
+    .{ .codepoint = 0x219A, .mapping = &[_]u21{ 0x2190, 0x0338 } },
+    .{ .codepoint = 0x219B, .mapping = &[_]u21{ 0x2192, 0x0338 } },
+    .{ .codepoint = 0x21AE, .mapping = &[_]u21{ 0x2194, 0x0338 } },
+    .{ .codepoint = 0x21CD, .mapping = &[_]u21{ 0x21D0, 0x0338 } },
+    .{ .codepoint = 0x21CE, .mapping = &[_]u21{ 0x21D4, 0x0338 } },
+    .{ .codepoint = 0x21CF, .mapping = &[_]u21{ 0x21D2, 0x0338 } },
+    .{ .codepoint = 0x2204, .mapping = &[_]u21{ 0x2203, 0x0338 } },
+    .{ .codepoint = 0x2209, .mapping = &[_]u21{ 0x2208, 0x0338 } },
+    .{ .codepoint = 0x220C, .mapping = &[_]u21{ 0x220B, 0x0338 } },
+    .{ .codepoint = 0x2224, .mapping = &[_]u21{ 0x2223, 0x0338 } },
+    .{ .codepoint = 0x2226, .mapping = &[_]u21{ 0x2225, 0x0338 } },
+    .{ .codepoint = 0x2241, .mapping = &[_]u21{ 0x223C, 0x0338 } },
+    .{ .codepoint = 0x2244, .mapping = &[_]u21{ 0x2243, 0x0338 } },
+    .{ .codepoint = 0x2247, .mapping = &[_]u21{ 0x2245, 0x0338 } },
+    .{ .codepoint = 0x2249, .mapping = &[_]u21{ 0x2248, 0x0338 } },
+    .{ .codepoint = 0x2260, .mapping = &[_]u21{ 0x003D, 0x0338 } },
+    .{ .codepoint = 0x2262, .mapping = &[_]u21{ 0x2261, 0x0338 } },
+    .{ .codepoint = 0x226D, .mapping = &[_]u21{ 0x224D, 0x0338 } },
+    .{ .codepoint = 0x226E, .mapping = &[_]u21{ 0x003C, 0x0338 } },
+    .{ .codepoint = 0x226F, .mapping = &[_]u21{ 0x003E, 0x0338 } },
+    .{ .codepoint = 0x2270, .mapping = &[_]u21{ 0x2264, 0x0338 } },
+    .{ .codepoint = 0x2271, .mapping = &[_]u21{ 0x2265, 0x0338 } },
+    .{ .codepoint = 0x2274, .mapping = &[_]u21{ 0x2272, 0x0338 } },
+    .{ .codepoint = 0x2275, .mapping = &[_]u21{ 0x2273, 0x0338 } },
+    .{ .codepoint = 0x2278, .mapping = &[_]u21{ 0x2276, 0x0338 } },
+    .{ .codepoint = 0x2279, .mapping = &[_]u21{ 0x2277, 0x0338 } },
+    .{ .codepoint = 0x2280, .mapping = &[_]u21{ 0x227A, 0x0338 } },
+    .{ .codepoint = 0x2281, .mapping = &[_]u21{ 0x227B, 0x0338 } },
+    .{ .codepoint = 0x2284, .mapping = &[_]u21{ 0x2282, 0x0338 } },
+    .{ .codepoint = 0x2285, .mapping = &[_]u21{ 0x2283, 0x0338 } },
+    .{ .codepoint = 0x2288, .mapping = &[_]u21{ 0x2286, 0x0338 } },
+    .{ .codepoint = 0x2289, .mapping = &[_]u21{ 0x2287, 0x0338 } },
+    .{ .codepoint = 0x22AC, .mapping = &[_]u21{ 0x22A2, 0x0338 } },
+    .{ .codepoint = 0x22AD, .mapping = &[_]u21{ 0x22A8, 0x0338 } },
+    .{ .codepoint = 0x22AE, .mapping = &[_]u21{ 0x22A9, 0x0338 } },
+    .{ .codepoint = 0x22AF, .mapping = &[_]u21{ 0x22AB, 0x0338 } },
+    .{ .codepoint = 0x22E0, .mapping = &[_]u21{ 0x227C, 0x0338 } },
+    .{ .codepoint = 0x22E1, .mapping = &[_]u21{ 0x227D, 0x0338 } },
+    .{ .codepoint = 0x22E2, .mapping = &[_]u21{ 0x2291, 0x0338 } },
+    .{ .codepoint = 0x22E3, .mapping = &[_]u21{ 0x2292, 0x0338 } },
+    .{ .codepoint = 0x22EA, .mapping = &[_]u21{ 0x22B2, 0x0338 } },
+    .{ .codepoint = 0x22EB, .mapping = &[_]u21{ 0x22B3, 0x0338 } },
+    .{ .codepoint = 0x22EC, .mapping = &[_]u21{ 0x22B4, 0x0338 } },
+    .{ .codepoint = 0x22ED, .mapping = &[_]u21{ 0x22B5, 0x0338 } },
+    .{ .codepoint = 0x2329, .mapping = &[_]u21{0x3008} },
+    .{ .codepoint = 0x232A, .mapping = &[_]u21{0x3009} },
+    .{ .codepoint = 0x2ADC, .mapping = &[_]u21{ 0x2ADD, 0x0338 } },
+    .{ .codepoint = 0x304C, .mapping = &[_]u21{ 0x304B, 0x3099 } },
+    .{ .codepoint = 0x304E, .mapping = &[_]u21{ 0x304D, 0x3099 } },
+    .{ .codepoint = 0x3050, .mapping = &[_]u21{ 0x304F, 0x3099 } },
+    .{ .codepoint = 0x3052, .mapping = &[_]u21{ 0x3051, 0x3099 } },
+    .{ .codepoint = 0x3054, .mapping = &[_]u21{ 0x3053, 0x3099 } },
+    .{ .codepoint = 0x3056, .mapping = &[_]u21{ 0x3055, 0x3099 } },
+    .{ .codepoint = 0x3058, .mapping = &[_]u21{ 0x3057, 0x3099 } },
+    .{ .codepoint = 0x305A, .mapping = &[_]u21{ 0x3059, 0x3099 } },
+    .{ .codepoint = 0x305C, .mapping = &[_]u21{ 0x305B, 0x3099 } },
+    .{ .codepoint = 0x305E, .mapping = &[_]u21{ 0x305D, 0x3099 } },
+    .{ .codepoint = 0x3060, .mapping = &[_]u21{ 0x305F, 0x3099 } },
+    .{ .codepoint = 0x3062, .mapping = &[_]u21{ 0x3061, 0x3099 } },
+    .{ .codepoint = 0x3065, .mapping = &[_]u21{ 0x3064, 0x3099 } },
+    .{ .codepoint = 0x3067, .mapping = &[_]u21{ 0x3066, 0x3099 } },
+    .{ .codepoint = 0x3069, .mapping = &[_]u21{ 0x3068, 0x3099 } },
+    .{ .codepoint = 0x3070, .mapping = &[_]u21{ 0x306F, 0x3099 } },
+    .{ .codepoint = 0x3071, .mapping = &[_]u21{ 0x306F, 0x309A } },
+    .{ .codepoint = 0x3073, .mapping = &[_]u21{ 0x3072, 0x3099 } },
+    .{ .codepoint = 0x3074, .mapping = &[_]u21{ 0x3072, 0x309A } },
+    .{ .codepoint = 0x3076, .mapping = &[_]u21{ 0x3075, 0x3099 } },
+    .{ .codepoint = 0x3077, .mapping = &[_]u21{ 0x3075, 0x309A } },
+    .{ .codepoint = 0x3079, .mapping = &[_]u21{ 0x3078, 0x3099 } },
+    .{ .codepoint = 0x307A, .mapping = &[_]u21{ 0x3078, 0x309A } },
+    .{ .codepoint = 0x307C, .mapping = &[_]u21{ 0x307B, 0x3099 } },
+    .{ .codepoint = 0x307D, .mapping = &[_]u21{ 0x307B, 0x309A } },
+    .{ .codepoint = 0x3094, .mapping = &[_]u21{ 0x3046, 0x3099 } },
+    .{ .codepoint = 0x309E, .mapping = &[_]u21{ 0x309D, 0x3099 } },
+    .{ .codepoint = 0x30AC, .mapping = &[_]u21{ 0x30AB, 0x3099 } },
+    .{ .codepoint = 0x30AE, .mapping = &[_]u21{ 0x30AD, 0x3099 } },
+    .{ .codepoint = 0x30B0, .mapping = &[_]u21{ 0x30AF, 0x3099 } },
+    .{ .codepoint = 0x30B2, .mapping = &[_]u21{ 0x30B1, 0x3099 } },
+    .{ .codepoint = 0x30B4, .mapping = &[_]u21{ 0x30B3, 0x3099 } },
+    .{ .codepoint = 0x30B6, .mapping = &[_]u21{ 0x30B5, 0x3099 } },
+    .{ .codepoint = 0x30B8, .mapping = &[_]u21{ 0x30B7, 0x3099 } },
+    .{ .codepoint = 0x30BA, .mapping = &[_]u21{ 0x30B9, 0x3099 } },
+    .{ .codepoint = 0x30BC, .mapping = &[_]u21{ 0x30BB, 0x3099 } },
+    .{ .codepoint = 0x30BE, .mapping = &[_]u21{ 0x30BD, 0x3099 } },
+    .{ .codepoint = 0x30C0, .mapping = &[_]u21{ 0x30BF, 0x3099 } },
+    .{ .codepoint = 0x30C2, .mapping = &[_]u21{ 0x30C1, 0x3099 } },
+    .{ .codepoint = 0x30C5, .mapping = &[_]u21{ 0x30C4, 0x3099 } },
+    .{ .codepoint = 0x30C7, .mapping = &[_]u21{ 0x30C6, 0x3099 } },
+    .{ .codepoint = 0x30C9, .mapping = &[_]u21{ 0x30C8, 0x3099 } },
+    .{ .codepoint = 0x30D0, .mapping = &[_]u21{ 0x30CF, 0x3099 } },
+    .{ .codepoint = 0x30D1, .mapping = &[_]u21{ 0x30CF, 0x309A } },
+    .{ .codepoint = 0x30D3, .mapping = &[_]u21{ 0x30D2, 0x3099 } },
+    .{ .codepoint = 0x30D4, .mapping = &[_]u21{ 0x30D2, 0x309A } },
+    .{ .codepoint = 0x30D6, .mapping = &[_]u21{ 0x30D5, 0x3099 } },
+    .{ .codepoint = 0x30D7, .mapping = &[_]u21{ 0x30D5, 0x309A } },
+    .{ .codepoint = 0x30D9, .mapping = &[_]u21{ 0x30D8, 0x3099 } },
+    .{ .codepoint = 0x30DA, .mapping = &[_]u21{ 0x30D8, 0x309A } },
+    .{ .codepoint = 0x30DC, .mapping = &[_]u21{ 0x30DB, 0x3099 } },
+    .{ .codepoint = 0x30DD, .mapping = &[_]u21{ 0x30DB, 0x309A } },
+    .{ .codepoint = 0x30F4, .mapping = &[_]u21{ 0x30A6, 0x3099 } },
+    .{ .codepoint = 0x30F7, .mapping = &[_]u21{ 0x30EF, 0x3099 } },
+    .{ .codepoint = 0x30F8, .mapping = &[_]u21{ 0x30F0, 0x3099 } },
+    .{ .codepoint = 0x30F9, .mapping = &[_]u21{ 0x30F1, 0x3099 } },
+    .{ .codepoint = 0x30FA, .mapping = &[_]u21{ 0x30F2, 0x3099 } },
+    .{ .codepoint = 0x30FE, .mapping = &[_]u21{ 0x30FD, 0x3099 } },
+    .{ .codepoint = 0xF900, .mapping = &[_]u21{0x8C48} },
+    .{ .codepoint = 0xF901, .mapping = &[_]u21{0x66F4} },
+    .{ .codepoint = 0xF902, .mapping = &[_]u21{0x8ECA} },
+    .{ .codepoint = 0xF903, .mapping = &[_]u21{0x8CC8} },
+    .{ .codepoint = 0xF904, .mapping = &[_]u21{0x6ED1} },
+    .{ .codepoint = 0xF905, .mapping = &[_]u21{0x4E32} },
+    .{ .codepoint = 0xF906, .mapping = &[_]u21{0x53E5} },
+    .{ .codepoint = 0xF907, .mapping = &[_]u21{0x9F9C} },
+    .{ .codepoint = 0xF908, .mapping = &[_]u21{0x9F9C} },
+    .{ .codepoint = 0xF909, .mapping = &[_]u21{0x5951} },
+    .{ .codepoint = 0xF90A, .mapping = &[_]u21{0x91D1} },
+    .{ .codepoint = 0xF90B, .mapping = &[_]u21{0x5587} },
+    .{ .codepoint = 0xF90C, .mapping = &[_]u21{0x5948} },
+    .{ .codepoint = 0xF90D, .mapping = &[_]u21{0x61F6} },
+    .{ .codepoint = 0xF90E, .mapping = &[_]u21{0x7669} },
+    .{ .codepoint = 0xF90F, .mapping = &[_]u21{0x7F85} },
+    .{ .codepoint = 0xF910, .mapping = &[_]u21{0x863F} },
+    .{ .codepoint = 0xF911, .mapping = &[_]u21{0x87BA} },
+    .{ .codepoint = 0xF912, .mapping = &[_]u21{0x88F8} },
+    .{ .codepoint = 0xF913, .mapping = &[_]u21{0x908F} },
+    .{ .codepoint = 0xF914, .mapping = &[_]u21{0x6A02} },
+    .{ .codepoint = 0xF915, .mapping = &[_]u21{0x6D1B} },
+    .{ .codepoint = 0xF916, .mapping = &[_]u21{0x70D9} },
+    .{ .codepoint = 0xF917, .mapping = &[_]u21{0x73DE} },
+    .{ .codepoint = 0xF918, .mapping = &[_]u21{0x843D} },
+    .{ .codepoint = 0xF919, .mapping = &[_]u21{0x916A} },
+    .{ .codepoint = 0xF91A, .mapping = &[_]u21{0x99F1} },
+    .{ .codepoint = 0xF91B, .mapping = &[_]u21{0x4E82} },
+    .{ .codepoint = 0xF91C, .mapping = &[_]u21{0x5375} },
+    .{ .codepoint = 0xF91D, .mapping = &[_]u21{0x6B04} },
+    .{ .codepoint = 0xF91E, .mapping = &[_]u21{0x721B} },
+    .{ .codepoint = 0xF91F, .mapping = &[_]u21{0x862D} },
+    .{ .codepoint = 0xF920, .mapping = &[_]u21{0x9E1E} },
+    .{ .codepoint = 0xF921, .mapping = &[_]u21{0x5D50} },
+    .{ .codepoint = 0xF922, .mapping = &[_]u21{0x6FEB} },
+    .{ .codepoint = 0xF923, .mapping = &[_]u21{0x85CD} },
+    .{ .codepoint = 0xF924, .mapping = &[_]u21{0x8964} },
+    .{ .codepoint = 0xF925, .mapping = &[_]u21{0x62C9} },
+    .{ .codepoint = 0xF926, .mapping = &[_]u21{0x81D8} },
+    .{ .codepoint = 0xF927, .mapping = &[_]u21{0x881F} },
+    .{ .codepoint = 0xF928, .mapping = &[_]u21{0x5ECA} },
+    .{ .codepoint = 0xF929, .mapping = &[_]u21{0x6717} },
+    .{ .codepoint = 0xF92A, .mapping = &[_]u21{0x6D6A} },
+    .{ .codepoint = 0xF92B, .mapping = &[_]u21{0x72FC} },
+    .{ .codepoint = 0xF92C, .mapping = &[_]u21{0x90CE} },
+    .{ .codepoint = 0xF92D, .mapping = &[_]u21{0x4F86} },
+    .{ .codepoint = 0xF92E, .mapping = &[_]u21{0x51B7} },
+    .{ .codepoint = 0xF92F, .mapping = &[_]u21{0x52DE} },
+    .{ .codepoint = 0xF930, .mapping = &[_]u21{0x64C4} },
+    .{ .codepoint = 0xF931, .mapping = &[_]u21{0x6AD3} },
+    .{ .codepoint = 0xF932, .mapping = &[_]u21{0x7210} },
+    .{ .codepoint = 0xF933, .mapping = &[_]u21{0x76E7} },
+    .{ .codepoint = 0xF934, .mapping = &[_]u21{0x8001} },
+    .{ .codepoint = 0xF935, .mapping = &[_]u21{0x8606} },
+    .{ .codepoint = 0xF936, .mapping = &[_]u21{0x865C} },
+    .{ .codepoint = 0xF937, .mapping = &[_]u21{0x8DEF} },
+    .{ .codepoint = 0xF938, .mapping = &[_]u21{0x9732} },
+    .{ .codepoint = 0xF939, .mapping = &[_]u21{0x9B6F} },
+    .{ .codepoint = 0xF93A, .mapping = &[_]u21{0x9DFA} },
+    .{ .codepoint = 0xF93B, .mapping = &[_]u21{0x788C} },
+    .{ .codepoint = 0xF93C, .mapping = &[_]u21{0x797F} },
+    .{ .codepoint = 0xF93D, .mapping = &[_]u21{0x7DA0} },
+    .{ .codepoint = 0xF93E, .mapping = &[_]u21{0x83C9} },
+    .{ .codepoint = 0xF93F, .mapping = &[_]u21{0x9304} },
+    .{ .codepoint = 0xF940, .mapping = &[_]u21{0x9E7F} },
+    .{ .codepoint = 0xF941, .mapping = &[_]u21{0x8AD6} },
+    .{ .codepoint = 0xF942, .mapping = &[_]u21{0x58DF} },
+    .{ .codepoint = 0xF943, .mapping = &[_]u21{0x5F04} },
+    .{ .codepoint = 0xF944, .mapping = &[_]u21{0x7C60} },
+    .{ .codepoint = 0xF945, .mapping = &[_]u21{0x807E} },
+    .{ .codepoint = 0xF946, .mapping = &[_]u21{0x7262} },
+    .{ .codepoint = 0xF947, .mapping = &[_]u21{0x78CA} },
+    .{ .codepoint = 0xF948, .mapping = &[_]u21{0x8CC2} },
+    .{ .codepoint = 0xF949, .mapping = &[_]u21{0x96F7} },
+    .{ .codepoint = 0xF94A, .mapping = &[_]u21{0x58D8} },
+    .{ .codepoint = 0xF94B, .mapping = &[_]u21{0x5C62} },
+    .{ .codepoint = 0xF94C, .mapping = &[_]u21{0x6A13} },
+    .{ .codepoint = 0xF94D, .mapping = &[_]u21{0x6DDA} },
+    .{ .codepoint = 0xF94E, .mapping = &[_]u21{0x6F0F} },
+    .{ .codepoint = 0xF94F, .mapping = &[_]u21{0x7D2F} },
+    .{ .codepoint = 0xF950, .mapping = &[_]u21{0x7E37} },
+    .{ .codepoint = 0xF951, .mapping = &[_]u21{0x964B} },
+    .{ .codepoint = 0xF952, .mapping = &[_]u21{0x52D2} },
+    .{ .codepoint = 0xF953, .mapping = &[_]u21{0x808B} },
+    .{ .codepoint = 0xF954, .mapping = &[_]u21{0x51DC} },
+    .{ .codepoint = 0xF955, .mapping = &[_]u21{0x51CC} },
+    .{ .codepoint = 0xF956, .mapping = &[_]u21{0x7A1C} },
+    .{ .codepoint = 0xF957, .mapping = &[_]u21{0x7DBE} },
+    .{ .codepoint = 0xF958, .mapping = &[_]u21{0x83F1} },
+    .{ .codepoint = 0xF959, .mapping = &[_]u21{0x9675} },
+    .{ .codepoint = 0xF95A, .mapping = &[_]u21{0x8B80} },
+    .{ .codepoint = 0xF95B, .mapping = &[_]u21{0x62CF} },
+    .{ .codepoint = 0xF95C, .mapping = &[_]u21{0x6A02} },
+    .{ .codepoint = 0xF95D, .mapping = &[_]u21{0x8AFE} },
+    .{ .codepoint = 0xF95E, .mapping = &[_]u21{0x4E39} },
+    .{ .codepoint = 0xF95F, .mapping = &[_]u21{0x5BE7} },
+    .{ .codepoint = 0xF960, .mapping = &[_]u21{0x6012} },
+    .{ .codepoint = 0xF961, .mapping = &[_]u21{0x7387} },
+    .{ .codepoint = 0xF962, .mapping = &[_]u21{0x7570} },
+    .{ .codepoint = 0xF963, .mapping = &[_]u21{0x5317} },
+    .{ .codepoint = 0xF964, .mapping = &[_]u21{0x78FB} },
+    .{ .codepoint = 0xF965, .mapping = &[_]u21{0x4FBF} },
+    .{ .codepoint = 0xF966, .mapping = &[_]u21{0x5FA9} },
+    .{ .codepoint = 0xF967, .mapping = &[_]u21{0x4E0D} },
+    .{ .codepoint = 0xF968, .mapping = &[_]u21{0x6CCC} },
+    .{ .codepoint = 0xF969, .mapping = &[_]u21{0x6578} },
+    .{ .codepoint = 0xF96A, .mapping = &[_]u21{0x7D22} },
+    .{ .codepoint = 0xF96B, .mapping = &[_]u21{0x53C3} },
+    .{ .codepoint = 0xF96C, .mapping = &[_]u21{0x585E} },
+    .{ .codepoint = 0xF96D, .mapping = &[_]u21{0x7701} },
+    .{ .codepoint = 0xF96E, .mapping = &[_]u21{0x8449} },
+    .{ .codepoint = 0xF96F, .mapping = &[_]u21{0x8AAA} },
+    .{ .codepoint = 0xF970, .mapping = &[_]u21{0x6BBA} },
+    .{ .codepoint = 0xF971, .mapping = &[_]u21{0x8FB0} },
+    .{ .codepoint = 0xF972, .mapping = &[_]u21{0x6C88} },
+    .{ .codepoint = 0xF973, .mapping = &[_]u21{0x62FE} },
+    .{ .codepoint = 0xF974, .mapping = &[_]u21{0x82E5} },
+    .{ .codepoint = 0xF975, .mapping = &[_]u21{0x63A0} },
+    .{ .codepoint = 0xF976, .mapping = &[_]u21{0x7565} },
+    .{ .codepoint = 0xF977, .mapping = &[_]u21{0x4EAE} },
+    .{ .codepoint = 0xF978, .mapping = &[_]u21{0x5169} },
+    .{ .codepoint = 0xF979, .mapping = &[_]u21{0x51C9} },
+    .{ .codepoint = 0xF97A, .mapping = &[_]u21{0x6881} },
+    .{ .codepoint = 0xF97B, .mapping = &[_]u21{0x7CE7} },
+    .{ .codepoint = 0xF97C, .mapping = &[_]u21{0x826F} },
+    .{ .codepoint = 0xF97D, .mapping = &[_]u21{0x8AD2} },
+    .{ .codepoint = 0xF97E, .mapping = &[_]u21{0x91CF} },
+    .{ .codepoint = 0xF97F, .mapping = &[_]u21{0x52F5} },
+    .{ .codepoint = 0xF980, .mapping = &[_]u21{0x5442} },
+    .{ .codepoint = 0xF981, .mapping = &[_]u21{0x5973} },
+    .{ .codepoint = 0xF982, .mapping = &[_]u21{0x5EEC} },
+    .{ .codepoint = 0xF983, .mapping = &[_]u21{0x65C5} },
+    .{ .codepoint = 0xF984, .mapping = &[_]u21{0x6FFE} },
+    .{ .codepoint = 0xF985, .mapping = &[_]u21{0x792A} },
+    .{ .codepoint = 0xF986, .mapping = &[_]u21{0x95AD} },
+    .{ .codepoint = 0xF987, .mapping = &[_]u21{0x9A6A} },
+    .{ .codepoint = 0xF988, .mapping = &[_]u21{0x9E97} },
+    .{ .codepoint = 0xF989, .mapping = &[_]u21{0x9ECE} },
+    .{ .codepoint = 0xF98A, .mapping = &[_]u21{0x529B} },
+    .{ .codepoint = 0xF98B, .mapping = &[_]u21{0x66C6} },
+    .{ .codepoint = 0xF98C, .mapping = &[_]u21{0x6B77} },
+    .{ .codepoint = 0xF98D, .mapping = &[_]u21{0x8F62} },
+    .{ .codepoint = 0xF98E, .mapping = &[_]u21{0x5E74} },
+    .{ .codepoint = 0xF98F, .mapping = &[_]u21{0x6190} },
+    .{ .codepoint = 0xF990, .mapping = &[_]u21{0x6200} },
+    .{ .codepoint = 0xF991, .mapping = &[_]u21{0x649A} },
+    .{ .codepoint = 0xF992, .mapping = &[_]u21{0x6F23} },
+    .{ .codepoint = 0xF993, .mapping = &[_]u21{0x7149} },
+    .{ .codepoint = 0xF994, .mapping = &[_]u21{0x7489} },
+    .{ .codepoint = 0xF995, .mapping = &[_]u21{0x79CA} },
+    .{ .codepoint = 0xF996, .mapping = &[_]u21{0x7DF4} },
+    .{ .codepoint = 0xF997, .mapping = &[_]u21{0x806F} },
+    .{ .codepoint = 0xF998, .mapping = &[_]u21{0x8F26} },
+    .{ .codepoint = 0xF999, .mapping = &[_]u21{0x84EE} },
+    .{ .codepoint = 0xF99A, .mapping = &[_]u21{0x9023} },
+    .{ .codepoint = 0xF99B, .mapping = &[_]u21{0x934A} },
+    .{ .codepoint = 0xF99C, .mapping = &[_]u21{0x5217} },
+    .{ .codepoint = 0xF99D, .mapping = &[_]u21{0x52A3} },
+    .{ .codepoint = 0xF99E, .mapping = &[_]u21{0x54BD} },
+    .{ .codepoint = 0xF99F, .mapping = &[_]u21{0x70C8} },
+    .{ .codepoint = 0xF9A0, .mapping = &[_]u21{0x88C2} },
+    .{ .codepoint = 0xF9A1, .mapping = &[_]u21{0x8AAA} },
+    .{ .codepoint = 0xF9A2, .mapping = &[_]u21{0x5EC9} },
+    .{ .codepoint = 0xF9A3, .mapping = &[_]u21{0x5FF5} },
+    .{ .codepoint = 0xF9A4, .mapping = &[_]u21{0x637B} },
+    .{ .codepoint = 0xF9A5, .mapping = &[_]u21{0x6BAE} },
+    .{ .codepoint = 0xF9A6, .mapping = &[_]u21{0x7C3E} },
+    .{ .codepoint = 0xF9A7, .mapping = &[_]u21{0x7375} },
+    .{ .codepoint = 0xF9A8, .mapping = &[_]u21{0x4EE4} },
+    .{ .codepoint = 0xF9A9, .mapping = &[_]u21{0x56F9} },
+    .{ .codepoint = 0xF9AA, .mapping = &[_]u21{0x5BE7} },
+    .{ .codepoint = 0xF9AB, .mapping = &[_]u21{0x5DBA} },
+    .{ .codepoint = 0xF9AC, .mapping = &[_]u21{0x601C} },
+    .{ .codepoint = 0xF9AD, .mapping = &[_]u21{0x73B2} },
+    .{ .codepoint = 0xF9AE, .mapping = &[_]u21{0x7469} },
+    .{ .codepoint = 0xF9AF, .mapping = &[_]u21{0x7F9A} },
+    .{ .codepoint = 0xF9B0, .mapping = &[_]u21{0x8046} },
+    .{ .codepoint = 0xF9B1, .mapping = &[_]u21{0x9234} },
+    .{ .codepoint = 0xF9B2, .mapping = &[_]u21{0x96F6} },
+    .{ .codepoint = 0xF9B3, .mapping = &[_]u21{0x9748} },
+    .{ .codepoint = 0xF9B4, .mapping = &[_]u21{0x9818} },
+    .{ .codepoint = 0xF9B5, .mapping = &[_]u21{0x4F8B} },
+    .{ .codepoint = 0xF9B6, .mapping = &[_]u21{0x79AE} },
+    .{ .codepoint = 0xF9B7, .mapping = &[_]u21{0x91B4} },
+    .{ .codepoint = 0xF9B8, .mapping = &[_]u21{0x96B8} },
+    .{ .codepoint = 0xF9B9, .mapping = &[_]u21{0x60E1} },
+    .{ .codepoint = 0xF9BA, .mapping = &[_]u21{0x4E86} },
+    .{ .codepoint = 0xF9BB, .mapping = &[_]u21{0x50DA} },
+    .{ .codepoint = 0xF9BC, .mapping = &[_]u21{0x5BEE} },
+    .{ .codepoint = 0xF9BD, .mapping = &[_]u21{0x5C3F} },
+    .{ .codepoint = 0xF9BE, .mapping = &[_]u21{0x6599} },
+    .{ .codepoint = 0xF9BF, .mapping = &[_]u21{0x6A02} },
+    .{ .codepoint = 0xF9C0, .mapping = &[_]u21{0x71CE} },
+    .{ .codepoint = 0xF9C1, .mapping = &[_]u21{0x7642} },
+    .{ .codepoint = 0xF9C2, .mapping = &[_]u21{0x84FC} },
+    .{ .codepoint = 0xF9C3, .mapping = &[_]u21{0x907C} },
+    .{ .codepoint = 0xF9C4, .mapping = &[_]u21{0x9F8D} },
+    .{ .codepoint = 0xF9C5, .mapping = &[_]u21{0x6688} },
+    .{ .codepoint = 0xF9C6, .mapping = &[_]u21{0x962E} },
+    .{ .codepoint = 0xF9C7, .mapping = &[_]u21{0x5289} },
+    .{ .codepoint = 0xF9C8, .mapping = &[_]u21{0x677B} },
+    .{ .codepoint = 0xF9C9, .mapping = &[_]u21{0x67F3} },
+    .{ .codepoint = 0xF9CA, .mapping = &[_]u21{0x6D41} },
+    .{ .codepoint = 0xF9CB, .mapping = &[_]u21{0x6E9C} },
+    .{ .codepoint = 0xF9CC, .mapping = &[_]u21{0x7409} },
+    .{ .codepoint = 0xF9CD, .mapping = &[_]u21{0x7559} },
+    .{ .codepoint = 0xF9CE, .mapping = &[_]u21{0x786B} },
+    .{ .codepoint = 0xF9CF, .mapping = &[_]u21{0x7D10} },
+    .{ .codepoint = 0xF9D0, .mapping = &[_]u21{0x985E} },
+    .{ .codepoint = 0xF9D1, .mapping = &[_]u21{0x516D} },
+    .{ .codepoint = 0xF9D2, .mapping = &[_]u21{0x622E} },
+    .{ .codepoint = 0xF9D3, .mapping = &[_]u21{0x9678} },
+    .{ .codepoint = 0xF9D4, .mapping = &[_]u21{0x502B} },
+    .{ .codepoint = 0xF9D5, .mapping = &[_]u21{0x5D19} },
+    .{ .codepoint = 0xF9D6, .mapping = &[_]u21{0x6DEA} },
+    .{ .codepoint = 0xF9D7, .mapping = &[_]u21{0x8F2A} },
+    .{ .codepoint = 0xF9D8, .mapping = &[_]u21{0x5F8B} },
+    .{ .codepoint = 0xF9D9, .mapping = &[_]u21{0x6144} },
+    .{ .codepoint = 0xF9DA, .mapping = &[_]u21{0x6817} },
+    .{ .codepoint = 0xF9DB, .mapping = &[_]u21{0x7387} },
+    .{ .codepoint = 0xF9DC, .mapping = &[_]u21{0x9686} },
+    .{ .codepoint = 0xF9DD, .mapping = &[_]u21{0x5229} },
+    .{ .codepoint = 0xF9DE, .mapping = &[_]u21{0x540F} },
+    .{ .codepoint = 0xF9DF, .mapping = &[_]u21{0x5C65} },
+    .{ .codepoint = 0xF9E0, .mapping = &[_]u21{0x6613} },
+    .{ .codepoint = 0xF9E1, .mapping = &[_]u21{0x674E} },
+    .{ .codepoint = 0xF9E2, .mapping = &[_]u21{0x68A8} },
+    .{ .codepoint = 0xF9E3, .mapping = &[_]u21{0x6CE5} },
+    .{ .codepoint = 0xF9E4, .mapping = &[_]u21{0x7406} },
+    .{ .codepoint = 0xF9E5, .mapping = &[_]u21{0x75E2} },
+    .{ .codepoint = 0xF9E6, .mapping = &[_]u21{0x7F79} },
+    .{ .codepoint = 0xF9E7, .mapping = &[_]u21{0x88CF} },
+    .{ .codepoint = 0xF9E8, .mapping = &[_]u21{0x88E1} },
+    .{ .codepoint = 0xF9E9, .mapping = &[_]u21{0x91CC} },
+    .{ .codepoint = 0xF9EA, .mapping = &[_]u21{0x96E2} },
+    .{ .codepoint = 0xF9EB, .mapping = &[_]u21{0x533F} },
+    .{ .codepoint = 0xF9EC, .mapping = &[_]u21{0x6EBA} },
+    .{ .codepoint = 0xF9ED, .mapping = &[_]u21{0x541D} },
+    .{ .codepoint = 0xF9EE, .mapping = &[_]u21{0x71D0} },
+    .{ .codepoint = 0xF9EF, .mapping = &[_]u21{0x7498} },
+    .{ .codepoint = 0xF9F0, .mapping = &[_]u21{0x85FA} },
+    .{ .codepoint = 0xF9F1, .mapping = &[_]u21{0x96A3} },
+    .{ .codepoint = 0xF9F2, .mapping = &[_]u21{0x9C57} },
+    .{ .codepoint = 0xF9F3, .mapping = &[_]u21{0x9E9F} },
+    .{ .codepoint = 0xF9F4, .mapping = &[_]u21{0x6797} },
+    .{ .codepoint = 0xF9F5, .mapping = &[_]u21{0x6DCB} },
+    .{ .codepoint = 0xF9F6, .mapping = &[_]u21{0x81E8} },
+    .{ .codepoint = 0xF9F7, .mapping = &[_]u21{0x7ACB} },
+    .{ .codepoint = 0xF9F8, .mapping = &[_]u21{0x7B20} },
+    .{ .codepoint = 0xF9F9, .mapping = &[_]u21{0x7C92} },
+    .{ .codepoint = 0xF9FA, .mapping = &[_]u21{0x72C0} },
+    .{ .codepoint = 0xF9FB, .mapping = &[_]u21{0x7099} },
+    .{ .codepoint = 0xF9FC, .mapping = &[_]u21{0x8B58} },
+    .{ .codepoint = 0xF9FD, .mapping = &[_]u21{0x4EC0} },
+    .{ .codepoint = 0xF9FE, .mapping = &[_]u21{0x8336} },
+    .{ .codepoint = 0xF9FF, .mapping = &[_]u21{0x523A} },
+    .{ .codepoint = 0xFA00, .mapping = &[_]u21{0x5207} },
+    .{ .codepoint = 0xFA01, .mapping = &[_]u21{0x5EA6} },
+    .{ .codepoint = 0xFA02, .mapping = &[_]u21{0x62D3} },
+    .{ .codepoint = 0xFA03, .mapping = &[_]u21{0x7CD6} },
+    .{ .codepoint = 0xFA04, .mapping = &[_]u21{0x5B85} },
+    .{ .codepoint = 0xFA05, .mapping = &[_]u21{0x6D1E} },
+    .{ .codepoint = 0xFA06, .mapping = &[_]u21{0x66B4} },
+    .{ .codepoint = 0xFA07, .mapping = &[_]u21{0x8F3B} },
+    .{ .codepoint = 0xFA08, .mapping = &[_]u21{0x884C} },
+    .{ .codepoint = 0xFA09, .mapping = &[_]u21{0x964D} },
+    .{ .codepoint = 0xFA0A, .mapping = &[_]u21{0x898B} },
+    .{ .codepoint = 0xFA0B, .mapping = &[_]u21{0x5ED3} },
+    .{ .codepoint = 0xFA0C, .mapping = &[_]u21{0x5140} },
+    .{ .codepoint = 0xFA0D, .mapping = &[_]u21{0x55C0} },
+    .{ .codepoint = 0xFA10, .mapping = &[_]u21{0x585A} },
+    .{ .codepoint = 0xFA12, .mapping = &[_]u21{0x6674} },
+    .{ .codepoint = 0xFA15, .mapping = &[_]u21{0x51DE} },
+    .{ .codepoint = 0xFA16, .mapping = &[_]u21{0x732A} },
+    .{ .codepoint = 0xFA17, .mapping = &[_]u21{0x76CA} },
+    .{ .codepoint = 0xFA18, .mapping = &[_]u21{0x793C} },
+    .{ .codepoint = 0xFA19, .mapping = &[_]u21{0x795E} },
+    .{ .codepoint = 0xFA1A, .mapping = &[_]u21{0x7965} },
+    .{ .codepoint = 0xFA1B, .mapping = &[_]u21{0x798F} },
+    .{ .codepoint = 0xFA1C, .mapping = &[_]u21{0x9756} },
+    .{ .codepoint = 0xFA1D, .mapping = &[_]u21{0x7CBE} },
+    .{ .codepoint = 0xFA1E, .mapping = &[_]u21{0x7FBD} },
+    .{ .codepoint = 0xFA20, .mapping = &[_]u21{0x8612} },
+    .{ .codepoint = 0xFA22, .mapping = &[_]u21{0x8AF8} },
+    .{ .codepoint = 0xFA25, .mapping = &[_]u21{0x9038} },
+    .{ .codepoint = 0xFA26, .mapping = &[_]u21{0x90FD} },
+    .{ .codepoint = 0xFA2A, .mapping = &[_]u21{0x98EF} },
+    .{ .codepoint = 0xFA2B, .mapping = &[_]u21{0x98FC} },
+    .{ .codepoint = 0xFA2C, .mapping = &[_]u21{0x9928} },
+    .{ .codepoint = 0xFA2D, .mapping = &[_]u21{0x9DB4} },
+    .{ .codepoint = 0xFA2E, .mapping = &[_]u21{0x90DE} },
+    .{ .codepoint = 0xFA2F, .mapping = &[_]u21{0x96B7} },
+    .{ .codepoint = 0xFA30, .mapping = &[_]u21{0x4FAE} },
+    .{ .codepoint = 0xFA31, .mapping = &[_]u21{0x50E7} },
+    .{ .codepoint = 0xFA32, .mapping = &[_]u21{0x514D} },
+    .{ .codepoint = 0xFA33, .mapping = &[_]u21{0x52C9} },
+    .{ .codepoint = 0xFA34, .mapping = &[_]u21{0x52E4} },
+    .{ .codepoint = 0xFA35, .mapping = &[_]u21{0x5351} },
+    .{ .codepoint = 0xFA36, .mapping = &[_]u21{0x559D} },
+    .{ .codepoint = 0xFA37, .mapping = &[_]u21{0x5606} },
+    .{ .codepoint = 0xFA38, .mapping = &[_]u21{0x5668} },
+    .{ .codepoint = 0xFA39, .mapping = &[_]u21{0x5840} },
+    .{ .codepoint = 0xFA3A, .mapping = &[_]u21{0x58A8} },
+    .{ .codepoint = 0xFA3B, .mapping = &[_]u21{0x5C64} },
+    .{ .codepoint = 0xFA3C, .mapping = &[_]u21{0x5C6E} },
+    .{ .codepoint = 0xFA3D, .mapping = &[_]u21{0x6094} },
+    .{ .codepoint = 0xFA3E, .mapping = &[_]u21{0x6168} },
+    .{ .codepoint = 0xFA3F, .mapping = &[_]u21{0x618E} },
+    .{ .codepoint = 0xFA40, .mapping = &[_]u21{0x61F2} },
+    .{ .codepoint = 0xFA41, .mapping = &[_]u21{0x654F} },
+    .{ .codepoint = 0xFA42, .mapping = &[_]u21{0x65E2} },
+    .{ .codepoint = 0xFA43, .mapping = &[_]u21{0x6691} },
+    .{ .codepoint = 0xFA44, .mapping = &[_]u21{0x6885} },
+    .{ .codepoint = 0xFA45, .mapping = &[_]u21{0x6D77} },
+    .{ .codepoint = 0xFA46, .mapping = &[_]u21{0x6E1A} },
+    .{ .codepoint = 0xFA47, .mapping = &[_]u21{0x6F22} },
+    .{ .codepoint = 0xFA48, .mapping = &[_]u21{0x716E} },
+    .{ .codepoint = 0xFA49, .mapping = &[_]u21{0x722B} },
+    .{ .codepoint = 0xFA4A, .mapping = &[_]u21{0x7422} },
+    .{ .codepoint = 0xFA4B, .mapping = &[_]u21{0x7891} },
+    .{ .codepoint = 0xFA4C, .mapping = &[_]u21{0x793E} },
+    .{ .codepoint = 0xFA4D, .mapping = &[_]u21{0x7949} },
+    .{ .codepoint = 0xFA4E, .mapping = &[_]u21{0x7948} },
+    .{ .codepoint = 0xFA4F, .mapping = &[_]u21{0x7950} },
+    .{ .codepoint = 0xFA50, .mapping = &[_]u21{0x7956} },
+    .{ .codepoint = 0xFA51, .mapping = &[_]u21{0x795D} },
+    .{ .codepoint = 0xFA52, .mapping = &[_]u21{0x798D} },
+    .{ .codepoint = 0xFA53, .mapping = &[_]u21{0x798E} },
+    .{ .codepoint = 0xFA54, .mapping = &[_]u21{0x7A40} },
+    .{ .codepoint = 0xFA55, .mapping = &[_]u21{0x7A81} },
+    .{ .codepoint = 0xFA56, .mapping = &[_]u21{0x7BC0} },
+    .{ .codepoint = 0xFA57, .mapping = &[_]u21{0x7DF4} },
+    .{ .codepoint = 0xFA58, .mapping = &[_]u21{0x7E09} },
+    .{ .codepoint = 0xFA59, .mapping = &[_]u21{0x7E41} },
+    .{ .codepoint = 0xFA5A, .mapping = &[_]u21{0x7F72} },
+    .{ .codepoint = 0xFA5B, .mapping = &[_]u21{0x8005} },
+    .{ .codepoint = 0xFA5C, .mapping = &[_]u21{0x81ED} },
+    .{ .codepoint = 0xFA5D, .mapping = &[_]u21{0x8279} },
+    .{ .codepoint = 0xFA5E, .mapping = &[_]u21{0x8279} },
+    .{ .codepoint = 0xFA5F, .mapping = &[_]u21{0x8457} },
+    .{ .codepoint = 0xFA60, .mapping = &[_]u21{0x8910} },
+    .{ .codepoint = 0xFA61, .mapping = &[_]u21{0x8996} },
+    .{ .codepoint = 0xFA62, .mapping = &[_]u21{0x8B01} },
+    .{ .codepoint = 0xFA63, .mapping = &[_]u21{0x8B39} },
+    .{ .codepoint = 0xFA64, .mapping = &[_]u21{0x8CD3} },
+    .{ .codepoint = 0xFA65, .mapping = &[_]u21{0x8D08} },
+    .{ .codepoint = 0xFA66, .mapping = &[_]u21{0x8FB6} },
+    .{ .codepoint = 0xFA67, .mapping = &[_]u21{0x9038} },
+    .{ .codepoint = 0xFA68, .mapping = &[_]u21{0x96E3} },
+    .{ .codepoint = 0xFA69, .mapping = &[_]u21{0x97FF} },
+    .{ .codepoint = 0xFA6A, .mapping = &[_]u21{0x983B} },
+    .{ .codepoint = 0xFA6B, .mapping = &[_]u21{0x6075} },
+    .{ .codepoint = 0xFA6C, .mapping = &[_]u21{0x242EE} },
+    .{ .codepoint = 0xFA6D, .mapping = &[_]u21{0x8218} },
+    .{ .codepoint = 0xFA70, .mapping = &[_]u21{0x4E26} },
+    .{ .codepoint = 0xFA71, .mapping = &[_]u21{0x51B5} },
+    .{ .codepoint = 0xFA72, .mapping = &[_]u21{0x5168} },
+    .{ .codepoint = 0xFA73, .mapping = &[_]u21{0x4F80} },
+    .{ .codepoint = 0xFA74, .mapping = &[_]u21{0x5145} },
+    .{ .codepoint = 0xFA75, .mapping = &[_]u21{0x5180} },
+    .{ .codepoint = 0xFA76, .mapping = &[_]u21{0x52C7} },
+    .{ .codepoint = 0xFA77, .mapping = &[_]u21{0x52FA} },
+    .{ .codepoint = 0xFA78, .mapping = &[_]u21{0x559D} },
+    .{ .codepoint = 0xFA79, .mapping = &[_]u21{0x5555} },
+    .{ .codepoint = 0xFA7A, .mapping = &[_]u21{0x5599} },
+    .{ .codepoint = 0xFA7B, .mapping = &[_]u21{0x55E2} },
+    .{ .codepoint = 0xFA7C, .mapping = &[_]u21{0x585A} },
+    .{ .codepoint = 0xFA7D, .mapping = &[_]u21{0x58B3} },
+    .{ .codepoint = 0xFA7E, .mapping = &[_]u21{0x5944} },
+    .{ .codepoint = 0xFA7F, .mapping = &[_]u21{0x5954} },
+    .{ .codepoint = 0xFA80, .mapping = &[_]u21{0x5A62} },
+    .{ .codepoint = 0xFA81, .mapping = &[_]u21{0x5B28} },
+    .{ .codepoint = 0xFA82, .mapping = &[_]u21{0x5ED2} },
+    .{ .codepoint = 0xFA83, .mapping = &[_]u21{0x5ED9} },
+    .{ .codepoint = 0xFA84, .mapping = &[_]u21{0x5F69} },
+    .{ .codepoint = 0xFA85, .mapping = &[_]u21{0x5FAD} },
+    .{ .codepoint = 0xFA86, .mapping = &[_]u21{0x60D8} },
+    .{ .codepoint = 0xFA87, .mapping = &[_]u21{0x614E} },
+    .{ .codepoint = 0xFA88, .mapping = &[_]u21{0x6108} },
+    .{ .codepoint = 0xFA89, .mapping = &[_]u21{0x618E} },
+    .{ .codepoint = 0xFA8A, .mapping = &[_]u21{0x6160} },
+    .{ .codepoint = 0xFA8B, .mapping = &[_]u21{0x61F2} },
+    .{ .codepoint = 0xFA8C, .mapping = &[_]u21{0x6234} },
+    .{ .codepoint = 0xFA8D, .mapping = &[_]u21{0x63C4} },
+    .{ .codepoint = 0xFA8E, .mapping = &[_]u21{0x641C} },
+    .{ .codepoint = 0xFA8F, .mapping = &[_]u21{0x6452} },
+    .{ .codepoint = 0xFA90, .mapping = &[_]u21{0x6556} },
+    .{ .codepoint = 0xFA91, .mapping = &[_]u21{0x6674} },
+    .{ .codepoint = 0xFA92, .mapping = &[_]u21{0x6717} },
+    .{ .codepoint = 0xFA93, .mapping = &[_]u21{0x671B} },
+    .{ .codepoint = 0xFA94, .mapping = &[_]u21{0x6756} },
+    .{ .codepoint = 0xFA95, .mapping = &[_]u21{0x6B79} },
+    .{ .codepoint = 0xFA96, .mapping = &[_]u21{0x6BBA} },
+    .{ .codepoint = 0xFA97, .mapping = &[_]u21{0x6D41} },
+    .{ .codepoint = 0xFA98, .mapping = &[_]u21{0x6EDB} },
+    .{ .codepoint = 0xFA99, .mapping = &[_]u21{0x6ECB} },
+    .{ .codepoint = 0xFA9A, .mapping = &[_]u21{0x6F22} },
+    .{ .codepoint = 0xFA9B, .mapping = &[_]u21{0x701E} },
+    .{ .codepoint = 0xFA9C, .mapping = &[_]u21{0x716E} },
+    .{ .codepoint = 0xFA9D, .mapping = &[_]u21{0x77A7} },
+    .{ .codepoint = 0xFA9E, .mapping = &[_]u21{0x7235} },
+    .{ .codepoint = 0xFA9F, .mapping = &[_]u21{0x72AF} },
+    .{ .codepoint = 0xFAA0, .mapping = &[_]u21{0x732A} },
+    .{ .codepoint = 0xFAA1, .mapping = &[_]u21{0x7471} },
+    .{ .codepoint = 0xFAA2, .mapping = &[_]u21{0x7506} },
+    .{ .codepoint = 0xFAA3, .mapping = &[_]u21{0x753B} },
+    .{ .codepoint = 0xFAA4, .mapping = &[_]u21{0x761D} },
+    .{ .codepoint = 0xFAA5, .mapping = &[_]u21{0x761F} },
+    .{ .codepoint = 0xFAA6, .mapping = &[_]u21{0x76CA} },
+    .{ .codepoint = 0xFAA7, .mapping = &[_]u21{0x76DB} },
+    .{ .codepoint = 0xFAA8, .mapping = &[_]u21{0x76F4} },
+    .{ .codepoint = 0xFAA9, .mapping = &[_]u21{0x774A} },
+    .{ .codepoint = 0xFAAA, .mapping = &[_]u21{0x7740} },
+    .{ .codepoint = 0xFAAB, .mapping = &[_]u21{0x78CC} },
+    .{ .codepoint = 0xFAAC, .mapping = &[_]u21{0x7AB1} },
+    .{ .codepoint = 0xFAAD, .mapping = &[_]u21{0x7BC0} },
+    .{ .codepoint = 0xFAAE, .mapping = &[_]u21{0x7C7B} },
+    .{ .codepoint = 0xFAAF, .mapping = &[_]u21{0x7D5B} },
+    .{ .codepoint = 0xFAB0, .mapping = &[_]u21{0x7DF4} },
+    .{ .codepoint = 0xFAB1, .mapping = &[_]u21{0x7F3E} },
+    .{ .codepoint = 0xFAB2, .mapping = &[_]u21{0x8005} },
+    .{ .codepoint = 0xFAB3, .mapping = &[_]u21{0x8352} },
+    .{ .codepoint = 0xFAB4, .mapping = &[_]u21{0x83EF} },
+    .{ .codepoint = 0xFAB5, .mapping = &[_]u21{0x8779} },
+    .{ .codepoint = 0xFAB6, .mapping = &[_]u21{0x8941} },
+    .{ .codepoint = 0xFAB7, .mapping = &[_]u21{0x8986} },
+    .{ .codepoint = 0xFAB8, .mapping = &[_]u21{0x8996} },
+    .{ .codepoint = 0xFAB9, .mapping = &[_]u21{0x8ABF} },
+    .{ .codepoint = 0xFABA, .mapping = &[_]u21{0x8AF8} },
+    .{ .codepoint = 0xFABB, .mapping = &[_]u21{0x8ACB} },
+    .{ .codepoint = 0xFABC, .mapping = &[_]u21{0x8B01} },
+    .{ .codepoint = 0xFABD, .mapping = &[_]u21{0x8AFE} },
+    .{ .codepoint = 0xFABE, .mapping = &[_]u21{0x8AED} },
+    .{ .codepoint = 0xFABF, .mapping = &[_]u21{0x8B39} },
+    .{ .codepoint = 0xFAC0, .mapping = &[_]u21{0x8B8A} },
+    .{ .codepoint = 0xFAC1, .mapping = &[_]u21{0x8D08} },
+    .{ .codepoint = 0xFAC2, .mapping = &[_]u21{0x8F38} },
+    .{ .codepoint = 0xFAC3, .mapping = &[_]u21{0x9072} },
+    .{ .codepoint = 0xFAC4, .mapping = &[_]u21{0x9199} },
+    .{ .codepoint = 0xFAC5, .mapping = &[_]u21{0x9276} },
+    .{ .codepoint = 0xFAC6, .mapping = &[_]u21{0x967C} },
+    .{ .codepoint = 0xFAC7, .mapping = &[_]u21{0x96E3} },
+    .{ .codepoint = 0xFAC8, .mapping = &[_]u21{0x9756} },
+    .{ .codepoint = 0xFAC9, .mapping = &[_]u21{0x97DB} },
+    .{ .codepoint = 0xFACA, .mapping = &[_]u21{0x97FF} },
+    .{ .codepoint = 0xFACB, .mapping = &[_]u21{0x980B} },
+    .{ .codepoint = 0xFACC, .mapping = &[_]u21{0x983B} },
+    .{ .codepoint = 0xFACD, .mapping = &[_]u21{0x9B12} },
+    .{ .codepoint = 0xFACE, .mapping = &[_]u21{0x9F9C} },
+    .{ .codepoint = 0xFACF, .mapping = &[_]u21{0x2284A} },
+    .{ .codepoint = 0xFAD0, .mapping = &[_]u21{0x22844} },
+    .{ .codepoint = 0xFAD1, .mapping = &[_]u21{0x233D5} },
+    .{ .codepoint = 0xFAD2, .mapping = &[_]u21{0x3B9D} },
+    .{ .codepoint = 0xFAD3, .mapping = &[_]u21{0x4018} },
+    .{ .codepoint = 0xFAD4, .mapping = &[_]u21{0x4039} },
+    .{ .codepoint = 0xFAD5, .mapping = &[_]u21{0x25249} },
+    .{ .codepoint = 0xFAD6, .mapping = &[_]u21{0x25CD0} },
+    .{ .codepoint = 0xFAD7, .mapping = &[_]u21{0x27ED3} },
+    .{ .codepoint = 0xFAD8, .mapping = &[_]u21{0x9F43} },
+    .{ .codepoint = 0xFAD9, .mapping = &[_]u21{0x9F8E} },
+    .{ .codepoint = 0xFB1D, .mapping = &[_]u21{ 0x05D9, 0x05B4 } },
+    .{ .codepoint = 0xFB1F, .mapping = &[_]u21{ 0x05F2, 0x05B7 } },
+    .{ .codepoint = 0xFB2A, .mapping = &[_]u21{ 0x05E9, 0x05C1 } },
+    .{ .codepoint = 0xFB2B, .mapping = &[_]u21{ 0x05E9, 0x05C2 } },
+    .{ .codepoint = 0xFB2C, .mapping = &[_]u21{ 0xFB49, 0x05C1 } },
+    .{ .codepoint = 0xFB2D, .mapping = &[_]u21{ 0xFB49, 0x05C2 } },
+    .{ .codepoint = 0xFB2E, .mapping = &[_]u21{ 0x05D0, 0x05B7 } },
+    .{ .codepoint = 0xFB2F, .mapping = &[_]u21{ 0x05D0, 0x05B8 } },
+    .{ .codepoint = 0xFB30, .mapping = &[_]u21{ 0x05D0, 0x05BC } },
+    .{ .codepoint = 0xFB31, .mapping = &[_]u21{ 0x05D1, 0x05BC } },
+    .{ .codepoint = 0xFB32, .mapping = &[_]u21{ 0x05D2, 0x05BC } },
+    .{ .codepoint = 0xFB33, .mapping = &[_]u21{ 0x05D3, 0x05BC } },
+    .{ .codepoint = 0xFB34, .mapping = &[_]u21{ 0x05D4, 0x05BC } },
+    .{ .codepoint = 0xFB35, .mapping = &[_]u21{ 0x05D5, 0x05BC } },
+    .{ .codepoint = 0xFB36, .mapping = &[_]u21{ 0x05D6, 0x05BC } },
+    .{ .codepoint = 0xFB38, .mapping = &[_]u21{ 0x05D8, 0x05BC } },
+    .{ .codepoint = 0xFB39, .mapping = &[_]u21{ 0x05D9, 0x05BC } },
+    .{ .codepoint = 0xFB3A, .mapping = &[_]u21{ 0x05DA, 0x05BC } },
+    .{ .codepoint = 0xFB3B, .mapping = &[_]u21{ 0x05DB, 0x05BC } },
+    .{ .codepoint = 0xFB3C, .mapping = &[_]u21{ 0x05DC, 0x05BC } },
+    .{ .codepoint = 0xFB3E, .mapping = &[_]u21{ 0x05DE, 0x05BC } },
+    .{ .codepoint = 0xFB40, .mapping = &[_]u21{ 0x05E0, 0x05BC } },
+    .{ .codepoint = 0xFB41, .mapping = &[_]u21{ 0x05E1, 0x05BC } },
+    .{ .codepoint = 0xFB43, .mapping = &[_]u21{ 0x05E3, 0x05BC } },
+    .{ .codepoint = 0xFB44, .mapping = &[_]u21{ 0x05E4, 0x05BC } },
+    .{ .codepoint = 0xFB46, .mapping = &[_]u21{ 0x05E6, 0x05BC } },
+    .{ .codepoint = 0xFB47, .mapping = &[_]u21{ 0x05E7, 0x05BC } },
+    .{ .codepoint = 0xFB48, .mapping = &[_]u21{ 0x05E8, 0x05BC } },
+    .{ .codepoint = 0xFB49, .mapping = &[_]u21{ 0x05E9, 0x05BC } },
+    .{ .codepoint = 0xFB4A, .mapping = &[_]u21{ 0x05EA, 0x05BC } },
+    .{ .codepoint = 0xFB4B, .mapping = &[_]u21{ 0x05D5, 0x05B9 } },
+    .{ .codepoint = 0xFB4C, .mapping = &[_]u21{ 0x05D1, 0x05BF } },
+    .{ .codepoint = 0xFB4D, .mapping = &[_]u21{ 0x05DB, 0x05BF } },
+    .{ .codepoint = 0xFB4E, .mapping = &[_]u21{ 0x05E4, 0x05BF } },
+    .{ .codepoint = 0x1109A, .mapping = &[_]u21{ 0x11099, 0x110BA } },
+    .{ .codepoint = 0x1109C, .mapping = &[_]u21{ 0x1109B, 0x110BA } },
+    .{ .codepoint = 0x110AB, .mapping = &[_]u21{ 0x110A5, 0x110BA } },
+    .{ .codepoint = 0x1112E, .mapping = &[_]u21{ 0x11131, 0x11127 } },
+    .{ .codepoint = 0x1112F, .mapping = &[_]u21{ 0x11132, 0x11127 } },
+    .{ .codepoint = 0x1134B, .mapping = &[_]u21{ 0x11347, 0x1133E } },
+    .{ .codepoint = 0x1134C, .mapping = &[_]u21{ 0x11347, 0x11357 } },
+    .{ .codepoint = 0x114BB, .mapping = &[_]u21{ 0x114B9, 0x114BA } },
+    .{ .codepoint = 0x114BC, .mapping = &[_]u21{ 0x114B9, 0x114B0 } },
+    .{ .codepoint = 0x114BE, .mapping = &[_]u21{ 0x114B9, 0x114BD } },
+    .{ .codepoint = 0x115BA, .mapping = &[_]u21{ 0x115B8, 0x115AF } },
+    .{ .codepoint = 0x115BB, .mapping = &[_]u21{ 0x115B9, 0x115AF } },
+    .{ .codepoint = 0x11938, .mapping = &[_]u21{ 0x11935, 0x11930 } },
+    .{ .codepoint = 0x1D15E, .mapping = &[_]u21{ 0x1D157, 0x1D165 } },
+    .{ .codepoint = 0x1D15F, .mapping = &[_]u21{ 0x1D158, 0x1D165 } },
+    .{ .codepoint = 0x1D160, .mapping = &[_]u21{ 0x1D15F, 0x1D16E } },
+    .{ .codepoint = 0x1D161, .mapping = &[_]u21{ 0x1D15F, 0x1D16F } },
+    .{ .codepoint = 0x1D162, .mapping = &[_]u21{ 0x1D15F, 0x1D170 } },
+    .{ .codepoint = 0x1D163, .mapping = &[_]u21{ 0x1D15F, 0x1D171 } },
+    .{ .codepoint = 0x1D164, .mapping = &[_]u21{ 0x1D15F, 0x1D172 } },
+    .{ .codepoint = 0x1D1BB, .mapping = &[_]u21{ 0x1D1B9, 0x1D165 } },
+    .{ .codepoint = 0x1D1BC, .mapping = &[_]u21{ 0x1D1BA, 0x1D165 } },
+    .{ .codepoint = 0x1D1BD, .mapping = &[_]u21{ 0x1D1BB, 0x1D16E } },
+    .{ .codepoint = 0x1D1BE, .mapping = &[_]u21{ 0x1D1BC, 0x1D16E } },
+    .{ .codepoint = 0x1D1BF, .mapping = &[_]u21{ 0x1D1BB, 0x1D16F } },
+    .{ .codepoint = 0x1D1C0, .mapping = &[_]u21{ 0x1D1BC, 0x1D16F } },
+    .{ .codepoint = 0x2F800, .mapping = &[_]u21{0x4E3D} },
+    .{ .codepoint = 0x2F801, .mapping = &[_]u21{0x4E38} },
+    .{ .codepoint = 0x2F802, .mapping = &[_]u21{0x4E41} },
+    .{ .codepoint = 0x2F803, .mapping = &[_]u21{0x20122} },
+    .{ .codepoint = 0x2F804, .mapping = &[_]u21{0x4F60} },
+    .{ .codepoint = 0x2F805, .mapping = &[_]u21{0x4FAE} },
+    .{ .codepoint = 0x2F806, .mapping = &[_]u21{0x4FBB} },
+    .{ .codepoint = 0x2F807, .mapping = &[_]u21{0x5002} },
+    .{ .codepoint = 0x2F808, .mapping = &[_]u21{0x507A} },
+    .{ .codepoint = 0x2F809, .mapping = &[_]u21{0x5099} },
+    .{ .codepoint = 0x2F80A, .mapping = &[_]u21{0x50E7} },
+    .{ .codepoint = 0x2F80B, .mapping = &[_]u21{0x50CF} },
+    .{ .codepoint = 0x2F80C, .mapping = &[_]u21{0x349E} },
+    .{ .codepoint = 0x2F80D, .mapping = &[_]u21{0x2063A} },
+    .{ .codepoint = 0x2F80E, .mapping = &[_]u21{0x514D} },
+    .{ .codepoint = 0x2F80F, .mapping = &[_]u21{0x5154} },
+    .{ .codepoint = 0x2F810, .mapping = &[_]u21{0x5164} },
+    .{ .codepoint = 0x2F811, .mapping = &[_]u21{0x5177} },
+    .{ .codepoint = 0x2F812, .mapping = &[_]u21{0x2051C} },
+    .{ .codepoint = 0x2F813, .mapping = &[_]u21{0x34B9} },
+    .{ .codepoint = 0x2F814, .mapping = &[_]u21{0x5167} },
+    .{ .codepoint = 0x2F815, .mapping = &[_]u21{0x518D} },
+    .{ .codepoint = 0x2F816, .mapping = &[_]u21{0x2054B} },
+    .{ .codepoint = 0x2F817, .mapping = &[_]u21{0x5197} },
+    .{ .codepoint = 0x2F818, .mapping = &[_]u21{0x51A4} },
+    .{ .codepoint = 0x2F819, .mapping = &[_]u21{0x4ECC} },
+    .{ .codepoint = 0x2F81A, .mapping = &[_]u21{0x51AC} },
+    .{ .codepoint = 0x2F81B, .mapping = &[_]u21{0x51B5} },
+    .{ .codepoint = 0x2F81C, .mapping = &[_]u21{0x291DF} },
+    .{ .codepoint = 0x2F81D, .mapping = &[_]u21{0x51F5} },
+    .{ .codepoint = 0x2F81E, .mapping = &[_]u21{0x5203} },
+    .{ .codepoint = 0x2F81F, .mapping = &[_]u21{0x34DF} },
+    .{ .codepoint = 0x2F820, .mapping = &[_]u21{0x523B} },
+    .{ .codepoint = 0x2F821, .mapping = &[_]u21{0x5246} },
+    .{ .codepoint = 0x2F822, .mapping = &[_]u21{0x5272} },
+    .{ .codepoint = 0x2F823, .mapping = &[_]u21{0x5277} },
+    .{ .codepoint = 0x2F824, .mapping = &[_]u21{0x3515} },
+    .{ .codepoint = 0x2F825, .mapping = &[_]u21{0x52C7} },
+    .{ .codepoint = 0x2F826, .mapping = &[_]u21{0x52C9} },
+    .{ .codepoint = 0x2F827, .mapping = &[_]u21{0x52E4} },
+    .{ .codepoint = 0x2F828, .mapping = &[_]u21{0x52FA} },
+    .{ .codepoint = 0x2F829, .mapping = &[_]u21{0x5305} },
+    .{ .codepoint = 0x2F82A, .mapping = &[_]u21{0x5306} },
+    .{ .codepoint = 0x2F82B, .mapping = &[_]u21{0x5317} },
+    .{ .codepoint = 0x2F82C, .mapping = &[_]u21{0x5349} },
+    .{ .codepoint = 0x2F82D, .mapping = &[_]u21{0x5351} },
+    .{ .codepoint = 0x2F82E, .mapping = &[_]u21{0x535A} },
+    .{ .codepoint = 0x2F82F, .mapping = &[_]u21{0x5373} },
+    .{ .codepoint = 0x2F830, .mapping = &[_]u21{0x537D} },
+    .{ .codepoint = 0x2F831, .mapping = &[_]u21{0x537F} },
+    .{ .codepoint = 0x2F832, .mapping = &[_]u21{0x537F} },
+    .{ .codepoint = 0x2F833, .mapping = &[_]u21{0x537F} },
+    .{ .codepoint = 0x2F834, .mapping = &[_]u21{0x20A2C} },
+    .{ .codepoint = 0x2F835, .mapping = &[_]u21{0x7070} },
+    .{ .codepoint = 0x2F836, .mapping = &[_]u21{0x53CA} },
+    .{ .codepoint = 0x2F837, .mapping = &[_]u21{0x53DF} },
+    .{ .codepoint = 0x2F838, .mapping = &[_]u21{0x20B63} },
+    .{ .codepoint = 0x2F839, .mapping = &[_]u21{0x53EB} },
+    .{ .codepoint = 0x2F83A, .mapping = &[_]u21{0x53F1} },
+    .{ .codepoint = 0x2F83B, .mapping = &[_]u21{0x5406} },
+    .{ .codepoint = 0x2F83C, .mapping = &[_]u21{0x549E} },
+    .{ .codepoint = 0x2F83D, .mapping = &[_]u21{0x5438} },
+    .{ .codepoint = 0x2F83E, .mapping = &[_]u21{0x5448} },
+    .{ .codepoint = 0x2F83F, .mapping = &[_]u21{0x5468} },
+    .{ .codepoint = 0x2F840, .mapping = &[_]u21{0x54A2} },
+    .{ .codepoint = 0x2F841, .mapping = &[_]u21{0x54F6} },
+    .{ .codepoint = 0x2F842, .mapping = &[_]u21{0x5510} },
+    .{ .codepoint = 0x2F843, .mapping = &[_]u21{0x5553} },
+    .{ .codepoint = 0x2F844, .mapping = &[_]u21{0x5563} },
+    .{ .codepoint = 0x2F845, .mapping = &[_]u21{0x5584} },
+    .{ .codepoint = 0x2F846, .mapping = &[_]u21{0x5584} },
+    .{ .codepoint = 0x2F847, .mapping = &[_]u21{0x5599} },
+    .{ .codepoint = 0x2F848, .mapping = &[_]u21{0x55AB} },
+    .{ .codepoint = 0x2F849, .mapping = &[_]u21{0x55B3} },
+    .{ .codepoint = 0x2F84A, .mapping = &[_]u21{0x55C2} },
+    .{ .codepoint = 0x2F84B, .mapping = &[_]u21{0x5716} },
+    .{ .codepoint = 0x2F84C, .mapping = &[_]u21{0x5606} },
+    .{ .codepoint = 0x2F84D, .mapping = &[_]u21{0x5717} },
+    .{ .codepoint = 0x2F84E, .mapping = &[_]u21{0x5651} },
+    .{ .codepoint = 0x2F84F, .mapping = &[_]u21{0x5674} },
+    .{ .codepoint = 0x2F850, .mapping = &[_]u21{0x5207} },
+    .{ .codepoint = 0x2F851, .mapping = &[_]u21{0x58EE} },
+    .{ .codepoint = 0x2F852, .mapping = &[_]u21{0x57CE} },
+    .{ .codepoint = 0x2F853, .mapping = &[_]u21{0x57F4} },
+    .{ .codepoint = 0x2F854, .mapping = &[_]u21{0x580D} },
+    .{ .codepoint = 0x2F855, .mapping = &[_]u21{0x578B} },
+    .{ .codepoint = 0x2F856, .mapping = &[_]u21{0x5832} },
+    .{ .codepoint = 0x2F857, .mapping = &[_]u21{0x5831} },
+    .{ .codepoint = 0x2F858, .mapping = &[_]u21{0x58AC} },
+    .{ .codepoint = 0x2F859, .mapping = &[_]u21{0x214E4} },
+    .{ .codepoint = 0x2F85A, .mapping = &[_]u21{0x58F2} },
+    .{ .codepoint = 0x2F85B, .mapping = &[_]u21{0x58F7} },
+    .{ .codepoint = 0x2F85C, .mapping = &[_]u21{0x5906} },
+    .{ .codepoint = 0x2F85D, .mapping = &[_]u21{0x591A} },
+    .{ .codepoint = 0x2F85E, .mapping = &[_]u21{0x5922} },
+    .{ .codepoint = 0x2F85F, .mapping = &[_]u21{0x5962} },
+    .{ .codepoint = 0x2F860, .mapping = &[_]u21{0x216A8} },
+    .{ .codepoint = 0x2F861, .mapping = &[_]u21{0x216EA} },
+    .{ .codepoint = 0x2F862, .mapping = &[_]u21{0x59EC} },
+    .{ .codepoint = 0x2F863, .mapping = &[_]u21{0x5A1B} },
+    .{ .codepoint = 0x2F864, .mapping = &[_]u21{0x5A27} },
+    .{ .codepoint = 0x2F865, .mapping = &[_]u21{0x59D8} },
+    .{ .codepoint = 0x2F866, .mapping = &[_]u21{0x5A66} },
+    .{ .codepoint = 0x2F867, .mapping = &[_]u21{0x36EE} },
+    .{ .codepoint = 0x2F868, .mapping = &[_]u21{0x36FC} },
+    .{ .codepoint = 0x2F869, .mapping = &[_]u21{0x5B08} },
+    .{ .codepoint = 0x2F86A, .mapping = &[_]u21{0x5B3E} },
+    .{ .codepoint = 0x2F86B, .mapping = &[_]u21{0x5B3E} },
+    .{ .codepoint = 0x2F86C, .mapping = &[_]u21{0x219C8} },
+    .{ .codepoint = 0x2F86D, .mapping = &[_]u21{0x5BC3} },
+    .{ .codepoint = 0x2F86E, .mapping = &[_]u21{0x5BD8} },
+    .{ .codepoint = 0x2F86F, .mapping = &[_]u21{0x5BE7} },
+    .{ .codepoint = 0x2F870, .mapping = &[_]u21{0x5BF3} },
+    .{ .codepoint = 0x2F871, .mapping = &[_]u21{0x21B18} },
+    .{ .codepoint = 0x2F872, .mapping = &[_]u21{0x5BFF} },
+    .{ .codepoint = 0x2F873, .mapping = &[_]u21{0x5C06} },
+    .{ .codepoint = 0x2F874, .mapping = &[_]u21{0x5F53} },
+    .{ .codepoint = 0x2F875, .mapping = &[_]u21{0x5C22} },
+    .{ .codepoint = 0x2F876, .mapping = &[_]u21{0x3781} },
+    .{ .codepoint = 0x2F877, .mapping = &[_]u21{0x5C60} },
+    .{ .codepoint = 0x2F878, .mapping = &[_]u21{0x5C6E} },
+    .{ .codepoint = 0x2F879, .mapping = &[_]u21{0x5CC0} },
+    .{ .codepoint = 0x2F87A, .mapping = &[_]u21{0x5C8D} },
+    .{ .codepoint = 0x2F87B, .mapping = &[_]u21{0x21DE4} },
+    .{ .codepoint = 0x2F87C, .mapping = &[_]u21{0x5D43} },
+    .{ .codepoint = 0x2F87D, .mapping = &[_]u21{0x21DE6} },
+    .{ .codepoint = 0x2F87E, .mapping = &[_]u21{0x5D6E} },
+    .{ .codepoint = 0x2F87F, .mapping = &[_]u21{0x5D6B} },
+    .{ .codepoint = 0x2F880, .mapping = &[_]u21{0x5D7C} },
+    .{ .codepoint = 0x2F881, .mapping = &[_]u21{0x5DE1} },
+    .{ .codepoint = 0x2F882, .mapping = &[_]u21{0x5DE2} },
+    .{ .codepoint = 0x2F883, .mapping = &[_]u21{0x382F} },
+    .{ .codepoint = 0x2F884, .mapping = &[_]u21{0x5DFD} },
+    .{ .codepoint = 0x2F885, .mapping = &[_]u21{0x5E28} },
+    .{ .codepoint = 0x2F886, .mapping = &[_]u21{0x5E3D} },
+    .{ .codepoint = 0x2F887, .mapping = &[_]u21{0x5E69} },
+    .{ .codepoint = 0x2F888, .mapping = &[_]u21{0x3862} },
+    .{ .codepoint = 0x2F889, .mapping = &[_]u21{0x22183} },
+    .{ .codepoint = 0x2F88A, .mapping = &[_]u21{0x387C} },
+    .{ .codepoint = 0x2F88B, .mapping = &[_]u21{0x5EB0} },
+    .{ .codepoint = 0x2F88C, .mapping = &[_]u21{0x5EB3} },
+    .{ .codepoint = 0x2F88D, .mapping = &[_]u21{0x5EB6} },
+    .{ .codepoint = 0x2F88E, .mapping = &[_]u21{0x5ECA} },
+    .{ .codepoint = 0x2F88F, .mapping = &[_]u21{0x2A392} },
+    .{ .codepoint = 0x2F890, .mapping = &[_]u21{0x5EFE} },
+    .{ .codepoint = 0x2F891, .mapping = &[_]u21{0x22331} },
+    .{ .codepoint = 0x2F892, .mapping = &[_]u21{0x22331} },
+    .{ .codepoint = 0x2F893, .mapping = &[_]u21{0x8201} },
+    .{ .codepoint = 0x2F894, .mapping = &[_]u21{0x5F22} },
+    .{ .codepoint = 0x2F895, .mapping = &[_]u21{0x5F22} },
+    .{ .codepoint = 0x2F896, .mapping = &[_]u21{0x38C7} },
+    .{ .codepoint = 0x2F897, .mapping = &[_]u21{0x232B8} },
+    .{ .codepoint = 0x2F898, .mapping = &[_]u21{0x261DA} },
+    .{ .codepoint = 0x2F899, .mapping = &[_]u21{0x5F62} },
+    .{ .codepoint = 0x2F89A, .mapping = &[_]u21{0x5F6B} },
+    .{ .codepoint = 0x2F89B, .mapping = &[_]u21{0x38E3} },
+    .{ .codepoint = 0x2F89C, .mapping = &[_]u21{0x5F9A} },
+    .{ .codepoint = 0x2F89D, .mapping = &[_]u21{0x5FCD} },
+    .{ .codepoint = 0x2F89E, .mapping = &[_]u21{0x5FD7} },
+    .{ .codepoint = 0x2F89F, .mapping = &[_]u21{0x5FF9} },
+    .{ .codepoint = 0x2F8A0, .mapping = &[_]u21{0x6081} },
+    .{ .codepoint = 0x2F8A1, .mapping = &[_]u21{0x393A} },
+    .{ .codepoint = 0x2F8A2, .mapping = &[_]u21{0x391C} },
+    .{ .codepoint = 0x2F8A3, .mapping = &[_]u21{0x6094} },
+    .{ .codepoint = 0x2F8A4, .mapping = &[_]u21{0x226D4} },
+    .{ .codepoint = 0x2F8A5, .mapping = &[_]u21{0x60C7} },
+    .{ .codepoint = 0x2F8A6, .mapping = &[_]u21{0x6148} },
+    .{ .codepoint = 0x2F8A7, .mapping = &[_]u21{0x614C} },
+    .{ .codepoint = 0x2F8A8, .mapping = &[_]u21{0x614E} },
+    .{ .codepoint = 0x2F8A9, .mapping = &[_]u21{0x614C} },
+    .{ .codepoint = 0x2F8AA, .mapping = &[_]u21{0x617A} },
+    .{ .codepoint = 0x2F8AB, .mapping = &[_]u21{0x618E} },
+    .{ .codepoint = 0x2F8AC, .mapping = &[_]u21{0x61B2} },
+    .{ .codepoint = 0x2F8AD, .mapping = &[_]u21{0x61A4} },
+    .{ .codepoint = 0x2F8AE, .mapping = &[_]u21{0x61AF} },
+    .{ .codepoint = 0x2F8AF, .mapping = &[_]u21{0x61DE} },
+    .{ .codepoint = 0x2F8B0, .mapping = &[_]u21{0x61F2} },
+    .{ .codepoint = 0x2F8B1, .mapping = &[_]u21{0x61F6} },
+    .{ .codepoint = 0x2F8B2, .mapping = &[_]u21{0x6210} },
+    .{ .codepoint = 0x2F8B3, .mapping = &[_]u21{0x621B} },
+    .{ .codepoint = 0x2F8B4, .mapping = &[_]u21{0x625D} },
+    .{ .codepoint = 0x2F8B5, .mapping = &[_]u21{0x62B1} },
+    .{ .codepoint = 0x2F8B6, .mapping = &[_]u21{0x62D4} },
+    .{ .codepoint = 0x2F8B7, .mapping = &[_]u21{0x6350} },
+    .{ .codepoint = 0x2F8B8, .mapping = &[_]u21{0x22B0C} },
+    .{ .codepoint = 0x2F8B9, .mapping = &[_]u21{0x633D} },
+    .{ .codepoint = 0x2F8BA, .mapping = &[_]u21{0x62FC} },
+    .{ .codepoint = 0x2F8BB, .mapping = &[_]u21{0x6368} },
+    .{ .codepoint = 0x2F8BC, .mapping = &[_]u21{0x6383} },
+    .{ .codepoint = 0x2F8BD, .mapping = &[_]u21{0x63E4} },
+    .{ .codepoint = 0x2F8BE, .mapping = &[_]u21{0x22BF1} },
+    .{ .codepoint = 0x2F8BF, .mapping = &[_]u21{0x6422} },
+    .{ .codepoint = 0x2F8C0, .mapping = &[_]u21{0x63C5} },
+    .{ .codepoint = 0x2F8C1, .mapping = &[_]u21{0x63A9} },
+    .{ .codepoint = 0x2F8C2, .mapping = &[_]u21{0x3A2E} },
+    .{ .codepoint = 0x2F8C3, .mapping = &[_]u21{0x6469} },
+    .{ .codepoint = 0x2F8C4, .mapping = &[_]u21{0x647E} },
+    .{ .codepoint = 0x2F8C5, .mapping = &[_]u21{0x649D} },
+    .{ .codepoint = 0x2F8C6, .mapping = &[_]u21{0x6477} },
+    .{ .codepoint = 0x2F8C7, .mapping = &[_]u21{0x3A6C} },
+    .{ .codepoint = 0x2F8C8, .mapping = &[_]u21{0x654F} },
+    .{ .codepoint = 0x2F8C9, .mapping = &[_]u21{0x656C} },
+    .{ .codepoint = 0x2F8CA, .mapping = &[_]u21{0x2300A} },
+    .{ .codepoint = 0x2F8CB, .mapping = &[_]u21{0x65E3} },
+    .{ .codepoint = 0x2F8CC, .mapping = &[_]u21{0x66F8} },
+    .{ .codepoint = 0x2F8CD, .mapping = &[_]u21{0x6649} },
+    .{ .codepoint = 0x2F8CE, .mapping = &[_]u21{0x3B19} },
+    .{ .codepoint = 0x2F8CF, .mapping = &[_]u21{0x6691} },
+    .{ .codepoint = 0x2F8D0, .mapping = &[_]u21{0x3B08} },
+    .{ .codepoint = 0x2F8D1, .mapping = &[_]u21{0x3AE4} },
+    .{ .codepoint = 0x2F8D2, .mapping = &[_]u21{0x5192} },
+    .{ .codepoint = 0x2F8D3, .mapping = &[_]u21{0x5195} },
+    .{ .codepoint = 0x2F8D4, .mapping = &[_]u21{0x6700} },
+    .{ .codepoint = 0x2F8D5, .mapping = &[_]u21{0x669C} },
+    .{ .codepoint = 0x2F8D6, .mapping = &[_]u21{0x80AD} },
+    .{ .codepoint = 0x2F8D7, .mapping = &[_]u21{0x43D9} },
+    .{ .codepoint = 0x2F8D8, .mapping = &[_]u21{0x6717} },
+    .{ .codepoint = 0x2F8D9, .mapping = &[_]u21{0x671B} },
+    .{ .codepoint = 0x2F8DA, .mapping = &[_]u21{0x6721} },
+    .{ .codepoint = 0x2F8DB, .mapping = &[_]u21{0x675E} },
+    .{ .codepoint = 0x2F8DC, .mapping = &[_]u21{0x6753} },
+    .{ .codepoint = 0x2F8DD, .mapping = &[_]u21{0x233C3} },
+    .{ .codepoint = 0x2F8DE, .mapping = &[_]u21{0x3B49} },
+    .{ .codepoint = 0x2F8DF, .mapping = &[_]u21{0x67FA} },
+    .{ .codepoint = 0x2F8E0, .mapping = &[_]u21{0x6785} },
+    .{ .codepoint = 0x2F8E1, .mapping = &[_]u21{0x6852} },
+    .{ .codepoint = 0x2F8E2, .mapping = &[_]u21{0x6885} },
+    .{ .codepoint = 0x2F8E3, .mapping = &[_]u21{0x2346D} },
+    .{ .codepoint = 0x2F8E4, .mapping = &[_]u21{0x688E} },
+    .{ .codepoint = 0x2F8E5, .mapping = &[_]u21{0x681F} },
+    .{ .codepoint = 0x2F8E6, .mapping = &[_]u21{0x6914} },
+    .{ .codepoint = 0x2F8E7, .mapping = &[_]u21{0x3B9D} },
+    .{ .codepoint = 0x2F8E8, .mapping = &[_]u21{0x6942} },
+    .{ .codepoint = 0x2F8E9, .mapping = &[_]u21{0x69A3} },
+    .{ .codepoint = 0x2F8EA, .mapping = &[_]u21{0x69EA} },
+    .{ .codepoint = 0x2F8EB, .mapping = &[_]u21{0x6AA8} },
+    .{ .codepoint = 0x2F8EC, .mapping = &[_]u21{0x236A3} },
+    .{ .codepoint = 0x2F8ED, .mapping = &[_]u21{0x6ADB} },
+    .{ .codepoint = 0x2F8EE, .mapping = &[_]u21{0x3C18} },
+    .{ .codepoint = 0x2F8EF, .mapping = &[_]u21{0x6B21} },
+    .{ .codepoint = 0x2F8F0, .mapping = &[_]u21{0x238A7} },
+    .{ .codepoint = 0x2F8F1, .mapping = &[_]u21{0x6B54} },
+    .{ .codepoint = 0x2F8F2, .mapping = &[_]u21{0x3C4E} },
+    .{ .codepoint = 0x2F8F3, .mapping = &[_]u21{0x6B72} },
+    .{ .codepoint = 0x2F8F4, .mapping = &[_]u21{0x6B9F} },
+    .{ .codepoint = 0x2F8F5, .mapping = &[_]u21{0x6BBA} },
+    .{ .codepoint = 0x2F8F6, .mapping = &[_]u21{0x6BBB} },
+    .{ .codepoint = 0x2F8F7, .mapping = &[_]u21{0x23A8D} },
+    .{ .codepoint = 0x2F8F8, .mapping = &[_]u21{0x21D0B} },
+    .{ .codepoint = 0x2F8F9, .mapping = &[_]u21{0x23AFA} },
+    .{ .codepoint = 0x2F8FA, .mapping = &[_]u21{0x6C4E} },
+    .{ .codepoint = 0x2F8FB, .mapping = &[_]u21{0x23CBC} },
+    .{ .codepoint = 0x2F8FC, .mapping = &[_]u21{0x6CBF} },
+    .{ .codepoint = 0x2F8FD, .mapping = &[_]u21{0x6CCD} },
+    .{ .codepoint = 0x2F8FE, .mapping = &[_]u21{0x6C67} },
+    .{ .codepoint = 0x2F8FF, .mapping = &[_]u21{0x6D16} },
+    .{ .codepoint = 0x2F900, .mapping = &[_]u21{0x6D3E} },
+    .{ .codepoint = 0x2F901, .mapping = &[_]u21{0x6D77} },
+    .{ .codepoint = 0x2F902, .mapping = &[_]u21{0x6D41} },
+    .{ .codepoint = 0x2F903, .mapping = &[_]u21{0x6D69} },
+    .{ .codepoint = 0x2F904, .mapping = &[_]u21{0x6D78} },
+    .{ .codepoint = 0x2F905, .mapping = &[_]u21{0x6D85} },
+    .{ .codepoint = 0x2F906, .mapping = &[_]u21{0x23D1E} },
+    .{ .codepoint = 0x2F907, .mapping = &[_]u21{0x6D34} },
+    .{ .codepoint = 0x2F908, .mapping = &[_]u21{0x6E2F} },
+    .{ .codepoint = 0x2F909, .mapping = &[_]u21{0x6E6E} },
+    .{ .codepoint = 0x2F90A, .mapping = &[_]u21{0x3D33} },
+    .{ .codepoint = 0x2F90B, .mapping = &[_]u21{0x6ECB} },
+    .{ .codepoint = 0x2F90C, .mapping = &[_]u21{0x6EC7} },
+    .{ .codepoint = 0x2F90D, .mapping = &[_]u21{0x23ED1} },
+    .{ .codepoint = 0x2F90E, .mapping = &[_]u21{0x6DF9} },
+    .{ .codepoint = 0x2F90F, .mapping = &[_]u21{0x6F6E} },
+    .{ .codepoint = 0x2F910, .mapping = &[_]u21{0x23F5E} },
+    .{ .codepoint = 0x2F911, .mapping = &[_]u21{0x23F8E} },
+    .{ .codepoint = 0x2F912, .mapping = &[_]u21{0x6FC6} },
+    .{ .codepoint = 0x2F913, .mapping = &[_]u21{0x7039} },
+    .{ .codepoint = 0x2F914, .mapping = &[_]u21{0x701E} },
+    .{ .codepoint = 0x2F915, .mapping = &[_]u21{0x701B} },
+    .{ .codepoint = 0x2F916, .mapping = &[_]u21{0x3D96} },
+    .{ .codepoint = 0x2F917, .mapping = &[_]u21{0x704A} },
+    .{ .codepoint = 0x2F918, .mapping = &[_]u21{0x707D} },
+    .{ .codepoint = 0x2F919, .mapping = &[_]u21{0x7077} },
+    .{ .codepoint = 0x2F91A, .mapping = &[_]u21{0x70AD} },
+    .{ .codepoint = 0x2F91B, .mapping = &[_]u21{0x20525} },
+    .{ .codepoint = 0x2F91C, .mapping = &[_]u21{0x7145} },
+    .{ .codepoint = 0x2F91D, .mapping = &[_]u21{0x24263} },
+    .{ .codepoint = 0x2F91E, .mapping = &[_]u21{0x719C} },
+    .{ .codepoint = 0x2F91F, .mapping = &[_]u21{0x243AB} },
+    .{ .codepoint = 0x2F920, .mapping = &[_]u21{0x7228} },
+    .{ .codepoint = 0x2F921, .mapping = &[_]u21{0x7235} },
+    .{ .codepoint = 0x2F922, .mapping = &[_]u21{0x7250} },
+    .{ .codepoint = 0x2F923, .mapping = &[_]u21{0x24608} },
+    .{ .codepoint = 0x2F924, .mapping = &[_]u21{0x7280} },
+    .{ .codepoint = 0x2F925, .mapping = &[_]u21{0x7295} },
+    .{ .codepoint = 0x2F926, .mapping = &[_]u21{0x24735} },
+    .{ .codepoint = 0x2F927, .mapping = &[_]u21{0x24814} },
+    .{ .codepoint = 0x2F928, .mapping = &[_]u21{0x737A} },
+    .{ .codepoint = 0x2F929, .mapping = &[_]u21{0x738B} },
+    .{ .codepoint = 0x2F92A, .mapping = &[_]u21{0x3EAC} },
+    .{ .codepoint = 0x2F92B, .mapping = &[_]u21{0x73A5} },
+    .{ .codepoint = 0x2F92C, .mapping = &[_]u21{0x3EB8} },
+    .{ .codepoint = 0x2F92D, .mapping = &[_]u21{0x3EB8} },
+    .{ .codepoint = 0x2F92E, .mapping = &[_]u21{0x7447} },
+    .{ .codepoint = 0x2F92F, .mapping = &[_]u21{0x745C} },
+    .{ .codepoint = 0x2F930, .mapping = &[_]u21{0x7471} },
+    .{ .codepoint = 0x2F931, .mapping = &[_]u21{0x7485} },
+    .{ .codepoint = 0x2F932, .mapping = &[_]u21{0x74CA} },
+    .{ .codepoint = 0x2F933, .mapping = &[_]u21{0x3F1B} },
+    .{ .codepoint = 0x2F934, .mapping = &[_]u21{0x7524} },
+    .{ .codepoint = 0x2F935, .mapping = &[_]u21{0x24C36} },
+    .{ .codepoint = 0x2F936, .mapping = &[_]u21{0x753E} },
+    .{ .codepoint = 0x2F937, .mapping = &[_]u21{0x24C92} },
+    .{ .codepoint = 0x2F938, .mapping = &[_]u21{0x7570} },
+    .{ .codepoint = 0x2F939, .mapping = &[_]u21{0x2219F} },
+    .{ .codepoint = 0x2F93A, .mapping = &[_]u21{0x7610} },
+    .{ .codepoint = 0x2F93B, .mapping = &[_]u21{0x24FA1} },
+    .{ .codepoint = 0x2F93C, .mapping = &[_]u21{0x24FB8} },
+    .{ .codepoint = 0x2F93D, .mapping = &[_]u21{0x25044} },
+    .{ .codepoint = 0x2F93E, .mapping = &[_]u21{0x3FFC} },
+    .{ .codepoint = 0x2F93F, .mapping = &[_]u21{0x4008} },
+    .{ .codepoint = 0x2F940, .mapping = &[_]u21{0x76F4} },
+    .{ .codepoint = 0x2F941, .mapping = &[_]u21{0x250F3} },
+    .{ .codepoint = 0x2F942, .mapping = &[_]u21{0x250F2} },
+    .{ .codepoint = 0x2F943, .mapping = &[_]u21{0x25119} },
+    .{ .codepoint = 0x2F944, .mapping = &[_]u21{0x25133} },
+    .{ .codepoint = 0x2F945, .mapping = &[_]u21{0x771E} },
+    .{ .codepoint = 0x2F946, .mapping = &[_]u21{0x771F} },
+    .{ .codepoint = 0x2F947, .mapping = &[_]u21{0x771F} },
+    .{ .codepoint = 0x2F948, .mapping = &[_]u21{0x774A} },
+    .{ .codepoint = 0x2F949, .mapping = &[_]u21{0x4039} },
+    .{ .codepoint = 0x2F94A, .mapping = &[_]u21{0x778B} },
+    .{ .codepoint = 0x2F94B, .mapping = &[_]u21{0x4046} },
+    .{ .codepoint = 0x2F94C, .mapping = &[_]u21{0x4096} },
+    .{ .codepoint = 0x2F94D, .mapping = &[_]u21{0x2541D} },
+    .{ .codepoint = 0x2F94E, .mapping = &[_]u21{0x784E} },
+    .{ .codepoint = 0x2F94F, .mapping = &[_]u21{0x788C} },
+    .{ .codepoint = 0x2F950, .mapping = &[_]u21{0x78CC} },
+    .{ .codepoint = 0x2F951, .mapping = &[_]u21{0x40E3} },
+    .{ .codepoint = 0x2F952, .mapping = &[_]u21{0x25626} },
+    .{ .codepoint = 0x2F953, .mapping = &[_]u21{0x7956} },
+    .{ .codepoint = 0x2F954, .mapping = &[_]u21{0x2569A} },
+    .{ .codepoint = 0x2F955, .mapping = &[_]u21{0x256C5} },
+    .{ .codepoint = 0x2F956, .mapping = &[_]u21{0x798F} },
+    .{ .codepoint = 0x2F957, .mapping = &[_]u21{0x79EB} },
+    .{ .codepoint = 0x2F958, .mapping = &[_]u21{0x412F} },
+    .{ .codepoint = 0x2F959, .mapping = &[_]u21{0x7A40} },
+    .{ .codepoint = 0x2F95A, .mapping = &[_]u21{0x7A4A} },
+    .{ .codepoint = 0x2F95B, .mapping = &[_]u21{0x7A4F} },
+    .{ .codepoint = 0x2F95C, .mapping = &[_]u21{0x2597C} },
+    .{ .codepoint = 0x2F95D, .mapping = &[_]u21{0x25AA7} },
+    .{ .codepoint = 0x2F95E, .mapping = &[_]u21{0x25AA7} },
+    .{ .codepoint = 0x2F95F, .mapping = &[_]u21{0x7AEE} },
+    .{ .codepoint = 0x2F960, .mapping = &[_]u21{0x4202} },
+    .{ .codepoint = 0x2F961, .mapping = &[_]u21{0x25BAB} },
+    .{ .codepoint = 0x2F962, .mapping = &[_]u21{0x7BC6} },
+    .{ .codepoint = 0x2F963, .mapping = &[_]u21{0x7BC9} },
+    .{ .codepoint = 0x2F964, .mapping = &[_]u21{0x4227} },
+    .{ .codepoint = 0x2F965, .mapping = &[_]u21{0x25C80} },
+    .{ .codepoint = 0x2F966, .mapping = &[_]u21{0x7CD2} },
+    .{ .codepoint = 0x2F967, .mapping = &[_]u21{0x42A0} },
+    .{ .codepoint = 0x2F968, .mapping = &[_]u21{0x7CE8} },
+    .{ .codepoint = 0x2F969, .mapping = &[_]u21{0x7CE3} },
+    .{ .codepoint = 0x2F96A, .mapping = &[_]u21{0x7D00} },
+    .{ .codepoint = 0x2F96B, .mapping = &[_]u21{0x25F86} },
+    .{ .codepoint = 0x2F96C, .mapping = &[_]u21{0x7D63} },
+    .{ .codepoint = 0x2F96D, .mapping = &[_]u21{0x4301} },
+    .{ .codepoint = 0x2F96E, .mapping = &[_]u21{0x7DC7} },
+    .{ .codepoint = 0x2F96F, .mapping = &[_]u21{0x7E02} },
+    .{ .codepoint = 0x2F970, .mapping = &[_]u21{0x7E45} },
+    .{ .codepoint = 0x2F971, .mapping = &[_]u21{0x4334} },
+    .{ .codepoint = 0x2F972, .mapping = &[_]u21{0x26228} },
+    .{ .codepoint = 0x2F973, .mapping = &[_]u21{0x26247} },
+    .{ .codepoint = 0x2F974, .mapping = &[_]u21{0x4359} },
+    .{ .codepoint = 0x2F975, .mapping = &[_]u21{0x262D9} },
+    .{ .codepoint = 0x2F976, .mapping = &[_]u21{0x7F7A} },
+    .{ .codepoint = 0x2F977, .mapping = &[_]u21{0x2633E} },
+    .{ .codepoint = 0x2F978, .mapping = &[_]u21{0x7F95} },
+    .{ .codepoint = 0x2F979, .mapping = &[_]u21{0x7FFA} },
+    .{ .codepoint = 0x2F97A, .mapping = &[_]u21{0x8005} },
+    .{ .codepoint = 0x2F97B, .mapping = &[_]u21{0x264DA} },
+    .{ .codepoint = 0x2F97C, .mapping = &[_]u21{0x26523} },
+    .{ .codepoint = 0x2F97D, .mapping = &[_]u21{0x8060} },
+    .{ .codepoint = 0x2F97E, .mapping = &[_]u21{0x265A8} },
+    .{ .codepoint = 0x2F97F, .mapping = &[_]u21{0x8070} },
+    .{ .codepoint = 0x2F980, .mapping = &[_]u21{0x2335F} },
+    .{ .codepoint = 0x2F981, .mapping = &[_]u21{0x43D5} },
+    .{ .codepoint = 0x2F982, .mapping = &[_]u21{0x80B2} },
+    .{ .codepoint = 0x2F983, .mapping = &[_]u21{0x8103} },
+    .{ .codepoint = 0x2F984, .mapping = &[_]u21{0x440B} },
+    .{ .codepoint = 0x2F985, .mapping = &[_]u21{0x813E} },
+    .{ .codepoint = 0x2F986, .mapping = &[_]u21{0x5AB5} },
+    .{ .codepoint = 0x2F987, .mapping = &[_]u21{0x267A7} },
+    .{ .codepoint = 0x2F988, .mapping = &[_]u21{0x267B5} },
+    .{ .codepoint = 0x2F989, .mapping = &[_]u21{0x23393} },
+    .{ .codepoint = 0x2F98A, .mapping = &[_]u21{0x2339C} },
+    .{ .codepoint = 0x2F98B, .mapping = &[_]u21{0x8201} },
+    .{ .codepoint = 0x2F98C, .mapping = &[_]u21{0x8204} },
+    .{ .codepoint = 0x2F98D, .mapping = &[_]u21{0x8F9E} },
+    .{ .codepoint = 0x2F98E, .mapping = &[_]u21{0x446B} },
+    .{ .codepoint = 0x2F98F, .mapping = &[_]u21{0x8291} },
+    .{ .codepoint = 0x2F990, .mapping = &[_]u21{0x828B} },
+    .{ .codepoint = 0x2F991, .mapping = &[_]u21{0x829D} },
+    .{ .codepoint = 0x2F992, .mapping = &[_]u21{0x52B3} },
+    .{ .codepoint = 0x2F993, .mapping = &[_]u21{0x82B1} },
+    .{ .codepoint = 0x2F994, .mapping = &[_]u21{0x82B3} },
+    .{ .codepoint = 0x2F995, .mapping = &[_]u21{0x82BD} },
+    .{ .codepoint = 0x2F996, .mapping = &[_]u21{0x82E6} },
+    .{ .codepoint = 0x2F997, .mapping = &[_]u21{0x26B3C} },
+    .{ .codepoint = 0x2F998, .mapping = &[_]u21{0x82E5} },
+    .{ .codepoint = 0x2F999, .mapping = &[_]u21{0x831D} },
+    .{ .codepoint = 0x2F99A, .mapping = &[_]u21{0x8363} },
+    .{ .codepoint = 0x2F99B, .mapping = &[_]u21{0x83AD} },
+    .{ .codepoint = 0x2F99C, .mapping = &[_]u21{0x8323} },
+    .{ .codepoint = 0x2F99D, .mapping = &[_]u21{0x83BD} },
+    .{ .codepoint = 0x2F99E, .mapping = &[_]u21{0x83E7} },
+    .{ .codepoint = 0x2F99F, .mapping = &[_]u21{0x8457} },
+    .{ .codepoint = 0x2F9A0, .mapping = &[_]u21{0x8353} },
+    .{ .codepoint = 0x2F9A1, .mapping = &[_]u21{0x83CA} },
+    .{ .codepoint = 0x2F9A2, .mapping = &[_]u21{0x83CC} },
+    .{ .codepoint = 0x2F9A3, .mapping = &[_]u21{0x83DC} },
+    .{ .codepoint = 0x2F9A4, .mapping = &[_]u21{0x26C36} },
+    .{ .codepoint = 0x2F9A5, .mapping = &[_]u21{0x26D6B} },
+    .{ .codepoint = 0x2F9A6, .mapping = &[_]u21{0x26CD5} },
+    .{ .codepoint = 0x2F9A7, .mapping = &[_]u21{0x452B} },
+    .{ .codepoint = 0x2F9A8, .mapping = &[_]u21{0x84F1} },
+    .{ .codepoint = 0x2F9A9, .mapping = &[_]u21{0x84F3} },
+    .{ .codepoint = 0x2F9AA, .mapping = &[_]u21{0x8516} },
+    .{ .codepoint = 0x2F9AB, .mapping = &[_]u21{0x273CA} },
+    .{ .codepoint = 0x2F9AC, .mapping = &[_]u21{0x8564} },
+    .{ .codepoint = 0x2F9AD, .mapping = &[_]u21{0x26F2C} },
+    .{ .codepoint = 0x2F9AE, .mapping = &[_]u21{0x455D} },
+    .{ .codepoint = 0x2F9AF, .mapping = &[_]u21{0x4561} },
+    .{ .codepoint = 0x2F9B0, .mapping = &[_]u21{0x26FB1} },
+    .{ .codepoint = 0x2F9B1, .mapping = &[_]u21{0x270D2} },
+    .{ .codepoint = 0x2F9B2, .mapping = &[_]u21{0x456B} },
+    .{ .codepoint = 0x2F9B3, .mapping = &[_]u21{0x8650} },
+    .{ .codepoint = 0x2F9B4, .mapping = &[_]u21{0x865C} },
+    .{ .codepoint = 0x2F9B5, .mapping = &[_]u21{0x8667} },
+    .{ .codepoint = 0x2F9B6, .mapping = &[_]u21{0x8669} },
+    .{ .codepoint = 0x2F9B7, .mapping = &[_]u21{0x86A9} },
+    .{ .codepoint = 0x2F9B8, .mapping = &[_]u21{0x8688} },
+    .{ .codepoint = 0x2F9B9, .mapping = &[_]u21{0x870E} },
+    .{ .codepoint = 0x2F9BA, .mapping = &[_]u21{0x86E2} },
+    .{ .codepoint = 0x2F9BB, .mapping = &[_]u21{0x8779} },
+    .{ .codepoint = 0x2F9BC, .mapping = &[_]u21{0x8728} },
+    .{ .codepoint = 0x2F9BD, .mapping = &[_]u21{0x876B} },
+    .{ .codepoint = 0x2F9BE, .mapping = &[_]u21{0x8786} },
+    .{ .codepoint = 0x2F9BF, .mapping = &[_]u21{0x45D7} },
+    .{ .codepoint = 0x2F9C0, .mapping = &[_]u21{0x87E1} },
+    .{ .codepoint = 0x2F9C1, .mapping = &[_]u21{0x8801} },
+    .{ .codepoint = 0x2F9C2, .mapping = &[_]u21{0x45F9} },
+    .{ .codepoint = 0x2F9C3, .mapping = &[_]u21{0x8860} },
+    .{ .codepoint = 0x2F9C4, .mapping = &[_]u21{0x8863} },
+    .{ .codepoint = 0x2F9C5, .mapping = &[_]u21{0x27667} },
+    .{ .codepoint = 0x2F9C6, .mapping = &[_]u21{0x88D7} },
+    .{ .codepoint = 0x2F9C7, .mapping = &[_]u21{0x88DE} },
+    .{ .codepoint = 0x2F9C8, .mapping = &[_]u21{0x4635} },
+    .{ .codepoint = 0x2F9C9, .mapping = &[_]u21{0x88FA} },
+    .{ .codepoint = 0x2F9CA, .mapping = &[_]u21{0x34BB} },
+    .{ .codepoint = 0x2F9CB, .mapping = &[_]u21{0x278AE} },
+    .{ .codepoint = 0x2F9CC, .mapping = &[_]u21{0x27966} },
+    .{ .codepoint = 0x2F9CD, .mapping = &[_]u21{0x46BE} },
+    .{ .codepoint = 0x2F9CE, .mapping = &[_]u21{0x46C7} },
+    .{ .codepoint = 0x2F9CF, .mapping = &[_]u21{0x8AA0} },
+    .{ .codepoint = 0x2F9D0, .mapping = &[_]u21{0x8AED} },
+    .{ .codepoint = 0x2F9D1, .mapping = &[_]u21{0x8B8A} },
+    .{ .codepoint = 0x2F9D2, .mapping = &[_]u21{0x8C55} },
+    .{ .codepoint = 0x2F9D3, .mapping = &[_]u21{0x27CA8} },
+    .{ .codepoint = 0x2F9D4, .mapping = &[_]u21{0x8CAB} },
+    .{ .codepoint = 0x2F9D5, .mapping = &[_]u21{0x8CC1} },
+    .{ .codepoint = 0x2F9D6, .mapping = &[_]u21{0x8D1B} },
+    .{ .codepoint = 0x2F9D7, .mapping = &[_]u21{0x8D77} },
+    .{ .codepoint = 0x2F9D8, .mapping = &[_]u21{0x27F2F} },
+    .{ .codepoint = 0x2F9D9, .mapping = &[_]u21{0x20804} },
+    .{ .codepoint = 0x2F9DA, .mapping = &[_]u21{0x8DCB} },
+    .{ .codepoint = 0x2F9DB, .mapping = &[_]u21{0x8DBC} },
+    .{ .codepoint = 0x2F9DC, .mapping = &[_]u21{0x8DF0} },
+    .{ .codepoint = 0x2F9DD, .mapping = &[_]u21{0x208DE} },
+    .{ .codepoint = 0x2F9DE, .mapping = &[_]u21{0x8ED4} },
+    .{ .codepoint = 0x2F9DF, .mapping = &[_]u21{0x8F38} },
+    .{ .codepoint = 0x2F9E0, .mapping = &[_]u21{0x285D2} },
+    .{ .codepoint = 0x2F9E1, .mapping = &[_]u21{0x285ED} },
+    .{ .codepoint = 0x2F9E2, .mapping = &[_]u21{0x9094} },
+    .{ .codepoint = 0x2F9E3, .mapping = &[_]u21{0x90F1} },
+    .{ .codepoint = 0x2F9E4, .mapping = &[_]u21{0x9111} },
+    .{ .codepoint = 0x2F9E5, .mapping = &[_]u21{0x2872E} },
+    .{ .codepoint = 0x2F9E6, .mapping = &[_]u21{0x911B} },
+    .{ .codepoint = 0x2F9E7, .mapping = &[_]u21{0x9238} },
+    .{ .codepoint = 0x2F9E8, .mapping = &[_]u21{0x92D7} },
+    .{ .codepoint = 0x2F9E9, .mapping = &[_]u21{0x92D8} },
+    .{ .codepoint = 0x2F9EA, .mapping = &[_]u21{0x927C} },
+    .{ .codepoint = 0x2F9EB, .mapping = &[_]u21{0x93F9} },
+    .{ .codepoint = 0x2F9EC, .mapping = &[_]u21{0x9415} },
+    .{ .codepoint = 0x2F9ED, .mapping = &[_]u21{0x28BFA} },
+    .{ .codepoint = 0x2F9EE, .mapping = &[_]u21{0x958B} },
+    .{ .codepoint = 0x2F9EF, .mapping = &[_]u21{0x4995} },
+    .{ .codepoint = 0x2F9F0, .mapping = &[_]u21{0x95B7} },
+    .{ .codepoint = 0x2F9F1, .mapping = &[_]u21{0x28D77} },
+    .{ .codepoint = 0x2F9F2, .mapping = &[_]u21{0x49E6} },
+    .{ .codepoint = 0x2F9F3, .mapping = &[_]u21{0x96C3} },
+    .{ .codepoint = 0x2F9F4, .mapping = &[_]u21{0x5DB2} },
+    .{ .codepoint = 0x2F9F5, .mapping = &[_]u21{0x9723} },
+    .{ .codepoint = 0x2F9F6, .mapping = &[_]u21{0x29145} },
+    .{ .codepoint = 0x2F9F7, .mapping = &[_]u21{0x2921A} },
+    .{ .codepoint = 0x2F9F8, .mapping = &[_]u21{0x4A6E} },
+    .{ .codepoint = 0x2F9F9, .mapping = &[_]u21{0x4A76} },
+    .{ .codepoint = 0x2F9FA, .mapping = &[_]u21{0x97E0} },
+    .{ .codepoint = 0x2F9FB, .mapping = &[_]u21{0x2940A} },
+    .{ .codepoint = 0x2F9FC, .mapping = &[_]u21{0x4AB2} },
+    .{ .codepoint = 0x2F9FD, .mapping = &[_]u21{0x29496} },
+    .{ .codepoint = 0x2F9FE, .mapping = &[_]u21{0x980B} },
+    .{ .codepoint = 0x2F9FF, .mapping = &[_]u21{0x980B} },
+    .{ .codepoint = 0x2FA00, .mapping = &[_]u21{0x9829} },
+    .{ .codepoint = 0x2FA01, .mapping = &[_]u21{0x295B6} },
+    .{ .codepoint = 0x2FA02, .mapping = &[_]u21{0x98E2} },
+    .{ .codepoint = 0x2FA03, .mapping = &[_]u21{0x4B33} },
+    .{ .codepoint = 0x2FA04, .mapping = &[_]u21{0x9929} },
+    .{ .codepoint = 0x2FA05, .mapping = &[_]u21{0x99A7} },
+    .{ .codepoint = 0x2FA06, .mapping = &[_]u21{0x99C2} },
+    .{ .codepoint = 0x2FA07, .mapping = &[_]u21{0x99FE} },
+    .{ .codepoint = 0x2FA08, .mapping = &[_]u21{0x4BCE} },
+    .{ .codepoint = 0x2FA09, .mapping = &[_]u21{0x29B30} },
+    .{ .codepoint = 0x2FA0A, .mapping = &[_]u21{0x9B12} },
+    .{ .codepoint = 0x2FA0B, .mapping = &[_]u21{0x9C40} },
+    .{ .codepoint = 0x2FA0C, .mapping = &[_]u21{0x9CFD} },
+    .{ .codepoint = 0x2FA0D, .mapping = &[_]u21{0x4CCE} },
+    .{ .codepoint = 0x2FA0E, .mapping = &[_]u21{0x4CED} },
+    .{ .codepoint = 0x2FA0F, .mapping = &[_]u21{0x9D67} },
+    .{ .codepoint = 0x2FA10, .mapping = &[_]u21{0x2A0CE} },
+    .{ .codepoint = 0x2FA11, .mapping = &[_]u21{0x4CF8} },
+    .{ .codepoint = 0x2FA12, .mapping = &[_]u21{0x2A105} },
+    .{ .codepoint = 0x2FA13, .mapping = &[_]u21{0x2A20E} },
+    .{ .codepoint = 0x2FA14, .mapping = &[_]u21{0x2A291} },
+    .{ .codepoint = 0x2FA15, .mapping = &[_]u21{0x9EBB} },
+    .{ .codepoint = 0x2FA16, .mapping = &[_]u21{0x4D56} },
+    .{ .codepoint = 0x2FA17, .mapping = &[_]u21{0x9EF9} },
+    .{ .codepoint = 0x2FA18, .mapping = &[_]u21{0x9EFE} },
+    .{ .codepoint = 0x2FA19, .mapping = &[_]u21{0x9F05} },
+    .{ .codepoint = 0x2FA1A, .mapping = &[_]u21{0x9F0F} },
+    .{ .codepoint = 0x2FA1B, .mapping = &[_]u21{0x9F16} },
+    .{ .codepoint = 0x2FA1C, .mapping = &[_]u21{0x9F3B} },
+    .{ .codepoint = 0x2FA1D, .mapping = &[_]u21{0x2A600} },
+};
+
+pub const compositions = [_]Composition{
+    .{ .first = 0x003C, .second = 0x0338, .composite = 0x226E },
+    .{ .first = 0x003D, .second = 0x0338, .composite = 0x2260 },
+    .{ .first = 0x003E, .second = 0x0338, .composite = 0x226F },
+    .{ .first = 0x0041, .second = 0x0300, .composite = 0x00C0 },
+    .{ .first = 0x0041, .second = 0x0301, .composite = 0x00C1 },
+    .{ .first = 0x0041, .second = 0x0302, .composite = 0x00C2 },
+    .{ .first = 0x0041, .second = 0x0303, .composite = 0x00C3 },
+    .{ .first = 0x0041, .second = 0x0304, .composite = 0x0100 },
+    .{ .first = 0x0041, .second = 0x0306, .composite = 0x0102 },
+    .{ .first = 0x0041, .second = 0x0307, .composite = 0x0226 },
+    .{ .first = 0x0041, .second = 0x0308, .composite = 0x00C4 },
+    .{ .first = 0x0041, .second = 0x0309, .composite = 0x1EA2 },
+    .{ .first = 0x0041, .second = 0x030A, .composite = 0x00C5 },
+    .{ .first = 0x0041, .second = 0x030C, .composite = 0x01CD },
+    .{ .first = 0x0041, .second = 0x030F, .composite = 0x0200 },
+    .{ .first = 0x0041, .second = 0x0311, .composite = 0x0202 },
+    .{ .first = 0x0041, .second = 0x0323, .composite = 0x1EA0 },
+    .{ .first = 0x0041, .second = 0x0325, .composite = 0x1E00 },
+    .{ .first = 0x0041, .second = 0x0328, .composite = 0x0104 },
+    .{ .first = 0x0042, .second = 0x0307, .composite = 0x1E02 },
+    .{ .first = 0x0042, .second = 0x0323, .composite = 0x1E04 },
+    .{ .first = 0x0042, .second = 0x0331, .composite = 0x1E06 },
+    .{ .first = 0x0043, .second = 0x0301, .composite = 0x0106 },
+    .{ .first = 0x0043, .second = 0x0302, .composite = 0x0108 },
+    .{ .first = 0x0043, .second = 0x0307, .composite = 0x010A },
+    .{ .first = 0x0043, .second = 0x030C, .composite = 0x010C },
+    .{ .first = 0x0043, .second = 0x0327, .composite = 0x00C7 },
+    .{ .first = 0x0044, .second = 0x0307, .composite = 0x1E0A },
+    .{ .first = 0x0044, .second = 0x030C, .composite = 0x010E },
+    .{ .first = 0x0044, .second = 0x0323, .composite = 0x1E0C },
+    .{ .first = 0x0044, .second = 0x0327, .composite = 0x1E10 },
+    .{ .first = 0x0044, .second = 0x032D, .composite = 0x1E12 },
+    .{ .first = 0x0044, .second = 0x0331, .composite = 0x1E0E },
+    .{ .first = 0x0045, .second = 0x0300, .composite = 0x00C8 },
+    .{ .first = 0x0045, .second = 0x0301, .composite = 0x00C9 },
+    .{ .first = 0x0045, .second = 0x0302, .composite = 0x00CA },
+    .{ .first = 0x0045, .second = 0x0303, .composite = 0x1EBC },
+    .{ .first = 0x0045, .second = 0x0304, .composite = 0x0112 },
+    .{ .first = 0x0045, .second = 0x0306, .composite = 0x0114 },
+    .{ .first = 0x0045, .second = 0x0307, .composite = 0x0116 },
+    .{ .first = 0x0045, .second = 0x0308, .composite = 0x00CB },
+    .{ .first = 0x0045, .second = 0x0309, .composite = 0x1EBA },
+    .{ .first = 0x0045, .second = 0x030C, .composite = 0x011A },
+    .{ .first = 0x0045, .second = 0x030F, .composite = 0x0204 },
+    .{ .first = 0x0045, .second = 0x0311, .composite = 0x0206 },
+    .{ .first = 0x0045, .second = 0x0323, .composite = 0x1EB8 },
+    .{ .first = 0x0045, .second = 0x0327, .composite = 0x0228 },
+    .{ .first = 0x0045, .second = 0x0328, .composite = 0x0118 },
+    .{ .first = 0x0045, .second = 0x032D, .composite = 0x1E18 },
+    .{ .first = 0x0045, .second = 0x0330, .composite = 0x1E1A },
+    .{ .first = 0x0046, .second = 0x0307, .composite = 0x1E1E },
+    .{ .first = 0x0047, .second = 0x0301, .composite = 0x01F4 },
+    .{ .first = 0x0047, .second = 0x0302, .composite = 0x011C },
+    .{ .first = 0x0047, .second = 0x0304, .composite = 0x1E20 },
+    .{ .first = 0x0047, .second = 0x0306, .composite = 0x011E },
+    .{ .first = 0x0047, .second = 0x0307, .composite = 0x0120 },
+    .{ .first = 0x0047, .second = 0x030C, .composite = 0x01E6 },
+    .{ .first = 0x0047, .second = 0x0327, .composite = 0x0122 },
+    .{ .first = 0x0048, .second = 0x0302, .composite = 0x0124 },
+    .{ .first = 0x0048, .second = 0x0307, .composite = 0x1E22 },
+    .{ .first = 0x0048, .second = 0x0308, .composite = 0x1E26 },
+    .{ .first = 0x0048, .second = 0x030C, .composite = 0x021E },
+    .{ .first = 0x0048, .second = 0x0323, .composite = 0x1E24 },
+    .{ .first = 0x0048, .second = 0x0327, .composite = 0x1E28 },
+    .{ .first = 0x0048, .second = 0x032E, .composite = 0x1E2A },
+    .{ .first = 0x0049, .second = 0x0300, .composite = 0x00CC },
+    .{ .first = 0x0049, .second = 0x0301, .composite = 0x00CD },
+    .{ .first = 0x0049, .second = 0x0302, .composite = 0x00CE },
+    .{ .first = 0x0049, .second = 0x0303, .composite = 0x0128 },
+    .{ .first = 0x0049, .second = 0x0304, .composite = 0x012A },
+    .{ .first = 0x0049, .second = 0x0306, .composite = 0x012C },
+    .{ .first = 0x0049, .second = 0x0307, .composite = 0x0130 },
+    .{ .first = 0x0049, .second = 0x0308, .composite = 0x00CF },
+    .{ .first = 0x0049, .second = 0x0309, .composite = 0x1EC8 },
+    .{ .first = 0x0049, .second = 0x030C, .composite = 0x01CF },
+    .{ .first = 0x0049, .second = 0x030F, .composite = 0x0208 },
+    .{ .first = 0x0049, .second = 0x0311, .composite = 0x020A },
+    .{ .first = 0x0049, .second = 0x0323, .composite = 0x1ECA },
+    .{ .first = 0x0049, .second = 0x0328, .composite = 0x012E },
+    .{ .first = 0x0049, .second = 0x0330, .composite = 0x1E2C },
+    .{ .first = 0x004A, .second = 0x0302, .composite = 0x0134 },
+    .{ .first = 0x004B, .second = 0x0301, .composite = 0x1E30 },
+    .{ .first = 0x004B, .second = 0x030C, .composite = 0x01E8 },
+    .{ .first = 0x004B, .second = 0x0323, .composite = 0x1E32 },
+    .{ .first = 0x004B, .second = 0x0327, .composite = 0x0136 },
+    .{ .first = 0x004B, .second = 0x0331, .composite = 0x1E34 },
+    .{ .first = 0x004C, .second = 0x0301, .composite = 0x0139 },
+    .{ .first = 0x004C, .second = 0x030C, .composite = 0x013D },
+    .{ .first = 0x004C, .second = 0x0323, .composite = 0x1E36 },
+    .{ .first = 0x004C, .second = 0x0327, .composite = 0x013B },
+    .{ .first = 0x004C, .second = 0x032D, .composite = 0x1E3C },
+    .{ .first = 0x004C, .second = 0x0331, .composite = 0x1E3A },
+    .{ .first = 0x004D, .second = 0x0301, .composite = 0x1E3E },
+    .{ .first = 0x004D, .second = 0x0307, .composite = 0x1E40 },
+    .{ .first = 0x004D, .second = 0x0323, .composite = 0x1E42 },
+    .{ .first = 0x004E, .second = 0x0300, .composite = 0x01F8 },
+    .{ .first = 0x004E, .second = 0x0301, .composite = 0x0143 },
+    .{ .first = 0x004E, .second = 0x0303, .composite = 0x00D1 },
+    .{ .first = 0x004E, .second = 0x0307, .composite = 0x1E44 },
+    .{ .first = 0x004E, .second = 0x030C, .composite = 0x0147 },
+    .{ .first = 0x004E, .second = 0x0323, .composite = 0x1E46 },
+    .{ .first = 0x004E, .second = 0x0327, .composite = 0x0145 },
+    .{ .first = 0x004E, .second = 0x032D, .composite = 0x1E4A },
+    .{ .first = 0x004E, .second = 0x0331, .composite = 0x1E48 },
+    .{ .first = 0x004F, .second = 0x0300, .composite = 0x00D2 },
+    .{ .first = 0x004F, .second = 0x0301, .composite = 0x00D3 },
+    .{ .first = 0x004F, .second = 0x0302, .composite = 0x00D4 },
+    .{ .first = 0x004F, .second = 0x0303, .composite = 0x00D5 },
+    .{ .first = 0x004F, .second = 0x0304, .composite = 0x014C },
+    .{ .first = 0x004F, .second = 0x0306, .composite = 0x014E },
+    .{ .first = 0x004F, .second = 0x0307, .composite = 0x022E },
+    .{ .first = 0x004F, .second = 0x0308, .composite = 0x00D6 },
+    .{ .first = 0x004F, .second = 0x0309, .composite = 0x1ECE },
+    .{ .first = 0x004F, .second = 0x030B, .composite = 0x0150 },
+    .{ .first = 0x004F, .second = 0x030C, .composite = 0x01D1 },
+    .{ .first = 0x004F, .second = 0x030F, .composite = 0x020C },
+    .{ .first = 0x004F, .second = 0x0311, .composite = 0x020E },
+    .{ .first = 0x004F, .second = 0x031B, .composite = 0x01A0 },
+    .{ .first = 0x004F, .second = 0x0323, .composite = 0x1ECC },
+    .{ .first = 0x004F, .second = 0x0328, .composite = 0x01EA },
+    .{ .first = 0x0050, .second = 0x0301, .composite = 0x1E54 },
+    .{ .first = 0x0050, .second = 0x0307, .composite = 0x1E56 },
+    .{ .first = 0x0052, .second = 0x0301, .composite = 0x0154 },
+    .{ .first = 0x0052, .second = 0x0307, .composite = 0x1E58 },
+    .{ .first = 0x0052, .second = 0x030C, .composite = 0x0158 },
+    .{ .first = 0x0052, .second = 0x030F, .composite = 0x0210 },
+    .{ .first = 0x0052, .second = 0x0311, .composite = 0x0212 },
+    .{ .first = 0x0052, .second = 0x0323, .composite = 0x1E5A },
+    .{ .first = 0x0052, .second = 0x0327, .composite = 0x0156 },
+    .{ .first = 0x0052, .second = 0x0331, .composite = 0x1E5E },
+    .{ .first = 0x0053, .second = 0x0301, .composite = 0x015A },
+    .{ .first = 0x0053, .second = 0x0302, .composite = 0x015C },
+    .{ .first = 0x0053, .second = 0x0307, .composite = 0x1E60 },
+    .{ .first = 0x0053, .second = 0x030C, .composite = 0x0160 },
+    .{ .first = 0x0053, .second = 0x0323, .composite = 0x1E62 },
+    .{ .first = 0x0053, .second = 0x0326, .composite = 0x0218 },
+    .{ .first = 0x0053, .second = 0x0327, .composite = 0x015E },
+    .{ .first = 0x0054, .second = 0x0307, .composite = 0x1E6A },
+    .{ .first = 0x0054, .second = 0x030C, .composite = 0x0164 },
+    .{ .first = 0x0054, .second = 0x0323, .composite = 0x1E6C },
+    .{ .first = 0x0054, .second = 0x0326, .composite = 0x021A },
+    .{ .first = 0x0054, .second = 0x0327, .composite = 0x0162 },
+    .{ .first = 0x0054, .second = 0x032D, .composite = 0x1E70 },
+    .{ .first = 0x0054, .second = 0x0331, .composite = 0x1E6E },
+    .{ .first = 0x0055, .second = 0x0300, .composite = 0x00D9 },
+    .{ .first = 0x0055, .second = 0x0301, .composite = 0x00DA },
+    .{ .first = 0x0055, .second = 0x0302, .composite = 0x00DB },
+    .{ .first = 0x0055, .second = 0x0303, .composite = 0x0168 },
+    .{ .first = 0x0055, .second = 0x0304, .composite = 0x016A },
+    .{ .first = 0x0055, .second = 0x0306, .composite = 0x016C },
+    .{ .first = 0x0055, .second = 0x0308, .composite = 0x00DC },
+    .{ .first = 0x0055, .second = 0x0309, .composite = 0x1EE6 },
+    .{ .first = 0x0055, .second = 0x030A, .composite = 0x016E },
+    .{ .first = 0x0055, .second = 0x030B, .composite = 0x0170 },
+    .{ .first = 0x0055, .second = 0x030C, .composite = 0x01D3 },
+    .{ .first = 0x0055, .second = 0x030F, .composite = 0x0214 },
+    .{ .first = 0x0055, .second = 0x0311, .composite = 0x0216 },
+    .{ .first = 0x0055, .second = 0x031B, .composite = 0x01AF },
+    .{ .first = 0x0055, .second = 0x0323, .composite = 0x1EE4 },
+    .{ .first = 0x0055, .second = 0x0324, .composite = 0x1E72 },
+    .{ .first = 0x0055, .second = 0x0328, .composite = 0x0172 },
+    .{ .first = 0x0055, .second = 0x032D, .composite = 0x1E76 },
+    .{ .first = 0x0055, .second = 0x0330, .composite = 0x1E74 },
+    .{ .first = 0x0056, .second = 0x0303, .composite = 0x1E7C },
+    .{ .first = 0x0056, .second = 0x0323, .composite = 0x1E7E },
+    .{ .first = 0x0057, .second = 0x0300, .composite = 0x1E80 },
+    .{ .first = 0x0057, .second = 0x0301, .composite = 0x1E82 },
+    .{ .first = 0x0057, .second = 0x0302, .composite = 0x0174 },
+    .{ .first = 0x0057, .second = 0x0307, .composite = 0x1E86 },
+    .{ .first = 0x0057, .second = 0x0308, .composite = 0x1E84 },
+    .{ .first = 0x0057, .second = 0x0323, .composite = 0x1E88 },
+    .{ .first = 0x0058, .second = 0x0307, .composite = 0x1E8A },
+    .{ .first = 0x0058, .second = 0x0308, .composite = 0x1E8C },
+    .{ .first = 0x0059, .second = 0x0300, .composite = 0x1EF2 },
+    .{ .first = 0x0059, .second = 0x0301, .composite = 0x00DD },
+    .{ .first = 0x0059, .second = 0x0302, .composite = 0x0176 },
+    .{ .first = 0x0059, .second = 0x0303, .composite = 0x1EF8 },
+    .{ .first = 0x0059, .second = 0x0304, .composite = 0x0232 },
+    .{ .first = 0x0059, .second = 0x0307, .composite = 0x1E8E },
+    .{ .first = 0x0059, .second = 0x0308, .composite = 0x0178 },
+    .{ .first = 0x0059, .second = 0x0309, .composite = 0x1EF6 },
+    .{ .first = 0x0059, .second = 0x0323, .composite = 0x1EF4 },
+    .{ .first = 0x005A, .second = 0x0301, .composite = 0x0179 },
+    .{ .first = 0x005A, .second = 0x0302, .composite = 0x1E90 },
+    .{ .first = 0x005A, .second = 0x0307, .composite = 0x017B },
+    .{ .first = 0x005A, .second = 0x030C, .composite = 0x017D },
+    .{ .first = 0x005A, .second = 0x0323, .composite = 0x1E92 },
+    .{ .first = 0x005A, .second = 0x0331, .composite = 0x1E94 },
+    .{ .first = 0x0061, .second = 0x0300, .composite = 0x00E0 },
+    .{ .first = 0x0061, .second = 0x0301, .composite = 0x00E1 },
+    .{ .first = 0x0061, .second = 0x0302, .composite = 0x00E2 },
+    .{ .first = 0x0061, .second = 0x0303, .composite = 0x00E3 },
+    .{ .first = 0x0061, .second = 0x0304, .composite = 0x0101 },
+    .{ .first = 0x0061, .second = 0x0306, .composite = 0x0103 },
+    .{ .first = 0x0061, .second = 0x0307, .composite = 0x0227 },
+    .{ .first = 0x0061, .second = 0x0308, .composite = 0x00E4 },
+    .{ .first = 0x0061, .second = 0x0309, .composite = 0x1EA3 },
+    .{ .first = 0x0061, .second = 0x030A, .composite = 0x00E5 },
+    .{ .first = 0x0061, .second = 0x030C, .composite = 0x01CE },
+    .{ .first = 0x0061, .second = 0x030F, .composite = 0x0201 },
+    .{ .first = 0x0061, .second = 0x0311, .composite = 0x0203 },
+    .{ .first = 0x0061, .second = 0x0323, .composite = 0x1EA1 },
+    .{ .first = 0x0061, .second = 0x0325, .composite = 0x1E01 },
+    .{ .first = 0x0061, .second = 0x0328, .composite = 0x0105 },
+    .{ .first = 0x0062, .second = 0x0307, .composite = 0x1E03 },
+    .{ .first = 0x0062, .second = 0x0323, .composite = 0x1E05 },
+    .{ .first = 0x0062, .second = 0x0331, .composite = 0x1E07 },
+    .{ .first = 0x0063, .second = 0x0301, .composite = 0x0107 },
+    .{ .first = 0x0063, .second = 0x0302, .composite = 0x0109 },
+    .{ .first = 0x0063, .second = 0x0307, .composite = 0x010B },
+    .{ .first = 0x0063, .second = 0x030C, .composite = 0x010D },
+    .{ .first = 0x0063, .second = 0x0327, .composite = 0x00E7 },
+    .{ .first = 0x0064, .second = 0x0307, .composite = 0x1E0B },
+    .{ .first = 0x0064, .second = 0x030C, .composite = 0x010F },
+    .{ .first = 0x0064, .second = 0x0323, .composite = 0x1E0D },
+    .{ .first = 0x0064, .second = 0x0327, .composite = 0x1E11 },
+    .{ .first = 0x0064, .second = 0x032D, .composite = 0x1E13 },
+    .{ .first = 0x0064, .second = 0x0331, .composite = 0x1E0F },
+    .{ .first = 0x0065, .second = 0x0300, .composite = 0x00E8 },
+    .{ .first = 0x0065, .second = 0x0301, .composite = 0x00E9 },
+    .{ .first = 0x0065, .second = 0x0302, .composite = 0x00EA },
+    .{ .first = 0x0065, .second = 0x0303, .composite = 0x1EBD },
+    .{ .first = 0x0065, .second = 0x0304, .composite = 0x0113 },
+    .{ .first = 0x0065, .second = 0x0306, .composite = 0x0115 },
+    .{ .first = 0x0065, .second = 0x0307, .composite = 0x0117 },
+    .{ .first = 0x0065, .second = 0x0308, .composite = 0x00EB },
+    .{ .first = 0x0065, .second = 0x0309, .composite = 0x1EBB },
+    .{ .first = 0x0065, .second = 0x030C, .composite = 0x011B },
+    .{ .first = 0x0065, .second = 0x030F, .composite = 0x0205 },
+    .{ .first = 0x0065, .second = 0x0311, .composite = 0x0207 },
+    .{ .first = 0x0065, .second = 0x0323, .composite = 0x1EB9 },
+    .{ .first = 0x0065, .second = 0x0327, .composite = 0x0229 },
+    .{ .first = 0x0065, .second = 0x0328, .composite = 0x0119 },
+    .{ .first = 0x0065, .second = 0x032D, .composite = 0x1E19 },
+    .{ .first = 0x0065, .second = 0x0330, .composite = 0x1E1B },
+    .{ .first = 0x0066, .second = 0x0307, .composite = 0x1E1F },
+    .{ .first = 0x0067, .second = 0x0301, .composite = 0x01F5 },
+    .{ .first = 0x0067, .second = 0x0302, .composite = 0x011D },
+    .{ .first = 0x0067, .second = 0x0304, .composite = 0x1E21 },
+    .{ .first = 0x0067, .second = 0x0306, .composite = 0x011F },
+    .{ .first = 0x0067, .second = 0x0307, .composite = 0x0121 },
+    .{ .first = 0x0067, .second = 0x030C, .composite = 0x01E7 },
+    .{ .first = 0x0067, .second = 0x0327, .composite = 0x0123 },
+    .{ .first = 0x0068, .second = 0x0302, .composite = 0x0125 },
+    .{ .first = 0x0068, .second = 0x0307, .composite = 0x1E23 },
+    .{ .first = 0x0068, .second = 0x0308, .composite = 0x1E27 },
+    .{ .first = 0x0068, .second = 0x030C, .composite = 0x021F },
+    .{ .first = 0x0068, .second = 0x0323, .composite = 0x1E25 },
+    .{ .first = 0x0068, .second = 0x0327, .composite = 0x1E29 },
+    .{ .first = 0x0068, .second = 0x032E, .composite = 0x1E2B },
+    .{ .first = 0x0068, .second = 0x0331, .composite = 0x1E96 },
+    .{ .first = 0x0069, .second = 0x0300, .composite = 0x00EC },
+    .{ .first = 0x0069, .second = 0x0301, .composite = 0x00ED },
+    .{ .first = 0x0069, .second = 0x0302, .composite = 0x00EE },
+    .{ .first = 0x0069, .second = 0x0303, .composite = 0x0129 },
+    .{ .first = 0x0069, .second = 0x0304, .composite = 0x012B },
+    .{ .first = 0x0069, .second = 0x0306, .composite = 0x012D },
+    .{ .first = 0x0069, .second = 0x0308, .composite = 0x00EF },
+    .{ .first = 0x0069, .second = 0x0309, .composite = 0x1EC9 },
+    .{ .first = 0x0069, .second = 0x030C, .composite = 0x01D0 },
+    .{ .first = 0x0069, .second = 0x030F, .composite = 0x0209 },
+    .{ .first = 0x0069, .second = 0x0311, .composite = 0x020B },
+    .{ .first = 0x0069, .second = 0x0323, .composite = 0x1ECB },
+    .{ .first = 0x0069, .second = 0x0328, .composite = 0x012F },
+    .{ .first = 0x0069, .second = 0x0330, .composite = 0x1E2D },
+    .{ .first = 0x006A, .second = 0x0302, .composite = 0x0135 },
+    .{ .first = 0x006A, .second = 0x030C, .composite = 0x01F0 },
+    .{ .first = 0x006B, .second = 0x0301, .composite = 0x1E31 },
+    .{ .first = 0x006B, .second = 0x030C, .composite = 0x01E9 },
+    .{ .first = 0x006B, .second = 0x0323, .composite = 0x1E33 },
+    .{ .first = 0x006B, .second = 0x0327, .composite = 0x0137 },
+    .{ .first = 0x006B, .second = 0x0331, .composite = 0x1E35 },
+    .{ .first = 0x006C, .second = 0x0301, .composite = 0x013A },
+    .{ .first = 0x006C, .second = 0x030C, .composite = 0x013E },
+    .{ .first = 0x006C, .second = 0x0323, .composite = 0x1E37 },
+    .{ .first = 0x006C, .second = 0x0327, .composite = 0x013C },
+    .{ .first = 0x006C, .second = 0x032D, .composite = 0x1E3D },
+    .{ .first = 0x006C, .second = 0x0331, .composite = 0x1E3B },
+    .{ .first = 0x006D, .second = 0x0301, .composite = 0x1E3F },
+    .{ .first = 0x006D, .second = 0x0307, .composite = 0x1E41 },
+    .{ .first = 0x006D, .second = 0x0323, .composite = 0x1E43 },
+    .{ .first = 0x006E, .second = 0x0300, .composite = 0x01F9 },
+    .{ .first = 0x006E, .second = 0x0301, .composite = 0x0144 },
+    .{ .first = 0x006E, .second = 0x0303, .composite = 0x00F1 },
+    .{ .first = 0x006E, .second = 0x0307, .composite = 0x1E45 },
+    .{ .first = 0x006E, .second = 0x030C, .composite = 0x0148 },
+    .{ .first = 0x006E, .second = 0x0323, .composite = 0x1E47 },
+    .{ .first = 0x006E, .second = 0x0327, .composite = 0x0146 },
+    .{ .first = 0x006E, .second = 0x032D, .composite = 0x1E4B },
+    .{ .first = 0x006E, .second = 0x0331, .composite = 0x1E49 },
+    .{ .first = 0x006F, .second = 0x0300, .composite = 0x00F2 },
+    .{ .first = 0x006F, .second = 0x0301, .composite = 0x00F3 },
+    .{ .first = 0x006F, .second = 0x0302, .composite = 0x00F4 },
+    .{ .first = 0x006F, .second = 0x0303, .composite = 0x00F5 },
+    .{ .first = 0x006F, .second = 0x0304, .composite = 0x014D },
+    .{ .first = 0x006F, .second = 0x0306, .composite = 0x014F },
+    .{ .first = 0x006F, .second = 0x0307, .composite = 0x022F },
+    .{ .first = 0x006F, .second = 0x0308, .composite = 0x00F6 },
+    .{ .first = 0x006F, .second = 0x0309, .composite = 0x1ECF },
+    .{ .first = 0x006F, .second = 0x030B, .composite = 0x0151 },
+    .{ .first = 0x006F, .second = 0x030C, .composite = 0x01D2 },
+    .{ .first = 0x006F, .second = 0x030F, .composite = 0x020D },
+    .{ .first = 0x006F, .second = 0x0311, .composite = 0x020F },
+    .{ .first = 0x006F, .second = 0x031B, .composite = 0x01A1 },
+    .{ .first = 0x006F, .second = 0x0323, .composite = 0x1ECD },
+    .{ .first = 0x006F, .second = 0x0328, .composite = 0x01EB },
+    .{ .first = 0x0070, .second = 0x0301, .composite = 0x1E55 },
+    .{ .first = 0x0070, .second = 0x0307, .composite = 0x1E57 },
+    .{ .first = 0x0072, .second = 0x0301, .composite = 0x0155 },
+    .{ .first = 0x0072, .second = 0x0307, .composite = 0x1E59 },
+    .{ .first = 0x0072, .second = 0x030C, .composite = 0x0159 },
+    .{ .first = 0x0072, .second = 0x030F, .composite = 0x0211 },
+    .{ .first = 0x0072, .second = 0x0311, .composite = 0x0213 },
+    .{ .first = 0x0072, .second = 0x0323, .composite = 0x1E5B },
+    .{ .first = 0x0072, .second = 0x0327, .composite = 0x0157 },
+    .{ .first = 0x0072, .second = 0x0331, .composite = 0x1E5F },
+    .{ .first = 0x0073, .second = 0x0301, .composite = 0x015B },
+    .{ .first = 0x0073, .second = 0x0302, .composite = 0x015D },
+    .{ .first = 0x0073, .second = 0x0307, .composite = 0x1E61 },
+    .{ .first = 0x0073, .second = 0x030C, .composite = 0x0161 },
+    .{ .first = 0x0073, .second = 0x0323, .composite = 0x1E63 },
+    .{ .first = 0x0073, .second = 0x0326, .composite = 0x0219 },
+    .{ .first = 0x0073, .second = 0x0327, .composite = 0x015F },
+    .{ .first = 0x0074, .second = 0x0307, .composite = 0x1E6B },
+    .{ .first = 0x0074, .second = 0x0308, .composite = 0x1E97 },
+    .{ .first = 0x0074, .second = 0x030C, .composite = 0x0165 },
+    .{ .first = 0x0074, .second = 0x0323, .composite = 0x1E6D },
+    .{ .first = 0x0074, .second = 0x0326, .composite = 0x021B },
+    .{ .first = 0x0074, .second = 0x0327, .composite = 0x0163 },
+    .{ .first = 0x0074, .second = 0x032D, .composite = 0x1E71 },
+    .{ .first = 0x0074, .second = 0x0331, .composite = 0x1E6F },
+    .{ .first = 0x0075, .second = 0x0300, .composite = 0x00F9 },
+    .{ .first = 0x0075, .second = 0x0301, .composite = 0x00FA },
+    .{ .first = 0x0075, .second = 0x0302, .composite = 0x00FB },
+    .{ .first = 0x0075, .second = 0x0303, .composite = 0x0169 },
+    .{ .first = 0x0075, .second = 0x0304, .composite = 0x016B },
+    .{ .first = 0x0075, .second = 0x0306, .composite = 0x016D },
+    .{ .first = 0x0075, .second = 0x0308, .composite = 0x00FC },
+    .{ .first = 0x0075, .second = 0x0309, .composite = 0x1EE7 },
+    .{ .first = 0x0075, .second = 0x030A, .composite = 0x016F },
+    .{ .first = 0x0075, .second = 0x030B, .composite = 0x0171 },
+    .{ .first = 0x0075, .second = 0x030C, .composite = 0x01D4 },
+    .{ .first = 0x0075, .second = 0x030F, .composite = 0x0215 },
+    .{ .first = 0x0075, .second = 0x0311, .composite = 0x0217 },
+    .{ .first = 0x0075, .second = 0x031B, .composite = 0x01B0 },
+    .{ .first = 0x0075, .second = 0x0323, .composite = 0x1EE5 },
+    .{ .first = 0x0075, .second = 0x0324, .composite = 0x1E73 },
+    .{ .first = 0x0075, .second = 0x0328, .composite = 0x0173 },
+    .{ .first = 0x0075, .second = 0x032D, .composite = 0x1E77 },
+    .{ .first = 0x0075, .second = 0x0330, .composite = 0x1E75 },
+    .{ .first = 0x0076, .second = 0x0303, .composite = 0x1E7D },
+    .{ .first = 0x0076, .second = 0x0323, .composite = 0x1E7F },
+    .{ .first = 0x0077, .second = 0x0300, .composite = 0x1E81 },
+    .{ .first = 0x0077, .second = 0x0301, .composite = 0x1E83 },
+    .{ .first = 0x0077, .second = 0x0302, .composite = 0x0175 },
+    .{ .first = 0x0077, .second = 0x0307, .composite = 0x1E87 },
+    .{ .first = 0x0077, .second = 0x0308, .composite = 0x1E85 },
+    .{ .first = 0x0077, .second = 0x030A, .composite = 0x1E98 },
+    .{ .first = 0x0077, .second = 0x0323, .composite = 0x1E89 },
+    .{ .first = 0x0078, .second = 0x0307, .composite = 0x1E8B },
+    .{ .first = 0x0078, .second = 0x0308, .composite = 0x1E8D },
+    .{ .first = 0x0079, .second = 0x0300, .composite = 0x1EF3 },
+    .{ .first = 0x0079, .second = 0x0301, .composite = 0x00FD },
+    .{ .first = 0x0079, .second = 0x0302, .composite = 0x0177 },
+    .{ .first = 0x0079, .second = 0x0303, .composite = 0x1EF9 },
+    .{ .first = 0x0079, .second = 0x0304, .composite = 0x0233 },
+    .{ .first = 0x0079, .second = 0x0307, .composite = 0x1E8F },
+    .{ .first = 0x0079, .second = 0x0308, .composite = 0x00FF },
+    .{ .first = 0x0079, .second = 0x0309, .composite = 0x1EF7 },
+    .{ .first = 0x0079, .second = 0x030A, .composite = 0x1E99 },
+    .{ .first = 0x0079, .second = 0x0323, .composite = 0x1EF5 },
+    .{ .first = 0x007A, .second = 0x0301, .composite = 0x017A },
+    .{ .first = 0x007A, .second = 0x0302, .composite = 0x1E91 },
+    .{ .first = 0x007A, .second = 0x0307, .composite = 0x017C },
+    .{ .first = 0x007A, .second = 0x030C, .composite = 0x017E },
+    .{ .first = 0x007A, .second = 0x0323, .composite = 0x1E93 },
+    .{ .first = 0x007A, .second = 0x0331, .composite = 0x1E95 },
+    .{ .first = 0x00A8, .second = 0x0300, .composite = 0x1FED },
+    .{ .first = 0x00A8, .second = 0x0301, .composite = 0x0385 },
+    .{ .first = 0x00A8, .second = 0x0342, .composite = 0x1FC1 },
+    .{ .first = 0x00C2, .second = 0x0300, .composite = 0x1EA6 },
+    .{ .first = 0x00C2, .second = 0x0301, .composite = 0x1EA4 },
+    .{ .first = 0x00C2, .second = 0x0303, .composite = 0x1EAA },
+    .{ .first = 0x00C2, .second = 0x0309, .composite = 0x1EA8 },
+    .{ .first = 0x00C4, .second = 0x0304, .composite = 0x01DE },
+    .{ .first = 0x00C5, .second = 0x0301, .composite = 0x01FA },
+    .{ .first = 0x00C6, .second = 0x0301, .composite = 0x01FC },
+    .{ .first = 0x00C6, .second = 0x0304, .composite = 0x01E2 },
+    .{ .first = 0x00C7, .second = 0x0301, .composite = 0x1E08 },
+    .{ .first = 0x00CA, .second = 0x0300, .composite = 0x1EC0 },
+    .{ .first = 0x00CA, .second = 0x0301, .composite = 0x1EBE },
+    .{ .first = 0x00CA, .second = 0x0303, .composite = 0x1EC4 },
+    .{ .first = 0x00CA, .second = 0x0309, .composite = 0x1EC2 },
+    .{ .first = 0x00CF, .second = 0x0301, .composite = 0x1E2E },
+    .{ .first = 0x00D4, .second = 0x0300, .composite = 0x1ED2 },
+    .{ .first = 0x00D4, .second = 0x0301, .composite = 0x1ED0 },
+    .{ .first = 0x00D4, .second = 0x0303, .composite = 0x1ED6 },
+    .{ .first = 0x00D4, .second = 0x0309, .composite = 0x1ED4 },
+    .{ .first = 0x00D5, .second = 0x0301, .composite = 0x1E4C },
+    .{ .first = 0x00D5, .second = 0x0304, .composite = 0x022C },
+    .{ .first = 0x00D5, .second = 0x0308, .composite = 0x1E4E },
+    .{ .first = 0x00D6, .second = 0x0304, .composite = 0x022A },
+    .{ .first = 0x00D8, .second = 0x0301, .composite = 0x01FE },
+    .{ .first = 0x00DC, .second = 0x0300, .composite = 0x01DB },
+    .{ .first = 0x00DC, .second = 0x0301, .composite = 0x01D7 },
+    .{ .first = 0x00DC, .second = 0x0304, .composite = 0x01D5 },
+    .{ .first = 0x00DC, .second = 0x030C, .composite = 0x01D9 },
+    .{ .first = 0x00E2, .second = 0x0300, .composite = 0x1EA7 },
+    .{ .first = 0x00E2, .second = 0x0301, .composite = 0x1EA5 },
+    .{ .first = 0x00E2, .second = 0x0303, .composite = 0x1EAB },
+    .{ .first = 0x00E2, .second = 0x0309, .composite = 0x1EA9 },
+    .{ .first = 0x00E4, .second = 0x0304, .composite = 0x01DF },
+    .{ .first = 0x00E5, .second = 0x0301, .composite = 0x01FB },
+    .{ .first = 0x00E6, .second = 0x0301, .composite = 0x01FD },
+    .{ .first = 0x00E6, .second = 0x0304, .composite = 0x01E3 },
+    .{ .first = 0x00E7, .second = 0x0301, .composite = 0x1E09 },
+    .{ .first = 0x00EA, .second = 0x0300, .composite = 0x1EC1 },
+    .{ .first = 0x00EA, .second = 0x0301, .composite = 0x1EBF },
+    .{ .first = 0x00EA, .second = 0x0303, .composite = 0x1EC5 },
+    .{ .first = 0x00EA, .second = 0x0309, .composite = 0x1EC3 },
+    .{ .first = 0x00EF, .second = 0x0301, .composite = 0x1E2F },
+    .{ .first = 0x00F4, .second = 0x0300, .composite = 0x1ED3 },
+    .{ .first = 0x00F4, .second = 0x0301, .composite = 0x1ED1 },
+    .{ .first = 0x00F4, .second = 0x0303, .composite = 0x1ED7 },
+    .{ .first = 0x00F4, .second = 0x0309, .composite = 0x1ED5 },
+    .{ .first = 0x00F5, .second = 0x0301, .composite = 0x1E4D },
+    .{ .first = 0x00F5, .second = 0x0304, .composite = 0x022D },
+    .{ .first = 0x00F5, .second = 0x0308, .composite = 0x1E4F },
+    .{ .first = 0x00F6, .second = 0x0304, .composite = 0x022B },
+    .{ .first = 0x00F8, .second = 0x0301, .composite = 0x01FF },
+    .{ .first = 0x00FC, .second = 0x0300, .composite = 0x01DC },
+    .{ .first = 0x00FC, .second = 0x0301, .composite = 0x01D8 },
+    .{ .first = 0x00FC, .second = 0x0304, .composite = 0x01D6 },
+    .{ .first = 0x00FC, .second = 0x030C, .composite = 0x01DA },
+    .{ .first = 0x0102, .second = 0x0300, .composite = 0x1EB0 },
+    .{ .first = 0x0102, .second = 0x0301, .composite = 0x1EAE },
+    .{ .first = 0x0102, .second = 0x0303, .composite = 0x1EB4 },
+    .{ .first = 0x0102, .second = 0x0309, .composite = 0x1EB2 },
+    .{ .first = 0x0103, .second = 0x0300, .composite = 0x1EB1 },
+    .{ .first = 0x0103, .second = 0x0301, .composite = 0x1EAF },
+    .{ .first = 0x0103, .second = 0x0303, .composite = 0x1EB5 },
+    .{ .first = 0x0103, .second = 0x0309, .composite = 0x1EB3 },
+    .{ .first = 0x0112, .second = 0x0300, .composite = 0x1E14 },
+    .{ .first = 0x0112, .second = 0x0301, .composite = 0x1E16 },
+    .{ .first = 0x0113, .second = 0x0300, .composite = 0x1E15 },
+    .{ .first = 0x0113, .second = 0x0301, .composite = 0x1E17 },
+    .{ .first = 0x014C, .second = 0x0300, .composite = 0x1E50 },
+    .{ .first = 0x014C, .second = 0x0301, .composite = 0x1E52 },
+    .{ .first = 0x014D, .second = 0x0300, .composite = 0x1E51 },
+    .{ .first = 0x014D, .second = 0x0301, .composite = 0x1E53 },
+    .{ .first = 0x015A, .second = 0x0307, .composite = 0x1E64 },
+    .{ .first = 0x015B, .second = 0x0307, .composite = 0x1E65 },
+    .{ .first = 0x0160, .second = 0x0307, .composite = 0x1E66 },
+    .{ .first = 0x0161, .second = 0x0307, .composite = 0x1E67 },
+    .{ .first = 0x0168, .second = 0x0301, .composite = 0x1E78 },
+    .{ .first = 0x0169, .second = 0x0301, .composite = 0x1E79 },
+    .{ .first = 0x016A, .second = 0x0308, .composite = 0x1E7A },
+    .{ .first = 0x016B, .second = 0x0308, .composite = 0x1E7B },
+    .{ .first = 0x017F, .second = 0x0307, .composite = 0x1E9B },
+    .{ .first = 0x01A0, .second = 0x0300, .composite = 0x1EDC },
+    .{ .first = 0x01A0, .second = 0x0301, .composite = 0x1EDA },
+    .{ .first = 0x01A0, .second = 0x0303, .composite = 0x1EE0 },
+    .{ .first = 0x01A0, .second = 0x0309, .composite = 0x1EDE },
+    .{ .first = 0x01A0, .second = 0x0323, .composite = 0x1EE2 },
+    .{ .first = 0x01A1, .second = 0x0300, .composite = 0x1EDD },
+    .{ .first = 0x01A1, .second = 0x0301, .composite = 0x1EDB },
+    .{ .first = 0x01A1, .second = 0x0303, .composite = 0x1EE1 },
+    .{ .first = 0x01A1, .second = 0x0309, .composite = 0x1EDF },
+    .{ .first = 0x01A1, .second = 0x0323, .composite = 0x1EE3 },
+    .{ .first = 0x01AF, .second = 0x0300, .composite = 0x1EEA },
+    .{ .first = 0x01AF, .second = 0x0301, .composite = 0x1EE8 },
+    .{ .first = 0x01AF, .second = 0x0303, .composite = 0x1EEE },
+    .{ .first = 0x01AF, .second = 0x0309, .composite = 0x1EEC },
+    .{ .first = 0x01AF, .second = 0x0323, .composite = 0x1EF0 },
+    .{ .first = 0x01B0, .second = 0x0300, .composite = 0x1EEB },
+    .{ .first = 0x01B0, .second = 0x0301, .composite = 0x1EE9 },
+    .{ .first = 0x01B0, .second = 0x0303, .composite = 0x1EEF },
+    .{ .first = 0x01B0, .second = 0x0309, .composite = 0x1EED },
+    .{ .first = 0x01B0, .second = 0x0323, .composite = 0x1EF1 },
+    .{ .first = 0x01B7, .second = 0x030C, .composite = 0x01EE },
+    .{ .first = 0x01EA, .second = 0x0304, .composite = 0x01EC },
+    .{ .first = 0x01EB, .second = 0x0304, .composite = 0x01ED },
+    .{ .first = 0x0226, .second = 0x0304, .composite = 0x01E0 },
+    .{ .first = 0x0227, .second = 0x0304, .composite = 0x01E1 },
+    .{ .first = 0x0228, .second = 0x0306, .composite = 0x1E1C },
+    .{ .first = 0x0229, .second = 0x0306, .composite = 0x1E1D },
+    .{ .first = 0x022E, .second = 0x0304, .composite = 0x0230 },
+    .{ .first = 0x022F, .second = 0x0304, .composite = 0x0231 },
+    .{ .first = 0x0292, .second = 0x030C, .composite = 0x01EF },
+    .{ .first = 0x0391, .second = 0x0300, .composite = 0x1FBA },
+    .{ .first = 0x0391, .second = 0x0301, .composite = 0x0386 },
+    .{ .first = 0x0391, .second = 0x0304, .composite = 0x1FB9 },
+    .{ .first = 0x0391, .second = 0x0306, .composite = 0x1FB8 },
+    .{ .first = 0x0391, .second = 0x0313, .composite = 0x1F08 },
+    .{ .first = 0x0391, .second = 0x0314, .composite = 0x1F09 },
+    .{ .first = 0x0391, .second = 0x0345, .composite = 0x1FBC },
+    .{ .first = 0x0395, .second = 0x0300, .composite = 0x1FC8 },
+    .{ .first = 0x0395, .second = 0x0301, .composite = 0x0388 },
+    .{ .first = 0x0395, .second = 0x0313, .composite = 0x1F18 },
+    .{ .first = 0x0395, .second = 0x0314, .composite = 0x1F19 },
+    .{ .first = 0x0397, .second = 0x0300, .composite = 0x1FCA },
+    .{ .first = 0x0397, .second = 0x0301, .composite = 0x0389 },
+    .{ .first = 0x0397, .second = 0x0313, .composite = 0x1F28 },
+    .{ .first = 0x0397, .second = 0x0314, .composite = 0x1F29 },
+    .{ .first = 0x0397, .second = 0x0345, .composite = 0x1FCC },
+    .{ .first = 0x0399, .second = 0x0300, .composite = 0x1FDA },
+    .{ .first = 0x0399, .second = 0x0301, .composite = 0x038A },
+    .{ .first = 0x0399, .second = 0x0304, .composite = 0x1FD9 },
+    .{ .first = 0x0399, .second = 0x0306, .composite = 0x1FD8 },
+    .{ .first = 0x0399, .second = 0x0308, .composite = 0x03AA },
+    .{ .first = 0x0399, .second = 0x0313, .composite = 0x1F38 },
+    .{ .first = 0x0399, .second = 0x0314, .composite = 0x1F39 },
+    .{ .first = 0x039F, .second = 0x0300, .composite = 0x1FF8 },
+    .{ .first = 0x039F, .second = 0x0301, .composite = 0x038C },
+    .{ .first = 0x039F, .second = 0x0313, .composite = 0x1F48 },
+    .{ .first = 0x039F, .second = 0x0314, .composite = 0x1F49 },
+    .{ .first = 0x03A1, .second = 0x0314, .composite = 0x1FEC },
+    .{ .first = 0x03A5, .second = 0x0300, .composite = 0x1FEA },
+    .{ .first = 0x03A5, .second = 0x0301, .composite = 0x038E },
+    .{ .first = 0x03A5, .second = 0x0304, .composite = 0x1FE9 },
+    .{ .first = 0x03A5, .second = 0x0306, .composite = 0x1FE8 },
+    .{ .first = 0x03A5, .second = 0x0308, .composite = 0x03AB },
+    .{ .first = 0x03A5, .second = 0x0314, .composite = 0x1F59 },
+    .{ .first = 0x03A9, .second = 0x0300, .composite = 0x1FFA },
+    .{ .first = 0x03A9, .second = 0x0301, .composite = 0x038F },
+    .{ .first = 0x03A9, .second = 0x0313, .composite = 0x1F68 },
+    .{ .first = 0x03A9, .second = 0x0314, .composite = 0x1F69 },
+    .{ .first = 0x03A9, .second = 0x0345, .composite = 0x1FFC },
+    .{ .first = 0x03AC, .second = 0x0345, .composite = 0x1FB4 },
+    .{ .first = 0x03AE, .second = 0x0345, .composite = 0x1FC4 },
+    .{ .first = 0x03B1, .second = 0x0300, .composite = 0x1F70 },
+    .{ .first = 0x03B1, .second = 0x0301, .composite = 0x03AC },
+    .{ .first = 0x03B1, .second = 0x0304, .composite = 0x1FB1 },
+    .{ .first = 0x03B1, .second = 0x0306, .composite = 0x1FB0 },
+    .{ .first = 0x03B1, .second = 0x0313, .composite = 0x1F00 },
+    .{ .first = 0x03B1, .second = 0x0314, .composite = 0x1F01 },
+    .{ .first = 0x03B1, .second = 0x0342, .composite = 0x1FB6 },
+    .{ .first = 0x03B1, .second = 0x0345, .composite = 0x1FB3 },
+    .{ .first = 0x03B5, .second = 0x0300, .composite = 0x1F72 },
+    .{ .first = 0x03B5, .second = 0x0301, .composite = 0x03AD },
+    .{ .first = 0x03B5, .second = 0x0313, .composite = 0x1F10 },
+    .{ .first = 0x03B5, .second = 0x0314, .composite = 0x1F11 },
+    .{ .first = 0x03B7, .second = 0x0300, .composite = 0x1F74 },
+    .{ .first = 0x03B7, .second = 0x0301, .composite = 0x03AE },
+    .{ .first = 0x03B7, .second = 0x0313, .composite = 0x1F20 },
+    .{ .first = 0x03B7, .second = 0x0314, .composite = 0x1F21 },
+    .{ .first = 0x03B7, .second = 0x0342, .composite = 0x1FC6 },
+    .{ .first = 0x03B7, .second = 0x0345, .composite = 0x1FC3 },
+    .{ .first = 0x03B9, .second = 0x0300, .composite = 0x1F76 },
+    .{ .first = 0x03B9, .second = 0x0301, .composite = 0x03AF },
+    .{ .first = 0x03B9, .second = 0x0304, .composite = 0x1FD1 },
+    .{ .first = 0x03B9, .second = 0x0306, .composite = 0x1FD0 },
+    .{ .first = 0x03B9, .second = 0x0308, .composite = 0x03CA },
+    .{ .first = 0x03B9, .second = 0x0313, .composite = 0x1F30 },
+    .{ .first = 0x03B9, .second = 0x0314, .composite = 0x1F31 },
+    .{ .first = 0x03B9, .second = 0x0342, .composite = 0x1FD6 },
+    .{ .first = 0x03BF, .second = 0x0300, .composite = 0x1F78 },
+    .{ .first = 0x03BF, .second = 0x0301, .composite = 0x03CC },
+    .{ .first = 0x03BF, .second = 0x0313, .composite = 0x1F40 },
+    .{ .first = 0x03BF, .second = 0x0314, .composite = 0x1F41 },
+    .{ .first = 0x03C1, .second = 0x0313, .composite = 0x1FE4 },
+    .{ .first = 0x03C1, .second = 0x0314, .composite = 0x1FE5 },
+    .{ .first = 0x03C5, .second = 0x0300, .composite = 0x1F7A },
+    .{ .first = 0x03C5, .second = 0x0301, .composite = 0x03CD },
+    .{ .first = 0x03C5, .second = 0x0304, .composite = 0x1FE1 },
+    .{ .first = 0x03C5, .second = 0x0306, .composite = 0x1FE0 },
+    .{ .first = 0x03C5, .second = 0x0308, .composite = 0x03CB },
+    .{ .first = 0x03C5, .second = 0x0313, .composite = 0x1F50 },
+    .{ .first = 0x03C5, .second = 0x0314, .composite = 0x1F51 },
+    .{ .first = 0x03C5, .second = 0x0342, .composite = 0x1FE6 },
+    .{ .first = 0x03C9, .second = 0x0300, .composite = 0x1F7C },
+    .{ .first = 0x03C9, .second = 0x0301, .composite = 0x03CE },
+    .{ .first = 0x03C9, .second = 0x0313, .composite = 0x1F60 },
+    .{ .first = 0x03C9, .second = 0x0314, .composite = 0x1F61 },
+    .{ .first = 0x03C9, .second = 0x0342, .composite = 0x1FF6 },
+    .{ .first = 0x03C9, .second = 0x0345, .composite = 0x1FF3 },
+    .{ .first = 0x03CA, .second = 0x0300, .composite = 0x1FD2 },
+    .{ .first = 0x03CA, .second = 0x0301, .composite = 0x0390 },
+    .{ .first = 0x03CA, .second = 0x0342, .composite = 0x1FD7 },
+    .{ .first = 0x03CB, .second = 0x0300, .composite = 0x1FE2 },
+    .{ .first = 0x03CB, .second = 0x0301, .composite = 0x03B0 },
+    .{ .first = 0x03CB, .second = 0x0342, .composite = 0x1FE7 },
+    .{ .first = 0x03CE, .second = 0x0345, .composite = 0x1FF4 },
+    .{ .first = 0x03D2, .second = 0x0301, .composite = 0x03D3 },
+    .{ .first = 0x03D2, .second = 0x0308, .composite = 0x03D4 },
+    .{ .first = 0x0406, .second = 0x0308, .composite = 0x0407 },
+    .{ .first = 0x0410, .second = 0x0306, .composite = 0x04D0 },
+    .{ .first = 0x0410, .second = 0x0308, .composite = 0x04D2 },
+    .{ .first = 0x0413, .second = 0x0301, .composite = 0x0403 },
+    .{ .first = 0x0415, .second = 0x0300, .composite = 0x0400 },
+    .{ .first = 0x0415, .second = 0x0306, .composite = 0x04D6 },
+    .{ .first = 0x0415, .second = 0x0308, .composite = 0x0401 },
+    .{ .first = 0x0416, .second = 0x0306, .composite = 0x04C1 },
+    .{ .first = 0x0416, .second = 0x0308, .composite = 0x04DC },
+    .{ .first = 0x0417, .second = 0x0308, .composite = 0x04DE },
+    .{ .first = 0x0418, .second = 0x0300, .composite = 0x040D },
+    .{ .first = 0x0418, .second = 0x0304, .composite = 0x04E2 },
+    .{ .first = 0x0418, .second = 0x0306, .composite = 0x0419 },
+    .{ .first = 0x0418, .second = 0x0308, .composite = 0x04E4 },
+    .{ .first = 0x041A, .second = 0x0301, .composite = 0x040C },
+    .{ .first = 0x041E, .second = 0x0308, .composite = 0x04E6 },
+    .{ .first = 0x0423, .second = 0x0304, .composite = 0x04EE },
+    .{ .first = 0x0423, .second = 0x0306, .composite = 0x040E },
+    .{ .first = 0x0423, .second = 0x0308, .composite = 0x04F0 },
+    .{ .first = 0x0423, .second = 0x030B, .composite = 0x04F2 },
+    .{ .first = 0x0427, .second = 0x0308, .composite = 0x04F4 },
+    .{ .first = 0x042B, .second = 0x0308, .composite = 0x04F8 },
+    .{ .first = 0x042D, .second = 0x0308, .composite = 0x04EC },
+    .{ .first = 0x0430, .second = 0x0306, .composite = 0x04D1 },
+    .{ .first = 0x0430, .second = 0x0308, .composite = 0x04D3 },
+    .{ .first = 0x0433, .second = 0x0301, .composite = 0x0453 },
+    .{ .first = 0x0435, .second = 0x0300, .composite = 0x0450 },
+    .{ .first = 0x0435, .second = 0x0306, .composite = 0x04D7 },
+    .{ .first = 0x0435, .second = 0x0308, .composite = 0x0451 },
+    .{ .first = 0x0436, .second = 0x0306, .composite = 0x04C2 },
+    .{ .first = 0x0436, .second = 0x0308, .composite = 0x04DD },
+    .{ .first = 0x0437, .second = 0x0308, .composite = 0x04DF },
+    .{ .first = 0x0438, .second = 0x0300, .composite = 0x045D },
+    .{ .first = 0x0438, .second = 0x0304, .composite = 0x04E3 },
+    .{ .first = 0x0438, .second = 0x0306, .composite = 0x0439 },
+    .{ .first = 0x0438, .second = 0x0308, .composite = 0x04E5 },
+    .{ .first = 0x043A, .second = 0x0301, .composite = 0x045C },
+    .{ .first = 0x043E, .second = 0x0308, .composite = 0x04E7 },
+    .{ .first = 0x0443, .second = 0x0304, .composite = 0x04EF },
+    .{ .first = 0x0443, .second = 0x0306, .composite = 0x045E },
+    .{ .first = 0x0443, .second = 0x0308, .composite = 0x04F1 },
+    .{ .first = 0x0443, .second = 0x030B, .composite = 0x04F3 },
+    .{ .first = 0x0447, .second = 0x0308, .composite = 0x04F5 },
+    .{ .first = 0x044B, .second = 0x0308, .composite = 0x04F9 },
+    .{ .first = 0x044D, .second = 0x0308, .composite = 0x04ED },
+    .{ .first = 0x0456, .second = 0x0308, .composite = 0x0457 },
+    .{ .first = 0x0474, .second = 0x030F, .composite = 0x0476 },
+    .{ .first = 0x0475, .second = 0x030F, .composite = 0x0477 },
+    .{ .first = 0x04D8, .second = 0x0308, .composite = 0x04DA },
+    .{ .first = 0x04D9, .second = 0x0308, .composite = 0x04DB },
+    .{ .first = 0x04E8, .second = 0x0308, .composite = 0x04EA },
+    .{ .first = 0x04E9, .second = 0x0308, .composite = 0x04EB },
+    .{ .first = 0x0627, .second = 0x0653, .composite = 0x0622 },
+    .{ .first = 0x0627, .second = 0x0654, .composite = 0x0623 },
+    .{ .first = 0x0627, .second = 0x0655, .composite = 0x0625 },
+    .{ .first = 0x0648, .second = 0x0654, .composite = 0x0624 },
+    .{ .first = 0x064A, .second = 0x0654, .composite = 0x0626 },
+    .{ .first = 0x06C1, .second = 0x0654, .composite = 0x06C2 },
+    .{ .first = 0x06D2, .second = 0x0654, .composite = 0x06D3 },
+    .{ .first = 0x06D5, .second = 0x0654, .composite = 0x06C0 },
+    .{ .first = 0x0928, .second = 0x093C, .composite = 0x0929 },
+    .{ .first = 0x0930, .second = 0x093C, .composite = 0x0931 },
+    .{ .first = 0x0933, .second = 0x093C, .composite = 0x0934 },
+    .{ .first = 0x09C7, .second = 0x09BE, .composite = 0x09CB },
+    .{ .first = 0x09C7, .second = 0x09D7, .composite = 0x09CC },
+    .{ .first = 0x0B47, .second = 0x0B3E, .composite = 0x0B4B },
+    .{ .first = 0x0B47, .second = 0x0B56, .composite = 0x0B48 },
+    .{ .first = 0x0B47, .second = 0x0B57, .composite = 0x0B4C },
+    .{ .first = 0x0B92, .second = 0x0BD7, .composite = 0x0B94 },
+    .{ .first = 0x0BC6, .second = 0x0BBE, .composite = 0x0BCA },
+    .{ .first = 0x0BC6, .second = 0x0BD7, .composite = 0x0BCC },
+    .{ .first = 0x0BC7, .second = 0x0BBE, .composite = 0x0BCB },
+    .{ .first = 0x0C46, .second = 0x0C56, .composite = 0x0C48 },
+    .{ .first = 0x0CBF, .second = 0x0CD5, .composite = 0x0CC0 },
+    .{ .first = 0x0CC6, .second = 0x0CC2, .composite = 0x0CCA },
+    .{ .first = 0x0CC6, .second = 0x0CD5, .composite = 0x0CC7 },
+    .{ .first = 0x0CC6, .second = 0x0CD6, .composite = 0x0CC8 },
+    .{ .first = 0x0CCA, .second = 0x0CD5, .composite = 0x0CCB },
+    .{ .first = 0x0D46, .second = 0x0D3E, .composite = 0x0D4A },
+    .{ .first = 0x0D46, .second = 0x0D57, .composite = 0x0D4C },
+    .{ .first = 0x0D47, .second = 0x0D3E, .composite = 0x0D4B },
+    .{ .first = 0x0DD9, .second = 0x0DCA, .composite = 0x0DDA },
+    .{ .first = 0x0DD9, .second = 0x0DCF, .composite = 0x0DDC },
+    .{ .first = 0x0DD9, .second = 0x0DDF, .composite = 0x0DDE },
+    .{ .first = 0x0DDC, .second = 0x0DCA, .composite = 0x0DDD },
+    .{ .first = 0x1025, .second = 0x102E, .composite = 0x1026 },
+    .{ .first = 0x1B05, .second = 0x1B35, .composite = 0x1B06 },
+    .{ .first = 0x1B07, .second = 0x1B35, .composite = 0x1B08 },
+    .{ .first = 0x1B09, .second = 0x1B35, .composite = 0x1B0A },
+    .{ .first = 0x1B0B, .second = 0x1B35, .composite = 0x1B0C },
+    .{ .first = 0x1B0D, .second = 0x1B35, .composite = 0x1B0E },
+    .{ .first = 0x1B11, .second = 0x1B35, .composite = 0x1B12 },
+    .{ .first = 0x1B3A, .second = 0x1B35, .composite = 0x1B3B },
+    .{ .first = 0x1B3C, .second = 0x1B35, .composite = 0x1B3D },
+    .{ .first = 0x1B3E, .second = 0x1B35, .composite = 0x1B40 },
+    .{ .first = 0x1B3F, .second = 0x1B35, .composite = 0x1B41 },
+    .{ .first = 0x1B42, .second = 0x1B35, .composite = 0x1B43 },
+    .{ .first = 0x1E36, .second = 0x0304, .composite = 0x1E38 },
+    .{ .first = 0x1E37, .second = 0x0304, .composite = 0x1E39 },
+    .{ .first = 0x1E5A, .second = 0x0304, .composite = 0x1E5C },
+    .{ .first = 0x1E5B, .second = 0x0304, .composite = 0x1E5D },
+    .{ .first = 0x1E62, .second = 0x0307, .composite = 0x1E68 },
+    .{ .first = 0x1E63, .second = 0x0307, .composite = 0x1E69 },
+    .{ .first = 0x1EA0, .second = 0x0302, .composite = 0x1EAC },
+    .{ .first = 0x1EA0, .second = 0x0306, .composite = 0x1EB6 },
+    .{ .first = 0x1EA1, .second = 0x0302, .composite = 0x1EAD },
+    .{ .first = 0x1EA1, .second = 0x0306, .composite = 0x1EB7 },
+    .{ .first = 0x1EB8, .second = 0x0302, .composite = 0x1EC6 },
+    .{ .first = 0x1EB9, .second = 0x0302, .composite = 0x1EC7 },
+    .{ .first = 0x1ECC, .second = 0x0302, .composite = 0x1ED8 },
+    .{ .first = 0x1ECD, .second = 0x0302, .composite = 0x1ED9 },
+    .{ .first = 0x1F00, .second = 0x0300, .composite = 0x1F02 },
+    .{ .first = 0x1F00, .second = 0x0301, .composite = 0x1F04 },
+    .{ .first = 0x1F00, .second = 0x0342, .composite = 0x1F06 },
+    .{ .first = 0x1F00, .second = 0x0345, .composite = 0x1F80 },
+    .{ .first = 0x1F01, .second = 0x0300, .composite = 0x1F03 },
+    .{ .first = 0x1F01, .second = 0x0301, .composite = 0x1F05 },
+    .{ .first = 0x1F01, .second = 0x0342, .composite = 0x1F07 },
+    .{ .first = 0x1F01, .second = 0x0345, .composite = 0x1F81 },
+    .{ .first = 0x1F02, .second = 0x0345, .composite = 0x1F82 },
+    .{ .first = 0x1F03, .second = 0x0345, .composite = 0x1F83 },
+    .{ .first = 0x1F04, .second = 0x0345, .composite = 0x1F84 },
+    .{ .first = 0x1F05, .second = 0x0345, .composite = 0x1F85 },
+    .{ .first = 0x1F06, .second = 0x0345, .composite = 0x1F86 },
+    .{ .first = 0x1F07, .second = 0x0345, .composite = 0x1F87 },
+    .{ .first = 0x1F08, .second = 0x0300, .composite = 0x1F0A },
+    .{ .first = 0x1F08, .second = 0x0301, .composite = 0x1F0C },
+    .{ .first = 0x1F08, .second = 0x0342, .composite = 0x1F0E },
+    .{ .first = 0x1F08, .second = 0x0345, .composite = 0x1F88 },
+    .{ .first = 0x1F09, .second = 0x0300, .composite = 0x1F0B },
+    .{ .first = 0x1F09, .second = 0x0301, .composite = 0x1F0D },
+    .{ .first = 0x1F09, .second = 0x0342, .composite = 0x1F0F },
+    .{ .first = 0x1F09, .second = 0x0345, .composite = 0x1F89 },
+    .{ .first = 0x1F0A, .second = 0x0345, .composite = 0x1F8A },
+    .{ .first = 0x1F0B, .second = 0x0345, .composite = 0x1F8B },
+    .{ .first = 0x1F0C, .second = 0x0345, .composite = 0x1F8C },
+    .{ .first = 0x1F0D, .second = 0x0345, .composite = 0x1F8D },
+    .{ .first = 0x1F0E, .second = 0x0345, .composite = 0x1F8E },
+    .{ .first = 0x1F0F, .second = 0x0345, .composite = 0x1F8F },
+    .{ .first = 0x1F10, .second = 0x0300, .composite = 0x1F12 },
+    .{ .first = 0x1F10, .second = 0x0301, .composite = 0x1F14 },
+    .{ .first = 0x1F11, .second = 0x0300, .composite = 0x1F13 },
+    .{ .first = 0x1F11, .second = 0x0301, .composite = 0x1F15 },
+    .{ .first = 0x1F18, .second = 0x0300, .composite = 0x1F1A },
+    .{ .first = 0x1F18, .second = 0x0301, .composite = 0x1F1C },
+    .{ .first = 0x1F19, .second = 0x0300, .composite = 0x1F1B },
+    .{ .first = 0x1F19, .second = 0x0301, .composite = 0x1F1D },
+    .{ .first = 0x1F20, .second = 0x0300, .composite = 0x1F22 },
+    .{ .first = 0x1F20, .second = 0x0301, .composite = 0x1F24 },
+    .{ .first = 0x1F20, .second = 0x0342, .composite = 0x1F26 },
+    .{ .first = 0x1F20, .second = 0x0345, .composite = 0x1F90 },
+    .{ .first = 0x1F21, .second = 0x0300, .composite = 0x1F23 },
+    .{ .first = 0x1F21, .second = 0x0301, .composite = 0x1F25 },
+    .{ .first = 0x1F21, .second = 0x0342, .composite = 0x1F27 },
+    .{ .first = 0x1F21, .second = 0x0345, .composite = 0x1F91 },
+    .{ .first = 0x1F22, .second = 0x0345, .composite = 0x1F92 },
+    .{ .first = 0x1F23, .second = 0x0345, .composite = 0x1F93 },
+    .{ .first = 0x1F24, .second = 0x0345, .composite = 0x1F94 },
+    .{ .first = 0x1F25, .second = 0x0345, .composite = 0x1F95 },
+    .{ .first = 0x1F26, .second = 0x0345, .composite = 0x1F96 },
+    .{ .first = 0x1F27, .second = 0x0345, .composite = 0x1F97 },
+    .{ .first = 0x1F28, .second = 0x0300, .composite = 0x1F2A },
+    .{ .first = 0x1F28, .second = 0x0301, .composite = 0x1F2C },
+    .{ .first = 0x1F28, .second = 0x0342, .composite = 0x1F2E },
+    .{ .first = 0x1F28, .second = 0x0345, .composite = 0x1F98 },
+    .{ .first = 0x1F29, .second = 0x0300, .composite = 0x1F2B },
+    .{ .first = 0x1F29, .second = 0x0301, .composite = 0x1F2D },
+    .{ .first = 0x1F29, .second = 0x0342, .composite = 0x1F2F },
+    .{ .first = 0x1F29, .second = 0x0345, .composite = 0x1F99 },
+    .{ .first = 0x1F2A, .second = 0x0345, .composite = 0x1F9A },
+    .{ .first = 0x1F2B, .second = 0x0345, .composite = 0x1F9B },
+    .{ .first = 0x1F2C, .second = 0x0345, .composite = 0x1F9C },
+    .{ .first = 0x1F2D, .second = 0x0345, .composite = 0x1F9D },
+    .{ .first = 0x1F2E, .second = 0x0345, .composite = 0x1F9E },
+    .{ .first = 0x1F2F, .second = 0x0345, .composite = 0x1F9F },
+    .{ .first = 0x1F30, .second = 0x0300, .composite = 0x1F32 },
+    .{ .first = 0x1F30, .second = 0x0301, .composite = 0x1F34 },
+    .{ .first = 0x1F30, .second = 0x0342, .composite = 0x1F36 },
+    .{ .first = 0x1F31, .second = 0x0300, .composite = 0x1F33 },
+    .{ .first = 0x1F31, .second = 0x0301, .composite = 0x1F35 },
+    .{ .first = 0x1F31, .second = 0x0342, .composite = 0x1F37 },
+    .{ .first = 0x1F38, .second = 0x0300, .composite = 0x1F3A },
+    .{ .first = 0x1F38, .second = 0x0301, .composite = 0x1F3C },
+    .{ .first = 0x1F38, .second = 0x0342, .composite = 0x1F3E },
+    .{ .first = 0x1F39, .second = 0x0300, .composite = 0x1F3B },
+    .{ .first = 0x1F39, .second = 0x0301, .composite = 0x1F3D },
+    .{ .first = 0x1F39, .second = 0x0342, .composite = 0x1F3F },
+    .{ .first = 0x1F40, .second = 0x0300, .composite = 0x1F42 },
+    .{ .first = 0x1F40, .second = 0x0301, .composite = 0x1F44 },
+    .{ .first = 0x1F41, .second = 0x0300, .composite = 0x1F43 },
+    .{ .first = 0x1F41, .second = 0x0301, .composite = 0x1F45 },
+    .{ .first = 0x1F48, .second = 0x0300, .composite = 0x1F4A },
+    .{ .first = 0x1F48, .second = 0x0301, .composite = 0x1F4C },
+    .{ .first = 0x1F49, .second = 0x0300, .composite = 0x1F4B },
+    .{ .first = 0x1F49, .second = 0x0301, .composite = 0x1F4D },
+    .{ .first = 0x1F50, .second = 0x0300, .composite = 0x1F52 },
+    .{ .first = 0x1F50, .second = 0x0301, .composite = 0x1F54 },
+    .{ .first = 0x1F50, .second = 0x0342, .composite = 0x1F56 },
+    .{ .first = 0x1F51, .second = 0x0300, .composite = 0x1F53 },
+    .{ .first = 0x1F51, .second = 0x0301, .composite = 0x1F55 },
+    .{ .first = 0x1F51, .second = 0x0342, .composite = 0x1F57 },
+    .{ .first = 0x1F59, .second = 0x0300, .composite = 0x1F5B },
+    .{ .first = 0x1F59, .second = 0x0301, .composite = 0x1F5D },
+    .{ .first = 0x1F59, .second = 0x0342, .composite = 0x1F5F },
+    .{ .first = 0x1F60, .second = 0x0300, .composite = 0x1F62 },
+    .{ .first = 0x1F60, .second = 0x0301, .composite = 0x1F64 },
+    .{ .first = 0x1F60, .second = 0x0342, .composite = 0x1F66 },
+    .{ .first = 0x1F60, .second = 0x0345, .composite = 0x1FA0 },
+    .{ .first = 0x1F61, .second = 0x0300, .composite = 0x1F63 },
+    .{ .first = 0x1F61, .second = 0x0301, .composite = 0x1F65 },
+    .{ .first = 0x1F61, .second = 0x0342, .composite = 0x1F67 },
+    .{ .first = 0x1F61, .second = 0x0345, .composite = 0x1FA1 },
+    .{ .first = 0x1F62, .second = 0x0345, .composite = 0x1FA2 },
+    .{ .first = 0x1F63, .second = 0x0345, .composite = 0x1FA3 },
+    .{ .first = 0x1F64, .second = 0x0345, .composite = 0x1FA4 },
+    .{ .first = 0x1F65, .second = 0x0345, .composite = 0x1FA5 },
+    .{ .first = 0x1F66, .second = 0x0345, .composite = 0x1FA6 },
+    .{ .first = 0x1F67, .second = 0x0345, .composite = 0x1FA7 },
+    .{ .first = 0x1F68, .second = 0x0300, .composite = 0x1F6A },
+    .{ .first = 0x1F68, .second = 0x0301, .composite = 0x1F6C },
+    .{ .first = 0x1F68, .second = 0x0342, .composite = 0x1F6E },
+    .{ .first = 0x1F68, .second = 0x0345, .composite = 0x1FA8 },
+    .{ .first = 0x1F69, .second = 0x0300, .composite = 0x1F6B },
+    .{ .first = 0x1F69, .second = 0x0301, .composite = 0x1F6D },
+    .{ .first = 0x1F69, .second = 0x0342, .composite = 0x1F6F },
+    .{ .first = 0x1F69, .second = 0x0345, .composite = 0x1FA9 },
+    .{ .first = 0x1F6A, .second = 0x0345, .composite = 0x1FAA },
+    .{ .first = 0x1F6B, .second = 0x0345, .composite = 0x1FAB },
+    .{ .first = 0x1F6C, .second = 0x0345, .composite = 0x1FAC },
+    .{ .first = 0x1F6D, .second = 0x0345, .composite = 0x1FAD },
+    .{ .first = 0x1F6E, .second = 0x0345, .composite = 0x1FAE },
+    .{ .first = 0x1F6F, .second = 0x0345, .composite = 0x1FAF },
+    .{ .first = 0x1F70, .second = 0x0345, .composite = 0x1FB2 },
+    .{ .first = 0x1F74, .second = 0x0345, .composite = 0x1FC2 },
+    .{ .first = 0x1F7C, .second = 0x0345, .composite = 0x1FF2 },
+    .{ .first = 0x1FB6, .second = 0x0345, .composite = 0x1FB7 },
+    .{ .first = 0x1FBF, .second = 0x0300, .composite = 0x1FCD },
+    .{ .first = 0x1FBF, .second = 0x0301, .composite = 0x1FCE },
+    .{ .first = 0x1FBF, .second = 0x0342, .composite = 0x1FCF },
+    .{ .first = 0x1FC6, .second = 0x0345, .composite = 0x1FC7 },
+    .{ .first = 0x1FF6, .second = 0x0345, .composite = 0x1FF7 },
+    .{ .first = 0x1FFE, .second = 0x0300, .composite = 0x1FDD },
+    .{ .first = 0x1FFE, .second = 0x0301, .composite = 0x1FDE },
+    .{ .first = 0x1FFE, .second = 0x0342, .composite = 0x1FDF },
+    .{ .first = 0x2190, .second = 0x0338, .composite = 0x219A },
+    .{ .first = 0x2192, .second = 0x0338, .composite = 0x219B },
+    .{ .first = 0x2194, .second = 0x0338, .composite = 0x21AE },
+    .{ .first = 0x21D0, .second = 0x0338, .composite = 0x21CD },
+    .{ .first = 0x21D2, .second = 0x0338, .composite = 0x21CF },
+    .{ .first = 0x21D4, .second = 0x0338, .composite = 0x21CE },
+    .{ .first = 0x2203, .second = 0x0338, .composite = 0x2204 },
+    .{ .first = 0x2208, .second = 0x0338, .composite = 0x2209 },
+    .{ .first = 0x220B, .second = 0x0338, .composite = 0x220C },
+    .{ .first = 0x2223, .second = 0x0338, .composite = 0x2224 },
+    .{ .first = 0x2225, .second = 0x0338, .composite = 0x2226 },
+    .{ .first = 0x223C, .second = 0x0338, .composite = 0x2241 },
+    .{ .first = 0x2243, .second = 0x0338, .composite = 0x2244 },
+    .{ .first = 0x2245, .second = 0x0338, .composite = 0x2247 },
+    .{ .first = 0x2248, .second = 0x0338, .composite = 0x2249 },
+    .{ .first = 0x224D, .second = 0x0338, .composite = 0x226D },
+    .{ .first = 0x2261, .second = 0x0338, .composite = 0x2262 },
+    .{ .first = 0x2264, .second = 0x0338, .composite = 0x2270 },
+    .{ .first = 0x2265, .second = 0x0338, .composite = 0x2271 },
+    .{ .first = 0x2272, .second = 0x0338, .composite = 0x2274 },
+    .{ .first = 0x2273, .second = 0x0338, .composite = 0x2275 },
+    .{ .first = 0x2276, .second = 0x0338, .composite = 0x2278 },
+    .{ .first = 0x2277, .second = 0x0338, .composite = 0x2279 },
+    .{ .first = 0x227A, .second = 0x0338, .composite = 0x2280 },
+    .{ .first = 0x227B, .second = 0x0338, .composite = 0x2281 },
+    .{ .first = 0x227C, .second = 0x0338, .composite = 0x22E0 },
+    .{ .first = 0x227D, .second = 0x0338, .composite = 0x22E1 },
+    .{ .first = 0x2282, .second = 0x0338, .composite = 0x2284 },
+    .{ .first = 0x2283, .second = 0x0338, .composite = 0x2285 },
+    .{ .first = 0x2286, .second = 0x0338, .composite = 0x2288 },
+    .{ .first = 0x2287, .second = 0x0338, .composite = 0x2289 },
+    .{ .first = 0x2291, .second = 0x0338, .composite = 0x22E2 },
+    .{ .first = 0x2292, .second = 0x0338, .composite = 0x22E3 },
+    .{ .first = 0x22A2, .second = 0x0338, .composite = 0x22AC },
+    .{ .first = 0x22A8, .second = 0x0338, .composite = 0x22AD },
+    .{ .first = 0x22A9, .second = 0x0338, .composite = 0x22AE },
+    .{ .first = 0x22AB, .second = 0x0338, .composite = 0x22AF },
+    .{ .first = 0x22B2, .second = 0x0338, .composite = 0x22EA },
+    .{ .first = 0x22B3, .second = 0x0338, .composite = 0x22EB },
+    .{ .first = 0x22B4, .second = 0x0338, .composite = 0x22EC },
+    .{ .first = 0x22B5, .second = 0x0338, .composite = 0x22ED },
+    .{ .first = 0x3046, .second = 0x3099, .composite = 0x3094 },
+    .{ .first = 0x304B, .second = 0x3099, .composite = 0x304C },
+    .{ .first = 0x304D, .second = 0x3099, .composite = 0x304E },
+    .{ .first = 0x304F, .second = 0x3099, .composite = 0x3050 },
+    .{ .first = 0x3051, .second = 0x3099, .composite = 0x3052 },
+    .{ .first = 0x3053, .second = 0x3099, .composite = 0x3054 },
+    .{ .first = 0x3055, .second = 0x3099, .composite = 0x3056 },
+    .{ .first = 0x3057, .second = 0x3099, .composite = 0x3058 },
+    .{ .first = 0x3059, .second = 0x3099, .composite = 0x305A },
+    .{ .first = 0x305B, .second = 0x3099, .composite = 0x305C },
+    .{ .first = 0x305D, .second = 0x3099, .composite = 0x305E },
+    .{ .first = 0x305F, .second = 0x3099, .composite = 0x3060 },
+    .{ .first = 0x3061, .second = 0x3099, .composite = 0x3062 },
+    .{ .first = 0x3064, .second = 0x3099, .composite = 0x3065 },
+    .{ .first = 0x3066, .second = 0x3099, .composite = 0x3067 },
+    .{ .first = 0x3068, .second = 0x3099, .composite = 0x3069 },
+    .{ .first = 0x306F, .second = 0x3099, .composite = 0x3070 },
+    .{ .first = 0x306F, .second = 0x309A, .composite = 0x3071 },
+    .{ .first = 0x3072, .second = 0x3099, .composite = 0x3073 },
+    .{ .first = 0x3072, .second = 0x309A, .composite = 0x3074 },
+    .{ .first = 0x3075, .second = 0x3099, .composite = 0x3076 },
+    .{ .first = 0x3075, .second = 0x309A, .composite = 0x3077 },
+    .{ .first = 0x3078, .second = 0x3099, .composite = 0x3079 },
+    .{ .first = 0x3078, .second = 0x309A, .composite = 0x307A },
+    .{ .first = 0x307B, .second = 0x3099, .composite = 0x307C },
+    .{ .first = 0x307B, .second = 0x309A, .composite = 0x307D },
+    .{ .first = 0x309D, .second = 0x3099, .composite = 0x309E },
+    .{ .first = 0x30A6, .second = 0x3099, .composite = 0x30F4 },
+    .{ .first = 0x30AB, .second = 0x3099, .composite = 0x30AC },
+    .{ .first = 0x30AD, .second = 0x3099, .composite = 0x30AE },
+    .{ .first = 0x30AF, .second = 0x3099, .composite = 0x30B0 },
+    .{ .first = 0x30B1, .second = 0x3099, .composite = 0x30B2 },
+    .{ .first = 0x30B3, .second = 0x3099, .composite = 0x30B4 },
+    .{ .first = 0x30B5, .second = 0x3099, .composite = 0x30B6 },
+    .{ .first = 0x30B7, .second = 0x3099, .composite = 0x30B8 },
+    .{ .first = 0x30B9, .second = 0x3099, .composite = 0x30BA },
+    .{ .first = 0x30BB, .second = 0x3099, .composite = 0x30BC },
+    .{ .first = 0x30BD, .second = 0x3099, .composite = 0x30BE },
+    .{ .first = 0x30BF, .second = 0x3099, .composite = 0x30C0 },
+    .{ .first = 0x30C1, .second = 0x3099, .composite = 0x30C2 },
+    .{ .first = 0x30C4, .second = 0x3099, .composite = 0x30C5 },
+    .{ .first = 0x30C6, .second = 0x3099, .composite = 0x30C7 },
+    .{ .first = 0x30C8, .second = 0x3099, .composite = 0x30C9 },
+    .{ .first = 0x30CF, .second = 0x3099, .composite = 0x30D0 },
+    .{ .first = 0x30CF, .second = 0x309A, .composite = 0x30D1 },
+    .{ .first = 0x30D2, .second = 0x3099, .composite = 0x30D3 },
+    .{ .first = 0x30D2, .second = 0x309A, .composite = 0x30D4 },
+    .{ .first = 0x30D5, .second = 0x3099, .composite = 0x30D6 },
+    .{ .first = 0x30D5, .second = 0x309A, .composite = 0x30D7 },
+    .{ .first = 0x30D8, .second = 0x3099, .composite = 0x30D9 },
+    .{ .first = 0x30D8, .second = 0x309A, .composite = 0x30DA },
+    .{ .first = 0x30DB, .second = 0x3099, .composite = 0x30DC },
+    .{ .first = 0x30DB, .second = 0x309A, .composite = 0x30DD },
+    .{ .first = 0x30EF, .second = 0x3099, .composite = 0x30F7 },
+    .{ .first = 0x30F0, .second = 0x3099, .composite = 0x30F8 },
+    .{ .first = 0x30F1, .second = 0x3099, .composite = 0x30F9 },
+    .{ .first = 0x30F2, .second = 0x3099, .composite = 0x30FA },
+    .{ .first = 0x30FD, .second = 0x3099, .composite = 0x30FE },
+    .{ .first = 0x11099, .second = 0x110BA, .composite = 0x1109A },
+    .{ .first = 0x1109B, .second = 0x110BA, .composite = 0x1109C },
+    .{ .first = 0x110A5, .second = 0x110BA, .composite = 0x110AB },
+    .{ .first = 0x11131, .second = 0x11127, .composite = 0x1112E },
+    .{ .first = 0x11132, .second = 0x11127, .composite = 0x1112F },
+    .{ .first = 0x11347, .second = 0x1133E, .composite = 0x1134B },
+    .{ .first = 0x11347, .second = 0x11357, .composite = 0x1134C },
+    .{ .first = 0x114B9, .second = 0x114B0, .composite = 0x114BC },
+    .{ .first = 0x114B9, .second = 0x114BA, .composite = 0x114BB },
+    .{ .first = 0x114B9, .second = 0x114BD, .composite = 0x114BE },
+    .{ .first = 0x115B8, .second = 0x115AF, .composite = 0x115BA },
+    .{ .first = 0x115B9, .second = 0x115AF, .composite = 0x115BB },
+    .{ .first = 0x11935, .second = 0x11930, .composite = 0x11938 },
+};
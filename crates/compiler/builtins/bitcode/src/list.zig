@@ -1,5 +1,6 @@
 const std = @import("std");
 const utils = @import("utils.zig");
+const refcount_trace = @import("refcount_trace.zig");
 const UpdateMode = utils.UpdateMode;
 const mem = std.mem;
 const math = std.math;
@@ -119,6 +120,8 @@ pub const RocList = extern struct {
         }
 
         // unfortunately, we have to clone
+        refcount_trace.copied("list", self.refcountMachine(), self.len() * element_width);
+
         var new_list = RocList.allocate(alignment, self.length, element_width);
 
         var old_bytes: [*]u8 = @ptrCast([*]u8, self.bytes);
@@ -168,6 +171,8 @@ pub const RocList = extern struct {
                     return RocList{ .bytes = new_source, .length = new_length, .capacity = new_capacity };
                 }
             }
+
+            refcount_trace.copied("list", self.refcountMachine(), self.len() * element_width);
             return self.reallocateFresh(alignment, new_length, element_width);
         }
         return RocList.allocate(alignment, new_length, element_width);
@@ -868,6 +873,15 @@ pub fn listIsUnique(
     return list.isEmpty() or list.isUnique();
 }
 
+// Copies the list, unless it is already unique, so that it can be updated in place.
+pub fn listMakeUnique(
+    list: RocList,
+    alignment: u32,
+    element_width: usize,
+) callconv(.C) RocList {
+    return list.makeUnique(alignment, element_width);
+}
+
 test "listConcat: non-unique with unique overlapping" {
     var nonUnique = RocList.fromSlice(u8, ([_]u8{1})[0..]);
     var bytes: [*]u8 = @ptrCast([*]u8, nonUnique.bytes);
@@ -2,6 +2,9 @@ const std = @import("std");
 const builtin = @import("builtin");
 const math = std.math;
 const utils = @import("utils.zig");
+const memcheck = @import("memcheck.zig");
+const refcount_trace = @import("refcount_trace.zig");
+const hash_seed = @import("hash_seed.zig");
 const expect = @import("expect.zig");
 const panic_utils = @import("panic.zig");
 
@@ -15,11 +18,17 @@ const dec = @import("dec.zig");
 comptime {
     exportDecFn(dec.fromStr, "from_str");
     exportDecFn(dec.toStr, "to_str");
+    exportDecFn(dec.toStrFixedC, "to_str_fixed");
+    exportDecFn(dec.toStrScientificC, "to_str_scientific");
     exportDecFn(dec.fromF64C, "from_f64");
     exportDecFn(dec.eqC, "eq");
     exportDecFn(dec.neqC, "neq");
     exportDecFn(dec.negateC, "negate");
+    exportDecFn(dec.absC, "abs");
     exportDecFn(dec.divC, "div");
+    exportDecFn(dec.toF64C, "to_f64");
+    exportDecFn(dec.toF32CheckedC, "to_f32_checked");
+    exportDecFn(dec.toF64CheckedC, "to_f64_checked");
 
     exportDecFn(dec.addC, "add_with_overflow");
     exportDecFn(dec.addOrPanicC, "add_or_panic");
@@ -32,6 +41,13 @@ comptime {
     exportDecFn(dec.mulC, "mul_with_overflow");
     exportDecFn(dec.mulOrPanicC, "mul_or_panic");
     exportDecFn(dec.mulSaturatedC, "mul_saturated");
+
+    inline for (INTEGERS) |T| {
+        dec.exportFromInt(T, ROC_BUILTINS ++ ".dec.from_int.");
+        dec.exportRound(T, ROC_BUILTINS ++ ".dec.round.");
+        dec.exportFloor(T, ROC_BUILTINS ++ ".dec.floor.");
+        dec.exportCeiling(T, ROC_BUILTINS ++ ".dec.ceiling.");
+    }
 }
 
 // List Module
@@ -54,6 +70,7 @@ comptime {
     exportListFn(list.listReplaceInPlace, "replace_in_place");
     exportListFn(list.listSwap, "swap");
     exportListFn(list.listIsUnique, "is_unique");
+    exportListFn(list.listMakeUnique, "make_unique");
 }
 
 // Num Module
@@ -67,6 +84,8 @@ const NUMBERS = INTEGERS ++ FLOATS;
 comptime {
     exportNumFn(num.bytesToU16C, "bytes_to_u16");
     exportNumFn(num.bytesToU32C, "bytes_to_u32");
+    exportNumFn(num.padZeros, "pad_zeros");
+    exportNumFn(num.groupDigits, "group_digits");
 
     inline for (INTEGERS) |T, i| {
         num.exportPow(T, ROC_BUILTINS ++ "." ++ NUM ++ ".pow_int.");
@@ -74,18 +93,51 @@ comptime {
 
         num.exportRoundF32(T, ROC_BUILTINS ++ "." ++ NUM ++ ".round_f32.");
         num.exportRoundF64(T, ROC_BUILTINS ++ "." ++ NUM ++ ".round_f64.");
+        num.exportCeilingF32(T, ROC_BUILTINS ++ "." ++ NUM ++ ".ceiling_f32.");
+        num.exportCeilingF64(T, ROC_BUILTINS ++ "." ++ NUM ++ ".ceiling_f64.");
+        num.exportFloorF32(T, ROC_BUILTINS ++ "." ++ NUM ++ ".floor_f32.");
+        num.exportFloorF64(T, ROC_BUILTINS ++ "." ++ NUM ++ ".floor_f64.");
+        num.exportIntToFloat(T, f32, ROC_BUILTINS ++ "." ++ NUM ++ ".int_to_f32.");
+        num.exportIntToFloat(T, f64, ROC_BUILTINS ++ "." ++ NUM ++ ".int_to_f64.");
+
+        num.exportCompare(T, ROC_BUILTINS ++ "." ++ NUM ++ ".compare.");
+        num.exportDivTrunc(T, ROC_BUILTINS ++ "." ++ NUM ++ ".div_trunc.");
+        num.exportRem(T, ROC_BUILTINS ++ "." ++ NUM ++ ".rem.");
+        num.exportIsMultipleOf(T, ROC_BUILTINS ++ "." ++ NUM ++ ".is_multiple_of.");
+        num.exportAbsOrPanic(T, ROC_BUILTINS ++ "." ++ NUM ++ ".abs_or_panic.");
+        num.exportNegOrPanic(T, ROC_BUILTINS ++ "." ++ NUM ++ ".neg_or_panic.");
+
+        num.exportShiftLeftBy(T, ROC_BUILTINS ++ "." ++ NUM ++ ".shift_left_by.");
+        num.exportShiftRightBy(T, ROC_BUILTINS ++ "." ++ NUM ++ ".shift_right_by.");
+        num.exportShiftRightZfBy(T, ROC_BUILTINS ++ "." ++ NUM ++ ".shift_right_zf_by.");
+
+        num.exportCountLeadingZeroBits(T, ROC_BUILTINS ++ "." ++ NUM ++ ".count_leading_zero_bits.");
+        num.exportCountTrailingZeroBits(T, ROC_BUILTINS ++ "." ++ NUM ++ ".count_trailing_zero_bits.");
+        num.exportCountOneBits(T, ROC_BUILTINS ++ "." ++ NUM ++ ".count_one_bits.");
+        num.exportRotateLeftBy(T, ROC_BUILTINS ++ "." ++ NUM ++ ".rotate_left_by.");
+        num.exportRotateRightBy(T, ROC_BUILTINS ++ "." ++ NUM ++ ".rotate_right_by.");
+        num.exportSwapBytes(T, ROC_BUILTINS ++ "." ++ NUM ++ ".swap_bytes.");
+        num.exportToStrRadix(T, ROC_BUILTINS ++ "." ++ NUM ++ ".to_str_radix.");
 
         num.exportAddWithOverflow(T, ROC_BUILTINS ++ "." ++ NUM ++ ".add_with_overflow.");
         num.exportAddOrPanic(T, ROC_BUILTINS ++ "." ++ NUM ++ ".add_or_panic.");
         num.exportAddSaturatedInt(T, ROC_BUILTINS ++ "." ++ NUM ++ ".add_saturated.");
+        num.exportAddWrappedInt(T, ROC_BUILTINS ++ "." ++ NUM ++ ".add_wrapped.");
 
         num.exportSubWithOverflow(T, ROC_BUILTINS ++ "." ++ NUM ++ ".sub_with_overflow.");
         num.exportSubOrPanic(T, ROC_BUILTINS ++ "." ++ NUM ++ ".sub_or_panic.");
         num.exportSubSaturatedInt(T, ROC_BUILTINS ++ "." ++ NUM ++ ".sub_saturated.");
+        num.exportSubWrappedInt(T, ROC_BUILTINS ++ "." ++ NUM ++ ".sub_wrapped.");
 
         num.exportMulWithOverflow(T, WIDEINTS[i], ROC_BUILTINS ++ "." ++ NUM ++ ".mul_with_overflow.");
         num.exportMulOrPanic(T, WIDEINTS[i], ROC_BUILTINS ++ "." ++ NUM ++ ".mul_or_panic.");
         num.exportMulSaturatedInt(T, WIDEINTS[i], ROC_BUILTINS ++ "." ++ NUM ++ ".mul_saturated.");
+        num.exportMulWrappedInt(T, ROC_BUILTINS ++ "." ++ NUM ++ ".mul_wrapped.");
+    }
+
+    inline for (NUMBERS) |T| {
+        num.exportToFloatChecked(T, f32, ROC_BUILTINS ++ "." ++ NUM ++ ".to_f32_checked.");
+        num.exportToFloatChecked(T, f64, ROC_BUILTINS ++ "." ++ NUM ++ ".to_f64_checked.");
     }
 
     inline for (INTEGERS) |FROM| {
@@ -112,6 +164,9 @@ comptime {
         num.exportMulWithOverflow(T, T, ROC_BUILTINS ++ "." ++ NUM ++ ".mul_with_overflow.");
 
         num.exportIsFinite(T, ROC_BUILTINS ++ "." ++ NUM ++ ".is_finite.");
+
+        num.exportToStrFixed(T, ROC_BUILTINS ++ "." ++ NUM ++ ".to_str_fixed.");
+        num.exportToStrScientific(T, ROC_BUILTINS ++ "." ++ NUM ++ ".to_str_scientific.");
     }
 }
 
@@ -147,26 +202,50 @@ comptime {
     exportStrFn(str.strCloneTo, "clone_to");
     exportStrFn(str.withCapacity, "with_capacity");
     exportStrFn(str.strGraphemes, "graphemes");
+    exportStrFn(str.strToUppercase, "to_uppercase");
+    exportStrFn(str.strToLowercase, "to_lowercase");
+    exportStrFn(str.strCaseFold, "case_fold");
+    exportStrFn(str.strToNfc, "to_nfc");
+    exportStrFn(str.strToNfd, "to_nfd");
+    exportStrFn(str.strFind, "find");
 
     inline for (INTEGERS) |T| {
         str.exportFromInt(T, ROC_BUILTINS ++ "." ++ STR ++ ".from_int.");
         num.exportParseInt(T, ROC_BUILTINS ++ "." ++ STR ++ ".to_int.");
+        num.exportParseIntRadix(T, ROC_BUILTINS ++ "." ++ STR ++ ".to_int_radix.");
     }
 
     inline for (FLOATS) |T| {
         str.exportFromFloat(T, ROC_BUILTINS ++ "." ++ STR ++ ".from_float.");
         num.exportParseFloat(T, ROC_BUILTINS ++ "." ++ STR ++ ".to_float.");
+        num.exportParseFloatChecked(T, ROC_BUILTINS ++ "." ++ STR ++ ".to_float_checked.");
     }
 }
 
 // Utils
 comptime {
     exportUtilsFn(utils.test_panic, "test_panic");
+    if (utils.has_test_dispatch) {
+        exportUtilsFn(utils.test_dispatch, "test_dispatch");
+    }
     exportUtilsFn(utils.increfC, "incref");
     exportUtilsFn(utils.decrefC, "decref");
     exportUtilsFn(utils.decrefCheckNullC, "decref_check_null");
     exportUtilsFn(utils.allocateWithRefcountC, "allocate_with_refcount");
 
+    exportUtilsFn(memcheck.enter, "memcheck_enter");
+    exportUtilsFn(memcheck.alloc, "memcheck_alloc");
+    exportUtilsFn(memcheck.realloc, "memcheck_realloc");
+    exportUtilsFn(memcheck.dealloc, "memcheck_dealloc");
+    exportUtilsFn(memcheck.report, "memcheck_report");
+    exportUtilsFn(memcheck.reportAtExit, "memcheck_report_at_exit");
+
+    exportUtilsFn(refcount_trace.setLocation, "refcount_trace_location");
+    exportUtilsFn(refcount_trace.resetLocation, "refcount_trace_reset");
+    exportUtilsFn(refcount_trace.incref, "refcount_trace_incref");
+
+    exportUtilsFn(hash_seed.randomSeed, "hash_random_seed");
+
     @export(panic_utils.panic, .{ .name = "roc_builtins.utils." ++ "panic", .linkage = .Weak });
 
     if (builtin.target.cpu.arch != .wasm32) {
@@ -179,6 +258,11 @@ comptime {
         @export(expect.setSharedBuffer, .{ .name = "set_shared_buffer", .linkage = .Weak });
 
         exportUtilsFn(expect.readSharedBufferEnv, "read_env_shared_buffer");
+
+        // `--check-memory` points roc_alloc, roc_realloc and roc_dealloc at these when linking on linux
+        @export(memcheck.hostAlloc, .{ .name = "__wrap_roc_alloc", .linkage = .Weak });
+        @export(memcheck.hostRealloc, .{ .name = "__wrap_roc_realloc", .linkage = .Weak });
+        @export(memcheck.hostDealloc, .{ .name = "__wrap_roc_dealloc", .linkage = .Weak });
     }
 
     if (builtin.target.cpu.arch == .aarch64) {
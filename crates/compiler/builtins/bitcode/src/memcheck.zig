@@ -0,0 +1,294 @@
+const std = @import("std");
+const builtin = @import("builtin");
+
+// Allocation tracking for `roc run --check-memory` and `roc test --check-memory`.
+//
+// The generated code calls `enter` whenever a procedure starts (or is returned to),
+// and sends its allocations through `alloc`, `realloc` and `dealloc` below. We record
+// every pointer along with the procedure that allocated it, and `report` lists the
+// allocations that are still live, so refcounting mistakes show up as concrete leaks
+// or bad frees rather than silent corruption.
+//
+// The host frees Roc values too, by calling roc_dealloc itself. On linux, `--check-memory`
+// gives the platform's allocator functions a second name, `__real_roc_alloc` and so on,
+// and then points `roc_alloc`, `roc_realloc` and `roc_dealloc` at the `hostAlloc`,
+// `hostRealloc` and `hostDealloc` wrappers below, so those calls are checked as well.
+
+extern fn roc_alloc(size: usize, alignment: u32) callconv(.C) ?*anyopaque;
+extern fn roc_realloc(c_ptr: *anyopaque, new_size: usize, old_size: usize, alignment: u32) callconv(.C) ?*anyopaque;
+extern fn roc_dealloc(c_ptr: *anyopaque, alignment: u32) callconv(.C) void;
+
+// null unless the platform's allocator functions were renamed
+const real_roc_alloc = @extern(?fn (usize, u32) callconv(.C) ?*anyopaque, .{ .name = "__real_roc_alloc", .linkage = .Weak });
+const real_roc_realloc = @extern(?fn (*anyopaque, usize, usize, u32) callconv(.C) ?*anyopaque, .{ .name = "__real_roc_realloc", .linkage = .Weak });
+const real_roc_dealloc = @extern(?fn (*anyopaque, u32) callconv(.C) void, .{ .name = "__real_roc_dealloc", .linkage = .Weak });
+
+// When they were, a plain call to roc_alloc would come straight back to the wrappers
+fn platformAlloc(size: usize, alignment: u32) ?*anyopaque {
+    if (real_roc_alloc) |real| {
+        return real(size, alignment);
+    }
+
+    return roc_alloc(size, alignment);
+}
+
+fn platformRealloc(c_ptr: *anyopaque, new_size: usize, old_size: usize, alignment: u32) ?*anyopaque {
+    if (real_roc_realloc) |real| {
+        return real(c_ptr, new_size, old_size, alignment);
+    }
+
+    return roc_realloc(c_ptr, new_size, old_size, alignment);
+}
+
+fn platformDealloc(c_ptr: *anyopaque, alignment: u32) void {
+    if (real_roc_dealloc) |real| {
+        return real(c_ptr, alignment);
+    }
+
+    roc_dealloc(c_ptr, alignment);
+}
+
+const Entry = struct {
+    ptr: usize,
+    size: usize,
+    allocated_by: ?[*:0]const u8,
+    // null while the allocation is live. Freed entries are kept around, so that
+    // freeing the same pointer twice can be told apart from freeing a pointer we never saw.
+    freed_by: ?[*:0]const u8,
+};
+
+pub var enabled: bool = false;
+
+var current_proc: ?[*:0]const u8 = null;
+
+var entries: [*]Entry = undefined;
+var capacity: usize = 0;
+var occupied: usize = 0;
+
+var problems: usize = 0;
+
+const UNKNOWN_PROC: [*:0]const u8 = "<host or builtin>";
+
+fn procName(name: ?[*:0]const u8) [*:0]const u8 {
+    return name orelse UNKNOWN_PROC;
+}
+
+fn print(comptime fmt: []const u8, args: anytype) void {
+    if (comptime builtin.target.cpu.arch != .wasm32) {
+        std.debug.print(fmt, args);
+    }
+}
+
+fn hash(ptr: usize) usize {
+    // pointers are at least 8-byte aligned, so the low bits carry no information
+    return (ptr >> 3) *% 0x9E3779B97F4A7C15;
+}
+
+// Find the slot for this pointer: either the entry that tracks it, or the empty slot it would go in.
+fn find(ptr: usize) *Entry {
+    var index = hash(ptr) & (capacity - 1);
+
+    while (true) {
+        const entry = &entries[index];
+        if (entry.ptr == ptr or entry.ptr == 0) {
+            return entry;
+        }
+
+        index = (index + 1) & (capacity - 1);
+    }
+}
+
+fn grow() void {
+    const old_entries = entries;
+    const old_capacity = capacity;
+
+    capacity = if (old_capacity == 0) 1024 else old_capacity * 2;
+
+    const bytes = capacity * @sizeOf(Entry);
+    entries = @ptrCast([*]Entry, @alignCast(@alignOf(Entry), platformAlloc(bytes, @alignOf(Entry))));
+    @memset(@ptrCast([*]u8, entries), 0, bytes);
+
+    var i: usize = 0;
+    while (i < old_capacity) : (i += 1) {
+        if (old_entries[i].ptr != 0) {
+            find(old_entries[i].ptr).* = old_entries[i];
+        }
+    }
+
+    if (old_capacity != 0) {
+        platformDealloc(@ptrCast(*anyopaque, old_entries), @alignOf(Entry));
+    }
+}
+
+fn track(ptr: usize, size: usize) void {
+    // keep the load factor under 3/4
+    if (4 * (occupied + 1) > 3 * capacity) {
+        grow();
+    }
+
+    const entry = find(ptr);
+    if (entry.ptr == 0) {
+        occupied += 1;
+    }
+
+    // a freed pointer may well be handed out again by the allocator
+    entry.* = Entry{
+        .ptr = ptr,
+        .size = size,
+        .allocated_by = current_proc,
+        .freed_by = null,
+    };
+}
+
+// Returns whether the pointer may be passed on to roc_dealloc/roc_realloc
+fn untrack(ptr: usize, action: []const u8) bool {
+    if (capacity == 0) {
+        return untrackUnknown(ptr, action);
+    }
+
+    const entry = find(ptr);
+
+    if (entry.ptr == 0) {
+        return untrackUnknown(ptr, action);
+    }
+
+    if (entry.freed_by) |freed_by| {
+        problems += 1;
+        print("Memory check: {s} of already deallocated pointer 0x{x} in {s}\n", .{ action, ptr, procName(current_proc) });
+        print("    it was allocated in {s} and first deallocated in {s}\n", .{ procName(entry.allocated_by), freed_by });
+
+        // actually freeing it again would corrupt the heap before we can report anything else
+        return false;
+    }
+
+    entry.freed_by = procName(current_proc);
+
+    return true;
+}
+
+fn untrackUnknown(ptr: usize, action: []const u8) bool {
+    problems += 1;
+    print("Memory check: {s} of unknown pointer 0x{x} in {s}\n", .{ action, ptr, procName(current_proc) });
+    print("    (if the platform allocated this memory itself, this may be harmless)\n", .{});
+
+    return true;
+}
+
+/// Called at the start of every Roc procedure, and when a call to one returns
+pub fn enter(proc_name: [*:0]const u8) callconv(.C) void {
+    enabled = true;
+    current_proc = proc_name;
+}
+
+pub fn alloc(size: usize, alignment: u32) callconv(.C) ?*anyopaque {
+    const result = platformAlloc(size, alignment);
+
+    if (result) |ptr| {
+        track(@ptrToInt(ptr), size);
+    }
+
+    return result;
+}
+
+pub fn realloc(c_ptr: *anyopaque, new_size: usize, old_size: usize, alignment: u32) callconv(.C) ?*anyopaque {
+    if (!untrack(@ptrToInt(c_ptr), "reallocation")) {
+        // it is already gone; hand out fresh memory so the program can limp along to the report
+        return alloc(new_size, alignment);
+    }
+
+    const result = platformRealloc(c_ptr, new_size, old_size, alignment);
+
+    if (result) |ptr| {
+        track(@ptrToInt(ptr), new_size);
+    }
+
+    return result;
+}
+
+pub fn dealloc(c_ptr: *anyopaque, alignment: u32) callconv(.C) void {
+    if (untrack(@ptrToInt(c_ptr), "deallocation")) {
+        platformDealloc(c_ptr, alignment);
+    }
+}
+
+// Calls that reach the wrappers come from the host, or from builtins that ran before any
+// Roc procedure did. Either way they don't belong to the procedure that ran last.
+
+pub fn hostAlloc(size: usize, alignment: u32) callconv(.C) ?*anyopaque {
+    const proc = current_proc;
+    current_proc = null;
+    defer current_proc = proc;
+
+    return alloc(size, alignment);
+}
+
+pub fn hostRealloc(c_ptr: *anyopaque, new_size: usize, old_size: usize, alignment: u32) callconv(.C) ?*anyopaque {
+    const proc = current_proc;
+    current_proc = null;
+    defer current_proc = proc;
+
+    return realloc(c_ptr, new_size, old_size, alignment);
+}
+
+pub fn hostDealloc(c_ptr: *anyopaque, alignment: u32) callconv(.C) void {
+    const proc = current_proc;
+    current_proc = null;
+    defer current_proc = proc;
+
+    dealloc(c_ptr, alignment);
+}
+
+/// Print every allocation that is still live, and return the number of problems found so far
+pub fn report() callconv(.C) usize {
+    var leaked: usize = 0;
+    var leaked_bytes: usize = 0;
+
+    var i: usize = 0;
+    while (i < capacity) : (i += 1) {
+        const entry = entries[i];
+
+        if (entry.ptr != 0 and entry.freed_by == null) {
+            leaked += 1;
+            leaked_bytes += entry.size;
+
+            print("Memory check: {} bytes at 0x{x} were never deallocated\n", .{ entry.size, entry.ptr });
+            print("    allocated while in {s}\n", .{procName(entry.allocated_by)});
+        }
+    }
+
+    if (leaked > 0) {
+        print("Memory check: {} allocations ({} bytes) still live\n", .{ leaked, leaked_bytes });
+    }
+
+    return problems + leaked;
+}
+
+/// Registered to run when the program exits
+pub fn reportAtExit() callconv(.C) void {
+    if (enabled) {
+        _ = report();
+    }
+}
+
+test "memcheck tracks allocations" {
+    const expectEqual = std.testing.expectEqual;
+
+    enter("Test.allocates");
+
+    const a = alloc(16, 8).?;
+    const b = alloc(32, 8).?;
+    const c = realloc(b, 64, 32, 8).?;
+
+    dealloc(a, 8);
+
+    try expectEqual(@as(usize, 1), report());
+
+    dealloc(c, 8);
+
+    try expectEqual(@as(usize, 0), report());
+
+    roc_dealloc(@ptrCast(*anyopaque, entries), @alignOf(Entry));
+    capacity = 0;
+    occupied = 0;
+    enabled = false;
+}
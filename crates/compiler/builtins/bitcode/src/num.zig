@@ -3,7 +3,8 @@ const always_inline = std.builtin.CallOptions.Modifier.always_inline;
 const math = std.math;
 const RocList = @import("list.zig").RocList;
 const RocStr = @import("str.zig").RocStr;
-const WithOverflow = @import("utils.zig").WithOverflow;
+const utils = @import("utils.zig");
+const WithOverflow = utils.WithOverflow;
 const roc_panic = @import("panic.zig").panic_help;
 
 pub fn NumParseResult(comptime T: type) type {
@@ -86,6 +87,256 @@ pub fn exportParseFloat(comptime T: type, comptime name: []const u8) void {
     @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
 }
 
+// The reason a Str.toU32Radix or Str.toF64Checked failed, if it did.
+pub const NumParseProblem = enum(u8) {
+    None = 0,
+    MissingDigits = 1,
+    InvalidDigit = 2,
+    Overflow = 3,
+    InvalidRadix = 4,
+};
+
+pub fn NumParseCheckedResult(comptime T: type) type {
+    // on the roc side we sort by alignment, and then by field name. So the
+    // value goes before the index only if it's at least as aligned as a usize
+    if (@alignOf(T) >= @alignOf(usize)) {
+        return extern struct {
+            value: T,
+            index: usize,
+            problem: u8,
+        };
+    } else {
+        return extern struct {
+            index: usize,
+            value: T,
+            problem: u8,
+        };
+    }
+}
+
+fn parseFailure(comptime T: type, index: usize, problem: NumParseProblem) NumParseCheckedResult(T) {
+    return .{ .value = 0, .index = index, .problem = @enumToInt(problem) };
+}
+
+fn parseSuccess(comptime T: type, value: T) NumParseCheckedResult(T) {
+    return .{ .value = value, .index = 0, .problem = @enumToInt(NumParseProblem.None) };
+}
+
+// Reports the expected digits at `index` as missing if the input ends there, and invalid otherwise.
+fn parseNoDigits(comptime T: type, bytes: []const u8, index: usize) NumParseCheckedResult(T) {
+    const problem = if (index == bytes.len) NumParseProblem.MissingDigits else NumParseProblem.InvalidDigit;
+
+    return parseFailure(T, index, problem);
+}
+
+// The prefix which number literals use for this radix, like `0x` for 16.
+fn radixPrefix(radix: u8) ?[]const u8 {
+    return switch (radix) {
+        2 => "0b",
+        8 => "0o",
+        16 => "0x",
+        else => null,
+    };
+}
+
+// Like number literals, this accepts a leading `-`, a prefix that matches the
+// radix, and single underscores between the digits.
+pub fn parseIntRadix(comptime T: type, bytes: []const u8, radix: u8) NumParseCheckedResult(T) {
+    if (radix < 2 or radix > 36) {
+        return parseFailure(T, 0, .InvalidRadix);
+    }
+
+    const is_negative = bytes.len > 0 and bytes[0] == '-';
+    var index: usize = if (is_negative) 1 else 0;
+
+    if (radixPrefix(radix)) |prefix| {
+        if (std.mem.startsWith(u8, bytes[index..], prefix)) {
+            index += prefix.len;
+        }
+    }
+
+    var has_digits = false;
+    var value: T = 0;
+
+    // an underscore must come right after a digit, and be followed by one
+    var underscore_allowed = false;
+
+    while (index < bytes.len) : (index += 1) {
+        const byte = bytes[index];
+
+        if (byte == '_' and underscore_allowed) {
+            if (index + 1 == bytes.len) {
+                return parseFailure(T, index, .InvalidDigit);
+            }
+
+            underscore_allowed = false;
+            continue;
+        }
+
+        const digit = std.fmt.charToDigit(byte, radix) catch return parseFailure(T, index, .InvalidDigit);
+        has_digits = true;
+        underscore_allowed = true;
+
+        // negative numbers are built up below zero, so that the minimum
+        // value of a signed integer doesn't overflow on the way
+        const shifted = math.mul(T, value, @intCast(T, radix)) catch return parseFailure(T, index, .Overflow);
+        const next = if (is_negative) math.sub(T, shifted, @intCast(T, digit)) else math.add(T, shifted, @intCast(T, digit));
+        value = next catch return parseFailure(T, index, .Overflow);
+    }
+
+    if (!has_digits) {
+        return parseFailure(T, index, .MissingDigits);
+    }
+
+    return parseSuccess(T, value);
+}
+
+// Skips the decimal digits starting at `start`, along with single underscores between them.
+fn skipDecimalDigits(bytes: []const u8, start: usize) usize {
+    var index = start;
+
+    while (index < bytes.len) : (index += 1) {
+        const byte = bytes[index];
+        const is_separator = byte == '_' and index > start and
+            std.ascii.isDigit(bytes[index - 1]) and
+            index + 1 < bytes.len and std.ascii.isDigit(bytes[index + 1]);
+
+        if (!std.ascii.isDigit(byte) and !is_separator) {
+            break;
+        }
+    }
+
+    return index;
+}
+
+// Accepts an optional `-`, digits, an optional fraction, and an optional exponent,
+// always with `.` as the decimal point regardless of locale. Like number literals,
+// there must be digits before the `.`, and underscores may go between digits.
+// Values too large to be finite are reported as an overflow.
+pub fn parseFloatChecked(comptime T: type, bytes: []const u8) NumParseCheckedResult(T) {
+    const whole_start: usize = if (bytes.len > 0 and bytes[0] == '-') 1 else 0;
+    var index = whole_start;
+    var exponent_start: ?usize = null;
+
+    const whole_end = skipDecimalDigits(bytes, index);
+    if (whole_end == index) {
+        return parseNoDigits(T, bytes, index);
+    }
+    index = whole_end;
+
+    if (index < bytes.len and bytes[index] == '.') {
+        index += 1;
+
+        const fraction_end = skipDecimalDigits(bytes, index);
+        if (fraction_end == index) {
+            return parseNoDigits(T, bytes, index);
+        }
+        index = fraction_end;
+    }
+
+    if (index < bytes.len and (bytes[index] == 'e' or bytes[index] == 'E')) {
+        index += 1;
+
+        if (index < bytes.len and (bytes[index] == '-' or bytes[index] == '+')) {
+            index += 1;
+        }
+
+        const exponent_end = skipDecimalDigits(bytes, index);
+        if (exponent_end == index) {
+            return parseNoDigits(T, bytes, index);
+        }
+        exponent_start = index;
+        index = exponent_end;
+    }
+
+    if (index < bytes.len) {
+        return parseFailure(T, index, .InvalidDigit);
+    }
+
+    const value = parseFloatWithoutUnderscores(T, bytes) catch return parseFailure(T, 0, .InvalidDigit);
+
+    if (math.isInf(value)) {
+        return parseFailure(T, floatOverflowIndex(T, bytes, whole_start, whole_end, exponent_start), .Overflow);
+    }
+
+    return parseSuccess(T, value);
+}
+
+// Points at the digit that made a float too large to be finite: the first digit before the
+// point that the largest finite value doesn't have, or else the exponent, which must have pushed
+// the value over. Without either, the whole part was rounded up, so it's the last digit of it.
+fn floatOverflowIndex(comptime T: type, bytes: []const u8, whole_start: usize, whole_end: usize, exponent_start: ?usize) usize {
+    const max_whole_digits: usize = switch (T) {
+        f32 => 39,
+        f64 => 309,
+        else => @compileError("floatOverflowIndex is only implemented for f32 and f64"),
+    };
+
+    var digits: usize = 0;
+    var index = whole_start;
+
+    while (index < whole_end) : (index += 1) {
+        const byte = bytes[index];
+
+        // leading zeros don't make the number any bigger
+        if (byte == '_' or (byte == '0' and digits == 0)) {
+            continue;
+        }
+
+        digits += 1;
+        if (digits > max_whole_digits) {
+            return index;
+        }
+    }
+
+    return exponent_start orelse whole_end - 1;
+}
+
+fn parseFloatWithoutUnderscores(comptime T: type, bytes: []const u8) !T {
+    var underscores: usize = 0;
+    for (bytes) |byte| {
+        if (byte == '_') {
+            underscores += 1;
+        }
+    }
+
+    if (underscores == 0) {
+        return std.fmt.parseFloat(T, bytes);
+    }
+
+    const length = bytes.len - underscores;
+    const buffer = utils.alloc(length, 1) orelse unreachable;
+    defer utils.dealloc(buffer, 1);
+
+    var position: usize = 0;
+    for (bytes) |byte| {
+        if (byte != '_') {
+            buffer[position] = byte;
+            position += 1;
+        }
+    }
+
+    return std.fmt.parseFloat(T, buffer[0..length]);
+}
+
+pub fn exportParseIntRadix(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(buf: RocStr, radix: u8) callconv(.C) NumParseCheckedResult(T) {
+            return parseIntRadix(T, buf.asSlice(), radix);
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportParseFloatChecked(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(buf: RocStr) callconv(.C) NumParseCheckedResult(T) {
+            return parseFloatChecked(T, buf.asSlice());
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
 pub fn exportPow(comptime T: type, comptime name: []const u8) void {
     comptime var f = struct {
         fn func(base: T, exp: T) callconv(.C) T {
@@ -185,6 +436,274 @@ pub fn exportDivCeil(comptime T: type, comptime name: []const u8) void {
     @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
 }
 
+pub fn exportCeilingF32(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(input: f32) callconv(.C) T {
+            return @floatToInt(T, (@ceil(input)));
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportCeilingF64(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(input: f64) callconv(.C) T {
+            return @floatToInt(T, (@ceil(input)));
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportFloorF32(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(input: f32) callconv(.C) T {
+            return @floatToInt(T, (@floor(input)));
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportFloorF64(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(input: f64) callconv(.C) T {
+            return @floatToInt(T, (@floor(input)));
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportIntToFloat(comptime T: type, comptime F: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(input: T) callconv(.C) F {
+            return @intToFloat(F, input);
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportCompare(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        // Returns the tag id of Roc's `[EQ, GT, LT]`
+        fn func(self: T, other: T) callconv(.C) u8 {
+            if (self == other) {
+                return 0;
+            } else if (self > other) {
+                return 1;
+            } else {
+                return 2;
+            }
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportDivTrunc(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T, other: T) callconv(.C) T {
+            return @divTrunc(self, other);
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportRem(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T, other: T) callconv(.C) T {
+            return @rem(self, other);
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportIsMultipleOf(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T, other: T) callconv(.C) bool {
+            if (other == 0) {
+                return self == 0;
+            }
+
+            if (@typeInfo(T).Int.signedness == .signed) {
+                // `minInt % -1` overflows, but every number is a multiple of -1
+                if (other == -1) {
+                    return true;
+                }
+            }
+
+            return @rem(self, other) == 0;
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportAbsOrPanic(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T) callconv(.C) T {
+            if (@typeInfo(T).Int.signedness == .unsigned) {
+                return self;
+            } else if (self == std.math.minInt(T)) {
+                roc_panic("integer absolute overflowed because its argument is the minimum value", 0);
+                unreachable;
+            } else {
+                return if (self < 0) -self else self;
+            }
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportNegOrPanic(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T) callconv(.C) T {
+            var answer: T = undefined;
+            if (@subWithOverflow(T, 0, self, &answer)) {
+                roc_panic("integer negation overflowed because its argument is the minimum value", 0);
+                unreachable;
+            }
+
+            return answer;
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+// Roc defines all three shifts on every integer type, and shifting by the bit width or more
+// shifts out every bit, rather than wrapping the shift amount like most hardware does.
+
+pub fn exportShiftLeftBy(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T, bits: u8) callconv(.C) T {
+            if (bits >= @bitSizeOf(T)) {
+                return 0;
+            }
+
+            return self << @intCast(math.Log2Int(T), bits);
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportShiftRightBy(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T, bits: u8) callconv(.C) T {
+            // The number is treated as signed, so that the sign bit is copied in from the left
+            const S = std.meta.Int(.signed, @bitSizeOf(T));
+            const signed = @bitCast(S, self);
+
+            if (bits >= @bitSizeOf(T)) {
+                return @bitCast(T, if (signed < 0) @as(S, -1) else @as(S, 0));
+            }
+
+            return @bitCast(T, signed >> @intCast(math.Log2Int(S), bits));
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportShiftRightZfBy(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T, bits: u8) callconv(.C) T {
+            // The number is treated as unsigned, so that zeroes are shifted in from the left
+            const U = std.meta.Int(.unsigned, @bitSizeOf(T));
+            const unsigned = @bitCast(U, self);
+
+            if (bits >= @bitSizeOf(T)) {
+                return 0;
+            }
+
+            return @bitCast(T, unsigned >> @intCast(math.Log2Int(U), bits));
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+// Bit counts are always returned as a U8, which is wide enough for every integer type.
+
+pub fn exportCountLeadingZeroBits(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T) callconv(.C) u8 {
+            return @as(u8, @clz(T, self));
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportCountTrailingZeroBits(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T) callconv(.C) u8 {
+            return @as(u8, @ctz(T, self));
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportCountOneBits(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T) callconv(.C) u8 {
+            return @as(u8, @popCount(T, self));
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+// Unlike shifts, rotations wrap the amount around the bit width, so no bits are ever lost.
+
+pub fn exportRotateLeftBy(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T, bits: u8) callconv(.C) T {
+            const U = std.meta.Int(.unsigned, @bitSizeOf(T));
+            return @bitCast(T, math.rotl(U, @bitCast(U, self), bits));
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportRotateRightBy(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T, bits: u8) callconv(.C) T {
+            const U = std.meta.Int(.unsigned, @bitSizeOf(T));
+            return @bitCast(T, math.rotr(U, @bitCast(U, self), bits));
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportSwapBytes(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T) callconv(.C) T {
+            return @byteSwap(T, self);
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn ToFloatCheckedResult(comptime T: type) type {
+    return extern struct {
+        value: T,
+        out_of_bounds: bool,
+    };
+}
+
+pub fn exportToFloatChecked(comptime From: type, comptime To: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(input: From) callconv(.C) ToFloatCheckedResult(To) {
+            const value = switch (@typeInfo(From)) {
+                .Int => @intToFloat(To, input),
+                else => @floatCast(To, input),
+            };
+
+            // Only a finite input that is too big for the target type turns into an infinity.
+            // Infinities and NaN are passed through as they are.
+            const was_finite = switch (@typeInfo(From)) {
+                .Int => true,
+                else => math.isFinite(input),
+            };
+
+            return .{ .value = value, .out_of_bounds = was_finite and !math.isFinite(value) };
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(From), .linkage = .Strong });
+}
+
 pub fn ToIntCheckedResult(comptime T: type) type {
     // On the Roc side we sort by alignment; putting the errorcode last
     // always works out (no number with smaller alignment than 1).
@@ -294,6 +813,15 @@ pub fn exportAddOrPanic(comptime T: type, comptime name: []const u8) void {
     @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
 }
 
+pub fn exportAddWrappedInt(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T, other: T) callconv(.C) T {
+            return self +% other;
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
 fn subWithOverflow(comptime T: type, self: T, other: T) WithOverflow(T) {
     switch (@typeInfo(T)) {
         .Int => {
@@ -353,6 +881,15 @@ pub fn exportSubOrPanic(comptime T: type, comptime name: []const u8) void {
     @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
 }
 
+pub fn exportSubWrappedInt(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T, other: T) callconv(.C) T {
+            return self -% other;
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
 fn mulWithOverflow(comptime T: type, comptime W: type, self: T, other: T) WithOverflow(T) {
     switch (@typeInfo(T)) {
         .Int => {
@@ -460,3 +997,269 @@ pub fn exportMulOrPanic(comptime T: type, comptime W: type, comptime name: []con
     }.func;
     @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
 }
+
+pub fn exportMulWrappedInt(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(self: T, other: T) callconv(.C) T {
+            return self *% other;
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+// Runs `format` twice: once to measure the output, and once to write it into a string of exactly that length.
+pub fn formatToStr(args: anytype, comptime format: anytype) RocStr {
+    var counter = std.io.countingWriter(std.io.null_writer);
+    format(args, counter.writer()) catch unreachable;
+
+    const length = @intCast(usize, counter.bytes_written);
+    var result = RocStr.allocate(length);
+    var stream = std.io.fixedBufferStream(result.asU8ptrMut()[0..length]);
+    format(args, stream.writer()) catch unreachable;
+
+    return result;
+}
+
+// Num.toStr, Num.toStrFixed and Num.toStrScientific all spell the non-finite floats like this.
+pub fn nonFiniteFloatStr(float: anytype) ?[]const u8 {
+    if (math.isNan(float)) {
+        return "NaN";
+    } else if (math.isPositiveInf(float)) {
+        return "∞";
+    } else if (math.isNegativeInf(float)) {
+        return "-∞";
+    } else {
+        return null;
+    }
+}
+
+fn writeFloatFixed(args: anytype, writer: anytype) !void {
+    if (nonFiniteFloatStr(args.float)) |str| {
+        return writer.writeAll(str);
+    }
+
+    try std.fmt.formatFloatDecimal(args.float, .{ .precision = args.precision }, writer);
+}
+
+fn writeFloatScientific(args: anytype, writer: anytype) !void {
+    if (nonFiniteFloatStr(args.float)) |str| {
+        return writer.writeAll(str);
+    }
+
+    try std.fmt.formatFloatScientific(args.float, .{ .precision = args.precision }, writer);
+}
+
+pub fn exportToStrFixed(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(float: T, precision: usize) callconv(.C) RocStr {
+            return formatToStr(.{ .float = float, .precision = precision }, writeFloatFixed);
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportToStrScientific(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(float: T, precision: usize) callconv(.C) RocStr {
+            return formatToStr(.{ .float = float, .precision = precision }, writeFloatScientific);
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+pub fn exportToStrRadix(comptime T: type, comptime name: []const u8) void {
+    comptime var f = struct {
+        fn func(int: T, radix: u8) callconv(.C) RocStr {
+            // binary needs one digit per bit, plus a minus sign
+            var buf: [@bitSizeOf(T) + 1]u8 = undefined;
+            const length = std.fmt.formatIntBuf(&buf, int, radix, .lower, .{});
+
+            return RocStr.init(&buf, length);
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
+}
+
+fn signLength(bytes: []const u8) usize {
+    return if (bytes.len > 0 and (bytes[0] == '-' or bytes[0] == '+')) 1 else 0;
+}
+
+// Num.padZeros
+pub fn padZeros(string: RocStr, width: usize) callconv(.C) RocStr {
+    const bytes = string.asSlice();
+    const sign_length = signLength(bytes);
+    const number_length = bytes.len - sign_length;
+
+    // Leave alone anything that is already wide enough, or doesn't start with a digit (like "NaN" or "∞")
+    if (number_length >= width or number_length == 0 or !std.ascii.isXDigit(bytes[sign_length])) {
+        return string;
+    }
+
+    const zeros = width - number_length;
+    var result = RocStr.allocate(bytes.len + zeros);
+    const result_ptr = result.asU8ptrMut();
+
+    @memcpy(result_ptr, bytes.ptr, sign_length);
+    @memset(result_ptr + sign_length, '0', zeros);
+    @memcpy(result_ptr + sign_length + zeros, bytes.ptr + sign_length, number_length);
+
+    string.deinit();
+
+    return result;
+}
+
+// Num.groupDigits
+pub fn groupDigits(string: RocStr, separator: RocStr) callconv(.C) RocStr {
+    const bytes = string.asSlice();
+    const separator_bytes = separator.asSlice();
+    const sign_length = signLength(bytes);
+
+    // only the decimal digits before the decimal point (or exponent) are grouped
+    var digits_end = sign_length;
+    while (digits_end < bytes.len and std.ascii.isDigit(bytes[digits_end])) {
+        digits_end += 1;
+    }
+
+    const digit_count = digits_end - sign_length;
+
+    if (digit_count <= 3 or separator_bytes.len == 0) {
+        return string;
+    }
+
+    const separator_count = (digit_count - 1) / 3;
+    var result = RocStr.allocate(bytes.len + separator_count * separator_bytes.len);
+    const result_ptr = result.asU8ptrMut();
+
+    @memcpy(result_ptr, bytes.ptr, sign_length);
+    var position = sign_length;
+
+    for (bytes[sign_length..digits_end]) |digit, index| {
+        // the first group gets whatever is left over after splitting the rest into groups of three
+        if (index > 0 and (digit_count - index) % 3 == 0) {
+            @memcpy(result_ptr + position, separator_bytes.ptr, separator_bytes.len);
+            position += separator_bytes.len;
+        }
+
+        result_ptr[position] = digit;
+        position += 1;
+    }
+
+    @memcpy(result_ptr + position, bytes.ptr + digits_end, bytes.len - digits_end);
+
+    string.deinit();
+
+    return result;
+}
+
+const testing = std.testing;
+const expect = testing.expect;
+
+fn expectStr(actual: RocStr, expected_bytes: []const u8) !void {
+    defer actual.deinit();
+
+    const expected = RocStr.fromSlice(expected_bytes);
+    defer expected.deinit();
+
+    try expect(actual.eq(expected));
+}
+
+test "formatToStr: fixed precision" {
+    try expectStr(formatToStr(.{ .float = @as(f64, 3.14159), .precision = 2 }, writeFloatFixed), "3.14");
+    try expectStr(formatToStr(.{ .float = @as(f64, 2.5), .precision = 0 }, writeFloatFixed), "3");
+    try expectStr(formatToStr(.{ .float = @as(f64, -0.5), .precision = 3 }, writeFloatFixed), "-0.500");
+}
+
+test "formatToStr: scientific" {
+    try expectStr(formatToStr(.{ .float = @as(f64, 1234.5), .precision = 2 }, writeFloatScientific), "1.23e+03");
+    try expectStr(formatToStr(.{ .float = @as(f64, 0.00042), .precision = 1 }, writeFloatScientific), "4.2e-04");
+    try expectStr(formatToStr(.{ .float = @as(f64, 0), .precision = 2 }, writeFloatScientific), "0.00e+00");
+}
+
+test "formatToStr: non-finite floats" {
+    try expectStr(formatToStr(.{ .float = math.nan(f64), .precision = 2 }, writeFloatFixed), "NaN");
+    try expectStr(formatToStr(.{ .float = math.inf(f32), .precision = 2 }, writeFloatFixed), "∞");
+    try expectStr(formatToStr(.{ .float = -math.inf(f64), .precision = 2 }, writeFloatScientific), "-∞");
+}
+
+test "padZeros" {
+    try expectStr(padZeros(RocStr.fromSlice("42"), 5), "00042");
+    try expectStr(padZeros(RocStr.fromSlice("-42"), 5), "-00042");
+    try expectStr(padZeros(RocStr.fromSlice("ff"), 4), "00ff");
+    try expectStr(padZeros(RocStr.fromSlice("1.5"), 5), "001.5");
+    try expectStr(padZeros(RocStr.fromSlice("123456"), 3), "123456");
+    try expectStr(padZeros(RocStr.fromSlice("-∞"), 6), "-∞");
+}
+
+test "groupDigits" {
+    const comma = RocStr.fromSlice(",");
+
+    try expectStr(groupDigits(RocStr.fromSlice("1234567"), comma), "1,234,567");
+    try expectStr(groupDigits(RocStr.fromSlice("-123456.789"), comma), "-123,456.789");
+    try expectStr(groupDigits(RocStr.fromSlice("999"), comma), "999");
+    try expectStr(groupDigits(RocStr.fromSlice("1000.0"), RocStr.fromSlice(" ")), "1 000.0");
+    try expectStr(groupDigits(RocStr.fromSlice("12345678901234567890123"), RocStr.fromSlice("_")), "12_345_678_901_234_567_890_123");
+}
+
+fn expectParsed(comptime T: type, actual: NumParseCheckedResult(T), expected: T) !void {
+    try testing.expectEqual(@enumToInt(NumParseProblem.None), actual.problem);
+    try testing.expectEqual(expected, actual.value);
+}
+
+fn expectParseFailure(comptime T: type, actual: NumParseCheckedResult(T), index: usize, problem: NumParseProblem) !void {
+    try testing.expectEqual(@enumToInt(problem), actual.problem);
+    try testing.expectEqual(index, actual.index);
+}
+
+test "parseIntRadix" {
+    try expectParsed(u32, parseIntRadix(u32, "ff", 16), 255);
+    try expectParsed(u32, parseIntRadix(u32, "0xFF", 16), 255);
+    try expectParsed(u8, parseIntRadix(u8, "0b1010_0101", 2), 0b1010_0101);
+    try expectParsed(i16, parseIntRadix(i16, "-0o777", 8), -0o777);
+    try expectParsed(i64, parseIntRadix(i64, "1_000_000", 10), 1_000_000);
+    try expectParsed(i8, parseIntRadix(i8, "-128", 10), -128);
+    try expectParsed(u64, parseIntRadix(u64, "zz", 36), 36 * 36 - 1);
+}
+
+test "parseIntRadix: failures" {
+    try expectParseFailure(u32, parseIntRadix(u32, "", 10), 0, .MissingDigits);
+    try expectParseFailure(u32, parseIntRadix(u32, "0x", 16), 2, .MissingDigits);
+    try expectParseFailure(u32, parseIntRadix(u32, "12a4", 10), 2, .InvalidDigit);
+    try expectParseFailure(u32, parseIntRadix(u32, "0x12", 10), 1, .InvalidDigit);
+    try expectParseFailure(u32, parseIntRadix(u32, "_12", 10), 0, .InvalidDigit);
+    try expectParseFailure(i8, parseIntRadix(i8, "128", 10), 2, .Overflow);
+    try expectParseFailure(u8, parseIntRadix(u8, "-1", 10), 1, .Overflow);
+    try expectParseFailure(u8, parseIntRadix(u8, "1", 37), 0, .InvalidRadix);
+    try expectParseFailure(u32, parseIntRadix(u32, "1__2", 10), 2, .InvalidDigit);
+    try expectParseFailure(u32, parseIntRadix(u32, "1_", 10), 1, .InvalidDigit);
+    try expectParseFailure(u32, parseIntRadix(u32, "0x_1", 16), 2, .InvalidDigit);
+    try expectParseFailure(i32, parseIntRadix(i32, "-_1", 10), 1, .InvalidDigit);
+}
+
+test "parseFloatChecked" {
+    try expectParsed(f64, parseFloatChecked(f64, "1.5"), 1.5);
+    try expectParsed(f64, parseFloatChecked(f64, "-0.25"), -0.25);
+    try expectParsed(f64, parseFloatChecked(f64, "1_000.5"), 1000.5);
+    try expectParsed(f64, parseFloatChecked(f64, "2.5e3"), 2500);
+    try expectParsed(f32, parseFloatChecked(f32, "5E-1"), 0.5);
+}
+
+test "parseFloatChecked: failures" {
+    try expectParseFailure(f64, parseFloatChecked(f64, ""), 0, .MissingDigits);
+    try expectParseFailure(f64, parseFloatChecked(f64, "1."), 2, .MissingDigits);
+    try expectParseFailure(f64, parseFloatChecked(f64, ".5"), 0, .InvalidDigit);
+    try expectParseFailure(f64, parseFloatChecked(f64, "1,5"), 1, .InvalidDigit);
+    try expectParseFailure(f64, parseFloatChecked(f64, "1.5e"), 4, .MissingDigits);
+    try expectParseFailure(f64, parseFloatChecked(f64, "nan"), 0, .InvalidDigit);
+    try expectParseFailure(f64, parseFloatChecked(f64, "1__2.0"), 1, .InvalidDigit);
+    try expectParseFailure(f64, parseFloatChecked(f64, "1_.5"), 1, .InvalidDigit);
+    try expectParseFailure(f64, parseFloatChecked(f64, "1.5_"), 3, .InvalidDigit);
+    try expectParseFailure(f64, parseFloatChecked(f64, "1e_5"), 2, .InvalidDigit);
+}
+
+test "parseFloatChecked: overflow" {
+    try expectParseFailure(f64, parseFloatChecked(f64, "1e400"), 2, .Overflow);
+    try expectParseFailure(f32, parseFloatChecked(f32, "-1.5e39"), 5, .Overflow);
+    try expectParseFailure(f64, parseFloatChecked(f64, "1" ++ "0" ** 309), 309, .Overflow);
+    try expectParseFailure(f64, parseFloatChecked(f64, "00" ++ "9" ** 310 ++ ".5"), 311, .Overflow);
+    try expectParseFailure(f32, parseFloatChecked(f32, "4" ++ "0" ** 38), 38, .Overflow);
+}
@@ -0,0 +1,199 @@
+const std = @import("std");
+const builtin = @import("builtin");
+const utils = @import("utils.zig");
+const RocStr = @import("str.zig").RocStr;
+
+// Copy tracing for `--trace-refcounts`.
+//
+// Updates like `List.set` or `Str.concat` happen in place when the value is unique (its
+// refcount is 1), and copy the whole value otherwise. When tracing is on, the compiler calls
+// `setLocation` right before every `List` and `Str` builtin call and record update in app code,
+// and `resetLocation` right after it. While a location is set, the builtins report each copy
+// they make because their input was shared, and the refcount helpers report each value the
+// operation leaves shared, so an accidentally quadratic loop shows up as a stream of copies at
+// one source location.
+
+pub var enabled: bool = false;
+
+// A builtin like `List.map` can call back into app code that sets a location of its own, so the
+// locations form a stack. Generated code may pass a small string that lives on its stack, so we
+// keep our own copy of each.
+const max_depth = 16;
+var location_buffers: [max_depth][256]u8 = undefined;
+var location_lens: [max_depth]usize = undefined;
+var depth: usize = 0;
+
+// Values that stopped being unique during a traced operation. They are only reported when the
+// operation ends, and only if they are still shared by then: a record update, for example,
+// increments the fields it takes out of a record that it decrements right after.
+const Pending = struct {
+    ptr_to_refcount: *isize,
+    depth: usize,
+};
+const max_pending = 64;
+var pending: [max_pending]Pending = undefined;
+var pending_len: usize = 0;
+
+fn print(comptime fmt: []const u8, args: anytype) void {
+    if (comptime builtin.target.cpu.arch != .wasm32) {
+        std.debug.print(fmt, args);
+    }
+}
+
+fn location() ?[]const u8 {
+    if (depth == 0) {
+        return null;
+    }
+
+    // Beyond `max_depth` we report the deepest location we kept
+    const index = std.math.min(depth, max_depth) - 1;
+    return location_buffers[index][0..location_lens[index]];
+}
+
+/// Called right before a `List` or `Str` builtin, with e.g. "List.set at Main.roc:12:9"
+pub fn setLocation(operation: RocStr) callconv(.C) void {
+    enabled = true;
+
+    if (depth < max_depth) {
+        const bytes = operation.asSlice();
+        const len = std.math.min(bytes.len, location_buffers[depth].len);
+        @memcpy(&location_buffers[depth], bytes.ptr, len);
+        location_lens[depth] = len;
+    }
+
+    depth += 1;
+}
+
+/// Called right after the operation passed to the matching `setLocation`
+pub fn resetLocation() callconv(.C) void {
+    if (depth == 0) {
+        return;
+    }
+
+    const where = location().?;
+
+    var i: usize = 0;
+    while (i < pending_len) {
+        if (pending[i].depth == depth) {
+            if (pending[i].ptr_to_refcount.* != utils.REFCOUNT_ONE_ISIZE) {
+                reportShared(where);
+            }
+
+            pending_len -= 1;
+            pending[i] = pending[pending_len];
+        } else {
+            i += 1;
+        }
+    }
+
+    depth -= 1;
+}
+
+/// Called by the builtins whenever they copy a value because it was shared.
+/// The refcount is passed in its in-memory representation.
+pub fn copied(kind: []const u8, refcount: usize, bytes: usize) void {
+    if (!enabled) {
+        return;
+    }
+
+    const where = location() orelse "untraced code";
+
+    if (refcount == @bitCast(usize, utils.REFCOUNT_MAX_ISIZE)) {
+        // e.g. a string literal, which lives in read-only memory
+        print("Refcount trace: copied a constant {s} of {} bytes in {s}\n", .{ kind, bytes, where });
+    } else {
+        const shared_by = refcount - utils.REFCOUNT_ONE + 1;
+        print("Refcount trace: copied a {s} of {} bytes shared {} times in {s}\n", .{ kind, bytes, shared_by, where });
+    }
+}
+
+fn reportShared(where: []const u8) void {
+    print("Refcount trace: made a unique value shared in {s}\n", .{where});
+}
+
+/// Used by the refcount helpers instead of `utils.increfC` when tracing is on
+pub fn incref(ptr_to_refcount: *isize, amount: isize) callconv(.C) void {
+    if (depth > 0 and ptr_to_refcount.* == utils.REFCOUNT_ONE_ISIZE) {
+        if (pending_len < max_pending) {
+            pending[pending_len] = .{ .ptr_to_refcount = ptr_to_refcount, .depth = depth };
+            pending_len += 1;
+        } else {
+            // Too many to keep track of, so report it right away
+            reportShared(location().?);
+        }
+    }
+
+    utils.increfC(ptr_to_refcount, amount);
+}
+
+/// Called right before a value is freed, so we never look at its refcount again
+pub fn forget(ptr_to_refcount: *isize) void {
+    var i: usize = 0;
+    while (i < pending_len) {
+        if (pending[i].ptr_to_refcount == ptr_to_refcount) {
+            pending_len -= 1;
+            pending[i] = pending[pending_len];
+        } else {
+            i += 1;
+        }
+    }
+}
+
+test "refcount trace keeps its own copy of the location" {
+    const expectEqualSlices = std.testing.expectEqualSlices;
+
+    var operation = RocStr.init("List.set at Main.roc:3:5", 24);
+    setLocation(operation);
+    operation.deinit();
+
+    try expectEqualSlices(u8, "List.set at Main.roc:3:5", location().?);
+
+    resetLocation();
+    enabled = false;
+}
+
+test "refcount trace restores the enclosing location" {
+    const expect = std.testing.expect;
+    const expectEqualSlices = std.testing.expectEqualSlices;
+
+    var outer = RocStr.init("List.map at Main.roc:3:5", 24);
+    defer outer.deinit();
+    var inner = RocStr.init("List.set at Main.roc:4:9", 24);
+    defer inner.deinit();
+
+    setLocation(outer);
+    setLocation(inner);
+    try expectEqualSlices(u8, "List.set at Main.roc:4:9", location().?);
+
+    resetLocation();
+    try expectEqualSlices(u8, "List.map at Main.roc:3:5", location().?);
+
+    resetLocation();
+    try expect(location() == null);
+
+    enabled = false;
+}
+
+test "refcount trace only keeps values that are still shared at the end of the operation" {
+    const expectEqual = std.testing.expectEqual;
+
+    var operation = RocStr.init("record update at Main.roc:3:5", 29);
+    defer operation.deinit();
+
+    var kept: isize = utils.REFCOUNT_ONE_ISIZE;
+    var undone: isize = utils.REFCOUNT_ONE_ISIZE;
+
+    setLocation(operation);
+    incref(&kept, 1);
+    incref(&undone, 1);
+    try expectEqual(@as(usize, 2), pending_len);
+
+    undone -= 1;
+    forget(&undone);
+    try expectEqual(@as(usize, 1), pending_len);
+
+    resetLocation();
+    try expectEqual(@as(usize, 0), pending_len);
+
+    enabled = false;
+}
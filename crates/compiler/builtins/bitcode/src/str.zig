@@ -1,6 +1,9 @@
 const utils = @import("utils.zig");
 const RocList = @import("list.zig").RocList;
+const refcount_trace = @import("refcount_trace.zig");
 const grapheme = @import("helpers/grapheme.zig");
+const unicode_tables = @import("helpers/unicode_tables.zig");
+const num = @import("num.zig");
 const UpdateMode = utils.UpdateMode;
 const std = @import("std");
 const mem = std.mem;
@@ -162,7 +165,12 @@ pub const RocStr = extern struct {
         const element_width = 1;
         const old_capacity = self.getCapacity();
 
-        if (self.isSmallStr() or !self.isUnique()) {
+        if (self.isSmallStr()) {
+            return self.reallocateFresh(new_length);
+        }
+
+        if (!self.isUnique()) {
+            refcount_trace.copied("string", self.refcountMachine(), self.len());
             return self.reallocateFresh(new_length);
         }
 
@@ -782,6 +790,10 @@ pub fn exportFromFloat(comptime T: type, comptime name: []const u8) void {
 }
 
 fn strFromFloatHelp(comptime T: type, float: T) RocStr {
+    if (num.nonFiniteFloatStr(float)) |str| {
+        return RocStr.fromSlice(str);
+    }
+
     var buf: [400]u8 = undefined;
     const result = std.fmt.bufPrint(&buf, "{d}", .{float}) catch unreachable;
 
@@ -2705,3 +2717,457 @@ pub fn strCloneTo(
         return extra_offset + slice.len;
     }
 }
+
+// Str.find
+// Returns the byte index of the first occurrence of `needle`, or usize's max value if there is none.
+pub fn strFind(haystack: RocStr, needle: RocStr) callconv(.C) usize {
+    return mem.indexOf(u8, haystack.asSlice(), needle.asSlice()) orelse std.math.maxInt(usize);
+}
+
+test "strFind: found" {
+    const haystack_bytes = "a string so large that it must be heap-allocated";
+    const haystack = RocStr.init(haystack_bytes, haystack_bytes.len);
+    defer haystack.deinit();
+
+    const needle_bytes = "heap";
+    const needle = RocStr.init(needle_bytes, needle_bytes.len);
+    defer needle.deinit();
+
+    try expectEqual(strFind(haystack, needle), 31);
+}
+
+test "strFind: not found" {
+    const haystack_bytes = "héllo";
+    const haystack = RocStr.init(haystack_bytes, haystack_bytes.len);
+    defer haystack.deinit();
+
+    const needle_bytes = "hel";
+    const needle = RocStr.init(needle_bytes, needle_bytes.len);
+    defer needle.deinit();
+
+    try expectEqual(strFind(haystack, needle), std.math.maxInt(usize));
+}
+
+test "strFind: empty needle" {
+    const haystack_bytes = "hello";
+    const haystack = RocStr.init(haystack_bytes, haystack_bytes.len);
+    defer haystack.deinit();
+
+    try expectEqual(strFind(haystack, RocStr.empty()), 0);
+}
+
+// Unicode case mapping and normalization, using the tables in helpers/unicode_tables.zig.
+// Case mapping is done per scalar, so context-sensitive mappings (like the Greek final sigma)
+// are not applied.
+
+const CaseMap = enum {
+    upper,
+    lower,
+    fold,
+};
+
+// Binary search a table of entries sorted by their `codepoint` field.
+fn lookupCodepoint(comptime T: type, table: []const T, codepoint: u21) ?*const T {
+    var low: usize = 0;
+    var high: usize = table.len;
+
+    while (low < high) {
+        const mid = low + (high - low) / 2;
+        const entry = &table[mid];
+
+        if (entry.codepoint == codepoint) {
+            return entry;
+        } else if (entry.codepoint < codepoint) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    return null;
+}
+
+fn isAscii(bytes: []const u8) bool {
+    for (bytes) |byte| {
+        if (byte >= 0x80) {
+            return false;
+        }
+    }
+
+    return true;
+}
+
+fn mapAsciiCase(mode: CaseMap, byte: u8) u8 {
+    return switch (mode) {
+        .upper => std.ascii.toUpper(byte),
+        .lower, .fold => std.ascii.toLower(byte),
+    };
+}
+
+// The scalars that `codepoint` maps to. Scalars without an entry in the table map to themselves,
+// in which case `scratch` is used to hold the result.
+fn mapCase(mode: CaseMap, codepoint: u21, scratch: *[1]u21) []const u21 {
+    if (lookupCodepoint(unicode_tables.CaseMapping, &unicode_tables.case_mappings, codepoint)) |entry| {
+        const mapping = switch (mode) {
+            .upper => entry.upper,
+            .lower => entry.lower,
+            .fold => entry.fold,
+        };
+
+        if (mapping.len > 0) {
+            return mapping;
+        }
+    }
+
+    scratch[0] = codepoint;
+    return scratch[0..];
+}
+
+fn strMapCase(string: RocStr, mode: CaseMap) RocStr {
+    const bytes = string.asSlice();
+
+    if (isAscii(bytes)) {
+        const in_place = string.isUnique();
+        var result = if (in_place) string else RocStr.allocate(bytes.len);
+        const result_ptr = result.asU8ptrMut();
+
+        for (bytes) |byte, i| {
+            result_ptr[i] = mapAsciiCase(mode, byte);
+        }
+
+        if (!in_place) {
+            string.deinit();
+        }
+
+        return result;
+    }
+
+    // Measure the result first, so that it is only allocated once.
+    var scratch: [1]u21 = undefined;
+    var result_len: usize = 0;
+    var it = (unicode.Utf8View.init(bytes) catch unreachable).iterator();
+
+    while (it.nextCodepoint()) |codepoint| {
+        for (mapCase(mode, codepoint, &scratch)) |scalar| {
+            result_len += unicode.utf8CodepointSequenceLength(scalar) catch unreachable;
+        }
+    }
+
+    var result = RocStr.allocate(result_len);
+    const result_bytes = result.asU8ptrMut()[0..result_len];
+    var offset: usize = 0;
+    it = (unicode.Utf8View.init(bytes) catch unreachable).iterator();
+
+    while (it.nextCodepoint()) |codepoint| {
+        for (mapCase(mode, codepoint, &scratch)) |scalar| {
+            offset += unicode.utf8Encode(scalar, result_bytes[offset..]) catch unreachable;
+        }
+    }
+
+    string.deinit();
+
+    return result;
+}
+
+// Str.toUppercase
+pub fn strToUppercase(string: RocStr) callconv(.C) RocStr {
+    return strMapCase(string, .upper);
+}
+
+// Str.toLowercase
+pub fn strToLowercase(string: RocStr) callconv(.C) RocStr {
+    return strMapCase(string, .lower);
+}
+
+// Str.caseFold
+pub fn strCaseFold(string: RocStr) callconv(.C) RocStr {
+    return strMapCase(string, .fold);
+}
+
+const HANGUL_S_BASE: u21 = 0xAC00;
+const HANGUL_L_BASE: u21 = 0x1100;
+const HANGUL_V_BASE: u21 = 0x1161;
+const HANGUL_T_BASE: u21 = 0x11A7;
+const HANGUL_L_COUNT: u21 = 19;
+const HANGUL_V_COUNT: u21 = 21;
+const HANGUL_T_COUNT: u21 = 28;
+const HANGUL_N_COUNT: u21 = HANGUL_V_COUNT * HANGUL_T_COUNT;
+const HANGUL_S_COUNT: u21 = HANGUL_L_COUNT * HANGUL_N_COUNT;
+
+// A fixed-capacity buffer of scalars. The full canonical decomposition of a UTF-8 string
+// never has more scalars than 1.5 times its number of bytes, so twice the byte length is enough.
+const ScalarBuffer = struct {
+    scalars: [*]u21,
+    len: usize,
+    capacity: usize,
+
+    fn withCapacity(capacity: usize) ScalarBuffer {
+        const bytes = utils.alloc(capacity * @sizeOf(u21), @alignOf(u21)) orelse unreachable;
+
+        return ScalarBuffer{
+            .scalars = @ptrCast([*]u21, @alignCast(@alignOf(u21), bytes)),
+            .len = 0,
+            .capacity = capacity,
+        };
+    }
+
+    fn deinit(self: ScalarBuffer) void {
+        utils.dealloc(@ptrCast([*]u8, self.scalars), @alignOf(u21));
+    }
+
+    fn push(self: *ScalarBuffer, scalar: u21) void {
+        std.debug.assert(self.len < self.capacity);
+
+        self.scalars[self.len] = scalar;
+        self.len += 1;
+    }
+
+    fn slice(self: ScalarBuffer) []u21 {
+        return self.scalars[0..self.len];
+    }
+};
+
+fn combiningClass(codepoint: u21) u8 {
+    if (lookupCodepoint(unicode_tables.CombiningClass, &unicode_tables.combining_classes, codepoint)) |entry| {
+        return entry.class;
+    }
+
+    return 0;
+}
+
+fn decompose(buffer: *ScalarBuffer, codepoint: u21) void {
+    if (codepoint >= HANGUL_S_BASE and codepoint < HANGUL_S_BASE + HANGUL_S_COUNT) {
+        // Hangul syllables decompose algorithmically, see section 3.12 of the Unicode standard.
+        const s_index = codepoint - HANGUL_S_BASE;
+        const t_index = s_index % HANGUL_T_COUNT;
+
+        buffer.push(HANGUL_L_BASE + s_index / HANGUL_N_COUNT);
+        buffer.push(HANGUL_V_BASE + (s_index % HANGUL_N_COUNT) / HANGUL_T_COUNT);
+
+        if (t_index != 0) {
+            buffer.push(HANGUL_T_BASE + t_index);
+        }
+    } else if (lookupCodepoint(unicode_tables.Decomposition, &unicode_tables.decompositions, codepoint)) |entry| {
+        // The table only holds one level of decomposition.
+        for (entry.mapping) |scalar| {
+            decompose(buffer, scalar);
+        }
+    } else {
+        buffer.push(codepoint);
+    }
+}
+
+// Stable sort every run of non-starters by combining class.
+fn canonicalOrder(scalars: []u21) void {
+    var i: usize = 1;
+
+    while (i < scalars.len) : (i += 1) {
+        const scalar = scalars[i];
+        const class = combiningClass(scalar);
+
+        if (class == 0) {
+            continue;
+        }
+
+        // Starters have class 0, so this never moves a scalar past one.
+        var j = i;
+        while (j > 0 and combiningClass(scalars[j - 1]) > class) : (j -= 1) {
+            scalars[j] = scalars[j - 1];
+        }
+
+        scalars[j] = scalar;
+    }
+}
+
+fn composePair(first: u21, second: u21) ?u21 {
+    if (first >= HANGUL_L_BASE and first < HANGUL_L_BASE + HANGUL_L_COUNT and
+        second >= HANGUL_V_BASE and second < HANGUL_V_BASE + HANGUL_V_COUNT)
+    {
+        const lv_index = (first - HANGUL_L_BASE) * HANGUL_N_COUNT + (second - HANGUL_V_BASE) * HANGUL_T_COUNT;
+        return HANGUL_S_BASE + lv_index;
+    }
+
+    if (first >= HANGUL_S_BASE and first < HANGUL_S_BASE + HANGUL_S_COUNT and
+        (first - HANGUL_S_BASE) % HANGUL_T_COUNT == 0 and
+        second > HANGUL_T_BASE and second < HANGUL_T_BASE + HANGUL_T_COUNT)
+    {
+        return first + (second - HANGUL_T_BASE);
+    }
+
+    const compositions = &unicode_tables.compositions;
+    var low: usize = 0;
+    var high: usize = compositions.len;
+
+    while (low < high) {
+        const mid = low + (high - low) / 2;
+        const entry = compositions[mid];
+
+        if (entry.first == first and entry.second == second) {
+            return entry.composite;
+        } else if (entry.first < first or (entry.first == first and entry.second < second)) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    return null;
+}
+
+// Canonical composition of a canonically ordered decomposition, in place.
+// Returns the number of scalars that remain.
+fn canonicalCompose(scalars: []u21) usize {
+    var starter: ?usize = null;
+    var last_class: u8 = 0;
+    var write: usize = 0;
+
+    for (scalars) |scalar| {
+        const class = combiningClass(scalar);
+
+        if (starter) |starter_index| {
+            // A scalar can combine with the last starter, unless a scalar in between has a
+            // class that is 0 or at least as high as its own.
+            const adjacent = write == starter_index + 1;
+
+            if (adjacent or (last_class != 0 and last_class < class)) {
+                if (composePair(scalars[starter_index], scalar)) |composite| {
+                    scalars[starter_index] = composite;
+                    continue;
+                }
+            }
+        }
+
+        if (class == 0) {
+            starter = write;
+            last_class = 0;
+        } else {
+            last_class = class;
+        }
+
+        scalars[write] = scalar;
+        write += 1;
+    }
+
+    return write;
+}
+
+fn scalarsToStr(scalars: []const u21) RocStr {
+    var result_len: usize = 0;
+    for (scalars) |scalar| {
+        result_len += unicode.utf8CodepointSequenceLength(scalar) catch unreachable;
+    }
+
+    var result = RocStr.allocate(result_len);
+    const result_bytes = result.asU8ptrMut()[0..result_len];
+    var offset: usize = 0;
+
+    for (scalars) |scalar| {
+        offset += unicode.utf8Encode(scalar, result_bytes[offset..]) catch unreachable;
+    }
+
+    return result;
+}
+
+fn strNormalize(string: RocStr, compose: bool) RocStr {
+    const bytes = string.asSlice();
+
+    // ASCII is unaffected by normalization.
+    if (isAscii(bytes)) {
+        return string;
+    }
+
+    var buffer = ScalarBuffer.withCapacity(bytes.len * 2);
+    defer buffer.deinit();
+
+    var it = (unicode.Utf8View.init(bytes) catch unreachable).iterator();
+    while (it.nextCodepoint()) |codepoint| {
+        decompose(&buffer, codepoint);
+    }
+
+    canonicalOrder(buffer.slice());
+
+    if (compose) {
+        buffer.len = canonicalCompose(buffer.slice());
+    }
+
+    const result = scalarsToStr(buffer.slice());
+    string.deinit();
+
+    return result;
+}
+
+// Str.toNfc
+pub fn strToNfc(string: RocStr) callconv(.C) RocStr {
+    return strNormalize(string, true);
+}
+
+// Str.toNfd
+pub fn strToNfd(string: RocStr) callconv(.C) RocStr {
+    return strNormalize(string, false);
+}
+
+fn expectStrTransform(transform: fn (RocStr) callconv(.C) RocStr, input: []const u8, expected_bytes: []const u8) !void {
+    const actual = transform(RocStr.fromSlice(input));
+    defer actual.deinit();
+
+    const expected = RocStr.fromSlice(expected_bytes);
+    defer expected.deinit();
+
+    try expect(actual.eq(expected));
+}
+
+test "strToUppercase: small ascii" {
+    try expectStrTransform(strToUppercase, "hello, World!", "HELLO, WORLD!");
+}
+
+test "strToUppercase: big ascii" {
+    try expectStrTransform(
+        strToUppercase,
+        "a string so large that it must be heap-allocated",
+        "A STRING SO LARGE THAT IT MUST BE HEAP-ALLOCATED",
+    );
+}
+
+test "strToUppercase: unicode" {
+    try expectStrTransform(strToUppercase, "grüße, ǆ", "GRÜSSE, Ǆ");
+}
+
+test "strToLowercase: unicode" {
+    try expectStrTransform(strToLowercase, "ÀÉÎ ǅ ΣΑΣ", "àéî ǆ σασ");
+}
+
+test "strCaseFold: unicode" {
+    try expectStrTransform(strCaseFold, "Straße ΣΑΣ", "strasse σασ");
+}
+
+test "strToNfd: ascii" {
+    try expectStrTransform(strToNfd, "hello", "hello");
+}
+
+test "strToNfd: decomposes recursively" {
+    try expectStrTransform(strToNfd, "\u{1E9B}\u{323}", "\u{17F}\u{323}\u{307}");
+}
+
+test "strToNfd: reorders combining marks" {
+    try expectStrTransform(strToNfd, "a\u{302}\u{323}", "a\u{323}\u{302}");
+}
+
+test "strToNfd: hangul" {
+    try expectStrTransform(strToNfd, "\u{AC01}", "\u{1100}\u{1161}\u{11A8}");
+}
+
+test "strToNfc: composes" {
+    try expectStrTransform(strToNfc, "e\u{301} A\u{30A}", "\u{E9} \u{C5}");
+}
+
+test "strToNfc: reorders before composing" {
+    try expectStrTransform(strToNfc, "a\u{302}\u{323}", "\u{1EAD}");
+}
+
+test "strToNfc: hangul" {
+    try expectStrTransform(strToNfc, "\u{1100}\u{1161}\u{11A8}", "\u{AC01}");
+}
+
+test "strToNfc: blocked composition" {
+    try expectStrTransform(strToNfc, "\u{1E9B}\u{323}", "\u{1E9B}\u{323}");
+}
@@ -1,6 +1,9 @@
 const std = @import("std");
+const builtin = @import("builtin");
 const always_inline = std.builtin.CallOptions.Modifier.always_inline;
 const Monotonic = std.builtin.AtomicOrder.Monotonic;
+const memcheck = @import("memcheck.zig");
+const refcount_trace = @import("refcount_trace.zig");
 
 pub fn WithOverflow(comptime T: type) type {
     return extern struct { value: T, has_overflowed: bool };
@@ -40,7 +43,6 @@ fn testing_roc_mmap(addr: ?*anyopaque, length: c_uint, prot: c_int, flags: c_int
 }
 
 comptime {
-    const builtin = @import("builtin");
     // During tests, use the testing allocators to satisfy these functions.
     if (builtin.is_test) {
         @export(testing_roc_alloc, .{ .name = "roc_alloc", .linkage = .Strong });
@@ -90,14 +92,26 @@ fn testing_roc_memcpy(dest: *anyopaque, src: *anyopaque, bytes: usize) callconv(
 }
 
 pub fn alloc(size: usize, alignment: u32) ?[*]u8 {
+    if (memcheck.enabled) {
+        return @ptrCast(?[*]u8, memcheck.alloc(size, alignment));
+    }
+
     return @ptrCast(?[*]u8, roc_alloc(size, alignment));
 }
 
 pub fn realloc(c_ptr: [*]u8, new_size: usize, old_size: usize, alignment: u32) [*]u8 {
+    if (memcheck.enabled) {
+        return @ptrCast([*]u8, memcheck.realloc(c_ptr, new_size, old_size, alignment));
+    }
+
     return @ptrCast([*]u8, roc_realloc(c_ptr, new_size, old_size, alignment));
 }
 
 pub fn dealloc(c_ptr: [*]u8, alignment: u32) void {
+    if (memcheck.enabled) {
+        return memcheck.dealloc(c_ptr, alignment);
+    }
+
     return roc_dealloc(c_ptr, alignment);
 }
 
@@ -105,24 +119,57 @@ pub fn memcpy(dst: [*]u8, src: [*]u8, size: usize) void {
     roc_memcpy(dst, src, size);
 }
 
+// The dev backend cannot unwind out of the code it generates, so its tests catch a
+// roc_panic by calling the program through test_dispatch, which sets up a setjmp that
+// test_panic jumps back to.
+pub const has_test_dispatch = builtin.target.cpu.arch != .wasm32 and builtin.os.tag != .windows;
+
+extern fn setjmp([*c]c_int) c_int;
+extern fn longjmp([*c]c_int, c_int) noreturn;
+
+// Larger than the jmp_buf of every libc we target
+var test_jmp_buf: [128]c_int align(16) = undefined;
+var test_dispatch_active: bool = false;
+var test_panic_msg: [3]usize = undefined;
+var test_panic_tag: u32 = 0;
+
 // indirection because otherwise zig creates an alias to the panic function which our LLVM code
 // does not know how to deal with
-pub fn test_panic(c_ptr: *anyopaque, alignment: u32) callconv(.C) void {
-    _ = c_ptr;
-    _ = alignment;
-    // const cstr = @ptrCast([*:0]u8, c_ptr);
+pub fn test_panic(c_ptr: *anyopaque, tag_id: u32) callconv(.C) void {
+    if (has_test_dispatch and test_dispatch_active) {
+        // The message is a RocStr passed by reference. Copy it out, because the stack
+        // frame it lives in is gone once we jump back to test_dispatch.
+        test_panic_msg = @ptrCast(*const [3]usize, @alignCast(@alignOf(usize), c_ptr)).*;
+        test_panic_tag = tag_id;
+
+        longjmp(&test_jmp_buf, 1);
+    }
+}
 
-    // const stderr = std.io.getStdErr().writer();
-    // stderr.print("Roc panicked: {s}!\n", .{cstr}) catch unreachable;
+pub const TestMain = fn (?*anyopaque) callconv(.C) void;
 
-    // std.c.exit(1);
+// Calls `main(data)`. Returns 0 if it returned normally, or the crash tag plus one if
+// it called roc_panic, in which case the panic message is written to `msg`.
+pub fn test_dispatch(main: TestMain, data: ?*anyopaque, msg: *[3]usize) callconv(.C) u32 {
+    if (setjmp(&test_jmp_buf) == 0) {
+        test_dispatch_active = true;
+        main(data);
+        test_dispatch_active = false;
+
+        return 0;
+    } else {
+        test_dispatch_active = false;
+        msg.* = test_panic_msg;
+
+        return test_panic_tag + 1;
+    }
 }
 
 pub const Inc = fn (?[*]u8) callconv(.C) void;
 pub const IncN = fn (?[*]u8, u64) callconv(.C) void;
 pub const Dec = fn (?[*]u8) callconv(.C) void;
 
-const REFCOUNT_MAX_ISIZE: isize = 0;
+pub const REFCOUNT_MAX_ISIZE: isize = 0;
 pub const REFCOUNT_ONE_ISIZE: isize = std.math.minInt(isize);
 pub const REFCOUNT_ONE: usize = @bitCast(usize, REFCOUNT_ONE_ISIZE);
 
@@ -217,12 +264,14 @@ inline fn decref_ptr_to_refcount(
             Refcount.normal => {
                 refcount_ptr[0] = refcount -% 1;
                 if (refcount == REFCOUNT_ONE_ISIZE) {
+                    refcount_trace.forget(&refcount_ptr[0]);
                     dealloc(@ptrCast([*]u8, refcount_ptr) - (extra_bytes - @sizeOf(usize)), alignment);
                 }
             },
             Refcount.atomic => {
                 var last = @atomicRmw(isize, &refcount_ptr[0], std.builtin.AtomicRmwOp.Sub, 1, Monotonic);
                 if (last == REFCOUNT_ONE_ISIZE) {
+                    refcount_trace.forget(&refcount_ptr[0]);
                     dealloc(@ptrCast([*]u8, refcount_ptr) - (extra_bytes - @sizeOf(usize)), alignment);
                 }
             },
@@ -0,0 +1,331 @@
+//! Generates `bitcode/src/helpers/unicode_tables.zig` from the Unicode Character Database.
+//!
+//! This only uses the standard library, so it can be run without cargo. From `bitcode`:
+//!
+//! ```text
+//! rustc -O unicode/generate_tables.rs -o /tmp/generate_tables
+//! /tmp/generate_tables path/to/ucd > src/helpers/unicode_tables.zig
+//! ```
+//!
+//! where `path/to/ucd` holds `UnicodeData.txt`, `SpecialCasing.txt`, `CaseFolding.txt` and
+//! `DerivedNormalizationProps.txt` of the same Unicode version, e.g. from
+//! <https://www.unicode.org/Public/14.0.0/ucd/>.
+//!
+//! The tables are sorted by code point, so that the zig builtins can binary search them.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+#[derive(Default)]
+struct CaseMapping {
+    upper: Vec<u32>,
+    lower: Vec<u32>,
+    fold: Vec<u32>,
+}
+
+struct Normalization {
+    codepoint: u32,
+    combining_class: u8,
+    decomposition: Vec<u32>,
+    is_primary_composite: bool,
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+
+    let ucd_dir = match &args[..] {
+        [_, ucd_dir] => Path::new(ucd_dir),
+        _ => {
+            eprintln!("Usage: generate_tables path/to/ucd > src/helpers/unicode_tables.zig");
+            std::process::exit(1);
+        }
+    };
+
+    let unicode_data = read_data_file(ucd_dir, "UnicodeData.txt");
+    let special_casing = read_data_file(ucd_dir, "SpecialCasing.txt");
+    let case_folding = read_data_file(ucd_dir, "CaseFolding.txt");
+    let normalization_props = read_data_file(ucd_dir, "DerivedNormalizationProps.txt");
+
+    let version = ucd_version(&normalization_props);
+    let case_mappings = case_mappings(&unicode_data, &special_casing, &case_folding);
+    let normalizations = normalizations(&unicode_data, &normalization_props);
+
+    print!("{}", render(&version, &case_mappings, &normalizations));
+}
+
+fn read_data_file(ucd_dir: &Path, name: &str) -> String {
+    let path = ucd_dir.join(name);
+
+    fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("Failed to read unicode data {:?}: {:?}", path, err))
+}
+
+/// The fields of every line that isn't empty or a comment, without the trailing comment.
+fn data_lines(contents: &str) -> impl Iterator<Item = Vec<&str>> {
+    contents.lines().filter_map(|line| {
+        let data = line.split('#').next().unwrap_or_default().trim();
+
+        if data.is_empty() {
+            None
+        } else {
+            Some(data.split(';').map(str::trim).collect())
+        }
+    })
+}
+
+/// The derived property files start with e.g. `# DerivedNormalizationProps-14.0.0.txt`.
+fn ucd_version(normalization_props: &str) -> String {
+    normalization_props
+        .lines()
+        .next()
+        .and_then(|line| line.strip_prefix("# DerivedNormalizationProps-"))
+        .and_then(|name| name.strip_suffix(".txt"))
+        .unwrap_or_else(|| panic!("DerivedNormalizationProps.txt has no version header"))
+        .to_string()
+}
+
+/// The uppercase and lowercase mappings combine the simple mappings of UnicodeData.txt with
+/// the unconditional mappings of SpecialCasing.txt. The case foldings are the C and F
+/// mappings of CaseFolding.txt. Code points that map to themselves in all three are left out.
+fn case_mappings(
+    unicode_data: &str,
+    special_casing: &str,
+    case_folding: &str,
+) -> BTreeMap<u32, CaseMapping> {
+    let mut mappings: BTreeMap<u32, CaseMapping> = BTreeMap::new();
+
+    for fields in data_lines(unicode_data) {
+        let codepoint = parse_codepoint(fields[0]);
+        let upper = parse_codepoints(fields[12]);
+        let lower = parse_codepoints(fields[13]);
+
+        if !upper.is_empty() || !lower.is_empty() {
+            let mapping = mappings.entry(codepoint).or_default();
+
+            mapping.upper = upper;
+            mapping.lower = lower;
+        }
+    }
+
+    for fields in data_lines(special_casing) {
+        // <code>; <lower>; <title>; <upper>; (<condition_list>;)?
+        let is_unconditional = fields.get(4).map_or(true, |condition| condition.is_empty());
+
+        if is_unconditional {
+            let mapping = mappings.entry(parse_codepoint(fields[0])).or_default();
+
+            mapping.lower = parse_codepoints(fields[1]);
+            mapping.upper = parse_codepoints(fields[3]);
+        }
+    }
+
+    for fields in data_lines(case_folding) {
+        // <code>; <status>; <mapping>
+        if fields[1] == "C" || fields[1] == "F" {
+            mappings.entry(parse_codepoint(fields[0])).or_default().fold =
+                parse_codepoints(fields[2]);
+        }
+    }
+
+    // Mapping a code point to itself is the same as not mapping it.
+    for (codepoint, mapping) in mappings.iter_mut() {
+        for field in [&mut mapping.upper, &mut mapping.lower, &mut mapping.fold] {
+            if field[..] == [*codepoint] {
+                field.clear();
+            }
+        }
+    }
+
+    mappings.retain(|_, mapping| {
+        !mapping.upper.is_empty() || !mapping.lower.is_empty() || !mapping.fold.is_empty()
+    });
+
+    mappings
+}
+
+/// The canonical combining classes and single-level canonical decompositions of
+/// UnicodeData.txt. A decomposition is a primary composite, which NFC composes back into the
+/// code point, unless DerivedNormalizationProps.txt lists it as a full composition exclusion.
+///
+/// Hangul syllables are left out, because they are decomposed and composed algorithmically.
+fn normalizations(unicode_data: &str, normalization_props: &str) -> Vec<Normalization> {
+    let mut exclusions = BTreeSet::new();
+
+    for fields in data_lines(normalization_props) {
+        if fields.get(1) == Some(&"Full_Composition_Exclusion") {
+            let (first, last) = match fields[0].split_once("..") {
+                Some((first, last)) => (parse_codepoint(first), parse_codepoint(last)),
+                None => (parse_codepoint(fields[0]), parse_codepoint(fields[0])),
+            };
+
+            exclusions.extend(first..=last);
+        }
+    }
+
+    data_lines(unicode_data)
+        .filter_map(|fields| {
+            let codepoint = parse_codepoint(fields[0]);
+            let combining_class: u8 = fields[3]
+                .parse()
+                .unwrap_or_else(|_| panic!("Invalid combining class {:?}", fields[3]));
+
+            // Compatibility decompositions start with a tag like `<compat>`
+            let decomposition = if fields[5].starts_with('<') {
+                Vec::new()
+            } else {
+                parse_codepoints(fields[5])
+            };
+
+            if combining_class == 0 && decomposition.is_empty() {
+                return None;
+            }
+
+            Some(Normalization {
+                codepoint,
+                combining_class,
+                is_primary_composite: !decomposition.is_empty() && !exclusions.contains(&codepoint),
+                decomposition,
+            })
+        })
+        .collect()
+}
+
+fn parse_codepoint(hex: &str) -> u32 {
+    u32::from_str_radix(hex, 16).unwrap_or_else(|_| panic!("Invalid code point {:?}", hex))
+}
+
+fn parse_codepoints(field: &str) -> Vec<u32> {
+    field.split_whitespace().map(parse_codepoint).collect()
+}
+
+fn render(
+    version: &str,
+    case_mappings: &BTreeMap<u32, CaseMapping>,
+    normalizations: &[Normalization],
+) -> String {
+    let mut out = String::new();
+
+    writeln!(
+        out,
+        "// This file is generated by `crates/compiler/builtins/bitcode/unicode/generate_tables.rs`\n\
+         // from the Unicode Character Database, version {}. Do not edit it by hand!",
+        version
+    )
+    .unwrap();
+
+    out.push_str(
+        "\n\
+         pub const CaseMapping = struct {\n    \
+             codepoint: u21,\n    \
+             upper: []const u21,\n    \
+             lower: []const u21,\n    \
+             fold: []const u21,\n\
+         };\n\
+         \n\
+         pub const CombiningClass = struct {\n    \
+             codepoint: u21,\n    \
+             class: u8,\n\
+         };\n\
+         \n\
+         pub const Decomposition = struct {\n    \
+             codepoint: u21,\n    \
+             mapping: []const u21,\n\
+         };\n\
+         \n\
+         pub const Composition = struct {\n    \
+             first: u21,\n    \
+             second: u21,\n    \
+             composite: u21,\n\
+         };\n\
+         \n",
+    );
+
+    // Code points that map to themselves in all three fields are left out.
+    out.push_str("pub const case_mappings = [_]CaseMapping{\n");
+    for (codepoint, mapping) in case_mappings {
+        writeln!(
+            out,
+            "    .{{ .codepoint = {}, .upper = {}, .lower = {}, .fold = {} }},",
+            hex(*codepoint),
+            slice(&mapping.upper),
+            slice(&mapping.lower),
+            slice(&mapping.fold),
+        )
+        .unwrap();
+    }
+    out.push_str("};\n\n");
+
+    // Only the non-zero combining classes are listed.
+    out.push_str("pub const combining_classes = [_]CombiningClass{\n");
+    for entry in normalizations.iter().filter(|n| n.combining_class != 0) {
+        writeln!(
+            out,
+            "    .{{ .codepoint = {}, .class = {} }},",
+            hex(entry.codepoint),
+            entry.combining_class
+        )
+        .unwrap();
+    }
+    out.push_str("};\n\n");
+
+    out.push_str("pub const decompositions = [_]Decomposition{\n");
+    for entry in normalizations
+        .iter()
+        .filter(|n| !n.decomposition.is_empty())
+    {
+        writeln!(
+            out,
+            "    .{{ .codepoint = {}, .mapping = {} }},",
+            hex(entry.codepoint),
+            slice(&entry.decomposition)
+        )
+        .unwrap();
+    }
+    out.push_str("};\n\n");
+
+    // Sorted by the pair of code points that compose, rather than by the composite.
+    let mut compositions: Vec<(u32, u32, u32)> = normalizations
+        .iter()
+        .filter(|n| n.is_primary_composite)
+        .map(|n| match n.decomposition[..] {
+            [first, second] => (first, second, n.codepoint),
+            _ => panic!(
+                "Primary composite {} does not decompose into a pair",
+                hex(n.codepoint)
+            ),
+        })
+        .collect();
+    compositions.sort_unstable();
+
+    out.push_str("pub const compositions = [_]Composition{\n");
+    for (first, second, composite) in compositions {
+        writeln!(
+            out,
+            "    .{{ .first = {}, .second = {}, .composite = {} }},",
+            hex(first),
+            hex(second),
+            hex(composite)
+        )
+        .unwrap();
+    }
+    out.push_str("};\n");
+
+    out
+}
+
+fn hex(codepoint: u32) -> String {
+    format!("0x{:04X}", codepoint)
+}
+
+fn slice(codepoints: &[u32]) -> String {
+    match codepoints {
+        [] => "&[_]u21{}".to_string(),
+        [single] => format!("&[_]u21{{{}}}", hex(*single)),
+        _ => {
+            let elements: Vec<String> = codepoints.iter().map(|c| hex(*c)).collect();
+            format!("&[_]u21{{ {} }}", elements.join(", "))
+        }
+    }
+}
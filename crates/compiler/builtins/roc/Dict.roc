@@ -3,6 +3,8 @@ interface Dict
         Dict,
         empty,
         withCapacity,
+        withHasher,
+        withRandomSeed,
         single,
         clear,
         capacity,
@@ -92,23 +94,95 @@ Dict k v := {
     # TODO: As an optimization, we can make all of these lists in one allocation
     # TODO: Grow data with the rest of the hashmap. This will require creating a list of garbage data.
     # TODO: Change remove to use tombstones. Store the tombstones in a bitmap.
-    # TODO: define Eq and Hash that are unordered. Only if value has hash/eq?
+    # TODO: define Hash that is unordered. Only if value has hash?
     metadata : List I8,
     dataIndices : List Nat,
     data : List (T k v),
     size : Nat,
-} | k has Hash & Eq
+    hasher : DictHasher,
+}
+     has [
+         Eq {
+             isEq,
+         },
+     ]
+
+# Like in Set, `| k has Hash & Eq` should go above the `has`, but it causes the
+# formatter to fail currently.
+
+# The hash function a dictionary keys its entries with. It is stored in the
+# dictionary so that every operation on it agrees on where a key lives.
+#
+# This is a closed set on purpose, see [Dict.withHasher].
+DictHasher : [Wyhash { seed : U64 }, SipHash13 { k0 : U64, k1 : U64 }]
+
+# Two dictionaries are equal if they have the same keys with the same values.
+# Neither the hash function nor the order of the entries matter.
+isEq : Dict k v, Dict k v -> Bool | k has Hash & Eq, v has Eq
+isEq = \xs, ys ->
+    if len xs != len ys then
+        Bool.false
+    else
+        walkUntil xs Bool.true \_, key, xValue ->
+            when get ys key is
+                Ok yValue if yValue == xValue ->
+                    Continue Bool.true
+
+                _ ->
+                    Break Bool.false
 
 ## Return an empty dictionary.
+##
+## Its keys are hashed with a fast hash function and a fixed seed, which means
+## an attacker who controls the keys can make lookups slow by choosing keys
+## that collide. Use [Dict.withRandomSeed] or [Dict.withHasher] for dictionaries
+## keyed by untrusted input.
 empty : {} -> Dict k v | k has Hash & Eq
 empty = \{} ->
+    withHasher (Wyhash { seed: defaultSeed })
+
+## Return an empty dictionary that hashes its keys with the given hash function.
+##
+## * `Wyhash { seed }` is the fast default used by [Dict.empty], with a custom seed.
+## * `SipHash13 { k0, k1 }` is a keyed hash that is slower, but resistant to
+## [hash flooding](https://en.wikipedia.org/wiki/Collision_attack#Hash_flooding)
+## as long as the keys stay secret.
+##
+## Every dictionary derived from the result, for example by [Dict.insert] or
+## [Dict.clear], keeps using the same hash function. Two dictionaries with the
+## same entries are equal, even if they use different hash functions.
+##
+## These are the only hash functions a dictionary can use. Accepting any value
+## with the [Hasher] ability would make the hasher's type part of the type of
+## the dictionary, so every `Dict k v` would need a third type parameter.
+##
+##     expect
+##         Dict.withHasher (SipHash13 { k0: 0x0706_0504_0302_0100, k1: 0x0F0E_0D0C_0B0A_0908 })
+##         |> Dict.insert "Apples" 12
+##         |> Dict.get "Apples"
+##         |> Bool.isEq (Ok 12)
+withHasher : [Wyhash { seed : U64 }, SipHash13 { k0 : U64, k1 : U64 }] -> Dict k v | k has Hash & Eq
+withHasher = \hasher ->
     @Dict {
         metadata: List.repeat emptySlot 8,
         dataIndices: List.repeat 0 8,
         data: [],
         size: 0,
+        hasher,
     }
 
+## Return an empty dictionary that hashes its keys with [SipHash](https://en.wikipedia.org/wiki/SipHash)
+## keyed by a random seed.
+##
+## The seed is obtained once per process from the operating system, see [Hash.randomSeed];
+## on wasm32, this crashes. Use this for
+## dictionaries keyed by untrusted input, such as strings received over the network.
+withRandomSeed : {} -> Dict k v | k has Hash & Eq
+withRandomSeed = \{} ->
+    seed = Hash.randomSeed {}
+
+    withHasher (SipHash13 { k0: seed, k1: wymix seed wyp0 })
+
 ## Returns the max number of elements the dictionary can hold before requiring a rehash.
 capacity : Dict k v -> Nat | k has Hash & Eq
 capacity = \@Dict { dataIndices } ->
@@ -161,12 +235,12 @@ len = \@Dict { size } ->
 
 ## Clears all elements from a dictionary keeping around the allocation if it isn't huge.
 clear : Dict k v -> Dict k v | k has Hash & Eq
-clear = \@Dict { metadata, dataIndices, data } ->
+clear = \@Dict { metadata, dataIndices, data, hasher } ->
     cap = List.len dataIndices
 
     # Only clear large allocations.
     if cap > 128 * 8 then
-        empty {}
+        withHasher hasher
     else
         @Dict {
             metadata: List.map metadata (\_ -> emptySlot),
@@ -175,6 +249,7 @@ clear = \@Dict { metadata, dataIndices, data } ->
             # use takeFirst to keep around the capacity.
             data: List.takeFirst data 0,
             size: 0,
+            hasher,
         }
 
 ## Iterate through the keys and values in the dictionary and call the provided
@@ -217,11 +292,8 @@ walkUntil = \@Dict { data }, initialState, transform ->
 ##     expect Dict.get dictionary 1 == Ok "Apple"
 ##     expect Dict.get dictionary 2000 == Err KeyNotFound
 get : Dict k v, k -> Result v [KeyNotFound] | k has Hash & Eq
-get = \@Dict { metadata, dataIndices, data }, key ->
-    hashKey =
-        createLowLevelHasher {}
-        |> Hash.hash key
-        |> complete
+get = \@Dict { metadata, dataIndices, data, hasher }, key ->
+    hashKey = hashWith hasher key
     h1Key = h1 hashKey
     h2Key = h2 hashKey
     probe = newProbe h1Key (div8 (List.len metadata))
@@ -244,11 +316,8 @@ get = \@Dict { metadata, dataIndices, data }, key ->
 ##         |> Dict.contains 1234
 ##         |> Bool.isEq Bool.true
 contains : Dict k v, k -> Bool | k has Hash & Eq
-contains = \@Dict { metadata, dataIndices, data }, key ->
-    hashKey =
-        createLowLevelHasher {}
-        |> Hash.hash key
-        |> complete
+contains = \@Dict { metadata, dataIndices, data, hasher }, key ->
+    hashKey = hashWith hasher key
     h1Key = h1 hashKey
     h2Key = h2 hashKey
     probe = newProbe h1Key (div8 (List.len metadata))
@@ -268,11 +337,8 @@ contains = \@Dict { metadata, dataIndices, data }, key ->
 ##         |> Dict.get "Apples"
 ##         |> Bool.isEq (Ok 12)
 insert : Dict k v, k, v -> Dict k v | k has Hash & Eq
-insert = \@Dict { metadata, dataIndices, data, size }, key, value ->
-    hashKey =
-        createLowLevelHasher {}
-        |> Hash.hash key
-        |> complete
+insert = \@Dict { metadata, dataIndices, data, size, hasher }, key, value ->
+    hashKey = hashWith hasher key
     h1Key = h1 hashKey
     h2Key = h2 hashKey
     probe = newProbe h1Key (div8 (List.len metadata))
@@ -286,6 +352,7 @@ insert = \@Dict { metadata, dataIndices, data, size }, key, value ->
                 dataIndices,
                 data: List.set data dataIndex (T key value),
                 size,
+                hasher,
             }
 
         Err NotFound ->
@@ -298,6 +365,7 @@ insert = \@Dict { metadata, dataIndices, data, size }, key, value ->
                             dataIndices,
                             data,
                             size: size + 1,
+                            hasher,
                         }
                     )
 
@@ -313,12 +381,9 @@ insert = \@Dict { metadata, dataIndices, data, size }, key, value ->
 ##         |> Dict.len
 ##         |> Bool.isEq 0
 remove : Dict k v, k -> Dict k v | k has Hash & Eq
-remove = \@Dict { metadata, dataIndices, data, size }, key ->
+remove = \@Dict { metadata, dataIndices, data, size, hasher }, key ->
     # TODO: change this from swap remove to tombstone and test is performance is still good.
-    hashKey =
-        createLowLevelHasher {}
-        |> Hash.hash key
-        |> complete
+    hashKey = hashWith hasher key
     h1Key = h1 hashKey
     h2Key = h2 hashKey
     probe = newProbe h1Key (div8 (List.len metadata))
@@ -334,12 +399,13 @@ remove = \@Dict { metadata, dataIndices, data, size }, key ->
                     dataIndices,
                     data: List.dropLast data,
                     size: size - 1,
+                    hasher,
                 }
             else
-                swapAndUpdateDataIndex (@Dict { metadata, dataIndices, data, size }) index last
+                swapAndUpdateDataIndex (@Dict { metadata, dataIndices, data, size, hasher }) index last
 
         Err NotFound ->
-            @Dict { metadata, dataIndices, data, size }
+            @Dict { metadata, dataIndices, data, size, hasher }
 
 ## Insert or remove a value for a specified key. This function enables a
 ## performance optimisation for the use case of providing a default when a value
@@ -455,9 +521,11 @@ insertAll = \xs, ys ->
 ##     expect Dict.keepShared first second == first
 keepShared : Dict k v, Dict k v -> Dict k v | k has Hash & Eq
 keepShared = \xs, ys ->
+    (@Dict { hasher }) = xs
+
     walk
         xs
-        (empty {})
+        (withHasher hasher)
         (\state, k, v ->
             if contains ys k then
                 insert state k v
@@ -489,12 +557,9 @@ removeAll = \xs, ys ->
     walk ys xs (\state, k, _ -> remove state k)
 
 swapAndUpdateDataIndex : Dict k v, Nat, Nat -> Dict k v | k has Hash & Eq
-swapAndUpdateDataIndex = \@Dict { metadata, dataIndices, data, size }, removedIndex, lastIndex ->
+swapAndUpdateDataIndex = \@Dict { metadata, dataIndices, data, size, hasher }, removedIndex, lastIndex ->
     (T key _) = listGetUnsafe data lastIndex
-    hashKey =
-        createLowLevelHasher {}
-        |> Hash.hash key
-        |> complete
+    hashKey = hashWith hasher key
     h1Key = h1 hashKey
     h2Key = h2 hashKey
     probe = newProbe h1Key (div8 (List.len metadata))
@@ -515,6 +580,7 @@ swapAndUpdateDataIndex = \@Dict { metadata, dataIndices, data, size }, removedIn
                 dataIndices: List.set dataIndices index dataIndex,
                 data: nextData,
                 size: size - 1,
+                hasher,
             }
 
         Err NotFound ->
@@ -522,7 +588,7 @@ swapAndUpdateDataIndex = \@Dict { metadata, dataIndices, data, size }, removedIn
             crash "unreachable state in dict swapAndUpdateDataIndex hit. Definitely a standard library bug."
 
 insertNotFoundHelper : Dict k v, k, v, U64, I8 -> Dict k v
-insertNotFoundHelper = \@Dict { metadata, dataIndices, data, size }, key, value, h1Key, h2Key ->
+insertNotFoundHelper = \@Dict { metadata, dataIndices, data, size, hasher }, key, value, h1Key, h2Key ->
     probe = newProbe h1Key (div8 (List.len metadata))
     index = nextEmptyOrDeletedHelper metadata probe 0
     dataIndex = List.len data
@@ -533,6 +599,7 @@ insertNotFoundHelper = \@Dict { metadata, dataIndices, data, size }, key, value,
         dataIndices: List.set dataIndices index dataIndex,
         data: nextData,
         size,
+        hasher,
     }
 
 nextEmptyOrDeletedHelper : List I8, Probe, Nat -> Nat
@@ -585,20 +652,20 @@ findIndexHelper = \metadata, dataIndices, data, h2Key, key, probe, offset ->
 # If we aren't to the load factor yet, just ignore this.
 # The container must have an updated size including any elements about to be inserted.
 maybeRehash : Dict k v -> Dict k v | k has Hash & Eq
-maybeRehash = \@Dict { metadata, dataIndices, data, size } ->
+maybeRehash = \@Dict { metadata, dataIndices, data, size, hasher } ->
     cap = List.len dataIndices
     maxLoadCap =
         # This is 7/8 * capacity, which is the max load factor.
         cap - Num.shiftRightZfBy cap 3
 
     if size > maxLoadCap then
-        rehash (@Dict { metadata, dataIndices, data, size })
+        rehash (@Dict { metadata, dataIndices, data, size, hasher })
     else
-        @Dict { metadata, dataIndices, data, size }
+        @Dict { metadata, dataIndices, data, size, hasher }
 
 # TODO: switch rehash to iterate data and eventually clear out tombstones as well.
 rehash : Dict k v -> Dict k v | k has Hash & Eq
-rehash = \@Dict { metadata, dataIndices, data, size } ->
+rehash = \@Dict { metadata, dataIndices, data, size, hasher } ->
     newLen = 2 * List.len dataIndices
     newDict =
         @Dict {
@@ -606,6 +673,7 @@ rehash = \@Dict { metadata, dataIndices, data, size } ->
             dataIndices: List.repeat 0 newLen,
             data,
             size,
+            hasher,
         }
 
     rehashHelper newDict metadata dataIndices data 0
@@ -632,11 +700,8 @@ rehashHelper = \dict, oldMetadata, oldDataIndices, oldData, index ->
             dict
 
 insertForRehash : Dict k v, k, Nat -> Dict k v | k has Hash & Eq
-insertForRehash = \@Dict { metadata, dataIndices, data, size }, key, dataIndex ->
-    hashKey =
-        createLowLevelHasher {}
-        |> Hash.hash key
-        |> complete
+insertForRehash = \@Dict { metadata, dataIndices, data, size, hasher }, key, dataIndex ->
+    hashKey = hashWith hasher key
     h1Key = h1 hashKey
     h2Key = h2 hashKey
     probe = newProbe h1Key (div8 (List.len metadata))
@@ -647,6 +712,7 @@ insertForRehash = \@Dict { metadata, dataIndices, data, size }, key, dataIndex -
         dataIndices: List.set dataIndices index dataIndex,
         data,
         size,
+        hasher,
     }
 
 emptySlot : I8
@@ -828,6 +894,19 @@ expect
     |> get 7
     |> Bool.isEq (Ok "Testing")
 
+hashWith : DictHasher, k -> U64 | k has Hash & Eq
+hashWith = \hasher, key ->
+    when hasher is
+        Wyhash { seed } ->
+            createSeededHasher seed
+            |> Hash.hash key
+            |> complete
+
+        SipHash13 sipKeys ->
+            createSipHasher sipKeys
+            |> Hash.hash key
+            |> sipComplete
+
 # We have decided not to expose the standard roc hashing algorithm.
 # This is to avoid external dependence and the need for versioning.
 # The current implementation is a form of [Wyhash final3](https://github.com/wangyi-fudan/wyhash/blob/a5995b98ebfa7bd38bfadc0919326d2e7aabb805/wyhash.h).
@@ -851,8 +930,15 @@ LowLevelHasher := { originalSeed : U64, state : U64 } has [
 # TODO hide behind an InternalList.roc module
 listGetUnsafe : List a, Nat -> a
 
+defaultSeed : U64
+defaultSeed = 0x526F_6352_616E_643F
+
 createLowLevelHasher : { seed ?U64 } -> LowLevelHasher
-createLowLevelHasher = \{ seed ? 0x526F_6352_616E_643F } ->
+createLowLevelHasher = \{ seed ? defaultSeed } ->
+    createSeededHasher seed
+
+createSeededHasher : U64 -> LowLevelHasher
+createSeededHasher = \seed ->
     @LowLevelHasher { originalSeed: seed, state: seed }
 
 combineState : LowLevelHasher, { a : U64, b : U64, seed : U64, length : U64 } -> LowLevelHasher
@@ -1068,6 +1154,112 @@ wyr3 = \list, index, k ->
 
     Num.bitwiseOr a p3
 
+# A keyed hasher using the rounds of [SipHash-1-3](https://www.aumasson.jp/siphash/siphash.pdf).
+# Every value added to the hasher is compressed as one or more 64-bit message words:
+# integers are zero extended to a single word, while byte lists are split into
+# little endian words with the final word carrying the list length in its top byte.
+# This keeps the hasher streaming without buffering partial words between calls.
+SipHasher := SipState has [
+         Hasher {
+             addBytes: sipAddBytes,
+             addU8: sipAddU8,
+             addU16: sipAddU16,
+             addU32: sipAddU32,
+             addU64: sipAddU64,
+             addU128: sipAddU128,
+             complete: sipComplete,
+         },
+     ]
+
+SipState : { v0 : U64, v1 : U64, v2 : U64, v3 : U64, length : U64 }
+
+createSipHasher : { k0 : U64, k1 : U64 } -> SipHasher
+createSipHasher = \{ k0, k1 } ->
+    @SipHasher {
+        v0: Num.bitwiseXor k0 0x736F_6D65_7073_6575,
+        v1: Num.bitwiseXor k1 0x646F_7261_6E64_6F6D,
+        v2: Num.bitwiseXor k0 0x6C79_6765_6E65_7261,
+        v3: Num.bitwiseXor k1 0x7465_6462_7974_6573,
+        length: 0,
+    }
+
+sipRound : SipState -> SipState
+sipRound = \{ v0, v1, v2, v3, length } ->
+    a0 = Num.addWrap v0 v1
+    a1 = Num.rotateLeftBy v1 13 |> Num.bitwiseXor a0
+    b2 = Num.addWrap v2 v3
+    b3 = Num.rotateLeftBy v3 16 |> Num.bitwiseXor b2
+    c0 = Num.addWrap (Num.rotateLeftBy a0 32) b3
+    c3 = Num.rotateLeftBy b3 21 |> Num.bitwiseXor c0
+    c2 = Num.addWrap b2 a1
+    c1 = Num.rotateLeftBy a1 17 |> Num.bitwiseXor c2
+
+    { v0: c0, v1: c1, v2: Num.rotateLeftBy c2 32, v3: c3, length }
+
+# Compresses one message word, `bytes` long, into the state.
+sipAddWord : SipState, U64, U64 -> SipState
+sipAddWord = \state, word, bytes ->
+    mixed = sipRound { state & v3: Num.bitwiseXor state.v3 word }
+
+    { mixed & v0: Num.bitwiseXor mixed.v0 word, length: Num.addWrap state.length bytes }
+
+sipAddU8 = \@SipHasher state, u8 ->
+    @SipHasher (sipAddWord state (Num.toU64 u8) 1)
+
+sipAddU16 = \@SipHasher state, u16 ->
+    @SipHasher (sipAddWord state (Num.toU64 u16) 2)
+
+sipAddU32 = \@SipHasher state, u32 ->
+    @SipHasher (sipAddWord state (Num.toU64 u32) 4)
+
+sipAddU64 = \@SipHasher state, u64 ->
+    @SipHasher (sipAddWord state u64 8)
+
+sipAddU128 = \@SipHasher state, u128 ->
+    lower = u128 |> Num.toU64
+    upper = Num.shiftRightZfBy u128 64 |> Num.toU64
+
+    @SipHasher (sipAddWord (sipAddWord state lower 8) upper 8)
+
+sipAddBytes : SipHasher, List U8 -> SipHasher
+sipAddBytes = \@SipHasher state, list ->
+    @SipHasher (sipAddBytesHelper state list 0 (List.len list))
+
+sipAddBytesHelper : SipState, List U8, Nat, Nat -> SipState
+sipAddBytesHelper = \state, list, index, length ->
+    remaining = Num.subWrap length index
+
+    if remaining >= 8 then
+        next = sipAddWord state (wyr8 list index) 8
+
+        sipAddBytesHelper next list (Num.addWrap index 8) length
+    else
+        lengthByte = Num.shiftLeftBy (Num.toU64 length) 56
+        tail = sipReadTail list index remaining
+
+        sipAddWord state (Num.bitwiseOr lengthByte tail) (Num.toU64 remaining)
+
+# Reads the last `remaining` bytes (fewer than 8) as a little endian U64.
+sipReadTail : List U8, Nat, Nat -> U64
+sipReadTail = \list, index, remaining ->
+    if remaining == 0 then
+        0
+    else
+        last = Num.subWrap remaining 1
+        byte = listGetUnsafe list (Num.addWrap index last) |> Num.toU64
+
+        Num.shiftLeftBy byte (Num.toU8 (last * 8))
+        |> Num.bitwiseOr (sipReadTail list index last)
+
+sipComplete : SipHasher -> U64
+sipComplete = \@SipHasher state ->
+    final = Num.shiftLeftBy state.length 56
+    compressed = sipRound { state & v3: Num.bitwiseXor state.v3 final }
+    finalized = { compressed & v0: Num.bitwiseXor compressed.v0 final, v2: Num.bitwiseXor compressed.v2 0xFF }
+    { v0, v1, v2, v3 } = finalized |> sipRound |> sipRound |> sipRound
+
+    v0 |> Num.bitwiseXor v1 |> Num.bitwiseXor v2 |> Num.bitwiseXor v3
+
 # TODO: would be great to have table driven expects for this.
 # Would also be great to have some sort of property based hasher
 # where we can compare `addU*` functions to the `addBytes` function.
@@ -1242,3 +1434,69 @@ expect
         |> complete
 
     hash1 != hash2
+
+expect
+    hash =
+        createSipHasher { k0: 0x0706_0504_0302_0100, k1: 0x0F0E_0D0C_0B0A_0908 }
+        |> sipComplete
+
+    # With no message words this is exactly SipHash-1-3 of the empty message.
+    hash == 0xABAC_0158_050F_C4DC
+
+expect
+    hash =
+        createSipHasher { k0: 0x0706_0504_0302_0100, k1: 0x0F0E_0D0C_0B0A_0908 }
+        |> sipAddBytes [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14]
+        |> sipComplete
+
+    hash == 0xAAFF_F166_3673_4A73
+
+expect
+    hash =
+        createSipHasher { k0: 0x0706_0504_0302_0100, k1: 0x0F0E_0D0C_0B0A_0908 }
+        |> sipAddU8 0x42
+        |> sipComplete
+
+    hash == 0x3BBB_8D62_F77C_773E
+
+expect
+    hash1 =
+        createSipHasher { k0: 1, k1: 2 }
+        |> sipAddBytes [0x42]
+        |> sipComplete
+
+    hash2 =
+        createSipHasher { k0: 1, k1: 3 }
+        |> sipAddBytes [0x42]
+        |> sipComplete
+
+    hash1 != hash2
+
+expect
+    dict =
+        withHasher (SipHash13 { k0: 1, k1: 2 })
+        |> insert "foo" 1
+        |> insert "bar" 2
+        |> remove "foo"
+        |> clear
+        |> insert "baz" 3
+
+    (@Dict { hasher }) = dict
+
+    get dict "baz" == Ok 3 && hasher == SipHash13 { k0: 1, k1: 2 }
+
+expect
+    sipDict =
+        withHasher (SipHash13 { k0: 1, k1: 2 })
+        |> insert "foo" 1
+        |> insert "bar" 2
+
+    wyDict =
+        empty {}
+        |> insert "bar" 2
+        |> insert "foo" 1
+
+    sipDict == wyDict
+
+expect
+    single "foo" 1 != single "foo" 2 && single "foo" 1 != single "bar" 1
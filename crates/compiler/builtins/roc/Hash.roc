@@ -19,6 +19,7 @@ interface Hash
         hashStrBytes,
         hashList,
         hashUnordered,
+        randomSeed,
     ] imports [
         Bool.{ isEq },
         List,
@@ -128,3 +129,10 @@ hashUnordered = \hasher, container, walk ->
                 nextAccum
         )
     |> \accum -> addU64 hasher accum
+
+## Returns a seed for keyed hashers, such as the one behind `Dict.withRandomSeed`.
+##
+## The seed is random per process. It is requested from the operating system the first
+## time it is needed, and stays the same afterwards. There is no operating system to ask
+## on wasm32, so this crashes there.
+randomSeed : {} -> U64
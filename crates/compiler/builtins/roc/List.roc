@@ -64,11 +64,13 @@ interface List
         reserve,
         walkBackwardsUntil,
         countIf,
+        compare,
     ]
     imports [
         Bool.{ Bool, Eq },
         Result.{ Result },
         Num.{ Nat, Num, Int },
+        Ord.{ Ord },
     ]
 
 ## Types
@@ -757,19 +759,57 @@ expect
 ## Sort with a custom comparison function
 sortWith : List a, (a, a -> [LT, EQ, GT]) -> List a
 
-## Sorts a list in ascending order (lowest to highest), using a function which
-## specifies a way to represent each element as a number.
+## Sorts a list in ascending order (lowest to highest), using the [Ord](../Ord#Ord)
+## ability of its elements.
+##
+##     expect List.sortAsc [3, 1, 2] == [1, 2, 3]
+##
+## Records are ordered by their fields in alphabetical order of the field names,
+## and tag unions by the alphabetical order of their tags, then by their
+## payloads.
+##
+##     expect List.sortAsc [{ x: 2, y: 1 }, { x: 1, y: 5 }] == [{ x: 1, y: 5 }, { x: 2, y: 1 }]
 ##
 ## To sort in descending order (highest to lowest), use [List.sortDesc] instead.
-sortAsc : List (Num a) -> List (Num a)
-sortAsc = \list -> List.sortWith list Num.compare
+## [F32](../Num#F32) and [F64](../Num#F64) don't have [Ord](../Ord#Ord), so use [Num.sortAsc](../Num#sortAsc) for them.
+##
+## The compiler has to know the type of the elements to know how to order them,
+## so sorting a bare `[]` needs an annotation somewhere, like `empty : List I64`.
+sortAsc : List a -> List a | a has Ord
+sortAsc = \list -> List.sortWith list Ord.compare
 
-## Sorts a list in descending order (highest to lowest), using a function which
-## specifies a way to represent each element as a number.
+## Sorts a list in descending order (highest to lowest), using the [Ord](../Ord#Ord)
+## ability of its elements.
 ##
 ## To sort in ascending order (lowest to highest), use [List.sortAsc] instead.
-sortDesc : List (Num a) -> List (Num a)
-sortDesc = \list -> List.sortWith list (\a, b -> Num.compare b a)
+## [F32](../Num#F32) and [F64](../Num#F64) don't have [Ord](../Ord#Ord), so use [Num.sortDesc](../Num#sortDesc) for them.
+sortDesc : List a -> List a | a has Ord
+sortDesc = \list -> List.sortWith list (\a, b -> Ord.compare b a)
+
+## Compares two lists lexicographically. The first pair of elements that are
+## not equal decides the order; if one list runs out first, it is the smaller
+## one.
+##
+##     expect List.compare [1, 2, 3] [1, 3] == LT
+##     expect List.compare [1, 2] [1, 2, 0] == LT
+##     expect List.compare ["b"] ["a", "c"] == GT
+compare : List a, List a -> [LT, EQ, GT] | a has Ord
+compare = \a, b ->
+    compareHelp a b 0 (List.len a) (List.len b)
+
+compareHelp : List a, List a, Nat, Nat, Nat -> [LT, EQ, GT] | a has Ord
+compareHelp = \a, b, index, lenA, lenB ->
+    if index == lenA then
+        if index == lenB then
+            EQ
+        else
+            LT
+    else if index == lenB then
+        GT
+    else
+        when Ord.compare (List.getUnsafe a index) (List.getUnsafe b index) is
+            EQ -> compareHelp a b (index + 1) lenA lenB
+            order -> order
 
 swap : List a, Nat, Nat -> List a
 
@@ -66,6 +66,8 @@ interface Num
         ceiling,
         floor,
         compare,
+        sortAsc,
+        sortDesc,
         pow,
         powInt,
         addWrap,
@@ -77,6 +79,12 @@ interface Num
         shiftLeftBy,
         shiftRightBy,
         shiftRightZfBy,
+        countLeadingZeroBits,
+        countTrailingZeroBits,
+        countOneBits,
+        rotateLeftBy,
+        rotateRightBy,
+        swapBytes,
         subWrap,
         subChecked,
         subSaturated,
@@ -86,11 +94,50 @@ interface Num
         intCast,
         bytesToU16,
         bytesToU32,
+        bytesToU64,
+        bytesToU128,
+        u16ToBytes,
+        u32ToBytes,
+        u64ToBytes,
+        u128ToBytes,
+        readU8,
+        writeU8,
+        readU16Le,
+        readU16Be,
+        writeU16Le,
+        writeU16Be,
+        readU32Le,
+        readU32Be,
+        writeU32Le,
+        writeU32Be,
+        readU64Le,
+        readU64Be,
+        writeU64Le,
+        writeU64Be,
+        readU128Le,
+        readU128Be,
+        writeU128Le,
+        writeU128Be,
+        readF32Le,
+        readF32Be,
+        writeF32Le,
+        writeF32Be,
+        readF64Le,
+        readF64Be,
+        writeF64Le,
+        writeF64Be,
         divCeil,
         divCeilChecked,
         divTrunc,
         divTruncChecked,
         toStr,
+        toStrFixed,
+        toStrScientific,
+        toStrHex,
+        toStrOctal,
+        toStrBinary,
+        padZeros,
+        groupDigits,
         isMultipleOf,
         minI8,
         maxI8,
@@ -504,8 +551,83 @@ Dec : Num (FloatingPoint Decimal)
 ## When this function is given a non-[finite](Num.isFinite)
 ## [F64] or [F32] value, the returned string will be `"NaN"`, `"∞"`, or `"-∞"`.
 ##
-## To get strings in hexadecimal, octal, or binary format, use `Num.format`.
+## To get strings in hexadecimal, octal, or binary format, use [Num.toStrHex],
+## [Num.toStrOctal], or [Num.toStrBinary]. To control the number of decimal places,
+## use [Num.toStrFixed] or [Num.toStrScientific].
 toStr : Num * -> Str
+
+## Convert a [Frac] to a [Str] with exactly the given number of digits after the
+## decimal point. If the number has more digits than that, it is rounded, with
+## half-way cases rounded away from zero.
+##
+## >>> Num.toStrFixed 1234.5678dec 2
+##
+## >>> Num.toStrFixed 0.125dec 2
+##
+## >>> Num.toStrFixed 42.0dec 0
+##
+## For [F32] and [F64], rounding starts from the shortest decimal representation
+## which converts back to the same float, so `Num.toStrFixed 2.675f64 2` gives
+## `"2.68"` even though the closest [F64] to 2.675 is slightly below it.
+##
+## Like in [Num.toStr], non-[finite](Num.isFinite) [F64] and [F32] values become
+## `"NaN"`, `"∞"`, or `"-∞"`.
+toStrFixed : Frac *, Nat -> Str
+
+## Convert a [Frac] to a [Str] in scientific notation, with one digit before the
+## decimal point, the given number of digits after it, and an exponent of at
+## least two digits. Half-way cases are rounded away from zero, like in [Num.toStrFixed].
+##
+## >>> Num.toStrScientific 1234.5678dec 2
+##
+## >>> Num.toStrScientific 0.00042f64 1
+toStrScientific : Frac *, Nat -> Str
+
+## Convert an [Int] to a [Str] in hexadecimal, using lowercase letters and no prefix.
+## Negative numbers get a minus sign, rather than being shown in two's complement.
+##
+## >>> Num.toStrHex 255
+##
+## >>> Num.toStrHex -255i16
+toStrHex : Int * -> Str
+toStrHex = \num -> toStrRadix num 16
+
+## Convert an [Int] to a [Str] in octal, with no prefix.
+## Negative numbers get a minus sign, rather than being shown in two's complement.
+##
+## >>> Num.toStrOctal 8
+toStrOctal : Int * -> Str
+toStrOctal = \num -> toStrRadix num 8
+
+## Convert an [Int] to a [Str] in binary, with no prefix.
+## Negative numbers get a minus sign, rather than being shown in two's complement.
+##
+## >>> Num.toStrBinary 5u8
+toStrBinary : Int * -> Str
+toStrBinary = \num -> toStrRadix num 2
+
+toStrRadix : Int *, U8 -> Str
+
+## Pad a formatted number with zeros, so that it has at least the given number of
+## characters (not counting a leading sign). The zeros go after the sign.
+##
+## >>> Num.padZeros (Num.toStr 42) 5
+##
+## >>> Num.padZeros (Num.toStrHex -255i16) 4
+##
+## Strings which don't start with a digit, like `"nan"`, are returned unchanged.
+padZeros : Str, Nat -> Str
+
+## Insert a separator between every group of three digits before the decimal point
+## of a formatted number.
+##
+## >>> Num.groupDigits (Num.toStr 1234567) ","
+##
+## >>> Num.groupDigits (Num.toStrFixed -1234.5dec 2) " "
+##
+## Only decimal digits are grouped, so this is meant for the output of [Num.toStr]
+## and [Num.toStrFixed].
+groupDigits : Str, Str -> Str
 intCast : Int a -> Int b
 
 bytesToU16Lowlevel : List U8, Nat -> U16
@@ -531,8 +653,291 @@ bytesToU32 = \bytes, index ->
     else
         Err OutOfBounds
 
+## Read a [U64] from the 8 bytes starting at the given index, least significant byte first.
+## This is the same as [readU64Le].
+bytesToU64 : List U8, Nat -> Result U64 [OutOfBounds]
+bytesToU64 = \bytes, index -> readU64Le bytes index
+
+## Read a [U128] from the 16 bytes starting at the given index, least significant byte first.
+## This is the same as [readU128Le].
+bytesToU128 : List U8, Nat -> Result U128 [OutOfBounds]
+bytesToU128 = \bytes, index -> readU128Le bytes index
+
+## Split a [U16] into its bytes, least significant byte first.
+## This is the inverse of [bytesToU16], and the same as [writeU16Le] into 2 zeroes.
+##
+## >>> Num.u16ToBytes 0x1234 == [0x34, 0x12]
+u16ToBytes : U16 -> List U8
+u16ToBytes = \num -> writeLeUnsafe [0, 0] 0 num
+
+## Split a [U32] into its bytes, least significant byte first.
+## This is the inverse of [bytesToU32], and the same as [writeU32Le] into 4 zeroes.
+u32ToBytes : U32 -> List U8
+u32ToBytes = \num -> writeLeUnsafe [0, 0, 0, 0] 0 num
+
+## Split a [U64] into its bytes, least significant byte first.
+## This is the inverse of [bytesToU64], and the same as [writeU64Le] into 8 zeroes.
+u64ToBytes : U64 -> List U8
+u64ToBytes = \num -> writeLeUnsafe [0, 0, 0, 0, 0, 0, 0, 0] 0 num
+
+## Split a [U128] into its bytes, least significant byte first.
+## This is the inverse of [bytesToU128], and the same as [writeU128Le] into 16 zeroes.
+u128ToBytes : U128 -> List U8
+u128ToBytes = \num ->
+    writeLeUnsafe [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0] 0 num
+
+readLeUnsafe : List U8, Nat -> Num a
+readBeUnsafe : List U8, Nat -> Num a
+writeLeUnsafe : List U8, Nat, Num a -> List U8
+writeBeUnsafe : List U8, Nat, Num a -> List U8
+
+# whether `size` bytes starting at `index` are all within the list
+fitsAt : List U8, Nat, Nat -> Bool
+fitsAt = \bytes, index, size ->
+    len = List.len bytes
+
+    if index > len then
+        Bool.false
+    else
+        size <= len - index
+
+## Read the byte at the given index.
+##
+## >>> Num.readU8 [0x12, 0x34] 1 == Ok 0x34
+readU8 : List U8, Nat -> Result U8 [OutOfBounds]
+readU8 = \bytes, index ->
+    if fitsAt bytes index 1 then
+        Ok (readLeUnsafe bytes index)
+    else
+        Err OutOfBounds
+
+## Overwrite the byte at the given index.
+writeU8 : List U8, Nat, U8 -> Result (List U8) [OutOfBounds]
+writeU8 = \bytes, index, num ->
+    if fitsAt bytes index 1 then
+        Ok (writeLeUnsafe bytes index num)
+    else
+        Err OutOfBounds
+
+## Read a [U16] from the 2 bytes starting at the given index, least significant byte first.
+readU16Le : List U8, Nat -> Result U16 [OutOfBounds]
+readU16Le = \bytes, index ->
+    if fitsAt bytes index 2 then
+        Ok (readLeUnsafe bytes index)
+    else
+        Err OutOfBounds
+
+## Write a [U16] over the 2 bytes starting at the given index, least significant byte first.
+writeU16Le : List U8, Nat, U16 -> Result (List U8) [OutOfBounds]
+writeU16Le = \bytes, index, num ->
+    if fitsAt bytes index 2 then
+        Ok (writeLeUnsafe bytes index num)
+    else
+        Err OutOfBounds
+
+## Read a [U16] from the 2 bytes starting at the given index, most significant byte first.
+readU16Be : List U8, Nat -> Result U16 [OutOfBounds]
+readU16Be = \bytes, index ->
+    if fitsAt bytes index 2 then
+        Ok (readBeUnsafe bytes index)
+    else
+        Err OutOfBounds
+
+## Write a [U16] over the 2 bytes starting at the given index, most significant byte first.
+writeU16Be : List U8, Nat, U16 -> Result (List U8) [OutOfBounds]
+writeU16Be = \bytes, index, num ->
+    if fitsAt bytes index 2 then
+        Ok (writeBeUnsafe bytes index num)
+    else
+        Err OutOfBounds
+
+## Read a [U32] from the 4 bytes starting at the given index, least significant byte first.
+##
+## The bytes don't need to be aligned in any way.
+##
+## >>> Num.readU32Le [0xFF, 0x78, 0x56, 0x34, 0x12] 1 == Ok 0x12345678
+## >>> Num.readU32Le [0x78, 0x56, 0x34] 0 == Err OutOfBounds
+readU32Le : List U8, Nat -> Result U32 [OutOfBounds]
+readU32Le = \bytes, index ->
+    if fitsAt bytes index 4 then
+        Ok (readLeUnsafe bytes index)
+    else
+        Err OutOfBounds
+
+## Write a [U32] over the 4 bytes starting at the given index, least significant byte first.
+##
+## The list is updated in place when it is unique.
+##
+## >>> Num.writeU32Le [0, 0, 0, 0, 0] 1 0x12345678 == Ok [0, 0x78, 0x56, 0x34, 0x12]
+writeU32Le : List U8, Nat, U32 -> Result (List U8) [OutOfBounds]
+writeU32Le = \bytes, index, num ->
+    if fitsAt bytes index 4 then
+        Ok (writeLeUnsafe bytes index num)
+    else
+        Err OutOfBounds
+
+## Read a [U32] from the 4 bytes starting at the given index, most significant byte first.
+readU32Be : List U8, Nat -> Result U32 [OutOfBounds]
+readU32Be = \bytes, index ->
+    if fitsAt bytes index 4 then
+        Ok (readBeUnsafe bytes index)
+    else
+        Err OutOfBounds
+
+## Write a [U32] over the 4 bytes starting at the given index, most significant byte first.
+writeU32Be : List U8, Nat, U32 -> Result (List U8) [OutOfBounds]
+writeU32Be = \bytes, index, num ->
+    if fitsAt bytes index 4 then
+        Ok (writeBeUnsafe bytes index num)
+    else
+        Err OutOfBounds
+
+## Read a [U64] from the 8 bytes starting at the given index, least significant byte first.
+readU64Le : List U8, Nat -> Result U64 [OutOfBounds]
+readU64Le = \bytes, index ->
+    if fitsAt bytes index 8 then
+        Ok (readLeUnsafe bytes index)
+    else
+        Err OutOfBounds
+
+## Write a [U64] over the 8 bytes starting at the given index, least significant byte first.
+writeU64Le : List U8, Nat, U64 -> Result (List U8) [OutOfBounds]
+writeU64Le = \bytes, index, num ->
+    if fitsAt bytes index 8 then
+        Ok (writeLeUnsafe bytes index num)
+    else
+        Err OutOfBounds
+
+## Read a [U64] from the 8 bytes starting at the given index, most significant byte first.
+readU64Be : List U8, Nat -> Result U64 [OutOfBounds]
+readU64Be = \bytes, index ->
+    if fitsAt bytes index 8 then
+        Ok (readBeUnsafe bytes index)
+    else
+        Err OutOfBounds
+
+## Write a [U64] over the 8 bytes starting at the given index, most significant byte first.
+writeU64Be : List U8, Nat, U64 -> Result (List U8) [OutOfBounds]
+writeU64Be = \bytes, index, num ->
+    if fitsAt bytes index 8 then
+        Ok (writeBeUnsafe bytes index num)
+    else
+        Err OutOfBounds
+
+## Read a [U128] from the 16 bytes starting at the given index, least significant byte first.
+readU128Le : List U8, Nat -> Result U128 [OutOfBounds]
+readU128Le = \bytes, index ->
+    if fitsAt bytes index 16 then
+        Ok (readLeUnsafe bytes index)
+    else
+        Err OutOfBounds
+
+## Write a [U128] over the 16 bytes starting at the given index, least significant byte first.
+writeU128Le : List U8, Nat, U128 -> Result (List U8) [OutOfBounds]
+writeU128Le = \bytes, index, num ->
+    if fitsAt bytes index 16 then
+        Ok (writeLeUnsafe bytes index num)
+    else
+        Err OutOfBounds
+
+## Read a [U128] from the 16 bytes starting at the given index, most significant byte first.
+readU128Be : List U8, Nat -> Result U128 [OutOfBounds]
+readU128Be = \bytes, index ->
+    if fitsAt bytes index 16 then
+        Ok (readBeUnsafe bytes index)
+    else
+        Err OutOfBounds
+
+## Write a [U128] over the 16 bytes starting at the given index, most significant byte first.
+writeU128Be : List U8, Nat, U128 -> Result (List U8) [OutOfBounds]
+writeU128Be = \bytes, index, num ->
+    if fitsAt bytes index 16 then
+        Ok (writeBeUnsafe bytes index num)
+    else
+        Err OutOfBounds
+
+## Read a [F32] from the 4 bytes starting at the given index, least significant byte first.
+readF32Le : List U8, Nat -> Result F32 [OutOfBounds]
+readF32Le = \bytes, index ->
+    if fitsAt bytes index 4 then
+        Ok (readLeUnsafe bytes index)
+    else
+        Err OutOfBounds
+
+## Write a [F32] over the 4 bytes starting at the given index, least significant byte first.
+writeF32Le : List U8, Nat, F32 -> Result (List U8) [OutOfBounds]
+writeF32Le = \bytes, index, num ->
+    if fitsAt bytes index 4 then
+        Ok (writeLeUnsafe bytes index num)
+    else
+        Err OutOfBounds
+
+## Read a [F32] from the 4 bytes starting at the given index, most significant byte first.
+readF32Be : List U8, Nat -> Result F32 [OutOfBounds]
+readF32Be = \bytes, index ->
+    if fitsAt bytes index 4 then
+        Ok (readBeUnsafe bytes index)
+    else
+        Err OutOfBounds
+
+## Write a [F32] over the 4 bytes starting at the given index, most significant byte first.
+writeF32Be : List U8, Nat, F32 -> Result (List U8) [OutOfBounds]
+writeF32Be = \bytes, index, num ->
+    if fitsAt bytes index 4 then
+        Ok (writeBeUnsafe bytes index num)
+    else
+        Err OutOfBounds
+
+## Read a [F64] from the 8 bytes starting at the given index, least significant byte first.
+readF64Le : List U8, Nat -> Result F64 [OutOfBounds]
+readF64Le = \bytes, index ->
+    if fitsAt bytes index 8 then
+        Ok (readLeUnsafe bytes index)
+    else
+        Err OutOfBounds
+
+## Write a [F64] over the 8 bytes starting at the given index, least significant byte first.
+writeF64Le : List U8, Nat, F64 -> Result (List U8) [OutOfBounds]
+writeF64Le = \bytes, index, num ->
+    if fitsAt bytes index 8 then
+        Ok (writeLeUnsafe bytes index num)
+    else
+        Err OutOfBounds
+
+## Read a [F64] from the 8 bytes starting at the given index, most significant byte first.
+readF64Be : List U8, Nat -> Result F64 [OutOfBounds]
+readF64Be = \bytes, index ->
+    if fitsAt bytes index 8 then
+        Ok (readBeUnsafe bytes index)
+    else
+        Err OutOfBounds
+
+## Write a [F64] over the 8 bytes starting at the given index, most significant byte first.
+writeF64Be : List U8, Nat, F64 -> Result (List U8) [OutOfBounds]
+writeF64Be = \bytes, index, num ->
+    if fitsAt bytes index 8 then
+        Ok (writeBeUnsafe bytes index num)
+    else
+        Err OutOfBounds
+
 compare : Num a, Num a -> [LT, EQ, GT]
 
+## Sorts a list of numbers in ascending order (lowest to highest).
+##
+## Unlike [List.sortAsc](../List#sortAsc), this also works for [F32] and [F64],
+## which don't have [Ord](../Ord#Ord). Where [*NaN*](Num.isNaN) ends up is unspecified.
+##
+## >>> Num.sortAsc [2.5, 1.0, 3.0]
+sortAsc : List (Num a) -> List (Num a)
+sortAsc = \list -> List.sortWith list compare
+
+## Sorts a list of numbers in descending order (highest to lowest).
+##
+## Unlike [List.sortDesc](../List#sortDesc), this also works for [F32] and [F64],
+## which don't have [Ord](../Ord#Ord). Where [*NaN*](Num.isNaN) ends up is unspecified.
+sortDesc : List (Num a) -> List (Num a)
+sortDesc = \list -> List.sortWith list (\a, b -> compare b a)
+
 ## Returns `Bool.true` if the first number is less than the second.
 ##
 ## `a < b` is shorthand for `Num.isLt a b`.
@@ -895,6 +1300,48 @@ shiftRightBy : Int a, U8 -> Int a
 ## In some languages `shiftRightBy` is implemented as a binary operator `>>`.
 shiftRightZfBy : Int a, U8 -> Int a
 
+## Counts the number of most-significant (leading in a big-Endian sense) zeroes in an integer.
+##
+## >>> Num.countLeadingZeroBits 0b0001_1100u8 == 3
+##
+## >>> Num.countLeadingZeroBits 0b0000_0000u8 == 8
+countLeadingZeroBits : Int a -> U8
+
+## Counts the number of least-significant (trailing in a big-Endian sense) zeroes in an integer.
+##
+## >>> Num.countTrailingZeroBits 0b0001_1100u8 == 2
+##
+## >>> Num.countTrailingZeroBits 0b0000_0000u8 == 8
+countTrailingZeroBits : Int a -> U8
+
+## Counts the number of set bits in an integer.
+##
+## >>> Num.countOneBits 0b0001_1100u8 == 3
+countOneBits : Int a -> U8
+
+## Bitwise rotation of a number to the left.
+##
+## The bits that are shifted out on the left come back in on the right. The rotation
+## amount wraps around the bit width, so rotating a [U8] by 9 is the same as rotating it by 1.
+##
+## >>> rotateLeftBy 0b1000_0011u8 2 == 0b0000_1110
+rotateLeftBy : Int a, U8 -> Int a
+
+## Bitwise rotation of a number to the right.
+##
+## The bits that are shifted out on the right come back in on the left. The rotation
+## amount wraps around the bit width, so rotating a [U8] by 9 is the same as rotating it by 1.
+##
+## >>> rotateRightBy 0b1000_0011u8 2 == 0b1110_0000
+rotateRightBy : Int a, U8 -> Int a
+
+## Reverses the order of the bytes in an integer.
+##
+## Use this to convert between little-endian and big-endian representations.
+##
+## >>> swapBytes 0x1234u16 == 0x3412
+swapBytes : Int a -> Int a
+
 ## Round off the given fraction to the nearest integer.
 round : Frac * -> Int *
 floor : Frac * -> Int *
@@ -0,0 +1,32 @@
+interface Ord
+    exposes [Ord, compare]
+    imports []
+
+## Defines a type whose values can be put in a total order.
+##
+## A total order means that any two values `a`, `b` of the type can be
+## compared, and that `compare a b` is `EQ` if and only if `a` and `b` are
+## the same value.
+##
+## [Ord] is what keeps the keys of an [OrdDict](../OrdDict#OrdDict) and the
+## elements of an [OrdSet](../OrdSet#OrdSet) sorted.
+Ord has
+    ## Returns `LT` if the first value comes before the second, `GT` if it comes
+    ## after, and `EQ` if they are the same.
+    ##
+    ## **Note** that when `compare` is determined by the Roc compiler, values are
+    ## ordered as follows:
+    ##
+    ## 1. Integers and [Dec](../Num#Dec) are ordered by their numeric value.
+    ## 2. [Str] values are ordered by their UTF-8 bytes, which is the same as
+    ## ordering them by their Unicode scalar values.
+    ## 3. [List] values are ordered lexicographically: the first elements that
+    ## differ decide, and a list that is a prefix of another comes first.
+    ## 4. Records are ordered lexicographically by their fields, in alphabetical
+    ## order of the field names: `{ a: 1, b: 9 }` comes before `{ a: 2, b: 0 }`.
+    ## 5. Tag unions are ordered by their tags, in alphabetical order of the tag
+    ## names, and values with the same tag are ordered lexicographically by
+    ## their payloads: `A 9` comes before `B 0`, which comes before `B 1`.
+    ## 6. [F32](../Num#F32) and [F64](../Num#F64) do not have an order, because
+    ## `NaN` is not comparable to anything, including itself.
+    compare : a, a -> [LT, EQ, GT] | a has Ord
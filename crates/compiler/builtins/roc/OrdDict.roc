@@ -0,0 +1,717 @@
+interface OrdDict
+    exposes [
+        OrdDict,
+        empty,
+        single,
+        len,
+        get,
+        contains,
+        insert,
+        remove,
+        update,
+        walk,
+        walkUntil,
+        walkFrom,
+        walkFromUntil,
+        toList,
+        fromList,
+        keys,
+        values,
+        insertAll,
+        keepShared,
+        removeAll,
+        min,
+        max,
+        floor,
+        ceiling,
+        range,
+    ]
+    imports [
+        Bool.{ Bool },
+        Result.{ Result },
+        List,
+        Num.{ Nat },
+        Ord.{ Ord },
+    ]
+
+## A [dictionary](https://en.wikipedia.org/wiki/Associative_array) that keeps
+## its keys in sorted order.
+##
+## An [OrdDict] is used like a [Dict](../Dict#Dict), but instead of hashing its
+## keys it orders them with the [Ord](../Ord#Ord) ability. In exchange for
+## lookups, insertions and removals taking logarithmic rather than constant
+## time, it can answer questions about the order of its keys:
+##
+##     meetingsByHour =
+##         OrdDict.empty {}
+##         |> OrdDict.insert 14 "Design review"
+##         |> OrdDict.insert 9 "Standup"
+##         |> OrdDict.insert 12 "Lunch"
+##         |> OrdDict.insert 16 "Retro"
+##
+##     expect OrdDict.keys meetingsByHour == [9, 12, 14, 16]
+##     expect OrdDict.min meetingsByHour == Ok (T 9 "Standup")
+##     expect OrdDict.floor meetingsByHour 13 == Ok (T 12 "Lunch")
+##     expect
+##         OrdDict.range meetingsByHour { start: At 12, end: Before 16 }
+##         |> OrdDict.values
+##         |> Bool.isEq ["Lunch", "Design review"]
+##
+## [OrdDict.walk], [OrdDict.toList], [OrdDict.keys] and [OrdDict.values] all
+## visit the entries from the smallest key to the largest.
+##
+## The dictionary is a persistent
+## [weight-balanced tree](https://en.wikipedia.org/wiki/Weight-balanced_tree):
+## updating it rebuilds only the path from the root to the changed entry, and
+## shares the rest of the tree with the dictionary it was made from.
+OrdDict k v := Tree k v | k has Ord
+
+# Every node stores the number of entries in its subtree. That makes [len]
+# constant-time, and is the weight the tree is balanced by: neither subtree of
+# a node may hold more than `delta` times the entries of the other.
+Tree k v : [Empty, Node Nat k v (Tree k v) (Tree k v)]
+
+T k v : [T k v]
+
+## Return an empty dictionary.
+empty : {} -> OrdDict k v | k has Ord
+empty = \{} -> @OrdDict Empty
+
+## Returns a dictionary containing the key and value provided as input.
+##
+##     expect
+##         OrdDict.single "A" "B"
+##         |> OrdDict.toList
+##         |> Bool.isEq [T "A" "B"]
+single : k, v -> OrdDict k v | k has Ord
+single = \key, value ->
+    @OrdDict (Node 1 key value Empty Empty)
+
+## Returns an [OrdDict] containing the key-value pairs of the list. If a key
+## appears more than once, the last value for it is kept.
+fromList : List (T k v) -> OrdDict k v | k has Ord
+fromList = \data ->
+    List.walk data (empty {}) (\dict, T k v -> insert dict k v)
+
+## Returns the number of values in the dictionary.
+##
+##     expect
+##         OrdDict.empty {}
+##         |> OrdDict.insert "One" "A Song"
+##         |> OrdDict.insert "Two" "Candy Canes"
+##         |> OrdDict.insert "Three" "Boughs of Holly"
+##         |> OrdDict.len
+##         |> Bool.isEq 3
+len : OrdDict k v -> Nat | k has Ord
+len = \@OrdDict tree ->
+    size tree
+
+## Iterate through the keys and values in the dictionary, from the smallest
+## key to the largest, and call the provided function with signature
+## `state, k, v -> state` for each value, with an initial `state` value
+## provided for the first call.
+##
+##     expect
+##         OrdDict.empty {}
+##         |> OrdDict.insert "Apples" 12
+##         |> OrdDict.insert "Orange" 24
+##         |> OrdDict.walk 0 (\count, _, qty -> count + qty)
+##         |> Bool.isEq 36
+walk : OrdDict k v, state, (state, k, v -> state) -> state | k has Ord
+walk = \@OrdDict tree, initialState, transform ->
+    walkHelp tree initialState transform
+
+## Same as [OrdDict.walk], except you can stop walking early by returning
+## `Break`.
+walkUntil : OrdDict k v, state, (state, k, v -> [Continue state, Break state]) -> state | k has Ord
+walkUntil = \@OrdDict tree, initialState, transform ->
+    when walkUntilHelp tree initialState transform is
+        Continue state -> state
+        Break state -> state
+
+## Same as [OrdDict.walk], except it starts at the first key that is greater
+## than or equal to the given key. Entries with smaller keys are skipped
+## without being visited.
+##
+##     expect
+##         OrdDict.fromList [T 1 "a", T 3 "b", T 5 "c"]
+##         |> OrdDict.walkFrom 2 [] (\state, k, _ -> List.append state k)
+##         |> Bool.isEq [3, 5]
+walkFrom : OrdDict k v, k, state, (state, k, v -> state) -> state | k has Ord
+walkFrom = \@OrdDict tree, from, initialState, transform ->
+    walkFromHelp tree from initialState transform
+
+## A combination of [OrdDict.walkFrom] and [OrdDict.walkUntil].
+walkFromUntil : OrdDict k v, k, state, (state, k, v -> [Continue state, Break state]) -> state | k has Ord
+walkFromUntil = \@OrdDict tree, from, initialState, transform ->
+    when walkFromUntilHelp tree from initialState transform is
+        Continue state -> state
+        Break state -> state
+
+## Get the value for a given key. If there is a value for the specified key it
+## will return [Ok value], otherwise return [Err KeyNotFound].
+##
+##     dictionary =
+##         OrdDict.empty {}
+##         |> OrdDict.insert 1 "Apple"
+##         |> OrdDict.insert 2 "Orange"
+##
+##     expect OrdDict.get dictionary 1 == Ok "Apple"
+##     expect OrdDict.get dictionary 2000 == Err KeyNotFound
+get : OrdDict k v, k -> Result v [KeyNotFound] | k has Ord
+get = \@OrdDict tree, key ->
+    getHelp tree key
+
+## Check if the dictionary has a value for a specified key.
+##
+##     expect
+##         OrdDict.empty {}
+##         |> OrdDict.insert 1234 "5678"
+##         |> OrdDict.contains 1234
+##         |> Bool.isEq Bool.true
+contains : OrdDict k v, k -> Bool | k has Ord
+contains = \dict, key ->
+    get dict key
+    |> Result.isOk
+
+## Insert a value into the dictionary at a specified key. If the key is
+## already present, its value is replaced.
+##
+##     expect
+##         OrdDict.empty {}
+##         |> OrdDict.insert "Apples" 12
+##         |> OrdDict.get "Apples"
+##         |> Bool.isEq (Ok 12)
+insert : OrdDict k v, k, v -> OrdDict k v | k has Ord
+insert = \@OrdDict tree, key, value ->
+    @OrdDict (insertHelp tree key value)
+
+## Remove a value from the dictionary for a specified key.
+##
+##     expect
+##         OrdDict.empty {}
+##         |> OrdDict.insert "Some" "Value"
+##         |> OrdDict.remove "Some"
+##         |> OrdDict.len
+##         |> Bool.isEq 0
+remove : OrdDict k v, k -> OrdDict k v | k has Ord
+remove = \@OrdDict tree, key ->
+    @OrdDict (removeHelp tree key)
+
+## Insert or remove a value for a specified key, like [Dict.update](../Dict#update).
+##
+##     alterValue : [Present Bool, Missing] -> [Present Bool, Missing]
+##     alterValue = \possibleValue ->
+##         when possibleValue is
+##             Missing -> Present Bool.false
+##             Present value -> if value then Missing else Present Bool.true
+##
+##     expect OrdDict.get (OrdDict.update (OrdDict.empty {}) "a" alterValue) "a" == Ok Bool.false
+##     expect OrdDict.get (OrdDict.update (OrdDict.single "a" Bool.false) "a" alterValue) "a" == Ok Bool.true
+##     expect OrdDict.len (OrdDict.update (OrdDict.single "a" Bool.true) "a" alterValue) == 0
+update : OrdDict k v, k, ([Present v, Missing] -> [Present v, Missing]) -> OrdDict k v | k has Ord
+update = \dict, key, alter ->
+    possibleValue =
+        get dict key
+        |> Result.map Present
+        |> Result.withDefault Missing
+
+    when alter possibleValue is
+        Present value -> insert dict key value
+        Missing -> remove dict key
+
+## Returns the keys and values of a dictionary as a [List], sorted by key.
+##
+##     expect
+##         OrdDict.single 3 "Three"
+##         |> OrdDict.insert 1 "One"
+##         |> OrdDict.insert 2 "Two"
+##         |> OrdDict.toList
+##         |> Bool.isEq [T 1 "One", T 2 "Two", T 3 "Three"]
+toList : OrdDict k v -> List (T k v) | k has Ord
+toList = \@OrdDict tree ->
+    walkHelp tree (List.withCapacity (size tree)) (\state, k, v -> List.append state (T k v))
+
+## Returns the keys of a dictionary as a sorted [List].
+##
+##     expect
+##         OrdDict.single 3 "Three"
+##         |> OrdDict.insert 1 "One"
+##         |> OrdDict.insert 2 "Two"
+##         |> OrdDict.keys
+##         |> Bool.isEq [1, 2, 3]
+keys : OrdDict k v -> List k | k has Ord
+keys = \@OrdDict tree ->
+    walkHelp tree (List.withCapacity (size tree)) (\state, k, _ -> List.append state k)
+
+## Returns the values of a dictionary as a [List], in the order of their keys.
+##
+##     expect
+##         OrdDict.single 3 "Three"
+##         |> OrdDict.insert 1 "One"
+##         |> OrdDict.insert 2 "Two"
+##         |> OrdDict.values
+##         |> Bool.isEq ["One", "Two", "Three"]
+values : OrdDict k v -> List v | k has Ord
+values = \@OrdDict tree ->
+    walkHelp tree (List.withCapacity (size tree)) (\state, _, v -> List.append state v)
+
+## Combine two dictionaries by keeping the [union](https://en.wikipedia.org/wiki/Union_(set_theory))
+## of all the key-value pairs. Where both dictionaries have the same key, the
+## value from the second input is kept.
+insertAll : OrdDict k v, OrdDict k v -> OrdDict k v | k has Ord
+insertAll = \xs, ys ->
+    walk ys xs insert
+
+## Combine two dictionaries by keeping the [intersection](https://en.wikipedia.org/wiki/Intersection_(set_theory))
+## of all the key-value pairs. Where both dictionaries have the same key, the
+## value from the first input is kept.
+keepShared : OrdDict k v, OrdDict k v -> OrdDict k v | k has Ord
+keepShared = \xs, ys ->
+    walk
+        xs
+        []
+        (\state, k, v ->
+            if contains ys k then
+                List.append state (T k v)
+            else
+                state
+        )
+    |> fromSortedList
+
+## Remove the key-value pairs in the first input whose keys are also in the
+## second, leaving the [set difference](https://en.wikipedia.org/wiki/Complement_(set_theory)#Relative_complement)
+## of the two dictionaries.
+removeAll : OrdDict k v, OrdDict k v -> OrdDict k v | k has Ord
+removeAll = \xs, ys ->
+    walk ys xs (\state, k, _ -> remove state k)
+
+## Returns the entry with the smallest key, or [Err OrdDictWasEmpty] if the
+## dictionary is empty.
+##
+##     expect
+##         OrdDict.fromList [T "b" 2, T "a" 1, T "c" 3]
+##         |> OrdDict.min
+##         |> Bool.isEq (Ok (T "a" 1))
+min : OrdDict k v -> Result (T k v) [OrdDictWasEmpty] | k has Ord
+min = \@OrdDict tree ->
+    when tree is
+        Empty -> Err OrdDictWasEmpty
+        Node _ k v left _ -> Ok (minHelp k v left)
+
+## Returns the entry with the largest key, or [Err OrdDictWasEmpty] if the
+## dictionary is empty.
+##
+##     expect
+##         OrdDict.fromList [T "b" 2, T "a" 1, T "c" 3]
+##         |> OrdDict.max
+##         |> Bool.isEq (Ok (T "c" 3))
+max : OrdDict k v -> Result (T k v) [OrdDictWasEmpty] | k has Ord
+max = \@OrdDict tree ->
+    when tree is
+        Empty -> Err OrdDictWasEmpty
+        Node _ k v _ right -> Ok (maxHelp k v right)
+
+## Returns the entry with the largest key that is less than or equal to the
+## given key, or [Err KeyNotFound] if every key is larger.
+##
+##     dictionary = OrdDict.fromList [T 10 "ten", T 20 "twenty"]
+##
+##     expect OrdDict.floor dictionary 15 == Ok (T 10 "ten")
+##     expect OrdDict.floor dictionary 20 == Ok (T 20 "twenty")
+##     expect OrdDict.floor dictionary 5 == Err KeyNotFound
+floor : OrdDict k v, k -> Result (T k v) [KeyNotFound] | k has Ord
+floor = \@OrdDict tree, key ->
+    floorHelp tree key (Err KeyNotFound)
+
+## Returns the entry with the smallest key that is greater than or equal to the
+## given key, or [Err KeyNotFound] if every key is smaller.
+##
+##     dictionary = OrdDict.fromList [T 10 "ten", T 20 "twenty"]
+##
+##     expect OrdDict.ceiling dictionary 15 == Ok (T 20 "twenty")
+##     expect OrdDict.ceiling dictionary 10 == Ok (T 10 "ten")
+##     expect OrdDict.ceiling dictionary 25 == Err KeyNotFound
+ceiling : OrdDict k v, k -> Result (T k v) [KeyNotFound] | k has Ord
+ceiling = \@OrdDict tree, key ->
+    ceilingHelp tree key (Err KeyNotFound)
+
+## Returns a dictionary of the entries whose keys lie between `start` and
+## `end`. Like [List.range](../List#range), the start can be inclusive (`At`) or
+## exclusive (`After`), and the end can be inclusive (`At`) or exclusive
+## (`Before`). Either side can also be left `Unbounded`.
+##
+##     dictionary = OrdDict.fromList [T 1 "a", T 2 "b", T 3 "c", T 4 "d"]
+##
+##     expect
+##         OrdDict.range dictionary { start: At 2, end: At 3 }
+##         |> OrdDict.keys
+##         |> Bool.isEq [2, 3]
+##
+##     expect
+##         OrdDict.range dictionary { start: After 2, end: Unbounded }
+##         |> OrdDict.keys
+##         |> Bool.isEq [3, 4]
+range : OrdDict k v, { start : [At k, After k, Unbounded], end : [At k, Before k, Unbounded] } -> OrdDict k v | k has Ord
+range = \@OrdDict tree, { start, end } ->
+    rangeHelp tree start end []
+    |> fromSortedList
+
+# Tree operations
+
+size : Tree k v -> Nat
+size = \tree ->
+    when tree is
+        Empty -> 0
+        Node s _ _ _ _ -> s
+
+node : k, v, Tree k v, Tree k v -> Tree k v
+node = \k, v, left, right ->
+    Node (size left + size right + 1) k v left right
+
+delta : Nat
+delta = 3
+
+ratio : Nat
+ratio = 2
+
+# Builds a node whose subtrees were balanced with each other before one of them
+# gained or lost a single entry, rotating entries across if that tipped the
+# balance.
+balance : k, v, Tree k v, Tree k v -> Tree k v
+balance = \k, v, left, right ->
+    sizeLeft = size left
+    sizeRight = size right
+
+    if sizeLeft + sizeRight <= 1 then
+        node k v left right
+    else if sizeRight > delta * sizeLeft then
+        rotateLeft k v left right
+    else if sizeLeft > delta * sizeRight then
+        rotateRight k v left right
+    else
+        node k v left right
+
+rotateLeft : k, v, Tree k v, Tree k v -> Tree k v
+rotateLeft = \k, v, left, right ->
+    when right is
+        Node _ rk rv rl rr ->
+            if size rl < ratio * size rr then
+                node rk rv (node k v left rl) rr
+            else
+                when rl is
+                    Node _ rlk rlv rll rlr ->
+                        node rlk rlv (node k v left rll) (node rk rv rlr rr)
+
+                    Empty ->
+                        node k v left right
+
+        Empty ->
+            node k v left right
+
+rotateRight : k, v, Tree k v, Tree k v -> Tree k v
+rotateRight = \k, v, left, right ->
+    when left is
+        Node _ lk lv ll lr ->
+            if size lr < ratio * size ll then
+                node lk lv ll (node k v lr right)
+            else
+                when lr is
+                    Node _ lrk lrv lrl lrr ->
+                        node lrk lrv (node lk lv ll lrl) (node k v lrr right)
+
+                    Empty ->
+                        node k v left right
+
+        Empty ->
+            node k v left right
+
+getHelp : Tree k v, k -> Result v [KeyNotFound] | k has Ord
+getHelp = \tree, key ->
+    when tree is
+        Empty ->
+            Err KeyNotFound
+
+        Node _ k v left right ->
+            when Ord.compare key k is
+                LT -> getHelp left key
+                GT -> getHelp right key
+                EQ -> Ok v
+
+insertHelp : Tree k v, k, v -> Tree k v | k has Ord
+insertHelp = \tree, key, value ->
+    when tree is
+        Empty ->
+            Node 1 key value Empty Empty
+
+        Node s k v left right ->
+            when Ord.compare key k is
+                LT -> balance k v (insertHelp left key value) right
+                GT -> balance k v left (insertHelp right key value)
+                EQ -> Node s key value left right
+
+removeHelp : Tree k v, k -> Tree k v | k has Ord
+removeHelp = \tree, key ->
+    when tree is
+        Empty ->
+            Empty
+
+        Node _ k v left right ->
+            when Ord.compare key k is
+                LT -> balance k v (removeHelp left key) right
+                GT -> balance k v left (removeHelp right key)
+                EQ -> glue left right
+
+# Joins the two subtrees of a removed node, by moving the entry closest to the
+# removed key up from the larger of the two.
+glue : Tree k v, Tree k v -> Tree k v
+glue = \left, right ->
+    when left is
+        Empty ->
+            right
+
+        Node sizeLeft lk lv ll lr ->
+            when right is
+                Empty ->
+                    left
+
+                Node sizeRight rk rv rl rr ->
+                    if sizeLeft > sizeRight then
+                        { key, value, rest } = removeMax lk lv ll lr
+
+                        balance key value rest right
+                    else
+                        { key, value, rest } = removeMin rk rv rl rr
+
+                        balance key value left rest
+
+removeMin : k, v, Tree k v, Tree k v -> { key : k, value : v, rest : Tree k v }
+removeMin = \k, v, left, right ->
+    when left is
+        Empty ->
+            { key: k, value: v, rest: right }
+
+        Node _ lk lv ll lr ->
+            { key, value, rest } = removeMin lk lv ll lr
+
+            { key, value, rest: balance k v rest right }
+
+removeMax : k, v, Tree k v, Tree k v -> { key : k, value : v, rest : Tree k v }
+removeMax = \k, v, left, right ->
+    when right is
+        Empty ->
+            { key: k, value: v, rest: left }
+
+        Node _ rk rv rl rr ->
+            { key, value, rest } = removeMax rk rv rl rr
+
+            { key, value, rest: balance k v left rest }
+
+minHelp : k, v, Tree k v -> T k v
+minHelp = \k, v, left ->
+    when left is
+        Empty -> T k v
+        Node _ lk lv ll _ -> minHelp lk lv ll
+
+maxHelp : k, v, Tree k v -> T k v
+maxHelp = \k, v, right ->
+    when right is
+        Empty -> T k v
+        Node _ rk rv _ rr -> maxHelp rk rv rr
+
+floorHelp : Tree k v, k, Result (T k v) [KeyNotFound] -> Result (T k v) [KeyNotFound] | k has Ord
+floorHelp = \tree, key, best ->
+    when tree is
+        Empty ->
+            best
+
+        Node _ k v left right ->
+            when Ord.compare key k is
+                LT -> floorHelp left key best
+                GT -> floorHelp right key (Ok (T k v))
+                EQ -> Ok (T k v)
+
+ceilingHelp : Tree k v, k, Result (T k v) [KeyNotFound] -> Result (T k v) [KeyNotFound] | k has Ord
+ceilingHelp = \tree, key, best ->
+    when tree is
+        Empty ->
+            best
+
+        Node _ k v left right ->
+            when Ord.compare key k is
+                LT -> ceilingHelp left key (Ok (T k v))
+                GT -> ceilingHelp right key best
+                EQ -> Ok (T k v)
+
+walkHelp : Tree k v, state, (state, k, v -> state) -> state
+walkHelp = \tree, state, transform ->
+    when tree is
+        Empty ->
+            state
+
+        Node _ k v left right ->
+            leftState = walkHelp left state transform
+
+            walkHelp right (transform leftState k v) transform
+
+walkUntilHelp : Tree k v, state, (state, k, v -> [Continue state, Break state]) -> [Continue state, Break state]
+walkUntilHelp = \tree, state, transform ->
+    when tree is
+        Empty ->
+            Continue state
+
+        Node _ k v left right ->
+            when walkUntilHelp left state transform is
+                Continue leftState ->
+                    when transform leftState k v is
+                        Continue nodeState -> walkUntilHelp right nodeState transform
+                        Break nodeState -> Break nodeState
+
+                Break leftState ->
+                    Break leftState
+
+walkFromHelp : Tree k v, k, state, (state, k, v -> state) -> state | k has Ord
+walkFromHelp = \tree, from, state, transform ->
+    when tree is
+        Empty ->
+            state
+
+        Node _ k v left right ->
+            when Ord.compare k from is
+                LT ->
+                    walkFromHelp right from state transform
+
+                _ ->
+                    leftState = walkFromHelp left from state transform
+
+                    walkHelp right (transform leftState k v) transform
+
+walkFromUntilHelp : Tree k v, k, state, (state, k, v -> [Continue state, Break state]) -> [Continue state, Break state] | k has Ord
+walkFromUntilHelp = \tree, from, state, transform ->
+    when tree is
+        Empty ->
+            Continue state
+
+        Node _ k v left right ->
+            when Ord.compare k from is
+                LT ->
+                    walkFromUntilHelp right from state transform
+
+                _ ->
+                    when walkFromUntilHelp left from state transform is
+                        Continue leftState ->
+                            when transform leftState k v is
+                                Continue nodeState -> walkUntilHelp right nodeState transform
+                                Break nodeState -> Break nodeState
+
+                        Break leftState ->
+                            Break leftState
+
+# Appends the entries of the tree that lie within the bounds to `entries`, in
+# order. Subtrees that lie entirely outside of the bounds are not visited.
+rangeHelp : Tree k v, [At k, After k, Unbounded], [At k, Before k, Unbounded], List (T k v) -> List (T k v) | k has Ord
+rangeHelp = \tree, start, end, entries ->
+    when tree is
+        Empty ->
+            entries
+
+        Node _ k v left right ->
+            afterStart = isAfterStart k start
+            beforeEnd = isBeforeEnd k end
+
+            withLeft =
+                if afterStart then
+                    rangeHelp left start end entries
+                else
+                    entries
+
+            withNode =
+                if afterStart && beforeEnd then
+                    List.append withLeft (T k v)
+                else
+                    withLeft
+
+            if beforeEnd then
+                rangeHelp right start end withNode
+            else
+                withNode
+
+isAfterStart : k, [At k, After k, Unbounded] -> Bool | k has Ord
+isAfterStart = \k, start ->
+    when start is
+        At bound -> Ord.compare k bound != LT
+        After bound -> Ord.compare k bound == GT
+        Unbounded -> Bool.true
+
+isBeforeEnd : k, [At k, Before k, Unbounded] -> Bool | k has Ord
+isBeforeEnd = \k, end ->
+    when end is
+        At bound -> Ord.compare k bound != GT
+        Before bound -> Ord.compare k bound == LT
+        Unbounded -> Bool.true
+
+# Builds a dictionary out of entries that are already sorted by key, without
+# comparing any keys. Taking the middle entry as the root at every level gives
+# subtrees that differ in size by at most one.
+fromSortedList : List (T k v) -> OrdDict k v | k has Ord
+fromSortedList = \entries ->
+    @OrdDict (fromSortedHelp entries 0 (List.len entries))
+
+fromSortedHelp : List (T k v), Nat, Nat -> Tree k v
+fromSortedHelp = \entries, start, count ->
+    leftCount = Num.divTrunc count 2
+
+    when List.get entries (start + leftCount) is
+        Ok (T k v) if count > 0 ->
+            left = fromSortedHelp entries start leftCount
+            right = fromSortedHelp entries (start + leftCount + 1) (count - leftCount - 1)
+
+            Node count k v left right
+
+        _ ->
+            Empty
+
+# Checks the weight-balance invariant of every node, and that every stored size
+# is correct. The expects below run it after inserts, removes and ranges.
+isBalanced : Tree k v -> Bool
+isBalanced = \tree ->
+    when tree is
+        Empty ->
+            Bool.true
+
+        Node s _ _ left right ->
+            sizeLeft = size left
+            sizeRight = size right
+
+            (s == sizeLeft + sizeRight + 1)
+            && (sizeLeft + sizeRight <= 1 || (sizeLeft <= delta * sizeRight && sizeRight <= delta * sizeLeft))
+            && isBalanced left
+            && isBalanced right
+
+expect
+    dict = List.walk (List.range { start: At 0, end: Before 100 }) (empty {}) (\d, i -> insert d i i)
+    (@OrdDict tree) = dict
+
+    isBalanced tree && keys dict == List.range { start: At 0, end: Before 100 }
+
+expect
+    dict = List.walk (List.range { start: At 100, end: At 1, step: -1 }) (empty {}) (\d, i -> insert d i i)
+    (@OrdDict tree) = List.walk (List.range { start: At 0, end: Before 100, step: 3 }) dict remove
+
+    isBalanced tree && len (@OrdDict tree) == 67
+
+expect
+    dict = fromList [T 5 "e", T 1 "a", T 3 "c", T 2 "b", T 4 "d"]
+    visited = walkFromUntil dict 2 [] \state, k, _ ->
+        if k == 4 then
+            Break state
+        else
+            Continue (List.append state k)
+
+    visited == [2, 3]
+
+expect
+    dict = List.walk (List.range { start: At 0, end: Before 20 }) (empty {}) (\d, i -> insert d i {})
+    (@OrdDict tree) = range dict { start: After 3, end: Before 17 }
+
+    isBalanced tree && keys (@OrdDict tree) == List.range { start: At 4, end: At 16 }
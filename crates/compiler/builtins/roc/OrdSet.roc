@@ -0,0 +1,180 @@
+interface OrdSet
+    exposes [
+        OrdSet,
+        empty,
+        single,
+        walk,
+        walkUntil,
+        walkFrom,
+        walkFromUntil,
+        insert,
+        len,
+        remove,
+        contains,
+        toList,
+        fromList,
+        union,
+        intersection,
+        difference,
+        min,
+        max,
+        floor,
+        ceiling,
+        range,
+    ]
+    imports [
+        List,
+        Bool.{ Bool, Eq },
+        Result.{ Result },
+        OrdDict.{ OrdDict },
+        Num.{ Nat },
+        Ord.{ Ord },
+    ]
+
+## A set whose elements are kept in sorted order by the [Ord](../Ord#Ord)
+## ability. It is to [OrdDict](../OrdDict#OrdDict) what [Set](../Set#Set) is to
+## [Dict](../Dict#Dict).
+OrdSet k := OrdDict.OrdDict k {}
+     has [
+         Eq {
+             isEq,
+         },
+     ]
+
+isEq : OrdSet k, OrdSet k -> Bool | k has Ord
+isEq = \xs, ys ->
+    if len xs != len ys then
+        Bool.false
+    else
+        List.compare (toList xs) (toList ys) == EQ
+
+## Creates a new empty set.
+empty : {} -> OrdSet k | k has Ord
+empty = \{} -> @OrdSet (OrdDict.empty {})
+
+single : k -> OrdSet k | k has Ord
+single = \key ->
+    OrdDict.single key {} |> @OrdSet
+
+insert : OrdSet k, k -> OrdSet k | k has Ord
+insert = \@OrdSet dict, key ->
+    OrdDict.insert dict key {} |> @OrdSet
+
+len : OrdSet k -> Nat | k has Ord
+len = \@OrdSet dict ->
+    OrdDict.len dict
+
+## Drops the given element from the set.
+remove : OrdSet k, k -> OrdSet k | k has Ord
+remove = \@OrdSet dict, key ->
+    OrdDict.remove dict key |> @OrdSet
+
+contains : OrdSet k, k -> Bool | k has Ord
+contains = \@OrdSet dict, key ->
+    OrdDict.contains dict key
+
+## Returns the elements of the set as a sorted [List].
+toList : OrdSet k -> List k | k has Ord
+toList = \@OrdSet dict ->
+    OrdDict.keys dict
+
+fromList : List k -> OrdSet k | k has Ord
+fromList = \list ->
+    List.walk list (empty {}) insert
+
+union : OrdSet k, OrdSet k -> OrdSet k | k has Ord
+union = \@OrdSet dict1, @OrdSet dict2 ->
+    OrdDict.insertAll dict1 dict2 |> @OrdSet
+
+intersection : OrdSet k, OrdSet k -> OrdSet k | k has Ord
+intersection = \@OrdSet dict1, @OrdSet dict2 ->
+    OrdDict.keepShared dict1 dict2 |> @OrdSet
+
+difference : OrdSet k, OrdSet k -> OrdSet k | k has Ord
+difference = \@OrdSet dict1, @OrdSet dict2 ->
+    OrdDict.removeAll dict1 dict2 |> @OrdSet
+
+## Iterates through the elements of the set from the smallest to the largest.
+walk : OrdSet k, state, (state, k -> state) -> state | k has Ord
+walk = \@OrdSet dict, state, step ->
+    OrdDict.walk dict state (\s, k, _ -> step s k)
+
+walkUntil : OrdSet k, state, (state, k -> [Continue state, Break state]) -> state | k has Ord
+walkUntil = \@OrdSet dict, state, step ->
+    OrdDict.walkUntil dict state (\s, k, _ -> step s k)
+
+## Same as [OrdSet.walk], except it starts at the first element that is greater
+## than or equal to the given one.
+walkFrom : OrdSet k, k, state, (state, k -> state) -> state | k has Ord
+walkFrom = \@OrdSet dict, from, state, step ->
+    OrdDict.walkFrom dict from state (\s, k, _ -> step s k)
+
+## A combination of [OrdSet.walkFrom] and [OrdSet.walkUntil].
+walkFromUntil : OrdSet k, k, state, (state, k -> [Continue state, Break state]) -> state | k has Ord
+walkFromUntil = \@OrdSet dict, from, state, step ->
+    OrdDict.walkFromUntil dict from state (\s, k, _ -> step s k)
+
+## Returns the smallest element of the set.
+min : OrdSet k -> Result k [OrdSetWasEmpty] | k has Ord
+min = \@OrdSet dict ->
+    when OrdDict.min dict is
+        Ok (T k _) -> Ok k
+        Err OrdDictWasEmpty -> Err OrdSetWasEmpty
+
+## Returns the largest element of the set.
+max : OrdSet k -> Result k [OrdSetWasEmpty] | k has Ord
+max = \@OrdSet dict ->
+    when OrdDict.max dict is
+        Ok (T k _) -> Ok k
+        Err OrdDictWasEmpty -> Err OrdSetWasEmpty
+
+## Returns the largest element that is less than or equal to the given one.
+floor : OrdSet k, k -> Result k [NotFound] | k has Ord
+floor = \@OrdSet dict, key ->
+    when OrdDict.floor dict key is
+        Ok (T k _) -> Ok k
+        Err KeyNotFound -> Err NotFound
+
+## Returns the smallest element that is greater than or equal to the given one.
+ceiling : OrdSet k, k -> Result k [NotFound] | k has Ord
+ceiling = \@OrdSet dict, key ->
+    when OrdDict.ceiling dict key is
+        Ok (T k _) -> Ok k
+        Err KeyNotFound -> Err NotFound
+
+## Returns the elements that lie between `start` and `end`, with the same
+## bounds as [OrdDict.range](../OrdDict#range).
+##
+##     expect
+##         OrdSet.fromList [1, 2, 3, 4, 5]
+##         |> OrdSet.range { start: After 1, end: At 4 }
+##         |> OrdSet.toList
+##         |> Bool.isEq [2, 3, 4]
+range : OrdSet k, { start : [At k, After k, Unbounded], end : [At k, Before k, Unbounded] } -> OrdSet k | k has Ord
+range = \@OrdSet dict, bounds ->
+    OrdDict.range dict bounds |> @OrdSet
+
+# Inserting a duplicate key has no effect.
+expect
+    actual =
+        empty {}
+        |> insert "foo"
+        |> insert "bar"
+        |> insert "foo"
+        |> insert "baz"
+
+    toList actual == ["bar", "baz", "foo"]
+
+expect
+    union (fromList [1, 2, 3, 4]) (fromList [3, 4, 5]) == fromList [1, 2, 3, 4, 5]
+
+expect
+    intersection (fromList [1, 2, 3, 4]) (fromList [3, 4, 5]) == fromList [3, 4]
+
+expect
+    difference (fromList [1, 2, 3, 4]) (fromList [3, 4, 5]) == fromList [1, 2]
+
+expect
+    x = fromList [9, 8, 7, 6, 5, 4, 3, 2, 1, 0]
+
+    x == fromList (toList x) && min x == Ok 0 && max x == Ok 9
@@ -2,6 +2,8 @@ interface Set
     exposes [
         Set,
         empty,
+        withHasher,
+        withRandomSeed,
         single,
         walk,
         insert,
@@ -18,7 +20,7 @@ interface Set
         List,
         Bool.{ Bool, Eq },
         Dict.{ Dict },
-        Num.{ Nat },
+        Num.{ Nat, U64 },
         Hash.{ Hash },
     ]
 
@@ -47,6 +49,16 @@ isEq = \xs, ys ->
 empty : {} -> Set k | k has Hash & Eq
 empty = \{} -> @Set (Dict.empty {})
 
+## Creates a new empty set that hashes its elements with the given hash function.
+## See [Dict.withHasher] for the available hash functions.
+withHasher : [Wyhash { seed : U64 }, SipHash13 { k0 : U64, k1 : U64 }] -> Set k | k has Hash & Eq
+withHasher = \hasher -> @Set (Dict.withHasher hasher)
+
+## Creates a new empty set that hashes its elements with a randomly keyed hash
+## function. See [Dict.withRandomSeed].
+withRandomSeed : {} -> Set k | k has Hash & Eq
+withRandomSeed = \{} -> @Set (Dict.withRandomSeed {})
+
 single : k -> Set k | k has Hash & Eq
 single = \key ->
     Dict.single key {} |> @Set
@@ -73,6 +73,7 @@ interface Str
     exposes [
         Utf8Problem,
         Utf8ByteProblem,
+        NumStrProblem,
         concat,
         isEmpty,
         joinWith,
@@ -103,6 +104,19 @@ interface Str
         toI16,
         toU8,
         toI8,
+        toNatRadix,
+        toU128Radix,
+        toI128Radix,
+        toU64Radix,
+        toI64Radix,
+        toU32Radix,
+        toI32Radix,
+        toU16Radix,
+        toI16Radix,
+        toU8Radix,
+        toI8Radix,
+        toF64Checked,
+        toF32Checked,
         toScalars,
         replaceEach,
         replaceFirst,
@@ -117,12 +131,20 @@ interface Str
         withCapacity,
         withPrefix,
         graphemes,
+        compare,
+        toUppercase,
+        toLowercase,
+        toNfc,
+        toNfd,
+        compareIgnoringCase,
+        contains,
+        find,
     ]
     imports [
         Bool.{ Bool, Eq },
         Result.{ Result },
         List,
-        Num.{ Nat, Num, U8, U16, U32, U64, U128, I8, I16, I32, I64, I128, F32, F64, Dec },
+        Num.{ Nat, Num, Int, Frac, U8, U16, U32, U64, U128, I8, I16, I32, I64, I128, F32, F64, Dec },
     ]
 
 ## Test
@@ -137,6 +159,16 @@ Utf8ByteProblem : [
 
 Utf8Problem : { byteIndex : Nat, problem : Utf8ByteProblem }
 
+## Why [Str.toU32Radix], [Str.toF64Checked], and similar functions couldn't parse a
+## number. They return it along with the index of the byte where parsing failed.
+##
+## * `MissingDigits` - the string ended where digits were expected, like in `"-"` or `"0x"`.
+## * `InvalidDigit` - the byte can't go there, like the `g` in `"12g4"` in base 16.
+## * `Overflow` - the number doesn't fit in the type. The index is of the digit that
+##   made it too big, or too small. For fractions, that is the first digit before the
+##   point which the largest finite value doesn't have, or else the start of the exponent.
+NumStrProblem : [MissingDigits, InvalidDigit, Overflow]
+
 ## Returns [Bool.true] if the string is empty, and [Bool.false] otherwise.
 ##
 ##     expect Str.isEmpty "hi!" == Bool.false
@@ -470,6 +502,85 @@ toU8 = \string -> strToNumHelp string
 toI8 : Str -> Result I8 [InvalidNumStr]
 toI8 = \string -> strToNumHelp string
 
+## Parse a [Str] as an unsigned [U32] integer in the given radix (base), which must be
+## from 2 to 36. Digits past 9 are the letters `a` to `z`, in either case.
+##
+## Like number literals in Roc code, the string may start with a `-`, and may have a
+## `0b`, `0o`, or `0x` prefix when the radix is 2, 8, or 16. Single underscores may go
+## between the digits, but not right after the prefix or at the end. A `-` on a nonzero number gives an `Overflow` for unsigned integers.
+##
+## When the string isn't a valid number, the error says why and at which byte.
+##
+##     expect Str.toU32Radix "ff" 16 == Ok 255
+##     expect Str.toU32Radix "0xFF" 16 == Ok 255
+##     expect Str.toU32Radix "1_000" 10 == Ok 1000
+##     expect Str.toU32Radix "12g4" 16 == Err (BadNumStr InvalidDigit 2)
+##     expect Str.toU32Radix "4294967296" 10 == Err (BadNumStr Overflow 9)
+##     expect Str.toU32Radix "0x" 16 == Err (BadNumStr MissingDigits 2)
+##     expect Str.toU32Radix "12" 37 == Err InvalidRadix
+toU32Radix : Str, U8 -> Result U32 [BadNumStr NumStrProblem Nat, InvalidRadix]
+toU32Radix = \string, radix -> strToIntRadixHelp string radix
+
+## Parse a [Str] as a [Nat] in the given radix. See [Str.toU32Radix] for the accepted formats.
+toNatRadix : Str, U8 -> Result Nat [BadNumStr NumStrProblem Nat, InvalidRadix]
+toNatRadix = \string, radix -> strToIntRadixHelp string radix
+
+## Parse a [Str] as an unsigned [U128] integer in the given radix. See [Str.toU32Radix] for the accepted formats.
+toU128Radix : Str, U8 -> Result U128 [BadNumStr NumStrProblem Nat, InvalidRadix]
+toU128Radix = \string, radix -> strToIntRadixHelp string radix
+
+## Parse a [Str] as a signed [I128] integer in the given radix. See [Str.toU32Radix] for the accepted formats.
+toI128Radix : Str, U8 -> Result I128 [BadNumStr NumStrProblem Nat, InvalidRadix]
+toI128Radix = \string, radix -> strToIntRadixHelp string radix
+
+## Parse a [Str] as an unsigned [U64] integer in the given radix. See [Str.toU32Radix] for the accepted formats.
+toU64Radix : Str, U8 -> Result U64 [BadNumStr NumStrProblem Nat, InvalidRadix]
+toU64Radix = \string, radix -> strToIntRadixHelp string radix
+
+## Parse a [Str] as a signed [I64] integer in the given radix. See [Str.toU32Radix] for the accepted formats.
+toI64Radix : Str, U8 -> Result I64 [BadNumStr NumStrProblem Nat, InvalidRadix]
+toI64Radix = \string, radix -> strToIntRadixHelp string radix
+
+## Parse a [Str] as a signed [I32] integer in the given radix. See [Str.toU32Radix] for the accepted formats.
+toI32Radix : Str, U8 -> Result I32 [BadNumStr NumStrProblem Nat, InvalidRadix]
+toI32Radix = \string, radix -> strToIntRadixHelp string radix
+
+## Parse a [Str] as an unsigned [U16] integer in the given radix. See [Str.toU32Radix] for the accepted formats.
+toU16Radix : Str, U8 -> Result U16 [BadNumStr NumStrProblem Nat, InvalidRadix]
+toU16Radix = \string, radix -> strToIntRadixHelp string radix
+
+## Parse a [Str] as a signed [I16] integer in the given radix. See [Str.toU32Radix] for the accepted formats.
+toI16Radix : Str, U8 -> Result I16 [BadNumStr NumStrProblem Nat, InvalidRadix]
+toI16Radix = \string, radix -> strToIntRadixHelp string radix
+
+## Parse a [Str] as an unsigned [U8] integer in the given radix. See [Str.toU32Radix] for the accepted formats.
+toU8Radix : Str, U8 -> Result U8 [BadNumStr NumStrProblem Nat, InvalidRadix]
+toU8Radix = \string, radix -> strToIntRadixHelp string radix
+
+## Parse a [Str] as a signed [I8] integer in the given radix. See [Str.toU32Radix] for the accepted formats.
+toI8Radix : Str, U8 -> Result I8 [BadNumStr NumStrProblem Nat, InvalidRadix]
+toI8Radix = \string, radix -> strToIntRadixHelp string radix
+
+## Parse a [Str] as a [F64], always with `.` as the decimal point, no matter the locale.
+##
+## The string may start with a `-`, must have digits before and after any `.`, and
+## may end with an exponent like `e-3`. Single underscores may go between the digits.
+## Numbers too big to be finite are an `Overflow`, rather than infinity.
+##
+## When the string isn't a valid number, the error says why and at which byte.
+##
+##     expect Str.toF64Checked "-1_000.5" == Ok -1000.5
+##     expect Str.toF64Checked "2.5e3" == Ok 2500
+##     expect Str.toF64Checked "1,5" == Err (BadNumStr InvalidDigit 1)
+##     expect Str.toF64Checked "1." == Err (BadNumStr MissingDigits 2)
+##     expect Str.toF64Checked "1e400" == Err (BadNumStr Overflow 2)
+toF64Checked : Str -> Result F64 [BadNumStr NumStrProblem Nat]
+toF64Checked = \string -> strToFloatCheckedHelp string
+
+## Parse a [Str] as a [F32]. See [Str.toF64Checked] for the accepted format.
+toF32Checked : Str -> Result F32 [BadNumStr NumStrProblem Nat]
+toF32Checked = \string -> strToFloatCheckedHelp string
+
 ## Get the byte at the given index, without performing a bounds check.
 getUnsafe : Str, Nat -> U8
 
@@ -580,21 +691,9 @@ expect splitFirst "foo" "foo" == Ok { before: "", after: "" }
 
 firstMatch : Str, Str -> [Some Nat, None]
 firstMatch = \haystack, needle ->
-    haystackLength = Str.countUtf8Bytes haystack
-    needleLength = Str.countUtf8Bytes needle
-    lastPossible = Num.subSaturated haystackLength needleLength
-
-    firstMatchHelp haystack needle 0 lastPossible
-
-firstMatchHelp : Str, Str, Nat, Nat -> [Some Nat, None]
-firstMatchHelp = \haystack, needle, index, lastPossible ->
-    if index <= lastPossible then
-        if matchesAt haystack index needle then
-            Some index
-        else
-            firstMatchHelp haystack needle (index + 1) lastPossible
-    else
-        None
+    when find haystack needle is
+        Ok index -> Some index
+        Err NotFound -> None
 
 ## Returns the given [Str] before the last occurrence of a delimiter, as well as
 ## the rest of the string after that occurrence.
@@ -794,8 +893,166 @@ strToNumHelp = \string ->
     else
         Err InvalidNumStr
 
+strToIntRadix : Str, U8 -> { aresult : Int *, bindex : Nat, cproblem : U8 }
+
+strToIntRadixHelp : Str, U8 -> Result (Int a) [BadNumStr NumStrProblem Nat, InvalidRadix]
+strToIntRadixHelp = \string, radix ->
+    result : { aresult : Int a, bindex : Nat, cproblem : U8 }
+    result = strToIntRadix string radix
+
+    when result.cproblem is
+        0 -> Ok result.aresult
+        4 -> Err InvalidRadix
+        code -> Err (BadNumStr (numStrProblemFromCode code) result.bindex)
+
+strToFloatChecked : Str -> { aresult : Frac *, bindex : Nat, cproblem : U8 }
+
+strToFloatCheckedHelp : Str -> Result (Frac a) [BadNumStr NumStrProblem Nat]
+strToFloatCheckedHelp = \string ->
+    result : { aresult : Frac a, bindex : Nat, cproblem : U8 }
+    result = strToFloatChecked string
+
+    if result.cproblem == 0 then
+        Ok result.aresult
+    else
+        Err (BadNumStr (numStrProblemFromCode result.cproblem) result.bindex)
+
+# These codes match NumParseProblem in the zig builtins
+numStrProblemFromCode : U8 -> NumStrProblem
+numStrProblemFromCode = \code ->
+    when code is
+        1 -> MissingDigits
+        2 -> InvalidDigit
+        _ -> Overflow
+
 ## Adds a prefix to the given [Str].
 ##
 ##     expect Str.withPrefix "Awesome" "Roc" == "RocAwesome"
 withPrefix : Str, Str -> Str
 withPrefix = \str, prefix -> Str.concat prefix str
+
+## Compares two strings by their UTF-8 bytes. This is the same as comparing
+## their Unicode scalar values one by one; it does not take the conventions of
+## any human language into account.
+##
+##     expect Str.compare "apple" "banana" == LT
+##     expect Str.compare "apple" "app" == GT
+##     expect Str.compare "Zebra" "apple" == LT
+compare : Str, Str -> [LT, EQ, GT]
+compare = \a, b ->
+    compareHelp a b 0 (Str.countUtf8Bytes a) (Str.countUtf8Bytes b)
+
+compareHelp : Str, Str, Nat, Nat, Nat -> [LT, EQ, GT]
+compareHelp = \a, b, index, lenA, lenB ->
+    if index == lenA then
+        if index == lenB then
+            EQ
+        else
+            LT
+    else if index == lenB then
+        GT
+    else
+        when Num.compare (Str.getUnsafe a index) (Str.getUnsafe b index) is
+            EQ -> compareHelp a b (index + 1) lenA lenB
+            order -> order
+
+## Converts every letter in the [Str] to uppercase, using the case mappings of the
+## [Unicode Character Database](https://unicode.org/ucd/). Some letters become
+## more than one letter when uppercased, so the result may be longer than the input.
+##
+##     expect Str.toUppercase "Hello, World!" == "HELLO, WORLD!"
+##     expect Str.toUppercase "straße" == "STRASSE"
+##
+## Each scalar is mapped on its own, so mappings which depend on the surrounding
+## letters or on the language of the text are not applied.
+toUppercase : Str -> Str
+
+## Converts every letter in the [Str] to lowercase, using the case mappings of the
+## [Unicode Character Database](https://unicode.org/ucd/).
+##
+##     expect Str.toLowercase "Hello, World!" == "hello, world!"
+##     expect Str.toLowercase "ÀÉÎ" == "àéî"
+##
+## Each scalar is mapped on its own, so (for example) a Greek capital sigma always
+## becomes "σ", even at the end of a word.
+toLowercase : Str -> Str
+
+## Applies full Unicode case folding, which removes case distinctions so that
+## strings can be compared while ignoring case. Not exposed, because the result
+## is only meaningful for comparisons; see [Str.compareIgnoringCase].
+caseFold : Str -> Str
+
+## Converts the [Str] to [Normalization Form C](https://unicode.org/reports/tr15/),
+## where characters are composed into single code points wherever possible.
+##
+## Two strings can look the same but be made of different code points, such as
+## "é" written as one code point or as "e" followed by a combining accent.
+## Normalizing both strings first makes them compare equal.
+##
+##     expect Str.toNfc "e\u(301)" == "\u(e9)"
+##     expect Str.toNfc "Roc" == "Roc"
+toNfc : Str -> Str
+
+## Converts the [Str] to [Normalization Form D](https://unicode.org/reports/tr15/),
+## where characters are decomposed into a base code point followed by combining marks.
+##
+##     expect Str.toNfd "\u(e9)" == "e\u(301)"
+##     expect Str.toNfd "Roc" == "Roc"
+toNfd : Str -> Str
+
+## Compares two strings like [Str.compare], but ignoring differences in case and
+## in how characters are composed. Two strings compare as [EQ] if they are a
+## [canonical caseless match](https://www.unicode.org/versions/Unicode14.0.0/ch03.pdf#G34145).
+##
+##     expect Str.compareIgnoringCase "Straße" "STRASSE" == EQ
+##     expect Str.compareIgnoringCase "apple" "Banana" == LT
+compareIgnoringCase : Str, Str -> [LT, EQ, GT]
+compareIgnoringCase = \a, b ->
+    compare (caselessKey a) (caselessKey b)
+
+caselessKey : Str -> Str
+caselessKey = \str ->
+    str
+    |> toNfd
+    |> caseFold
+    |> toNfd
+
+expect compareIgnoringCase "Straße" "STRASSE" == EQ
+expect compareIgnoringCase "\u(c5)ngstr\u(f6)m" "A\u(30a)NGSTRO\u(308)M" == EQ
+expect compareIgnoringCase "apple" "Banana" == LT
+expect compareIgnoringCase "b" "A" == GT
+
+## Returns the index of the first byte of the first occurrence of the needle
+## in the haystack, or [Err NotFound] if the needle does not occur in it.
+##
+##     expect Str.find "hello, world" "o" == Ok 4
+##     expect Str.find "hello, world" "z" == Err NotFound
+##
+## The index counts UTF-8 bytes, like [Str.countUtf8Bytes]. An empty needle is
+## found at index 0.
+find : Str, Str -> Result Nat [NotFound]
+find = \haystack, needle ->
+    index = findUnsafe haystack needle
+
+    # findUnsafe returns a number past the end of the haystack if there is no match
+    if index > Str.countUtf8Bytes haystack then
+        Err NotFound
+    else
+        Ok index
+
+findUnsafe : Str, Str -> Nat
+
+expect find "hello, world" "o" == Ok 4
+expect find "héllo" "llo" == Ok 3
+expect find "hello" "" == Ok 0
+expect find "" "a" == Err NotFound
+
+## Returns [Bool.true] if the needle occurs anywhere in the haystack.
+##
+##     expect Str.contains "hello, world" "lo, w"
+##     expect !(Str.contains "hello, world" "World")
+contains : Str, Str -> Bool
+contains = \haystack, needle ->
+    when find haystack needle is
+        Ok _ -> Bool.true
+        Err NotFound -> Bool.false
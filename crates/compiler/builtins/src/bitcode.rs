@@ -324,6 +324,18 @@ macro_rules! int_intrinsic {
     }};
 }
 
+#[macro_export]
+macro_rules! num_intrinsic {
+    ($name:literal) => {{
+        let mut output = int_intrinsic!($name);
+
+        output.options[1] = concat!($name, ".f32");
+        output.options[2] = concat!($name, ".f64");
+
+        output
+    }};
+}
+
 pub const NUM_SIN: IntrinsicName = float_intrinsic!("roc_builtins.num.sin");
 pub const NUM_COS: IntrinsicName = float_intrinsic!("roc_builtins.num.cos");
 pub const NUM_ASIN: IntrinsicName = float_intrinsic!("roc_builtins.num.asin");
@@ -337,21 +349,53 @@ pub const NUM_POW_INT: IntrinsicName = int_intrinsic!("roc_builtins.num.pow_int"
 pub const NUM_DIV_CEIL: IntrinsicName = int_intrinsic!("roc_builtins.num.div_ceil");
 pub const NUM_ROUND_F32: IntrinsicName = int_intrinsic!("roc_builtins.num.round_f32");
 pub const NUM_ROUND_F64: IntrinsicName = int_intrinsic!("roc_builtins.num.round_f64");
+pub const NUM_CEILING_F32: IntrinsicName = int_intrinsic!("roc_builtins.num.ceiling_f32");
+pub const NUM_CEILING_F64: IntrinsicName = int_intrinsic!("roc_builtins.num.ceiling_f64");
+pub const NUM_FLOOR_F32: IntrinsicName = int_intrinsic!("roc_builtins.num.floor_f32");
+pub const NUM_FLOOR_F64: IntrinsicName = int_intrinsic!("roc_builtins.num.floor_f64");
+pub const NUM_INT_TO_F32: IntrinsicName = int_intrinsic!("roc_builtins.num.int_to_f32");
+pub const NUM_INT_TO_F64: IntrinsicName = int_intrinsic!("roc_builtins.num.int_to_f64");
+pub const NUM_TO_F32_CHECKED: IntrinsicName = num_intrinsic!("roc_builtins.num.to_f32_checked");
+pub const NUM_TO_F64_CHECKED: IntrinsicName = num_intrinsic!("roc_builtins.num.to_f64_checked");
+
+pub const NUM_COMPARE: IntrinsicName = int_intrinsic!("roc_builtins.num.compare");
+pub const NUM_DIV_TRUNC: IntrinsicName = int_intrinsic!("roc_builtins.num.div_trunc");
+pub const NUM_REM: IntrinsicName = int_intrinsic!("roc_builtins.num.rem");
+pub const NUM_IS_MULTIPLE_OF: IntrinsicName = int_intrinsic!("roc_builtins.num.is_multiple_of");
+pub const NUM_ABS_OR_PANIC: IntrinsicName = int_intrinsic!("roc_builtins.num.abs_or_panic");
+pub const NUM_NEG_OR_PANIC: IntrinsicName = int_intrinsic!("roc_builtins.num.neg_or_panic");
+
+pub const NUM_SHIFT_LEFT_BY: IntrinsicName = int_intrinsic!("roc_builtins.num.shift_left_by");
+pub const NUM_SHIFT_RIGHT_BY: IntrinsicName = int_intrinsic!("roc_builtins.num.shift_right_by");
+pub const NUM_SHIFT_RIGHT_ZF_BY: IntrinsicName =
+    int_intrinsic!("roc_builtins.num.shift_right_zf_by");
+
+pub const NUM_COUNT_LEADING_ZERO_BITS: IntrinsicName =
+    int_intrinsic!("roc_builtins.num.count_leading_zero_bits");
+pub const NUM_COUNT_TRAILING_ZERO_BITS: IntrinsicName =
+    int_intrinsic!("roc_builtins.num.count_trailing_zero_bits");
+pub const NUM_COUNT_ONE_BITS: IntrinsicName = int_intrinsic!("roc_builtins.num.count_one_bits");
+pub const NUM_ROTATE_LEFT_BY: IntrinsicName = int_intrinsic!("roc_builtins.num.rotate_left_by");
+pub const NUM_ROTATE_RIGHT_BY: IntrinsicName = int_intrinsic!("roc_builtins.num.rotate_right_by");
+pub const NUM_SWAP_BYTES: IntrinsicName = int_intrinsic!("roc_builtins.num.swap_bytes");
 
 pub const NUM_ADD_OR_PANIC_INT: IntrinsicName = int_intrinsic!("roc_builtins.num.add_or_panic");
 pub const NUM_ADD_SATURATED_INT: IntrinsicName = int_intrinsic!("roc_builtins.num.add_saturated");
+pub const NUM_ADD_WRAPPED_INT: IntrinsicName = int_intrinsic!("roc_builtins.num.add_wrapped");
 pub const NUM_ADD_CHECKED_INT: IntrinsicName = int_intrinsic!("roc_builtins.num.add_with_overflow");
 pub const NUM_ADD_CHECKED_FLOAT: IntrinsicName =
     float_intrinsic!("roc_builtins.num.add_with_overflow");
 
 pub const NUM_SUB_OR_PANIC_INT: IntrinsicName = int_intrinsic!("roc_builtins.num.sub_or_panic");
 pub const NUM_SUB_SATURATED_INT: IntrinsicName = int_intrinsic!("roc_builtins.num.sub_saturated");
+pub const NUM_SUB_WRAPPED_INT: IntrinsicName = int_intrinsic!("roc_builtins.num.sub_wrapped");
 pub const NUM_SUB_CHECKED_INT: IntrinsicName = int_intrinsic!("roc_builtins.num.sub_with_overflow");
 pub const NUM_SUB_CHECKED_FLOAT: IntrinsicName =
     float_intrinsic!("roc_builtins.num.sub_with_overflow");
 
 pub const NUM_MUL_OR_PANIC_INT: IntrinsicName = int_intrinsic!("roc_builtins.num.mul_or_panic");
 pub const NUM_MUL_SATURATED_INT: IntrinsicName = int_intrinsic!("roc_builtins.num.mul_saturated");
+pub const NUM_MUL_WRAPPED_INT: IntrinsicName = int_intrinsic!("roc_builtins.num.mul_wrapped");
 pub const NUM_MUL_CHECKED_INT: IntrinsicName = int_intrinsic!("roc_builtins.num.mul_with_overflow");
 pub const NUM_MUL_CHECKED_FLOAT: IntrinsicName =
     float_intrinsic!("roc_builtins.num.mul_with_overflow");
@@ -359,6 +403,13 @@ pub const NUM_MUL_CHECKED_FLOAT: IntrinsicName =
 pub const NUM_BYTES_TO_U16: &str = "roc_builtins.num.bytes_to_u16";
 pub const NUM_BYTES_TO_U32: &str = "roc_builtins.num.bytes_to_u32";
 
+pub const NUM_TO_STR_FIXED: IntrinsicName = float_intrinsic!("roc_builtins.num.to_str_fixed");
+pub const NUM_TO_STR_SCIENTIFIC: IntrinsicName =
+    float_intrinsic!("roc_builtins.num.to_str_scientific");
+pub const NUM_TO_STR_RADIX: IntrinsicName = int_intrinsic!("roc_builtins.num.to_str_radix");
+pub const NUM_PAD_ZEROS: &str = "roc_builtins.num.pad_zeros";
+pub const NUM_GROUP_DIGITS: &str = "roc_builtins.num.group_digits";
+
 pub const STR_INIT: &str = "roc_builtins.str.init";
 pub const STR_COUNT_SEGMENTS: &str = "roc_builtins.str.count_segments";
 pub const STR_CONCAT: &str = "roc_builtins.str.concat";
@@ -375,7 +426,10 @@ pub const STR_NUMBER_OF_BYTES: &str = "roc_builtins.str.number_of_bytes";
 pub const STR_FROM_INT: IntrinsicName = int_intrinsic!("roc_builtins.str.from_int");
 pub const STR_FROM_FLOAT: IntrinsicName = float_intrinsic!("roc_builtins.str.from_float");
 pub const STR_TO_INT: IntrinsicName = int_intrinsic!("roc_builtins.str.to_int");
+pub const STR_TO_INT_RADIX: IntrinsicName = int_intrinsic!("roc_builtins.str.to_int_radix");
 pub const STR_TO_FLOAT: IntrinsicName = float_intrinsic!("roc_builtins.str.to_float");
+pub const STR_TO_FLOAT_CHECKED: IntrinsicName =
+    float_intrinsic!("roc_builtins.str.to_float_checked");
 pub const STR_TO_DECIMAL: &str = "roc_builtins.str.to_decimal";
 pub const STR_EQUAL: &str = "roc_builtins.str.equal";
 pub const STR_SUBSTRING_UNSAFE: &str = "roc_builtins.str.substring_unsafe";
@@ -392,6 +446,12 @@ pub const STR_GET_SCALAR_UNSAFE: &str = "roc_builtins.str.get_scalar_unsafe";
 pub const STR_CLONE_TO: &str = "roc_builtins.str.clone_to";
 pub const STR_WITH_CAPACITY: &str = "roc_builtins.str.with_capacity";
 pub const STR_GRAPHEMES: &str = "roc_builtins.str.graphemes";
+pub const STR_TO_UPPERCASE: &str = "roc_builtins.str.to_uppercase";
+pub const STR_TO_LOWERCASE: &str = "roc_builtins.str.to_lowercase";
+pub const STR_CASE_FOLD: &str = "roc_builtins.str.case_fold";
+pub const STR_TO_NFC: &str = "roc_builtins.str.to_nfc";
+pub const STR_TO_NFD: &str = "roc_builtins.str.to_nfd";
+pub const STR_FIND: &str = "roc_builtins.str.find";
 
 pub const LIST_MAP: &str = "roc_builtins.list.map";
 pub const LIST_MAP2: &str = "roc_builtins.list.map2";
@@ -406,16 +466,27 @@ pub const LIST_CONCAT: &str = "roc_builtins.list.concat";
 pub const LIST_REPLACE: &str = "roc_builtins.list.replace";
 pub const LIST_REPLACE_IN_PLACE: &str = "roc_builtins.list.replace_in_place";
 pub const LIST_IS_UNIQUE: &str = "roc_builtins.list.is_unique";
+pub const LIST_MAKE_UNIQUE: &str = "roc_builtins.list.make_unique";
 pub const LIST_PREPEND: &str = "roc_builtins.list.prepend";
 pub const LIST_APPEND_UNSAFE: &str = "roc_builtins.list.append_unsafe";
 pub const LIST_RESERVE: &str = "roc_builtins.list.reserve";
 
 pub const DEC_FROM_STR: &str = "roc_builtins.dec.from_str";
 pub const DEC_TO_STR: &str = "roc_builtins.dec.to_str";
+pub const DEC_TO_STR_FIXED: &str = "roc_builtins.dec.to_str_fixed";
+pub const DEC_TO_STR_SCIENTIFIC: &str = "roc_builtins.dec.to_str_scientific";
 pub const DEC_FROM_F64: &str = "roc_builtins.dec.from_f64";
 pub const DEC_EQ: &str = "roc_builtins.dec.eq";
 pub const DEC_NEQ: &str = "roc_builtins.dec.neq";
 pub const DEC_NEGATE: &str = "roc_builtins.dec.negate";
+pub const DEC_ABS: &str = "roc_builtins.dec.abs";
+pub const DEC_TO_F64: &str = "roc_builtins.dec.to_f64";
+pub const DEC_TO_F32_CHECKED: &str = "roc_builtins.dec.to_f32_checked";
+pub const DEC_TO_F64_CHECKED: &str = "roc_builtins.dec.to_f64_checked";
+pub const DEC_FROM_INT: IntrinsicName = int_intrinsic!("roc_builtins.dec.from_int");
+pub const DEC_ROUND: IntrinsicName = int_intrinsic!("roc_builtins.dec.round");
+pub const DEC_FLOOR: IntrinsicName = int_intrinsic!("roc_builtins.dec.floor");
+pub const DEC_CEILING: IntrinsicName = int_intrinsic!("roc_builtins.dec.ceiling");
 pub const DEC_MUL_WITH_OVERFLOW: &str = "roc_builtins.dec.mul_with_overflow";
 pub const DEC_DIV: &str = "roc_builtins.dec.div";
 pub const DEC_ADD_WITH_OVERFLOW: &str = "roc_builtins.dec.add_with_overflow";
@@ -428,11 +499,23 @@ pub const DEC_MUL_OR_PANIC: &str = "roc_builtins.dec.mul_or_panic";
 pub const DEC_MUL_SATURATED: &str = "roc_builtins.dec.mul_saturated";
 
 pub const UTILS_TEST_PANIC: &str = "roc_builtins.utils.test_panic";
+pub const UTILS_TEST_DISPATCH: &str = "roc_builtins.utils.test_dispatch";
 pub const UTILS_ALLOCATE_WITH_REFCOUNT: &str = "roc_builtins.utils.allocate_with_refcount";
 pub const UTILS_INCREF: &str = "roc_builtins.utils.incref";
 pub const UTILS_DECREF: &str = "roc_builtins.utils.decref";
 pub const UTILS_DECREF_CHECK_NULL: &str = "roc_builtins.utils.decref_check_null";
 
+pub const UTILS_MEMCHECK_ENTER: &str = "roc_builtins.utils.memcheck_enter";
+pub const UTILS_MEMCHECK_ALLOC: &str = "roc_builtins.utils.memcheck_alloc";
+pub const UTILS_MEMCHECK_REALLOC: &str = "roc_builtins.utils.memcheck_realloc";
+pub const UTILS_MEMCHECK_DEALLOC: &str = "roc_builtins.utils.memcheck_dealloc";
+pub const UTILS_MEMCHECK_REPORT: &str = "roc_builtins.utils.memcheck_report";
+pub const UTILS_MEMCHECK_REPORT_AT_EXIT: &str = "roc_builtins.utils.memcheck_report_at_exit";
+pub const UTILS_REFCOUNT_TRACE_LOCATION: &str = "roc_builtins.utils.refcount_trace_location";
+pub const UTILS_REFCOUNT_TRACE_RESET: &str = "roc_builtins.utils.refcount_trace_reset";
+pub const UTILS_REFCOUNT_TRACE_INCREF: &str = "roc_builtins.utils.refcount_trace_incref";
+pub const UTILS_HASH_RANDOM_SEED: &str = "roc_builtins.utils.hash_random_seed";
+
 pub const UTILS_EXPECT_FAILED_START_SHARED_BUFFER: &str =
     "roc_builtins.utils.expect_failed_start_shared_buffer";
 pub const UTILS_EXPECT_FAILED_START_SHARED_FILE: &str =
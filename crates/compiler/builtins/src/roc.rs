@@ -15,6 +15,9 @@ pub fn module_source(module_id: ModuleId) -> &'static str {
         ModuleId::DECODE => DECODE,
         ModuleId::HASH => HASH,
         ModuleId::JSON => JSON,
+        ModuleId::ORD => ORD,
+        ModuleId::ORD_DICT => ORD_DICT,
+        ModuleId::ORD_SET => ORD_SET,
         _ => panic!(
             "ModuleId {:?} is not part of the standard library",
             module_id
@@ -34,3 +37,6 @@ const ENCODE: &str = include_str!("../roc/Encode.roc");
 const DECODE: &str = include_str!("../roc/Decode.roc");
 const HASH: &str = include_str!("../roc/Hash.roc");
 const JSON: &str = include_str!("../roc/Json.roc");
+const ORD: &str = include_str!("../roc/Ord.roc");
+const ORD_DICT: &str = include_str!("../roc/OrdDict.roc");
+const ORD_SET: &str = include_str!("../roc/OrdSet.roc");
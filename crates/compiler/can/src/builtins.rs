@@ -85,8 +85,14 @@ macro_rules! map_symbol_to_lowlevel_and_arity {
                 // these are used internally and not tied to a symbol
                 LowLevel::Hash => unimplemented!(),
                 LowLevel::PtrCast => unimplemented!(),
+                LowLevel::PtrStore => unimplemented!(),
+                LowLevel::PtrLoad => unimplemented!(),
+                LowLevel::Alloca => unimplemented!(),
                 LowLevel::RefCountInc => unimplemented!(),
                 LowLevel::RefCountDec => unimplemented!(),
+                LowLevel::RefCountTraceLocation => unimplemented!(),
+                LowLevel::RefCountTraceReset => unimplemented!(),
+                LowLevel::RefCountTraceInc => unimplemented!(),
 
                 // these are not implemented, not sure why
                 LowLevel::StrFromInt => unimplemented!(),
@@ -123,9 +129,17 @@ map_symbol_to_lowlevel_and_arity! {
     StrAppendScalar; STR_APPEND_SCALAR_UNSAFE; 2,
     StrGetScalarUnsafe; STR_GET_SCALAR_UNSAFE; 2,
     StrToNum; STR_TO_NUM; 1,
+    StrToIntRadix; STR_TO_INT_RADIX; 2,
+    StrToFloatChecked; STR_TO_FLOAT_CHECKED; 1,
     StrGetCapacity; STR_CAPACITY; 1,
     StrWithCapacity; STR_WITH_CAPACITY; 1,
     StrGraphemes; STR_GRAPHEMES; 1,
+    StrToUppercase; STR_TO_UPPERCASE; 1,
+    StrToLowercase; STR_TO_LOWERCASE; 1,
+    StrCaseFold; STR_CASE_FOLD; 1,
+    StrToNfc; STR_TO_NFC; 1,
+    StrToNfd; STR_TO_NFD; 1,
+    StrFind; STR_FIND_UNSAFE; 2,
 
     ListLen; LIST_LEN; 1,
     ListWithCapacity; LIST_WITH_CAPACITY; 1,
@@ -187,13 +201,28 @@ map_symbol_to_lowlevel_and_arity! {
     NumAsin; NUM_ASIN; 1,
     NumBytesToU16; NUM_BYTES_TO_U16_LOWLEVEL; 2,
     NumBytesToU32; NUM_BYTES_TO_U32_LOWLEVEL; 2,
+    NumReadBytesLe; NUM_READ_LE_UNSAFE; 2,
+    NumReadBytesBe; NUM_READ_BE_UNSAFE; 2,
+    NumWriteBytesLe; NUM_WRITE_LE_UNSAFE; 3,
+    NumWriteBytesBe; NUM_WRITE_BE_UNSAFE; 3,
     NumBitwiseAnd; NUM_BITWISE_AND; 2,
     NumBitwiseXor; NUM_BITWISE_XOR; 2,
     NumBitwiseOr; NUM_BITWISE_OR; 2,
     NumShiftLeftBy; NUM_SHIFT_LEFT; 2,
     NumShiftRightBy; NUM_SHIFT_RIGHT; 2,
     NumShiftRightZfBy; NUM_SHIFT_RIGHT_ZERO_FILL; 2,
+    NumCountLeadingZeroBits; NUM_COUNT_LEADING_ZERO_BITS; 1,
+    NumCountTrailingZeroBits; NUM_COUNT_TRAILING_ZERO_BITS; 1,
+    NumCountOneBits; NUM_COUNT_ONE_BITS; 1,
+    NumRotateLeftBy; NUM_ROTATE_LEFT_BY; 2,
+    NumRotateRightBy; NUM_ROTATE_RIGHT_BY; 2,
+    NumSwapBytes; NUM_SWAP_BYTES; 1,
     NumToStr; NUM_TO_STR; 1,
+    NumToStrFixed; NUM_TO_STR_FIXED; 2,
+    NumToStrScientific; NUM_TO_STR_SCIENTIFIC; 2,
+    NumToStrRadix; NUM_TO_STR_RADIX; 2,
+    NumPadZeros; NUM_PAD_ZEROS; 2,
+    NumGroupDigits; NUM_GROUP_DIGITS; 2,
 
     Eq; BOOL_STRUCTURAL_EQ; 2,
     NotEq; BOOL_STRUCTURAL_NOT_EQ; 2,
@@ -203,6 +232,8 @@ map_symbol_to_lowlevel_and_arity! {
     BoxExpr; BOX_BOX_FUNCTION; 1,
     UnboxExpr; BOX_UNBOX; 1,
     Unreachable; LIST_UNREACHABLE; 1,
+
+    HashRandomSeed; HASH_RANDOM_SEED; 1,
 }
 
 /// Some builtins cannot be constructed in code gen alone, and need to be defined
@@ -5,7 +5,7 @@ use crate::expr::Expr::{self, *};
 use crate::expr::{
     ClosureData, DeclarationTag, Declarations, FunctionDef, OpaqueWrapFunctionData, WhenBranch,
 };
-use crate::pattern::{Pattern, RecordDestruct};
+use crate::pattern::{ListPatterns, Pattern, RecordDestruct};
 
 use roc_module::symbol::{Interns, ModuleId, Symbol};
 
@@ -18,6 +18,10 @@ pub struct Ctx<'a> {
 }
 
 pub fn pretty_print_declarations(c: &Ctx, declarations: &Declarations) -> String {
+    if declarations.is_empty() {
+        return String::new();
+    }
+
     let f = Arena::new();
     let mut defs = Vec::with_capacity(declarations.len());
     for (index, tag) in declarations.iter_bottom_up() {
@@ -32,10 +36,18 @@ pub fn pretty_print_declarations(c: &Ctx, declarations: &Declarations) -> String
                 let function_def = &declarations.function_bodies[f_index.index()].value;
                 toplevel_function(c, &f, symbol, function_def, &body.value)
             }
-            DeclarationTag::Expectation => todo!(),
-            DeclarationTag::ExpectationFx => todo!(),
-            DeclarationTag::Destructure(_) => todo!(),
-            DeclarationTag::MutualRecursion { .. } => todo!(),
+            DeclarationTag::Expectation | DeclarationTag::ExpectationFx => f
+                .text("expect")
+                .append(f.line())
+                .append(expr(c, EPrec::Free, &f, &body.value))
+                .nest(2)
+                .group(),
+            DeclarationTag::Destructure(d_index) => {
+                let loc_pattern = &declarations.destructs[d_index.index()].loc_pattern;
+                def_help(c, &f, &loc_pattern.value, &body.value)
+            }
+            // Only marks the start of a group; its members are declarations of their own.
+            DeclarationTag::MutualRecursion { .. } => continue,
         };
 
         defs.push(def);
@@ -216,7 +228,11 @@ fn expr<'a>(c: &Ctx, p: EPrec, f: &'a Arena<'a>, e: &'a Expr) -> DocBuilder<'a,
                     .nest(2),
             )
             .group(),
-        LetRec(_, _, _) => todo!(),
+        LetRec(defs, body, _) => f
+            .intersperse(defs.iter().map(|d| def(c, f, d)), f.hardline())
+            .append(f.hardline())
+            .append(expr(c, Free, f, &body.value))
+            .group(),
         LetNonRec(loc_def, body) => def(c, f, loc_def)
             .append(f.hardline())
             .append(expr(c, Free, f, &body.value))
@@ -258,7 +274,24 @@ fn expr<'a>(c: &Ctx, p: EPrec, f: &'a Arena<'a>, e: &'a Expr) -> DocBuilder<'a,
                     .nest(2)
             )
         }
-        ForeignCall { .. } => todo!(),
+        ForeignCall {
+            foreign_symbol,
+            args,
+            ..
+        } => maybe_paren!(
+            Free,
+            p,
+            f.text(foreign_symbol.as_str().to_owned())
+                .append(
+                    f.concat(
+                        args.iter()
+                            .map(|le| f.line().append(expr(c, AppArg, f, &le.1)))
+                    )
+                    .group()
+                )
+                .group()
+                .nest(2)
+        ),
         Closure(ClosureData {
             arguments,
             loc_body,
@@ -315,7 +348,7 @@ fn expr<'a>(c: &Ctx, p: EPrec, f: &'a Arena<'a>, e: &'a Expr) -> DocBuilder<'a,
         OpaqueWrapFunction(OpaqueWrapFunctionData { opaque_name, .. }) => {
             f.text(format!("@{}", opaque_name.as_str(c.interns)))
         }
-        Accessor(_) => todo!(),
+        Accessor(data) => f.text(format!(".{}", data.field.as_str())),
         Update {
             symbol, updates, ..
         } => f
@@ -364,17 +397,67 @@ fn expr<'a>(c: &Ctx, p: EPrec, f: &'a Arena<'a>, e: &'a Expr) -> DocBuilder<'a,
                 )
                 .group()
         ),
-        Crash { .. } => todo!(),
-        ZeroArgumentTag { .. } => todo!(),
-        OpaqueRef { .. } => todo!(),
-        Dbg { .. } => todo!(),
-        Expect { .. } => todo!(),
-        ExpectFx { .. } => todo!(),
-        TypedHole(_) => todo!(),
-        RuntimeError(_) => todo!(),
+        Crash { msg, .. } => maybe_paren!(
+            Free,
+            p,
+            f.text("crash")
+                .append(f.line())
+                .append(expr(c, AppArg, f, &msg.value))
+                .group()
+                .nest(2)
+        ),
+        ZeroArgumentTag { name, .. } => f.text(name.0.as_str()),
+        OpaqueRef { name, argument, .. } => maybe_paren!(
+            Free,
+            p,
+            f.text(format!("@{}", name.as_str(c.interns)))
+                .append(f.line())
+                .append(expr(c, AppArg, f, &argument.1.value))
+                .group()
+                .nest(2)
+        ),
+        Dbg {
+            loc_condition,
+            loc_continuation,
+            ..
+        } => statement(c, f, "dbg", &loc_condition.value, &loc_continuation.value),
+        Expect {
+            loc_condition,
+            loc_continuation,
+            ..
+        }
+        | ExpectFx {
+            loc_condition,
+            loc_continuation,
+            ..
+        } => statement(
+            c,
+            f,
+            "expect",
+            &loc_condition.value,
+            &loc_continuation.value,
+        ),
+        TypedHole(_) => f.text("_"),
+        RuntimeError(_) => f.text("<runtime error>"),
     }
 }
 
+fn statement<'a>(
+    c: &Ctx,
+    f: &'a Arena<'a>,
+    keyword: &'static str,
+    condition: &'a Expr,
+    continuation: &'a Expr,
+) -> DocBuilder<'a, Arena<'a>> {
+    f.text(keyword)
+        .append(f.line())
+        .append(expr(c, EPrec::Free, f, condition))
+        .nest(2)
+        .group()
+        .append(f.hardline())
+        .append(expr(c, EPrec::Free, f, continuation))
+}
+
 fn pp_sym<'a>(c: &Ctx, f: &'a Arena<'a>, sym: Symbol) -> DocBuilder<'a, Arena<'a>> {
     if sym.module_id() == c.home {
         f.text(sym.as_str(c.interns).to_owned())
@@ -485,7 +568,27 @@ fn pattern<'a>(
             )
             .append(f.text("}"))
             .group(),
-        List { .. } => todo!(),
+        List {
+            patterns: ListPatterns { patterns, opt_rest },
+            ..
+        } => {
+            let mut elems: Vec<_> = patterns
+                .iter()
+                .map(|lp| pattern(c, Free, f, &lp.value))
+                .collect();
+            if let Some((index, opt_name)) = opt_rest {
+                let rest = match opt_name {
+                    Some(name) => f.text(".. as ").append(pp_sym(c, f, *name)),
+                    None => f.text(".."),
+                };
+                elems.insert(*index, rest);
+            }
+
+            f.text("[")
+                .append(f.intersperse(elems, f.text(", ")))
+                .append(f.text("]"))
+                .group()
+        }
         NumLiteral(_, n, _, _) | IntLiteral(_, _, n, _, _) | FloatLiteral(_, _, n, _, _) => {
             f.text(&**n)
         }
@@ -493,9 +596,8 @@ fn pattern<'a>(
         SingleQuote(_, _, c, _) => f.text(format!("'{}'", c)),
         Underscore => f.text("_"),
 
-        Shadowed(_, _, _) => todo!(),
-        OpaqueNotInScope(_) => todo!(),
-        UnsupportedPattern(_) => todo!(),
-        MalformedPattern(_, _) => todo!(),
+        Shadowed(_, _, sym) => pp_sym(c, f, *sym),
+        OpaqueNotInScope(ident) => f.text(format!("@{}", ident.value.as_str())),
+        UnsupportedPattern(_) | MalformedPattern(_, _) => f.text("<malformed pattern>"),
     }
 }
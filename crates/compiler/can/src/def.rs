@@ -38,6 +38,7 @@ use roc_parse::ast::TypeHeader;
 use roc_parse::pattern::PatternType;
 use roc_problem::can::ShadowKind;
 use roc_problem::can::{CycleEntry, Problem, RuntimeError};
+use roc_problem::Edit;
 use roc_region::all::{Loc, Region};
 use roc_types::subs::IllegalCycleMark;
 use roc_types::subs::{VarStore, Variable};
@@ -1025,6 +1026,10 @@ fn canonicalize_value_defs<'a>(
     for loc_pending_def in value_defs {
         match loc_pending_def.value {
             PendingValue::Def(pending_def) => {
+                if let Pattern::Identifier(symbol) = pending_def.loc_pattern().value {
+                    env.removable_defs.insert(symbol, loc_pending_def.region);
+                }
+
                 // Record the ast::Expr for later. We'll do another pass through these
                 // once we have the entire scope assembled. If we were to canonicalize
                 // the exprs right now, they wouldn't have symbols in scope from defs
@@ -2378,7 +2383,9 @@ pub fn can_defs_with_return<'a>(
         if !output.references.has_type_or_value_lookup(symbol)
             && !scope.abilities_store.is_specialization_name(symbol)
         {
-            env.problem(Problem::UnusedDef(symbol, region));
+            let fix = env.removable_defs.get(&symbol).copied().map(Edit::remove);
+
+            env.problem(Problem::UnusedDef(symbol, region, fix));
         }
     }
 
@@ -162,6 +162,23 @@ fn hash<'a>(env: &mut Env<'a>, at_opaque: &'a str) -> ast::Expr<'a> {
 }
 
 fn is_eq<'a>(env: &mut Env<'a>, at_opaque: &'a str) -> ast::Expr<'a> {
+    // \@Opaq payload1, @Opaq payload2 -> Bool.isEq payload1 payload2
+    compare_payloads(env, at_opaque, "Bool", "isEq")
+}
+
+fn compare<'a>(env: &mut Env<'a>, at_opaque: &'a str) -> ast::Expr<'a> {
+    // \@Opaq payload1, @Opaq payload2 -> Ord.compare payload1 payload2
+    compare_payloads(env, at_opaque, "Ord", "compare")
+}
+
+/// Builds `\@Opaq payload1, @Opaq payload2 -> Module.member payload1 payload2`, for ability
+/// members that take two values of the opaque type.
+fn compare_payloads<'a>(
+    env: &mut Env<'a>,
+    at_opaque: &'a str,
+    module_name: &'a str,
+    ident: &'a str,
+) -> ast::Expr<'a> {
     let alloc_pat = |it| env.arena.alloc(Loc::at(DERIVED_REGION, it));
     let alloc_expr = |it| env.arena.alloc(Loc::at(DERIVED_REGION, it));
 
@@ -184,12 +201,9 @@ fn is_eq<'a>(env: &mut Env<'a>, at_opaque: &'a str) -> ast::Expr<'a> {
             .alloc([Loc::at(DERIVED_REGION, ast::Pattern::Identifier(payload2))]),
     );
 
-    // Bool.isEq payload1 payload2
+    // Module.member payload1 payload2
     let call_member = alloc_expr(ast::Expr::Apply(
-        alloc_expr(ast::Expr::Var {
-            module_name: "Bool",
-            ident: "isEq",
-        }),
+        alloc_expr(ast::Expr::Var { module_name, ident }),
         &*env.arena.alloc([
             &*alloc_expr(ast::Expr::Var {
                 module_name: "",
@@ -203,7 +217,7 @@ fn is_eq<'a>(env: &mut Env<'a>, at_opaque: &'a str) -> ast::Expr<'a> {
         roc_module::called_via::CalledVia::Space,
     ));
 
-    // \@Opaq payload1, @Opaq payload2 -> Bool.isEq payload1 payload2
+    // \@Opaq payload1, @Opaq payload2 -> Module.member payload1 payload2
     ast::Expr::Closure(
         env.arena.alloc([
             Loc::at(DERIVED_REGION, opaque1),
@@ -232,6 +246,7 @@ pub(crate) fn synthesize_member_impl<'a>(
         Symbol::DECODE_DECODER => (format!("#{}_decoder", opaque_name), decoder(env, at_opaque)),
         Symbol::HASH_HASH => (format!("#{}_hash", opaque_name), hash(env, at_opaque)),
         Symbol::BOOL_IS_EQ => (format!("#{}_isEq", opaque_name), is_eq(env, at_opaque)),
+        Symbol::ORD_COMPARE => (format!("#{}_compare", opaque_name), compare(env, at_opaque)),
         other => internal_error!("{:?} is not a derivable ability member!", other),
     };
 
@@ -34,6 +34,10 @@ pub struct Env<'a> {
 
     pub top_level_symbols: VecSet<Symbol>,
 
+    /// Regions of the defs that define nothing but a single identifier,
+    /// so they can be removed entirely if that identifier goes unused.
+    pub removable_defs: MutMap<Symbol, Region>,
+
     pub arena: &'a Bump,
 }
 
@@ -55,6 +59,7 @@ impl<'a> Env<'a> {
             qualified_type_lookups: VecSet::default(),
             tailcallable_symbol: None,
             top_level_symbols: VecSet::default(),
+            removable_defs: MutMap::default(),
         }
     }
 
@@ -21,6 +21,7 @@ use roc_module::symbol::Symbol;
 use roc_parse::ast::{self, Defs, StrLiteral};
 use roc_parse::pattern::PatternType::*;
 use roc_problem::can::{PrecedenceProblem, Problem, RuntimeError};
+use roc_problem::Edit;
 use roc_region::all::{Loc, Region};
 use roc_types::num::SingleQuoteBound;
 use roc_types::subs::{ExhaustiveMark, IllegalCycleMark, RedundantMark, VarStore, Variable};
@@ -910,17 +911,9 @@ pub fn canonicalize_expr<'a>(
         ast::Expr::Var { module_name, ident } => {
             canonicalize_var_lookup(env, var_store, scope, module_name, ident, region)
         }
-        ast::Expr::Underscore(name) => {
-            // we parse underscores, but they are not valid expression syntax
-            let problem = roc_problem::can::RuntimeError::MalformedIdentifier(
-                (*name).into(),
-                roc_parse::ident::BadIdent::Underscore(region.start()),
-                region,
-            );
-
-            env.problem(Problem::RuntimeError(problem.clone()));
-
-            (RuntimeError(problem), Output::default())
+        ast::Expr::Underscore(_) => {
+            // `_` and `_name` are typed holes; their type is reported once the module is solved
+            (TypedHole(var_store.fresh()), Output::default())
         }
         ast::Expr::Crash => {
             // Naked crashes aren't allowed; we'll admit this with our own message, but yield an
@@ -1397,11 +1390,18 @@ fn canonicalize_closure_body<'a>(
     for (sub_symbol, region) in bound_by_argument_patterns {
         if !output.references.has_value_lookup(sub_symbol) {
             // The body never referenced this argument we declared. It's an unused argument!
+            // If it's a plain identifier, prefixing it with an underscore makes that explicit.
+            let is_identifier = can_args.iter().any(|(_, _, loc_pattern)| {
+                matches!(loc_pattern.value, Pattern::Identifier(s) if s == sub_symbol)
+            });
+            let fix = is_identifier.then(|| Edit::insert(region.start(), "_"));
+
             env.problem(Problem::UnusedArgument(
                 symbol,
                 is_anonymous,
                 sub_symbol,
                 region,
+                fix,
             ));
         } else {
             // We shouldn't ultimately count arguments as referenced locals. Otherwise,
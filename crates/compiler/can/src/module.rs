@@ -1,5 +1,5 @@
 use crate::abilities::{AbilitiesStore, ImplKey, PendingAbilitiesStore, ResolvedImpl};
-use crate::annotation::{canonicalize_annotation, AnnotationFor};
+use crate::annotation::{canonicalize_annotation, AnnotationFor, IntroducedVariables};
 use crate::def::{canonicalize_defs, Def};
 use crate::effect_module::HostedGeneratedFunctions;
 use crate::env::Env;
@@ -9,7 +9,7 @@ use crate::expr::{
 use crate::pattern::{BindingsFromPattern, Pattern};
 use crate::scope::Scope;
 use bumpalo::Bump;
-use roc_collections::{MutMap, SendMap, VecMap, VecSet};
+use roc_collections::{ImMap, MutMap, SendMap, VecMap, VecSet};
 use roc_error_macros::internal_error;
 use roc_module::ident::Ident;
 use roc_module::ident::Lowercase;
@@ -18,9 +18,10 @@ use roc_parse::ast::{Defs, TypeAnnotation};
 use roc_parse::header::HeaderType;
 use roc_parse::pattern::PatternType;
 use roc_problem::can::{Problem, RuntimeError};
+use roc_problem::Edit;
 use roc_region::all::{Loc, Region};
 use roc_types::subs::{ExposedTypesStorageSubs, Subs, VarStore, Variable};
-use roc_types::types::{AbilitySet, Alias, AliasKind, AliasVar, Type};
+use roc_types::types::{AbilitySet, Alias, AliasCommon, AliasKind, AliasVar, LambdaSet, Type};
 
 /// The types of all exposed values/functions of a collection of modules
 #[derive(Clone, Debug, Default)]
@@ -390,7 +391,9 @@ pub fn canonicalize_module_defs<'a>(
             && !scope.abilities_store.is_specialization_name(symbol)
             && !symbol.is_exposed_for_builtin_derivers()
         {
-            env.problem(Problem::UnusedDef(symbol, region));
+            let fix = env.removable_defs.get(&symbol).copied().map(Edit::remove);
+
+            env.problem(Problem::UnusedDef(symbol, region, fix));
         }
     }
 
@@ -449,6 +452,27 @@ pub fn canonicalize_module_defs<'a>(
         "I thought pending derives are only found during def introduction"
     );
 
+    if let HeaderType::Platform { .. } = header_type {
+        let mut closure_names = 0;
+
+        for index in 0..declarations.len() {
+            if !exposed_symbols.contains(&declarations.symbols[index].value) {
+                continue;
+            }
+
+            if let Some(annotation) = declarations.annotations[index].as_mut() {
+                expose_closures_to_host(
+                    &mut scope,
+                    var_store,
+                    home,
+                    annotation,
+                    &mut output.aliases,
+                    &mut closure_names,
+                );
+            }
+        }
+    }
+
     let symbols_from_requires = symbols_from_requires
         .iter()
         .map(|(symbol, loc_ann)| {
@@ -677,8 +701,22 @@ pub fn canonicalize_module_defs<'a>(
     // exposed_symbols and added to exposed_vars_by_symbol. If any were
     // not, that means they were declared as exposed but there was
     // no actual declaration with that name!
+    let exposes = header_type.exposed_or_provided_values();
+    let exposed_regions: Vec<Region> = exposes.iter().map(|exposed| exposed.region).collect();
+
     for symbol in exposed_but_not_defined {
-        env.problem(Problem::ExposedButNotDefined(symbol));
+        // The fix is to stop exposing it - unless it's what an app provides to its platform
+        let fix = match header_type {
+            HeaderType::App { .. } => None,
+            _ => exposes
+                .iter()
+                .position(|exposed| {
+                    scope.locals.ident_ids.get_id(exposed.value.as_str()) == Some(symbol.ident_id())
+                })
+                .map(|index| Edit::remove_from_collection(&exposed_regions, index)),
+        };
+
+        env.problem(Problem::ExposedButNotDefined(symbol, fix));
 
         // In case this exposed value is referenced by other modules,
         // create a decl for it whose implementation is a runtime error.
@@ -813,6 +851,205 @@ pub fn canonicalize_module_defs<'a>(
     }
 }
 
+/// The host can only call a closure it gets from a platform through the `roc__..._caller`
+/// function generated for a host-exposed alias, like `Fx` in `mainForHost : (Str -> Str) as Fx`.
+/// This gives every closure in the annotation of a value the platform provides to the host
+/// an alias like that, named `Closure1`, `Closure2` and so on, unless it already has one.
+fn expose_closures_to_host(
+    scope: &mut Scope,
+    var_store: &mut VarStore,
+    home: ModuleId,
+    annotation: &mut crate::def::Annotation,
+    aliases: &mut VecMap<Symbol, Alias>,
+    closure_names: &mut u32,
+) {
+    let mut exposer = ClosureExposer {
+        scope,
+        var_store,
+        home,
+        region: annotation.region,
+        introduced_variables: &mut annotation.introduced_variables,
+        aliases,
+        closure_names,
+    };
+
+    match &mut annotation.signature {
+        // A provided function is called directly, through `roc__mainForHost_1_exposed`
+        Type::Function(args, _, ret) => {
+            for arg in args.iter_mut() {
+                exposer.visit(arg);
+            }
+
+            exposer.visit(ret);
+        }
+        other => exposer.visit(other),
+    }
+}
+
+struct ClosureExposer<'a> {
+    scope: &'a mut Scope,
+    var_store: &'a mut VarStore,
+    home: ModuleId,
+    region: Region,
+    introduced_variables: &'a mut IntroducedVariables,
+    aliases: &'a mut VecMap<Symbol, Alias>,
+    closure_names: &'a mut u32,
+}
+
+impl ClosureExposer<'_> {
+    fn visit(&mut self, typ: &mut Type) {
+        match typ {
+            Type::Function(args, _, ret) => {
+                for arg in args.iter_mut() {
+                    self.visit(arg);
+                }
+
+                self.visit(ret);
+
+                let actual = std::mem::replace(typ, Type::EmptyRec);
+
+                *typ = self.host_exposed_alias(actual);
+            }
+            Type::HostExposedAlias { actual, .. } => match actual.as_mut() {
+                // This closure is already exposed, but the ones it takes or returns may not be
+                Type::Function(args, _, ret) => {
+                    for arg in args.iter_mut() {
+                        self.visit(arg);
+                    }
+
+                    self.visit(ret);
+                }
+                other => self.visit(other),
+            },
+            Type::Record(fields, _) => {
+                for (_, field) in fields.iter_mut() {
+                    self.visit(field.as_inner_mut());
+                }
+            }
+            Type::TagUnion(tags, _) | Type::RecursiveTagUnion(_, tags, _) => {
+                for (_, args) in tags.iter_mut() {
+                    for arg in args.iter_mut() {
+                        self.visit(arg);
+                    }
+                }
+            }
+            Type::Apply(_, args, _) => {
+                for arg in args.iter_mut() {
+                    self.visit(&mut arg.value);
+                }
+            }
+            Type::Alias {
+                actual,
+                kind: AliasKind::Structural,
+                ..
+            } => self.visit(actual),
+            Type::DelayedAlias(common) => {
+                if let Some(mut expanded) = self.expand_alias(common) {
+                    self.visit(&mut expanded);
+
+                    *typ = expanded;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Expands a use of one of this module's own aliases, so the closures in it can be exposed.
+    /// Aliases with type arguments or recursion are left alone, as are the ones without closures.
+    fn expand_alias(&self, common: &mut AliasCommon) -> Option<Type> {
+        let alias = self.scope.lookup_alias(common.symbol)?;
+
+        if common.symbol.module_id() != self.home
+            || alias.kind != AliasKind::Structural
+            || !alias.type_variables.is_empty()
+            || !alias.recursion_variables.is_empty()
+            || alias.lambda_set_variables.is_empty()
+        {
+            return None;
+        }
+
+        let mut substitutions = ImMap::default();
+
+        for (alias_lambda_set, lambda_set) in alias
+            .lambda_set_variables
+            .iter()
+            .zip(common.lambda_set_variables.iter())
+        {
+            if let Type::Variable(var) = alias_lambda_set.0 {
+                substitutions.insert(var, lambda_set.0.clone());
+            }
+        }
+
+        for (var, typ) in alias
+            .infer_ext_in_output_variables
+            .iter()
+            .zip(common.infer_ext_in_output_types.iter())
+        {
+            substitutions.insert(*var, typ.clone());
+        }
+
+        let mut actual = alias.typ.clone();
+        actual.substitute(&substitutions);
+
+        Some(Type::Alias {
+            symbol: common.symbol,
+            type_arguments: Vec::new(),
+            lambda_set_variables: std::mem::take(&mut common.lambda_set_variables),
+            infer_ext_in_output_types: std::mem::take(&mut common.infer_ext_in_output_types),
+            actual: Box::new(actual),
+            kind: AliasKind::Structural,
+        })
+    }
+
+    fn host_exposed_alias(&mut self, actual: Type) -> Type {
+        let name = loop {
+            *self.closure_names += 1;
+
+            let name = format!("Closure{}", self.closure_names);
+
+            if self.scope.locals.ident_ids.get_id(&name).is_none() {
+                break name;
+            }
+        };
+
+        let symbol = self
+            .scope
+            .scopeless_symbol(&Ident::from(name.as_str()), self.region);
+        let actual_var = self.var_store.fresh();
+
+        self.introduced_variables
+            .insert_host_exposed_alias(symbol, actual_var);
+
+        let lambda_set_variables: Vec<LambdaSet> = actual
+            .variables_detail()
+            .lambda_set_variables
+            .into_iter()
+            .map(|var| LambdaSet(Type::Variable(var)))
+            .collect();
+
+        self.aliases.insert(
+            symbol,
+            Alias {
+                region: self.region,
+                type_variables: Vec::new(),
+                lambda_set_variables: lambda_set_variables.clone(),
+                infer_ext_in_output_variables: Vec::new(),
+                recursion_variables: Default::default(),
+                typ: actual.clone(),
+                kind: AliasKind::Structural,
+            },
+        );
+
+        Type::HostExposedAlias {
+            name: symbol,
+            type_arguments: Vec::new(),
+            lambda_set_variables,
+            actual_var,
+            actual: Box::new(actual),
+        }
+    }
+}
+
 fn fix_values_captured_in_closure_def(
     def: &mut crate::def::Def,
     no_capture_symbols: &mut VecSet<Symbol>,
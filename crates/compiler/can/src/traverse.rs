@@ -679,3 +679,238 @@ pub fn symbols_introduced_from_pattern(
         }
     }
 }
+
+/// A typed hole `_` in expression position, along with the bindings in scope at that point.
+#[derive(Debug)]
+pub struct FoundHole {
+    pub region: Region,
+    pub var: Variable,
+    /// Bindings visible from the hole, innermost last. Definitions that are still being defined
+    /// where the hole is (for example, the function the hole is in) are not included.
+    pub in_scope: Vec<(Symbol, Variable)>,
+}
+
+/// Finds all typed holes in the declarations, in source order.
+pub fn find_typed_holes(decls: &Declarations) -> Vec<FoundHole> {
+    use crate::expr::DeclarationTag::*;
+
+    let mut visitor = HoleVisitor {
+        scope: Vec::new(),
+        defining: Vec::new(),
+        holes: Vec::new(),
+    };
+
+    // all top-level definitions are in scope everywhere
+    for (index, tag) in decls.declarations.iter().enumerate() {
+        match tag {
+            Value | Function(_) | Recursive(_) | TailRecursive(_) => {
+                let symbol = decls.symbols[index].value;
+                visitor.scope.push((symbol, decls.variables[index]));
+            }
+            Destructure(destructure_index) => {
+                let destructure = &decls.destructs[destructure_index.index() as usize];
+                pattern_bindings(
+                    &destructure.loc_pattern.value,
+                    Some(decls.variables[index]),
+                    &mut visitor.scope,
+                );
+            }
+            Expectation | ExpectationFx | MutualRecursion { .. } => {}
+        }
+    }
+
+    for (index, tag) in decls.declarations.iter().enumerate() {
+        let loc_expr = &decls.expressions[index];
+        let expr_var = decls.variables[index];
+
+        match tag {
+            Value | Destructure(_) => {
+                visitor.defining.push(decls.symbols[index].value);
+                visitor.visit_expr(&loc_expr.value, loc_expr.region, expr_var);
+                visitor.defining.pop();
+            }
+            Function(function_index)
+            | Recursive(function_index)
+            | TailRecursive(function_index) => {
+                let function_def = &decls.function_bodies[function_index.index() as usize];
+
+                visitor.defining.push(decls.symbols[index].value);
+                visitor.visit_closure(
+                    &function_def.value.arguments,
+                    loc_expr,
+                    function_def.value.return_type,
+                );
+                visitor.defining.pop();
+            }
+            Expectation | ExpectationFx => {
+                visitor.visit_expr(&loc_expr.value, loc_expr.region, Variable::BOOL);
+            }
+            MutualRecursion { .. } => {}
+        }
+    }
+
+    visitor.holes
+}
+
+struct HoleVisitor {
+    scope: Vec<(Symbol, Variable)>,
+    defining: Vec<Symbol>,
+    holes: Vec<FoundHole>,
+}
+
+impl HoleVisitor {
+    fn visit_closure(
+        &mut self,
+        arguments: &[(Variable, AnnotatedMark, Loc<Pattern>)],
+        loc_body: &Loc<Expr>,
+        return_type: Variable,
+    ) {
+        let scope_len = self.scope.len();
+
+        for (var, _, loc_pattern) in arguments {
+            pattern_bindings(&loc_pattern.value, Some(*var), &mut self.scope);
+        }
+        self.visit_expr(&loc_body.value, loc_body.region, return_type);
+
+        self.scope.truncate(scope_len);
+    }
+
+    fn visit_def_body(&mut self, def: &Def) {
+        let defining_len = self.defining.len();
+
+        self.defining.extend(def.pattern_vars.keys().copied());
+        self.visit_expr(&def.loc_expr.value, def.loc_expr.region, def.expr_var);
+
+        self.defining.truncate(defining_len);
+    }
+}
+
+impl Visitor for HoleVisitor {
+    fn visit_expr(&mut self, expr: &Expr, region: Region, var: Variable) {
+        let scope_len = self.scope.len();
+
+        match expr {
+            Expr::TypedHole(hole_var) => {
+                let in_scope = self
+                    .scope
+                    .iter()
+                    .filter(|(symbol, _)| !self.defining.contains(symbol))
+                    .copied()
+                    .collect();
+
+                self.holes.push(FoundHole {
+                    region,
+                    var: *hole_var,
+                    in_scope,
+                });
+            }
+            Expr::Closure(ClosureData {
+                arguments,
+                loc_body,
+                return_type,
+                ..
+            }) => self.visit_closure(arguments, loc_body, *return_type),
+            Expr::LetNonRec(def, body) => {
+                self.visit_def_body(def);
+
+                self.scope
+                    .extend(def.pattern_vars.iter().map(|(s, v)| (*s, *v)));
+                self.visit_expr(&body.value, body.region, var);
+            }
+            Expr::LetRec(defs, body, _cycle_mark) => {
+                for def in defs {
+                    self.scope
+                        .extend(def.pattern_vars.iter().map(|(s, v)| (*s, *v)));
+                }
+
+                for def in defs {
+                    self.visit_def_body(def);
+                }
+                self.visit_expr(&body.value, body.region, var);
+            }
+            Expr::When {
+                cond_var,
+                expr_var,
+                loc_cond,
+                branches,
+                ..
+            } => {
+                self.visit_expr(&loc_cond.value, loc_cond.region, *cond_var);
+
+                for branch in branches {
+                    // every pattern of a branch binds the same symbols
+                    if let Some(pattern) = branch.patterns.first() {
+                        pattern_bindings(&pattern.pattern.value, Some(*cond_var), &mut self.scope);
+                    }
+
+                    if let Some(guard) = &branch.guard {
+                        self.visit_expr(&guard.value, guard.region, Variable::BOOL);
+                    }
+                    self.visit_expr(&branch.value.value, branch.value.region, *expr_var);
+
+                    self.scope.truncate(scope_len);
+                }
+            }
+            _ => walk_expr(self, expr, var),
+        }
+
+        self.scope.truncate(scope_len);
+    }
+}
+
+/// The symbols a pattern binds, with their variables, where they are known.
+fn pattern_bindings(
+    pattern: &Pattern,
+    opt_var: Option<Variable>,
+    bindings: &mut Vec<(Symbol, Variable)>,
+) {
+    use Pattern::*;
+
+    match pattern {
+        Identifier(symbol) | AbilityMemberSpecialization { ident: symbol, .. } => {
+            bindings.extend(opt_var.map(|var| (*symbol, var)));
+        }
+        As(subpattern, symbol) => {
+            bindings.extend(opt_var.map(|var| (*symbol, var)));
+            pattern_bindings(&subpattern.value, opt_var, bindings);
+        }
+        AppliedTag { arguments, .. } => {
+            for (var, loc_pattern) in arguments {
+                pattern_bindings(&loc_pattern.value, Some(*var), bindings);
+            }
+        }
+        UnwrappedOpaque { argument, .. } => {
+            let (var, loc_pattern) = &**argument;
+            pattern_bindings(&loc_pattern.value, Some(*var), bindings);
+        }
+        RecordDestructure { destructs, .. } => {
+            for destruct in destructs {
+                match &destruct.value.typ {
+                    DestructType::Guard(var, loc_pattern) => {
+                        pattern_bindings(&loc_pattern.value, Some(*var), bindings);
+                    }
+                    DestructType::Required | DestructType::Optional(..) => {
+                        bindings.push((destruct.value.symbol, destruct.value.var));
+                    }
+                }
+            }
+        }
+        List {
+            patterns, elem_var, ..
+        } => {
+            for loc_pattern in patterns.patterns.iter() {
+                pattern_bindings(&loc_pattern.value, Some(*elem_var), bindings);
+            }
+        }
+        NumLiteral(..)
+        | IntLiteral(..)
+        | FloatLiteral(..)
+        | StrLiteral(..)
+        | SingleQuote(..)
+        | Underscore
+        | Shadowed(..)
+        | OpaqueNotInScope(..)
+        | UnsupportedPattern(..)
+        | MalformedPattern(..) => {}
+    }
+}
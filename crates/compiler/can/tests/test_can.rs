@@ -546,7 +546,7 @@ mod test_can {
         assert_eq!(problems.len(), 1);
         assert!(problems
             .iter()
-            .all(|problem| matches!(problem, Problem::UnusedDef(_, _))));
+            .all(|problem| matches!(problem, Problem::UnusedDef(_, _, _))));
     }
 
     #[test]
@@ -568,7 +568,7 @@ mod test_can {
         assert_eq!(problems.len(), 2);
         assert!(problems
             .iter()
-            .all(|problem| matches!(problem, Problem::UnusedDef(_, _))));
+            .all(|problem| matches!(problem, Problem::UnusedDef(_, _, _))));
     }
     // LOCALS
 
@@ -738,7 +738,7 @@ mod test_can {
         assert_eq!(problems, Vec::new());
         assert!(problems
             .iter()
-            .all(|problem| matches!(problem, Problem::UnusedDef(_, _))));
+            .all(|problem| matches!(problem, Problem::UnusedDef(_, _, _))));
 
         let actual = loc_expr.value;
 
@@ -785,7 +785,7 @@ mod test_can {
     //     // There should be two UnusedDef problems: one for h, and one for p
     //     assert_eq!(problems.len(), 2);
     //     assert!(problems.iter().all(|problem| match problem {
-    //         Problem::UnusedDef(_, _) => true,
+    //         Problem::UnusedDef(_, _, _) => true,
     //         _ => false,
     //     }));
 
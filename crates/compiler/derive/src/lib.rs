@@ -19,6 +19,7 @@ use util::Env;
 mod decoding;
 mod encoding;
 mod hash;
+mod ord;
 
 mod util;
 
@@ -79,6 +80,7 @@ fn build_derived_body(
             decoding::derive_decoder(&mut env, decoder_key, derived_symbol)
         }
         DeriveKey::Hash(hash_key) => hash::derive_hash(&mut env, hash_key, derived_symbol),
+        DeriveKey::Compare(ord_key) => ord::derive_compare(&mut env, ord_key, derived_symbol),
     };
 
     let def = Def {
@@ -0,0 +1,581 @@
+//! Derivers for the `Ord` ability.
+
+use std::iter::once;
+
+use roc_can::{
+    expr::{AnnotatedMark, ClosureData, Expr, Recursive, WhenBranch, WhenBranchPattern},
+    pattern::Pattern,
+};
+use roc_derive_key::ord::FlatOrdKey;
+use roc_module::{
+    called_via::CalledVia,
+    ident::{Lowercase, TagName},
+    symbol::Symbol,
+};
+use roc_region::all::{Loc, Region};
+use roc_types::{
+    subs::{
+        Content, ExhaustiveMark, FlatType, GetSubsSlice, LambdaSet, OptVariable, RecordFields,
+        RedundantMark, SubsIndex, SubsSlice, TagExt, UnionLambdas, UnionTags, Variable,
+        VariableSubsSlice,
+    },
+    types::RecordField,
+};
+
+use crate::{synth_var, util::Env, DerivedBody};
+
+pub(crate) fn derive_compare(
+    env: &mut Env<'_>,
+    key: FlatOrdKey,
+    def_symbol: Symbol,
+) -> DerivedBody {
+    let (body_type, body) = match key {
+        FlatOrdKey::Record(fields) => compare_record(env, def_symbol, fields),
+        FlatOrdKey::TagUnion(tags) => {
+            if tags.len() == 1 {
+                compare_newtype_tag_union(env, def_symbol, tags.into_iter().next().unwrap())
+            } else {
+                compare_tag_union(env, def_symbol, tags)
+            }
+        }
+    };
+
+    let specialization_lambda_sets =
+        env.get_specialization_lambda_sets(body_type, Symbol::ORD_COMPARE);
+
+    DerivedBody {
+        body,
+        body_type,
+        specialization_lambda_sets,
+    }
+}
+
+fn compare_record(env: &mut Env<'_>, fn_name: Symbol, fields: Vec<Lowercase>) -> (Variable, Expr) {
+    // Suppose rcd = { f1, ..., fn }.
+    // Build a generalized type t_rcd = { f1: t1, ..., fn: tn }, with fresh t1, ..., tn,
+    // so that we can re-use the derived impl for many records of the same fields.
+    let (record_var, record_fields) = {
+        let flex_fields = fields
+            .into_iter()
+            .map(|name| {
+                (
+                    name,
+                    RecordField::Required(env.subs.fresh_unnamed_flex_var()),
+                )
+            })
+            .collect::<Vec<(Lowercase, _)>>();
+        let fields = RecordFields::insert_into_subs(env.subs, flex_fields);
+        let record_var = synth_var(
+            env.subs,
+            Content::Structure(FlatType::Record(fields, Variable::EMPTY_RECORD)),
+        );
+
+        (record_var, fields)
+    };
+
+    // Now, a comparator for this record is
+    //
+    // compare_rcd : { f1: t1, ..., fn: tn }, { f1: t1, ..., fn: tn } -> [LT, EQ, GT]
+    // compare_rcd = \rcd1, rcd2 ->
+    //   when Ord.compare rcd1.f1 rcd2.f1 is
+    //     EQ ->
+    //       ...
+    //         Ord.compare rcd1.fn rcd2.fn
+    //     order -> order
+    //
+    // where the fields are visited in the (sorted) order of their names.
+    let rcd1_sym = env.new_symbol("rcd1");
+    let rcd2_sym = env.new_symbol("rcd2");
+
+    let order_var = order_var(env);
+
+    let pairs = record_fields
+        .iter_all()
+        .map(|(field_name, field_var, _)| {
+            let field_name = env.subs[field_name].clone();
+            let field_var = env.subs[field_var];
+
+            let mut access = |rcd_sym| Expr::Access {
+                record_var,
+                field_var,
+                ext_var: env.subs.fresh_unnamed_flex_var(),
+                loc_expr: Box::new(Loc::at_zero(Expr::Var(
+                    rcd_sym,
+                    env.subs.fresh_unnamed_flex_var(),
+                ))),
+                field: field_name.clone(),
+            };
+
+            (field_var, access(rcd1_sym), access(rcd2_sym))
+        })
+        .collect();
+
+    let body = compare_lexicographic(env, order_var, pairs);
+
+    // Finally, build the closure
+    // \rcd1, rcd2 -> body
+    build_outer_derived_closure(
+        env,
+        fn_name,
+        (record_var, Pattern::Identifier(rcd1_sym)),
+        (record_var, Pattern::Identifier(rcd2_sym)),
+        (order_var, body),
+    )
+}
+
+/// Build a `compare` implementation for a non-singleton tag union.
+fn compare_tag_union(
+    env: &mut Env<'_>,
+    fn_name: Symbol,
+    tags: Vec<(TagName, u16)>,
+) -> (Variable, Expr) {
+    // Suppose tags = [ A p11 .. p1n, ..., Q pq1 .. pqm ]
+    // Build a generalized type t_tags = [ A t11 .. t1n, ..., Q tq1 .. tqm ],
+    // with fresh t1, ..., tqm, so that we can re-use the derived impl for many
+    // unions of the same tags and payloads.
+    let (union_var, union_tags) = {
+        let flex_tag_labels = tags
+            .into_iter()
+            .map(|(label, arity)| {
+                let variables_slice = VariableSubsSlice::reserve_into_subs(env.subs, arity.into());
+                for var_index in variables_slice {
+                    env.subs[var_index] = env.subs.fresh_unnamed_flex_var();
+                }
+                (label, variables_slice)
+            })
+            .collect::<Vec<_>>();
+        let union_tags = UnionTags::insert_slices_into_subs(env.subs, flex_tag_labels);
+        let tag_union_var = synth_var(
+            env.subs,
+            Content::Structure(FlatType::TagUnion(
+                union_tags,
+                TagExt::Any(Variable::EMPTY_TAG_UNION),
+            )),
+        );
+
+        (tag_union_var, union_tags)
+    };
+
+    // Now, a comparator for this tag union is
+    //
+    // compare_union : [ A t11 .. t1n, ..., Q tq1 .. tqm ], [ A t11 .. t1n, ..., Q tq1 .. tqm ] -> [LT, EQ, GT]
+    // compare_union = \union1, union2 ->
+    //   when union1 is
+    //     A x11 .. x1n ->
+    //       when union2 is
+    //         A y11 .. y1n -> <compare x11 .. x1n against y11 .. y1n, like a record>
+    //         B _ .. _ | ... | Q _ .. _ -> LT
+    //     ...
+    //     Q xq1 .. xqm ->
+    //       when union2 is
+    //         Q yq1 .. yqm -> <compare xq1 .. xqm against yq1 .. yqm, like a record>
+    //         A _ .. _ | ... | P _ .. _ -> GT
+    //
+    // That is, tags are ordered by their (sorted) names, and only tags of the same name compare
+    // their payloads.
+    let union1_sym = env.new_symbol("union1");
+    let union2_sym = env.new_symbol("union2");
+
+    let order_var = order_var(env);
+
+    let tags: Vec<(TagName, Vec<Variable>)> = union_tags
+        .iter_all()
+        .map(|(tag, payloads)| {
+            let tag_name = env.subs[tag].clone();
+            let payload_vars = env.subs.get_subs_slice(env.subs[payloads]).to_vec();
+            (tag_name, payload_vars)
+        })
+        .collect();
+
+    let ignore_tag_pattern = |(tag_name, payload_vars): &(TagName, Vec<Variable>)| {
+        let arguments = payload_vars
+            .iter()
+            .map(|var| (*var, Loc::at_zero(Pattern::Underscore)))
+            .collect();
+        WhenBranchPattern {
+            pattern: Loc::at_zero(tag_pattern(union_var, tag_name.clone(), arguments)),
+            degenerate: false,
+        }
+    };
+
+    let branches = tags
+        .iter()
+        .enumerate()
+        .map(|(discr_n, (tag_name, payload_vars))| {
+            // x11 .. x1n
+            let payload1_syms: Vec<_> = std::iter::repeat_with(|| env.unique_symbol())
+                .take(payload_vars.len())
+                .collect();
+            // y11 .. y1n
+            let payload2_syms: Vec<_> = std::iter::repeat_with(|| env.unique_symbol())
+                .take(payload_vars.len())
+                .collect();
+
+            let bind_tag_pattern = |syms: &[Symbol]| {
+                let arguments = (payload_vars.iter())
+                    .zip(syms.iter())
+                    .map(|(var, sym)| (*var, Loc::at_zero(Pattern::Identifier(*sym))))
+                    .collect();
+                WhenBranchPattern {
+                    pattern: Loc::at_zero(tag_pattern(union_var, tag_name.clone(), arguments)),
+                    degenerate: false,
+                }
+            };
+
+            // `A y11 .. y1n -> <compare payloads>`
+            let pairs = (payload_vars.iter())
+                .zip(payload1_syms.iter().zip(payload2_syms.iter()))
+                .map(|(var, (sym1, sym2))| (*var, Expr::Var(*sym1, *var), Expr::Var(*sym2, *var)))
+                .collect();
+            let same_tag_branch = WhenBranch {
+                patterns: vec![bind_tag_pattern(&payload2_syms)],
+                value: Loc::at_zero(compare_lexicographic(env, order_var, pairs)),
+                guard: None,
+                redundant: RedundantMark::known_non_redundant(),
+            };
+
+            // `A _ .. _ | ... -> GT` for the tags before this one, and
+            // `B _ .. _ | ... -> LT` for the tags after it.
+            let (before, after) = (&tags[..discr_n], &tags[discr_n + 1..]);
+            let other_tag_branches = [(before, "GT"), (after, "LT")]
+                .into_iter()
+                .filter(|(others, _)| !others.is_empty())
+                .map(|(others, order)| WhenBranch {
+                    patterns: others.iter().map(&ignore_tag_pattern).collect(),
+                    value: Loc::at_zero(order_tag(order_var, order)),
+                    guard: None,
+                    redundant: RedundantMark::known_non_redundant(),
+                });
+
+            let inner_when = Expr::When {
+                loc_cond: Box::new(Loc::at_zero(Expr::Var(union2_sym, union_var))),
+                cond_var: union_var,
+                expr_var: order_var,
+                region: Region::zero(),
+                branches: once(same_tag_branch).chain(other_tag_branches).collect(),
+                branches_cond_var: union_var,
+                exhaustive: ExhaustiveMark::known_exhaustive(),
+            };
+
+            WhenBranch {
+                patterns: vec![bind_tag_pattern(&payload1_syms)],
+                value: Loc::at_zero(inner_when),
+                guard: None,
+                redundant: RedundantMark::known_non_redundant(),
+            }
+        })
+        .collect();
+
+    // when union1 is
+    //   ...
+    let when_expr = Expr::When {
+        loc_cond: Box::new(Loc::at_zero(Expr::Var(union1_sym, union_var))),
+        cond_var: union_var,
+        expr_var: order_var,
+        region: Region::zero(),
+        branches,
+        branches_cond_var: union_var,
+        exhaustive: ExhaustiveMark::known_exhaustive(),
+    };
+
+    // Finally, build the closure
+    // \union1, union2 -> body
+    build_outer_derived_closure(
+        env,
+        fn_name,
+        (union_var, Pattern::Identifier(union1_sym)),
+        (union_var, Pattern::Identifier(union2_sym)),
+        (order_var, when_expr),
+    )
+}
+
+/// Build a `compare` implementation for a newtype (singleton) tag union.
+/// If a tag union is a newtype, both values always have the same tag, so only the payloads need
+/// to be compared.
+fn compare_newtype_tag_union(
+    env: &mut Env<'_>,
+    fn_name: Symbol,
+    tag: (TagName, u16),
+) -> (Variable, Expr) {
+    // Suppose tags = [ A p1 .. pn ]
+    // Build a generalized type t_tags = [ A t1 .. tn ],
+    // with fresh t1, ..., tn, so that we can re-use the derived impl for many
+    // unions of the same tag and payload arity.
+    let (union_var, tag_name, payload_vars) = {
+        let (label, arity) = tag;
+
+        let variables_slice = VariableSubsSlice::reserve_into_subs(env.subs, arity.into());
+        for var_index in variables_slice {
+            env.subs[var_index] = env.subs.fresh_unnamed_flex_var();
+        }
+
+        let variables_slices_slice =
+            SubsSlice::extend_new(&mut env.subs.variable_slices, [variables_slice]);
+        let tag_name_index = SubsIndex::push_new(&mut env.subs.tag_names, label.clone());
+
+        let union_tags = UnionTags::from_slices(tag_name_index.as_slice(), variables_slices_slice);
+        let tag_union_var = synth_var(
+            env.subs,
+            Content::Structure(FlatType::TagUnion(
+                union_tags,
+                TagExt::Any(Variable::EMPTY_TAG_UNION),
+            )),
+        );
+
+        (
+            tag_union_var,
+            label,
+            env.subs.get_subs_slice(variables_slice).to_vec(),
+        )
+    };
+
+    // Now, a comparator for this tag union is
+    //
+    // compare_union : [ A t1 .. tn ], [ A t1 .. tn ] -> [LT, EQ, GT]
+    // compare_union = \A x1 .. xn, A y1 .. yn ->
+    //   <compare x1 .. xn against y1 .. yn, like a record>
+    let order_var = order_var(env);
+
+    // x1 .. xn
+    let payload1_syms: Vec<_> = std::iter::repeat_with(|| env.unique_symbol())
+        .take(payload_vars.len())
+        .collect();
+    // y1 .. yn
+    let payload2_syms: Vec<_> = std::iter::repeat_with(|| env.unique_symbol())
+        .take(payload_vars.len())
+        .collect();
+
+    let bind_tag_pattern = |syms: &[Symbol]| {
+        let arguments = (payload_vars.iter())
+            .zip(syms.iter())
+            .map(|(var, sym)| (*var, Loc::at_zero(Pattern::Identifier(*sym))))
+            .collect();
+        tag_pattern(union_var, tag_name.clone(), arguments)
+    };
+    let pattern1 = bind_tag_pattern(&payload1_syms);
+    let pattern2 = bind_tag_pattern(&payload2_syms);
+
+    let pairs = (payload_vars.iter())
+        .zip(payload1_syms.iter().zip(payload2_syms.iter()))
+        .map(|(var, (sym1, sym2))| (*var, Expr::Var(*sym1, *var), Expr::Var(*sym2, *var)))
+        .collect();
+    let body = compare_lexicographic(env, order_var, pairs);
+
+    // Finally, build the closure
+    // \A x1 .. xn, A y1 .. yn -> body
+    build_outer_derived_closure(
+        env,
+        fn_name,
+        (union_var, pattern1),
+        (union_var, pattern2),
+        (order_var, body),
+    )
+}
+
+/// Builds the closed `[EQ, GT, LT]` type that `compare` returns.
+fn order_var(env: &mut Env<'_>) -> Variable {
+    let no_payload: [Variable; 0] = [];
+    let order_tags = UnionTags::insert_into_subs(
+        env.subs,
+        [
+            ("EQ".into(), no_payload),
+            ("GT".into(), no_payload),
+            ("LT".into(), no_payload),
+        ],
+    );
+
+    synth_var(
+        env.subs,
+        Content::Structure(FlatType::TagUnion(
+            order_tags,
+            TagExt::Any(Variable::EMPTY_TAG_UNION),
+        )),
+    )
+}
+
+/// `EQ`, `GT` or `LT`
+fn order_tag(order_var: Variable, order: &str) -> Expr {
+    Expr::Tag {
+        tag_union_var: order_var,
+        ext_var: Variable::EMPTY_TAG_UNION,
+        name: order.into(),
+        arguments: Vec::new(),
+    }
+}
+
+fn tag_pattern(
+    union_var: Variable,
+    tag_name: TagName,
+    arguments: Vec<(Variable, Loc<Pattern>)>,
+) -> Pattern {
+    Pattern::AppliedTag {
+        whole_var: union_var,
+        tag_name,
+        ext_var: Variable::EMPTY_TAG_UNION,
+        arguments,
+    }
+}
+
+/// Compares pairs of values in order, stopping at the first pair that is not equal:
+///
+///   when Ord.compare l1 r1 is
+///     EQ ->
+///       ...
+///         Ord.compare ln rn
+///     order -> order
+///
+/// If there are no pairs to compare, the result is `EQ`.
+fn compare_lexicographic(
+    env: &mut Env<'_>,
+    order_var: Variable,
+    pairs: Vec<(Variable, Expr, Expr)>,
+) -> Expr {
+    let mut pairs = pairs.into_iter().rev();
+
+    let last = match pairs.next() {
+        Some(pair) => call_ord_compare(env, order_var, pair),
+        None => return order_tag(order_var, "EQ"),
+    };
+
+    pairs.fold(last, |rest, pair| {
+        let compare = call_ord_compare(env, order_var, pair);
+        let order_sym = env.new_symbol("order");
+
+        // EQ -> rest
+        let eq_branch = WhenBranch {
+            patterns: vec![WhenBranchPattern {
+                pattern: Loc::at_zero(tag_pattern(order_var, "EQ".into(), vec![])),
+                degenerate: false,
+            }],
+            value: Loc::at_zero(rest),
+            guard: None,
+            redundant: RedundantMark::known_non_redundant(),
+        };
+        // order -> order
+        let other_branch = WhenBranch {
+            patterns: vec![WhenBranchPattern {
+                pattern: Loc::at_zero(Pattern::Identifier(order_sym)),
+                degenerate: false,
+            }],
+            value: Loc::at_zero(Expr::Var(order_sym, order_var)),
+            guard: None,
+            redundant: RedundantMark::known_non_redundant(),
+        };
+
+        Expr::When {
+            loc_cond: Box::new(Loc::at_zero(compare)),
+            cond_var: order_var,
+            expr_var: order_var,
+            region: Region::zero(),
+            branches: vec![eq_branch, other_branch],
+            branches_cond_var: order_var,
+            exhaustive: ExhaustiveMark::known_exhaustive(),
+        }
+    })
+}
+
+fn call_ord_compare(
+    env: &mut Env<'_>,
+    order_var: Variable,
+    (val_var, lhs_expr, rhs_expr): (Variable, Expr, Expr),
+) -> Expr {
+    // build `Ord.compare` function type
+    //
+    // val, val -[uls]-> [LT, EQ, GT] | val has Ord
+    let exposed_compare_fn_var = env.import_builtin_symbol_var(Symbol::ORD_COMPARE);
+
+    // (typeof val), (typeof val) -[clos]-> [EQ, GT, LT]
+    let this_arguments_slice = VariableSubsSlice::insert_into_subs(env.subs, [val_var, val_var]);
+    let this_compare_clos_var = env.subs.fresh_unnamed_flex_var();
+    let this_compare_fn_var = synth_var(
+        env.subs,
+        Content::Structure(FlatType::Func(
+            this_arguments_slice,
+            this_compare_clos_var,
+            order_var,
+        )),
+    );
+
+    //   val,          val          -[uls]->  [LT, EQ, GT] | val has Ord
+    // ~ (typeof val), (typeof val) -[clos]-> [EQ, GT, LT]
+    env.unify(exposed_compare_fn_var, this_compare_fn_var);
+
+    // Ord.compare : (typeof val), (typeof val) -[clos]-> [LT, EQ, GT] | (typeof val) has Ord
+    let compare_fn_head = Expr::AbilityMember(Symbol::ORD_COMPARE, None, this_compare_fn_var);
+    let compare_fn_data = Box::new((
+        this_compare_fn_var,
+        Loc::at_zero(compare_fn_head),
+        this_compare_clos_var,
+        order_var,
+    ));
+
+    let compare_arguments = vec![
+        (val_var, Loc::at_zero(lhs_expr)),
+        (val_var, Loc::at_zero(rhs_expr)),
+    ];
+
+    Expr::Call(compare_fn_data, compare_arguments, CalledVia::Space)
+}
+
+fn build_outer_derived_closure(
+    env: &mut Env<'_>,
+    fn_name: Symbol,
+    lhs: (Variable, Pattern),
+    rhs: (Variable, Pattern),
+    body: (Variable, Expr),
+) -> (Variable, Expr) {
+    let (lhs_var, lhs_pattern) = lhs;
+    let (rhs_var, rhs_pattern) = rhs;
+    let (body_var, body_expr) = body;
+
+    let (fn_var, fn_clos_var) = {
+        // Create fn_var for ambient capture; we fix it up below.
+        let fn_var = synth_var(env.subs, Content::Error);
+
+        // -[fn_name]->
+        let fn_captures = vec![];
+        let fn_name_labels = UnionLambdas::insert_into_subs(env.subs, once((fn_name, fn_captures)));
+        let fn_clos_var = synth_var(
+            env.subs,
+            Content::LambdaSet(LambdaSet {
+                solved: fn_name_labels,
+                recursion_var: OptVariable::NONE,
+                unspecialized: SubsSlice::default(),
+                ambient_function: fn_var,
+            }),
+        );
+
+        // lhs_var, rhs_var -[fn_name]-> body_var
+        let args_slice = SubsSlice::insert_into_subs(env.subs, [lhs_var, rhs_var]);
+        env.subs.set_content(
+            fn_var,
+            Content::Structure(FlatType::Func(args_slice, fn_clos_var, body_var)),
+        );
+
+        (fn_var, fn_clos_var)
+    };
+
+    let clos_expr = Expr::Closure(ClosureData {
+        function_type: fn_var,
+        closure_type: fn_clos_var,
+        return_type: body_var,
+        name: fn_name,
+        captured_symbols: vec![],
+        recursive: Recursive::NotRecursive,
+        arguments: vec![
+            (
+                lhs_var,
+                AnnotatedMark::known_exhaustive(),
+                Loc::at_zero(lhs_pattern),
+            ),
+            (
+                rhs_var,
+                AnnotatedMark::known_exhaustive(),
+                Loc::at_zero(rhs_pattern),
+            ),
+        ],
+        loc_body: Box::new(Loc::at_zero(body_expr)),
+    });
+
+    (fn_var, clos_expr)
+}
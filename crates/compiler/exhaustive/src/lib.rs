@@ -159,6 +159,15 @@ impl Error {
             Error::Unmatchable { .. } => Warning,
         }
     }
+
+    /// A stable name for this kind of problem, like `redundant-pattern`
+    pub fn name(&self) -> &'static str {
+        match self {
+            Error::Incomplete(..) => "non-exhaustive-pattern",
+            Error::Redundant { .. } => "redundant-pattern",
+            Error::Unmatchable { .. } => "unmatchable-pattern",
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
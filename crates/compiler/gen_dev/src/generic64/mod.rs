@@ -9,7 +9,8 @@ use roc_error_macros::internal_error;
 use roc_module::symbol::{Interns, ModuleId, Symbol};
 use roc_mono::code_gen_help::CodeGenHelp;
 use roc_mono::ir::{
-    BranchInfo, JoinPointId, ListLiteralElement, Literal, Param, ProcLayout, SelfRecursive, Stmt,
+    BranchInfo, CrashTag, JoinPointId, ListLiteralElement, Literal, Param, ProcLayout,
+    SelfRecursive, Stmt,
 };
 use roc_mono::layout::{
     Builtin, InLayout, Layout, LayoutInterner, STLayoutInterner, TagIdIntType, UnionLayout,
@@ -23,9 +24,12 @@ mod disassembler_test_macro;
 pub(crate) mod storage;
 pub(crate) mod x86_64;
 
-use storage::{RegStorage, StorageManager};
+use storage::{is_primitive, RegStorage, StorageManager};
 
 const REFCOUNT_ONE: u64 = i64::MIN as u64;
+// On 64 bit targets, unions with fewer than 8 tags store the tag id in the low bits of the pointer.
+const TAG_ID_POINTER_MASK: i64 = 0b111;
+const TAG_ID_POINTER_CLEAR_MASK: i64 = !TAG_ID_POINTER_MASK;
 // TODO: on all number functions double check and deal with over/underflow.
 
 pub trait CallConv<GeneralReg: RegTrait, FloatReg: RegTrait, ASM: Assembler<GeneralReg, FloatReg>>:
@@ -128,6 +132,12 @@ pub trait Assembler<GeneralReg: RegTrait, FloatReg: RegTrait>: Sized + Copy {
         dst: FloatReg,
         src: FloatReg,
     );
+    fn abs_freg32_freg32(
+        buf: &mut Vec<'_, u8>,
+        relocs: &mut Vec<'_, Relocation>,
+        dst: FloatReg,
+        src: FloatReg,
+    );
 
     fn add_reg64_reg64_imm32(buf: &mut Vec<'_, u8>, dst: GeneralReg, src1: GeneralReg, imm32: i32);
     fn add_freg32_freg32_freg32(
@@ -202,6 +212,13 @@ pub trait Assembler<GeneralReg: RegTrait, FloatReg: RegTrait>: Sized + Copy {
         imm: f64,
     );
     fn mov_reg64_imm64(buf: &mut Vec<'_, u8>, dst: GeneralReg, imm: i64);
+    /// Load the address of a copy of `data` that is stored alongside the procedure
+    fn mov_reg64_local_data_address(
+        buf: &mut Vec<'_, u8>,
+        relocs: &mut Vec<'_, Relocation>,
+        dst: GeneralReg,
+        data: std::vec::Vec<u8>,
+    );
     fn mov_freg64_freg64(buf: &mut Vec<'_, u8>, dst: FloatReg, src: FloatReg);
     fn mov_reg64_reg64(buf: &mut Vec<'_, u8>, dst: GeneralReg, src: GeneralReg);
 
@@ -224,6 +241,35 @@ pub trait Assembler<GeneralReg: RegTrait, FloatReg: RegTrait>: Sized + Copy {
         src: GeneralReg,
     );
 
+    /// Sign extends the data at `src + offset` with `size` as it copies it to `dst`
+    /// size must be less than or equal to 8.
+    fn movsx_reg64_mem64_offset32(
+        buf: &mut Vec<'_, u8>,
+        dst: GeneralReg,
+        src: GeneralReg,
+        offset: i32,
+        size: u8,
+    );
+    /// Zero extends the data at `src + offset` with `size` as it copies it to `dst`
+    /// size must be less than or equal to 8.
+    fn movzx_reg64_mem64_offset32(
+        buf: &mut Vec<'_, u8>,
+        dst: GeneralReg,
+        src: GeneralReg,
+        offset: i32,
+        size: u8,
+    );
+
+    /// Stores the low `size` bytes of `src` to `dst + offset`, leaving the bytes after them untouched.
+    /// size must be less than or equal to 8.
+    fn movtrunc_mem64_offset32_reg64(
+        buf: &mut Vec<'_, u8>,
+        dst: GeneralReg,
+        offset: i32,
+        src: GeneralReg,
+        size: u8,
+    );
+
     /// Sign extends the data at `offset` with `size` as it copies it to `dst`
     /// size must be less than or equal to 8.
     fn movsx_reg64_base32(buf: &mut Vec<'_, u8>, dst: GeneralReg, offset: i32, size: u8);
@@ -237,6 +283,24 @@ pub trait Assembler<GeneralReg: RegTrait, FloatReg: RegTrait>: Sized + Copy {
     fn mov_stack32_reg64(buf: &mut Vec<'_, u8>, offset: i32, src: GeneralReg);
 
     fn neg_reg64_reg64(buf: &mut Vec<'_, u8>, dst: GeneralReg, src: GeneralReg);
+    fn neg_freg64_freg64(
+        buf: &mut Vec<'_, u8>,
+        relocs: &mut Vec<'_, Relocation>,
+        dst: FloatReg,
+        src: FloatReg,
+    );
+    fn neg_freg32_freg32(
+        buf: &mut Vec<'_, u8>,
+        relocs: &mut Vec<'_, Relocation>,
+        dst: FloatReg,
+        src: FloatReg,
+    );
+    /// Reverses the order of the bytes of `src`, storing the result in `dst`.
+    fn bswap_reg64_reg64(buf: &mut Vec<'_, u8>, dst: GeneralReg, src: GeneralReg);
+    /// Reverses the order of the low 4 bytes of `src`, storing them in the low 4 bytes of `dst`.
+    fn bswap_reg32_reg32(buf: &mut Vec<'_, u8>, dst: GeneralReg, src: GeneralReg);
+    /// Reverses the order of the low 2 bytes of `src`, storing them in the low 2 bytes of `dst`.
+    fn bswap_reg16_reg16(buf: &mut Vec<'_, u8>, dst: GeneralReg, src: GeneralReg);
     fn mul_freg32_freg32_freg32(
         buf: &mut Vec<'_, u8>,
         dst: FloatReg,
@@ -304,6 +368,19 @@ pub trait Assembler<GeneralReg: RegTrait, FloatReg: RegTrait>: Sized + Copy {
         src2: GeneralReg,
     );
 
+    fn sub_freg32_freg32_freg32(
+        buf: &mut Vec<'_, u8>,
+        dst: FloatReg,
+        src1: FloatReg,
+        src2: FloatReg,
+    );
+    fn sub_freg64_freg64_freg64(
+        buf: &mut Vec<'_, u8>,
+        dst: FloatReg,
+        src1: FloatReg,
+        src2: FloatReg,
+    );
+
     fn eq_reg64_reg64_reg64(
         buf: &mut Vec<'_, u8>,
         dst: GeneralReg,
@@ -368,11 +445,48 @@ pub trait Assembler<GeneralReg: RegTrait, FloatReg: RegTrait>: Sized + Copy {
         src2: GeneralReg,
     );
 
+    fn ulte_reg64_reg64_reg64(
+        buf: &mut Vec<'_, u8>,
+        dst: GeneralReg,
+        src1: GeneralReg,
+        src2: GeneralReg,
+    );
+
+    fn ugte_reg64_reg64_reg64(
+        buf: &mut Vec<'_, u8>,
+        dst: GeneralReg,
+        src1: GeneralReg,
+        src2: GeneralReg,
+    );
+
+    /// Sets `dst` to 1 if `src1 <operation> src2` holds, and 0 otherwise.
+    /// Every comparison involving NaN is false, except `NotEqual` which is true.
+    fn cmp_freg_freg_reg64(
+        buf: &mut Vec<'_, u8>,
+        dst: GeneralReg,
+        src1: FloatReg,
+        src2: FloatReg,
+        width: FloatWidth,
+        operation: CompareOperation,
+    );
+
     fn set_if_overflow(buf: &mut Vec<'_, u8>, dst: GeneralReg);
+    /// Sets `dst` to 1 if the last unsigned operation carried out of the register, and 0 otherwise.
+    fn set_if_carry(buf: &mut Vec<'_, u8>, dst: GeneralReg);
 
     fn ret(buf: &mut Vec<'_, u8>);
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOperation {
+    Equal,
+    NotEqual,
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+}
+
 pub trait RegTrait:
     Copy + PartialEq + Eq + std::hash::Hash + std::fmt::Debug + std::fmt::Display + 'static
 {
@@ -392,6 +506,7 @@ pub struct Backend64Bit<
     phantom_asm: PhantomData<ASM>,
     phantom_cc: PhantomData<CC>,
     env: &'r Env<'a>,
+    target_info: TargetInfo,
     layout_interner: &'r mut STLayoutInterner<'a>,
     interns: &'r mut Interns,
     helper_proc_gen: CodeGenHelp<'a>,
@@ -400,6 +515,8 @@ pub struct Backend64Bit<
     relocs: Vec<'a, Relocation>,
     proc_name: Option<String>,
     is_self_recursive: Option<SelfRecursive>,
+    /// The human-readable name of the procedure, for the memory checker
+    memcheck_name: Option<String>,
 
     last_seen_map: MutMap<Symbol, *const Stmt<'a>>,
     layout_map: MutMap<Symbol, InLayout<'a>>,
@@ -429,12 +546,19 @@ pub fn new_backend_64bit<
         phantom_asm: PhantomData,
         phantom_cc: PhantomData,
         env,
+        target_info,
         interns,
         layout_interner,
-        helper_proc_gen: CodeGenHelp::new(env.arena, target_info, env.module_id),
+        helper_proc_gen: CodeGenHelp::new(
+            env.arena,
+            target_info,
+            env.module_id,
+            env.trace_refcounts,
+        ),
         helper_proc_symbols: bumpalo::vec![in env.arena],
         proc_name: None,
         is_self_recursive: None,
+        memcheck_name: None,
         buf: bumpalo::vec![in env.arena],
         relocs: bumpalo::vec![in env.arena],
         last_seen_map: MutMap::default(),
@@ -446,19 +570,6 @@ pub fn new_backend_64bit<
     }
 }
 
-macro_rules! quadword_and_smaller {
-    () => {
-        IntWidth::I64
-            | IntWidth::U64
-            | IntWidth::I32
-            | IntWidth::U32
-            | IntWidth::I16
-            | IntWidth::U16
-            | IntWidth::I8
-            | IntWidth::U8
-    };
-}
-
 impl<
         'a,
         'r,
@@ -645,6 +756,34 @@ impl<
         (out.into_bump_slice(), offset)
     }
 
+    fn build_memcheck_enter(&mut self, proc_name: String) {
+        self.memcheck_name = Some(proc_name);
+        self.build_memcheck_reenter();
+    }
+
+    fn build_memcheck_reenter(&mut self) {
+        let mut name = match &self.memcheck_name {
+            Some(name) => name.as_bytes().to_vec(),
+            None => return,
+        };
+        name.push(0);
+
+        let reg = self
+            .storage_manager
+            .claim_general_reg(&mut self.buf, &Symbol::DEV_TMP);
+        ASM::mov_reg64_local_data_address(&mut self.buf, &mut self.relocs, reg, name);
+
+        self.build_fn_call(
+            &Symbol::DEV_TMP2,
+            bitcode::UTILS_MEMCHECK_ENTER.to_string(),
+            &[Symbol::DEV_TMP],
+            &[Layout::U64],
+            &Layout::UNIT,
+        );
+        self.free_symbol(&Symbol::DEV_TMP);
+        self.free_symbol(&Symbol::DEV_TMP2);
+    }
+
     fn build_fn_call(
         &mut self,
         dst: &Symbol,
@@ -677,7 +816,7 @@ impl<
         ASM::call(&mut self.buf, &mut self.relocs, fn_name);
 
         // move return value to dst.
-        match *ret_layout {
+        match register_layout(self.layout_interner, *ret_layout) {
             single_register_integers!() => {
                 let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
                 ASM::mov_reg64_reg64(&mut self.buf, dst_reg, CC::GENERAL_RETURN_REGS[0]);
@@ -827,23 +966,54 @@ impl<
 
     fn build_num_abs(&mut self, dst: &Symbol, src: &Symbol, layout: &InLayout<'a>) {
         match self.interner().get(*layout) {
-            Layout::Builtin(Builtin::Int(IntWidth::I64 | IntWidth::U64)) => {
+            Layout::Builtin(Builtin::Int(IntWidth::I64)) => {
                 let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
                 let src_reg = self.storage_manager.load_to_general_reg(&mut self.buf, src);
                 ASM::abs_reg64_reg64(&mut self.buf, dst_reg, src_reg);
             }
+            Layout::Builtin(Builtin::Int(
+                IntWidth::U64 | IntWidth::U32 | IntWidth::U16 | IntWidth::U8,
+            )) => {
+                let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
+                let src_reg = self.storage_manager.load_to_general_reg(&mut self.buf, src);
+                ASM::mov_reg64_reg64(&mut self.buf, dst_reg, src_reg);
+            }
+            Layout::Builtin(Builtin::Int(int_width)) => {
+                // The absolute value of the minimum value overflows at the integer's own width.
+                self.build_fn_call(
+                    dst,
+                    bitcode::NUM_ABS_OR_PANIC[int_width].to_string(),
+                    &[*src],
+                    &[*layout],
+                    layout,
+                );
+            }
+            Layout::Builtin(Builtin::Decimal) => {
+                self.build_fn_call(
+                    dst,
+                    bitcode::DEC_ABS.to_string(),
+                    &[*src],
+                    &[*layout],
+                    layout,
+                );
+            }
             Layout::Builtin(Builtin::Float(FloatWidth::F64)) => {
                 let dst_reg = self.storage_manager.claim_float_reg(&mut self.buf, dst);
                 let src_reg = self.storage_manager.load_to_float_reg(&mut self.buf, src);
                 ASM::abs_freg64_freg64(&mut self.buf, &mut self.relocs, dst_reg, src_reg);
             }
-            x => todo!("NumAbs: layout, {:?}", x),
+            Layout::Builtin(Builtin::Float(FloatWidth::F32)) => {
+                let dst_reg = self.storage_manager.claim_float_reg(&mut self.buf, dst);
+                let src_reg = self.storage_manager.load_to_float_reg(&mut self.buf, src);
+                ASM::abs_freg32_freg32(&mut self.buf, &mut self.relocs, dst_reg, src_reg);
+            }
+            x => internal_error!("NumAbs: layout, {:?}", x),
         }
     }
 
     fn build_num_add(&mut self, dst: &Symbol, src1: &Symbol, src2: &Symbol, layout: &InLayout<'a>) {
         match self.layout_interner.get(*layout) {
-            Layout::Builtin(Builtin::Int(quadword_and_smaller!())) => {
+            Layout::Builtin(Builtin::Int(IntWidth::I64 | IntWidth::U64)) => {
                 let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
                 let src1_reg = self
                     .storage_manager
@@ -853,6 +1023,26 @@ impl<
                     .load_to_general_reg(&mut self.buf, src2);
                 ASM::add_reg64_reg64_reg64(&mut self.buf, dst_reg, src1_reg, src2_reg);
             }
+            Layout::Builtin(Builtin::Int(int_width)) => {
+                // A 64-bit register addition would neither wrap nor panic on overflow of the
+                // narrower integers, and 128-bit integers don't fit in a register.
+                self.build_fn_call(
+                    dst,
+                    bitcode::NUM_ADD_OR_PANIC_INT[int_width].to_string(),
+                    &[*src1, *src2],
+                    &[*layout, *layout],
+                    layout,
+                );
+            }
+            Layout::Builtin(Builtin::Decimal) => {
+                self.build_fn_call(
+                    dst,
+                    bitcode::DEC_ADD_OR_PANIC.to_string(),
+                    &[*src1, *src2],
+                    &[*layout, *layout],
+                    layout,
+                );
+            }
             Layout::Builtin(Builtin::Float(FloatWidth::F64)) => {
                 let dst_reg = self.storage_manager.claim_float_reg(&mut self.buf, dst);
                 let src1_reg = self.storage_manager.load_to_float_reg(&mut self.buf, src1);
@@ -865,7 +1055,7 @@ impl<
                 let src2_reg = self.storage_manager.load_to_float_reg(&mut self.buf, src2);
                 ASM::add_freg32_freg32_freg32(&mut self.buf, dst_reg, src1_reg, src2_reg);
             }
-            x => todo!("NumAdd: layout, {:?}", x),
+            x => internal_error!("NumAdd: layout, {:?}", x),
         }
     }
 
@@ -879,14 +1069,14 @@ impl<
     ) {
         use Builtin::Int;
 
-        let buf = &mut self.buf;
+        match self.layout_interner.get(*num_layout) {
+            Layout::Builtin(Int(int_width @ (IntWidth::I64 | IntWidth::U64))) => {
+                let buf = &mut self.buf;
 
-        let struct_size = self.layout_interner.stack_size(*return_layout);
+                let struct_size = self.layout_interner.stack_size(*return_layout);
 
-        let base_offset = self.storage_manager.claim_stack_area(dst, struct_size);
+                let base_offset = self.storage_manager.claim_stack_area(dst, struct_size);
 
-        match self.layout_interner.get(*num_layout) {
-            Layout::Builtin(Int(IntWidth::I64 | IntWidth::I32 | IntWidth::I16 | IntWidth::I8)) => {
                 let dst_reg = self
                     .storage_manager
                     .claim_general_reg(buf, &Symbol::DEV_TMP);
@@ -899,7 +1089,11 @@ impl<
                 let src2_reg = self.storage_manager.load_to_general_reg(buf, src2);
 
                 ASM::add_reg64_reg64_reg64(buf, dst_reg, src1_reg, src2_reg);
-                ASM::set_if_overflow(buf, overflow_reg);
+                if int_width == IntWidth::I64 {
+                    ASM::set_if_overflow(buf, overflow_reg);
+                } else {
+                    ASM::set_if_carry(buf, overflow_reg);
+                }
 
                 ASM::mov_base32_reg64(buf, base_offset, dst_reg);
                 ASM::mov_base32_reg64(buf, base_offset + 8, overflow_reg);
@@ -907,16 +1101,76 @@ impl<
                 self.free_symbol(&Symbol::DEV_TMP);
                 self.free_symbol(&Symbol::DEV_TMP2);
             }
-            Layout::Builtin(Int(IntWidth::U64 | IntWidth::U32 | IntWidth::U16 | IntWidth::U8)) => {
-                todo!("addChecked for unsigned integers")
-            }
             Layout::Builtin(Builtin::Float(FloatWidth::F64)) => {
-                todo!("addChecked for f64")
+                // Zig would return `{ f64, bool }` in a float and a general register,
+                // which our calling convention doesn't support yet. So only the finiteness check is a call.
+                let struct_size = self.layout_interner.stack_size(*return_layout);
+                let base_offset = self.storage_manager.claim_stack_area(dst, struct_size);
+
+                let dst_reg = self
+                    .storage_manager
+                    .claim_float_reg(&mut self.buf, &Symbol::DEV_TMP);
+                let src1_reg = self.storage_manager.load_to_float_reg(&mut self.buf, src1);
+                let src2_reg = self.storage_manager.load_to_float_reg(&mut self.buf, src2);
+                ASM::add_freg64_freg64_freg64(&mut self.buf, dst_reg, src1_reg, src2_reg);
+                ASM::mov_base32_freg64(&mut self.buf, base_offset, dst_reg);
+
+                self.build_fn_call(
+                    &Symbol::DEV_TMP2,
+                    bitcode::NUM_IS_FINITE[FloatWidth::F64].to_string(),
+                    &[Symbol::DEV_TMP],
+                    &[*num_layout],
+                    &Layout::BOOL,
+                );
+                self.load_literal(&Symbol::DEV_TMP3, &Layout::BOOL, &Literal::Bool(false));
+
+                let overflow_reg = self
+                    .storage_manager
+                    .claim_general_reg(&mut self.buf, &Symbol::DEV_TMP4);
+                let is_finite_reg = self
+                    .storage_manager
+                    .load_to_general_reg(&mut self.buf, &Symbol::DEV_TMP2);
+                let false_reg = self
+                    .storage_manager
+                    .load_to_general_reg(&mut self.buf, &Symbol::DEV_TMP3);
+                ASM::eq_reg64_reg64_reg64(&mut self.buf, overflow_reg, is_finite_reg, false_reg);
+                ASM::mov_base32_reg64(&mut self.buf, base_offset + 8, overflow_reg);
+
+                self.free_symbol(&Symbol::DEV_TMP);
+                self.free_symbol(&Symbol::DEV_TMP2);
+                self.free_symbol(&Symbol::DEV_TMP3);
+                self.free_symbol(&Symbol::DEV_TMP4);
+            }
+            Layout::Builtin(Int(int_width)) => {
+                // Narrower integers are packed into a single register along with the overflow flag,
+                // and 128-bit integers are returned through a pointer, so let Zig lay out the result.
+                self.build_fn_call(
+                    dst,
+                    bitcode::NUM_ADD_CHECKED_INT[int_width].to_string(),
+                    &[*src1, *src2],
+                    &[*num_layout, *num_layout],
+                    return_layout,
+                );
             }
             Layout::Builtin(Builtin::Float(FloatWidth::F32)) => {
-                todo!("addChecked for f32")
+                self.build_fn_call(
+                    dst,
+                    bitcode::NUM_ADD_CHECKED_FLOAT[FloatWidth::F32].to_string(),
+                    &[*src1, *src2],
+                    &[*num_layout, *num_layout],
+                    return_layout,
+                );
+            }
+            Layout::Builtin(Builtin::Decimal) => {
+                self.build_fn_call(
+                    dst,
+                    bitcode::DEC_ADD_WITH_OVERFLOW.to_string(),
+                    &[*src1, *src2],
+                    &[*num_layout, *num_layout],
+                    return_layout,
+                );
             }
-            x => todo!("NumAdd: layout, {:?}", x),
+            x => internal_error!("NumAdd: layout, {:?}", x),
         }
     }
 
@@ -924,7 +1178,7 @@ impl<
         use Builtin::Int;
 
         match self.layout_interner.get(*layout) {
-            Layout::Builtin(Int(IntWidth::I64 | IntWidth::I32 | IntWidth::I16 | IntWidth::I8)) => {
+            Layout::Builtin(Int(IntWidth::I64)) => {
                 let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
                 let src1_reg = self
                     .storage_manager
@@ -934,7 +1188,7 @@ impl<
                     .load_to_general_reg(&mut self.buf, src2);
                 ASM::imul_reg64_reg64_reg64(&mut self.buf, dst_reg, src1_reg, src2_reg);
             }
-            Layout::Builtin(Int(IntWidth::U64 | IntWidth::U32 | IntWidth::U16 | IntWidth::U8)) => {
+            Layout::Builtin(Int(IntWidth::U64)) => {
                 let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
                 let src1_reg = self
                     .storage_manager
@@ -963,7 +1217,26 @@ impl<
                 let src2_reg = self.storage_manager.load_to_float_reg(&mut self.buf, src2);
                 ASM::mul_freg32_freg32_freg32(&mut self.buf, dst_reg, src1_reg, src2_reg);
             }
-            x => todo!("NumMul: layout, {:?}", x),
+            Layout::Builtin(Int(int_width)) => {
+                // Like addition, narrower integers need their overflow checked at their own width.
+                self.build_fn_call(
+                    dst,
+                    bitcode::NUM_MUL_OR_PANIC_INT[int_width].to_string(),
+                    &[*src1, *src2],
+                    &[*layout, *layout],
+                    layout,
+                );
+            }
+            Layout::Builtin(Builtin::Decimal) => {
+                self.build_fn_call(
+                    dst,
+                    bitcode::DEC_MUL_OR_PANIC.to_string(),
+                    &[*src1, *src2],
+                    &[*layout, *layout],
+                    layout,
+                );
+            }
+            x => internal_error!("NumMul: layout, {:?}", x),
         }
     }
 
@@ -1019,7 +1292,25 @@ impl<
                 let src2_reg = self.storage_manager.load_to_float_reg(&mut self.buf, src2);
                 ASM::div_freg32_freg32_freg32(&mut self.buf, dst_reg, src1_reg, src2_reg);
             }
-            x => todo!("NumDiv: layout, {:?}", x),
+            Layout::Builtin(Builtin::Int(int_width @ (IntWidth::I128 | IntWidth::U128))) => {
+                self.build_fn_call(
+                    dst,
+                    bitcode::NUM_DIV_TRUNC[int_width].to_string(),
+                    &[*src1, *src2],
+                    &[*layout, *layout],
+                    layout,
+                );
+            }
+            Layout::Builtin(Builtin::Decimal) => {
+                self.build_fn_call(
+                    dst,
+                    bitcode::DEC_DIV.to_string(),
+                    &[*src1, *src2],
+                    &[*layout, *layout],
+                    layout,
+                );
+            }
+            x => internal_error!("NumDiv: layout, {:?}", x),
         }
     }
 
@@ -1030,7 +1321,36 @@ impl<
                 let src_reg = self.storage_manager.load_to_general_reg(&mut self.buf, src);
                 ASM::neg_reg64_reg64(&mut self.buf, dst_reg, src_reg);
             }
-            x => todo!("NumNeg: layout, {:?}", x),
+            Layout::Builtin(Builtin::Int(int_width)) => {
+                // Negating the minimum value overflows at the integer's own width.
+                self.build_fn_call(
+                    dst,
+                    bitcode::NUM_NEG_OR_PANIC[int_width].to_string(),
+                    &[*src],
+                    &[*layout],
+                    layout,
+                );
+            }
+            Layout::Builtin(Builtin::Decimal) => {
+                self.build_fn_call(
+                    dst,
+                    bitcode::DEC_NEGATE.to_string(),
+                    &[*src],
+                    &[*layout],
+                    layout,
+                );
+            }
+            Layout::Builtin(Builtin::Float(FloatWidth::F64)) => {
+                let dst_reg = self.storage_manager.claim_float_reg(&mut self.buf, dst);
+                let src_reg = self.storage_manager.load_to_float_reg(&mut self.buf, src);
+                ASM::neg_freg64_freg64(&mut self.buf, &mut self.relocs, dst_reg, src_reg);
+            }
+            Layout::Builtin(Builtin::Float(FloatWidth::F32)) => {
+                let dst_reg = self.storage_manager.claim_float_reg(&mut self.buf, dst);
+                let src_reg = self.storage_manager.load_to_float_reg(&mut self.buf, src);
+                ASM::neg_freg32_freg32(&mut self.buf, &mut self.relocs, dst_reg, src_reg);
+            }
+            x => internal_error!("NumNeg: layout, {:?}", x),
         }
     }
 
@@ -1046,7 +1366,38 @@ impl<
                     .load_to_general_reg(&mut self.buf, src2);
                 ASM::sub_reg64_reg64_reg64(&mut self.buf, dst_reg, src1_reg, src2_reg);
             }
-            x => todo!("NumSub: layout, {:?}", x),
+            Layout::Builtin(Builtin::Int(int_width)) => {
+                // Like addition, narrower integers need their overflow checked at their own width.
+                self.build_fn_call(
+                    dst,
+                    bitcode::NUM_SUB_OR_PANIC_INT[int_width].to_string(),
+                    &[*src1, *src2],
+                    &[*layout, *layout],
+                    layout,
+                );
+            }
+            Layout::Builtin(Builtin::Decimal) => {
+                self.build_fn_call(
+                    dst,
+                    bitcode::DEC_SUB_OR_PANIC.to_string(),
+                    &[*src1, *src2],
+                    &[*layout, *layout],
+                    layout,
+                );
+            }
+            Layout::Builtin(Builtin::Float(FloatWidth::F64)) => {
+                let dst_reg = self.storage_manager.claim_float_reg(&mut self.buf, dst);
+                let src1_reg = self.storage_manager.load_to_float_reg(&mut self.buf, src1);
+                let src2_reg = self.storage_manager.load_to_float_reg(&mut self.buf, src2);
+                ASM::sub_freg64_freg64_freg64(&mut self.buf, dst_reg, src1_reg, src2_reg);
+            }
+            Layout::Builtin(Builtin::Float(FloatWidth::F32)) => {
+                let dst_reg = self.storage_manager.claim_float_reg(&mut self.buf, dst);
+                let src1_reg = self.storage_manager.load_to_float_reg(&mut self.buf, src1);
+                let src2_reg = self.storage_manager.load_to_float_reg(&mut self.buf, src2);
+                ASM::sub_freg32_freg32_freg32(&mut self.buf, dst_reg, src1_reg, src2_reg);
+            }
+            x => internal_error!("NumSub: layout, {:?}", x),
         }
     }
 
@@ -1068,40 +1419,26 @@ impl<
                     .load_to_general_reg(&mut self.buf, src2);
                 ASM::sub_reg64_reg64_reg64(&mut self.buf, dst_reg, src1_reg, src2_reg);
             }
-            x => todo!("NumSubWrap: layout, {:?}", x),
+            Layout::Builtin(Builtin::Int(int_width)) => {
+                // The narrower integers have to wrap at their own width, rather than at 64 bits.
+                self.build_fn_call(
+                    dst,
+                    bitcode::NUM_SUB_WRAPPED_INT[int_width].to_string(),
+                    &[*src1, *src2],
+                    &[*layout, *layout],
+                    layout,
+                );
+            }
+            x => internal_error!("NumSubWrap: layout, {:?}", x),
         }
     }
 
     fn build_eq(&mut self, dst: &Symbol, src1: &Symbol, src2: &Symbol, arg_layout: &InLayout<'a>) {
-        match *arg_layout {
-            single_register_int_builtins!() => {
-                let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
-                let src1_reg = self
-                    .storage_manager
-                    .load_to_general_reg(&mut self.buf, src1);
-                let src2_reg = self
-                    .storage_manager
-                    .load_to_general_reg(&mut self.buf, src2);
-                ASM::eq_reg64_reg64_reg64(&mut self.buf, dst_reg, src1_reg, src2_reg);
-            }
-            x => todo!("NumEq: layout, {:?}", x),
-        }
+        self.build_compare(dst, src1, src2, arg_layout, CompareOperation::Equal);
     }
 
     fn build_neq(&mut self, dst: &Symbol, src1: &Symbol, src2: &Symbol, arg_layout: &InLayout<'a>) {
-        match self.layout_interner.get(*arg_layout) {
-            Layout::Builtin(Builtin::Int(IntWidth::I64 | IntWidth::U64)) => {
-                let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
-                let src1_reg = self
-                    .storage_manager
-                    .load_to_general_reg(&mut self.buf, src1);
-                let src2_reg = self
-                    .storage_manager
-                    .load_to_general_reg(&mut self.buf, src2);
-                ASM::neq_reg64_reg64_reg64(&mut self.buf, dst_reg, src1_reg, src2_reg);
-            }
-            x => todo!("NumNeq: layout, {:?}", x),
-        }
+        self.build_compare(dst, src1, src2, arg_layout, CompareOperation::NotEqual);
     }
 
     fn build_num_lt(
@@ -1111,29 +1448,7 @@ impl<
         src2: &Symbol,
         arg_layout: &InLayout<'a>,
     ) {
-        match self.layout_interner.get(*arg_layout) {
-            Layout::Builtin(Builtin::Int(IntWidth::I64)) => {
-                let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
-                let src1_reg = self
-                    .storage_manager
-                    .load_to_general_reg(&mut self.buf, src1);
-                let src2_reg = self
-                    .storage_manager
-                    .load_to_general_reg(&mut self.buf, src2);
-                ASM::ilt_reg64_reg64_reg64(&mut self.buf, dst_reg, src1_reg, src2_reg);
-            }
-            Layout::Builtin(Builtin::Int(IntWidth::U64)) => {
-                let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
-                let src1_reg = self
-                    .storage_manager
-                    .load_to_general_reg(&mut self.buf, src1);
-                let src2_reg = self
-                    .storage_manager
-                    .load_to_general_reg(&mut self.buf, src2);
-                ASM::ult_reg64_reg64_reg64(&mut self.buf, dst_reg, src1_reg, src2_reg);
-            }
-            x => todo!("NumLt: layout, {:?}", x),
-        }
+        self.build_compare(dst, src1, src2, arg_layout, CompareOperation::LessThan);
     }
 
     fn build_num_gt(
@@ -1143,54 +1458,70 @@ impl<
         src2: &Symbol,
         arg_layout: &InLayout<'a>,
     ) {
-        match self.layout_interner.get(*arg_layout) {
-            Layout::Builtin(Builtin::Int(IntWidth::I64)) => {
-                let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
-                let src1_reg = self
-                    .storage_manager
-                    .load_to_general_reg(&mut self.buf, src1);
-                let src2_reg = self
-                    .storage_manager
-                    .load_to_general_reg(&mut self.buf, src2);
-                ASM::igt_reg64_reg64_reg64(&mut self.buf, dst_reg, src1_reg, src2_reg);
-            }
-            Layout::Builtin(Builtin::Int(IntWidth::U64)) => {
-                let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
-                let src1_reg = self
-                    .storage_manager
-                    .load_to_general_reg(&mut self.buf, src1);
-                let src2_reg = self
-                    .storage_manager
-                    .load_to_general_reg(&mut self.buf, src2);
-                ASM::ugt_reg64_reg64_reg64(&mut self.buf, dst_reg, src1_reg, src2_reg);
-            }
-            x => todo!("NumGt: layout, {:?}", x),
-        }
+        self.build_compare(dst, src1, src2, arg_layout, CompareOperation::GreaterThan);
     }
 
-    fn build_num_to_frac(
+    fn build_num_compare(
         &mut self,
         dst: &Symbol,
-        src: &Symbol,
+        src1: &Symbol,
+        src2: &Symbol,
         arg_layout: &InLayout<'a>,
-        ret_layout: &InLayout<'a>,
     ) {
-        let dst_reg = self.storage_manager.claim_float_reg(&mut self.buf, dst);
-        match (
-            self.layout_interner.get(*arg_layout),
-            self.layout_interner.get(*ret_layout),
-        ) {
-            (
-                Layout::Builtin(Builtin::Int(IntWidth::I32 | IntWidth::I64)),
-                Layout::Builtin(Builtin::Float(FloatWidth::F64)),
-            ) => {
-                let src_reg = self.storage_manager.load_to_general_reg(&mut self.buf, src);
+        // The tags of [EQ, GT, LT] are sorted, so the tag id is `(src1 > src2) + 2 * (src1 < src2)`.
+        self.build_compare(
+            &Symbol::DEV_TMP,
+            src1,
+            src2,
+            arg_layout,
+            CompareOperation::GreaterThan,
+        );
+        self.build_compare(
+            &Symbol::DEV_TMP2,
+            src1,
+            src2,
+            arg_layout,
+            CompareOperation::LessThan,
+        );
+
+        let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
+        let gt_reg = self
+            .storage_manager
+            .load_to_general_reg(&mut self.buf, &Symbol::DEV_TMP);
+        let lt_reg = self
+            .storage_manager
+            .load_to_general_reg(&mut self.buf, &Symbol::DEV_TMP2);
+        ASM::add_reg64_reg64_reg64(&mut self.buf, dst_reg, gt_reg, lt_reg);
+        ASM::add_reg64_reg64_reg64(&mut self.buf, dst_reg, dst_reg, lt_reg);
+
+        self.free_symbol(&Symbol::DEV_TMP);
+        self.free_symbol(&Symbol::DEV_TMP2);
+    }
+
+    fn build_num_to_frac(
+        &mut self,
+        dst: &Symbol,
+        src: &Symbol,
+        arg_layout: &InLayout<'a>,
+        ret_layout: &InLayout<'a>,
+    ) {
+        match (
+            self.layout_interner.get(*arg_layout),
+            self.layout_interner.get(*ret_layout),
+        ) {
+            (
+                Layout::Builtin(Builtin::Int(IntWidth::I32 | IntWidth::I64)),
+                Layout::Builtin(Builtin::Float(FloatWidth::F64)),
+            ) => {
+                let dst_reg = self.storage_manager.claim_float_reg(&mut self.buf, dst);
+                let src_reg = self.storage_manager.load_to_general_reg(&mut self.buf, src);
                 ASM::to_float_freg64_reg64(&mut self.buf, dst_reg, src_reg);
             }
             (
                 Layout::Builtin(Builtin::Int(IntWidth::I32 | IntWidth::I64)),
                 Layout::Builtin(Builtin::Float(FloatWidth::F32)),
             ) => {
+                let dst_reg = self.storage_manager.claim_float_reg(&mut self.buf, dst);
                 let src_reg = self.storage_manager.load_to_general_reg(&mut self.buf, src);
                 ASM::to_float_freg32_reg64(&mut self.buf, dst_reg, src_reg);
             }
@@ -1198,6 +1529,7 @@ impl<
                 Layout::Builtin(Builtin::Float(FloatWidth::F64)),
                 Layout::Builtin(Builtin::Float(FloatWidth::F32)),
             ) => {
+                let dst_reg = self.storage_manager.claim_float_reg(&mut self.buf, dst);
                 let src_reg = self.storage_manager.load_to_float_reg(&mut self.buf, src);
                 ASM::to_float_freg32_freg64(&mut self.buf, dst_reg, src_reg);
             }
@@ -1205,6 +1537,7 @@ impl<
                 Layout::Builtin(Builtin::Float(FloatWidth::F32)),
                 Layout::Builtin(Builtin::Float(FloatWidth::F64)),
             ) => {
+                let dst_reg = self.storage_manager.claim_float_reg(&mut self.buf, dst);
                 let src_reg = self.storage_manager.load_to_float_reg(&mut self.buf, src);
                 ASM::to_float_freg64_freg32(&mut self.buf, dst_reg, src_reg);
             }
@@ -1212,6 +1545,7 @@ impl<
                 Layout::Builtin(Builtin::Float(FloatWidth::F64)),
                 Layout::Builtin(Builtin::Float(FloatWidth::F64)),
             ) => {
+                let dst_reg = self.storage_manager.claim_float_reg(&mut self.buf, dst);
                 let src_reg = self.storage_manager.load_to_float_reg(&mut self.buf, src);
                 ASM::mov_freg64_freg64(&mut self.buf, dst_reg, src_reg);
             }
@@ -1219,10 +1553,107 @@ impl<
                 Layout::Builtin(Builtin::Float(FloatWidth::F32)),
                 Layout::Builtin(Builtin::Float(FloatWidth::F32)),
             ) => {
+                let dst_reg = self.storage_manager.claim_float_reg(&mut self.buf, dst);
                 let src_reg = self.storage_manager.load_to_float_reg(&mut self.buf, src);
                 ASM::mov_freg64_freg64(&mut self.buf, dst_reg, src_reg);
             }
-            (a, r) => todo!("NumToFrac: layout, arg {:?}, ret {:?}", a, r),
+            (
+                Layout::Builtin(Builtin::Int(int_width)),
+                Layout::Builtin(Builtin::Float(float_width)),
+            ) => {
+                // Unsigned, narrow and 128-bit integers don't map directly onto a conversion instruction.
+                let intrinsic = match float_width {
+                    FloatWidth::F32 => &bitcode::NUM_INT_TO_F32,
+                    FloatWidth::F64 => &bitcode::NUM_INT_TO_F64,
+                };
+                self.build_fn_call(
+                    dst,
+                    intrinsic[int_width].to_string(),
+                    &[*src],
+                    &[*arg_layout],
+                    ret_layout,
+                );
+            }
+            (
+                Layout::Builtin(Builtin::Decimal),
+                Layout::Builtin(Builtin::Float(FloatWidth::F64)),
+            ) => {
+                self.build_fn_call(
+                    dst,
+                    bitcode::DEC_TO_F64.to_string(),
+                    &[*src],
+                    &[*arg_layout],
+                    ret_layout,
+                );
+            }
+            (
+                Layout::Builtin(Builtin::Decimal),
+                Layout::Builtin(Builtin::Float(FloatWidth::F32)),
+            ) => {
+                self.build_fn_call(
+                    &Symbol::DEV_TMP,
+                    bitcode::DEC_TO_F64.to_string(),
+                    &[*src],
+                    &[*arg_layout],
+                    &Layout::F64,
+                );
+                let dst_reg = self.storage_manager.claim_float_reg(&mut self.buf, dst);
+                let src_reg = self
+                    .storage_manager
+                    .load_to_float_reg(&mut self.buf, &Symbol::DEV_TMP);
+                ASM::to_float_freg32_freg64(&mut self.buf, dst_reg, src_reg);
+                self.free_symbol(&Symbol::DEV_TMP);
+            }
+            (Layout::Builtin(Builtin::Int(int_width)), Layout::Builtin(Builtin::Decimal)) => {
+                self.build_fn_call(
+                    dst,
+                    bitcode::DEC_FROM_INT[int_width].to_string(),
+                    &[*src],
+                    &[*arg_layout],
+                    ret_layout,
+                );
+            }
+            (
+                Layout::Builtin(Builtin::Float(FloatWidth::F64)),
+                Layout::Builtin(Builtin::Decimal),
+            ) => {
+                self.build_fn_call(
+                    dst,
+                    bitcode::DEC_FROM_F64.to_string(),
+                    &[*src],
+                    &[*arg_layout],
+                    ret_layout,
+                );
+            }
+            (
+                Layout::Builtin(Builtin::Float(FloatWidth::F32)),
+                Layout::Builtin(Builtin::Decimal),
+            ) => {
+                let tmp_reg = self
+                    .storage_manager
+                    .claim_float_reg(&mut self.buf, &Symbol::DEV_TMP);
+                let src_reg = self.storage_manager.load_to_float_reg(&mut self.buf, src);
+                ASM::to_float_freg64_freg32(&mut self.buf, tmp_reg, src_reg);
+                self.build_fn_call(
+                    dst,
+                    bitcode::DEC_FROM_F64.to_string(),
+                    &[Symbol::DEV_TMP],
+                    &[Layout::F64],
+                    ret_layout,
+                );
+                self.free_symbol(&Symbol::DEV_TMP);
+            }
+            (Layout::Builtin(Builtin::Decimal), Layout::Builtin(Builtin::Decimal)) => {
+                let base_offset = self.storage_manager.claim_stack_area(dst, 16);
+                self.storage_manager.copy_symbol_to_stack_offset(
+                    self.layout_interner,
+                    &mut self.buf,
+                    base_offset,
+                    src,
+                    arg_layout,
+                );
+            }
+            (a, r) => internal_error!("NumToFrac: layout, arg {:?}, ret {:?}", a, r),
         }
     }
 
@@ -1233,19 +1664,13 @@ impl<
         src2: &Symbol,
         arg_layout: &InLayout<'a>,
     ) {
-        match *arg_layout {
-            single_register_int_builtins!() => {
-                let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
-                let src1_reg = self
-                    .storage_manager
-                    .load_to_general_reg(&mut self.buf, src1);
-                let src2_reg = self
-                    .storage_manager
-                    .load_to_general_reg(&mut self.buf, src2);
-                ASM::lte_reg64_reg64_reg64(&mut self.buf, dst_reg, src1_reg, src2_reg);
-            }
-            x => todo!("NumLte: layout, {:?}", x),
-        }
+        self.build_compare(
+            dst,
+            src1,
+            src2,
+            arg_layout,
+            CompareOperation::LessThanOrEqual,
+        );
     }
 
     fn build_num_gte(
@@ -1255,19 +1680,13 @@ impl<
         src2: &Symbol,
         arg_layout: &InLayout<'a>,
     ) {
-        match *arg_layout {
-            single_register_int_builtins!() => {
-                let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
-                let src1_reg = self
-                    .storage_manager
-                    .load_to_general_reg(&mut self.buf, src1);
-                let src2_reg = self
-                    .storage_manager
-                    .load_to_general_reg(&mut self.buf, src2);
-                ASM::gte_reg64_reg64_reg64(&mut self.buf, dst_reg, src1_reg, src2_reg);
-            }
-            x => todo!("NumGte: layout, {:?}", x),
-        }
+        self.build_compare(
+            dst,
+            src1,
+            src2,
+            arg_layout,
+            CompareOperation::GreaterThanOrEqual,
+        );
     }
 
     fn build_list_len(&mut self, dst: &Symbol, list: &Symbol) {
@@ -1286,26 +1705,33 @@ impl<
             .storage_manager
             .load_to_general_reg(&mut self.buf, index);
         let ret_stack_size = self.layout_interner.stack_size(*ret_layout);
-        // TODO: This can be optimized with smarter instructions.
-        // Also can probably be moved into storage manager at least partly.
-        self.storage_manager.with_tmp_general_reg(
+
+        // Elements are copied out a word at a time, so only numbers can have a partial word.
+        if ret_stack_size % 8 != 0 && !is_primitive(self.layout_interner, *ret_layout) {
+            internal_error!("Loading list element with layout: {:?}", ret_layout);
+        }
+
+        // The element starts at `list.bytes + index * size`.
+        let ptr_reg = self
+            .storage_manager
+            .claim_general_reg(&mut self.buf, &Symbol::DEV_TMP);
+        ASM::mov_reg64_base32(&mut self.buf, ptr_reg, base_offset);
+        self.storage_manager
+            .with_tmp_general_reg(&mut self.buf, |_storage_manager, buf, tmp| {
+                ASM::mov_reg64_imm64(buf, tmp, ret_stack_size as i64);
+                ASM::imul_reg64_reg64_reg64(buf, tmp, tmp, index_reg);
+                ASM::add_reg64_reg64_reg64(buf, ptr_reg, ptr_reg, tmp);
+            });
+        self.storage_manager.load_from_memory(
+            self.layout_interner,
             &mut self.buf,
-            |storage_manager, buf, list_ptr| {
-                ASM::mov_reg64_base32(buf, list_ptr, base_offset as i32);
-                storage_manager.with_tmp_general_reg(buf, |storage_manager, buf, tmp| {
-                    ASM::mov_reg64_imm64(buf, tmp, ret_stack_size as i64);
-                    ASM::imul_reg64_reg64_reg64(buf, tmp, tmp, index_reg);
-                    ASM::add_reg64_reg64_reg64(buf, tmp, tmp, list_ptr);
-                    match *ret_layout {
-                        single_register_integers!() if ret_stack_size == 8 => {
-                            let dst_reg = storage_manager.claim_general_reg(buf, dst);
-                            ASM::mov_reg64_mem64_offset32(buf, dst_reg, tmp, 0);
-                        }
-                        x => internal_error!("Loading list element with layout: {:?}", x),
-                    }
-                });
-            },
+            dst,
+            ptr_reg,
+            0,
+            ret_layout,
         );
+
+        self.free_symbol(&Symbol::DEV_TMP);
     }
 
     fn build_list_replace_unsafe(
@@ -1432,162 +1858,605 @@ impl<
         self.free_symbol(&Symbol::DEV_TMP5);
     }
 
-    fn build_ptr_cast(&mut self, dst: &Symbol, src: &Symbol) {
-        let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
-        self.storage_manager
-            .ensure_symbol_on_stack(&mut self.buf, src);
-        let (offset, _) = self.storage_manager.stack_offset_and_size(src);
-        ASM::add_reg64_reg64_imm32(&mut self.buf, dst_reg, CC::BASE_PTR_REG, offset);
-    }
-
-    fn create_empty_array(&mut self, sym: &Symbol) {
-        let base_offset = self.storage_manager.claim_stack_area(sym, 24);
-        self.storage_manager
-            .with_tmp_general_reg(&mut self.buf, |_storage_manager, buf, reg| {
-                ASM::mov_reg64_imm64(buf, reg, 0);
-                ASM::mov_base32_reg64(buf, base_offset, reg);
-                ASM::mov_base32_reg64(buf, base_offset + 8, reg);
-                ASM::mov_base32_reg64(buf, base_offset + 16, reg);
-            });
+    fn build_list_get_capacity(&mut self, dst: &Symbol, list: &Symbol) {
+        self.storage_manager.list_capacity(&mut self.buf, dst, list);
     }
 
-    fn create_array(
+    fn build_list_with_capacity(
         &mut self,
-        sym: &Symbol,
-        elem_layout: &InLayout<'a>,
-        elems: &'a [ListLiteralElement<'a>],
+        dst: &Symbol,
+        capacity: &Symbol,
+        capacity_layout: &InLayout<'a>,
+        ret_layout: &InLayout<'a>,
     ) {
-        // Allocate
-        // This requires at least 8 for the refcount alignment.
-        let allocation_alignment = std::cmp::max(
-            8,
-            self.layout_interner
-                .allocation_alignment_bytes(*elem_layout) as u64,
-        );
-
-        let elem_size = self.layout_interner.stack_size(*elem_layout) as u64;
-        let allocation_size = elem_size * elems.len() as u64 + allocation_alignment /* add space for refcount */;
-        let u64_layout = Layout::U64;
-        self.load_literal(
-            &Symbol::DEV_TMP,
-            &u64_layout,
-            &Literal::Int((allocation_size as i128).to_ne_bytes()),
-        );
-        let u32_layout = Layout::U32;
-        self.load_literal(
-            &Symbol::DEV_TMP2,
-            &u32_layout,
-            &Literal::Int((allocation_alignment as i128).to_ne_bytes()),
-        );
+        let elem_layout = self.list_element_layout(*ret_layout);
+        self.load_element_alignment(&Symbol::DEV_TMP, elem_layout);
+        self.load_element_width(&Symbol::DEV_TMP2, elem_layout);
 
+        let lowlevel_args = bumpalo::vec![
+        in self.env.arena;
+            *capacity,
+            Symbol::DEV_TMP,
+            Symbol::DEV_TMP2,
+         ];
+        let lowlevel_arg_layouts = bumpalo::vec![
+        in self.env.arena;
+                *capacity_layout,
+                Layout::U32,
+                Layout::U64,
+        ];
         self.build_fn_call(
-            &Symbol::DEV_TMP3,
-            "roc_alloc".to_string(),
-            &[Symbol::DEV_TMP, Symbol::DEV_TMP2],
-            &[u64_layout, u32_layout],
-            &u64_layout,
+            dst,
+            bitcode::LIST_WITH_CAPACITY.to_string(),
+            &lowlevel_args,
+            &lowlevel_arg_layouts,
+            ret_layout,
         );
         self.free_symbol(&Symbol::DEV_TMP);
         self.free_symbol(&Symbol::DEV_TMP2);
+    }
 
-        // Fill pointer with elems
-        let ptr_reg = self
-            .storage_manager
-            .load_to_general_reg(&mut self.buf, &Symbol::DEV_TMP3);
-        // Point to first element of array.
-        ASM::add_reg64_reg64_imm32(&mut self.buf, ptr_reg, ptr_reg, allocation_alignment as i32);
-
-        // fill refcount at -8.
-        self.storage_manager.with_tmp_general_reg(
-            &mut self.buf,
-            |_storage_manager, buf, tmp_reg| {
-                ASM::mov_reg64_imm64(buf, tmp_reg, REFCOUNT_ONE as i64);
-                ASM::mov_mem64_offset32_reg64(buf, ptr_reg, -8, tmp_reg);
-            },
-        );
-
-        // Copy everything into output array.
-        let mut elem_offset = 0;
-        for elem in elems {
-            // TODO: this could be a lot faster when loading large lists
-            // if we move matching on the element layout to outside this loop.
-            // It also greatly bloats the code here.
-            // Refactor this and switch to one external match.
-            // We also could make loadining indivitual literals much faster
-            let elem_sym = match elem {
-                ListLiteralElement::Symbol(sym) => sym,
-                ListLiteralElement::Literal(lit) => {
-                    self.load_literal(&Symbol::DEV_TMP, elem_layout, lit);
-                    &Symbol::DEV_TMP
-                }
-            };
-            // TODO: Expand to all types.
-            match self.layout_interner.get(*elem_layout) {
-                Layout::Builtin(Builtin::Int(IntWidth::I64 | IntWidth::U64)) => {
-                    let sym_reg = self
-                        .storage_manager
-                        .load_to_general_reg(&mut self.buf, elem_sym);
-                    ASM::mov_mem64_offset32_reg64(&mut self.buf, ptr_reg, elem_offset, sym_reg);
-                }
-                _ if elem_size == 0 => {}
-                _ if elem_size > 8 => {
-                    let (from_offset, size) = self.storage_manager.stack_offset_and_size(elem_sym);
-                    debug_assert!(from_offset % 8 == 0);
-                    debug_assert!(size % 8 == 0);
-                    debug_assert_eq!(size as u64, elem_size);
-                    self.storage_manager.with_tmp_general_reg(
-                        &mut self.buf,
-                        |_storage_manager, buf, tmp_reg| {
-                            for i in (0..size as i32).step_by(8) {
-                                ASM::mov_reg64_base32(buf, tmp_reg, from_offset + i);
-                                ASM::mov_mem64_offset32_reg64(buf, ptr_reg, elem_offset, tmp_reg);
-                            }
-                        },
-                    );
-                }
-                x => todo!("copying data to list with layout, {:?}", x),
-            }
-            elem_offset += elem_size as i32;
-            if elem_sym == &Symbol::DEV_TMP {
-                self.free_symbol(elem_sym);
-            }
-        }
-
-        // Setup list on stack.
-        self.storage_manager.with_tmp_general_reg(
-            &mut self.buf,
-            |storage_manager, buf, tmp_reg| {
-                let base_offset = storage_manager.claim_stack_area(sym, 24);
-                ASM::mov_base32_reg64(buf, base_offset, ptr_reg);
+    fn build_list_reserve(
+        &mut self,
+        dst: &Symbol,
+        args: &'a [Symbol],
+        arg_layouts: &[InLayout<'a>],
+        ret_layout: &InLayout<'a>,
+    ) {
+        let elem_layout = self.list_element_layout(arg_layouts[0]);
+        self.load_element_alignment(&Symbol::DEV_TMP, elem_layout);
+        self.load_element_width(&Symbol::DEV_TMP2, elem_layout);
+        self.load_update_mode(&Symbol::DEV_TMP3);
 
-                ASM::mov_reg64_imm64(buf, tmp_reg, elems.len() as i64);
-                ASM::mov_base32_reg64(buf, base_offset + 8, tmp_reg);
-                ASM::mov_base32_reg64(buf, base_offset + 16, tmp_reg);
-            },
+        let lowlevel_args = bumpalo::vec![
+        in self.env.arena;
+            args[0],
+            Symbol::DEV_TMP,
+            args[1],
+            Symbol::DEV_TMP2,
+            Symbol::DEV_TMP3,
+         ];
+        let lowlevel_arg_layouts = bumpalo::vec![
+        in self.env.arena;
+                arg_layouts[0],
+                Layout::U32,
+                arg_layouts[1],
+                Layout::U64,
+                Layout::U8,
+        ];
+        self.build_fn_call(
+            dst,
+            bitcode::LIST_RESERVE.to_string(),
+            &lowlevel_args,
+            &lowlevel_arg_layouts,
+            ret_layout,
         );
+        self.free_symbol(&Symbol::DEV_TMP);
+        self.free_symbol(&Symbol::DEV_TMP2);
         self.free_symbol(&Symbol::DEV_TMP3);
     }
 
-    fn create_struct(&mut self, sym: &Symbol, layout: &InLayout<'a>, fields: &'a [Symbol]) {
-        self.storage_manager.create_struct(
-            self.layout_interner,
-            &mut self.buf,
-            sym,
-            layout,
-            fields,
-        );
-    }
-
-    fn load_struct_at_index(
+    fn build_list_append_unsafe(
         &mut self,
-        sym: &Symbol,
-        structure: &Symbol,
-        index: u64,
-        field_layouts: &'a [InLayout<'a>],
+        dst: &Symbol,
+        args: &'a [Symbol],
+        arg_layouts: &[InLayout<'a>],
+        ret_layout: &InLayout<'a>,
     ) {
-        self.storage_manager.load_field_at_index(
-            self.layout_interner,
-            sym,
+        self.load_element_address(&Symbol::DEV_TMP, &args[1]);
+        self.load_element_width(&Symbol::DEV_TMP2, arg_layouts[1]);
+
+        let lowlevel_args = bumpalo::vec![
+        in self.env.arena;
+            args[0],
+            Symbol::DEV_TMP,
+            Symbol::DEV_TMP2,
+         ];
+        let lowlevel_arg_layouts = bumpalo::vec![
+        in self.env.arena;
+                arg_layouts[0],
+                Layout::U64,
+                Layout::U64,
+        ];
+        self.build_fn_call(
+            dst,
+            bitcode::LIST_APPEND_UNSAFE.to_string(),
+            &lowlevel_args,
+            &lowlevel_arg_layouts,
+            ret_layout,
+        );
+        self.free_symbol(&Symbol::DEV_TMP);
+        self.free_symbol(&Symbol::DEV_TMP2);
+    }
+
+    fn build_list_prepend(
+        &mut self,
+        dst: &Symbol,
+        args: &'a [Symbol],
+        arg_layouts: &[InLayout<'a>],
+        ret_layout: &InLayout<'a>,
+    ) {
+        self.load_element_alignment(&Symbol::DEV_TMP, arg_layouts[1]);
+        self.load_element_address(&Symbol::DEV_TMP2, &args[1]);
+        self.load_element_width(&Symbol::DEV_TMP3, arg_layouts[1]);
+
+        let lowlevel_args = bumpalo::vec![
+        in self.env.arena;
+            args[0],
+            Symbol::DEV_TMP,
+            Symbol::DEV_TMP2,
+            Symbol::DEV_TMP3,
+         ];
+        let lowlevel_arg_layouts = bumpalo::vec![
+        in self.env.arena;
+                arg_layouts[0],
+                Layout::U32,
+                Layout::U64,
+                Layout::U64,
+        ];
+        self.build_fn_call(
+            dst,
+            bitcode::LIST_PREPEND.to_string(),
+            &lowlevel_args,
+            &lowlevel_arg_layouts,
+            ret_layout,
+        );
+        self.free_symbol(&Symbol::DEV_TMP);
+        self.free_symbol(&Symbol::DEV_TMP2);
+        self.free_symbol(&Symbol::DEV_TMP3);
+    }
+
+    fn build_list_concat(
+        &mut self,
+        dst: &Symbol,
+        args: &'a [Symbol],
+        arg_layouts: &[InLayout<'a>],
+        ret_layout: &InLayout<'a>,
+    ) {
+        let elem_layout = self.list_element_layout(arg_layouts[0]);
+        self.load_element_alignment(&Symbol::DEV_TMP, elem_layout);
+        self.load_element_width(&Symbol::DEV_TMP2, elem_layout);
+
+        let lowlevel_args = bumpalo::vec![
+        in self.env.arena;
+            args[0],
+            args[1],
+            Symbol::DEV_TMP,
+            Symbol::DEV_TMP2,
+         ];
+        let lowlevel_arg_layouts = bumpalo::vec![
+        in self.env.arena;
+                arg_layouts[0],
+                arg_layouts[1],
+                Layout::U32,
+                Layout::U64,
+        ];
+        self.build_fn_call(
+            dst,
+            bitcode::LIST_CONCAT.to_string(),
+            &lowlevel_args,
+            &lowlevel_arg_layouts,
+            ret_layout,
+        );
+        self.free_symbol(&Symbol::DEV_TMP);
+        self.free_symbol(&Symbol::DEV_TMP2);
+    }
+
+    fn build_list_swap(
+        &mut self,
+        dst: &Symbol,
+        args: &'a [Symbol],
+        arg_layouts: &[InLayout<'a>],
+        ret_layout: &InLayout<'a>,
+    ) {
+        let elem_layout = self.list_element_layout(arg_layouts[0]);
+        self.load_element_alignment(&Symbol::DEV_TMP, elem_layout);
+        self.load_element_width(&Symbol::DEV_TMP2, elem_layout);
+        self.load_update_mode(&Symbol::DEV_TMP3);
+
+        let lowlevel_args = bumpalo::vec![
+        in self.env.arena;
+            args[0],
+            Symbol::DEV_TMP,
+            Symbol::DEV_TMP2,
+            args[1],
+            args[2],
+            Symbol::DEV_TMP3,
+         ];
+        let lowlevel_arg_layouts = bumpalo::vec![
+        in self.env.arena;
+                arg_layouts[0],
+                Layout::U32,
+                Layout::U64,
+                arg_layouts[1],
+                arg_layouts[2],
+                Layout::U8,
+        ];
+        self.build_fn_call(
+            dst,
+            bitcode::LIST_SWAP.to_string(),
+            &lowlevel_args,
+            &lowlevel_arg_layouts,
+            ret_layout,
+        );
+        self.free_symbol(&Symbol::DEV_TMP);
+        self.free_symbol(&Symbol::DEV_TMP2);
+        self.free_symbol(&Symbol::DEV_TMP3);
+    }
+
+    fn build_num_read_bytes(
+        &mut self,
+        dst: &Symbol,
+        list: &Symbol,
+        position: &Symbol,
+        ret_layout: &InLayout<'a>,
+        big_endian: bool,
+    ) {
+        let size = self.layout_interner.stack_size(*ret_layout) as i32;
+        let (list_offset, _) = self.storage_manager.stack_offset_and_size(list);
+        let position_reg = self
+            .storage_manager
+            .load_to_general_reg(&mut self.buf, position);
+
+        // The number starts at `list.bytes + position`.
+        let ptr_reg = self
+            .storage_manager
+            .claim_general_reg(&mut self.buf, &Symbol::DEV_TMP);
+        ASM::mov_reg64_base32(&mut self.buf, ptr_reg, list_offset);
+        ASM::add_reg64_reg64_reg64(&mut self.buf, ptr_reg, ptr_reg, position_reg);
+
+        if big_endian && size > 1 {
+            // Reverse the bytes into a scratch area on the stack, then read the number from there.
+            // The last word of the big-endian bytes becomes the first word of the number.
+            let words = (size + 7) / 8;
+            let chunk = size.min(8);
+            let swap_offset = self
+                .storage_manager
+                .claim_stack_area(&Symbol::DEV_TMP2, 8 * words as u32);
+            self.storage_manager.with_tmp_general_reg(
+                &mut self.buf,
+                |_storage_manager, buf, tmp| {
+                    for i in 0..words {
+                        let offset = size - chunk * (i + 1);
+                        ASM::movzx_reg64_mem64_offset32(buf, tmp, ptr_reg, offset, chunk as u8);
+                        match chunk {
+                            2 => ASM::bswap_reg16_reg16(buf, tmp, tmp),
+                            4 => ASM::bswap_reg32_reg32(buf, tmp, tmp),
+                            _ => ASM::bswap_reg64_reg64(buf, tmp, tmp),
+                        }
+                        ASM::mov_base32_reg64(buf, swap_offset + 8 * i, tmp);
+                    }
+                },
+            );
+            self.storage_manager.load_from_memory(
+                self.layout_interner,
+                &mut self.buf,
+                dst,
+                CC::BASE_PTR_REG,
+                swap_offset,
+                ret_layout,
+            );
+            self.free_symbol(&Symbol::DEV_TMP2);
+        } else {
+            self.storage_manager.load_from_memory(
+                self.layout_interner,
+                &mut self.buf,
+                dst,
+                ptr_reg,
+                0,
+                ret_layout,
+            );
+        }
+
+        self.free_symbol(&Symbol::DEV_TMP);
+    }
+
+    fn build_num_write_bytes(
+        &mut self,
+        dst: &Symbol,
+        args: &'a [Symbol],
+        arg_layouts: &[InLayout<'a>],
+        ret_layout: &InLayout<'a>,
+        big_endian: bool,
+    ) {
+        let list = args[0];
+        let position = args[1];
+        let value = args[2];
+        let list_layout = arg_layouts[0];
+        let value_layout = arg_layouts[2];
+
+        // Without update modes we can't know whether the list is unique,
+        // so let the zig builtin copy it if it isn't.
+        let u32_layout = Layout::U32;
+        let u64_layout = Layout::U64;
+        self.load_literal(
+            &Symbol::DEV_TMP,
+            &u32_layout,
+            &Literal::Int(1i128.to_ne_bytes()),
+        );
+        self.load_literal(
+            &Symbol::DEV_TMP2,
+            &u64_layout,
+            &Literal::Int(1i128.to_ne_bytes()),
+        );
+        let lowlevel_args = bumpalo::vec![
+        in self.env.arena;
+            list,
+            Symbol::DEV_TMP,
+            Symbol::DEV_TMP2,
+         ];
+        let lowlevel_arg_layouts = bumpalo::vec![
+        in self.env.arena;
+                list_layout,
+                u32_layout,
+                u64_layout,
+        ];
+        self.build_fn_call(
+            dst,
+            bitcode::LIST_MAKE_UNIQUE.to_string(),
+            &lowlevel_args,
+            &lowlevel_arg_layouts,
+            ret_layout,
+        );
+        self.free_symbol(&Symbol::DEV_TMP);
+        self.free_symbol(&Symbol::DEV_TMP2);
+
+        let size = self.layout_interner.stack_size(value_layout) as i32;
+        self.storage_manager
+            .ensure_symbol_on_stack(&mut self.buf, &value);
+        let (value_offset, _) = self.storage_manager.stack_offset_and_size(&value);
+
+        let swapped = big_endian && size > 1;
+        let src_offset = if swapped {
+            // Reverse the bytes into a scratch area on the stack, then copy them from there.
+            // The first word of the number becomes the last word of the big-endian bytes.
+            let words = (size + 7) / 8;
+            let swap_offset = self
+                .storage_manager
+                .claim_stack_area(&Symbol::DEV_TMP2, 8 * words as u32);
+            self.storage_manager.with_tmp_general_reg(
+                &mut self.buf,
+                |_storage_manager, buf, tmp| {
+                    for i in 0..words {
+                        ASM::mov_reg64_base32(buf, tmp, value_offset + 8 * i);
+                        match size {
+                            2 => ASM::bswap_reg16_reg16(buf, tmp, tmp),
+                            4 => ASM::bswap_reg32_reg32(buf, tmp, tmp),
+                            _ => ASM::bswap_reg64_reg64(buf, tmp, tmp),
+                        }
+                        ASM::mov_base32_reg64(buf, swap_offset + 8 * (words - 1 - i), tmp);
+                    }
+                },
+            );
+            swap_offset
+        } else {
+            value_offset
+        };
+
+        // The number goes to `list.bytes + position`.
+        let (list_offset, _) = self.storage_manager.stack_offset_and_size(dst);
+        let position_reg = self
+            .storage_manager
+            .load_to_general_reg(&mut self.buf, &position);
+        let ptr_reg = self
+            .storage_manager
+            .claim_general_reg(&mut self.buf, &Symbol::DEV_TMP);
+        ASM::mov_reg64_base32(&mut self.buf, ptr_reg, list_offset);
+        ASM::add_reg64_reg64_reg64(&mut self.buf, ptr_reg, ptr_reg, position_reg);
+
+        // Only write the bytes of the number, the rest of the list must stay untouched.
+        self.storage_manager
+            .with_tmp_general_reg(&mut self.buf, |_storage_manager, buf, tmp| {
+                for offset in (0..size).step_by(8) {
+                    let chunk = (size - offset).min(8) as u8;
+                    ASM::movzx_reg64_base32(buf, tmp, src_offset + offset, chunk);
+                    ASM::movtrunc_mem64_offset32_reg64(buf, ptr_reg, offset, tmp, chunk);
+                }
+            });
+
+        self.free_symbol(&Symbol::DEV_TMP);
+        if swapped {
+            self.free_symbol(&Symbol::DEV_TMP2);
+        }
+    }
+
+    fn build_ptr_cast(&mut self, dst: &Symbol, src: &Symbol) {
+        if self.storage_manager.is_stored_primitive(src) {
+            // Pointers and integers are the same at runtime, so just copy the value.
+            let src_reg = self.storage_manager.load_to_general_reg(&mut self.buf, src);
+            let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
+            ASM::mov_reg64_reg64(&mut self.buf, dst_reg, src_reg);
+        } else {
+            // Complex values are cast to a pointer to where they live on the stack.
+            let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
+            self.storage_manager
+                .ensure_symbol_on_stack(&mut self.buf, src);
+            let (offset, _) = self.storage_manager.stack_offset_and_size(src);
+            ASM::add_reg64_reg64_imm32(&mut self.buf, dst_reg, CC::BASE_PTR_REG, offset);
+        }
+    }
+
+    fn build_ptr_store(
+        &mut self,
+        sym: &Symbol,
+        ptr: &Symbol,
+        value: &Symbol,
+        element_layout: &InLayout<'a>,
+    ) {
+        let ptr_reg = self.storage_manager.load_to_general_reg(&mut self.buf, ptr);
+        self.storage_manager.copy_symbol_to_memory(
+            self.layout_interner,
+            &mut self.buf,
+            ptr_reg,
+            0,
+            value,
+            element_layout,
+        );
+
+        // PtrStore returns unit.
+        self.create_struct(sym, &Layout::UNIT, &[]);
+    }
+
+    fn build_ptr_load(&mut self, sym: &Symbol, ptr: &Symbol, element_layout: &InLayout<'a>) {
+        let ptr_reg = self.storage_manager.load_to_general_reg(&mut self.buf, ptr);
+        self.storage_manager.load_from_memory(
+            self.layout_interner,
+            &mut self.buf,
+            sym,
+            ptr_reg,
+            0,
+            element_layout,
+        );
+    }
+
+    fn build_alloca(&mut self, sym: &Symbol, value: &Symbol, element_layout: &InLayout<'a>) {
+        let size = self.layout_interner.stack_size(*element_layout);
+        let base_offset = self.storage_manager.claim_anonymous_stack_area(size);
+        self.storage_manager.copy_symbol_to_memory(
+            self.layout_interner,
+            &mut self.buf,
+            CC::BASE_PTR_REG,
+            base_offset,
+            value,
+            element_layout,
+        );
+
+        let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, sym);
+        ASM::add_reg64_reg64_imm32(&mut self.buf, dst_reg, CC::BASE_PTR_REG, base_offset);
+    }
+
+    fn create_empty_array(&mut self, sym: &Symbol) {
+        let base_offset = self.storage_manager.claim_stack_area(sym, 24);
+        self.storage_manager
+            .with_tmp_general_reg(&mut self.buf, |_storage_manager, buf, reg| {
+                ASM::mov_reg64_imm64(buf, reg, 0);
+                ASM::mov_base32_reg64(buf, base_offset, reg);
+                ASM::mov_base32_reg64(buf, base_offset + 8, reg);
+                ASM::mov_base32_reg64(buf, base_offset + 16, reg);
+            });
+    }
+
+    fn create_array(
+        &mut self,
+        sym: &Symbol,
+        elem_layout: &InLayout<'a>,
+        elems: &'a [ListLiteralElement<'a>],
+    ) {
+        // Allocate
+        // This requires at least 8 for the refcount alignment.
+        let allocation_alignment = std::cmp::max(
+            8,
+            self.layout_interner
+                .allocation_alignment_bytes(*elem_layout) as u64,
+        );
+
+        // A list of literals can be shared by every evaluation of this expression.
+        // load_static_data_address puts a zeroed refcount (REFCOUNT_MAX) in front of it,
+        // so refcounting never frees it, and it is never unique, so List builtins copy it
+        // before writing to it.
+        let layout = self.layout_interner.get(*elem_layout);
+        let static_bytes = elems
+            .iter()
+            .try_fold(std::vec::Vec::new(), |mut bytes, elem| {
+                match elem {
+                    ListLiteralElement::Literal(lit) => bytes.extend(lit.to_le_bytes(&layout)?),
+                    ListLiteralElement::Symbol(_) => return None,
+                }
+
+                Some(bytes)
+            });
+
+        let ptr_reg = if let Some(bytes) = static_bytes {
+            let ptr_reg = self
+                .storage_manager
+                .claim_general_reg(&mut self.buf, &Symbol::DEV_TMP3);
+            Self::load_static_data_address(
+                &mut self.buf,
+                &mut self.relocs,
+                ptr_reg,
+                allocation_alignment,
+                &bytes,
+            );
+
+            ptr_reg
+        } else {
+            let elem_size = self.layout_interner.stack_size(*elem_layout) as u64;
+            self.allocate_with_refcount(
+                &Symbol::DEV_TMP3,
+                elem_size * elems.len() as u64,
+                allocation_alignment,
+            );
+
+            // Fill pointer with elems
+            let ptr_reg = self
+                .storage_manager
+                .load_to_general_reg(&mut self.buf, &Symbol::DEV_TMP3);
+
+            // Copy everything into output array.
+            let mut elem_offset = 0;
+            for elem in elems {
+                // TODO: this could be a lot faster when loading large lists
+                // if we move matching on the element layout to outside this loop.
+                // We also could make loadining indivitual literals much faster
+                let elem_sym = match elem {
+                    ListLiteralElement::Symbol(sym) => sym,
+                    ListLiteralElement::Literal(lit) => {
+                        self.load_literal(&Symbol::DEV_TMP, elem_layout, lit);
+                        &Symbol::DEV_TMP
+                    }
+                };
+                self.storage_manager.copy_symbol_to_memory(
+                    self.layout_interner,
+                    &mut self.buf,
+                    ptr_reg,
+                    elem_offset,
+                    elem_sym,
+                    elem_layout,
+                );
+                elem_offset += elem_size as i32;
+                if elem_sym == &Symbol::DEV_TMP {
+                    self.free_symbol(elem_sym);
+                }
+            }
+
+            ptr_reg
+        };
+
+        // Setup list on stack.
+        self.storage_manager.with_tmp_general_reg(
+            &mut self.buf,
+            |storage_manager, buf, tmp_reg| {
+                let base_offset = storage_manager.claim_stack_area(sym, 24);
+                ASM::mov_base32_reg64(buf, base_offset, ptr_reg);
+
+                ASM::mov_reg64_imm64(buf, tmp_reg, elems.len() as i64);
+                ASM::mov_base32_reg64(buf, base_offset + 8, tmp_reg);
+                ASM::mov_base32_reg64(buf, base_offset + 16, tmp_reg);
+            },
+        );
+        self.free_symbol(&Symbol::DEV_TMP3);
+    }
+
+    fn create_struct(&mut self, sym: &Symbol, layout: &InLayout<'a>, fields: &'a [Symbol]) {
+        self.storage_manager.create_struct(
+            self.layout_interner,
+            &mut self.buf,
+            sym,
+            layout,
+            fields,
+        );
+    }
+
+    fn load_struct_at_index(
+        &mut self,
+        sym: &Symbol,
+        structure: &Symbol,
+        index: u64,
+        field_layouts: &'a [InLayout<'a>],
+    ) {
+        self.storage_manager.load_field_at_index(
+            self.layout_interner,
+            sym,
             structure,
             index,
             field_layouts,
@@ -1603,7 +2472,7 @@ impl<
         union_layout: &UnionLayout<'a>,
     ) {
         match union_layout {
-            UnionLayout::NonRecursive(tag_layouts) | UnionLayout::Recursive(tag_layouts) => {
+            UnionLayout::NonRecursive(tag_layouts) => {
                 self.storage_manager.load_field_at_index(
                     self.layout_interner,
                     sym,
@@ -1612,18 +2481,59 @@ impl<
                     tag_layouts[tag_id as usize],
                 );
             }
-            x => todo!("loading from union type: {:?}", x),
+            _ => {
+                let field_layouts = heap_union_field_layouts(union_layout, tag_id);
+                let offset: u32 = field_layouts
+                    .iter()
+                    .take(index as usize)
+                    .map(|layout| self.layout_interner.stack_size(*layout))
+                    .sum();
+                let ptr_reg =
+                    self.load_union_data_pointer(&Symbol::DEV_TMP, structure, union_layout);
+                self.storage_manager.load_from_memory(
+                    self.layout_interner,
+                    &mut self.buf,
+                    sym,
+                    ptr_reg,
+                    offset as i32,
+                    &field_layouts[index as usize],
+                );
+                self.free_symbol(&Symbol::DEV_TMP);
+            }
         }
     }
 
+    fn load_union_field_ptr_at_index(
+        &mut self,
+        sym: &Symbol,
+        structure: &Symbol,
+        tag_id: TagIdIntType,
+        index: u64,
+        union_layout: &UnionLayout<'a>,
+    ) {
+        let field_layouts = heap_union_field_layouts(union_layout, tag_id);
+        let offset: u32 = field_layouts
+            .iter()
+            .take(index as usize)
+            .map(|layout| self.layout_interner.stack_size(*layout))
+            .sum();
+        let ptr_reg = self.load_union_data_pointer(sym, structure, union_layout);
+        ASM::add_reg64_reg64_imm32(&mut self.buf, ptr_reg, ptr_reg, offset as i32);
+    }
+
     fn get_tag_id(&mut self, sym: &Symbol, structure: &Symbol, union_layout: &UnionLayout<'a>) {
-        self.storage_manager.load_union_tag_id(
-            self.layout_interner,
-            &mut self.buf,
-            sym,
-            structure,
-            union_layout,
-        );
+        match union_layout {
+            UnionLayout::NonRecursive(_) => {
+                self.storage_manager.load_union_tag_id(
+                    self.layout_interner,
+                    &mut self.buf,
+                    sym,
+                    structure,
+                    union_layout,
+                );
+            }
+            _ => self.load_heap_union_tag_id(sym, structure, union_layout),
+        }
     }
 
     fn tag(
@@ -1633,18 +2543,32 @@ impl<
         union_layout: &UnionLayout<'a>,
         tag_id: TagIdIntType,
     ) {
-        self.storage_manager.create_union(
-            self.layout_interner,
-            &mut self.buf,
-            sym,
-            union_layout,
-            fields,
-            tag_id,
-        )
+        match union_layout {
+            UnionLayout::NonRecursive(_) => self.storage_manager.create_union(
+                self.layout_interner,
+                &mut self.buf,
+                sym,
+                union_layout,
+                fields,
+                tag_id,
+            ),
+            _ => self.create_heap_union(sym, fields, union_layout, tag_id),
+        }
+    }
+
+    fn load_null_pointer(&mut self, sym: &Symbol) {
+        let reg = self.storage_manager.claim_general_reg(&mut self.buf, sym);
+        ASM::mov_reg64_imm64(&mut self.buf, reg, 0);
     }
 
     fn load_literal(&mut self, sym: &Symbol, layout: &InLayout<'a>, lit: &Literal<'a>) {
-        match (lit, self.layout_interner.get(*layout)) {
+        // Enum-like lambda sets are represented by their tag id.
+        let layout = self
+            .layout_interner
+            .get(*layout)
+            .runtime_representation(self.layout_interner);
+
+        match (lit, layout) {
             (
                 Literal::Int(x),
                 Layout::Builtin(Builtin::Int(
@@ -1662,10 +2586,50 @@ impl<
                 let val = *x;
                 ASM::mov_reg64_imm64(&mut self.buf, reg, i128::from_ne_bytes(val) as i64);
             }
+            (
+                Literal::Int(bytes) | Literal::U128(bytes),
+                Layout::Builtin(Builtin::Int(IntWidth::I128 | IntWidth::U128)),
+            )
+            | (Literal::Decimal(bytes), Layout::Builtin(Builtin::Decimal)) => {
+                // 128-bit values live on the stack, so store them as two 64-bit halves.
+                self.storage_manager.with_tmp_general_reg(
+                    &mut self.buf,
+                    |storage_manager, buf, reg| {
+                        let base_offset = storage_manager.claim_stack_area(sym, 16);
+
+                        let mut num_bytes = [0; 8];
+                        num_bytes.copy_from_slice(&bytes[..8]);
+                        let num = i64::from_ne_bytes(num_bytes);
+                        ASM::mov_reg64_imm64(buf, reg, num);
+                        ASM::mov_base32_reg64(buf, base_offset, reg);
+
+                        num_bytes.copy_from_slice(&bytes[8..16]);
+                        let num = i64::from_ne_bytes(num_bytes);
+                        ASM::mov_reg64_imm64(buf, reg, num);
+                        ASM::mov_base32_reg64(buf, base_offset + 8, reg);
+                    },
+                );
+            }
             (Literal::Bool(x), Layout::Builtin(Builtin::Bool)) => {
                 let reg = self.storage_manager.claim_general_reg(&mut self.buf, sym);
-                let val = [*x as u8; 16];
-                ASM::mov_reg64_imm64(&mut self.buf, reg, i128::from_ne_bytes(val) as i64);
+                let val = [*x as u8; 16];
+                ASM::mov_reg64_imm64(&mut self.buf, reg, i128::from_ne_bytes(val) as i64);
+            }
+            (
+                Literal::Byte(x),
+                Layout::Builtin(Builtin::Int(
+                    IntWidth::U8
+                    | IntWidth::U16
+                    | IntWidth::U32
+                    | IntWidth::U64
+                    | IntWidth::I8
+                    | IntWidth::I16
+                    | IntWidth::I32
+                    | IntWidth::I64,
+                )),
+            ) => {
+                let reg = self.storage_manager.claim_general_reg(&mut self.buf, sym);
+                ASM::mov_reg64_imm64(&mut self.buf, reg, *x as i64);
             }
             (Literal::Float(x), Layout::Builtin(Builtin::Float(FloatWidth::F64))) => {
                 let reg = self.storage_manager.claim_float_reg(&mut self.buf, sym);
@@ -1705,7 +2669,26 @@ impl<
                     },
                 );
             }
-            x => todo!("loading literal, {:?}", x),
+            (Literal::Str(x), Layout::Builtin(Builtin::Str)) => {
+                // Large strings live in the data section, like list literals.
+                let relocs = &mut self.relocs;
+                self.storage_manager.with_tmp_general_reg(
+                    &mut self.buf,
+                    |storage_manager, buf, reg| {
+                        let base_offset = storage_manager.claim_stack_area(sym, 24);
+
+                        Self::load_static_data_address(buf, relocs, reg, 8, x.as_bytes());
+                        ASM::mov_base32_reg64(buf, base_offset, reg);
+
+                        ASM::mov_reg64_imm64(buf, reg, x.len() as i64);
+                        ASM::mov_base32_reg64(buf, base_offset + 8, reg);
+                        ASM::mov_base32_reg64(buf, base_offset + 16, reg);
+                    },
+                );
+            }
+            (lit, layout) => {
+                internal_error!("Cannot load literal {:?} with layout {:?}", lit, layout)
+            }
         }
     }
 
@@ -1714,10 +2697,33 @@ impl<
         self.storage_manager.free_symbol(sym);
     }
 
+    fn build_crash(&mut self, msg: &Symbol, tag: CrashTag) {
+        // roc_panic takes the message by reference.
+        self.build_ptr_cast(&Symbol::DEV_TMP, msg);
+        self.load_literal(
+            &Symbol::DEV_TMP2,
+            &Layout::U32,
+            &Literal::Int((tag as i128).to_ne_bytes()),
+        );
+
+        // roc_panic never returns, so there is no need to jump to the end of the function.
+        self.build_fn_call(
+            &Symbol::DEV_TMP3,
+            "roc_panic".to_string(),
+            &[Symbol::DEV_TMP, Symbol::DEV_TMP2],
+            &[Layout::U64, Layout::U32],
+            &Layout::UNIT,
+        );
+
+        self.free_symbol(&Symbol::DEV_TMP);
+        self.free_symbol(&Symbol::DEV_TMP2);
+        self.free_symbol(&Symbol::DEV_TMP3);
+    }
+
     fn return_symbol(&mut self, sym: &Symbol, layout: &InLayout<'a>) {
         if self.storage_manager.is_stored_primitive(sym) {
             // Just load it to the correct type of reg as a stand alone value.
-            match *layout {
+            match register_layout(self.layout_interner, *layout) {
                 single_register_integers!() => {
                     self.storage_manager.load_to_specified_general_reg(
                         &mut self.buf,
@@ -1761,11 +2767,12 @@ impl<
         src2: &Symbol,
         int_width: IntWidth,
     ) {
-        let buf = &mut self.buf;
-
         match int_width {
-            IntWidth::U128 | IntWidth::I128 => todo!(),
+            IntWidth::U128 | IntWidth::I128 => {
+                self.build_int128_bitwise(dst, src1, src2, ASM::and_reg64_reg64_reg64)
+            }
             _ => {
+                let buf = &mut self.buf;
                 let dst_reg = self.storage_manager.claim_general_reg(buf, dst);
                 let src1_reg = self.storage_manager.load_to_general_reg(buf, src1);
                 let src2_reg = self.storage_manager.load_to_general_reg(buf, src2);
@@ -1781,11 +2788,12 @@ impl<
         src2: &Symbol,
         int_width: IntWidth,
     ) {
-        let buf = &mut self.buf;
-
         match int_width {
-            IntWidth::U128 | IntWidth::I128 => todo!(),
+            IntWidth::U128 | IntWidth::I128 => {
+                self.build_int128_bitwise(dst, src1, src2, ASM::or_reg64_reg64_reg64)
+            }
             _ => {
+                let buf = &mut self.buf;
                 let dst_reg = self.storage_manager.claim_general_reg(buf, dst);
                 let src1_reg = self.storage_manager.load_to_general_reg(buf, src1);
                 let src2_reg = self.storage_manager.load_to_general_reg(buf, src2);
@@ -1801,11 +2809,12 @@ impl<
         src2: &Symbol,
         int_width: IntWidth,
     ) {
-        let buf = &mut self.buf;
-
         match int_width {
-            IntWidth::U128 | IntWidth::I128 => todo!(),
+            IntWidth::U128 | IntWidth::I128 => {
+                self.build_int128_bitwise(dst, src1, src2, ASM::xor_reg64_reg64_reg64)
+            }
             _ => {
+                let buf = &mut self.buf;
                 let dst_reg = self.storage_manager.claim_general_reg(buf, dst);
                 let src1_reg = self.storage_manager.load_to_general_reg(buf, src1);
                 let src2_reg = self.storage_manager.load_to_general_reg(buf, src2);
@@ -1826,7 +2835,519 @@ impl<
         CC: CallConv<GeneralReg, FloatReg, ASM>,
     > Backend64Bit<'a, 'r, GeneralReg, FloatReg, ASM, CC>
 {
+    /// Puts `bytes` in the data section after a refcount of zero, which marks them as read-only,
+    /// and loads the address of the first byte after the refcount into `reg`.
+    ///
+    /// `alignment` is the space reserved for the refcount, like in a heap allocation.
+    fn load_static_data_address(
+        buf: &mut Vec<'a, u8>,
+        relocs: &mut Vec<'a, Relocation>,
+        reg: GeneralReg,
+        alignment: u64,
+        bytes: &[u8],
+    ) {
+        let mut data = vec![0; alignment as usize];
+        data.extend_from_slice(bytes);
+
+        ASM::mov_reg64_local_data_address(buf, relocs, reg, data);
+        ASM::add_reg64_reg64_imm32(buf, reg, reg, alignment as i32);
+    }
+
+    /// The layout of the elements of a list of layout `list_layout`.
+    fn list_element_layout(&self, list_layout: InLayout<'a>) -> InLayout<'a> {
+        match self.layout_interner.get(list_layout) {
+            Layout::Builtin(Builtin::List(elem_layout)) => elem_layout,
+            x => internal_error!("Expected a list layout but found: {:?}", x),
+        }
+    }
+
+    /// Loads the alignment of an element as a U32, which the zig list builtins use to allocate.
+    fn load_element_alignment(&mut self, sym: &Symbol, elem_layout: InLayout<'a>) {
+        let alignment = self.layout_interner.alignment_bytes(elem_layout);
+        self.load_literal(
+            sym,
+            &Layout::U32,
+            &Literal::Int((alignment as i128).to_ne_bytes()),
+        );
+    }
+
+    /// Loads the stack size of an element as a U64, which the zig list builtins use to index.
+    fn load_element_width(&mut self, sym: &Symbol, elem_layout: InLayout<'a>) {
+        let width = self.layout_interner.stack_size(elem_layout);
+        self.load_literal(
+            sym,
+            &Layout::U64,
+            &Literal::Int((width as i128).to_ne_bytes()),
+        );
+    }
+
+    /// The zig list builtins take elements by pointer, so put `elem` on the stack and load its address.
+    fn load_element_address(&mut self, sym: &Symbol, elem: &Symbol) {
+        self.storage_manager
+            .ensure_symbol_on_stack(&mut self.buf, elem);
+        let (elem_offset, _) = self.storage_manager.stack_offset_and_size(elem);
+        let reg = self.storage_manager.claim_general_reg(&mut self.buf, sym);
+        ASM::add_reg64_reg64_imm32(&mut self.buf, reg, CC::BASE_PTR_REG, elem_offset);
+    }
+
+    /// Loads `UpdateMode.Immutable` for the zig list builtins that take an update mode.
+    /// Without update modes we can't know whether a list is unique, so the builtin has to check.
+    fn load_update_mode(&mut self, sym: &Symbol) {
+        self.load_literal(sym, &Layout::U8, &Literal::Int(0i128.to_ne_bytes()));
+    }
+
+    /// Stores in `dst` whether `src1 <operation> src2` holds for values of layout `arg_layout`.
+    /// Layouts other than numbers can only be checked for (in)equality.
+    fn build_compare(
+        &mut self,
+        dst: &Symbol,
+        src1: &Symbol,
+        src2: &Symbol,
+        arg_layout: &InLayout<'a>,
+        operation: CompareOperation,
+    ) {
+        use CompareOperation::*;
+
+        match *arg_layout {
+            Layout::BOOL | Layout::U8 | Layout::U16 | Layout::U32 | Layout::U64 => {
+                let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
+                let src1_reg = self
+                    .storage_manager
+                    .load_to_general_reg(&mut self.buf, src1);
+                let src2_reg = self
+                    .storage_manager
+                    .load_to_general_reg(&mut self.buf, src2);
+                let buf = &mut self.buf;
+                match operation {
+                    Equal => ASM::eq_reg64_reg64_reg64(buf, dst_reg, src1_reg, src2_reg),
+                    NotEqual => ASM::neq_reg64_reg64_reg64(buf, dst_reg, src1_reg, src2_reg),
+                    LessThan => ASM::ult_reg64_reg64_reg64(buf, dst_reg, src1_reg, src2_reg),
+                    LessThanOrEqual => {
+                        ASM::ulte_reg64_reg64_reg64(buf, dst_reg, src1_reg, src2_reg)
+                    }
+                    GreaterThan => ASM::ugt_reg64_reg64_reg64(buf, dst_reg, src1_reg, src2_reg),
+                    GreaterThanOrEqual => {
+                        ASM::ugte_reg64_reg64_reg64(buf, dst_reg, src1_reg, src2_reg)
+                    }
+                }
+            }
+            Layout::I8 | Layout::I16 | Layout::I32 | Layout::I64 => {
+                let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
+                let src1_reg = self
+                    .storage_manager
+                    .load_to_general_reg(&mut self.buf, src1);
+                let src2_reg = self
+                    .storage_manager
+                    .load_to_general_reg(&mut self.buf, src2);
+                let buf = &mut self.buf;
+                match operation {
+                    Equal => ASM::eq_reg64_reg64_reg64(buf, dst_reg, src1_reg, src2_reg),
+                    NotEqual => ASM::neq_reg64_reg64_reg64(buf, dst_reg, src1_reg, src2_reg),
+                    LessThan => ASM::ilt_reg64_reg64_reg64(buf, dst_reg, src1_reg, src2_reg),
+                    LessThanOrEqual => ASM::lte_reg64_reg64_reg64(buf, dst_reg, src1_reg, src2_reg),
+                    GreaterThan => ASM::igt_reg64_reg64_reg64(buf, dst_reg, src1_reg, src2_reg),
+                    GreaterThanOrEqual => {
+                        ASM::gte_reg64_reg64_reg64(buf, dst_reg, src1_reg, src2_reg)
+                    }
+                }
+            }
+            Layout::F32 | Layout::F64 => {
+                let width = if *arg_layout == Layout::F32 {
+                    FloatWidth::F32
+                } else {
+                    FloatWidth::F64
+                };
+                let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
+                let src1_reg = self.storage_manager.load_to_float_reg(&mut self.buf, src1);
+                let src2_reg = self.storage_manager.load_to_float_reg(&mut self.buf, src2);
+                ASM::cmp_freg_freg_reg64(
+                    &mut self.buf,
+                    dst_reg,
+                    src1_reg,
+                    src2_reg,
+                    width,
+                    operation,
+                );
+            }
+            Layout::I128 | Layout::U128 | Layout::DEC => {
+                // A Dec is an I128 scaled by a constant, so it is ordered the same way.
+                let int_width = if *arg_layout == Layout::U128 {
+                    IntWidth::U128
+                } else {
+                    IntWidth::I128
+                };
+                self.build_fn_call(
+                    &Symbol::DEV_TMP,
+                    bitcode::NUM_COMPARE[int_width].to_string(),
+                    &[*src1, *src2],
+                    &[*arg_layout, *arg_layout],
+                    &Layout::U8,
+                );
+
+                // The call returns the tag id of `[EQ, GT, LT]`.
+                // Each operation either requires or excludes exactly one of them.
+                let (tag_id, is_required) = match operation {
+                    Equal => (0, true),
+                    NotEqual => (0, false),
+                    GreaterThan => (1, true),
+                    LessThanOrEqual => (1, false),
+                    LessThan => (2, true),
+                    GreaterThanOrEqual => (2, false),
+                };
+                self.load_literal(
+                    &Symbol::DEV_TMP2,
+                    &Layout::U64,
+                    &Literal::Int((tag_id as i128).to_ne_bytes()),
+                );
+                self.load_literal(
+                    &Symbol::DEV_TMP3,
+                    &Layout::U64,
+                    &Literal::Int(0xFFi128.to_ne_bytes()),
+                );
+
+                let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
+                let order_reg = self
+                    .storage_manager
+                    .load_to_general_reg(&mut self.buf, &Symbol::DEV_TMP);
+                let tag_id_reg = self
+                    .storage_manager
+                    .load_to_general_reg(&mut self.buf, &Symbol::DEV_TMP2);
+                let mask_reg = self
+                    .storage_manager
+                    .load_to_general_reg(&mut self.buf, &Symbol::DEV_TMP3);
+
+                // Only the low byte of a returned u8 is defined.
+                ASM::and_reg64_reg64_reg64(&mut self.buf, order_reg, order_reg, mask_reg);
+                if is_required {
+                    ASM::eq_reg64_reg64_reg64(&mut self.buf, dst_reg, order_reg, tag_id_reg);
+                } else {
+                    ASM::neq_reg64_reg64_reg64(&mut self.buf, dst_reg, order_reg, tag_id_reg);
+                }
+
+                self.free_symbol(&Symbol::DEV_TMP);
+                self.free_symbol(&Symbol::DEV_TMP2);
+                self.free_symbol(&Symbol::DEV_TMP3);
+            }
+            _ if !matches!(operation, Equal | NotEqual) => {
+                internal_error!(
+                    "{:?}: only numbers can be ordered, got {:?}",
+                    operation,
+                    self.layout_interner.get(*arg_layout)
+                )
+            }
+            _ => {
+                let eq_dst = if operation == Equal {
+                    *dst
+                } else {
+                    Symbol::DEV_TMP
+                };
+
+                if *arg_layout == Layout::STR {
+                    self.build_fn_call(
+                        &eq_dst,
+                        bitcode::STR_EQUAL.to_string(),
+                        &[*src1, *src2],
+                        &[*arg_layout, *arg_layout],
+                        &Layout::BOOL,
+                    );
+                } else {
+                    // Everything else gets a specialized helper proc, like refcounting does.
+                    let arguments = self.env.arena.alloc([*src1, *src2]);
+                    let (eq_expr, new_specializations) = {
+                        let (module_id, layout_interner, interns, helper_proc_gen) =
+                            self.module_interns_helpers_mut();
+                        let ident_ids = interns.all_ident_ids.get_mut(&module_id).unwrap();
+
+                        helper_proc_gen.call_specialized_equals(
+                            ident_ids,
+                            layout_interner,
+                            *arg_layout,
+                            arguments,
+                        )
+                    };
+
+                    for spec in new_specializations.into_iter() {
+                        self.helper_proc_symbols.push(spec);
+                    }
+
+                    self.build_expr(&eq_dst, &eq_expr, &Layout::BOOL);
+                }
+
+                if operation == NotEqual {
+                    self.load_literal(&Symbol::DEV_TMP2, &Layout::BOOL, &Literal::Bool(false));
+
+                    let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
+                    let eq_reg = self
+                        .storage_manager
+                        .load_to_general_reg(&mut self.buf, &Symbol::DEV_TMP);
+                    let false_reg = self
+                        .storage_manager
+                        .load_to_general_reg(&mut self.buf, &Symbol::DEV_TMP2);
+                    ASM::eq_reg64_reg64_reg64(&mut self.buf, dst_reg, eq_reg, false_reg);
+
+                    self.free_symbol(&Symbol::DEV_TMP);
+                    self.free_symbol(&Symbol::DEV_TMP2);
+                }
+            }
+        }
+    }
+
+    /// Applies the 64-bit bitwise `op` to the low and high halves of two 128-bit integers.
+    fn build_int128_bitwise(
+        &mut self,
+        dst: &Symbol,
+        src1: &Symbol,
+        src2: &Symbol,
+        op: fn(&mut Vec<'a, u8>, GeneralReg, GeneralReg, GeneralReg),
+    ) {
+        let (src1_offset, _) = self.storage_manager.stack_offset_and_size(src1);
+        let (src2_offset, _) = self.storage_manager.stack_offset_and_size(src2);
+        let dst_offset = self.storage_manager.claim_stack_area(dst, 16);
+
+        self.storage_manager
+            .with_tmp_general_reg(&mut self.buf, |storage_manager, buf, tmp1| {
+                storage_manager.with_tmp_general_reg(buf, |_storage_manager, buf, tmp2| {
+                    for half_offset in [0, 8] {
+                        ASM::mov_reg64_base32(buf, tmp1, src1_offset + half_offset);
+                        ASM::mov_reg64_base32(buf, tmp2, src2_offset + half_offset);
+                        op(buf, tmp1, tmp1, tmp2);
+                        ASM::mov_base32_reg64(buf, dst_offset + half_offset, tmp1);
+                    }
+                });
+            });
+    }
+
     /// Updates a jump instruction to a new offset and returns the number of bytes written.
+    /// Allocates `data_bytes` on the heap with a refcount of one in front of the data.
+    /// `dst` is set to a pointer to the data.
+    fn allocate_with_refcount(&mut self, dst: &Symbol, data_bytes: u64, alignment: u64) {
+        // This requires at least 8 for the refcount alignment.
+        debug_assert!(alignment >= 8);
+
+        // Memory is written in 64 bit chunks, so round the data up to a full chunk.
+        let data_bytes = (data_bytes + 7) & !7;
+        let allocation_size = data_bytes + alignment /* add space for refcount */;
+        let u64_layout = Layout::U64;
+        self.load_literal(
+            &Symbol::DEV_TMP,
+            &u64_layout,
+            &Literal::Int((allocation_size as i128).to_ne_bytes()),
+        );
+        let u32_layout = Layout::U32;
+        self.load_literal(
+            &Symbol::DEV_TMP2,
+            &u32_layout,
+            &Literal::Int((alignment as i128).to_ne_bytes()),
+        );
+
+        let alloc_fn_name = if self.env.check_memory {
+            bitcode::UTILS_MEMCHECK_ALLOC
+        } else {
+            "roc_alloc"
+        };
+
+        self.build_fn_call(
+            dst,
+            alloc_fn_name.to_string(),
+            &[Symbol::DEV_TMP, Symbol::DEV_TMP2],
+            &[u64_layout, u32_layout],
+            &u64_layout,
+        );
+        self.free_symbol(&Symbol::DEV_TMP);
+        self.free_symbol(&Symbol::DEV_TMP2);
+
+        // Point to the data after the refcount.
+        let ptr_reg = self.storage_manager.load_to_general_reg(&mut self.buf, dst);
+        ASM::add_reg64_reg64_imm32(&mut self.buf, ptr_reg, ptr_reg, alignment as i32);
+
+        // fill refcount at -8.
+        self.storage_manager.with_tmp_general_reg(
+            &mut self.buf,
+            |_storage_manager, buf, tmp_reg| {
+                ASM::mov_reg64_imm64(buf, tmp_reg, REFCOUNT_ONE as i64);
+                ASM::mov_mem64_offset32_reg64(buf, ptr_reg, -8, tmp_reg);
+            },
+        );
+    }
+
+    /// Loads the pointer to the data of a heap-allocated union into `dst`, clearing any tag id bits.
+    fn load_union_data_pointer(
+        &mut self,
+        dst: &Symbol,
+        structure: &Symbol,
+        union_layout: &UnionLayout<'a>,
+    ) -> GeneralReg {
+        let ptr_reg = self
+            .storage_manager
+            .load_to_general_reg(&mut self.buf, structure);
+        let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
+        if union_layout.stores_tag_id_in_pointer(self.target_info) {
+            ASM::mov_reg64_imm64(&mut self.buf, dst_reg, TAG_ID_POINTER_CLEAR_MASK);
+            ASM::and_reg64_reg64_reg64(&mut self.buf, dst_reg, dst_reg, ptr_reg);
+        } else {
+            ASM::mov_reg64_reg64(&mut self.buf, dst_reg, ptr_reg);
+        }
+        dst_reg
+    }
+
+    /// Allocates a heap-allocated union, copies the fields into it, and tags it.
+    fn create_heap_union(
+        &mut self,
+        sym: &Symbol,
+        fields: &'a [Symbol],
+        union_layout: &UnionLayout<'a>,
+        tag_id: TagIdIntType,
+    ) {
+        if union_layout.tag_is_null(tag_id) {
+            self.load_null_pointer(sym);
+            return;
+        }
+
+        let target_info = self.target_info;
+        let (data_size, _) =
+            union_layout.data_size_and_alignment(self.layout_interner, target_info);
+        let tag_id_offset = union_layout
+            .tag_id_offset(self.layout_interner, target_info)
+            .filter(|_| union_layout.stores_tag_id_as_data(target_info));
+        // The tag id is written as a full 64 bit chunk.
+        let data_size = tag_id_offset.map_or(data_size, |offset| data_size.max(offset + 8));
+        let alignment = union_layout.allocation_alignment_bytes(self.layout_interner, target_info);
+        self.allocate_with_refcount(sym, data_size as u64, alignment as u64);
+
+        let ptr_reg = self.storage_manager.load_to_general_reg(&mut self.buf, sym);
+        let mut offset = 0;
+        for (field, field_layout) in fields
+            .iter()
+            .zip(heap_union_field_layouts(union_layout, tag_id))
+        {
+            self.storage_manager.copy_symbol_to_memory(
+                self.layout_interner,
+                &mut self.buf,
+                ptr_reg,
+                offset,
+                field,
+                field_layout,
+            );
+            offset += self.layout_interner.stack_size(*field_layout) as i32;
+        }
+
+        if let Some(tag_id_offset) = tag_id_offset {
+            self.storage_manager.with_tmp_general_reg(
+                &mut self.buf,
+                |_storage_manager, buf, tmp_reg| {
+                    ASM::mov_reg64_imm64(buf, tmp_reg, tag_id as i64);
+                    ASM::mov_mem64_offset32_reg64(buf, ptr_reg, tag_id_offset as i32, tmp_reg);
+                },
+            );
+        } else if union_layout.stores_tag_id_in_pointer(target_info) && tag_id != 0 {
+            self.storage_manager.with_tmp_general_reg(
+                &mut self.buf,
+                |_storage_manager, buf, tmp_reg| {
+                    ASM::mov_reg64_imm64(buf, tmp_reg, tag_id as i64);
+                    ASM::or_reg64_reg64_reg64(buf, ptr_reg, ptr_reg, tmp_reg);
+                },
+            );
+        }
+    }
+
+    /// Loads the tag id of a heap-allocated union.
+    fn load_heap_union_tag_id(
+        &mut self,
+        sym: &Symbol,
+        structure: &Symbol,
+        union_layout: &UnionLayout<'a>,
+    ) {
+        let target_info = self.target_info;
+        match union_layout {
+            UnionLayout::NonRecursive(_) => {
+                internal_error!("NonRecursive unions are not heap-allocated")
+            }
+            UnionLayout::NonNullableUnwrapped(_) => {
+                let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, sym);
+                ASM::mov_reg64_imm64(&mut self.buf, dst_reg, 0);
+            }
+            UnionLayout::NullableUnwrapped { nullable_id, .. } => {
+                // The null pointer has tag id `nullable_id` and the allocated value has the other one.
+                let ptr_reg = self
+                    .storage_manager
+                    .load_to_general_reg(&mut self.buf, structure);
+                let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, sym);
+                self.storage_manager.with_tmp_general_reg(
+                    &mut self.buf,
+                    |_storage_manager, buf, tmp_reg| {
+                        ASM::mov_reg64_imm64(buf, tmp_reg, 0);
+                        if *nullable_id {
+                            ASM::eq_reg64_reg64_reg64(buf, dst_reg, ptr_reg, tmp_reg);
+                        } else {
+                            ASM::neq_reg64_reg64_reg64(buf, dst_reg, ptr_reg, tmp_reg);
+                        }
+                    },
+                );
+            }
+            UnionLayout::Recursive(_) | UnionLayout::NullableWrapped { .. } => {
+                let nullable_id = match union_layout {
+                    UnionLayout::NullableWrapped { nullable_id, .. } => Some(*nullable_id),
+                    _ => None,
+                };
+                let ptr_reg = self
+                    .storage_manager
+                    .load_to_general_reg(&mut self.buf, structure);
+                let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, sym);
+                let tmp_reg = self
+                    .storage_manager
+                    .claim_general_reg(&mut self.buf, &Symbol::DEV_TMP);
+
+                // The null pointer must not be read. Instead, it has tag id `nullable_id`.
+                // The jumps stay within this expression, so the storage state is the same either way.
+                let mut tmp = bumpalo::vec![in self.env.arena];
+                let null_jumps = nullable_id.map(|nullable_id| {
+                    ASM::mov_reg64_imm64(&mut self.buf, dst_reg, nullable_id as i64);
+                    let jne_location = self.buf.len();
+                    let jne_offset = ASM::jne_reg64_imm64_imm32(&mut self.buf, ptr_reg, 0, 0);
+                    let jmp_location = self.buf.len();
+                    let jmp_offset = ASM::jmp_imm32(&mut self.buf, 0x1234_5678);
+                    (jne_location, jne_offset, jmp_location, jmp_offset)
+                });
+                if let Some((jne_location, jne_offset, _, _)) = null_jumps {
+                    tmp.clear();
+                    let offset = self.buf.len() - jne_offset;
+                    ASM::jne_reg64_imm64_imm32(&mut tmp, ptr_reg, 0, offset as i32);
+                    for (i, byte) in tmp.iter().enumerate() {
+                        self.buf[jne_location + i] = *byte;
+                    }
+                }
+
+                if union_layout.stores_tag_id_in_pointer(target_info) {
+                    ASM::mov_reg64_imm64(&mut self.buf, tmp_reg, TAG_ID_POINTER_MASK);
+                    ASM::and_reg64_reg64_reg64(&mut self.buf, dst_reg, ptr_reg, tmp_reg);
+                } else {
+                    let tag_id_offset = union_layout
+                        .tag_id_offset(self.layout_interner, target_info)
+                        .unwrap();
+                    let size = union_layout.discriminant().stack_size();
+                    ASM::movzx_reg64_mem64_offset32(
+                        &mut self.buf,
+                        dst_reg,
+                        ptr_reg,
+                        tag_id_offset as i32,
+                        size as u8,
+                    );
+                }
+
+                if let Some((_, _, jmp_location, jmp_offset)) = null_jumps {
+                    let end_offset = self.buf.len();
+                    self.update_jmp_imm32_offset(
+                        &mut tmp,
+                        jmp_location as u64,
+                        jmp_offset as u64,
+                        end_offset as u64,
+                    );
+                }
+                self.free_symbol(&Symbol::DEV_TMP);
+            }
+        }
+    }
+
     fn update_jmp_imm32_offset(
         &mut self,
         tmp: &mut Vec<'a, u8>,
@@ -1843,6 +3364,48 @@ impl<
     }
 }
 
+/// The field layouts of `tag_id` in a heap-allocated union.
+fn heap_union_field_layouts<'a>(
+    union_layout: &UnionLayout<'a>,
+    tag_id: TagIdIntType,
+) -> &'a [InLayout<'a>] {
+    match *union_layout {
+        UnionLayout::NonRecursive(_) => {
+            internal_error!("NonRecursive unions are not heap-allocated")
+        }
+        UnionLayout::Recursive(tag_layouts) => tag_layouts[tag_id as usize],
+        UnionLayout::NonNullableUnwrapped(field_layouts) => field_layouts,
+        UnionLayout::NullableWrapped {
+            nullable_id,
+            other_tags,
+        } => {
+            debug_assert_ne!(nullable_id, tag_id);
+            let index = if tag_id < nullable_id {
+                tag_id
+            } else {
+                tag_id - 1
+            };
+            other_tags[index as usize]
+        }
+        UnionLayout::NullableUnwrapped { other_fields, .. } => other_fields,
+    }
+}
+
+/// Boxes and heap-allocated unions are represented by a single pointer.
+/// They are stored and passed around exactly like a `U64`.
+pub(crate) fn register_layout<'a>(
+    layout_interner: &STLayoutInterner<'a>,
+    layout: InLayout<'a>,
+) -> InLayout<'a> {
+    match layout_interner.get(layout) {
+        Layout::Boxed(_) => Layout::U64,
+        Layout::Union(union_layout) if !matches!(union_layout, UnionLayout::NonRecursive(_)) => {
+            Layout::U64
+        }
+        _ => layout,
+    }
+}
+
 #[macro_export]
 macro_rules! sign_extended_int_builtins {
     () => {
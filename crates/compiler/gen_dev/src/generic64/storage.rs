@@ -1,5 +1,5 @@
 use crate::{
-    generic64::{Assembler, CallConv, RegTrait},
+    generic64::{register_layout, Assembler, CallConv, RegTrait},
     sign_extended_int_builtins, single_register_floats, single_register_int_builtins,
     single_register_integers, single_register_layouts, Env,
 };
@@ -553,7 +553,7 @@ impl<
                 self.allocation_map.insert(*sym, owned_data);
                 self.symbol_storage_map.insert(
                     *sym,
-                    Stack(if is_primitive(layout) {
+                    Stack(if is_primitive(layout_interner, layout) {
                         ReferencedPrimitive {
                             base_offset: data_offset,
                             size,
@@ -628,6 +628,22 @@ impl<
         );
     }
 
+    // Loads the dst to be the last 64 bits of a list (its capacity).
+    pub fn list_capacity(&mut self, _buf: &mut Vec<'a, u8>, dst: &Symbol, list: &Symbol) {
+        let owned_data = self.remove_allocation_for_sym(list);
+        self.allocation_map.insert(*list, Rc::clone(&owned_data));
+        self.allocation_map.insert(*dst, owned_data);
+        let (list_offset, _) = self.stack_offset_and_size(list);
+        self.symbol_storage_map.insert(
+            *dst,
+            Stack(ReferencedPrimitive {
+                base_offset: list_offset + 16,
+                size: 8,
+                sign_extend: false,
+            }),
+        );
+    }
+
     /// Creates a struct on the stack, moving the data in fields into the struct.
     pub fn create_struct(
         &mut self,
@@ -770,6 +786,82 @@ impl<
         }
     }
 
+    /// Loads a value of type `layout` from the memory at `ptr_reg + offset` into `sym`.
+    /// The value is copied to the stack, so the memory does not need to outlive `sym`.
+    /// Like everywhere else, memory is read in aligned 64 bit chunks.
+    pub fn load_from_memory(
+        &mut self,
+        layout_interner: &mut STLayoutInterner<'a>,
+        buf: &mut Vec<'a, u8>,
+        sym: &Symbol,
+        ptr_reg: GeneralReg,
+        offset: i32,
+        layout: &InLayout<'a>,
+    ) {
+        let size = layout_interner.stack_size(*layout);
+        if size == 0 {
+            self.symbol_storage_map.insert(*sym, NoData);
+        } else if is_primitive(layout_interner, *layout) {
+            let base_offset = self.claim_stack_size(8);
+            let sign_extend = matches!(*layout, sign_extended_int_builtins!());
+            self.with_tmp_general_reg(buf, |_storage_manager, buf, tmp_reg| {
+                if sign_extend {
+                    ASM::movsx_reg64_mem64_offset32(buf, tmp_reg, ptr_reg, offset, size as u8);
+                } else {
+                    ASM::movzx_reg64_mem64_offset32(buf, tmp_reg, ptr_reg, offset, size as u8);
+                }
+                ASM::mov_base32_reg64(buf, base_offset, tmp_reg);
+            });
+            self.symbol_storage_map.insert(
+                *sym,
+                Stack(Primitive {
+                    base_offset,
+                    reg: None,
+                }),
+            );
+            self.allocation_map.insert(*sym, Rc::new((base_offset, 8)));
+        } else {
+            let base_offset = self.claim_stack_area(sym, size);
+            self.with_tmp_general_reg(buf, |_storage_manager, buf, tmp_reg| {
+                for i in (0..size as i32).step_by(8) {
+                    ASM::mov_reg64_mem64_offset32(buf, tmp_reg, ptr_reg, offset + i);
+                    ASM::mov_base32_reg64(buf, base_offset + i, tmp_reg);
+                }
+            });
+        }
+    }
+
+    /// Copies `sym` to the memory at `ptr_reg + offset`.
+    /// Memory is written in 64 bit chunks, so a value smaller than 8 bytes may clobber the bytes after it.
+    /// When filling an allocation, copy fields in increasing offset order and pad the allocation to 8 bytes.
+    pub fn copy_symbol_to_memory(
+        &mut self,
+        layout_interner: &mut STLayoutInterner<'a>,
+        buf: &mut Vec<'a, u8>,
+        ptr_reg: GeneralReg,
+        offset: i32,
+        sym: &Symbol,
+        layout: &InLayout<'a>,
+    ) {
+        match register_layout(layout_interner, *layout) {
+            single_register_integers!() => {
+                let reg = self.load_to_general_reg(buf, sym);
+                ASM::mov_mem64_offset32_reg64(buf, ptr_reg, offset, reg);
+            }
+            _ if layout_interner.stack_size(*layout) == 0 => {}
+            _ => {
+                self.ensure_symbol_on_stack(buf, sym);
+                let (from_offset, size) = self.stack_offset_and_size(sym);
+                self.with_tmp_general_reg(buf, |_storage_manager, buf, tmp_reg| {
+                    for i in (0..size as i32).step_by(8) {
+                        ASM::mov_reg64_base32(buf, tmp_reg, from_offset + i);
+                        ASM::mov_mem64_offset32_reg64(buf, ptr_reg, offset + i, tmp_reg);
+                    }
+                });
+            }
+        }
+    }
+
     #[allow(dead_code)]
     /// Ensures that a register is free. If it is not free, data will be moved to make it free.
     pub fn ensure_reg_free(
@@ -1019,7 +1111,7 @@ impl<
             }
             // Claim a location for every join point parameter to be loaded at.
             // Put everything on the stack for simplicity.
-            match *layout {
+            match register_layout(layout_interner, *layout) {
                 single_register_layouts!() => {
                     let base_offset = self.claim_stack_size(8);
                     self.symbol_storage_map.insert(
@@ -1089,7 +1181,7 @@ impl<
                 Stack(Primitive {
                     base_offset,
                     reg: None,
-                }) => match *layout {
+                }) => match register_layout(layout_interner, *layout) {
                     single_register_integers!() => {
                         let reg = self.load_to_general_reg(buf, sym);
                         ASM::mov_base32_reg64(buf, *base_offset, reg);
@@ -1134,6 +1226,12 @@ impl<
         base_offset
     }
 
+    /// Claims a stack area that does not belong to any symbol.
+    /// It is never freed, so it stays valid until the procedure returns.
+    pub fn claim_anonymous_stack_area(&mut self, size: u32) -> i32 {
+        self.claim_stack_size(size)
+    }
+
     /// claim_stack_size claims `amount` bytes from the stack alignind to 8.
     /// This may be free space in the stack or result in increasing the stack size.
     /// It returns base pointer relative offset of the new data.
@@ -1331,6 +1429,12 @@ impl<
     }
 }
 
-fn is_primitive(layout: InLayout<'_>) -> bool {
-    matches!(layout, single_register_layouts!())
+pub(crate) fn is_primitive<'a>(
+    layout_interner: &STLayoutInterner<'a>,
+    layout: InLayout<'a>,
+) -> bool {
+    matches!(
+        register_layout(layout_interner, layout),
+        single_register_layouts!()
+    )
 }
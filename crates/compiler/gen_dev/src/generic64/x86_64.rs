@@ -1,12 +1,15 @@
-use crate::generic64::{storage::StorageManager, Assembler, CallConv, RegTrait};
+use crate::generic64::{
+    register_layout, storage::StorageManager, Assembler, CallConv, CompareOperation, RegTrait,
+};
 use crate::{
     single_register_floats, single_register_int_builtins, single_register_integers,
     single_register_layouts, Relocation,
 };
 use bumpalo::collections::Vec;
+use roc_builtins::bitcode::FloatWidth;
 use roc_error_macros::internal_error;
 use roc_module::symbol::Symbol;
-use roc_mono::layout::{InLayout, Layout, LayoutInterner, STLayoutInterner};
+use roc_mono::layout::{Builtin, InLayout, Layout, LayoutInterner, STLayoutInterner};
 
 // Not sure exactly how I want to represent registers.
 // If we want max speed, we would likely make them structs that impl the same trait to avoid ifs.
@@ -248,7 +251,7 @@ impl CallConv<X86_64GeneralReg, X86_64FloatReg, X86_64Assembler> for X86_64Syste
 
     #[inline(always)]
     fn load_args<'a, 'r>(
-        _buf: &mut Vec<'a, u8>,
+        buf: &mut Vec<'a, u8>,
         storage_manager: &mut StorageManager<
             'a,
             'r,
@@ -270,7 +273,7 @@ impl CallConv<X86_64GeneralReg, X86_64FloatReg, X86_64Assembler> for X86_64Syste
         }
         for (layout, sym) in args.iter() {
             let stack_size = layout_interner.stack_size(*layout);
-            match *layout {
+            match register_layout(layout_interner, *layout) {
                 single_register_integers!() => {
                     if general_i < Self::GENERAL_PARAM_REGS.len() {
                         storage_manager.general_reg_arg(sym, Self::GENERAL_PARAM_REGS[general_i]);
@@ -297,8 +300,26 @@ impl CallConv<X86_64GeneralReg, X86_64FloatReg, X86_64Assembler> for X86_64Syste
                     storage_manager.complex_stack_arg(sym, arg_offset, stack_size);
                     arg_offset += stack_size as i32;
                 }
-                x => {
-                    todo!("Loading args with layout {:?}", x);
+                _ => {
+                    // Values of up to 16 bytes (like 128-bit numbers) are passed in general registers, 8 bytes per register.
+                    // TODO: structs that only contain floats should use float registers.
+                    let eightbytes = (stack_size as usize + 7) / 8;
+                    if general_i + eightbytes <= Self::GENERAL_PARAM_REGS.len() {
+                        let base_offset = storage_manager.claim_stack_area(sym, stack_size);
+                        for i in 0..eightbytes {
+                            X86_64Assembler::mov_base32_reg64(
+                                buf,
+                                base_offset + 8 * i as i32,
+                                Self::GENERAL_PARAM_REGS[general_i + i],
+                            );
+                        }
+                        general_i += eightbytes;
+                    } else {
+                        let alignment = layout_interner.alignment_bytes(*layout) as i32;
+                        arg_offset = round_up_to_multiple(arg_offset, alignment);
+                        storage_manager.complex_stack_arg(sym, arg_offset, stack_size);
+                        arg_offset += 8 * eightbytes as i32;
+                    }
                 }
             }
         }
@@ -339,7 +360,7 @@ impl CallConv<X86_64GeneralReg, X86_64FloatReg, X86_64Assembler> for X86_64Syste
             );
         }
         for (sym, layout) in args.iter().zip(arg_layouts.iter()) {
-            match *layout {
+            match register_layout(layout_interner, *layout) {
                 single_register_integers!() => {
                     if general_i < Self::GENERAL_PARAM_REGS.len() {
                         storage_manager.load_to_specified_general_reg(
@@ -408,7 +429,37 @@ impl CallConv<X86_64GeneralReg, X86_64FloatReg, X86_64Assembler> for X86_64Syste
                     tmp_stack_offset += size as i32;
                 }
                 x => {
-                    todo!("calling with arg type, {:?}", x);
+                    // Values of up to 16 bytes (like 128-bit numbers) are passed in general registers, 8 bytes per register.
+                    // All caller saved registers were freed before the call, so the param registers are free to use.
+                    let (base_offset, size) = storage_manager.stack_offset_and_size(sym);
+                    debug_assert_eq!(base_offset % 8, 0);
+                    let eightbytes = (size as usize + 7) / 8;
+                    if general_i + eightbytes <= Self::GENERAL_PARAM_REGS.len() {
+                        for i in 0..eightbytes {
+                            X86_64Assembler::mov_reg64_base32(
+                                buf,
+                                Self::GENERAL_PARAM_REGS[general_i + i],
+                                base_offset + 8 * i as i32,
+                            );
+                        }
+                        general_i += eightbytes;
+                    } else {
+                        let alignment = layout_interner.alignment_bytes(x) as i32;
+                        tmp_stack_offset = round_up_to_multiple(tmp_stack_offset, alignment);
+                        for i in 0..eightbytes as i32 {
+                            X86_64Assembler::mov_reg64_base32(
+                                buf,
+                                Self::GENERAL_RETURN_REGS[0],
+                                base_offset + 8 * i,
+                            );
+                            X86_64Assembler::mov_stack32_reg64(
+                                buf,
+                                tmp_stack_offset + 8 * i,
+                                Self::GENERAL_RETURN_REGS[0],
+                            );
+                        }
+                        tmp_stack_offset += 8 * eightbytes as i32;
+                    }
                 }
             }
         }
@@ -437,27 +488,22 @@ impl CallConv<X86_64GeneralReg, X86_64FloatReg, X86_64Assembler> for X86_64Syste
             x if !Self::returns_via_arg_pointer(layout_interner, &x) => {
                 let (base_offset, size) = storage_manager.stack_offset_and_size(sym);
                 debug_assert_eq!(base_offset % 8, 0);
-                if size <= 8 {
-                    X86_64Assembler::mov_reg64_base32(
-                        buf,
-                        Self::GENERAL_RETURN_REGS[0],
-                        base_offset,
-                    );
-                } else if size <= 16 {
-                    X86_64Assembler::mov_reg64_base32(
-                        buf,
-                        Self::GENERAL_RETURN_REGS[0],
-                        base_offset,
-                    );
-                    X86_64Assembler::mov_reg64_base32(
-                        buf,
-                        Self::GENERAL_RETURN_REGS[1],
-                        base_offset + 8,
-                    );
-                } else {
-                    internal_error!(
-                        "types that don't return via arg pointer must be less than 16 bytes"
-                    );
+                let mut general_regs = Self::GENERAL_RETURN_REGS.iter();
+                let mut float_regs = Self::FLOAT_RETURN_REGS.iter();
+                let eightbytes = Self::float_eightbytes(layout_interner, x);
+                for (i, is_float) in eightbytes
+                    .into_iter()
+                    .take((size as usize + 7) / 8)
+                    .enumerate()
+                {
+                    let offset = base_offset + 8 * i as i32;
+                    if is_float {
+                        let reg = *float_regs.next().unwrap();
+                        X86_64Assembler::mov_freg64_base32(buf, reg, offset);
+                    } else {
+                        let reg = *general_regs.next().unwrap();
+                        X86_64Assembler::mov_reg64_base32(buf, reg, offset);
+                    }
                 }
             }
             _ => {
@@ -494,20 +540,23 @@ impl CallConv<X86_64GeneralReg, X86_64FloatReg, X86_64Assembler> for X86_64Syste
             x if layout_interner.stack_size(x) == 0 => {}
             x if !Self::returns_via_arg_pointer(layout_interner, &x) => {
                 let size = layout_interner.stack_size(*layout);
-                let offset = storage_manager.claim_stack_area(sym, size);
-                if size <= 8 {
-                    X86_64Assembler::mov_base32_reg64(buf, offset, Self::GENERAL_RETURN_REGS[0]);
-                } else if size <= 16 {
-                    X86_64Assembler::mov_base32_reg64(buf, offset, Self::GENERAL_RETURN_REGS[0]);
-                    X86_64Assembler::mov_base32_reg64(
-                        buf,
-                        offset + 8,
-                        Self::GENERAL_RETURN_REGS[1],
-                    );
-                } else {
-                    internal_error!(
-                        "types that don't return via arg pointer must be less than 16 bytes"
-                    );
+                let base_offset = storage_manager.claim_stack_area(sym, size);
+                let mut general_regs = Self::GENERAL_RETURN_REGS.iter();
+                let mut float_regs = Self::FLOAT_RETURN_REGS.iter();
+                let eightbytes = Self::float_eightbytes(layout_interner, x);
+                for (i, is_float) in eightbytes
+                    .into_iter()
+                    .take((size as usize + 7) / 8)
+                    .enumerate()
+                {
+                    let offset = base_offset + 8 * i as i32;
+                    if is_float {
+                        let reg = *float_regs.next().unwrap();
+                        X86_64Assembler::mov_base32_freg64(buf, offset, reg);
+                    } else {
+                        let reg = *general_regs.next().unwrap();
+                        X86_64Assembler::mov_base32_reg64(buf, offset, reg);
+                    }
                 }
             }
             _ => {
@@ -528,6 +577,43 @@ impl X86_64SystemV {
         // details here: https://github.com/hjl-tools/x86-psABI/wiki/x86-64-psABI-1.0.pdf
         interner.stack_size(*ret_layout) > 16
     }
+
+    /// Whether each eightbyte of a type returned in registers goes in a float register.
+    /// That's only the case when it holds nothing but floats, so `{ F64, U8 }` comes back in XMM0 and RAX.
+    fn float_eightbytes<'a>(interner: &STLayoutInterner<'a>, layout: InLayout<'a>) -> [bool; 2] {
+        let mut floats = [false; 2];
+        let mut others = [false; 2];
+        Self::classify_eightbytes(interner, layout, 0, &mut floats, &mut others);
+        [floats[0] && !others[0], floats[1] && !others[1]]
+    }
+
+    fn classify_eightbytes<'a>(
+        interner: &STLayoutInterner<'a>,
+        layout: InLayout<'a>,
+        offset: u32,
+        floats: &mut [bool; 2],
+        others: &mut [bool; 2],
+    ) {
+        match interner.get(layout) {
+            Layout::Struct { field_layouts, .. } => {
+                // The storage manager puts fields one after the other, largest alignment first.
+                let mut field_offset = offset;
+                for field in field_layouts {
+                    Self::classify_eightbytes(interner, *field, field_offset, floats, others);
+                    field_offset += interner.stack_size(*field);
+                }
+            }
+            Layout::Builtin(Builtin::Float(_)) => floats[offset as usize / 8] = true,
+            _ => {
+                let size = interner.stack_size(layout);
+                if size > 0 {
+                    for eightbyte in offset / 8..=(offset + size - 1) / 8 {
+                        others[eightbyte as usize] = true;
+                    }
+                }
+            }
+        }
+    }
 }
 
 impl CallConv<X86_64GeneralReg, X86_64FloatReg, X86_64Assembler> for X86_64WindowsFastcall {
@@ -682,7 +768,7 @@ impl CallConv<X86_64GeneralReg, X86_64FloatReg, X86_64Assembler> for X86_64Windo
         }
         for (layout, sym) in args.iter() {
             if i < Self::GENERAL_PARAM_REGS.len() {
-                match *layout {
+                match register_layout(layout_interner, *layout) {
                     single_register_integers!() => {
                         storage_manager.general_reg_arg(sym, Self::GENERAL_PARAM_REGS[i]);
                         i += 1;
@@ -697,7 +783,7 @@ impl CallConv<X86_64GeneralReg, X86_64FloatReg, X86_64Assembler> for X86_64Windo
                     }
                 }
             } else {
-                match *layout {
+                match register_layout(layout_interner, *layout) {
                     single_register_layouts!() => {
                         storage_manager.primitive_stack_arg(sym, arg_offset);
                         arg_offset += 8;
@@ -734,7 +820,7 @@ impl CallConv<X86_64GeneralReg, X86_64FloatReg, X86_64Assembler> for X86_64Windo
             todo!("claim first parama reg for the address");
         }
         for (i, (sym, layout)) in args.iter().zip(arg_layouts.iter()).enumerate() {
-            match *layout {
+            match register_layout(layout_interner, *layout) {
                 single_register_integers!() => {
                     if i < Self::GENERAL_PARAM_REGS.len() {
                         storage_manager.load_to_specified_general_reg(
@@ -922,6 +1008,15 @@ fn x86_64_generic_cleanup_stack<'a>(
 
 type Reg64 = X86_64GeneralReg;
 
+/// Rounds `offset` up to the next multiple of `alignment`, for placing args on the stack.
+fn round_up_to_multiple(offset: i32, alignment: i32) -> i32 {
+    if alignment > 1 && offset % alignment != 0 {
+        offset + alignment - (offset % alignment)
+    } else {
+        offset
+    }
+}
+
 fn binop_move_src_to_dst_reg64<F>(buf: &mut Vec<'_, u8>, f: F, dst: Reg64, src1: Reg64, src2: Reg64)
 where
     F: FnOnce(&mut Vec<'_, u8>, X86_64GeneralReg, X86_64GeneralReg),
@@ -964,6 +1059,23 @@ impl Assembler<X86_64GeneralReg, X86_64FloatReg> for X86_64Assembler {
         andpd_freg64_freg64(buf, dst, src);
     }
 
+    #[inline(always)]
+    fn abs_freg32_freg32(
+        buf: &mut Vec<'_, u8>,
+        relocs: &mut Vec<'_, Relocation>,
+        dst: X86_64FloatReg,
+        src: X86_64FloatReg,
+    ) {
+        movss_freg32_rip_offset32(buf, dst, 0);
+
+        relocs.push(Relocation::LocalData {
+            offset: buf.len() as u64 - 4,
+            data: 0x7fffffffu32.to_le_bytes().to_vec(),
+        });
+
+        andps_freg32_freg32(buf, dst, src);
+    }
+
     #[inline(always)]
     fn add_reg64_reg64_imm32(
         buf: &mut Vec<'_, u8>,
@@ -1216,6 +1328,19 @@ impl Assembler<X86_64GeneralReg, X86_64FloatReg> for X86_64Assembler {
         mov_reg64_imm64(buf, dst, imm);
     }
     #[inline(always)]
+    fn mov_reg64_local_data_address(
+        buf: &mut Vec<'_, u8>,
+        relocs: &mut Vec<'_, Relocation>,
+        dst: X86_64GeneralReg,
+        data: std::vec::Vec<u8>,
+    ) {
+        lea_reg64_rip_offset32(buf, dst, 0);
+        relocs.push(Relocation::LocalData {
+            offset: buf.len() as u64 - 4,
+            data,
+        });
+    }
+    #[inline(always)]
     fn mov_freg64_freg64(buf: &mut Vec<'_, u8>, dst: X86_64FloatReg, src: X86_64FloatReg) {
         movsd_freg64_freg64(buf, dst, src);
     }
@@ -1260,12 +1385,67 @@ impl Assembler<X86_64GeneralReg, X86_64FloatReg> for X86_64Assembler {
         mov_base64_offset32_reg64(buf, dst, offset, src)
     }
 
+    #[inline(always)]
+    fn movsx_reg64_mem64_offset32(
+        buf: &mut Vec<'_, u8>,
+        dst: X86_64GeneralReg,
+        src: X86_64GeneralReg,
+        offset: i32,
+        size: u8,
+    ) {
+        debug_assert!(size <= 8);
+        match size {
+            8 => mov_reg64_base64_offset32(buf, dst, src, offset),
+            4 => movsxd_reg64_base32_offset32(buf, dst, src, offset),
+            2 => movsx_reg64_base16_offset32(buf, dst, src, offset),
+            1 => movsx_reg64_base8_offset32(buf, dst, src, offset),
+            _ => internal_error!("Invalid size for sign extension: {size}"),
+        }
+    }
+    #[inline(always)]
+    fn movzx_reg64_mem64_offset32(
+        buf: &mut Vec<'_, u8>,
+        dst: X86_64GeneralReg,
+        src: X86_64GeneralReg,
+        offset: i32,
+        size: u8,
+    ) {
+        debug_assert!(size <= 8);
+        match size {
+            8 => mov_reg64_base64_offset32(buf, dst, src, offset),
+            4 => mov_reg32_base32_offset32(buf, dst, src, offset),
+            2 => movzx_reg64_base16_offset32(buf, dst, src, offset),
+            1 => movzx_reg64_base8_offset32(buf, dst, src, offset),
+            _ => internal_error!("Invalid size for zero extension: {size}"),
+        }
+    }
+
+    #[inline(always)]
+    fn movtrunc_mem64_offset32_reg64(
+        buf: &mut Vec<'_, u8>,
+        dst: X86_64GeneralReg,
+        offset: i32,
+        src: X86_64GeneralReg,
+        size: u8,
+    ) {
+        debug_assert!(size <= 8);
+        match size {
+            8 => mov_base64_offset32_reg64(buf, dst, offset, src),
+            4 => mov_base32_offset32_reg32(buf, dst, offset, src),
+            2 => mov_base16_offset32_reg16(buf, dst, offset, src),
+            1 => mov_base8_offset32_reg8(buf, dst, offset, src),
+            _ => internal_error!("Invalid size for truncation: {size}"),
+        }
+    }
+
     #[inline(always)]
     fn movsx_reg64_base32(buf: &mut Vec<'_, u8>, dst: X86_64GeneralReg, offset: i32, size: u8) {
         debug_assert!(size <= 8);
         match size {
             8 => Self::mov_reg64_base32(buf, dst, offset),
-            4 | 2 | 1 => todo!("sign extending {size} byte values"),
+            4 => movsxd_reg64_base32_offset32(buf, dst, X86_64GeneralReg::RBP, offset),
+            2 => movsx_reg64_base16_offset32(buf, dst, X86_64GeneralReg::RBP, offset),
+            1 => movsx_reg64_base8_offset32(buf, dst, X86_64GeneralReg::RBP, offset),
             _ => internal_error!("Invalid size for sign extension: {size}"),
         }
     }
@@ -1274,7 +1454,8 @@ impl Assembler<X86_64GeneralReg, X86_64FloatReg> for X86_64Assembler {
         debug_assert!(size <= 8);
         match size {
             8 => Self::mov_reg64_base32(buf, dst, offset),
-            4 | 2 => todo!("zero extending {size} byte values"),
+            4 => mov_reg32_base32_offset32(buf, dst, X86_64GeneralReg::RBP, offset),
+            2 => movzx_reg64_base16_offset32(buf, dst, X86_64GeneralReg::RBP, offset),
             1 => movzx_reg64_base8_offset32(buf, dst, X86_64GeneralReg::RBP, offset),
             _ => internal_error!("Invalid size for zero extension: {size}"),
         }
@@ -1303,6 +1484,58 @@ impl Assembler<X86_64GeneralReg, X86_64FloatReg> for X86_64Assembler {
         neg_reg64(buf, dst);
     }
 
+    #[inline(always)]
+    fn neg_freg64_freg64(
+        buf: &mut Vec<'_, u8>,
+        relocs: &mut Vec<'_, Relocation>,
+        dst: X86_64FloatReg,
+        src: X86_64FloatReg,
+    ) {
+        // Flipping the sign bit, unlike subtracting from zero, also negates zero.
+        movsd_freg64_rip_offset32(buf, dst, 0);
+
+        relocs.push(Relocation::LocalData {
+            offset: buf.len() as u64 - 4,
+            data: 0x8000000000000000u64.to_le_bytes().to_vec(),
+        });
+
+        xorpd_freg64_freg64(buf, dst, src);
+    }
+
+    #[inline(always)]
+    fn neg_freg32_freg32(
+        buf: &mut Vec<'_, u8>,
+        relocs: &mut Vec<'_, Relocation>,
+        dst: X86_64FloatReg,
+        src: X86_64FloatReg,
+    ) {
+        movss_freg32_rip_offset32(buf, dst, 0);
+
+        relocs.push(Relocation::LocalData {
+            offset: buf.len() as u64 - 4,
+            data: 0x80000000u32.to_le_bytes().to_vec(),
+        });
+
+        xorps_freg32_freg32(buf, dst, src);
+    }
+
+    #[inline(always)]
+    fn bswap_reg64_reg64(buf: &mut Vec<'_, u8>, dst: X86_64GeneralReg, src: X86_64GeneralReg) {
+        mov_reg64_reg64(buf, dst, src);
+        bswap_reg64(buf, dst);
+    }
+    #[inline(always)]
+    fn bswap_reg32_reg32(buf: &mut Vec<'_, u8>, dst: X86_64GeneralReg, src: X86_64GeneralReg) {
+        mov_reg64_reg64(buf, dst, src);
+        bswap_reg32(buf, dst);
+    }
+    #[inline(always)]
+    fn bswap_reg16_reg16(buf: &mut Vec<'_, u8>, dst: X86_64GeneralReg, src: X86_64GeneralReg) {
+        // There is no 16-bit BSWAP, but rotating the low 16 bits by 8 swaps its two bytes.
+        mov_reg64_reg64(buf, dst, src);
+        rol_reg16_imm8(buf, dst, 8);
+    }
+
     #[inline(always)]
     fn sub_reg64_reg64_imm32(
         buf: &mut Vec<'_, u8>,
@@ -1324,6 +1557,33 @@ impl Assembler<X86_64GeneralReg, X86_64FloatReg> for X86_64Assembler {
         sub_reg64_reg64(buf, dst, src2);
     }
 
+    #[inline(always)]
+    fn sub_freg32_freg32_freg32(
+        buf: &mut Vec<'_, u8>,
+        dst: X86_64FloatReg,
+        src1: X86_64FloatReg,
+        src2: X86_64FloatReg,
+    ) {
+        if dst == src2 {
+            internal_error!("the destination of a subtraction can not be its second argument");
+        }
+        movss_freg32_freg32(buf, dst, src1);
+        subss_freg32_freg32(buf, dst, src2);
+    }
+    #[inline(always)]
+    fn sub_freg64_freg64_freg64(
+        buf: &mut Vec<'_, u8>,
+        dst: X86_64FloatReg,
+        src1: X86_64FloatReg,
+        src2: X86_64FloatReg,
+    ) {
+        if dst == src2 {
+            internal_error!("the destination of a subtraction can not be its second argument");
+        }
+        movsd_freg64_freg64(buf, dst, src1);
+        subsd_freg64_freg64(buf, dst, src2);
+    }
+
     #[inline(always)]
     fn eq_reg64_reg64_reg64(
         buf: &mut Vec<'_, u8>,
@@ -1437,10 +1697,75 @@ impl Assembler<X86_64GeneralReg, X86_64FloatReg> for X86_64Assembler {
         ret(buf);
     }
 
+    #[inline(always)]
+    fn ulte_reg64_reg64_reg64(
+        buf: &mut Vec<'_, u8>,
+        dst: X86_64GeneralReg,
+        src1: X86_64GeneralReg,
+        src2: X86_64GeneralReg,
+    ) {
+        cmp_reg64_reg64(buf, src1, src2);
+        setbe_reg64(buf, dst);
+    }
+
+    #[inline(always)]
+    fn ugte_reg64_reg64_reg64(
+        buf: &mut Vec<'_, u8>,
+        dst: X86_64GeneralReg,
+        src1: X86_64GeneralReg,
+        src2: X86_64GeneralReg,
+    ) {
+        cmp_reg64_reg64(buf, src1, src2);
+        setae_reg64(buf, dst);
+    }
+
+    fn cmp_freg_freg_reg64(
+        buf: &mut Vec<'_, u8>,
+        dst: X86_64GeneralReg,
+        src1: X86_64FloatReg,
+        src2: X86_64FloatReg,
+        width: FloatWidth,
+        operation: CompareOperation,
+    ) {
+        use CompareOperation::*;
+
+        // UCOMISD reports an unordered result (a NaN operand) as "below and equal".
+        // Only ever test for "above" so that NaN compares false, swapping operands for less than.
+        let (lhs, rhs) = match operation {
+            LessThan | LessThanOrEqual => (src2, src1),
+            _ => (src1, src2),
+        };
+        match width {
+            FloatWidth::F32 => ucomiss_freg32_freg32(buf, lhs, rhs),
+            FloatWidth::F64 => ucomisd_freg64_freg64(buf, lhs, rhs),
+        }
+        match operation {
+            LessThan | GreaterThan => seta_reg64(buf, dst),
+            LessThanOrEqual | GreaterThanOrEqual => setae_reg64(buf, dst),
+            Equal | NotEqual => {
+                // Equality has to check the parity flag to rule out an unordered result.
+                // MOV does not touch the flags, so the default can be loaded before the jump.
+                mov_reg64_imm32(buf, dst, (operation == NotEqual) as i32);
+                let jp_location = buf.len();
+                jp_imm8(buf, 0);
+                if operation == Equal {
+                    sete_reg64(buf, dst);
+                } else {
+                    setne_reg64(buf, dst);
+                }
+                buf[jp_location + 1] = (buf.len() - jp_location - 2) as u8;
+            }
+        }
+    }
+
     fn set_if_overflow(buf: &mut Vec<'_, u8>, dst: X86_64GeneralReg) {
         seto_reg64(buf, dst);
     }
 
+    fn set_if_carry(buf: &mut Vec<'_, u8>, dst: X86_64GeneralReg) {
+        setc_reg64(buf, dst);
+    }
+
     fn and_reg64_reg64_reg64(buf: &mut Vec<'_, u8>, dst: Reg64, src1: Reg64, src2: Reg64) {
         binop_move_src_to_dst_reg64(buf, and_reg64_reg64, dst, src1, src2)
     }
@@ -1616,6 +1941,85 @@ fn addss_freg32_freg32(buf: &mut Vec<'_, u8>, dst: X86_64FloatReg, src: X86_64Fl
     }
 }
 
+/// `UCOMISD xmm1,xmm2/m64` -> Compare low double-precision floating-point values in xmm1 and xmm2/mem64 and set the EFLAGS flags accordingly.
+#[inline(always)]
+fn ucomisd_freg64_freg64(buf: &mut Vec<'_, u8>, src1: X86_64FloatReg, src2: X86_64FloatReg) {
+    let src1_high = src1 as u8 > 7;
+    let src1_mod = src1 as u8 % 8;
+    let src2_high = src2 as u8 > 7;
+    let src2_mod = src2 as u8 % 8;
+    if src1_high || src2_high {
+        buf.extend([
+            0x66,
+            0x40 | ((src1_high as u8) << 2) | (src2_high as u8),
+            0x0F,
+            0x2E,
+            0xC0 | (src1_mod << 3) | (src2_mod),
+        ])
+    } else {
+        buf.extend([0x66, 0x0F, 0x2E, 0xC0 | (src1_mod << 3) | (src2_mod)])
+    }
+}
+
+/// `UCOMISS xmm1,xmm2/m32` -> Compare low single-precision floating-point values in xmm1 and xmm2/mem32 and set the EFLAGS flags accordingly.
+#[inline(always)]
+fn ucomiss_freg32_freg32(buf: &mut Vec<'_, u8>, src1: X86_64FloatReg, src2: X86_64FloatReg) {
+    let src1_high = src1 as u8 > 7;
+    let src1_mod = src1 as u8 % 8;
+    let src2_high = src2 as u8 > 7;
+    let src2_mod = src2 as u8 % 8;
+    if src1_high || src2_high {
+        buf.extend([
+            0x40 | ((src1_high as u8) << 2) | (src2_high as u8),
+            0x0F,
+            0x2E,
+            0xC0 | (src1_mod << 3) | (src2_mod),
+        ])
+    } else {
+        buf.extend([0x0F, 0x2E, 0xC0 | (src1_mod << 3) | (src2_mod)])
+    }
+}
+
+/// `SUBSD xmm1,xmm2/m64` -> Subtract the low double-precision floating-point value in xmm2/mem from xmm1 and store the result in xmm1.
+#[inline(always)]
+fn subsd_freg64_freg64(buf: &mut Vec<'_, u8>, dst: X86_64FloatReg, src: X86_64FloatReg) {
+    let dst_high = dst as u8 > 7;
+    let dst_mod = dst as u8 % 8;
+    let src_high = src as u8 > 7;
+    let src_mod = src as u8 % 8;
+    if dst_high || src_high {
+        buf.extend([
+            0xF2,
+            0x40 | ((dst_high as u8) << 2) | (src_high as u8),
+            0x0F,
+            0x5C,
+            0xC0 | (dst_mod << 3) | (src_mod),
+        ])
+    } else {
+        buf.extend([0xF2, 0x0F, 0x5C, 0xC0 | (dst_mod << 3) | (src_mod)])
+    }
+}
+
+/// `SUBSS xmm1,xmm2/m64` -> Subtract the low single-precision floating-point value in xmm2/mem from xmm1 and store the result in xmm1.
+#[inline(always)]
+fn subss_freg32_freg32(buf: &mut Vec<'_, u8>, dst: X86_64FloatReg, src: X86_64FloatReg) {
+    let dst_high = dst as u8 > 7;
+    let dst_mod = dst as u8 % 8;
+    let src_high = src as u8 > 7;
+    let src_mod = src as u8 % 8;
+    if dst_high || src_high {
+        buf.extend([
+            0xF3,
+            0x40 | ((dst_high as u8) << 2) | (src_high as u8),
+            0x0F,
+            0x5C,
+            0xC0 | (dst_mod << 3) | (src_mod),
+        ])
+    } else {
+        buf.extend([0xF3, 0x0F, 0x5C, 0xC0 | (dst_mod << 3) | (src_mod)])
+    }
+}
+
 /// `MULSD xmm1,xmm2/m64` -> Multiply the low double-precision floating-point value from xmm2/mem to xmm1 and store the result in xmm1.
 #[inline(always)]
 fn mulsd_freg64_freg64(buf: &mut Vec<'_, u8>, dst: X86_64FloatReg, src: X86_64FloatReg) {
@@ -1716,28 +2120,89 @@ fn andpd_freg64_freg64(buf: &mut Vec<'_, u8>, dst: X86_64FloatReg, src: X86_64Fl
     }
 }
 
-/// r/m64 AND imm8 (sign-extended).
+/// `ANDPS xmm1,xmm2/m128` -> Bitwise AND of packed single-precision floating-point values in xmm1 and xmm2/mem.
 #[inline(always)]
-fn and_reg64_imm8(buf: &mut Vec<'_, u8>, dst: X86_64GeneralReg, imm: i8) {
-    let rex = add_rm_extension(dst, REX_W);
+fn andps_freg32_freg32(buf: &mut Vec<'_, u8>, dst: X86_64FloatReg, src: X86_64FloatReg) {
+    let dst_high = dst as u8 > 7;
     let dst_mod = dst as u8 % 8;
-    buf.extend([rex, 0x83, 0xE0 | dst_mod, imm as u8]);
-}
-
-/// `CMOVL r64,r/m64` -> Move if less (SF≠ OF).
-#[inline(always)]
-fn cmovl_reg64_reg64(buf: &mut Vec<'_, u8>, dst: X86_64GeneralReg, src: X86_64GeneralReg) {
-    let rex = add_reg_extension(dst, REX_W);
-    let rex = add_rm_extension(src, rex);
-    let dst_mod = (dst as u8 % 8) << 3;
+    let src_high = src as u8 > 7;
     let src_mod = src as u8 % 8;
-    buf.extend([rex, 0x0F, 0x4C, 0xC0 | dst_mod | src_mod]);
+
+    if dst_high || src_high {
+        buf.extend([
+            0x40 | ((dst_high as u8) << 2) | (src_high as u8),
+            0x0F,
+            0x54,
+            0xC0 | (dst_mod << 3) | (src_mod),
+        ])
+    } else {
+        buf.extend([0x0F, 0x54, 0xC0 | (dst_mod << 3) | (src_mod)])
+    }
 }
 
-/// `CMP r/m64,i32` -> Compare i32 to r/m64.
+/// `XORPD xmm1,xmm2/m128` -> Bitwise exclusive-OR of packed double-precision floating-point values in xmm1 and xmm2/mem.
 #[inline(always)]
-fn cmp_reg64_imm32(buf: &mut Vec<'_, u8>, dst: X86_64GeneralReg, imm: i32) {
-    let rex = add_rm_extension(dst, REX_W);
+fn xorpd_freg64_freg64(buf: &mut Vec<'_, u8>, dst: X86_64FloatReg, src: X86_64FloatReg) {
+    let dst_high = dst as u8 > 7;
+    let dst_mod = dst as u8 % 8;
+    let src_high = src as u8 > 7;
+    let src_mod = src as u8 % 8;
+
+    if dst_high || src_high {
+        buf.extend([
+            0x66,
+            0x40 | ((dst_high as u8) << 2) | (src_high as u8),
+            0x0F,
+            0x57,
+            0xC0 | (dst_mod << 3) | (src_mod),
+        ])
+    } else {
+        buf.extend([0x66, 0x0F, 0x57, 0xC0 | (dst_mod << 3) | (src_mod)])
+    }
+}
+
+/// `XORPS xmm1,xmm2/m128` -> Bitwise exclusive-OR of packed single-precision floating-point values in xmm1 and xmm2/mem.
+#[inline(always)]
+fn xorps_freg32_freg32(buf: &mut Vec<'_, u8>, dst: X86_64FloatReg, src: X86_64FloatReg) {
+    let dst_high = dst as u8 > 7;
+    let dst_mod = dst as u8 % 8;
+    let src_high = src as u8 > 7;
+    let src_mod = src as u8 % 8;
+
+    if dst_high || src_high {
+        buf.extend([
+            0x40 | ((dst_high as u8) << 2) | (src_high as u8),
+            0x0F,
+            0x57,
+            0xC0 | (dst_mod << 3) | (src_mod),
+        ])
+    } else {
+        buf.extend([0x0F, 0x57, 0xC0 | (dst_mod << 3) | (src_mod)])
+    }
+}
+
+/// r/m64 AND imm8 (sign-extended).
+#[inline(always)]
+fn and_reg64_imm8(buf: &mut Vec<'_, u8>, dst: X86_64GeneralReg, imm: i8) {
+    let rex = add_rm_extension(dst, REX_W);
+    let dst_mod = dst as u8 % 8;
+    buf.extend([rex, 0x83, 0xE0 | dst_mod, imm as u8]);
+}
+
+/// `CMOVL r64,r/m64` -> Move if less (SF≠ OF).
+#[inline(always)]
+fn cmovl_reg64_reg64(buf: &mut Vec<'_, u8>, dst: X86_64GeneralReg, src: X86_64GeneralReg) {
+    let rex = add_reg_extension(dst, REX_W);
+    let rex = add_rm_extension(src, rex);
+    let dst_mod = (dst as u8 % 8) << 3;
+    let src_mod = src as u8 % 8;
+    buf.extend([rex, 0x0F, 0x4C, 0xC0 | dst_mod | src_mod]);
+}
+
+/// `CMP r/m64,i32` -> Compare i32 to r/m64.
+#[inline(always)]
+fn cmp_reg64_imm32(buf: &mut Vec<'_, u8>, dst: X86_64GeneralReg, imm: i32) {
+    let rex = add_rm_extension(dst, REX_W);
     let dst_mod = dst as u8 % 8;
     buf.reserve(7);
     buf.extend([rex, 0x81, 0xF8 | dst_mod]);
@@ -1827,6 +2292,12 @@ fn jmp_imm32(buf: &mut Vec<'_, u8>, imm: i32) {
     buf.extend(imm.to_le_bytes());
 }
 
+/// Jump short if parity (PF=1).
+#[inline(always)]
+fn jp_imm8(buf: &mut Vec<'_, u8>, imm: i8) {
+    buf.extend([0x7A, imm as u8]);
+}
+
 /// Jump near if not equal (ZF=0).
 #[inline(always)]
 fn jne_imm32(buf: &mut Vec<'_, u8>, imm: i32) {
@@ -1836,6 +2307,16 @@ fn jne_imm32(buf: &mut Vec<'_, u8>, imm: i32) {
     buf.extend(imm.to_le_bytes());
 }
 
+/// `LEA r64,m` -> Store effective address for m in register r64, where m is relative to the instruction pointer.
+#[inline(always)]
+fn lea_reg64_rip_offset32(buf: &mut Vec<'_, u8>, dst: X86_64GeneralReg, offset: u32) {
+    let rex = add_reg_extension(dst, REX_W);
+    let dst_mod = dst as u8 % 8;
+    buf.reserve(7);
+    buf.extend([rex, 0x8D, 0x05 | (dst_mod << 3)]);
+    buf.extend(offset.to_le_bytes());
+}
+
 /// `MOV r/m64, imm32` -> Move imm32 sign extended to 64-bits to r/m64.
 #[inline(always)]
 fn mov_reg64_imm32(buf: &mut Vec<'_, u8>, dst: X86_64GeneralReg, imm: i32) {
@@ -1899,6 +2380,68 @@ fn mov_base64_offset32_reg64(
     buf.extend(offset.to_le_bytes());
 }
 
+/// `MOV r/m32,r32` -> Move r32 to r/m32, where m32 references a base + offset.
+#[inline(always)]
+fn mov_base32_offset32_reg32(
+    buf: &mut Vec<'_, u8>,
+    base: X86_64GeneralReg,
+    offset: i32,
+    src: X86_64GeneralReg,
+) {
+    let rex = add_rm_extension(base, REX);
+    let rex = add_reg_extension(src, rex);
+    let src_mod = (src as u8 % 8) << 3;
+    let base_mod = base as u8 % 8;
+    buf.reserve(8);
+    if src as u8 > 7 || base as u8 > 7 {
+        buf.push(rex);
+    }
+    buf.extend([0x89, 0x80 | src_mod | base_mod]);
+    // Using RSP or R12 requires a secondary index byte.
+    if base == X86_64GeneralReg::RSP || base == X86_64GeneralReg::R12 {
+        buf.push(0x24);
+    }
+    buf.extend(offset.to_le_bytes());
+}
+
+/// `MOV r/m16,r16` -> Move r16 to r/m16, where m16 references a base + offset.
+#[inline(always)]
+fn mov_base16_offset32_reg16(
+    buf: &mut Vec<'_, u8>,
+    base: X86_64GeneralReg,
+    offset: i32,
+    src: X86_64GeneralReg,
+) {
+    buf.push(0x66);
+    mov_base32_offset32_reg32(buf, base, offset, src);
+}
+
+/// `MOV r/m8,r8` -> Move r8 to r/m8, where m8 references a base + offset.
+#[inline(always)]
+fn mov_base8_offset32_reg8(
+    buf: &mut Vec<'_, u8>,
+    base: X86_64GeneralReg,
+    offset: i32,
+    src: X86_64GeneralReg,
+) {
+    use X86_64GeneralReg::*;
+    let rex = add_rm_extension(base, REX);
+    let rex = add_reg_extension(src, rex);
+    let src_mod = (src as u8 % 8) << 3;
+    let base_mod = base as u8 % 8;
+    buf.reserve(9);
+    // Without a REX prefix, the low byte of RSP, RBP, RSI and RDI would mean AH, CH, DH and BH.
+    if src as u8 > 7 || base as u8 > 7 || matches!(src, RSP | RBP | RSI | RDI) {
+        buf.push(rex);
+    }
+    buf.extend([0x88, 0x80 | src_mod | base_mod]);
+    // Using RSP or R12 requires a secondary index byte.
+    if base == RSP || base == R12 {
+        buf.push(0x24);
+    }
+    buf.extend(offset.to_le_bytes());
+}
+
 /// `MOV r64,r/m64` -> Move r/m64 to r64, where m64 references a base + offset.
 #[inline(always)]
 fn mov_reg64_base64_offset32(
@@ -1941,6 +2484,114 @@ fn movzx_reg64_base8_offset32(
     buf.extend(offset.to_le_bytes());
 }
 
+/// `MOVZX r64,r/m16` -> Move r/m16 with zero extention to r64, where m16 references a base + offset.
+#[inline(always)]
+fn movzx_reg64_base16_offset32(
+    buf: &mut Vec<'_, u8>,
+    dst: X86_64GeneralReg,
+    base: X86_64GeneralReg,
+    offset: i32,
+) {
+    let rex = add_rm_extension(base, REX_W);
+    let rex = add_reg_extension(dst, rex);
+    let dst_mod = (dst as u8 % 8) << 3;
+    let base_mod = base as u8 % 8;
+    buf.reserve(9);
+    buf.extend([rex, 0x0F, 0xB7, 0x80 | dst_mod | base_mod]);
+    // Using RSP or R12 requires a secondary index byte.
+    if base == X86_64GeneralReg::RSP || base == X86_64GeneralReg::R12 {
+        buf.push(0x24);
+    }
+    buf.extend(offset.to_le_bytes());
+}
+
+/// `MOV r32,r/m32` -> Move r/m32 to r32, where m32 references a base + offset. Writing r32 zero extends to r64.
+#[inline(always)]
+fn mov_reg32_base32_offset32(
+    buf: &mut Vec<'_, u8>,
+    dst: X86_64GeneralReg,
+    base: X86_64GeneralReg,
+    offset: i32,
+) {
+    let rex = add_rm_extension(base, REX);
+    let rex = add_reg_extension(dst, rex);
+    let dst_mod = (dst as u8 % 8) << 3;
+    let base_mod = base as u8 % 8;
+    buf.reserve(8);
+    if dst as u8 > 7 || base as u8 > 7 {
+        buf.push(rex);
+    }
+    buf.extend([0x8B, 0x80 | dst_mod | base_mod]);
+    // Using RSP or R12 requires a secondary index byte.
+    if base == X86_64GeneralReg::RSP || base == X86_64GeneralReg::R12 {
+        buf.push(0x24);
+    }
+    buf.extend(offset.to_le_bytes());
+}
+
+/// `MOVSX r64,r/m8` -> Move r/m8 with sign extention to r64, where m8 references a base + offset.
+#[inline(always)]
+fn movsx_reg64_base8_offset32(
+    buf: &mut Vec<'_, u8>,
+    dst: X86_64GeneralReg,
+    base: X86_64GeneralReg,
+    offset: i32,
+) {
+    let rex = add_rm_extension(base, REX_W);
+    let rex = add_reg_extension(dst, rex);
+    let dst_mod = (dst as u8 % 8) << 3;
+    let base_mod = base as u8 % 8;
+    buf.reserve(9);
+    buf.extend([rex, 0x0F, 0xBE, 0x80 | dst_mod | base_mod]);
+    // Using RSP or R12 requires a secondary index byte.
+    if base == X86_64GeneralReg::RSP || base == X86_64GeneralReg::R12 {
+        buf.push(0x24);
+    }
+    buf.extend(offset.to_le_bytes());
+}
+
+/// `MOVSX r64,r/m16` -> Move r/m16 with sign extention to r64, where m16 references a base + offset.
+#[inline(always)]
+fn movsx_reg64_base16_offset32(
+    buf: &mut Vec<'_, u8>,
+    dst: X86_64GeneralReg,
+    base: X86_64GeneralReg,
+    offset: i32,
+) {
+    let rex = add_rm_extension(base, REX_W);
+    let rex = add_reg_extension(dst, rex);
+    let dst_mod = (dst as u8 % 8) << 3;
+    let base_mod = base as u8 % 8;
+    buf.reserve(9);
+    buf.extend([rex, 0x0F, 0xBF, 0x80 | dst_mod | base_mod]);
+    // Using RSP or R12 requires a secondary index byte.
+    if base == X86_64GeneralReg::RSP || base == X86_64GeneralReg::R12 {
+        buf.push(0x24);
+    }
+    buf.extend(offset.to_le_bytes());
+}
+
+/// `MOVSXD r64,r/m32` -> Move r/m32 with sign extention to r64, where m32 references a base + offset.
+#[inline(always)]
+fn movsxd_reg64_base32_offset32(
+    buf: &mut Vec<'_, u8>,
+    dst: X86_64GeneralReg,
+    base: X86_64GeneralReg,
+    offset: i32,
+) {
+    let rex = add_rm_extension(base, REX_W);
+    let rex = add_reg_extension(dst, rex);
+    let dst_mod = (dst as u8 % 8) << 3;
+    let base_mod = base as u8 % 8;
+    buf.reserve(8);
+    buf.extend([rex, 0x63, 0x80 | dst_mod | base_mod]);
+    // Using RSP or R12 requires a secondary index byte.
+    if base == X86_64GeneralReg::RSP || base == X86_64GeneralReg::R12 {
+        buf.push(0x24);
+    }
+    buf.extend(offset.to_le_bytes());
+}
+
 /// `MOVSD xmm1,xmm2` -> Move scalar double-precision floating-point value from xmm2 to xmm1 register.
 /// This will not generate anything if dst and src are the same.
 #[inline(always)]
@@ -2087,6 +2738,36 @@ fn neg_reg64(buf: &mut Vec<'_, u8>, reg: X86_64GeneralReg) {
     buf.extend([rex, 0xF7, 0xD8 | reg_mod]);
 }
 
+/// `BSWAP r64` -> Reverse the byte order of r64.
+#[inline(always)]
+fn bswap_reg64(buf: &mut Vec<'_, u8>, reg: X86_64GeneralReg) {
+    let rex = add_opcode_extension(reg, REX_W);
+    let reg_mod = reg as u8 % 8;
+    buf.extend([rex, 0x0F, 0xC8 | reg_mod]);
+}
+
+/// `BSWAP r32` -> Reverse the byte order of r32.
+#[inline(always)]
+fn bswap_reg32(buf: &mut Vec<'_, u8>, reg: X86_64GeneralReg) {
+    let reg_mod = reg as u8 % 8;
+    if reg as u8 > 7 {
+        buf.extend([add_opcode_extension(reg, REX), 0x0F, 0xC8 | reg_mod]);
+    } else {
+        buf.extend([0x0F, 0xC8 | reg_mod]);
+    }
+}
+
+/// `ROL r/m16,imm8` -> Rotate r/m16 left by imm8 bits.
+#[inline(always)]
+fn rol_reg16_imm8(buf: &mut Vec<'_, u8>, reg: X86_64GeneralReg, imm: u8) {
+    let reg_mod = reg as u8 % 8;
+    buf.push(0x66);
+    if reg as u8 > 7 {
+        buf.push(add_rm_extension(reg, REX));
+    }
+    buf.extend([0xC1, 0xC0 | reg_mod, imm]);
+}
+
 // helper function for `set*` instructions
 #[inline(always)]
 fn set_reg64_help(op_code: u8, buf: &mut Vec<'_, u8>, reg: X86_64GeneralReg) {
@@ -2219,12 +2900,30 @@ fn setge_reg64(buf: &mut Vec<'_, u8>, reg: X86_64GeneralReg) {
     set_reg64_help(0x9d, buf, reg);
 }
 
+/// `SETAE r/m64` -> Set byte if above or equal (CF=0).
+#[inline(always)]
+fn setae_reg64(buf: &mut Vec<'_, u8>, reg: X86_64GeneralReg) {
+    set_reg64_help(0x93, buf, reg);
+}
+
+/// `SETBE r/m64` -> Set byte if below or equal (CF=1 or ZF=1).
+#[inline(always)]
+fn setbe_reg64(buf: &mut Vec<'_, u8>, reg: X86_64GeneralReg) {
+    set_reg64_help(0x96, buf, reg);
+}
+
 /// `SETO r/m64` -> Set byte if oveflow flag is set.
 #[inline(always)]
 fn seto_reg64(buf: &mut Vec<'_, u8>, reg: X86_64GeneralReg) {
     set_reg64_help(0x90, buf, reg);
 }
 
+/// `SETC r/m64` -> Set byte if carry flag is set (CF=1).
+#[inline(always)]
+fn setc_reg64(buf: &mut Vec<'_, u8>, reg: X86_64GeneralReg) {
+    set_reg64_help(0x92, buf, reg);
+}
+
 /// `RET` -> Near return to calling procedure.
 #[inline(always)]
 fn ret(buf: &mut Vec<'_, u8>) {
@@ -2302,6 +3001,50 @@ mod tests {
                 X86_64GeneralReg::R15 => "r15b",
             }
         }
+
+        #[allow(dead_code)]
+        fn low_16bits_string(&self) -> &str {
+            match self {
+                X86_64GeneralReg::RAX => "ax",
+                X86_64GeneralReg::RBX => "bx",
+                X86_64GeneralReg::RCX => "cx",
+                X86_64GeneralReg::RDX => "dx",
+                X86_64GeneralReg::RBP => "bp",
+                X86_64GeneralReg::RSP => "sp",
+                X86_64GeneralReg::RDI => "di",
+                X86_64GeneralReg::RSI => "si",
+                X86_64GeneralReg::R8 => "r8w",
+                X86_64GeneralReg::R9 => "r9w",
+                X86_64GeneralReg::R10 => "r10w",
+                X86_64GeneralReg::R11 => "r11w",
+                X86_64GeneralReg::R12 => "r12w",
+                X86_64GeneralReg::R13 => "r13w",
+                X86_64GeneralReg::R14 => "r14w",
+                X86_64GeneralReg::R15 => "r15w",
+            }
+        }
+
+        #[allow(dead_code)]
+        fn low_32bits_string(&self) -> &str {
+            match self {
+                X86_64GeneralReg::RAX => "eax",
+                X86_64GeneralReg::RBX => "ebx",
+                X86_64GeneralReg::RCX => "ecx",
+                X86_64GeneralReg::RDX => "edx",
+                X86_64GeneralReg::RBP => "ebp",
+                X86_64GeneralReg::RSP => "esp",
+                X86_64GeneralReg::RDI => "edi",
+                X86_64GeneralReg::RSI => "esi",
+                X86_64GeneralReg::R8 => "r8d",
+                X86_64GeneralReg::R9 => "r9d",
+                X86_64GeneralReg::R10 => "r10d",
+                X86_64GeneralReg::R11 => "r11d",
+                X86_64GeneralReg::R12 => "r12d",
+                X86_64GeneralReg::R13 => "r13d",
+                X86_64GeneralReg::R14 => "r14d",
+                X86_64GeneralReg::R15 => "r15d",
+            }
+        }
     }
     const TEST_I32: i32 = 0x12345678;
     const TEST_I64: i64 = 0x1234_5678_9ABC_DEF0;
@@ -2417,6 +3160,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_andps_freg32_freg32() {
+        disassembler_test!(
+            andps_freg32_freg32,
+            |reg1, reg2| format!("andps {}, {}", reg1, reg2),
+            ALL_FLOAT_REGS,
+            ALL_FLOAT_REGS
+        );
+    }
+
+    #[test]
+    fn test_xorpd_freg64_freg64() {
+        disassembler_test!(
+            xorpd_freg64_freg64,
+            |reg1, reg2| format!("xorpd {}, {}", reg1, reg2),
+            ALL_FLOAT_REGS,
+            ALL_FLOAT_REGS
+        );
+    }
+
+    #[test]
+    fn test_xorps_freg32_freg32() {
+        disassembler_test!(
+            xorps_freg32_freg32,
+            |reg1, reg2| format!("xorps {}, {}", reg1, reg2),
+            ALL_FLOAT_REGS,
+            ALL_FLOAT_REGS
+        );
+    }
+
     #[test]
     fn test_and_reg64_reg64() {
         disassembler_test!(
@@ -2564,6 +3337,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_jp_imm8() {
+        const INST_SIZE: i8 = 2;
+        disassembler_test!(jp_imm8, |imm| format!("jp 0x{:x}", imm + INST_SIZE), [0x12]);
+    }
+
+    #[test]
+    fn test_lea_reg64_rip_offset32() {
+        disassembler_test!(
+            lea_reg64_rip_offset32,
+            |reg, imm| format!("lea {}, [rip + 0x{:x}]", reg, imm),
+            ALL_GENERAL_REGS,
+            [TEST_I32 as u32]
+        );
+    }
+
     #[test]
     fn test_mov_reg64_imm32() {
         disassembler_test!(
@@ -2644,6 +3433,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_mov_base32_offset32_reg32() {
+        disassembler_test!(
+            mov_base32_offset32_reg32,
+            |reg1, imm, reg2: X86_64GeneralReg| format!(
+                "mov dword ptr [{} + 0x{:x}], {}",
+                reg1,
+                imm,
+                reg2.low_32bits_string()
+            ),
+            ALL_GENERAL_REGS,
+            [TEST_I32],
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_mov_base16_offset32_reg16() {
+        disassembler_test!(
+            mov_base16_offset32_reg16,
+            |reg1, imm, reg2: X86_64GeneralReg| format!(
+                "mov word ptr [{} + 0x{:x}], {}",
+                reg1,
+                imm,
+                reg2.low_16bits_string()
+            ),
+            ALL_GENERAL_REGS,
+            [TEST_I32],
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_mov_base8_offset32_reg8() {
+        disassembler_test!(
+            mov_base8_offset32_reg8,
+            |reg1, imm, reg2: X86_64GeneralReg| format!(
+                "mov byte ptr [{} + 0x{:x}], {}",
+                reg1,
+                imm,
+                reg2.low_8bits_string()
+            ),
+            ALL_GENERAL_REGS,
+            [TEST_I32],
+            ALL_GENERAL_REGS
+        );
+    }
+
     #[test]
     fn test_movzx_reg64_base8_offset32() {
         disassembler_test!(
@@ -2700,6 +3537,34 @@ mod tests {
         disassembler_test!(neg_reg64, |reg| format!("neg {}", reg), ALL_GENERAL_REGS);
     }
 
+    #[test]
+    fn test_bswap_reg64() {
+        disassembler_test!(
+            bswap_reg64,
+            |reg| format!("bswap {}", reg),
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_bswap_reg32() {
+        disassembler_test!(
+            bswap_reg32,
+            |reg: X86_64GeneralReg| format!("bswap {}", reg.low_32bits_string()),
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_rol_reg16_imm8() {
+        disassembler_test!(
+            rol_reg16_imm8,
+            |reg: X86_64GeneralReg, imm| format!("rol {}, {}", reg.low_16bits_string(), imm),
+            ALL_GENERAL_REGS,
+            [8]
+        );
+    }
+
     #[test]
     fn test_cvtsi2_help() {
         const CVTSI2SS_CODE: u8 = 0x2A;
@@ -2738,6 +3603,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_setae_reg64() {
+        disassembler_test!(
+            setae_reg64,
+            |reg: X86_64GeneralReg| format!("setae {}\nand {}, 1", reg.low_8bits_string(), reg),
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_setbe_reg64() {
+        disassembler_test!(
+            setbe_reg64,
+            |reg: X86_64GeneralReg| format!("setbe {}\nand {}, 1", reg.low_8bits_string(), reg),
+            ALL_GENERAL_REGS
+        );
+    }
+
+    #[test]
+    fn test_ucomisd_freg64_freg64() {
+        disassembler_test!(
+            ucomisd_freg64_freg64,
+            |reg1, reg2| format!("ucomisd {}, {}", reg1, reg2),
+            ALL_FLOAT_REGS,
+            ALL_FLOAT_REGS
+        );
+    }
+
+    #[test]
+    fn test_ucomiss_freg32_freg32() {
+        disassembler_test!(
+            ucomiss_freg32_freg32,
+            |reg1, reg2| format!("ucomiss {}, {}", reg1, reg2),
+            ALL_FLOAT_REGS,
+            ALL_FLOAT_REGS
+        );
+    }
+
     #[test]
     fn test_ret() {
         disassembler_test!(ret, || "ret");
@@ -12,14 +12,14 @@ use roc_error_macros::internal_error;
 use roc_module::ident::ModuleName;
 use roc_module::low_level::{LowLevel, LowLevelWrapperType};
 use roc_module::symbol::{Interns, ModuleId, Symbol};
-use roc_mono::code_gen_help::CodeGenHelp;
+use roc_mono::code_gen_help::{CodeGenHelp, HelperOp};
 use roc_mono::ir::{
-    BranchInfo, CallType, Expr, JoinPointId, ListLiteralElement, Literal, Param, Proc, ProcLayout,
-    SelfRecursive, Stmt,
+    BranchInfo, CallSpecId, CallType, CrashTag, Expr, JoinPointId, ListLiteralElement, Literal,
+    Param, Proc, ProcLayout, SelfRecursive, Stmt,
 };
 use roc_mono::layout::{
-    Builtin, InLayout, Layout, LayoutId, LayoutIds, LayoutInterner, STLayoutInterner, TagIdIntType,
-    UnionLayout,
+    Builtin, InLayout, LambdaName, Layout, LayoutId, LayoutIds, LayoutInterner, STLayoutInterner,
+    TagIdIntType, UnionLayout,
 };
 
 mod generic64;
@@ -33,6 +33,10 @@ pub struct Env<'a> {
     pub exposed_to_host: MutSet<Symbol>,
     pub lazy_literals: bool,
     pub generate_allocators: bool,
+    /// Report leaks and bad frees at runtime, see `roc run --check-memory`
+    pub check_memory: bool,
+    /// Report values that stop being unique, see `roc run --trace-refcounts`
+    pub trace_refcounts: bool,
 }
 
 // These relocations likely will need a length.
@@ -111,6 +115,14 @@ trait Backend<'a> {
     /// Used for generating wrappers for malloc/realloc/free
     fn build_wrapped_jmp(&mut self) -> (&'a [u8], u64);
 
+    /// build_memcheck_enter tells the memory checker that the procedure with this name is now running.
+    /// Allocations made from here on are attributed to it.
+    fn build_memcheck_enter(&mut self, proc_name: String);
+
+    /// build_memcheck_reenter tells the memory checker that we are back in the procedure being built,
+    /// after a call to another procedure returns.
+    fn build_memcheck_reenter(&mut self);
+
     /// build_proc creates a procedure and outputs it to the wrapped object writer.
     /// Returns the procedure bytes, its relocations, and the names of the refcounting functions it references.
     fn build_proc(
@@ -127,6 +139,15 @@ trait Backend<'a> {
         }
         self.scan_ast(&proc.body);
         self.create_free_map();
+        if self.env().check_memory {
+            let symbol = proc.name.name();
+            let readable_name = format!(
+                "{}.{}",
+                symbol.module_string(self.interns()),
+                symbol.as_str(self.interns())
+            );
+            self.build_memcheck_enter(readable_name);
+        }
         self.build_stmt(&proc.body, &proc.ret_layout);
         let mut helper_proc_names = bumpalo::vec![in self.env().arena];
         helper_proc_names.reserve(self.helper_proc_symbols().len());
@@ -226,6 +247,11 @@ trait Backend<'a> {
                 self.build_jump(id, args, arg_layouts.into_bump_slice(), ret_layout);
                 self.free_symbols(stmt);
             }
+            Stmt::Crash(msg, tag) => {
+                self.load_literal_symbols(&[*msg]);
+                self.build_crash(msg, *tag);
+                self.free_symbols(stmt);
+            }
             x => todo!("the statement, {:?}", x),
         }
     }
@@ -295,7 +321,10 @@ trait Backend<'a> {
                             let fn_name = self.symbol_to_string(func_sym.name(), layout_id);
                             // Now that the arguments are needed, load them if they are literals.
                             self.load_literal_symbols(arguments);
-                            self.build_fn_call(sym, fn_name, arguments, arg_layouts, ret_layout)
+                            self.build_fn_call(sym, fn_name, arguments, arg_layouts, ret_layout);
+                            if self.env().check_memory {
+                                self.build_memcheck_reenter();
+                            }
                         } else {
                             self.build_builtin(
                                 sym,
@@ -365,6 +394,14 @@ trait Backend<'a> {
             } => {
                 self.load_union_at_index(sym, structure, *tag_id, *index, union_layout);
             }
+            Expr::UnionFieldPtrAtIndex {
+                structure,
+                tag_id,
+                union_layout,
+                index,
+            } => {
+                self.load_union_field_ptr_at_index(sym, structure, *tag_id, *index, union_layout);
+            }
             Expr::GetTagId {
                 structure,
                 union_layout,
@@ -380,6 +417,52 @@ trait Backend<'a> {
                 self.load_literal_symbols(arguments);
                 self.tag(sym, arguments, tag_layout, *tag_id);
             }
+            Expr::NullPointer => {
+                self.load_null_pointer(sym);
+            }
+            Expr::Reset { symbol, .. } => {
+                // The dev backend does not reuse allocations yet.
+                // Resetting just drops the value, and the matching `Reuse` allocates a new one.
+                let reset_layout = *self.layout_map().get(symbol).unwrap();
+                let (dec_proc, new_specializations) = {
+                    let (module_id, layout_interner, interns, rc_proc_gen) =
+                        self.module_interns_helpers_mut();
+                    let ident_ids = interns.all_ident_ids.get_mut(&module_id).unwrap();
+
+                    rc_proc_gen.gen_refcount_proc(
+                        ident_ids,
+                        layout_interner,
+                        reset_layout,
+                        HelperOp::Dec,
+                    )
+                };
+
+                for spec in new_specializations.into_iter() {
+                    self.helper_proc_symbols_mut().push(spec);
+                }
+
+                let dec_expr = Expr::Call(roc_mono::ir::Call {
+                    call_type: CallType::ByName {
+                        name: LambdaName::no_niche(dec_proc),
+                        ret_layout: Layout::UNIT,
+                        arg_layouts: self.env().arena.alloc([reset_layout]),
+                        specialization_id: CallSpecId::BACKEND_DUMMY,
+                    },
+                    arguments: self.env().arena.alloc([*symbol]),
+                });
+                self.build_expr(&Symbol::DEV_TMP, &dec_expr, &Layout::UNIT);
+                self.free_symbol(&Symbol::DEV_TMP);
+                self.load_null_pointer(sym);
+            }
+            Expr::Reuse {
+                tag_layout,
+                tag_id,
+                arguments,
+                ..
+            } => {
+                self.load_literal_symbols(arguments);
+                self.tag(sym, arguments, tag_layout, *tag_id);
+            }
             x => todo!("the expression, {:?}", x),
         }
     }
@@ -553,6 +636,114 @@ trait Backend<'a> {
                     internal_error!("bitwise xor on a non-integer")
                 }
             }
+            LowLevel::NumCountLeadingZeroBits => {
+                if let Layout::Builtin(Builtin::Int(int_width)) =
+                    self.interner().get(arg_layouts[0])
+                {
+                    self.build_fn_call(
+                        sym,
+                        bitcode::NUM_COUNT_LEADING_ZERO_BITS[int_width].to_string(),
+                        args,
+                        arg_layouts,
+                        ret_layout,
+                    )
+                } else {
+                    internal_error!("count leading zero bits on a non-integer")
+                }
+            }
+            LowLevel::NumCountTrailingZeroBits => {
+                if let Layout::Builtin(Builtin::Int(int_width)) =
+                    self.interner().get(arg_layouts[0])
+                {
+                    self.build_fn_call(
+                        sym,
+                        bitcode::NUM_COUNT_TRAILING_ZERO_BITS[int_width].to_string(),
+                        args,
+                        arg_layouts,
+                        ret_layout,
+                    )
+                } else {
+                    internal_error!("count trailing zero bits on a non-integer")
+                }
+            }
+            LowLevel::NumCountOneBits => {
+                if let Layout::Builtin(Builtin::Int(int_width)) =
+                    self.interner().get(arg_layouts[0])
+                {
+                    self.build_fn_call(
+                        sym,
+                        bitcode::NUM_COUNT_ONE_BITS[int_width].to_string(),
+                        args,
+                        arg_layouts,
+                        ret_layout,
+                    )
+                } else {
+                    internal_error!("count one bits on a non-integer")
+                }
+            }
+            LowLevel::NumRotateLeftBy => {
+                if let Layout::Builtin(Builtin::Int(int_width)) =
+                    self.interner().get(arg_layouts[0])
+                {
+                    self.build_fn_call(
+                        sym,
+                        bitcode::NUM_ROTATE_LEFT_BY[int_width].to_string(),
+                        args,
+                        arg_layouts,
+                        ret_layout,
+                    )
+                } else {
+                    internal_error!("rotate left on a non-integer")
+                }
+            }
+            LowLevel::NumRotateRightBy => {
+                if let Layout::Builtin(Builtin::Int(int_width)) =
+                    self.interner().get(arg_layouts[0])
+                {
+                    self.build_fn_call(
+                        sym,
+                        bitcode::NUM_ROTATE_RIGHT_BY[int_width].to_string(),
+                        args,
+                        arg_layouts,
+                        ret_layout,
+                    )
+                } else {
+                    internal_error!("rotate right on a non-integer")
+                }
+            }
+            LowLevel::NumSwapBytes => {
+                if let Layout::Builtin(Builtin::Int(int_width)) =
+                    self.interner().get(arg_layouts[0])
+                {
+                    self.build_fn_call(
+                        sym,
+                        bitcode::NUM_SWAP_BYTES[int_width].to_string(),
+                        args,
+                        arg_layouts,
+                        ret_layout,
+                    )
+                } else {
+                    internal_error!("swap bytes on a non-integer")
+                }
+            }
+            LowLevel::NumReadBytesLe | LowLevel::NumReadBytesBe => {
+                debug_assert_eq!(
+                    2,
+                    args.len(),
+                    "NumReadBytes: expected to have exactly two arguments"
+                );
+                let big_endian = matches!(lowlevel, LowLevel::NumReadBytesBe);
+                self.build_num_read_bytes(sym, &args[0], &args[1], ret_layout, big_endian)
+            }
+            LowLevel::NumWriteBytesLe | LowLevel::NumWriteBytesBe => {
+                debug_assert_eq!(
+                    3,
+                    args.len(),
+                    "NumWriteBytes: expected to have exactly three arguments"
+                );
+                let big_endian = matches!(lowlevel, LowLevel::NumWriteBytesBe);
+                self.build_num_write_bytes(sym, args, arg_layouts, ret_layout, big_endian)
+            }
             LowLevel::Eq => {
                 debug_assert_eq!(2, args.len(), "Eq: expected to have exactly two argument");
                 debug_assert_eq!(
@@ -625,8 +816,8 @@ trait Backend<'a> {
                 );
 
                 debug_assert!(
-                    matches!(*ret_layout, Layout::F32 | Layout::F64),
-                    "NumToFrac: expected to have return layout of type Float"
+                    matches!(*ret_layout, Layout::F32 | Layout::F64 | Layout::DEC),
+                    "NumToFrac: expected to have return layout of type Frac"
                 );
                 self.build_num_to_frac(sym, &args[0], &arg_layouts[0], ret_layout)
             }
@@ -664,6 +855,18 @@ trait Backend<'a> {
                 );
                 self.build_num_gte(sym, &args[0], &args[1], &arg_layouts[0])
             }
+            LowLevel::NumCompare => {
+                debug_assert_eq!(
+                    2,
+                    args.len(),
+                    "NumCompare: expected to have exactly two arguments"
+                );
+                debug_assert_eq!(
+                    arg_layouts[0], arg_layouts[1],
+                    "NumCompare: expected all arguments to have the same layout"
+                );
+                self.build_num_compare(sym, &args[0], &args[1], &arg_layouts[0])
+            }
             LowLevel::NumRound => self.build_fn_call(
                 sym,
                 bitcode::NUM_ROUND_F64[IntWidth::I64].to_string(),
@@ -695,6 +898,62 @@ trait Backend<'a> {
                 );
                 self.build_list_replace_unsafe(sym, args, arg_layouts, ret_layout)
             }
+            LowLevel::ListGetCapacity => {
+                debug_assert_eq!(
+                    1,
+                    args.len(),
+                    "ListGetCapacity: expected to have exactly one argument"
+                );
+                self.build_list_get_capacity(sym, &args[0])
+            }
+            LowLevel::ListWithCapacity => {
+                debug_assert_eq!(
+                    1,
+                    args.len(),
+                    "ListWithCapacity: expected to have exactly one argument"
+                );
+                self.build_list_with_capacity(sym, &args[0], &arg_layouts[0], ret_layout)
+            }
+            LowLevel::ListReserve => {
+                debug_assert_eq!(
+                    2,
+                    args.len(),
+                    "ListReserve: expected to have exactly two arguments"
+                );
+                self.build_list_reserve(sym, args, arg_layouts, ret_layout)
+            }
+            LowLevel::ListAppendUnsafe => {
+                debug_assert_eq!(
+                    2,
+                    args.len(),
+                    "ListAppendUnsafe: expected to have exactly two arguments"
+                );
+                self.build_list_append_unsafe(sym, args, arg_layouts, ret_layout)
+            }
+            LowLevel::ListPrepend => {
+                debug_assert_eq!(
+                    2,
+                    args.len(),
+                    "ListPrepend: expected to have exactly two arguments"
+                );
+                self.build_list_prepend(sym, args, arg_layouts, ret_layout)
+            }
+            LowLevel::ListConcat => {
+                debug_assert_eq!(
+                    2,
+                    args.len(),
+                    "ListConcat: expected to have exactly two arguments"
+                );
+                self.build_list_concat(sym, args, arg_layouts, ret_layout)
+            }
+            LowLevel::ListSwap => {
+                debug_assert_eq!(
+                    3,
+                    args.len(),
+                    "ListSwap: expected to have exactly three arguments"
+                );
+                self.build_list_swap(sym, args, arg_layouts, ret_layout)
+            }
             LowLevel::StrConcat => self.build_fn_call(
                 sym,
                 bitcode::STR_CONCAT.to_string(),
@@ -702,6 +961,369 @@ trait Backend<'a> {
                 arg_layouts,
                 ret_layout,
             ),
+            LowLevel::StrJoinWith => self.build_fn_call(
+                sym,
+                bitcode::STR_JOIN_WITH.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrToScalars => self.build_fn_call(
+                sym,
+                bitcode::STR_TO_SCALARS.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrStartsWith => self.build_fn_call(
+                sym,
+                bitcode::STR_STARTS_WITH.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrStartsWithScalar => self.build_fn_call(
+                sym,
+                bitcode::STR_STARTS_WITH_SCALAR.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrEndsWith => self.build_fn_call(
+                sym,
+                bitcode::STR_ENDS_WITH.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrToUtf8 => self.build_fn_call(
+                sym,
+                bitcode::STR_TO_UTF8.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrRepeat => self.build_fn_call(
+                sym,
+                bitcode::STR_REPEAT.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrSplit => self.build_fn_call(
+                sym,
+                bitcode::STR_SPLIT.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrCountGraphemes => self.build_fn_call(
+                sym,
+                bitcode::STR_COUNT_GRAPEHEME_CLUSTERS.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrCountUtf8Bytes => self.build_fn_call(
+                sym,
+                bitcode::STR_COUNT_UTF8_BYTES.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrGetCapacity => self.build_fn_call(
+                sym,
+                bitcode::STR_CAPACITY.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrGetUnsafe => self.build_fn_call(
+                sym,
+                bitcode::STR_GET_UNSAFE.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrSubstringUnsafe => self.build_fn_call(
+                sym,
+                bitcode::STR_SUBSTRING_UNSAFE.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrReserve => self.build_fn_call(
+                sym,
+                bitcode::STR_RESERVE.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrAppendScalar => self.build_fn_call(
+                sym,
+                bitcode::STR_APPEND_SCALAR.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrTrim => self.build_fn_call(
+                sym,
+                bitcode::STR_TRIM.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrTrimLeft => self.build_fn_call(
+                sym,
+                bitcode::STR_TRIM_LEFT.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrTrimRight => self.build_fn_call(
+                sym,
+                bitcode::STR_TRIM_RIGHT.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrWithCapacity => self.build_fn_call(
+                sym,
+                bitcode::STR_WITH_CAPACITY.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrGraphemes => self.build_fn_call(
+                sym,
+                bitcode::STR_GRAPHEMES.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrToUppercase => self.build_fn_call(
+                sym,
+                bitcode::STR_TO_UPPERCASE.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrToLowercase => self.build_fn_call(
+                sym,
+                bitcode::STR_TO_LOWERCASE.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrCaseFold => self.build_fn_call(
+                sym,
+                bitcode::STR_CASE_FOLD.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrToNfc => self.build_fn_call(
+                sym,
+                bitcode::STR_TO_NFC.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrToNfd => self.build_fn_call(
+                sym,
+                bitcode::STR_TO_NFD.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrFind => self.build_fn_call(
+                sym,
+                bitcode::STR_FIND.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrIsEmpty => {
+                self.build_fn_call(
+                    &Symbol::DEV_TMP,
+                    bitcode::STR_NUMBER_OF_BYTES.to_string(),
+                    args,
+                    arg_layouts,
+                    &Layout::U64,
+                );
+                self.load_literal(
+                    &Symbol::DEV_TMP2,
+                    &Layout::U64,
+                    &Literal::Int(0i128.to_ne_bytes()),
+                );
+                self.build_eq(sym, &Symbol::DEV_TMP, &Symbol::DEV_TMP2, &Layout::U64);
+                self.free_symbol(&Symbol::DEV_TMP);
+                self.free_symbol(&Symbol::DEV_TMP2);
+            }
+            LowLevel::StrFromInt => match self.interner().get(arg_layouts[0]) {
+                Layout::Builtin(Builtin::Int(int_width)) => self.build_fn_call(
+                    sym,
+                    bitcode::STR_FROM_INT[int_width].to_string(),
+                    args,
+                    arg_layouts,
+                    ret_layout,
+                ),
+                x => internal_error!("StrFromInt: expected an integer, got {:?}", x),
+            },
+            LowLevel::StrToNum => {
+                let number_layout = match self.interner().get(*ret_layout) {
+                    Layout::Struct { field_layouts, .. } => field_layouts[0],
+                    x => internal_error!("StrToNum: expected a struct, got {:?}", x),
+                };
+                let fn_name = match self.interner().get(number_layout) {
+                    Layout::Builtin(Builtin::Int(int_width)) => &bitcode::STR_TO_INT[int_width],
+                    Layout::Builtin(Builtin::Float(float_width)) => {
+                        &bitcode::STR_TO_FLOAT[float_width]
+                    }
+                    Layout::Builtin(Builtin::Decimal) => bitcode::DEC_FROM_STR,
+                    x => internal_error!("StrToNum: expected a number, got {:?}", x),
+                };
+                self.build_fn_call(sym, fn_name.to_string(), args, arg_layouts, ret_layout)
+            }
+            LowLevel::StrFromUtf8Range => {
+                debug_assert_eq!(
+                    3,
+                    args.len(),
+                    "StrFromUtf8Range: expected to have exactly three arguments"
+                );
+                // Without update modes we can't know whether the list is unique, so pass
+                // `UpdateMode.Immutable` and let the builtin check.
+                self.load_literal(
+                    &Symbol::DEV_TMP,
+                    &Layout::U8,
+                    &Literal::Int(0i128.to_ne_bytes()),
+                );
+                self.build_fn_call(
+                    sym,
+                    bitcode::STR_FROM_UTF8_RANGE.to_string(),
+                    &[args[0], args[1], args[2], Symbol::DEV_TMP],
+                    &[arg_layouts[0], arg_layouts[1], arg_layouts[2], Layout::U8],
+                    ret_layout,
+                );
+                self.free_symbol(&Symbol::DEV_TMP);
+            }
+            LowLevel::StrToIntRadix => {
+                let field_layouts = match self.interner().get(*ret_layout) {
+                    Layout::Struct { field_layouts, .. } => field_layouts,
+                    x => internal_error!("StrToIntRadix: expected a struct, got {:?}", x),
+                };
+                // The fields are sorted by alignment, so the number comes either before or after
+                // the index. Every target of this backend has 64-bit pointers.
+                let number_layout = if field_layouts[0] == Layout::U64 {
+                    field_layouts[1]
+                } else {
+                    field_layouts[0]
+                };
+                match self.interner().get(number_layout) {
+                    Layout::Builtin(Builtin::Int(int_width)) => self.build_fn_call(
+                        sym,
+                        bitcode::STR_TO_INT_RADIX[int_width].to_string(),
+                        args,
+                        arg_layouts,
+                        ret_layout,
+                    ),
+                    x => internal_error!("StrToIntRadix: expected an integer, got {:?}", x),
+                }
+            }
+            LowLevel::StrToFloatChecked => {
+                let field_layouts = match self.interner().get(*ret_layout) {
+                    Layout::Struct { field_layouts, .. } => field_layouts,
+                    x => internal_error!("StrToFloatChecked: expected a struct, got {:?}", x),
+                };
+                let float_width = field_layouts
+                    .iter()
+                    .find_map(|field| match self.interner().get(*field) {
+                        Layout::Builtin(Builtin::Float(float_width)) => Some(float_width),
+                        _ => None,
+                    })
+                    .unwrap();
+                self.build_fn_call(
+                    sym,
+                    bitcode::STR_TO_FLOAT_CHECKED[float_width].to_string(),
+                    args,
+                    arg_layouts,
+                    ret_layout,
+                )
+            }
+            LowLevel::StrFromFloat => match self.interner().get(arg_layouts[0]) {
+                Layout::Builtin(Builtin::Float(float_width)) => self.build_fn_call(
+                    sym,
+                    bitcode::STR_FROM_FLOAT[float_width].to_string(),
+                    args,
+                    arg_layouts,
+                    ret_layout,
+                ),
+                x => internal_error!("StrFromFloat: expected a float, got {:?}", x),
+            },
+            LowLevel::NumToStr => match self.interner().get(arg_layouts[0]) {
+                Layout::Builtin(Builtin::Int(int_width)) => self.build_fn_call(
+                    sym,
+                    bitcode::STR_FROM_INT[int_width].to_string(),
+                    args,
+                    arg_layouts,
+                    ret_layout,
+                ),
+                Layout::Builtin(Builtin::Float(float_width)) => self.build_fn_call(
+                    sym,
+                    bitcode::STR_FROM_FLOAT[float_width].to_string(),
+                    args,
+                    arg_layouts,
+                    ret_layout,
+                ),
+                Layout::Builtin(Builtin::Decimal) => self.build_fn_call(
+                    sym,
+                    bitcode::DEC_TO_STR.to_string(),
+                    args,
+                    arg_layouts,
+                    ret_layout,
+                ),
+                x => internal_error!("NumToStr: expected a number, got {:?}", x),
+            },
+            LowLevel::NumToStrFixed | LowLevel::NumToStrScientific => {
+                let fn_name = match (self.interner().get(arg_layouts[0]), *lowlevel) {
+                    (Layout::Builtin(Builtin::Float(float_width)), LowLevel::NumToStrFixed) => {
+                        &bitcode::NUM_TO_STR_FIXED[float_width]
+                    }
+                    (Layout::Builtin(Builtin::Float(float_width)), _) => {
+                        &bitcode::NUM_TO_STR_SCIENTIFIC[float_width]
+                    }
+                    (Layout::Builtin(Builtin::Decimal), LowLevel::NumToStrFixed) => {
+                        bitcode::DEC_TO_STR_FIXED
+                    }
+                    (Layout::Builtin(Builtin::Decimal), _) => bitcode::DEC_TO_STR_SCIENTIFIC,
+                    (x, _) => internal_error!("{:?}: expected a fraction, got {:?}", lowlevel, x),
+                };
+                self.build_fn_call(sym, fn_name.to_string(), args, arg_layouts, ret_layout)
+            }
+            LowLevel::NumToStrRadix => match self.interner().get(arg_layouts[0]) {
+                Layout::Builtin(Builtin::Int(int_width)) => self.build_fn_call(
+                    sym,
+                    bitcode::NUM_TO_STR_RADIX[int_width].to_string(),
+                    args,
+                    arg_layouts,
+                    ret_layout,
+                ),
+                x => internal_error!("NumToStrRadix: expected an integer, got {:?}", x),
+            },
+            LowLevel::NumPadZeros => self.build_fn_call(
+                sym,
+                bitcode::NUM_PAD_ZEROS.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::NumGroupDigits => self.build_fn_call(
+                sym,
+                bitcode::NUM_GROUP_DIGITS.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
             LowLevel::PtrCast => {
                 debug_assert_eq!(
                     1,
@@ -710,6 +1332,39 @@ trait Backend<'a> {
                 );
                 self.build_ptr_cast(sym, &args[0])
             }
+            LowLevel::PtrStore => {
+                debug_assert_eq!(
+                    2,
+                    args.len(),
+                    "PtrStore: expected to have exactly two arguments"
+                );
+                self.build_ptr_store(sym, &args[0], &args[1], &arg_layouts[1]);
+            }
+            LowLevel::PtrLoad => {
+                debug_assert_eq!(
+                    1,
+                    args.len(),
+                    "PtrLoad: expected to have exactly one argument"
+                );
+                self.build_ptr_load(sym, &args[0], ret_layout);
+            }
+            LowLevel::Alloca => {
+                debug_assert_eq!(
+                    1,
+                    args.len(),
+                    "Alloca: expected to have exactly one argument"
+                );
+                self.build_alloca(sym, &args[0], &arg_layouts[0]);
+            }
+            LowLevel::And => {
+                // Refcounting helpers also use `And` on pointer-sized integers.
+                let int_width = match self.interner().get(*ret_layout) {
+                    Layout::Builtin(Builtin::Int(int_width)) => int_width,
+                    Layout::Builtin(Builtin::Bool) => IntWidth::U8,
+                    _ => internal_error!("And on a non-integer"),
+                };
+                self.build_int_bitwise_and(sym, &args[0], &args[1], int_width)
+            }
             LowLevel::RefCountDec => self.build_fn_call(
                 sym,
                 bitcode::UTILS_DECREF.to_string(),
@@ -724,6 +1379,34 @@ trait Backend<'a> {
                 arg_layouts,
                 ret_layout,
             ),
+            LowLevel::HashRandomSeed => self.build_fn_call(
+                sym,
+                bitcode::UTILS_HASH_RANDOM_SEED.to_string(),
+                &[],
+                &[],
+                ret_layout,
+            ),
+            LowLevel::RefCountTraceLocation => self.build_fn_call(
+                sym,
+                bitcode::UTILS_REFCOUNT_TRACE_LOCATION.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::RefCountTraceReset => self.build_fn_call(
+                sym,
+                bitcode::UTILS_REFCOUNT_TRACE_RESET.to_string(),
+                &[],
+                &[],
+                ret_layout,
+            ),
+            LowLevel::RefCountTraceInc => self.build_fn_call(
+                sym,
+                bitcode::UTILS_REFCOUNT_TRACE_INCREF.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
             x => todo!("low level, {:?}", x),
         }
     }
@@ -915,6 +1598,15 @@ trait Backend<'a> {
         arg_layout: &InLayout<'a>,
     );
 
+    /// build_num_compare stores the tag id of `Num.compare src1 src2` into dst.
+    fn build_num_compare(
+        &mut self,
+        dst: &Symbol,
+        src1: &Symbol,
+        src2: &Symbol,
+        arg_layout: &InLayout<'a>,
+    );
+
     /// build_list_len returns the length of a list.
     fn build_list_len(&mut self, dst: &Symbol, list: &Symbol);
 
@@ -936,9 +1628,105 @@ trait Backend<'a> {
         ret_layout: &InLayout<'a>,
     );
 
+    /// build_list_get_capacity returns the capacity of a list.
+    fn build_list_get_capacity(&mut self, dst: &Symbol, list: &Symbol);
+
+    /// build_list_with_capacity returns an empty list with room for `capacity` elements.
+    fn build_list_with_capacity(
+        &mut self,
+        dst: &Symbol,
+        capacity: &Symbol,
+        capacity_layout: &InLayout<'a>,
+        ret_layout: &InLayout<'a>,
+    );
+
+    /// build_list_reserve returns the list with room for at least `spare` more elements.
+    fn build_list_reserve(
+        &mut self,
+        dst: &Symbol,
+        args: &'a [Symbol],
+        arg_layouts: &[InLayout<'a>],
+        ret_layout: &InLayout<'a>,
+    );
+
+    /// build_list_append_unsafe returns the list with the element added to its end.
+    /// The list must already have room for the element.
+    fn build_list_append_unsafe(
+        &mut self,
+        dst: &Symbol,
+        args: &'a [Symbol],
+        arg_layouts: &[InLayout<'a>],
+        ret_layout: &InLayout<'a>,
+    );
+
+    /// build_list_prepend returns the list with the element added to its start.
+    fn build_list_prepend(
+        &mut self,
+        dst: &Symbol,
+        args: &'a [Symbol],
+        arg_layouts: &[InLayout<'a>],
+        ret_layout: &InLayout<'a>,
+    );
+
+    /// build_list_concat returns the elements of the first list followed by those of the second.
+    fn build_list_concat(
+        &mut self,
+        dst: &Symbol,
+        args: &'a [Symbol],
+        arg_layouts: &[InLayout<'a>],
+        ret_layout: &InLayout<'a>,
+    );
+
+    /// build_list_swap returns the list with the elements at the two indices swapped.
+    fn build_list_swap(
+        &mut self,
+        dst: &Symbol,
+        args: &'a [Symbol],
+        arg_layouts: &[InLayout<'a>],
+        ret_layout: &InLayout<'a>,
+    );
+
+    /// build_num_read_bytes reads a number from the bytes of a list, starting at `position`.
+    /// Roc code has already checked that the whole number fits in the list.
+    fn build_num_read_bytes(
+        &mut self,
+        dst: &Symbol,
+        list: &Symbol,
+        position: &Symbol,
+        ret_layout: &InLayout<'a>,
+        big_endian: bool,
+    );
+
+    /// build_num_write_bytes writes a number to the bytes of a list, starting at `position`.
+    /// The list is made unique first, so the returned list may be a copy.
+    fn build_num_write_bytes(
+        &mut self,
+        dst: &Symbol,
+        args: &'a [Symbol],
+        arg_layouts: &[InLayout<'a>],
+        ret_layout: &InLayout<'a>,
+        big_endian: bool,
+    );
+
     /// build_refcount_getptr loads the pointer to the reference count of src into dst.
     fn build_ptr_cast(&mut self, dst: &Symbol, src: &Symbol);
 
+    /// build_ptr_store stores `value` at the address in `ptr`.
+    fn build_ptr_store(
+        &mut self,
+        sym: &Symbol,
+        ptr: &Symbol,
+        value: &Symbol,
+        element_layout: &InLayout<'a>,
+    );
+
+    /// build_ptr_load loads the value at the address in `ptr` into `sym`.
+    fn build_ptr_load(&mut self, sym: &Symbol, ptr: &Symbol, element_layout: &InLayout<'a>);
+
+    /// build_alloca copies `value` to a new stack slot and loads its address into `sym`.
+    /// The stack slot lives until the procedure returns.
+    fn build_alloca(&mut self, sym: &Symbol, value: &Symbol, element_layout: &InLayout<'a>);
+
     /// literal_map gets the map from symbol to literal and layout, used for lazy loading and literal folding.
     fn literal_map(&mut self) -> &mut MutMap<Symbol, (*const Literal<'a>, *const InLayout<'a>)>;
 
@@ -992,6 +1780,16 @@ trait Backend<'a> {
         union_layout: &UnionLayout<'a>,
     );
 
+    /// load_union_field_ptr_at_index loads into `sym` a pointer to the field at `index` for `tag_id`.
+    fn load_union_field_ptr_at_index(
+        &mut self,
+        sym: &Symbol,
+        structure: &Symbol,
+        tag_id: TagIdIntType,
+        index: u64,
+        union_layout: &UnionLayout<'a>,
+    );
+
     /// get_tag_id loads the tag id from a the union.
     fn get_tag_id(&mut self, sym: &Symbol, structure: &Symbol, union_layout: &UnionLayout<'a>);
 
@@ -1004,6 +1802,12 @@ trait Backend<'a> {
         tag_id: TagIdIntType,
     );
 
+    /// load_null_pointer sets the symbol to a null pointer.
+    fn load_null_pointer(&mut self, sym: &Symbol);
+
+    /// build_crash calls roc_panic with the message and crash tag. It never returns.
+    fn build_crash(&mut self, msg: &Symbol, tag: CrashTag);
+
     /// return_symbol moves a symbol to the correct return location for the backend and adds a jump to the end of the function.
     fn return_symbol(&mut self, sym: &Symbol, layout: &InLayout<'a>);
 
@@ -1103,6 +1907,9 @@ trait Backend<'a> {
                     Expr::UnionAtIndex { structure, .. } => {
                         self.set_last_seen(*structure, stmt);
                     }
+                    Expr::UnionFieldPtrAtIndex { structure, .. } => {
+                        self.set_last_seen(*structure, stmt);
+                    }
                     Expr::Array { elems, .. } => {
                         for elem in *elems {
                             if let ListLiteralElement::Symbol(sym) = elem {
@@ -1121,7 +1928,7 @@ trait Backend<'a> {
                     Expr::Reset { symbol, .. } => {
                         self.set_last_seen(*symbol, stmt);
                     }
-                    Expr::EmptyArray => {}
+                    Expr::EmptyArray | Expr::NullPointer => {}
                     Expr::RuntimeErrorFunction(_) => {}
                 }
                 self.scan_ast(following);
@@ -1179,7 +1986,9 @@ trait Backend<'a> {
             Stmt::Expect { .. } => todo!("expect is not implemented in the dev backend"),
             Stmt::ExpectFx { .. } => todo!("expect-fx is not implemented in the dev backend"),
 
-            Stmt::Crash(..) => todo!("crash is not implemented in the dev backend"),
+            Stmt::Crash(msg, _) => {
+                self.set_last_seen(*msg, stmt);
+            }
         }
     }
 
@@ -4,9 +4,10 @@ use bumpalo::collections::Vec;
 use object::write::{self, SectionId, SymbolId};
 use object::write::{Object, StandardSection, StandardSegment, Symbol, SymbolSection};
 use object::{
-    Architecture, BinaryFormat, Endianness, RelocationEncoding, RelocationKind, SectionKind,
-    SymbolFlags, SymbolKind, SymbolScope,
+    elf, macho, Architecture, BinaryFormat, Endianness, RelocationEncoding, RelocationKind,
+    SectionFlags, SectionKind, SymbolFlags, SymbolKind, SymbolScope,
 };
+use roc_builtins::bitcode;
 use roc_collections::all::MutMap;
 use roc_error_macros::internal_error;
 use roc_module::symbol;
@@ -165,6 +166,59 @@ fn generate_wrapper<'a, B: Backend<'a>>(
     }
 }
 
+/// Report leaked allocations when the program exits, see `roc run --check-memory`
+fn add_memcheck_report_at_exit(output: &mut Object) {
+    let (segment, name, kind, flags) = match output.format() {
+        BinaryFormat::MachO => (
+            b"__DATA".to_vec(),
+            b"__mod_term_func".to_vec(),
+            SectionKind::Data,
+            SectionFlags::MachO {
+                flags: macho::S_MOD_TERM_FUNC_POINTERS,
+            },
+        ),
+        _ => (
+            vec![],
+            b".fini_array".to_vec(),
+            SectionKind::Elf(elf::SHT_FINI_ARRAY),
+            SectionFlags::Elf {
+                sh_flags: (elf::SHF_ALLOC | elf::SHF_WRITE) as u64,
+            },
+        ),
+    };
+
+    let section_id = output.add_section(segment, name, kind);
+    output.section_mut(section_id).flags = flags;
+    let offset = output.append_section_data(section_id, &[0; 8], 8);
+
+    let name = bitcode::UTILS_MEMCHECK_REPORT_AT_EXIT.as_bytes();
+    let symbol_id = output.symbol_id(name).unwrap_or_else(|| {
+        output.add_symbol(Symbol {
+            name: name.to_vec(),
+            value: 0,
+            size: 0,
+            kind: SymbolKind::Text,
+            scope: SymbolScope::Dynamic,
+            weak: false,
+            section: SymbolSection::Undefined,
+            flags: SymbolFlags::None,
+        })
+    });
+
+    let reloc = write::Relocation {
+        offset,
+        size: 64,
+        kind: RelocationKind::Absolute,
+        encoding: RelocationEncoding::Generic,
+        symbol: symbol_id,
+        addend: 0,
+    };
+
+    if let Err(e) = output.add_relocation(section_id, reloc) {
+        internal_error!("{:?}", e);
+    }
+}
+
 fn build_object<'a, B: Backend<'a>>(
     procedures: MutMap<(symbol::Symbol, ProcLayout<'a>), Proc<'a>>,
     mut backend: B,
@@ -211,6 +265,10 @@ fn build_object<'a, B: Backend<'a>>(
         );
     }
 
+    if backend.env().check_memory {
+        add_memcheck_report_at_exit(&mut output);
+    }
+
     // Setup layout_ids for procedure calls.
     let mut layout_ids = LayoutIds::default();
     let mut procs = Vec::with_capacity_in(procedures.len(), arena);
@@ -396,14 +454,54 @@ fn build_proc<'a, B: Backend<'a>>(
                 };
                 local_data_index += 1;
                 let data_id = output.add_symbol(data_symbol);
-                output.add_symbol_data(data_id, data_section, data, 4);
-                write::Relocation {
-                    offset: offset + proc_offset,
-                    size: 32,
-                    kind: RelocationKind::Relative,
-                    encoding: RelocationEncoding::Generic,
-                    symbol: data_id,
-                    addend: -4,
+                // list literals can hold 128-bit numbers, which need 16-byte alignment
+                output.add_symbol_data(data_id, data_section, data, 16);
+                if output.architecture() == Architecture::Aarch64 {
+                    // an ADRP for the page of the data, followed by an ADD for the offset within that page
+                    let (page_kind, page_offset_kind) = match output.format() {
+                        BinaryFormat::MachO => (
+                            RelocationKind::MachO {
+                                value: macho::ARM64_RELOC_PAGE21,
+                                relative: true,
+                            },
+                            RelocationKind::MachO {
+                                value: macho::ARM64_RELOC_PAGEOFF12,
+                                relative: false,
+                            },
+                        ),
+                        _ => (
+                            RelocationKind::Elf(elf::R_AARCH64_ADR_PREL_PG_HI21),
+                            RelocationKind::Elf(elf::R_AARCH64_ADD_ABS_LO12_NC),
+                        ),
+                    };
+                    relocations.push((
+                        section_id,
+                        write::Relocation {
+                            offset: offset + proc_offset,
+                            size: 32,
+                            kind: page_kind,
+                            encoding: RelocationEncoding::Generic,
+                            symbol: data_id,
+                            addend: 0,
+                        },
+                    ));
+                    write::Relocation {
+                        offset: offset + proc_offset + 4,
+                        size: 32,
+                        kind: page_offset_kind,
+                        encoding: RelocationEncoding::Generic,
+                        symbol: data_id,
+                        addend: 0,
+                    }
+                } else {
+                    write::Relocation {
+                        offset: offset + proc_offset,
+                        size: 32,
+                        kind: RelocationKind::Relative,
+                        encoding: RelocationEncoding::Generic,
+                        symbol: data_id,
+                        addend: -4,
+                    }
                 }
             }
             Relocation::LinkedData { offset, name } => {
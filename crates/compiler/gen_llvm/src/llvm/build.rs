@@ -1,11 +1,11 @@
-use crate::llvm::bitcode::call_bitcode_fn;
+use crate::llvm::bitcode::{build_dec_wrapper, build_inc_wrapper, call_bitcode_fn};
 use crate::llvm::build_list::{self, allocate_list, empty_polymorphic_list};
 use crate::llvm::convert::{
     argument_type_from_layout, basic_type_from_builtin, basic_type_from_layout, zig_str_type,
 };
 use crate::llvm::expect::{clone_to_shared_memory, SharedMemoryPointer};
 use crate::llvm::refcounting::{
-    build_reset, decrement_refcount_layout, increment_refcount_layout, PointerToRefcount,
+    build_reset, decrement_refcount_layout, increment_refcount_layout, Mode, PointerToRefcount,
 };
 use bumpalo::collections::Vec;
 use bumpalo::Bump;
@@ -250,6 +250,10 @@ pub struct Env<'a, 'ctx, 'env> {
     pub target_info: TargetInfo,
     pub mode: LlvmBackendMode,
     pub exposed_to_host: MutSet<Symbol>,
+    /// Send allocations through the memory checker in the builtins, see `roc run --check-memory`
+    pub check_memory: bool,
+    /// Report values that stop being unique, see `roc run --trace-refcounts`
+    pub trace_refcounts: bool,
 }
 
 impl<'a, 'ctx, 'env> Env<'a, 'ctx, 'env> {
@@ -348,7 +352,13 @@ impl<'a, 'ctx, 'env> Env<'a, 'ctx, 'env> {
         number_of_bytes: IntValue<'ctx>,
         alignment: u32,
     ) -> PointerValue<'ctx> {
-        let function = self.module.get_function("roc_alloc").unwrap();
+        let function = if self.check_memory {
+            self.module
+                .get_function(bitcode::UTILS_MEMCHECK_ALLOC)
+                .unwrap()
+        } else {
+            self.module.get_function("roc_alloc").unwrap()
+        };
         let alignment = self.alignment_const(alignment);
         let call = self.builder.build_call(
             function,
@@ -366,7 +376,13 @@ impl<'a, 'ctx, 'env> Env<'a, 'ctx, 'env> {
     }
 
     pub fn call_dealloc(&self, ptr: PointerValue<'ctx>, alignment: u32) -> InstructionValue<'ctx> {
-        let function = self.module.get_function("roc_dealloc").unwrap();
+        let function = if self.check_memory {
+            self.module
+                .get_function(bitcode::UTILS_MEMCHECK_DEALLOC)
+                .unwrap()
+        } else {
+            self.module.get_function("roc_dealloc").unwrap()
+        };
         let alignment = self.alignment_const(alignment);
         let call =
             self.builder
@@ -1273,6 +1289,10 @@ pub fn build_exp_expr<'a, 'ctx, 'env>(
         }
 
         EmptyArray => empty_polymorphic_list(env),
+        NullPointer => basic_type_from_layout(env, layout_interner, layout)
+            .into_pointer_type()
+            .const_null()
+            .into(),
         Array { elem_layout, elems } => {
             list_literal(env, layout_interner, parent, scope, *elem_layout, elems)
         }
@@ -1416,6 +1436,66 @@ pub fn build_exp_expr<'a, 'ctx, 'env>(
             }
         }
 
+        UnionFieldPtrAtIndex {
+            tag_id,
+            structure,
+            index,
+            union_layout,
+        } => {
+            let argument = load_symbol(scope, structure).into_pointer_value();
+
+            let (data_ptr, field_layouts) = match union_layout {
+                UnionLayout::NonRecursive(_) => {
+                    unreachable!("a non-recursive tag is not heap-allocated")
+                }
+                UnionLayout::Recursive(tag_layouts) => (
+                    tag_pointer_clear_tag_id(env, argument),
+                    tag_layouts[*tag_id as usize],
+                ),
+                UnionLayout::NullableWrapped {
+                    nullable_id,
+                    other_tags,
+                } => {
+                    debug_assert_ne!(*tag_id, *nullable_id);
+
+                    let tag_index = if *tag_id < *nullable_id {
+                        *tag_id
+                    } else {
+                        tag_id - 1
+                    };
+
+                    (
+                        tag_pointer_clear_tag_id(env, argument),
+                        other_tags[tag_index as usize],
+                    )
+                }
+                UnionLayout::NonNullableUnwrapped(field_layouts)
+                | UnionLayout::NullableUnwrapped {
+                    other_fields: field_layouts,
+                    ..
+                } => (argument, *field_layouts),
+            };
+
+            let struct_layout = layout_interner.insert(Layout::struct_no_name_order(field_layouts));
+            let struct_type =
+                basic_type_from_layout(env, layout_interner, struct_layout).into_struct_type();
+
+            let data_ptr = env.builder.build_pointer_cast(
+                data_ptr,
+                struct_type.ptr_type(AddressSpace::Generic),
+                "cast_field_ptr_at_index",
+            );
+
+            let elem_ptr = env
+                .builder
+                .new_build_struct_gep(struct_type, data_ptr, *index as u32, "field_ptr_at_index")
+                .unwrap();
+
+            env.builder
+                .build_ptr_to_int(elem_ptr, env.ptr_int(), "field_ptr_to_int")
+                .into()
+        }
+
         GetTagId {
             structure,
             union_layout,
@@ -2203,136 +2283,73 @@ fn list_literal<'a, 'ctx, 'env>(
     let list_length = elems.len();
     let list_length_intval = env.ptr_int().const_int(list_length as _, false);
 
-    // TODO re-enable, currently causes morphic segfaults because it tries to update
-    // constants in-place...
-    // if element_type.is_int_type() {
-    if false {
-        let element_type = element_type.into_int_type();
+    let is_all_constant = elems
+        .iter()
+        .all(|element| matches!(element, ListLiteralElement::Literal(_)));
+
+    // Alias analysis treats a list of literals as a constant that is never unique, so it is
+    // never updated in place and can live in the constants section.
+    if is_all_constant && (element_type.is_int_type() || element_type.is_float_type()) {
         let element_width = layout_interner.stack_size(element_layout);
-        let size = list_length * element_width as usize;
         let alignment = layout_interner
             .alignment_bytes(element_layout)
             .max(env.target_info.ptr_width() as u32);
 
-        let mut is_all_constant = true;
+        // Zero elements in front of the first element represent the refcount; a refcount of
+        // zero marks the list as read-only.
         let zero_elements =
             (env.target_info.ptr_width() as u8 as f64 / element_width as f64).ceil() as usize;
 
-        // runtime-evaluated elements
-        let mut runtime_evaluated_elements = Vec::with_capacity_in(list_length, env.arena);
-
-        // set up a global that contains all the literal elements of the array
-        // any variables or expressions are represented as `undef`
-        let global = {
-            let mut global_elements = Vec::with_capacity_in(list_length, env.arena);
-
-            // Add zero bytes that represent the refcount
-            //
-            // - if all elements are const, then we store the whole list as a constant.
-            //      It then needs a refcount before the first element.
-            // - but if the list is not all constants, then we will just copy the constant values,
-            //      and we do not need that refcount at the start
-            //
-            // In the latter case, we won't store the zeros in the globals
-            // (we slice them off again below)
-            for _ in 0..zero_elements {
-                global_elements.push(element_type.const_zero());
-            }
-
-            // Copy the elements from the list literal into the array
-            for (index, element) in elems.iter().enumerate() {
-                match element {
-                    ListLiteralElement::Literal(literal) => {
-                        let val = build_exp_literal(
-                            env,
-                            layout_interner,
-                            parent,
-                            element_layout,
-                            literal,
-                        );
-                        global_elements.push(val.into_int_value());
-                    }
-                    ListLiteralElement::Symbol(symbol) => {
-                        let val = load_symbol(scope, symbol);
-
-                        // here we'd like to furthermore check for intval.is_const().
-                        // if all elements are const for LLVM, we could make the array a constant.
-                        // BUT morphic does not know about this, and could allow us to modify that
-                        // array in-place. That would cause a segfault. So, we'll have to find
-                        // constants ourselves and cannot lean on LLVM here.
+        let mut global_elements = Vec::with_capacity_in(zero_elements + list_length, env.arena);
 
-                        is_all_constant = false;
-
-                        runtime_evaluated_elements.push((index, val));
+        for _ in 0..zero_elements {
+            global_elements.push(element_type.const_zero());
+        }
 
-                        global_elements.push(element_type.get_undef());
-                    }
-                };
+        for element in elems.iter() {
+            if let ListLiteralElement::Literal(literal) = element {
+                let val = build_exp_literal(env, layout_interner, parent, element_layout, literal);
+                global_elements.push(val);
             }
+        }
 
-            let const_elements = if is_all_constant {
-                global_elements.into_bump_slice()
-            } else {
-                &global_elements[zero_elements..]
-            };
-
-            // use None for the address space (e.g. Const does not work)
-            let typ = element_type.array_type(const_elements.len() as u32);
-            let global = env.module.add_global(typ, None, "roc__list_literal");
-
-            global.set_constant(true);
-            global.set_alignment(alignment);
-            global.set_unnamed_addr(true);
-            global.set_linkage(inkwell::module::Linkage::Private);
-
-            global.set_initializer(&element_type.const_array(const_elements));
-            global.as_pointer_value()
+        let initializer = if element_type.is_int_type() {
+            let ints = Vec::from_iter_in(
+                global_elements.iter().map(|val| val.into_int_value()),
+                env.arena,
+            );
+            element_type.into_int_type().const_array(&ints)
+        } else {
+            let floats = Vec::from_iter_in(
+                global_elements.iter().map(|val| val.into_float_value()),
+                env.arena,
+            );
+            element_type.into_float_type().const_array(&floats)
         };
 
-        if is_all_constant {
-            // all elements are constants, so we can use the memory in the constants section directly
-            // here we make a pointer to the first actual element (skipping the 0 bytes that
-            // represent the refcount)
-            let zero = env.ptr_int().const_zero();
-            let offset = env.ptr_int().const_int(zero_elements as _, false);
-
-            let ptr = unsafe {
-                env.builder.new_build_in_bounds_gep(
-                    element_type,
-                    global,
-                    &[zero, offset],
-                    "first_element_pointer",
-                )
-            };
+        // use None for the address space (e.g. Const does not work)
+        let typ = element_type.array_type(global_elements.len() as u32);
+        let global = env.module.add_global(typ, None, "roc__list_literal");
 
-            super::build_list::store_list(env, ptr, list_length_intval).into()
-        } else {
-            // some of our elements are non-constant, so we must allocate space on the heap
-            let ptr = allocate_list(env, layout_interner, element_layout, list_length_intval);
+        global.set_constant(true);
+        global.set_alignment(alignment);
+        global.set_unnamed_addr(true);
+        global.set_linkage(inkwell::module::Linkage::Private);
+        global.set_initializer(&initializer);
 
-            // then, copy the relevant segment from the constant section into the heap
-            env.builder
-                .build_memcpy(
-                    ptr,
-                    alignment,
-                    global,
-                    alignment,
-                    env.ptr_int().const_int(size as _, false),
-                )
-                .unwrap();
-
-            // then replace the `undef`s with the values that we evaluate at runtime
-            for (index, val) in runtime_evaluated_elements {
-                let index_val = ctx.i64_type().const_int(index as u64, false);
-                let elem_ptr = unsafe {
-                    builder.new_build_in_bounds_gep(element_type, ptr, &[index_val], "index")
-                };
+        // make a pointer to the first actual element, skipping the refcount
+        let offset = env.ptr_int().const_int(zero_elements as _, false);
 
-                builder.build_store(elem_ptr, val);
-            }
+        let ptr = unsafe {
+            env.builder.new_build_in_bounds_gep(
+                element_type,
+                global.as_pointer_value(),
+                &[offset],
+                "first_element_pointer",
+            )
+        };
 
-            super::build_list::store_list(env, ptr, list_length_intval).into()
-        }
+        super::build_list::store_list(env, ptr, list_length_intval).into()
     } else {
         let ptr = allocate_list(env, layout_interner, element_layout, list_length_intval);
 
@@ -4915,6 +4932,7 @@ fn build_proc_header<'a, 'ctx, 'env>(
 fn expose_alias_to_host<'a, 'ctx, 'env>(
     env: &Env<'a, 'ctx, 'env>,
     layout_interner: &mut STLayoutInterner<'a>,
+    layout_ids: &mut LayoutIds<'a>,
     mod_solutions: &'a ModSolutions,
     proc_name: LambdaName,
     alias_symbol: Symbol,
@@ -4970,6 +4988,7 @@ fn expose_alias_to_host<'a, 'ctx, 'env>(
             build_closure_caller(
                 env,
                 layout_interner,
+                layout_ids,
                 &fn_name,
                 evaluator,
                 alias_symbol,
@@ -5001,6 +5020,7 @@ fn expose_alias_to_host<'a, 'ctx, 'env>(
 fn build_closure_caller<'a, 'ctx, 'env>(
     env: &Env<'a, 'ctx, 'env>,
     layout_interner: &mut STLayoutInterner<'a>,
+    layout_ids: &mut LayoutIds<'a>,
     def_name: &str,
     evaluator: FunctionValue<'ctx>,
     alias_symbol: Symbol,
@@ -5132,6 +5152,86 @@ fn build_closure_caller<'a, 'ctx, 'env>(
         alias_symbol,
         lambda_set.runtime_representation(),
     );
+
+    // STEP 5: build i8* -> {} functions that increment and decrement the refcounts
+    // of the values captured by the closure. The caller takes ownership of the closure
+    // data, so the host uses these to keep a closure it calls again, and to drop it.
+    build_host_exposed_alias_refcount(
+        env,
+        layout_interner,
+        layout_ids,
+        def_name,
+        alias_symbol,
+        lambda_set.runtime_representation(),
+        Mode::Inc,
+    );
+    build_host_exposed_alias_refcount(
+        env,
+        layout_interner,
+        layout_ids,
+        def_name,
+        alias_symbol,
+        lambda_set.runtime_representation(),
+        Mode::Dec,
+    );
+}
+
+fn build_host_exposed_alias_refcount<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    layout_interner: &mut STLayoutInterner<'a>,
+    layout_ids: &mut LayoutIds<'a>,
+    def_name: &str,
+    alias_symbol: Symbol,
+    closure_layout: InLayout<'a>,
+    rc_operation: Mode,
+) {
+    let builder = env.builder;
+    let context = env.context;
+
+    let label = match rc_operation {
+        Mode::Inc => "inc",
+        Mode::Dec => "dec",
+    };
+
+    // e.g. `roc__mainForHost_1__Fx_inc`
+    let function_name = format!(
+        "roc__{}_{}_{}_{}",
+        def_name,
+        alias_symbol.module_string(&env.interns),
+        alias_symbol.as_str(&env.interns),
+        label
+    );
+
+    let arg_type = context.i8_type().ptr_type(AddressSpace::Generic);
+    let function_spec = FunctionSpec::cconv(env, CCReturn::Void, None, &[arg_type.into()]);
+
+    let function_value = add_func(
+        env.context,
+        env.module,
+        function_name.as_str(),
+        function_spec,
+        Linkage::External,
+    );
+
+    let subprogram = env.new_subprogram(&function_name);
+    function_value.set_subprogram(subprogram);
+
+    let entry = context.append_basic_block(function_value, "entry");
+
+    builder.position_at_end(entry);
+
+    debug_info_init!(env, function_value);
+
+    let rc_function = match rc_operation {
+        Mode::Inc => build_inc_wrapper(env, layout_interner, layout_ids, closure_layout),
+        Mode::Dec => build_dec_wrapper(env, layout_interner, layout_ids, closure_layout),
+    };
+
+    let closure_data = function_value.get_nth_param(0).unwrap();
+    let call = builder.build_call(rc_function, &[closure_data.into()], "");
+    call.set_call_convention(C_CALL_CONV);
+
+    builder.build_return(None);
 }
 
 fn build_host_exposed_alias_size<'a, 'r, 'ctx, 'env>(
@@ -5221,6 +5321,7 @@ pub fn build_proc<'a, 'ctx, 'env>(
                         expose_alias_to_host(
                             env,
                             layout_interner,
+                            layout_ids,
                             mod_solutions,
                             proc.name,
                             *alias_name,
@@ -5245,6 +5346,17 @@ pub fn build_proc<'a, 'ctx, 'env>(
 
     debug_info_init!(env, fn_val);
 
+    if env.check_memory {
+        let symbol = proc.name.name();
+        let proc_name = match symbol.module_string(&env.interns).as_str() {
+            "" => symbol.as_str(&env.interns).to_string(),
+            module_name => format!("{}.{}", module_name, symbol.as_str(&env.interns)),
+        };
+
+        add_memcheck_name(env, fn_val, &proc_name);
+        build_memcheck_enter(env, fn_val);
+    }
+
     // Add args to scope
     for (arg_val, (layout, arg_symbol)) in fn_val.get_param_iter().zip(args) {
         arg_val.set_name(arg_symbol.as_str(&env.interns));
@@ -5269,6 +5381,86 @@ pub fn build_proc<'a, 'ctx, 'env>(
     }
 }
 
+fn memcheck_name_global(fn_val: FunctionValue<'_>) -> String {
+    format!("roc_memcheck_name.{}", fn_val.get_name().to_str().unwrap())
+}
+
+/// Store the human-readable name of a procedure, for the memory checker to report
+fn add_memcheck_name<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    fn_val: FunctionValue<'ctx>,
+    proc_name: &str,
+) {
+    let name = env.context.const_string(proc_name.as_bytes(), true);
+
+    let global = env
+        .module
+        .add_global(name.get_type(), None, &memcheck_name_global(fn_val));
+    global.set_initializer(&name);
+    global.set_constant(true);
+    global.set_linkage(Linkage::Private);
+}
+
+/// Tell the memory checker that this procedure is (again) the one running,
+/// so that allocations made from here on are attributed to it
+fn build_memcheck_enter<'a, 'ctx, 'env>(env: &Env<'a, 'ctx, 'env>, fn_val: FunctionValue<'ctx>) {
+    // helpers like refcounting functions have no name of their own
+    if let Some(global) = env.module.get_global(&memcheck_name_global(fn_val)) {
+        let name_ptr = env.builder.build_pointer_cast(
+            global.as_pointer_value(),
+            env.context.i8_type().ptr_type(AddressSpace::Generic),
+            "memcheck_name",
+        );
+
+        let function = env
+            .module
+            .get_function(bitcode::UTILS_MEMCHECK_ENTER)
+            .unwrap();
+        let call = env
+            .builder
+            .build_call(function, &[name_ptr.into()], "memcheck_enter");
+
+        call.set_call_convention(C_CALL_CONV);
+    }
+}
+
+/// Report leaked allocations when a program built with `--check-memory` exits
+pub fn add_memcheck_report_at_exit<'a, 'ctx, 'env>(env: &Env<'a, 'ctx, 'env>) {
+    let ctx = env.context;
+
+    let report = env
+        .module
+        .get_function(bitcode::UTILS_MEMCHECK_REPORT_AT_EXIT)
+        .unwrap();
+
+    // { i32 priority, void ()* function, i8* data }
+    let dtor_type = ctx.struct_type(
+        &[
+            ctx.i32_type().into(),
+            report.get_type().ptr_type(AddressSpace::Generic).into(),
+            ctx.i8_type().ptr_type(AddressSpace::Generic).into(),
+        ],
+        false,
+    );
+
+    let dtor = dtor_type.const_named_struct(&[
+        ctx.i32_type().const_int(65535, false).into(),
+        report.as_global_value().as_pointer_value().into(),
+        ctx.i8_type()
+            .ptr_type(AddressSpace::Generic)
+            .const_null()
+            .into(),
+    ]);
+
+    let dtors = dtor_type.const_array(&[dtor]);
+
+    let global = env
+        .module
+        .add_global(dtors.get_type(), None, "llvm.global_dtors");
+    global.set_initializer(&dtors);
+    global.set_linkage(Linkage::Appending);
+}
+
 pub fn verify_fn(fn_val: FunctionValue<'_>) {
     if !fn_val.verify(print_fn_verification_output()) {
         unsafe {
@@ -5363,7 +5555,7 @@ pub fn call_roc_function<'a, 'ctx, 'env>(
 ) -> BasicValueEnum<'ctx> {
     let pass_by_pointer = roc_function.get_type().get_param_types().len() == arguments.len() + 1;
 
-    match RocReturn::from_layout(env, layout_interner, result_layout) {
+    let result = match RocReturn::from_layout(env, layout_interner, result_layout) {
         RocReturn::ByPointer if !pass_by_pointer => {
             // WARNING this is a hack!!
             let it = arguments.iter().map(|x| (*x).into());
@@ -5438,7 +5630,20 @@ pub fn call_roc_function<'a, 'ctx, 'env>(
                 )
             })
         }
+    };
+
+    if env.check_memory {
+        // the callee is done, so allocations belong to the caller again
+        let caller = env
+            .builder
+            .get_insert_block()
+            .unwrap()
+            .get_parent()
+            .unwrap();
+        build_memcheck_enter(env, caller);
     }
+
+    result
 }
 
 /// Translates a target_lexicon::Triple to a LLVM calling convention u32
@@ -122,6 +122,24 @@ pub(crate) fn list_with_capacity<'a, 'ctx, 'env>(
     )
 }
 
+/// Copies the list, unless it is unique, so that its elements can be overwritten in place.
+pub(crate) fn list_make_unique<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    layout_interner: &mut STLayoutInterner<'a>,
+    list: StructValue<'ctx>,
+    element_layout: InLayout<'a>,
+) -> BasicValueEnum<'ctx> {
+    call_list_bitcode_fn_1(
+        env,
+        list,
+        &[
+            env.alignment_intvalue(layout_interner, element_layout),
+            layout_width(env, layout_interner, element_layout),
+        ],
+        bitcode::LIST_MAKE_UNIQUE,
+    )
+}
+
 pub(crate) fn list_get_unsafe<'a, 'ctx, 'env>(
     env: &Env<'a, 'ctx, 'env>,
     layout_interner: &mut STLayoutInterner<'a>,
@@ -148,6 +148,41 @@ pub(crate) fn add_intrinsics<'ctx>(ctx: &'ctx Context, module: &Module<'ctx>) {
     add_int_intrinsic(ctx, module, &LLVM_SUB_SATURATED, |t| {
         t.fn_type(&[t.into(), t.into()], false)
     });
+
+    add_int_intrinsic(ctx, module, &LLVM_CTLZ, |t| {
+        t.fn_type(&[t.into(), i1_type.into()], false)
+    });
+
+    add_int_intrinsic(ctx, module, &LLVM_CTTZ, |t| {
+        t.fn_type(&[t.into(), i1_type.into()], false)
+    });
+
+    add_int_intrinsic(ctx, module, &LLVM_CTPOP, |t| t.fn_type(&[t.into()], false));
+
+    add_int_intrinsic(ctx, module, &LLVM_FSHL, |t| {
+        t.fn_type(&[t.into(), t.into(), t.into()], false)
+    });
+
+    add_int_intrinsic(ctx, module, &LLVM_FSHR, |t| {
+        t.fn_type(&[t.into(), t.into(), t.into()], false)
+    });
+
+    // llvm.bswap is only defined for an even number of bytes, so there is no 8-bit version.
+    for (width, typ) in [
+        (IntWidth::U16, ctx.i16_type()),
+        (IntWidth::U32, ctx.i32_type()),
+        (IntWidth::U64, ctx.i64_type()),
+        (IntWidth::U128, ctx.i128_type()),
+    ] {
+        if module.get_function(&LLVM_BSWAP[width]).is_none() {
+            add_intrinsic(
+                ctx,
+                module,
+                &LLVM_BSWAP[width],
+                typ.fn_type(&[typ.into()], false),
+            );
+        }
+    }
 }
 
 pub const LLVM_POW: IntrinsicName = float_intrinsic!("llvm.pow");
@@ -180,6 +215,16 @@ pub const LLVM_MUL_WITH_OVERFLOW: IntrinsicName =
 pub const LLVM_ADD_SATURATED: IntrinsicName = llvm_int_intrinsic!("llvm.sadd.sat", "llvm.uadd.sat");
 pub const LLVM_SUB_SATURATED: IntrinsicName = llvm_int_intrinsic!("llvm.ssub.sat", "llvm.usub.sat");
 
+pub const LLVM_CTLZ: IntrinsicName = llvm_int_intrinsic!("llvm.ctlz", "llvm.ctlz");
+pub const LLVM_CTTZ: IntrinsicName = llvm_int_intrinsic!("llvm.cttz", "llvm.cttz");
+pub const LLVM_CTPOP: IntrinsicName = llvm_int_intrinsic!("llvm.ctpop", "llvm.ctpop");
+
+/// A funnel shift of a value with itself is a rotation.
+pub const LLVM_FSHL: IntrinsicName = llvm_int_intrinsic!("llvm.fshl", "llvm.fshl");
+pub const LLVM_FSHR: IntrinsicName = llvm_int_intrinsic!("llvm.fshr", "llvm.fshr");
+
+pub const LLVM_BSWAP: IntrinsicName = llvm_int_intrinsic!("llvm.bswap", "llvm.bswap");
+
 fn add_intrinsic<'ctx>(
     context: &Context,
     module: &Module<'ctx>,
@@ -24,22 +24,23 @@ use crate::llvm::{
     },
     build::{
         complex_bitcast_check_size, create_entry_block_alloca, function_value_by_func_spec,
-        load_roc_value, roc_function_call, BuilderExt, RocReturn,
+        load_roc_value, roc_function_call, store_roc_value, BuilderExt, RocReturn,
     },
     build_list::{
         list_append_unsafe, list_capacity, list_concat, list_drop_at, list_get_unsafe, list_len,
-        list_map, list_map2, list_map3, list_map4, list_prepend, list_replace_unsafe, list_reserve,
-        list_sort_with, list_sublist, list_swap, list_symbol_to_c_abi, list_with_capacity,
-        pass_update_mode,
+        list_make_unique, list_map, list_map2, list_map3, list_map4, list_prepend,
+        list_replace_unsafe, list_reserve, list_sort_with, list_sublist, list_swap,
+        list_symbol_to_c_abi, list_with_capacity, load_list_ptr, pass_update_mode,
     },
     compare::{generic_eq, generic_neq},
     convert::{
         self, basic_type_from_layout, zig_num_parse_result_type, zig_to_int_checked_result_type,
     },
     intrinsics::{
-        LLVM_ADD_SATURATED, LLVM_ADD_WITH_OVERFLOW, LLVM_CEILING, LLVM_COS, LLVM_FABS, LLVM_FLOOR,
-        LLVM_LOG, LLVM_MUL_WITH_OVERFLOW, LLVM_POW, LLVM_ROUND, LLVM_SIN, LLVM_SQRT,
-        LLVM_SUB_SATURATED, LLVM_SUB_WITH_OVERFLOW,
+        LLVM_ADD_SATURATED, LLVM_ADD_WITH_OVERFLOW, LLVM_BSWAP, LLVM_CEILING, LLVM_COS, LLVM_CTLZ,
+        LLVM_CTPOP, LLVM_CTTZ, LLVM_FABS, LLVM_FLOOR, LLVM_FSHL, LLVM_FSHR, LLVM_LOG,
+        LLVM_MUL_WITH_OVERFLOW, LLVM_POW, LLVM_ROUND, LLVM_SIN, LLVM_SQRT, LLVM_SUB_SATURATED,
+        LLVM_SUB_WITH_OVERFLOW,
     },
 };
 
@@ -314,6 +315,64 @@ pub(crate) fn run_low_level<'a, 'ctx, 'env>(
                 result
             }
         }
+        StrToIntRadix => {
+            // Str.strToIntRadix : Str, U8 -> { aresult : Int *, bindex : Nat, cproblem : U8 }
+            arguments!(string, radix);
+
+            let field_layouts = match layout_interner.get(layout) {
+                Layout::Struct { field_layouts, .. } => field_layouts,
+                _ => unreachable!(),
+            };
+
+            // the fields are sorted by alignment, so the number comes either before or after the index
+            let number_layout = if field_layouts[0] == Layout::usize(env.target_info) {
+                field_layouts[1]
+            } else {
+                field_layouts[0]
+            };
+
+            let intrinsic = match layout_interner.get(number_layout) {
+                Layout::Builtin(Builtin::Int(int_width)) => &bitcode::STR_TO_INT_RADIX[int_width],
+                _ => unreachable!(),
+            };
+
+            call_str_bitcode_fn_returning_struct(
+                env,
+                layout_interner,
+                parent,
+                string,
+                &[radix],
+                layout,
+                intrinsic,
+            )
+        }
+        StrToFloatChecked => {
+            // Str.strToFloatChecked : Str -> { aresult : Frac *, bindex : Nat, cproblem : U8 }
+            arguments!(string);
+
+            let field_layouts = match layout_interner.get(layout) {
+                Layout::Struct { field_layouts, .. } => field_layouts,
+                _ => unreachable!(),
+            };
+
+            let float_width = field_layouts
+                .iter()
+                .find_map(|field| match layout_interner.get(*field) {
+                    Layout::Builtin(Builtin::Float(float_width)) => Some(float_width),
+                    _ => None,
+                })
+                .unwrap();
+
+            call_str_bitcode_fn_returning_struct(
+                env,
+                layout_interner,
+                parent,
+                string,
+                &[],
+                layout,
+                &bitcode::STR_TO_FLOAT_CHECKED[float_width],
+            )
+        }
         StrFromInt => {
             // Str.fromInt : Int -> Str
             debug_assert_eq!(args.len(), 1);
@@ -633,6 +692,78 @@ pub(crate) fn run_low_level<'a, 'ctx, 'env>(
                 bitcode::STR_GRAPHEMES,
             )
         }
+        StrToUppercase => {
+            // Str.toUppercase : Str -> Str
+            arguments!(string);
+
+            call_str_bitcode_fn(
+                env,
+                &[string],
+                &[],
+                BitcodeReturns::Str,
+                bitcode::STR_TO_UPPERCASE,
+            )
+        }
+        StrToLowercase => {
+            // Str.toLowercase : Str -> Str
+            arguments!(string);
+
+            call_str_bitcode_fn(
+                env,
+                &[string],
+                &[],
+                BitcodeReturns::Str,
+                bitcode::STR_TO_LOWERCASE,
+            )
+        }
+        StrCaseFold => {
+            // Str.caseFold : Str -> Str
+            arguments!(string);
+
+            call_str_bitcode_fn(
+                env,
+                &[string],
+                &[],
+                BitcodeReturns::Str,
+                bitcode::STR_CASE_FOLD,
+            )
+        }
+        StrToNfc => {
+            // Str.toNfc : Str -> Str
+            arguments!(string);
+
+            call_str_bitcode_fn(
+                env,
+                &[string],
+                &[],
+                BitcodeReturns::Str,
+                bitcode::STR_TO_NFC,
+            )
+        }
+        StrToNfd => {
+            // Str.toNfd : Str -> Str
+            arguments!(string);
+
+            call_str_bitcode_fn(
+                env,
+                &[string],
+                &[],
+                BitcodeReturns::Str,
+                bitcode::STR_TO_NFD,
+            )
+        }
+        StrFind => {
+            // Str.findUnsafe : Str, Str -> Nat
+            arguments!(haystack, needle);
+
+            call_str_bitcode_fn(
+                env,
+                &[haystack, needle],
+                &[],
+                BitcodeReturns::Basic,
+                bitcode::STR_FIND,
+            )
+        }
         ListLen => {
             // List.len : List * -> Nat
             arguments!(list);
@@ -852,10 +983,79 @@ pub(crate) fn run_low_level<'a, 'ctx, 'env>(
                         &bitcode::STR_FROM_FLOAT[float_width],
                     )
                 }
-                Layout::Builtin(Builtin::Decimal) => dec_to_str(env, num),
+                Layout::Builtin(Builtin::Decimal) => dec_to_str(env, num, &[], bitcode::DEC_TO_STR),
                 _ => unreachable!(),
             }
         }
+        NumToStrFixed | NumToStrScientific => {
+            // Num.toStrFixed : Frac a, Nat -> Str
+            // Num.toStrScientific : Frac a, Nat -> Str
+            arguments_with_layouts!((num, num_layout), (precision, _precision_layout));
+
+            match layout_interner.get(num_layout) {
+                Layout::Builtin(Builtin::Float(float_width)) => {
+                    let intrinsic = match op {
+                        NumToStrFixed => &bitcode::NUM_TO_STR_FIXED,
+                        _ => &bitcode::NUM_TO_STR_SCIENTIFIC,
+                    };
+
+                    call_str_bitcode_fn(
+                        env,
+                        &[],
+                        &[num, precision],
+                        BitcodeReturns::Str,
+                        &intrinsic[float_width],
+                    )
+                }
+                Layout::Builtin(Builtin::Decimal) => {
+                    let fn_name = match op {
+                        NumToStrFixed => bitcode::DEC_TO_STR_FIXED,
+                        _ => bitcode::DEC_TO_STR_SCIENTIFIC,
+                    };
+
+                    dec_to_str(env, num, &[precision], fn_name)
+                }
+                _ => unreachable!(),
+            }
+        }
+        NumToStrRadix => {
+            // Num.toStrRadix : Int a, U8 -> Str
+            arguments_with_layouts!((num, num_layout), (radix, _radix_layout));
+
+            let int_width = intwidth_from_layout(num_layout);
+
+            call_str_bitcode_fn(
+                env,
+                &[],
+                &[num, radix],
+                BitcodeReturns::Str,
+                &bitcode::NUM_TO_STR_RADIX[int_width],
+            )
+        }
+        NumPadZeros => {
+            // Num.padZeros : Str, Nat -> Str
+            arguments!(string, width);
+
+            call_str_bitcode_fn(
+                env,
+                &[string],
+                &[width],
+                BitcodeReturns::Str,
+                bitcode::NUM_PAD_ZEROS,
+            )
+        }
+        NumGroupDigits => {
+            // Num.groupDigits : Str, Str -> Str
+            arguments!(string, separator);
+
+            call_str_bitcode_fn(
+                env,
+                &[string, separator],
+                &[],
+                BitcodeReturns::Str,
+                bitcode::NUM_GROUP_DIGITS,
+            )
+        }
         NumAbs | NumNeg | NumRound | NumSqrtUnchecked | NumLogUnchecked | NumSin | NumCos
         | NumCeiling | NumFloor | NumToFrac | NumIsFinite | NumAtan | NumAcos | NumAsin
         | NumToIntChecked => {
@@ -922,6 +1122,46 @@ pub(crate) fn run_low_level<'a, 'ctx, 'env>(
                 bitcode::NUM_BYTES_TO_U32,
             )
         }
+        NumReadBytesLe | NumReadBytesBe => {
+            // Num.readLeUnsafe : List U8, Nat -> Num *
+            arguments!(list, position);
+
+            read_bytes(
+                env,
+                layout_interner,
+                list.into_struct_value(),
+                position.into_int_value(),
+                layout,
+                matches!(op, NumReadBytesBe),
+            )
+        }
+        NumWriteBytesLe | NumWriteBytesBe => {
+            // Num.writeLeUnsafe : List U8, Nat, Num * -> List U8
+            arguments_with_layouts!(
+                (list, _list_layout),
+                (position, _position_layout),
+                (value, value_layout)
+            );
+
+            let list = match update_mode {
+                UpdateMode::InPlace => list,
+                UpdateMode::Immutable => {
+                    list_make_unique(env, layout_interner, list.into_struct_value(), Layout::U8)
+                }
+            };
+
+            write_bytes(
+                env,
+                layout_interner,
+                list.into_struct_value(),
+                position.into_int_value(),
+                value,
+                value_layout,
+                matches!(op, NumWriteBytesBe),
+            );
+
+            list
+        }
         NumCompare => {
             arguments_with_layouts!((lhs_arg, lhs_layout), (rhs_arg, rhs_layout));
 
@@ -940,7 +1180,7 @@ pub(crate) fn run_low_level<'a, 'ctx, 'env>(
                     let tag_lt = env.context.i8_type().const_int(2_u64, false);
 
                     match lhs_builtin {
-                        Int(int_width) => {
+                        Int(_) | Decimal => {
                             let are_equal = env.builder.build_int_compare(
                                 IntPredicate::EQ,
                                 lhs_arg.into_int_value(),
@@ -948,10 +1188,10 @@ pub(crate) fn run_low_level<'a, 'ctx, 'env>(
                                 "int_eq",
                             );
 
-                            let predicate = if int_width.is_signed() {
-                                IntPredicate::SLT
-                            } else {
-                                IntPredicate::ULT
+                            // A Dec is stored as a signed 128-bit integer, so it orders like one.
+                            let predicate = match lhs_builtin {
+                                Int(int_width) if !int_width.is_signed() => IntPredicate::ULT,
+                                _ => IntPredicate::SLT,
                             };
 
                             let is_less_than = env.builder.build_int_compare(
@@ -1070,6 +1310,60 @@ pub(crate) fn run_low_level<'a, 'ctx, 'env>(
                 op,
             )
         }
+        NumCountLeadingZeroBits | NumCountTrailingZeroBits | NumCountOneBits => {
+            arguments_with_layouts!((arg, arg_layout));
+
+            let int_width = intwidth_from_layout(arg_layout);
+            let is_zero_poison = env.context.bool_type().const_zero();
+
+            let count = match op {
+                NumCountLeadingZeroBits => {
+                    env.call_intrinsic(&LLVM_CTLZ[int_width], &[arg, is_zero_poison.into()])
+                }
+                NumCountTrailingZeroBits => {
+                    env.call_intrinsic(&LLVM_CTTZ[int_width], &[arg, is_zero_poison.into()])
+                }
+                _ => env.call_intrinsic(&LLVM_CTPOP[int_width], &[arg]),
+            };
+
+            // The count has the type of the argument, but Roc always returns a U8.
+            env.builder
+                .build_int_cast_sign_flag(
+                    count.into_int_value(),
+                    env.context.i8_type(),
+                    false,
+                    "count_to_u8",
+                )
+                .into()
+        }
+        NumRotateLeftBy | NumRotateRightBy => {
+            arguments_with_layouts!((lhs_arg, lhs_layout), (rhs_arg, rhs_layout));
+
+            let int_width = intwidth_from_layout(lhs_layout);
+
+            debug_assert_eq!(rhs_layout, Layout::U8);
+            // Funnel shifts expect all arguments to have the same type, and take the rotation
+            // amount modulo the bit width, which is exactly how Roc defines rotations.
+            let rhs_arg = env.builder.build_int_cast_sign_flag(
+                rhs_arg.into_int_value(),
+                lhs_arg.get_type().into_int_type(),
+                false,
+                "cast_for_rotate",
+            );
+
+            let intrinsic = match op {
+                NumRotateLeftBy => &LLVM_FSHL,
+                _ => &LLVM_FSHR,
+            };
+
+            env.call_intrinsic(&intrinsic[int_width], &[lhs_arg, lhs_arg, rhs_arg.into()])
+        }
+        NumSwapBytes => {
+            arguments_with_layouts!((arg, arg_layout));
+
+            let int_width = intwidth_from_layout(arg_layout);
+            swap_bytes(env, arg.into_int_value(), int_width).into()
+        }
         NumIntCast => {
             arguments!(arg);
 
@@ -1182,6 +1476,7 @@ pub(crate) fn run_low_level<'a, 'ctx, 'env>(
         Hash => {
             unimplemented!()
         }
+        HashRandomSeed => call_bitcode_fn(env, &[], bitcode::UTILS_HASH_RANDOM_SEED),
 
         ListMap | ListMap2 | ListMap3 | ListMap4 | ListSortWith => {
             unreachable!("these are higher order, and are handled elsewhere")
@@ -1191,10 +1486,79 @@ pub(crate) fn run_low_level<'a, 'ctx, 'env>(
             unreachable!("The {:?} operation is turned into mono Expr", op)
         }
 
-        PtrCast | RefCountInc | RefCountDec => {
+        PtrCast | RefCountInc | RefCountDec | RefCountTraceInc => {
             unreachable!("Not used in LLVM backend: {:?}", op);
         }
 
+        RefCountTraceLocation => {
+            // only emitted by mono when refcount tracing is enabled
+            arguments!(string);
+
+            match env.target_info.ptr_width() {
+                PtrWidth::Bytes4 => {
+                    let (a, b) = pass_list_or_string_to_zig_32bit(env, string.into_struct_value());
+
+                    call_void_bitcode_fn(
+                        env,
+                        &[a.into(), b.into()],
+                        bitcode::UTILS_REFCOUNT_TRACE_LOCATION,
+                    );
+                }
+                PtrWidth::Bytes8 => {
+                    call_void_bitcode_fn(env, &[string], bitcode::UTILS_REFCOUNT_TRACE_LOCATION);
+                }
+            }
+
+            basic_type_from_layout(env, layout_interner, layout).const_zero()
+        }
+
+        RefCountTraceReset => {
+            call_void_bitcode_fn(env, &[], bitcode::UTILS_REFCOUNT_TRACE_RESET);
+
+            basic_type_from_layout(env, layout_interner, layout).const_zero()
+        }
+
+        PtrStore => {
+            arguments_with_layouts!((ptr, _ptr_layout), (value, value_layout));
+
+            let value_type = basic_type_from_layout(env, layout_interner, value_layout);
+            let destination = env.builder.build_int_to_ptr(
+                ptr.into_int_value(),
+                value_type.ptr_type(AddressSpace::Generic),
+                "ptr_store_destination",
+            );
+
+            store_roc_value(env, layout_interner, value_layout, destination, value);
+
+            basic_type_from_layout(env, layout_interner, layout).const_zero()
+        }
+
+        PtrLoad => {
+            arguments!(ptr);
+
+            let value_type = basic_type_from_layout(env, layout_interner, layout);
+            let source = env.builder.build_int_to_ptr(
+                ptr.into_int_value(),
+                value_type.ptr_type(AddressSpace::Generic),
+                "ptr_load_source",
+            );
+
+            load_roc_value(env, layout_interner, layout, source, "ptr_load")
+        }
+
+        Alloca => {
+            arguments_with_layouts!((value, value_layout));
+
+            let value_type = basic_type_from_layout(env, layout_interner, value_layout);
+            let ptr = create_entry_block_alloca(env, parent, value_type, "alloca");
+
+            store_roc_value(env, layout_interner, value_layout, ptr, value);
+
+            env.builder
+                .build_ptr_to_int(ptr, env.ptr_int(), "alloca_to_int")
+                .into()
+        }
+
         Unreachable => match RocReturn::from_layout(env, layout_interner, layout) {
             RocReturn::Return => {
                 let basic_type = basic_type_from_layout(env, layout_interner, layout);
@@ -1633,6 +1997,179 @@ fn throw_on_overflow<'a, 'ctx, 'env>(
         .unwrap()
 }
 
+/// Calls a zig function that returns an `extern struct` laid out like the roc record `layout`.
+/// Depending on the target and the size of the struct, zig either returns it directly or
+/// writes it through a pointer passed as the first argument, so we check which one it does.
+fn call_str_bitcode_fn_returning_struct<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    layout_interner: &mut STLayoutInterner<'a>,
+    parent: FunctionValue<'ctx>,
+    string: BasicValueEnum<'ctx>,
+    other_arguments: &[BasicValueEnum<'ctx>],
+    layout: InLayout<'a>,
+    fn_name: &str,
+) -> BasicValueEnum<'ctx> {
+    let mut arguments = match env.target_info.ptr_width() {
+        PtrWidth::Bytes4 => {
+            let (a, b) = pass_list_or_string_to_zig_32bit(env, string.into_struct_value());
+            vec![a.into(), b.into()]
+        }
+        PtrWidth::Bytes8 => vec![string],
+    };
+    arguments.extend_from_slice(other_arguments);
+
+    let roc_return_type = basic_type_from_layout(env, layout_interner, layout);
+    let zig_function = env.module.get_function(fn_name).unwrap();
+
+    match zig_function.get_type().get_return_type() {
+        Some(_) => {
+            let result = call_bitcode_fn(env, &arguments, fn_name);
+
+            // zig passes the result as a packed integer sometimes, instead of a struct. So we cast
+            if result.get_type() != roc_return_type {
+                complex_bitcast_check_size(env, result, roc_return_type, "zig_struct_cast")
+            } else {
+                result
+            }
+        }
+        None => {
+            let return_alloca =
+                create_entry_block_alloca(env, parent, roc_return_type, "zig_struct_return");
+
+            // the struct may contain an i128, and zig expects it to be 16-byte aligned
+            return_alloca
+                .as_instruction()
+                .unwrap()
+                .set_alignment(16)
+                .unwrap();
+
+            arguments.insert(0, return_alloca.into());
+            call_void_bitcode_fn(env, &arguments, fn_name);
+
+            load_roc_value(
+                env,
+                layout_interner,
+                layout,
+                return_alloca,
+                "zig_struct_result",
+            )
+        }
+    }
+}
+
+/// A pointer to the bytes at `position` in a `List U8`, typed as an integer as wide as the number
+/// we want to read or write there. The bytes may not be aligned for that integer.
+fn list_bytes_ptr<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    list: StructValue<'ctx>,
+    position: IntValue<'ctx>,
+    int_type: IntType<'ctx>,
+) -> PointerValue<'ctx> {
+    let i8_type = env.context.i8_type();
+    let bytes_ptr = load_list_ptr(env.builder, list, i8_type.ptr_type(AddressSpace::Generic));
+
+    let byte_ptr = unsafe {
+        env.builder
+            .new_build_in_bounds_gep(i8_type, bytes_ptr, &[position], "byte_ptr")
+    };
+
+    env.builder.build_pointer_cast(
+        byte_ptr,
+        int_type.ptr_type(AddressSpace::Generic),
+        "num_bytes_ptr",
+    )
+}
+
+/// Reverses the bytes of an integer. A single byte is its own reverse, and llvm.bswap only
+/// exists for an even number of bytes.
+fn swap_bytes<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    int: IntValue<'ctx>,
+    int_width: IntWidth,
+) -> IntValue<'ctx> {
+    if int_width.stack_size() > 1 {
+        env.call_intrinsic(&LLVM_BSWAP[int_width], &[int.into()])
+            .into_int_value()
+    } else {
+        int
+    }
+}
+
+/// All of our targets are little-endian, so big-endian bytes need to be swapped around.
+fn swap_bytes_if_big_endian<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    int: IntValue<'ctx>,
+    int_width: IntWidth,
+    big_endian: bool,
+) -> IntValue<'ctx> {
+    if big_endian {
+        swap_bytes(env, int, int_width)
+    } else {
+        int
+    }
+}
+
+/// The unsigned integer with the same width as the given number layout
+fn num_bytes_int_width(layout_interner: &STLayoutInterner<'_>, layout: InLayout<'_>) -> IntWidth {
+    match layout_interner.get(layout) {
+        Layout::Builtin(Builtin::Int(int_width)) => int_width,
+        Layout::Builtin(Builtin::Float(FloatWidth::F32)) => IntWidth::U32,
+        Layout::Builtin(Builtin::Float(FloatWidth::F64)) => IntWidth::U64,
+        other => internal_error!("Cannot read or write the bytes of {:?}", other),
+    }
+}
+
+fn read_bytes<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    layout_interner: &mut STLayoutInterner<'a>,
+    list: StructValue<'ctx>,
+    position: IntValue<'ctx>,
+    layout: InLayout<'a>,
+    big_endian: bool,
+) -> BasicValueEnum<'ctx> {
+    let int_width = num_bytes_int_width(layout_interner, layout);
+    let int_type = env
+        .context
+        .custom_width_int_type(int_width.stack_size() * 8);
+    let ptr = list_bytes_ptr(env, list, position, int_type);
+
+    let loaded = env.builder.new_build_load(int_type, ptr, "read_bytes");
+    loaded
+        .as_instruction_value()
+        .unwrap()
+        .set_alignment(1)
+        .unwrap();
+
+    let int = swap_bytes_if_big_endian(env, loaded.into_int_value(), int_width, big_endian);
+
+    let number_type = basic_type_from_layout(env, layout_interner, layout);
+    env.builder.build_bitcast(int, number_type, "bytes_to_num")
+}
+
+fn write_bytes<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    layout_interner: &mut STLayoutInterner<'a>,
+    list: StructValue<'ctx>,
+    position: IntValue<'ctx>,
+    value: BasicValueEnum<'ctx>,
+    value_layout: InLayout<'a>,
+    big_endian: bool,
+) {
+    let int_width = num_bytes_int_width(layout_interner, value_layout);
+    let int_type = env
+        .context
+        .custom_width_int_type(int_width.stack_size() * 8);
+    let ptr = list_bytes_ptr(env, list, position, int_type);
+
+    let int = env
+        .builder
+        .build_bitcast(value, int_type, "num_to_bytes")
+        .into_int_value();
+    let int = swap_bytes_if_big_endian(env, int, int_width, big_endian);
+
+    env.builder.build_store(ptr, int).set_alignment(1).unwrap();
+}
+
 fn dec_split_into_words<'a, 'ctx, 'env>(
     env: &Env<'a, 'ctx, 'env>,
     value: IntValue<'ctx>,
@@ -1672,9 +2209,12 @@ fn dec_alloca<'a, 'ctx, 'env>(
     alloca
 }
 
+/// Calls a zig function that takes a Dec (followed by `other_arguments`) and returns a Str
 fn dec_to_str<'a, 'ctx, 'env>(
     env: &Env<'a, 'ctx, 'env>,
     dec: BasicValueEnum<'ctx>,
+    other_arguments: &[BasicValueEnum<'ctx>],
+    fn_name: &str,
 ) -> BasicValueEnum<'ctx> {
     use roc_target::OperatingSystem::*;
 
@@ -1682,25 +2222,18 @@ fn dec_to_str<'a, 'ctx, 'env>(
 
     match env.target_info.operating_system {
         Windows => {
-            //
-            call_str_bitcode_fn(
-                env,
-                &[],
-                &[dec_alloca(env, dec).into()],
-                BitcodeReturns::Str,
-                bitcode::DEC_TO_STR,
-            )
+            let mut arguments = vec![dec_alloca(env, dec).into()];
+            arguments.extend_from_slice(other_arguments);
+
+            call_str_bitcode_fn(env, &[], &arguments, BitcodeReturns::Str, fn_name)
         }
         Unix => {
             let (low, high) = dec_split_into_words(env, dec);
 
-            call_str_bitcode_fn(
-                env,
-                &[],
-                &[low.into(), high.into()],
-                BitcodeReturns::Str,
-                bitcode::DEC_TO_STR,
-            )
+            let mut arguments = vec![low.into(), high.into()];
+            arguments.extend_from_slice(other_arguments);
+
+            call_str_bitcode_fn(env, &[], &arguments, BitcodeReturns::Str, fn_name)
         }
         Wasi => unimplemented!(),
     }
@@ -210,6 +210,13 @@ fn incref_pointer<'a, 'ctx, 'env>(
     pointer: PointerValue<'ctx>,
     amount: IntValue<'ctx>,
 ) {
+    // The traced version reports the values that stop being unique
+    let fn_name = if env.trace_refcounts {
+        roc_builtins::bitcode::UTILS_REFCOUNT_TRACE_INCREF
+    } else {
+        roc_builtins::bitcode::UTILS_INCREF
+    };
+
     call_void_bitcode_fn(
         env,
         &[
@@ -222,7 +229,7 @@ fn incref_pointer<'a, 'ctx, 'env>(
                 .into(),
             amount.into(),
         ],
-        roc_builtins::bitcode::UTILS_INCREF,
+        fn_name,
     );
 }
 
@@ -1060,6 +1060,9 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
 
             Expr::EmptyArray => self.expr_empty_array(sym, storage),
 
+            // A recursive union is stored as a pointer, like the null tag of a nullable union
+            Expr::NullPointer => self.code_builder.i32_const(0),
+
             Expr::Tag {
                 tag_layout: union_layout,
                 tag_id,
@@ -1079,6 +1082,13 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
                 index,
             } => self.expr_union_at_index(*structure, *tag_id, union_layout, *index, sym),
 
+            Expr::UnionFieldPtrAtIndex {
+                structure,
+                tag_id,
+                union_layout,
+                index,
+            } => self.expr_union_field_ptr_at_index(*structure, *tag_id, union_layout, *index),
+
             Expr::ExprBox { symbol: arg_sym } => self.expr_box(sym, *arg_sym, layout, storage),
 
             Expr::ExprUnbox { symbol: arg_sym } => self.expr_unbox(sym, *arg_sym),
@@ -1189,7 +1199,7 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
             self.code_builder.i32_store(Align::Bytes4, offset + 8);
         } else {
             let bytes = string.as_bytes();
-            let elements_addr = self.store_bytes_in_data_section(bytes);
+            let elements_addr = self.store_bytes_in_data_section(bytes, 1);
 
             // ptr
             self.code_builder.get_local(local_id);
@@ -1208,12 +1218,15 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
         };
     }
 
-    /// Create a string constant in the module data section
+    /// Create a string or list constant in the module data section
     /// Return the data we need for code gen: linker symbol index and memory address
-    fn store_bytes_in_data_section(&mut self, bytes: &[u8]) -> u32 {
-        // Place the segment at a 4-byte aligned offset
-        let segment_addr = round_up_to_alignment!(self.module.data.end_addr, PTR_SIZE);
-        let elements_addr = segment_addr + PTR_SIZE;
+    fn store_bytes_in_data_section(&mut self, bytes: &[u8], alignment: u32) -> u32 {
+        // Align the elements, and the refcount that comes right before them
+        let elements_addr = round_up_to_alignment!(
+            self.module.data.end_addr + PTR_SIZE,
+            alignment.max(PTR_SIZE)
+        );
+        let segment_addr = elements_addr - PTR_SIZE;
         let length_with_refcount = 4 + bytes.len();
         self.module.data.end_addr = segment_addr + length_with_refcount as u32;
 
@@ -1528,6 +1541,33 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
         elems: &'a [ListLiteralElement<'a>],
     ) {
         if let StoredValue::StackMemory { location, .. } = storage {
+            let (stack_local_id, stack_offset) =
+                location.local_and_offset(self.storage.stack_frame_pointer);
+
+            // A list of literals can be shared by every evaluation of this expression.
+            // The data segment gives it the refcount REFCOUNT_MAX, so refcounting never frees it,
+            // and it is never unique, so List builtins copy it before writing to it.
+            if let Some(bytes) = self.static_list_bytes(elem_layout, elems) {
+                let alignment = self.layout_interner.alignment_bytes(elem_layout);
+                let elements_addr = self.store_bytes_in_data_section(&bytes, alignment);
+
+                self.code_builder.get_local(stack_local_id);
+                self.code_builder.i32_const(elements_addr as i32);
+                self.code_builder.i32_store(Align::Bytes4, stack_offset);
+
+                self.code_builder.get_local(stack_local_id);
+                self.code_builder.i32_const(elems.len() as i32);
+                self.code_builder
+                    .i32_store(Align::Bytes4, stack_offset + 4 * Builtin::WRAPPER_LEN);
+
+                self.code_builder.get_local(stack_local_id);
+                self.code_builder.i32_const(elems.len() as i32);
+                self.code_builder
+                    .i32_store(Align::Bytes4, stack_offset + 4 * Builtin::WRAPPER_CAPACITY);
+
+                return;
+            }
+
             let size = self.layout_interner.stack_size(elem_layout) * (elems.len() as u32);
 
             // Allocate heap space and store its address in a local variable
@@ -1536,9 +1576,6 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
             self.allocate_with_refcount(Some(size), heap_alignment, 1);
             self.code_builder.set_local(heap_local_id);
 
-            let (stack_local_id, stack_offset) =
-                location.local_and_offset(self.storage.stack_frame_pointer);
-
             // elements pointer
             self.code_builder.get_local(stack_local_id);
             self.code_builder.get_local(heap_local_id);
@@ -1592,6 +1629,27 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
         }
     }
 
+    /// The contents of a list literal whose elements are all number or boolean literals
+    fn static_list_bytes(
+        &self,
+        elem_layout: InLayout<'a>,
+        elems: &[ListLiteralElement<'a>],
+    ) -> Option<std::vec::Vec<u8>> {
+        let layout = self.layout_interner.get(elem_layout);
+        let mut bytes = std::vec::Vec::new();
+
+        for elem in elems {
+            match elem {
+                ListLiteralElement::Literal(literal) => {
+                    bytes.extend(literal.to_le_bytes(&layout)?);
+                }
+                ListLiteralElement::Symbol(_) => return None,
+            }
+        }
+
+        Some(bytes)
+    }
+
     fn expr_empty_array(&mut self, sym: Symbol, storage: &StoredValue) {
         if let StoredValue::StackMemory { location, .. } = storage {
             let (local_id, offset) = location.local_and_offset(self.storage.stack_frame_pointer);
@@ -1804,6 +1862,43 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
         index: u64,
         symbol: Symbol,
     ) {
+        let (from_addr_val, from_offset) =
+            self.union_field_address(structure, tag_id, union_layout, index);
+
+        self.storage.copy_value_from_memory(
+            &mut self.code_builder,
+            symbol,
+            from_addr_val,
+            from_offset,
+        );
+    }
+
+    fn expr_union_field_ptr_at_index(
+        &mut self,
+        structure: Symbol,
+        tag_id: TagIdIntType,
+        union_layout: &UnionLayout<'a>,
+        index: u64,
+    ) {
+        let (addr_val, offset) = self.union_field_address(structure, tag_id, union_layout, index);
+
+        if let AddressValue::NotLoaded(local_id) = addr_val {
+            self.code_builder.get_local(local_id);
+        }
+        if offset != 0 {
+            self.code_builder.i32_const(offset as i32);
+            self.code_builder.i32_add();
+        }
+    }
+
+    /// Address and offset of a field in a tag's data
+    fn union_field_address(
+        &mut self,
+        structure: Symbol,
+        tag_id: TagIdIntType,
+        union_layout: &UnionLayout<'a>,
+        index: u64,
+    ) -> (AddressValue, u32) {
         use UnionLayout::*;
 
         debug_assert!(!union_layout.tag_is_null(tag_id));
@@ -1861,13 +1956,7 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
             AddressValue::NotLoaded(tag_local_id)
         };
 
-        let from_offset = tag_offset + field_offset;
-        self.storage.copy_value_from_memory(
-            &mut self.code_builder,
-            symbol,
-            from_addr_val,
-            from_offset,
-        );
+        (from_addr_val, tag_offset + field_offset)
     }
 
     /*******************************************************************
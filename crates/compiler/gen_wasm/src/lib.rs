@@ -135,7 +135,13 @@ pub fn build_app_module<'a, 'r>(
         host_to_app_map,
         host_module,
         fn_index_offset,
-        CodeGenHelp::new(env.arena, TargetInfo::default_wasm32(), env.module_id),
+        // The refcount trace can't print anything on wasm32, so there's no point instrumenting
+        CodeGenHelp::new(
+            env.arena,
+            TargetInfo::default_wasm32(),
+            env.module_id,
+            false,
+        ),
     );
 
     if DEBUG_SETTINGS.user_procs_ir {
@@ -10,6 +10,7 @@ use roc_mono::layout::{Builtin, FieldOrderHash, InLayout, Layout, LayoutInterner
 use roc_mono::low_level::HigherOrder;
 
 use crate::backend::{ProcLookupData, ProcSource, WasmBackend};
+use crate::code_builder::CodeBuilder;
 use crate::layout::{CallConv, StackMemoryFormat, WasmLayout};
 use crate::storage::{AddressValue, StackMemoryLocation, StoredValue};
 use crate::{PTR_TYPE, TARGET_INFO};
@@ -41,6 +42,10 @@ impl CodeGenNumType {
 
 const UPDATE_MODE_IMMUTABLE: i32 = 0;
 
+/// Tag ids of Roc's `[EQ, GT, LT]`, the result of `Num.compare`
+const ORDER_GT: i32 = 1;
+const ORDER_LT: i32 = 2;
+
 impl From<InLayout<'_>> for CodeGenNumType {
     fn from(layout: InLayout<'_>) -> CodeGenNumType {
         use CodeGenNumType::*;
@@ -121,6 +126,28 @@ fn symbol_is_signed_int(backend: &WasmBackend<'_, '_>, symbol: Symbol) -> bool {
     layout_is_signed_int(backend.storage.symbol_layouts[&symbol])
 }
 
+/// The width of an integer symbol, for choosing between the Zig builtins for each width.
+/// Dec is represented as an I128 that counts in units of 10^-18,
+/// so it can share the I128 builtins for operations that don't care about the scale.
+fn symbol_int_width(backend: &WasmBackend<'_, '_>, symbol: Symbol) -> IntWidth {
+    let layout = backend.storage.symbol_layouts[&symbol];
+    match backend.layout_interner.get(layout) {
+        Layout::Builtin(Builtin::Int(width)) => width,
+        Layout::Builtin(Builtin::Decimal) => IntWidth::I128,
+        x => internal_error!("Expected an integer layout but found {:?}", x),
+    }
+}
+
+/// The address of a value in stack memory, such as a 128-bit number
+fn stack_memory_address(backend: &WasmBackend<'_, '_>, stored: &StoredValue) -> (LocalId, u32) {
+    match stored {
+        StoredValue::StackMemory { location, .. } => {
+            location.local_and_offset(backend.storage.stack_frame_pointer)
+        }
+        _ => internal_error!("Expected a value in stack memory but found {:?}", stored),
+    }
+}
+
 pub struct LowLevelCall<'a> {
     pub lowlevel: LowLevel,
     pub arguments: &'a [Symbol],
@@ -193,6 +220,312 @@ impl<'a> LowLevelCall<'a> {
         }
     }
 
+    /// Compare two 128-bit numbers using a Zig builtin.
+    /// Leaves the tag id of their order (`[EQ, GT, LT]`) on the value stack.
+    fn num128_compare(&self, backend: &mut WasmBackend<'a, '_>) {
+        let width = symbol_int_width(backend, self.arguments[0]);
+        self.load_args_and_call_zig(backend, &bitcode::NUM_COMPARE[width]);
+    }
+
+    /// Bitwise operations on 128-bit integers, one 64-bit half at a time
+    fn num128_bitwise(&self, backend: &mut WasmBackend<'a, '_>) {
+        let (ret_local, ret_offset) = stack_memory_address(backend, &self.ret_storage);
+        let (local0, offset0) =
+            stack_memory_address(backend, backend.storage.get(&self.arguments[0]));
+        let (local1, offset1) =
+            stack_memory_address(backend, backend.storage.get(&self.arguments[1]));
+
+        for half in [0, 8] {
+            backend.code_builder.get_local(ret_local);
+            backend.code_builder.get_local(local0);
+            backend.code_builder.i64_load(Align::Bytes8, offset0 + half);
+            backend.code_builder.get_local(local1);
+            backend.code_builder.i64_load(Align::Bytes8, offset1 + half);
+            match self.lowlevel {
+                LowLevel::NumBitwiseAnd => backend.code_builder.i64_and(),
+                LowLevel::NumBitwiseOr => backend.code_builder.i64_or(),
+                LowLevel::NumBitwiseXor => backend.code_builder.i64_xor(),
+                x => internal_error!("{:?} is not a bitwise operation", x),
+            }
+            backend
+                .code_builder
+                .i64_store(Align::Bytes8, ret_offset + half);
+        }
+    }
+
+    /// Save the address `list.bytes + position` to a new local.
+    /// The caller has already checked that the whole number fits in the list.
+    fn byte_address(
+        &self,
+        backend: &mut WasmBackend<'a, '_>,
+        list_storage: &StoredValue,
+        position: Symbol,
+    ) -> LocalId {
+        let (list_local, list_offset) = stack_memory_address(backend, list_storage);
+        backend.code_builder.get_local(list_local);
+        backend.code_builder.i32_load(Align::Bytes4, list_offset);
+        backend
+            .storage
+            .load_symbols(&mut backend.code_builder, &[position]);
+        backend.code_builder.i32_add();
+
+        let address = backend.storage.create_anonymous_local(PTR_TYPE);
+        backend.code_builder.set_local(address);
+        address
+    }
+
+    /// Num.readLeUnsafe, Num.readBeUnsafe : List U8, Nat -> Num *
+    fn num_read_bytes(&self, backend: &mut WasmBackend<'a, '_>, big_endian: bool) {
+        use CodeGenNumType::*;
+
+        let list_storage = backend.storage.get(&self.arguments[0]).to_owned();
+        let address = self.byte_address(backend, &list_storage, self.arguments[1]);
+        let size = self
+            .ret_layout_raw
+            .stack_size(backend.layout_interner, TARGET_INFO);
+        let cb = &mut backend.code_builder;
+
+        match CodeGenNumType::from(self.ret_layout) {
+            I32 | F32 if big_endian => {
+                load_bytes_be(cb, address, 0, size, ValueType::I32);
+                match self.ret_layout_raw {
+                    Layout::Builtin(Builtin::Int(width)) => self.wrap_small_int(backend, width),
+                    _ => cb.f32_reinterpret_i32(),
+                }
+            }
+            I64 | F64 if big_endian => {
+                load_bytes_be(cb, address, 0, size, ValueType::I64);
+                if self.ret_layout == Layout::F64 {
+                    cb.f64_reinterpret_i64();
+                }
+            }
+            I32 => {
+                cb.get_local(address);
+                match self.ret_layout {
+                    Layout::I8 => cb.i32_load8_s(Align::Bytes1, 0),
+                    Layout::U8 => cb.i32_load8_u(Align::Bytes1, 0),
+                    Layout::I16 => cb.i32_load16_s(Align::Bytes1, 0),
+                    Layout::U16 => cb.i32_load16_u(Align::Bytes1, 0),
+                    _ => cb.i32_load(Align::Bytes1, 0),
+                }
+            }
+            I64 => {
+                cb.get_local(address);
+                cb.i64_load(Align::Bytes1, 0);
+            }
+            F32 => {
+                cb.get_local(address);
+                cb.f32_load(Align::Bytes1, 0);
+            }
+            F64 => {
+                cb.get_local(address);
+                cb.f64_load(Align::Bytes1, 0);
+            }
+            I128 | Decimal => {
+                let (ret_local, ret_offset) = stack_memory_address(backend, &self.ret_storage);
+                let cb = &mut backend.code_builder;
+                for half in [0, 8] {
+                    cb.get_local(ret_local);
+                    if big_endian {
+                        // The most significant half comes first, and is stored second
+                        load_bytes_be(cb, address, 8 - half, 8, ValueType::I64);
+                    } else {
+                        cb.get_local(address);
+                        cb.i64_load(Align::Bytes1, half);
+                    }
+                    cb.i64_store(Align::Bytes8, ret_offset + half);
+                }
+            }
+        }
+    }
+
+    /// Num.writeLeUnsafe, Num.writeBeUnsafe : List U8, Nat, Num * -> List U8
+    fn num_write_bytes(&self, backend: &mut WasmBackend<'a, '_>, big_endian: bool) {
+        use CodeGenNumType::*;
+
+        let list = self.arguments[0];
+        let position = self.arguments[1];
+        let value = self.arguments[2];
+
+        // Zig arguments          Wasm types
+        //  (return pointer)       i32
+        //  list: RocList          i64, i32
+        //  alignment: u32         i32
+        //  element_width: usize   i32
+        //
+        // There is an in-place version of this but we don't use it for dev backends. No morphic_lib analysis.
+        backend
+            .storage
+            .load_symbols(&mut backend.code_builder, &[self.ret_symbol]);
+        backend
+            .storage
+            .load_symbol_zig(&mut backend.code_builder, list);
+        backend.code_builder.i32_const(1);
+        backend.code_builder.i32_const(1);
+        backend.call_host_fn_after_loading_args(bitcode::LIST_MAKE_UNIQUE, 5, false);
+
+        let ret_storage = self.ret_storage.to_owned();
+        let address = self.byte_address(backend, &ret_storage, position);
+
+        let value_layout = backend.storage.symbol_layouts[&value];
+        let size = backend.layout_interner.stack_size(value_layout);
+
+        match CodeGenNumType::from(value_layout) {
+            I32 | I64 | F32 | F64 if big_endian => {
+                backend
+                    .storage
+                    .load_symbols(&mut backend.code_builder, &[value]);
+                let cb = &mut backend.code_builder;
+                let value_type = match value_layout {
+                    Layout::F32 => {
+                        cb.i32_reinterpret_f32();
+                        ValueType::I32
+                    }
+                    Layout::F64 => {
+                        cb.i64_reinterpret_f64();
+                        ValueType::I64
+                    }
+                    Layout::U64 | Layout::I64 => ValueType::I64,
+                    _ => ValueType::I32,
+                };
+                let value_local = backend.storage.create_anonymous_local(value_type);
+                let cb = &mut backend.code_builder;
+                cb.set_local(value_local);
+                store_bytes_be(cb, address, 0, size, value_local, value_type);
+            }
+            I32 | I64 | F32 | F64 => {
+                backend.code_builder.get_local(address);
+                backend
+                    .storage
+                    .load_symbols(&mut backend.code_builder, &[value]);
+                let cb = &mut backend.code_builder;
+                match value_layout {
+                    Layout::F32 => cb.f32_store(Align::Bytes1, 0),
+                    Layout::F64 => cb.f64_store(Align::Bytes1, 0),
+                    Layout::U64 | Layout::I64 => cb.i64_store(Align::Bytes1, 0),
+                    _ => match size {
+                        1 => cb.i32_store8(Align::Bytes1, 0),
+                        2 => cb.i32_store16(Align::Bytes1, 0),
+                        _ => cb.i32_store(Align::Bytes1, 0),
+                    },
+                }
+            }
+            I128 | Decimal => {
+                let (value_local, value_offset) =
+                    stack_memory_address(backend, backend.storage.get(&value));
+                let half_local = backend.storage.create_anonymous_local(ValueType::I64);
+                let cb = &mut backend.code_builder;
+                for half in [0, 8] {
+                    if big_endian {
+                        // The most significant half is stored second, and goes first
+                        cb.get_local(value_local);
+                        cb.i64_load(Align::Bytes8, value_offset + half);
+                        cb.set_local(half_local);
+                        store_bytes_be(cb, address, 8 - half, 8, half_local, ValueType::I64);
+                    } else {
+                        cb.get_local(address);
+                        cb.get_local(value_local);
+                        cb.i64_load(Align::Bytes8, value_offset + half);
+                        cb.i64_store(Align::Bytes1, half);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Convert the argument to a float, leaving the result on the value stack
+    fn load_arg_as_float(&self, backend: &mut WasmBackend<'a, '_>, ret_width: FloatWidth) {
+        use CodeGenNumType::*;
+
+        let arg = self.arguments[0];
+        let arg_layout = backend.storage.symbol_layouts[&arg];
+        let arg_type = CodeGenNumType::from(arg_layout);
+
+        match backend.layout_interner.get(arg_layout) {
+            Layout::Builtin(Builtin::Int(arg_width)) if arg_type == I128 => {
+                let name = match ret_width {
+                    FloatWidth::F32 => &bitcode::NUM_INT_TO_F32[arg_width],
+                    FloatWidth::F64 => &bitcode::NUM_INT_TO_F64[arg_width],
+                };
+                backend
+                    .storage
+                    .load_symbol_zig(&mut backend.code_builder, arg);
+                backend.call_host_fn_after_loading_args(name, 2, true);
+            }
+            Layout::Builtin(Builtin::Int(arg_width)) => {
+                backend
+                    .storage
+                    .load_symbols(&mut backend.code_builder, &[arg]);
+                let code_builder = &mut backend.code_builder;
+                match (ret_width, arg_type, arg_width.is_signed()) {
+                    (FloatWidth::F32, I32, true) => code_builder.f32_convert_s_i32(),
+                    (FloatWidth::F32, I32, false) => code_builder.f32_convert_u_i32(),
+                    (FloatWidth::F32, I64, true) => code_builder.f32_convert_s_i64(),
+                    (FloatWidth::F32, I64, false) => code_builder.f32_convert_u_i64(),
+                    (FloatWidth::F64, I32, true) => code_builder.f64_convert_s_i32(),
+                    (FloatWidth::F64, I32, false) => code_builder.f64_convert_u_i32(),
+                    (FloatWidth::F64, I64, true) => code_builder.f64_convert_s_i64(),
+                    (FloatWidth::F64, I64, false) => code_builder.f64_convert_u_i64(),
+                    _ => internal_error!("Unexpected integer type {:?}", arg_type),
+                }
+            }
+            Layout::Builtin(Builtin::Float(arg_width)) => {
+                backend
+                    .storage
+                    .load_symbols(&mut backend.code_builder, &[arg]);
+                match (ret_width, arg_width) {
+                    (FloatWidth::F32, FloatWidth::F64) => backend.code_builder.f32_demote_f64(),
+                    (FloatWidth::F64, FloatWidth::F32) => backend.code_builder.f64_promote_f32(),
+                    _ => {}
+                }
+            }
+            Layout::Builtin(Builtin::Decimal) => {
+                backend
+                    .storage
+                    .load_symbol_zig(&mut backend.code_builder, arg);
+                backend.call_host_fn_after_loading_args(bitcode::DEC_TO_F64, 2, true);
+                if ret_width == FloatWidth::F32 {
+                    backend.code_builder.f32_demote_f64();
+                }
+            }
+            x => internal_error!("{:?} is not defined for {:?}", self.lowlevel, x),
+        }
+    }
+
+    /// Convert the argument to a Dec, for `Num.toFrac` with a Dec return type
+    fn num_to_dec(&self, backend: &mut WasmBackend<'a, '_>) {
+        let arg = self.arguments[0];
+        let arg_layout = backend.storage.symbol_layouts[&arg];
+
+        match backend.layout_interner.get(arg_layout) {
+            Layout::Builtin(Builtin::Int(width)) => {
+                self.load_args_and_call_zig(backend, &bitcode::DEC_FROM_INT[width])
+            }
+            Layout::Builtin(Builtin::Float(FloatWidth::F64)) => {
+                self.load_args_and_call_zig(backend, bitcode::DEC_FROM_F64)
+            }
+            Layout::Builtin(Builtin::Float(FloatWidth::F32)) => {
+                let (num_wasm_args, has_return_val, _) = self.load_args(backend);
+                backend.code_builder.f64_promote_f32();
+                backend.call_host_fn_after_loading_args(
+                    bitcode::DEC_FROM_F64,
+                    num_wasm_args,
+                    has_return_val,
+                );
+            }
+            Layout::Builtin(Builtin::Decimal) => {
+                let (ret_local, ret_offset) = stack_memory_address(backend, &self.ret_storage);
+                backend.storage.copy_value_to_memory(
+                    &mut backend.code_builder,
+                    ret_local,
+                    ret_offset,
+                    arg,
+                );
+            }
+            x => internal_error!("{:?} is not defined for {:?}", self.lowlevel, x),
+        }
+    }
+
     ///  Main entrypoint from WasmBackend
     pub fn generate(&self, backend: &mut WasmBackend<'a, '_>) {
         use CodeGenNumType::*;
@@ -255,6 +588,47 @@ impl<'a> LowLevelCall<'a> {
 
                 self.load_args_and_call_zig(backend, intrinsic);
             }
+            StrToIntRadix => {
+                let field_layouts = match backend.layout_interner.get(self.ret_layout) {
+                    Layout::Struct { field_layouts, .. } => field_layouts,
+                    _ => internal_error!(
+                        "Unexpected mono layout {:?} for StrToIntRadix",
+                        self.ret_layout
+                    ),
+                };
+                // the fields are sorted by alignment, so the number comes either before or after the index
+                let number_layout = if field_layouts[0] == Layout::usize(TARGET_INFO) {
+                    field_layouts[1]
+                } else {
+                    field_layouts[0]
+                };
+                let intrinsic = match backend.layout_interner.get(number_layout) {
+                    Layout::Builtin(Builtin::Int(int_width)) => {
+                        &bitcode::STR_TO_INT_RADIX[int_width]
+                    }
+                    rest => internal_error!("Unexpected layout {:?} for StrToIntRadix", rest),
+                };
+
+                self.load_args_and_call_zig(backend, intrinsic);
+            }
+            StrToFloatChecked => {
+                let field_layouts = match backend.layout_interner.get(self.ret_layout) {
+                    Layout::Struct { field_layouts, .. } => field_layouts,
+                    _ => internal_error!(
+                        "Unexpected mono layout {:?} for StrToFloatChecked",
+                        self.ret_layout
+                    ),
+                };
+                let float_width = field_layouts
+                    .iter()
+                    .find_map(|field| match backend.layout_interner.get(*field) {
+                        Layout::Builtin(Builtin::Float(float_width)) => Some(float_width),
+                        _ => None,
+                    })
+                    .unwrap();
+
+                self.load_args_and_call_zig(backend, &bitcode::STR_TO_FLOAT_CHECKED[float_width]);
+            }
             StrFromInt => self.num_to_str(backend),
             StrFromFloat => self.num_to_str(backend),
             StrFromUtf8Range => {
@@ -296,6 +670,12 @@ impl<'a> LowLevelCall<'a> {
             }
             StrWithCapacity => self.load_args_and_call_zig(backend, bitcode::STR_WITH_CAPACITY),
             StrGraphemes => self.load_args_and_call_zig(backend, bitcode::STR_GRAPHEMES),
+            StrToUppercase => self.load_args_and_call_zig(backend, bitcode::STR_TO_UPPERCASE),
+            StrToLowercase => self.load_args_and_call_zig(backend, bitcode::STR_TO_LOWERCASE),
+            StrCaseFold => self.load_args_and_call_zig(backend, bitcode::STR_CASE_FOLD),
+            StrToNfc => self.load_args_and_call_zig(backend, bitcode::STR_TO_NFC),
+            StrToNfd => self.load_args_and_call_zig(backend, bitcode::STR_TO_NFD),
+            StrFind => self.load_args_and_call_zig(backend, bitcode::STR_FIND),
 
             // List
             ListLen => match backend.storage.get(&self.arguments[0]) {
@@ -818,8 +1198,7 @@ impl<'a> LowLevelCall<'a> {
             NumAddWrap => match self.ret_layout_raw {
                 Layout::Builtin(Builtin::Int(width)) => match width {
                     IntWidth::I128 | IntWidth::U128 => {
-                        // TODO: don't panic
-                        self.load_args_and_call_zig(backend, &bitcode::NUM_ADD_OR_PANIC_INT[width])
+                        self.load_args_and_call_zig(backend, &bitcode::NUM_ADD_WRAPPED_INT[width])
                     }
                     IntWidth::I64 | IntWidth::U64 => {
                         self.load_args(backend);
@@ -853,6 +1232,27 @@ impl<'a> LowLevelCall<'a> {
             },
 
             NumToStr => self.num_to_str(backend),
+            NumToStrFixed | NumToStrScientific => {
+                let arg_layout = backend.storage.symbol_layouts[&self.arguments[0]];
+                let name = match (backend.layout_interner.get(arg_layout), self.lowlevel) {
+                    (Layout::Builtin(Builtin::Float(width)), NumToStrFixed) => {
+                        &bitcode::NUM_TO_STR_FIXED[width]
+                    }
+                    (Layout::Builtin(Builtin::Float(width)), _) => {
+                        &bitcode::NUM_TO_STR_SCIENTIFIC[width]
+                    }
+                    (Layout::Builtin(Builtin::Decimal), NumToStrFixed) => bitcode::DEC_TO_STR_FIXED,
+                    (Layout::Builtin(Builtin::Decimal), _) => bitcode::DEC_TO_STR_SCIENTIFIC,
+                    (x, _) => internal_error!("{:?} is not defined for {:?}", self.lowlevel, x),
+                };
+                self.load_args_and_call_zig(backend, name)
+            }
+            NumToStrRadix => {
+                let width = symbol_int_width(backend, self.arguments[0]);
+                self.load_args_and_call_zig(backend, &bitcode::NUM_TO_STR_RADIX[width])
+            }
+            NumPadZeros => self.load_args_and_call_zig(backend, bitcode::NUM_PAD_ZEROS),
+            NumGroupDigits => self.load_args_and_call_zig(backend, bitcode::NUM_GROUP_DIGITS),
             NumAddChecked => {
                 let arg_layout = backend.storage.symbol_layouts[&self.arguments[0]];
                 match backend.layout_interner.get(arg_layout) {
@@ -909,8 +1309,7 @@ impl<'a> LowLevelCall<'a> {
             NumSubWrap => match self.ret_layout_raw {
                 Layout::Builtin(Builtin::Int(width)) => match width {
                     IntWidth::I128 | IntWidth::U128 => {
-                        // TODO: don't panic
-                        self.load_args_and_call_zig(backend, &bitcode::NUM_SUB_OR_PANIC_INT[width])
+                        self.load_args_and_call_zig(backend, &bitcode::NUM_SUB_WRAPPED_INT[width])
                     }
                     IntWidth::I64 | IntWidth::U64 => {
                         self.load_args(backend);
@@ -997,8 +1396,7 @@ impl<'a> LowLevelCall<'a> {
             NumMulWrap => match self.ret_layout_raw {
                 Layout::Builtin(Builtin::Int(width)) => match width {
                     IntWidth::I128 | IntWidth::U128 => {
-                        // TODO: don't panic
-                        self.load_args_and_call_zig(backend, &bitcode::NUM_MUL_OR_PANIC_INT[width])
+                        self.load_args_and_call_zig(backend, &bitcode::NUM_MUL_WRAPPED_INT[width])
                     }
                     IntWidth::I64 | IntWidth::U64 => {
                         self.load_args(backend);
@@ -1064,8 +1462,17 @@ impl<'a> LowLevelCall<'a> {
                 }
             }
             NumGt => {
+                let num_type = CodeGenNumType::for_symbol(backend, self.arguments[0]);
+                if let I128 | Decimal = num_type {
+                    // x > y when the order is GT
+                    self.num128_compare(backend);
+                    backend.code_builder.i32_const(ORDER_GT);
+                    backend.code_builder.i32_eq();
+                    return;
+                }
+
                 self.load_args(backend);
-                match CodeGenNumType::for_symbol(backend, self.arguments[0]) {
+                match num_type {
                     I32 => {
                         if symbol_is_signed_int(backend, self.arguments[0]) {
                             backend.code_builder.i32_gt_s()
@@ -1082,12 +1489,21 @@ impl<'a> LowLevelCall<'a> {
                     }
                     F32 => backend.code_builder.f32_gt(),
                     F64 => backend.code_builder.f64_gt(),
-                    x => todo!("{:?} for {:?}", self.lowlevel, x),
+                    I128 | Decimal => unreachable!("handled above"),
                 }
             }
             NumGte => {
+                let num_type = CodeGenNumType::for_symbol(backend, self.arguments[0]);
+                if let I128 | Decimal = num_type {
+                    // x >= y when the order is anything but LT
+                    self.num128_compare(backend);
+                    backend.code_builder.i32_const(ORDER_LT);
+                    backend.code_builder.i32_ne();
+                    return;
+                }
+
                 self.load_args(backend);
-                match CodeGenNumType::for_symbol(backend, self.arguments[0]) {
+                match num_type {
                     I32 => {
                         if symbol_is_signed_int(backend, self.arguments[0]) {
                             backend.code_builder.i32_ge_s()
@@ -1104,12 +1520,21 @@ impl<'a> LowLevelCall<'a> {
                     }
                     F32 => backend.code_builder.f32_ge(),
                     F64 => backend.code_builder.f64_ge(),
-                    x => todo!("{:?} for {:?}", self.lowlevel, x),
+                    I128 | Decimal => unreachable!("handled above"),
                 }
             }
             NumLt => {
+                let num_type = CodeGenNumType::for_symbol(backend, self.arguments[0]);
+                if let I128 | Decimal = num_type {
+                    // x < y when the order is LT
+                    self.num128_compare(backend);
+                    backend.code_builder.i32_const(ORDER_LT);
+                    backend.code_builder.i32_eq();
+                    return;
+                }
+
                 self.load_args(backend);
-                match CodeGenNumType::for_symbol(backend, self.arguments[0]) {
+                match num_type {
                     I32 => {
                         if symbol_is_signed_int(backend, self.arguments[0]) {
                             backend.code_builder.i32_lt_s()
@@ -1126,13 +1551,22 @@ impl<'a> LowLevelCall<'a> {
                     }
                     F32 => backend.code_builder.f32_lt(),
                     F64 => backend.code_builder.f64_lt(),
-                    x => todo!("{:?} for {:?}", self.lowlevel, x),
+                    I128 | Decimal => unreachable!("handled above"),
                 }
             }
             NumLte => {
-                self.load_args(backend);
                 let layout = backend.storage.symbol_layouts[&self.arguments[0]];
-                match CodeGenNumType::from(layout) {
+                let num_type = CodeGenNumType::from(layout);
+                if let I128 | Decimal = num_type {
+                    // x <= y when the order is anything but GT
+                    self.num128_compare(backend);
+                    backend.code_builder.i32_const(ORDER_GT);
+                    backend.code_builder.i32_ne();
+                    return;
+                }
+
+                self.load_args(backend);
+                match num_type {
                     I32 => {
                         if layout_is_signed_int(layout) {
                             backend.code_builder.i32_le_s()
@@ -1149,7 +1583,7 @@ impl<'a> LowLevelCall<'a> {
                     }
                     F32 => backend.code_builder.f32_le(),
                     F64 => backend.code_builder.f64_le(),
-                    x => todo!("{:?} for {:?}", self.lowlevel, x),
+                    I128 | Decimal => unreachable!("handled above"),
                 }
             }
             NumCompare => {
@@ -1199,23 +1633,26 @@ impl<'a> LowLevelCall<'a> {
                         backend.code_builder.f64_lt();
                         backend.code_builder.i32_add();
                     }
-                    x => todo!("{:?} for {:?}", self.lowlevel, x),
+                    I128 | Decimal => self.num128_compare(backend),
                 }
             }
-            NumDivFrac => {
-                self.load_args(backend);
-                match CodeGenNumType::for_symbol(backend, self.arguments[0]) {
-                    F32 => backend.code_builder.f32_div(),
-                    F64 => backend.code_builder.f64_div(),
-                    Decimal => self.load_args_and_call_zig(backend, bitcode::DEC_DIV),
-                    x => todo!("{:?} for {:?}", self.lowlevel, x),
+            NumDivFrac => match CodeGenNumType::for_symbol(backend, self.arguments[0]) {
+                F32 => {
+                    self.load_args(backend);
+                    backend.code_builder.f32_div();
                 }
-            }
+                F64 => {
+                    self.load_args(backend);
+                    backend.code_builder.f64_div();
+                }
+                Decimal => self.load_args_and_call_zig(backend, bitcode::DEC_DIV),
+                x => internal_error!("{:?} is not defined for {:?}", self.lowlevel, x),
+            },
             NumDivTruncUnchecked => {
-                self.load_args(backend);
                 let is_signed = symbol_is_signed_int(backend, self.arguments[0]);
                 match CodeGenNumType::for_symbol(backend, self.arguments[0]) {
                     I32 => {
+                        self.load_args(backend);
                         if is_signed {
                             backend.code_builder.i32_div_s()
                         } else {
@@ -1223,13 +1660,18 @@ impl<'a> LowLevelCall<'a> {
                         }
                     }
                     I64 => {
+                        self.load_args(backend);
                         if is_signed {
                             backend.code_builder.i64_div_s()
                         } else {
                             backend.code_builder.i64_div_u()
                         }
                     }
-                    x => todo!("{:?} for {:?}", self.lowlevel, x),
+                    I128 => {
+                        let width = symbol_int_width(backend, self.arguments[0]);
+                        self.load_args_and_call_zig(backend, &bitcode::NUM_DIV_TRUNC[width])
+                    }
+                    x => internal_error!("{:?} is not defined for {:?}", self.lowlevel, x),
                 }
             }
             NumDivCeilUnchecked => match self.ret_layout_raw {
@@ -1240,11 +1682,29 @@ impl<'a> LowLevelCall<'a> {
             },
 
             NumRemUnchecked => {
-                self.load_args(backend);
+                let is_signed = symbol_is_signed_int(backend, self.arguments[0]);
                 match CodeGenNumType::for_symbol(backend, self.arguments[0]) {
-                    I32 => backend.code_builder.i32_rem_s(),
-                    I64 => backend.code_builder.i64_rem_s(),
-                    _ => todo!("{:?} for {:?}", self.lowlevel, self.ret_layout),
+                    I32 => {
+                        self.load_args(backend);
+                        if is_signed {
+                            backend.code_builder.i32_rem_s()
+                        } else {
+                            backend.code_builder.i32_rem_u()
+                        }
+                    }
+                    I64 => {
+                        self.load_args(backend);
+                        if is_signed {
+                            backend.code_builder.i64_rem_s()
+                        } else {
+                            backend.code_builder.i64_rem_u()
+                        }
+                    }
+                    I128 => {
+                        let width = symbol_int_width(backend, self.arguments[0]);
+                        self.load_args_and_call_zig(backend, &bitcode::NUM_REM[width])
+                    }
+                    x => internal_error!("{:?} is not defined for {:?}", self.lowlevel, x),
                 }
             }
             NumIsMultipleOf => {
@@ -1343,6 +1803,11 @@ impl<'a> LowLevelCall<'a> {
                         code_builder.get_local(tmp);
                     }
 
+                    I128 => {
+                        let width = symbol_int_width(backend, lhs);
+                        self.load_args_and_call_zig(backend, &bitcode::NUM_IS_MULTIPLE_OF[width])
+                    }
+
                     _ => panic_ret_type(),
                 }
             }
@@ -1350,6 +1815,16 @@ impl<'a> LowLevelCall<'a> {
                 const PANIC_MSG: &str =
                     "integer absolute overflowed because its argument is the minimum value";
 
+                match CodeGenNumType::from(self.ret_layout) {
+                    I128 => {
+                        let width = symbol_int_width(backend, self.arguments[0]);
+                        let name = &bitcode::NUM_ABS_OR_PANIC[width];
+                        return self.load_args_and_call_zig(backend, name);
+                    }
+                    Decimal => return self.load_args_and_call_zig(backend, bitcode::DEC_ABS),
+                    _ => {}
+                }
+
                 self.load_args(backend);
 
                 match CodeGenNumType::from(self.ret_layout) {
@@ -1407,13 +1882,23 @@ impl<'a> LowLevelCall<'a> {
                     }
                     F32 => backend.code_builder.f32_abs(),
                     F64 => backend.code_builder.f64_abs(),
-                    _ => todo!("{:?} for {:?}", self.lowlevel, self.ret_layout),
+                    I128 | Decimal => unreachable!("handled above"),
                 }
             }
             NumNeg => {
                 const PANIC_MSG: &str =
                     "integer negation overflowed because its argument is the minimum value";
 
+                match CodeGenNumType::from(self.ret_layout) {
+                    I128 => {
+                        let width = symbol_int_width(backend, self.arguments[0]);
+                        let name = &bitcode::NUM_NEG_OR_PANIC[width];
+                        return self.load_args_and_call_zig(backend, name);
+                    }
+                    Decimal => return self.load_args_and_call_zig(backend, bitcode::DEC_NEGATE),
+                    _ => {}
+                }
+
                 self.load_args(backend);
                 match CodeGenNumType::from(self.ret_layout) {
                     I32 => {
@@ -1440,7 +1925,7 @@ impl<'a> LowLevelCall<'a> {
                     }
                     F32 => backend.code_builder.f32_neg(),
                     F64 => backend.code_builder.f64_neg(),
-                    _ => todo!("{:?} for {:?}", self.lowlevel, self.ret_layout),
+                    I128 | Decimal => unreachable!("handled above"),
                 }
             }
             NumSin => match self.ret_layout_raw {
@@ -1473,24 +1958,11 @@ impl<'a> LowLevelCall<'a> {
                 }
                 _ => panic_ret_type(),
             },
-            NumToFrac => {
-                self.load_args(backend);
-                let ret_type = CodeGenNumType::from(self.ret_layout);
-                let arg_type = CodeGenNumType::for_symbol(backend, self.arguments[0]);
-                match (ret_type, arg_type) {
-                    (F32, I32) => backend.code_builder.f32_convert_s_i32(),
-                    (F32, I64) => backend.code_builder.f32_convert_s_i64(),
-                    (F32, F32) => {}
-                    (F32, F64) => backend.code_builder.f32_demote_f64(),
-
-                    (F64, I32) => backend.code_builder.f64_convert_s_i32(),
-                    (F64, I64) => backend.code_builder.f64_convert_s_i64(),
-                    (F64, F32) => backend.code_builder.f64_promote_f32(),
-                    (F64, F64) => {}
-
-                    _ => todo!("{:?}: {:?} -> {:?}", self.lowlevel, arg_type, ret_type),
-                }
-            }
+            NumToFrac => match self.ret_layout_raw {
+                Layout::Builtin(Builtin::Float(width)) => self.load_arg_as_float(backend, width),
+                Layout::Builtin(Builtin::Decimal) => self.num_to_dec(backend),
+                _ => panic_ret_type(),
+            },
             NumPow => match self.ret_layout_raw {
                 Layout::Builtin(Builtin::Float(width)) => {
                     self.load_args_and_call_zig(backend, &bitcode::NUM_POW[width]);
@@ -1498,27 +1970,48 @@ impl<'a> LowLevelCall<'a> {
                 _ => panic_ret_type(),
             },
             NumRound => {
-                self.load_args(backend);
                 let arg_type = CodeGenNumType::for_symbol(backend, self.arguments[0]);
                 let ret_type = CodeGenNumType::from(self.ret_layout);
 
-                let width = match ret_type {
-                    CodeGenNumType::I32 => IntWidth::I32,
-                    CodeGenNumType::I64 => IntWidth::I64,
-                    CodeGenNumType::I128 => todo!("{:?} for I128", self.lowlevel),
+                let width = match (ret_type, self.ret_layout_raw) {
+                    (CodeGenNumType::I32, _) => IntWidth::I32,
+                    (CodeGenNumType::I64, _) => IntWidth::I64,
+                    (CodeGenNumType::I128, Layout::Builtin(Builtin::Int(width))) => width,
                     _ => internal_error!("Invalid return type for round: {:?}", ret_type),
                 };
 
                 match arg_type {
                     F32 => self.load_args_and_call_zig(backend, &bitcode::NUM_ROUND_F32[width]),
                     F64 => self.load_args_and_call_zig(backend, &bitcode::NUM_ROUND_F64[width]),
+                    Decimal => self.load_args_and_call_zig(backend, &bitcode::DEC_ROUND[width]),
                     _ => internal_error!("Invalid argument type for round: {:?}", arg_type),
                 }
             }
             NumCeiling | NumFloor => {
-                self.load_args(backend);
                 let arg_type = CodeGenNumType::for_symbol(backend, self.arguments[0]);
                 let ret_type = CodeGenNumType::from(self.ret_layout);
+
+                // Wasm can only truncate floats to 32 and 64-bit integers
+                if ret_type == I128 || arg_type == Decimal {
+                    let width = match self.ret_layout_raw {
+                        Layout::Builtin(Builtin::Int(width)) => width,
+                        x => {
+                            internal_error!("Invalid return type for {:?}: {:?}", self.lowlevel, x)
+                        }
+                    };
+                    let name = match (arg_type, self.lowlevel) {
+                        (F32, NumCeiling) => &bitcode::NUM_CEILING_F32[width],
+                        (F64, NumCeiling) => &bitcode::NUM_CEILING_F64[width],
+                        (Decimal, NumCeiling) => &bitcode::DEC_CEILING[width],
+                        (F32, NumFloor) => &bitcode::NUM_FLOOR_F32[width],
+                        (F64, NumFloor) => &bitcode::NUM_FLOOR_F64[width],
+                        (Decimal, NumFloor) => &bitcode::DEC_FLOOR[width],
+                        _ => internal_error!("Invalid argument type for ceiling: {:?}", arg_type),
+                    };
+                    return self.load_args_and_call_zig(backend, name);
+                }
+
+                self.load_args(backend);
                 match (arg_type, self.lowlevel) {
                     (F32, NumCeiling) => {
                         backend.code_builder.f32_ceil();
@@ -1540,12 +2033,10 @@ impl<'a> LowLevelCall<'a> {
                     (I32, F64) => backend.code_builder.i32_trunc_s_f64(),
                     (I64, F32) => backend.code_builder.i64_trunc_s_f32(),
                     (I64, F64) => backend.code_builder.i64_trunc_s_f64(),
-                    (I128, _) => todo!("{:?} for I128", self.lowlevel),
                     _ => panic_ret_type(),
                 }
             }
             NumPowInt => {
-                self.load_args(backend);
                 let base_type = CodeGenNumType::for_symbol(backend, self.arguments[0]);
                 let exponent_type = CodeGenNumType::for_symbol(backend, self.arguments[1]);
                 let ret_type = CodeGenNumType::from(self.ret_layout);
@@ -1553,10 +2044,10 @@ impl<'a> LowLevelCall<'a> {
                 debug_assert!(base_type == exponent_type);
                 debug_assert!(exponent_type == ret_type);
 
-                let width = match ret_type {
-                    CodeGenNumType::I32 => IntWidth::I32,
-                    CodeGenNumType::I64 => IntWidth::I64,
-                    CodeGenNumType::I128 => todo!("{:?} for I128", self.lowlevel),
+                let width = match (ret_type, self.ret_layout_raw) {
+                    (CodeGenNumType::I32, _) => IntWidth::I32,
+                    (CodeGenNumType::I64, _) => IntWidth::I64,
+                    (CodeGenNumType::I128, Layout::Builtin(Builtin::Int(width))) => width,
                     _ => internal_error!("Invalid return type for pow: {:?}", ret_type),
                 };
 
@@ -1585,46 +2076,168 @@ impl<'a> LowLevelCall<'a> {
             },
             NumBytesToU16 => self.load_args_and_call_zig(backend, bitcode::NUM_BYTES_TO_U16),
             NumBytesToU32 => self.load_args_and_call_zig(backend, bitcode::NUM_BYTES_TO_U32),
-            NumBitwiseAnd => {
-                self.load_args(backend);
-                match CodeGenNumType::from(self.ret_layout) {
-                    I32 => backend.code_builder.i32_and(),
-                    I64 => backend.code_builder.i64_and(),
-                    I128 => todo!("{:?} for I128", self.lowlevel),
-                    _ => panic_ret_type(),
+            NumReadBytesLe | NumReadBytesBe => {
+                self.num_read_bytes(backend, matches!(self.lowlevel, NumReadBytesBe))
+            }
+            NumWriteBytesLe | NumWriteBytesBe => {
+                self.num_write_bytes(backend, matches!(self.lowlevel, NumWriteBytesBe))
+            }
+            NumBitwiseAnd => match CodeGenNumType::from(self.ret_layout) {
+                I32 => {
+                    self.load_args(backend);
+                    backend.code_builder.i32_and();
+                }
+                I64 => {
+                    self.load_args(backend);
+                    backend.code_builder.i64_and();
+                }
+                I128 => self.num128_bitwise(backend),
+                _ => panic_ret_type(),
+            },
+            NumBitwiseXor => match CodeGenNumType::from(self.ret_layout) {
+                I32 => {
+                    self.load_args(backend);
+                    backend.code_builder.i32_xor();
+                }
+                I64 => {
+                    self.load_args(backend);
+                    backend.code_builder.i64_xor();
+                }
+                I128 => self.num128_bitwise(backend),
+                _ => panic_ret_type(),
+            },
+            NumBitwiseOr => match CodeGenNumType::from(self.ret_layout) {
+                I32 => {
+                    self.load_args(backend);
+                    backend.code_builder.i32_or();
+                }
+                I64 => {
+                    self.load_args(backend);
+                    backend.code_builder.i64_or();
+                }
+                I128 => self.num128_bitwise(backend),
+                _ => panic_ret_type(),
+            },
+            // Narrower integers are stored in Wasm's i32 type, so only the exact widths map onto
+            // Wasm instructions. Zig handles the rest.
+            NumCountLeadingZeroBits => {
+                let width = symbol_int_width(backend, self.arguments[0]);
+                match width {
+                    IntWidth::I32 | IntWidth::U32 => {
+                        self.load_args(backend);
+                        backend.code_builder.i32_clz();
+                    }
+                    IntWidth::I64 | IntWidth::U64 => {
+                        self.load_args(backend);
+                        backend.code_builder.i64_clz();
+                        backend.code_builder.i32_wrap_i64();
+                    }
+                    _ => self.load_args_and_call_zig(
+                        backend,
+                        &bitcode::NUM_COUNT_LEADING_ZERO_BITS[width],
+                    ),
                 }
             }
-            NumBitwiseXor => {
-                self.load_args(backend);
-                match CodeGenNumType::from(self.ret_layout) {
-                    I32 => backend.code_builder.i32_xor(),
-                    I64 => backend.code_builder.i64_xor(),
-                    I128 => todo!("{:?} for I128", self.lowlevel),
-                    _ => panic_ret_type(),
+            NumCountTrailingZeroBits => {
+                let width = symbol_int_width(backend, self.arguments[0]);
+                match width {
+                    IntWidth::I32 | IntWidth::U32 => {
+                        self.load_args(backend);
+                        backend.code_builder.i32_ctz();
+                    }
+                    IntWidth::I64 | IntWidth::U64 => {
+                        self.load_args(backend);
+                        backend.code_builder.i64_ctz();
+                        backend.code_builder.i32_wrap_i64();
+                    }
+                    _ => self.load_args_and_call_zig(
+                        backend,
+                        &bitcode::NUM_COUNT_TRAILING_ZERO_BITS[width],
+                    ),
                 }
             }
-            NumBitwiseOr => {
-                self.load_args(backend);
-                match CodeGenNumType::from(self.ret_layout) {
-                    I32 => backend.code_builder.i32_or(),
-                    I64 => backend.code_builder.i64_or(),
-                    I128 => todo!("{:?} for I128", self.lowlevel),
-                    _ => panic_ret_type(),
+            NumCountOneBits => {
+                let width = symbol_int_width(backend, self.arguments[0]);
+                match width {
+                    IntWidth::I32 | IntWidth::U32 => {
+                        self.load_args(backend);
+                        backend.code_builder.i32_popcnt();
+                    }
+                    IntWidth::I64 | IntWidth::U64 => {
+                        self.load_args(backend);
+                        backend.code_builder.i64_popcnt();
+                        backend.code_builder.i32_wrap_i64();
+                    }
+                    _ => self.load_args_and_call_zig(backend, &bitcode::NUM_COUNT_ONE_BITS[width]),
                 }
             }
+            NumRotateLeftBy => {
+                let num = self.arguments[0];
+                let bits = self.arguments[1];
+                let width = symbol_int_width(backend, num);
+                match width {
+                    IntWidth::I32 | IntWidth::U32 => {
+                        backend
+                            .storage
+                            .load_symbols(&mut backend.code_builder, &[num, bits]);
+                        backend.code_builder.i32_rotl();
+                    }
+                    IntWidth::I64 | IntWidth::U64 => {
+                        backend
+                            .storage
+                            .load_symbols(&mut backend.code_builder, &[num, bits]);
+                        backend.code_builder.i64_extend_u_i32();
+                        backend.code_builder.i64_rotl();
+                    }
+                    _ => self.load_args_and_call_zig(backend, &bitcode::NUM_ROTATE_LEFT_BY[width]),
+                }
+            }
+            NumRotateRightBy => {
+                let num = self.arguments[0];
+                let bits = self.arguments[1];
+                let width = symbol_int_width(backend, num);
+                match width {
+                    IntWidth::I32 | IntWidth::U32 => {
+                        backend
+                            .storage
+                            .load_symbols(&mut backend.code_builder, &[num, bits]);
+                        backend.code_builder.i32_rotr();
+                    }
+                    IntWidth::I64 | IntWidth::U64 => {
+                        backend
+                            .storage
+                            .load_symbols(&mut backend.code_builder, &[num, bits]);
+                        backend.code_builder.i64_extend_u_i32();
+                        backend.code_builder.i64_rotr();
+                    }
+                    _ => self.load_args_and_call_zig(backend, &bitcode::NUM_ROTATE_RIGHT_BY[width]),
+                }
+            }
+            NumSwapBytes => {
+                let width = symbol_int_width(backend, self.arguments[0]);
+                self.load_args_and_call_zig(backend, &bitcode::NUM_SWAP_BYTES[width])
+            }
             NumShiftLeftBy => {
                 let num = self.arguments[0];
                 let bits = self.arguments[1];
-                backend
-                    .storage
-                    .load_symbols(&mut backend.code_builder, &[num, bits]);
                 match CodeGenNumType::from(self.ret_layout) {
-                    I32 => backend.code_builder.i32_shl(),
+                    I32 => {
+                        backend
+                            .storage
+                            .load_symbols(&mut backend.code_builder, &[num, bits]);
+                        backend.code_builder.i32_shl();
+                    }
                     I64 => {
+                        backend
+                            .storage
+                            .load_symbols(&mut backend.code_builder, &[num, bits]);
                         backend.code_builder.i64_extend_u_i32();
                         backend.code_builder.i64_shl();
                     }
-                    I128 => todo!("{:?} for I128", self.lowlevel),
+                    I128 => {
+                        let width = symbol_int_width(backend, num);
+                        self.load_args_and_call_zig(backend, &bitcode::NUM_SHIFT_LEFT_BY[width])
+                    }
                     _ => panic_ret_type(),
                 }
             }
@@ -1673,7 +2286,10 @@ impl<'a> LowLevelCall<'a> {
                         backend.code_builder.i64_extend_u_i32();
                         backend.code_builder.i64_shr_s();
                     }
-                    I128 => todo!("{:?} for I128", self.lowlevel),
+                    I128 => {
+                        let width = symbol_int_width(backend, num);
+                        self.load_args_and_call_zig(backend, &bitcode::NUM_SHIFT_RIGHT_BY[width])
+                    }
                     _ => panic_ret_type(),
                 }
             }
@@ -1716,13 +2332,17 @@ impl<'a> LowLevelCall<'a> {
                         backend.code_builder.i64_extend_u_i32();
                         backend.code_builder.i64_shr_u();
                     }
-                    I128 => todo!("{:?} for I128", self.lowlevel),
+                    I128 => {
+                        let width = symbol_int_width(backend, num);
+                        let name = &bitcode::NUM_SHIFT_RIGHT_ZF_BY[width];
+                        self.load_args_and_call_zig(backend, name)
+                    }
                     _ => panic_ret_type(),
                 }
             }
             NumIntCast => {
-                self.load_args(backend);
-                let arg_layout = backend.storage.symbol_layouts[&self.arguments[0]];
+                let arg = self.arguments[0];
+                let arg_layout = backend.storage.symbol_layouts[&arg];
                 let arg_type = CodeGenNumType::from(arg_layout);
                 let arg_width = match backend.layout_interner.get(arg_layout) {
                     Layout::Builtin(Builtin::Int(w)) => w,
@@ -1737,70 +2357,98 @@ impl<'a> LowLevelCall<'a> {
                 };
 
                 match (ret_type, arg_type) {
-                    (I32, I32) => self.wrap_small_int(backend, ret_width),
+                    (I32, I32) => {
+                        self.load_args(backend);
+                        self.wrap_small_int(backend, ret_width);
+                    }
                     (I32, I64) => {
+                        self.load_args(backend);
                         backend.code_builder.i32_wrap_i64();
                         self.wrap_small_int(backend, ret_width);
                     }
                     (I64, I32) => {
+                        self.load_args(backend);
                         if arg_width.is_signed() {
                             backend.code_builder.i64_extend_s_i32()
                         } else {
                             backend.code_builder.i64_extend_u_i32()
                         }
                     }
-                    (I64, I64) => {}
-
-                    _ => todo!("{:?}: {:?} -> {:?}", self.lowlevel, arg_type, ret_type),
-                }
-            }
-            NumToFloatCast => {
-                self.load_args(backend);
-                let arg_layout = backend.storage.symbol_layouts[&self.arguments[0]];
-                let arg_signed = match backend.layout_interner.get(arg_layout) {
-                    Layout::Builtin(Builtin::Int(w)) => w.is_signed(),
-                    Layout::Builtin(Builtin::Float(_)) => true, // unused
-                    Layout::Builtin(Builtin::Decimal) => true,
-                    x => internal_error!("Num.intCast is not defined for {:?}", x),
-                };
-                let ret_type = CodeGenNumType::from(self.ret_layout);
-                let arg_type = CodeGenNumType::from(arg_layout);
-
-                match (ret_type, arg_type) {
-                    (F32, F32) => {}
-                    (F32, F64) => backend.code_builder.f32_demote_f64(),
-                    (F32, I32) => {
-                        if arg_signed {
-                            backend.code_builder.f32_convert_s_i32()
-                        } else {
-                            backend.code_builder.f32_convert_u_i32()
-                        }
+                    (I64, I64) => {
+                        self.load_args(backend);
                     }
-                    (F32, I64) => {
-                        if arg_signed {
-                            backend.code_builder.f32_convert_s_i64()
-                        } else {
-                            backend.code_builder.f32_convert_u_i64()
+
+                    (I32 | I64, I128) => {
+                        // Truncating only needs the low half, which comes first in memory
+                        let (arg_local, arg_offset) =
+                            stack_memory_address(backend, backend.storage.get(&arg));
+                        backend.code_builder.get_local(arg_local);
+                        backend.code_builder.i64_load(Align::Bytes8, arg_offset);
+                        if ret_type == I32 {
+                            backend.code_builder.i32_wrap_i64();
+                            self.wrap_small_int(backend, ret_width);
                         }
                     }
-                    (F64, F64) => {}
-                    (F64, I32) => {
-                        if arg_signed {
-                            backend.code_builder.f64_convert_s_i32()
-                        } else {
-                            backend.code_builder.f64_convert_u_i32()
+                    (I128, I32 | I64) => {
+                        let (ret_local, ret_offset) =
+                            stack_memory_address(backend, &self.ret_storage);
+
+                        // low half
+                        backend.code_builder.get_local(ret_local);
+                        backend
+                            .storage
+                            .load_symbols(&mut backend.code_builder, &[arg]);
+                        if arg_type == I32 {
+                            if arg_width.is_signed() {
+                                backend.code_builder.i64_extend_s_i32()
+                            } else {
+                                backend.code_builder.i64_extend_u_i32()
+                            }
                         }
-                    }
-                    (F64, I64) => {
-                        if arg_signed {
-                            backend.code_builder.f64_convert_s_i64()
+                        backend.code_builder.i64_store(Align::Bytes8, ret_offset);
+
+                        // high half: copies of the sign bit, or zeroes
+                        backend.code_builder.get_local(ret_local);
+                        if arg_width.is_signed() {
+                            backend
+                                .storage
+                                .load_symbols(&mut backend.code_builder, &[arg]);
+                            if arg_type == I32 {
+                                backend.code_builder.i64_extend_s_i32();
+                            }
+                            backend.code_builder.i64_const(63);
+                            backend.code_builder.i64_shr_s();
                         } else {
-                            backend.code_builder.f64_convert_u_i64()
+                            backend.code_builder.i64_const(0);
                         }
-                    }
-                    _ => todo!("{:?}: {:?} -> {:?}", self.lowlevel, arg_type, ret_type),
-                }
-            }
+                        backend
+                            .code_builder
+                            .i64_store(Align::Bytes8, ret_offset + 8);
+                    }
+                    (I128, I128) => {
+                        // I128 and U128 have the same representation
+                        let (ret_local, ret_offset) =
+                            stack_memory_address(backend, &self.ret_storage);
+                        backend.storage.copy_value_to_memory(
+                            &mut backend.code_builder,
+                            ret_local,
+                            ret_offset,
+                            arg,
+                        );
+                    }
+
+                    _ => internal_error!(
+                        "{:?} is not defined for {:?} -> {:?}",
+                        self.lowlevel,
+                        arg_type,
+                        ret_type
+                    ),
+                }
+            }
+            NumToFloatCast => match self.ret_layout_raw {
+                Layout::Builtin(Builtin::Float(width)) => self.load_arg_as_float(backend, width),
+                _ => panic_ret_type(),
+            },
             NumToIntChecked => {
                 let arg_layout = backend.storage.symbol_layouts[&self.arguments[0]];
 
@@ -1844,7 +2492,42 @@ impl<'a> LowLevelCall<'a> {
                 }
             }
             NumToFloatChecked => {
-                todo!("implement toF32Checked and toF64Checked");
+                // The return layout is { value: F32 or F64, out_of_bounds: Bool }
+                let ret_width = match self.ret_layout_raw {
+                    Layout::Struct {
+                        field_layouts: &[value, ..],
+                        ..
+                    } => match backend.layout_interner.get(value) {
+                        Layout::Builtin(Builtin::Float(width)) => width,
+                        x => internal_error!("NumToFloatChecked cannot return {:?}", x),
+                    },
+                    x => internal_error!("NumToFloatChecked cannot return {:?}", x),
+                };
+
+                let arg_layout = backend.storage.symbol_layouts[&self.arguments[0]];
+                let name = match (backend.layout_interner.get(arg_layout), ret_width) {
+                    (Layout::Builtin(Builtin::Int(width)), FloatWidth::F32) => {
+                        &bitcode::NUM_TO_F32_CHECKED[width]
+                    }
+                    (Layout::Builtin(Builtin::Int(width)), FloatWidth::F64) => {
+                        &bitcode::NUM_TO_F64_CHECKED[width]
+                    }
+                    (Layout::Builtin(Builtin::Float(width)), FloatWidth::F32) => {
+                        &bitcode::NUM_TO_F32_CHECKED[width]
+                    }
+                    (Layout::Builtin(Builtin::Float(width)), FloatWidth::F64) => {
+                        &bitcode::NUM_TO_F64_CHECKED[width]
+                    }
+                    (Layout::Builtin(Builtin::Decimal), FloatWidth::F32) => {
+                        bitcode::DEC_TO_F32_CHECKED
+                    }
+                    (Layout::Builtin(Builtin::Decimal), FloatWidth::F64) => {
+                        bitcode::DEC_TO_F64_CHECKED
+                    }
+                    (x, _) => internal_error!("NumToFloatChecked is not defined for {:?}", x),
+                };
+
+                self.load_args_and_call_zig(backend, name);
             }
             And => {
                 self.load_args(backend);
@@ -1860,13 +2543,91 @@ impl<'a> LowLevelCall<'a> {
             }
             RefCountInc => self.load_args_and_call_zig(backend, bitcode::UTILS_INCREF),
             RefCountDec => self.load_args_and_call_zig(backend, bitcode::UTILS_DECREF),
+            RefCountTraceLocation => {
+                self.load_args_and_call_zig(backend, bitcode::UTILS_REFCOUNT_TRACE_LOCATION)
+            }
+            RefCountTraceReset => {
+                self.load_args_and_call_zig(backend, bitcode::UTILS_REFCOUNT_TRACE_RESET)
+            }
+            RefCountTraceInc => {
+                self.load_args_and_call_zig(backend, bitcode::UTILS_REFCOUNT_TRACE_INCREF)
+            }
 
             PtrCast => {
                 let code_builder = &mut backend.code_builder;
                 backend.storage.load_symbols(code_builder, self.arguments);
             }
 
-            Hash => todo!("{:?}", self.lowlevel),
+            PtrStore => {
+                let ptr = self.arguments[0];
+                let ptr_storage = backend.storage.get(&ptr).to_owned();
+                let ptr_local_id = match backend.storage.ensure_value_has_local(
+                    &mut backend.code_builder,
+                    ptr,
+                    ptr_storage,
+                ) {
+                    StoredValue::Local { local_id, .. } => local_id,
+                    _ => internal_error!("A pointer will always be an i32"),
+                };
+
+                backend.storage.copy_value_to_memory(
+                    &mut backend.code_builder,
+                    ptr_local_id,
+                    0,
+                    self.arguments[1],
+                );
+            }
+
+            PtrLoad => {
+                let ptr = self.arguments[0];
+                let from_addr_val = match backend.storage.get(&ptr) {
+                    StoredValue::VirtualMachineStack { .. } => {
+                        backend
+                            .storage
+                            .load_symbols(&mut backend.code_builder, &[ptr]);
+                        AddressValue::Loaded
+                    }
+                    StoredValue::Local { local_id, .. } => AddressValue::NotLoaded(*local_id),
+                    StoredValue::StackMemory { .. } => {
+                        internal_error!("A pointer will always be an i32")
+                    }
+                };
+
+                backend.storage.copy_value_from_memory(
+                    &mut backend.code_builder,
+                    self.ret_symbol,
+                    from_addr_val,
+                    0,
+                );
+            }
+
+            Alloca => {
+                let value = self.arguments[0];
+                let layout = backend.storage.symbol_layouts[&value];
+                let (size, alignment) = backend.layout_interner.stack_size_and_alignment(layout);
+                let (frame_ptr, offset) = backend
+                    .storage
+                    .allocate_anonymous_stack_memory(size, alignment);
+
+                backend.storage.copy_value_to_memory(
+                    &mut backend.code_builder,
+                    frame_ptr,
+                    offset,
+                    value,
+                );
+
+                backend.code_builder.get_local(frame_ptr);
+                if offset != 0 {
+                    backend.code_builder.i32_const(offset as i32);
+                    backend.code_builder.i32_add();
+                }
+            }
+
+            Hash => internal_error!(
+                "{:?} is never generated. Hashing is implemented in Roc, using the Hash ability",
+                self.lowlevel
+            ),
+            HashRandomSeed => self.load_args_and_call_zig(backend, bitcode::UTILS_HASH_RANDOM_SEED),
 
             Eq | NotEq => self.eq_or_neq(backend),
 
@@ -2071,6 +2832,70 @@ impl<'a> LowLevelCall<'a> {
 }
 
 /// Helper for NumIsFinite op, and also part of Eq/NotEq
+/// Load `size` big-endian bytes from `address + offset`, leaving an integer of type
+/// `value_type` on the value stack. Wasm has no byte swap instruction, so we put the
+/// number together one byte at a time, which also means the bytes needn't be aligned.
+fn load_bytes_be(
+    code_builder: &mut CodeBuilder,
+    address: LocalId,
+    offset: u32,
+    size: u32,
+    value_type: ValueType,
+) {
+    for i in 0..size {
+        let shift = 8 * (size - 1 - i);
+        code_builder.get_local(address);
+        if value_type == ValueType::I64 {
+            code_builder.i64_load8_u(Align::Bytes1, offset + i);
+            if shift > 0 {
+                code_builder.i64_const(shift as i64);
+                code_builder.i64_shl();
+            }
+            if i > 0 {
+                code_builder.i64_or();
+            }
+        } else {
+            code_builder.i32_load8_u(Align::Bytes1, offset + i);
+            if shift > 0 {
+                code_builder.i32_const(shift as i32);
+                code_builder.i32_shl();
+            }
+            if i > 0 {
+                code_builder.i32_or();
+            }
+        }
+    }
+}
+
+/// Store the low `size` bytes of the integer in `value` to `address + offset`, big-endian
+fn store_bytes_be(
+    code_builder: &mut CodeBuilder,
+    address: LocalId,
+    offset: u32,
+    size: u32,
+    value: LocalId,
+    value_type: ValueType,
+) {
+    for i in 0..size {
+        let shift = 8 * (size - 1 - i);
+        code_builder.get_local(address);
+        code_builder.get_local(value);
+        if value_type == ValueType::I64 {
+            if shift > 0 {
+                code_builder.i64_const(shift as i64);
+                code_builder.i64_shr_u();
+            }
+            code_builder.i64_store8(Align::Bytes1, offset + i);
+        } else {
+            if shift > 0 {
+                code_builder.i32_const(shift as i32);
+                code_builder.i32_shr_u();
+            }
+            code_builder.i32_store8(Align::Bytes1, offset + i);
+        }
+    }
+}
+
 fn num_is_finite(backend: &mut WasmBackend<'_, '_>, argument: Symbol) {
     use StoredValue::*;
     let stored = backend.storage.get(&argument).to_owned();
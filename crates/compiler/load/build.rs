@@ -26,6 +26,9 @@ const MODULES: &[(ModuleId, &str)] = &[
     (ModuleId::DECODE, "Decode.roc"),
     (ModuleId::HASH, "Hash.roc"),
     (ModuleId::JSON, "Json.roc"),
+    (ModuleId::ORD, "Ord.roc"),
+    (ModuleId::ORD_DICT, "OrdDict.roc"),
+    (ModuleId::ORD_SET, "OrdSet.roc"),
 ];
 
 fn main() {
@@ -70,7 +73,7 @@ fn write_types_for_module_dummy(output_path: &Path) {
 fn write_types_for_module_real(module_id: ModuleId, filename: &str, output_path: &Path) {
     use roc_can::module::TypeState;
     use roc_load_internal::file::{LoadingProblem, Threading};
-    use roc_reporting::cli::report_problems;
+    use roc_reporting::cli::{report_problems, DiagnosticLevels};
 
     let arena = Bump::new();
     let cwd = std::env::current_dir().unwrap();
@@ -106,6 +109,7 @@ fn write_types_for_module_real(module_id: ModuleId, filename: &str, output_path:
         &module.interns,
         &mut module.can_problems,
         &mut module.type_problems,
+        &DiagnosticLevels::default(),
     );
 
     if problems.errors + problems.warnings > 0 {
@@ -18,8 +18,9 @@ const SKIP_SUBS_CACHE: bool = {
 
 pub use roc_load_internal::docs;
 pub use roc_load_internal::file::{
-    EntryPoint, ExecutionMode, ExpectMetadata, Expectations, LoadConfig, LoadResult, LoadStart,
-    LoadedModule, LoadingProblem, MonomorphizedModule, Phase, Threading,
+    EmitIr, EmittedIr, EntryPoint, ExecutionMode, ExpectMetadata, Expectations, LoadConfig,
+    LoadResult, LoadStart, LoadedModule, LoadingProblem, ModuleTiming, MonomorphizedModule, Phase,
+    Threading, TimingSpan,
 };
 
 #[allow(clippy::too_many_arguments)]
@@ -65,6 +66,9 @@ pub fn load_single_threaded<'a>(
         render,
         palette,
         exec_mode,
+        true,
+        EmitIr::default(),
+        false,
         roc_cache_dir,
     )
 }
@@ -204,6 +208,9 @@ const BOX: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/Box.dat")) as &[_];
 const ENCODE: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/Encode.dat")) as &[_];
 const DECODE: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/Decode.dat")) as &[_];
 const HASH: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/Hash.dat")) as &[_];
+const ORD: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/Ord.dat")) as &[_];
+const ORD_DICT: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/OrdDict.dat")) as &[_];
+const ORD_SET: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/OrdSet.dat")) as &[_];
 
 fn deserialize_help(bytes: &[u8]) -> TypeState {
     let (state, _offset) = TypeState::deserialize(bytes);
@@ -234,6 +241,10 @@ fn read_cached_types() -> MutMap<ModuleId, TypeState> {
         output.insert(ModuleId::DECODE, deserialize_help(DECODE));
 
         output.insert(ModuleId::HASH, deserialize_help(HASH));
+
+        output.insert(ModuleId::ORD, deserialize_help(ORD));
+        output.insert(ModuleId::ORD_DICT, deserialize_help(ORD_DICT));
+        output.insert(ModuleId::ORD_SET, deserialize_help(ORD_SET));
     }
 
     output
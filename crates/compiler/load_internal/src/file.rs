@@ -9,6 +9,7 @@ use parking_lot::Mutex;
 use roc_builtins::roc::module_source;
 use roc_can::abilities::{AbilitiesStore, PendingAbilitiesStore, ResolvedImpl};
 use roc_can::constraint::{Constraint as ConstraintSoa, Constraints, TypeOrVar};
+use roc_can::debug::{pretty_print_declarations, PPCtx};
 use roc_can::expr::{DbgLookup, Declarations, ExpectLookup, PendingDerives};
 use roc_can::module::{
     canonicalize_module_defs, ExposedByModule, ExposedForModule, ExposedModuleTypes, Module,
@@ -31,8 +32,8 @@ use roc_module::symbol::{
     PackageQualified, Symbol,
 };
 use roc_mono::ir::{
-    CapturedSymbols, ExternalSpecializations, PartialProc, Proc, ProcLayout, Procs, ProcsBase,
-    UpdateModeIds,
+    CapturedSymbols, ExternalSpecializations, HostExposedLayouts, PartialProc, Proc, ProcLayout,
+    Procs, ProcsBase, RefcountTrace, UpdateModeIds,
 };
 use roc_mono::layout::{
     GlobalLayoutInterner, LambdaName, Layout, LayoutCache, LayoutProblem, Niche, STLayoutInterner,
@@ -49,7 +50,7 @@ use roc_parse::header::{
 use roc_parse::header::{HeaderType, PackageName};
 use roc_parse::module::module_defs;
 use roc_parse::parser::{FileError, Parser, SourceError, SyntaxError};
-use roc_problem::Severity;
+use roc_problem::{Edit, Severity};
 use roc_region::all::{LineInfo, Loc, Region};
 use roc_reporting::report::{Annotation, Palette, RenderTarget};
 use roc_solve::module::{extract_module_owned_implementations, Solved, SolvedModule};
@@ -57,6 +58,7 @@ use roc_solve_problem::TypeError;
 use roc_target::TargetInfo;
 use roc_types::subs::{CopiedImport, ExposedTypesStorageSubs, Subs, VarStore, Variable};
 use roc_types::types::{Alias, Types};
+use std::cell::Cell;
 use std::collections::hash_map::Entry::{Occupied, Vacant};
 use std::collections::HashMap;
 use std::env::current_dir;
@@ -98,6 +100,34 @@ pub struct LoadConfig {
     pub palette: Palette,
     pub threading: Threading,
     pub exec_mode: ExecutionMode,
+    /// Evaluate constant expressions (including top-level values) at compile time.
+    ///
+    /// Tests of the code generators turn this off, so that the operations they test are not
+    /// folded away.
+    pub fold_constants: bool,
+    pub emit_ir: EmitIr,
+    /// Make the `List` and `Str` builtins report the copies they make because a value was
+    /// shared, for `--trace-refcounts`.
+    pub trace_refcounts: bool,
+}
+
+/// Intermediate representations to render as text while loading, for `roc build --emit`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EmitIr {
+    /// The canonical AST of the root module, after type checking
+    pub can: bool,
+    /// The mono IR right after specialization
+    pub mono: bool,
+    /// The mono IR after reset/reuse and refcount operations have been inserted
+    pub mono_refcounted: bool,
+}
+
+/// The intermediate representations requested through [EmitIr], rendered as text.
+#[derive(Debug, Default)]
+pub struct EmittedIr {
+    pub can: Option<String>,
+    pub mono: Option<String>,
+    pub mono_refcounted: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -198,6 +228,9 @@ impl Default for ModuleCache<'_> {
             DECODE,
             HASH,
             JSON,
+            ORD,
+            ORD_DICT,
+            ORD_SET,
         }
 
         Self {
@@ -472,6 +505,7 @@ fn start_phase<'a>(
                     derived_module,
                     expectations,
                     build_expects,
+                    refcount_trace: refcount_trace(state, module_id),
                 }
             }
             Phase::MakeSpecializations => {
@@ -607,6 +641,7 @@ fn start_phase<'a>(
                     exposed_by_module: state.exposed_types.clone(),
                     derived_module,
                     expectations,
+                    refcount_trace: refcount_trace(state, module_id),
                 }
             }
         }
@@ -615,6 +650,22 @@ fn start_phase<'a>(
     vec![task]
 }
 
+/// With `--trace-refcounts`, mono needs the source of a module to describe its call sites.
+/// Builtin modules are never traced.
+fn refcount_trace<'a>(state: &State<'a>, module_id: ModuleId) -> Option<RefcountTrace<'a>> {
+    if !state.trace_refcounts || module_id.is_builtin() {
+        return None;
+    }
+
+    let (path, src) = state.module_cache.sources.get(&module_id)?;
+
+    Some(RefcountTrace {
+        filename: path.display().to_string(),
+        src,
+        line_info: LineInfo::new(src),
+    })
+}
+
 #[derive(Debug)]
 pub struct LoadedModule {
     pub module_id: ModuleId,
@@ -682,6 +733,8 @@ struct ModuleHeader<'a> {
     package_qualified_imported_modules: MutSet<PackageQualified<'a, ModuleId>>,
     exposes: Vec<Symbol>,
     exposed_imports: MutMap<Ident, (Symbol, Region)>,
+    /// How to remove each import (keyed by its region), for when it turns out to be unused
+    import_fixes: MutMap<Region, Edit>,
     parse_state: roc_parse::state::State<'a>,
     header_type: HeaderType<'a>,
     header_comments: &'a [CommentOrNewline<'a>],
@@ -694,6 +747,7 @@ struct ConstrainedModule {
     module: Module,
     declarations: Declarations,
     imported_modules: MutMap<ModuleId, Region>,
+    import_fixes: MutMap<Region, Edit>,
     constraints: Constraints,
     constraint: ConstraintSoa,
     ident_ids: IdentIds,
@@ -762,6 +816,7 @@ pub struct MonomorphizedModule<'a> {
     pub timings: MutMap<ModuleId, ModuleTiming>,
     pub expectations: VecMap<ModuleId, Expectations>,
     pub uses_prebuilt_platform: bool,
+    pub emitted_ir: EmittedIr,
 }
 
 /// Values used to render expect output
@@ -823,6 +878,7 @@ struct ParsedModule<'a> {
     imported_modules: MutMap<ModuleId, Region>,
     exposed_ident_ids: IdentIds,
     exposed_imports: MutMap<Ident, (Symbol, Region)>,
+    import_fixes: MutMap<Region, Edit>,
     parsed_defs: Defs<'a>,
     symbols_from_requires: Vec<(Loc<Symbol>, Loc<TypeAnnotation<'a>>)>,
     header_type: HeaderType<'a>,
@@ -1005,6 +1061,10 @@ struct State<'a> {
     pub render: RenderTarget,
     pub palette: Palette,
     pub exec_mode: ExecutionMode,
+    pub fold_constants: bool,
+    pub emit_ir: EmitIr,
+    pub emitted_ir: EmittedIr,
+    pub trace_refcounts: bool,
 
     /// All abilities across all modules.
     pub world_abilities: WorldAbilities,
@@ -1036,6 +1096,9 @@ impl<'a> State<'a> {
         palette: Palette,
         number_of_workers: usize,
         exec_mode: ExecutionMode,
+        fold_constants: bool,
+        emit_ir: EmitIr,
+        trace_refcounts: bool,
     ) -> Self {
         let arc_shorthands = Arc::new(Mutex::new(MutMap::default()));
         let cache_dir = roc_packaging::cache::roc_cache_dir();
@@ -1070,6 +1133,10 @@ impl<'a> State<'a> {
             render,
             palette,
             exec_mode,
+            fold_constants,
+            emit_ir,
+            emitted_ir: EmittedIr::default(),
+            trace_refcounts,
             make_specializations_pass: MakeSpecializationsPass::Pass(1),
             world_abilities: Default::default(),
             layout_interner: GlobalLayoutInterner::with_capacity(128, target_info),
@@ -1089,12 +1156,28 @@ pub struct ModuleTiming {
     // indexed by make specializations pass
     pub make_specializations: Vec<Duration>,
     // TODO pub monomorphize: Duration,
+    /// When and on which thread each of the phases above ran, in the order they ran
+    pub spans: Vec<TimingSpan>,
     /// Total duration will always be more than the sum of the other fields, due
     /// to things like state lookups in between phases, waiting on other threads, etc.
     start_time: Instant,
     end_time: Instant,
 }
 
+/// One phase of work on a module, as seen by `roc build --time-trace`
+#[derive(Debug, Clone, Copy)]
+pub struct TimingSpan {
+    pub phase: &'static str,
+    /// 0 is the thread driving the load; worker threads are numbered from 1
+    pub thread: usize,
+    pub start: Instant,
+    pub end: Instant,
+}
+
+thread_local! {
+    static TIMING_THREAD: Cell<usize> = Cell::new(0);
+}
+
 impl ModuleTiming {
     pub fn new(start_time: Instant) -> Self {
         ModuleTiming {
@@ -1106,6 +1189,7 @@ impl ModuleTiming {
             solve: Duration::default(),
             find_specializations: Duration::default(),
             make_specializations: Vec::with_capacity(2),
+            spans: Vec::with_capacity(8),
             start_time,
             end_time: start_time, // just for now; we'll overwrite this at the end
         }
@@ -1115,6 +1199,18 @@ impl ModuleTiming {
         self.end_time.duration_since(self.start_time)
     }
 
+    /// Record that `phase` ran from `start` to `end` on the current thread
+    fn record_span(&mut self, phase: &'static str, start: Instant, end: Instant) {
+        let thread = TIMING_THREAD.with(|thread| thread.get());
+
+        self.spans.push(TimingSpan {
+            phase,
+            thread,
+            start,
+            end,
+        });
+    }
+
     /// Subtract all the other fields from total_start_to_finish
     pub fn other(&self) -> Duration {
         let Self {
@@ -1126,6 +1222,7 @@ impl ModuleTiming {
             solve,
             find_specializations,
             make_specializations,
+            spans: _,
             start_time,
             end_time,
         } = self;
@@ -1160,21 +1257,26 @@ fn report_timing(
     )
 }
 
+const READ_ROC_FILE: &str = "Read .roc file from disk";
+const PARSE_HEADER: &str = "Parse header";
+const PARSE_BODY: &str = "Parse body";
+const CANONICALIZE: &str = "Canonicalize";
+const CONSTRAIN: &str = "Constrain";
+const SOLVE: &str = "Solve";
+const FIND_SPECIALIZATIONS: &str = "Find Specializations";
+const MAKE_SPECIALIZATIONS: &str = "Make Specializations";
+
 impl std::fmt::Display for ModuleTiming {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let module_timing = self;
 
-        report_timing(f, "Read .roc file from disk", module_timing.read_roc_file)?;
-        report_timing(f, "Parse header", module_timing.parse_header)?;
-        report_timing(f, "Parse body", module_timing.parse_body)?;
-        report_timing(f, "Canonicalize", module_timing.canonicalize)?;
-        report_timing(f, "Constrain", module_timing.constrain)?;
-        report_timing(f, "Solve", module_timing.solve)?;
-        report_timing(
-            f,
-            "Find Specializations",
-            module_timing.find_specializations,
-        )?;
+        report_timing(f, READ_ROC_FILE, module_timing.read_roc_file)?;
+        report_timing(f, PARSE_HEADER, module_timing.parse_header)?;
+        report_timing(f, PARSE_BODY, module_timing.parse_body)?;
+        report_timing(f, CANONICALIZE, module_timing.canonicalize)?;
+        report_timing(f, CONSTRAIN, module_timing.constrain)?;
+        report_timing(f, SOLVE, module_timing.solve)?;
+        report_timing(f, FIND_SPECIALIZATIONS, module_timing.find_specializations)?;
         let multiple_make_specializations_passes = module_timing.make_specializations.len() > 1;
         for (i, pass_time) in module_timing.make_specializations.iter().enumerate() {
             let suffix = if multiple_make_specializations_passes {
@@ -1182,7 +1284,11 @@ impl std::fmt::Display for ModuleTiming {
             } else {
                 String::new()
             };
-            report_timing(f, &format!("Make Specializations{}", suffix), *pass_time)?;
+            report_timing(
+                f,
+                &format!("{}{}", MAKE_SPECIALIZATIONS, suffix),
+                *pass_time,
+            )?;
         }
         report_timing(f, "Other", module_timing.other())?;
         f.write_str("\n")?;
@@ -1244,6 +1350,7 @@ enum BuildTask<'a> {
         derived_module: SharedDerivedModule,
         expectations: Option<Expectations>,
         build_expects: bool,
+        refcount_trace: Option<RefcountTrace<'a>>,
     },
     MakeSpecializations {
         module_id: ModuleId,
@@ -1257,6 +1364,7 @@ enum BuildTask<'a> {
         world_abilities: WorldAbilities,
         derived_module: SharedDerivedModule,
         expectations: Option<Expectations>,
+        refcount_trace: Option<RefcountTrace<'a>>,
     },
 }
 
@@ -1346,6 +1454,9 @@ pub fn load_and_typecheck_str<'a>(
         palette,
         threading,
         exec_mode: ExecutionMode::Check,
+        fold_constants: true,
+        emit_ir: EmitIr::default(),
+        trace_refcounts: false,
     };
 
     match load(
@@ -1605,6 +1716,9 @@ pub fn load<'a>(
             load_config.render,
             load_config.palette,
             load_config.exec_mode,
+            load_config.fold_constants,
+            load_config.emit_ir,
+            load_config.trace_refcounts,
             roc_cache_dir,
         ),
         Threads::Many(threads) => load_multi_threaded(
@@ -1617,6 +1731,9 @@ pub fn load<'a>(
             load_config.palette,
             threads,
             load_config.exec_mode,
+            load_config.fold_constants,
+            load_config.emit_ir,
+            load_config.trace_refcounts,
             roc_cache_dir,
         ),
     }
@@ -1632,6 +1749,9 @@ pub fn load_single_threaded<'a>(
     render: RenderTarget,
     palette: Palette,
     exec_mode: ExecutionMode,
+    fold_constants: bool,
+    emit_ir: EmitIr,
+    trace_refcounts: bool,
     roc_cache_dir: RocCacheDir<'_>,
 ) -> Result<LoadResult<'a>, LoadingProblem<'a>> {
     let LoadStart {
@@ -1663,6 +1783,9 @@ pub fn load_single_threaded<'a>(
         palette,
         number_of_workers,
         exec_mode,
+        fold_constants,
+        emit_ir,
+        trace_refcounts,
     );
 
     // We'll add tasks to this, and then worker threads will take tasks from it.
@@ -1945,6 +2068,9 @@ fn load_multi_threaded<'a>(
     palette: Palette,
     available_threads: usize,
     exec_mode: ExecutionMode,
+    fold_constants: bool,
+    emit_ir: EmitIr,
+    trace_refcounts: bool,
     roc_cache_dir: RocCacheDir<'_>,
 ) -> Result<LoadResult<'a>, LoadingProblem<'a>> {
     let LoadStart {
@@ -1991,6 +2117,9 @@ fn load_multi_threaded<'a>(
         palette,
         num_workers,
         exec_mode,
+        fold_constants,
+        emit_ir,
+        trace_refcounts,
     );
 
     // an arena for every worker, stored in an arena-allocated bumpalo vec to make the lifetimes work
@@ -2024,7 +2153,7 @@ fn load_multi_threaded<'a>(
             let mut worker_listeners =
                 bumpalo::collections::Vec::with_capacity_in(num_workers, arena);
 
-            for worker_arena in it {
+            for (worker_index, worker_arena) in it.enumerate() {
                 let msg_tx = msg_tx.clone();
                 let worker = worker_queues.pop().unwrap();
 
@@ -2042,6 +2171,8 @@ fn load_multi_threaded<'a>(
                     .builder()
                     .stack_size(EXPANDED_STACK_SIZE)
                     .spawn(move |_| {
+                        TIMING_THREAD.with(|thread| thread.set(worker_index + 1));
+
                         // will process messages until we run out
                         worker_task(
                             worker_arena,
@@ -2296,18 +2427,21 @@ fn start_tasks<'a>(
     Ok(())
 }
 
+fn pretty_print_procs<'a>(
+    procedures: &MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+    interner: &STLayoutInterner<'a>,
+) -> String {
+    procedures
+        .values()
+        .map(|proc| proc.to_pretty(interner, 200, true))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 macro_rules! debug_print_ir {
     ($state:expr, $interner:expr, $flag:path) => {
         dbg_do!($flag, {
-            let procs_string = $state
-                .procedures
-                .values()
-                .map(|proc| proc.to_pretty($interner, 200, true))
-                .collect::<Vec<_>>();
-
-            let result = procs_string.join("\n");
-
-            eprintln!("{}", result);
+            eprintln!("{}", pretty_print_procs(&$state.procedures, $interner));
         })
     };
 }
@@ -2357,16 +2491,24 @@ fn report_unused_imported_modules<'a>(
         Occupied(entry) => entry.into_mut(),
     };
 
+    let import_fixes = &constrained_module.import_fixes;
+
     for (unused, region) in unused_imported_modules.drain() {
         if !unused.is_builtin() {
             existing.push(roc_problem::can::Problem::UnusedModuleImport(
-                unused, region,
+                unused,
+                region,
+                import_fixes.get(&region).cloned(),
             ));
         }
     }
 
     for (unused, region) in unused_imports.drain() {
-        existing.push(roc_problem::can::Problem::UnusedImport(unused, region));
+        existing.push(roc_problem::can::Problem::UnusedImport(
+            unused,
+            region,
+            import_fixes.get(&region).cloned(),
+        ));
     }
 }
 
@@ -2602,6 +2744,9 @@ fn update<'a>(
                 extend_header_with_builtin(header, ModuleId::ENCODE);
                 extend_header_with_builtin(header, ModuleId::DECODE);
                 extend_header_with_builtin(header, ModuleId::HASH);
+                extend_header_with_builtin(header, ModuleId::ORD);
+                extend_header_with_builtin(header, ModuleId::ORD_DICT);
+                extend_header_with_builtin(header, ModuleId::ORD_SET);
             }
 
             state
@@ -2732,6 +2877,23 @@ fn update<'a>(
             log!("solved types for {:?}", module_id);
             module_timing.end_time = Instant::now();
 
+            if module_id == state.root_id && state.emit_ir.can {
+                let mut all_ident_ids = dep_idents.clone();
+                all_ident_ids.insert(module_id, ident_ids.clone());
+
+                let interns = Interns {
+                    module_ids: state.arc_modules.lock().clone().into_module_ids(),
+                    all_ident_ids,
+                };
+                let ctx = PPCtx {
+                    home: module_id,
+                    interns: &interns,
+                    print_lambda_names: true,
+                };
+
+                state.emitted_ir.can = Some(pretty_print_declarations(&ctx, &decls));
+            }
+
             state
                 .module_cache
                 .type_problems
@@ -3083,10 +3245,33 @@ fn update<'a>(
                     log!("specializations complete from {:?}", module_id);
 
                     debug_print_ir!(state, &layout_interner, ROC_PRINT_IR_AFTER_SPECIALIZATION);
+
+                    if state.emit_ir.mono {
+                        state.emitted_ir.mono =
+                            Some(pretty_print_procs(&state.procedures, &layout_interner));
+                    }
                     debug_check_ir!(state, arena, layout_interner, ROC_CHECK_MONO_IR);
 
                     let ident_ids = state.constrained_ident_ids.get_mut(&module_id).unwrap();
 
+                    Proc::inline_small_procs(
+                        arena,
+                        module_id,
+                        ident_ids,
+                        &mut update_mode_ids,
+                        &mut state.procedures,
+                    );
+
+                    if state.fold_constants {
+                        Proc::fold_constants(
+                            arena,
+                            &layout_interner,
+                            module_id,
+                            ident_ids,
+                            &mut state.procedures,
+                        );
+                    }
+
                     Proc::insert_reset_reuse_operations(
                         arena,
                         &mut layout_interner,
@@ -3098,8 +3283,25 @@ fn update<'a>(
 
                     debug_print_ir!(state, &layout_interner, ROC_PRINT_IR_AFTER_RESET_REUSE);
 
+                    // The procs that call a closure on behalf of the host (the `roc__..._caller`
+                    // functions) are host-exposed too. The host gives them a reference to the
+                    // closure data for every call, so they must own it.
+                    let host_exposed_callers = state
+                        .procedures
+                        .values()
+                        .filter_map(|proc| match &proc.host_exposed_layouts {
+                            HostExposedLayouts::NotHostExposed => None,
+                            HostExposedLayouts::HostExposed { aliases, .. } => Some(aliases),
+                        })
+                        .flat_map(|aliases| aliases.values().map(|(name, _, _)| *name));
+
                     let host_exposed_procs = bumpalo::collections::Vec::from_iter_in(
-                        state.exposed_to_host.values.keys().copied(),
+                        state
+                            .exposed_to_host
+                            .values
+                            .keys()
+                            .copied()
+                            .chain(host_exposed_callers),
                         arena,
                     );
 
@@ -3115,6 +3317,11 @@ fn update<'a>(
 
                     debug_print_ir!(state, &layout_interner, ROC_PRINT_IR_AFTER_REFCOUNT);
 
+                    if state.emit_ir.mono_refcounted {
+                        state.emitted_ir.mono_refcounted =
+                            Some(pretty_print_procs(&state.procedures, &layout_interner));
+                    }
+
                     // This is not safe with the new non-recursive RC updates that we do for tag unions
                     //
                     // Proc::optimize_refcount_operations(
@@ -3308,6 +3515,7 @@ fn finish_specialization<'a>(
         platform_path,
         platform_data,
         exec_mode,
+        emitted_ir,
         ..
     } = state;
 
@@ -3414,6 +3622,7 @@ fn finish_specialization<'a>(
         timings: state.timings,
         toplevel_expects,
         uses_prebuilt_platform,
+        emitted_ir,
     })
 }
 
@@ -3523,6 +3732,16 @@ fn load_package_from_disk<'a>(
 
             pkg_module_timing.read_roc_file = file_io_duration;
             pkg_module_timing.parse_header = parse_header_duration;
+            pkg_module_timing.record_span(
+                READ_ROC_FILE,
+                file_io_start,
+                file_io_start + file_io_duration,
+            );
+            pkg_module_timing.record_span(
+                PARSE_HEADER,
+                parse_start,
+                parse_start + parse_header_duration,
+            );
 
             match parsed {
                 Ok((
@@ -3735,6 +3954,11 @@ fn load_module<'a>(
 
     module_timing.read_roc_file = Default::default();
     module_timing.parse_header = parse_header_duration;
+    module_timing.record_span(
+        PARSE_HEADER,
+        parse_start,
+        parse_start + parse_header_duration,
+    );
 
     macro_rules! load_builtins {
         ($($name:literal, $module_id:path)*) => {
@@ -3770,6 +3994,9 @@ fn load_module<'a>(
         "Decode", ModuleId::DECODE
         "Hash", ModuleId::HASH
         "Json", ModuleId::JSON
+        "Ord", ModuleId::ORD
+        "OrdDict", ModuleId::ORD_DICT
+        "OrdSet", ModuleId::ORD_SET
     }
 
     let (filename, opt_shorthand) = module_name_to_path(src_dir, &module_name, arc_shorthands);
@@ -3960,6 +4187,19 @@ fn parse_header<'a>(
 
     module_timing.read_roc_file = read_file_duration;
     module_timing.parse_header = parse_header_duration;
+    // the file was read right before we started parsing its header
+    module_timing.record_span(
+        READ_ROC_FILE,
+        parse_start
+            .checked_sub(read_file_duration)
+            .unwrap_or(parse_start),
+        parse_start,
+    );
+    module_timing.record_span(
+        PARSE_HEADER,
+        parse_start,
+        parse_start + parse_header_duration,
+    );
 
     match parsed {
         Ok((
@@ -4427,12 +4667,29 @@ fn build_header<'a>(
     let mut imported: Vec<(QualifiedModuleName, Vec<Loc<Ident>>, Region)> =
         Vec::with_capacity(imports.len());
     let mut scope_size = 0;
+    let mut import_fixes = MutMap::default();
+    let import_regions: Vec<Region> = imports.iter().map(|entry| entry.region).collect();
 
-    for loc_entry in imports {
+    for (index, loc_entry) in imports.iter().enumerate() {
         let (qualified_module_name, exposed) = exposed_from_import(&loc_entry.value);
 
         scope_size += num_exposes;
 
+        // Record how to remove the import, or any of the values it exposes, in case it's unused
+        let exposed_regions: Vec<Region> = exposed.iter().map(|ident| ident.region).collect();
+
+        import_fixes.insert(
+            loc_entry.region,
+            Edit::remove_from_collection(&import_regions, index),
+        );
+
+        for (index, region) in exposed_regions.iter().enumerate() {
+            import_fixes.insert(
+                *region,
+                Edit::remove_from_collection(&exposed_regions, index),
+            );
+        }
+
         imported.push((qualified_module_name, exposed, loc_entry.region));
     }
 
@@ -4675,6 +4932,7 @@ fn build_header<'a>(
             exposes: exposed,
             parse_state,
             exposed_imports: scope,
+            import_fixes,
             symbols_from_requires,
             header_type,
             header_comments,
@@ -5151,6 +5409,13 @@ fn run_solve<'a>(
     };
 
     let mut solved_subs = solved_subs;
+    let mut problems = problems;
+    problems.extend(roc_solve::module::typed_hole_problems(
+        solved_subs.inner_mut(),
+        &abilities_store,
+        &decls,
+    ));
+
     let exposed_types = roc_solve::module::exposed_types_storage_subs(
         module_id,
         &mut solved_subs,
@@ -5170,6 +5435,7 @@ fn run_solve<'a>(
     // Record the final timings
     let solve_end = Instant::now();
     module_timing.solve = solve_end.duration_since(solve_start);
+    module_timing.record_span(SOLVE, solve_start, solve_end);
 
     // Send the subs to the main thread for processing,
     Msg::SolvedTypes {
@@ -5331,6 +5597,7 @@ fn canonicalize_and_constrain<'a>(
         parsed_defs,
         exposed_imports,
         imported_modules,
+        import_fixes,
         mut module_timing,
         symbols_from_requires,
         ..
@@ -5374,6 +5641,7 @@ fn canonicalize_and_constrain<'a>(
     let canonicalize_end = Instant::now();
 
     module_timing.canonicalize = canonicalize_end.duration_since(canonicalize_start);
+    module_timing.record_span(CANONICALIZE, canonicalize_start, canonicalize_end);
 
     // Generate documentation information
     // TODO: store timing information?
@@ -5412,6 +5680,7 @@ fn canonicalize_and_constrain<'a>(
     // _before has an underscore because it's unused in --release builds
     let _before = roc_types::types::get_type_clone_count();
 
+    let constrain_start = Instant::now();
     let mut constraints = Constraints::new();
 
     let constraint = if skip_constraint_gen {
@@ -5427,6 +5696,10 @@ fn canonicalize_and_constrain<'a>(
         )
     };
 
+    let constrain_end = Instant::now();
+    module_timing.constrain = constrain_end.duration_since(constrain_start);
+    module_timing.record_span(CONSTRAIN, constrain_start, constrain_end);
+
     // _after has an underscore because it's unused in --release builds
     let _after = roc_types::types::get_type_clone_count();
 
@@ -5459,6 +5732,9 @@ fn canonicalize_and_constrain<'a>(
                         | ModuleId::DICT
                         | ModuleId::SET
                         | ModuleId::HASH
+                        | ModuleId::ORD
+                        | ModuleId::ORD_DICT
+                        | ModuleId::ORD_SET
                 );
 
                 if !name.is_builtin() || should_include_builtin {
@@ -5485,6 +5761,7 @@ fn canonicalize_and_constrain<'a>(
         module,
         declarations: module_output.declarations,
         imported_modules,
+        import_fixes,
         var_store,
         constraints,
         constraint,
@@ -5521,6 +5798,7 @@ fn parse<'a>(arena: &'a Bump, header: ModuleHeader<'a>) -> Result<Msg<'a>, Loadi
     let parse_end = Instant::now();
 
     module_timing.parse_body = parse_end.duration_since(parse_start);
+    module_timing.record_span(PARSE_BODY, parse_start, parse_end);
 
     let imported_modules = header.imported_modules;
 
@@ -5534,6 +5812,7 @@ fn parse<'a>(arena: &'a Bump, header: ModuleHeader<'a>) -> Result<Msg<'a>, Loadi
         deps_by_name,
         exposed_ident_ids,
         exposed_imports,
+        import_fixes,
         module_path,
         header_type,
         symbols_from_requires,
@@ -5550,6 +5829,7 @@ fn parse<'a>(arena: &'a Bump, header: ModuleHeader<'a>) -> Result<Msg<'a>, Loadi
         imported_modules,
         exposed_ident_ids,
         exposed_imports,
+        import_fixes,
         parsed_defs,
         symbols_from_requires,
         header_type,
@@ -5613,6 +5893,7 @@ fn make_specializations<'a>(
     exposed_by_module: &ExposedByModule,
     derived_module: SharedDerivedModule,
     mut expectations: Option<Expectations>,
+    refcount_trace: Option<&RefcountTrace<'a>>,
 ) -> Msg<'a> {
     let make_specializations_start = Instant::now();
     let mut update_mode_ids = UpdateModeIds::new();
@@ -5630,6 +5911,7 @@ fn make_specializations<'a>(
         abilities: AbilitiesView::World(&world_abilities),
         exposed_by_module,
         derived_module: &derived_module,
+        refcount_trace,
     };
 
     let mut procs = Procs::new_in(arena);
@@ -5663,6 +5945,11 @@ fn make_specializations<'a>(
     module_timing
         .make_specializations
         .push(make_specializations_end.duration_since(make_specializations_start));
+    module_timing.record_span(
+        MAKE_SPECIALIZATIONS,
+        make_specializations_start,
+        make_specializations_end,
+    );
 
     Msg::MadeSpecializations {
         module_id: home,
@@ -5694,6 +5981,7 @@ fn build_pending_specializations<'a>(
     derived_module: SharedDerivedModule,
     mut expectations: Option<Expectations>,
     build_expects: bool,
+    refcount_trace: Option<&RefcountTrace<'a>>,
 ) -> Msg<'a> {
     let find_specializations_start = Instant::now();
 
@@ -5726,6 +6014,7 @@ fn build_pending_specializations<'a>(
         abilities: AbilitiesView::Module(&abilities_store),
         exposed_by_module,
         derived_module: &derived_module,
+        refcount_trace,
     };
 
     // Add modules' decls to Procs
@@ -6093,6 +6382,11 @@ fn build_pending_specializations<'a>(
     let find_specializations_end = Instant::now();
     module_timing.find_specializations =
         find_specializations_end.duration_since(find_specializations_start);
+    module_timing.record_span(
+        FIND_SPECIALIZATIONS,
+        find_specializations_start,
+        find_specializations_end,
+    );
 
     Msg::FoundSpecializations {
         module_id: home,
@@ -6159,6 +6453,8 @@ fn load_derived_partial_procs<'a>(
             abilities: AbilitiesView::World(world_abilities),
             exposed_by_module,
             derived_module,
+            // Derived implementations have no source to point at.
+            refcount_trace: None,
         };
 
         let partial_proc = match derived_expr {
@@ -6214,6 +6510,11 @@ fn load_derived_partial_procs<'a>(
 
     module_timing.find_specializations =
         load_derived_procs_end.duration_since(load_derived_procs_start);
+    module_timing.record_span(
+        FIND_SPECIALIZATIONS,
+        load_derived_procs_start,
+        load_derived_procs_end,
+    );
 }
 
 fn run_task<'a>(
@@ -6310,6 +6611,7 @@ fn run_task<'a>(
             derived_module,
             expectations,
             build_expects,
+            refcount_trace,
         } => Ok(build_pending_specializations(
             arena,
             solved_subs,
@@ -6326,6 +6628,7 @@ fn run_task<'a>(
             derived_module,
             expectations,
             build_expects,
+            refcount_trace.as_ref(),
         )),
         MakeSpecializations {
             module_id,
@@ -6339,6 +6642,7 @@ fn run_task<'a>(
             exposed_by_module,
             derived_module,
             expectations,
+            refcount_trace,
         } => Ok(make_specializations(
             arena,
             module_id,
@@ -6353,6 +6657,7 @@ fn run_task<'a>(
             &exposed_by_module,
             derived_module,
             expectations,
+            refcount_trace.as_ref(),
         )),
     }?;
 
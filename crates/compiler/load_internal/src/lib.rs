@@ -24,4 +24,7 @@ pub const BUILTIN_MODULES: &[(ModuleId, &str)] = &[
     (ModuleId::DECODE, "Decode"),
     (ModuleId::HASH, "Hash"),
     (ModuleId::JSON, "Json"),
+    (ModuleId::ORD, "Ord"),
+    (ModuleId::ORD_DICT, "OrdDict"),
+    (ModuleId::ORD_SET, "OrdSet"),
 ];
@@ -17,7 +17,7 @@ mod helpers;
 use crate::helpers::fixtures_dir;
 use bumpalo::Bump;
 use roc_can::module::ExposedByModule;
-use roc_load_internal::file::{ExecutionMode, LoadConfig, Threading};
+use roc_load_internal::file::{EmitIr, ExecutionMode, LoadConfig, Threading};
 use roc_load_internal::file::{LoadResult, LoadStart, LoadedModule, LoadingProblem};
 use roc_module::ident::ModuleName;
 use roc_module::symbol::{Interns, ModuleId};
@@ -54,6 +54,9 @@ fn load_and_typecheck(
         palette: DEFAULT_PALETTE,
         threading: Threading::Single,
         exec_mode: ExecutionMode::Check,
+        fold_constants: true,
+        emit_ir: EmitIr::default(),
+        trace_refcounts: false,
     };
 
     match roc_load_internal::file::load(
@@ -924,6 +927,80 @@ fn import_builtin_in_platform_and_check_app() {
     assert!(result.is_ok(), "should check");
 }
 
+#[test]
+fn platform_exposes_closures_without_aliases_to_host() {
+    let modules = vec![
+        (
+            "platform/main.roc",
+            indoc!(
+                r#"
+                    platform "testplatform"
+                        requires {} { main : Str -> { program : Program, farewell : Str -> Str, callbacks : List (Str -> Str) } }
+                        exposes []
+                        packages {}
+                        imports []
+                        provides [mainForHost]
+
+                    Program : { init : {} -> Str, update : Str -> Str }
+
+                    mainForHost : Str -> { program : Program, farewell : (Str -> Str) as Farewell, callbacks : List (Str -> Str) }
+                    mainForHost = \name -> main name
+                    "#
+            ),
+        ),
+        (
+            "Main",
+            indoc!(
+                r#"
+                    app "test"
+                        packages { pf: "platform/main.roc" }
+                        provides [main] to pf
+
+                    main = \name ->
+                        {
+                            program: { init: \{} -> name, update: \str -> Str.concat str name },
+                            farewell: \str -> Str.concat "Bye, " str,
+                            callbacks: [\str -> str],
+                        }
+                    "#
+            ),
+        ),
+    ];
+
+    let mut loaded_module =
+        multiple_modules("platform_exposes_closures_without_aliases_to_host", modules).unwrap();
+
+    let main_for_host = *loaded_module.exposed_to_host.keys().next().unwrap();
+    let platform = main_for_host.module_id();
+
+    assert!(loaded_module
+        .type_problems
+        .remove(&platform)
+        .unwrap_or_default()
+        .is_empty());
+
+    let decls = &loaded_module.declarations_by_id[&platform];
+    let index = decls
+        .symbols
+        .iter()
+        .position(|symbol| symbol.value == main_for_host)
+        .unwrap();
+    let annotation = decls.annotations[index].as_ref().unwrap();
+
+    let mut host_exposed_aliases: Vec<&str> = annotation
+        .introduced_variables
+        .host_exposed_aliases
+        .keys()
+        .map(|symbol| symbol.as_str(&loaded_module.interns))
+        .collect();
+    host_exposed_aliases.sort_unstable();
+
+    assert_eq!(
+        host_exposed_aliases,
+        ["Closure1", "Closure2", "Closure3", "Farewell"]
+    );
+}
+
 #[test]
 fn module_doesnt_match_file_path() {
     let modules = vec![(
@@ -108,6 +108,9 @@ impl ModuleName {
     pub const DECODE: &'static str = "Decode";
     pub const HASH: &'static str = "Hash";
     pub const JSON: &'static str = "Json";
+    pub const ORD: &'static str = "Ord";
+    pub const ORD_DICT: &'static str = "OrdDict";
+    pub const ORD_SET: &'static str = "OrdSet";
 
     pub fn as_str(&self) -> &str {
         self.0.as_str()
@@ -23,6 +23,8 @@ pub enum LowLevel {
     StrTrimLeft,
     StrTrimRight,
     StrToNum,
+    StrToIntRadix,
+    StrToFloatChecked,
     StrToScalars,
     StrGetUnsafe,
     StrSubstringUnsafe,
@@ -32,6 +34,12 @@ pub enum LowLevel {
     StrGetCapacity,
     StrWithCapacity,
     StrGraphemes,
+    StrToUppercase,
+    StrToLowercase,
+    StrCaseFold,
+    StrToNfc,
+    StrToNfd,
+    StrFind,
     ListLen,
     ListWithCapacity,
     ListReserve,
@@ -90,26 +98,48 @@ pub enum LowLevel {
     NumAsin,
     NumBytesToU16,
     NumBytesToU32,
+    NumReadBytesLe,
+    NumReadBytesBe,
+    NumWriteBytesLe,
+    NumWriteBytesBe,
     NumBitwiseAnd,
     NumBitwiseXor,
     NumBitwiseOr,
     NumShiftLeftBy,
     NumShiftRightBy,
     NumShiftRightZfBy,
+    NumCountLeadingZeroBits,
+    NumCountTrailingZeroBits,
+    NumCountOneBits,
+    NumRotateLeftBy,
+    NumRotateRightBy,
+    NumSwapBytes,
     NumIntCast,
     NumToFloatCast,
     NumToIntChecked,
     NumToFloatChecked,
     NumToStr,
+    NumToStrFixed,
+    NumToStrScientific,
+    NumToStrRadix,
+    NumPadZeros,
+    NumGroupDigits,
     Eq,
     NotEq,
     And,
     Or,
     Not,
     Hash,
+    HashRandomSeed,
     PtrCast,
+    PtrStore,
+    PtrLoad,
+    Alloca,
     RefCountInc,
     RefCountDec,
+    RefCountTraceLocation,
+    RefCountTraceReset,
+    RefCountTraceInc,
     BoxExpr,
     UnboxExpr,
     Unreachable,
@@ -212,8 +242,14 @@ macro_rules! map_symbol_to_lowlevel {
                 // these are used internally and not tied to a symbol
                 LowLevel::Hash => unimplemented!(),
                 LowLevel::PtrCast => unimplemented!(),
+                LowLevel::PtrStore => unimplemented!(),
+                LowLevel::PtrLoad => unimplemented!(),
+                LowLevel::Alloca => unimplemented!(),
                 LowLevel::RefCountInc => unimplemented!(),
                 LowLevel::RefCountDec => unimplemented!(),
+                LowLevel::RefCountTraceLocation => unimplemented!(),
+                LowLevel::RefCountTraceReset => unimplemented!(),
+                LowLevel::RefCountTraceInc => unimplemented!(),
 
                 // these are not implemented, not sure why
                 LowLevel::StrFromInt => unimplemented!(),
@@ -250,9 +286,17 @@ map_symbol_to_lowlevel! {
     StrAppendScalar <= STR_APPEND_SCALAR_UNSAFE,
     StrGetScalarUnsafe <= STR_GET_SCALAR_UNSAFE,
     StrToNum <= STR_TO_NUM,
+    StrToIntRadix <= STR_TO_INT_RADIX,
+    StrToFloatChecked <= STR_TO_FLOAT_CHECKED,
     StrGetCapacity <= STR_CAPACITY,
     StrWithCapacity <= STR_WITH_CAPACITY,
     StrGraphemes <= STR_GRAPHEMES,
+    StrToUppercase <= STR_TO_UPPERCASE,
+    StrToLowercase <= STR_TO_LOWERCASE,
+    StrCaseFold <= STR_CASE_FOLD,
+    StrToNfc <= STR_TO_NFC,
+    StrToNfd <= STR_TO_NFD,
+    StrFind <= STR_FIND_UNSAFE,
     ListLen <= LIST_LEN,
     ListGetCapacity <= LIST_CAPACITY,
     ListWithCapacity <= LIST_WITH_CAPACITY,
@@ -305,17 +349,33 @@ map_symbol_to_lowlevel! {
     NumAsin <= NUM_ASIN,
     NumBytesToU16 <= NUM_BYTES_TO_U16_LOWLEVEL,
     NumBytesToU32 <= NUM_BYTES_TO_U32_LOWLEVEL,
+    NumReadBytesLe <= NUM_READ_LE_UNSAFE,
+    NumReadBytesBe <= NUM_READ_BE_UNSAFE,
+    NumWriteBytesLe <= NUM_WRITE_LE_UNSAFE,
+    NumWriteBytesBe <= NUM_WRITE_BE_UNSAFE,
     NumBitwiseAnd <= NUM_BITWISE_AND,
     NumBitwiseXor <= NUM_BITWISE_XOR,
     NumBitwiseOr <= NUM_BITWISE_OR,
     NumShiftLeftBy <= NUM_SHIFT_LEFT,
     NumShiftRightBy <= NUM_SHIFT_RIGHT,
     NumShiftRightZfBy <= NUM_SHIFT_RIGHT_ZERO_FILL,
+    NumCountLeadingZeroBits <= NUM_COUNT_LEADING_ZERO_BITS,
+    NumCountTrailingZeroBits <= NUM_COUNT_TRAILING_ZERO_BITS,
+    NumCountOneBits <= NUM_COUNT_ONE_BITS,
+    NumRotateLeftBy <= NUM_ROTATE_LEFT_BY,
+    NumRotateRightBy <= NUM_ROTATE_RIGHT_BY,
+    NumSwapBytes <= NUM_SWAP_BYTES,
     NumToStr <= NUM_TO_STR,
+    NumToStrFixed <= NUM_TO_STR_FIXED,
+    NumToStrScientific <= NUM_TO_STR_SCIENTIFIC,
+    NumToStrRadix <= NUM_TO_STR_RADIX,
+    NumPadZeros <= NUM_PAD_ZEROS,
+    NumGroupDigits <= NUM_GROUP_DIGITS,
     Eq <= BOOL_STRUCTURAL_EQ,
     NotEq <= BOOL_STRUCTURAL_NOT_EQ,
     And <= BOOL_AND,
     Or <= BOOL_OR,
     Not <= BOOL_NOT,
     Unreachable <= LIST_UNREACHABLE,
+    HashRandomSeed <= HASH_RANDOM_SEED,
 }
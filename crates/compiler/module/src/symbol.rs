@@ -52,6 +52,7 @@ pub const DERIVABLE_ABILITIES: &[(Symbol, &[Symbol])] = &[
     (Symbol::DECODE_DECODING, &[Symbol::DECODE_DECODER]),
     (Symbol::HASH_HASH_ABILITY, &[Symbol::HASH_HASH]),
     (Symbol::BOOL_EQ, &[Symbol::BOOL_IS_EQ]),
+    (Symbol::ORD_ORD, &[Symbol::ORD_COMPARE]),
 ];
 
 /// In Debug builds only, Symbol has a name() method that lets
@@ -1246,6 +1247,58 @@ define_builtins! {
         145 NUM_MUL_CHECKED_LOWLEVEL: "mulCheckedLowlevel"
         146 NUM_BYTES_TO_U16_LOWLEVEL: "bytesToU16Lowlevel"
         147 NUM_BYTES_TO_U32_LOWLEVEL: "bytesToU32Lowlevel"
+        148 NUM_COUNT_LEADING_ZERO_BITS: "countLeadingZeroBits"
+        149 NUM_COUNT_TRAILING_ZERO_BITS: "countTrailingZeroBits"
+        150 NUM_COUNT_ONE_BITS: "countOneBits"
+        151 NUM_ROTATE_LEFT_BY: "rotateLeftBy"
+        152 NUM_ROTATE_RIGHT_BY: "rotateRightBy"
+        153 NUM_SWAP_BYTES: "swapBytes"
+        154 NUM_TO_STR_FIXED: "toStrFixed"
+        155 NUM_TO_STR_SCIENTIFIC: "toStrScientific"
+        156 NUM_TO_STR_HEX: "toStrHex"
+        157 NUM_TO_STR_OCTAL: "toStrOctal"
+        158 NUM_TO_STR_BINARY: "toStrBinary"
+        159 NUM_TO_STR_RADIX: "toStrRadix"
+        160 NUM_PAD_ZEROS: "padZeros"
+        161 NUM_GROUP_DIGITS: "groupDigits"
+        162 NUM_READ_LE_UNSAFE: "readLeUnsafe"
+        163 NUM_READ_BE_UNSAFE: "readBeUnsafe"
+        164 NUM_WRITE_LE_UNSAFE: "writeLeUnsafe"
+        165 NUM_WRITE_BE_UNSAFE: "writeBeUnsafe"
+        166 NUM_READ_U8: "readU8"
+        167 NUM_WRITE_U8: "writeU8"
+        168 NUM_READ_U16_LE: "readU16Le"
+        169 NUM_READ_U16_BE: "readU16Be"
+        170 NUM_WRITE_U16_LE: "writeU16Le"
+        171 NUM_WRITE_U16_BE: "writeU16Be"
+        172 NUM_READ_U32_LE: "readU32Le"
+        173 NUM_READ_U32_BE: "readU32Be"
+        174 NUM_WRITE_U32_LE: "writeU32Le"
+        175 NUM_WRITE_U32_BE: "writeU32Be"
+        176 NUM_READ_U64_LE: "readU64Le"
+        177 NUM_READ_U64_BE: "readU64Be"
+        178 NUM_WRITE_U64_LE: "writeU64Le"
+        179 NUM_WRITE_U64_BE: "writeU64Be"
+        180 NUM_READ_U128_LE: "readU128Le"
+        181 NUM_READ_U128_BE: "readU128Be"
+        182 NUM_WRITE_U128_LE: "writeU128Le"
+        183 NUM_WRITE_U128_BE: "writeU128Be"
+        184 NUM_READ_F32_LE: "readF32Le"
+        185 NUM_READ_F32_BE: "readF32Be"
+        186 NUM_WRITE_F32_LE: "writeF32Le"
+        187 NUM_WRITE_F32_BE: "writeF32Be"
+        188 NUM_READ_F64_LE: "readF64Le"
+        189 NUM_READ_F64_BE: "readF64Be"
+        190 NUM_WRITE_F64_LE: "writeF64Le"
+        191 NUM_WRITE_F64_BE: "writeF64Be"
+        192 NUM_BYTES_TO_U64: "bytesToU64"
+        193 NUM_BYTES_TO_U128: "bytesToU128"
+        194 NUM_U16_TO_BYTES: "u16ToBytes"
+        195 NUM_U32_TO_BYTES: "u32ToBytes"
+        196 NUM_U64_TO_BYTES: "u64ToBytes"
+        197 NUM_U128_TO_BYTES: "u128ToBytes"
+        198 NUM_SORT_ASC: "sortAsc"
+        199 NUM_SORT_DESC: "sortDesc"
     }
     4 BOOL: "Bool" => {
         0 BOOL_BOOL: "Bool" exposed_type=true // the Bool.Bool type alias
@@ -1319,6 +1372,32 @@ define_builtins! {
         53 STR_WITH_CAPACITY: "withCapacity"
         54 STR_WITH_PREFIX: "withPrefix"
         55 STR_GRAPHEMES: "graphemes"
+        56 STR_COMPARE: "compare"
+        57 STR_TO_UPPERCASE: "toUppercase"
+        58 STR_TO_LOWERCASE: "toLowercase"
+        59 STR_CASE_FOLD: "caseFold"
+        60 STR_TO_NFC: "toNfc"
+        61 STR_TO_NFD: "toNfd"
+        62 STR_COMPARE_IGNORING_CASE: "compareIgnoringCase"
+        63 STR_CONTAINS: "contains"
+        64 STR_FIND: "find"
+        65 STR_FIND_UNSAFE: "findUnsafe"
+        66 STR_NUM_STR_PROBLEM: "NumStrProblem" // the NumStrProblem type alias
+        67 STR_TO_INT_RADIX: "strToIntRadix"
+        68 STR_TO_FLOAT_CHECKED: "strToFloatChecked"
+        69 STR_TO_NAT_RADIX: "toNatRadix"
+        70 STR_TO_U128_RADIX: "toU128Radix"
+        71 STR_TO_I128_RADIX: "toI128Radix"
+        72 STR_TO_U64_RADIX: "toU64Radix"
+        73 STR_TO_I64_RADIX: "toI64Radix"
+        74 STR_TO_U32_RADIX: "toU32Radix"
+        75 STR_TO_I32_RADIX: "toI32Radix"
+        76 STR_TO_U16_RADIX: "toU16Radix"
+        77 STR_TO_I16_RADIX: "toI16Radix"
+        78 STR_TO_U8_RADIX: "toU8Radix"
+        79 STR_TO_I8_RADIX: "toI8Radix"
+        80 STR_TO_F64_CHECKED: "toF64Checked"
+        81 STR_TO_F32_CHECKED: "toF32Checked"
     }
     6 LIST: "List" => {
         0 LIST_LIST: "List" exposed_apply_type=true // the List.List type alias
@@ -1401,6 +1480,7 @@ define_builtins! {
         77 LIST_COUNT_IF: "countIf"
         78 LIST_WALK_FROM: "walkFrom"
         79 LIST_WALK_FROM_UNTIL: "walkFromUntil"
+        80 LIST_COMPARE: "compare"
     }
     7 RESULT: "Result" => {
         0 RESULT_RESULT: "Result" exposed_type=true // the Result.Result type alias
@@ -1442,6 +1522,8 @@ define_builtins! {
         21 DICT_UPDATE: "update"
 
         22 DICT_LIST_GET_UNSAFE: "listGetUnsafe"
+        23 DICT_WITH_HASHER: "withHasher"
+        24 DICT_WITH_RANDOM_SEED: "withRandomSeed"
     }
     9 SET: "Set" => {
         0 SET_SET: "Set" exposed_type=true // the Set.Set type alias
@@ -1461,6 +1543,8 @@ define_builtins! {
         14 SET_CONTAINS: "contains"
         15 SET_TO_DICT: "toDict"
         16 SET_CAPACITY: "capacity"
+        17 SET_WITH_HASHER: "withHasher"
+        18 SET_WITH_RANDOM_SEED: "withRandomSeed"
     }
     10 BOX: "Box" => {
         0 BOX_BOX_TYPE: "Box" exposed_apply_type=true // the Box.Box opaque type
@@ -1545,10 +1629,68 @@ define_builtins! {
         16 HASH_HASH_STR_BYTES: "hashStrBytes"
         17 HASH_HASH_LIST: "hashList"
         18 HASH_HASH_UNORDERED: "hashUnordered"
+        19 HASH_RANDOM_SEED: "randomSeed"
     }
     14 JSON: "Json" => {
         0 JSON_JSON: "Json"
     }
-
-    num_modules: 15 // Keep this count up to date by hand! (TODO: see the mut_map! macro for how we could determine this count correctly in the macro)
+    15 ORD: "Ord" => {
+        0 ORD_ORD: "Ord" exposed_type=true
+        1 ORD_COMPARE: "compare"
+    }
+    16 ORD_DICT: "OrdDict" => {
+        0 ORD_DICT_ORD_DICT: "OrdDict" exposed_type=true // the OrdDict.OrdDict type alias
+        1 ORD_DICT_EMPTY: "empty"
+        2 ORD_DICT_SINGLE: "single"
+        3 ORD_DICT_LEN: "len"
+        4 ORD_DICT_GET: "get"
+        5 ORD_DICT_CONTAINS: "contains"
+        6 ORD_DICT_INSERT: "insert"
+        7 ORD_DICT_REMOVE: "remove"
+        8 ORD_DICT_UPDATE: "update"
+
+        9 ORD_DICT_WALK: "walk"
+        10 ORD_DICT_WALK_UNTIL: "walkUntil"
+        11 ORD_DICT_WALK_FROM: "walkFrom"
+        12 ORD_DICT_WALK_FROM_UNTIL: "walkFromUntil"
+        13 ORD_DICT_FROM_LIST: "fromList"
+        14 ORD_DICT_TO_LIST: "toList"
+        15 ORD_DICT_KEYS: "keys"
+        16 ORD_DICT_VALUES: "values"
+
+        17 ORD_DICT_INSERT_ALL: "insertAll" // union
+        18 ORD_DICT_KEEP_SHARED: "keepShared" // intersection
+        19 ORD_DICT_REMOVE_ALL: "removeAll" // difference
+
+        20 ORD_DICT_MIN: "min"
+        21 ORD_DICT_MAX: "max"
+        22 ORD_DICT_FLOOR: "floor"
+        23 ORD_DICT_CEILING: "ceiling"
+        24 ORD_DICT_RANGE: "range"
+    }
+    17 ORD_SET: "OrdSet" => {
+        0 ORD_SET_ORD_SET: "OrdSet" exposed_type=true // the OrdSet.OrdSet type alias
+        1 ORD_SET_EMPTY: "empty"
+        2 ORD_SET_SINGLE: "single"
+        3 ORD_SET_LEN: "len"
+        4 ORD_SET_INSERT: "insert"
+        5 ORD_SET_REMOVE: "remove"
+        6 ORD_SET_UNION: "union"
+        7 ORD_SET_DIFFERENCE: "difference"
+        8 ORD_SET_INTERSECTION: "intersection"
+        9 ORD_SET_TO_LIST: "toList"
+        10 ORD_SET_FROM_LIST: "fromList"
+        11 ORD_SET_WALK: "walk"
+        12 ORD_SET_WALK_UNTIL: "walkUntil"
+        13 ORD_SET_WALK_FROM: "walkFrom"
+        14 ORD_SET_WALK_FROM_UNTIL: "walkFromUntil"
+        15 ORD_SET_CONTAINS: "contains"
+        16 ORD_SET_MIN: "min"
+        17 ORD_SET_MAX: "max"
+        18 ORD_SET_FLOOR: "floor"
+        19 ORD_SET_CEILING: "ceiling"
+        20 ORD_SET_RANGE: "range"
+    }
+
+    num_modules: 18 // Keep this count up to date by hand! (TODO: see the mut_map! macro for how we could determine this count correctly in the macro)
 }
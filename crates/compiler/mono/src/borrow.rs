@@ -726,7 +726,7 @@ impl<'a> BorrowInfState<'a> {
 
             Call(call) => self.collect_call(param_map, z, call),
 
-            Literal(_) | RuntimeErrorFunction(_) => {}
+            Literal(_) | NullPointer | RuntimeErrorFunction(_) => {}
 
             StructAtIndex { structure: x, .. } => {
                 // if the structure (record/tag/array) is owned, the extracted value is
@@ -751,6 +751,10 @@ impl<'a> BorrowInfState<'a> {
                 // if the extracted value is owned, the structure must be too
                 self.if_is_owned_then_own(z, *x);
             }
+
+            UnionFieldPtrAtIndex { .. } => {
+                // an address is not refcounted, and does not keep the structure alive
+            }
         }
     }
 
@@ -959,8 +963,14 @@ pub fn lowlevel_borrow_signature(arena: &Bump, op: LowLevel) -> &[bool] {
         StrTrim => arena.alloc_slice_copy(&[owned]),
         StrTrimLeft => arena.alloc_slice_copy(&[owned]),
         StrTrimRight => arena.alloc_slice_copy(&[owned]),
+        StrToUppercase | StrToLowercase | StrCaseFold | StrToNfc | StrToNfd => {
+            arena.alloc_slice_copy(&[owned])
+        }
+        StrFind => arena.alloc_slice_copy(&[borrowed, borrowed]),
         StrSplit => arena.alloc_slice_copy(&[borrowed, borrowed]),
         StrToNum => arena.alloc_slice_copy(&[borrowed]),
+        StrToIntRadix => arena.alloc_slice_copy(&[borrowed, irrelevant]),
+        StrToFloatChecked => arena.alloc_slice_copy(&[borrowed]),
         ListPrepend => arena.alloc_slice_copy(&[owned, owned]),
         StrJoinWith => arena.alloc_slice_copy(&[borrowed, borrowed]),
         ListMap => arena.alloc_slice_copy(&[owned, function, closure_data]),
@@ -982,15 +992,29 @@ pub fn lowlevel_borrow_signature(arena: &Bump, op: LowLevel) -> &[bool] {
         | NumMulChecked | NumGt | NumGte | NumLt | NumLte | NumCompare | NumDivFrac
         | NumDivTruncUnchecked | NumDivCeilUnchecked | NumRemUnchecked | NumIsMultipleOf
         | NumPow | NumPowInt | NumBitwiseAnd | NumBitwiseXor | NumBitwiseOr | NumShiftLeftBy
-        | NumShiftRightBy | NumShiftRightZfBy => arena.alloc_slice_copy(&[irrelevant, irrelevant]),
+        | NumShiftRightBy | NumShiftRightZfBy | NumRotateLeftBy | NumRotateRightBy => {
+            arena.alloc_slice_copy(&[irrelevant, irrelevant])
+        }
 
         NumToStr | NumAbs | NumNeg | NumSin | NumCos | NumSqrtUnchecked | NumLogUnchecked
         | NumRound | NumCeiling | NumFloor | NumToFrac | Not | NumIsFinite | NumAtan | NumAcos
         | NumAsin | NumIntCast | NumToIntChecked | NumToFloatCast | NumToFloatChecked => {
             arena.alloc_slice_copy(&[irrelevant])
         }
+        NumCountLeadingZeroBits | NumCountTrailingZeroBits | NumCountOneBits | NumSwapBytes => {
+            arena.alloc_slice_copy(&[irrelevant])
+        }
+        NumToStrFixed | NumToStrScientific | NumToStrRadix => {
+            arena.alloc_slice_copy(&[irrelevant, irrelevant])
+        }
+        NumPadZeros => arena.alloc_slice_copy(&[owned, irrelevant]),
+        NumGroupDigits => arena.alloc_slice_copy(&[owned, borrowed]),
         NumBytesToU16 => arena.alloc_slice_copy(&[borrowed, irrelevant]),
         NumBytesToU32 => arena.alloc_slice_copy(&[borrowed, irrelevant]),
+        NumReadBytesLe | NumReadBytesBe => arena.alloc_slice_copy(&[borrowed, irrelevant]),
+        NumWriteBytesLe | NumWriteBytesBe => {
+            arena.alloc_slice_copy(&[owned, irrelevant, irrelevant])
+        }
         StrStartsWith | StrEndsWith => arena.alloc_slice_copy(&[borrowed, borrowed]),
         StrStartsWithScalar => arena.alloc_slice_copy(&[borrowed, irrelevant]),
         StrFromUtf8Range => arena.alloc_slice_copy(&[owned, irrelevant, irrelevant]),
@@ -998,16 +1022,25 @@ pub fn lowlevel_borrow_signature(arena: &Bump, op: LowLevel) -> &[bool] {
         StrRepeat => arena.alloc_slice_copy(&[borrowed, irrelevant]),
         StrFromInt | StrFromFloat => arena.alloc_slice_copy(&[irrelevant]),
         Hash => arena.alloc_slice_copy(&[borrowed, irrelevant]),
+        HashRandomSeed => arena.alloc_slice_copy(&[irrelevant]),
 
         ListIsUnique => arena.alloc_slice_copy(&[borrowed]),
 
+        // the stored value is moved into memory; the loaded value is moved out of it
+        PtrStore => arena.alloc_slice_copy(&[irrelevant, owned]),
+        PtrLoad => arena.alloc_slice_copy(&[irrelevant]),
+        Alloca => arena.alloc_slice_copy(&[owned]),
+
         BoxExpr | UnboxExpr => {
             unreachable!("These lowlevel operations are turned into mono Expr's")
         }
 
-        PtrCast | RefCountInc | RefCountDec => {
+        PtrCast | RefCountInc | RefCountDec | RefCountTraceInc => {
             unreachable!("Only inserted *after* borrow checking: {:?}", op);
         }
+
+        RefCountTraceLocation => arena.alloc_slice_copy(&[borrowed]),
+        RefCountTraceReset => &[],
     }
 }
 
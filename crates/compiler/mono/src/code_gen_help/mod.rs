@@ -81,10 +81,17 @@ pub struct CodeGenHelp<'a> {
     union_refcount: UnionLayout<'a>,
     specializations: Vec<'a, Specialization<'a>>,
     debug_recursion_depth: usize,
+    /// Report values that stop being unique, see `roc run --trace-refcounts`
+    trace_refcounts: bool,
 }
 
 impl<'a> CodeGenHelp<'a> {
-    pub fn new(arena: &'a Bump, target_info: TargetInfo, home: ModuleId) -> Self {
+    pub fn new(
+        arena: &'a Bump,
+        target_info: TargetInfo,
+        home: ModuleId,
+        trace_refcounts: bool,
+    ) -> Self {
         let layout_isize = Layout::isize(target_info);
 
         // Refcount is a boxed isize. TODO: use the new Box layout when dev backends support it
@@ -98,6 +105,7 @@ impl<'a> CodeGenHelp<'a> {
             union_refcount,
             specializations: Vec::with_capacity_in(16, arena),
             debug_recursion_depth: 0,
+            trace_refcounts,
         }
     }
 
@@ -622,9 +622,16 @@ fn modify_refcount<'a>(
     let zig_call_result = root.create_symbol(ident_ids, "zig_call_result");
     match ctx.op {
         HelperOp::Inc => {
+            // The traced version reports the values that stop being unique
+            let op = if root.trace_refcounts {
+                LowLevel::RefCountTraceInc
+            } else {
+                LowLevel::RefCountInc
+            };
+
             let zig_call_expr = Expr::Call(Call {
                 call_type: CallType::LowLevel {
-                    op: LowLevel::RefCountInc,
+                    op,
                     update_mode: UpdateModeId::BACKEND_DUMMY,
                 },
                 arguments: root.arena.alloc([rc_ptr, Symbol::ARG_2]),
@@ -0,0 +1,766 @@
+//! Constant folding on the mono IR.
+//!
+//! Top-level values that are not functions are compiled to zero-argument procedures (thunks),
+//! which are called every time the value is used. This pass evaluates at compile time what
+//! can be evaluated at compile time:
+//!
+//! - arithmetic, comparisons and boolean operations on number and boolean literals
+//! - concatenation of string literals
+//! - field accesses into records that are built from constants
+//! - list literals whose elements are constants
+//! - `Switch`es on a constant condition
+//!
+//! Thunks whose body folds down to a constant value (a literal, or records, tuples and list
+//! literals built from literals) are then inlined at every use site. The backends emit literal
+//! strings and lists as static data, so the value is no longer computed at runtime.
+//!
+//! This pass runs before reset/reuse and refcounting, so those passes see the folded bodies.
+
+use crate::ir::{Call, CallType, Expr, ListLiteralElement, Literal, Proc, ProcLayout, Stmt};
+use crate::layout::{Builtin, InLayout, Layout, LayoutInterner, STLayoutInterner};
+use bumpalo::collections::Vec;
+use bumpalo::Bump;
+use roc_builtins::bitcode::{FloatWidth, IntWidth};
+use roc_collections::all::{MutMap, MutSet};
+use roc_module::low_level::{LowLevel, LowLevelWrapperType};
+use roc_module::symbol::{IdentIds, ModuleId, Symbol};
+use std::cmp::Ordering;
+
+/// Thunks can refer to other thunks, so folding one thunk can make another one constant.
+/// Every round of folding resolves at least one more level of such references; this bounds
+/// the number of rounds for pathologically deep chains.
+const MAX_ROUNDS: usize = 8;
+
+pub fn fold_constants<'a>(
+    arena: &'a Bump,
+    interner: &STLayoutInterner<'a>,
+    home: ModuleId,
+    ident_ids: &mut IdentIds,
+    procs: &mut MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+) {
+    let mut constant_thunks = MutMap::default();
+
+    for _ in 0..MAX_ROUNDS {
+        for proc in procs.values_mut() {
+            let mut env = Env {
+                arena,
+                interner,
+                home,
+                ident_ids,
+                constant_thunks: &constant_thunks,
+                constants: MutMap::default(),
+            };
+
+            proc.body = fold_stmt(&mut env, &proc.body);
+        }
+
+        let mut changed = false;
+
+        for proc in procs.values() {
+            if !proc.args.is_empty() || proc.closure_data_layout.is_some() {
+                continue;
+            }
+
+            let key = (proc.name.name(), proc.ret_layout);
+
+            if constant_thunks.contains_key(&key) {
+                continue;
+            }
+
+            if let Some(body) = constant_body(arena, &proc.body) {
+                constant_thunks.insert(key, body);
+                changed = true;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Constant<'a> {
+    Literal(Literal<'a>, InLayout<'a>),
+    /// A record or tuple whose fields are all constants
+    Struct(&'a [Symbol]),
+    /// A list literal whose elements are all literals
+    List,
+}
+
+/// The body of a thunk that does nothing but build a constant value
+#[derive(Clone, Copy, Debug)]
+struct ConstantBody<'a> {
+    /// The bindings that the returned value is built from, in order
+    bindings: &'a [(Symbol, Expr<'a>, InLayout<'a>)],
+    returned: Symbol,
+}
+
+struct Env<'a, 'r> {
+    arena: &'a Bump,
+    interner: &'r STLayoutInterner<'a>,
+    home: ModuleId,
+    ident_ids: &'r mut IdentIds,
+
+    /// Thunks (by name and return layout) that are known to evaluate to a constant
+    constant_thunks: &'r MutMap<(Symbol, InLayout<'a>), ConstantBody<'a>>,
+
+    /// Symbols in the current procedure that are bound to a constant.
+    /// Symbols are unique within a procedure, so this does not need to be scoped.
+    constants: MutMap<Symbol, Constant<'a>>,
+}
+
+impl<'a, 'r> Env<'a, 'r> {
+    fn literal(&self, symbol: Symbol) -> Option<(Literal<'a>, InLayout<'a>)> {
+        match self.constants.get(&symbol)? {
+            Constant::Literal(literal, layout) => Some((*literal, *layout)),
+            Constant::Struct(_) | Constant::List => None,
+        }
+    }
+
+    fn is_constant(&self, symbol: &Symbol) -> bool {
+        self.constants.contains_key(symbol)
+    }
+
+    fn unique_symbol(&mut self) -> Symbol {
+        let ident_id = self.ident_ids.gen_unique();
+
+        Symbol::new(self.home, ident_id)
+    }
+
+    /// The body of the constant thunk that this expression calls, if any
+    fn constant_thunk_call(&self, expr: &Expr<'a>) -> Option<ConstantBody<'a>> {
+        match expr {
+            Expr::Call(Call {
+                call_type: CallType::ByName {
+                    name, ret_layout, ..
+                },
+                arguments: [],
+            }) => self
+                .constant_thunks
+                .get(&(name.name(), *ret_layout))
+                .copied(),
+            _ => None,
+        }
+    }
+}
+
+fn fold_stmt<'a>(env: &mut Env<'a, '_>, stmt: &Stmt<'a>) -> Stmt<'a> {
+    use Stmt::*;
+
+    let arena = env.arena;
+
+    match stmt {
+        Let(symbol, expr, layout, continuation) => {
+            if let Some(body) = env.constant_thunk_call(expr) {
+                let inlined = inline_constant_body(env, body, *symbol, *continuation);
+
+                return fold_stmt(env, inlined);
+            }
+
+            let expr = fold_expr(env, expr, *layout);
+
+            match &expr {
+                Expr::Literal(literal) => {
+                    env.constants
+                        .insert(*symbol, Constant::Literal(*literal, *layout));
+                }
+                Expr::Struct(fields) if fields.iter().all(|f| env.is_constant(f)) => {
+                    env.constants.insert(*symbol, Constant::Struct(*fields));
+                }
+                Expr::Array { elems, .. }
+                    if elems
+                        .iter()
+                        .all(|elem| matches!(elem, ListLiteralElement::Literal(_))) =>
+                {
+                    env.constants.insert(*symbol, Constant::List);
+                }
+                Expr::EmptyArray => {
+                    env.constants.insert(*symbol, Constant::List);
+                }
+                _ => {}
+            }
+
+            let continuation = fold_stmt(env, continuation);
+
+            Let(*symbol, expr, *layout, arena.alloc(continuation))
+        }
+        Switch {
+            cond_symbol,
+            cond_layout,
+            branches,
+            default_branch,
+            ret_layout,
+        } => {
+            if let Some(value) = switch_value(env, *cond_symbol) {
+                let taken = branches
+                    .iter()
+                    .find(|(label, _, _)| *label == value)
+                    .map(|(_, _, branch)| branch)
+                    .unwrap_or(default_branch.1);
+
+                return fold_stmt(env, taken);
+            }
+
+            let branches = Vec::from_iter_in(
+                branches
+                    .iter()
+                    .map(|(label, info, branch)| (*label, info.clone(), fold_stmt(env, branch))),
+                arena,
+            )
+            .into_bump_slice();
+
+            let default_branch = (
+                default_branch.0.clone(),
+                &*arena.alloc(fold_stmt(env, default_branch.1)),
+            );
+
+            Switch {
+                cond_symbol: *cond_symbol,
+                cond_layout: *cond_layout,
+                branches,
+                default_branch,
+                ret_layout: *ret_layout,
+            }
+        }
+        Join {
+            id,
+            parameters,
+            body,
+            remainder,
+        } => {
+            let body = fold_stmt(env, body);
+            let remainder = fold_stmt(env, remainder);
+
+            Join {
+                id: *id,
+                parameters: *parameters,
+                body: arena.alloc(body),
+                remainder: arena.alloc(remainder),
+            }
+        }
+        Refcounting(modify, continuation) => {
+            let continuation = fold_stmt(env, continuation);
+
+            Refcounting(*modify, arena.alloc(continuation))
+        }
+        Expect {
+            condition,
+            region,
+            lookups,
+            variables,
+            remainder,
+        } => {
+            let remainder = fold_stmt(env, remainder);
+
+            Expect {
+                condition: *condition,
+                region: *region,
+                lookups: *lookups,
+                variables: *variables,
+                remainder: arena.alloc(remainder),
+            }
+        }
+        ExpectFx {
+            condition,
+            region,
+            lookups,
+            variables,
+            remainder,
+        } => {
+            let remainder = fold_stmt(env, remainder);
+
+            ExpectFx {
+                condition: *condition,
+                region: *region,
+                lookups: *lookups,
+                variables: *variables,
+                remainder: arena.alloc(remainder),
+            }
+        }
+        Dbg {
+            symbol,
+            variable,
+            remainder,
+        } => {
+            let remainder = fold_stmt(env, remainder);
+
+            Dbg {
+                symbol: *symbol,
+                variable: *variable,
+                remainder: arena.alloc(remainder),
+            }
+        }
+        Ret(_) | Jump(_, _) | Crash(..) => stmt.clone(),
+    }
+}
+
+fn fold_expr<'a>(env: &Env<'a, '_>, expr: &Expr<'a>, layout: InLayout<'a>) -> Expr<'a> {
+    match expr {
+        Expr::Call(Call {
+            call_type: CallType::LowLevel { op, .. },
+            arguments,
+        }) => match fold_lowlevel(env, *op, arguments, layout) {
+            Some(literal) => Expr::Literal(literal),
+            None => expr.clone(),
+        },
+        Expr::Call(Call {
+            call_type: CallType::ByName { name, .. },
+            arguments,
+        }) => {
+            match LowLevelWrapperType::from_symbol(name.name()) {
+                LowLevelWrapperType::CanBeReplacedBy(op) => {
+                    match fold_lowlevel(env, op, arguments, layout) {
+                        Some(literal) => Expr::Literal(literal),
+                        None => expr.clone(),
+                    }
+                }
+                LowLevelWrapperType::NotALowLevelWrapper => expr.clone(),
+            }
+        }
+        Expr::StructAtIndex {
+            index, structure, ..
+        } => {
+            let field = match env.constants.get(structure) {
+                Some(Constant::Struct(fields)) => fields.get(*index as usize).copied(),
+                _ => None,
+            };
+
+            match field.and_then(|field| env.literal(field)) {
+                Some((literal, _)) => Expr::Literal(literal),
+                None => expr.clone(),
+            }
+        }
+        Expr::Array { elem_layout, elems } => {
+            // string literals are refcounted (as a no-op), so those stay behind their symbol
+            let folded_element = |elem: &ListLiteralElement<'a>| match elem {
+                ListLiteralElement::Symbol(symbol) => match env.literal(*symbol)? {
+                    (Literal::Str(_), _) => None,
+                    (literal, _) => Some(ListLiteralElement::Literal(literal)),
+                },
+                ListLiteralElement::Literal(_) => None,
+            };
+
+            if !elems.iter().any(|elem| folded_element(elem).is_some()) {
+                return expr.clone();
+            }
+
+            let elems = Vec::from_iter_in(
+                elems
+                    .iter()
+                    .map(|elem| folded_element(elem).unwrap_or(*elem)),
+                env.arena,
+            )
+            .into_bump_slice();
+
+            Expr::Array {
+                elem_layout: *elem_layout,
+                elems,
+            }
+        }
+        _ => expr.clone(),
+    }
+}
+
+/// The value a `Switch` on this symbol would compare against its branch labels
+fn switch_value(env: &Env, symbol: Symbol) -> Option<u64> {
+    match env.literal(symbol)? {
+        (Literal::Bool(b), _) => Some(b as u64),
+        (Literal::Byte(b), _) => Some(b as u64),
+        (Literal::Int(bytes), _) => Some(i128::from_ne_bytes(bytes) as u64),
+        _ => None,
+    }
+}
+
+/// Returns the bindings of a thunk body that does nothing but build a constant value and return
+/// it. A constant value is a literal, a record or tuple of constants, or a list literal whose
+/// elements are all literals. Bindings that the returned value is not built from are left out.
+fn constant_body<'a>(arena: &'a Bump, mut stmt: &Stmt<'a>) -> Option<ConstantBody<'a>> {
+    let mut bindings = Vec::new_in(arena);
+
+    let returned = loop {
+        match stmt {
+            Stmt::Let(symbol, expr, layout, continuation) => {
+                let is_constant = match expr {
+                    Expr::Literal(_) | Expr::EmptyArray => true,
+                    Expr::Struct(fields) => fields
+                        .iter()
+                        .all(|field| bindings.iter().any(|(bound, _, _)| bound == field)),
+                    Expr::Array { elems, .. } => elems
+                        .iter()
+                        .all(|elem| matches!(elem, ListLiteralElement::Literal(_))),
+                    _ => false,
+                };
+
+                if !is_constant {
+                    return None;
+                }
+
+                bindings.push((*symbol, expr.clone(), *layout));
+                stmt = continuation;
+            }
+            Stmt::Ret(symbol) => break *symbol,
+            _ => return None,
+        }
+    };
+
+    let mut needed = MutSet::default();
+    needed.insert(returned);
+
+    let mut kept = Vec::new_in(arena);
+
+    for (symbol, expr, layout) in bindings.into_iter().rev() {
+        if needed.remove(&symbol) {
+            if let Expr::Struct(fields) = &expr {
+                needed.extend(fields.iter().copied());
+            }
+
+            kept.push((symbol, expr, layout));
+        }
+    }
+
+    // the returned symbol must be bound in the body itself
+    if !needed.is_empty() {
+        return None;
+    }
+
+    kept.reverse();
+
+    Some(ConstantBody {
+        bindings: kept.into_bump_slice(),
+        returned,
+    })
+}
+
+/// Binds `symbol` to the value that a constant thunk body builds, in front of `continuation`.
+/// A thunk can be used more than once in a procedure, so the other symbols that the body binds
+/// are replaced by fresh ones.
+fn inline_constant_body<'a>(
+    env: &mut Env<'a, '_>,
+    body: ConstantBody<'a>,
+    symbol: Symbol,
+    continuation: &'a Stmt<'a>,
+) -> &'a Stmt<'a> {
+    let mut renamed = MutMap::default();
+
+    for (bound, _, _) in body.bindings {
+        let fresh = if *bound == body.returned {
+            symbol
+        } else {
+            env.unique_symbol()
+        };
+
+        renamed.insert(*bound, fresh);
+    }
+
+    let mut stmt = continuation;
+
+    for (bound, expr, layout) in body.bindings.iter().rev() {
+        let expr = match expr {
+            Expr::Struct(fields) => Expr::Struct(
+                Vec::from_iter_in(fields.iter().map(|field| renamed[field]), env.arena)
+                    .into_bump_slice(),
+            ),
+            // literals and list literals of literals do not refer to other symbols
+            _ => expr.clone(),
+        };
+
+        stmt = env
+            .arena
+            .alloc(Stmt::Let(renamed[bound], expr, *layout, stmt));
+    }
+
+    stmt
+}
+
+/// A number literal, interpreted according to its layout
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Number {
+    Int(i128, IntWidth),
+    U128(u128),
+    Float(f64, FloatWidth),
+    /// the raw representation of a `Dec`, i.e. the value times 10^18
+    Dec(i128),
+}
+
+impl Number {
+    fn from_literal<'a>(
+        interner: &STLayoutInterner<'a>,
+        literal: Literal<'a>,
+        layout: InLayout<'a>,
+    ) -> Option<Self> {
+        match (interner.get(layout), literal) {
+            (Layout::Builtin(Builtin::Int(IntWidth::U128)), Literal::Int(bytes)) => {
+                u128::try_from(i128::from_ne_bytes(bytes))
+                    .ok()
+                    .map(Number::U128)
+            }
+            (Layout::Builtin(Builtin::Int(IntWidth::U128)), Literal::U128(bytes)) => {
+                Some(Number::U128(u128::from_ne_bytes(bytes)))
+            }
+            (Layout::Builtin(Builtin::Int(width)), Literal::Int(bytes)) => {
+                Some(Number::Int(i128::from_ne_bytes(bytes), width))
+            }
+            (Layout::Builtin(Builtin::Float(width)), Literal::Float(float)) => {
+                Some(Number::Float(float, width))
+            }
+            (Layout::Builtin(Builtin::Decimal), Literal::Decimal(bytes)) => {
+                Some(Number::Dec(i128::from_ne_bytes(bytes)))
+            }
+            _ => None,
+        }
+    }
+
+    fn to_literal(self) -> Literal<'static> {
+        match self {
+            Number::Int(n, _) => Literal::Int(n.to_ne_bytes()),
+            Number::U128(n) => Literal::U128(n.to_ne_bytes()),
+            Number::Float(f, _) => Literal::Float(f),
+            Number::Dec(n) => Literal::Decimal(n.to_ne_bytes()),
+        }
+    }
+
+    fn compare(self, other: Self) -> Option<Ordering> {
+        match (self, other) {
+            (Number::Int(a, _), Number::Int(b, _)) => Some(a.cmp(&b)),
+            (Number::U128(a), Number::U128(b)) => Some(a.cmp(&b)),
+            (Number::Float(a, _), Number::Float(b, _)) => a.partial_cmp(&b),
+            (Number::Dec(a), Number::Dec(b)) => Some(a.cmp(&b)),
+            _ => None,
+        }
+    }
+}
+
+/// Truncates a value to the given width, then sign- or zero-extends it back to an i128
+fn wrap_to_width(value: i128, width: IntWidth) -> i128 {
+    let shift = 128 - 8 * width.stack_size();
+
+    if shift == 0 {
+        value
+    } else if width.is_signed() {
+        (value << shift) >> shift
+    } else {
+        (((value as u128) << shift) >> shift) as i128
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Arith {
+    Add,
+    Sub,
+    Mul,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Overflow {
+    /// overflow crashes at runtime, so an overflowing operation must not be folded
+    Crash,
+    Wrap,
+}
+
+fn fold_arith(op: Arith, overflow: Overflow, a: Number, b: Number) -> Option<Number> {
+    use Arith::*;
+
+    match (a, b) {
+        (Number::Int(a, width), Number::Int(b, _)) => {
+            let result = match overflow {
+                Overflow::Crash => {
+                    let wide = match op {
+                        Add => a.checked_add(b)?,
+                        Sub => a.checked_sub(b)?,
+                        Mul => a.checked_mul(b)?,
+                    };
+
+                    if wrap_to_width(wide, width) != wide {
+                        return None;
+                    }
+
+                    wide
+                }
+                Overflow::Wrap => {
+                    let wide = match op {
+                        Add => a.wrapping_add(b),
+                        Sub => a.wrapping_sub(b),
+                        Mul => a.wrapping_mul(b),
+                    };
+
+                    wrap_to_width(wide, width)
+                }
+            };
+
+            Some(Number::Int(result, width))
+        }
+        (Number::U128(a), Number::U128(b)) => {
+            let result = match (overflow, op) {
+                (Overflow::Crash, Add) => a.checked_add(b)?,
+                (Overflow::Crash, Sub) => a.checked_sub(b)?,
+                (Overflow::Crash, Mul) => a.checked_mul(b)?,
+                (Overflow::Wrap, Add) => a.wrapping_add(b),
+                (Overflow::Wrap, Sub) => a.wrapping_sub(b),
+                (Overflow::Wrap, Mul) => a.wrapping_mul(b),
+            };
+
+            Some(Number::U128(result))
+        }
+        (Number::Float(a, width), Number::Float(b, _)) => {
+            let result = match width {
+                FloatWidth::F32 => {
+                    let (a, b) = (a as f32, b as f32);
+
+                    (match op {
+                        Add => a + b,
+                        Sub => a - b,
+                        Mul => a * b,
+                    }) as f64
+                }
+                FloatWidth::F64 => match op {
+                    Add => a + b,
+                    Sub => a - b,
+                    Mul => a * b,
+                },
+            };
+
+            Some(Number::Float(result, width))
+        }
+        (Number::Dec(a), Number::Dec(b)) => {
+            // Dec multiplication needs rounding; leave it to the runtime
+            let result = match op {
+                Add => a.checked_add(b)?,
+                Sub => a.checked_sub(b)?,
+                Mul => return None,
+            };
+
+            Some(Number::Dec(result))
+        }
+        _ => None,
+    }
+}
+
+fn fold_bitwise(op: LowLevel, a: Number, b: Number) -> Option<Number> {
+    let apply = |a: u128, b: u128| match op {
+        LowLevel::NumBitwiseAnd => a & b,
+        LowLevel::NumBitwiseOr => a | b,
+        LowLevel::NumBitwiseXor => a ^ b,
+        _ => unreachable!(),
+    };
+
+    match (a, b) {
+        (Number::Int(a, width), Number::Int(b, _)) => {
+            let result = apply(a as u128, b as u128) as i128;
+
+            Some(Number::Int(wrap_to_width(result, width), width))
+        }
+        (Number::U128(a), Number::U128(b)) => Some(Number::U128(apply(a, b))),
+        _ => None,
+    }
+}
+
+fn fold_neg(a: Number) -> Option<Number> {
+    match a {
+        Number::Int(n, width) if width.is_signed() => {
+            let result = n.checked_neg()?;
+
+            if wrap_to_width(result, width) != result {
+                return None;
+            }
+
+            Some(Number::Int(result, width))
+        }
+        Number::Float(f, width) => Some(Number::Float(-f, width)),
+        Number::Dec(n) => Some(Number::Dec(n.checked_neg()?)),
+        _ => None,
+    }
+}
+
+fn fold_lowlevel<'a>(
+    env: &Env<'a, '_>,
+    op: LowLevel,
+    arguments: &[Symbol],
+    ret_layout: InLayout<'a>,
+) -> Option<Literal<'a>> {
+    use LowLevel::*;
+
+    let mut literals = Vec::with_capacity_in(arguments.len(), env.arena);
+    for argument in arguments {
+        literals.push(env.literal(*argument)?);
+    }
+
+    let number = |index: usize| -> Option<Number> {
+        let (literal, layout) = literals[index];
+        Number::from_literal(env.interner, literal, layout)
+    };
+
+    let result = match op {
+        NumAdd | NumSub | NumMul | NumAddWrap | NumSubWrap | NumMulWrap => {
+            let arith = match op {
+                NumAdd | NumAddWrap => Arith::Add,
+                NumSub | NumSubWrap => Arith::Sub,
+                _ => Arith::Mul,
+            };
+            let overflow = match op {
+                NumAddWrap | NumSubWrap | NumMulWrap => Overflow::Wrap,
+                _ => Overflow::Crash,
+            };
+
+            fold_arith(arith, overflow, number(0)?, number(1)?)?.to_literal()
+        }
+        NumBitwiseAnd | NumBitwiseOr | NumBitwiseXor => {
+            fold_bitwise(op, number(0)?, number(1)?)?.to_literal()
+        }
+        NumNeg => fold_neg(number(0)?)?.to_literal(),
+        NumGt | NumGte | NumLt | NumLte => {
+            let ordering = number(0)?.compare(number(1)?)?;
+
+            Literal::Bool(match op {
+                NumGt => ordering == Ordering::Greater,
+                NumGte => ordering != Ordering::Less,
+                NumLt => ordering == Ordering::Less,
+                _ => ordering != Ordering::Greater,
+            })
+        }
+        Eq | NotEq => {
+            let equal = match (literals[0].0, literals[1].0) {
+                (Literal::Str(a), Literal::Str(b)) => a == b,
+                (Literal::Bool(a), Literal::Bool(b)) => a == b,
+                (Literal::Byte(a), Literal::Byte(b)) => a == b,
+                _ => number(0)?.compare(number(1)?)? == Ordering::Equal,
+            };
+
+            Literal::Bool(if op == Eq { equal } else { !equal })
+        }
+        And | Or => match (literals[0].0, literals[1].0) {
+            (Literal::Bool(a), Literal::Bool(b)) => {
+                Literal::Bool(if op == And { a && b } else { a || b })
+            }
+            _ => return None,
+        },
+        Not => match literals[0].0 {
+            Literal::Bool(b) => Literal::Bool(!b),
+            _ => return None,
+        },
+        StrConcat => match (literals[0].0, literals[1].0) {
+            (Literal::Str(a), Literal::Str(b)) => {
+                // every backend stores string literals that do not fit in a small string in
+                // its data section
+                let mut concatenated =
+                    bumpalo::collections::String::with_capacity_in(a.len() + b.len(), env.arena);
+                concatenated.push_str(a);
+                concatenated.push_str(b);
+
+                Literal::Str(concatenated.into_bump_str())
+            }
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    // sanity check: the folded literal must be representable in the layout of the result
+    match (env.interner.get(ret_layout), result) {
+        (Layout::Builtin(Builtin::Bool), Literal::Bool(_))
+        | (Layout::Builtin(Builtin::Str), Literal::Str(_))
+        | (Layout::Builtin(Builtin::Int(_)), Literal::Int(_) | Literal::U128(_))
+        | (Layout::Builtin(Builtin::Float(_)), Literal::Float(_))
+        | (Layout::Builtin(Builtin::Decimal), Literal::Decimal(_)) => Some(result),
+        _ => None,
+    }
+}
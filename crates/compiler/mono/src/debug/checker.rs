@@ -416,6 +416,16 @@ impl<'a, 'r> Ctx<'a, 'r> {
                 union_layout,
                 index,
             } => self.check_union_at_index(structure, union_layout, tag_id, index),
+            &Expr::UnionFieldPtrAtIndex {
+                structure,
+                tag_id,
+                union_layout,
+                index,
+            } => {
+                self.check_union_at_index(structure, union_layout, tag_id, index);
+                // TODO: the address is a usize, but we don't know the target here
+                None
+            }
             Expr::Array { elem_layout, elems } => {
                 for elem in elems.iter() {
                     match elem {
@@ -468,6 +478,7 @@ impl<'a, 'r> Ctx<'a, 'r> {
                 self.check_sym_exists(symbol);
                 None
             }
+            Expr::NullPointer => None,
             Expr::RuntimeErrorFunction(_) => None,
         }
     }
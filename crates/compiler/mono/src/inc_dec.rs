@@ -213,10 +213,13 @@ pub fn occurring_variables_expr(expr: &Expr<'_>, result: &mut MutSet<Symbol>) {
             result.insert(*symbol);
         }
 
-        EmptyArray | RuntimeErrorFunction(_) | Literal(_) => {}
+        EmptyArray | NullPointer | RuntimeErrorFunction(_) | Literal(_) => {}
 
         GetTagId {
             structure: symbol, ..
+        }
+        | UnionFieldPtrAtIndex {
+            structure: symbol, ..
         } => {
             result.insert(*symbol);
         }
@@ -899,7 +902,7 @@ impl<'a, 'i> Context<'a, 'i> {
                 self.arena.alloc(Stmt::Let(z, v, l, b))
             }
 
-            GetTagId { structure: x, .. } => {
+            GetTagId { structure: x, .. } | UnionFieldPtrAtIndex { structure: x, .. } => {
                 let b = self.add_dec_if_needed(x, b, b_live_vars);
                 let info_x = self.get_var_info(x);
                 let b = if info_x.consume {
@@ -945,7 +948,7 @@ impl<'a, 'i> Context<'a, 'i> {
                 self.arena.alloc(Stmt::Let(z, v, l, b))
             }
 
-            EmptyArray | Literal(_) | Reset { .. } | RuntimeErrorFunction(_) => {
+            EmptyArray | NullPointer | Literal(_) | Reset { .. } | RuntimeErrorFunction(_) => {
                 // EmptyArray is always stack-allocated function pointers are persistent
                 self.arena.alloc(Stmt::Let(z, v, l, b))
             }
@@ -956,7 +959,8 @@ impl<'a, 'i> Context<'a, 'i> {
 
     fn update_var_info(&self, symbol: Symbol, layout: &InLayout<'a>, expr: &Expr<'a>) -> Self {
         // is this value a constant? TODO do function pointers also fall into this category?
-        let persistent = false;
+        // A null pointer is a placeholder that is overwritten before it is ever used.
+        let persistent = matches!(expr, Expr::NullPointer);
 
         // must this value be consumed?
         let consume = consume_expr(&self.vars, expr);
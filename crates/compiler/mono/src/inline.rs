@@ -0,0 +1,693 @@
+//! Inlining of small procedures on the mono IR.
+//!
+//! The LLVM backend gets inlining from LLVM, but the dev and wasm backends emit every call to a
+//! `Proc` as a real call. Many procedures are tiny: wrappers around a lowlevel (`Num.add`),
+//! record accessors, and the closures that a lambda set dispatches to. This pass replaces calls
+//! to such procedures with a copy of their body, so every backend benefits.
+//!
+//! A call `let x = f a b; rest` becomes
+//!
+//! ```text
+//! join jp x =
+//!     rest
+//! in
+//!     <body of f, with its arguments bound to a and b, and every `ret y` replaced by `jump jp y`>
+//! ```
+//!
+//! and a tail call `let x = f a b; ret x` is replaced by the body of `f` directly.
+//!
+//! This pass runs before reset/reuse and refcounting, so those passes see through the inlined
+//! bodies. In particular, an increment in the caller can now cancel against a decrement that
+//! used to be hidden in the callee.
+
+use crate::borrow::Ownership;
+use crate::ir::{
+    BranchInfo, Call, CallSpecId, CallType, Expr, JoinPointId, ListLiteralElement, ModifyRc, Param,
+    Proc, ProcLayout, SelfRecursive, Stmt, UpdateModeIds,
+};
+use crate::layout::InLayout;
+use bumpalo::collections::Vec;
+use bumpalo::Bump;
+use roc_collections::all::MutMap;
+use roc_module::symbol::{IdentIds, ModuleId, Symbol};
+
+/// Procedures with at most this many statements are inlined at every call site.
+const INLINE_THRESHOLD: usize = 8;
+
+/// Inlined bodies can themselves contain calls to small procedures (e.g. a wrapper around a
+/// wrapper). Every round inlines one more level of such calls; this bounds the code growth.
+const MAX_ROUNDS: usize = 4;
+
+pub fn inline_procs<'a, 'i>(
+    arena: &'a Bump,
+    home: ModuleId,
+    ident_ids: &'i mut IdentIds,
+    update_mode_ids: &'i mut UpdateModeIds,
+    procs: &mut MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+) {
+    // Specialization ids must be unique within a procedure. Inlining the same procedure twice
+    // into the same caller would duplicate the ids of its calls, so inlined calls get fresh ids
+    // that are larger than any id in use.
+    let mut next_call_spec_id = procs
+        .values()
+        .filter_map(|proc| max_call_spec_id(&proc.body))
+        .max()
+        .unwrap_or(CallSpecId::BACKEND_DUMMY)
+        .next();
+
+    for _ in 0..MAX_ROUNDS {
+        let candidates: MutMap<_, _> = procs
+            .iter()
+            .filter(|(_, proc)| is_inline_candidate(proc))
+            .map(|(key, proc)| {
+                let candidate = Candidate {
+                    args: proc.args,
+                    body: proc.body.clone(),
+                };
+
+                (*key, candidate)
+            })
+            .collect();
+
+        if candidates.is_empty() {
+            break;
+        }
+
+        let mut changed = false;
+
+        for proc in procs.values_mut() {
+            let mut env = Env {
+                arena,
+                home,
+                ident_ids,
+                update_mode_ids,
+                next_call_spec_id: &mut next_call_spec_id,
+                candidates: &candidates,
+                caller: proc.name.name(),
+                changed: false,
+            };
+
+            let new_body = inline_stmt(&mut env, &proc.body);
+
+            if env.changed {
+                proc.body = new_body;
+                changed = true;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+}
+
+struct Candidate<'a> {
+    args: &'a [(InLayout<'a>, Symbol)],
+    body: Stmt<'a>,
+}
+
+struct Env<'a, 'r> {
+    arena: &'a Bump,
+    home: ModuleId,
+    ident_ids: &'r mut IdentIds,
+    update_mode_ids: &'r mut UpdateModeIds,
+    next_call_spec_id: &'r mut CallSpecId,
+
+    /// Procedures that are small enough to be inlined, with their body at the start of the round
+    candidates: &'r MutMap<(Symbol, ProcLayout<'a>), Candidate<'a>>,
+
+    /// The procedure we are inlining into; it is never inlined into itself
+    caller: Symbol,
+
+    changed: bool,
+}
+
+impl<'a, 'r> Env<'a, 'r> {
+    fn unique_symbol(&mut self) -> Symbol {
+        let ident_id = self.ident_ids.gen_unique();
+
+        Symbol::new(self.home, ident_id)
+    }
+
+    fn next_call_spec_id(&mut self) -> CallSpecId {
+        let id = *self.next_call_spec_id;
+        *self.next_call_spec_id = id.next();
+        id
+    }
+}
+
+fn is_inline_candidate(proc: &Proc) -> bool {
+    let mut budget = INLINE_THRESHOLD;
+
+    fits_in_budget(&proc.body, proc, &mut budget)
+}
+
+/// Whether the statement can be inlined, and has at most `budget` statements
+fn fits_in_budget(stmt: &Stmt, proc: &Proc, budget: &mut usize) -> bool {
+    use Stmt::*;
+
+    if *budget == 0 {
+        return false;
+    }
+
+    *budget -= 1;
+
+    match stmt {
+        Let(_, expr, _, continuation) => {
+            let inlinable = match expr {
+                Expr::Call(Call {
+                    call_type: CallType::ByName { name, .. },
+                    ..
+                }) => name.name() != proc.name.name(),
+                // the passed function of a higher-order lowlevel is specialized by alias
+                // analysis per call site; keep those calls where they are
+                Expr::Call(Call {
+                    call_type: CallType::HigherOrder(_),
+                    ..
+                }) => false,
+                Expr::Reset { .. } | Expr::Reuse { .. } => false,
+                _ => true,
+            };
+
+            inlinable && fits_in_budget(continuation, proc, budget)
+        }
+        Switch {
+            branches,
+            default_branch,
+            ..
+        } => {
+            branches
+                .iter()
+                .all(|(_, _, branch)| fits_in_budget(branch, proc, budget))
+                && fits_in_budget(default_branch.1, proc, budget)
+        }
+        Join {
+            body, remainder, ..
+        } => fits_in_budget(body, proc, budget) && fits_in_budget(remainder, proc, budget),
+        // closures are marked as self-recursive conservatively; only a tail call (which became a
+        // jump to the entry of the procedure) makes them actually recursive
+        Jump(id, _) => match proc.is_self_recursive {
+            SelfRecursive::SelfRecursive(entry) => *id != entry,
+            SelfRecursive::NotSelfRecursive => true,
+        },
+        Ret(_) | Crash(_, _) => true,
+        // expects and dbgs report the source region and types of the procedure they are in
+        Refcounting(_, _) | Expect { .. } | ExpectFx { .. } | Dbg { .. } => false,
+    }
+}
+
+fn inline_stmt<'a>(env: &mut Env<'a, '_>, stmt: &Stmt<'a>) -> Stmt<'a> {
+    use Stmt::*;
+
+    let arena = env.arena;
+
+    match stmt {
+        Let(symbol, expr, layout, continuation) => {
+            let continuation = inline_stmt(env, continuation);
+            let candidates = env.candidates;
+
+            let candidate = match expr {
+                Expr::Call(Call {
+                    call_type:
+                        CallType::ByName {
+                            name,
+                            ret_layout,
+                            arg_layouts,
+                            ..
+                        },
+                    arguments,
+                }) if name.name() != env.caller => {
+                    let proc_layout = ProcLayout {
+                        arguments: arg_layouts,
+                        result: *ret_layout,
+                        niche: name.niche(),
+                    };
+
+                    candidates
+                        .get(&(name.name(), proc_layout))
+                        .map(|candidate| (candidate, *arguments))
+                }
+                _ => None,
+            };
+
+            match candidate {
+                None => Let(*symbol, expr.clone(), *layout, arena.alloc(continuation)),
+                Some((candidate, arguments)) => {
+                    env.changed = true;
+
+                    let mut substitution = Substitution {
+                        symbols: MutMap::default(),
+                        join_points: MutMap::default(),
+                        ret: Return::Tail,
+                    };
+
+                    for ((_, param), argument) in candidate.args.iter().zip(arguments) {
+                        substitution.symbols.insert(*param, *argument);
+                    }
+
+                    if returns_last_binding(&candidate.body) {
+                        substitution.ret = Return::Bind {
+                            symbol: *symbol,
+                            continuation: arena.alloc(continuation),
+                        };
+
+                        return substitution.copy_stmt(env, &candidate.body);
+                    }
+
+                    match continuation {
+                        Ret(returned) if returned == *symbol => {
+                            substitution.copy_stmt(env, &candidate.body)
+                        }
+                        _ => {
+                            let id = JoinPointId(env.unique_symbol());
+                            substitution.ret = Return::Jump(id);
+
+                            let remainder = substitution.copy_stmt(env, &candidate.body);
+                            let param = Param {
+                                symbol: *symbol,
+                                ownership: Ownership::Owned,
+                                layout: *layout,
+                            };
+
+                            Join {
+                                id,
+                                parameters: arena.alloc([param]),
+                                body: arena.alloc(continuation),
+                                remainder: arena.alloc(remainder),
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Switch {
+            cond_symbol,
+            cond_layout,
+            branches,
+            default_branch,
+            ret_layout,
+        } => {
+            let branches = Vec::from_iter_in(
+                branches
+                    .iter()
+                    .map(|(label, info, branch)| (*label, info.clone(), inline_stmt(env, branch))),
+                arena,
+            );
+
+            let default_branch = (
+                default_branch.0.clone(),
+                &*arena.alloc(inline_stmt(env, default_branch.1)),
+            );
+
+            Switch {
+                cond_symbol: *cond_symbol,
+                cond_layout: *cond_layout,
+                branches: branches.into_bump_slice(),
+                default_branch,
+                ret_layout: *ret_layout,
+            }
+        }
+        Join {
+            id,
+            parameters,
+            body,
+            remainder,
+        } => Join {
+            id: *id,
+            parameters,
+            body: arena.alloc(inline_stmt(env, body)),
+            remainder: arena.alloc(inline_stmt(env, remainder)),
+        },
+        Refcounting(modify, continuation) => {
+            Refcounting(*modify, arena.alloc(inline_stmt(env, continuation)))
+        }
+        Expect {
+            condition,
+            region,
+            lookups,
+            variables,
+            remainder,
+        } => Expect {
+            condition: *condition,
+            region: *region,
+            lookups,
+            variables,
+            remainder: arena.alloc(inline_stmt(env, remainder)),
+        },
+        ExpectFx {
+            condition,
+            region,
+            lookups,
+            variables,
+            remainder,
+        } => ExpectFx {
+            condition: *condition,
+            region: *region,
+            lookups,
+            variables,
+            remainder: arena.alloc(inline_stmt(env, remainder)),
+        },
+        Dbg {
+            symbol,
+            variable,
+            remainder,
+        } => Dbg {
+            symbol: *symbol,
+            variable: *variable,
+            remainder: arena.alloc(inline_stmt(env, remainder)),
+        },
+        Ret(_) | Jump(_, _) | Crash(_, _) => stmt.clone(),
+    }
+}
+
+/// Whether the statement is a sequence of `let`s that returns the symbol bound by the last one
+fn returns_last_binding(stmt: &Stmt) -> bool {
+    match stmt {
+        Stmt::Let(symbol, _, _, Stmt::Ret(returned)) => symbol == returned,
+        Stmt::Let(_, _, _, continuation) => returns_last_binding(continuation),
+        _ => false,
+    }
+}
+
+/// What an inlined `ret` turns into
+#[derive(Clone, Copy)]
+enum Return<'a> {
+    /// The body is inlined in tail position, so a `ret` stays a `ret`
+    Tail,
+    /// A `ret` jumps to the join point of the rest of the caller
+    Jump(JoinPointId),
+    /// The body returns the symbol bound by its last `let`; that `let` binds the caller's symbol
+    /// instead, and is followed by the rest of the caller
+    Bind {
+        symbol: Symbol,
+        continuation: &'a Stmt<'a>,
+    },
+}
+
+/// Renames the symbols and join points of an inlined body, so that they are unique in the caller
+struct Substitution<'a> {
+    symbols: MutMap<Symbol, Symbol>,
+    join_points: MutMap<JoinPointId, JoinPointId>,
+    ret: Return<'a>,
+}
+
+impl<'a> Substitution<'a> {
+    fn get(&self, symbol: Symbol) -> Symbol {
+        self.symbols.get(&symbol).copied().unwrap_or(symbol)
+    }
+
+    fn get_slice(&self, arena: &'a Bump, symbols: &[Symbol]) -> &'a [Symbol] {
+        Vec::from_iter_in(symbols.iter().map(|s| self.get(*s)), arena).into_bump_slice()
+    }
+
+    fn bind(&mut self, env: &mut Env, symbol: Symbol) -> Symbol {
+        let fresh = env.unique_symbol();
+        self.symbols.insert(symbol, fresh);
+        fresh
+    }
+
+    fn copy_stmt(&mut self, env: &mut Env<'a, '_>, stmt: &Stmt<'a>) -> Stmt<'a> {
+        use Stmt::*;
+
+        let arena = env.arena;
+
+        match stmt {
+            Let(symbol, expr, layout, continuation) => {
+                let expr = self.copy_expr(env, expr);
+
+                if let (
+                    Return::Bind {
+                        symbol,
+                        continuation: rest,
+                    },
+                    Ret(_),
+                ) = (self.ret, continuation)
+                {
+                    return Let(symbol, expr, *layout, rest);
+                }
+
+                let symbol = self.bind(env, *symbol);
+                let continuation = self.copy_stmt(env, continuation);
+
+                Let(symbol, expr, *layout, arena.alloc(continuation))
+            }
+            Switch {
+                cond_symbol,
+                cond_layout,
+                branches,
+                default_branch,
+                ret_layout,
+            } => {
+                let branches = Vec::from_iter_in(
+                    branches.iter().map(|(label, info, branch)| {
+                        (
+                            *label,
+                            self.copy_branch_info(info),
+                            self.copy_stmt(env, branch),
+                        )
+                    }),
+                    arena,
+                );
+
+                let default_branch = (
+                    self.copy_branch_info(&default_branch.0),
+                    &*arena.alloc(self.copy_stmt(env, default_branch.1)),
+                );
+
+                Switch {
+                    cond_symbol: self.get(*cond_symbol),
+                    cond_layout: *cond_layout,
+                    branches: branches.into_bump_slice(),
+                    default_branch,
+                    ret_layout: *ret_layout,
+                }
+            }
+            Ret(symbol) => match self.ret {
+                Return::Tail => Ret(self.get(*symbol)),
+                Return::Jump(id) => Jump(id, arena.alloc([self.get(*symbol)])),
+                Return::Bind { .. } => unreachable!("the last `let` binds the returned symbol"),
+            },
+            Join {
+                id,
+                parameters,
+                body,
+                remainder,
+            } => {
+                let new_id = JoinPointId(env.unique_symbol());
+                self.join_points.insert(*id, new_id);
+
+                let parameters = Vec::from_iter_in(
+                    parameters.iter().map(|param| Param {
+                        symbol: self.bind(env, param.symbol),
+                        ..*param
+                    }),
+                    arena,
+                );
+
+                Join {
+                    id: new_id,
+                    parameters: parameters.into_bump_slice(),
+                    body: arena.alloc(self.copy_stmt(env, body)),
+                    remainder: arena.alloc(self.copy_stmt(env, remainder)),
+                }
+            }
+            Jump(id, arguments) => {
+                let id = self.join_points.get(id).copied().unwrap_or(*id);
+
+                Jump(id, self.get_slice(arena, arguments))
+            }
+            Crash(symbol, tag) => Crash(self.get(*symbol), *tag),
+            Refcounting(modify, continuation) => {
+                let modify = match modify {
+                    ModifyRc::Inc(symbol, n) => ModifyRc::Inc(self.get(*symbol), *n),
+                    ModifyRc::Dec(symbol) => ModifyRc::Dec(self.get(*symbol)),
+                    ModifyRc::DecRef(symbol) => ModifyRc::DecRef(self.get(*symbol)),
+                };
+
+                Refcounting(modify, arena.alloc(self.copy_stmt(env, continuation)))
+            }
+            Expect { .. } | ExpectFx { .. } | Dbg { .. } => {
+                unreachable!("procedures with expects or dbgs are not inlined")
+            }
+        }
+    }
+
+    fn copy_branch_info(&self, info: &BranchInfo<'a>) -> BranchInfo<'a> {
+        match info {
+            BranchInfo::None => BranchInfo::None,
+            BranchInfo::Constructor {
+                scrutinee,
+                layout,
+                tag_id,
+            } => BranchInfo::Constructor {
+                scrutinee: self.get(*scrutinee),
+                layout: *layout,
+                tag_id: *tag_id,
+            },
+        }
+    }
+
+    fn copy_expr(&self, env: &mut Env<'a, '_>, expr: &Expr<'a>) -> Expr<'a> {
+        use Expr::*;
+
+        let arena = env.arena;
+
+        match expr {
+            Literal(_) | EmptyArray | NullPointer | RuntimeErrorFunction(_) => expr.clone(),
+            Call(call) => {
+                let call_type = match &call.call_type {
+                    CallType::ByName {
+                        name,
+                        ret_layout,
+                        arg_layouts,
+                        ..
+                    } => CallType::ByName {
+                        name: *name,
+                        ret_layout: *ret_layout,
+                        arg_layouts,
+                        specialization_id: env.next_call_spec_id(),
+                    },
+                    CallType::LowLevel { op, .. } => CallType::LowLevel {
+                        op: *op,
+                        update_mode: env.update_mode_ids.next_id(),
+                    },
+                    CallType::Foreign { .. } => call.call_type.clone(),
+                    CallType::HigherOrder(_) => {
+                        unreachable!("procedures with higher-order lowlevels are not inlined")
+                    }
+                };
+
+                Call(crate::ir::Call {
+                    call_type,
+                    arguments: self.get_slice(arena, call.arguments),
+                })
+            }
+            Tag {
+                tag_layout,
+                tag_id,
+                arguments,
+            } => Tag {
+                tag_layout: *tag_layout,
+                tag_id: *tag_id,
+                arguments: self.get_slice(arena, arguments),
+            },
+            Struct(fields) => Struct(self.get_slice(arena, fields)),
+            StructAtIndex {
+                index,
+                field_layouts,
+                structure,
+            } => StructAtIndex {
+                index: *index,
+                field_layouts,
+                structure: self.get(*structure),
+            },
+            GetTagId {
+                structure,
+                union_layout,
+            } => GetTagId {
+                structure: self.get(*structure),
+                union_layout: *union_layout,
+            },
+            UnionAtIndex {
+                structure,
+                tag_id,
+                union_layout,
+                index,
+            } => UnionAtIndex {
+                structure: self.get(*structure),
+                tag_id: *tag_id,
+                union_layout: *union_layout,
+                index: *index,
+            },
+            UnionFieldPtrAtIndex {
+                structure,
+                tag_id,
+                union_layout,
+                index,
+            } => UnionFieldPtrAtIndex {
+                structure: self.get(*structure),
+                tag_id: *tag_id,
+                union_layout: *union_layout,
+                index: *index,
+            },
+            Array { elem_layout, elems } => {
+                let elems = Vec::from_iter_in(
+                    elems.iter().map(|elem| match elem {
+                        ListLiteralElement::Literal(_) => *elem,
+                        ListLiteralElement::Symbol(symbol) => {
+                            ListLiteralElement::Symbol(self.get(*symbol))
+                        }
+                    }),
+                    arena,
+                );
+
+                Array {
+                    elem_layout: *elem_layout,
+                    elems: elems.into_bump_slice(),
+                }
+            }
+            ExprBox { symbol } => ExprBox {
+                symbol: self.get(*symbol),
+            },
+            ExprUnbox { symbol } => ExprUnbox {
+                symbol: self.get(*symbol),
+            },
+            Reset { .. } | Reuse { .. } => {
+                unreachable!("inlining runs before reset/reuse insertion")
+            }
+        }
+    }
+}
+
+/// The largest specialization id of the calls in this statement
+fn max_call_spec_id(stmt: &Stmt) -> Option<CallSpecId> {
+    use Stmt::*;
+
+    let mut max = None;
+    let mut stack = vec![stmt];
+
+    while let Some(stmt) = stack.pop() {
+        match stmt {
+            Let(_, expr, _, continuation) => {
+                let id = match expr {
+                    Expr::Call(Call {
+                        call_type:
+                            CallType::ByName {
+                                specialization_id, ..
+                            },
+                        ..
+                    }) => Some(*specialization_id),
+                    Expr::Call(Call {
+                        call_type: CallType::HigherOrder(higher_order),
+                        ..
+                    }) => Some(higher_order.passed_function.specialization_id),
+                    _ => None,
+                };
+
+                max = max.max(id);
+                stack.push(continuation);
+            }
+            Switch {
+                branches,
+                default_branch,
+                ..
+            } => {
+                stack.extend(branches.iter().map(|(_, _, branch)| branch));
+                stack.push(default_branch.1);
+            }
+            Join {
+                body, remainder, ..
+            } => {
+                stack.push(body);
+                stack.push(remainder);
+            }
+            Refcounting(_, continuation) => stack.push(continuation),
+            Expect { remainder, .. } | ExpectFx { remainder, .. } | Dbg { remainder, .. } => {
+                stack.push(remainder)
+            }
+            Ret(_) | Jump(_, _) | Crash(_, _) => {}
+        }
+    }
+
+    max
+}
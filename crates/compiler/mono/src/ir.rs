@@ -25,11 +25,12 @@ use roc_error_macros::{internal_error, todo_abilities};
 use roc_exhaustive::{Ctor, CtorName, ListArity, RenderAs, TagId};
 use roc_late_solve::storage::{ExternalModuleStorage, ExternalModuleStorageSnapshot};
 use roc_late_solve::{resolve_ability_specialization, AbilitiesView, Resolved, UnificationFailed};
+use roc_module::called_via::CalledVia;
 use roc_module::ident::{ForeignSymbol, Lowercase, TagName};
 use roc_module::low_level::LowLevel;
 use roc_module::symbol::{IdentIds, ModuleId, Symbol};
 use roc_problem::can::{RuntimeError, ShadowKind};
-use roc_region::all::{Loc, Region};
+use roc_region::all::{LineInfo, Loc, Region};
 use roc_std::RocDec;
 use roc_target::TargetInfo;
 use roc_types::subs::{
@@ -394,6 +395,26 @@ impl<'a> Proc<'a> {
         String::from_utf8(w).unwrap()
     }
 
+    pub fn fold_constants(
+        arena: &'a Bump,
+        layout_interner: &STLayoutInterner<'a>,
+        home: ModuleId,
+        ident_ids: &mut IdentIds,
+        procs: &mut MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+    ) {
+        crate::const_fold::fold_constants(arena, layout_interner, home, ident_ids, procs);
+    }
+
+    pub fn inline_small_procs<'i>(
+        arena: &'a Bump,
+        home: ModuleId,
+        ident_ids: &'i mut IdentIds,
+        update_mode_ids: &'i mut UpdateModeIds,
+        procs: &mut MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+    ) {
+        crate::inline::inline_procs(arena, home, ident_ids, update_mode_ids, procs);
+    }
+
     pub fn insert_refcount_operations<'i>(
         arena: &'a Bump,
         layout_interner: &'i STLayoutInterner<'a>,
@@ -451,7 +472,7 @@ impl<'a> Proc<'a> {
         use self::SelfRecursive::*;
         if let SelfRecursive(id) = self.is_self_recursive {
             let transformed = crate::tail_recursion::make_tail_recursive(
-                env.arena,
+                env,
                 id,
                 self.name,
                 self.body.clone(),
@@ -1422,6 +1443,38 @@ impl<'a> Specializations<'a> {
     }
 }
 
+/// What we need to describe a call site in the home module, for `--trace-refcounts`
+#[derive(Debug)]
+pub struct RefcountTrace<'a> {
+    pub filename: String,
+    pub src: &'a str,
+    pub line_info: LineInfo,
+}
+
+impl<'a> RefcountTrace<'a> {
+    /// e.g. "List.set at Main.roc:12:9"
+    fn describe_call(&self, callee_region: Region) -> String {
+        let start = callee_region.start().offset as usize;
+        let end = callee_region.end().offset as usize;
+        let callee = self.src.get(start..end).unwrap_or("builtin");
+
+        self.describe(callee, callee_region)
+    }
+
+    /// e.g. "record update at Main.roc:12:9"
+    fn describe(&self, operation: &str, region: Region) -> String {
+        let position = self.line_info.convert_pos(region.start());
+
+        format!(
+            "{} at {}:{}:{}",
+            operation,
+            self.filename,
+            position.line + 1,
+            position.column + 1
+        )
+    }
+}
+
 pub struct Env<'a, 'i> {
     pub arena: &'a Bump,
     pub subs: &'i mut Subs,
@@ -1437,6 +1490,8 @@ pub struct Env<'a, 'i> {
     pub abilities: AbilitiesView<'i>,
     pub exposed_by_module: &'i ExposedByModule,
     pub derived_module: &'i SharedDerivedModule,
+    /// [Some] when compiling with `--trace-refcounts`; [None] for builtin modules
+    pub refcount_trace: Option<&'i RefcountTrace<'a>>,
 }
 
 impl<'a, 'i> Env<'a, 'i> {
@@ -1812,7 +1867,7 @@ impl<'a> Call<'a> {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct CallSpecId {
     id: u32,
 }
@@ -1825,6 +1880,11 @@ impl CallSpecId {
     /// Dummy value for generating refcount helper procs in the backends
     /// This happens *after* specialization so it's safe
     pub const BACKEND_DUMMY: Self = Self { id: 0 };
+
+    /// The id after this one, for passes that create calls after specialization
+    pub(crate) fn next(self) -> Self {
+        Self { id: self.id + 1 }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -1941,12 +2001,25 @@ pub enum Expr<'a> {
         index: u64,
     },
 
+    /// The address (a `usize`) of a field of a heap-allocated tag. Used by tail recursion modulo
+    /// cons to fill in a field after the tag has been constructed.
+    UnionFieldPtrAtIndex {
+        structure: Symbol,
+        tag_id: TagIdIntType,
+        union_layout: UnionLayout<'a>,
+        index: u64,
+    },
+
     Array {
         elem_layout: InLayout<'a>,
         elems: &'a [ListLiteralElement<'a>],
     },
     EmptyArray,
 
+    /// A null pointer of a recursive union layout. Used by tail recursion modulo cons as a
+    /// placeholder for a field that is filled in later; it is never read or refcounted.
+    NullPointer,
+
     ExprBox {
         symbol: Symbol,
     },
@@ -1991,6 +2064,35 @@ impl<'a> Literal<'a> {
             Str(lit) => alloc.text(format!("{:?}", lit)),
         }
     }
+
+    /// The little-endian bytes of a number or boolean literal, as it is stored in memory with
+    /// the given layout. Used to emit list literals as static data.
+    ///
+    /// Returns `None` for strings, and for layouts that the literal cannot be stored as.
+    pub fn to_le_bytes(&self, layout: &Layout<'a>) -> Option<std::vec::Vec<u8>> {
+        use Literal::*;
+
+        let bytes = match (self, layout) {
+            (Int(bytes) | U128(bytes), Layout::Builtin(Builtin::Int(width))) => {
+                let le_bytes = i128::from_ne_bytes(*bytes).to_le_bytes();
+                le_bytes[..width.stack_size() as usize].to_vec()
+            }
+            (Float(float), Layout::Builtin(Builtin::Float(FloatWidth::F32))) => {
+                (*float as f32).to_le_bytes().to_vec()
+            }
+            (Float(float), Layout::Builtin(Builtin::Float(FloatWidth::F64))) => {
+                float.to_le_bytes().to_vec()
+            }
+            (Decimal(bytes), Layout::Builtin(Builtin::Decimal)) => {
+                i128::from_ne_bytes(*bytes).to_le_bytes().to_vec()
+            }
+            (Bool(b), Layout::Builtin(Builtin::Bool)) => vec![*b as u8],
+            (Byte(b), _) => vec![*b],
+            _ => return None,
+        };
+
+        Some(bytes)
+    }
 }
 
 pub(crate) fn symbol_to_doc_string(symbol: Symbol, force_pretty: bool) -> String {
@@ -2112,6 +2214,7 @@ impl<'a> Expr<'a> {
                     .append(alloc.text("]"))
             }
             EmptyArray => alloc.text("Array []"),
+            NullPointer => alloc.text("NullPointer"),
 
             StructAtIndex {
                 index, structure, ..
@@ -2141,6 +2244,18 @@ impl<'a> Expr<'a> {
             } => alloc
                 .text(format!("UnionAtIndex (Id {}) (Index {}) ", tag_id, index))
                 .append(symbol_to_doc(alloc, *structure, pretty)),
+
+            UnionFieldPtrAtIndex {
+                tag_id,
+                structure,
+                index,
+                ..
+            } => alloc
+                .text(format!(
+                    "UnionFieldPtrAtIndex (Id {}) (Index {}) ",
+                    tag_id, index
+                ))
+                .append(symbol_to_doc(alloc, *structure, pretty)),
         }
     }
 
@@ -4927,6 +5042,20 @@ pub fn with_hole<'a>(
         } => {
             use FieldType::*;
 
+            // With `--trace-refcounts`, report the fields that this update makes shared
+            let trace_location = env.refcount_trace.and_then(|trace| {
+                let region = updates
+                    .values()
+                    .map(|field| field.region)
+                    .min_by_key(|region| region.start().offset)?;
+
+                Some(trace.describe("record update", region))
+            });
+            let hole = match trace_location {
+                Some(_) => refcount_trace_reset(env, hole),
+                None => hole,
+            };
+
             enum FieldType<'a> {
                 CopyExisting(u64),
                 UpdateExisting(&'a roc_can::expr::Field),
@@ -5001,7 +5130,7 @@ pub fn with_hole<'a>(
             debug_assert_eq!(field_layouts.len(), symbols.len());
             debug_assert_eq!(fields.len(), symbols.len());
 
-            if symbols.len() == 1 {
+            let stmt = if symbols.len() == 1 {
                 // TODO we can probably special-case this more, skippiing the generation of
                 // UpdateExisting
                 let mut stmt = hole.clone();
@@ -5087,6 +5216,11 @@ pub fn with_hole<'a>(
                     }
                 }
                 stmt
+            };
+
+            match trace_location {
+                Some(location) => refcount_trace_location(env, &location, stmt),
+                None => stmt,
             }
         }
 
@@ -5159,7 +5293,26 @@ pub fn with_hole<'a>(
             }
         }
 
-        Call(boxed, loc_args, _) => {
+        Call(boxed, loc_args, called_via) => {
+            if let Some(trace) = env.refcount_trace {
+                if let roc_can::expr::Expr::Var(proc_name, _) = boxed.1.value {
+                    if matches!(proc_name.module_id(), ModuleId::LIST | ModuleId::STR) {
+                        return call_with_refcount_trace(
+                            env,
+                            procs,
+                            layout_cache,
+                            trace,
+                            boxed,
+                            loc_args,
+                            called_via,
+                            variable,
+                            assigned,
+                            hole,
+                        );
+                    }
+                }
+            }
+
             let (fn_var, loc_expr, _lambda_set_var, _ret_var) = *boxed;
 
             // even if a call looks like it's by name, it may in fact be by-pointer.
@@ -7231,7 +7384,7 @@ fn substitute_in_expr<'a>(
     use Expr::*;
 
     match expr {
-        Literal(_) | EmptyArray | RuntimeErrorFunction(_) => None,
+        Literal(_) | EmptyArray | NullPointer | RuntimeErrorFunction(_) => None,
 
         Call(call) => substitute_in_call(arena, call, subs).map(Expr::Call),
 
@@ -7369,6 +7522,21 @@ fn substitute_in_expr<'a>(
             }),
             None => None,
         },
+
+        UnionFieldPtrAtIndex {
+            structure,
+            tag_id,
+            index,
+            union_layout,
+        } => match substitute(subs, *structure) {
+            Some(structure) => Some(UnionFieldPtrAtIndex {
+                structure,
+                tag_id: *tag_id,
+                index: *index,
+                union_layout: *union_layout,
+            }),
+            None => None,
+        },
     }
 }
 
@@ -8540,6 +8708,151 @@ where
     result
 }
 
+/// With `--trace-refcounts`, a call to a `List` or `Str` builtin first tells the builtins where
+/// it comes from, so that the copies they make because a value is shared can be traced back to
+/// the source.
+#[allow(clippy::too_many_arguments)]
+fn call_with_refcount_trace<'a>(
+    env: &mut Env<'a, '_>,
+    procs: &mut Procs<'a>,
+    layout_cache: &mut LayoutCache<'a>,
+    trace: &RefcountTrace<'a>,
+    boxed: Box<(Variable, Loc<roc_can::expr::Expr>, Variable, Variable)>,
+    loc_args: std::vec::Vec<(Variable, Loc<roc_can::expr::Expr>)>,
+    called_via: CalledVia,
+    variable: Variable,
+    assigned: Symbol,
+    hole: &'a Stmt<'a>,
+) -> Stmt<'a> {
+    use roc_can::expr::Expr::*;
+
+    // The arguments may call builtins themselves, so the location must be set after they are
+    // evaluated. Bind every argument that does any work to a variable first, e.g.
+    //
+    // List.set (List.append list x) 0 y
+    //
+    // becomes
+    //
+    // arg = List.append list x
+    // List.set arg 0 y
+    let does_work = |expr: &roc_can::expr::Expr| {
+        !matches!(
+            expr,
+            Var(..)
+                | AbilityMember(..)
+                | Num(..)
+                | Int(..)
+                | Float(..)
+                | Str(_)
+                | SingleQuote(..)
+                | Closure(_)
+        )
+    };
+
+    if loc_args
+        .iter()
+        .any(|(_, loc_arg)| does_work(&loc_arg.value))
+    {
+        let mut defs = std::vec::Vec::new();
+
+        let loc_args = loc_args
+            .into_iter()
+            .map(|(arg_var, loc_arg)| {
+                if does_work(&loc_arg.value) {
+                    let symbol = env.unique_symbol();
+                    let region = loc_arg.region;
+
+                    defs.push(roc_can::def::Def {
+                        annotation: None,
+                        expr_var: arg_var,
+                        loc_expr: loc_arg,
+                        loc_pattern: Loc::at(region, roc_can::pattern::Pattern::Identifier(symbol)),
+                        pattern_vars: std::iter::once((symbol, arg_var)).collect(),
+                    });
+
+                    (arg_var, Loc::at(region, Var(symbol, arg_var)))
+                } else {
+                    (arg_var, loc_arg)
+                }
+            })
+            .collect();
+
+        let mut expr = Call(boxed, loc_args, called_via);
+        for def in defs.into_iter().rev() {
+            let region = def.loc_expr.region;
+            expr = LetNonRec(Box::new(def), Box::new(Loc::at(region, expr)));
+        }
+
+        return with_hole(env, expr, variable, procs, layout_cache, assigned, hole);
+    }
+
+    let (fn_var, loc_expr, _lambda_set_var, _ret_var) = *boxed;
+    let proc_name = match loc_expr.value {
+        Var(proc_name, _) => proc_name,
+        _ => internal_error!("refcount tracing is only done for calls by name"),
+    };
+
+    let location = trace.describe_call(loc_expr.region);
+    let hole = refcount_trace_reset(env, hole);
+
+    let result = call_by_name(
+        env,
+        procs,
+        fn_var,
+        proc_name,
+        loc_args,
+        layout_cache,
+        assigned,
+        hole,
+    );
+
+    refcount_trace_location(env, &location, result)
+}
+
+/// With `--trace-refcounts`, tell the builtins where the operation in `stmt` comes from. It must
+/// be paired with a [refcount_trace_reset] after the operation.
+fn refcount_trace_location<'a>(env: &mut Env<'a, '_>, location: &str, stmt: Stmt<'a>) -> Stmt<'a> {
+    let location_sym = env.unique_symbol();
+    let location_str = env.arena.alloc_str(location);
+
+    let unit = env.unique_symbol();
+    let set_location = Expr::Call(self::Call {
+        call_type: CallType::LowLevel {
+            op: LowLevel::RefCountTraceLocation,
+            update_mode: env.next_update_mode_id(),
+        },
+        arguments: env.arena.alloc([location_sym]),
+    });
+
+    Stmt::Let(
+        location_sym,
+        Expr::Literal(Literal::Str(location_str)),
+        Layout::STR,
+        env.arena.alloc(Stmt::Let(
+            unit,
+            set_location,
+            Layout::UNIT,
+            env.arena.alloc(stmt),
+        )),
+    )
+}
+
+/// Go back to the location the builtins had before the matching [refcount_trace_location], so
+/// that nothing after the operation is reported against it.
+fn refcount_trace_reset<'a>(env: &mut Env<'a, '_>, hole: &'a Stmt<'a>) -> &'a Stmt<'a> {
+    let unit = env.unique_symbol();
+    let reset_location = Expr::Call(self::Call {
+        call_type: CallType::LowLevel {
+            op: LowLevel::RefCountTraceReset,
+            update_mode: env.next_update_mode_id(),
+        },
+        arguments: &[],
+    });
+
+    env.arena
+        .alloc(Stmt::Let(unit, reset_location, Layout::UNIT, hole))
+}
+
 fn add_needed_external<'a>(
     procs: &mut Procs<'a>,
     env: &mut Env<'a, '_>,
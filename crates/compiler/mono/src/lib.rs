@@ -9,7 +9,9 @@
 
 pub mod borrow;
 pub mod code_gen_help;
+pub mod const_fold;
 pub mod inc_dec;
+pub mod inline;
 pub mod ir;
 pub mod layout;
 pub mod layout_soa;
@@ -1,10 +1,12 @@
 #![allow(clippy::manual_map)]
 
 use crate::borrow::Ownership;
-use crate::ir::{CallType, Expr, JoinPointId, Param, Stmt};
-use crate::layout::{InLayout, LambdaName};
+use crate::ir::{Call, CallType, Env, Expr, JoinPointId, Param, Stmt};
+use crate::layout::{InLayout, LambdaName, Layout, TagIdIntType, UnionLayout};
 use bumpalo::collections::Vec;
 use bumpalo::Bump;
+use roc_collections::all::MutSet;
+use roc_module::low_level::LowLevel;
 use roc_module::symbol::Symbol;
 
 /// Make tail calls into loops (using join points)
@@ -29,16 +31,26 @@ use roc_module::symbol::Symbol;
 ///
 /// This will effectively compile into a loop in llvm, and
 /// won't grow the call stack for each iteration
+///
+/// Self-calls that are wrapped in a tag of a recursive union are made into loops too, see
+/// `make_tail_recursive_modulo_cons`.
 pub fn make_tail_recursive<'a>(
-    arena: &'a Bump,
+    env: &mut Env<'a, '_>,
     id: JoinPointId,
-    needle: LambdaName,
+    needle: LambdaName<'a>,
     stmt: Stmt<'a>,
     args: &'a [(InLayout<'a>, Symbol, Symbol)],
     ret_layout: InLayout<'a>,
 ) -> Option<Stmt<'a>> {
+    let arena = env.arena;
     let allocated = arena.alloc(stmt);
 
+    if let Some(join) =
+        make_tail_recursive_modulo_cons(env, id, needle, allocated, args, ret_layout)
+    {
+        return Some(join);
+    }
+
     let new_stmt = insert_jumps(arena, allocated, id, needle, args, ret_layout)?;
 
     // if we did not early-return, jumps were inserted, we must now add a join point
@@ -68,6 +80,490 @@ pub fn make_tail_recursive<'a>(
     Some(join)
 }
 
+/// Tail recursion modulo cons
+///
+/// A self-call whose result is immediately wrapped in a tag is not a tail call, but when the tag
+/// belongs to a recursive union (so it is heap-allocated), we can still turn it into a jump. The
+/// tag is allocated before the call, with a null pointer as a placeholder for the recursive
+/// field. The address of that field (the "hole") is passed to the next iteration, which fills
+/// it in.
+///
+/// e.g.
+///
+/// > map = \list, f -> when list is
+/// >     Nil -> Nil
+/// >     Cons x xs -> Cons (f x) (map xs f)
+///
+/// becomes
+///
+/// ```elm
+/// map list1 f1 =
+///     let initial = alloca null
+///     let joinpoint j list f hole =
+///             when list is
+///                 Nil ->
+///                     store hole Nil
+///                     load initial
+///                 Cons x xs ->
+///                     let cons = Cons (f x) null
+///                     store hole cons
+///                     jump j xs f (address of the second field of cons)
+///
+///     in
+///         jump j list1 f1 initial
+/// ```
+///
+/// The null pointer is a safe placeholder: every hole is filled in before the result is loaded,
+/// and the placeholder itself is never refcounted.
+fn make_tail_recursive_modulo_cons<'a>(
+    env: &mut Env<'a, '_>,
+    id: JoinPointId,
+    needle: LambdaName<'a>,
+    stmt: &'a Stmt<'a>,
+    args: &'a [(InLayout<'a>, Symbol, Symbol)],
+    ret_layout: InLayout<'a>,
+) -> Option<Stmt<'a>> {
+    let arena = env.arena;
+
+    let needle = Needle {
+        name: needle,
+        arguments: args,
+        result: ret_layout,
+    };
+
+    let union_layout = find_cons_union(&needle, stmt)?;
+
+    let usize_layout = Layout::usize(env.target_info);
+
+    let ctx = ConsContext {
+        goal_id: id,
+        needle,
+        union_layout,
+        hole: env.unique_symbol(),
+        initial: env.unique_symbol(),
+    };
+
+    let new_stmt = insert_holes(env, &ctx, stmt);
+
+    let params = Vec::from_iter_in(
+        args.iter()
+            .map(|(layout, symbol, _)| Param {
+                symbol: *symbol,
+                layout: *layout,
+                ownership: Ownership::Borrowed,
+            })
+            .chain(std::iter::once(Param {
+                symbol: ctx.hole,
+                layout: usize_layout,
+                ownership: Ownership::Borrowed,
+            })),
+        arena,
+    )
+    .into_bump_slice();
+
+    let jump_args = Vec::from_iter_in(
+        args.iter().map(|t| t.2).chain(std::iter::once(ctx.initial)),
+        arena,
+    )
+    .into_bump_slice();
+
+    let join = Stmt::Join {
+        id,
+        remainder: arena.alloc(Stmt::Jump(id, jump_args)),
+        parameters: params,
+        body: new_stmt,
+    };
+
+    // the result is written into a stack slot, which initially holds a null pointer
+    let null = env.unique_symbol();
+    let alloca = Expr::Call(Call {
+        call_type: CallType::LowLevel {
+            op: LowLevel::Alloca,
+            update_mode: env.next_update_mode_id(),
+        },
+        arguments: arena.alloc([null]),
+    });
+
+    let stmt = Stmt::Let(
+        null,
+        Expr::NullPointer,
+        ret_layout,
+        arena.alloc(Stmt::Let(
+            ctx.initial,
+            alloca,
+            usize_layout,
+            arena.alloc(join),
+        )),
+    );
+
+    Some(stmt)
+}
+
+struct Needle<'a> {
+    name: LambdaName<'a>,
+    arguments: &'a [(InLayout<'a>, Symbol, Symbol)],
+    result: InLayout<'a>,
+}
+
+impl<'a> Needle<'a> {
+    /// to insert a tail-call, it must not just be a call to the function itself, but it must also
+    /// have the same layout.
+    fn is_self_call(&self, call_type: &CallType<'a>) -> bool {
+        match call_type {
+            CallType::ByName {
+                name,
+                ret_layout,
+                arg_layouts,
+                ..
+            } => {
+                let it = self.arguments.iter().map(|t| &t.0);
+                self.name == *name && it.eq(arg_layouts.iter()) && self.result == *ret_layout
+            }
+            _ => false,
+        }
+    }
+}
+
+struct ConsContext<'a> {
+    goal_id: JoinPointId,
+    needle: Needle<'a>,
+    union_layout: UnionLayout<'a>,
+    /// the address that the result of the current iteration should be written to
+    hole: Symbol,
+    /// the address that the result of the first iteration is written to
+    initial: Symbol,
+}
+
+impl<'a> ConsContext<'a> {
+    fn jump_with_hole(&self, arena: &'a Bump, arguments: &[Symbol], hole: Symbol) -> Stmt<'a> {
+        let mut args = Vec::with_capacity_in(arguments.len() + 1, arena);
+        args.extend(arguments.iter().copied());
+        args.push(hole);
+
+        Stmt::Jump(self.goal_id, args.into_bump_slice())
+    }
+}
+
+/// Only the tags of recursive unions are heap-allocated, so only those have fields with an address
+fn is_recursive(union_layout: UnionLayout) -> bool {
+    !matches!(union_layout, UnionLayout::NonRecursive(_))
+}
+
+/// A self-call followed by the construction of a tag that holds its result
+struct ConsSite<'a> {
+    /// bindings between the call and the tag; they do not use the result of the call
+    lets: std::vec::Vec<(Symbol, &'a Expr<'a>, InLayout<'a>)>,
+    symbol: Symbol,
+    tag_layout: UnionLayout<'a>,
+    tag_id: TagIdIntType,
+    arguments: &'a [Symbol],
+    /// the field of the tag that holds the result of the call
+    index: usize,
+}
+
+fn cons_site<'a>(
+    needle: &Needle<'a>,
+    call_symbol: Symbol,
+    mut stmt: &'a Stmt<'a>,
+) -> Option<ConsSite<'a>> {
+    let mut lets = std::vec::Vec::new();
+
+    while let Stmt::Let(symbol, expr, layout, cont) = stmt {
+        match (expr, cont) {
+            (
+                Expr::Tag {
+                    tag_layout,
+                    tag_id,
+                    arguments,
+                },
+                Stmt::Ret(ret_symbol),
+            ) if ret_symbol == symbol && *layout == needle.result => {
+                if !is_recursive(*tag_layout) {
+                    return None;
+                }
+
+                let mut positions = arguments
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, s)| **s == call_symbol);
+
+                return match (positions.next(), positions.next()) {
+                    (Some((index, _)), None) => Some(ConsSite {
+                        lets,
+                        symbol: *symbol,
+                        tag_layout: *tag_layout,
+                        tag_id: *tag_id,
+                        arguments,
+                        index,
+                    }),
+                    _ => None,
+                };
+            }
+            _ => {
+                let mut occurring = MutSet::default();
+                crate::inc_dec::occurring_variables_expr(expr, &mut occurring);
+
+                if occurring.contains(&call_symbol) {
+                    return None;
+                }
+
+                lets.push((*symbol, expr, *layout));
+                stmt = cont;
+            }
+        }
+    }
+
+    None
+}
+
+fn find_cons_union<'a>(needle: &Needle<'a>, stmt: &'a Stmt<'a>) -> Option<UnionLayout<'a>> {
+    use Stmt::*;
+
+    match stmt {
+        Let(symbol, expr, _, cont) => {
+            if let Expr::Call(Call { call_type, .. }) = expr {
+                if needle.is_self_call(call_type) {
+                    if let Some(site) = cons_site(needle, *symbol, cont) {
+                        return Some(site.tag_layout);
+                    }
+                }
+            }
+
+            find_cons_union(needle, cont)
+        }
+        Join {
+            body, remainder, ..
+        } => find_cons_union(needle, remainder).or_else(|| find_cons_union(needle, body)),
+        Switch {
+            branches,
+            default_branch,
+            ..
+        } => branches
+            .iter()
+            .find_map(|(_, _, branch)| find_cons_union(needle, branch))
+            .or_else(|| find_cons_union(needle, default_branch.1)),
+        Refcounting(_, cont) => find_cons_union(needle, cont),
+        Dbg { remainder, .. } | Expect { remainder, .. } | ExpectFx { remainder, .. } => {
+            find_cons_union(needle, remainder)
+        }
+        Ret(_) | Jump(_, _) | Crash(..) => None,
+    }
+}
+
+fn insert_holes<'a>(
+    env: &mut Env<'a, '_>,
+    ctx: &ConsContext<'a>,
+    stmt: &'a Stmt<'a>,
+) -> &'a Stmt<'a> {
+    use Stmt::*;
+
+    let arena = env.arena;
+
+    match stmt {
+        Let(symbol, expr, layout, cont) => {
+            if let Expr::Call(Call {
+                call_type,
+                arguments,
+            }) = expr
+            {
+                if ctx.needle.is_self_call(call_type) {
+                    if let Ret(ret_symbol) = cont {
+                        if ret_symbol == symbol {
+                            // a plain tail call writes its result into our hole
+                            return arena.alloc(ctx.jump_with_hole(arena, arguments, ctx.hole));
+                        }
+                    }
+
+                    if let Some(site) = cons_site(&ctx.needle, *symbol, cont) {
+                        if site.tag_layout == ctx.union_layout {
+                            return fill_hole(env, ctx, arguments, site);
+                        }
+                    }
+                }
+            }
+
+            let cont = insert_holes(env, ctx, cont);
+
+            arena.alloc(Let(*symbol, expr.clone(), *layout, cont))
+        }
+        Join {
+            id,
+            parameters,
+            body,
+            remainder,
+        } => {
+            let body = insert_holes(env, ctx, body);
+            let remainder = insert_holes(env, ctx, remainder);
+
+            arena.alloc(Join {
+                id: *id,
+                parameters,
+                body,
+                remainder,
+            })
+        }
+        Switch {
+            cond_symbol,
+            cond_layout,
+            branches,
+            default_branch,
+            ret_layout,
+        } => {
+            let mut new_branches = Vec::with_capacity_in(branches.len(), arena);
+
+            for (label, info, branch) in branches.iter() {
+                let branch = insert_holes(env, ctx, branch);
+                new_branches.push((*label, info.clone(), branch.clone()));
+            }
+
+            let default_branch = (
+                default_branch.0.clone(),
+                insert_holes(env, ctx, default_branch.1),
+            );
+
+            arena.alloc(Switch {
+                cond_symbol: *cond_symbol,
+                cond_layout: *cond_layout,
+                branches: new_branches.into_bump_slice(),
+                default_branch,
+                ret_layout: *ret_layout,
+            })
+        }
+        Refcounting(modify, cont) => {
+            let cont = insert_holes(env, ctx, cont);
+
+            arena.alloc(Refcounting(*modify, cont))
+        }
+        Dbg {
+            symbol,
+            variable,
+            remainder,
+        } => {
+            let remainder = insert_holes(env, ctx, remainder);
+
+            arena.alloc(Dbg {
+                symbol: *symbol,
+                variable: *variable,
+                remainder,
+            })
+        }
+        Expect {
+            condition,
+            region,
+            lookups,
+            variables,
+            remainder,
+        } => {
+            let remainder = insert_holes(env, ctx, remainder);
+
+            arena.alloc(Expect {
+                condition: *condition,
+                region: *region,
+                lookups,
+                variables,
+                remainder,
+            })
+        }
+        ExpectFx {
+            condition,
+            region,
+            lookups,
+            variables,
+            remainder,
+        } => {
+            let remainder = insert_holes(env, ctx, remainder);
+
+            arena.alloc(ExpectFx {
+                condition: *condition,
+                region: *region,
+                lookups,
+                variables,
+                remainder,
+            })
+        }
+        Ret(symbol) => {
+            // write the value into the hole, then return the value of the first iteration
+            let unit = env.unique_symbol();
+            let result = env.unique_symbol();
+
+            let store = ptr_store(env, ctx.hole, *symbol);
+            let load = Expr::Call(Call {
+                call_type: CallType::LowLevel {
+                    op: LowLevel::PtrLoad,
+                    update_mode: env.next_update_mode_id(),
+                },
+                arguments: arena.alloc([ctx.initial]),
+            });
+
+            let ret = arena.alloc(Ret(result));
+            let load = arena.alloc(Let(result, load, ctx.needle.result, ret));
+
+            arena.alloc(Let(unit, store, Layout::UNIT, load))
+        }
+        Jump(_, _) | Crash(..) => stmt,
+    }
+}
+
+/// Allocate the tag with a null pointer as a placeholder, write it into the current hole, and
+/// continue with the placeholder as the new hole.
+fn fill_hole<'a>(
+    env: &mut Env<'a, '_>,
+    ctx: &ConsContext<'a>,
+    call_arguments: &[Symbol],
+    site: ConsSite<'a>,
+) -> &'a Stmt<'a> {
+    let arena = env.arena;
+
+    let null = env.unique_symbol();
+    let field_ptr = env.unique_symbol();
+    let unit = env.unique_symbol();
+
+    let mut arguments = Vec::from_iter_in(site.arguments.iter().copied(), arena);
+    arguments[site.index] = null;
+
+    let tag = Expr::Tag {
+        tag_layout: site.tag_layout,
+        tag_id: site.tag_id,
+        arguments: arguments.into_bump_slice(),
+    };
+
+    let field_ptr_expr = Expr::UnionFieldPtrAtIndex {
+        structure: site.symbol,
+        tag_id: site.tag_id,
+        union_layout: site.tag_layout,
+        index: site.index as u64,
+    };
+
+    let store = ptr_store(env, ctx.hole, site.symbol);
+
+    let jump = arena.alloc(ctx.jump_with_hole(arena, call_arguments, field_ptr));
+    let stmt = arena.alloc(Stmt::Let(unit, store, Layout::UNIT, jump));
+    let stmt = arena.alloc(Stmt::Let(
+        field_ptr,
+        field_ptr_expr,
+        Layout::usize(env.target_info),
+        stmt,
+    ));
+    let stmt = arena.alloc(Stmt::Let(site.symbol, tag, ctx.needle.result, stmt));
+    let mut stmt = arena.alloc(Stmt::Let(null, Expr::NullPointer, ctx.needle.result, stmt));
+
+    for (symbol, expr, layout) in site.lets.into_iter().rev() {
+        stmt = arena.alloc(Stmt::Let(symbol, expr.clone(), layout, stmt));
+    }
+
+    stmt
+}
+
+fn ptr_store<'a>(env: &mut Env<'a, '_>, ptr: Symbol, value: Symbol) -> Expr<'a> {
+    Expr::Call(Call {
+        call_type: CallType::LowLevel {
+            op: LowLevel::PtrStore,
+            update_mode: env.next_update_mode_id(),
+        },
+        arguments: env.arena.alloc([ptr, value]),
+    })
+}
+
 fn insert_jumps<'a>(
     arena: &'a Bump,
     stmt: &'a Stmt<'a>,
@@ -7,7 +7,7 @@ use roc_parse::pattern::PatternType;
 use roc_region::all::{Loc, Region};
 use roc_types::types::AliasKind;
 
-use crate::Severity;
+use crate::{Edit, Severity};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct CycleEntry {
@@ -32,15 +32,17 @@ pub enum ShadowKind {
 /// Problems that can occur in the course of canonicalization.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Problem {
-    UnusedDef(Symbol, Region),
-    UnusedImport(Symbol, Region),
-    UnusedModuleImport(ModuleId, Region),
-    ExposedButNotDefined(Symbol),
+    /// The edit removes the def, if that's all it defines
+    UnusedDef(Symbol, Region, Option<Edit>),
+    UnusedImport(Symbol, Region, Option<Edit>),
+    UnusedModuleImport(ModuleId, Region, Option<Edit>),
+    ExposedButNotDefined(Symbol, Option<Edit>),
     UnknownGeneratesWith(Loc<Ident>),
     /// First symbol is the name of the closure with that argument
     /// Bool is whether the closure is anonymous
     /// Second symbol is the name of the argument that is unused
-    UnusedArgument(Symbol, bool, Symbol, Region),
+    /// The edit prefixes the argument with an underscore, if it's a plain identifier
+    UnusedArgument(Symbol, bool, Symbol, Region, Option<Edit>),
     UnusedBranchDef(Symbol, Region),
     DefsOnlyUsedInRecursion(usize, Region),
     PrecedenceProblem(PrecedenceProblem),
@@ -206,17 +208,36 @@ pub enum Problem {
     },
 }
 
+/// The names of the problems that are only ever warnings, and so are the ones whose level
+/// can be changed; see [crate::DiagnosticLevels].
+pub const WARNING_NAMES: &[&str] = &[
+    "unused-def",
+    "unused-import",
+    "unused-argument",
+    "unused-branch-def",
+    "defs-only-used-in-recursion",
+    "phantom-type-argument",
+    "duplicate-record-field-value",
+    "duplicate-has-ability",
+    "duplicate-impl",
+    "not-an-ability",
+    "implements-non-required",
+    "no-identifiers-introduced",
+    "overloaded-specialization",
+    "unnecessary-output-wildcard",
+];
+
 impl Problem {
     pub fn severity(&self) -> Severity {
         use Severity::{RuntimeError, Warning};
 
         match self {
-            Problem::UnusedDef(_, _) => Warning,
-            Problem::UnusedImport(_, _) => Warning,
-            Problem::UnusedModuleImport(_, _) => Warning,
-            Problem::ExposedButNotDefined(_) => RuntimeError,
+            Problem::UnusedDef(_, _, _) => Warning,
+            Problem::UnusedImport(_, _, _) => Warning,
+            Problem::UnusedModuleImport(_, _, _) => Warning,
+            Problem::ExposedButNotDefined(_, _) => RuntimeError,
             Problem::UnknownGeneratesWith(_) => RuntimeError,
-            Problem::UnusedArgument(_, _, _, _) => Warning,
+            Problem::UnusedArgument(_, _, _, _, _) => Warning,
             Problem::UnusedBranchDef(_, _) => Warning,
             Problem::PrecedenceProblem(_) => RuntimeError,
             Problem::UnsupportedPattern(_, _) => RuntimeError,
@@ -272,6 +293,67 @@ impl Problem {
         }
     }
 
+    /// A stable name for this kind of problem, like `unused-import`, which is how
+    /// `--allow`, `--warn`, and `--deny` refer to it. Several variants can share a name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Problem::UnusedDef(..) => "unused-def",
+            Problem::UnusedImport(..) => "unused-import",
+            Problem::UnusedModuleImport(..) => "unused-import",
+            Problem::ExposedButNotDefined(..) => "exposed-but-not-defined",
+            Problem::UnknownGeneratesWith(_) => "unknown-generates-with",
+            Problem::UnusedArgument(..) => "unused-argument",
+            Problem::UnusedBranchDef(..) => "unused-branch-def",
+            Problem::DefsOnlyUsedInRecursion(..) => "defs-only-used-in-recursion",
+            Problem::PrecedenceProblem(_) => "precedence",
+            Problem::UnsupportedPattern(..) => "unsupported-pattern",
+            Problem::Shadowing { .. } => "shadowing",
+            Problem::CyclicAlias(..) => "cyclic-alias",
+            Problem::BadRecursion(_) => "bad-recursion",
+            Problem::PhantomTypeArgument { .. } => "phantom-type-argument",
+            Problem::UnboundTypeVariable { .. } => "unbound-type-variable",
+            Problem::DuplicateRecordFieldValue { .. } => "duplicate-record-field-value",
+            Problem::DuplicateRecordFieldType { .. } => "duplicate-record-field-type",
+            Problem::InvalidOptionalValue { .. } => "invalid-optional-value",
+            Problem::DuplicateTag { .. } => "duplicate-tag",
+            Problem::RuntimeError(runtime_error) => runtime_error.name(),
+            Problem::SignatureDefMismatch { .. } => "signature-def-mismatch",
+            Problem::InvalidAliasRigid { .. } => "invalid-alias-rigid",
+            Problem::InvalidInterpolation(_) => "invalid-interpolation",
+            Problem::InvalidHexadecimal(_) => "invalid-hexadecimal",
+            Problem::InvalidUnicodeCodePt(_) => "invalid-unicode-code-point",
+            Problem::NestedDatatype { .. } => "nested-datatype",
+            Problem::InvalidExtensionType { .. } => "invalid-extension-type",
+            Problem::AbilityHasTypeVariables { .. } => "ability-has-type-variables",
+            Problem::HasClauseIsNotAbility { .. } => "has-clause-is-not-ability",
+            Problem::IllegalHasClause { .. } => "illegal-has-clause",
+            Problem::DuplicateHasAbility { .. } => "duplicate-has-ability",
+            Problem::AbilityMemberMissingHasClause { .. } => "ability-member-missing-has-clause",
+            Problem::AbilityMemberMultipleBoundVars { .. } => "ability-member-multiple-bound-vars",
+            Problem::AbilityNotOnToplevel { .. } => "ability-not-on-toplevel",
+            Problem::AbilityUsedAsType(..) => "ability-used-as-type",
+            Problem::NestedSpecialization(..) => "nested-specialization",
+            Problem::IllegalDerivedAbility(_) => "illegal-derived-ability",
+            Problem::ImplementationNotFound { .. } => "implementation-not-found",
+            Problem::NotAnAbilityMember { .. } => "not-an-ability-member",
+            Problem::OptionalAbilityImpl { .. } => "optional-ability-impl",
+            Problem::QualifiedAbilityImpl { .. } => "qualified-ability-impl",
+            Problem::AbilityImplNotIdent { .. } => "ability-impl-not-ident",
+            Problem::DuplicateImpl { .. } => "duplicate-impl",
+            Problem::NotAnAbility(_) => "not-an-ability",
+            Problem::ImplementsNonRequired { .. } => "implements-non-required",
+            Problem::DoesNotImplementAbility { .. } => "does-not-implement-ability",
+            Problem::NotBoundInAllPatterns { .. } => "not-bound-in-all-patterns",
+            Problem::NoIdentifiersIntroduced(_) => "no-identifiers-introduced",
+            Problem::OverloadedSpecialization { .. } => "overloaded-specialization",
+            Problem::UnnecessaryOutputWildcard { .. } => "unnecessary-output-wildcard",
+            Problem::MultipleListRestPattern { .. } => "multiple-list-rest-pattern",
+            Problem::BadTypeArguments { .. } => "bad-type-arguments",
+            Problem::UnappliedCrash { .. } => "unapplied-crash",
+            Problem::OverAppliedCrash { .. } => "over-applied-crash",
+        }
+    }
+
     /// Returns a Region value from the Problem, if possible.
     /// Some problems have more than one region; in those cases,
     /// this tries to pick the one that's closest to the original
@@ -280,15 +362,15 @@ impl Problem {
     /// on their Region being outside the expression currently being evaluated.
     pub fn region(&self) -> Option<Region> {
         match self {
-            Problem::UnusedDef(_, region)
+            Problem::UnusedDef(_, region, _)
             | Problem::Shadowing {
                 original_region: region,
                 ..
             }
-            | Problem::UnusedImport(_, region)
-            | Problem::UnusedModuleImport(_, region)
+            | Problem::UnusedImport(_, region, _)
+            | Problem::UnusedModuleImport(_, region, _)
             | Problem::UnknownGeneratesWith(Loc { region, .. })
-            | Problem::UnusedArgument(_, _, _, region)
+            | Problem::UnusedArgument(_, _, _, region, _)
             | Problem::UnusedBranchDef(_, region)
             | Problem::PrecedenceProblem(PrecedenceProblem::BothNonAssociative(region, _, _))
             | Problem::UnsupportedPattern(_, region)
@@ -414,7 +496,20 @@ impl Problem {
             | Problem::RuntimeError(RuntimeError::VoidValue)
             | Problem::RuntimeError(RuntimeError::ExposedButNotDefined(_))
             | Problem::RuntimeError(RuntimeError::NoImplementationNamed { .. })
-            | Problem::ExposedButNotDefined(_) => None,
+            | Problem::ExposedButNotDefined(_, _) => None,
+        }
+    }
+
+    /// Returns an edit to the source code that fixes the problem, if there's an obvious one.
+    pub fn fix(&self) -> Option<Edit> {
+        match self {
+            Problem::UnusedDef(_, _, fix)
+            | Problem::UnusedImport(_, _, fix)
+            | Problem::UnusedModuleImport(_, _, fix)
+            | Problem::ExposedButNotDefined(_, fix)
+            | Problem::UnusedArgument(_, _, _, _, fix) => fix.clone(),
+            Problem::UnnecessaryOutputWildcard { region } => Some(Edit::remove(*region)),
+            _ => None,
         }
     }
 }
@@ -582,6 +677,44 @@ pub enum RuntimeError {
 }
 
 impl RuntimeError {
+    /// A stable name for this kind of problem; see [Problem::name].
+    pub fn name(&self) -> &'static str {
+        match self {
+            RuntimeError::Shadowing { .. } => "shadowing",
+            RuntimeError::InvalidOptionalValue { .. } => "invalid-optional-value",
+            RuntimeError::UnsupportedPattern(_) => "unsupported-pattern",
+            RuntimeError::MalformedPattern(..) => "malformed-pattern",
+            RuntimeError::UnresolvedTypeVar => "unresolved-type-var",
+            RuntimeError::ErroneousType => "erroneous-type",
+            RuntimeError::LookupNotInScope(..) => "not-in-scope",
+            RuntimeError::OpaqueNotDefined { .. } => "opaque-not-defined",
+            RuntimeError::OpaqueOutsideScope { .. } => "opaque-outside-scope",
+            RuntimeError::OpaqueNotApplied(_) => "opaque-not-applied",
+            RuntimeError::OpaqueAppliedToMultipleArgs(_) => "opaque-applied-to-multiple-args",
+            RuntimeError::ValueNotExposed { .. } => "value-not-exposed",
+            RuntimeError::ModuleNotImported { .. } => "module-not-imported",
+            RuntimeError::InvalidPrecedence(..) => "precedence",
+            RuntimeError::MalformedIdentifier(..) => "malformed-identifier",
+            RuntimeError::MalformedTypeName(..) => "malformed-type-name",
+            RuntimeError::MalformedClosure(_) => "malformed-closure",
+            RuntimeError::InvalidRecordUpdate { .. } => "invalid-record-update",
+            RuntimeError::InvalidFloat(..) => "invalid-float",
+            RuntimeError::InvalidInt(..) => "invalid-int",
+            RuntimeError::CircularDef(_) => "circular-def",
+            RuntimeError::NonExhaustivePattern => "non-exhaustive-pattern",
+            RuntimeError::InvalidInterpolation(_) => "invalid-interpolation",
+            RuntimeError::InvalidHexadecimal(_) => "invalid-hexadecimal",
+            RuntimeError::InvalidUnicodeCodePt(_) => "invalid-unicode-code-point",
+            RuntimeError::NoImplementationNamed { .. } => "no-implementation",
+            RuntimeError::NoImplementation => "no-implementation",
+            RuntimeError::VoidValue => "void-value",
+            RuntimeError::ExposedButNotDefined(_) => "exposed-but-not-defined",
+            RuntimeError::EmptySingleQuote(_) => "empty-single-quote",
+            RuntimeError::MultipleCharsInSingleQuote(_) => "multiple-chars-in-single-quote",
+            RuntimeError::DegenerateBranch(_) => "degenerate-branch",
+        }
+    }
+
     pub fn runtime_message(self) -> String {
         use RuntimeError::*;
 
@@ -609,3 +742,96 @@ pub enum MalformedPatternProblem {
     MultipleCharsInSingleQuote,
     DuplicateListRestPattern,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// One problem of each kind that is a warning.
+    fn warnings() -> Vec<Problem> {
+        let region = Region::zero();
+
+        vec![
+            Problem::UnusedDef(Symbol::LIST_MAP, region, None),
+            Problem::UnusedImport(Symbol::LIST_MAP, region, None),
+            Problem::UnusedModuleImport(ModuleId::LIST, region, None),
+            Problem::UnusedArgument(Symbol::LIST_MAP, false, Symbol::LIST_MAP, region, None),
+            Problem::UnusedBranchDef(Symbol::LIST_MAP, region),
+            Problem::DefsOnlyUsedInRecursion(1, region),
+            Problem::PhantomTypeArgument {
+                typ: Symbol::LIST_LIST,
+                variable_region: region,
+                variable_name: Lowercase::from("a"),
+                alias_kind: AliasKind::Structural,
+            },
+            Problem::DuplicateRecordFieldValue {
+                field_name: Lowercase::from("a"),
+                record_region: region,
+                field_region: region,
+                replaced_region: region,
+            },
+            Problem::DuplicateHasAbility {
+                ability: Symbol::BOOL_EQ,
+                region,
+            },
+            Problem::DuplicateImpl {
+                original: region,
+                duplicate: region,
+            },
+            Problem::NotAnAbility(region),
+            Problem::ImplementsNonRequired {
+                region,
+                ability: Symbol::BOOL_EQ,
+                not_required: vec![Symbol::LIST_MAP],
+            },
+            Problem::NoIdentifiersIntroduced(region),
+            Problem::OverloadedSpecialization {
+                overload: region,
+                original_opaque: Symbol::LIST_LIST,
+                ability_member: Symbol::BOOL_IS_EQ,
+            },
+            Problem::UnnecessaryOutputWildcard { region },
+        ]
+    }
+
+    #[test]
+    fn warning_names_are_the_names_of_warnings() {
+        let warnings = warnings();
+
+        for problem in &warnings {
+            assert_eq!(problem.severity(), Severity::Warning, "{:?}", problem);
+            assert!(
+                WARNING_NAMES.contains(&problem.name()),
+                "`{}` is a warning, but it isn't in WARNING_NAMES",
+                problem.name()
+            );
+        }
+
+        for name in WARNING_NAMES {
+            assert!(
+                warnings.iter().any(|problem| problem.name() == *name),
+                "`{}` is in WARNING_NAMES, but no warning has that name",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn errors_are_not_in_warning_names() {
+        let region = Region::zero();
+        let errors = [
+            Problem::Shadowing {
+                original_region: region,
+                shadow: Loc::at(region, Ident::from("a")),
+                kind: ShadowKind::Variable,
+            },
+            Problem::ExposedButNotDefined(Symbol::LIST_MAP, None),
+            Problem::RuntimeError(RuntimeError::EmptySingleQuote(region)),
+        ];
+
+        for problem in &errors {
+            assert_eq!(problem.severity(), Severity::RuntimeError, "{:?}", problem);
+            assert!(!WARNING_NAMES.contains(&problem.name()), "{:?}", problem);
+        }
+    }
+}
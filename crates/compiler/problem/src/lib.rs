@@ -4,6 +4,8 @@
 #![allow(clippy::large_enum_variant)]
 pub mod can;
 
+use roc_region::all::{Position, Region};
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Severity {
     /// This will cause a runtime error if some code get srun
@@ -15,3 +17,130 @@ pub enum Severity {
     /// (e.g. unused def, unused import)
     Warning,
 }
+
+/// What to do about a kind of warning, as configured for a project or on the command line.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Level {
+    /// Don't report it at all
+    Allow,
+    /// Report it as a warning, which is the default
+    Warn,
+    /// Report it as an error, so the build fails
+    Deny,
+}
+
+impl Level {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "allow" => Some(Level::Allow),
+            "warn" => Some(Level::Warn),
+            "deny" => Some(Level::Deny),
+            _ => None,
+        }
+    }
+}
+
+/// Levels for kinds of warnings, by their stable name (e.g. `unused-import`, see
+/// [can::Problem::name].) Problems that are errors always stay errors, because the code they
+/// affect would crash if it ran.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DiagnosticLevels {
+    levels: Vec<(String, Level)>,
+}
+
+impl DiagnosticLevels {
+    /// Setting the level of a name again overrides the earlier level.
+    pub fn set(&mut self, name: &str, level: Level) {
+        match self.levels.iter_mut().find(|(n, _)| n == name) {
+            Some((_, existing)) => *existing = level,
+            None => self.levels.push((name.to_string(), level)),
+        }
+    }
+
+    pub fn level(&self, name: &str) -> Option<Level> {
+        self.levels
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, level)| *level)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.levels.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// The severity to report a problem with, or None if it shouldn't be reported at all.
+    pub fn severity(&self, name: &str, default: Severity) -> Option<Severity> {
+        match (default, self.level(name)) {
+            (Severity::RuntimeError, _) => Some(Severity::RuntimeError),
+            (Severity::Warning, Some(Level::Allow)) => None,
+            (Severity::Warning, Some(Level::Deny)) => Some(Severity::RuntimeError),
+            (Severity::Warning, Some(Level::Warn) | None) => Some(Severity::Warning),
+        }
+    }
+
+    /// Parses a config file with one `allow NAME`, `warn NAME`, or `deny NAME` per line.
+    /// Blank lines and lines starting with `#` are ignored.
+    pub fn parse_config(src: &str) -> Result<Self, String> {
+        let mut levels = DiagnosticLevels::default();
+
+        for (index, line) in src.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut words = line.split_whitespace();
+
+            match (words.next().and_then(Level::from_name), words.next(), words.next()) {
+                (Some(level), Some(name), None) => levels.set(name, level),
+                _ => {
+                    return Err(format!(
+                        "line {} should be `allow`, `warn`, or `deny` followed by the name of a warning, but it was `{}`",
+                        index + 1,
+                        line
+                    ))
+                }
+            }
+        }
+
+        Ok(levels)
+    }
+}
+
+/// A mechanical fix for a problem: the code in the region gets replaced with the text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Edit {
+    pub region: Region,
+    pub replacement: String,
+}
+
+impl Edit {
+    pub fn remove(region: Region) -> Self {
+        Edit {
+            region,
+            replacement: String::new(),
+        }
+    }
+
+    pub fn insert(position: Position, text: &str) -> Self {
+        Edit {
+            region: Region::new(position, position),
+            replacement: text.to_string(),
+        }
+    }
+
+    /// Removes one of the items of a comma-separated collection, along with the comma between it
+    /// and the next item (or the previous one, if it's the last item.)
+    pub fn remove_from_collection(items: &[Region], index: usize) -> Self {
+        let item = items[index];
+
+        let region = match items.get(index + 1) {
+            Some(next) => Region::new(item.start(), next.start()),
+            None if index > 0 => Region::new(items[index - 1].end(), item.end()),
+            None => item,
+        };
+
+        Edit::remove(region)
+    }
+}
@@ -344,6 +344,7 @@ where
     }
 }
 
+#[derive(Debug)]
 pub struct LineInfo {
     line_offsets: Vec<u32>,
 }
@@ -1,16 +1,22 @@
-use crate::solve::{self, Aliases};
+use crate::ability::{AbilityImplError, ObligationCache};
+use crate::solve::{self, deep_copy_var_in, Aliases, Pools};
+use bumpalo::Bump;
 use roc_can::abilities::{AbilitiesStore, ResolvedImpl};
 use roc_can::constraint::{Constraint as ConstraintSoa, Constraints};
-use roc_can::expr::PendingDerives;
+use roc_can::expr::{Declarations, PendingDerives};
 use roc_can::module::{ExposedByModule, ResolvedImplementations, RigidVariables};
+use roc_can::traverse::find_typed_holes;
 use roc_collections::all::MutMap;
 use roc_collections::VecMap;
 use roc_derive::SharedDerivedModule;
 use roc_error_macros::internal_error;
 use roc_module::symbol::{ModuleId, Symbol};
 use roc_solve_problem::TypeError;
-use roc_types::subs::{Content, ExposedTypesStorageSubs, FlatType, StorageSubs, Subs, Variable};
-use roc_types::types::{Alias, MemberImpl, Types};
+use roc_types::subs::{
+    Content, ExposedTypesStorageSubs, FlatType, Rank, StorageSubs, Subs, Variable,
+};
+use roc_types::types::{Alias, MemberImpl, Polarity, Types};
+use roc_unify::unify::{unify, Env as UEnv, Mode, Unified};
 
 /// A marker that a given Subs has been solved.
 /// The only way to obtain a Solved<Subs> is by running the solver on it.
@@ -101,6 +107,84 @@ pub fn run_solve(
     (solved_subs, solved_env, problems, abilities_store)
 }
 
+/// Reports the type expected at every typed hole in a solved module, along with the bindings in
+/// scope that would fit there.
+pub fn typed_hole_problems(
+    subs: &mut Subs,
+    abilities_store: &AbilitiesStore,
+    decls: &Declarations,
+) -> Vec<TypeError> {
+    let mut problems = Vec::new();
+
+    for hole in find_typed_holes(decls) {
+        // Anything fits a hole that nothing constrains, so listing bindings would only be noise.
+        let fits = match subs.get_content_without_compacting(hole.var) {
+            Content::FlexVar(_) => Vec::new(),
+            _ => hole
+                .in_scope
+                .iter()
+                .filter_map(|&(symbol, var)| {
+                    if fits_hole(subs, abilities_store, hole.var, var) {
+                        Some((symbol, subs.var_to_error_type(var, Polarity::OF_VALUE)))
+                    } else {
+                        None
+                    }
+                })
+                .collect(),
+        };
+
+        problems.push(TypeError::TypedHole {
+            region: hole.region,
+            typ: subs.var_to_error_type(hole.var, Polarity::OF_VALUE),
+            fits,
+        });
+    }
+
+    problems
+}
+
+/// Does a binding of type `var` fit a hole of type `hole_var`? Checked in a snapshot, so the
+/// types are left as they were.
+fn fits_hole(
+    subs: &mut Subs,
+    abilities_store: &AbilitiesStore,
+    hole_var: Variable,
+    var: Variable,
+) -> bool {
+    let snapshot = subs.snapshot();
+
+    // A generalized binding like `List.len : List a -> Nat` fits wherever an instance of its type
+    // does, so unify a fresh copy of it, with its rigids turned into flex vars.
+    let arena = Bump::new();
+    let mut pools = Pools::default();
+    let instance = deep_copy_var_in(subs, Rank::toplevel(), &mut pools, var, &arena);
+
+    let fits = match unify(
+        &mut UEnv::new(subs),
+        hole_var,
+        instance,
+        Mode::EQ,
+        Polarity::OF_VALUE,
+    ) {
+        Unified::Success {
+            must_implement_ability,
+            ..
+        } => ObligationCache::default()
+            .check_obligations(
+                subs,
+                abilities_store,
+                must_implement_ability,
+                AbilityImplError::DoesNotImplement,
+            )
+            .is_empty(),
+        Unified::Failure(..) => false,
+    };
+
+    subs.rollback_to(snapshot);
+
+    fits
+}
+
 /// Copies exposed types and all ability specializations, which may be implicitly exposed.
 pub fn exposed_types_storage_subs(
     home: ModuleId,
@@ -186,7 +186,7 @@ mod solve_expr {
         can_problems.retain(|prob| {
             !matches!(
                 prob,
-                roc_problem::can::Problem::UnusedDef(_, _)
+                roc_problem::can::Problem::UnusedDef(_, _, _)
                     | roc_problem::can::Problem::UnusedBranchDef(..)
             )
         });
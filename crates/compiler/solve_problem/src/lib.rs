@@ -6,6 +6,10 @@ use roc_region::all::Region;
 
 use roc_types::types::{Category, ErrorType, PatternCategory};
 
+/// The names of the type problems that are only ever warnings; see
+/// [roc_problem::can::WARNING_NAMES].
+pub const WARNING_NAMES: &[&str] = &["redundant-pattern", "unmatchable-pattern"];
+
 #[derive(Debug, Clone)]
 pub enum TypeError {
     BadExpr(Region, Category, ErrorType, Expected<ErrorType>),
@@ -29,6 +33,13 @@ pub enum TypeError {
         expected_opaque: Symbol,
         found_opaque: Symbol,
     },
+    /// A `_` in expression position, with the type expected there and the bindings in scope
+    /// whose types fit it.
+    TypedHole {
+        region: Region,
+        typ: ErrorType,
+        fits: Vec<(Symbol, ErrorType)>,
+    },
 }
 
 impl TypeError {
@@ -48,6 +59,26 @@ impl TypeError {
             TypeError::Exhaustive(exhtv) => exhtv.severity(),
             TypeError::StructuralSpecialization { .. } => RuntimeError,
             TypeError::WrongSpecialization { .. } => RuntimeError,
+            TypeError::TypedHole { .. } => RuntimeError,
+        }
+    }
+
+    /// A stable name for this kind of problem, like `redundant-pattern`; see
+    /// [roc_problem::can::Problem::name].
+    pub fn name(&self) -> &'static str {
+        match self {
+            TypeError::BadExpr(..) => "type-mismatch",
+            TypeError::BadPattern(..) => "type-mismatch",
+            TypeError::CircularType(..) => "circular-type",
+            TypeError::CircularDef(_) => "circular-def",
+            TypeError::UnexposedLookup(_) => "value-not-exposed",
+            TypeError::UnfulfilledAbility(_) => "unfulfilled-ability",
+            TypeError::BadExprMissingAbility(..) => "unfulfilled-ability",
+            TypeError::BadPatternMissingAbility(..) => "unfulfilled-ability",
+            TypeError::Exhaustive(exhtv) => exhtv.name(),
+            TypeError::StructuralSpecialization { .. } => "structural-specialization",
+            TypeError::WrongSpecialization { .. } => "wrong-specialization",
+            TypeError::TypedHole { .. } => "typed-hole",
         }
     }
 }
@@ -89,6 +120,7 @@ pub enum NotDerivableContext {
     Opaque(Symbol),
     Decode(NotDerivableDecode),
     Eq(NotDerivableEq),
+    Ord(NotDerivableOrd),
 }
 
 #[derive(PartialEq, Eq, Debug, Clone)]
@@ -100,3 +132,48 @@ pub enum NotDerivableDecode {
 pub enum NotDerivableEq {
     FloatingPoint,
 }
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum NotDerivableOrd {
+    FloatingPoint,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use roc_collections::all::HumanIndex;
+
+    #[test]
+    fn warning_names_are_the_names_of_warnings() {
+        let region = Region::zero();
+        let warnings = [
+            TypeError::Exhaustive(roc_exhaustive::Error::Redundant {
+                overall_region: region,
+                branch_region: region,
+                index: HumanIndex::FIRST,
+            }),
+            TypeError::Exhaustive(roc_exhaustive::Error::Unmatchable {
+                overall_region: region,
+                branch_region: region,
+                index: HumanIndex::FIRST,
+            }),
+        ];
+
+        for problem in &warnings {
+            assert_eq!(problem.severity(), Severity::Warning, "{:?}", problem);
+            assert!(
+                WARNING_NAMES.contains(&problem.name()),
+                "`{}` is a warning, but it isn't in WARNING_NAMES",
+                problem.name()
+            );
+        }
+
+        for name in WARNING_NAMES {
+            assert!(
+                warnings.iter().any(|problem| problem.name() == *name),
+                "`{}` is in WARNING_NAMES, but no warning has that name",
+                name
+            );
+        }
+    }
+}
@@ -0,0 +1,137 @@
+#![cfg(test)]
+// Even with #[allow(non_snake_case)] on individual idents, rust-analyzer issues diagnostics.
+// See https://github.com/rust-lang/rust-analyzer/issues/6541.
+// For the `v!` macro we use uppercase variables when constructing tag unions.
+#![allow(non_snake_case)]
+
+use crate::{
+    test_key_eq, test_key_neq,
+    util::{check_derivable, check_single_lset_immediate, check_underivable, derive_test},
+    v,
+};
+use insta::assert_snapshot;
+use roc_module::symbol::Symbol;
+use roc_types::subs::Variable;
+
+use roc_derive_key::{ord::FlatOrdKey, DeriveBuiltin::Compare, DeriveError, DeriveKey};
+
+test_key_eq! {
+    Compare,
+
+    same_record:
+        v!({ a: v!(U8), }), v!({ a: v!(U8), })
+    same_record_fields_diff_types:
+        v!({ a: v!(U8), }), v!({ a: v!(STR), })
+    same_record_fields_any_order:
+        v!({ a: v!(U8), b: v!(U8), c: v!(U8), }),
+        v!({ c: v!(U8), a: v!(U8), b: v!(U8), })
+    explicit_empty_record_and_implicit_empty_record:
+        v!(EMPTY_RECORD), v!({})
+
+    same_tag_union:
+        v!([ A v!(U8) v!(STR), B v!(STR) ]), v!([ A v!(U8) v!(STR), B v!(STR) ])
+    same_tag_union_tags_diff_types:
+        v!([ A v!(U8) v!(U8), B v!(U8) ]), v!([ A v!(STR) v!(STR), B v!(STR) ])
+    same_tag_union_tags_any_order:
+        v!([ A v!(U8) v!(U8), B v!(U8), C ]), v!([ C, B v!(STR), A v!(STR) v!(STR) ])
+    explicit_empty_tag_union_and_implicit_empty_tag_union:
+        v!(EMPTY_TAG_UNION), v!([])
+
+    same_recursive_tag_union:
+        v!([ Nil, Cons v!(^lst)] as lst), v!([ Nil, Cons v!(^lst)] as lst)
+    same_tag_union_and_recursive_tag_union_fields:
+        v!([ Nil, Cons v!(STR)]), v!([ Nil, Cons v!(^lst)] as lst)
+}
+
+test_key_neq! {
+    Compare,
+
+    different_record_fields:
+        v!({ a: v!(U8), }), v!({ b: v!(U8), })
+    record_empty_vs_nonempty:
+        v!(EMPTY_RECORD), v!({ a: v!(U8), })
+
+    different_tag_union_tags:
+        v!([ A v!(U8) ]), v!([ B v!(U8) ])
+    tag_union_empty_vs_nonempty:
+        v!(EMPTY_TAG_UNION), v!([ B v!(U8) ])
+    different_recursive_tag_union_tags:
+        v!([ Nil, Cons v!(^lst) ] as lst), v!([ Nil, Next v!(^lst) ] as lst)
+}
+
+#[test]
+fn immediates() {
+    check_single_lset_immediate(Compare, v!(U8), Symbol::NUM_COMPARE);
+    check_single_lset_immediate(Compare, v!(U16), Symbol::NUM_COMPARE);
+    check_single_lset_immediate(Compare, v!(U32), Symbol::NUM_COMPARE);
+    check_single_lset_immediate(Compare, v!(U64), Symbol::NUM_COMPARE);
+    check_single_lset_immediate(Compare, v!(U128), Symbol::NUM_COMPARE);
+    check_single_lset_immediate(Compare, v!(I8), Symbol::NUM_COMPARE);
+    check_single_lset_immediate(Compare, v!(I16), Symbol::NUM_COMPARE);
+    check_single_lset_immediate(Compare, v!(I32), Symbol::NUM_COMPARE);
+    check_single_lset_immediate(Compare, v!(I64), Symbol::NUM_COMPARE);
+    check_single_lset_immediate(Compare, v!(I128), Symbol::NUM_COMPARE);
+    check_single_lset_immediate(Compare, v!(NAT), Symbol::NUM_COMPARE);
+    check_single_lset_immediate(Compare, v!(DEC), Symbol::NUM_COMPARE);
+    check_single_lset_immediate(Compare, v!(STR), Symbol::STR_COMPARE);
+    check_single_lset_immediate(Compare, v!(Symbol::LIST_LIST v!(U8)), Symbol::LIST_COMPARE);
+    check_single_lset_immediate(Compare, v!(Symbol::LIST_LIST v!(STR)), Symbol::LIST_COMPARE);
+
+    // NOTE: despite this reaching an immediate, `F64`s will never actually be allowed to be
+    // compared, because obligation checking will rule them out from `compare`!
+    check_single_lset_immediate(Compare, v!(F64), Symbol::NUM_COMPARE);
+}
+
+#[test]
+fn optional_record_field_derive_error() {
+    check_underivable(Compare, v!({ ?a: v!(U8), }), DeriveError::Underivable);
+}
+
+#[test]
+fn derivable_record_with_record_ext() {
+    check_derivable(
+        Compare,
+        v!({ b: v!(STR), }{ a: v!(STR), } ),
+        DeriveKey::Compare(FlatOrdKey::Record(vec!["a".into(), "b".into()])),
+    );
+}
+
+#[test]
+fn derivable_tag_with_tag_ext() {
+    check_derivable(
+        Compare,
+        v!([ B v!(STR) v!(U8) ][ A v!(STR) ]),
+        DeriveKey::Compare(FlatOrdKey::TagUnion(vec![("A".into(), 1), ("B".into(), 2)])),
+    );
+}
+
+#[test]
+fn empty_record() {
+    derive_test(Compare, v!(EMPTY_RECORD), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for {}
+        # {}, {} -[[compare_{}(0)]]-> [EQ, GT, LT]
+        # {}, {} -[[compare_{}(0)]]-> [EQ, GT, LT]
+        # Specialization lambda sets:
+        #   @<1>: [[compare_{}(0)]]
+        #Derived.compare_{} = \#Derived.rcd1, #Derived.rcd2 -> EQ
+        "###
+        )
+    })
+}
+
+#[test]
+fn one_field_record() {
+    derive_test(Compare, v!({ a: v!(U8), }), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for { a : U8 }
+        # { a : a }, { a : a } -[[compare_{a}(0)]]-> [EQ, GT, LT] | a has Ord
+        # { a : a }, { a : a } -[[compare_{a}(0)]]-> [EQ, GT, LT] | a has Ord
+        # Specialization lambda sets:
+        #   @<1>: [[compare_{a}(0)]]
+        #Derived.compare_{a} =
+          \#Derived.rcd1, #Derived.rcd2 -> compare #Derived.rcd1.a #Derived.rcd2.a
+        "###
+        )
+    })
+}
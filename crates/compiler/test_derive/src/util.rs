@@ -63,6 +63,11 @@ fn module_source_and_path(builtin: DeriveBuiltin) -> (ModuleId, &'static str, Pa
             module_source(ModuleId::BOOL),
             builtins_path.join("Bool.roc"),
         ),
+        DeriveBuiltin::Compare => (
+            ModuleId::ORD,
+            module_source(ModuleId::ORD),
+            builtins_path.join("Ord.roc"),
+        ),
     }
 }
 
@@ -1,19 +1,28 @@
 #[cfg(feature = "gen-llvm")]
 use crate::helpers::llvm::assert_evals_to;
 
+#[cfg(feature = "gen-dev")]
+use crate::helpers::dev::assert_evals_to;
+
 #[cfg(feature = "gen-wasm")]
 use crate::helpers::wasm::assert_evals_to;
 
-#[cfg(all(test, any(feature = "gen-llvm", feature = "gen-wasm")))]
+#[cfg(all(
+    test,
+    any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev")
+))]
 use indoc::indoc;
 
 #[cfg(all(test, any(feature = "gen-llvm", feature = "gen-wasm")))]
 use roc_std::RocList;
-#[cfg(all(test, any(feature = "gen-llvm", feature = "gen-wasm")))]
+#[cfg(all(
+    test,
+    any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev")
+))]
 use roc_std::RocStr;
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn hash_specialization() {
     assert_evals_to!(
         indoc!(
@@ -36,7 +45,7 @@ fn hash_specialization() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn hash_specialization_multiple_add() {
     assert_evals_to!(
         indoc!(
@@ -63,7 +72,7 @@ fn hash_specialization_multiple_add() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn alias_member_specialization() {
     assert_evals_to!(
         indoc!(
@@ -88,7 +97,7 @@ fn alias_member_specialization() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn ability_constrained_in_non_member_usage() {
     assert_evals_to!(
         indoc!(
@@ -113,7 +122,7 @@ fn ability_constrained_in_non_member_usage() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn ability_constrained_in_non_member_usage_inferred() {
     assert_evals_to!(
         indoc!(
@@ -137,7 +146,7 @@ fn ability_constrained_in_non_member_usage_inferred() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn ability_constrained_in_non_member_multiple_specializations() {
     assert_evals_to!(
         indoc!(
@@ -165,7 +174,7 @@ fn ability_constrained_in_non_member_multiple_specializations() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn ability_constrained_in_non_member_multiple_specializations_inferred() {
     assert_evals_to!(
         indoc!(
@@ -192,7 +201,7 @@ fn ability_constrained_in_non_member_multiple_specializations_inferred() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn ability_used_as_type_still_compiles() {
     assert_evals_to!(
         indoc!(
@@ -220,7 +229,7 @@ fn ability_used_as_type_still_compiles() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn bounds_to_multiple_abilities() {
     assert_evals_to!(
         indoc!(
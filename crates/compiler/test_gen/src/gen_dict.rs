@@ -1,22 +1,25 @@
 #![cfg(all(
-    any(feature = "gen-llvm", feature = "gen-wasm"),
+    any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"),
     not(debug_assertions) // https://github.com/roc-lang/roc/issues/3898
 ))]
 
 #[cfg(feature = "gen-llvm")]
 use crate::helpers::llvm::assert_evals_to;
 
-// #[cfg(feature = "gen-dev")]
-// use crate::helpers::dev::assert_evals_to;
+#[cfg(feature = "gen-dev")]
+use crate::helpers::dev::assert_evals_to;
 
 #[cfg(feature = "gen-wasm")]
 use crate::helpers::wasm::assert_evals_to;
 
 use indoc::indoc;
+#[cfg(feature = "gen-llvm")]
+use roc_std::RocResult;
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
 use roc_std::{RocList, RocStr};
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn dict_empty_len() {
     assert_evals_to!(
         indoc!(
@@ -557,3 +560,101 @@ fn walk_sum_keys() {
         i64
     );
 }
+
+#[test]
+#[cfg(any(feature = "gen-llvm"))]
+fn with_hasher_siphash() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            dict : Dict.Dict Str I64
+            dict =
+                Dict.withHasher (SipHash13 { k0: 1, k1: 2 })
+                    |> Dict.insert "apple" 1
+                    |> Dict.insert "banana" 2
+                    |> Dict.insert "cherry" 3
+                    |> Dict.remove "banana"
+
+            Dict.walk dict 0 \acc, _, v -> acc + v
+            "#
+        ),
+        4,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm"))]
+fn with_hasher_survives_rehash() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            dict : Dict.Dict I64 I64
+            dict =
+                List.range { start: At 0, end: Before 100 }
+                |> List.walk (Dict.withHasher (Wyhash { seed: 42 })) \d, k -> Dict.insert d k (k * 2)
+
+            Dict.get dict 77
+            "#
+        ),
+        RocResult::ok(154),
+        RocResult<i64, ()>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm"))]
+fn eq_ignores_hasher_and_order() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            sipDict : Dict.Dict Str I64
+            sipDict =
+                Dict.withHasher (SipHash13 { k0: 1, k1: 2 })
+                    |> Dict.insert "apple" 1
+                    |> Dict.insert "banana" 2
+
+            wyDict : Dict.Dict Str I64
+            wyDict =
+                Dict.withHasher (Wyhash { seed: 42 })
+                    |> Dict.insert "banana" 2
+                    |> Dict.insert "apple" 1
+
+            [sipDict == wyDict, sipDict == Dict.insert wyDict "apple" 3]
+            "#
+        ),
+        RocList::from_slice(&[true, false]),
+        RocList<bool>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm"))]
+fn with_random_seed() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            Dict.withRandomSeed {}
+                |> Dict.insert "key" 42
+                |> Dict.get "key"
+            "#
+        ),
+        RocResult::ok(42),
+        RocResult<i64, ()>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm"))]
+fn random_seed_is_per_process() {
+    assert_evals_to!("Hash.randomSeed {} == Hash.randomSeed {}", true, bool);
+}
+
+#[test]
+#[cfg(any(feature = "gen-wasm"))]
+#[should_panic(
+    expected = r#"Roc failed with message: "Dict.withRandomSeed needs a random seed, but there is no operating system to get one from on wasm32.""#
+)]
+fn random_seed_on_wasm() {
+    assert_evals_to!("Hash.randomSeed {}", 0, u64);
+}
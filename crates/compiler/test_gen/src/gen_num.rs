@@ -31,7 +31,7 @@ fn nat_alias() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn i128_signed_int_alias() {
     assert_evals_to!(
         indoc!(
@@ -196,7 +196,7 @@ fn i8_hex_int_alias() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn u128_signed_int_alias() {
     assert_evals_to!(
         indoc!(
@@ -615,7 +615,7 @@ fn i64_abs() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn various_sized_abs() {
     assert_evals_to!("Num.abs -6i8", 6, i8);
     assert_evals_to!("Num.abs -6i16", 6, i16);
@@ -675,7 +675,7 @@ fn gen_if_fn() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn gen_float_eq() {
     assert_evals_to!(
         indoc!(
@@ -689,7 +689,7 @@ fn gen_float_eq() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn gen_add_dec() {
     assert_evals_to!(
         indoc!(
@@ -798,7 +798,7 @@ fn gen_div_checked_by_zero_f64() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn gen_div_dec() {
     assert_evals_to!(
         indoc!(
@@ -875,7 +875,7 @@ fn gen_int_eq() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn gen_int_neq() {
     assert_evals_to!(
         indoc!(
@@ -943,7 +943,7 @@ fn gen_dec_neq() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn gen_wrap_int_neq() {
     assert_evals_to!(
         indoc!(
@@ -1059,7 +1059,7 @@ fn gen_add_i64() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn gen_sub_dec() {
     assert_evals_to!(
         indoc!(
@@ -1082,7 +1082,7 @@ fn gen_sub_dec() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn gen_sub_f64() {
     assert_evals_to!(
         indoc!(
@@ -1110,7 +1110,7 @@ fn gen_sub_i64() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn gen_mul_dec() {
     assert_evals_to!(
         indoc!(
@@ -1352,7 +1352,7 @@ fn lt_u8() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn lte_u8() {
     assert_evals_to!("1u8 <= 1u8", true, bool);
     assert_evals_to!("2u8 <= 1u8", false, bool);
@@ -1372,7 +1372,7 @@ fn gt_u8() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn gte_u8() {
     assert_evals_to!("1u8 >= 1u8", true, bool);
     assert_evals_to!("1u8 >= 2u8", false, bool);
@@ -1392,7 +1392,7 @@ fn lt_u64() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn lte_u64() {
     assert_evals_to!("1u64 <= 1u64", true, bool);
     assert_evals_to!("2u64 <= 1u64", false, bool);
@@ -1412,7 +1412,7 @@ fn gt_u64() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn gte_u64() {
     assert_evals_to!("1u64 >= 1u64", true, bool);
     assert_evals_to!("1u64 >= 2u64", false, bool);
@@ -1431,7 +1431,7 @@ fn lt_i64() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn lte_i64() {
     assert_evals_to!("1 <= 1", true, bool);
     assert_evals_to!("2 <= 1", false, bool);
@@ -1449,7 +1449,7 @@ fn gt_i64() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn gte_i64() {
     assert_evals_to!("1 >= 1", true, bool);
     assert_evals_to!("1 >= 2", false, bool);
@@ -1467,7 +1467,7 @@ fn lt_f64() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn lte_f64() {
     assert_evals_to!("1.1 <= 1.1", true, bool);
     assert_evals_to!("1.2 <= 1.1", false, bool);
@@ -1476,7 +1476,7 @@ fn lte_f64() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn gt_f64() {
     assert_evals_to!("2.2 > 1.1", true, bool);
     assert_evals_to!("2.2 > 2.2", false, bool);
@@ -1485,7 +1485,7 @@ fn gt_f64() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn gte_f64() {
     assert_evals_to!("1.1 >= 1.1", true, bool);
     assert_evals_to!("1.1 >= 1.2", false, bool);
@@ -1615,7 +1615,39 @@ fn neg_min_int_overflow() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn float_negate() {
+    assert_evals_to!("Num.neg 1.5f64", -1.5, f64);
+    assert_evals_to!("Num.neg -2.25f32", 2.25, f32);
+    assert_evals_to!("Num.neg 0.0f64", true, f64, |x: f64| x.is_sign_negative());
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn float_abs_f32() {
+    assert_evals_to!("Num.abs -2.5f32", 2.5, f32);
+    assert_evals_to!("Num.abs 2.5f32", 2.5, f32);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn various_sized_negate() {
+    assert_evals_to!("Num.neg 5i8", -5, i8);
+    assert_evals_to!("Num.neg 100i16", -100, i16);
+    assert_evals_to!("Num.neg -7i32", 7, i32);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+#[should_panic(
+    expected = r#"Roc failed with message: "integer negation overflowed because its argument is the minimum value"#
+)]
+fn neg_min_i8_overflow() {
+    assert_evals_to!("Num.neg -128i8", 0, i8);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn gen_wrap_int_neg() {
     assert_evals_to!(
         indoc!(
@@ -1660,7 +1692,7 @@ fn num_to_frac() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn num_to_frac_f64_to_f32() {
     assert_evals_to!(
         indoc!(
@@ -1679,7 +1711,7 @@ fn num_to_frac_f64_to_f32() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn num_to_frac_f32_to_f32() {
     assert_evals_to!(
         indoc!(
@@ -1760,6 +1792,23 @@ fn float_compare() {
     assert_evals_to!("Num.compare 3.14 0.01", RocOrder::Gt, RocOrder);
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn sort_asc_desc_floats() {
+    use roc_std::RocList;
+
+    assert_evals_to!(
+        "Num.sortAsc [2.5f64, 1.0, 3.0]",
+        RocList::from_slice(&[1.0f64, 2.5, 3.0]),
+        RocList<f64>
+    );
+    assert_evals_to!(
+        "Num.sortDesc [2.5f64, 1.0, 3.0]",
+        RocList::from_slice(&[3.0f64, 2.5, 1.0]),
+        RocList<f64>
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
 fn pow() {
@@ -1836,7 +1885,7 @@ fn int_add_wrap() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn float_add_checked_pass() {
     assert_evals_to!(
         "Num.addChecked 1.0 0.0",
@@ -1846,7 +1895,7 @@ fn float_add_checked_pass() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn float_add_checked_fail() {
     assert_evals_to!(
         "Num.addChecked 1.7976931348623157e308 1.7976931348623157e308",
@@ -1856,7 +1905,7 @@ fn float_add_checked_fail() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen_dev"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn float_add_overflow() {
     assert_evals_to!(
         "1.7976931348623157e308 + 1.7976931348623157e308",
@@ -1895,7 +1944,22 @@ fn int_sub_wrap() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn small_int_sub_wrap() {
+    assert_evals_to!("Num.subWrap 0u8 1", 255, u8);
+    assert_evals_to!("Num.subWrap -128i8 1", 127, i8);
+    assert_evals_to!("Num.subWrap 0u32 2", u32::MAX - 1, u32);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+#[should_panic(expected = r#"Roc failed with message: "integer addition overflowed!"#)]
+fn u8_add_overflow() {
+    assert_evals_to!("255u8 + 1", 0, u8);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn float_sub_overflow() {
     assert_evals_to!(
         "-1.7976931348623157e308 - 1.7976931348623157e308",
@@ -1991,7 +2055,7 @@ fn int_negative_mul_overflow() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn float_positive_mul_overflow() {
     assert_evals_to!(
         indoc!(
@@ -2005,7 +2069,7 @@ fn float_positive_mul_overflow() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn float_negative_mul_overflow() {
     assert_evals_to!(
         indoc!(
@@ -2159,7 +2223,7 @@ fn shift_right_zf_by() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn min_i128() {
     assert_evals_to!(
         indoc!(
@@ -2173,7 +2237,7 @@ fn min_i128() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn max_i128() {
     assert_evals_to!(
         indoc!(
@@ -2476,7 +2540,7 @@ fn to_nat_truncate_wraps() {
 macro_rules! num_conversion_tests {
     ($($fn:expr, $typ:ty, ($($test_name:ident, $input:expr, $output:expr $(, [$($support_gen:literal),*])? )*))*) => {$($(
         #[test]
-        #[cfg(any(feature = "gen-llvm", $($(feature = $support_gen)*)?))]
+        #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", $($(feature = $support_gen)*)?))]
         fn $test_name() {
             let input = format!("{} {}", $fn, $input);
             assert_evals_to!(&input, $output, $typ)
@@ -2486,25 +2550,25 @@ macro_rules! num_conversion_tests {
 
 num_conversion_tests! {
     "Num.toI8", i8, (
-        to_i8_same_width, "15u8", 15, ["gen-wasm"]
-        to_i8_truncate, "115i32", 115, ["gen-wasm"]
-        to_i8_truncate_wraps, "500i32", -12, ["gen-wasm"]
+        to_i8_same_width, "15u8", 15
+        to_i8_truncate, "115i32", 115
+        to_i8_truncate_wraps, "500i32", -12
     )
     "Num.toI16", i16, (
-        to_i16_same_width, "15u16", 15, ["gen-wasm"]
-        to_i16_extend, "15i8", 15, ["gen-wasm"]
-        to_i16_truncate, "115i32", 115, ["gen-wasm"]
-        to_i16_truncate_wraps, "60000i32", -5536, ["gen-wasm"]
+        to_i16_same_width, "15u16", 15
+        to_i16_extend, "15i8", 15
+        to_i16_truncate, "115i32", 115
+        to_i16_truncate_wraps, "60000i32", -5536
     )
     "Num.toI32", i32, (
-        to_i32_same_width, "15u32", 15, ["gen-wasm"]
-        to_i32_extend, "15i8", 15, ["gen-wasm"]
-        to_i32_truncate, "115i64", 115, ["gen-wasm"]
-        to_i32_truncate_wraps, "5000000000i64", 705032704, ["gen-wasm"]
+        to_i32_same_width, "15u32", 15
+        to_i32_extend, "15i8", 15
+        to_i32_truncate, "115i64", 115
+        to_i32_truncate_wraps, "5000000000i64", 705032704
     )
     "Num.toI64", i64, (
-        to_i64_same_width, "15u64", 15, ["gen-wasm"]
-        to_i64_extend, "15i8", 15, ["gen-wasm"]
+        to_i64_same_width, "15u64", 15
+        to_i64_extend, "15i8", 15
         to_i64_truncate, "115i128", 115
         to_i64_truncate_wraps, "10_000_000_000_000_000_000i128", -8446744073709551616
     )
@@ -2513,25 +2577,25 @@ num_conversion_tests! {
         to_i128_extend, "15i8", 15
     )
     "Num.toU8", u8, (
-        to_u8_same_width, "15i8", 15, ["gen-wasm"]
-        to_u8_truncate, "115i32", 115, ["gen-wasm"]
-        to_u8_truncate_wraps, "500i32", 244, ["gen-wasm"]
+        to_u8_same_width, "15i8", 15
+        to_u8_truncate, "115i32", 115
+        to_u8_truncate_wraps, "500i32", 244
     )
     "Num.toU16", u16, (
-        to_u16_same_width, "15i16", 15, ["gen-wasm"]
-        to_u16_extend, "15i8", 15, ["gen-wasm"]
-        to_u16_truncate, "115i32", 115, ["gen-wasm"]
-        to_u16_truncate_wraps, "600000000i32", 17920, ["gen-wasm"]
+        to_u16_same_width, "15i16", 15
+        to_u16_extend, "15i8", 15
+        to_u16_truncate, "115i32", 115
+        to_u16_truncate_wraps, "600000000i32", 17920
     )
     "Num.toU32", u32, (
-        to_u32_same_width, "15i32", 15, ["gen-wasm"]
-        to_u32_extend, "15i8", 15, ["gen-wasm"]
-        to_u32_truncate, "115i64", 115, ["gen-wasm"]
-        to_u32_truncate_wraps, "5000000000000000000i64", 1156841472, ["gen-wasm"]
+        to_u32_same_width, "15i32", 15
+        to_u32_extend, "15i8", 15
+        to_u32_truncate, "115i64", 115
+        to_u32_truncate_wraps, "5000000000000000000i64", 1156841472
     )
     "Num.toU64", u64, (
-        to_u64_same_width, "15i64", 15, ["gen-wasm"]
-        to_u64_extend, "15i8", 15, ["gen-wasm"]
+        to_u64_same_width, "15i64", 15
+        to_u64_extend, "15i8", 15
         to_u64_truncate, "115i128", 115
         to_u64_truncate_wraps, "10_000_000_000_000_000_000_000i128", 1864712049423024128
     )
@@ -2540,8 +2604,8 @@ num_conversion_tests! {
         to_u128_extend, "15i8", 15
     )
     "Num.toNat", usize, (
-        to_nat_same_width, "15i64", 15, ["gen-wasm"]
-        to_nat_extend, "15i8", 15, ["gen-wasm"]
+        to_nat_same_width, "15i64", 15
+        to_nat_extend, "15i8", 15
         to_nat_truncate, "115i128", 115
     )
     "Num.toF32", f32, (
@@ -2920,6 +2984,354 @@ fn bytes_to_u32_random_u8s() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn read_u64_le_random_u8s() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                when Num.readU64Le [1, 2, 3, 4, 5, 6, 7, 8, 9] 1 is
+                    Ok v -> v
+                    Err OutOfBounds -> 1
+                "#
+        ),
+        0x0908_0706_0504_0302,
+        u64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn read_u64_le_subtly_out_of_bounds() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                when Num.readU64Le [1, 2, 3, 4, 5, 6, 7, 8] 1 is
+                    Ok v -> v
+                    Err OutOfBounds -> 1
+                "#
+        ),
+        1,
+        u64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn read_u128_le_max_u8s() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                bytes = [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255]
+
+                when Num.readU128Le bytes 0 is
+                    Ok v -> v
+                    Err OutOfBounds -> 1
+                "#
+        ),
+        u128::MAX,
+        u128
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn write_u32_le() {
+    use roc_std::RocList;
+
+    assert_evals_to!(
+        indoc!(
+            r#"
+                when Num.writeU32Le [0, 0, 0, 0] 0 0x0403_0201 is
+                    Ok bytes -> bytes
+                    Err OutOfBounds -> []
+                "#
+        ),
+        RocList::from_slice(&[1u8, 2, 3, 4]),
+        RocList<u8>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn write_u64_le_round_trip() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                when Num.writeU64Le [0, 0, 0, 0, 0, 0, 0, 0] 0 0x0123_4567_89AB_CDEF is
+                    Ok bytes ->
+                        when Num.readU64Le bytes 0 is
+                            Ok v -> v
+                            Err OutOfBounds -> 1
+                    Err OutOfBounds -> 2
+                "#
+        ),
+        0x0123_4567_89AB_CDEF,
+        u64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn bytes_to_u64_random_u8s() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                when Num.bytesToU64 [1, 2, 3, 4, 5, 6, 7, 8, 9] 1 is
+                    Ok v -> v
+                    Err OutOfBounds -> 1
+                "#
+        ),
+        0x0908_0706_0504_0302,
+        u64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn bytes_to_u64_subtly_out_of_bounds() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                when Num.bytesToU64 [1, 2, 3, 4, 5, 6, 7, 8] 1 is
+                    Ok v -> v
+                    Err OutOfBounds -> 1
+                "#
+        ),
+        1,
+        u64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn bytes_to_u128_max_u8s() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                bytes = [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255]
+
+                when Num.bytesToU128 bytes 0 is
+                    Ok v -> v
+                    Err OutOfBounds -> 1
+                "#
+        ),
+        u128::MAX,
+        u128
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn u32_to_bytes() {
+    use roc_std::RocList;
+
+    assert_evals_to!(
+        "Num.u32ToBytes 0x0403_0201",
+        RocList::from_slice(&[1u8, 2, 3, 4]),
+        RocList<u8>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn u64_to_bytes_round_trip() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                when Num.bytesToU64 (Num.u64ToBytes 0x0123_4567_89AB_CDEF) 0 is
+                    Ok v -> v
+                    Err OutOfBounds -> 1
+                "#
+        ),
+        0x0123_4567_89AB_CDEF,
+        u64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn read_u32_le_and_be() {
+    assert_evals_to!(
+        "Num.readU32Le [0xFF, 0x78, 0x56, 0x34, 0x12] 1",
+        RocResult::ok(0x1234_5678),
+        RocResult<u32, ()>
+    );
+    assert_evals_to!(
+        "Num.readU32Be [0xFF, 0x78, 0x56, 0x34, 0x12] 1",
+        RocResult::ok(0x7856_3412),
+        RocResult<u32, ()>
+    );
+    assert_evals_to!("Num.readU8 [0x12, 0x34] 1", RocResult::ok(0x34), RocResult<u8, ()>);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn write_u16_be() {
+    use roc_std::RocList;
+
+    assert_evals_to!(
+        indoc!(
+            r#"
+                when Num.writeU16Be [0, 0, 0, 0] 1 0x1234 is
+                    Ok bytes -> bytes
+                    Err OutOfBounds -> []
+                "#
+        ),
+        RocList::from_slice(&[0u8, 0x12, 0x34, 0]),
+        RocList<u8>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn write_f64_le_and_be() {
+    use roc_std::RocList;
+
+    assert_evals_to!(
+        indoc!(
+            r#"
+                when Num.writeF64Le [0, 0, 0, 0, 0, 0, 0, 0] 0 1.5 is
+                    Ok bytes -> bytes
+                    Err OutOfBounds -> []
+                "#
+        ),
+        RocList::from_slice(&[0u8, 0, 0, 0, 0, 0, 0xF8, 0x3F]),
+        RocList<u8>
+    );
+    assert_evals_to!(
+        indoc!(
+            r#"
+                when Num.writeF64Be [0, 0, 0, 0, 0, 0, 0, 0] 0 1.5 is
+                    Ok bytes -> bytes
+                    Err OutOfBounds -> []
+                "#
+        ),
+        RocList::from_slice(&[0x3Fu8, 0xF8, 0, 0, 0, 0, 0, 0]),
+        RocList<u8>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn read_write_bytes_round_trip() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                bytes = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+
+                when Num.writeU128Be bytes 3 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210 is
+                    Ok written ->
+                        when Num.readU128Be written 3 is
+                            Ok v -> v
+                            Err OutOfBounds -> 1
+                    Err OutOfBounds -> 2
+                "#
+        ),
+        0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210,
+        u128
+    );
+    assert_evals_to!(
+        indoc!(
+            r#"
+                when Num.writeF32Be [0, 0, 0, 0, 0] 1 -2.5 is
+                    Ok written ->
+                        when Num.readF32Be written 1 is
+                            Ok v -> v
+                            Err OutOfBounds -> 1
+                    Err OutOfBounds -> 2
+                "#
+        ),
+        -2.5,
+        f32
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn read_write_bytes_out_of_bounds() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                isOutOfBounds = \result ->
+                    when result is
+                        Ok _ -> 0
+                        Err OutOfBounds -> 1
+
+                isOutOfBounds (Num.readU8 [] 0)
+                + isOutOfBounds (Num.readU16Le [1] 0)
+                + isOutOfBounds (Num.readU32Be [1, 2, 3, 4] 1)
+                + isOutOfBounds (Num.readU64Le [1, 2] 234)
+                + isOutOfBounds (Num.writeU64Le [0, 0, 0, 0, 0, 0, 0] 0 1)
+                + isOutOfBounds (Num.writeF32Be [1, 2, 3, 4] 0 1.0)
+                "#
+        ),
+        5,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn count_leading_zero_bits() {
+    assert_evals_to!("Num.countLeadingZeroBits 0b0010_1000u8", 2, u8);
+    assert_evals_to!("Num.countLeadingZeroBits 0u8", 8, u8);
+    assert_evals_to!("Num.countLeadingZeroBits 0b0010_1000u16", 10, u8);
+    assert_evals_to!("Num.countLeadingZeroBits 1u32", 31, u8);
+    assert_evals_to!("Num.countLeadingZeroBits -1i64", 0, u8);
+    assert_evals_to!("Num.countLeadingZeroBits 1u128", 127, u8);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn count_trailing_zero_bits() {
+    assert_evals_to!("Num.countTrailingZeroBits 0b0010_1000u8", 3, u8);
+    assert_evals_to!("Num.countTrailingZeroBits 0u16", 16, u8);
+    assert_evals_to!("Num.countTrailingZeroBits 0x8000_0000u32", 31, u8);
+    assert_evals_to!("Num.countTrailingZeroBits 0u64", 64, u8);
+    assert_evals_to!("Num.countTrailingZeroBits 0i128", 128, u8);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn count_one_bits() {
+    assert_evals_to!("Num.countOneBits 0b0010_1000u8", 2, u8);
+    assert_evals_to!("Num.countOneBits -1i16", 16, u8);
+    assert_evals_to!("Num.countOneBits 0xFFFF_0000u32", 16, u8);
+    assert_evals_to!("Num.countOneBits Num.maxU64", 64, u8);
+    assert_evals_to!("Num.countOneBits Num.maxU128", 128, u8);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn rotate_left_by() {
+    assert_evals_to!("Num.rotateLeftBy 0b1000_0011u8 2", 0b0000_1110, u8);
+    assert_evals_to!("Num.rotateLeftBy 0x8000_0001u32 1", 3, u32);
+    assert_evals_to!("Num.rotateLeftBy 1u64 64", 1, u64);
+    assert_evals_to!("Num.rotateLeftBy -2i64 1", -3, i64);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn rotate_right_by() {
+    assert_evals_to!("Num.rotateRightBy 0b1000_0011u8 2", 0b1110_0000, u8);
+    assert_evals_to!("Num.rotateRightBy 3u32 1", 0x8000_0001, u32);
+    assert_evals_to!("Num.rotateRightBy 1u16 17", 0x8000, u16);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn swap_bytes() {
+    assert_evals_to!("Num.swapBytes 0x12u8", 0x12, u8);
+    assert_evals_to!("Num.swapBytes 0x1234u16", 0x3412, u16);
+    assert_evals_to!("Num.swapBytes 0x1234_5678u32", 0x7856_3412, u32);
+    assert_evals_to!(
+        "Num.swapBytes 0x0102_0304_0506_0708u64",
+        0x0807_0605_0403_0201,
+        u64
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
 fn when_on_i32() {
@@ -2967,7 +3379,7 @@ fn when_on_i16() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn num_to_str() {
     use roc_std::RocStr;
 
@@ -2991,7 +3403,7 @@ fn num_to_str() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn num_to_str_u8() {
     use roc_std::RocStr;
 
@@ -3004,7 +3416,7 @@ fn num_to_str_u8() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn num_to_str_u16() {
     use roc_std::RocStr;
 
@@ -3021,7 +3433,7 @@ fn num_to_str_u16() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn num_to_str_u32() {
     use roc_std::RocStr;
 
@@ -3038,7 +3450,7 @@ fn num_to_str_u32() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn num_to_str_u64() {
     use roc_std::RocStr;
 
@@ -3055,7 +3467,7 @@ fn num_to_str_u64() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn num_to_str_i8() {
     use roc_std::RocStr;
 
@@ -3073,7 +3485,7 @@ fn num_to_str_i8() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn num_to_str_i16() {
     use roc_std::RocStr;
 
@@ -3099,7 +3511,7 @@ fn num_to_str_i16() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn num_to_str_i32() {
     use roc_std::RocStr;
 
@@ -3125,7 +3537,7 @@ fn num_to_str_i32() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn num_to_str_i64() {
     use roc_std::RocStr;
 
@@ -3175,7 +3587,7 @@ fn num_to_str_f32() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn num_to_str_f64() {
     use roc_std::RocStr;
 
@@ -3199,7 +3611,7 @@ fn num_to_str_f64() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn num_to_str_dec() {
     use roc_std::RocStr;
 
@@ -3222,6 +3634,108 @@ fn num_to_str_dec() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn num_to_str_fixed() {
+    use roc_std::RocStr;
+
+    assert_evals_to!(
+        r#"Num.toStrFixed 1234.5678dec 2"#,
+        RocStr::from("1234.57"),
+        RocStr
+    );
+    assert_evals_to!(r#"Num.toStrFixed 0.125dec 2"#, RocStr::from("0.13"), RocStr);
+    assert_evals_to!(
+        r#"Num.toStrFixed -0.125dec 2"#,
+        RocStr::from("-0.13"),
+        RocStr
+    );
+    assert_evals_to!(r#"Num.toStrFixed 42dec 0"#, RocStr::from("42"), RocStr);
+    assert_evals_to!(r#"Num.toStrFixed 2.675f64 2"#, RocStr::from("2.68"), RocStr);
+    assert_evals_to!(r#"Num.toStrFixed 1.5f32 3"#, RocStr::from("1.500"), RocStr);
+    assert_evals_to!(
+        r#"Num.toStrFixed (-1.0f64 / 0.0) 2"#,
+        RocStr::from("-∞"),
+        RocStr
+    );
+    assert_evals_to!(
+        r#"Num.toStrScientific (0.0f64 / 0.0) 2"#,
+        RocStr::from("NaN"),
+        RocStr
+    );
+    assert_evals_to!(r#"Num.toStr (1.0f64 / 0.0)"#, RocStr::from("∞"), RocStr);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn num_to_str_scientific() {
+    use roc_std::RocStr;
+
+    assert_evals_to!(
+        r#"Num.toStrScientific 1234.5678dec 2"#,
+        RocStr::from("1.23e+03"),
+        RocStr
+    );
+    assert_evals_to!(
+        r#"Num.toStrScientific 0.00042f64 1"#,
+        RocStr::from("4.2e-04"),
+        RocStr
+    );
+    assert_evals_to!(
+        r#"Num.toStrScientific 9.99dec 1"#,
+        RocStr::from("1.0e+01"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn num_to_str_radix() {
+    use roc_std::RocStr;
+
+    assert_evals_to!(r#"Num.toStrHex 255"#, RocStr::from("ff"), RocStr);
+    assert_evals_to!(r#"Num.toStrHex -255i16"#, RocStr::from("-ff"), RocStr);
+    assert_evals_to!(r#"Num.toStrOctal 8u8"#, RocStr::from("10"), RocStr);
+    assert_evals_to!(r#"Num.toStrBinary 5u32"#, RocStr::from("101"), RocStr);
+    assert_evals_to!(
+        r#"Num.toStrHex Num.maxU128"#,
+        RocStr::from("ffffffffffffffffffffffffffffffff"),
+        RocStr
+    );
+    assert_evals_to!(
+        r#"Num.toStrBinary Num.minI8"#,
+        RocStr::from("-10000000"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn num_pad_zeros_and_group_digits() {
+    use roc_std::RocStr;
+
+    assert_evals_to!(
+        r#"Num.padZeros (Num.toStr 42) 5"#,
+        RocStr::from("00042"),
+        RocStr
+    );
+    assert_evals_to!(
+        r#"Num.padZeros (Num.toStrHex -255i16) 4"#,
+        RocStr::from("-00ff"),
+        RocStr
+    );
+    assert_evals_to!(
+        r#"Num.groupDigits (Num.toStr 1234567) ",""#,
+        RocStr::from("1,234,567"),
+        RocStr
+    );
+    assert_evals_to!(
+        r#"Num.groupDigits (Num.toStrFixed -1234567.891dec 2) " ""#,
+        RocStr::from("-1 234 567.89"),
+        RocStr
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
 fn u8_addition_greater_than_i8() {
@@ -3825,3 +4339,138 @@ fn condition_polymorphic_num_becomes_float() {
         f32
     );
 }
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn compare_i128() {
+    assert_evals_to!("18446744073709551616i128 > -1i128", true, bool);
+    assert_evals_to!("-18446744073709551616i128 >= -1i128", false, bool);
+    assert_evals_to!("-18446744073709551616i128 < -1i128", true, bool);
+    assert_evals_to!(
+        "18446744073709551616i128 <= 18446744073709551616i128",
+        true,
+        bool
+    );
+    assert_evals_to!(
+        "Num.compare -18446744073709551616i128 18446744073709551616i128",
+        RocOrder::Lt,
+        RocOrder
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn compare_u128() {
+    // would be -1 if it were compared as a signed number
+    assert_evals_to!(
+        "340282366920938463463374607431768211455u128 > 1u128",
+        true,
+        bool
+    );
+    assert_evals_to!(
+        "Num.compare 340282366920938463463374607431768211455u128 1u128",
+        RocOrder::Gt,
+        RocOrder
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn div_and_rem_i128() {
+    assert_evals_to!("-18446744073709551617i128 // 2", -9223372036854775808, i128);
+    assert_evals_to!("Num.rem -18446744073709551617i128 2", -1, i128);
+    assert_evals_to!("Num.isMultipleOf 36893488147419103232i128 -2", true, bool);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn bitwise_u128() {
+    assert_evals_to!(
+        "Num.bitwiseXor 18446744073709551616u128 1",
+        18446744073709551617,
+        u128
+    );
+    assert_evals_to!(
+        "Num.bitwiseAnd 340282366920938463463374607431768211455u128 18446744073709551617",
+        18446744073709551617,
+        u128
+    );
+    assert_evals_to!(
+        "Num.bitwiseOr 18446744073709551616u128 1",
+        18446744073709551617,
+        u128
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn shift_128() {
+    assert_evals_to!("Num.shiftLeftBy 1u128 100", 1u128 << 100, u128);
+    assert_evals_to!("Num.shiftRightBy -18446744073709551616i128 64", -1, i128);
+    assert_evals_to!(
+        "Num.shiftRightZfBy 340282366920938463463374607431768211455u128 127",
+        1,
+        u128
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-wasm")]
+fn dec_compare_and_abs() {
+    assert_evals_to!("1.5dec > 1.25dec", true, bool);
+    assert_evals_to!("-1.5dec >= 1.25dec", false, bool);
+    assert_evals_to!("Num.compare 1.5dec 1.5dec", RocOrder::Eq, RocOrder);
+    assert_evals_to!(
+        "Num.abs -1.5dec",
+        RocDec::from_str_to_i128_unsafe("1.5"),
+        i128
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-wasm")]
+fn dec_conversions() {
+    assert_evals_to!("Num.toF64 -1.5dec", -1.5, f64);
+    assert_evals_to!(
+        indoc!(
+            r#"
+            n : I128
+            n = Num.round 2.5dec
+            n
+            "#
+        ),
+        3,
+        i128
+    );
+    assert_evals_to!(
+        indoc!(
+            r#"
+            d : Dec
+            d = Num.toFrac 18446744073709551616i128
+            d
+            "#
+        ),
+        RocDec::from_str_to_i128_unsafe("18446744073709551616.0"),
+        i128
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-wasm")]
+fn to_float_checked() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            big : F64
+            big = 1e300
+
+            Result.withDefault (Num.toF32Checked big) 23
+            "#
+        ),
+        23.0,
+        f32
+    );
+    assert_evals_to!("Result.withDefault (Num.toF32Checked 1.5f64) 23", 1.5, f32);
+    assert_evals_to!("Result.withDefault (Num.toF64Checked 15i128) 23", 15.0, f64);
+    assert_evals_to!("Result.withDefault (Num.toF64Checked 1.5dec) 23", 1.5, f64);
+}
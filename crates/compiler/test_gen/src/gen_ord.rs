@@ -0,0 +1,270 @@
+#![cfg(all(
+    any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"),
+    not(debug_assertions) // https://github.com/roc-lang/roc/issues/3898
+))]
+
+#[cfg(feature = "gen-llvm")]
+use crate::helpers::llvm::assert_evals_to;
+
+#[cfg(feature = "gen-dev")]
+use crate::helpers::dev::assert_evals_to;
+
+#[cfg(feature = "gen-wasm")]
+use crate::helpers::wasm::assert_evals_to;
+
+use indoc::indoc;
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+use roc_std::{RocList, RocResult, RocStr};
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_compare() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            toI64 = \order ->
+                when order is
+                    LT -> -1
+                    EQ -> 0
+                    GT -> 1
+
+            [
+                Str.compare "apple" "banana",
+                Str.compare "banana" "apple",
+                Str.compare "app" "apple",
+                Str.compare "apple" "apple",
+                Str.compare "" "",
+            ]
+            |> List.map toI64
+            "#
+        ),
+        RocList::from_slice(&[-1, 1, -1, 0, 0]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn list_compare() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            toI64 = \order ->
+                when order is
+                    LT -> -1
+                    EQ -> 0
+                    GT -> 1
+
+            [
+                List.compare [1, 2, 3] [1, 2, 4],
+                List.compare [1, 3] [1, 2, 4],
+                List.compare [1, 2] [1, 2, 3],
+                List.compare [] [],
+                List.compare [-5] [5],
+            ]
+            |> List.map toI64
+            "#
+        ),
+        RocList::from_slice(&[-1, 1, -1, 0, -1]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn num_compare_dec() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            x : Dec
+            x = -1.5
+
+            when Num.compare x 0.25 is
+                LT -> Bool.true
+                _ -> Bool.false
+            "#
+        ),
+        true,
+        bool
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn ord_dict_keys_are_sorted() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            OrdDict.empty {}
+            |> OrdDict.insert 3 "three"
+            |> OrdDict.insert 1 "one"
+            |> OrdDict.insert 2 "two"
+            |> OrdDict.insert -4 "minus four"
+            |> OrdDict.keys
+            "#
+        ),
+        RocList::from_slice(&[-4, 1, 2, 3]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn ord_dict_insert_replaces_value() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            OrdDict.fromList [T "b" 1, T "a" 2, T "b" 3]
+            |> OrdDict.get "b"
+            "#
+        ),
+        RocResult::ok(3),
+        RocResult<i64, ()>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn ord_dict_remove() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            OrdDict.fromList (List.map (List.range { start: At 0, end: Before 10 }) \k -> T k k)
+            |> OrdDict.remove 3
+            |> OrdDict.remove 7
+            |> OrdDict.remove 42
+            |> OrdDict.values
+            "#
+        ),
+        RocList::from_slice(&[0, 1, 2, 4, 5, 6, 8, 9]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn ord_dict_min_max() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            dict = OrdDict.fromList [T 5 "five", T 2 "two", T 9 "nine"]
+
+            when T (OrdDict.min dict) (OrdDict.max dict) is
+                T (Ok (T lo _)) (Ok (T hi _)) -> hi - lo
+                _ -> -1
+            "#
+        ),
+        7,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn ord_dict_floor_ceiling() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            dict = OrdDict.fromList [T 10 {}, T 20 {}, T 30 {}]
+
+            keyOr = \result, default ->
+                when result is
+                    Ok (T k _) -> k
+                    Err KeyNotFound -> default
+
+            [
+                keyOr (OrdDict.floor dict 25) -1,
+                keyOr (OrdDict.floor dict 20) -1,
+                keyOr (OrdDict.floor dict 5) -1,
+                keyOr (OrdDict.ceiling dict 25) -1,
+                keyOr (OrdDict.ceiling dict 35) -1,
+            ]
+            "#
+        ),
+        RocList::from_slice(&[20, 20, -1, 30, -1]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn ord_dict_range() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            OrdDict.fromList (List.map (List.range { start: At 0, end: Before 10 }) \k -> T k {})
+            |> OrdDict.range { start: After 2, end: At 6 }
+            |> OrdDict.keys
+            "#
+        ),
+        RocList::from_slice(&[3, 4, 5, 6]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn ord_dict_walk_from() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            OrdDict.fromList [T 1 "a", T 3 "b", T 5 "c", T 7 "d"]
+            |> OrdDict.walkFrom 4 "" \state, _, v -> Str.concat state v
+            "#
+        ),
+        RocStr::from("cd"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn ord_dict_opaque_key() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            Version := U32 has [Ord]
+
+            main =
+                OrdDict.empty {}
+                |> OrdDict.insert (@Version 3) "c"
+                |> OrdDict.insert (@Version 1) "a"
+                |> OrdDict.insert (@Version 2) "b"
+                |> OrdDict.values
+            "#
+        ),
+        RocList::from_slice(&[RocStr::from("a"), RocStr::from("b"), RocStr::from("c")]),
+        RocList<RocStr>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn ord_set_union() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            OrdSet.union (OrdSet.fromList [5, 1, 3]) (OrdSet.fromList [4, 3, 2])
+            |> OrdSet.toList
+            "#
+        ),
+        RocList::from_slice(&[1, 2, 3, 4, 5]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn ord_set_eq() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            OrdSet.fromList ["b", "a"] == OrdSet.fromList ["a", "b", "a"]
+            "#
+        ),
+        true,
+        bool
+    );
+}
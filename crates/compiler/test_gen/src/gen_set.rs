@@ -1,22 +1,23 @@
 #![cfg(all(
-    any(feature = "gen-llvm"),
+    any(feature = "gen-llvm", feature = "gen-dev"),
     not(debug_assertions) // https://github.com/roc-lang/roc/issues/3898
 ))]
 
 #[cfg(feature = "gen-llvm")]
 use crate::helpers::llvm::assert_evals_to;
 
-// #[cfg(feature = "gen-dev")]
-// use crate::helpers::dev::assert_evals_to;
+#[cfg(feature = "gen-dev")]
+use crate::helpers::dev::assert_evals_to;
 
 // #[cfg(feature = "gen-wasm")]
 // use crate::helpers::wasm::assert_evals_to;
 
 use indoc::indoc;
+#[cfg(feature = "gen-llvm")]
 use roc_std::RocList;
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn empty_len() {
     assert_evals_to!(
         indoc!(
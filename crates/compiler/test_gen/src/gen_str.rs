@@ -100,7 +100,7 @@ fn str_split_str_concat_repeated() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_split_small_str_bigger_delimiter() {
     assert_evals_to!(
         indoc!(r#"Str.split "JJJ" "0123456789abcdefghi""#),
@@ -110,7 +110,7 @@ fn str_split_small_str_bigger_delimiter() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_split_big_str_small_delimiter() {
     assert_evals_to!(
         indoc!(
@@ -140,7 +140,7 @@ fn str_split_big_str_small_delimiter() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_split_small_str_small_delimiter() {
     assert_evals_to!(
         indoc!(
@@ -154,7 +154,7 @@ fn str_split_small_str_small_delimiter() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_split_bigger_delimiter_big_strs() {
     assert_evals_to!(
         indoc!(
@@ -170,7 +170,7 @@ fn str_split_bigger_delimiter_big_strs() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_split_empty_strs() {
     assert_evals_to!(
         indoc!(
@@ -184,7 +184,7 @@ fn str_split_empty_strs() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_split_minimal_example() {
     assert_evals_to!(
         indoc!(
@@ -198,7 +198,7 @@ fn str_split_minimal_example() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_split_small_str_big_delimiter() {
     assert_evals_to!(
         indoc!(
@@ -227,7 +227,7 @@ fn str_split_small_str_big_delimiter() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_split_small_str_20_char_delimiter() {
     assert_evals_to!(
         indoc!(
@@ -463,13 +463,13 @@ fn str_concat_empty() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn small_str_is_empty() {
     assert_evals_to!(r#"Str.isEmpty "abc""#, false, bool);
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn big_str_is_empty() {
     assert_evals_to!(
         r#"Str.isEmpty "this is more than 15 chars long""#,
@@ -479,13 +479,13 @@ fn big_str_is_empty() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn empty_str_is_empty() {
     assert_evals_to!(r#"Str.isEmpty """#, true, bool);
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_starts_with() {
     assert_evals_to!(r#"Str.startsWith "hello world" "hell""#, true, bool);
     assert_evals_to!(r#"Str.startsWith "hello world" """#, true, bool);
@@ -495,7 +495,7 @@ fn str_starts_with() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_starts_with_scalar() {
     assert_evals_to!(
         &format!(r#"Str.startsWithScalar "foobar" {}"#, 'f' as u32),
@@ -510,7 +510,7 @@ fn str_starts_with_scalar() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_ends_with() {
     assert_evals_to!(r#"Str.endsWith "hello world" "world""#, true, bool);
     assert_evals_to!(r#"Str.endsWith "nope" "hello world""#, false, bool);
@@ -518,19 +518,19 @@ fn str_ends_with() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_count_graphemes_small_str() {
     assert_evals_to!(r#"Str.countGraphemes "å🤔""#, 2, usize);
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_count_graphemes_three_js() {
     assert_evals_to!(r#"Str.countGraphemes "JJJ""#, 3, usize);
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_count_graphemes_big_str() {
     assert_evals_to!(
         r#"Str.countGraphemes "6🤔å🤔e¥🤔çppkd🙃1jdal🦯asdfa∆ltråø˚waia8918.,🏅jjc""#,
@@ -540,7 +540,7 @@ fn str_count_graphemes_big_str() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_starts_with_same_big_str() {
     assert_evals_to!(
         r#"Str.startsWith "123456789123456789" "123456789123456789""#,
@@ -550,7 +550,7 @@ fn str_starts_with_same_big_str() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_starts_with_different_big_str() {
     assert_evals_to!(
         r#"Str.startsWith "12345678912345678910" "123456789123456789""#,
@@ -560,24 +560,24 @@ fn str_starts_with_different_big_str() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_starts_with_same_small_str() {
     assert_evals_to!(r#"Str.startsWith "1234" "1234""#, true, bool);
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_starts_with_different_small_str() {
     assert_evals_to!(r#"Str.startsWith "1234" "12""#, true, bool);
 }
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_starts_with_false_small_str() {
     assert_evals_to!(r#"Str.startsWith "1234" "23""#, false, bool);
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_from_utf8_pass_single_ascii() {
     assert_evals_to!(
         indoc!(
@@ -593,7 +593,7 @@ fn str_from_utf8_pass_single_ascii() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_from_utf8_pass_many_ascii() {
     assert_evals_to!(
         indoc!(
@@ -609,7 +609,7 @@ fn str_from_utf8_pass_many_ascii() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_from_utf8_pass_single_unicode() {
     assert_evals_to!(
         indoc!(
@@ -625,7 +625,7 @@ fn str_from_utf8_pass_single_unicode() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_from_utf8_pass_many_unicode() {
     assert_evals_to!(
         indoc!(
@@ -641,7 +641,7 @@ fn str_from_utf8_pass_many_unicode() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_from_utf8_pass_single_grapheme() {
     assert_evals_to!(
         indoc!(
@@ -657,7 +657,7 @@ fn str_from_utf8_pass_single_grapheme() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_from_utf8_pass_many_grapheme() {
     assert_evals_to!(
         indoc!(
@@ -673,7 +673,7 @@ fn str_from_utf8_pass_many_grapheme() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_from_utf8_pass_all() {
     assert_evals_to!(
         indoc!(
@@ -689,7 +689,7 @@ fn str_from_utf8_pass_all() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_from_utf8_fail_invalid_start_byte() {
     assert_evals_to!(
         indoc!(
@@ -709,7 +709,7 @@ fn str_from_utf8_fail_invalid_start_byte() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_from_utf8_fail_unexpected_end_of_sequence() {
     assert_evals_to!(
         indoc!(
@@ -729,7 +729,7 @@ fn str_from_utf8_fail_unexpected_end_of_sequence() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_from_utf8_fail_expected_continuation() {
     assert_evals_to!(
         indoc!(
@@ -749,7 +749,7 @@ fn str_from_utf8_fail_expected_continuation() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_from_utf8_fail_overlong_encoding() {
     assert_evals_to!(
         indoc!(
@@ -769,7 +769,7 @@ fn str_from_utf8_fail_overlong_encoding() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_from_utf8_fail_codepoint_too_large() {
     assert_evals_to!(
         indoc!(
@@ -789,7 +789,7 @@ fn str_from_utf8_fail_codepoint_too_large() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_from_utf8_fail_surrogate_half() {
     assert_evals_to!(
         indoc!(
@@ -865,7 +865,7 @@ fn nested_recursive_literal() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_join_comma_small() {
     assert_evals_to!(
         r#"Str.joinWith ["1", "2"] ", " "#,
@@ -875,7 +875,7 @@ fn str_join_comma_small() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_join_comma_big() {
     assert_evals_to!(
         r#"Str.joinWith ["10000000", "2000000", "30000000"] ", " "#,
@@ -885,13 +885,13 @@ fn str_join_comma_big() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_join_comma_single() {
     assert_evals_to!(r#"Str.joinWith ["1"] ", " "#, RocStr::from("1"), RocStr);
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_to_utf8() {
     assert_evals_to!(
         r#"Str.toUtf8 "hello""#,
@@ -909,7 +909,7 @@ fn str_to_utf8() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_from_utf8_range() {
     assert_evals_to!(
         indoc!(
@@ -926,7 +926,7 @@ fn str_from_utf8_range() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_from_utf8_range_slice() {
     assert_evals_to!(
         indoc!(
@@ -943,7 +943,7 @@ fn str_from_utf8_range_slice() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_from_utf8_range_slice_not_end() {
     assert_evals_to!(
         indoc!(
@@ -960,7 +960,7 @@ fn str_from_utf8_range_slice_not_end() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_from_utf8_range_order_does_not_matter() {
     assert_evals_to!(
         indoc!(
@@ -977,7 +977,7 @@ fn str_from_utf8_range_order_does_not_matter() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_from_utf8_range_out_of_bounds_start_value() {
     assert_evals_to!(
         indoc!(
@@ -995,7 +995,7 @@ fn str_from_utf8_range_out_of_bounds_start_value() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_from_utf8_range_count_too_high() {
     assert_evals_to!(
         indoc!(
@@ -1013,7 +1013,7 @@ fn str_from_utf8_range_count_too_high() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_from_utf8_range_count_too_high_for_start() {
     assert_evals_to!(
         indoc!(
@@ -1031,7 +1031,7 @@ fn str_from_utf8_range_count_too_high_for_start() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_repeat_small_stays_small() {
     assert_evals_to!(
         indoc!(r#"Str.repeat "Roc" 3"#),
@@ -1041,7 +1041,7 @@ fn str_repeat_small_stays_small() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_repeat_small_becomes_big() {
     assert_evals_to!(
         indoc!(r#"Str.repeat "less than 23 characters" 2"#),
@@ -1051,7 +1051,7 @@ fn str_repeat_small_becomes_big() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_repeat_big() {
     assert_evals_to!(
         indoc!(r#"Str.repeat "more than 23 characters now" 2"#),
@@ -1061,7 +1061,7 @@ fn str_repeat_big() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_repeat_empty_string() {
     let a = indoc!(r#"Str.repeat "" 3"#);
     let b = RocStr::from("");
@@ -1069,25 +1069,25 @@ fn str_repeat_empty_string() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_repeat_zero_times() {
     assert_evals_to!(indoc!(r#"Str.repeat "Roc" 0"#), RocStr::from(""), RocStr);
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_trim_empty_string() {
     assert_evals_to!(indoc!(r#"Str.trim """#), RocStr::from(""), RocStr);
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_trim_small_blank_string() {
     assert_evals_to!(indoc!(r#"Str.trim " ""#), RocStr::from(""), RocStr);
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_trim_small_to_small() {
     assert_evals_to!(
         indoc!(r#"Str.trim "  hello world  ""#),
@@ -1097,7 +1097,7 @@ fn str_trim_small_to_small() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_trim_large_to_large_unique() {
     assert_evals_to!(
         indoc!(r#"Str.trim (Str.concat "  " "hello world from a large string ")"#),
@@ -1107,7 +1107,7 @@ fn str_trim_large_to_large_unique() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_trim_large_to_small_unique() {
     assert_evals_to!(
         indoc!(r#"Str.trim (Str.concat "  " "hello world        ")"#),
@@ -1117,7 +1117,7 @@ fn str_trim_large_to_small_unique() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_trim_large_to_large_shared() {
     assert_evals_to!(
         indoc!(
@@ -1137,7 +1137,7 @@ fn str_trim_large_to_large_shared() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_trim_large_to_small_shared() {
     assert_evals_to!(
         indoc!(
@@ -1157,7 +1157,7 @@ fn str_trim_large_to_small_shared() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_trim_small_to_small_shared() {
     assert_evals_to!(
         indoc!(
@@ -1174,13 +1174,13 @@ fn str_trim_small_to_small_shared() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_trim_left_small_blank_string() {
     assert_evals_to!(indoc!(r#"Str.trimLeft " ""#), RocStr::from(""), RocStr);
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_trim_left_small_to_small() {
     assert_evals_to!(
         indoc!(r#"Str.trimLeft "  hello world  ""#),
@@ -1190,7 +1190,7 @@ fn str_trim_left_small_to_small() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_trim_left_large_to_large_unique() {
     assert_evals_to!(
         indoc!(r#"Str.trimLeft (Str.concat "    " "hello world from a large string ")"#),
@@ -1200,7 +1200,7 @@ fn str_trim_left_large_to_large_unique() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_trim_left_large_to_small_unique() {
     assert_evals_to!(
         indoc!(r#"Str.trimLeft (Str.concat "  " "hello world        ")"#),
@@ -1210,7 +1210,7 @@ fn str_trim_left_large_to_small_unique() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_trim_left_large_to_large_shared() {
     assert_evals_to!(
         indoc!(
@@ -1230,7 +1230,7 @@ fn str_trim_left_large_to_large_shared() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_trim_left_large_to_small_shared() {
     assert_evals_to!(
         indoc!(
@@ -1250,7 +1250,7 @@ fn str_trim_left_large_to_small_shared() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_trim_left_small_to_small_shared() {
     assert_evals_to!(
         indoc!(
@@ -1267,13 +1267,13 @@ fn str_trim_left_small_to_small_shared() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_trim_right_small_blank_string() {
     assert_evals_to!(indoc!(r#"Str.trimRight " ""#), RocStr::from(""), RocStr);
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_trim_right_small_to_small() {
     assert_evals_to!(
         indoc!(r#"Str.trimRight "  hello world  ""#),
@@ -1283,7 +1283,7 @@ fn str_trim_right_small_to_small() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_trim_right_large_to_large_unique() {
     assert_evals_to!(
         indoc!(r#"Str.trimRight (Str.concat " hello world from a large string" "    ")"#),
@@ -1293,7 +1293,7 @@ fn str_trim_right_large_to_large_unique() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_trim_right_large_to_small_unique() {
     assert_evals_to!(
         indoc!(r#"Str.trimRight (Str.concat "        hello world" "  ")"#),
@@ -1303,7 +1303,7 @@ fn str_trim_right_large_to_small_unique() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_trim_right_large_to_large_shared() {
     assert_evals_to!(
         indoc!(
@@ -1323,7 +1323,7 @@ fn str_trim_right_large_to_large_shared() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_trim_right_large_to_small_shared() {
     assert_evals_to!(
         indoc!(
@@ -1343,7 +1343,7 @@ fn str_trim_right_large_to_small_shared() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_trim_right_small_to_small_shared() {
     assert_evals_to!(
         indoc!(
@@ -1360,13 +1360,13 @@ fn str_trim_right_small_to_small_shared() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_to_nat() {
     assert_evals_to!(r#"Str.toNat "1" |> Result.withDefault 0"#, 1, usize);
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_to_i128() {
     assert_evals_to!(
         indoc!(
@@ -1383,7 +1383,7 @@ fn str_to_i128() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_to_u128() {
     assert_evals_to!(
         indoc!(
@@ -1400,7 +1400,7 @@ fn str_to_u128() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_to_i64() {
     assert_evals_to!(
         indoc!(
@@ -1417,7 +1417,7 @@ fn str_to_i64() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_to_u64() {
     assert_evals_to!(
         r#"Str.toU64 "1""#,
@@ -1427,7 +1427,7 @@ fn str_to_u64() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_to_i32() {
     assert_evals_to!(
         indoc!(
@@ -1444,7 +1444,7 @@ fn str_to_i32() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_to_u32() {
     assert_evals_to!(
         r#"Str.toU32 "1""#,
@@ -1454,7 +1454,7 @@ fn str_to_u32() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_to_i16() {
     assert_evals_to!(
         indoc!(
@@ -1471,7 +1471,7 @@ fn str_to_i16() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_to_u16() {
     assert_evals_to!(
         indoc!(
@@ -1488,7 +1488,7 @@ fn str_to_u16() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_to_i8() {
     assert_evals_to!(
         indoc!(
@@ -1505,7 +1505,7 @@ fn str_to_i8() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_to_u8() {
     assert_evals_to!(
         indoc!(
@@ -1522,7 +1522,7 @@ fn str_to_u8() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_to_f64() {
     assert_evals_to!(
         indoc!(
@@ -1539,7 +1539,7 @@ fn str_to_f64() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_to_f32() {
     assert_evals_to!(
         indoc!(
@@ -1556,7 +1556,7 @@ fn str_to_f32() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_to_dec() {
     use roc_std::RocDec;
 
@@ -1591,7 +1591,7 @@ fn issue_2811() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn to_scalar_1_byte() {
     assert_evals_to!(
         indoc!(
@@ -1615,7 +1615,7 @@ fn to_scalar_1_byte() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn to_scalar_2_byte() {
     assert_evals_to!(
         indoc!(
@@ -1639,7 +1639,7 @@ fn to_scalar_2_byte() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn to_scalar_3_byte() {
     assert_evals_to!(
         indoc!(
@@ -1663,7 +1663,7 @@ fn to_scalar_3_byte() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn to_scalar_4_byte() {
     // from https://design215.com/toolbox/utf8-4byte-characters.php
     assert_evals_to!(
@@ -1921,7 +1921,7 @@ fn when_on_strings() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn with_capacity() {
     assert_evals_to!(
         indoc!(
@@ -1935,7 +1935,7 @@ fn with_capacity() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn with_capacity_concat() {
     assert_evals_to!(
         indoc!(
@@ -2014,3 +2014,271 @@ fn destructure_pattern_assigned_from_thunk_tag() {
         RocStr
     );
 }
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn str_to_uppercase() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            Str.toUppercase "a string so large that it must be heap-allocated, straße"
+            "#
+        ),
+        RocStr::from("A STRING SO LARGE THAT IT MUST BE HEAP-ALLOCATED, STRASSE"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm"))]
+fn str_to_uppercase_shared() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            original = "a string so large that it must be heap-allocated"
+
+            Str.concat (Str.toUppercase original) original
+            "#
+        ),
+        RocStr::from(
+            "A STRING SO LARGE THAT IT MUST BE HEAP-ALLOCATEDa string so large that it must be heap-allocated"
+        ),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn str_to_lowercase() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            Str.toLowercase "ÀÉÎ, Hello!"
+            "#
+        ),
+        RocStr::from("àéî, hello!"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn str_to_nfc() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            Str.toNfc "e\u(301) a\u(302)\u(323) \u(1100)\u(1161)\u(11a8)"
+            "#
+        ),
+        RocStr::from("\u{e9} \u{1ead} \u{ac01}"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn str_to_nfd() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            Str.toNfd "\u(e9) \u(1ead) \u(ac01)"
+            "#
+        ),
+        RocStr::from("e\u{301} a\u{323}\u{302} \u{1100}\u{1161}\u{11a8}"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm"))]
+fn str_compare_ignoring_case() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            toI64 = \order ->
+                when order is
+                    LT -> -1
+                    EQ -> 0
+                    GT -> 1
+
+            [
+                Str.compareIgnoringCase "Straße" "STRASSE",
+                Str.compareIgnoringCase "\u(c5)ngstr\u(f6)m" "A\u(30a)NGSTRO\u(308)M",
+                Str.compareIgnoringCase "apple" "Banana",
+                Str.compareIgnoringCase "b" "A",
+            ]
+            |> List.map toI64
+            "#
+        ),
+        RocList::from_slice(&[0, 0, -1, 1]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm"))]
+fn str_find() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            Str.find "a string so large that it must be heap-allocated" "heap"
+            "#
+        ),
+        RocResult::ok(31),
+        RocResult<usize, ()>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm"))]
+fn str_find_not_found() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            Str.find "héllo" "hel"
+            "#
+        ),
+        RocResult::err(()),
+        RocResult<usize, ()>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm"))]
+fn str_contains() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            [
+                Str.contains "hello, world" "lo, w",
+                Str.contains "hello, world" "World",
+                Str.contains "" "",
+            ]
+            "#
+        ),
+        RocList::from_slice(&[true, false, true]),
+        RocList<bool>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm"))]
+fn str_to_int_radix() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            [
+                Str.toU32Radix "ff" 16,
+                Str.toU32Radix "0xFF" 16,
+                Str.toU32Radix "0b1010_1010" 2,
+                Str.toU32Radix "-0" 10,
+                Str.toU32Radix "zz" 36,
+            ]
+            |> List.map \result -> Result.withDefault result 0
+            "#
+        ),
+        RocList::from_slice(&[255, 255, 170, 0, 1295]),
+        RocList<u32>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn str_to_i128_radix() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            when Str.toI128Radix "-0x8000_0000_0000_0000_0000_0000_0000_0000" 16 is
+                Ok n -> n
+                Err _ -> 0
+            "#
+        ),
+        i128::MIN,
+        i128
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn str_to_int_radix_errors() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            describe = \result ->
+                when result is
+                    Ok n -> "Ok \(Num.toStr n)"
+                    Err (BadNumStr MissingDigits index) -> "MissingDigits \(Num.toStr index)"
+                    Err (BadNumStr InvalidDigit index) -> "InvalidDigit \(Num.toStr index)"
+                    Err (BadNumStr Overflow index) -> "Overflow \(Num.toStr index)"
+                    Err InvalidRadix -> "InvalidRadix"
+
+            [
+                describe (Str.toU32Radix "12g4" 16),
+                describe (Str.toI8Radix "-129" 10),
+                describe (Str.toU16Radix "0x" 16),
+                describe (Str.toU8Radix "-1" 10),
+                describe (Str.toU64Radix "7" 1),
+                describe (Str.toU32Radix "1__2" 10),
+                describe (Str.toU32Radix "1_" 10),
+                describe (Str.toU32Radix "0x_1" 16),
+            ]
+            |> Str.joinWith ", "
+            "#
+        ),
+        RocStr::from(
+            "InvalidDigit 2, Overflow 3, MissingDigits 2, Overflow 1, InvalidRadix, InvalidDigit 2, InvalidDigit 1, InvalidDigit 2"
+        ),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm"))]
+fn str_to_f64_checked() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            [
+                Str.toF64Checked "-1_000.5",
+                Str.toF64Checked "2.5e3",
+                Str.toF64Checked "5E-1",
+            ]
+            |> List.map \result -> Result.withDefault result 0
+            "#
+        ),
+        RocList::from_slice(&[-1000.5, 2500.0, 0.5]),
+        RocList<f64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn str_to_float_checked_errors() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            describe = \result ->
+                when result is
+                    Ok _ -> "Ok"
+                    Err (BadNumStr MissingDigits index) -> "MissingDigits \(Num.toStr index)"
+                    Err (BadNumStr InvalidDigit index) -> "InvalidDigit \(Num.toStr index)"
+                    Err (BadNumStr Overflow index) -> "Overflow \(Num.toStr index)"
+
+            [
+                describe (Str.toF64Checked "1,5"),
+                describe (Str.toF64Checked "1."),
+                describe (Str.toF64Checked "1e400"),
+                describe (Str.toF32Checked "1e39"),
+                describe (Str.toF32Checked "0.5"),
+                describe (Str.toF64Checked "1__0.5"),
+                describe (Str.toF32Checked "400_000_000_000_000_000_000_000_000_000_000_000_000"),
+            ]
+            |> Str.joinWith ", "
+            "#
+        ),
+        RocStr::from(
+            "InvalidDigit 1, MissingDigits 2, Overflow 2, Overflow 2, Ok, InvalidDigit 1, Overflow 50"
+        ),
+        RocStr
+    );
+}
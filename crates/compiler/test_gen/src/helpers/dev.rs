@@ -1,10 +1,12 @@
 use libloading::Library;
 use roc_build::link::{link, LinkType};
 use roc_builtins::bitcode;
-use roc_load::{EntryPoint, ExecutionMode, LoadConfig, Threading};
-use roc_mono::ir::SingleEntryPoint;
+use roc_load::{EmitIr, EntryPoint, ExecutionMode, LoadConfig, Threading};
+use roc_mono::ir::{CrashTag, SingleEntryPoint};
 use roc_packaging::cache::RocCacheDir;
 use roc_region::all::LineInfo;
+use roc_std::RocStr;
+use std::mem::MaybeUninit;
 use tempfile::tempdir;
 
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
@@ -58,6 +60,9 @@ pub fn helper(
         palette: roc_reporting::report::DEFAULT_PALETTE,
         threading: Threading::Single,
         exec_mode: ExecutionMode::Executable,
+        fold_constants: false,
+        emit_ir: EmitIr::default(),
+        trace_refcounts: false,
     };
     let loaded = roc_load::load_and_monomorphize_from_str(
         arena,
@@ -154,7 +159,9 @@ pub fn helper(
         for problem in can_problems.into_iter() {
             // Ignore "unused" problems
             match problem {
-                UnusedDef(_, _) | UnusedArgument(_, _, _, _) | UnusedModuleImport(_, _) => {
+                UnusedDef(_, _, _)
+                | UnusedArgument(_, _, _, _, _)
+                | UnusedModuleImport(_, _, _) => {
                     delayed_errors.push(problem);
                     continue;
                 }
@@ -191,6 +198,8 @@ pub fn helper(
         exposed_to_host: exposed_to_host.values.keys().copied().collect(),
         lazy_literals,
         generate_allocators: true, // Needed for testing, since we don't have a platform
+        check_memory: false,
+        trace_refcounts: false,
     };
 
     let target = target_lexicon::Triple::host();
@@ -220,6 +229,7 @@ pub fn helper(
             builtins_host_tempfile.path().to_str().unwrap(),
         ],
         LinkType::Dylib,
+        false,
     )
     .expect("failed to link dynamic library");
 
@@ -239,6 +249,64 @@ pub fn helper(
     (main_fn_name, delayed_errors, lib)
 }
 
+struct DevCall<T> {
+    main: unsafe extern "C" fn() -> T,
+    result: MaybeUninit<T>,
+}
+
+extern "C" fn call_dev_main<T>(data: *mut std::ffi::c_void) {
+    let call = unsafe { &mut *(data as *mut DevCall<T>) };
+
+    call.result.write(unsafe { (call.main)() });
+}
+
+/// Calls main through the builtins' test_dispatch, so that a roc_panic jumps back
+/// here instead of running off the end of the dev backend's generated code.
+#[allow(dead_code)]
+pub fn try_run_lib_function<T>(main_fn_name: &str, lib: &Library) -> Result<T, (String, CrashTag)> {
+    type Dispatch = unsafe extern "C" fn(
+        extern "C" fn(*mut std::ffi::c_void),
+        *mut std::ffi::c_void,
+        *mut RocStr,
+    ) -> u32;
+
+    unsafe {
+        let main: libloading::Symbol<unsafe extern "C" fn() -> T> = lib
+            .get(main_fn_name.as_bytes())
+            .ok()
+            .ok_or(format!("Unable to JIT compile `{}`", main_fn_name))
+            .expect("errored");
+        let dispatch: libloading::Symbol<Dispatch> = lib
+            .get(bitcode::UTILS_TEST_DISPATCH.as_bytes())
+            .expect("the builtins do not export test_dispatch");
+
+        let mut call = DevCall {
+            main: *main,
+            result: MaybeUninit::uninit(),
+        };
+        let mut msg = MaybeUninit::<RocStr>::uninit();
+
+        let tag = dispatch(
+            call_dev_main::<T>,
+            &mut call as *mut DevCall<T> as *mut std::ffi::c_void,
+            msg.as_mut_ptr(),
+        );
+
+        match tag {
+            0 => Ok(call.result.assume_init()),
+            n => {
+                // The message belongs to the Roc program; don't let Rust deallocate it.
+                let msg = std::mem::ManuallyDrop::new(msg.assume_init());
+                let tag = (n - 1)
+                    .try_into()
+                    .unwrap_or_else(|_| panic!("received illegal tag: {}", n - 1));
+
+                Err((msg.as_str().to_owned(), tag))
+            }
+        }
+    }
+}
+
 #[allow(unused_macros)]
 macro_rules! assert_evals_to {
     ($src:expr, $expected:expr, $ty:ty) => {{
@@ -261,18 +329,33 @@ macro_rules! assert_evals_to {
     };
     ($src:expr, $expected:expr, $ty:ty, $transform:expr, $leak:expr, $lazy_literals:expr) => {
         use bumpalo::Bump;
-        use roc_gen_dev::run_jit_function_raw;
+        use roc_mono::ir::CrashTag;
 
         let arena = Bump::new();
         let (main_fn_name, errors, lib) =
             $crate::helpers::dev::helper(&arena, $src, $leak, $lazy_literals);
 
-        let transform = |success| {
-            let expected = $expected;
-            let given = $transform(success);
-            assert_eq!(&given, &expected);
-        };
-        run_jit_function_raw!(lib, main_fn_name, $ty, transform, errors)
+        let result = $crate::helpers::dev::try_run_lib_function::<$ty>(&main_fn_name, &lib);
+
+        match result {
+            Ok(raw) => {
+                // only if there are no exceptions thrown, check for errors
+                assert_eq!(
+                    errors,
+                    std::vec::Vec::new(),
+                    "Encountered errors: {:?}",
+                    errors
+                );
+
+                #[allow(clippy::redundant_closure_call)]
+                let given = $transform(raw);
+                assert_eq!(&given, &$expected);
+            }
+            Err((msg, tag)) => match tag {
+                CrashTag::Roc => panic!(r#"Roc failed with message: "{}""#, msg),
+                CrashTag::User => panic!(r#"User crash with message: "{}""#, msg),
+            },
+        }
     };
 }
 
@@ -7,7 +7,7 @@ use roc_build::link::llvm_module_to_dylib;
 use roc_collections::all::MutSet;
 use roc_gen_llvm::llvm::externs::add_default_roc_externs;
 use roc_gen_llvm::{llvm::build::LlvmBackendMode, run_roc::RocCallResult};
-use roc_load::{EntryPoint, ExecutionMode, LoadConfig, LoadMonomorphizedError, Threading};
+use roc_load::{EmitIr, EntryPoint, ExecutionMode, LoadConfig, LoadMonomorphizedError, Threading};
 use roc_mono::ir::{CrashTag, OptLevel, SingleEntryPoint};
 use roc_packaging::cache::RocCacheDir;
 use roc_region::all::LineInfo;
@@ -74,6 +74,9 @@ fn create_llvm_module<'a>(
         palette: DEFAULT_PALETTE,
         threading: Threading::Single,
         exec_mode: ExecutionMode::Executable,
+        fold_constants: false,
+        emit_ir: EmitIr::default(),
+        trace_refcounts: false,
     };
     let loaded = roc_load::load_and_monomorphize_from_str(
         arena,
@@ -130,9 +133,9 @@ fn create_llvm_module<'a>(
         for problem in can_problems.into_iter() {
             match problem {
                 // Ignore "unused" problems
-                UnusedDef(_, _)
-                | UnusedArgument(_, _, _, _)
-                | UnusedModuleImport(_, _)
+                UnusedDef(_, _, _)
+                | UnusedArgument(_, _, _, _, _)
+                | UnusedModuleImport(_, _, _)
                 | RuntimeError(_)
                 | UnsupportedPattern(_, _)
                 | ExposedButNotDefined(_) => {
@@ -226,6 +229,8 @@ fn create_llvm_module<'a>(
         mode: config.mode,
         // important! we don't want any procedures to get the C calling convention
         exposed_to_host: MutSet::default(),
+        check_memory: false,
+        trace_refcounts: false,
     };
 
     // strip Zig debug stuff
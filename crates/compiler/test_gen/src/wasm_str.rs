@@ -1327,3 +1327,72 @@ fn str_walk_scalars() {
         RocList<char>
     );
 }
+
+#[test]
+fn str_to_uppercase() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            Str.toUppercase "a string so large that it must be heap-allocated, straße"
+            "#
+        ),
+        RocStr::from("A STRING SO LARGE THAT IT MUST BE HEAP-ALLOCATED, STRASSE"),
+        RocStr
+    );
+}
+
+#[test]
+fn str_to_nfc() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            Str.toNfc "e\u(301) \u(1100)\u(1161)\u(11a8)"
+            "#
+        ),
+        RocStr::from("\u{e9} \u{ac01}"),
+        RocStr
+    );
+}
+
+#[test]
+fn str_find() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            when Str.find "hello, world" "world" is
+                Ok index -> index
+                Err NotFound -> 999
+            "#
+        ),
+        7,
+        usize
+    );
+}
+
+#[test]
+fn str_to_int_radix() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            when Str.toU16Radix "-0o17" 8 is
+                Err (BadNumStr Overflow index) -> index
+                _ -> 999
+            "#
+        ),
+        3,
+        usize
+    );
+}
+
+#[test]
+fn str_to_f64_checked() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            Str.toF64Checked "2.5e3" |> Result.withDefault 0
+            "#
+        ),
+        2500.0,
+        f64
+    );
+}
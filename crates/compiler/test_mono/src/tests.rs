@@ -16,9 +16,9 @@ const EXPANDED_STACK_SIZE: usize = 8 * 1024 * 1024;
 use bumpalo::Bump;
 use roc_collections::all::MutMap;
 use roc_load::ExecutionMode;
-use roc_load::LoadConfig;
 use roc_load::LoadMonomorphizedError;
 use roc_load::Threading;
+use roc_load::{EmitIr, LoadConfig};
 use roc_module::symbol::Interns;
 use roc_module::symbol::Symbol;
 use roc_mono::ir::Proc;
@@ -76,7 +76,7 @@ fn promote_expr_to_module(src: &str) -> String {
     buffer
 }
 
-fn compiles_to_ir(test_name: &str, src: &str, mode: &str, no_check: bool) {
+fn compiles_to_ir(test_name: &str, src: &str, mode: &str, no_check: bool, trace_refcounts: bool) {
     use roc_packaging::cache::RocCacheDir;
     use std::path::PathBuf;
 
@@ -108,6 +108,9 @@ fn compiles_to_ir(test_name: &str, src: &str, mode: &str, no_check: bool) {
         render: roc_reporting::report::RenderTarget::Generic,
         palette: roc_reporting::report::DEFAULT_PALETTE,
         exec_mode,
+        fold_constants: true,
+        emit_ir: EmitIr::default(),
+        trace_refcounts,
     };
     let loaded = roc_load::load_and_monomorphize_from_str(
         arena,
@@ -2496,3 +2499,197 @@ fn weakening_avoids_overspecialization() {
         "###
     )
 }
+
+#[mono_test]
+fn constant_folding_of_toplevel_thunks() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        base = 40 + 2
+
+        scaled = base * 10
+
+        greeting = Str.concat "Hello, " "World!"
+
+        main =
+            if scaled > 400 then
+                greeting
+            else
+                "too small"
+        "#
+    )
+}
+
+#[mono_test]
+fn constant_folding_of_record_fields_and_list_elements() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        main =
+            point = { x: 1 + 2, y: 3 * 4 }
+
+            [point.x, point.y, point.x - point.y]
+        "#
+    )
+}
+
+#[mono_test]
+fn constant_folding_of_records_lists_and_large_strings_in_thunks() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        config =
+            width = 80 * 2
+            height = 24 * 2
+
+            {
+                width,
+                height,
+                title: Str.concat "A window title that is " "too long for a small string",
+                sizes: [width, height, width * height],
+            }
+
+        main =
+            { area: config.width * config.height, title: config.title, sizes: config.sizes }
+        "#
+    )
+}
+
+#[mono_test]
+fn inline_small_procedures() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        clamp = \low, high, x ->
+            if x < low then
+                low
+            else if x > high then
+                high
+            else
+                x
+
+        getX = \point -> point.x
+
+        main = \x ->
+            point = { x: clamp 0 100 x, y: x }
+
+            getX point + 1
+        "#
+    )
+}
+
+#[mono_test]
+fn inline_lambda_set_dispatch() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        apply = \f, x -> f x
+
+        main = \n ->
+            f = if n > 10 then (\x -> x + n) else (\x -> x * 2)
+
+            apply f n
+        "#
+    )
+}
+
+#[mono_test]
+fn tail_recursion_modulo_cons() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        ConsList a : [Cons a (ConsList a), Nil]
+
+        increment : ConsList I64 -> ConsList I64
+        increment = \list ->
+            when list is
+                Nil -> Nil
+                Cons x xs -> Cons (x + 1) (increment xs)
+
+        main =
+            increment (Cons 1 (Cons 2 Nil))
+        "#
+    )
+}
+
+#[mono_test]
+fn tail_recursion_modulo_cons_nullable_wrapped() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        Expr : [Add I64 Expr, Mul I64 Expr, Lit]
+
+        double : Expr -> Expr
+        double = \expr ->
+            when expr is
+                Lit -> Lit
+                Add n rest -> Add (2 * n) (double rest)
+                Mul n rest -> Mul n (double rest)
+
+        main =
+            double (Add 1 (Mul 2 Lit))
+        "#
+    )
+}
+
+#[mono_test]
+fn tail_recursion_modulo_cons_recursive() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        Chain : [Link I64 Chain, End I64]
+
+        increment : Chain -> Chain
+        increment = \chain ->
+            when chain is
+                End n -> End (n + 1)
+                Link n rest -> Link (n + 1) (increment rest)
+
+        main =
+            increment (Link 1 (End 2))
+        "#
+    )
+}
+
+#[mono_test(trace_refcounts = "true")]
+fn trace_refcounts_list_set() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        update : List I64, I64 -> List I64
+        update = \list, x ->
+            List.set (List.append list x) 0 x
+
+        main =
+            update [1, 2, 3] 4
+        "#
+    )
+}
+
+#[mono_test(trace_refcounts = "true")]
+fn trace_refcounts_record_update() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        Counter : { items : List I64, count : I64 }
+
+        bump : Counter -> Counter
+        bump = \counter -> { counter & count: counter.count + 1 }
+
+        main =
+            counter = bump { items: [1, 2, 3], count: 0 }
+
+            counter.count
+        "#
+    )
+}
@@ -1679,6 +1679,8 @@ impl Subs {
     pub const AB_HASH: SubsSlice<Symbol>     = SubsSlice::new(3, 1);
     #[rustfmt::skip]
     pub const AB_EQ: SubsSlice<Symbol>       = SubsSlice::new(4, 1);
+    #[rustfmt::skip]
+    pub const AB_ORD: SubsSlice<Symbol>      = SubsSlice::new(5, 1);
 
     pub fn new() -> Self {
         Self::with_capacity(0)
@@ -1703,6 +1705,7 @@ impl Subs {
         symbol_names.push(Symbol::HASH_HASHER);
         symbol_names.push(Symbol::HASH_HASH_ABILITY);
         symbol_names.push(Symbol::BOOL_EQ);
+        symbol_names.push(Symbol::ORD_ORD);
 
         let mut subs = Subs {
             utable: UnificationTable::default(),
@@ -1,12 +1,15 @@
-platform "roc-lang/rbt"
-    requires {} { makeGlue : Str -> Types }
+platform "roc-lang/glue"
+    requires {} { makeGlue : List Types -> Result (List File) Str }
     exposes []
     packages {}
     imports []
     provides [makeGlueForHost]
 
-makeGlueForHost : Str -> Types
-makeGlueForHost = makeGlue
+makeGlueForHost : List Types -> Result (List File) Str
+makeGlueForHost = \typesByTarget -> makeGlue typesByTarget
+
+## A file to write, with a name relative to the output directory
+File : { name : Str, content : Str }
 
 # TODO move into separate Target.roc interface once glue works across interfaces.
 Target : {
@@ -28,21 +31,19 @@ OperatingSystem : [
     Wasi,
 ]
 
-TypeId := Nat
+TypeId : Nat
 
-Types := {
+## The types in a platform's API, as laid out on one target
+Types : {
     # These are all indexed by TypeId
     types: List RocType,
     sizes: List U32,
     aligns: List U32,
 
-    # Needed to check for duplicates
-    typesByName: Dict Str TypeId,
-
     ## Dependencies - that is, which type depends on which other type.
     ## This is important for declaration order in C; we need to output a
     ## type declaration earlier in the file than where it gets referenced by another type.
-    deps: Dict TypeId (List TypeId),
+    deps: List (List TypeId),
     target: Target,
 }
 
@@ -71,8 +72,16 @@ RocType : [
     RecursivePointer TypeId,
     Function {
         name: Str,
+        ## The part of the symbol names the platform exports for calling this function,
+        ## e.g. "mainForHost_1__Fx" for roc__mainForHost_1__Fx_caller.
+        ## Empty if the function is not exposed to the host through an alias.
+        externName: Str,
         args: List TypeId,
         ret: TypeId,
+        ## False for closures that come from the application, like its `main`.
+        ## The size of the values they capture isn't known until the platform is
+        ## linked with an application.
+        closureSizeKnown: Bool,
     },
     # A zero-sized type, such as an empty record or a single-tag union with no payload
     Unit,
@@ -16,6 +16,20 @@
 #![allow(clippy::needless_borrow)]
 #![allow(clippy::clone_on_copy)]
 
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct File {
+    pub content: roc_std::RocStr,
+    pub name: roc_std::RocStr,
+}
+
 #[cfg(any(
     target_arch = "arm",
     target_arch = "wasm32",
@@ -25,10 +39,9 @@
 #[repr(C)]
 pub struct Types {
     pub aligns: roc_std::RocList<u32>,
-    pub deps: roc_std::RocDict<u32, roc_std::RocList<u32>>,
+    pub deps: roc_std::RocList<roc_std::RocList<u32>>,
     pub sizes: roc_std::RocList<u32>,
     pub types: roc_std::RocList<RocType>,
-    pub typesByName: roc_std::RocDict<roc_std::RocStr, u32>,
     pub target: Target,
 }
 
@@ -94,7 +107,7 @@ pub union RocType {
     RocBox: u32,
     RocDict: RocType_RocDict,
     RocList: u32,
-    RocResult: RocType_RocResult,
+    RocResult: RocType_RocDict,
     RocSet: u32,
     Struct: core::mem::ManuallyDrop<R2>,
     TagUnion: core::mem::ManuallyDrop<RocTagUnion>,
@@ -114,34 +127,6 @@ pub struct R15 {
     pub r#type: u32,
 }
 
-#[cfg(any(
-    target_arch = "arm",
-    target_arch = "aarch64",
-    target_arch = "wasm32",
-    target_arch = "x86",
-    target_arch = "x86_64"
-))]
-#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
-#[repr(C)]
-pub struct R12 {
-    pub name: roc_std::RocStr,
-    pub payload: U4,
-}
-
-#[cfg(any(
-    target_arch = "arm",
-    target_arch = "aarch64",
-    target_arch = "wasm32",
-    target_arch = "x86",
-    target_arch = "x86_64"
-))]
-#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
-#[repr(C)]
-pub struct R10 {
-    pub name: roc_std::RocStr,
-    pub payload: U3,
-}
-
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
@@ -295,7 +280,7 @@ pub union RocTagUnion {
     NonRecursive: core::mem::ManuallyDrop<R6>,
     NullableUnwrapped: core::mem::ManuallyDrop<R8>,
     NullableWrapped: core::mem::ManuallyDrop<R9>,
-    Recursive: core::mem::ManuallyDrop<R11>,
+    Recursive: core::mem::ManuallyDrop<R6>,
     SingleTagStruct: core::mem::ManuallyDrop<R13>,
     _sizer: [u8; 48],
 }
@@ -313,54 +298,6 @@ pub struct R13 {
     pub tagName: roc_std::RocStr,
 }
 
-#[cfg(any(
-    target_arch = "arm",
-    target_arch = "wasm32",
-    target_arch = "x86"
-))]
-#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
-#[repr(C)]
-pub struct R11 {
-    pub discriminantOffset: u32,
-    pub discriminantSize: u32,
-    pub name: roc_std::RocStr,
-    pub tags: roc_std::RocList<R12>,
-}
-
-#[cfg(any(
-    target_arch = "arm",
-    target_arch = "aarch64",
-    target_arch = "wasm32",
-    target_arch = "x86",
-    target_arch = "x86_64"
-))]
-#[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
-#[repr(u8)]
-pub enum discriminant_U4 {
-    None = 0,
-    Some = 1,
-}
-
-impl core::fmt::Debug for discriminant_U4 {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        match self {
-            Self::None => f.write_str("discriminant_U4::None"),
-            Self::Some => f.write_str("discriminant_U4::Some"),
-        }
-    }
-}
-
-#[cfg(any(
-    target_arch = "arm",
-    target_arch = "wasm32",
-    target_arch = "x86"
-))]
-#[repr(C)]
-pub union U4 {
-    Some: u32,
-    _sizer: [u8; 8],
-}
-
 #[cfg(any(
     target_arch = "arm",
     target_arch = "wasm32",
@@ -372,44 +309,10 @@ pub struct R9 {
     pub discriminantOffset: u32,
     pub discriminantSize: u32,
     pub name: roc_std::RocStr,
-    pub tags: roc_std::RocList<R10>,
+    pub tags: roc_std::RocList<R7>,
     pub indexOfNullTag: u16,
 }
 
-#[cfg(any(
-    target_arch = "arm",
-    target_arch = "aarch64",
-    target_arch = "wasm32",
-    target_arch = "x86",
-    target_arch = "x86_64"
-))]
-#[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
-#[repr(u8)]
-pub enum discriminant_U3 {
-    None = 0,
-    Some = 1,
-}
-
-impl core::fmt::Debug for discriminant_U3 {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        match self {
-            Self::None => f.write_str("discriminant_U3::None"),
-            Self::Some => f.write_str("discriminant_U3::Some"),
-        }
-    }
-}
-
-#[cfg(any(
-    target_arch = "arm",
-    target_arch = "wasm32",
-    target_arch = "x86"
-))]
-#[repr(C)]
-pub union U3 {
-    Some: u32,
-    _sizer: [u8; 8],
-}
-
 #[cfg(any(
     target_arch = "arm",
     target_arch = "wasm32",
@@ -536,18 +439,6 @@ pub struct R2 {
     pub name: roc_std::RocStr,
 }
 
-#[cfg(any(
-    target_arch = "arm",
-    target_arch = "wasm32",
-    target_arch = "x86"
-))]
-#[derive(Clone, Copy, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
-#[repr(C)]
-struct RocType_RocResult {
-    pub f0: u32,
-    pub f1: u32,
-}
-
 #[cfg(any(
     target_arch = "arm",
     target_arch = "wasm32",
@@ -571,18 +462,18 @@ struct RocType_RocDict {
 #[repr(u8)]
 pub enum RocNum {
     Dec = 0,
-    F32 = 2,
-    F64 = 3,
-    I128 = 4,
-    I16 = 5,
-    I32 = 6,
-    I64 = 7,
-    I8 = 8,
-    U128 = 9,
-    U16 = 10,
-    U32 = 11,
-    U64 = 12,
-    U8 = 13,
+    F32 = 1,
+    F64 = 2,
+    I128 = 3,
+    I16 = 4,
+    I32 = 5,
+    I64 = 6,
+    I8 = 7,
+    U128 = 8,
+    U16 = 9,
+    U32 = 10,
+    U64 = 11,
+    U8 = 12,
 }
 
 impl core::fmt::Debug for RocNum {
@@ -614,8 +505,10 @@ impl core::fmt::Debug for RocNum {
 #[repr(C)]
 pub struct R1 {
     pub args: roc_std::RocList<u32>,
+    pub externName: roc_std::RocStr,
     pub name: roc_std::RocStr,
     pub ret: u32,
+    pub closureSizeKnown: bool,
 }
 
 #[cfg(any(
@@ -626,10 +519,9 @@ pub struct R1 {
 #[repr(C)]
 pub struct Types {
     pub aligns: roc_std::RocList<u32>,
-    pub deps: roc_std::RocDict<u64, roc_std::RocList<u64>>,
+    pub deps: roc_std::RocList<roc_std::RocList<u64>>,
     pub sizes: roc_std::RocList<u32>,
     pub types: roc_std::RocList<RocType>,
-    pub typesByName: roc_std::RocDict<roc_std::RocStr, u64>,
     pub target: Target,
 }
 
@@ -645,7 +537,7 @@ pub union RocType {
     RocBox: u64,
     RocDict: RocType_RocDict,
     RocList: u64,
-    RocResult: RocType_RocResult,
+    RocResult: RocType_RocDict,
     RocSet: u64,
     Struct: core::mem::ManuallyDrop<R2>,
     TagUnion: core::mem::ManuallyDrop<RocTagUnion>,
@@ -686,7 +578,7 @@ pub union RocTagUnion {
     NonRecursive: core::mem::ManuallyDrop<R6>,
     NullableUnwrapped: core::mem::ManuallyDrop<R8>,
     NullableWrapped: core::mem::ManuallyDrop<R9>,
-    Recursive: core::mem::ManuallyDrop<R11>,
+    Recursive: core::mem::ManuallyDrop<R6>,
     SingleTagStruct: core::mem::ManuallyDrop<R13>,
     _sizer: [u8; 96],
 }
@@ -703,29 +595,6 @@ pub struct R13 {
     pub tagName: roc_std::RocStr,
 }
 
-#[cfg(any(
-    target_arch = "aarch64",
-    target_arch = "x86_64"
-))]
-#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
-#[repr(C)]
-pub struct R11 {
-    pub name: roc_std::RocStr,
-    pub tags: roc_std::RocList<R12>,
-    pub discriminantOffset: u32,
-    pub discriminantSize: u32,
-}
-
-#[cfg(any(
-    target_arch = "aarch64",
-    target_arch = "x86_64"
-))]
-#[repr(C)]
-pub union U4 {
-    Some: u64,
-    _sizer: [u8; 16],
-}
-
 #[cfg(any(
     target_arch = "aarch64",
     target_arch = "x86_64"
@@ -734,22 +603,12 @@ pub union U4 {
 #[repr(C)]
 pub struct R9 {
     pub name: roc_std::RocStr,
-    pub tags: roc_std::RocList<R10>,
+    pub tags: roc_std::RocList<R7>,
     pub discriminantOffset: u32,
     pub discriminantSize: u32,
     pub indexOfNullTag: u16,
 }
 
-#[cfg(any(
-    target_arch = "aarch64",
-    target_arch = "x86_64"
-))]
-#[repr(C)]
-pub union U3 {
-    Some: u64,
-    _sizer: [u8; 16],
-}
-
 #[cfg(any(
     target_arch = "aarch64",
     target_arch = "x86_64"
@@ -811,17 +670,6 @@ pub struct R4 {
     pub size: u32,
 }
 
-#[cfg(any(
-    target_arch = "aarch64",
-    target_arch = "x86_64"
-))]
-#[derive(Clone, Copy, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
-#[repr(C)]
-struct RocType_RocResult {
-    pub f0: u64,
-    pub f1: u64,
-}
-
 #[cfg(any(
     target_arch = "aarch64",
     target_arch = "x86_64"
@@ -841,8 +689,10 @@ struct RocType_RocDict {
 #[repr(C)]
 pub struct R1 {
     pub args: roc_std::RocList<u64>,
+    pub externName: roc_std::RocStr,
     pub name: roc_std::RocStr,
     pub ret: u64,
+    pub closureSizeKnown: bool,
 }
 
 impl RocType {
@@ -1273,7 +1123,7 @@ impl RocType {
     /// Construct a tag named `RocResult`, with the appropriate payload
     pub fn RocResult(arg0: u32, arg1: u32) -> Self {
             let mut answer = Self {
-                RocResult: RocType_RocResult {
+                RocResult: RocType_RocDict {
                     f0: arg0,
                     f1: arg1,
                 }
@@ -1882,7 +1732,7 @@ impl RocType {
     /// Construct a tag named `RocResult`, with the appropriate payload
     pub fn RocResult(arg0: u64, arg1: u64) -> Self {
             let mut answer = Self {
-                RocResult: RocType_RocResult {
+                RocResult: RocType_RocDict {
                     f0: arg0,
                     f1: arg1,
                 }
@@ -2692,7 +2542,7 @@ impl RocTagUnion {
         target_arch = "x86_64"
     ))]
     /// Construct a tag named `Recursive`, with the appropriate payload
-    pub fn Recursive(arg0: R11) -> Self {
+    pub fn Recursive(arg0: R6) -> Self {
             let mut answer = Self {
                 Recursive: core::mem::ManuallyDrop::new(arg0)
             };
@@ -2712,7 +2562,7 @@ impl RocTagUnion {
     /// Unsafely assume the given `RocTagUnion` has a `.discriminant()` of `Recursive` and convert it to `Recursive`'s payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
             /// Panics in debug builds if the `.discriminant()` doesn't return `Recursive`.
-            pub unsafe fn into_Recursive(mut self) -> R11 {
+            pub unsafe fn into_Recursive(mut self) -> R6 {
                 debug_assert_eq!(self.discriminant(), discriminant_RocTagUnion::Recursive);
         let payload = {
             let mut uninitialized = core::mem::MaybeUninit::uninit();
@@ -2742,7 +2592,7 @@ impl RocTagUnion {
     /// Unsafely assume the given `RocTagUnion` has a `.discriminant()` of `Recursive` and return its payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
             /// Panics in debug builds if the `.discriminant()` doesn't return `Recursive`.
-            pub unsafe fn as_Recursive(&self) -> &R11 {
+            pub unsafe fn as_Recursive(&self) -> &R6 {
                 debug_assert_eq!(self.discriminant(), discriminant_RocTagUnion::Recursive);
         let payload = &self.Recursive;
 
@@ -3073,682 +2923,6 @@ impl core::fmt::Debug for RocTagUnion {
     }
 }
 
-impl U4 {
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
-    ))]
-    /// Returns which variant this tag union holds. Note that this never includes a payload!
-    pub fn discriminant(&self) -> discriminant_U4 {
-        unsafe {
-            let bytes = core::mem::transmute::<&Self, &[u8; core::mem::size_of::<Self>()]>(self);
-
-            core::mem::transmute::<u8, discriminant_U4>(*bytes.as_ptr().add(4))
-        }
-    }
-
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
-    ))]
-    /// Internal helper
-    fn set_discriminant(&mut self, discriminant: discriminant_U4) {
-        let discriminant_ptr: *mut discriminant_U4 = (self as *mut U4).cast();
-
-        unsafe {
-            *(discriminant_ptr.add(4)) = discriminant;
-        }
-    }
-
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
-    ))]
-    /// A tag named None, which has no payload.
-    pub const None: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<U4>()];
-
-        bytes[4] = discriminant_U4::None as u8;
-
-        core::mem::transmute::<[u8; core::mem::size_of::<U4>()], U4>(bytes)
-    };
-
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "aarch64",
-        target_arch = "wasm32",
-        target_arch = "x86",
-        target_arch = "x86_64"
-    ))]
-    /// Other `into_` methods return a payload, but since the None tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_None(self) {
-        ()
-    }
-
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "aarch64",
-        target_arch = "wasm32",
-        target_arch = "x86",
-        target_arch = "x86_64"
-    ))]
-    /// Other `as` methods return a payload, but since the None tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_None(&self) {
-        ()
-    }
-
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
-    ))]
-    /// Construct a tag named `Some`, with the appropriate payload
-    pub fn Some(arg: u32) -> Self {
-            let mut answer = Self {
-                Some: arg
-            };
-
-            answer.set_discriminant(discriminant_U4::Some);
-
-            answer
-    }
-
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
-    ))]
-    /// Unsafely assume the given `U4` has a `.discriminant()` of `Some` and convert it to `Some`'s payload.
-            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Some`.
-            pub unsafe fn into_Some(self) -> u32 {
-                debug_assert_eq!(self.discriminant(), discriminant_U4::Some);
-        let payload = self.Some;
-
-        payload
-    }
-
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
-    ))]
-    /// Unsafely assume the given `U4` has a `.discriminant()` of `Some` and return its payload.
-            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Some`.
-            pub unsafe fn as_Some(&self) -> &u32 {
-                debug_assert_eq!(self.discriminant(), discriminant_U4::Some);
-        let payload = &self.Some;
-
-        &payload
-    }
-
-    #[cfg(any(
-        target_arch = "aarch64",
-        target_arch = "x86_64"
-    ))]
-    /// Returns which variant this tag union holds. Note that this never includes a payload!
-    pub fn discriminant(&self) -> discriminant_U4 {
-        unsafe {
-            let bytes = core::mem::transmute::<&Self, &[u8; core::mem::size_of::<Self>()]>(self);
-
-            core::mem::transmute::<u8, discriminant_U4>(*bytes.as_ptr().add(8))
-        }
-    }
-
-    #[cfg(any(
-        target_arch = "aarch64",
-        target_arch = "x86_64"
-    ))]
-    /// Internal helper
-    fn set_discriminant(&mut self, discriminant: discriminant_U4) {
-        let discriminant_ptr: *mut discriminant_U4 = (self as *mut U4).cast();
-
-        unsafe {
-            *(discriminant_ptr.add(8)) = discriminant;
-        }
-    }
-
-    #[cfg(any(
-        target_arch = "aarch64",
-        target_arch = "x86_64"
-    ))]
-    /// A tag named None, which has no payload.
-    pub const None: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<U4>()];
-
-        bytes[8] = discriminant_U4::None as u8;
-
-        core::mem::transmute::<[u8; core::mem::size_of::<U4>()], U4>(bytes)
-    };
-
-    #[cfg(any(
-        target_arch = "aarch64",
-        target_arch = "x86_64"
-    ))]
-    /// Construct a tag named `Some`, with the appropriate payload
-    pub fn Some(arg: u64) -> Self {
-            let mut answer = Self {
-                Some: arg
-            };
-
-            answer.set_discriminant(discriminant_U4::Some);
-
-            answer
-    }
-
-    #[cfg(any(
-        target_arch = "aarch64",
-        target_arch = "x86_64"
-    ))]
-    /// Unsafely assume the given `U4` has a `.discriminant()` of `Some` and convert it to `Some`'s payload.
-            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Some`.
-            pub unsafe fn into_Some(self) -> u64 {
-                debug_assert_eq!(self.discriminant(), discriminant_U4::Some);
-        let payload = self.Some;
-
-        payload
-    }
-
-    #[cfg(any(
-        target_arch = "aarch64",
-        target_arch = "x86_64"
-    ))]
-    /// Unsafely assume the given `U4` has a `.discriminant()` of `Some` and return its payload.
-            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Some`.
-            pub unsafe fn as_Some(&self) -> &u64 {
-                debug_assert_eq!(self.discriminant(), discriminant_U4::Some);
-        let payload = &self.Some;
-
-        &payload
-    }
-}
-
-impl Eq for U4 {}
-
-impl PartialEq for U4 {
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "aarch64",
-        target_arch = "wasm32",
-        target_arch = "x86",
-        target_arch = "x86_64"
-    ))]
-    fn eq(&self, other: &Self) -> bool {
-            if self.discriminant() != other.discriminant() {
-                return false;
-            }
-
-            unsafe {
-            match self.discriminant() {
-                discriminant_U4::None => true,
-                discriminant_U4::Some => self.Some == other.Some,
-            }
-        }
-    }
-}
-
-impl PartialOrd for U4 {
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "aarch64",
-        target_arch = "wasm32",
-        target_arch = "x86",
-        target_arch = "x86_64"
-    ))]
-    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-        match self.discriminant().partial_cmp(&other.discriminant()) {
-            Some(core::cmp::Ordering::Equal) => {}
-            not_eq => return not_eq,
-        }
-
-        unsafe {
-            match self.discriminant() {
-                discriminant_U4::None => Some(core::cmp::Ordering::Equal),
-                discriminant_U4::Some => self.Some.partial_cmp(&other.Some),
-            }
-        }
-    }
-}
-
-impl Ord for U4 {
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "aarch64",
-        target_arch = "wasm32",
-        target_arch = "x86",
-        target_arch = "x86_64"
-    ))]
-    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
-            match self.discriminant().cmp(&other.discriminant()) {
-                core::cmp::Ordering::Equal => {}
-                not_eq => return not_eq,
-            }
-
-            unsafe {
-            match self.discriminant() {
-                discriminant_U4::None => core::cmp::Ordering::Equal,
-                discriminant_U4::Some => self.Some.cmp(&other.Some),
-            }
-        }
-    }
-}
-
-impl Copy for U4 {}
-
-impl Clone for U4 {
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "aarch64",
-        target_arch = "wasm32",
-        target_arch = "x86",
-        target_arch = "x86_64"
-    ))]
-    fn clone(&self) -> Self {
-        let mut answer = unsafe {
-            match self.discriminant() {
-                discriminant_U4::None => core::mem::transmute::<
-                    core::mem::MaybeUninit<U4>,
-                    U4,
-                >(core::mem::MaybeUninit::uninit()),
-                discriminant_U4::Some => Self {
-                    Some: self.Some.clone(),
-                },
-            }
-
-        };
-
-        answer.set_discriminant(self.discriminant());
-
-        answer
-    }
-}
-
-impl core::hash::Hash for U4 {
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "aarch64",
-        target_arch = "wasm32",
-        target_arch = "x86",
-        target_arch = "x86_64"
-    ))]
-    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {        match self.discriminant() {
-            discriminant_U4::None => discriminant_U4::None.hash(state),
-            discriminant_U4::Some => unsafe {
-                    discriminant_U4::Some.hash(state);
-                    self.Some.hash(state);
-                },
-        }
-    }
-}
-
-impl core::fmt::Debug for U4 {
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "aarch64",
-        target_arch = "wasm32",
-        target_arch = "x86",
-        target_arch = "x86_64"
-    ))]
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.write_str("U4::")?;
-
-        unsafe {
-            match self.discriminant() {
-                discriminant_U4::None => f.write_str("None"),
-                discriminant_U4::Some => f.debug_tuple("Some")
-        .field(&self.Some)
-        .finish(),
-            }
-        }
-    }
-}
-
-impl U3 {
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
-    ))]
-    /// Returns which variant this tag union holds. Note that this never includes a payload!
-    pub fn discriminant(&self) -> discriminant_U3 {
-        unsafe {
-            let bytes = core::mem::transmute::<&Self, &[u8; core::mem::size_of::<Self>()]>(self);
-
-            core::mem::transmute::<u8, discriminant_U3>(*bytes.as_ptr().add(4))
-        }
-    }
-
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
-    ))]
-    /// Internal helper
-    fn set_discriminant(&mut self, discriminant: discriminant_U3) {
-        let discriminant_ptr: *mut discriminant_U3 = (self as *mut U3).cast();
-
-        unsafe {
-            *(discriminant_ptr.add(4)) = discriminant;
-        }
-    }
-
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
-    ))]
-    /// A tag named None, which has no payload.
-    pub const None: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<U3>()];
-
-        bytes[4] = discriminant_U3::None as u8;
-
-        core::mem::transmute::<[u8; core::mem::size_of::<U3>()], U3>(bytes)
-    };
-
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "aarch64",
-        target_arch = "wasm32",
-        target_arch = "x86",
-        target_arch = "x86_64"
-    ))]
-    /// Other `into_` methods return a payload, but since the None tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_None(self) {
-        ()
-    }
-
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "aarch64",
-        target_arch = "wasm32",
-        target_arch = "x86",
-        target_arch = "x86_64"
-    ))]
-    /// Other `as` methods return a payload, but since the None tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_None(&self) {
-        ()
-    }
-
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
-    ))]
-    /// Construct a tag named `Some`, with the appropriate payload
-    pub fn Some(arg: u32) -> Self {
-            let mut answer = Self {
-                Some: arg
-            };
-
-            answer.set_discriminant(discriminant_U3::Some);
-
-            answer
-    }
-
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
-    ))]
-    /// Unsafely assume the given `U3` has a `.discriminant()` of `Some` and convert it to `Some`'s payload.
-            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Some`.
-            pub unsafe fn into_Some(self) -> u32 {
-                debug_assert_eq!(self.discriminant(), discriminant_U3::Some);
-        let payload = self.Some;
-
-        payload
-    }
-
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
-    ))]
-    /// Unsafely assume the given `U3` has a `.discriminant()` of `Some` and return its payload.
-            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Some`.
-            pub unsafe fn as_Some(&self) -> &u32 {
-                debug_assert_eq!(self.discriminant(), discriminant_U3::Some);
-        let payload = &self.Some;
-
-        &payload
-    }
-
-    #[cfg(any(
-        target_arch = "aarch64",
-        target_arch = "x86_64"
-    ))]
-    /// Returns which variant this tag union holds. Note that this never includes a payload!
-    pub fn discriminant(&self) -> discriminant_U3 {
-        unsafe {
-            let bytes = core::mem::transmute::<&Self, &[u8; core::mem::size_of::<Self>()]>(self);
-
-            core::mem::transmute::<u8, discriminant_U3>(*bytes.as_ptr().add(8))
-        }
-    }
-
-    #[cfg(any(
-        target_arch = "aarch64",
-        target_arch = "x86_64"
-    ))]
-    /// Internal helper
-    fn set_discriminant(&mut self, discriminant: discriminant_U3) {
-        let discriminant_ptr: *mut discriminant_U3 = (self as *mut U3).cast();
-
-        unsafe {
-            *(discriminant_ptr.add(8)) = discriminant;
-        }
-    }
-
-    #[cfg(any(
-        target_arch = "aarch64",
-        target_arch = "x86_64"
-    ))]
-    /// A tag named None, which has no payload.
-    pub const None: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<U3>()];
-
-        bytes[8] = discriminant_U3::None as u8;
-
-        core::mem::transmute::<[u8; core::mem::size_of::<U3>()], U3>(bytes)
-    };
-
-    #[cfg(any(
-        target_arch = "aarch64",
-        target_arch = "x86_64"
-    ))]
-    /// Construct a tag named `Some`, with the appropriate payload
-    pub fn Some(arg: u64) -> Self {
-            let mut answer = Self {
-                Some: arg
-            };
-
-            answer.set_discriminant(discriminant_U3::Some);
-
-            answer
-    }
-
-    #[cfg(any(
-        target_arch = "aarch64",
-        target_arch = "x86_64"
-    ))]
-    /// Unsafely assume the given `U3` has a `.discriminant()` of `Some` and convert it to `Some`'s payload.
-            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Some`.
-            pub unsafe fn into_Some(self) -> u64 {
-                debug_assert_eq!(self.discriminant(), discriminant_U3::Some);
-        let payload = self.Some;
-
-        payload
-    }
-
-    #[cfg(any(
-        target_arch = "aarch64",
-        target_arch = "x86_64"
-    ))]
-    /// Unsafely assume the given `U3` has a `.discriminant()` of `Some` and return its payload.
-            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Some`.
-            pub unsafe fn as_Some(&self) -> &u64 {
-                debug_assert_eq!(self.discriminant(), discriminant_U3::Some);
-        let payload = &self.Some;
-
-        &payload
-    }
-}
-
-impl Eq for U3 {}
-
-impl PartialEq for U3 {
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "aarch64",
-        target_arch = "wasm32",
-        target_arch = "x86",
-        target_arch = "x86_64"
-    ))]
-    fn eq(&self, other: &Self) -> bool {
-            if self.discriminant() != other.discriminant() {
-                return false;
-            }
-
-            unsafe {
-            match self.discriminant() {
-                discriminant_U3::None => true,
-                discriminant_U3::Some => self.Some == other.Some,
-            }
-        }
-    }
-}
-
-impl PartialOrd for U3 {
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "aarch64",
-        target_arch = "wasm32",
-        target_arch = "x86",
-        target_arch = "x86_64"
-    ))]
-    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-        match self.discriminant().partial_cmp(&other.discriminant()) {
-            Some(core::cmp::Ordering::Equal) => {}
-            not_eq => return not_eq,
-        }
-
-        unsafe {
-            match self.discriminant() {
-                discriminant_U3::None => Some(core::cmp::Ordering::Equal),
-                discriminant_U3::Some => self.Some.partial_cmp(&other.Some),
-            }
-        }
-    }
-}
-
-impl Ord for U3 {
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "aarch64",
-        target_arch = "wasm32",
-        target_arch = "x86",
-        target_arch = "x86_64"
-    ))]
-    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
-            match self.discriminant().cmp(&other.discriminant()) {
-                core::cmp::Ordering::Equal => {}
-                not_eq => return not_eq,
-            }
-
-            unsafe {
-            match self.discriminant() {
-                discriminant_U3::None => core::cmp::Ordering::Equal,
-                discriminant_U3::Some => self.Some.cmp(&other.Some),
-            }
-        }
-    }
-}
-
-impl Copy for U3 {}
-
-impl Clone for U3 {
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "aarch64",
-        target_arch = "wasm32",
-        target_arch = "x86",
-        target_arch = "x86_64"
-    ))]
-    fn clone(&self) -> Self {
-        let mut answer = unsafe {
-            match self.discriminant() {
-                discriminant_U3::None => core::mem::transmute::<
-                    core::mem::MaybeUninit<U3>,
-                    U3,
-                >(core::mem::MaybeUninit::uninit()),
-                discriminant_U3::Some => Self {
-                    Some: self.Some.clone(),
-                },
-            }
-
-        };
-
-        answer.set_discriminant(self.discriminant());
-
-        answer
-    }
-}
-
-impl core::hash::Hash for U3 {
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "aarch64",
-        target_arch = "wasm32",
-        target_arch = "x86",
-        target_arch = "x86_64"
-    ))]
-    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {        match self.discriminant() {
-            discriminant_U3::None => discriminant_U3::None.hash(state),
-            discriminant_U3::Some => unsafe {
-                    discriminant_U3::Some.hash(state);
-                    self.Some.hash(state);
-                },
-        }
-    }
-}
-
-impl core::fmt::Debug for U3 {
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "aarch64",
-        target_arch = "wasm32",
-        target_arch = "x86",
-        target_arch = "x86_64"
-    ))]
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.write_str("U3::")?;
-
-        unsafe {
-            match self.discriminant() {
-                discriminant_U3::None => f.write_str("None"),
-                discriminant_U3::Some => f.debug_tuple("Some")
-        .field(&self.Some)
-        .finish(),
-            }
-        }
-    }
-}
-
 impl U1 {
     #[cfg(any(
         target_arch = "arm",
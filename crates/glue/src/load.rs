@@ -1,14 +1,31 @@
+use crate::glue as roc_type;
 use crate::rust_glue;
-use crate::types::{Env, Types};
+use crate::types::{self, Types};
 use bumpalo::Bump;
-use roc_load::{ExecutionMode, LoadConfig, LoadedModule, LoadingProblem, Threading};
+use inkwell::context::Context;
+use roc_build::link::llvm_module_to_dylib;
+use roc_collections::{MutSet, VecMap};
+use roc_gen_llvm::llvm::build::{
+    build_procedures_return_main, construct_optimization_passes, module_from_builtins, Env,
+    LlvmBackendMode,
+};
+use roc_gen_llvm::llvm::externs::add_default_roc_externs;
+use roc_gen_llvm::run_roc_dylib;
+use roc_load::{
+    EmitIr, EntryPoint, ExecutionMode, LoadConfig, LoadMonomorphizedError, LoadedModule,
+    LoadingProblem, MonomorphizedModule, Threading,
+};
+use roc_mono::ir::{OptLevel, SingleEntryPoint};
 use roc_mono::layout::GlobalLayoutInterner;
 use roc_packaging::cache::{self, RocCacheDir};
+use roc_reporting::cli::{report_problems, DiagnosticLevels};
 use roc_reporting::report::{RenderTarget, DEFAULT_PALETTE};
+use roc_std::{RocList, RocResult, RocStr};
 use roc_target::{Architecture, OperatingSystem, TargetInfo};
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::{self, ErrorKind, Write};
-use std::path::{Path, PathBuf};
+use std::mem::{ManuallyDrop, MaybeUninit};
+use std::path::{Component, Path, PathBuf};
 use std::process;
 use strum::IntoEnumIterator;
 use target_lexicon::Triple;
@@ -21,13 +38,24 @@ impl IgnoreErrors {
     const NONE: Self = IgnoreErrors { can: false };
 }
 
-pub fn generate(input_path: &Path, output_path: &Path) -> io::Result<i32> {
+/// Generates glue for the platform at `input_path`. Without a spec, this writes Rust glue to
+/// `output_path`. With one, the spec (an app written against the RocType.roc platform) decides
+/// what to generate, and `output_path` is the directory to write its files into.
+pub fn generate(
+    input_path: &Path,
+    output_path: &Path,
+    spec_path: Option<&Path>,
+) -> io::Result<i32> {
     match load_types(
         input_path.to_path_buf(),
         Threading::AllAvailable,
         IgnoreErrors::NONE,
     ) {
         Ok(types_and_targets) => {
+            if let Some(spec_path) = spec_path {
+                return run_glue_spec(spec_path, &types_and_targets, output_path);
+            }
+
             let mut file = File::create(output_path).unwrap_or_else(|err| {
                 eprintln!(
                     "Unable to create output file {} - {:?}",
@@ -77,6 +105,254 @@ pub fn generate(input_path: &Path, output_path: &Path) -> io::Result<i32> {
     }
 }
 
+/// Compiles a glue spec, passes it the platform's types on every target,
+/// and writes the files it returns into the output directory.
+fn run_glue_spec(
+    spec_path: &Path,
+    types_and_targets: &[(Types, TargetInfo)],
+    output_dir: &Path,
+) -> io::Result<i32> {
+    let target = Triple::host();
+    let arena = &Bump::new();
+    let opt_level = OptLevel::Normal;
+
+    let load_result = roc_load::load_and_monomorphize(
+        arena,
+        spec_path.to_path_buf(),
+        RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
+        LoadConfig {
+            target_info: (&target).into(),
+            render: RenderTarget::ColorTerminal,
+            palette: DEFAULT_PALETTE,
+            threading: Threading::AllAvailable,
+            exec_mode: ExecutionMode::Executable,
+            fold_constants: true,
+            emit_ir: EmitIr::default(),
+            trace_refcounts: false,
+        },
+    );
+
+    let mut loaded = match load_result {
+        Ok(loaded) => loaded,
+        Err(LoadMonomorphizedError::LoadingProblem(LoadingProblem::FormattedReport(report))) => {
+            eprintln!("{}", report);
+
+            return Ok(1);
+        }
+        Err(LoadMonomorphizedError::LoadingProblem(problem)) => {
+            eprintln!(
+                "Error loading glue spec {} - {:?}",
+                spec_path.display(),
+                problem
+            );
+
+            return Ok(1);
+        }
+        Err(LoadMonomorphizedError::ErrorModule(mut module)) => {
+            let problems = report_problems(
+                module.total_problems(),
+                &module.sources,
+                &module.interns,
+                &mut module.can_problems,
+                &mut module.type_problems,
+                &DiagnosticLevels::default(),
+            );
+
+            return Ok(problems.exit_code());
+        }
+    };
+
+    let problems = report_problems(
+        loaded.total_problems(),
+        &loaded.sources,
+        &loaded.interns,
+        &mut loaded.can_problems,
+        &mut loaded.type_problems,
+        &DiagnosticLevels::default(),
+    );
+
+    if problems.errors > 0 {
+        return Ok(problems.exit_code());
+    }
+
+    let (lib, main_fn_name) = match spec_to_dylib(arena, &target, loaded, opt_level) {
+        Ok(answer) => answer,
+        Err(err) => {
+            eprintln!(
+                "Unable to load the compiled glue spec {} - {:?}",
+                spec_path.display(),
+                err
+            );
+
+            return Ok(1);
+        }
+    };
+
+    let make_glue = run_roc_dylib!(
+        lib,
+        main_fn_name,
+        &RocList<roc_type::Types>,
+        RocResult<RocList<roc_type::File>, RocStr>
+    );
+
+    // The spec owns its argument, and frees it once it's done with it.
+    let types = ManuallyDrop::new(
+        types_and_targets
+            .iter()
+            .map(|(types, target_info)| types.to_roc(*target_info))
+            .collect::<RocList<_>>(),
+    );
+    let mut call_result = MaybeUninit::uninit();
+
+    let answer: Result<_, _> = unsafe {
+        make_glue(&types, call_result.as_mut_ptr());
+
+        call_result.assume_init().into()
+    };
+
+    let files = match answer.map(Result::from) {
+        Ok(Ok(files)) => files,
+        Ok(Err(msg)) => {
+            eprintln!("The glue spec {} failed: {}", spec_path.display(), msg);
+
+            return Ok(1);
+        }
+        Err((msg, _)) => {
+            eprintln!("The glue spec {} crashed: {}", spec_path.display(), msg);
+
+            return Ok(1);
+        }
+    };
+
+    let mut written = Vec::with_capacity(files.len());
+
+    for file in files.iter() {
+        let name = Path::new(file.name.as_str());
+
+        if name.is_absolute() || name.components().any(|c| c == Component::ParentDir) {
+            eprintln!(
+                "The glue spec {} tried to write {}, which is outside of the output directory.",
+                spec_path.display(),
+                name.display()
+            );
+
+            return Ok(1);
+        }
+
+        let path = output_dir.join(name);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(&path, file.content.as_str()).unwrap_or_else(|err| {
+            eprintln!(
+                "Unable to write glue to output file {} - {:?}",
+                path.display(),
+                err
+            );
+
+            process::exit(1);
+        });
+
+        written.push(path);
+    }
+
+    print!("🎉 Generated glue in:\n\n");
+
+    for path in written {
+        println!("\t{}", path.display());
+    }
+
+    Ok(0)
+}
+
+fn spec_to_dylib<'a>(
+    arena: &'a Bump,
+    target: &Triple,
+    loaded: MonomorphizedModule<'a>,
+    opt_level: OptLevel,
+) -> Result<(libloading::Library, &'a str), libloading::Error> {
+    let MonomorphizedModule {
+        procedures,
+        entry_point,
+        interns,
+        mut layout_interner,
+        ..
+    } = loaded;
+
+    let context = Context::create();
+    let builder = context.create_builder();
+    let module = arena.alloc(module_from_builtins(target, &context, ""));
+
+    let (module_pass, function_pass) = construct_optimization_passes(module, opt_level);
+    let (dibuilder, compile_unit) = Env::new_debug_info(module);
+
+    let env = Env {
+        arena,
+        builder: &builder,
+        dibuilder: &dibuilder,
+        compile_unit: &compile_unit,
+        context: &context,
+        interns,
+        module,
+        target_info: target.into(),
+        mode: LlvmBackendMode::GenTest, // so a crash in the spec is reported, not fatal
+        // important! we don't want any procedures to get the C calling convention
+        exposed_to_host: MutSet::default(),
+        check_memory: false,
+        trace_refcounts: false,
+    };
+
+    // Add roc_alloc, roc_realloc, and roc_dealloc, since there's no host to provide them.
+    add_default_roc_externs(&env);
+
+    let entry_point = match entry_point {
+        EntryPoint::Executable {
+            exposed_to_host, ..
+        } => {
+            // RocType.roc provides exactly one function, makeGlueForHost
+            debug_assert_eq!(exposed_to_host.len(), 1);
+            let (symbol, layout) = exposed_to_host[0];
+
+            SingleEntryPoint { symbol, layout }
+        }
+        EntryPoint::Test => {
+            unreachable!()
+        }
+    };
+
+    let (main_fn_name, main_fn) = build_procedures_return_main(
+        &env,
+        &mut layout_interner,
+        opt_level,
+        procedures,
+        entry_point,
+    );
+
+    env.dibuilder.finalize();
+
+    // we don't use the debug info, and it causes weird errors.
+    module.strip_debug_info();
+
+    if main_fn.verify(true) {
+        function_pass.run_on(&main_fn);
+    } else {
+        panic!(
+            "Main function {} failed LLVM verification in glue generation.",
+            main_fn_name
+        );
+    }
+
+    module_pass.run_on(env.module);
+
+    if let Err(errors) = env.module.verify() {
+        panic!("Errors defining glue spec module:\n{}", errors.to_string());
+    }
+
+    llvm_module_to_dylib(env.module, target, opt_level).map(|lib| (lib, main_fn_name))
+}
+
 pub fn load_types(
     full_file_path: PathBuf,
     threading: Threading,
@@ -102,6 +378,9 @@ pub fn load_types(
             palette: DEFAULT_PALETTE,
             threading,
             exec_mode: ExecutionMode::Check,
+            fold_constants: true,
+            emit_ir: EmitIr::default(),
+            trace_refcounts: false,
         },
     )
     .unwrap_or_else(|problem| match problem {
@@ -150,7 +429,19 @@ pub fn load_types(
         }
     });
 
-    let layout_interner = GlobalLayoutInterner::with_capacity(128, target_info);
+    // The closures a platform exposes to the host are named with `as` in the exposed
+    // defs' annotations, e.g. `mainForHost : { init : ({} -> Model) as Init }`
+    let mut host_exposed_aliases = VecMap::default();
+
+    for (index, annotation) in decls.annotations.iter().enumerate() {
+        if let Some(annotation) = annotation {
+            let def_symbol = decls.symbols[index].value;
+
+            for alias in annotation.introduced_variables.host_exposed_aliases.keys() {
+                host_exposed_aliases.insert(*alias, def_symbol);
+            }
+        }
+    }
 
     let architectures = Architecture::iter();
     let mut types_and_targets = Vec::with_capacity(architectures.len());
@@ -161,7 +452,16 @@ pub fn load_types(
         };
 
         let types = {
-            let mut env = Env::new(arena, subs, &interns, layout_interner.fork(), target_info);
+            // Layout sizes depend on the target, so each target needs its own interner
+            let layout_interner = GlobalLayoutInterner::with_capacity(128, target_info);
+            let mut env = types::Env::new(
+                arena,
+                subs,
+                &interns,
+                layout_interner.fork(),
+                host_exposed_aliases.clone(),
+                target_info,
+            );
 
             env.vars_to_types(variables.clone())
         };
@@ -294,12 +294,367 @@ fn add_type(target_info: TargetInfo, id: TypeId, types: &Types, impls: &mut Impl
             // This is recursively pointing to a type that should already have been added,
             // so no extra work needs to happen.
         }
-        RocType::Function { .. } => {
-            // TODO actually generate glue functions!
+        RocType::Function {
+            name,
+            extern_name,
+            args,
+            ret,
+            closure_size_known,
+        } => {
+            // Only closures the platform exposes to the host have symbols we can call.
+            if !extern_name.is_empty() {
+                add_function(
+                    name,
+                    extern_name,
+                    args,
+                    *ret,
+                    *closure_size_known,
+                    id,
+                    target_info,
+                    types,
+                    impls,
+                );
+            }
         }
     }
 }
 
+/// Whether this closure comes from the application, so the wrapper keeps the data it
+/// captured on the heap instead of inline.
+fn is_heap_closure(id: TypeId, types: &Types) -> bool {
+    matches!(
+        types.get_type(id),
+        RocType::Function {
+            closure_size_known: false,
+            ..
+        }
+    )
+}
+
+/// Generates a wrapper for a closure exposed to the host, which calls into the
+/// `roc__{extern_name}_caller` function the platform exports.
+///
+/// If the closure's size is known, the wrapper holds its captured data inline, so it can
+/// be part of other types like records and tag unions. Otherwise, the closure comes from
+/// the application, and the wrapper owns a heap-allocated copy of the captured data,
+/// whose size is only known once the platform has been linked with an application.
+#[allow(clippy::too_many_arguments)]
+fn add_function(
+    name: &str,
+    extern_name: &str,
+    args: &[TypeId],
+    ret: TypeId,
+    closure_size_known: bool,
+    id: TypeId,
+    target_info: TargetInfo,
+    types: &Types,
+    impls: &mut Impls,
+) {
+    let name = escape_kw(name.to_string());
+    let ret_is_heap_closure = is_heap_closure(ret, types);
+    let ret_type_name = type_name(ret, types);
+
+    // The extern declarations of the functions the platform exports for this closure
+    {
+        let mut caller_params = String::new();
+
+        for (index, arg_id) in args.iter().enumerate() {
+            let arg_type = match types.get_type(*arg_id) {
+                RocType::Function { .. } => "u8".to_string(),
+                _ => type_name(*arg_id, types),
+            };
+
+            write!(caller_params, "arg{index}: *const {arg_type}, ").unwrap();
+        }
+
+        let output_type = if ret_is_heap_closure {
+            "u8"
+        } else {
+            ret_type_name.as_str()
+        };
+
+        add_decl(
+            impls,
+            None,
+            target_info,
+            format!(
+                r#"extern "C" {{
+    fn roc__{extern_name}_caller({caller_params}closure_data: *const u8, output: *mut {output_type});
+    fn roc__{extern_name}_size() -> i64;
+    fn roc__{extern_name}_result_size() -> i64;
+    fn roc__{extern_name}_inc(closure_data: *const u8);
+    fn roc__{extern_name}_dec(closure_data: *const u8);
+}}"#
+            ),
+        );
+    }
+
+    let opt_impl = Some(format!("impl {name}"));
+
+    if closure_size_known {
+        let size = types.size_rounded_to_alignment(id);
+        // Closures that capture nothing have an alignment of 0, which Rust doesn't allow.
+        let align = types.align(id).max(1);
+
+        add_decl(
+            impls,
+            None,
+            target_info,
+            format!(
+                r#"/// A Roc closure of type `{name}`, along with the values it captured.
+#[repr(C, align({align}))]
+pub struct {name} {{
+    closure_data: [u8; {size}],
+}}"#
+            ),
+        );
+
+        add_decl(
+            impls,
+            opt_impl.clone(),
+            target_info,
+            format!(
+                r#"/// The number of bytes of data this closure captures.
+    pub fn closure_size() -> usize {{
+        {size}
+    }}"#
+            ),
+        );
+
+        add_decl(
+            impls,
+            opt_impl.clone(),
+            target_info,
+            r#"/// Copies the closure data at the given pointer, taking over its refcounts.
+    ///
+    /// # Safety
+    ///
+    /// `closure_data` must point to `Self::closure_size()` bytes of data for this closure,
+    /// such as the output of a Roc function returning it. Nothing else may decrement the
+    /// refcounts of the values it captured afterwards.
+    pub unsafe fn from_closure_data(closure_data: *const u8) -> Self {
+        let mut answer = core::mem::MaybeUninit::<Self>::uninit();
+
+        core::ptr::copy_nonoverlapping(closure_data, answer.as_mut_ptr().cast(), Self::closure_size());
+
+        answer.assume_init()
+    }"#
+            .to_string(),
+        );
+
+        add_decl(
+            impls,
+            opt_impl.clone(),
+            target_info,
+            r#"/// A pointer to the data this closure captured, valid for as long as `self` is.
+    pub fn as_closure_data(&self) -> *const u8 {
+        self.closure_data.as_ptr()
+    }"#
+            .to_string(),
+        );
+    } else {
+        add_decl(
+            impls,
+            None,
+            target_info,
+            format!(
+                r#"/// A Roc closure of type `{name}`, along with the values it captured.
+pub struct {name} {{
+    closure_data: *mut u8,
+}}"#
+            ),
+        );
+
+        add_decl(
+            impls,
+            opt_impl.clone(),
+            target_info,
+            format!(
+                r#"/// The number of bytes of data this closure captures.
+    pub fn closure_size() -> usize {{
+        unsafe {{ roc__{extern_name}_size() as usize }}
+    }}"#
+            ),
+        );
+
+        add_decl(
+            impls,
+            opt_impl.clone(),
+            target_info,
+            // Roc never aligns values to more than 16 bytes.
+            r#"fn alloc_closure_data() -> *mut u8 {
+        unsafe { crate::roc_alloc(Self::closure_size().max(1), 16).cast() }
+    }"#
+            .to_string(),
+        );
+
+        add_decl(
+            impls,
+            opt_impl.clone(),
+            target_info,
+            r#"/// Copies the closure data at the given pointer, taking over its refcounts.
+    ///
+    /// # Safety
+    ///
+    /// `closure_data` must point to `Self::closure_size()` bytes of data for this closure,
+    /// such as the output of a Roc function returning it. Nothing else may decrement the
+    /// refcounts of the values it captured afterwards.
+    pub unsafe fn from_closure_data(closure_data: *const u8) -> Self {
+        let copy = Self::alloc_closure_data();
+
+        core::ptr::copy_nonoverlapping(closure_data, copy, Self::closure_size());
+
+        Self { closure_data: copy }
+    }"#
+            .to_string(),
+        );
+
+        add_decl(
+            impls,
+            opt_impl.clone(),
+            target_info,
+            r#"/// A pointer to the data this closure captured, valid for as long as `self` is.
+    pub fn as_closure_data(&self) -> *const u8 {
+        self.closure_data
+    }"#
+            .to_string(),
+        );
+    }
+
+    // The Roc function the closure calls takes ownership of the data it captured,
+    // so we give it a reference of its own.
+    add_decl(
+        impls,
+        opt_impl.clone(),
+        target_info,
+        format!(
+            r#"fn share(&self) -> *const u8 {{
+        let closure_data = self.as_closure_data();
+
+        unsafe {{
+            roc__{extern_name}_inc(closure_data);
+        }}
+
+        closure_data
+    }}"#
+        ),
+    );
+
+    // The call method, e.g.
+    //
+    // pub fn call(&self, arg0: &roc_std::RocStr) -> Op
+    {
+        let mut params = String::new();
+        let mut owned_args = String::new();
+        let mut caller_args = String::new();
+
+        for (index, arg_id) in args.iter().enumerate() {
+            let arg_type = type_name(*arg_id, types);
+
+            write!(params, ", arg{index}: &{arg_type}").unwrap();
+
+            match types.get_type(*arg_id) {
+                RocType::Function { .. } => write!(caller_args, "arg{index}.share(), ").unwrap(),
+                _ => {
+                    write!(
+                        owned_args,
+                        "let arg{index} = core::mem::ManuallyDrop::new(arg{index}.clone());\n        "
+                    )
+                    .unwrap();
+                    write!(caller_args, "&*arg{index}, ").unwrap();
+                }
+            }
+        }
+
+        let body = if ret_is_heap_closure {
+            format!(
+                r#"{owned_args}let closure_data = {ret_type_name}::alloc_closure_data();
+
+        unsafe {{
+            roc__{extern_name}_caller({caller_args}self.share(), closure_data);
+        }}
+
+        {ret_type_name} {{ closure_data }}"#
+            )
+        } else {
+            format!(
+                r#"{owned_args}let mut output = core::mem::MaybeUninit::uninit();
+
+        unsafe {{
+            roc__{extern_name}_caller({caller_args}self.share(), output.as_mut_ptr());
+
+            output.assume_init()
+        }}"#
+            )
+        };
+
+        add_decl(
+            impls,
+            opt_impl,
+            target_info,
+            format!(
+                r#"/// Calls the closure. The closure and the arguments can be used again afterwards.
+    pub fn call(&self{params}) -> {ret_type_name} {{
+        {body}
+    }}"#
+            ),
+        );
+    }
+
+    let clone_body = if closure_size_known {
+        "Self {
+                closure_data: self.closure_data,
+            }"
+    } else {
+        "Self::from_closure_data(self.closure_data)"
+    };
+
+    add_decl(
+        impls,
+        Some(format!("impl Clone for {name}")),
+        target_info,
+        format!(
+            r#"fn clone(&self) -> Self {{
+        unsafe {{
+            roc__{extern_name}_inc(self.as_closure_data());
+
+            {clone_body}
+        }}
+    }}"#
+        ),
+    );
+
+    let dealloc = if closure_size_known {
+        ""
+    } else {
+        "\n\n            crate::roc_dealloc(self.closure_data.cast(), 16);"
+    };
+
+    add_decl(
+        impls,
+        Some(format!("impl Drop for {name}")),
+        target_info,
+        format!(
+            r#"fn drop(&mut self) {{
+        unsafe {{
+            roc__{extern_name}_dec(self.as_closure_data());{dealloc}
+        }}
+    }}"#
+        ),
+    );
+
+    add_decl(
+        impls,
+        Some(format!("impl core::fmt::Debug for {name}")),
+        target_info,
+        format!(
+            r#"fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {{
+        f.debug_struct("{name}").finish_non_exhaustive()
+    }}"#
+        ),
+    );
+}
+
 fn add_single_tag_struct(
     name: &str,
     tag_name: &str,
@@ -946,7 +1301,8 @@ pub struct {name} {{
                     | RocType::RocBox(_)
                     | RocType::TagUnion(_)
                     | RocType::RocResult(_, _)
-                    | RocType::RecursivePointer { .. } => {
+                    | RocType::RecursivePointer { .. }
+                    | RocType::Function { .. } => {
                         owned_ret_type = type_name(*payload_id, types);
                         borrowed_ret_type = format!("&{}", owned_ret_type);
                         owned_ret = "payload".to_string();
@@ -980,7 +1336,6 @@ pub struct {name} {{
                         payload_args = answer.payload_args;
                         args_to_payload = answer.args_to_payload;
                     }
-                    RocType::Function { .. } => todo!(),
                 };
 
                 {
@@ -1239,7 +1594,7 @@ pub struct {name} {{
     }
 
     // The PartialEq impl for the tag union
-    {
+    if !has_function(typ, types) {
         let opt_impl_prefix = if has_float(typ, types) {
             String::new()
         } else {
@@ -1294,7 +1649,7 @@ pub struct {name} {{
     }
 
     // The PartialOrd impl for the tag union
-    {
+    if !has_function(typ, types) {
         let opt_impl = Some(format!("impl PartialOrd for {name}"));
 
         let body = if discriminant_size == 0 {
@@ -1349,7 +1704,7 @@ pub struct {name} {{
     }
 
     // The Ord impl for the tag union
-    if !has_float(typ, types) {
+    if !has_float(typ, types) && !has_function(typ, types) {
         let opt_impl = Some(format!("impl Ord for {name}"));
 
         let body = if discriminant_size == 0 {
@@ -1475,7 +1830,7 @@ pub struct {name} {{
     }
 
     // The Hash impl for the tag union
-    if !has_float(typ, types) {
+    if !has_float(typ, types) && !has_function(typ, types) {
         let opt_impl = Some(format!("impl core::hash::Hash for {name}"));
         let mut buf = r#"fn hash<H: core::hash::Hasher>(&self, state: &mut H) {"#.to_string();
 
@@ -1573,7 +1928,8 @@ pub struct {name} {{
                             | RocType::TagUnion(_)
                             | RocType::RocResult(_, _)
                             | RocType::Struct { .. }
-                            | RocType::RecursivePointer { .. } => {
+                            | RocType::RecursivePointer { .. }
+                            | RocType::Function { .. } => {
                                 format!(".field({deref_str}{actual_self}.{tag_name})")
                             }
                             RocType::TagUnionPayload { fields, .. } => {
@@ -1588,7 +1944,6 @@ pub struct {name} {{
 
                                 buf.join("\n")
                             }
-                            RocType::Function { .. } => todo!(),
                         };
 
                         format!(
@@ -1771,25 +2126,29 @@ fn type_name(id: TypeId, types: &Types) -> String {
 /// case of a struct that's a payload for a recursive tag union, typ.has_enumeration()
 /// will return true, but actually we want to derive Debug here anyway.
 fn derive_str(typ: &RocType, types: &Types, include_debug: bool) -> String {
-    let mut buf = "#[derive(Clone, ".to_string();
+    let mut buf = "#[derive(Clone".to_string();
 
     if !cannot_derive_copy(typ, types) {
-        buf.push_str("Copy, ");
+        buf.push_str(", Copy");
     }
 
     if include_debug {
-        buf.push_str("Debug, ");
+        buf.push_str(", Debug");
     }
 
     if !cannot_derive_default(typ, types) {
-        buf.push_str("Default, ");
+        buf.push_str(", Default");
     }
 
-    if !has_float(typ, types) {
-        buf.push_str("Eq, Ord, Hash, ");
+    if !has_function(typ, types) {
+        if !has_float(typ, types) {
+            buf.push_str(", Eq, Ord, Hash");
+        }
+
+        buf.push_str(", PartialEq, PartialOrd");
     }
 
-    buf.push_str("PartialEq, PartialOrd)]");
+    buf.push_str(")]");
 
     buf
 }
@@ -1881,7 +2240,8 @@ pub struct {name} {{
             | RocType::RocBox(_)
             | RocType::RocResult(_, _)
             | RocType::TagUnion(_)
-            | RocType::RecursivePointer { .. } => {
+            | RocType::RecursivePointer { .. }
+            | RocType::Function { .. } => {
                 owned_ret_type = type_name(non_null_payload, types);
                 borrowed_ret_type = format!("&{}", owned_ret_type);
                 payload_args = format!("arg: {owned_ret_type}");
@@ -1911,7 +2271,6 @@ pub struct {name} {{
                 owned_ret_type = answer.owned_ret_type;
                 borrowed_ret_type = answer.borrowed_ret_type;
             }
-            RocType::Function { .. } => todo!(),
         };
 
         // Add a convenience constructor function for the tag with the payload, e.g.
@@ -2130,7 +2489,8 @@ pub struct {name} {{
             | RocType::RocBox(_)
             | RocType::RocResult(_, _)
             | RocType::TagUnion(_)
-            | RocType::RecursivePointer { .. } => {
+            | RocType::RecursivePointer { .. }
+            | RocType::Function { .. } => {
                 format!(
                     r#"f.debug_tuple("{non_null_tag}").field(&*{extra_deref}self.pointer).finish()"#
                 )
@@ -2154,7 +2514,6 @@ pub struct {name} {{
 
                 buf.join(&format!("\n{INDENT}{INDENT}{INDENT}{INDENT}{INDENT}"))
             }
-            RocType::Function { .. } => todo!(),
         };
 
         let body = format!(
@@ -2386,8 +2745,7 @@ fn cannot_derive_copy(roc_type: &RocType, types: &Types) -> bool {
         | RocType::EmptyTagUnion
         | RocType::Bool
         | RocType::Num(_)
-        | RocType::TagUnion(RocTagUnion::Enumeration { .. })
-        | RocType::Function { .. } => false,
+        | RocType::TagUnion(RocTagUnion::Enumeration { .. }) => false,
         RocType::RocStr
         | RocType::RocList(_)
         | RocType::RocDict(_, _)
@@ -2397,7 +2755,8 @@ fn cannot_derive_copy(roc_type: &RocType, types: &Types) -> bool {
         | RocType::TagUnion(RocTagUnion::NullableWrapped { .. })
         | RocType::TagUnion(RocTagUnion::Recursive { .. })
         | RocType::RecursivePointer { .. }
-        | RocType::TagUnion(RocTagUnion::NonNullableUnwrapped { .. }) => true,
+        | RocType::TagUnion(RocTagUnion::NonNullableUnwrapped { .. })
+        | RocType::Function { .. } => true,
         RocType::TagUnion(RocTagUnion::SingleTagStruct { payload_fields, .. }) => payload_fields
             .iter()
             .any(|type_id| cannot_derive_copy(types.get_type(*type_id), types)),
@@ -2496,6 +2855,75 @@ fn has_float_help(roc_type: &RocType, types: &Types, do_not_recurse: &[TypeId])
     }
 }
 
+/// Useful when determining whether to derive PartialEq, PartialOrd, Eq, Ord, and Hash
+/// in a Rust type, since Roc functions can't be compared or hashed.
+fn has_function(roc_type: &RocType, types: &Types) -> bool {
+    has_function_help(roc_type, types, &[])
+}
+
+fn has_function_help(roc_type: &RocType, types: &Types, do_not_recurse: &[TypeId]) -> bool {
+    match roc_type {
+        RocType::Function { .. } => true,
+        RocType::Unit
+        | RocType::EmptyTagUnion
+        | RocType::RocStr
+        | RocType::Bool
+        | RocType::Num(_)
+        | RocType::TagUnion(RocTagUnion::Enumeration { .. }) => false,
+        RocType::RocList(id) | RocType::RocSet(id) | RocType::RocBox(id) => {
+            has_function_help(types.get_type(*id), types, do_not_recurse)
+        }
+        RocType::RocResult(ok_id, err_id) => {
+            has_function_help(types.get_type(*ok_id), types, do_not_recurse)
+                || has_function_help(types.get_type(*err_id), types, do_not_recurse)
+        }
+        RocType::RocDict(key_id, val_id) => {
+            has_function_help(types.get_type(*key_id), types, do_not_recurse)
+                || has_function_help(types.get_type(*val_id), types, do_not_recurse)
+        }
+        RocType::Struct { fields, .. } => fields
+            .iter()
+            .any(|(_, type_id)| has_function_help(types.get_type(*type_id), types, do_not_recurse)),
+        RocType::TagUnionPayload { fields, .. } => fields
+            .iter()
+            .any(|(_, type_id)| has_function_help(types.get_type(*type_id), types, do_not_recurse)),
+        RocType::TagUnion(RocTagUnion::SingleTagStruct { payload_fields, .. }) => payload_fields
+            .iter()
+            .any(|type_id| has_function_help(types.get_type(*type_id), types, do_not_recurse)),
+        RocType::TagUnion(RocTagUnion::Recursive { tags, .. })
+        | RocType::TagUnion(RocTagUnion::NonRecursive { tags, .. }) => {
+            tags.iter().any(|(_, payloads)| {
+                payloads
+                    .iter()
+                    .any(|id| has_function_help(types.get_type(*id), types, do_not_recurse))
+            })
+        }
+        RocType::TagUnion(RocTagUnion::NullableWrapped { tags, .. }) => {
+            tags.iter().any(|(_, payloads)| {
+                payloads
+                    .iter()
+                    .any(|id| has_function_help(types.get_type(*id), types, do_not_recurse))
+            })
+        }
+        RocType::TagUnion(RocTagUnion::NonNullableUnwrapped { payload, .. })
+        | RocType::TagUnion(RocTagUnion::NullableUnwrapped {
+            non_null_payload: payload,
+            ..
+        })
+        | RocType::RecursivePointer(payload) => {
+            if do_not_recurse.contains(payload) {
+                false
+            } else {
+                let mut do_not_recurse: Vec<TypeId> = do_not_recurse.into();
+
+                do_not_recurse.push(*payload);
+
+                has_function_help(types.get_type(*payload), types, &do_not_recurse)
+            }
+        }
+    }
+}
+
 // Based on https://doc.rust-lang.org/reference/keywords.html
 const RESERVED_KEYWORDS: &[&str] = &[
     "try", "abstract", "become", "box", "do", "final", "macro", "override", "priv", "typeof",
@@ -1,4 +1,5 @@
 use crate::enums::Enums;
+use crate::glue as roc_type;
 use crate::structs::Structs;
 use bumpalo::Bump;
 use fnv::FnvHashMap;
@@ -15,7 +16,8 @@ use roc_mono::layout::{
     cmp_fields, ext_var_is_empty_tag_union, round_up_to_alignment, Builtin, Discriminant, InLayout,
     Layout, LayoutCache, LayoutInterner, TLLayoutInterner, UnionLayout,
 };
-use roc_target::TargetInfo;
+use roc_std::RocList;
+use roc_target::{Architecture, OperatingSystem, TargetInfo};
 use roc_types::{
     subs::{Content, FlatType, GetSubsSlice, Subs, UnionLabels, UnionTags, Variable},
     types::{AliasKind, RecordField},
@@ -297,18 +299,24 @@ impl Types {
             (
                 Function {
                     name: name_a,
+                    extern_name: extern_name_a,
                     args: args_a,
                     ret: ret_a,
+                    closure_size_known: known_a,
                 },
                 Function {
                     name: name_b,
+                    extern_name: extern_name_b,
                     args: args_b,
                     ret: ret_b,
+                    closure_size_known: known_b,
                 },
             ) => {
                 // for functions, the name is actually important because two functions
                 // with the same type could have completely different implementations!
                 if name_a == name_b
+                    && extern_name_a == extern_name_b
+                    && known_a == known_b
                     && args_a.len() == args_b.len()
                     && self.is_equivalent_help(
                         self.get_type_or_pending(*ret_a),
@@ -486,6 +494,39 @@ impl Types {
             } => unreachable!("Cyclic type definitions: {:?}", nodes_in_cycle),
         }
     }
+
+    /// Converts these types into the `Types` record which glue specs written in Roc receive,
+    /// as laid out by the platform in RocType.roc.
+    pub fn to_roc(&self, target: TargetInfo) -> roc_type::Types {
+        let deps = self
+            .ids()
+            .map(|id| match self.deps.get(&id) {
+                Some(deps) => deps.iter().map(|dep| dep.0 as _).collect(),
+                None => RocList::empty(),
+            })
+            .collect();
+
+        roc_type::Types {
+            aligns: self.aligns.as_slice().into(),
+            deps,
+            sizes: self.sizes.as_slice().into(),
+            types: self.types.iter().map(RocType::to_roc).collect(),
+            target: roc_type::Target {
+                architecture: match target.architecture {
+                    Architecture::Aarch32 => roc_type::Architecture::Aarch32,
+                    Architecture::Aarch64 => roc_type::Architecture::Aarch64,
+                    Architecture::Wasm32 => roc_type::Architecture::Wasm32,
+                    Architecture::X86_32 => roc_type::Architecture::X86x32,
+                    Architecture::X86_64 => roc_type::Architecture::X86x64,
+                },
+                operatingSystem: match target.operating_system {
+                    OperatingSystem::Windows => roc_type::OperatingSystem::Windows,
+                    OperatingSystem::Unix => roc_type::OperatingSystem::Unix,
+                    OperatingSystem::Wasi => roc_type::OperatingSystem::Wasi,
+                },
+            },
+        }
+    }
 }
 
 enum RocTypeOrPending<'a> {
@@ -518,15 +559,81 @@ pub enum RocType {
     /// this would be the field of Cons containing the (recursive) StrConsList type,
     /// and the TypeId is the TypeId of StrConsList itself.
     RecursivePointer(TypeId),
+    /// A function, e.g. a closure the platform calls.
+    ///
+    /// If the function is exposed to the host through an alias (such as
+    /// `mainForHost : (Str -> Str) as Fx`), then `extern_name` is the part of the
+    /// exported symbol names that identifies it, e.g. `mainForHost_1__Fx` for
+    /// `roc__mainForHost_1__Fx_caller`. Otherwise, it's empty.
+    ///
+    /// `closure_size_known` is false for closures that come from the application,
+    /// like its `main`. The size of the values they capture isn't known until the
+    /// platform is linked with an application.
     Function {
         name: String,
+        extern_name: String,
         args: Vec<TypeId>,
         ret: TypeId,
+        closure_size_known: bool,
     },
     /// A zero-sized type, such as an empty record or a single-tag union with no payload
     Unit,
 }
 
+impl RocType {
+    fn to_roc(&self) -> roc_type::RocType {
+        match self {
+            RocType::RocStr => roc_type::RocType::RocStr,
+            RocType::Bool => roc_type::RocType::Bool,
+            RocType::RocResult(ok, err) => roc_type::RocType::RocResult(ok.0 as _, err.0 as _),
+            RocType::Num(num) => roc_type::RocType::Num(num.to_roc()),
+            RocType::RocList(elem) => roc_type::RocType::RocList(elem.0 as _),
+            RocType::RocDict(key, value) => roc_type::RocType::RocDict(key.0 as _, value.0 as _),
+            RocType::RocSet(elem) => roc_type::RocType::RocSet(elem.0 as _),
+            RocType::RocBox(elem) => roc_type::RocType::RocBox(elem.0 as _),
+            RocType::TagUnion(union) => roc_type::RocType::TagUnion(union.to_roc()),
+            RocType::EmptyTagUnion => roc_type::RocType::EmptyTagUnion,
+            RocType::Struct { name, fields } => roc_type::RocType::Struct(roc_type::R2 {
+                name: name.as_str().into(),
+                fields: fields
+                    .iter()
+                    .map(|(name, id)| roc_type::R3 {
+                        name: name.as_str().into(),
+                        r#type: id.0 as _,
+                    })
+                    .collect(),
+            }),
+            RocType::TagUnionPayload { name, fields } => {
+                roc_type::RocType::TagUnionPayload(roc_type::R14 {
+                    name: name.as_str().into(),
+                    fields: fields
+                        .iter()
+                        .map(|(discriminant, id)| roc_type::R15 {
+                            discriminant: *discriminant as _,
+                            r#type: id.0 as _,
+                        })
+                        .collect(),
+                })
+            }
+            RocType::RecursivePointer(id) => roc_type::RocType::RecursivePointer(id.0 as _),
+            RocType::Function {
+                name,
+                extern_name,
+                args,
+                ret,
+                closure_size_known,
+            } => roc_type::RocType::Function(roc_type::R1 {
+                name: name.as_str().into(),
+                externName: extern_name.as_str().into(),
+                args: args.iter().map(|id| id.0 as _).collect(),
+                ret: ret.0 as _,
+                closureSizeKnown: *closure_size_known,
+            }),
+            RocType::Unit => roc_type::RocType::Unit,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
 pub enum RocNum {
     I8,
@@ -567,6 +674,24 @@ impl RocNum {
 
         answer as u32
     }
+
+    fn to_roc(self) -> roc_type::RocNum {
+        match self {
+            RocNum::I8 => roc_type::RocNum::I8,
+            RocNum::U8 => roc_type::RocNum::U8,
+            RocNum::I16 => roc_type::RocNum::I16,
+            RocNum::U16 => roc_type::RocNum::U16,
+            RocNum::I32 => roc_type::RocNum::I32,
+            RocNum::U32 => roc_type::RocNum::U32,
+            RocNum::I64 => roc_type::RocNum::I64,
+            RocNum::U64 => roc_type::RocNum::U64,
+            RocNum::I128 => roc_type::RocNum::I128,
+            RocNum::U128 => roc_type::RocNum::U128,
+            RocNum::F32 => roc_type::RocNum::F32,
+            RocNum::F64 => roc_type::RocNum::F64,
+            RocNum::Dec => roc_type::RocNum::Dec,
+        }
+    }
 }
 
 impl From<IntWidth> for RocNum {
@@ -654,6 +779,102 @@ pub enum RocTagUnion {
     },
 }
 
+impl RocTagUnion {
+    fn to_roc(&self) -> roc_type::RocTagUnion {
+        match self {
+            RocTagUnion::Enumeration { name, tags, size } => {
+                roc_type::RocTagUnion::Enumeration(roc_type::R4 {
+                    name: name.as_str().into(),
+                    tags: tags.iter().map(|tag| tag.as_str().into()).collect(),
+                    size: *size,
+                })
+            }
+            RocTagUnion::NonRecursive {
+                name,
+                tags,
+                discriminant_size,
+                discriminant_offset,
+            } => roc_type::RocTagUnion::NonRecursive(roc_type::R6 {
+                name: name.as_str().into(),
+                tags: tags_to_roc(tags),
+                discriminantSize: *discriminant_size,
+                discriminantOffset: *discriminant_offset,
+            }),
+            RocTagUnion::Recursive {
+                name,
+                tags,
+                discriminant_size,
+                discriminant_offset,
+            } => roc_type::RocTagUnion::Recursive(roc_type::R6 {
+                name: name.as_str().into(),
+                tags: tags_to_roc(tags),
+                discriminantSize: *discriminant_size,
+                discriminantOffset: *discriminant_offset,
+            }),
+            RocTagUnion::NonNullableUnwrapped {
+                name,
+                tag_name,
+                payload,
+            } => roc_type::RocTagUnion::NonNullableUnwrapped(roc_type::R5 {
+                name: name.as_str().into(),
+                tagName: tag_name.as_str().into(),
+                payload: payload.0 as _,
+            }),
+            RocTagUnion::SingleTagStruct {
+                name,
+                tag_name,
+                payload_fields,
+            } => roc_type::RocTagUnion::SingleTagStruct(roc_type::R13 {
+                name: name.as_str().into(),
+                tagName: tag_name.as_str().into(),
+                payloadFields: payload_fields.iter().map(|id| id.0 as _).collect(),
+            }),
+            RocTagUnion::NullableWrapped {
+                name,
+                index_of_null_tag,
+                tags,
+                discriminant_size,
+                discriminant_offset,
+            } => roc_type::RocTagUnion::NullableWrapped(roc_type::R9 {
+                name: name.as_str().into(),
+                indexOfNullTag: *index_of_null_tag,
+                tags: tags_to_roc(tags),
+                discriminantSize: *discriminant_size,
+                discriminantOffset: *discriminant_offset,
+            }),
+            RocTagUnion::NullableUnwrapped {
+                name,
+                null_tag,
+                non_null_tag,
+                non_null_payload,
+                null_represents_first_tag,
+            } => roc_type::RocTagUnion::NullableUnwrapped(roc_type::R8 {
+                name: name.as_str().into(),
+                nullTag: null_tag.as_str().into(),
+                nonNullTag: non_null_tag.as_str().into(),
+                nonNullPayload: non_null_payload.0 as _,
+                whichTagIsNull: if *null_represents_first_tag {
+                    roc_type::U2::FirstTagIsNull
+                } else {
+                    roc_type::U2::SecondTagIsNull
+                },
+            }),
+        }
+    }
+}
+
+fn tags_to_roc(tags: &[(String, Option<TypeId>)]) -> RocList<roc_type::R7> {
+    tags.iter()
+        .map(|(name, payload)| roc_type::R7 {
+            name: name.as_str().into(),
+            payload: match payload {
+                Some(id) => roc_type::U1::Some(id.0 as _),
+                None => roc_type::U1::None,
+            },
+        })
+        .collect()
+}
+
 pub struct Env<'a> {
     arena: &'a Bump,
     subs: &'a Subs,
@@ -663,6 +884,9 @@ pub struct Env<'a> {
     enum_names: Enums,
     pending_recursive_types: VecMap<TypeId, Variable>,
     known_recursive_types: VecMap<Variable, TypeId>,
+    /// The `as` aliases in the exposed defs' annotations (e.g. `Fx` in
+    /// `mainForHost : (Str -> Str) as Fx`), along with the def that introduced them.
+    host_exposed_aliases: VecMap<Symbol, Symbol>,
     target: TargetInfo,
 }
 
@@ -672,6 +896,7 @@ impl<'a> Env<'a> {
         subs: &'a Subs,
         interns: &'a Interns,
         layout_interner: TLLayoutInterner<'a>,
+        host_exposed_aliases: VecMap<Symbol, Symbol>,
         target: TargetInfo,
     ) -> Self {
         Env {
@@ -682,6 +907,7 @@ impl<'a> Env<'a> {
             enum_names: Default::default(),
             pending_recursive_types: Default::default(),
             known_recursive_types: Default::default(),
+            host_exposed_aliases,
             layout_cache: LayoutCache::new(layout_interner, target),
             target,
         }
@@ -699,6 +925,8 @@ impl<'a> Env<'a> {
 
         self.resolve_pending_recursive_types(&mut types);
 
+        check_nested_closures(&types);
+
         types
     }
 
@@ -838,14 +1066,43 @@ fn add_type_help<'a>(
                 add_type_help(env, ret_layout, *ret_var, None, types)
             };
 
-            let name = format!("TODO_roc_function_{:?}", closure_var);
+            // Only functions behind an `as` alias in an exposed def's annotation get
+            // exported to the host (as e.g. `roc__mainForHost_1__Fx_caller`).
+            let opt_exposed = opt_name.and_then(|alias| {
+                env.host_exposed_aliases
+                    .get(&alias)
+                    .map(|def_symbol| (alias, *def_symbol))
+            });
+
+            let (name, extern_name) = match opt_exposed {
+                Some((alias, def_symbol)) => (
+                    alias.as_str(env.interns).to_string(),
+                    format!(
+                        "{}_1_{}_{}",
+                        def_symbol.as_str(env.interns),
+                        alias.module_string(env.interns),
+                        alias.as_str(env.interns)
+                    ),
+                ),
+                None => (
+                    format!("RocFunction_{}", closure_var.index()),
+                    String::new(),
+                ),
+            };
+
             let fn_type_id = types.add_named(
                 &env.layout_cache.interner,
                 name.clone(),
                 RocType::Function {
                     name,
+                    extern_name,
                     args: arg_type_ids.clone(),
                     ret: ret_type_id,
+                    closure_size_known: lambda_set_is_resolved(
+                        env.subs,
+                        *closure_var,
+                        &mut Vec::new(),
+                    ),
                 },
                 layout,
             );
@@ -1618,3 +1875,145 @@ fn struct_fields_needed<I: IntoIterator<Item = Variable>>(env: &mut Env<'_>, var
         }
     })
 }
+
+/// The host can only call a closure through the functions the platform exports for it, and
+/// can only lay out a closure inside another value (like a record field or a tag payload)
+/// if its size is known. Closures that come from the application can still be passed to
+/// the host on their own, or as the argument or return value of another closure.
+fn check_nested_closures(types: &Types) {
+    for id in types.ids() {
+        let (container, nested, inline): (&str, Vec<TypeId>, bool) = match types.get_type(id) {
+            RocType::Struct { name, fields } => {
+                (name, fields.iter().map(|(_, id)| *id).collect(), true)
+            }
+            RocType::TagUnionPayload { name, fields } => {
+                (name, fields.iter().map(|(_, id)| *id).collect(), true)
+            }
+            RocType::TagUnion(RocTagUnion::NonRecursive { name, tags, .. })
+            | RocType::TagUnion(RocTagUnion::Recursive { name, tags, .. })
+            | RocType::TagUnion(RocTagUnion::NullableWrapped { name, tags, .. }) => (
+                name,
+                tags.iter().filter_map(|(_, payload)| *payload).collect(),
+                true,
+            ),
+            RocType::TagUnion(RocTagUnion::NonNullableUnwrapped { name, payload, .. })
+            | RocType::TagUnion(RocTagUnion::NullableUnwrapped {
+                name,
+                non_null_payload: payload,
+                ..
+            }) => (name, vec![*payload], true),
+            RocType::TagUnion(RocTagUnion::SingleTagStruct {
+                name,
+                payload_fields,
+                ..
+            }) => (name, payload_fields.clone(), true),
+            RocType::RocList(elem) | RocType::RocSet(elem) | RocType::RocBox(elem) => {
+                ("a builtin collection", vec![*elem], true)
+            }
+            RocType::RocDict(key, value) => ("a Dict", vec![*key, *value], true),
+            RocType::RocResult(ok, err) => ("a Result", vec![*ok, *err], true),
+            RocType::Function {
+                name,
+                extern_name,
+                args,
+                ret,
+                ..
+            } => {
+                // Functions the host doesn't call don't get any glue
+                if extern_name.is_empty() {
+                    continue;
+                }
+
+                let mut nested = args.clone();
+                nested.push(*ret);
+
+                (name, nested, false)
+            }
+            RocType::RocStr
+            | RocType::Bool
+            | RocType::Num(_)
+            | RocType::EmptyTagUnion
+            | RocType::TagUnion(RocTagUnion::Enumeration { .. })
+            | RocType::RecursivePointer(_)
+            | RocType::Unit => continue,
+        };
+
+        for nested_id in nested {
+            if let RocType::Function {
+                name,
+                extern_name,
+                closure_size_known,
+                ..
+            } = types.get_type(nested_id)
+            {
+                if extern_name.is_empty() {
+                    panic!(
+                        "The closure in `{}` isn't exposed to the host, so glue can't generate code to call it. Closures only get exposed when they're written out in the annotation of a value the platform provides (e.g. `{{ update : Str -> Str }}`), or given a name with `as` (e.g. `(Str -> Str) as Update`), rather than coming from an opaque type, a recursive type or a type alias with type parameters.",
+                        container
+                    );
+                }
+
+                if inline && !closure_size_known {
+                    panic!(
+                        "The closure `{}` in `{}` comes from the application, so its size isn't known until the platform is linked with one. Glue can only generate code for such a closure when the host gets it on its own, or as the argument or return value of another closure.",
+                        name, container
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Whether the closures in this lambda set, and any closures they capture, were all resolved
+/// when the platform was type-checked. A closure the application passes to the platform
+/// (like its `main`) isn't, so its lambda set is still empty and the size of the values it
+/// captures isn't known yet.
+fn lambda_set_is_resolved(subs: &Subs, closure_var: Variable, seen: &mut Vec<Variable>) -> bool {
+    match subs.get_content_without_compacting(closure_var) {
+        Content::LambdaSet(lambda_set) => {
+            !lambda_set.solved.is_empty()
+                && lambda_set.unspecialized.is_empty()
+                && lambda_set.solved.iter_from_subs(subs).all(|(_, captures)| {
+                    captures
+                        .iter()
+                        .all(|var| captures_are_resolved(subs, *var, seen))
+                })
+        }
+        _ => false,
+    }
+}
+
+fn captures_are_resolved(subs: &Subs, var: Variable, seen: &mut Vec<Variable>) -> bool {
+    let var = subs.get_root_key_without_compacting(var);
+
+    if seen.contains(&var) {
+        return true;
+    }
+
+    seen.push(var);
+
+    match subs.get_content_without_compacting(var) {
+        Content::Structure(FlatType::Func(_, closure_var, _)) => {
+            lambda_set_is_resolved(subs, *closure_var, seen)
+        }
+        Content::Structure(FlatType::Record(fields, _)) => fields
+            .iter_variables()
+            .all(|index| captures_are_resolved(subs, subs[index], seen)),
+        Content::Structure(FlatType::TagUnion(tags, _))
+        | Content::Structure(FlatType::RecursiveTagUnion(_, tags, _)) => {
+            tags.iter_from_subs(subs).all(|(_, payloads)| {
+                payloads
+                    .iter()
+                    .all(|var| captures_are_resolved(subs, *var, seen))
+            })
+        }
+        Content::Structure(FlatType::Apply(_, args)) => subs
+            .get_subs_slice(*args)
+            .iter()
+            .all(|var| captures_are_resolved(subs, *var, seen)),
+        Content::Alias(_, _, real_var, _) => captures_are_resolved(subs, *real_var, seen),
+        Content::RecursionVar { structure, .. } => captures_are_resolved(subs, *structure, seen),
+        Content::LambdaSet(_) => lambda_set_is_resolved(subs, var, seen),
+        _ => true,
+    }
+}
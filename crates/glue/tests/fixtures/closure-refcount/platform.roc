@@ -0,0 +1,12 @@
+platform "test-platform"
+    requires {} { main : List Str }
+    exposes []
+    packages {}
+    imports []
+    provides [mainForHost]
+
+mainForHost : (Str -> Bool) as HasName
+mainForHost =
+    names = main
+
+    \name -> List.contains names name
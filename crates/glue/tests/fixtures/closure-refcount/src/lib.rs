@@ -0,0 +1,96 @@
+mod test_glue;
+
+use roc_std::{RocList, RocStr};
+use test_glue::HasName;
+
+extern "C" {
+    #[link_name = "roc__mainForHost_1_exposed_generic"]
+    fn roc_main(_: *mut HasName);
+}
+
+#[no_mangle]
+pub extern "C" fn rust_main() -> i32 {
+    let has_name = unsafe {
+        let mut ret: core::mem::MaybeUninit<HasName> = core::mem::MaybeUninit::uninit();
+
+        roc_main(ret.as_mut_ptr());
+
+        ret.assume_init()
+    };
+
+    // The closure captured only this list, so that's all its closure data holds
+    let names = unsafe { &*(has_name.as_closure_data() as *const RocList<RocStr>) };
+
+    assert!(names.is_unique());
+
+    let rocco = RocStr::from("Rocco");
+    let someone_else = RocStr::from("Someone else");
+    let mut calls = 0;
+
+    for _ in 0..3 {
+        assert!(has_name.call(&rocco));
+        assert!(!has_name.call(&someone_else));
+
+        calls += 2;
+    }
+
+    // Each call got a reference to the list of its own, and must have given it back.
+    assert!(names.is_unique());
+
+    println!(
+        "After {} calls, the closure still holds the only reference to {:?}",
+        calls, names
+    ); // Debug
+
+    // Exit code
+    0
+}
+
+// Externs required by roc_std and by the Roc app
+
+use core::ffi::c_void;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+#[no_mangle]
+pub unsafe extern "C" fn roc_alloc(size: usize, _alignment: u32) -> *mut c_void {
+    return libc::malloc(size);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn roc_realloc(
+    c_ptr: *mut c_void,
+    new_size: usize,
+    _old_size: usize,
+    _alignment: u32,
+) -> *mut c_void {
+    return libc::realloc(c_ptr, new_size);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn roc_dealloc(c_ptr: *mut c_void, _alignment: u32) {
+    return libc::free(c_ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn roc_panic(c_ptr: *mut c_void, tag_id: u32) {
+    match tag_id {
+        0 => {
+            let slice = CStr::from_ptr(c_ptr as *const c_char);
+            let string = slice.to_str().unwrap();
+            eprintln!("Roc hit a panic: {}", string);
+            std::process::exit(1);
+        }
+        _ => todo!(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn roc_memcpy(dst: *mut c_void, src: *mut c_void, n: usize) -> *mut c_void {
+    libc::memcpy(dst, src, n)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn roc_memset(dst: *mut c_void, c: i32, n: usize) -> *mut c_void {
+    libc::memset(dst, c, n)
+}
@@ -0,0 +1,18 @@
+platform "test-platform"
+    requires {} { main : Str }
+    exposes []
+    packages {}
+    imports []
+    provides [mainForHost]
+
+Greeter : { greet : Str -> Str, shout : Str -> Str }
+
+mainForHost : Greeter
+mainForHost =
+    greeting = Str.concat main ", "
+    punctuation = "!"
+
+    {
+        greet: \name -> Str.concat greeting name,
+        shout: \str -> Str.concat str punctuation,
+    }
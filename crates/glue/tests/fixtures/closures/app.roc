@@ -0,0 +1,9 @@
+app "app"
+    packages { pf: "platform.roc" }
+    imports []
+    provides [main] to pf
+
+main = makeGreeting "Hello there, "
+
+makeGreeting : Str -> (Str -> Str)
+makeGreeting = \greeting -> \name -> Str.concat greeting name
@@ -0,0 +1,93 @@
+mod test_glue;
+
+use roc_std::RocStr;
+use test_glue::Greet;
+
+extern "C" {
+    #[link_name = "roc__mainForHost_1_exposed_generic"]
+    fn roc_main(_: *mut u8);
+}
+
+#[no_mangle]
+pub extern "C" fn rust_main() -> i32 {
+    let greet = unsafe {
+        let layout = std::alloc::Layout::from_size_align(Greet::closure_size().max(1), 16).unwrap();
+        let closure_data = std::alloc::alloc(layout);
+
+        roc_main(closure_data);
+
+        let greet = Greet::from_closure_data(closure_data);
+
+        std::alloc::dealloc(closure_data, layout);
+
+        greet
+    };
+
+    // Long enough to be allocated on the heap, so that refcounting matters
+    let name = RocStr::from("Rocco, who is visiting from out of town");
+
+    // `call` takes the closure and its arguments by reference, so both can be used again.
+    let first = greet.call(&name);
+    let copy = greet.clone();
+
+    drop(greet);
+
+    let second = copy.call(&name);
+
+    assert_eq!(first, second);
+    assert_eq!(name.as_str(), "Rocco, who is visiting from out of town");
+
+    println!("Greeting was: {}", second); // Display
+
+    // Exit code
+    0
+}
+
+// Externs required by roc_std and by the Roc app
+
+use core::ffi::c_void;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+#[no_mangle]
+pub unsafe extern "C" fn roc_alloc(size: usize, _alignment: u32) -> *mut c_void {
+    return libc::malloc(size);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn roc_realloc(
+    c_ptr: *mut c_void,
+    new_size: usize,
+    _old_size: usize,
+    _alignment: u32,
+) -> *mut c_void {
+    return libc::realloc(c_ptr, new_size);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn roc_dealloc(c_ptr: *mut c_void, _alignment: u32) {
+    return libc::free(c_ptr);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn roc_panic(c_ptr: *mut c_void, tag_id: u32) {
+    match tag_id {
+        0 => {
+            let slice = CStr::from_ptr(c_ptr as *const c_char);
+            let string = slice.to_str().unwrap();
+            eprintln!("Roc hit a panic: {}", string);
+            std::process::exit(1);
+        }
+        _ => todo!(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn roc_memcpy(dst: *mut c_void, src: *mut c_void, n: usize) -> *mut c_void {
+    libc::memcpy(dst, src, n)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn roc_memset(dst: *mut c_void, c: i32, n: usize) -> *mut c_void {
+    libc::memset(dst, c, n)
+}
@@ -62,6 +62,19 @@ pub fn fixtures_dir(dir_name: &str) -> PathBuf {
     path
 }
 
+#[allow(dead_code)]
+pub fn specs_dir() -> PathBuf {
+    let mut path = root_dir();
+
+    // Descend into glue/tests/specs
+    path.push("crates");
+    path.push("glue");
+    path.push("tests");
+    path.push("specs");
+
+    path
+}
+
 #[allow(dead_code)]
 pub fn root_dir() -> PathBuf {
     let mut path = env::current_exe().ok().unwrap();
@@ -0,0 +1,35 @@
+app "describe-structs"
+    packages { pf: "../../src/RocType.roc" }
+    imports []
+    provides [makeGlue] to pf
+
+makeGlue = \typesByTarget ->
+    typesByTarget
+    |> List.map describeTarget
+    |> Ok
+
+describeTarget = \types ->
+    name = archName types.target.architecture
+    content =
+        types.types
+        |> List.mapWithIndex \type, id ->
+            when type is
+                Struct { name: structName } ->
+                    size = List.get types.sizes id |> Result.withDefault 0 |> Num.toStr
+                    align = List.get types.aligns id |> Result.withDefault 0 |> Num.toStr
+
+                    "\(structName) is \(size) bytes, aligned to \(align)\n"
+
+                _ ->
+                    ""
+        |> Str.joinWith ""
+
+    { name: "\(name)/structs.txt", content }
+
+archName = \arch ->
+    when arch is
+        Aarch32 -> "aarch32"
+        Aarch64 -> "aarch64"
+        Wasm32 -> "wasm32"
+        X86x32 -> "x86x32"
+        X86x64 -> "x86x64"
@@ -11,8 +11,9 @@ mod helpers;
 
 #[cfg(test)]
 mod glue_cli_run {
-    use crate::helpers::fixtures_dir;
+    use crate::helpers::{fixtures_dir, specs_dir};
     use cli_utils::helpers::{run_glue, run_roc, Out};
+    use std::ffi::OsStr;
     use std::fs;
     use std::path::Path;
 
@@ -127,6 +128,36 @@ mod glue_cli_run {
         multiple_modules:"multiple-modules" => indoc!(r#"
             combined was: Combined { s1: DepStr1::S("hello"), s2: DepStr2::R("world") }
         "#),
+        closures:"closures" => "Greeting was: Hello there, Rocco, who is visiting from out of town\n",
+        closures_in_record:"closures-in-record" => "Greeting was: Hello there, Rocco, who is visiting from out of town!\n",
+        closure_refcount:"closure-refcount" => "After 6 calls, the closure still holds the only reference to [\"Rocco\", \"Rocco\", \"Rocco\"]\n",
+    }
+
+    #[test]
+    fn glue_spec() {
+        let platform_module_path = fixtures_dir("basic-record").join("platform.roc");
+        let spec_path = specs_dir().join("describe-structs.roc");
+        let out_dir = tempfile::tempdir().unwrap();
+
+        let glue_out = run_glue([
+            OsStr::new("glue"),
+            platform_module_path.as_os_str(),
+            out_dir.path().as_os_str(),
+            OsStr::new("--spec"),
+            spec_path.as_os_str(),
+        ]);
+
+        assert!(glue_out.status.success(), "bad status {:?}", glue_out);
+
+        // The spec writes one file per target, describing the structs on that target
+        for arch in ["aarch32", "aarch64", "wasm32", "x86x32", "x86x64"] {
+            let structs = out_dir.path().join(arch).join("structs.txt");
+
+            assert_eq!(
+                fs::read_to_string(structs).unwrap(),
+                "MyRcd is 24 bytes, aligned to 16\n"
+            );
+        }
     }
 
     fn check_for_tests(all_fixtures: &mut roc_collections::VecSet<String>) {
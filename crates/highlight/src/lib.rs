@@ -1,3 +1,3 @@
 //! Provides syntax highlighting for the editor by transforming a string to markup nodes.
 pub mod highlight_parser;
-pub mod tokenizer;
+pub use roc_highlight_ansi::tokenizer;
@@ -0,0 +1,231 @@
+//! Renders Roc code for a color terminal, using ANSI escape sequences.
+use std::ops::Range;
+
+use crate::tokenizer::{full_tokenize, Token};
+
+/// What a piece of code is, as far as highlighting is concerned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HighlightKind {
+    Keyword,
+    /// Type, tag and module names
+    UppercaseIdent,
+    LowercaseIdent,
+    /// Strings, characters and numbers
+    Literal,
+    Operator,
+    /// Brackets and commas
+    Delimiter,
+    Comment,
+    Malformed,
+    /// Whitespace
+    Plain,
+}
+
+impl From<Token> for HighlightKind {
+    fn from(token: Token) -> Self {
+        use Token::*;
+
+        match token {
+            LowercaseIdent | Underscore => HighlightKind::LowercaseIdent,
+            UppercaseIdent => HighlightKind::UppercaseIdent,
+            KeywordIf | KeywordThen | KeywordElse | KeywordWhen | KeywordAs | KeywordIs
+            | KeywordExpect | KeywordApp | KeywordInterface | KeywordPackages | KeywordImports
+            | KeywordProvides | KeywordTo | KeywordExposes | KeywordEffects | KeywordPackage
+            | KeywordPlatform | KeywordRequires | KeywordDbg => HighlightKind::Keyword,
+            String | NumberBase | Number => HighlightKind::Literal,
+            Comma | OpenParen | CloseParen | OpenCurly | CloseCurly | OpenSquare | CloseSquare
+            | OpenIndent | CloseIndent | SameIndent => HighlightKind::Delimiter,
+            Colon | OpPlus | OpMinus | OpSlash | OpPercent | OpCaret | OpGreaterThan
+            | OpLessThan | OpAssignment | OpPizza | OpEquals | OpNotEquals | OpGreaterThanOrEq
+            | OpLessThanOrEq | OpAnd | OpOr | OpDoubleSlash | OpBackpassing | QuestionMark
+            | Ampersand | Pipe | Dot | SpaceDot | Bang | LambdaStart | Arrow | FatArrow
+            | Asterisk => HighlightKind::Operator,
+            MalformedIdent | TodoNextThing | Malformed | MalformedOperator => {
+                HighlightKind::Malformed
+            }
+        }
+    }
+}
+
+/// Splits the code into consecutive spans that together cover all of it.
+pub fn highlight_spans(code: &str) -> Vec<(HighlightKind, Range<usize>)> {
+    let token_table = full_tokenize(code);
+
+    let mut spans = Vec::with_capacity(2 * token_table.tokens.len() + 1);
+    let mut end = 0;
+
+    let tokens = token_table
+        .tokens
+        .iter()
+        .zip(token_table.offsets.iter())
+        .zip(token_table.lengths.iter());
+
+    for ((token, offset), length) in tokens {
+        // indentation tokens take up no space
+        if *length == 0 || *offset < end {
+            continue;
+        }
+
+        let token_end = (offset + length).min(code.len());
+
+        push_whitespace_and_comments(code, end..*offset, &mut spans);
+        spans.push(((*token).into(), *offset..token_end));
+
+        end = token_end;
+    }
+
+    push_whitespace_and_comments(code, end..code.len(), &mut spans);
+
+    spans
+}
+
+/// The tokenizer skips over whitespace and comments, so they are what's left between tokens
+fn push_whitespace_and_comments(
+    code: &str,
+    range: Range<usize>,
+    spans: &mut Vec<(HighlightKind, Range<usize>)>,
+) {
+    let mut start = range.start;
+
+    while start < range.end {
+        match code[start..range.end].find('#') {
+            None => {
+                spans.push((HighlightKind::Plain, start..range.end));
+                break;
+            }
+            Some(hash) => {
+                let comment_start = start + hash;
+                let comment_end = match code[comment_start..range.end].find('\n') {
+                    Some(newline) => comment_start + newline,
+                    None => range.end,
+                };
+
+                if comment_start > start {
+                    spans.push((HighlightKind::Plain, start..comment_start));
+                }
+                spans.push((HighlightKind::Comment, comment_start..comment_end));
+
+                start = comment_end;
+            }
+        }
+    }
+}
+
+/// The escape sequences to style code with. An empty string leaves that kind of code unstyled.
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    pub keyword: &'static str,
+    pub uppercase_ident: &'static str,
+    pub literal: &'static str,
+    pub comment: &'static str,
+    pub reset: &'static str,
+}
+
+impl Theme {
+    /// Leaves all code unstyled, e.g. when the output is not a terminal
+    pub const PLAIN: Theme = Theme {
+        keyword: "",
+        uppercase_ident: "",
+        literal: "",
+        comment: "",
+        reset: "",
+    };
+
+    fn style(&self, kind: HighlightKind) -> &'static str {
+        match kind {
+            HighlightKind::Keyword => self.keyword,
+            HighlightKind::UppercaseIdent => self.uppercase_ident,
+            HighlightKind::Literal => self.literal,
+            HighlightKind::Comment => self.comment,
+            HighlightKind::LowercaseIdent
+            | HighlightKind::Operator
+            | HighlightKind::Delimiter
+            | HighlightKind::Malformed
+            | HighlightKind::Plain => "",
+        }
+    }
+}
+
+/// Highlights the code with the theme's escape sequences, for printing to a color terminal.
+pub fn highlight_ansi(code: &str, theme: &Theme) -> String {
+    let mut buf = String::with_capacity(code.len());
+
+    for (kind, range) in highlight_spans(code) {
+        let style = theme.style(kind);
+
+        if style.is_empty() {
+            buf.push_str(&code[range]);
+        } else {
+            buf.push_str(style);
+            buf.push_str(&code[range]);
+            buf.push_str(theme.reset);
+        }
+    }
+
+    buf
+}
+
+#[cfg(test)]
+mod test_ansi {
+    use super::{highlight_ansi, highlight_spans, HighlightKind, Theme};
+
+    const THEME: Theme = Theme {
+        keyword: "<k>",
+        uppercase_ident: "<u>",
+        literal: "<l>",
+        comment: "<c>",
+        reset: "</>",
+    };
+
+    fn spans(code: &str) -> Vec<(HighlightKind, &str)> {
+        highlight_spans(code)
+            .into_iter()
+            .map(|(kind, range)| (kind, &code[range]))
+            .collect()
+    }
+
+    #[test]
+    fn spans_cover_the_code() {
+        let code = "when x is\n    Ok n -> n + 1 # one more\n    _ -> \"none\"";
+
+        let joined: String = spans(code).into_iter().map(|(_, text)| text).collect();
+
+        assert_eq!(joined, code);
+    }
+
+    #[test]
+    fn comments_are_separate_from_whitespace() {
+        use HighlightKind::*;
+
+        assert_eq!(
+            spans("x = 1 # one"),
+            [
+                (LowercaseIdent, "x"),
+                (Plain, " "),
+                (Operator, "="),
+                (Plain, " "),
+                (Literal, "1"),
+                (Plain, " "),
+                (Comment, "# one"),
+            ]
+        );
+    }
+
+    #[test]
+    fn highlight_line() {
+        assert_eq!(
+            highlight_ansi("if x then Ok 'a' else Err \"b\"", &THEME),
+            "<k>if</> x <k>then</> <u>Ok</> <l>'a'</> <k>else</> <u>Err</> <l>\"b\"</>"
+        );
+    }
+
+    #[test]
+    fn highlight_malformed_code() {
+        // a snippet in an error report can be anything
+        assert_eq!(
+            highlight_ansi("x = @Age \"unterminated", &THEME),
+            "x = @<u>Age</> <l>\"unterminated</>"
+        );
+        assert_eq!(highlight_ansi("naïve\t# ✓", &THEME), "naïve\t<c># ✓</>");
+    }
+}
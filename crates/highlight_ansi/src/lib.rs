@@ -0,0 +1,4 @@
+//! Tokenizes Roc code and highlights it for a color terminal, e.g. in error reports.
+//! This has no dependencies, so that the compiler's error reporting can use it.
+pub mod ansi;
+pub mod tokenizer;
@@ -184,8 +184,14 @@ fn consume_all_tokens(state: &mut LexState, bytes: &[u8], consumer: &mut impl Co
                 i += skip_comment(bytes);
                 continue;
             }
-            b'"' => lex_string(bytes),
-            b => todo!("handle {:?}", b as char),
+            b'"' | b'\'' => lex_string(bytes),
+            b'?' => (Token::QuestionMark, 1),
+            b'\t' | b'\r' => {
+                i += 1;
+                continue;
+            }
+            // anything else (e.g. an opaque type's `@`, or a non-ASCII character) is malformed
+            b => (Token::Malformed, utf8_char_len(b).min(bytes.len())),
         };
 
         consumer.token(token, i, len);
@@ -331,7 +337,7 @@ fn skip_newlines_and_comments(bytes: &[u8]) -> SkipNewlineReturn {
                     }
                 }
             } else {
-                while bytes[skip] == b'#' {
+                while skip < bytes.len() && bytes[skip] == b'#' {
                     let comment_skip = skip_comment(&bytes[skip..]);
 
                     indent = 0;
@@ -395,10 +401,7 @@ fn lex_operator(bytes: &[u8]) -> (Token, usize) {
         b"//" => Token::OpDoubleSlash,
         b"->" => Token::Arrow,
         b"<-" => Token::OpBackpassing,
-        op => {
-            dbg!(std::str::from_utf8(op).unwrap());
-            Token::MalformedOperator
-        }
+        _ => Token::MalformedOperator,
     };
     (tok, i)
 }
@@ -473,23 +476,36 @@ fn lex_number(bytes: &[u8]) -> (Token, usize) {
     (Token::Number, i)
 }
 
+/// Lexes a string or a character literal, depending on the opening quote
 fn lex_string(bytes: &[u8]) -> (Token, usize) {
-    let mut i = 0;
-    assert_eq!(bytes[i], b'"');
-    i += 1;
+    let quote = bytes[0];
+    debug_assert!(quote == b'"' || quote == b'\'');
+
+    let mut i = 1;
 
     while i < bytes.len() {
         match bytes[i] {
-            b'"' => break,
-            // TODO: escapes
+            b'\\' => i += 2,
+            b if b == quote => {
+                i += 1;
+                break;
+            }
+            // an unterminated string ends at the end of the line
+            b'\n' => break,
             _ => i += 1,
         }
     }
 
-    assert_eq!(bytes[i], b'"');
-    i += 1;
+    (Token::String, i.min(bytes.len()))
+}
 
-    (Token::String, i)
+fn utf8_char_len(first_byte: u8) -> usize {
+    match first_byte {
+        0b1111_0000..=0b1111_1111 => 4,
+        0b1110_0000..=0b1110_1111 => 3,
+        0b1100_0000..=0b1101_1111 => 2,
+        _ => 1,
+    }
 }
 
 #[cfg(test)]
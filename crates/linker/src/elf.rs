@@ -24,8 +24,160 @@ use crate::{
 
 const MIN_SECTION_ALIGNMENT: usize = 0x40;
 
-// TODO: Analyze if this offset is always correct.
-const PLT_ADDRESS_OFFSET: u64 = 0x10;
+/// The instruction set specific parts of surgical linking for ELF.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ElfArch {
+    X86_64,
+    Aarch64,
+}
+
+impl ElfArch {
+    fn from_machine(e_machine: u16) -> Self {
+        match e_machine {
+            elf::EM_X86_64 => ElfArch::X86_64,
+            elf::EM_AARCH64 => ElfArch::Aarch64,
+            other => {
+                internal_error!("Surgical linking does not support ELF machine type {other}")
+            }
+        }
+    }
+
+    fn r_relative(self) -> u32 {
+        match self {
+            ElfArch::X86_64 => elf::R_X86_64_RELATIVE,
+            ElfArch::Aarch64 => elf::R_AARCH64_RELATIVE,
+        }
+    }
+
+    fn r_glob_dat(self) -> u32 {
+        match self {
+            ElfArch::X86_64 => elf::R_X86_64_GLOB_DAT,
+            ElfArch::Aarch64 => elf::R_AARCH64_GLOB_DAT,
+        }
+    }
+
+    fn r_jump_slot(self) -> u32 {
+        match self {
+            ElfArch::X86_64 => elf::R_X86_64_JUMP_SLOT,
+            ElfArch::Aarch64 => elf::R_AARCH64_JUMP_SLOT,
+        }
+    }
+
+    fn r_none(self) -> u32 {
+        match self {
+            ElfArch::X86_64 => elf::R_X86_64_NONE,
+            ElfArch::Aarch64 => elf::R_AARCH64_NONE,
+        }
+    }
+
+    /// The size of the PLT header that comes before the entry of the first function.
+    // TODO: Analyze if these sizes are always correct.
+    fn plt_header_size(self) -> u64 {
+        match self {
+            ElfArch::X86_64 => 0x10,
+            ElfArch::Aarch64 => 0x20,
+        }
+    }
+
+    fn plt_entry_size(self) -> u64 {
+        0x10
+    }
+
+    /// How far apart the data before and after the new program headers may be shifted.
+    /// On aarch64, code addresses data with `adrp` relative to its 4KiB page,
+    /// so any shift must keep everything at the same position within its page.
+    fn shift_alignment(self) -> u64 {
+        match self {
+            ElfArch::X86_64 => MIN_SECTION_ALIGNMENT as u64,
+            ElfArch::Aarch64 => 0x1000,
+        }
+    }
+}
+
+// The aarch64 instruction encodings the surgical linker needs to patch.
+const AARCH64_BRANCH_MASK: u32 = 0x7C00_0000;
+const AARCH64_BRANCH: u32 = 0x1400_0000;
+const AARCH64_BRANCH_IMM_MASK: u32 = 0x03FF_FFFF;
+const AARCH64_ADR_IMM_MASK: u32 = (0x3 << 29) | (0x7FFFF << 5);
+const AARCH64_IMM12_MASK: u32 = 0xFFF << 10;
+const AARCH64_ADD_X_IMM: u32 = 0x9100_0000;
+const AARCH64_NOP: u32 = 0xD503_201F;
+
+/// Is this a `b` or `bl` instruction with a 26-bit immediate offset?
+fn aarch64_is_branch(inst: u32) -> bool {
+    inst & AARCH64_BRANCH_MASK == AARCH64_BRANCH
+}
+
+fn aarch64_branch_target(address: u64, inst: u32) -> u64 {
+    // Sign extend the 26-bit immediate, which counts instructions.
+    let offset = (((inst & AARCH64_BRANCH_IMM_MASK) << 6) as i32 >> 6) as i64 * 4;
+    (address as i64 + offset) as u64
+}
+
+fn aarch64_set_branch_target(inst: u32, address: i64, target: i64) -> u32 {
+    let offset = target - address;
+    if offset % 4 != 0 || !(-(1 << 27)..(1 << 27)).contains(&offset) {
+        internal_error!("Branch from {address:+x} to {target:+x} is out of range for aarch64");
+    }
+    (inst & !AARCH64_BRANCH_IMM_MASK) | ((offset >> 2) as u32 & AARCH64_BRANCH_IMM_MASK)
+}
+
+fn aarch64_set_adr_imm(inst: u32, imm: i64) -> u32 {
+    if !(-(1 << 20)..(1 << 20)).contains(&imm) {
+        internal_error!("Immediate {imm:+x} is out of range for an aarch64 adr/adrp");
+    }
+    let imm = imm as u32;
+    let immlo = (imm & 0x3) << 29;
+    let immhi = ((imm >> 2) & 0x7FFFF) << 5;
+    (inst & !AARCH64_ADR_IMM_MASK) | immlo | immhi
+}
+
+fn aarch64_set_imm12(inst: u32, imm: u64) -> u32 {
+    (inst & !AARCH64_IMM12_MASK) | ((imm as u32 & 0xFFF) << 10)
+}
+
+/// Patch the aarch64 instruction at `place` (with virtual address `p`) for a relocation
+/// of the given kind that resolves to the virtual address `s_plus_a`.
+fn apply_aarch64_relocation(place: &mut [u8], kind: RelocationKind, s_plus_a: i64, p: i64) {
+    let r_type = match kind {
+        RelocationKind::PltRelative => elf::R_AARCH64_CALL26,
+        RelocationKind::Elf(r_type) => r_type,
+        x => {
+            internal_error!("Relocation Kind not yet support: {:?}", x);
+        }
+    };
+    let page = |address: i64| address & !0xFFF;
+    let lo12 = (s_plus_a & 0xFFF) as u64;
+
+    let inst = u32::from_le_bytes(<[u8; 4]>::try_from(&place[..4]).unwrap());
+    let new_inst = match r_type {
+        elf::R_AARCH64_CALL26 | elf::R_AARCH64_JUMP26 => {
+            aarch64_set_branch_target(inst, p, s_plus_a)
+        }
+        elf::R_AARCH64_ADR_PREL_LO21 => aarch64_set_adr_imm(inst, s_plus_a - p),
+        elf::R_AARCH64_ADR_PREL_PG_HI21 | elf::R_AARCH64_ADR_PREL_PG_HI21_NC => {
+            aarch64_set_adr_imm(inst, (page(s_plus_a) - page(p)) >> 12)
+        }
+        elf::R_AARCH64_ADD_ABS_LO12_NC | elf::R_AARCH64_LDST8_ABS_LO12_NC => {
+            aarch64_set_imm12(inst, lo12)
+        }
+        elf::R_AARCH64_LDST16_ABS_LO12_NC => aarch64_set_imm12(inst, lo12 >> 1),
+        elf::R_AARCH64_LDST32_ABS_LO12_NC => aarch64_set_imm12(inst, lo12 >> 2),
+        elf::R_AARCH64_LDST64_ABS_LO12_NC => aarch64_set_imm12(inst, lo12 >> 3),
+        elf::R_AARCH64_LDST128_ABS_LO12_NC => aarch64_set_imm12(inst, lo12 >> 4),
+        // The app has no GOT, and everything it references is at a known address,
+        // so `adrp` of the GOT entry becomes `adrp` of the symbol, and the
+        // `ldr xd, [xn, :got_lo12:sym]` after it becomes `add xd, xn, :lo12:sym`.
+        elf::R_AARCH64_ADR_GOT_PAGE => aarch64_set_adr_imm(inst, (page(s_plus_a) - page(p)) >> 12),
+        elf::R_AARCH64_LD64_GOT_LO12_NC => {
+            aarch64_set_imm12(AARCH64_ADD_X_IMM | (inst & 0x3FF), lo12)
+        }
+        other => {
+            internal_error!("AArch64 relocation type not yet supported: {other}");
+        }
+    };
+    place[..4].copy_from_slice(&new_inst.to_le_bytes());
+}
 
 struct ElfDynamicDeps {
     got_app_syms: Vec<(String, usize)>,
@@ -95,13 +247,18 @@ fn collect_roc_definitions<'a>(object: &object::File<'a, &'a [u8]>) -> MutMap<St
 }
 
 struct Surgeries<'a> {
+    arch: ElfArch,
     surgeries: MutMap<String, Vec<metadata::SurgeryEntry>>,
     app_func_addresses: MutMap<u64, &'a str>,
     indirect_warning_given: bool,
 }
 
 impl<'a> Surgeries<'a> {
-    fn new(application_symbols: &[Symbol], app_func_addresses: MutMap<u64, &'a str>) -> Self {
+    fn new(
+        arch: ElfArch,
+        application_symbols: &[Symbol],
+        app_func_addresses: MutMap<u64, &'a str>,
+    ) -> Self {
         let mut surgeries = MutMap::default();
 
         // for each symbol that the host expects from the application
@@ -112,6 +269,7 @@ impl<'a> Surgeries<'a> {
         }
 
         Self {
+            arch,
             surgeries,
             app_func_addresses,
             indirect_warning_given: false,
@@ -172,6 +330,19 @@ impl<'a> Surgeries<'a> {
                 internal_error!("Failed to load text section, {:+x?}: {}", sec, err);
             }
         };
+
+        if self.arch == ElfArch::Aarch64 {
+            self.append_aarch64_branches(
+                object_bytes,
+                sec,
+                &data,
+                file_offset,
+                compressed,
+                verbose,
+            );
+            return;
+        }
+
         let mut decoder = Decoder::with_ip(64, &data, sec.address(), DecoderOptions::NONE);
         let mut inst = Instruction::default();
 
@@ -263,6 +434,62 @@ impl<'a> Surgeries<'a> {
             }
         }
     }
+
+    /// On aarch64 every instruction is 4 bytes, and calls to app functions are `bl`
+    /// (or `b` for tail calls) to their PLT entries, with the offset in a 26-bit immediate.
+    fn append_aarch64_branches(
+        &mut self,
+        object_bytes: &[u8],
+        sec: &Section,
+        data: &[u8],
+        file_offset: u64,
+        compressed: bool,
+        verbose: bool,
+    ) {
+        for (i, word) in data.chunks_exact(4).enumerate() {
+            let inst = u32::from_le_bytes(<[u8; 4]>::try_from(word).unwrap());
+            if !aarch64_is_branch(inst) {
+                continue;
+            }
+
+            let address = sec.address() + 4 * i as u64;
+            let target = aarch64_branch_target(address, inst);
+            if let Some(func_name) = self.app_func_addresses.get(&target) {
+                if compressed {
+                    internal_error!(
+                        "Surgical linking does not work with compressed text sections: {:+x?}",
+                        sec
+                    );
+                }
+
+                let offset = file_offset + 4 * i as u64;
+                if verbose {
+                    println!(
+                        "Found branch from {:+x} to {:+x}({})",
+                        address, target, func_name
+                    );
+                    println!(
+                        "\tNeed to surgically replace the instruction at file offset {:+x}",
+                        offset,
+                    );
+                    println!(
+                        "\tIts current value is {:+x?}",
+                        &object_bytes[offset as usize..offset as usize + 4]
+                    )
+                }
+
+                // The branch offset is relative to the branch instruction itself.
+                self.surgeries
+                    .get_mut(*func_name)
+                    .unwrap()
+                    .push(metadata::SurgeryEntry {
+                        file_offset: offset,
+                        virtual_offset: VirtualOffset::Relative(address),
+                        size: 4,
+                    });
+            }
+        }
+    }
 }
 
 /// Constructs a `metadata::Metadata` from a host executable binary, and writes it to disk
@@ -285,6 +512,9 @@ pub(crate) fn preprocess_elf(
         }
     };
 
+    let exec_header = load_struct_inplace::<elf::FileHeader64<LE>>(exec_data, 0);
+    let arch = ElfArch::from_machine(exec_header.e_machine.get(NativeEndian));
+
     let mut md = metadata::Metadata {
         roc_symbol_vaddresses: collect_roc_definitions(&exec_obj),
         ..Default::default()
@@ -361,7 +591,7 @@ pub(crate) fn preprocess_elf(
                 }
             })
             .filter_map(|(_, reloc)| {
-                if let RelocationKind::Elf(7) = reloc.kind() {
+                if reloc.kind() == RelocationKind::Elf(arch.r_jump_slot()) {
                     Some(reloc)
                 } else {
                     None
@@ -370,8 +600,9 @@ pub(crate) fn preprocess_elf(
     for (i, reloc) in plt_relocs.enumerate() {
         for symbol in app_syms.iter() {
             if reloc.target() == RelocationTarget::Symbol(symbol.index()) {
-                let func_address = (i as u64 + 1) * PLT_ADDRESS_OFFSET + plt_address;
-                let func_offset = (i as u64 + 1) * PLT_ADDRESS_OFFSET + plt_offset;
+                let plt_entry_offset = arch.plt_header_size() + i as u64 * arch.plt_entry_size();
+                let func_address = plt_entry_offset + plt_address;
+                let func_offset = plt_entry_offset + plt_offset;
                 app_func_addresses.insert(func_address, symbol.name().unwrap());
                 md.plt_addresses.insert(
                     symbol.name().unwrap().to_string(),
@@ -407,7 +638,7 @@ pub(crate) fn preprocess_elf(
     // look at the text (i.e. code) sections and see collect work needs to be done
     let text_disassembly_start = Instant::now();
 
-    let mut surgeries = Surgeries::new(&app_syms, app_func_addresses);
+    let mut surgeries = Surgeries::new(arch, &app_syms, app_func_addresses);
     surgeries.append_text_sections(exec_data, &exec_obj, verbose);
     md.surgeries = surgeries.surgeries;
 
@@ -427,7 +658,7 @@ pub(crate) fn preprocess_elf(
                 dynamic_lib_count,
                 shared_lib_index,
             } = scan_elf_dynamic_deps(
-                &exec_obj, &mut md, &app_syms, shared_lib, exec_data, verbose,
+                arch, &exec_obj, &mut md, &app_syms, shared_lib, exec_data, verbose,
             );
 
             scanning_dynamic_deps_duration = scanning_dynamic_deps_start.elapsed();
@@ -436,6 +667,7 @@ pub(crate) fn preprocess_elf(
 
             // TODO little endian
             gen_elf_le(
+                arch,
                 exec_data,
                 &mut md,
                 preprocessed_path,
@@ -506,6 +738,7 @@ pub(crate) fn preprocess_elf(
 
 #[allow(clippy::too_many_arguments)]
 fn gen_elf_le(
+    arch: ElfArch,
     exec_data: &[u8],
     md: &mut metadata::Metadata,
     preprocessed_path: &Path,
@@ -538,7 +771,7 @@ fn gen_elf_le(
     let added_header_count = 2;
     md.added_byte_count = ph_ent_size as u64 * added_header_count;
     md.added_byte_count = md.added_byte_count
-        + (MIN_SECTION_ALIGNMENT as u64 - md.added_byte_count % MIN_SECTION_ALIGNMENT as u64);
+        + (arch.shift_alignment() - md.added_byte_count % arch.shift_alignment());
     let ph_end = ph_offset as usize + ph_num as usize * ph_ent_size as usize;
     let physical_shift_start = ph_end as u64;
 
@@ -678,18 +911,18 @@ fn gen_elf_le(
                 rel.r_offset = endian::U64::new(LE, r_offset + md.added_byte_count);
                 // Deal with potential adjusts to absolute jumps.
                 // TODO: Verify other relocation types.
-                if rel.r_type(LE, false) == elf::R_X86_64_RELATIVE {
+                if rel.r_type(LE, false) == arch.r_relative() {
                     let r_addend = rel.r_addend.get(LE);
                     rel.r_addend.set(LE, r_addend + md.added_byte_count as i64);
                 }
             }
             // If the relocation goes to a roc function, we need to surgically link it and change it to relative.
             let r_type = rel.r_type(NativeEndian, false);
-            if r_type == elf::R_X86_64_GLOB_DAT {
+            if r_type == arch.r_glob_dat() {
                 let r_sym = rel.r_sym(NativeEndian, false);
                 for (name, index) in got_app_syms.iter() {
                     if *index as u32 == r_sym {
-                        rel.set_r_info(LE, false, 0, elf::R_X86_64_RELATIVE);
+                        rel.set_r_info(LE, false, 0, arch.r_relative());
                         let addend_addr = sec_offset as usize
                             + i * mem::size_of::<elf::Rela64<LE>>()
                             // This 16 skips the first 2 fields and gets to the addend field.
@@ -717,8 +950,7 @@ fn gen_elf_le(
             .filter_map(|(i, rel)| {
                 let r_type = rel.r_type(NativeEndian, false);
                 let r_sym = rel.r_sym(NativeEndian, false);
-                if r_type == elf::R_X86_64_JUMP_SLOT && app_sym_indices.contains(&(r_sym as usize))
-                {
+                if r_type == arch.r_jump_slot() && app_sym_indices.contains(&(r_sym as usize)) {
                     Some(i)
                 } else {
                     None
@@ -734,7 +966,7 @@ fn gen_elf_le(
         for i in to_remove.iter() {
             relocations.swap(*i, j);
             let r_sym = relocations[j].r_sym(NativeEndian, false);
-            relocations[j].set_r_info(LE, false, r_sym, elf::R_X86_64_NONE);
+            relocations[j].set_r_info(LE, false, r_sym, arch.r_none());
             j -= 1;
         }
 
@@ -897,6 +1129,7 @@ fn gen_elf_le(
 }
 
 fn scan_elf_dynamic_deps(
+    arch: ElfArch,
     exec_obj: &object::File,
     md: &mut metadata::Metadata,
     app_syms: &[Symbol],
@@ -1044,7 +1277,7 @@ fn scan_elf_dynamic_deps(
         }
     })
     .filter_map(|(_, reloc)| {
-        if let RelocationKind::Elf(elf::R_X86_64_GLOB_DAT) = reloc.kind() {
+        if reloc.kind() == RelocationKind::Elf(arch.r_glob_dat()) {
             for symbol in app_syms.iter() {
                 if reloc.target() == RelocationTarget::Symbol(symbol.index()) {
                     return Some((symbol.name().unwrap().to_string(), symbol.index().0));
@@ -1063,7 +1296,7 @@ fn scan_elf_dynamic_deps(
         }
     })
     .filter_map(|(_, reloc)| {
-        if let RelocationKind::Elf(elf::R_X86_64_JUMP_SLOT) = reloc.kind() {
+        if reloc.kind() == RelocationKind::Elf(arch.r_jump_slot()) {
             for symbol in app_syms.iter() {
                 if reloc.target() == RelocationTarget::Symbol(symbol.index()) {
                     return Some(symbol.index().0);
@@ -1184,6 +1417,7 @@ fn surgery_elf_help(
         internal_error!("Only 64bit little endian elf currently supported for surgery");
     }
     let exec_header = load_struct_inplace::<elf::FileHeader64<LE>>(exec_mmap, 0);
+    let arch = ElfArch::from_machine(exec_header.e_machine.get(NativeEndian));
 
     let ph_offset = exec_header.e_phoff.get(NativeEndian);
     let ph_ent_size = exec_header.e_phentsize.get(NativeEndian);
@@ -1376,6 +1610,18 @@ fn surgery_elf_help(
                     if let Some(target_offset) = target_offset {
                         let virt_base = section_virtual_offset as usize + rel.0 as usize;
                         let base = section_offset as usize + rel.0 as usize;
+
+                        // On aarch64, most relocations patch an immediate inside an instruction.
+                        if arch == ElfArch::Aarch64 && rel.1.kind() != RelocationKind::Relative {
+                            apply_aarch64_relocation(
+                                &mut exec_mmap[base..][..4],
+                                rel.1.kind(),
+                                target_offset + rel.1.addend(),
+                                virt_base as i64,
+                            );
+                            continue;
+                        }
+
                         let target: i64 = match rel.1.kind() {
                             RelocationKind::Relative | RelocationKind::PltRelative => {
                                 target_offset - virt_base as i64 + rel.1.addend()
@@ -1551,6 +1797,23 @@ fn surgery_elf_help(
                 VirtualOffset::Absolute => 0,
             };
             match s.size {
+                4 if arch == ElfArch::Aarch64 => {
+                    let place =
+                        &mut exec_mmap[(s.file_offset + md.added_byte_count) as usize..][..4];
+                    let inst = u32::from_le_bytes(<[u8; 4]>::try_from(&*place).unwrap());
+                    let inst = aarch64_set_branch_target(
+                        inst,
+                        surgery_virt_offset,
+                        func_virt_offset as i64,
+                    );
+                    if verbose {
+                        println!(
+                            "\tTarget Jump: {:+x}",
+                            func_virt_offset as i64 - surgery_virt_offset
+                        );
+                    }
+                    place.copy_from_slice(&inst.to_le_bytes());
+                }
                 4 => {
                     let target = (func_virt_offset as i64 - surgery_virt_offset) as i32;
                     if verbose {
@@ -1580,18 +1843,41 @@ fn surgery_elf_help(
         if let Some((plt_off, plt_vaddr)) = md.plt_addresses.get(func_name) {
             let plt_off = (*plt_off + md.added_byte_count) as usize;
             let plt_vaddr = *plt_vaddr + md.added_byte_count;
-            let jmp_inst_len = 5;
-            let target =
-                (func_virt_offset as i64 - (plt_vaddr as i64 + jmp_inst_len as i64)) as i32;
             if verbose {
                 println!("\tPLT: {:+x}, {:+x}", plt_off, plt_vaddr);
-                println!("\tTarget Jump: {:+x}", target);
             }
-            let data = target.to_le_bytes();
-            exec_mmap[plt_off] = 0xE9;
-            exec_mmap[plt_off + 1..plt_off + jmp_inst_len].copy_from_slice(&data);
-            for i in jmp_inst_len..PLT_ADDRESS_OFFSET as usize {
-                exec_mmap[plt_off + i] = 0x90;
+            match arch {
+                ElfArch::X86_64 => {
+                    let jmp_inst_len = 5;
+                    let target =
+                        (func_virt_offset as i64 - (plt_vaddr as i64 + jmp_inst_len as i64)) as i32;
+                    if verbose {
+                        println!("\tTarget Jump: {:+x}", target);
+                    }
+                    let data = target.to_le_bytes();
+                    exec_mmap[plt_off] = 0xE9;
+                    exec_mmap[plt_off + 1..plt_off + jmp_inst_len].copy_from_slice(&data);
+                    for i in jmp_inst_len..arch.plt_entry_size() as usize {
+                        exec_mmap[plt_off + i] = 0x90;
+                    }
+                }
+                ElfArch::Aarch64 => {
+                    let jmp_inst = aarch64_set_branch_target(
+                        AARCH64_BRANCH,
+                        plt_vaddr as i64,
+                        func_virt_offset as i64,
+                    );
+                    if verbose {
+                        println!(
+                            "\tTarget Jump: {:+x}",
+                            func_virt_offset as i64 - plt_vaddr as i64
+                        );
+                    }
+                    exec_mmap[plt_off..][..4].copy_from_slice(&jmp_inst.to_le_bytes());
+                    for i in (4..arch.plt_entry_size() as usize).step_by(4) {
+                        exec_mmap[plt_off + i..][..4].copy_from_slice(&AARCH64_NOP.to_le_bytes());
+                    }
+                }
             }
         }
 
@@ -1696,6 +1982,57 @@ mod tests {
         )
     }
 
+    fn patch_aarch64(inst: u32, r_type: u32, s_plus_a: i64, p: i64) -> u32 {
+        let mut place = inst.to_le_bytes();
+        apply_aarch64_relocation(&mut place, RelocationKind::Elf(r_type), s_plus_a, p);
+        u32::from_le_bytes(place)
+    }
+
+    #[test]
+    fn aarch64_branches() {
+        // bl #0x1000
+        let bl = aarch64_set_branch_target(0x9400_0000, 0x1000, 0x2000);
+        assert_eq!(bl, 0x9400_0400);
+        assert!(aarch64_is_branch(bl));
+        assert_eq!(aarch64_branch_target(0x1000, bl), 0x2000);
+
+        // b #-0x1000
+        let b = aarch64_set_branch_target(AARCH64_BRANCH, 0x2000, 0x1000);
+        assert_eq!(b, 0x17FF_FC00);
+        assert!(aarch64_is_branch(b));
+        assert_eq!(aarch64_branch_target(0x2000, b), 0x1000);
+
+        assert!(!aarch64_is_branch(AARCH64_NOP));
+        assert_eq!(
+            patch_aarch64(0x9400_0000, elf::R_AARCH64_CALL26, 0x2000, 0x1000),
+            0x9400_0400
+        );
+    }
+
+    #[test]
+    fn aarch64_page_relocations() {
+        let p = 0x40_1234;
+        let s_plus_a = 0x41_2345;
+
+        // adrp x0, #0x11000
+        let adrp = patch_aarch64(0x9000_0000, elf::R_AARCH64_ADR_PREL_PG_HI21, s_plus_a, p);
+        assert_eq!(adrp, 0xB000_0080);
+        let adrp = patch_aarch64(0x9000_0000, elf::R_AARCH64_ADR_GOT_PAGE, s_plus_a, p);
+        assert_eq!(adrp, 0xB000_0080);
+
+        // add x0, x0, #0x345
+        let add = patch_aarch64(0x9100_0000, elf::R_AARCH64_ADD_ABS_LO12_NC, s_plus_a, p);
+        assert_eq!(add, 0x910D_1400);
+
+        // ldr x2, [x0, #0x348]
+        let ldr = patch_aarch64(0xF940_0002, elf::R_AARCH64_LDST64_ABS_LO12_NC, 0x41_2348, p);
+        assert_eq!(ldr, 0xF941_A402);
+
+        // ldr x1, [x0, :got_lo12:sym] is relaxed to add x1, x0, #0x345
+        let ldr_got = patch_aarch64(0xF940_0001, elf::R_AARCH64_LD64_GOT_LO12_NC, s_plus_a, p);
+        assert_eq!(ldr_got, 0x910D_1401);
+    }
+
     #[allow(dead_code)]
     fn zig_host_app_help(dir: &Path, target: &Triple) {
         let host_zig = indoc!(
@@ -1722,6 +2059,11 @@ mod tests {
         );
 
         let zig = std::env::var("ROC_ZIG").unwrap_or_else(|_| "zig".into());
+        let zig_target = match target.architecture {
+            target_lexicon::Architecture::X86_64 => "x86_64-linux-gnu",
+            target_lexicon::Architecture::Aarch64(_) => "aarch64-linux-gnu",
+            other => panic!("no zig target for {other:?}"),
+        };
 
         std::fs::write(dir.join("host.zig"), host_zig.as_bytes()).unwrap();
         std::fs::write(dir.join("app.zig"), app_zig.as_bytes()).unwrap();
@@ -1734,7 +2076,7 @@ mod tests {
                 "app.zig",
                 "-fPIC",
                 "-target",
-                "x86_64-linux-gnu",
+                zig_target,
                 "-OReleaseFast",
             ])
             .output()
@@ -1763,7 +2105,7 @@ mod tests {
                 .collect()
         };
 
-        let dylib_bytes = crate::generate_dylib::create_dylib_elf64(&names).unwrap();
+        let dylib_bytes = crate::generate_dylib::create_dylib_elf64(&names, target).unwrap();
         std::fs::write(dir.join("libapp.so"), dylib_bytes).unwrap();
 
         // now we can compile the host (it uses libapp.so, hence the order here)
@@ -1776,7 +2118,7 @@ mod tests {
                 "-fPIE",
                 "-lc",
                 "-target",
-                "x86_64-linux-gnu",
+                zig_target,
                 "-OReleaseFast",
             ])
             .output()
@@ -1814,6 +2156,24 @@ mod tests {
         );
     }
 
+    #[allow(dead_code)]
+    fn check_final_output(mut command: std::process::Command) {
+        let output = command.output().unwrap();
+
+        if !output.status.success() {
+            use std::io::Write;
+
+            std::io::stdout().write_all(&output.stdout).unwrap();
+            std::io::stderr().write_all(&output.stderr).unwrap();
+
+            panic!("app.exe failed");
+        }
+
+        let output = String::from_utf8_lossy(&output.stdout);
+
+        assert_eq!("Hello foo\n", output);
+    }
+
     #[cfg(target_os = "linux")]
     #[test]
     fn zig_host_app() {
@@ -1824,22 +2184,34 @@ mod tests {
 
         zig_host_app_help(dir, &Triple::from_str("x86_64-unknown-linux-musl").unwrap());
 
-        let output = std::process::Command::new(&dir.join("final"))
-            .current_dir(dir)
-            .output()
-            .unwrap();
+        let mut command = std::process::Command::new(&dir.join("final"));
+        command.current_dir(dir);
 
-        if !output.status.success() {
-            use std::io::Write;
+        check_final_output(command);
+    }
 
-            std::io::stdout().write_all(&output.stdout).unwrap();
-            std::io::stderr().write_all(&output.stderr).unwrap();
+    #[cfg(target_os = "linux")]
+    #[test]
+    #[ignore = "needs qemu-aarch64 and an aarch64 glibc; run with --ignored"]
+    fn zig_host_app_aarch64() {
+        use std::str::FromStr;
 
-            panic!("app.exe failed");
-        }
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path();
 
-        let output = String::from_utf8_lossy(&output.stdout);
+        zig_host_app_help(dir, &Triple::from_str("aarch64-unknown-linux-gnu").unwrap());
 
-        assert_eq!("Hello foo\n", output);
+        // qemu-user loads the aarch64 dynamic linker and libc from QEMU_LD_PREFIX.
+        // By default, look where Debian and Ubuntu install their aarch64 cross libc.
+        let ld_prefix =
+            std::env::var("QEMU_LD_PREFIX").unwrap_or_else(|_| "/usr/aarch64-linux-gnu".into());
+
+        let mut command = std::process::Command::new("qemu-aarch64");
+        command
+            .current_dir(dir)
+            .env("QEMU_LD_PREFIX", ld_prefix)
+            .arg(dir.join("final"));
+
+        check_final_output(command);
     }
 }
@@ -7,7 +7,7 @@ use memmap2::{Mmap, MmapMut};
 use object::Object;
 use roc_build::link::{get_target_triple_str, rebuild_host, LinkType};
 use roc_error_macros::internal_error;
-use roc_load::{EntryPoint, ExecutionMode, LoadConfig, Threading};
+use roc_load::{EmitIr, EntryPoint, ExecutionMode, LoadConfig, Threading};
 use roc_mono::ir::OptLevel;
 use roc_packaging::cache::RocCacheDir;
 use roc_reporting::report::{RenderTarget, DEFAULT_PALETTE};
@@ -33,6 +33,13 @@ pub fn supported(link_type: LinkType, target: &Triple) -> bool {
                 ..
             } => true,
 
+            Triple {
+                architecture: target_lexicon::Architecture::Aarch64(_),
+                operating_system: target_lexicon::OperatingSystem::Linux,
+                binary_format: target_lexicon::BinaryFormat::Elf,
+                ..
+            } => true,
+
             // macho support is incomplete
             Triple {
                 operating_system: target_lexicon::OperatingSystem::Darwin,
@@ -131,6 +138,9 @@ pub fn generate_stub_lib(
             palette: DEFAULT_PALETTE,
             threading: Threading::AllAvailable,
             exec_mode: ExecutionMode::Executable,
+            fold_constants: true,
+            emit_ir: EmitIr::default(),
+            trace_refcounts: false,
         },
     )
     .unwrap_or_else(|problem| todo!("{:?}", problem));
@@ -188,6 +198,8 @@ fn make_stub_dll_symbols(
                 format!("roc__{}_1_{}_caller", sym, closure_type),
                 format!("roc__{}_1_{}_size", sym, closure_type),
                 format!("roc__{}_1_{}_result_size", sym, closure_type),
+                format!("roc__{}_1_{}_inc", sym, closure_type),
+                format!("roc__{}_1_{}_dec", sym, closure_type),
             ]);
         }
     }
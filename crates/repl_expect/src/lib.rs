@@ -94,7 +94,7 @@ mod test {
     use indoc::indoc;
     use pretty_assertions::assert_eq;
     use roc_gen_llvm::{llvm::build::LlvmBackendMode, run_roc::RocCallResult, run_roc_dylib};
-    use roc_load::{ExecutionMode, LoadConfig, LoadMonomorphizedError, Threading};
+    use roc_load::{EmitIr, ExecutionMode, LoadConfig, LoadMonomorphizedError, Threading};
     use roc_packaging::cache::RocCacheDir;
     use roc_reporting::report::{RenderTarget, DEFAULT_PALETTE};
     use target_lexicon::Triple;
@@ -125,6 +125,9 @@ mod test {
             palette: DEFAULT_PALETTE,
             threading: Threading::Single,
             exec_mode: ExecutionMode::Test,
+            fold_constants: true,
+            emit_ir: EmitIr::default(),
+            trace_refcounts: false,
         };
         let loaded = match roc_load::load_and_monomorphize_from_str(
             arena,
@@ -153,6 +156,8 @@ mod test {
             loaded,
             opt_level,
             LlvmBackendMode::CliTest,
+            false,
+            false,
         )
         .unwrap();
 
@@ -10,6 +10,7 @@ use bumpalo::collections::Vec as BumpVec;
 use bumpalo::Bump;
 use inkwell::context::Context;
 use roc_build::link::llvm_module_to_dylib;
+use roc_builtins::bitcode;
 use roc_can::expr::ExpectLookup;
 use roc_collections::{MutSet, VecMap};
 use roc_error_macros::internal_error;
@@ -179,6 +180,18 @@ pub fn run_toplevel_expects<'a, W: std::io::Write>(
     )
 }
 
+/// Print the allocations that are still live, for `roc test --check-memory`.
+/// Returns the number of problems the memory checker found.
+pub fn report_memory_problems(lib: &libloading::Library) -> usize {
+    unsafe {
+        let report: libloading::Symbol<unsafe extern "C" fn() -> usize> = lib
+            .get(bitcode::UTILS_MEMCHECK_REPORT.as_bytes())
+            .unwrap_or_else(|e| internal_error!("memory checker is not linked in: {}", e));
+
+        report()
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn run_expects_with_memory<'a, W: std::io::Write>(
     writer: &mut W,
@@ -704,6 +717,8 @@ pub fn expect_mono_module_to_dylib<'a>(
     loaded: MonomorphizedModule<'a>,
     opt_level: OptLevel,
     mode: LlvmBackendMode,
+    check_memory: bool,
+    trace_refcounts: bool,
 ) -> Result<
     (
         libloading::Library,
@@ -747,6 +762,8 @@ pub fn expect_mono_module_to_dylib<'a>(
         mode,
         // important! we don't want any procedures to get the C calling convention
         exposed_to_host: MutSet::default(),
+        check_memory,
+        trace_refcounts,
     };
 
     // Add roc_alloc, roc_realloc, and roc_dealloc, since the repl has no
@@ -2,6 +2,7 @@ use std::path::PathBuf;
 
 use roc_collections::MutMap;
 use roc_module::symbol::{Interns, ModuleId};
+pub use roc_problem::{DiagnosticLevels, Level};
 use roc_region::all::LineInfo;
 use roc_solve_problem::TypeError;
 
@@ -50,12 +51,21 @@ impl Problems {
     }
 }
 
+/// The names of every kind of warning, which are the ones whose level can be changed
+pub fn warning_names() -> impl Iterator<Item = &'static str> {
+    roc_problem::can::WARNING_NAMES
+        .iter()
+        .chain(roc_solve_problem::WARNING_NAMES)
+        .copied()
+}
+
 pub fn report_problems(
     total_problems: usize,
     sources: &MutMap<ModuleId, (PathBuf, Box<str>)>,
     interns: &Interns,
     can_problems: &mut MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
     type_problems: &mut MutMap<ModuleId, Vec<TypeError>>,
+    levels: &DiagnosticLevels,
 ) -> Problems {
     use crate::report::{can_problem, type_problem, Report, RocDocAllocator, DEFAULT_PALETTE};
     use roc_problem::Severity::*;
@@ -79,8 +89,12 @@ pub fn report_problems(
         let problems = can_problems.remove(home).unwrap_or_default();
 
         for problem in problems.into_iter() {
+            let name = problem.name();
             let report = can_problem(&alloc, &lines, module_path.clone(), problem);
-            let severity = report.severity;
+            let severity = match levels.severity(name, report.severity) {
+                Some(severity) => severity,
+                None => continue,
+            };
             let mut buf = String::new();
 
             report.render_color_terminal(&mut buf, &alloc, &palette);
@@ -98,8 +112,13 @@ pub fn report_problems(
         let problems = type_problems.remove(home).unwrap_or_default();
 
         for problem in problems {
+            let name = problem.name();
+
             if let Some(report) = type_problem(&alloc, &lines, module_path.clone(), problem) {
-                let severity = report.severity;
+                let severity = match levels.severity(name, report.severity) {
+                    Some(severity) => severity,
+                    None => continue,
+                };
                 let mut buf = String::new();
 
                 report.render_color_terminal(&mut buf, &alloc, &palette);
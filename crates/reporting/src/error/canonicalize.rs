@@ -71,7 +71,7 @@ pub fn can_problem<'b>(
     let severity = problem.severity();
 
     match problem {
-        Problem::UnusedDef(symbol, region) => {
+        Problem::UnusedDef(symbol, region, _) => {
             let line =
                 r#" then remove it so future readers of your code don't wonder why it is there."#;
 
@@ -88,7 +88,7 @@ pub fn can_problem<'b>(
 
             title = UNUSED_DEF.to_string();
         }
-        Problem::UnusedImport(symbol, region) => {
+        Problem::UnusedImport(symbol, region, _) => {
             doc = alloc.stack([
                 alloc.concat([
                     alloc.symbol_qualified(symbol),
@@ -104,7 +104,7 @@ pub fn can_problem<'b>(
 
             title = UNUSED_IMPORT.to_string();
         }
-        Problem::UnusedModuleImport(module_id, region) => {
+        Problem::UnusedModuleImport(module_id, region, _) => {
             doc = alloc.stack([
                 alloc.concat([
                     alloc.reflow("Nothing from "),
@@ -147,7 +147,7 @@ pub fn can_problem<'b>(
 
             title = "DEFINITIONs ONLY USED IN RECURSION".to_string();
         }
-        Problem::ExposedButNotDefined(symbol) => {
+        Problem::ExposedButNotDefined(symbol, _) => {
             doc = alloc.stack([
                 alloc.symbol_unqualified(symbol).append(
                     alloc.reflow(" is listed as exposed, but it isn't defined in this module."),
@@ -176,7 +176,7 @@ pub fn can_problem<'b>(
 
             title = UNKNOWN_GENERATES_WITH.to_string();
         }
-        Problem::UnusedArgument(closure_symbol, is_anonymous, argument_symbol, region) => {
+        Problem::UnusedArgument(closure_symbol, is_anonymous, argument_symbol, region, _) => {
             let line = "\". Adding an underscore at the start of a variable name is a way of saying that the variable is not used.";
 
             doc = alloc.stack([
@@ -13,8 +13,8 @@ use roc_module::symbol::Symbol;
 use roc_problem::Severity;
 use roc_region::all::{LineInfo, Region};
 use roc_solve_problem::{
-    NotDerivableContext, NotDerivableDecode, NotDerivableEq, TypeError, UnderivableReason,
-    Unfulfilled,
+    NotDerivableContext, NotDerivableDecode, NotDerivableEq, NotDerivableOrd, TypeError,
+    UnderivableReason, Unfulfilled,
 };
 use roc_std::RocDec;
 use roc_types::pretty_print::{Parens, WILDCARD};
@@ -207,6 +207,44 @@ pub fn type_problem<'b>(
                 severity,
             })
         }
+        TypedHole { region, typ, fits } => {
+            // compiler-generated bindings aren't something the user could write here
+            let fits: Vec<_> = fits
+                .into_iter()
+                .filter(|(symbol, _)| {
+                    symbol
+                        .as_str(alloc.interns)
+                        .starts_with(|c: char| c.is_ascii_lowercase())
+                })
+                .collect();
+
+            let mut stack = vec![
+                alloc.reflow("This hole needs to be filled in:"),
+                alloc.region(lines.convert_region(region)),
+                alloc.reflow("It should be a value of type:"),
+                alloc.type_block(error_type_to_doc(alloc, typ)),
+            ];
+
+            if !fits.is_empty() {
+                stack.push(alloc.reflow("These values in scope have a type that fits:"));
+                stack.push(
+                    alloc.type_block(alloc.vcat(fits.into_iter().map(|(symbol, typ)| {
+                        alloc.concat([
+                            alloc.symbol_unqualified(symbol),
+                            alloc.text(" : "),
+                            error_type_to_doc(alloc, typ),
+                        ])
+                    }))),
+                );
+            }
+
+            Some(Report {
+                title: "TYPED HOLE".to_string(),
+                filename,
+                doc: alloc.stack(stack),
+                severity,
+            })
+        }
     }
 }
 
@@ -397,6 +435,18 @@ fn underivable_hint<'b>(
                 ])))
             }
         },
+        NotDerivableContext::Ord(reason) => match reason {
+            NotDerivableOrd::FloatingPoint => {
+                Some(alloc.note("").append(alloc.concat([
+                    alloc.reflow("I can't derive "),
+                    alloc.symbol_qualified(Symbol::ORD_COMPARE),
+                    alloc.reflow(" for floating-point types. That's because Roc's floating-point numbers do not have a total order - in Roc, `NaN` is neither less than, greater than, nor equal to `NaN`."),
+                    alloc.reflow(" If a type doesn't have a total order, it cannot support the "),
+                    alloc.symbol_unqualified(Symbol::ORD_ORD),
+                    alloc.reflow(" ability!"),
+                ])))
+            }
+        },
     }
 }
 
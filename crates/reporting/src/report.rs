@@ -1,3 +1,4 @@
+use roc_highlight_ansi::ansi::{highlight_spans, HighlightKind, Theme};
 use roc_module::ident::Ident;
 use roc_module::ident::{Lowercase, ModuleName, TagName, Uppercase};
 use roc_module::symbol::{Interns, ModuleId, PQModuleName, PackageQualified, Symbol};
@@ -198,6 +199,8 @@ pub struct Palette {
     pub typo: &'static str,
     pub typo_suggestion: &'static str,
     pub parser_suggestion: &'static str,
+    pub literal: &'static str,
+    pub comment: &'static str,
     pub bold: &'static str,
     pub underline: &'static str,
     pub reset: &'static str,
@@ -225,6 +228,8 @@ const fn default_palette_from_style_codes(codes: StyleCodes) -> Palette {
         typo: codes.yellow,
         typo_suggestion: codes.yellow,
         parser_suggestion: codes.yellow,
+        literal: codes.magenta,
+        comment: codes.cyan,
         bold: codes.bold,
         underline: codes.underline,
         reset: codes.reset,
@@ -233,6 +238,19 @@ const fn default_palette_from_style_codes(codes: StyleCodes) -> Palette {
 
 pub const DEFAULT_PALETTE: Palette = default_palette_from_style_codes(ANSI_STYLE_CODES);
 
+impl Palette {
+    /// The styles to highlight code with outside of a report, e.g. in a diff.
+    pub fn code_theme(&self) -> Theme {
+        Theme {
+            keyword: self.keyword,
+            uppercase_ident: self.alias,
+            literal: self.literal,
+            comment: self.comment,
+            reset: self.reset,
+        }
+    }
+}
+
 pub const DEFAULT_PALETTE_HTML: Palette = default_palette_from_style_codes(HTML_STYLE_CODES);
 
 /// A machine-readable format for text styles (colors and other styles)
@@ -518,6 +536,25 @@ impl<'a> RocDocAllocator<'a> {
             .append(line)
     }
 
+    /// Source code, split up so that its tokens can be styled. This must be (part of) a code block.
+    fn highlighted_code(&'a self, code: &'a str) -> DocBuilder<'a, Self, Annotation> {
+        self.concat(highlight_spans(code).into_iter().map(|(kind, range)| {
+            let text = self.text(&code[range]);
+
+            match kind {
+                HighlightKind::Keyword => text.annotate(Annotation::Keyword),
+                HighlightKind::UppercaseIdent => text.annotate(Annotation::Alias),
+                HighlightKind::Literal => text.annotate(Annotation::Literal),
+                HighlightKind::Comment => text.annotate(Annotation::Comment),
+                HighlightKind::LowercaseIdent
+                | HighlightKind::Operator
+                | HighlightKind::Delimiter
+                | HighlightKind::Malformed
+                | HighlightKind::Plain => text,
+            }
+        }))
+    }
+
     pub fn region_all_the_things(
         &'a self,
         region: LineColumnRegion,
@@ -545,7 +582,7 @@ impl<'a> RocDocAllocator<'a> {
             let line = self.src_lines[i as usize];
             let is_line_empty = line.trim().is_empty();
             let rest_of_line = if !is_line_empty {
-                self.text(line).indent(indent)
+                self.highlighted_code(line).indent(indent)
             } else {
                 self.nil()
             };
@@ -669,7 +706,7 @@ impl<'a> RocDocAllocator<'a> {
             let line: &str = self.src_lines.get(i as usize).unwrap_or(&"");
             let is_line_empty = line.trim().is_empty();
             let rest_of_line = if !is_line_empty {
-                self.text(line)
+                self.highlighted_code(line)
                     .annotate(Annotation::CodeBlock)
                     .indent(indent)
             } else {
@@ -758,7 +795,8 @@ impl<'a> RocDocAllocator<'a> {
             };
 
             let rest_of_line = if !line.trim().is_empty() {
-                self.text(line).annotate(Annotation::CodeBlock)
+                self.highlighted_code(line)
+                    .annotate(Annotation::CodeBlock)
             } else {
                 self.nil()
             };
@@ -839,6 +877,8 @@ pub enum Annotation {
     Tip,
     Header,
     ParserSuggestion,
+    Literal,
+    Comment,
 }
 
 /// Render with minimal formatting
@@ -1046,6 +1086,12 @@ where
             ParserSuggestion => {
                 self.write_str(self.palette.parser_suggestion)?;
             }
+            Literal => {
+                self.write_str(self.palette.literal)?;
+            }
+            Comment => {
+                self.write_str(self.palette.comment)?;
+            }
             TypeBlock | InlineTypeBlock | Tag | RecordField => { /* nothing yet */ }
         }
         self.style_stack.push(*annotation);
@@ -1060,8 +1106,13 @@ where
             Some(annotation) => match annotation {
                 Emphasized | Url | TypeVariable | Alias | Symbol | BinOp | Error | GutterBar
                 | Ellipsis | Typo | TypoSuggestion | ParserSuggestion | Structure | CodeBlock
-                | PlainText | LineNumber | Tip | Module | Header | Keyword => {
+                | PlainText | LineNumber | Tip | Module | Header | Keyword | Literal | Comment => {
                     self.write_str(self.palette.reset)?;
+
+                    // the reset also undid the style of the surrounding code
+                    if let Some(CodeBlock) = self.style_stack.last() {
+                        self.write_str(self.palette.code_block)?;
+                    }
                 }
 
                 TypeBlock | InlineTypeBlock | Tag | Opaque | RecordField => { /* nothing yet */ }
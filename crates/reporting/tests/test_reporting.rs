@@ -13,7 +13,9 @@ mod test_reporting {
     use indoc::indoc;
     use roc_can::abilities::AbilitiesStore;
     use roc_can::expr::PendingDerives;
-    use roc_load::{self, ExecutionMode, LoadConfig, LoadedModule, LoadingProblem, Threading};
+    use roc_load::{
+        self, EmitIr, ExecutionMode, LoadConfig, LoadedModule, LoadingProblem, Threading,
+    };
     use roc_module::symbol::{Interns, ModuleId};
     use roc_packaging::cache::RocCacheDir;
     use roc_parse::module::parse_header;
@@ -127,6 +129,9 @@ mod test_reporting {
                 palette: DEFAULT_PALETTE,
                 threading: Threading::Single,
                 exec_mode: ExecutionMode::Check,
+                fold_constants: true,
+                emit_ir: EmitIr::default(),
+                trace_refcounts: false,
             };
             let result = roc_load::load_and_typecheck(
                 arena,
@@ -9601,7 +9606,7 @@ In roc, functions are always written as a lambda, like{}
 
     Only builtin abilities can be derived.
 
-    Note: The builtin abilities are `Encoding`, `Decoding`, `Hash`, `Eq`
+    Note: The builtin abilities are `Encoding`, `Decoding`, `Hash`, `Eq`, `Ord`
     "###
     );
 
@@ -10059,21 +10064,113 @@ In roc, functions are always written as a lambda, like{}
             f 1 _ 1
             "#
         ),
-        |golden| pretty_assertions::assert_eq!(
-            golden,
-            &format!(
-                r###"── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+        @r###"
+    ── TYPED HOLE ──────────────────────────────────────────── /code/proj/Main.roc ─
 
-Underscores are not allowed in identifier names:
+    This hole needs to be filled in:
 
-6│      f 1 _ 1
-{}
+    6│      f 1 _ 1
+                ^
 
-I recommend using camelCase. It's the standard style in Roc code!
-"###,
-                "  " // TODO make the reporter not insert extraneous spaces here in the first place!
-            ),
-        )
+    It should be a value of type:
+
+        Num *
+    "###
+    );
+
+    test_report!(
+        typed_hole_lists_fitting_bindings,
+        indoc!(
+            r#"
+            greet : Str -> Str
+            greet = \name ->
+                suffix = "!"
+
+                Str.concat _ (Str.concat name suffix)
+
+            greet "Roc"
+            "#
+        ),
+        @r###"
+    ── TYPED HOLE ──────────────────────────────────────────── /code/proj/Main.roc ─
+
+    This hole needs to be filled in:
+
+    8│          Str.concat _ (Str.concat name suffix)
+                           ^
+
+    It should be a value of type:
+
+        Str
+
+    These values in scope have a type that fits:
+
+        name : Str
+        suffix : Str
+    "###
+    );
+
+    test_report!(
+        typed_hole_with_ability_constraint,
+        indoc!(
+            r#"
+            isSame : a, a -> Bool | a has Eq
+            isSame = \x, y ->
+                name = "x"
+
+                Str.isEmpty name || x == y || x == _
+
+            isSame 1u8 2u8
+            "#
+        ),
+        @r###"
+    ── TYPED HOLE ──────────────────────────────────────────── /code/proj/Main.roc ─
+
+    This hole needs to be filled in:
+
+    8│          Str.isEmpty name || x == y || x == _
+                                                   ^
+
+    It should be a value of type:
+
+        a | a has Eq
+
+    These values in scope have a type that fits:
+
+        x : a | a has Eq
+        y : a | a has Eq
+    "###
+    );
+
+    test_report!(
+        typed_hole_lists_polymorphic_bindings,
+        indoc!(
+            r#"
+            count : List a -> Nat
+            count = \list -> List.len list
+
+            measure : (List Str -> Nat) -> Nat
+            measure = \f -> f ["a", "b"]
+
+            measure _ + count [1, 2]
+            "#
+        ),
+        @r###"
+    ── TYPED HOLE ──────────────────────────────────────────── /code/proj/Main.roc ─
+
+    This hole needs to be filled in:
+
+    10│      measure _ + count [1, 2]
+                     ^
+
+    It should be a value of type:
+
+        List Str -> Nat
+
+    These values in scope have a type that fits:
+
+        count : List a -> Nat
+    "###
     );
 
     test_report!(